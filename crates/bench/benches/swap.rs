@@ -44,6 +44,7 @@ fn swap_proving_time(c: &mut Criterion) {
         delta_2,
         fee,
         claim_address,
+        fvk_recipient.nullifier_key(),
     );
     let fee_blinding = Fr::from(0u64);
     let fee_commitment = swap_plaintext.claim_fee.commit(fee_blinding);
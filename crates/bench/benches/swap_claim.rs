@@ -48,7 +48,9 @@ fn swap_claim_proving_time(c: &mut Criterion) {
         delta_2_i,
         fee,
         claim_address,
+        &nk,
     );
+    let claim_key = swap_plaintext.claim_key(&nk);
     let claim_fee = swap_plaintext.clone().claim_fee;
     let mut sct = tct::Tree::new();
     let swap_commitment = swap_plaintext.swap_commitment();
@@ -56,7 +58,7 @@ fn swap_claim_proving_time(c: &mut Criterion) {
     let anchor = sct.root();
     let state_commitment_proof = sct.witness(swap_commitment).unwrap();
     let position = state_commitment_proof.position();
-    let nullifier = Nullifier::derive(&nk, position, &swap_commitment);
+    let nullifier = Nullifier::derive(&claim_key, position, &swap_commitment);
     let epoch_duration = 20;
     let height = epoch_duration * position.epoch() + position.block();
 
@@ -91,7 +93,7 @@ fn swap_claim_proving_time(c: &mut Criterion) {
     let private = SwapClaimProofPrivate {
         swap_plaintext,
         state_commitment_proof,
-        nk,
+        claim_key,
         lambda_1,
         lambda_2,
         note_blinding_1,
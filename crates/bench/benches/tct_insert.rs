@@ -0,0 +1,79 @@
+use criterion::{criterion_group, criterion_main, BatchSize, BenchmarkId, Criterion, Throughput};
+use decaf377::Fq;
+use penumbra_tct as tct;
+
+/// Generate a block's worth of commitments, of which roughly a third are witnessed, as they might
+/// be when a client syncs a block.
+fn commitments(count: u64) -> Vec<(tct::Witness, tct::StateCommitment)> {
+    (0..count)
+        .map(|i| {
+            let witness = if i % 3 == 0 {
+                tct::Witness::Keep
+            } else {
+                tct::Witness::Forget
+            };
+            (witness, tct::StateCommitment(Fq::from(i)))
+        })
+        .collect()
+}
+
+fn tct_insertion(c: &mut Criterion) {
+    let mut group = c.benchmark_group("tct insertion");
+
+    for count in [100u64, 1_000, 10_000] {
+        let commitments = commitments(count);
+        group.throughput(Throughput::Elements(count));
+
+        group.bench_with_input(
+            BenchmarkId::new("sequential", count),
+            &commitments,
+            |b, commitments| {
+                b.iter_batched(
+                    tct::Tree::new,
+                    |mut tree| {
+                        for &(witness, commitment) in commitments {
+                            tree.insert(witness, commitment).unwrap();
+                        }
+                        tree.root()
+                    },
+                    BatchSize::SmallInput,
+                )
+            },
+        );
+
+        group.bench_with_input(
+            BenchmarkId::new("batch", count),
+            &commitments,
+            |b, commitments| {
+                b.iter_batched(
+                    tct::Tree::new,
+                    |mut tree| {
+                        tree.insert_batch(commitments.iter().copied()).unwrap();
+                        tree.root()
+                    },
+                    BatchSize::SmallInput,
+                )
+            },
+        );
+
+        group.bench_with_input(
+            BenchmarkId::new("block batch", count),
+            &commitments,
+            |b, commitments| {
+                b.iter_batched(
+                    tct::builder::block::Builder::new,
+                    |mut block| {
+                        block.insert_batch(commitments.iter().copied()).unwrap();
+                        block.root()
+                    },
+                    BatchSize::SmallInput,
+                )
+            },
+        );
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, tct_insertion);
+criterion_main!(benches);
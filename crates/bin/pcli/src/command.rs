@@ -1,3 +1,4 @@
+pub use custody::CustodyCmd;
 pub use debug::DebugCmd;
 pub use init::InitCmd;
 pub use query::QueryCmd;
@@ -9,6 +10,7 @@ pub use view::ViewCmd;
 use self::ceremony::CeremonyCmd;
 
 mod ceremony;
+mod custody;
 mod debug;
 mod init;
 mod query;
@@ -65,6 +67,9 @@ pub enum Command {
     /// Follow the threshold signing protocol.
     #[clap(subcommand, display_order = 500)]
     Threshold(ThresholdCmd),
+    /// Manage the custody backend, like the authorization policy it enforces.
+    #[clap(subcommand, display_order = 600)]
+    Custody(CustodyCmd),
 }
 
 impl Command {
@@ -79,6 +84,20 @@ impl Command {
             Command::Debug(cmd) => cmd.offline(),
             Command::Ceremony(_) => false,
             Command::Threshold(cmd) => cmd.offline(),
+            Command::Custody(cmd) => cmd.offline(),
+        }
+    }
+
+    /// Determine if this command uses the custody backend, and so requires
+    /// unlocking an encrypted custody configuration.
+    pub fn needs_custody(&self) -> bool {
+        match self {
+            Command::Init(_) | Command::Query(_) | Command::View(_) | Command::Debug(_) => false,
+            Command::Transaction(_)
+            | Command::Validator(_)
+            | Command::Ceremony(_)
+            | Command::Threshold(_)
+            | Command::Custody(_) => true,
         }
     }
 }
@@ -0,0 +1,66 @@
+use std::path::PathBuf;
+
+use anyhow::{Context, Result};
+use penumbra_custody::policy::{PolicyBundle, SignedPolicyBundle};
+use penumbra_proto::custody::v1::{ExportPolicyRequest, ImportPolicyRequest};
+
+use crate::App;
+
+#[derive(Debug, clap::Subcommand)]
+pub enum CustodyCmd {
+    /// Print the authorization policy enforced by the custody backend, as a
+    /// TOML policy bundle to review and sign.
+    ExportPolicy,
+    /// Replace the authorization policy enforced by the custody backend with
+    /// a signed policy bundle.
+    ///
+    /// The bundle must be signed by the policy signers configured in the
+    /// custody backend, and be newer than the policy it replaces.
+    ImportPolicy {
+        /// The signed policy bundle, as TOML or JSON.
+        bundle: PathBuf,
+    },
+}
+
+impl CustodyCmd {
+    pub fn offline(&self) -> bool {
+        match self {
+            CustodyCmd::ExportPolicy => true,
+            CustodyCmd::ImportPolicy { .. } => true,
+        }
+    }
+
+    #[tracing::instrument(skip(self, app))]
+    pub async fn exec(&self, app: &mut App) -> Result<()> {
+        match self {
+            CustodyCmd::ExportPolicy => {
+                let response = app
+                    .custody
+                    .export_policy(ExportPolicyRequest {})
+                    .await?
+                    .into_inner();
+                let bundle: PolicyBundle = serde_json::from_str(&response.bundle)
+                    .context("custody backend returned an invalid policy bundle")?;
+                print!("{}", toml::to_string_pretty(&bundle)?);
+            }
+            CustodyCmd::ImportPolicy { bundle } => {
+                let contents = std::fs::read_to_string(bundle)
+                    .with_context(|| format!("could not read {}", bundle.display()))?;
+                let signed: SignedPolicyBundle = toml::from_str(&contents)
+                    .or_else(|_| serde_json::from_str(&contents))
+                    .with_context(|| {
+                        format!("invalid signed policy bundle in {}", bundle.display())
+                    })?;
+                let response = app
+                    .custody
+                    .import_policy(ImportPolicyRequest {
+                        signed_bundle: serde_json::to_string(&signed)?,
+                    })
+                    .await?
+                    .into_inner();
+                println!("imported policy bundle version {}", response.version);
+            }
+        }
+        Ok(())
+    }
+}
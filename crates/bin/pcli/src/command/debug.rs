@@ -1,4 +1,5 @@
 use anyhow::Result;
+use penumbra_view::test_vectors::{self, TestVectors};
 use serde::Serialize;
 use std::path::PathBuf;
 use std::process::Command;
@@ -7,6 +8,13 @@ use std::process::Command;
 pub enum DebugCmd {
     /// Emit debugging info, useful for requesting support
     Info,
+    /// Emit deterministic test vectors, as JSON, for checking the compatibility of other wallets
+    TestVectors {
+        /// The hex-encoded 32-byte seed to generate the test vectors from [default: the seed of
+        /// the published test vectors]
+        #[clap(long)]
+        seed: Option<String>,
+    },
 }
 
 impl DebugCmd {
@@ -24,6 +32,16 @@ impl DebugCmd {
                 println!("{d}");
                 Ok(())
             }
+            DebugCmd::TestVectors { seed } => {
+                let seed = match seed {
+                    Some(seed) => hex::decode(seed)?
+                        .try_into()
+                        .map_err(|_| anyhow::anyhow!("seed must be 32 bytes"))?,
+                    None => test_vectors::DEFAULT_SEED,
+                };
+                println!("{}", TestVectors::generate(seed)?.to_json()?);
+                Ok(())
+            }
         }
     }
 }
@@ -12,7 +12,7 @@ use url::Url;
 
 use crate::{
     config::{CustodyConfig, PcliConfig},
-    terminal::ActualTerminal,
+    terminal::{read_password, ActualTerminal},
 };
 
 #[derive(Debug, clap::Parser)]
@@ -31,6 +31,11 @@ pub struct InitCmd {
             parse(try_from_str = Url::parse),
         )]
     grpc_url: Url,
+    /// Encrypt the custody configuration, including its keys, with a password.
+    ///
+    /// The password is then asked for whenever `pcli` runs.
+    #[clap(long)]
+    encrypted: bool,
 }
 
 #[derive(Debug, clap::Subcommand)]
@@ -41,6 +46,10 @@ pub enum InitSubCmd {
     /// Initialize `pcli` with a manual threshold signing backend.
     #[clap(subcommand, display_order = 150)]
     Threshold(ThresholdInitCmd),
+    /// Initialize `pcli` with a spend key wrapped by a PKCS#11 HSM.
+    #[cfg(feature = "pkcs11")]
+    #[clap(display_order = 175)]
+    Pkcs11(Pkcs11InitCmd),
     /// Initialize `pcli` in view-only mode, without spending keys.
     #[clap(display_order = 200)]
     ViewOnly {
@@ -117,6 +126,43 @@ impl SoftKmsInitCmd {
     }
 }
 
+#[cfg(feature = "pkcs11")]
+#[derive(Debug, clap::Parser)]
+pub struct Pkcs11InitCmd {
+    /// The path to the PKCS#11 module provided by the HSM vendor.
+    #[clap(long)]
+    module: std::path::PathBuf,
+    /// The label of the token to store the key in.
+    #[clap(long)]
+    token_label: String,
+    /// The label of the key within the token.
+    #[clap(long, default_value = "penumbra-spend-key")]
+    key_label: String,
+    /// Recover the configuration from a key already stored in the token,
+    /// rather than generating a new one.
+    #[clap(long)]
+    recover: bool,
+}
+
+#[cfg(feature = "pkcs11")]
+impl Pkcs11InitCmd {
+    fn config(&self) -> Result<penumbra_custody::pkcs11::Config> {
+        use penumbra_custody::pkcs11::{Pkcs11Kms, TokenConfig};
+
+        let token_config = TokenConfig {
+            module: self.module.clone(),
+            token_label: self.token_label.clone(),
+            user_pin: read_password("Enter the token's user PIN: ")?.to_string(),
+            key_label: self.key_label.clone(),
+        };
+        if self.recover {
+            Pkcs11Kms::recover(token_config)
+        } else {
+            Pkcs11Kms::initialize(token_config)
+        }
+    }
+}
+
 #[derive(Debug, clap::Subcommand)]
 pub enum ThresholdInitCmd {
     /// Use a centralized dealer to create config files for each signer.
@@ -173,6 +219,11 @@ fn exec_deal(threshold: u16, home: Vec<Utf8PathBuf>, grpc_url: Url) -> Result<()
 impl InitCmd {
     pub async fn exec(&self, home_dir: impl AsRef<camino::Utf8Path>) -> Result<()> {
         if let InitSubCmd::Threshold(ThresholdInitCmd::Deal { threshold, home }) = &self.subcmd {
+            if self.encrypted {
+                anyhow::bail!(
+                    "dealt configs can't be encrypted, since each signer should choose their own password"
+                );
+            }
             exec_deal(threshold.clone(), home.clone(), self.grpc_url.clone())?;
             return Ok(());
         }
@@ -214,12 +265,32 @@ impl InitCmd {
             InitSubCmd::Threshold(ThresholdInitCmd::Deal { .. }) => {
                 panic!("this should already have been handled above")
             }
+            #[cfg(feature = "pkcs11")]
+            InitSubCmd::Pkcs11(cmd) => {
+                let config = cmd.config()?;
+                (config.fvk.clone(), CustodyConfig::Pkcs11(config))
+            }
             InitSubCmd::ViewOnly { full_viewing_key } => {
+                if self.encrypted {
+                    anyhow::bail!("view-only configs have no keys to encrypt");
+                }
                 let full_viewing_key = full_viewing_key.parse()?;
                 (full_viewing_key, CustodyConfig::ViewOnly)
             }
         };
 
+        let custody = if self.encrypted {
+            let password = read_password("Enter password to encrypt custody: ")?;
+            if std::io::stdin().is_terminal() {
+                let confirmation = read_password("Confirm password: ")?;
+                anyhow::ensure!(password == confirmation, "passwords do not match");
+            }
+            anyhow::ensure!(!password.is_empty(), "password must not be empty");
+            custody.encrypt(&password)?
+        } else {
+            custody
+        };
+
         let config = PcliConfig {
             custody,
             full_viewing_key,
@@ -17,6 +17,8 @@ mod validator;
 pub(super) use validator::ValidatorCmd;
 mod ibc_query;
 use ibc_query::IbcCmd;
+mod funds_attestation;
+use funds_attestation::VerifyFundsAttestationCmd;
 
 use crate::App;
 
@@ -62,6 +64,8 @@ pub enum QueryCmd {
     /// Queries information about IBC.
     #[clap(subcommand)]
     Ibc(IbcCmd),
+    /// Verifies an attestation that someone held at least some amount of an asset.
+    VerifyFundsAttestation(VerifyFundsAttestationCmd),
     /// Subscribes to a filtered stream of state changes.
     Watch {
         /// The regex to filter keys in verifiable storage.
@@ -116,6 +120,10 @@ impl QueryCmd {
             return ibc.exec(app).await;
         }
 
+        if let QueryCmd::VerifyFundsAttestation(verify) = self {
+            return verify.exec(app).await;
+        }
+
         // TODO: this is a hack; we should replace all raw state key uses with RPC methods.
         if let QueryCmd::ShieldedPool(ShieldedPool::CompactBlock { height }) = self {
             use penumbra_proto::core::component::compact_block::v1::{
@@ -143,7 +151,8 @@ impl QueryCmd {
             | QueryCmd::Governance(_)
             | QueryCmd::CommunityPool(_)
             | QueryCmd::Watch { .. }
-            | QueryCmd::Ibc(_) => {
+            | QueryCmd::Ibc(_)
+            | QueryCmd::VerifyFundsAttestation(_) => {
                 unreachable!("query handled in guard");
             }
             QueryCmd::ShieldedPool(p) => p.key().clone(),
@@ -181,7 +190,8 @@ impl QueryCmd {
             | QueryCmd::Governance { .. }
             | QueryCmd::Key { .. }
             | QueryCmd::Watch { .. }
-            | QueryCmd::Ibc(_) => true,
+            | QueryCmd::Ibc(_)
+            | QueryCmd::VerifyFundsAttestation(_) => true,
         }
     }
 
@@ -198,7 +208,8 @@ impl QueryCmd {
             | QueryCmd::Governance { .. }
             | QueryCmd::CommunityPool { .. }
             | QueryCmd::Watch { .. }
-            | QueryCmd::Ibc(_) => {
+            | QueryCmd::Ibc(_)
+            | QueryCmd::VerifyFundsAttestation(_) => {
                 unreachable!("query is special cased")
             }
         }
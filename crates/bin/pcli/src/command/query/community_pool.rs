@@ -2,8 +2,9 @@ use crate::App;
 use anyhow::{Context, Result};
 use futures::TryStreamExt;
 use penumbra_asset::Value;
+use penumbra_community_pool::BudgetLineState;
 use penumbra_proto::{
-    core::component::community_pool::v1::CommunityPoolAssetBalancesRequest,
+    core::component::community_pool::v1::{BudgetLinesRequest, CommunityPoolAssetBalancesRequest},
     penumbra::core::component::community_pool::v1::query_service_client::QueryServiceClient as CommunityPoolQueryServiceClient,
 };
 use penumbra_view::ViewClient;
@@ -16,12 +17,15 @@ pub enum CommunityPoolCmd {
         /// Get only the balance of the specified asset.
         asset: Option<String>,
     },
+    /// List the active recurring budget lines, with what each has paid so far.
+    BudgetLines,
 }
 
 impl CommunityPoolCmd {
     pub async fn exec(&self, app: &mut App) -> Result<()> {
         match self {
             CommunityPoolCmd::Balance { asset } => self.print_balance(app, asset).await,
+            CommunityPoolCmd::BudgetLines => self.print_budget_lines(app).await,
         }
     }
 
@@ -63,4 +67,45 @@ impl CommunityPoolCmd {
 
         Ok(())
     }
+
+    pub async fn print_budget_lines(&self, app: &mut App) -> Result<()> {
+        let mut client = CommunityPoolQueryServiceClient::new(app.pd_channel().await?);
+        let budget_lines = client
+            .budget_lines(BudgetLinesRequest {})
+            .await?
+            .into_inner()
+            .try_collect::<Vec<_>>()
+            .await
+            .context("cannot process budget line data")?;
+
+        let asset_cache = app.view().assets().await?;
+        let mut writer = stdout();
+        for response in budget_lines {
+            let BudgetLineState {
+                id,
+                budget_line,
+                epochs_elapsed,
+                spent,
+            } = response
+                .budget_line
+                .expect("budget line should always be set")
+                .try_into()
+                .context("cannot parse budget line")?;
+            let spent = Value {
+                amount: spent,
+                asset_id: budget_line.amount_per_epoch.asset_id,
+            };
+
+            writeln!(
+                writer,
+                "{id}: {} per epoch to {} for {epochs_elapsed}/{} epochs, {} spent",
+                budget_line.amount_per_epoch.format(&asset_cache),
+                budget_line.recipient,
+                budget_line.epochs,
+                spent.format(&asset_cache),
+            )?;
+        }
+
+        Ok(())
+    }
 }
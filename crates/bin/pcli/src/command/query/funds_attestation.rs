@@ -0,0 +1,60 @@
+use anyhow::{Context, Result};
+
+use penumbra_asset::{asset, Value};
+use penumbra_proto::{
+    core::component::shielded_pool::v1 as pb,
+    util::tendermint_proxy::v1::{
+        tendermint_proxy_service_client::TendermintProxyServiceClient, GetBlockByHeightRequest,
+    },
+};
+use penumbra_shielded_pool::attestation::FundsAttestation;
+
+use crate::App;
+
+#[derive(Debug, clap::Parser)]
+pub struct VerifyFundsAttestationCmd {
+    /// The file containing the attestation, as written by `pcli view attest-funds`.
+    file: camino::Utf8PathBuf,
+    /// The context the attestation must be bound to, which should be unique to this
+    /// verification, such as a challenge string sent to the prover.
+    #[clap(long)]
+    context: String,
+}
+
+impl VerifyFundsAttestationCmd {
+    pub async fn exec(&self, app: &mut App) -> Result<()> {
+        let contents = std::fs::read_to_string(&self.file)
+            .with_context(|| format!("cannot read file {:?}", self.file))?;
+        let attestation: FundsAttestation =
+            serde_json::from_str::<pb::FundsAttestation>(&contents)?.try_into()?;
+
+        // The app hash of the state at the attested height is committed to by the header of the
+        // next block. This trusts the node we're connected to for that header.
+        let mut client = TendermintProxyServiceClient::new(app.pd_channel().await?);
+        let app_hash = client
+            .get_block_by_height(GetBlockByHeightRequest {
+                height: (attestation.height + 1).try_into()?,
+            })
+            .await?
+            .into_inner()
+            .block
+            .and_then(|block| block.header)
+            .ok_or_else(|| {
+                anyhow::anyhow!("no header found for height {}", attestation.height + 1)
+            })?
+            .app_hash;
+
+        let amount = attestation.verify(self.context.as_bytes(), &app_hash)?;
+        let value = Value {
+            amount,
+            asset_id: attestation.asset_id,
+        };
+        println!(
+            "Attestation is valid: at least {} was held at height {}",
+            value.format(&asset::Cache::with_known_assets()),
+            attestation.height
+        );
+
+        Ok(())
+    }
+}
@@ -6,7 +6,8 @@ use comfy_table::{presets, Table};
 use futures::TryStreamExt;
 use penumbra_num::Amount;
 use penumbra_proto::core::component::stake::v1::{
-    query_service_client::QueryServiceClient as StakeQueryServiceClient, ValidatorInfoRequest,
+    query_service_client::QueryServiceClient as StakeQueryServiceClient, ValidatorDiagnosisRequest,
+    ValidatorInfoRequest,
 };
 use penumbra_stake::{
     validator::{self, ValidatorToml},
@@ -35,6 +36,11 @@ pub enum ValidatorCmd {
         /// The identity key of the validator to fetch.
         identity_key: String,
     },
+    /// Explain why a particular validator is, or is not, in the active set.
+    Diagnose {
+        /// The identity key of the validator to diagnose.
+        identity_key: String,
+    },
 }
 
 impl ValidatorCmd {
@@ -209,6 +215,76 @@ impl ValidatorCmd {
                     println!("{}", toml::to_string_pretty(&validator)?);
                 }
             }
+            ValidatorCmd::Diagnose { identity_key } => {
+                let identity_key = identity_key.parse::<IdentityKey>()?;
+                let mut client = StakeQueryServiceClient::new(app.pd_channel().await?);
+
+                let diagnosis: validator::Diagnosis = client
+                    .validator_diagnosis(ValidatorDiagnosisRequest {
+                        identity_key: Some(identity_key.into()),
+                    })
+                    .await?
+                    .into_inner()
+                    .diagnosis
+                    .ok_or_else(|| anyhow::anyhow!("missing validator diagnosis"))?
+                    .try_into()?;
+
+                let mut table = Table::new();
+                table.load_preset(presets::NOTHING);
+                table.add_row(vec!["State".to_string(), diagnosis.state.to_string()]);
+                table.add_row(vec![
+                    "Reason".to_string(),
+                    format!("{:?}", diagnosis.reason),
+                ]);
+                table.add_row(vec![
+                    "Voting Power".to_string(),
+                    diagnosis.voting_power.to_string(),
+                ]);
+                table.add_row(vec![
+                    "Power Rank".to_string(),
+                    match diagnosis.power_rank {
+                        Some(rank) => format!(
+                            "{rank} (active validator limit {})",
+                            diagnosis.active_validator_limit
+                        ),
+                        None => "not eligible".to_string(),
+                    },
+                ]);
+                table.add_row(vec![
+                    "Pool Value".to_string(),
+                    format!(
+                        "{} (minimum {})",
+                        diagnosis.pool_value, diagnosis.min_validator_stake
+                    ),
+                ]);
+                table.add_row(vec![
+                    "Self-Bond Value".to_string(),
+                    format!(
+                        "{} (minimum {})",
+                        diagnosis.self_bond_value, diagnosis.min_validator_self_delegation
+                    ),
+                ]);
+                table.add_row(vec![
+                    "Missed Blocks".to_string(),
+                    format!(
+                        "{} of {} (jailed at {})",
+                        diagnosis.missed_blocks,
+                        diagnosis.signed_blocks_window_len,
+                        diagnosis.missed_blocks_maximum
+                    ),
+                ]);
+                for (epoch_index, penalty) in &diagnosis.penalties {
+                    table.add_row(vec![
+                        format!("Penalty (epoch {epoch_index})"),
+                        format!("{} of stake kept", penalty.kept_rate()),
+                    ]);
+                }
+                println!("{table}");
+
+                for hint in diagnosis.recovery_hints() {
+                    println!("* {hint}");
+                }
+            }
         }
 
         Ok(())
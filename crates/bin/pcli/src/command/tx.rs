@@ -25,11 +25,14 @@ use ibc_types::lightclients::tendermint::client_state::ClientState as Tendermint
 use rand_core::OsRng;
 use regex::Regex;
 
+use bundle::BundleCmd;
 use liquidity_position::PositionCmd;
 use penumbra_asset::{asset, asset::Metadata, Value, STAKING_TOKEN_ASSET_ID};
 use penumbra_dex::{lp::position, swap_claim::SwapClaimPlan};
 use penumbra_fee::Fee;
-use penumbra_governance::{proposal::ProposalToml, proposal_state::State as ProposalState, Vote};
+use penumbra_governance::{
+    proposal::ProposalToml, proposal_state::State as ProposalState, BallotKey, Vote,
+};
 use penumbra_keys::keys::AddressIndex;
 use penumbra_num::Amount;
 use penumbra_proto::{
@@ -40,8 +43,8 @@ use penumbra_proto::{
         },
         governance::v1::{
             query_service_client::QueryServiceClient as GovernanceQueryServiceClient,
-            NextProposalIdRequest, ProposalDataRequest, ProposalInfoRequest, ProposalInfoResponse,
-            ProposalRateDataRequest,
+            BallotKeyRequest, NextProposalIdRequest, ProposalDataRequest, ProposalInfoRequest,
+            ProposalInfoResponse, ProposalRateDataRequest,
         },
         sct::v1::{
             query_service_client::QueryServiceClient as SctQueryServiceClient, EpochByHeightRequest,
@@ -56,13 +59,18 @@ use penumbra_proto::{
 use penumbra_shielded_pool::Ics20Withdrawal;
 use penumbra_stake::rate::RateData;
 use penumbra_stake::{DelegationToken, IdentityKey, Penalty, UnbondingToken, UndelegateClaimPlan};
-use penumbra_transaction::{gas::swap_claim_gas_cost, memo::MemoPlaintext};
+use penumbra_transaction::{
+    gas::swap_claim_gas_cost, memo::MemoPlaintext, ActionPlan, TransactionParameters,
+    TransactionPlan,
+};
 use penumbra_view::ViewClient;
 use penumbra_wallet::plan::{self, Planner};
 use proposal::ProposalCmd;
 
+use super::view::swap_claim::DelegatedSwapClaim;
 use crate::App;
 
+mod bundle;
 mod liquidity_position;
 mod proposal;
 mod replicate;
@@ -114,6 +122,21 @@ pub enum TxCmd {
         #[clap(short, long, value_enum, default_value_t)]
         fee_tier: FeeTier,
     },
+    /// Move stake from one validator's delegation pool to another's, without unbonding.
+    #[clap(display_order = 200)]
+    Redelegate {
+        /// The identity key of the validator to redelegate to.
+        #[clap(long, display_order = 100)]
+        to: String,
+        /// The amount of delegation tokens to redelegate.
+        amount: String,
+        /// Only spend funds originally received by the given account.
+        #[clap(long, default_value = "0", display_order = 300)]
+        source: u32,
+        /// The selected fee tier to multiply the fee amount by.
+        #[clap(short, long, value_enum, default_value_t)]
+        fee_tier: FeeTier,
+    },
     /// Claim any undelegations that have finished unbonding.
     #[clap(display_order = 200)]
     UndelegateClaim {
@@ -143,6 +166,15 @@ pub enum TxCmd {
         #[clap(short, long, value_enum, default_value_t)]
         fee_tier: FeeTier,
     },
+    /// Claim a swap on behalf of someone else, who exported it with `pcli view export-swap-claim`.
+    ///
+    /// The swap's outputs are sent to the address of the swapper, and the claim's fee is
+    /// paid out of the fee they prepaid when creating the swap.
+    #[clap(display_order = 300)]
+    ClaimSwap {
+        /// The file containing the exported swap claim.
+        input: camino::Utf8PathBuf,
+    },
     /// Vote on a governance proposal in your role as a delegator (see also: `pcli validator vote`).
     #[clap(display_order = 400)]
     Vote {
@@ -227,6 +259,9 @@ pub enum TxCmd {
         #[clap(short, long, value_enum, default_value_t)]
         fee_tier: FeeTier,
     },
+    /// Collect the authorizations for a transaction from several approvers.
+    #[clap(display_order = 700, subcommand)]
+    Bundle(BundleCmd),
 }
 
 // A fee tier enum suitable for use with clap.
@@ -308,14 +343,17 @@ impl TxCmd {
             TxCmd::Send { .. } => false,
             TxCmd::Sweep { .. } => false,
             TxCmd::Swap { .. } => false,
+            TxCmd::ClaimSwap { .. } => false,
             TxCmd::Delegate { .. } => false,
             TxCmd::Undelegate { .. } => false,
+            TxCmd::Redelegate { .. } => false,
             TxCmd::UndelegateClaim { .. } => false,
             TxCmd::Vote { .. } => false,
             TxCmd::Proposal(proposal_cmd) => proposal_cmd.offline(),
             TxCmd::CommunityPoolDeposit { .. } => false,
             TxCmd::Position(lp_cmd) => lp_cmd.offline(),
             TxCmd::Withdraw { .. } => false,
+            TxCmd::Bundle(bundle_cmd) => bundle_cmd.offline(),
         }
     }
 
@@ -456,7 +494,13 @@ impl TxCmd {
                 let estimated_claim_fee = Fee::from_staking_token_amount(
                     Amount::from(2u32) * gas_prices.fee(&swap_claim_gas_cost()),
                 );
-                planner.swap(input, into.id(), estimated_claim_fee, claim_address)?;
+                planner.swap(
+                    input,
+                    into.id(),
+                    estimated_claim_fee,
+                    claim_address,
+                    fvk.nullifier_key(),
+                )?;
 
                 let plan = planner
                     .plan(app.view(), AddressIndex::new(*source))
@@ -518,6 +562,7 @@ impl TxCmd {
                         position: swap_record.position,
                         output_data: swap_record.output_data,
                         epoch_duration: params.sct_params.epoch_duration,
+                        claim_key: None,
                         proof_blinding_r: Fq::rand(&mut OsRng),
                         proof_blinding_s: Fq::rand(&mut OsRng),
                     })
@@ -530,6 +575,36 @@ impl TxCmd {
                 // https://github.com/penumbra-zone/penumbra/pull/2091/commits/128b24a6303c2f855a708e35f9342987f1dd34ec
                 app.build_and_submit_transaction(plan).await?;
             }
+            TxCmd::ClaimSwap { input } => {
+                let file = File::open(input).with_context(|| format!("cannot open {input}"))?;
+                let claim: DelegatedSwapClaim = serde_json::from_reader(file)
+                    .with_context(|| format!("cannot parse exported swap claim in {input}"))?;
+                if claim.plan.claim_key.is_none() {
+                    anyhow::bail!("exported swap claim is missing its claim key");
+                }
+
+                // The claim fee was prepaid when the swap was created, so the
+                // claim needs no inputs of our own, and its proofs are all
+                // against the anchor of the exported witness data.
+                let params = app.view().app_params().await?;
+                let plan = TransactionPlan {
+                    transaction_parameters: TransactionParameters {
+                        expiry_height: 0,
+                        chain_id: params.chain_id,
+                        fee: claim.plan.swap_plaintext.claim_fee.clone(),
+                    },
+                    actions: vec![claim.plan.into()],
+                    ..Default::default()
+                };
+                let transaction = penumbra_wallet::build_transaction_with_witness(
+                    &app.config.full_viewing_key,
+                    &mut app.custody,
+                    plan,
+                    claim.witness_data,
+                )
+                .await?;
+                app.submit_transaction(transaction).await?;
+            }
             TxCmd::Delegate {
                 to,
                 amount,
@@ -613,6 +688,54 @@ impl TxCmd {
 
                 app.build_and_submit_transaction(plan).await?;
             }
+            TxCmd::Redelegate {
+                to,
+                amount,
+                source,
+                fee_tier,
+            } => {
+                let Value {
+                    amount: delegation_amount,
+                    asset_id,
+                } = amount.parse::<Value>()?;
+
+                let delegation_token: DelegationToken = app
+                    .view()
+                    .assets()
+                    .await?
+                    .get(&asset_id)
+                    .ok_or_else(|| anyhow::anyhow!("unknown asset id {}", asset_id))?
+                    .clone()
+                    .try_into()
+                    .context("could not parse supplied denomination as a delegation token")?;
+
+                let from = delegation_token.validator();
+                let to = to.parse::<IdentityKey>()?;
+
+                let mut client = StakeQueryServiceClient::new(app.pd_channel().await?);
+                let from_rate_data: RateData = client
+                    .current_validator_rate(tonic::Request::new(from.into()))
+                    .await?
+                    .into_inner()
+                    .try_into()?;
+                let to_rate_data: RateData = client
+                    .current_validator_rate(tonic::Request::new(to.into()))
+                    .await?
+                    .into_inner()
+                    .try_into()?;
+
+                let mut planner = Planner::new(OsRng);
+                planner
+                    .set_gas_prices(gas_prices)
+                    .set_fee_tier((*fee_tier).into());
+                let plan = planner
+                    .redelegate(delegation_amount, from_rate_data, to_rate_data)
+                    .plan(app.view(), AddressIndex::new(*source))
+                    .await
+                    .context("can't plan redelegation")?;
+
+                app.build_and_submit_transaction(plan).await?;
+            }
             TxCmd::UndelegateClaim { fee_tier } => {
                 let channel = app.pd_channel().await?;
                 let view: &mut dyn ViewClient = app
@@ -707,6 +830,7 @@ impl TxCmd {
                 file,
                 source,
                 deposit_amount,
+                deposit_denom,
                 fee_tier,
             }) => {
                 let mut proposal_file = File::open(file).context("can't open proposal file")?;
@@ -720,12 +844,22 @@ impl TxCmd {
                     .try_into()
                     .context("can't parse proposal file")?;
 
+                let deposit = Value {
+                    amount: Amount::from(*deposit_amount),
+                    asset_id: match deposit_denom {
+                        Some(denom) => Metadata::try_from(denom.as_str())
+                            .context("can't parse deposit denomination")?
+                            .id(),
+                        None => *STAKING_TOKEN_ASSET_ID,
+                    },
+                };
+
                 let mut planner = Planner::new(OsRng);
                 planner
                     .set_gas_prices(gas_prices)
                     .set_fee_tier((*fee_tier).into());
                 let plan = planner
-                    .proposal_submit(proposal, Amount::from(*deposit_amount))
+                    .proposal_submit(proposal, deposit)
                     .plan(
                         app.view
                             .as_mut()
@@ -807,6 +941,15 @@ impl TxCmd {
                         proposal_id
                     ))?
                     .try_into()?;
+                // Nodes which don't report the deposit asset only take deposits in the staking token.
+                let deposit = Value {
+                    amount: deposit_amount,
+                    asset_id: proposal
+                        .proposal_deposit_asset_id
+                        .map(TryInto::try_into)
+                        .transpose()?
+                        .unwrap_or(*STAKING_TOKEN_ASSET_ID),
+                };
 
                 let outcome = match state {
                     ProposalState::Voting => anyhow::bail!(
@@ -825,7 +968,7 @@ impl TxCmd {
                 let plan = Planner::new(OsRng)
                     .set_gas_prices(gas_prices)
                     .set_fee_tier((*fee_tier).into())
-                    .proposal_deposit_claim(*proposal_id, deposit_amount, outcome)
+                    .proposal_deposit_claim(*proposal_id, deposit, outcome)
                     .plan(
                         app.view
                             .as_mut()
@@ -879,7 +1022,7 @@ impl TxCmd {
                     start_rate_data.insert(rate_data.identity_key.clone(), rate_data);
                 }
 
-                let plan = Planner::new(OsRng)
+                let mut plan = Planner::new(OsRng)
                     .set_gas_prices(gas_prices)
                     .set_fee_tier((*fee_tier).into())
                     .delegator_vote(
@@ -897,6 +1040,15 @@ impl TxCmd {
                     )
                     .await?;
 
+                // Votes on a proposal with sealed ballots are encrypted to its ballot key.
+                if let Some(ballot_key) = proposal_ballot_key(&mut client, proposal_id).await? {
+                    for action in plan.actions.iter_mut() {
+                        if let ActionPlan::DelegatorVote(vote) = action {
+                            vote.seal(&mut OsRng, &ballot_key);
+                        }
+                    }
+                }
+
                 app.build_and_submit_transaction(plan).await?;
             }
             TxCmd::Position(PositionCmd::Order(order)) => {
@@ -1223,7 +1375,26 @@ impl TxCmd {
             TxCmd::Position(PositionCmd::Replicate(replicate_cmd)) => {
                 replicate_cmd.exec(app).await?;
             }
+            TxCmd::Bundle(bundle_cmd) => {
+                bundle_cmd.exec(app).await?;
+            }
         }
         Ok(())
     }
 }
+
+/// Fetch the ballot key of a proposal, if it has sealed ballots and its ballot key has been
+/// established.
+pub(super) async fn proposal_ballot_key(
+    client: &mut GovernanceQueryServiceClient<tonic::transport::Channel>,
+    proposal_id: u64,
+) -> Result<Option<BallotKey>> {
+    client
+        .ballot_key(BallotKeyRequest { proposal_id })
+        .await?
+        .into_inner()
+        .ballot_key
+        .map(TryInto::try_into)
+        .transpose()
+        .context("invalid ballot key")
+}
@@ -0,0 +1,155 @@
+use std::{fs::File, io::Write};
+
+use anyhow::{Context, Result};
+
+use penumbra_custody::PreAuthorization;
+use penumbra_transaction::TransactionPlan;
+use penumbra_wallet::partial::PartiallyAuthorizedTransaction;
+
+use crate::App;
+
+/// Collect the authorizations for a transaction across several parties.
+///
+/// The wallet's owner creates a bundle from a transaction plan and passes it to the approvers
+/// whose pre-authorizations the wallet's custodian requires. Once the approvers' copies are
+/// merged, the custodian signs the bundle, and it can be built and submitted.
+#[derive(Debug, clap::Subcommand)]
+pub enum BundleCmd {
+    /// Create a bundle from a transaction plan spending this wallet's notes.
+    Create {
+        /// The file containing the transaction plan, in JSON format.
+        plan: camino::Utf8PathBuf,
+        /// A description of the transaction, for the approvers to review.
+        #[clap(long)]
+        description: Option<String>,
+        /// The file to write the bundle to, or stdout if unset.
+        #[clap(long)]
+        output: Option<camino::Utf8PathBuf>,
+    },
+    /// Add an approver's pre-authorization of the plan to a bundle, in place.
+    ///
+    /// The pre-authorization is checked by the custodian when the bundle is signed.
+    PreAuthorize {
+        /// The bundle to add the pre-authorization to.
+        bundle: camino::Utf8PathBuf,
+        /// The file containing the pre-authorization, in JSON format.
+        pre_authorization: camino::Utf8PathBuf,
+    },
+    /// Merge copies of a bundle holding different authorizations.
+    Merge {
+        /// The copies of the bundle to merge.
+        #[clap(required = true, num_args = 2..)]
+        bundles: Vec<camino::Utf8PathBuf>,
+        /// The file to write the merged bundle to, or stdout if unset.
+        #[clap(long)]
+        output: Option<camino::Utf8PathBuf>,
+    },
+    /// Ask this wallet's custodian to sign a bundle, in place.
+    Sign {
+        /// The bundle to sign.
+        bundle: camino::Utf8PathBuf,
+    },
+    /// Build a fully authorized bundle into a transaction, and submit it.
+    Submit {
+        /// The bundle to submit.
+        bundle: camino::Utf8PathBuf,
+    },
+}
+
+impl BundleCmd {
+    pub fn offline(&self) -> bool {
+        match self {
+            BundleCmd::Create { .. } => true,
+            BundleCmd::PreAuthorize { .. } => true,
+            BundleCmd::Merge { .. } => true,
+            BundleCmd::Sign { .. } => true,
+            BundleCmd::Submit { .. } => false,
+        }
+    }
+
+    pub async fn exec(&self, app: &mut App) -> Result<()> {
+        match self {
+            BundleCmd::Create {
+                plan,
+                description,
+                output,
+            } => {
+                let file = File::open(plan).with_context(|| format!("cannot open {plan}"))?;
+                let plan: TransactionPlan = serde_json::from_reader(file)
+                    .with_context(|| format!("cannot parse transaction plan in {plan}"))?;
+                let mut bundle =
+                    PartiallyAuthorizedTransaction::new(plan, &app.config.full_viewing_key)?;
+                if let Some(description) = description {
+                    bundle
+                        .metadata
+                        .insert("description".to_string(), description.clone());
+                }
+                write(&bundle, output.as_ref())?;
+            }
+            BundleCmd::PreAuthorize {
+                bundle: path,
+                pre_authorization,
+            } => {
+                let mut bundle = read(path)?;
+                let file = File::open(pre_authorization)
+                    .with_context(|| format!("cannot open {pre_authorization}"))?;
+                let pre_authorization: PreAuthorization = serde_json::from_reader(file)
+                    .with_context(|| {
+                        format!("cannot parse pre-authorization in {pre_authorization}")
+                    })?;
+                bundle.add_pre_authorization(pre_authorization);
+                write(&bundle, Some(path))?;
+            }
+            BundleCmd::Merge { bundles, output } => {
+                let mut paths = bundles.iter();
+                let mut merged = read(paths.next().expect("at least two bundles are given"))?;
+                for path in paths {
+                    merged
+                        .merge(read(path)?)
+                        .with_context(|| format!("cannot merge bundle {path}"))?;
+                }
+                write(&merged, output.as_ref())?;
+            }
+            BundleCmd::Sign { bundle: path } => {
+                let mut bundle = read(path)?;
+                let added =
+                    penumbra_wallet::authorize_partially(&mut app.custody, &mut bundle).await?;
+                println!("added {added} authorizations");
+                write(&bundle, Some(path))?;
+            }
+            BundleCmd::Submit { bundle: path } => {
+                let bundle = read(path)?;
+                let transaction =
+                    penumbra_wallet::build_partially_authorized(app.view(), &bundle).await?;
+                app.submit_transaction(transaction).await?;
+            }
+        }
+        Ok(())
+    }
+}
+
+fn read(path: &camino::Utf8Path) -> Result<PartiallyAuthorizedTransaction> {
+    let file = File::open(path).with_context(|| format!("cannot open {path}"))?;
+    let bundle: PartiallyAuthorizedTransaction =
+        serde_json::from_reader(file).with_context(|| format!("cannot parse bundle in {path}"))?;
+    bundle
+        .verify()
+        .with_context(|| format!("invalid bundle in {path}"))?;
+    Ok(bundle)
+}
+
+fn write(
+    bundle: &PartiallyAuthorizedTransaction,
+    path: Option<&camino::Utf8PathBuf>,
+) -> Result<()> {
+    let json = serde_json::to_string_pretty(bundle)?;
+    match path {
+        Some(path) => {
+            let mut file =
+                File::create(path).with_context(|| format!("cannot create file {path:?}"))?;
+            file.write_all(json.as_bytes())?;
+        }
+        None => println!("{json}"),
+    }
+    Ok(())
+}
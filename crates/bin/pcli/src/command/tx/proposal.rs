@@ -1,7 +1,10 @@
 use anyhow::{Context, Result};
 
 use penumbra_app::params::AppParameters;
+use penumbra_asset::Value;
+use penumbra_community_pool::BudgetLine;
 use penumbra_governance::{proposal::ChangedAppParameters, Proposal, ProposalPayload};
+use penumbra_keys::Address;
 use penumbra_proto::DomainType;
 use penumbra_transaction::TransactionPlan;
 
@@ -14,7 +17,7 @@ pub enum ProposalCmd {
         /// The file to output the template to.
         #[clap(long, global = true)]
         file: Option<camino::Utf8PathBuf>,
-        /// The kind of the proposal to template [one of: signaling, emergency, parameter-change, or community-pool-spend].
+        /// The kind of the proposal to template [one of: signaling, emergency, parameter-change, community-pool-spend, upgrade-plan, or recurring-budget].
         #[clap(subcommand)]
         kind: ProposalKindCmd,
     },
@@ -26,9 +29,15 @@ pub enum ProposalCmd {
         /// Only spend funds originally received by the given account.
         #[clap(long, default_value = "0")]
         source: u32,
-        /// The amount of the staking token to deposit alongside the proposal.
+        /// The amount to deposit alongside the proposal, in base units of the deposit asset.
         #[clap(long)]
         deposit_amount: u64,
+        /// The base denomination of the deposit asset, if not the staking token.
+        ///
+        /// This must be one of the assets accepted for proposal deposits by the governance
+        /// parameters.
+        #[clap(long)]
+        deposit_denom: Option<String>,
         /// The selected fee tier to multiply the fee amount by.
         #[clap(short, long, value_enum, default_value_t)]
         fee_tier: FeeTier,
@@ -84,6 +93,27 @@ pub enum ProposalKindCmd {
     },
     /// Generate a template for an upgrade proposal,
     UpgradePlan,
+    /// Generate a template for a recurring budget proposal.
+    RecurringBudget {
+        /// The address to pay at the end of each epoch.
+        ///
+        /// If not specified, the template only cancels budget lines.
+        #[clap(long)]
+        recipient: Option<Address>,
+        /// The value to pay at the end of each epoch, e.g. `100penumbra`.
+        #[clap(long, default_value = "1penumbra")]
+        amount_per_epoch: Value,
+        /// The number of epochs to pay for.
+        #[clap(long, default_value = "1")]
+        epochs: u64,
+        /// Cancel the budget line the first time the Community Pool can't pay it, rather than
+        /// skipping that epoch's payment.
+        #[clap(long)]
+        cancel_if_underfunded: bool,
+        /// The IDs of active budget lines to cancel.
+        #[clap(long)]
+        cancel: Vec<u64>,
+    },
 }
 
 impl ProposalKindCmd {
@@ -98,6 +128,7 @@ impl ProposalKindCmd {
                 old: Box::new(app_params.as_changed_params()),
                 new: Box::new(ChangedAppParameters {
                     community_pool_params: None,
+                    dex_params: None,
                     distributions_params: None,
                     ibc_params: None,
                     fee_params: None,
@@ -126,13 +157,29 @@ impl ProposalKindCmd {
                     }
                 }
             }
-            ProposalKindCmd::UpgradePlan { .. } => ProposalPayload::UpgradePlan { height: 0 },
+            ProposalKindCmd::UpgradePlan { .. } => ProposalPayload::UpgradePlan(Default::default()),
+            ProposalKindCmd::RecurringBudget {
+                recipient,
+                amount_per_epoch,
+                epochs,
+                cancel_if_underfunded,
+                cancel,
+            } => ProposalPayload::RecurringBudget {
+                budget_line: recipient.map(|recipient| BudgetLine {
+                    recipient,
+                    amount_per_epoch: *amount_per_epoch,
+                    epochs: *epochs,
+                    cancel_if_underfunded: *cancel_if_underfunded,
+                }),
+                cancel: cancel.clone(),
+            },
         };
 
         Ok(Proposal {
             id,
             title,
             description,
+            sealed_ballots: false,
             payload,
         })
     }
@@ -1,4 +1,5 @@
 use std::{
+    collections::{BTreeMap, BTreeSet},
     fs::File,
     io::{Read, Write},
 };
@@ -9,18 +10,29 @@ use serde_json::Value;
 
 use penumbra_fee::Fee;
 use penumbra_governance::{
-    ValidatorVote, ValidatorVoteBody, ValidatorVoteReason, Vote, MAX_VALIDATOR_VOTE_REASON_LENGTH,
+    sealed_ballot::{
+        BallotKeyComplaintBody, BallotKeyDealBody, BallotKeyRevealBody, ShareAllocation,
+    },
+    BallotKeyComplaint, BallotKeyDeal, BallotKeyReveal, EncryptedVote, ValidatorVote,
+    ValidatorVoteBody, ValidatorVoteReason, Vote, MAX_VALIDATOR_VOTE_REASON_LENGTH,
 };
 use penumbra_keys::keys::AddressIndex;
 use penumbra_proto::{
-    core::component::stake::v1::Validator as ProtoValidator, DomainType, Message,
+    core::component::{
+        governance::v1::{
+            query_service_client::QueryServiceClient as GovernanceQueryServiceClient,
+            BallotKeySetupRequest,
+        },
+        stake::v1::Validator as ProtoValidator,
+    },
+    DomainType, Message,
 };
 use penumbra_stake::{
     validator,
     validator::{Validator, ValidatorToml},
     FundingStream, FundingStreams, GovernanceKey, IdentityKey,
 };
-use penumbra_wallet::plan;
+use penumbra_wallet::plan::{self, Planner};
 
 use crate::{config::CustodyConfig, App};
 
@@ -50,6 +62,65 @@ pub enum ValidatorCmd {
         #[clap(long, default_value = "", global = true, display_order = 400)]
         reason: String,
     },
+    /// Take part in establishing the ballot key of a proposal with sealed ballots.
+    #[clap(subcommand)]
+    BallotKey(BallotKeyCmd),
+}
+
+#[derive(Debug, clap::Subcommand)]
+pub enum BallotKeyCmd {
+    /// Deal your validator's share of the ballot key, in the first half of the setup period.
+    Deal {
+        /// The proposal to deal the ballot key of.
+        proposal_id: u64,
+        /// The transaction fee (paid in upenumbra).
+        #[clap(long, default_value = "0")]
+        fee: u64,
+        /// Optional. Only spend funds originally received by the given account.
+        #[clap(long, default_value = "0")]
+        source: u32,
+    },
+    /// Complain about deals which sent invalid shares to your validator, in the second half of
+    /// the setup period.
+    Complain {
+        /// The proposal to check the ballot key deals of.
+        proposal_id: u64,
+        /// The transaction fee (paid in upenumbra).
+        #[clap(long, default_value = "0")]
+        fee: u64,
+        /// Optional. Only spend funds originally received by the given account.
+        #[clap(long, default_value = "0")]
+        source: u32,
+    },
+    /// Reveal your validator's ballot key secrets, once voting on the proposal has closed.
+    Reveal {
+        /// The proposal to reveal the ballot key secrets of.
+        proposal_id: u64,
+        /// The transaction fee (paid in upenumbra).
+        #[clap(long, default_value = "0")]
+        fee: u64,
+        /// Optional. Only spend funds originally received by the given account.
+        #[clap(long, default_value = "0")]
+        source: u32,
+    },
+}
+
+impl BallotKeyCmd {
+    fn proposal_id(&self) -> u64 {
+        match self {
+            BallotKeyCmd::Deal { proposal_id, .. }
+            | BallotKeyCmd::Complain { proposal_id, .. }
+            | BallotKeyCmd::Reveal { proposal_id, .. } => *proposal_id,
+        }
+    }
+
+    fn fee_and_source(&self) -> (u64, u32) {
+        match self {
+            BallotKeyCmd::Deal { fee, source, .. }
+            | BallotKeyCmd::Complain { fee, source, .. }
+            | BallotKeyCmd::Reveal { fee, source, .. } => (*fee, *source),
+        }
+    }
 }
 
 #[derive(Debug, clap::Subcommand)]
@@ -99,6 +170,7 @@ impl ValidatorCmd {
                 DefinitionCmd::Template { .. } | DefinitionCmd::Fetch { .. },
             ) => true,
             ValidatorCmd::Vote { .. } => false,
+            ValidatorCmd::BallotKey(_) => false,
         }
     }
 
@@ -189,13 +261,25 @@ impl ValidatorCmd {
                     anyhow::bail!("validator vote reason is too long, max 1024 bytes");
                 }
 
+                // Votes on a proposal with sealed ballots are encrypted to its ballot key, and
+                // abstain in plaintext.
+                let mut client = GovernanceQueryServiceClient::new(app.pd_channel().await?);
+                let encrypted_vote = super::tx::proposal_ballot_key(&mut client, proposal)
+                    .await?
+                    .map(|ballot_key| EncryptedVote::encrypt(&mut OsRng, vote, &ballot_key));
+
                 // Construct the vote body
                 let body = ValidatorVoteBody {
                     proposal,
-                    vote,
+                    vote: if encrypted_vote.is_some() {
+                        Vote::Abstain
+                    } else {
+                        vote
+                    },
                     identity_key,
                     governance_key,
                     reason: ValidatorVoteReason(reason.clone()),
+                    encrypted_vote,
                 };
 
                 // TODO: support signing with a separate governance key
@@ -224,6 +308,120 @@ impl ValidatorCmd {
 
                 println!("Cast validator vote");
             }
+            ValidatorCmd::BallotKey(cmd) => {
+                let identity_key = IdentityKey(*sk.full_viewing_key().spend_verification_key());
+                // TODO: support signing with a separate governance key
+                let governance_auth_key = sk.spend_auth_key();
+                let proposal = cmd.proposal_id();
+
+                let mut client = GovernanceQueryServiceClient::new(app.pd_channel().await?);
+                let setup = client
+                    .ballot_key_setup(BallotKeySetupRequest {
+                        proposal_id: proposal,
+                    })
+                    .await?
+                    .into_inner();
+                let mut voting_power = BTreeMap::new();
+                let mut governance_keys: BTreeMap<IdentityKey, GovernanceKey> = BTreeMap::new();
+                for participant in setup.participants {
+                    let participant_key: IdentityKey = participant
+                        .identity_key
+                        .ok_or_else(|| anyhow::anyhow!("missing participant identity key"))?
+                        .try_into()?;
+                    if let Some(governance_key) = participant.governance_key {
+                        governance_keys.insert(participant_key, governance_key.try_into()?);
+                    }
+                    voting_power.insert(participant_key, participant.voting_power);
+                }
+                let allocation = ShareAllocation::new(&voting_power);
+                let disqualified = setup
+                    .disqualified_dealers
+                    .into_iter()
+                    .map(TryInto::try_into)
+                    .collect::<Result<BTreeSet<IdentityKey>, _>>()?;
+                let deals = setup
+                    .deals
+                    .into_iter()
+                    .map(TryInto::try_into)
+                    .collect::<Result<Vec<BallotKeyDeal>, _>>()?;
+
+                if allocation.positions(&identity_key).is_none() {
+                    anyhow::bail!(
+                        "validator {} was not active at the start of proposal {}",
+                        identity_key,
+                        proposal
+                    );
+                }
+
+                let mut planner = Planner::new(OsRng);
+                match cmd {
+                    BallotKeyCmd::Deal { .. } => {
+                        let body = BallotKeyDealBody::deal(
+                            &mut OsRng,
+                            proposal,
+                            identity_key,
+                            governance_auth_key,
+                            &allocation,
+                            &governance_keys,
+                        )?;
+                        let auth_sig = governance_auth_key.sign(OsRng, &body.encode_to_vec());
+                        planner.ballot_key_deal(BallotKeyDeal { body, auth_sig });
+                    }
+                    BallotKeyCmd::Complain { .. } => {
+                        let mut complaints = 0;
+                        for deal in deals.iter().filter(|deal| {
+                            deal.body.identity_key != identity_key
+                                && !disqualified.contains(&deal.body.identity_key)
+                        }) {
+                            let Some(body) = BallotKeyComplaintBody::complain(
+                                &mut OsRng,
+                                identity_key,
+                                governance_auth_key,
+                                &allocation,
+                                &deal.body,
+                            )?
+                            else {
+                                continue;
+                            };
+                            let auth_sig = governance_auth_key.sign(OsRng, &body.encode_to_vec());
+                            planner.ballot_key_complaint(BallotKeyComplaint { body, auth_sig });
+                            complaints += 1;
+                        }
+                        if complaints == 0 {
+                            println!("All ballot key deals for proposal {proposal} are valid");
+                            return Ok(());
+                        }
+                    }
+                    BallotKeyCmd::Reveal { .. } => {
+                        let body = BallotKeyRevealBody::reveal(
+                            proposal,
+                            identity_key,
+                            governance_auth_key,
+                            &allocation,
+                            deals
+                                .iter()
+                                .filter(|deal| !disqualified.contains(&deal.body.identity_key)),
+                        );
+                        let auth_sig = governance_auth_key.sign(OsRng, &body.encode_to_vec());
+                        planner.ballot_key_reveal(BallotKeyReveal { body, auth_sig });
+                    }
+                }
+
+                let (fee, source) = cmd.fee_and_source();
+                let plan = planner
+                    .fee(Fee::from_staking_token_amount(fee.into()))
+                    .plan(
+                        app.view
+                            .as_mut()
+                            .context("view service must be initialized")?,
+                        AddressIndex::new(source),
+                    )
+                    .await
+                    .context("can't build ballot key plan")?;
+                app.build_and_submit_transaction(plan).await?;
+
+                println!("Submitted ballot key transaction for proposal {proposal}");
+            }
             ValidatorCmd::Definition(DefinitionCmd::Template {
                 file,
                 tendermint_validator_keyfile,
@@ -280,9 +478,14 @@ impl ValidatorCmd {
                         FundingStream::ToAddress {
                             address,
                             rate_bps: 100,
+                            auto_compound: false,
+                        },
+                        FundingStream::ToCommunityPool {
+                            rate_bps: 100,
+                            auto_compound: false,
                         },
-                        FundingStream::ToCommunityPool { rate_bps: 100 },
                     ])?,
+                    funding_streams_effective_epoch: None,
                     sequence_number: 0,
                 }
                 .into();
@@ -300,6 +503,14 @@ impl ValidatorCmd {
 #
 # Every time you upload a new validator config, you'll need to increment the
 # `sequence_number`.
+#
+# To give delegators advance notice of a change to your funding streams, set
+# `funding_streams_effective_epoch` (above any `[[funding_stream]]` sections)
+# to the index of the epoch from which the new funding streams should apply.
+#
+# To have the rewards paid to a funding stream automatically re-delegated to
+# your validator at each epoch boundary, set `auto_compound = true` in that
+# `[[funding_stream]]` section.
 
 {}
 ",
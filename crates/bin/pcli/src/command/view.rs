@@ -1,8 +1,10 @@
 use anyhow::Result;
 
 use address::AddressCmd;
+use attest_funds::AttestFundsCmd;
 use balance::BalanceCmd;
 use staked::StakedCmd;
+use swap_claim::ExportSwapClaimCmd;
 use transaction_hashes::TransactionHashesCmd;
 use tx::TxCmd;
 use wallet_id::WalletIdCmd;
@@ -10,10 +12,13 @@ use wallet_id::WalletIdCmd;
 use crate::App;
 
 mod address;
+mod attest_funds;
 mod balance;
 mod staked;
 mod wallet_id;
 
+pub mod swap_claim;
+
 pub mod transaction_hashes;
 mod tx;
 
@@ -39,6 +44,18 @@ pub enum ViewCmd {
     ListTransactionHashes(TransactionHashesCmd),
     /// Displays a transaction's details by hash.
     Tx(TxCmd),
+    /// Export one of your unclaimed swaps, so that someone else can claim it on your behalf.
+    ///
+    /// The swap's outputs are still sent to your address.  The exported file can be
+    /// claimed with `pcli tx claim-swap`.
+    ExportSwapClaim(ExportSwapClaimCmd),
+    /// Attest that you hold at least some amount of an asset, without revealing your keys or
+    /// your other notes.
+    ///
+    /// The attestation reveals the amount and nullifier of each note holding the asset, so
+    /// whoever receives it can recognize the transactions that later spend those notes.
+    /// Attestations can be checked with `pcli query verify-funds-attestation`.
+    AttestFunds(AttestFundsCmd),
 }
 
 impl ViewCmd {
@@ -52,6 +69,8 @@ impl ViewCmd {
             ViewCmd::Sync => false,
             ViewCmd::ListTransactionHashes(transactions_cmd) => transactions_cmd.offline(),
             ViewCmd::Tx(tx_cmd) => tx_cmd.offline(),
+            ViewCmd::ExportSwapClaim(export_cmd) => export_cmd.offline(),
+            ViewCmd::AttestFunds(attest_cmd) => attest_cmd.offline(),
         }
     }
 
@@ -66,6 +85,15 @@ impl ViewCmd {
             ViewCmd::Tx(tx_cmd) => {
                 tx_cmd.exec(app).await?;
             }
+            ViewCmd::ExportSwapClaim(export_cmd) => {
+                export_cmd.exec(&full_viewing_key, app.view()).await?;
+            }
+            ViewCmd::AttestFunds(attest_cmd) => {
+                let channel = app.pd_channel().await?;
+                attest_cmd
+                    .exec(&full_viewing_key, app.view(), channel)
+                    .await?;
+            }
             ViewCmd::ListTransactionHashes(transactions_cmd) => {
                 let view_client = app.view();
                 transactions_cmd
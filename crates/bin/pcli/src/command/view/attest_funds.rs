@@ -0,0 +1,175 @@
+use std::{fs::File, io::Write};
+
+use anyhow::{Context, Result};
+use ibc_types::core::commitment::MerkleProof;
+use rand_core::OsRng;
+use tonic::transport::Channel;
+
+use penumbra_asset::asset;
+use penumbra_keys::FullViewingKey;
+use penumbra_proto::{
+    cnidarium::v1::{query_service_client::QueryServiceClient, KeyValueRequest},
+    core::component::shielded_pool::v1 as pb,
+    util::tendermint_proxy::v1::{
+        tendermint_proxy_service_client::TendermintProxyServiceClient, GetStatusRequest,
+    },
+    view::v1::NotesRequest,
+    DomainType,
+};
+use penumbra_sct::state_key as sct_state_key;
+use penumbra_shielded_pool::{
+    attestation::{FundsAttestation, NoteToAttest},
+    SpendPlan,
+};
+use penumbra_transaction::TransactionPlan;
+use penumbra_view::ViewClient;
+
+#[derive(Debug, clap::Parser)]
+pub struct AttestFundsCmd {
+    /// The denomination of the asset to attest to, such as `penumbra`.
+    asset: String,
+    /// The context chosen by the verifier, such as a challenge string, which the
+    /// attestation is bound to.
+    #[clap(long)]
+    context: String,
+    /// The file to write the attestation to, or stdout if unset.
+    #[clap(long)]
+    output: Option<camino::Utf8PathBuf>,
+}
+
+impl AttestFundsCmd {
+    pub fn offline(&self) -> bool {
+        false
+    }
+
+    pub async fn exec(
+        &self,
+        fvk: &FullViewingKey,
+        view: &mut impl ViewClient,
+        channel: Channel,
+    ) -> Result<()> {
+        let asset_id = asset::REGISTRY.parse_unit(&self.asset).base().id();
+        let records = view
+            .notes(NotesRequest {
+                asset_id: Some(asset_id.into()),
+                ..Default::default()
+            })
+            .await?;
+        if records.is_empty() {
+            anyhow::bail!("no unspent notes of {} to attest to", self.asset);
+        }
+
+        // The attestation is made against the state at the height the wallet is synced to,
+        // so the node must be at that height too while we fetch proofs from it.
+        let height = view.status().await?.full_sync_height;
+        let mut tendermint = TendermintProxyServiceClient::new(channel.clone());
+        ensure_latest_height(&mut tendermint, height).await?;
+
+        let witness_data = view
+            .witness(&TransactionPlan {
+                actions: records
+                    .iter()
+                    .map(|record| SpendPlan::new(&mut OsRng, record.note.clone(), record.position))
+                    .map(Into::into)
+                    .collect(),
+                ..Default::default()
+            })
+            .await?;
+
+        let mut storage = QueryServiceClient::new(channel);
+        let (anchor, anchor_proof) =
+            key_value_with_proof(&mut storage, sct_state_key::tree::anchor_by_height(height))
+                .await?;
+        let anchor = penumbra_tct::Root::decode(
+            anchor
+                .ok_or_else(|| anyhow::anyhow!("no anchor found for height {height}"))?
+                .as_slice(),
+        )?;
+        anyhow::ensure!(
+            anchor == witness_data.anchor,
+            "the wallet's state commitment tree does not match the chain at height {height}"
+        );
+
+        let mut notes = Vec::with_capacity(records.len());
+        for record in records {
+            let state_commitment_proof = witness_data
+                .state_commitment_proofs
+                .get(&record.note_commitment)
+                .cloned()
+                .ok_or_else(|| anyhow::anyhow!("no witness for note {}", record.note_commitment))?;
+            let (_, unspent_proof) = key_value_with_proof(
+                &mut storage,
+                sct_state_key::nullifier_set::spent_nullifier_lookup(&record.nullifier),
+            )
+            .await?;
+            notes.push(NoteToAttest {
+                note: record.note,
+                state_commitment_proof,
+                unspent_proof,
+            });
+        }
+        ensure_latest_height(&mut tendermint, height).await?;
+
+        let attestation = FundsAttestation::prove(
+            &mut OsRng,
+            fvk,
+            self.context.as_bytes(),
+            height,
+            anchor,
+            anchor_proof,
+            notes,
+        )?;
+
+        let exported = serde_json::to_string_pretty(&pb::FundsAttestation::from(attestation))?;
+        match &self.output {
+            Some(path) => {
+                let mut file =
+                    File::create(path).with_context(|| format!("cannot create file {path:?}"))?;
+                file.write_all(exported.as_bytes())?;
+            }
+            None => println!("{exported}"),
+        }
+
+        Ok(())
+    }
+}
+
+/// Check that the node's latest block is at the given height, so that the proofs it returns
+/// are against the state at that height.
+async fn ensure_latest_height(
+    client: &mut TendermintProxyServiceClient<Channel>,
+    height: u64,
+) -> Result<()> {
+    let latest_height = client
+        .get_status(GetStatusRequest::default())
+        .await?
+        .into_inner()
+        .sync_info
+        .ok_or_else(|| anyhow::anyhow!("missing sync_info"))?
+        .latest_block_height;
+    anyhow::ensure!(
+        latest_height == height,
+        "the wallet is synced to height {height}, but the chain is at height {latest_height}; try again"
+    );
+    Ok(())
+}
+
+/// Fetch a key from the node's latest state, with a proof of its value or absence.
+async fn key_value_with_proof(
+    client: &mut QueryServiceClient<Channel>,
+    key: String,
+) -> Result<(Option<Vec<u8>>, MerkleProof)> {
+    let response = client
+        .key_value(KeyValueRequest {
+            key: key.clone(),
+            proof: true,
+        })
+        .await?
+        .into_inner();
+    let proof = response
+        .proof
+        .ok_or_else(|| anyhow::anyhow!("no proof returned for key {key}"))?;
+    let proof =
+        MerkleProof::try_from(proof).with_context(|| format!("malformed proof for key {key}"))?;
+    Ok((response.value.map(|value| value.value), proof))
+}
@@ -0,0 +1,90 @@
+use std::{fs::File, io::Write};
+
+use anyhow::{Context, Result};
+use ark_ff::UniformRand;
+use decaf377::Fq;
+use rand_core::OsRng;
+use serde::{Deserialize, Serialize};
+
+use penumbra_dex::swap_claim::SwapClaimPlan;
+use penumbra_keys::FullViewingKey;
+use penumbra_tct::StateCommitment;
+use penumbra_transaction::{TransactionPlan, WitnessData};
+use penumbra_view::ViewClient;
+
+/// Everything a third party needs to claim one of our swaps on our behalf.
+///
+/// The swap claim plan carries the swap's claim key, which only authorizes
+/// claiming that one swap into our claim address, and the witness data proves
+/// the swap's inclusion in the state commitment tree, which the third party's
+/// view service doesn't track.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DelegatedSwapClaim {
+    pub plan: SwapClaimPlan,
+    pub witness_data: WitnessData,
+}
+
+#[derive(Debug, clap::Parser)]
+pub struct ExportSwapClaimCmd {
+    /// The hex-encoded commitment of the swap to export.
+    swap_commitment: String,
+    /// The file to write the exported swap claim to, or stdout if unset.
+    #[clap(long)]
+    output: Option<camino::Utf8PathBuf>,
+}
+
+impl ExportSwapClaimCmd {
+    pub fn offline(&self) -> bool {
+        false
+    }
+
+    pub async fn exec(&self, fvk: &FullViewingKey, view: &mut impl ViewClient) -> Result<()> {
+        let swap_commitment: [u8; 32] = hex::decode(&self.swap_commitment)
+            .context("swap commitment must be hex-encoded")?
+            .try_into()
+            .map_err(|_| anyhow::anyhow!("swap commitment must be 32 bytes"))?;
+        let swap_commitment = StateCommitment::try_from(swap_commitment)?;
+
+        let swap_record = view.swap_by_commitment(swap_commitment).await?;
+        if swap_record.height_claimed.is_some() {
+            anyhow::bail!("swap {} has already been claimed", swap_commitment);
+        }
+        // Swaps without a claim key are claimed with our nullifier key, which
+        // must not leave the wallet.
+        if !swap_record.swap.has_claim_key() {
+            anyhow::bail!(
+                "swap {} predates claim keys, so it can't be claimed by a third party",
+                swap_commitment
+            );
+        }
+
+        let params = view.app_params().await?;
+        let plan = SwapClaimPlan {
+            claim_key: Some(swap_record.swap.claim_key(fvk.nullifier_key())),
+            swap_plaintext: swap_record.swap,
+            position: swap_record.position,
+            output_data: swap_record.output_data,
+            epoch_duration: params.sct_params.epoch_duration,
+            proof_blinding_r: Fq::rand(&mut OsRng),
+            proof_blinding_s: Fq::rand(&mut OsRng),
+        };
+        let witness_data = view
+            .witness(&TransactionPlan {
+                actions: vec![plan.clone().into()],
+                ..Default::default()
+            })
+            .await?;
+
+        let exported = serde_json::to_string_pretty(&DelegatedSwapClaim { plan, witness_data })?;
+        match &self.output {
+            Some(path) => {
+                let mut file =
+                    File::create(path).with_context(|| format!("cannot create file {path:?}"))?;
+                file.write_all(exported.as_bytes())?;
+            }
+            None => println!("{exported}"),
+        }
+
+        Ok(())
+    }
+}
@@ -56,6 +56,7 @@ impl TxCmd {
                 transaction: tx,
                 perspective: txp,
                 view: txv,
+                refunds: Vec::new(),
             }
         };
 
@@ -68,6 +69,17 @@ impl TxCmd {
         } else {
             use crate::transaction_view_ext::TransactionViewExt;
             tx_info.view.render_terminal();
+
+            if !tx_info.refunds.is_empty() {
+                let asset_cache = app.view().assets().await?;
+                for refund in &tx_info.refunds {
+                    println!(
+                        "Refunded {} at height {}, because the ICS20 transfer timed out",
+                        refund.note.value().format(&asset_cache),
+                        refund.height_created,
+                    );
+                }
+            }
         }
 
         Ok(())
@@ -5,8 +5,14 @@ use serde::{Deserialize, Serialize};
 use serde_with::{serde_as, DisplayFromStr};
 use url::Url;
 
-use penumbra_custody::{soft_kms::Config as SoftKmsConfig, threshold::Config as ThresholdConfig};
+#[cfg(feature = "pkcs11")]
+use penumbra_custody::pkcs11::Config as Pkcs11Config;
+use penumbra_custody::{
+    encrypted::Sealed, soft_kms::Config as SoftKmsConfig, threshold::Config as ThresholdConfig,
+};
 use penumbra_keys::FullViewingKey;
+use rand_core::OsRng;
+use zeroize::Zeroizing;
 
 /// Configuration data for `pcli`.
 #[serde_as]
@@ -53,6 +59,50 @@ pub enum CustodyConfig {
     SoftKms(SoftKmsConfig),
     /// A manual threshold custody service.
     Threshold(ThresholdConfig),
+    /// A custody service whose spend key is wrapped by a PKCS#11 HSM.
+    #[cfg(feature = "pkcs11")]
+    Pkcs11(Pkcs11Config),
+    /// One of the other custody backends, whose configuration, including its
+    /// keys, is encrypted with a password.
+    Encrypted(Sealed),
+}
+
+impl CustodyConfig {
+    /// Encrypt this custody configuration with the password.
+    pub fn encrypt(&self, password: &str) -> Result<Self> {
+        if let CustodyConfig::Encrypted(_) = self {
+            anyhow::bail!("custody configuration is already encrypted");
+        }
+        let contents = Zeroizing::new(toml::to_string(self)?);
+        Ok(CustodyConfig::Encrypted(Sealed::seal(
+            &mut OsRng,
+            password,
+            contents.as_bytes(),
+        )?))
+    }
+
+    /// Decrypt an encrypted custody configuration with the password.
+    ///
+    /// Custody configurations which aren't encrypted are returned as they are.
+    pub fn decrypt(&self, password: &str) -> Result<Self> {
+        let CustodyConfig::Encrypted(sealed) = self else {
+            return Ok(self.clone());
+        };
+        let contents = sealed.open(password)?;
+        let contents = std::str::from_utf8(&contents)
+            .context("decrypted custody configuration is not valid UTF-8")?;
+        let config: CustodyConfig =
+            toml::from_str(contents).context("failed to parse decrypted custody configuration")?;
+        if let CustodyConfig::Encrypted(_) = config {
+            anyhow::bail!("decrypted custody configuration is itself encrypted");
+        }
+        Ok(config)
+    }
+
+    /// Whether the configuration must be decrypted before use.
+    pub fn is_encrypted(&self) -> bool {
+        matches!(self, CustodyConfig::Encrypted(_))
+    }
 }
 
 impl Default for CustodyConfig {
@@ -98,4 +148,21 @@ mod tests {
         println!("{}", toml_config);
         println!("{}", toml_config2);
     }
+
+    #[test]
+    fn encrypted_custody_round_trips() -> Result<()> {
+        let custody = CustodyConfig::SoftKms(SoftKmsConfig::from(
+            penumbra_keys::test_keys::SPEND_KEY.clone(),
+        ));
+
+        let encrypted = custody.encrypt("hunter2")?;
+        assert!(encrypted.is_encrypted());
+        assert!(encrypted.encrypt("hunter2").is_err());
+        assert!(encrypted.decrypt("hunter3").is_err());
+
+        // The encrypted configuration survives a round trip through the config file.
+        let encrypted: CustodyConfig = toml::from_str(&toml::to_string(&encrypted)?)?;
+        assert_eq!(encrypted.decrypt("hunter2")?, custody);
+        Ok(())
+    }
 }
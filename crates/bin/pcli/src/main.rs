@@ -15,7 +15,7 @@ use penumbra_proto::{
     custody::v1::custody_service_client::CustodyServiceClient,
     view::v1::view_service_client::ViewServiceClient,
 };
-use penumbra_view::ViewClient;
+use penumbra_view::{InsufficientFunds, ViewClient};
 
 mod command;
 mod config;
@@ -28,6 +28,9 @@ mod warning;
 
 const CONFIG_FILE_NAME: &str = "config.toml";
 const VIEW_FILE_NAME: &str = "pcli-view.sqlite";
+const CUSTODY_FREEZE_FILE_NAME: &str = "custody-freeze.json";
+const CUSTODY_POLICY_FILE_NAME: &str = "custody-policy.json";
+const CUSTODY_AUDIT_LOG_FILE_NAME: &str = "custody-audit.jsonl";
 
 #[derive(Debug)]
 pub struct App {
@@ -94,6 +97,7 @@ async fn main() -> Result<()> {
     // Initialize tracing here, rather than when converting into an `App`, so
     // that tracing is set up even for wallet commands that don't build the `App`.
     opt.init_tracing();
+    opt.select_profile()?;
 
     //Ensure that the data_path exists, in case this is a cold start
     fs::create_dir_all(&opt.home)
@@ -130,16 +134,28 @@ async fn main() -> Result<()> {
     // make sure to be compatible with client for remote view service, with different
     // concrete type
 
-    match &cmd {
+    let result = match &cmd {
         Command::Init(_) => unreachable!("init command already executed"),
         Command::Debug(_) => unreachable!("debug command already executed"),
-        Command::Transaction(tx_cmd) => tx_cmd.exec(&mut app).await?,
-        Command::View(view_cmd) => view_cmd.exec(&mut app).await?,
-        Command::Validator(cmd) => cmd.exec(&mut app).await?,
-        Command::Query(cmd) => cmd.exec(&mut app).await?,
-        Command::Ceremony(cmd) => cmd.exec(&mut app).await?,
-        Command::Threshold(cmd) => cmd.exec(&mut app).await?,
-    }
-
-    Ok(())
+        Command::Transaction(tx_cmd) => tx_cmd.exec(&mut app).await,
+        Command::View(view_cmd) => view_cmd.exec(&mut app).await,
+        Command::Validator(cmd) => cmd.exec(&mut app).await,
+        Command::Query(cmd) => cmd.exec(&mut app).await,
+        Command::Ceremony(cmd) => cmd.exec(&mut app).await,
+        Command::Threshold(cmd) => cmd.exec(&mut app).await,
+        Command::Custody(cmd) => cmd.exec(&mut app).await,
+    };
+
+    // If a transaction couldn't be planned for lack of funds, point the user at the commands
+    // which can help, on top of the planner's explanation of what's missing.
+    result.map_err(|e| {
+        if e.chain().any(|cause| cause.is::<InsufficientFunds>()) {
+            e.context(
+                "not enough funds for this transaction: check your balance with `pcli view balance`, \
+                 and consolidate small notes with `pcli tx sweep`",
+            )
+        } else {
+            e
+        }
+    })
 }
@@ -2,6 +2,7 @@ use anyhow::Context;
 use futures::{FutureExt, TryStreamExt};
 use penumbra_fee::GasPrices;
 use penumbra_proto::{
+    rpc::ClientBuilder,
     util::tendermint_proxy::v1::tendermint_proxy_service_client::TendermintProxyServiceClient,
     view::v1::broadcast_transaction_response::Status as BroadcastStatus,
     view::v1::GasPricesRequest, DomainType,
@@ -9,7 +10,7 @@ use penumbra_proto::{
 use penumbra_transaction::{gas::GasCost, txhash::TransactionId, Transaction, TransactionPlan};
 use penumbra_view::ViewClient;
 use std::future::Future;
-use tonic::transport::{Channel, ClientTlsConfig};
+use tonic::transport::Channel;
 use tracing::instrument;
 
 use crate::App;
@@ -138,17 +139,9 @@ impl App {
 
     // TODO: why do we need this here but not in the view crate?
     pub async fn pd_channel(&self) -> anyhow::Result<Channel> {
-        match self.config.grpc_url.scheme() {
-            "http" => Ok(Channel::from_shared(self.config.grpc_url.to_string())?
-                .connect()
-                .await?),
-            "https" => Ok(Channel::from_shared(self.config.grpc_url.to_string())?
-                .tls_config(ClientTlsConfig::new())?
-                .connect()
-                .await?),
-            other => Err(anyhow::anyhow!("unknown url scheme {other}"))
-                .with_context(|| format!("could not connect to {}", self.config.grpc_url)),
-        }
+        ClientBuilder::new(self.config.grpc_url.to_string())
+            .connect()
+            .await
     }
 
     pub async fn tendermint_proxy_client(
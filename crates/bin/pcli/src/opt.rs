@@ -1,13 +1,13 @@
 use crate::{
     config::{CustodyConfig, PcliConfig},
-    terminal::ActualTerminal,
+    terminal::{read_password, ActualTerminal},
     App, Command,
 };
 use anyhow::Result;
 use camino::Utf8PathBuf;
 use clap::Parser;
 use directories::ProjectDirs;
-use penumbra_custody::soft_kms::SoftKms;
+use penumbra_custody::{audit::AuditLog, freeze::FreezeSwitch, soft_kms::SoftKms};
 use penumbra_proto::box_grpc_svc;
 use penumbra_proto::{
     custody::v1::{
@@ -27,6 +27,14 @@ pub struct Opt {
     /// The home directory used to store configuration and data.
     #[clap(long, default_value_t = default_home(), env = "PENUMBRA_PCLI_HOME")]
     pub home: Utf8PathBuf,
+    /// The named profile to use, such as `mainnet` or `testnet`.
+    ///
+    /// Each profile is kept in its own directory under `profiles/` in the home
+    /// directory, with its own configuration (including its custody backend
+    /// and endpoints) and view database, so that several wallets or networks
+    /// can be used side by side. Create one with `pcli --profile <name> init`.
+    #[clap(long, env = "PENUMBRA_PCLI_PROFILE")]
+    pub profile: Option<String>,
 }
 
 impl Opt {
@@ -47,17 +55,82 @@ impl Opt {
             .init();
     }
 
+    /// Switch the home directory to the selected profile's, if any.
+    ///
+    /// This must be called before the home directory is used.
+    pub fn select_profile(&mut self) -> Result<()> {
+        let Some(profile) = &self.profile else {
+            return Ok(());
+        };
+        anyhow::ensure!(
+            !profile.is_empty()
+                && profile
+                    .chars()
+                    .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_'),
+            "profile name {profile:?} must be non-empty, and only contain ASCII letters, digits, '-' and '_'"
+        );
+        self.home = self.home.join(PROFILES_DIR_NAME).join(profile);
+        Ok(())
+    }
+
     pub fn load_config(&self) -> Result<PcliConfig> {
         let path = self.home.join(crate::CONFIG_FILE_NAME);
-        PcliConfig::load(path)
+        let exists = path.exists();
+        PcliConfig::load(path).map_err(|e| match (&self.profile, self.profile_names()) {
+            (Some(profile), names) if !exists && !names.is_empty() => e.context(format!(
+                "profile {profile:?} is not initialized; the initialized profiles are: {}",
+                names.join(", ")
+            )),
+            _ => e,
+        })
+    }
+
+    /// The names of the profiles which have been created alongside the
+    /// selected one.
+    fn profile_names(&self) -> Vec<String> {
+        let Some(profiles_dir) = self.home.parent() else {
+            return Vec::new();
+        };
+        let mut names: Vec<String> = profiles_dir
+            .read_dir_utf8()
+            .into_iter()
+            .flatten()
+            .filter_map(|entry| entry.ok())
+            .filter(|entry| entry.path().join(crate::CONFIG_FILE_NAME).exists())
+            .map(|entry| entry.file_name().to_string())
+            .collect();
+        names.sort();
+        names
     }
 
     pub async fn into_app(self) -> Result<(App, Command)> {
-        let config = self.load_config()?;
+        let mut config = self.load_config()?;
+
+        // Secrets stored encrypted are only decrypted in memory, and only for
+        // commands which use them.
+        if config.custody.is_encrypted() && self.cmd.needs_custody() {
+            let password = read_password("Enter password to unlock custody: ")?;
+            config.custody = config.custody.decrypt(&password)?;
+        }
+
+        // Custodians persist their freeze switch, imported policy and audit log
+        // next to the config by default, so that they survive restarting pcli.
+        let freeze_state = self
+            .home
+            .join(crate::CUSTODY_FREEZE_FILE_NAME)
+            .into_std_path_buf();
+        let policy_state = self
+            .home
+            .join(crate::CUSTODY_POLICY_FILE_NAME)
+            .into_std_path_buf();
+        let audit_log = self
+            .home
+            .join(crate::CUSTODY_AUDIT_LOG_FILE_NAME)
+            .into_std_path_buf();
 
         // Build the custody service...
         let custody = match &config.custody {
-            CustodyConfig::ViewOnly => {
+            CustodyConfig::ViewOnly | CustodyConfig::Encrypted(_) => {
                 tracing::info!("using view-only custody service");
                 let null_kms = penumbra_custody::null_kms::NullKms::default();
                 let custody_svc = CustodyServiceServer::new(null_kms);
@@ -65,17 +138,41 @@ impl Opt {
             }
             CustodyConfig::SoftKms(config) => {
                 tracing::info!("using software KMS custody service");
-                let soft_kms = SoftKms::new(config.clone());
+                let mut config = config.clone();
+                config.freeze_state.get_or_insert(freeze_state);
+                config.policy_state.get_or_insert(policy_state);
+                config.audit_log.get_or_insert(audit_log);
+                let soft_kms = SoftKms::new(config)?;
                 let custody_svc = CustodyServiceServer::new(soft_kms);
                 CustodyServiceClient::new(box_grpc_svc::local(custody_svc))
             }
             CustodyConfig::Threshold(config) => {
                 tracing::info!("using manual threshold custody service");
+                let freeze = FreezeSwitch::open(
+                    config.freeze_state().cloned().unwrap_or(freeze_state),
+                    config.recovery_signers().cloned(),
+                )?;
+                let audit_log = AuditLog::open(
+                    config.audit_log().cloned().unwrap_or(audit_log),
+                    config.signing_key().clone(),
+                )?;
                 let threshold_kms =
-                    penumbra_custody::threshold::Threshold::new(config.clone(), ActualTerminal);
+                    penumbra_custody::threshold::Threshold::new(config.clone(), ActualTerminal)
+                        .with_freeze_switch(freeze)
+                        .with_audit_log(audit_log);
                 let custody_svc = CustodyServiceServer::new(threshold_kms);
                 CustodyServiceClient::new(box_grpc_svc::local(custody_svc))
             }
+            #[cfg(feature = "pkcs11")]
+            CustodyConfig::Pkcs11(config) => {
+                tracing::info!("using PKCS#11 HSM custody service");
+                let mut config = config.clone();
+                config.freeze_state.get_or_insert(freeze_state);
+                config.audit_log.get_or_insert(audit_log);
+                let pkcs11_kms = penumbra_custody::pkcs11::Pkcs11Kms::new(config)?;
+                let custody_svc = CustodyServiceServer::new(pkcs11_kms);
+                CustodyServiceClient::new(box_grpc_svc::local(custody_svc))
+            }
         };
 
         // ...and the view service...
@@ -116,6 +213,9 @@ impl Opt {
     }
 }
 
+/// The directory under the home directory in which named profiles are kept.
+const PROFILES_DIR_NAME: &str = "profiles";
+
 fn default_home() -> Utf8PathBuf {
     let path = ProjectDirs::from("zone", "penumbra", "pcli")
         .expect("Failed to get platform data dir")
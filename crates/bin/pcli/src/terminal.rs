@@ -1,8 +1,10 @@
 use anyhow::Result;
-use penumbra_custody::threshold::Terminal;
+use penumbra_asset::asset::Cache;
+use penumbra_custody::{threshold::Terminal, PlanText};
 use penumbra_transaction::TransactionPlan;
 use tokio::io::{self, AsyncBufReadExt};
 use tonic::async_trait;
+use zeroize::Zeroizing;
 
 /// For threshold custody, we need to implement this weird terminal abstraction.
 ///
@@ -12,8 +14,10 @@ pub struct ActualTerminal;
 #[async_trait]
 impl Terminal for ActualTerminal {
     async fn confirm_transaction(&self, transaction: &TransactionPlan) -> Result<bool> {
+        let plan_text = PlanText::render(transaction, &Cache::with_known_assets());
         println!("Do you approve this transaction?");
-        println!("{}", serde_json::to_string_pretty(transaction)?);
+        print!("{}", plan_text);
+        println!("Plan text hash: {}", hex::encode(plan_text.hash()));
         println!("Press enter to continue");
         self.next_response().await?;
         Ok(true)
@@ -42,3 +46,21 @@ impl Terminal for ActualTerminal {
         Ok(Some(line))
     }
 }
+
+/// Read a password, prompting for it without echoing it if stdin is a
+/// terminal, or else reading a single line from stdin, so that pcli can be
+/// used non-interactively.
+///
+/// The password is zeroized once dropped.
+pub fn read_password(prompt: &str) -> Result<Zeroizing<String>> {
+    use std::io::{BufRead as _, IsTerminal as _};
+
+    if std::io::stdin().is_terminal() {
+        return Ok(Zeroizing::new(rpassword::prompt_password(prompt)?));
+    }
+    let mut line = Zeroizing::new(String::new());
+    std::io::stdin().lock().read_line(&mut line)?;
+    Ok(Zeroizing::new(
+        line.trim_end_matches(['\r', '\n']).to_string(),
+    ))
+}
@@ -335,12 +335,60 @@ impl TransactionViewExt for TransactionView {
                     );
                     ["Open Liquidity Position", &action]
                 }
+                penumbra_transaction::ActionView::PositionOpenBatch(batch) => {
+                    action = format!(
+                        "Open: {} Strategy: {}",
+                        batch.positions.len(),
+                        batch.strategy_tag,
+                    );
+                    ["Open Liquidity Positions", &action]
+                }
                 penumbra_transaction::ActionView::PositionClose(_) => {
                     ["Close Liquitity Position", ""]
                 }
                 penumbra_transaction::ActionView::PositionWithdraw(_) => {
                     ["Withdraw Liquitity Position", ""]
                 }
+                penumbra_transaction::ActionView::PositionUpdateBatch(batch) => {
+                    action = format!(
+                        "Close: {} Withdraw: {} Open: {}",
+                        batch.closes.len(),
+                        batch.withdrawals.len(),
+                        batch.opens.len(),
+                    );
+                    ["Update Liquidity Positions", &action]
+                }
+                penumbra_transaction::ActionView::PositionRewrite(rewrite) => {
+                    action = format!(
+                        "Replace: {} With: {}",
+                        rewrite.position_id,
+                        rewrite.new_position.id(),
+                    );
+                    ["Rewrite Liquidity Position", &action]
+                }
+                penumbra_transaction::ActionView::ActionDutchAuctionSchedule(schedule) => {
+                    let description = &schedule.description;
+                    action = format!(
+                        "Input: {} {} Output: {} to {} {} Heights: {} to {} ID: {}",
+                        description.input.amount,
+                        format_asset_id(&description.input.asset_id),
+                        description.max_output,
+                        description.min_output,
+                        format_asset_id(&description.output_id),
+                        description.start_height,
+                        description.end_height,
+                        description.id(),
+                    );
+                    ["Schedule Dutch Auction", &action]
+                }
+                penumbra_transaction::ActionView::ActionDutchAuctionEnd(end) => {
+                    action = format!("ID: {}", end.auction_id);
+                    ["End Dutch Auction", &action]
+                }
+                penumbra_transaction::ActionView::ActionDutchAuctionWithdraw(withdraw) => {
+                    action = format!("ID: {}", withdraw.auction_id);
+                    ["Withdraw Dutch Auction", &action]
+                }
                 penumbra_transaction::ActionView::ProposalDepositClaim(proposal_deposit_claim) => {
                     action = format!(
                         "Claim Deposit for Governance Proposal #{}",
@@ -368,6 +416,11 @@ impl TransactionViewExt for TransactionView {
                     ["Upload Validator Definition", ""]
                 }
                 penumbra_transaction::ActionView::ValidatorVote(_) => ["Validator Vote", ""],
+                penumbra_transaction::ActionView::BallotKeyDeal(_) => ["Ballot Key Deal", ""],
+                penumbra_transaction::ActionView::BallotKeyReveal(_) => ["Ballot Key Reveal", ""],
+                penumbra_transaction::ActionView::BallotKeyComplaint(_) => {
+                    ["Ballot Key Complaint", ""]
+                }
                 penumbra_transaction::ActionView::CommunityPoolDeposit(_) => {
                     ["Community Pool Deposit", ""]
                 }
@@ -380,6 +433,7 @@ impl TransactionViewExt for TransactionView {
                 penumbra_transaction::ActionView::Delegate(_) => ["Delegation", ""],
                 penumbra_transaction::ActionView::Undelegate(_) => ["Undelegation", ""],
                 penumbra_transaction::ActionView::UndelegateClaim(_) => ["Undelegation Claim", ""],
+                penumbra_transaction::ActionView::Redelegate(_) => ["Redelegation", ""],
             };
 
             actions_table.add_row(row);
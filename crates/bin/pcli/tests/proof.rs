@@ -184,8 +184,15 @@ fn swap_proof_parameters_vs_current_swap_circuit() {
     let fee = Fee::default();
     let fee_blinding = Fr::rand(&mut OsRng);
 
-    let swap_plaintext =
-        SwapPlaintext::new(&mut rng, trading_pair, delta_1, delta_2, fee, claim_address);
+    let swap_plaintext = SwapPlaintext::new(
+        &mut rng,
+        trading_pair,
+        delta_1,
+        delta_2,
+        fee,
+        claim_address,
+        fvk_recipient.nullifier_key(),
+    );
     let fee_commitment = swap_plaintext.claim_fee.commit(fee_blinding);
     let swap_commitment = swap_plaintext.swap_commitment();
 
@@ -256,7 +263,9 @@ fn swap_claim_parameters_vs_current_swap_claim_circuit() {
         delta_2_i,
         fee,
         claim_address,
+        &nk,
     );
+    let claim_key = swap_plaintext.claim_key(&nk);
     let claim_fee = swap_plaintext.clone().claim_fee;
     let mut sct = tct::Tree::new();
     let swap_commitment = swap_plaintext.swap_commitment();
@@ -264,7 +273,7 @@ fn swap_claim_parameters_vs_current_swap_claim_circuit() {
     let anchor = sct.root();
     let state_commitment_proof = sct.witness(swap_commitment).unwrap();
     let position = state_commitment_proof.position();
-    let nullifier = Nullifier::derive(&nk, position, &swap_commitment);
+    let nullifier = Nullifier::derive(&claim_key, position, &swap_commitment);
     let epoch_duration = 20;
     let height = epoch_duration * position.epoch() + position.block();
 
@@ -299,7 +308,7 @@ fn swap_claim_parameters_vs_current_swap_claim_circuit() {
     let private = SwapClaimProofPrivate {
         swap_plaintext,
         state_commitment_proof,
-        nk,
+        claim_key,
         lambda_1,
         lambda_2,
         note_blinding_1,
@@ -17,6 +17,7 @@ use penumbra_proto::{
         query_service_client::QueryServiceClient as AppQueryServiceClient, AppParametersRequest,
     },
     custody::v1::custody_service_server::CustodyServiceServer,
+    standard_services,
     view::v1::view_service_server::ViewServiceServer,
 };
 use penumbra_view::{Storage, ViewServer};
@@ -27,7 +28,7 @@ use std::fs;
 use std::fs::File;
 use std::io::{self, BufRead, Write};
 use std::str::FromStr;
-use tonic::transport::Server;
+use tonic::{server::NamedService, transport::Server};
 use url::Url;
 
 mod proxy;
@@ -266,6 +267,17 @@ impl Opt {
                     soft_kms::Config {
                         spend_key,
                         auth_policy,
+                        require_nonce: false,
+                        reject_duplicate_effect_hashes: false,
+                        policy_version: 0,
+                        policy_signers: None,
+                        policy_state: None,
+                        recovery_signers: None,
+                        freeze_state: None,
+                        rotation: None,
+                        spend_ledger: None,
+                        audit_log: None,
+                        allowed_message_domains: Vec::new(),
                     }
                 });
 
@@ -319,9 +331,22 @@ impl Opt {
 
                 let view_service =
                     ViewServiceServer::new(ViewServer::new(storage, config.grpc_url).await?);
-                let custody_service = config.kms_config.as_ref().map(|kms_config| {
-                    CustodyServiceServer::new(SoftKms::new(kms_config.spend_key.clone().into()))
-                });
+                let custody_service = config
+                    .kms_config
+                    .as_ref()
+                    .map(|kms_config| {
+                        SoftKms::new(kms_config.spend_key.clone().into())
+                            .map(CustodyServiceServer::new)
+                    })
+                    .transpose()?;
+
+                // Report the view service, and the custody service if enabled, as serving; the
+                // proxied services are as healthy as the pd endpoint behind them.
+                let mut service_names = vec![<ViewServiceServer<ViewServer> as NamedService>::NAME];
+                if custody_service.is_some() {
+                    service_names.push(<CustodyServiceServer<SoftKms> as NamedService>::NAME);
+                }
+                let (_, health_service) = standard_services::health_service(service_names).await;
 
                 let server = Server::builder()
                     .accept_http1(true)
@@ -338,14 +363,8 @@ impl Opt {
                     .add_service(tonic_web::enable(stake_query_proxy))
                     .add_service(tonic_web::enable(compact_block_query_proxy))
                     .add_service(tonic_web::enable(tendermint_proxy_proxy))
-                    .add_service(tonic_web::enable(
-                        tonic_reflection::server::Builder::configure()
-                            .register_encoded_file_descriptor_set(
-                                penumbra_proto::FILE_DESCRIPTOR_SET,
-                            )
-                            .build()
-                            .with_context(|| "could not configure grpc reflection service")?,
-                    ))
+                    .add_service(tonic_web::enable(health_service))
+                    .add_service(tonic_web::enable(standard_services::reflection_service()?))
                     .serve(config.bind_addr);
 
                 tokio::spawn(server).await??;
@@ -41,6 +41,17 @@ fn generate_config() -> anyhow::Result<PclientdConfig> {
         kms_config: Some(soft_kms::Config {
             spend_key: test_keys::SPEND_KEY.clone(),
             auth_policy: Vec::new(),
+            require_nonce: false,
+            reject_duplicate_effect_hashes: false,
+            policy_version: 0,
+            policy_signers: None,
+            policy_state: None,
+            recovery_signers: None,
+            freeze_state: None,
+            rotation: None,
+            spend_ledger: None,
+            audit_log: None,
+            allowed_message_domains: Vec::new(),
         }),
     })
 }
@@ -142,6 +153,7 @@ async fn transaction_send_flow() -> anyhow::Result<()> {
         .authorize(AuthorizeRequest {
             plan: Some(plan.clone()),
             pre_authorizations: Vec::new(),
+            nonce: Vec::new(),
         })
         .await?
         .into_inner()
@@ -326,6 +338,7 @@ async fn swap_claim_flow() -> anyhow::Result<()> {
         .authorize(AuthorizeRequest {
             plan: Some(plan.clone()),
             pre_authorizations: Vec::new(),
+            nonce: Vec::new(),
         })
         .await?
         .into_inner()
@@ -440,6 +453,7 @@ async fn swap_claim_flow() -> anyhow::Result<()> {
         .authorize(AuthorizeRequest {
             plan: Some(plan.clone()),
             pre_authorizations: Vec::new(),
+            nonce: Vec::new(),
         })
         .await?
         .into_inner()
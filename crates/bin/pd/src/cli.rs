@@ -96,6 +96,61 @@ pub enum RootCommand {
         /// But, it is a potential DoS vector, so it is disabled by default.
         #[clap(short, long, display_order = 500)]
         enable_expensive_rpc: bool,
+
+        /// The number of recent blocks CometBFT must retain, allowing it to
+        /// prune older ones. CometBFT still keeps any blocks it needs for
+        /// evidence or state sync. If zero, every block is retained.
+        #[clap(
+            long,
+            env = "PENUMBRA_PD_RETAIN_BLOCKS",
+            default_value = "0",
+            display_order = 600
+        )]
+        retain_blocks: u64,
+    },
+    /// Serves queries from a read-only copy of another node's state.
+    ///
+    /// The replica periodically copies the state in `--snapshot-dir` into its
+    /// home directory, and serves the same gRPC query services as `pd start`
+    /// from the copy. It does not participate in consensus, so it can be used
+    /// to scale out query capacity without running additional full nodes.
+    Replica {
+        /// The path used to store the replica's copies of the snapshot.
+        #[clap(long, env = "PENUMBRA_PD_HOME", display_order = 100)]
+        home: PathBuf,
+        /// The directory containing the state to serve.
+        ///
+        /// This should contain a consistent copy of a full node's `rocksdb`
+        /// directory, such as one produced by `pd export`, and may be updated
+        /// in place with newer copies.
+        #[clap(long, display_order = 101)]
+        snapshot_dir: PathBuf,
+        /// How often to refresh the replica's state from the snapshot directory, in seconds.
+        #[clap(long, default_value = "60", display_order = 102)]
+        refresh_interval: u64,
+        /// Bind the gRPC server to this socket.
+        ///
+        /// The gRPC server supports both grpc (HTTP/2) and grpc-web (HTTP/1.1) clients.
+        #[clap(
+            short,
+            long,
+            env = "PENUMBRA_PD_GRPC_BIND",
+            default_value = "127.0.0.1:8080",
+            display_order = 200
+        )]
+        grpc_bind: SocketAddr,
+        /// The JSON-RPC address of a CometBFT node to proxy CometBFT requests to.
+        #[clap(
+            short,
+            long,
+            env = "PENUMBRA_PD_COMETBFT_PROXY_URL",
+            default_value = "http://127.0.0.1:26657",
+            display_order = 401
+        )]
+        cometbft_addr: Url,
+        /// Enable expensive RPCs, such as the trade simulation service.
+        #[clap(short, long, display_order = 500)]
+        enable_expensive_rpc: bool,
     },
     /// Generate, join, or reset a testnet.
     Testnet {
@@ -108,6 +163,17 @@ pub enum RootCommand {
         tn_cmd: TestnetCommand,
     },
 
+    /// Join an existing network as a full node.
+    Network {
+        /// Path to directory to store output in. Must not exist. Defaults to
+        /// ~/.penumbra/testnet_data", so that `pd start` finds it by default.
+        #[clap(long)]
+        network_dir: Option<PathBuf>,
+
+        #[clap(subcommand)]
+        net_cmd: NetworkCommand,
+    },
+
     /// Export the storage state the full node.
     Export {
         /// The home directory of the full node.
@@ -131,6 +197,106 @@ pub enum RootCommand {
         /// unless the migration logic overrides it.
         genesis_start: Option<tendermint::time::Time>,
     },
+    /// Inspect the storage state of the full node.
+    State {
+        #[clap(subcommand)]
+        state_cmd: StateCommand,
+    },
+}
+
+#[derive(Debug, Subcommand)]
+pub enum StateCommand {
+    /// Print every key with the given prefix in the latest state, and its
+    /// value.
+    ///
+    /// Values of well-known types are decoded and printed in a human-readable
+    /// form, and all other values are printed as hex. The node must not be
+    /// running, since its storage can only be opened by one process at a time.
+    Inspect {
+        /// The home directory of the full node.
+        #[clap(long, env = "PENUMBRA_PD_HOME", display_order = 100)]
+        home: PathBuf,
+        /// The prefix of the state keys to print, e.g. `staking/validators/definitions/`.
+        #[clap(long, default_value = "", display_order = 200)]
+        prefix: String,
+        /// The maximum number of keys to print.
+        #[clap(long, display_order = 300)]
+        limit: Option<usize>,
+        /// Print all values as hex, without decoding them.
+        #[clap(long, display_order = 400)]
+        raw: bool,
+    },
+}
+
+#[derive(Debug, Subcommand)]
+pub enum NetworkCommand {
+    /// Generates the configs for `pd` and CometBFT needed to run a full node on
+    /// the network to which the specified node belongs.
+    ///
+    /// The genesis and peers are fetched from the node, and the peers are checked
+    /// for reachability before anything is written. The CometBFT config is
+    /// written to `node0/cometbft`, and the settings for `pd start` to
+    /// `node0/pd/pd.env`.
+    Join {
+        /// URL of the remote CometBFT RPC endpoint for bootstrapping connection.
+        #[clap(env = "PENUMBRA_PD_JOIN_URL")]
+        node: Url,
+        /// Human-readable name to identify node on network
+        // Default: 'node-#'
+        #[clap(long, env = "PENUMBRA_PD_TM_MONIKER")]
+        moniker: Option<String>,
+        /// Public URL to advertise for this node's CometBFT P2P service.
+        /// Setting this option will instruct other nodes on the network to connect
+        /// to yours. Must be in the form of a socket, e.g. "1.2.3.4:26656".
+        #[clap(long, env = "PENUMBRA_PD_TM_EXTERNAL_ADDR")]
+        external_address: Option<SocketAddr>,
+        /// When generating CometBFT config, use this socket to bind the CometBFT RPC service.
+        #[clap(long, env = "PENUMBRA_PD_TM_RPC_BIND", default_value = "0.0.0.0:26657")]
+        cometbft_rpc_bind: SocketAddr,
+        /// When generating CometBFT config, use this socket to bind the CometBFT P2P service.
+        #[clap(long, env = "PENUMBRA_PD_TM_P2P_BIND", default_value = "0.0.0.0:26656")]
+        cometbft_p2p_bind: SocketAddr,
+        /// The CometBFT transaction indexer. `pd` needs the `kv` indexer to serve
+        /// transactions by hash, so only disable it on nodes which don't serve clients.
+        #[clap(long, value_enum, default_value = "kv")]
+        indexer: TxIndexer,
+        /// Have CometBFT discard ABCI responses once they are applied, saving
+        /// considerable disk space, at the cost of the `/block_results` RPC.
+        #[clap(long)]
+        discard_abci_responses: bool,
+        /// The maximum number of peers which may connect to this node.
+        #[clap(long, default_value = "40")]
+        max_inbound_peers: u64,
+        /// The maximum number of peers this node connects to.
+        #[clap(long, default_value = "10")]
+        max_outbound_peers: u64,
+        /// The number of recent blocks the node retains, pruning older ones.
+        /// Set to zero to keep a full history, e.g. for an archive node.
+        #[clap(long, default_value = "100000")]
+        retain_blocks: u64,
+        /// Write the configs even if no peer of the network can be reached.
+        #[clap(long)]
+        skip_connectivity_check: bool,
+    },
+}
+
+/// The CometBFT transaction indexers which `pd network join` can configure.
+#[derive(Debug, Clone, Copy, clap::ValueEnum)]
+pub enum TxIndexer {
+    /// Index transactions by hash and height.
+    Kv,
+    /// Don't index transactions.
+    Null,
+}
+
+impl TxIndexer {
+    /// The name of the indexer in the CometBFT config.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            TxIndexer::Kv => "kv",
+            TxIndexer::Null => "null",
+        }
+    }
 }
 
 #[derive(Debug, Subcommand)]
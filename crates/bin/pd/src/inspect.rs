@@ -0,0 +1,276 @@
+//! Inspecting the contents of a node's state, for debugging.
+//!
+//! Values in the state are stored as encoded protobuf messages, which are
+//! unreadable without knowing which message type was written under each key.
+//! This module keeps a registry of the types stored under the well-known key
+//! prefixes of each component, so that `pd state inspect` can print values in
+//! a human-readable form.
+
+use std::{fmt::Debug, path::PathBuf, sync::OnceLock};
+
+use anyhow::{Context as _, Result};
+use cnidarium::{StateRead as _, Storage};
+use futures::StreamExt as _;
+use penumbra_app::{state_key_registry::component_for_key, SUBSTORE_PREFIXES};
+use penumbra_proto::{DomainType, Message};
+use serde::Serialize;
+
+/// Decodes a raw value into a human-readable string.
+type Decoder = fn(&[u8]) -> Result<String>;
+
+/// The type of the values stored under a single key, or under the keys with a
+/// given prefix and suffix.
+struct KnownKey {
+    prefix: String,
+    /// The suffix of the matching keys, or `None` if only the prefix itself matches.
+    suffix: Option<String>,
+    decode: Decoder,
+}
+
+impl KnownKey {
+    /// The type of the value stored under the given key.
+    fn key(key: &str, decode: Decoder) -> Self {
+        Self {
+            prefix: key.to_string(),
+            suffix: None,
+            decode,
+        }
+    }
+
+    /// The type of the values stored under every key with the given prefix.
+    fn prefix(prefix: &str, decode: Decoder) -> Self {
+        Self {
+            prefix: prefix.to_string(),
+            suffix: Some(String::new()),
+            decode,
+        }
+    }
+
+    /// The type of the values stored under the keys built by a state key
+    /// function, given the key it builds for [`u64::MAX`].
+    ///
+    /// Keys match if they only differ from it where that value was rendered.
+    fn by_number(key: String, decode: Decoder) -> Self {
+        let (prefix, suffix) = key
+            .split_once(&u64::MAX.to_string())
+            .expect("the key contains the number");
+        Self {
+            prefix: prefix.to_string(),
+            suffix: Some(suffix.to_string()),
+            decode,
+        }
+    }
+
+    fn matches(&self, key: &str) -> bool {
+        match &self.suffix {
+            None => key == self.prefix,
+            Some(suffix) => {
+                key.len() >= self.prefix.len() + suffix.len()
+                    && key.starts_with(&self.prefix)
+                    && key.ends_with(suffix.as_str())
+            }
+        }
+    }
+}
+
+/// Decode a domain type, printing its debug representation, or the JSON
+/// representation of its proto if the proto does not convert to the domain
+/// type.
+fn domain<T>(bytes: &[u8]) -> Result<String>
+where
+    T: DomainType + Debug,
+    T::Proto: Serialize,
+    anyhow::Error: From<<T as TryFrom<T::Proto>>::Error>,
+{
+    match T::decode(bytes) {
+        Ok(value) => Ok(format!("{value:#?}")),
+        Err(error) => {
+            let proto = T::Proto::decode(bytes).context("could not decode value")?;
+            Ok(format!(
+                "{}\n(not a valid {}: {error:#})",
+                serde_json::to_string_pretty(&proto)?,
+                std::any::type_name::<T>()
+            ))
+        }
+    }
+}
+
+/// Decode a plain proto message, such as a number or a string.
+fn proto<P: Message + Default + Debug>(bytes: &[u8]) -> Result<String> {
+    Ok(format!("{:#?}", P::decode(bytes)?))
+}
+
+/// The types of the values stored under well-known keys, derived from the
+/// state key functions of each component.
+///
+/// When several entries match a key, the first one is used, so more specific
+/// patterns must come before more general ones.
+fn known_keys() -> &'static [KnownKey] {
+    static KNOWN_KEYS: OnceLock<Vec<KnownKey>> = OnceLock::new();
+    KNOWN_KEYS.get_or_init(|| {
+        use penumbra_app::app::state_key as app;
+        use penumbra_dex::state_key as dex;
+        use penumbra_governance::state_key as governance;
+        use penumbra_sct::state_key as sct;
+        use penumbra_stake::state_key::{parameters, validators};
+
+        vec![
+            // Application
+            KnownKey::key(app::data::chain_id(), proto::<String>),
+            KnownKey::key(
+                app::genesis::app_state(),
+                domain::<penumbra_genesis::AppState>,
+            ),
+            // Shielded state commitment tree
+            KnownKey::key(
+                sct::config::sct_params(),
+                domain::<penumbra_sct::params::SctParameters>,
+            ),
+            KnownKey::key(sct::block_manager::block_height(), proto::<u64>),
+            KnownKey::key(sct::block_manager::block_timestamp(), proto::<String>),
+            KnownKey::by_number(
+                sct::epoch_manager::epoch_by_height(u64::MAX),
+                domain::<penumbra_sct::epoch::Epoch>,
+            ),
+            // Staking
+            KnownKey::key(
+                parameters::key(),
+                domain::<penumbra_stake::params::StakeParameters>,
+            ),
+            KnownKey::prefix(
+                validators::definitions::prefix(),
+                domain::<penumbra_stake::validator::Validator>,
+            ),
+            KnownKey::prefix(
+                validators::state::prefix(),
+                domain::<penumbra_stake::validator::State>,
+            ),
+            KnownKey::prefix(
+                validators::rate::prefix(),
+                domain::<penumbra_stake::rate::RateData>,
+            ),
+            KnownKey::prefix(
+                validators::power::prefix(),
+                domain::<penumbra_num::Amount>,
+            ),
+            KnownKey::prefix(
+                validators::bonding_state::prefix(),
+                domain::<penumbra_stake::validator::BondingState>,
+            ),
+            KnownKey::prefix(
+                validators::uptime::prefix(),
+                domain::<penumbra_stake::Uptime>,
+            ),
+            // Dex
+            KnownKey::prefix(
+                dex::all_positions(),
+                domain::<penumbra_dex::lp::position::Position>,
+            ),
+            KnownKey::prefix(
+                dex::all_position_metadata(),
+                domain::<penumbra_dex::lp::PositionMetadata>,
+            ),
+            KnownKey::prefix(dex::outputs(), domain::<penumbra_dex::BatchSwapOutputData>),
+            KnownKey::prefix(
+                dex::swap_executions(),
+                domain::<penumbra_dex::SwapExecution>,
+            ),
+            KnownKey::prefix(
+                dex::arb_executions(),
+                domain::<penumbra_dex::SwapExecution>,
+            ),
+            // Governance
+            KnownKey::key(
+                governance::governance_params(),
+                domain::<penumbra_governance::params::GovernanceParameters>,
+            ),
+            KnownKey::key(governance::next_proposal_id(), proto::<u64>),
+            KnownKey::by_number(
+                governance::proposal_definition(u64::MAX),
+                domain::<penumbra_governance::Proposal>,
+            ),
+            KnownKey::by_number(
+                governance::proposal_state(u64::MAX),
+                domain::<penumbra_governance::proposal_state::State>,
+            ),
+        ]
+    })
+}
+
+/// Render a value stored under the given key, decoding it if its type is
+/// known, and printing it as hex otherwise.
+pub fn render_value(key: &str, value: &[u8]) -> String {
+    match known_keys().iter().find(|known| known.matches(key)) {
+        Some(known) => (known.decode)(value).unwrap_or_else(|error| {
+            format!("{}\n(could not decode: {error:#})", hex::encode(value))
+        }),
+        None => hex::encode(value),
+    }
+}
+
+/// Print every key in the node's latest state which starts with the given
+/// prefix, along with its value.
+pub async fn inspect(home: PathBuf, prefix: String, limit: Option<usize>, raw: bool) -> Result<()> {
+    let storage = Storage::load(home.join("rocksdb"), SUBSTORE_PREFIXES.to_vec())
+        .await
+        .context("could not open the node's storage")?;
+    let snapshot = storage.latest_snapshot();
+    println!("# state at version {}", snapshot.version());
+
+    let mut entries = std::pin::pin!(snapshot
+        .prefix_raw(&prefix)
+        .take(limit.unwrap_or(usize::MAX)));
+    let mut count = 0;
+    while let Some(entry) = entries.next().await {
+        let (key, value) = entry?;
+        let component = component_for_key(&key).unwrap_or("unregistered");
+        let value = if raw {
+            hex::encode(&value)
+        } else {
+            render_value(&key, &value)
+        };
+        println!("{key} ({component})\n{value}\n");
+        count += 1;
+    }
+    println!("# {count} keys with prefix {prefix:?}");
+
+    storage.release().await;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use penumbra_sct::epoch::Epoch;
+
+    #[test]
+    fn known_keys_are_decoded() {
+        let epoch = Epoch {
+            index: 3,
+            start_height: 60,
+        };
+        let key = penumbra_sct::state_key::epoch_manager::epoch_by_height(61);
+        assert_eq!(
+            render_value(&key, &epoch.encode_to_vec()),
+            format!("{epoch:#?}")
+        );
+
+        // The more specific pattern for a proposal's state is not confused
+        // with the proposal itself.
+        let key = penumbra_governance::state_key::proposal_state(1);
+        let state = penumbra_governance::proposal_state::State::Voting;
+        assert_eq!(
+            render_value(&key, &state.encode_to_vec()),
+            format!("{state:#?}")
+        );
+    }
+
+    #[test]
+    fn unknown_keys_are_printed_as_hex() {
+        assert_eq!(render_value("unregistered/key", &[0xab, 0xcd]), "abcd");
+
+        // Keys under a known single key aren't decoded as its type.
+        let key = penumbra_stake::state_key::parameters::updated_flag();
+        assert_eq!(render_value(key, &[0xab, 0xcd]), "abcd");
+    }
+}
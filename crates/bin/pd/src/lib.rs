@@ -8,7 +8,11 @@
 mod metrics;
 
 pub mod cli;
+pub mod inspect;
 pub mod migrate;
+pub mod network;
+pub mod replica;
+pub mod rpc;
 pub mod testnet;
 pub mod zipserve;
 
@@ -10,30 +10,22 @@ use metrics_util::layers::Stack;
 
 use anyhow::Context;
 use cnidarium::{StateDelta, Storage};
-use ibc_proto::ibc::core::channel::v1::query_server::QueryServer as ChannelQueryServer;
-use ibc_proto::ibc::core::client::v1::query_server::QueryServer as ClientQueryServer;
-use ibc_proto::ibc::core::connection::v1::query_server::QueryServer as ConnectionQueryServer;
 use metrics_exporter_prometheus::PrometheusBuilder;
 use pd::{
-    cli::{Opt, RootCommand, TestnetCommand},
+    cli::{NetworkCommand, Opt, RootCommand, StateCommand, TestnetCommand},
     migrate::Migration::SimpleMigration,
+    network::{network_join, JoinOptions},
     testnet::{
         config::{get_testnet_dir, parse_tm_address, url_has_necessary_parts},
         generate::TestnetConfig,
         join::testnet_join,
     },
 };
-use penumbra_app::{PenumbraHost, SUBSTORE_PREFIXES};
-use penumbra_proto::core::component::dex::v1::simulation_service_server::SimulationServiceServer;
-use penumbra_proto::util::tendermint_proxy::v1::tendermint_proxy_service_server::TendermintProxyServiceServer;
-use penumbra_tendermint_proxy::TendermintProxy;
-use penumbra_tower_trace::remote_addr;
+use penumbra_app::SUBSTORE_PREFIXES;
 use rand::Rng;
 use rand_core::OsRng;
 use tendermint_config::net::Address as TendermintAddress;
 use tokio::runtime;
-use tonic::transport::Server;
-use tower_http::cors::CorsLayer;
 use tracing_subscriber::{prelude::*, EnvFilter};
 use url::Url;
 
@@ -76,6 +68,7 @@ async fn main() -> anyhow::Result<()> {
             metrics_bind,
             cometbft_addr,
             enable_expensive_rpc,
+            retain_blocks,
         } => {
             // Use the given `grpc_bind` address if one was specified. If not, we will choose a
             // default depending on whether or not `grpc_auto_https` was set. See the
@@ -116,6 +109,12 @@ async fn main() -> anyhow::Result<()> {
                 .await
                 .context("Unable to initialize RocksDB storage")?;
 
+            // If the DEX's value circuit breaker halts the chain, the block is never committed,
+            // so the breach is recorded in the home directory instead, for post-mortem queries.
+            penumbra_dex::ValueCircuitBreakerBreach::record_to(
+                pd_home.join("value_circuit_breaker_breach.json"),
+            )?;
+
             tracing::info!(
                 ?abci_bind,
                 ?grpc_bind,
@@ -124,127 +123,18 @@ async fn main() -> anyhow::Result<()> {
                 ?metrics_bind,
                 %cometbft_addr,
                 ?enable_expensive_rpc,
+                retain_blocks,
                 "starting pd"
             );
 
-            let tm_proxy = TendermintProxy::new(cometbft_addr);
             let abci_server = tokio::task::Builder::new()
                 .name("abci_server")
-                .spawn(penumbra_app::server::new(storage.clone()).listen_tcp(abci_bind))
+                .spawn(
+                    penumbra_app::server::new(storage.clone(), retain_blocks).listen_tcp(abci_bind),
+                )
                 .expect("failed to spawn abci server");
 
-            let ibc = penumbra_ibc::component::rpc::IbcQuery::<PenumbraHost>::new(storage.clone());
-
-            // TODO: Once we migrate to Tonic 0.10.0, we'll be able to use the
-            // `Routes` structure to have each component define a method that
-            // returns a `Routes` with all of its query services bundled inside.
-            //
-            // This means we won't have to import all this shit and recite every
-            // single service -- we can e.g., have the app crate assemble all of
-            // its components' query services into a single `Routes` and then
-            // just add that to the gRPC server.
-
-            use cnidarium::rpc::proto::v1::query_service_server::QueryServiceServer as StorageQueryServiceServer;
-            use penumbra_proto::core::{
-                app::v1::query_service_server::QueryServiceServer as AppQueryServiceServer,
-                component::{
-                    compact_block::v1::query_service_server::QueryServiceServer as CompactBlockQueryServiceServer,
-                    dex::v1::query_service_server::QueryServiceServer as DexQueryServiceServer,
-                    fee::v1::query_service_server::QueryServiceServer as FeeQueryServiceServer,
-                    governance::v1::query_service_server::QueryServiceServer as GovernanceQueryServiceServer,
-                    sct::v1::query_service_server::QueryServiceServer as SctQueryServiceServer,
-                    shielded_pool::v1::query_service_server::QueryServiceServer as ShieldedPoolQueryServiceServer,
-                    stake::v1::query_service_server::QueryServiceServer as StakeQueryServiceServer,
-                },
-            };
-            use tonic_web::enable as we;
-
-            use cnidarium::rpc::Server as StorageServer;
-            use penumbra_app::rpc::Server as AppServer;
-            use penumbra_compact_block::component::rpc::Server as CompactBlockServer;
-            use penumbra_dex::component::rpc::Server as DexServer;
-            use penumbra_fee::component::rpc::Server as FeeServer;
-            use penumbra_governance::component::rpc::Server as GovernanceServer;
-            use penumbra_sct::component::rpc::Server as SctServer;
-            use penumbra_shielded_pool::component::rpc::Server as ShieldedPoolServer;
-            use penumbra_stake::component::rpc::Server as StakeServer;
-
-            let mut grpc_server = Server::builder()
-                .trace_fn(|req| match remote_addr(req) {
-                    Some(remote_addr) => {
-                        tracing::error_span!("grpc", ?remote_addr)
-                    }
-                    None => tracing::error_span!("grpc"),
-                })
-                // Allow HTTP/1, which will be used by grpc-web connections.
-                // This is particularly important when running locally, as gRPC
-                // typically uses HTTP/2, which requires HTTPS. Accepting HTTP/2
-                // allows local applications such as web browsers to talk to pd.
-                .accept_http1(true)
-                // As part of #2932, we are disabling all timeouts until we circle back to our
-                // performance story.
-                // Sets a timeout for all gRPC requests, but note that in the case of streaming
-                // requests, the timeout is only applied to the initial request. This means that
-                // this does not prevent long lived streams, for example to allow clients to obtain
-                // new blocks.
-                // .timeout(std::time::Duration::from_secs(7))
-                // Wrap each of the gRPC services in a tonic-web proxy:
-                .add_service(we(StorageQueryServiceServer::new(StorageServer::new(
-                    storage.clone(),
-                ))))
-                .add_service(we(AppQueryServiceServer::new(AppServer::new(
-                    storage.clone(),
-                ))))
-                .add_service(we(CompactBlockQueryServiceServer::new(
-                    CompactBlockServer::new(storage.clone()),
-                )))
-                .add_service(we(DexQueryServiceServer::new(DexServer::new(
-                    storage.clone(),
-                ))))
-                .add_service(we(FeeQueryServiceServer::new(FeeServer::new(
-                    storage.clone(),
-                ))))
-                .add_service(we(GovernanceQueryServiceServer::new(
-                    GovernanceServer::new(storage.clone()),
-                )))
-                .add_service(we(SctQueryServiceServer::new(SctServer::new(
-                    storage.clone(),
-                ))))
-                .add_service(we(ShieldedPoolQueryServiceServer::new(
-                    ShieldedPoolServer::new(storage.clone()),
-                )))
-                .add_service(we(StakeQueryServiceServer::new(StakeServer::new(
-                    storage.clone(),
-                ))))
-                .add_service(we(ClientQueryServer::new(ibc.clone())))
-                .add_service(we(ChannelQueryServer::new(ibc.clone())))
-                .add_service(we(ConnectionQueryServer::new(ibc.clone())))
-                .add_service(we(TendermintProxyServiceServer::new(tm_proxy.clone())))
-                .add_service(we(tonic_reflection::server::Builder::configure()
-                    .register_encoded_file_descriptor_set(penumbra_proto::FILE_DESCRIPTOR_SET)
-                    .build()
-                    .with_context(|| "could not configure grpc reflection service")?));
-
-            if enable_expensive_rpc {
-                grpc_server = grpc_server.add_service(we(SimulationServiceServer::new(
-                    DexServer::new(storage.clone()),
-                )));
-            }
-
-            // Create Axum routes for the frontend app.
-            let frontend = pd::zipserve::router("/app/", pd::MINIFRONT_ARCHIVE_BYTES);
-            let node_status = pd::zipserve::router("/", pd::NODE_STATUS_ARCHIVE_BYTES);
-
-            // Now we drop down a layer of abstraction, from tonic to axum, and merge handlers.
-            let router = grpc_server
-                .into_router()
-                .merge(frontend)
-                .merge(node_status)
-                // Set rather permissive CORS headers for pd's gRPC: the service
-                // should be accessible from arbitrary web contexts, such as localhost,
-                // or any FQDN that wants to reference its data.
-                .layer(CorsLayer::permissive());
-
+            let router = pd::rpc::router(&storage, cometbft_addr, enable_expensive_rpc).await?;
             let make_svc = router.into_make_service();
 
             // Now start the GRPC server, initializing an ACME client to use as a certificate
@@ -338,6 +228,45 @@ async fn main() -> anyhow::Result<()> {
             };
         }
 
+        RootCommand::Replica {
+            home,
+            snapshot_dir,
+            refresh_interval,
+            grpc_bind,
+            cometbft_addr,
+            enable_expensive_rpc,
+        } => {
+            if !url_has_necessary_parts(&cometbft_addr) {
+                anyhow::bail!(
+                    "Failed to parse '--cometbft-addr' as URL: {}",
+                    cometbft_addr
+                )
+            }
+            if refresh_interval == 0 {
+                anyhow::bail!("'--refresh-interval' must be at least one second");
+            }
+
+            tracing::info!(
+                ?home,
+                ?snapshot_dir,
+                refresh_interval,
+                ?grpc_bind,
+                %cometbft_addr,
+                ?enable_expensive_rpc,
+                "starting pd replica"
+            );
+
+            pd::replica::Replica {
+                snapshot_dir,
+                working_dir: home.join("replica"),
+                refresh_interval: std::time::Duration::from_secs(refresh_interval),
+                cometbft_addr,
+                enable_expensive_rpc,
+            }
+            .run(grpc_bind)
+            .await?;
+        }
+
         RootCommand::Testnet {
             tn_cmd: TestnetCommand::UnsafeResetAll {},
             testnet_dir,
@@ -402,6 +331,65 @@ async fn main() -> anyhow::Result<()> {
             .await?;
         }
 
+        RootCommand::Network {
+            net_cmd:
+                NetworkCommand::Join {
+                    node,
+                    moniker,
+                    external_address,
+                    cometbft_rpc_bind,
+                    cometbft_p2p_bind,
+                    indexer,
+                    discard_abci_responses,
+                    max_inbound_peers,
+                    max_outbound_peers,
+                    retain_blocks,
+                    skip_connectivity_check,
+                },
+            network_dir,
+        } => {
+            let output_dir = get_testnet_dir(network_dir);
+
+            // If the output directory already exists, bail out, rather than overwriting.
+            if output_dir.exists() {
+                anyhow::bail!(
+                    "output directory {:?} already exists, refusing to overwrite it",
+                    output_dir
+                );
+            }
+
+            let external_address: Option<TendermintAddress> = match external_address {
+                Some(a) => {
+                    let u = Url::parse(format!("tcp://{}", a).as_str())?;
+                    Some(parse_tm_address(None, &u)?)
+                }
+                None => None,
+            };
+
+            let moniker = match moniker {
+                Some(m) => m,
+                None => format!("node-{}", hex::encode(OsRng.gen::<u32>().to_le_bytes())),
+            };
+
+            network_join(
+                output_dir,
+                node,
+                JoinOptions {
+                    moniker,
+                    external_address,
+                    cometbft_rpc_bind,
+                    cometbft_p2p_bind,
+                    indexer,
+                    discard_abci_responses,
+                    max_inbound_peers,
+                    max_outbound_peers,
+                    retain_blocks,
+                    skip_connectivity_check,
+                },
+            )
+            .await?;
+        }
+
         RootCommand::Testnet {
             tn_cmd:
                 TestnetCommand::Generate {
@@ -520,6 +508,17 @@ async fn main() -> anyhow::Result<()> {
                 .await
                 .context("failed to upgrade state")?;
         }
+        RootCommand::State {
+            state_cmd:
+                StateCommand::Inspect {
+                    home,
+                    prefix,
+                    limit,
+                    raw,
+                },
+        } => {
+            pd::inspect::inspect(home, prefix, limit, raw).await?;
+        }
     }
     Ok(())
 }
@@ -0,0 +1,297 @@
+//! Logic for joining an existing network as a long-running full node.
+//!
+//! Unlike `pd testnet join`, which only writes a CometBFT config for a
+//! throwaway testnet node, this checks that the network can actually be
+//! reached before writing anything, applies defaults suited to a full node
+//! that will run indefinitely, and writes the settings `pd` needs to run
+//! alongside CometBFT, so that both can be started without further setup.
+
+use std::{fs, net::SocketAddr, path::PathBuf, time::Duration};
+
+use anyhow::Context;
+use futures::future::join_all;
+use tendermint_config::{net::Address as TendermintAddress, TendermintConfig};
+use url::Url;
+
+use crate::{
+    cli::TxIndexer,
+    testnet::{
+        config::TestnetTendermintConfig,
+        generate::TestnetValidator,
+        join::{fetch_genesis, fetch_listen_address, fetch_peers},
+    },
+};
+
+/// How long to wait for a peer to accept a connection before considering it
+/// unreachable.
+const PEER_CONNECT_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// The socket on which `pd` serves ABCI to CometBFT, which must match the
+/// `proxy_app` in the CometBFT config template.
+const ABCI_BIND: &str = "127.0.0.1:26658";
+
+/// Settings for the node joining the network.
+#[derive(Debug, Clone)]
+pub struct JoinOptions {
+    /// Human-readable name identifying the node on the network.
+    pub moniker: String,
+    /// The address other nodes should use to connect to this one, if any.
+    pub external_address: Option<TendermintAddress>,
+    /// The socket to bind the CometBFT RPC service to.
+    pub cometbft_rpc_bind: SocketAddr,
+    /// The socket to bind the CometBFT P2P service to.
+    pub cometbft_p2p_bind: SocketAddr,
+    /// The CometBFT transaction indexer to use.
+    pub indexer: TxIndexer,
+    /// Whether CometBFT should discard ABCI responses once they are applied.
+    pub discard_abci_responses: bool,
+    /// The maximum number of inbound CometBFT peers.
+    pub max_inbound_peers: u64,
+    /// The maximum number of outbound CometBFT peers.
+    pub max_outbound_peers: u64,
+    /// The number of recent blocks to retain, or zero to retain every block.
+    pub retain_blocks: u64,
+    /// Write the configs even if no peer of the network can be reached.
+    pub skip_connectivity_check: bool,
+}
+
+/// Join the network to which the CometBFT node at `node` belongs, writing
+/// configs for `pd` and CometBFT into `output_dir`.
+///
+/// The genesis and peers are fetched from `node`, and the peers are checked
+/// for reachability: unreachable peers are left out of the config, and if none
+/// can be reached, nothing is written, since the node would be unable to sync.
+pub async fn network_join(
+    output_dir: PathBuf,
+    node: Url,
+    options: JoinOptions,
+) -> anyhow::Result<()> {
+    let genesis = fetch_genesis(&node).await?;
+    check_node_network(&node, genesis.chain_id.as_str()).await?;
+
+    let mut peers = Vec::new();
+    if let Some(node_tm_address) = fetch_listen_address(&node).await {
+        peers.push(node_tm_address);
+    }
+    peers.extend(fetch_peers(&node).await?);
+
+    let reachable = join_all(peers.iter().map(peer_is_reachable)).await;
+    let (peers, unreachable): (Vec<_>, Vec<_>) = peers
+        .into_iter()
+        .zip(reachable)
+        .partition(|(_, reachable)| *reachable);
+    let peers: Vec<TendermintAddress> = peers.into_iter().map(|(peer, _)| peer).collect();
+    for (peer, _) in unreachable {
+        tracing::warn!(%peer, "could not connect to peer, leaving it out of the config");
+    }
+    if peers.is_empty() {
+        if options.skip_connectivity_check {
+            tracing::warn!(
+                "no peers of the network could be reached; the node may be unable to sync"
+            );
+        } else {
+            anyhow::bail!(
+                "no peers of the network could be reached from this machine, so the node would be \
+                 unable to sync; check the firewall, or pass --skip-connectivity-check to write the \
+                 configs anyway"
+            );
+        }
+    }
+    tracing::info!(
+        ?peers,
+        "reachable network peers for inclusion in generated configs"
+    );
+
+    let mut tm_config = TestnetTendermintConfig::new(
+        &options.moniker,
+        peers,
+        options.external_address.clone(),
+        Some(options.cometbft_rpc_bind),
+        Some(options.cometbft_p2p_bind),
+    )?;
+    tm_config.0 = apply_full_node_defaults(&tm_config.0, &options)?;
+
+    let node_dir = output_dir.join("node0");
+    tm_config.write_config(node_dir.clone(), &TestnetValidator::default(), &genesis)?;
+
+    let pd_env_path = node_dir.join("pd").join("pd.env");
+    let pd_home = fs::canonicalize(node_dir.join("pd"))?;
+    tracing::debug!(pd_env_path = %pd_env_path.display(), "writing pd settings");
+    fs::write(&pd_env_path, pd_env(&pd_home, &options))?;
+
+    tracing::info!(
+        chain_id = %genesis.chain_id,
+        node_dir = %node_dir.display(),
+        "joined network; start CometBFT with `--home {}`, and pd with the settings in {}",
+        node_dir.join("cometbft").display(),
+        pd_env_path.display(),
+    );
+    Ok(())
+}
+
+/// Check that the CometBFT node at `tm_url` is running the network with the
+/// given chain ID, and warn if it is still syncing, since its peers may then be
+/// a poor picture of the network.
+async fn check_node_network(tm_url: &Url, chain_id: &str) -> anyhow::Result<()> {
+    let status = reqwest::Client::new()
+        .get(tm_url.join("status")?)
+        .send()
+        .await?
+        .json::<serde_json::Value>()
+        .await?;
+    let result = status
+        .get("result")
+        .ok_or_else(|| anyhow::anyhow!("could not parse JSON from response"))?;
+
+    let network = result
+        .get("node_info")
+        .and_then(|v| v.get("network"))
+        .and_then(|v| v.as_str())
+        .ok_or_else(|| anyhow::anyhow!("could not parse node_info.network from JSON response"))?;
+    anyhow::ensure!(
+        network == chain_id,
+        "bootstrap node is running chain {network}, but served the genesis for chain {chain_id}"
+    );
+
+    if result
+        .get("sync_info")
+        .and_then(|v| v.get("catching_up"))
+        .and_then(|v| v.as_bool())
+        .unwrap_or(false)
+    {
+        tracing::warn!("bootstrap node is still catching up with the network");
+    }
+    Ok(())
+}
+
+/// Check whether a TCP connection can be opened to the peer.
+async fn peer_is_reachable(peer: &TendermintAddress) -> bool {
+    let TendermintAddress::Tcp { host, port, .. } = peer else {
+        return false;
+    };
+    let connect = tokio::net::TcpStream::connect((host.as_str(), *port));
+    matches!(
+        tokio::time::timeout(PEER_CONNECT_TIMEOUT, connect).await,
+        Ok(Ok(_))
+    )
+}
+
+/// Override the settings of the CometBFT config template which should differ
+/// for a long-running full node.
+///
+/// The overrides are applied to the TOML representation of the config, which
+/// is then parsed again, so they are validated the same way as the template.
+fn apply_full_node_defaults(
+    config: &TendermintConfig,
+    options: &JoinOptions,
+) -> anyhow::Result<TendermintConfig> {
+    let mut rendered = toml::Value::try_from(config)?;
+    let mut set = |table: &str, key: &str, value: toml::Value| -> anyhow::Result<()> {
+        rendered
+            .get_mut(table)
+            .and_then(|t| t.as_table_mut())
+            .ok_or_else(|| anyhow::anyhow!("CometBFT config has no [{table}] table"))?
+            .insert(key.to_string(), value);
+        Ok(())
+    };
+
+    set("tx_index", "indexer", options.indexer.as_str().into())?;
+    set(
+        "storage",
+        "discard_abci_responses",
+        options.discard_abci_responses.into(),
+    )?;
+    set(
+        "p2p",
+        "max_num_inbound_peers",
+        i64::try_from(options.max_inbound_peers)?.into(),
+    )?;
+    set(
+        "p2p",
+        "max_num_outbound_peers",
+        i64::try_from(options.max_outbound_peers)?.into(),
+    )?;
+    // Unlike testnet nodes, which are often run several to a machine, a full
+    // node should only accept one connection per address.
+    set("p2p", "allow_duplicate_ip", false.into())?;
+
+    TendermintConfig::parse_toml(toml::to_string(&rendered)?)
+        .context("Failed to apply full node defaults to the CometBFT config")
+}
+
+/// Render the settings `pd start` needs to run alongside the generated CometBFT
+/// config, as environment variables, suitable for use as a systemd
+/// `EnvironmentFile` or for sourcing from a shell.
+fn pd_env(pd_home: &std::path::Path, options: &JoinOptions) -> String {
+    let cometbft_rpc_bind = options.cometbft_rpc_bind;
+    // CometBFT's RPC may listen on all interfaces, but pd only needs to reach
+    // it locally.
+    let cometbft_rpc = if cometbft_rpc_bind.ip().is_unspecified() {
+        SocketAddr::new([127, 0, 0, 1].into(), cometbft_rpc_bind.port())
+    } else {
+        cometbft_rpc_bind
+    };
+    format!(
+        "PENUMBRA_PD_HOME={}\nPENUMBRA_PD_ABCI_BIND={}\nPENUMBRA_PD_COMETBFT_PROXY_URL=http://{}\nPENUMBRA_PD_RETAIN_BLOCKS={}\n",
+        pd_home.display(),
+        ABCI_BIND,
+        cometbft_rpc,
+        options.retain_blocks,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn options() -> JoinOptions {
+        JoinOptions {
+            moniker: "test".to_string(),
+            external_address: None,
+            cometbft_rpc_bind: "0.0.0.0:26657".parse().expect("valid socket address"),
+            cometbft_p2p_bind: "0.0.0.0:26656".parse().expect("valid socket address"),
+            indexer: TxIndexer::Null,
+            discard_abci_responses: true,
+            max_inbound_peers: 7,
+            max_outbound_peers: 3,
+            retain_blocks: 1000,
+            skip_connectivity_check: false,
+        }
+    }
+
+    #[test]
+    fn pd_env_points_at_local_cometbft() {
+        let env = pd_env(std::path::Path::new("/srv/penumbra/node0/pd"), &options());
+        assert_eq!(
+            env,
+            "PENUMBRA_PD_HOME=/srv/penumbra/node0/pd\n\
+             PENUMBRA_PD_ABCI_BIND=127.0.0.1:26658\n\
+             PENUMBRA_PD_COMETBFT_PROXY_URL=http://127.0.0.1:26657\n\
+             PENUMBRA_PD_RETAIN_BLOCKS=1000\n"
+        );
+    }
+
+    #[test]
+    fn full_node_defaults_are_applied() -> anyhow::Result<()> {
+        let base = TestnetTendermintConfig::new("test", Vec::new(), None, None, None)?;
+        let options = options();
+        let config = apply_full_node_defaults(&base.0, &options)?;
+
+        let rendered = toml::Value::try_from(&config)?;
+        assert_eq!(rendered["tx_index"]["indexer"].as_str(), Some("null"));
+        assert_eq!(
+            rendered["storage"]["discard_abci_responses"].as_bool(),
+            Some(true)
+        );
+        assert_eq!(
+            rendered["p2p"]["max_num_inbound_peers"].as_integer(),
+            Some(7)
+        );
+        assert_eq!(
+            rendered["p2p"]["max_num_outbound_peers"].as_integer(),
+            Some(3)
+        );
+        assert_eq!(rendered["p2p"]["allow_duplicate_ip"].as_bool(), Some(false));
+        Ok(())
+    }
+}
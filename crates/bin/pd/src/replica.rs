@@ -0,0 +1,355 @@
+//! A read-only replica mode for `pd`.
+//!
+//! A replica serves `pd`'s gRPC query services from a copy of another node's
+//! state, rather than from state it computes itself by participating in
+//! consensus.  This lets operators scale out query capacity by running any
+//! number of replicas behind a load balancer, with a single full node
+//! producing state for all of them.
+//!
+//! The replica periodically copies a snapshot directory into its own working
+//! directory and loads the copy as a fresh [`Storage`].  The snapshot
+//! directory should contain a consistent copy of a full node's `rocksdb`
+//! directory, such as one produced by `pd export` or a filesystem snapshot;
+//! copying the `rocksdb` directory of a running node is not safe.
+//!
+//! Each copy is a separate generation of the replica's state.  Files which
+//! never change once written, such as `rocksdb`'s table files, are hard-linked
+//! from the previous generation rather than copied again, so each refresh only
+//! copies what changed.  Once a new generation is loaded, new requests are
+//! routed to it, while requests which are already in flight complete against
+//! the generation they started on, whose copy is only deleted once the last of
+//! them completes.  In particular, long-lived streams (such as compact block
+//! streams) won't observe blocks added by later generations, so clients should
+//! expect to reconnect to follow the chain.
+
+use std::{
+    net::SocketAddr,
+    path::{Path, PathBuf},
+    pin::Pin,
+    sync::Arc,
+    task::{Context as TaskContext, Poll},
+    time::Duration,
+};
+
+use anyhow::Context;
+use axum::body::{Bytes, HttpBody};
+use cnidarium::Storage;
+use penumbra_app::SUBSTORE_PREFIXES;
+use tokio::sync::watch;
+use tower::ServiceExt as _;
+use url::Url;
+
+/// The extensions of files which `rocksdb` never modifies once written, and so
+/// can be shared between generations.
+const IMMUTABLE_EXTENSIONS: &[&str] = &["sst", "blob"];
+
+/// A read-only replica serving queries from a periodically refreshed snapshot.
+#[derive(Clone, Debug)]
+pub struct Replica {
+    /// The directory containing the snapshot of the state to serve.
+    pub snapshot_dir: PathBuf,
+    /// The directory the replica copies snapshots into.
+    ///
+    /// Any existing contents are deleted when the replica starts.
+    pub working_dir: PathBuf,
+    /// How often to refresh the replica's state from the snapshot directory.
+    pub refresh_interval: Duration,
+    /// The JSON-RPC address of a CometBFT node to proxy requests to.
+    pub cometbft_addr: Url,
+    /// Whether to serve expensive RPCs, such as the trade simulation service.
+    pub enable_expensive_rpc: bool,
+}
+
+/// A copy of the snapshot, loaded as a [`Storage`].
+struct Generation {
+    storage: Storage,
+    copy: Arc<SnapshotCopy>,
+}
+
+/// The directory holding a generation's copy of the snapshot, which is deleted
+/// once the generation is no longer loaded and no requests are using it.
+#[derive(Debug)]
+struct SnapshotCopy {
+    dir: PathBuf,
+}
+
+/// The router for the generation currently being served, along with its copy,
+/// which each request holds on to until its response is complete.
+type Serving = (axum::Router, Arc<SnapshotCopy>);
+
+impl Replica {
+    /// Serve queries on the given socket until an error occurs.
+    pub async fn run(self, grpc_bind: SocketAddr) -> anyhow::Result<()> {
+        // Discard any copies left behind by a previous run.
+        if self.working_dir.exists() {
+            std::fs::remove_dir_all(&self.working_dir).with_context(|| {
+                format!(
+                    "could not clear replica working directory {}",
+                    self.working_dir.display()
+                )
+            })?;
+        }
+        std::fs::create_dir_all(&self.working_dir)?;
+
+        let current = self.load(0, None).await?;
+        tracing::info!(
+            version = current.storage.latest_version(),
+            "serving snapshot"
+        );
+
+        // Each request is routed to the router for the latest generation at
+        // the time the request is received, and keeps that generation's copy
+        // until its response body has been sent or dropped.
+        let (serving_tx, serving_rx) = watch::channel(self.serving(&current).await?);
+        let make_svc = tower::make::Shared::new(tower::service_fn(
+            move |req: http::Request<axum::body::Body>| {
+                let (router, copy) = serving_rx.borrow().clone();
+                async move {
+                    let response = router.oneshot(req).await?;
+                    Ok::<_, std::convert::Infallible>(
+                        response.map(|body| axum::body::boxed(Guarded { body, _copy: copy })),
+                    )
+                }
+            },
+        ));
+        let grpc_server = tokio::task::Builder::new()
+            .name("grpc_server")
+            .spawn(axum_server::bind(grpc_bind).serve(make_svc))
+            .expect("failed to spawn grpc server");
+
+        tokio::select! {
+            x = grpc_server => x?.with_context(|| format!("grpc server on {} failed", grpc_bind)),
+            () = self.refresh(current, serving_tx) => unreachable!("refreshing never stops"),
+        }
+    }
+
+    /// Periodically load new generations of the snapshot, starting from
+    /// `current`, and send the router for each one to the gRPC server.
+    ///
+    /// A failed refresh leaves the current generation in place, and is retried
+    /// at the next interval.
+    async fn refresh(self, mut current: Generation, serving_tx: watch::Sender<Serving>) {
+        let mut generation = 0u64;
+        let mut interval = tokio::time::interval(self.refresh_interval);
+        interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+        // The first tick completes immediately, and we've just loaded the snapshot.
+        interval.tick().await;
+
+        loop {
+            interval.tick().await;
+
+            generation += 1;
+            match self.refresh_once(&current, generation, &serving_tx).await {
+                Ok(Some(next)) => {
+                    // The previous generation's copy is deleted once the
+                    // requests still in flight against it complete.
+                    current = next;
+                }
+                Ok(None) => {}
+                Err(e) => tracing::warn!(?e, generation, "failed to refresh snapshot"),
+            }
+        }
+    }
+
+    /// Load the given generation of the snapshot, and serve it if it is newer
+    /// than `current`, returning it if it is now being served.
+    async fn refresh_once(
+        &self,
+        current: &Generation,
+        generation: u64,
+        serving_tx: &watch::Sender<Serving>,
+    ) -> anyhow::Result<Option<Generation>> {
+        let next = self.load(generation, Some(&current.copy.dir)).await?;
+
+        let version = next.storage.latest_version();
+        if version == current.storage.latest_version() {
+            tracing::debug!(version, generation, "snapshot unchanged");
+            // Release the storage before its copy is deleted.
+            let Generation { storage, copy } = next;
+            storage.release().await;
+            drop(copy);
+            return Ok(None);
+        }
+
+        serving_tx.send_replace(self.serving(&next).await?);
+        tracing::info!(version, generation, "serving refreshed snapshot");
+        Ok(Some(next))
+    }
+
+    /// SnapshotCopy the snapshot directory into a fresh directory for the given
+    /// generation, sharing unchanged files with the `previous` copy, and load
+    /// it.
+    async fn load(&self, generation: u64, previous: Option<&Path>) -> anyhow::Result<Generation> {
+        let copy = Arc::new(SnapshotCopy {
+            dir: self.working_dir.join(generation.to_string()),
+        });
+        let snapshot_dir = self.snapshot_dir.clone();
+        let copy_to = copy.dir.clone();
+        let previous = previous.map(Path::to_owned);
+
+        tokio::task::spawn_blocking(move || {
+            copy_snapshot(&snapshot_dir, &copy_to, previous.as_deref())
+        })
+        .await?
+        .with_context(|| {
+            format!(
+                "could not copy snapshot from {}",
+                self.snapshot_dir.display()
+            )
+        })?;
+
+        let storage = Storage::load(copy.dir.clone(), SUBSTORE_PREFIXES.to_vec())
+            .await
+            .context("unable to load snapshot")?;
+
+        Ok(Generation { storage, copy })
+    }
+
+    async fn serving(&self, generation: &Generation) -> anyhow::Result<Serving> {
+        let router = crate::rpc::router(
+            &generation.storage,
+            self.cometbft_addr.clone(),
+            self.enable_expensive_rpc,
+        )
+        .await?;
+        Ok((router, generation.copy.clone()))
+    }
+}
+
+impl Drop for SnapshotCopy {
+    fn drop(&mut self) {
+        if let Err(e) = std::fs::remove_dir_all(&self.dir) {
+            tracing::warn!(?e, dir = %self.dir.display(), "failed to remove snapshot copy");
+        }
+    }
+}
+
+/// A response body which keeps the copy it is being served from until it is
+/// dropped.
+struct Guarded<B> {
+    // Fields are dropped in order, so the body releases the copy's state
+    // before the copy itself can be deleted.
+    body: B,
+    _copy: Arc<SnapshotCopy>,
+}
+
+impl<B: HttpBody<Data = Bytes> + Unpin> HttpBody for Guarded<B> {
+    type Data = Bytes;
+    type Error = B::Error;
+
+    fn poll_data(
+        mut self: Pin<&mut Self>,
+        cx: &mut TaskContext<'_>,
+    ) -> Poll<Option<Result<Self::Data, Self::Error>>> {
+        Pin::new(&mut self.body).poll_data(cx)
+    }
+
+    fn poll_trailers(
+        mut self: Pin<&mut Self>,
+        cx: &mut TaskContext<'_>,
+    ) -> Poll<Result<Option<http::HeaderMap>, Self::Error>> {
+        Pin::new(&mut self.body).poll_trailers(cx)
+    }
+
+    fn is_end_stream(&self) -> bool {
+        self.body.is_end_stream()
+    }
+}
+
+/// SnapshotCopy the directory `from` into `to`, hard-linking immutable files from the
+/// `previous` copy, if they are present there with the same size, rather than
+/// copying them again.
+fn copy_snapshot(from: &Path, to: &Path, previous: Option<&Path>) -> anyhow::Result<()> {
+    std::fs::create_dir_all(to)?;
+    for entry in std::fs::read_dir(from)? {
+        let entry = entry?;
+        let source = entry.path();
+        let target = to.join(entry.file_name());
+        let previous = previous.map(|previous| previous.join(entry.file_name()));
+
+        let metadata = entry.metadata()?;
+        if metadata.is_dir() {
+            copy_snapshot(&source, &target, previous.as_deref())?;
+            continue;
+        }
+
+        let immutable = source
+            .extension()
+            .and_then(|extension| extension.to_str())
+            .map_or(false, |extension| IMMUTABLE_EXTENSIONS.contains(&extension));
+        if let Some(previous) = previous.filter(|_| immutable) {
+            let unchanged = std::fs::metadata(&previous)
+                .map_or(false, |previous| previous.len() == metadata.len());
+            if unchanged && std::fs::hard_link(&previous, &target).is_ok() {
+                continue;
+            }
+        }
+        std::fs::copy(&source, &target)?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn refreshes_only_copy_changed_files() -> anyhow::Result<()> {
+        use std::os::unix::fs::MetadataExt as _;
+
+        let dir = tempfile::tempdir()?;
+        let snapshot = dir.path().join("snapshot");
+        std::fs::create_dir_all(&snapshot)?;
+        std::fs::write(snapshot.join("000001.sst"), b"table")?;
+        std::fs::write(snapshot.join("MANIFEST-000002"), b"manifest")?;
+
+        let first = dir.path().join("0");
+        copy_snapshot(&snapshot, &first, None)?;
+
+        std::fs::write(snapshot.join("MANIFEST-000002"), b"manifest, updated")?;
+        std::fs::write(snapshot.join("000003.sst"), b"another table")?;
+        let second = dir.path().join("1");
+        copy_snapshot(&snapshot, &second, Some(&first))?;
+
+        // The unchanged table is shared with the previous copy.
+        assert_eq!(
+            std::fs::metadata(first.join("000001.sst"))?.ino(),
+            std::fs::metadata(second.join("000001.sst"))?.ino()
+        );
+        // Everything else is copied afresh.
+        assert_eq!(
+            std::fs::read(second.join("MANIFEST-000002"))?,
+            b"manifest, updated"
+        );
+        assert_eq!(std::fs::read(second.join("000003.sst"))?, b"another table");
+        assert_eq!(std::fs::read(first.join("MANIFEST-000002"))?, b"manifest");
+
+        // Deleting the previous copy leaves the shared table in place.
+        std::fs::remove_dir_all(&first)?;
+        assert_eq!(std::fs::read(second.join("000001.sst"))?, b"table");
+        Ok(())
+    }
+
+    #[test]
+    fn copies_are_deleted_once_no_longer_in_use() -> anyhow::Result<()> {
+        let dir = tempfile::tempdir()?;
+        let copy = Arc::new(SnapshotCopy {
+            dir: dir.path().join("0"),
+        });
+        std::fs::create_dir_all(&copy.dir)?;
+
+        // A response still in flight keeps the copy after the generation is
+        // replaced.
+        let in_flight = Guarded {
+            body: axum::body::Body::empty(),
+            _copy: copy.clone(),
+        };
+        let path = copy.dir.clone();
+        drop(copy);
+        assert!(path.exists());
+
+        drop(in_flight);
+        assert!(!path.exists());
+        Ok(())
+    }
+}
@@ -209,6 +209,7 @@ impl TestnetConfig {
                         .unwrap_or(default_app_params.stake_params.unbonding_epochs),
                     ..Default::default()
                 },
+                validator_snapshot: None,
             },
             governance_content: GovernanceContent {
                 governance_params: gov_params,
@@ -555,11 +556,13 @@ impl TryFrom<&TestnetValidator> for Validator {
                             address: Address::from_str(&fs.address)
                                 .context("invalid funding stream address in validators.json")?,
                             rate_bps: fs.rate_bps,
+                            auto_compound: false,
                         })
                     })
                     .collect::<Result<Vec<FundingStream>, anyhow::Error>>()?,
             )
             .context("unable to construct funding streams from validators.json")?,
+            funding_streams_effective_epoch: None,
             sequence_number: tv.sequence_number,
         })
     }
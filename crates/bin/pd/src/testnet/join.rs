@@ -1,10 +1,12 @@
 //! Logic for onboarding a new `pd` node onto an existing testnet.
 //! Handles generation of config files for `pd` and `tendermint`.
 use anyhow::Context;
+use penumbra_genesis::AppState;
 use rand::seq::SliceRandom;
 use rand_core::OsRng;
 use std::net::{IpAddr, SocketAddr};
 use std::path::PathBuf;
+use tendermint::Genesis;
 use tendermint_config::net::Address as TendermintAddress;
 use url::Url;
 
@@ -24,23 +26,7 @@ pub async fn testnet_join(
 ) -> anyhow::Result<()> {
     let mut node_dir = output_dir;
     node_dir.push("node0");
-    let genesis_url = node.join("genesis")?;
-    tracing::info!(%genesis_url, "fetching genesis");
-    // We need to download the genesis data and the node ID from the remote node.
-    // TODO: replace with TendermintProxyServiceClient
-    let client = reqwest::Client::new();
-    let genesis_json = client
-        .get(genesis_url)
-        .send()
-        .await?
-        .json::<serde_json::Value>()
-        .await?
-        .get_mut("result")
-        .and_then(|v| v.get_mut("genesis"))
-        .ok_or_else(|| anyhow::anyhow!("could not parse JSON from response"))?
-        .take();
-    let genesis = serde_json::value::from_value(genesis_json)?;
-    tracing::info!("fetched genesis");
+    let genesis = fetch_genesis(&node).await?;
 
     // Look up more peers from the target node, so that generated tendermint config
     // contains multiple addresses, making peering easier.
@@ -69,6 +55,29 @@ pub async fn testnet_join(
     Ok(())
 }
 
+/// Query the Tendermint node's RPC endpoint at `tm_url` and return the genesis
+/// of the network it belongs to.
+pub async fn fetch_genesis(tm_url: &Url) -> anyhow::Result<Genesis<AppState>> {
+    let genesis_url = tm_url.join("genesis")?;
+    tracing::info!(%genesis_url, "fetching genesis");
+    // We need to download the genesis data and the node ID from the remote node.
+    // TODO: replace with TendermintProxyServiceClient
+    let client = reqwest::Client::new();
+    let genesis_json = client
+        .get(genesis_url)
+        .send()
+        .await?
+        .json::<serde_json::Value>()
+        .await?
+        .get_mut("result")
+        .and_then(|v| v.get_mut("genesis"))
+        .ok_or_else(|| anyhow::anyhow!("could not parse JSON from response"))?
+        .take();
+    let genesis = serde_json::value::from_value(genesis_json)?;
+    tracing::info!("fetched genesis");
+    Ok(genesis)
+}
+
 /// Query the Tendermint node's RPC endpoint at `tm_url` and return the listener
 /// address for the P2P endpoint. Returns an [Option<TendermintAddress>] because
 /// it's possible that no p2p listener is configured.
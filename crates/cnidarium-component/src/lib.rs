@@ -17,7 +17,9 @@
 //!   any locally-defined actions, and any other code touching the chain state
 //!   inside;
 //! - a `crate::state_key` module defining the component's state keys (which are
-//!   a public API, like the rest of the chain state);
+//!   a public API, like the rest of the chain state), and a `PREFIXES` constant
+//!   listing their prefixes, for the application's
+//!   [`state_key_registry!`](crate::state_key_registry);
 //! - a `crate::event` module defining any events emitted by the component;
 //!
 //! The structure of the feature-gated `component` submodule allows reusing data
@@ -42,6 +44,8 @@
 
 mod action_handler;
 mod component;
+pub mod registry;
 
 pub use action_handler::ActionHandler;
 pub use component::Component;
+pub use registry::{ComponentPrefixes, PrefixCollision};
@@ -0,0 +1,212 @@
+//! A registry of the state key prefixes used by each component.
+//!
+//! Every component keeps its state under a few key prefixes, defined in its
+//! `state_key` module.  Nothing stops two components from choosing
+//! overlapping prefixes, in which case one component can silently read or
+//! overwrite the other's state, or pick up the other's keys when scanning a
+//! prefix.  The [`state_key_registry!`](crate::state_key_registry) macro
+//! declares the prefixes of every component of an application in one place,
+//! and fails to compile if the prefixes of two different components overlap.
+
+/// The state key prefixes used by a single component.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ComponentPrefixes {
+    /// The name of the component.
+    pub component: &'static str,
+    /// The prefixes of all of the component's state keys.
+    pub prefixes: &'static [&'static str],
+}
+
+/// A pair of state key prefixes used by different components, one of which is
+/// a prefix of the other, so that some keys could belong to either component.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PrefixCollision {
+    /// The component using the shorter prefix, and the prefix.
+    pub shorter: (&'static str, &'static str),
+    /// The component using the longer prefix, and the prefix.
+    pub longer: (&'static str, &'static str),
+}
+
+/// Returns whether `prefix` is a prefix of `key`.
+const fn is_prefix_of(prefix: &str, key: &str) -> bool {
+    let (prefix, key) = (prefix.as_bytes(), key.as_bytes());
+    if prefix.len() > key.len() {
+        return false;
+    }
+    let mut i = 0;
+    while i < prefix.len() {
+        if prefix[i] != key[i] {
+            return false;
+        }
+        i += 1;
+    }
+    true
+}
+
+/// Returns whether any two prefixes used by different components in the
+/// registry overlap.
+///
+/// This is a `const fn`, so that the check can be performed at compile time;
+/// use [`collisions`] to find out which prefixes overlap.
+pub const fn has_collision(registry: &[ComponentPrefixes]) -> bool {
+    let mut a = 0;
+    while a < registry.len() {
+        let mut b = a + 1;
+        while b < registry.len() {
+            let mut i = 0;
+            while i < registry[a].prefixes.len() {
+                let mut j = 0;
+                while j < registry[b].prefixes.len() {
+                    let (x, y) = (registry[a].prefixes[i], registry[b].prefixes[j]);
+                    if is_prefix_of(x, y) || is_prefix_of(y, x) {
+                        return true;
+                    }
+                    j += 1;
+                }
+                i += 1;
+            }
+            b += 1;
+        }
+        a += 1;
+    }
+    false
+}
+
+/// List all pairs of prefixes used by different components in the registry
+/// which overlap.
+pub fn collisions(registry: &[ComponentPrefixes]) -> Vec<PrefixCollision> {
+    let mut collisions = Vec::new();
+    for (a, first) in registry.iter().enumerate() {
+        for second in &registry[a + 1..] {
+            for &x in first.prefixes {
+                for &y in second.prefixes {
+                    let (x, y) = ((first.component, x), (second.component, y));
+                    if is_prefix_of(x.1, y.1) {
+                        collisions.push(PrefixCollision {
+                            shorter: x,
+                            longer: y,
+                        });
+                    } else if is_prefix_of(y.1, x.1) {
+                        collisions.push(PrefixCollision {
+                            shorter: y,
+                            longer: x,
+                        });
+                    }
+                }
+            }
+        }
+    }
+    collisions
+}
+
+/// Declare a registry of the state key prefixes used by each component of an
+/// application, checking at compile time that no two components' prefixes
+/// overlap.
+///
+/// The registry is declared as a constant slice of [`ComponentPrefixes`]:
+///
+/// ```
+/// cnidarium_component::state_key_registry! {
+///     /// The prefixes used by each component.
+///     pub const PREFIXES = {
+///         "shielded_pool" => &["shielded_pool/"],
+///         "dex" => &["dex/"],
+///     };
+/// }
+///
+/// assert_eq!(PREFIXES[1].component, "dex");
+/// ```
+///
+/// Registering overlapping prefixes for different components fails to compile:
+///
+/// ```compile_fail
+/// cnidarium_component::state_key_registry! {
+///     const PREFIXES = {
+///         "staking" => &["staking/"],
+///         "stake" => &["stak"],
+///     };
+/// }
+/// ```
+#[macro_export]
+macro_rules! state_key_registry {
+    (
+        $(#[$meta:meta])*
+        $vis:vis const $name:ident = {
+            $($component:literal => $prefixes:expr),* $(,)?
+        };
+    ) => {
+        $(#[$meta])*
+        $vis const $name: &[$crate::registry::ComponentPrefixes] = &[
+            $($crate::registry::ComponentPrefixes {
+                component: $component,
+                prefixes: $prefixes,
+            }),*
+        ];
+
+        const _: () = assert!(
+            !$crate::registry::has_collision($name),
+            concat!(
+                "state key prefixes of different components collide in `",
+                stringify!($name),
+                "`"
+            ),
+        );
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const REGISTRY: &[ComponentPrefixes] = &[
+        ComponentPrefixes {
+            component: "ibc",
+            prefixes: &["ibc/", "ibc_client_counter"],
+        },
+        ComponentPrefixes {
+            component: "ibc-data",
+            prefixes: &["ibc-data/"],
+        },
+        ComponentPrefixes {
+            component: "staking",
+            prefixes: &["staking/", "ibc"],
+        },
+    ];
+
+    #[test]
+    fn overlapping_prefixes_collide() {
+        assert!(has_collision(REGISTRY));
+        assert_eq!(
+            collisions(REGISTRY),
+            vec![
+                PrefixCollision {
+                    shorter: ("staking", "ibc"),
+                    longer: ("ibc", "ibc/"),
+                },
+                PrefixCollision {
+                    shorter: ("staking", "ibc"),
+                    longer: ("ibc", "ibc_client_counter"),
+                },
+                PrefixCollision {
+                    shorter: ("staking", "ibc"),
+                    longer: ("ibc-data", "ibc-data/"),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn distinct_prefixes_do_not_collide() {
+        // Prefixes of the same component may overlap.
+        let registry = &[
+            REGISTRY[0],
+            REGISTRY[1],
+            ComponentPrefixes {
+                component: "staking",
+                prefixes: &["staking/", "staking/validators/"],
+            },
+        ];
+        assert!(!has_collision(registry));
+        assert!(collisions(registry).is_empty());
+    }
+}
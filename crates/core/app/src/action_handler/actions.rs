@@ -1,4 +1,4 @@
-use std::sync::Arc;
+use std::{collections::BTreeSet, sync::Arc};
 
 use anyhow::Result;
 use async_trait::async_trait;
@@ -10,7 +10,7 @@ use penumbra_txhash::TransactionContext;
 
 mod submit;
 
-use crate::PenumbraHost;
+use crate::{PenumbraCallbacks, PenumbraHost};
 
 use super::ActionHandler;
 use cnidarium_component::ActionHandler as _;
@@ -29,11 +29,21 @@ impl ActionHandler for Action {
             Action::Delegate(action) => action.check_stateless(()).await,
             Action::Undelegate(action) => action.check_stateless(()).await,
             Action::UndelegateClaim(action) => action.check_stateless(()).await,
+            Action::Redelegate(action) => action.check_stateless(()).await,
             Action::ValidatorDefinition(action) => action.check_stateless(()).await,
             Action::ValidatorVote(action) => action.check_stateless(()).await,
+            Action::BallotKeyDeal(action) => action.check_stateless(()).await,
+            Action::BallotKeyReveal(action) => action.check_stateless(()).await,
+            Action::BallotKeyComplaint(action) => action.check_stateless(()).await,
             Action::PositionClose(action) => action.check_stateless(()).await,
             Action::PositionOpen(action) => action.check_stateless(()).await,
+            Action::PositionOpenBatch(action) => action.check_stateless(()).await,
             Action::PositionWithdraw(action) => action.check_stateless(()).await,
+            Action::PositionUpdateBatch(action) => action.check_stateless(()).await,
+            Action::PositionRewrite(action) => action.check_stateless(()).await,
+            Action::ActionDutchAuctionSchedule(action) => action.check_stateless(()).await,
+            Action::ActionDutchAuctionEnd(action) => action.check_stateless(()).await,
+            Action::ActionDutchAuctionWithdraw(action) => action.check_stateless(()).await,
             Action::ProposalSubmit(action) => action.check_stateless(()).await,
             Action::ProposalWithdraw(action) => action.check_stateless(()).await,
             Action::ProposalDepositClaim(action) => action.check_stateless(()).await,
@@ -42,7 +52,7 @@ impl ActionHandler for Action {
             Action::IbcRelay(action) => {
                 action
                     .clone()
-                    .with_handler::<Ics20Transfer, PenumbraHost>()
+                    .with_handler::<Ics20Transfer<PenumbraCallbacks>, PenumbraHost>()
                     .check_stateless(())
                     .await
             }
@@ -58,18 +68,51 @@ impl ActionHandler for Action {
             Action::Delegate(action) => action.check_stateful(state).await,
             Action::Undelegate(action) => action.check_stateful(state).await,
             Action::UndelegateClaim(action) => action.check_stateful(state).await,
+            Action::Redelegate(action) => action.check_stateful(state).await,
             Action::ValidatorDefinition(action) => action.check_stateful(state).await,
             Action::DelegatorVote(action) => action.check_stateful(state).await,
             Action::ValidatorVote(action) => action.check_stateful(state).await,
+            Action::BallotKeyDeal(action) => action.check_stateful(state).await,
+            Action::BallotKeyReveal(action) => action.check_stateful(state).await,
+            Action::BallotKeyComplaint(action) => action.check_stateful(state).await,
             Action::PositionClose(action) => action.check_stateful(state).await,
             Action::PositionOpen(action) => action.check_stateful(state).await,
+            Action::PositionOpenBatch(action) => {
+                // The batch's combined reserves are checked once per asset,
+                // rather than once per position.
+                let provisioned = action
+                    .positions
+                    .iter()
+                    .flat_map(|position| {
+                        let pair = position.phi.pair;
+                        [
+                            (pair.asset_1(), position.reserves.r1),
+                            (pair.asset_2(), position.reserves.r2),
+                        ]
+                    })
+                    .filter(|(_, reserves)| *reserves > Amount::zero())
+                    .map(|(asset_id, _)| asset_id)
+                    .collect::<BTreeSet<_>>();
+                for asset_id in &provisioned {
+                    state.check_delegation_token_usable(asset_id).await?;
+                }
+                action.check_stateful(state).await
+            }
             Action::PositionWithdraw(action) => action.check_stateful(state).await,
+            Action::PositionUpdateBatch(action) => action.check_stateful(state).await,
+            Action::PositionRewrite(action) => action.check_stateful(state).await,
+            Action::ActionDutchAuctionSchedule(action) => action.check_stateful(state).await,
+            Action::ActionDutchAuctionEnd(action) => action.check_stateful(state).await,
+            Action::ActionDutchAuctionWithdraw(action) => action.check_stateful(state).await,
             Action::ProposalSubmit(action) => action.check_stateful(state).await,
             Action::ProposalWithdraw(action) => action.check_stateful(state).await,
             Action::ProposalDepositClaim(action) => action.check_stateful(state).await,
             Action::Swap(action) => action.check_stateful(state).await,
             Action::SwapClaim(action) => action.check_stateful(state).await,
-            Action::Spend(action) => action.check_stateful(state).await,
+            // The only stateful check of a spend is that its nullifier is
+            // unspent, which is done for all the spends of the transaction at
+            // once by its own stateful check.
+            Action::Spend(_) => Ok(()),
             Action::Output(action) => action.check_stateful(state).await,
             Action::IbcRelay(action) => {
                 if !state.get_ibc_params().await?.ibc_enabled {
@@ -78,7 +121,7 @@ impl ActionHandler for Action {
 
                 action
                     .clone()
-                    .with_handler::<Ics20Transfer, PenumbraHost>()
+                    .with_handler::<Ics20Transfer<PenumbraCallbacks>, PenumbraHost>()
                     .check_stateful(state)
                     .await
             }
@@ -94,12 +137,22 @@ impl ActionHandler for Action {
             Action::Delegate(action) => action.execute(state).await,
             Action::Undelegate(action) => action.execute(state).await,
             Action::UndelegateClaim(action) => action.execute(state).await,
+            Action::Redelegate(action) => action.execute(state).await,
             Action::ValidatorDefinition(action) => action.execute(state).await,
             Action::DelegatorVote(action) => action.execute(state).await,
             Action::ValidatorVote(action) => action.execute(state).await,
+            Action::BallotKeyDeal(action) => action.execute(state).await,
+            Action::BallotKeyReveal(action) => action.execute(state).await,
+            Action::BallotKeyComplaint(action) => action.execute(state).await,
             Action::PositionClose(action) => action.execute(state).await,
             Action::PositionOpen(action) => action.execute(state).await,
+            Action::PositionOpenBatch(action) => action.execute(state).await,
             Action::PositionWithdraw(action) => action.execute(state).await,
+            Action::PositionUpdateBatch(action) => action.execute(state).await,
+            Action::PositionRewrite(action) => action.execute(state).await,
+            Action::ActionDutchAuctionSchedule(action) => action.execute(state).await,
+            Action::ActionDutchAuctionEnd(action) => action.execute(state).await,
+            Action::ActionDutchAuctionWithdraw(action) => action.execute(state).await,
             Action::ProposalSubmit(action) => action.execute(state).await,
             Action::ProposalWithdraw(action) => action.execute(state).await,
             Action::ProposalDepositClaim(action) => action.execute(state).await,
@@ -110,7 +163,7 @@ impl ActionHandler for Action {
             Action::IbcRelay(action) => {
                 action
                     .clone()
-                    .with_handler::<Ics20Transfer, PenumbraHost>()
+                    .with_handler::<Ics20Transfer<PenumbraCallbacks>, PenumbraHost>()
                     .execute(state)
                     .await
             }
@@ -10,7 +10,7 @@ use ibc_types::core::client::ClientId;
 use once_cell::sync::Lazy;
 
 use cnidarium::{StateDelta, StateRead, StateWrite};
-use penumbra_asset::STAKING_TOKEN_DENOM;
+use penumbra_asset::Value;
 use penumbra_community_pool::component::StateReadExt as _;
 use penumbra_governance::{
     component::{StateReadExt as _, StateWriteExt as _},
@@ -51,11 +51,13 @@ impl ActionHandler for ProposalSubmit {
         let ProposalSubmit {
             proposal,
             deposit_amount: _, // we don't check the deposit amount because it's defined by state
+            deposit_asset_id: _, // nor the deposit asset, which is checked against state
         } = self;
         let Proposal {
             id: _, // we can't check the ID statelessly because it's defined by state
             title,
             description,
+            sealed_ballots,
             payload,
         } = proposal;
 
@@ -69,6 +71,14 @@ impl ActionHandler for ProposalSubmit {
             );
         }
 
+        // Emergency-tier proposals can pass as soon as enough validators vote for them, which
+        // requires their votes to be public.
+        if *sealed_ballots
+            && (payload.is_emergency() || payload.is_ibc_freeze() || payload.is_trading_pause())
+        {
+            anyhow::bail!("emergency-tier proposals can't have sealed ballots");
+        }
+
         use penumbra_governance::ProposalPayload::*;
         match payload {
             Signaling { commit: _ } => { /* all signaling proposals are valid */ }
@@ -106,7 +116,7 @@ impl ActionHandler for ProposalSubmit {
                                 "invalid action in Community Pool spend proposal (would require proving)"
                             )
                         }
-                        Delegate(_) | Undelegate(_) => {
+                        Delegate(_) | Undelegate(_) | Redelegate(_) => {
                             // Delegation and undelegation is disallowed due to Undelegateclaim requiring proving.
                             anyhow::bail!(
                                 "invalid action in Community Pool spend proposal (can't claim outputs of undelegation)"
@@ -120,9 +130,18 @@ impl ActionHandler for ProposalSubmit {
                         ValidatorDefinition(_)
                         | IbcAction(_)
                         | ValidatorVote(_)
+                        | BallotKeyDeal(_)
+                        | BallotKeyReveal(_)
+                        | BallotKeyComplaint(_)
                         | PositionOpen(_)
+                        | PositionOpenBatch(_)
                         | PositionClose(_)
                         | PositionWithdraw(_)
+                        | PositionUpdateBatch(_)
+                        | PositionRewrite(_)
+                        | ActionDutchAuctionSchedule(_)
+                        | ActionDutchAuctionEnd(_)
+                        | ActionDutchAuctionWithdraw(_)
                         | CommunityPoolSpend(_)
                         | CommunityPoolOutput(_)
                         | Ics20Withdrawal(_)
@@ -133,7 +152,7 @@ impl ActionHandler for ProposalSubmit {
                     }
                 }
             }
-            UpgradePlan { .. } => {}
+            UpgradePlan(upgrade_plan) => upgrade_plan.check_stateless()?,
             FreezeIbcClient { client_id } => {
                 let _ = &ClientId::from_str(client_id)
                     .context("can't decode client id from IBC proposal")?;
@@ -142,6 +161,25 @@ impl ActionHandler for ProposalSubmit {
                 let _ = &ClientId::from_str(client_id)
                     .context("can't decode client id from IBC proposal")?;
             }
+            RecurringBudget {
+                budget_line,
+                cancel,
+            } => {
+                anyhow::ensure!(
+                    budget_line.is_some() || !cancel.is_empty(),
+                    "recurring budget proposal must establish or cancel a budget line"
+                );
+                if let Some(budget_line) = budget_line {
+                    budget_line.check_stateless()?;
+                }
+                let mut seen = std::collections::BTreeSet::new();
+                for id in cancel {
+                    anyhow::ensure!(
+                        seen.insert(*id),
+                        "budget line {id} is cancelled more than once"
+                    );
+                }
+            }
         }
 
         Ok(())
@@ -150,18 +188,29 @@ impl ActionHandler for ProposalSubmit {
     async fn check_stateful<S: StateRead + 'static>(&self, state: Arc<S>) -> Result<()> {
         let ProposalSubmit {
             deposit_amount,
+            deposit_asset_id,
             proposal, // statelessly verified
         } = self;
 
-        // Check that the deposit amount agrees with the parameters
+        // Check that the deposit agrees with the parameters
+        state
+            .check_proposal_deposit(Value {
+                amount: *deposit_amount,
+                asset_id: *deposit_asset_id,
+            })
+            .await?;
         let governance_parameters = state.get_governance_params().await?;
-        if *deposit_amount != governance_parameters.proposal_deposit_amount {
+
+        // Check that the ballot key of a proposal with sealed ballots can be dealt before voting
+        // on it ends
+        if proposal.sealed_ballots
+            && governance_parameters.sealed_ballot_setup_blocks
+                >= governance_parameters.proposal_voting_blocks
+        {
             anyhow::bail!(
-                "submitted proposal deposit of {}{} does not match required proposal deposit of {}{}",
-                deposit_amount,
-                *STAKING_TOKEN_DENOM,
-                governance_parameters.proposal_deposit_amount,
-                *STAKING_TOKEN_DENOM,
+                "sealed ballot setup period of {} blocks does not fit within the voting period of {} blocks",
+                governance_parameters.sealed_ballot_setup_blocks,
+                governance_parameters.proposal_voting_blocks,
             );
         }
 
@@ -209,8 +258,15 @@ impl ActionHandler for ProposalSubmit {
                     "submitted Community Pool spend transaction failed to execute in current chain state",
                 )?;
             }
-            ProposalPayload::UpgradePlan { .. } => {
-                // TODO(erwan): no stateful checks for upgrade plan.
+            ProposalPayload::UpgradePlan(upgrade_plan) => {
+                // The chain can't halt for an upgrade at a height it has already passed.
+                let current_height = state.get_block_height().await?;
+                anyhow::ensure!(
+                    upgrade_plan.height > current_height,
+                    "upgrade plan height {} is not after the current height {}",
+                    upgrade_plan.height,
+                    current_height,
+                );
             }
             ProposalPayload::FreezeIbcClient { client_id } => {
                 // Check that the client ID is valid and that there is a corresponding
@@ -228,6 +284,22 @@ impl ActionHandler for ProposalSubmit {
                     .map_err(|e| tonic::Status::aborted(format!("invalid client id: {e}")))?;
                 let _ = state.get_client_state(client_id).await?;
             }
+            ProposalPayload::RecurringBudget { cancel, .. } => {
+                // Budget lines are paid with the spend authority of the Community Pool, so they
+                // are subject to the same switch as Community Pool spend proposals.
+                let community_pool_parameters = state.get_community_pool_params().await?;
+                anyhow::ensure!(
+                    community_pool_parameters.community_pool_spend_proposals_enabled,
+                    "Community Pool spend proposals are not enabled",
+                );
+
+                for id in cancel {
+                    anyhow::ensure!(
+                        state.budget_line(*id).await?.is_some(),
+                        "budget line {id} to cancel is not active"
+                    );
+                }
+            }
         }
 
         Ok(())
@@ -237,6 +309,7 @@ impl ActionHandler for ProposalSubmit {
         let ProposalSubmit {
             proposal,
             deposit_amount,
+            deposit_asset_id,
         } = self;
 
         // If the proposal is a Community Pool spend proposal, we've already built it, but we need to build it
@@ -262,6 +335,7 @@ impl ActionHandler for ProposalSubmit {
 
         // Set the deposit amount for the proposal
         state.put_deposit_amount(proposal_id, *deposit_amount);
+        state.put_deposit_asset_id(proposal_id, *deposit_asset_id);
 
         // Register the denom for the voting proposal NFT
         state
@@ -370,9 +444,169 @@ async fn build_community_pool_transaction(
 
 #[cfg(test)]
 mod test {
+    use cnidarium::TempStorage;
+    use cnidarium_component::ActionHandler as _;
+    use penumbra_asset::{asset, STAKING_TOKEN_ASSET_ID};
+    use penumbra_dex::{DirectedTradingPair, SwapExecution};
+    use penumbra_governance::{
+        component::record_deposit_rates, params::GovernanceParameters, proposal_state::Outcome,
+        ProposalDepositClaim,
+    };
+    use penumbra_proto::StateWriteProto as _;
+    use penumbra_sct::component::clock::EpochManager as _;
+
+    use super::*;
+
     /// Ensure that the Community Pool full viewing key can be constructed and does not panic when referenced.
     #[test]
     fn community_pool_fvk_can_be_constructed() {
         let _ = *super::COMMUNITY_POOL_FULL_VIEWING_KEY;
     }
+
+    /// Record a batch swap selling `input` of the asset for `output` of the staking token at the
+    /// given height, along with the block height.
+    async fn batch_swap(
+        storage: &TempStorage,
+        height: u64,
+        asset_id: asset::Id,
+        input: u64,
+        output: u64,
+    ) -> Result<()> {
+        let mut state = StateDelta::new(storage.latest_snapshot());
+        state.put_block_height(height);
+        state.put(
+            penumbra_dex::state_key::swap_execution(
+                height,
+                DirectedTradingPair::new(asset_id, *STAKING_TOKEN_ASSET_ID),
+            ),
+            SwapExecution {
+                traces: Vec::new(),
+                input: asset_id.value(input.into()),
+                output: STAKING_TOKEN_ASSET_ID.value(output.into()),
+            },
+        );
+        record_deposit_rates(&mut state).await?;
+        storage.commit(state).await?;
+        Ok(())
+    }
+
+    async fn set_block_height(storage: &TempStorage, height: u64) -> Result<()> {
+        let mut state = StateDelta::new(storage.latest_snapshot());
+        state.put_block_height(height);
+        storage.commit(state).await?;
+        Ok(())
+    }
+
+    fn submit(asset_id: asset::Id, amount: u64) -> ProposalSubmit {
+        ProposalSubmit {
+            proposal: Proposal {
+                id: 0,
+                title: "paid in gm".to_string(),
+                description: String::new(),
+                sealed_ballots: false,
+                payload: ProposalPayload::Signaling { commit: None },
+            },
+            deposit_amount: amount.into(),
+            deposit_asset_id: asset_id,
+        }
+    }
+
+    #[tokio::test]
+    async fn deposits_in_accepted_assets_are_valued_at_the_time_weighted_rate() -> Result<()> {
+        let gm = asset::Cache::with_known_assets()
+            .get_unit("gm")
+            .expect("gm is a known asset")
+            .id();
+        let storage = TempStorage::new().await?;
+        let mut state = StateDelta::new(storage.latest_snapshot());
+        state.put_governance_params(GovernanceParameters {
+            proposal_deposit_amount: 1000u64.into(),
+            accepted_deposit_assets: vec![gm],
+            deposit_rate_window_blocks: 10,
+            deposit_rate_max_age_blocks: 5,
+            deposit_rate_min_volume: 100u64.into(),
+            ..Default::default()
+        });
+        storage.commit(state).await?;
+
+        // Swaps at a rate of 2, then 3, and a swap at a rate of 50 which traded too little to
+        // count.
+        batch_swap(&storage, 1, gm, 100, 200).await?;
+        batch_swap(&storage, 6, gm, 50, 150).await?;
+        assert!(submit(gm, 1000)
+            .check_stateful(Arc::new(storage.latest_snapshot()))
+            .await
+            .is_err());
+        batch_swap(&storage, 9, gm, 1, 50).await?;
+
+        // Over the window ending at height 11, the rate was 2 for 5 blocks and 3 for 5 blocks.
+        set_block_height(&storage, 11).await?;
+        assert!(submit(gm, 399)
+            .check_stateful(Arc::new(storage.latest_snapshot()))
+            .await
+            .is_err());
+        let action = submit(gm, 400);
+        action.check_stateless(()).await?;
+        action
+            .check_stateful(Arc::new(storage.latest_snapshot()))
+            .await?;
+        // Assets which aren't accepted can't be used at all, and the staking token still has to
+        // match the deposit amount exactly.
+        let other = asset::Cache::with_known_assets()
+            .get_unit("gn")
+            .expect("gn is a known asset")
+            .id();
+        assert!(submit(other, 1_000_000)
+            .check_stateful(Arc::new(storage.latest_snapshot()))
+            .await
+            .is_err());
+        assert!(submit(*STAKING_TOKEN_ASSET_ID, 1001)
+            .check_stateful(Arc::new(storage.latest_snapshot()))
+            .await
+            .is_err());
+
+        let mut state = StateDelta::new(storage.latest_snapshot());
+        action.execute(&mut state).await?;
+        storage.commit(state).await?;
+
+        // Without recent swaps, the rate is too old to be relied on.
+        set_block_height(&storage, 12).await?;
+        assert!(submit(gm, 400)
+            .check_stateful(Arc::new(storage.latest_snapshot()))
+            .await
+            .is_err());
+
+        // The deposit is refunded in the asset it was paid in.
+        let mut state = StateDelta::new(storage.latest_snapshot());
+        state.put_proposal_state(
+            0,
+            ProposalState::Finished {
+                outcome: Outcome::Passed,
+            },
+        );
+        storage.commit(state).await?;
+        let claim = |deposit_asset_id| ProposalDepositClaim {
+            proposal: 0,
+            deposit_amount: 400u64.into(),
+            deposit_asset_id,
+            outcome: Outcome::Passed,
+        };
+        assert!(claim(*STAKING_TOKEN_ASSET_ID)
+            .check_stateful(Arc::new(storage.latest_snapshot()))
+            .await
+            .is_err());
+        let claim = claim(gm);
+        claim
+            .check_stateful(Arc::new(storage.latest_snapshot()))
+            .await?;
+        let mut state = StateDelta::new(storage.latest_snapshot());
+        claim.execute(&mut state).await?;
+        storage.commit(state).await?;
+        assert!(matches!(
+            storage.latest_snapshot().proposal_state(0).await?,
+            Some(ProposalState::Claimed { .. })
+        ));
+
+        Ok(())
+    }
 }
@@ -3,6 +3,7 @@ use std::sync::Arc;
 use anyhow::Result;
 use async_trait::async_trait;
 use cnidarium::{StateRead, StateWrite};
+use penumbra_compact_block::component::CompactBlockManager;
 use penumbra_sct::{component::source::SourceContext, CommitmentSource};
 use penumbra_transaction::Transaction;
 use tokio::task::JoinSet;
@@ -13,7 +14,10 @@ use super::ActionHandler;
 mod stateful;
 mod stateless;
 
-use self::stateful::{claimed_anchor_is_valid, fee_greater_than_base_fee, fmd_parameters_valid};
+use self::stateful::{
+    claimed_anchor_is_valid, fee_greater_than_base_fee, fmd_parameters_valid,
+    spent_nullifiers_unspent,
+};
 use stateless::{
     check_memo_exists_if_outputs_absent_if_not, no_duplicate_spends, no_duplicate_votes,
     num_clues_equal_to_num_outputs, valid_binding_signature,
@@ -62,6 +66,7 @@ impl ActionHandler for Transaction {
         claimed_anchor_is_valid(state.clone(), self).await?;
         fmd_parameters_valid(state.clone(), self).await?;
         fee_greater_than_base_fee(state.clone(), self).await?;
+        spent_nullifiers_unspent(state.clone(), self).await?;
 
         // Currently, we need to clone the component actions so that the spawned
         // futures can have 'static lifetimes. In the future, we could try to
@@ -100,6 +105,17 @@ impl ActionHandler for Transaction {
         // Delete the note source, in case someone else tries to read it.
         state.put_current_source(None);
 
+        // Keep the clue of each output alongside the compact block, so that
+        // compact blocks can be filtered by detection key.  There is exactly one
+        // clue per output, in the same order.
+        if let Some(detection_data) = &self.transaction_body.detection_data {
+            state.record_note_clues(
+                self.outputs()
+                    .map(|output| output.body.note_payload.note_commitment)
+                    .zip(detection_data.fmd_clues.iter().cloned()),
+            );
+        }
+
         Ok(())
     }
 }
@@ -79,6 +79,19 @@ pub async fn claimed_anchor_is_valid<S: StateRead>(
     state.check_claimed_anchor(transaction.anchor).await
 }
 
+/// Checks that none of the transaction's spends reuse a nullifier, looking up
+/// all of their nullifiers in one batch, rather than one spend at a time.
+pub async fn spent_nullifiers_unspent<S: StateRead>(
+    state: S,
+    transaction: &Transaction,
+) -> Result<()> {
+    let nullifiers = transaction
+        .spends()
+        .map(|spend| spend.body.nullifier)
+        .collect::<Vec<_>>();
+    state.check_nullifiers_unspent(&nullifiers).await
+}
+
 pub async fn fee_greater_than_base_fee<S: StateRead>(
     state: S,
     transaction: &Transaction,
@@ -104,3 +117,43 @@ pub async fn fee_greater_than_base_fee<S: StateRead>(
         ))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use cnidarium::{StateDelta, TempStorage};
+    use penumbra_proto::StateWriteProto as _;
+    use penumbra_sct::{state_key, NullificationInfo, Nullifier};
+
+    use super::*;
+
+    #[tokio::test]
+    async fn nullifiers_are_checked_in_one_batch() -> Result<()> {
+        let storage = TempStorage::new().await?;
+        let mut state = StateDelta::new(storage.latest_snapshot());
+
+        let spent = Nullifier(1u64.into());
+        let unspent = Nullifier(2u64.into());
+        let other = Nullifier(3u64.into());
+        state.put(
+            state_key::nullifier_set::spent_nullifier_lookup(&spent),
+            NullificationInfo {
+                id: [0; 32],
+                spend_height: 1,
+            },
+        );
+
+        state.check_nullifiers_unspent(&[unspent, other]).await?;
+        // A nullifier spent in an earlier transaction is rejected...
+        assert!(state
+            .check_nullifiers_unspent(&[unspent, spent])
+            .await
+            .is_err());
+        // ...as is a double-spend within the batch itself.
+        assert!(state
+            .check_nullifiers_unspent(&[unspent, other, unspent])
+            .await
+            .is_err());
+
+        Ok(())
+    }
+}
@@ -9,7 +9,7 @@ use jmt::RootHash;
 use penumbra_community_pool::component::{CommunityPool, StateWriteExt as _};
 use penumbra_community_pool::StateReadExt as _;
 use penumbra_compact_block::component::CompactBlockManager;
-use penumbra_dex::component::Dex;
+use penumbra_dex::component::{Dex, StateReadExt as _, StateWriteExt as _};
 use penumbra_distributions::component::{Distributions, StateReadExt as _, StateWriteExt as _};
 use penumbra_fee::component::{Fee, StateReadExt as _, StateWriteExt as _};
 use penumbra_funding::component::Funding;
@@ -71,6 +71,28 @@ impl App {
             anyhow::bail!("chain is halted, refusing to restart");
         }
 
+        // Similarly, if the chain has reached the height of a scheduled upgrade, we should not
+        // proceed unless this is the release specified by the upgrade plan.
+        if let Some(upgrade_plan) = state.scheduled_upgrade().await? {
+            let next_height = state.get_block_height().await?.saturating_add(1);
+            if next_height >= upgrade_plan.height
+                && !upgrade_plan.accepts_version(crate::RELEASE_VERSION)
+            {
+                tracing::error!(
+                    upgrade_height = upgrade_plan.height,
+                    required_version = ?upgrade_plan.version,
+                    running_version = crate::RELEASE_VERSION,
+                    "chain was upgraded to a different release, refusing to restart!"
+                );
+                anyhow::bail!(
+                    "chain was upgraded at height {} to version {}, but this is version {}",
+                    upgrade_plan.height,
+                    upgrade_plan.version.unwrap_or_default(),
+                    crate::RELEASE_VERSION,
+                );
+            }
+        }
+
         Ok(Self { state })
     }
 
@@ -116,7 +138,7 @@ impl App {
                 )
                 .await;
                 Ibc::init_chain(&mut state_tx, Some(&genesis.ibc_content)).await;
-                Dex::init_chain(&mut state_tx, Some(&())).await;
+                Dex::init_chain(&mut state_tx, Some(&genesis.dex_content)).await;
                 CommunityPool::init_chain(&mut state_tx, Some(&genesis.community_pool_content))
                     .await;
                 Governance::init_chain(&mut state_tx, Some(&genesis.governance_content)).await;
@@ -216,6 +238,9 @@ impl App {
             if let Some(community_pool_params) = app_params.new.community_pool_params {
                 state_tx.put_community_pool_params(community_pool_params);
             }
+            if let Some(dex_params) = app_params.new.dex_params {
+                state_tx.put_dex_params(dex_params);
+            }
             if let Some(distributions_params) = app_params.new.distributions_params {
                 state_tx.put_distributions_params(distributions_params);
             }
@@ -257,6 +282,7 @@ impl App {
         Staking::begin_block(&mut arc_state_tx, begin_block).await;
         Fee::begin_block(&mut arc_state_tx, begin_block).await;
         Funding::begin_block(&mut arc_state_tx, begin_block).await;
+        Dex::begin_block(&mut arc_state_tx, begin_block).await;
 
         let state_tx = Arc::try_unwrap(arc_state_tx)
             .expect("components did not retain copies of shared state");
@@ -616,6 +642,7 @@ pub trait StateReadExt: StateRead {
     /// Returns true if the app parameters have been changed in this block.
     fn app_params_updated(&self) -> bool {
         self.community_pool_params_updated()
+            || self.dex_params_updated()
             || self.distributions_params_updated()
             || self.ibc_params_updated()
             || self.fee_params_updated()
@@ -667,6 +694,7 @@ pub trait StateReadExt: StateRead {
         let chain_id = self.get_chain_id().await?;
         let community_pool_params: penumbra_community_pool::params::CommunityPoolParameters =
             self.get_community_pool_params().await?;
+        let dex_params = self.get_dex_params().await?;
         let distributions_params = self.get_distributions_params().await?;
         let ibc_params = self.get_ibc_params().await?;
         let fee_params = self.get_fee_params().await?;
@@ -679,6 +707,7 @@ pub trait StateReadExt: StateRead {
         Ok(AppParameters {
             chain_id,
             community_pool_params,
+            dex_params,
             distributions_params,
             fee_params,
             funding_params,
@@ -759,3 +788,74 @@ pub trait StateWriteExt: StateWrite {
 }
 
 impl<T: StateWrite + ?Sized> StateWriteExt for T {}
+
+#[cfg(test)]
+mod tests {
+    use cnidarium::TempStorage;
+    use penumbra_governance::{StateReadExt as _, StateWriteExt as _, UpgradePlan};
+    use penumbra_sct::component::clock::EpochManager as _;
+
+    use super::*;
+
+    /// Schedule an upgrade at height 10 pinned to the given release, with the chain at `height`.
+    async fn schedule_upgrade(storage: &TempStorage, height: u64, version: &str) -> Result<()> {
+        let mut state = StateDelta::new(storage.latest_snapshot());
+        state.put_block_height(height);
+        state
+            .signal_upgrade(UpgradePlan {
+                height: 10,
+                version: Some(version.to_string()),
+                binary_hash: None,
+            })
+            .await?;
+        storage.commit(state).await?;
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn app_restarts_past_an_upgrade_only_on_the_pinned_release() -> Result<()> {
+        // Before the upgrade height, the chain keeps running on the current release.
+        let storage = TempStorage::new().await?;
+        schedule_upgrade(&storage, 5, "0.0.0-other").await?;
+        assert!(App::new(storage.latest_snapshot()).await.is_ok());
+
+        // From the upgrade height on, only the release pinned by the plan may start.
+        let storage = TempStorage::new().await?;
+        schedule_upgrade(&storage, 9, "0.0.0-other").await?;
+        assert!(App::new(storage.latest_snapshot()).await.is_err());
+
+        let storage = TempStorage::new().await?;
+        schedule_upgrade(&storage, 9, crate::RELEASE_VERSION).await?;
+        assert!(App::new(storage.latest_snapshot()).await.is_ok());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn completed_upgrade_no_longer_pins_the_release() -> Result<()> {
+        let storage = TempStorage::new().await?;
+        schedule_upgrade(&storage, 9, "0.0.0-other").await?;
+
+        // Nothing is cleared until a block at the upgrade height runs.
+        let mut state = StateDelta::new(storage.latest_snapshot());
+        state.clear_completed_upgrade().await?;
+        storage.commit(state).await?;
+        assert!(storage
+            .latest_snapshot()
+            .scheduled_upgrade()
+            .await?
+            .is_some());
+
+        let mut state = StateDelta::new(storage.latest_snapshot());
+        state.put_block_height(10);
+        state.clear_completed_upgrade().await?;
+        storage.commit(state).await?;
+        assert_eq!(storage.latest_snapshot().scheduled_upgrade().await?, None);
+        assert!(!storage.latest_snapshot().is_upgrade_height().await?);
+
+        // A later release can now restart the chain.
+        assert!(App::new(storage.latest_snapshot()).await.is_ok());
+
+        Ok(())
+    }
+}
@@ -1,3 +1,7 @@
+/// The prefixes of all of the application's own state keys, for the state key
+/// registry.
+pub const PREFIXES: &[&str] = &["application/", "cometbft-data/"];
+
 pub mod genesis {
     pub fn app_state() -> &'static str {
         "application/genesis/app_state"
@@ -0,0 +1,160 @@
+use anyhow::{ensure, Context, Result};
+use async_trait::async_trait;
+use cnidarium::StateWrite;
+use cnidarium_component::ActionHandler as _;
+use penumbra_asset::{asset, Value, STAKING_TOKEN_ASSET_ID};
+use penumbra_dex::{
+    lp::{
+        position::{self, Position},
+        LpNft, Reserves,
+    },
+    DirectedTradingPair, PositionOpen,
+};
+use penumbra_keys::Address;
+use penumbra_proto::DomainType;
+use penumbra_sct::CommitmentSource;
+use penumbra_shielded_pool::component::{Ics20Callbacks, NoteManager as _};
+use penumbra_stake::{
+    component::validator_handler::ValidatorDataRead as _, DelegationToken, IdentityKey,
+};
+use rand_chacha::{rand_core::SeedableRng as _, ChaCha20Rng};
+use serde::Deserialize;
+
+/// A follow-up action requested in the `dest_callback` field of the memo of an
+/// inbound ICS-20 transfer, for example:
+///
+/// ```json
+/// { "dest_callback": { "delegate": { "validator": "penumbravalid1..." } } }
+/// ```
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "snake_case", deny_unknown_fields)]
+enum Callback {
+    /// Delegate the transferred staking tokens to the validator, crediting the
+    /// receiver with the delegation tokens.
+    Delegate { validator: String },
+    /// Open a position selling the transferred asset for the counter asset at
+    /// the price `p / q`, crediting the receiver with the position's LP NFT.
+    PositionOpen {
+        counter_denom: String,
+        p: u64,
+        q: u64,
+        fee: u32,
+    },
+}
+
+/// The implementation of [`Ics20Callbacks`] for Penumbra, which lets inbound
+/// transfers be staked or provided as liquidity in one step.
+///
+/// The follow-up actions are checked and executed by the staking and DEX
+/// components exactly as if the receiver had submitted them in a transaction.
+#[derive(Clone)]
+pub struct PenumbraCallbacks {}
+
+#[async_trait]
+impl Ics20Callbacks for PenumbraCallbacks {
+    async fn execute_recv_callback<S: StateWrite>(
+        mut state: S,
+        callback: &serde_json::Value,
+        value: Value,
+        receiver: &Address,
+        source: CommitmentSource,
+    ) -> Result<()> {
+        let callback = Callback::deserialize(callback).context("invalid ICS-20 callback")?;
+        tracing::debug!(?callback, ?value, "executing ICS-20 callback");
+
+        match callback {
+            Callback::Delegate { validator } => {
+                ensure!(
+                    value.asset_id == *STAKING_TOKEN_ASSET_ID,
+                    "only the staking token can be delegated"
+                );
+                let validator: IdentityKey = validator.parse()?;
+                let rate_data = state
+                    .get_validator_rate(&validator)
+                    .await?
+                    .ok_or_else(|| anyhow::anyhow!("unknown validator identity {}", validator))?;
+
+                let delegate = rate_data.build_delegate(value.amount);
+                delegate.check_stateless(()).await?;
+                delegate.check_against_state(&state).await?;
+                delegate.execute(&mut state).await?;
+
+                let delegation_tokens = Value {
+                    amount: delegate.delegation_amount,
+                    asset_id: DelegationToken::new(validator).id(),
+                };
+                state
+                    .add_public_note(delegation_tokens, receiver, source)
+                    .await
+            }
+            Callback::PositionOpen {
+                counter_denom,
+                p,
+                q,
+                fee,
+            } => {
+                let counter_denom = asset::Metadata::try_from(counter_denom.as_str())?;
+                let pair = DirectedTradingPair::new(value.asset_id, counter_denom.id());
+                let reserves = Reserves {
+                    r1: value.amount,
+                    r2: 0u64.into(),
+                };
+
+                // The position's nonce must be deterministic, so it is derived
+                // from the packet which opened it.
+                let seed: [u8; 32] = blake2b_simd::Params::default()
+                    .personal(b"PenumbraIcs20Lp")
+                    .to_state()
+                    .update(&source.encode_to_vec())
+                    .finalize()
+                    .as_bytes()[0..32]
+                    .try_into()?;
+                let rng = ChaCha20Rng::from_seed(seed);
+
+                let open = PositionOpen {
+                    position: Position::new(rng, pair, fee, p.into(), q.into(), reserves),
+                    strategy_tag: String::new(),
+                };
+                open.check_stateless(()).await?;
+                open.check_against_state(&state).await?;
+                open.execute(&mut state).await?;
+
+                let lp_nft = Value {
+                    amount: 1u64.into(),
+                    asset_id: LpNft::new(open.position.id(), position::State::Opened).asset_id(),
+                };
+                state.add_public_note(lp_nft, receiver, source).await
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn callbacks_are_parsed_from_memos() {
+        let delegate = serde_json::json!({ "delegate": { "validator": "penumbravalid1abc" } });
+        assert!(matches!(
+            Callback::deserialize(&delegate),
+            Ok(Callback::Delegate { validator }) if validator == "penumbravalid1abc"
+        ));
+
+        let open = serde_json::json!({
+            "position_open": { "counter_denom": "upenumbra", "p": 1, "q": 2, "fee": 30 }
+        });
+        assert!(matches!(
+            Callback::deserialize(&open),
+            Ok(Callback::PositionOpen {
+                p: 1,
+                q: 2,
+                fee: 30,
+                ..
+            })
+        ));
+
+        let unknown = serde_json::json!({ "swap": {} });
+        assert!(Callback::deserialize(&unknown).is_err());
+    }
+}
@@ -4,23 +4,29 @@
 pub mod app;
 pub mod metrics;
 pub mod params;
+pub mod proposal_effects;
 pub mod rpc;
 pub mod server;
+pub mod state_key_registry;
 
 mod action_handler;
 mod community_pool_ext;
+mod ics20_callbacks;
 mod penumbra_host_chain;
 
 pub use crate::{
     action_handler::ActionHandler, app::StateWriteExt,
-    community_pool_ext::CommunityPoolStateReadExt, metrics::register_metrics,
-    penumbra_host_chain::PenumbraHost,
+    community_pool_ext::CommunityPoolStateReadExt, ics20_callbacks::PenumbraCallbacks,
+    metrics::register_metrics, penumbra_host_chain::PenumbraHost,
 };
 
 use once_cell::sync::Lazy;
 
 pub const APP_VERSION: u64 = 1;
 
+/// The version string of this release, which upgrade plans can require nodes to run.
+pub const RELEASE_VERSION: &str = env!("CARGO_PKG_VERSION");
+
 pub static SUBSTORE_PREFIXES: Lazy<Vec<String>> = Lazy::new(|| {
     vec![
         penumbra_ibc::IBC_SUBSTORE_PREFIX.to_string(),
@@ -1,7 +1,9 @@
-use std::fmt::Display;
+use std::{collections::BTreeSet, fmt::Display};
 
 use anyhow::Result;
+use penumbra_asset::STAKING_TOKEN_ASSET_ID;
 use penumbra_community_pool::params::CommunityPoolParameters;
+use penumbra_dex::{lp::position::MAX_FEE_BPS, DexParameters};
 use penumbra_distributions::params::DistributionsParameters;
 use penumbra_fee::FeeParameters;
 use penumbra_funding::params::FundingParameters;
@@ -31,6 +33,14 @@ impl AppParameters {
                 CommunityPoolParameters {
                     community_pool_spend_proposals_enabled: _,
                 },
+            dex_params:
+                DexParameters {
+                    fee_tiers: _,
+                    protocol_fee_share_bps: _,
+                    paused_trading_pairs: _,
+                    execution_trace_retention_blocks: _,
+                    route_pruning_epsilon_bps: _,
+                },
             distributions_params:
                 DistributionsParameters {
                     staking_issuance_per_block: _,
@@ -46,6 +56,12 @@ impl AppParameters {
                     proposal_valid_quorum,
                     proposal_pass_threshold,
                     proposal_slash_threshold,
+                    sealed_ballot_setup_blocks: _,
+                    sealed_ballot_reveal_blocks: _,
+                    accepted_deposit_assets: _,
+                    deposit_rate_window_blocks: _,
+                    deposit_rate_max_age_blocks: _,
+                    deposit_rate_min_volume: _,
                 },
             ibc_params:
                 IBCParameters {
@@ -65,9 +81,14 @@ impl AppParameters {
                     base_reward_rate: _,
                     slashing_penalty_misbehavior: _,
                     slashing_penalty_downtime: _,
-                    signed_blocks_window_len,
+                    signed_blocks_window_len: _,
                     missed_blocks_maximum: _,
                     min_validator_stake: _,
+                    min_validator_self_delegation: _,
+                    inactive_validator_retention_epochs: _,
+                    redelegation_limit_bps: _,
+                    restrict_at_risk_delegation_tokens: _,
+                    enforce_unique_validator_names: _,
                 },
             // IMPORTANT: Don't use `..` here! We want to ensure every single field is verified!
         } = self;
@@ -85,11 +106,6 @@ impl AppParameters {
                 &new.stake_params.active_validator_limit,
                 "active validator limit",
             ),
-            (
-                signed_blocks_window_len,
-                &new.stake_params.signed_blocks_window_len,
-                "signed blocks window length",
-            ),
         ])?;
         check_invariant([
             (
@@ -119,6 +135,14 @@ impl AppParameters {
                 CommunityPoolParameters {
                     community_pool_spend_proposals_enabled: _,
                 },
+            dex_params:
+                DexParameters {
+                    fee_tiers,
+                    protocol_fee_share_bps,
+                    paused_trading_pairs,
+                    execution_trace_retention_blocks: _,
+                    route_pruning_epsilon_bps,
+                },
             distributions_params:
                 DistributionsParameters {
                     staking_issuance_per_block: _,
@@ -134,6 +158,12 @@ impl AppParameters {
                     proposal_valid_quorum,
                     proposal_pass_threshold,
                     proposal_slash_threshold,
+                    sealed_ballot_setup_blocks,
+                    sealed_ballot_reveal_blocks,
+                    accepted_deposit_assets,
+                    deposit_rate_window_blocks,
+                    deposit_rate_max_age_blocks,
+                    deposit_rate_min_volume,
                 },
             ibc_params:
                 IBCParameters {
@@ -156,6 +186,12 @@ impl AppParameters {
                     signed_blocks_window_len,
                     missed_blocks_maximum,
                     min_validator_stake,
+                    // Any self-delegation requirement is valid, including none.
+                    min_validator_self_delegation: _,
+                    inactive_validator_retention_epochs: _,
+                    redelegation_limit_bps,
+                    restrict_at_risk_delegation_tokens: _,
+                    enforce_unique_validator_names: _,
                 },
             // IMPORTANT: Don't use `..` here! We want to ensure every single field is verified!
         } = self;
@@ -202,6 +238,14 @@ impl AppParameters {
                 *missed_blocks_maximum >= 1,
                 "missed blocks maximum must be at least 1",
             ),
+            (
+                *missed_blocks_maximum < *signed_blocks_window_len,
+                "missed blocks maximum must be less than the signed blocks window length",
+            ),
+            (
+                *redelegation_limit_bps <= 10_000,
+                "redelegation limit must be at most 10,000 basis points",
+            ),
             (
                 (!*inbound_ics20_transfers_enabled && !*outbound_ics20_transfers_enabled)
                     || *ibc_enabled,
@@ -215,6 +259,14 @@ impl AppParameters {
                 *proposal_deposit_amount >= 1u64.into(),
                 "proposal deposit amount must be at least 1",
             ),
+            (
+                *sealed_ballot_setup_blocks >= 2,
+                "sealed ballot setup blocks must be at least 2",
+            ),
+            (
+                *sealed_ballot_reveal_blocks >= 1,
+                "sealed ballot reveal blocks must be at least 1",
+            ),
             (
                 *proposal_valid_quorum > Ratio::new(0, 1),
                 "proposal valid quorum must be greater than 0",
@@ -231,6 +283,47 @@ impl AppParameters {
                 *min_validator_stake >= 1_000_000u128.into(),
                 "the minimum validator stake must be at least 1penumbra",
             ),
+            (
+                fee_tiers.iter().all(|fee| *fee <= MAX_FEE_BPS),
+                "dex fee tiers must be at most 5,000 basis points",
+            ),
+            (
+                *protocol_fee_share_bps <= 10_000,
+                "dex protocol fee share must be at most 10,000 basis points",
+            ),
+            (
+                paused_trading_pairs.iter().collect::<BTreeSet<_>>().len()
+                    == paused_trading_pairs.len(),
+                "dex paused trading pairs must be distinct",
+            ),
+            (
+                *route_pruning_epsilon_bps <= 10_000,
+                "dex route pruning epsilon must be at most 10,000 basis points",
+            ),
+            (
+                !accepted_deposit_assets.contains(&*STAKING_TOKEN_ASSET_ID),
+                "accepted proposal deposit assets must not include the staking token",
+            ),
+            (
+                accepted_deposit_assets
+                    .iter()
+                    .collect::<BTreeSet<_>>()
+                    .len()
+                    == accepted_deposit_assets.len(),
+                "accepted proposal deposit assets must be distinct",
+            ),
+            (
+                *deposit_rate_window_blocks >= 1,
+                "deposit rate window blocks must be at least 1",
+            ),
+            (
+                *deposit_rate_max_age_blocks >= 1,
+                "deposit rate max age blocks must be at least 1",
+            ),
+            (
+                *deposit_rate_min_volume >= 1u64.into(),
+                "deposit rate min volume must be at least 1",
+            ),
         ])
     }
 
@@ -238,6 +331,7 @@ impl AppParameters {
     pub fn as_changed_params(&self) -> ChangedAppParameters {
         ChangedAppParameters {
             community_pool_params: Some(self.community_pool_params.clone()),
+            dex_params: Some(self.dex_params.clone()),
             distributions_params: Some(self.distributions_params.clone()),
             fee_params: Some(self.fee_params.clone()),
             funding_params: Some(self.funding_params.clone()),
@@ -260,6 +354,7 @@ impl AppParameters {
     ) -> Result<AppParameters> {
         if old.is_none()
             && (new.community_pool_params.is_none()
+                || new.dex_params.is_none()
                 || new.distributions_params.is_none()
                 || new.fee_params.is_none()
                 || new.funding_params.is_none()
@@ -285,6 +380,11 @@ impl AppParameters {
                     .community_pool_params
                     .clone()
             }),
+            dex_params: new.dex_params.clone().unwrap_or_else(|| {
+                old.expect("old should be set if new has any None values")
+                    .dex_params
+                    .clone()
+            }),
             distributions_params: new.distributions_params.clone().unwrap_or_else(|| {
                 old.expect("old should be set if new has any None values")
                     .distributions_params
@@ -361,3 +461,42 @@ fn check_invariant<'a, T: Eq + 'a>(
             .map(|(old, new, name)| ((*old == *new), format!("{name} can't be changed"))),
     )
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const WINDOW_CHECK: &str =
+        "missed blocks maximum must be less than the signed blocks window length";
+
+    fn with_downtime(signed_blocks_window_len: u64, missed_blocks_maximum: u64) -> AppParameters {
+        let mut params = AppParameters {
+            chain_id: "penumbra-test".to_string(),
+            ..Default::default()
+        };
+        params.stake_params.signed_blocks_window_len = signed_blocks_window_len;
+        params.stake_params.missed_blocks_maximum = missed_blocks_maximum;
+        params
+    }
+
+    fn violates_window_check(params: &AppParameters) -> bool {
+        params
+            .check_valid()
+            .is_err_and(|e| e.to_string().contains(WINDOW_CHECK))
+    }
+
+    #[test]
+    fn missed_blocks_maximum_must_fit_in_the_window() {
+        // A validator could never miss enough blocks to be jailed.
+        assert!(violates_window_check(&with_downtime(100, 100)));
+        assert!(violates_window_check(&with_downtime(100, 500)));
+        assert!(!violates_window_check(&with_downtime(100, 99)));
+
+        // Governance can't shrink the window below the missed blocks maximum.
+        let old = with_downtime(10_000, 9_500);
+        let new = with_downtime(9_000, 9_500);
+        assert!(old
+            .check_valid_update(&new)
+            .is_err_and(|e| e.to_string().contains(WINDOW_CHECK)));
+    }
+}
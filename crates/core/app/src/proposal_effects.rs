@@ -0,0 +1,275 @@
+//! Previewing the effects of enacting a governance proposal.
+//!
+//! Voters only see a proposal's payload, whose consequences are not always
+//! obvious: a parameter change proposal lists every parameter of each
+//! component it touches, and a community pool spend is an opaque transaction
+//! plan.  [`proposal_effects`] simulates the enactment of a proposal against a
+//! snapshot of the state, and reports what would actually change, so that
+//! wallets can show it to voters.
+
+use std::collections::BTreeMap;
+
+use anyhow::{Context as _, Result};
+use cnidarium::{Snapshot, StateDelta};
+use penumbra_asset::Value;
+use penumbra_community_pool::StateReadExt as _;
+use penumbra_governance::{Proposal, ProposalPayload, StateWriteExt as _};
+use penumbra_num::Amount;
+use penumbra_proto::{
+    core::app::v1::{self as pb, proposal_effect::Effect},
+    DomainType,
+};
+use penumbra_sct::component::clock::EpochRead as _;
+use penumbra_transaction::{ActionPlan, TransactionPlan};
+
+use crate::{app::StateReadExt as _, params::AppParameters};
+
+/// Simulate enacting the proposal against the given snapshot, and report its
+/// effects.
+///
+/// Failing to enact the proposal is not an error: the reason is reported in
+/// the response, alongside whatever effects could still be derived.
+pub async fn proposal_effects(
+    snapshot: Snapshot,
+    proposal: &Proposal,
+) -> Result<pb::ProposalEffectsResponse> {
+    let height = snapshot.get_block_height().await?;
+
+    // First, enact the proposal exactly as the governance component would,
+    // on a scratch copy of the state.
+    let mut failure = match StateDelta::new(snapshot.clone())
+        .enact_proposal(proposal.id, &proposal.payload)
+        .await
+    {
+        Ok(Ok(())) => None,
+        Ok(Err(e)) | Err(e) => Some(e),
+    };
+
+    let mut parameter_changes = Vec::new();
+    let mut effects = Vec::new();
+    match &proposal.payload {
+        ProposalPayload::Signaling { .. } => {}
+        ProposalPayload::Emergency { halt_chain } => {
+            if *halt_chain {
+                effects.push(Effect::ChainHalt(pb::proposal_effect::ChainHalt {}));
+            }
+        }
+        ProposalPayload::ParameterChange { old, new } => {
+            // The app validates parameter changes at the end of the block in
+            // which they are enacted, so do the same here.
+            match validate_parameter_change(&snapshot, old, new).await {
+                Ok((old, new)) => {
+                    parameter_changes = parameter_changes_between(&old, &new)?;
+                    if old.fee_params != new.fee_params {
+                        effects.push(Effect::FeeSchedule(pb::proposal_effect::FeeSchedule {
+                            old_gas_prices: Some(old.fee_params.fixed_gas_prices.into()),
+                            new_gas_prices: Some(new.fee_params.fixed_gas_prices.into()),
+                        }));
+                    }
+                }
+                Err(e) => {
+                    failure.get_or_insert(e);
+                }
+            }
+        }
+        ProposalPayload::CommunityPoolSpend { transaction_plan } => {
+            let plan = TransactionPlan::decode(&transaction_plan[..])
+                .context("proposal has an invalid transaction plan")?;
+            let mut withdrawn = BTreeMap::<_, Amount>::new();
+            for action in &plan.actions {
+                if let ActionPlan::CommunityPoolSpend(spend) = action {
+                    *withdrawn.entry(spend.value.asset_id).or_default() += spend.value.amount;
+                }
+            }
+            for (asset_id, amount) in withdrawn {
+                let balance = snapshot.community_pool_asset_balance(asset_id).await?;
+                if amount > balance {
+                    failure.get_or_insert_with(|| {
+                        anyhow::anyhow!(
+                            "the community pool holds {balance} of asset {asset_id}, less than the {amount} withdrawn"
+                        )
+                    });
+                }
+                effects.push(Effect::CommunityPoolWithdrawal(
+                    pb::proposal_effect::CommunityPoolWithdrawal {
+                        value: Some(Value { amount, asset_id }.into()),
+                        balance: Some(balance.into()),
+                    },
+                ));
+            }
+        }
+        ProposalPayload::UpgradePlan(upgrade_plan) => {
+            effects.push(Effect::ChainUpgrade(pb::proposal_effect::ChainUpgrade {
+                height: upgrade_plan.height,
+                blocks_remaining: upgrade_plan.height.saturating_sub(height),
+            }));
+        }
+        ProposalPayload::FreezeIbcClient { client_id } => {
+            effects.push(Effect::IbcClientStatus(
+                pb::proposal_effect::IbcClientStatus {
+                    client_id: client_id.clone(),
+                    frozen: true,
+                },
+            ));
+        }
+        ProposalPayload::UnfreezeIbcClient { client_id } => {
+            effects.push(Effect::IbcClientStatus(
+                pb::proposal_effect::IbcClientStatus {
+                    client_id: client_id.clone(),
+                    frozen: false,
+                },
+            ));
+        }
+        ProposalPayload::RecurringBudget {
+            budget_line,
+            cancel,
+        } => {
+            let mut cancelled = Vec::new();
+            for id in cancel {
+                if let Some(budget_line) = snapshot.budget_line(*id).await? {
+                    cancelled.push(budget_line.into());
+                }
+            }
+            effects.push(Effect::RecurringBudget(
+                pb::proposal_effect::RecurringBudget {
+                    established: budget_line.clone().map(Into::into),
+                    cancelled,
+                },
+            ));
+        }
+    }
+
+    Ok(pb::ProposalEffectsResponse {
+        height,
+        enactable: failure.is_none(),
+        failure_reason: failure.map(|e| format!("{e:#}")).unwrap_or_default(),
+        parameter_changes,
+        effects: effects
+            .into_iter()
+            .map(|effect| pb::ProposalEffect {
+                effect: Some(effect),
+            })
+            .collect(),
+    })
+}
+
+/// Check a parameter change as the app does before applying it, returning the
+/// complete old and new parameters.
+async fn validate_parameter_change(
+    snapshot: &Snapshot,
+    old: &penumbra_governance::proposal::ChangedAppParameters,
+    new: &penumbra_governance::proposal::ChangedAppParameters,
+) -> Result<(AppParameters, AppParameters)> {
+    let old = AppParameters::from_changed_params(old, None)?;
+    let new = AppParameters::from_changed_params(new, Some(&old))?;
+    old.check_valid_update(&new)?;
+    anyhow::ensure!(
+        old == snapshot.get_app_params().await?,
+        "the current app parameters no longer match the proposal's old parameters"
+    );
+    Ok((old, new))
+}
+
+/// List the individual parameters which differ between two sets of app
+/// parameters.
+///
+/// Parameters are named by their paths in the JSON encoding of the app
+/// parameters.  A parameter which is unset in one of the two, because it has
+/// its default value, is shown as `null`.
+pub fn parameter_changes_between(
+    old: &AppParameters,
+    new: &AppParameters,
+) -> Result<Vec<pb::ParameterChange>> {
+    let mut old_leaves = BTreeMap::new();
+    flatten(
+        String::new(),
+        serde_json::to_value(pb::AppParameters::from(old.clone()))?,
+        &mut old_leaves,
+    );
+    let mut new_leaves = BTreeMap::new();
+    flatten(
+        String::new(),
+        serde_json::to_value(pb::AppParameters::from(new.clone()))?,
+        &mut new_leaves,
+    );
+
+    let paths = old_leaves
+        .keys()
+        .chain(new_leaves.keys())
+        .cloned()
+        .collect::<std::collections::BTreeSet<_>>();
+    let null = serde_json::Value::Null;
+    Ok(paths
+        .into_iter()
+        .filter_map(|path| {
+            let old_value = old_leaves.get(&path).unwrap_or(&null);
+            let new_value = new_leaves.get(&path).unwrap_or(&null);
+            if old_value == new_value {
+                return None;
+            }
+            let (component, parameter) = path.split_once('.').unwrap_or((&path, ""));
+            Some(pb::ParameterChange {
+                component: component
+                    .strip_suffix("Params")
+                    .unwrap_or(component)
+                    .to_string(),
+                parameter: parameter.to_string(),
+                old_value: old_value.to_string(),
+                new_value: new_value.to_string(),
+            })
+        })
+        .collect())
+}
+
+/// Collect the leaves of a JSON value, keyed by their dotted paths.
+fn flatten(
+    path: String,
+    value: serde_json::Value,
+    leaves: &mut BTreeMap<String, serde_json::Value>,
+) {
+    match value {
+        serde_json::Value::Object(fields) => {
+            for (name, field) in fields {
+                let path = if path.is_empty() {
+                    name
+                } else {
+                    format!("{path}.{name}")
+                };
+                flatten(path, field, leaves);
+            }
+        }
+        leaf => {
+            leaves.insert(path, leaf);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn only_changed_parameters_are_listed() -> Result<()> {
+        let old = AppParameters::default();
+        let mut new = old.clone();
+        new.fee_params.fixed_gas_prices.block_space_price = 5;
+        new.governance_params.proposal_voting_blocks = 1000;
+
+        let changes = parameter_changes_between(&old, &new)?;
+        let changed = changes
+            .iter()
+            .map(|change| (change.component.as_str(), change.parameter.as_str()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            changed,
+            [
+                ("fee", "fixedGasPrices.blockSpacePrice"),
+                ("governance", "proposalVotingBlocks"),
+            ]
+        );
+        assert_eq!(changes[1].new_value, "\"1000\"");
+
+        assert!(parameter_changes_between(&old, &old)?.is_empty());
+        Ok(())
+    }
+}
@@ -1,12 +1,14 @@
 use cnidarium::Storage;
+use penumbra_governance::StateReadExt as _;
 use penumbra_proto::core::app::v1::{
     query_service_server::QueryService, AppParametersRequest, AppParametersResponse,
-    TransactionsByHeightRequest, TransactionsByHeightResponse,
+    ProposalEffectsRequest, ProposalEffectsResponse, TransactionsByHeightRequest,
+    TransactionsByHeightResponse,
 };
 use tonic::Status;
 use tracing::instrument;
 
-use crate::app::StateReadExt as _;
+use crate::{app::StateReadExt as _, proposal_effects::proposal_effects};
 
 // TODO: Hide this and only expose a Router?
 pub struct Server {
@@ -55,4 +57,37 @@ impl QueryService for Server {
             app_parameters: Some(app_parameters.into()),
         }))
     }
+
+    #[instrument(skip(self, request))]
+    async fn proposal_effects(
+        &self,
+        request: tonic::Request<ProposalEffectsRequest>,
+    ) -> Result<tonic::Response<ProposalEffectsResponse>, Status> {
+        let state = self.storage.latest_snapshot();
+        let proposal_id = request.into_inner().proposal_id;
+
+        let proposal = state
+            .proposal_definition(proposal_id)
+            .await
+            .map_err(|e| tonic::Status::internal(format!("unable to fetch proposal: {e}")))?
+            .ok_or_else(|| tonic::Status::not_found(format!("proposal {proposal_id} not found")))?;
+        let proposal_state = state
+            .proposal_state(proposal_id)
+            .await
+            .map_err(|e| tonic::Status::internal(format!("unable to fetch proposal state: {e}")))?
+            .ok_or_else(|| {
+                tonic::Status::not_found(format!("proposal {proposal_id} state not found"))
+            })?;
+        if !proposal_state.is_voting() {
+            return Err(tonic::Status::failed_precondition(format!(
+                "proposal {proposal_id} is no longer being voted on"
+            )));
+        }
+
+        let response = proposal_effects(state, &proposal).await.map_err(|e| {
+            tonic::Status::internal(format!("unable to simulate proposal enactment: {e:#}"))
+        })?;
+
+        Ok(tonic::Response::new(response))
+    }
 }
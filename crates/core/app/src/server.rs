@@ -21,8 +21,12 @@ pub mod snapshot;
 mod events;
 
 /// Returns a newly instantiated ABCI [`Server`], backed by the provided [`Storage`].
+///
+/// CometBFT is told it may prune all but the latest `retain_blocks` blocks; if
+/// `retain_blocks` is zero, every block is retained.
 pub fn new(
     storage: Storage,
+    retain_blocks: u64,
 ) -> Server<
     // These bounds ensure that the server can be bound to a TCP port, or a Unix socket.
     impl tower_service::Service<
@@ -50,7 +54,10 @@ pub fn new(
             req.create_span()
         }))
         .layer(EventIndexLayer::index_all())
-        .service(Consensus::new(storage.clone()));
+        .service(Consensus::with_retain_blocks(
+            storage.clone(),
+            retain_blocks,
+        ));
     let mempool = tower::ServiceBuilder::new()
         .layer(request_span::layer(|req: &MempoolRequest| {
             use penumbra_tower_trace::v037::RequestExt;
@@ -16,6 +16,7 @@ pub struct Consensus {
     queue: mpsc::Receiver<Message<Request, Response, tower::BoxError>>,
     storage: Storage,
     app: App,
+    retain_blocks: u64,
 }
 
 pub type ConsensusService = tower_actor::Actor<Request, Response, BoxError>;
@@ -35,10 +36,16 @@ impl Consensus {
     const QUEUE_SIZE: usize = 10;
 
     pub fn new(storage: Storage) -> ConsensusService {
-        tower_actor::Actor::new(Self::QUEUE_SIZE, |queue: _| {
+        Self::with_retain_blocks(storage, 0)
+    }
+
+    /// Like [`Consensus::new`], but lets CometBFT prune all but the latest
+    /// `retain_blocks` blocks. If `retain_blocks` is zero, every block is retained.
+    pub fn with_retain_blocks(storage: Storage, retain_blocks: u64) -> ConsensusService {
+        tower_actor::Actor::new(Self::QUEUE_SIZE, move |queue: _| {
             let storage = storage.clone();
             async move {
-                Consensus::new_inner(storage.clone(), queue)
+                Consensus::new_inner(storage.clone(), queue, retain_blocks)
                     .await?
                     .run()
                     .await
@@ -49,6 +56,7 @@ impl Consensus {
     async fn new_inner(
         storage: Storage,
         queue: mpsc::Receiver<Message<Request, Response, tower::BoxError>>,
+        retain_blocks: u64,
     ) -> Result<Self> {
         let app = App::new(storage.latest_snapshot()).await?;
 
@@ -56,6 +64,7 @@ impl Consensus {
             queue,
             storage,
             app,
+            retain_blocks,
         })
     }
 
@@ -245,9 +254,16 @@ impl Consensus {
         let app_hash = self.app.commit(self.storage.clone()).await;
         tracing::info!(?app_hash, "committed block");
 
+        // A retain height of zero tells CometBFT to keep every block.
+        let retain_height = if self.retain_blocks == 0 {
+            0
+        } else {
+            (self.storage.latest_version() + 1).saturating_sub(self.retain_blocks)
+        };
+
         Ok(response::Commit {
             data: app_hash.0.to_vec().into(),
-            retain_height: 0u32.into(),
+            retain_height: retain_height.try_into()?,
         })
     }
 }
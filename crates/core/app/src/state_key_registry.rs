@@ -0,0 +1,100 @@
+//! The registry of the state key prefixes used by each component of the application.
+
+use cnidarium_component::state_key_registry;
+
+state_key_registry! {
+    /// The state key prefixes used by the application and each of its components.
+    ///
+    /// Registering a component whose prefixes overlap with those of another
+    /// component fails to compile.
+    pub const STATE_KEY_PREFIXES = {
+        "application" => crate::app::state_key::PREFIXES,
+        "community_pool" => penumbra_community_pool::component::state_key::PREFIXES,
+        "compact_block" => penumbra_compact_block::state_key::PREFIXES,
+        "dex" => penumbra_dex::state_key::PREFIXES,
+        "distributions" => penumbra_distributions::component::state_key::PREFIXES,
+        "fee" => penumbra_fee::state_key::PREFIXES,
+        "funding" => penumbra_funding::component::state_key::PREFIXES,
+        "governance" => penumbra_governance::state_key::PREFIXES,
+        "ibc" => penumbra_ibc::component::state_key::PREFIXES,
+        "sct" => penumbra_sct::state_key::PREFIXES,
+        "shielded_pool" => penumbra_shielded_pool::state_key::PREFIXES,
+        "stake" => penumbra_stake::state_key::PREFIXES,
+    };
+}
+
+/// List every registered state key prefix, along with the component using it,
+/// ordered by prefix.
+pub fn known_prefixes() -> Vec<(&'static str, &'static str)> {
+    let mut prefixes: Vec<_> = STATE_KEY_PREFIXES
+        .iter()
+        .flat_map(|registered| {
+            registered
+                .prefixes
+                .iter()
+                .map(|&prefix| (prefix, registered.component))
+        })
+        .collect();
+    prefixes.sort_unstable();
+    prefixes
+}
+
+/// Returns the component whose state holds the given key, if the key has a
+/// registered prefix.
+pub fn component_for_key(key: &str) -> Option<&'static str> {
+    // Registered prefixes of different components never overlap, so at most
+    // one component can match.
+    STATE_KEY_PREFIXES
+        .iter()
+        .find(|registered| {
+            registered
+                .prefixes
+                .iter()
+                .any(|prefix| key.starts_with(prefix))
+        })
+        .map(|registered| registered.component)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn substores_are_registered() {
+        for substore in crate::SUBSTORE_PREFIXES.iter() {
+            let key = format!("{substore}/key");
+            assert!(
+                component_for_key(&key).is_some(),
+                "substore {substore} has no registered prefix"
+            );
+        }
+    }
+
+    #[test]
+    fn keys_belong_to_their_components() {
+        assert_eq!(
+            component_for_key(penumbra_dex::state_key::all_positions()),
+            Some("dex")
+        );
+        assert_eq!(
+            component_for_key(penumbra_stake::state_key::parameters::key()),
+            Some("stake")
+        );
+        assert_eq!(
+            component_for_key(&penumbra_governance::state_key::change_app_params_at_height(1)),
+            Some("governance")
+        );
+        assert_eq!(
+            component_for_key(crate::app::state_key::data::chain_id()),
+            Some("application")
+        );
+        assert_eq!(component_for_key("unregistered/key"), None);
+
+        assert_eq!(known_prefixes().len(), {
+            STATE_KEY_PREFIXES
+                .iter()
+                .map(|registered| registered.prefixes.len())
+                .sum::<usize>()
+        });
+    }
+}
@@ -0,0 +1,137 @@
+mod common;
+
+use self::common::TempStorageExt;
+use cnidarium::{Snapshot, StateDelta, TempStorage};
+use ibc_types::core::commitment::MerkleProof;
+use penumbra_keys::test_keys;
+use penumbra_mock_client::MockClient;
+use penumbra_num::Amount;
+use penumbra_proto::{DomainType, StateWriteProto as _};
+use penumbra_sct::{state_key as sct_state_key, NullificationInfo, Nullifier};
+use penumbra_shielded_pool::{
+    attestation::{FundsAttestation, NoteToAttest},
+    Note,
+};
+use rand_core::SeedableRng;
+
+/// Fetch a key from the snapshot, with a proof of its value or absence.
+async fn state_proof(snapshot: &Snapshot, key: String) -> anyhow::Result<MerkleProof> {
+    let (_value, proof) = snapshot.get_with_proof(key.into_bytes()).await?;
+    Ok(proof)
+}
+
+/// Attest to the given note held by the test keys, against the snapshot.
+async fn attest(
+    snapshot: &Snapshot,
+    client: &MockClient,
+    note: &Note,
+    context: &[u8],
+) -> anyhow::Result<FundsAttestation> {
+    let mut rng = rand_chacha::ChaChaRng::seed_from_u64(1312);
+    let state_commitment_proof = client.sct.witness(note.commit()).unwrap();
+    let nullifier = Nullifier::derive(
+        test_keys::FULL_VIEWING_KEY.nullifier_key(),
+        state_commitment_proof.position(),
+        &note.commit(),
+    );
+    let unspent_proof = state_proof(
+        snapshot,
+        sct_state_key::nullifier_set::spent_nullifier_lookup(&nullifier),
+    )
+    .await?;
+    let anchor_proof = state_proof(snapshot, sct_state_key::tree::anchor_by_height(0)).await?;
+
+    FundsAttestation::prove(
+        &mut rng,
+        &test_keys::FULL_VIEWING_KEY,
+        context,
+        0,
+        client.sct.root(),
+        anchor_proof,
+        vec![NoteToAttest {
+            note: note.clone(),
+            state_commitment_proof,
+            unspent_proof,
+        }],
+    )
+}
+
+#[tokio::test]
+async fn funds_attestation_verifies_only_as_made() -> anyhow::Result<()> {
+    let storage = TempStorage::new().await?.apply_default_genesis().await?;
+    let snapshot = storage.latest_snapshot();
+    let app_hash = snapshot.root_hash().await?.0;
+
+    // Precondition: This test uses the default genesis which has existing notes for the test keys.
+    let mut client = MockClient::new(test_keys::SPEND_KEY.clone());
+    client.sync_to(0, snapshot.clone()).await?;
+    let note = client.notes.values().next().unwrap().clone();
+
+    let attestation = attest(&snapshot, &client, &note, b"audit").await?;
+    assert_eq!(attestation.verify(b"audit", &app_hash)?, note.amount());
+
+    // The attestation survives encoding.
+    let decoded = FundsAttestation::decode(attestation.encode_to_vec().as_slice())?;
+    assert_eq!(decoded.verify(b"audit", &app_hash)?, note.amount());
+
+    // It can't be replayed to a verifier with a different context...
+    assert!(attestation.verify(b"another audit", &app_hash).is_err());
+    // ... or checked against another state.
+    assert!(attestation.verify(b"audit", &[0u8; 32]).is_err());
+
+    // Tampering with the attested amount, height or anchor is detected.
+    let mut inflated = attestation.clone();
+    inflated.notes[0].amount = note.amount() + Amount::from(1u64);
+    assert!(inflated.verify(b"audit", &app_hash).is_err());
+
+    let mut moved = attestation.clone();
+    moved.height = 1;
+    assert!(moved.verify(b"audit", &app_hash).is_err());
+
+    let mut reanchored = attestation.clone();
+    reanchored.anchor = penumbra_tct::Tree::new().root();
+    assert!(reanchored.verify(b"audit", &app_hash).is_err());
+
+    // Attesting the same note twice doesn't double its amount.
+    let mut doubled = attestation.clone();
+    doubled.notes.push(attestation.notes[0].clone());
+    assert!(doubled.verify(b"audit", &app_hash).is_err());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn spent_notes_cannot_be_attested() -> anyhow::Result<()> {
+    let storage = TempStorage::new().await?.apply_default_genesis().await?;
+
+    let mut client = MockClient::new(test_keys::SPEND_KEY.clone());
+    client.sync_to(0, storage.latest_snapshot()).await?;
+    let note = client.notes.values().next().unwrap().clone();
+    let mut stale = attest(&storage.latest_snapshot(), &client, &note, b"audit").await?;
+
+    // Spend the note.
+    let mut state = StateDelta::new(storage.latest_snapshot());
+    state.put(
+        sct_state_key::nullifier_set::spent_nullifier_lookup(&stale.notes[0].nullifier),
+        NullificationInfo {
+            id: [0; 32],
+            spend_height: 1,
+        },
+    );
+    storage.commit(state).await?;
+    let snapshot = storage.latest_snapshot();
+    let app_hash = snapshot.root_hash().await?.0;
+
+    // The earlier proof that the nullifier is unspent doesn't hold in the later state, even
+    // with the anchor proven against it...
+    stale.anchor_proof = state_proof(&snapshot, sct_state_key::tree::anchor_by_height(0)).await?;
+    let error = stale.verify(b"audit", &app_hash).unwrap_err();
+    assert!(format!("{error:#}").contains("may have been spent"));
+
+    // ... and a fresh attestation can only prove that it was spent.
+    let fresh = attest(&snapshot, &client, &note, b"audit").await?;
+    let error = fresh.verify(b"audit", &app_hash).unwrap_err();
+    assert!(format!("{error:#}").contains("may have been spent"));
+
+    Ok(())
+}
@@ -60,8 +60,15 @@ async fn swap_and_swap_claim() -> anyhow::Result<()> {
     let fee = Fee::default();
     let claim_address: Address = *test_keys::ADDRESS_0;
 
-    let plaintext =
-        SwapPlaintext::new(&mut rng, trading_pair, delta_1, delta_2, fee, claim_address);
+    let plaintext = SwapPlaintext::new(
+        &mut rng,
+        trading_pair,
+        delta_1,
+        delta_2,
+        fee,
+        claim_address,
+        test_keys::FULL_VIEWING_KEY.nullifier_key(),
+    );
 
     let swap_plan = SwapPlan::new(&mut rng, plaintext.clone());
     let swap = swap_plan.swap(&test_keys::FULL_VIEWING_KEY);
@@ -112,6 +119,7 @@ async fn swap_and_swap_claim() -> anyhow::Result<()> {
         position: swap_auth_path.position(),
         output_data,
         epoch_duration,
+        claim_key: None,
         proof_blinding_r: Fq::rand(&mut rng),
         proof_blinding_s: Fq::rand(&mut rng),
     };
@@ -175,8 +183,15 @@ async fn swap_claim_duplicate_nullifier_previous_transaction() {
     let fee = Fee::default();
     let claim_address: Address = *test_keys::ADDRESS_0;
 
-    let plaintext =
-        SwapPlaintext::new(&mut rng, trading_pair, delta_1, delta_2, fee, claim_address);
+    let plaintext = SwapPlaintext::new(
+        &mut rng,
+        trading_pair,
+        delta_1,
+        delta_2,
+        fee,
+        claim_address,
+        test_keys::FULL_VIEWING_KEY.nullifier_key(),
+    );
 
     let swap_plan = SwapPlan::new(&mut rng, plaintext.clone());
     let swap = swap_plan.swap(&test_keys::FULL_VIEWING_KEY);
@@ -226,6 +241,7 @@ async fn swap_claim_duplicate_nullifier_previous_transaction() {
         position: swap_auth_path.position(),
         output_data,
         epoch_duration,
+        claim_key: None,
         proof_blinding_r: Fq::rand(&mut rng),
         proof_blinding_s: Fq::rand(&mut rng),
     };
@@ -253,6 +269,7 @@ async fn swap_claim_duplicate_nullifier_previous_transaction() {
         position: swap_auth_path.position(),
         output_data,
         epoch_duration,
+        claim_key: None,
         proof_blinding_r: Fq::rand(&mut rng),
         proof_blinding_s: Fq::rand(&mut rng),
     };
@@ -295,8 +312,15 @@ async fn swap_with_nonzero_fee() -> anyhow::Result<()> {
     let fee = Fee::from_staking_token_amount(Amount::from(1u64));
     let claim_address: Address = *test_keys::ADDRESS_0;
 
-    let plaintext =
-        SwapPlaintext::new(&mut rng, trading_pair, delta_1, delta_2, fee, claim_address);
+    let plaintext = SwapPlaintext::new(
+        &mut rng,
+        trading_pair,
+        delta_1,
+        delta_2,
+        fee,
+        claim_address,
+        test_keys::FULL_VIEWING_KEY.nullifier_key(),
+    );
 
     let swap_plan = SwapPlan::new(&mut rng, plaintext.clone());
     let swap = swap_plan.swap(&test_keys::FULL_VIEWING_KEY);
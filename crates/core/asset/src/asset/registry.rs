@@ -389,6 +389,16 @@ pub static REGISTRY: Lazy<Registry> = Lazy::new(|| {
                 denom_metadata::Inner::new(format!("lpnft_{data}"), vec![])
             }) as for<'r> fn(&'r str) -> _,
         )
+        .add_asset(
+            // Note: this regex must be in sync with AuctionNft::try_from
+            // and the bech32 prefix for auction IDs defined in the proto crate.
+            "^auctionnft_(?P<data>[0-9]+_pauctid1[a-zA-HJ-NP-Z0-9]+)$",
+            &[ /* no display units - nft, unit 1 */ ],
+            (|data: &str| {
+                assert!(!data.is_empty());
+                denom_metadata::Inner::new(format!("auctionnft_{data}"), vec![])
+            }) as for<'r> fn(&'r str) -> _,
+        )
         .add_asset(
             // Note: this regex must be in sync with ProposalNft::try_from
             "^proposal_(?P<data>(?P<proposal_id>[0-9]+)_(?P<proposal_state>deposit|unbonding_deposit|passed|failed|slashed))$",
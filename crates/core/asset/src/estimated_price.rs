@@ -16,6 +16,9 @@ pub struct EstimatedPrice {
     pub numeraire: asset::Id,
     /// Multiply units of the priced asset by this number to get the value in the numeraire.
     ///
+    /// Both are measured in their display units, so that prices don't depend on the number of
+    /// decimals of each asset.
+    ///
     /// This is a floating-point number since the price is approximate.
     pub numeraire_per_unit: f64,
     /// If nonzero, gives some idea of when the price was estimated (in terms of block height).
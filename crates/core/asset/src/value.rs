@@ -74,14 +74,18 @@ impl ValueView {
                     if metadata.id() == price.priced_asset
                         && known_metadata.contains_key(&price.numeraire)
                     {
-                        let equivalent_amount_f =
-                            (amount.value() as f64) * price.numeraire_per_unit;
+                        let numeraire = known_metadata
+                            .get(&price.numeraire)
+                            .expect("we checked containment above");
+                        // Prices are quoted in display units, but amounts are in base units.
+                        let exponent = i32::from(numeraire.default_unit().exponent())
+                            - i32::from(metadata.default_unit().exponent());
+                        let equivalent_amount_f = (amount.value() as f64)
+                            * price.numeraire_per_unit
+                            * 10f64.powi(exponent);
                         Some(EquivalentValue {
                             equivalent_amount: Amount::from(equivalent_amount_f as u128),
-                            numeraire: known_metadata
-                                .get(&price.numeraire)
-                                .expect("we checked containment above")
-                                .clone(),
+                            numeraire: numeraire.clone(),
                             as_of_height: price.as_of_height,
                         })
                     } else {
@@ -377,7 +381,7 @@ impl FromStr for Value {
             let asset_id =
                 Id::from_str(asset_id_str).context("unable to parse string value's asset ID")?;
             let amount = numeric_str
-                .parse::<u64>()
+                .parse::<u128>()
                 .context("unable to parse string value's numeric amount")?;
 
             Ok(Value {
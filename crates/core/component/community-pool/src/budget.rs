@@ -0,0 +1,154 @@
+use anyhow::Context;
+use penumbra_asset::Value;
+use penumbra_keys::Address;
+use penumbra_num::Amount;
+use penumbra_proto::{core::component::community_pool::v1 as pb, DomainType};
+use serde::{Deserialize, Serialize};
+
+/// A recurring budget line, paying a fixed amount from the Community Pool to a
+/// recipient at the end of each epoch, for a fixed number of epochs.
+///
+/// Budget lines are established by governance proposals, and identified by the
+/// ID of the proposal which established them.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(try_from = "pb::BudgetLine", into = "pb::BudgetLine")]
+pub struct BudgetLine {
+    /// The address paid by the budget line.
+    pub recipient: Address,
+    /// The value paid at the end of each epoch.
+    pub amount_per_epoch: Value,
+    /// The number of epochs the budget line lasts for.
+    pub epochs: u64,
+    /// Whether the budget line is cancelled the first time the Community Pool
+    /// can't pay it, rather than skipping that epoch's payment.
+    pub cancel_if_underfunded: bool,
+}
+
+impl BudgetLine {
+    /// Check that the budget line pays something, for at least one epoch.
+    pub fn check_stateless(&self) -> anyhow::Result<()> {
+        anyhow::ensure!(
+            self.amount_per_epoch.amount > Amount::zero(),
+            "budget line must pay a nonzero amount per epoch"
+        );
+        anyhow::ensure!(self.epochs > 0, "budget line must last at least one epoch");
+        anyhow::ensure!(
+            self.total().is_some(),
+            "total paid by budget line overflows an amount"
+        );
+        Ok(())
+    }
+
+    /// The total amount the budget line pays over its duration, if it is paid
+    /// in every epoch.
+    pub fn total(&self) -> Option<Amount> {
+        self.amount_per_epoch
+            .amount
+            .value()
+            .checked_mul(self.epochs.into())
+            .map(Amount::from)
+    }
+}
+
+/// The state of an active budget line.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(try_from = "pb::BudgetLineState", into = "pb::BudgetLineState")]
+pub struct BudgetLineState {
+    /// The budget line's identifier, which is the ID of the proposal
+    /// establishing it.
+    pub id: u64,
+    /// The terms of the budget line.
+    pub budget_line: BudgetLine,
+    /// The number of epochs which have ended since the budget line was
+    /// established, including those whose payment was skipped.
+    pub epochs_elapsed: u64,
+    /// The total amount paid by the budget line so far.
+    pub spent: Amount,
+}
+
+impl BudgetLineState {
+    /// The state of a newly established budget line.
+    pub fn new(id: u64, budget_line: BudgetLine) -> Self {
+        Self {
+            id,
+            budget_line,
+            epochs_elapsed: 0,
+            spent: Amount::zero(),
+        }
+    }
+
+    /// Whether the budget line has run for all of its epochs.
+    pub fn is_complete(&self) -> bool {
+        self.epochs_elapsed >= self.budget_line.epochs
+    }
+}
+
+impl DomainType for BudgetLine {
+    type Proto = pb::BudgetLine;
+}
+
+impl From<BudgetLine> for pb::BudgetLine {
+    fn from(line: BudgetLine) -> Self {
+        pb::BudgetLine {
+            recipient: Some(line.recipient.into()),
+            amount_per_epoch: Some(line.amount_per_epoch.into()),
+            epochs: line.epochs,
+            cancel_if_underfunded: line.cancel_if_underfunded,
+        }
+    }
+}
+
+impl TryFrom<pb::BudgetLine> for BudgetLine {
+    type Error = anyhow::Error;
+
+    fn try_from(proto: pb::BudgetLine) -> anyhow::Result<Self> {
+        Ok(BudgetLine {
+            recipient: proto
+                .recipient
+                .ok_or_else(|| anyhow::anyhow!("missing recipient"))?
+                .try_into()
+                .context("malformed recipient")?,
+            amount_per_epoch: proto
+                .amount_per_epoch
+                .ok_or_else(|| anyhow::anyhow!("missing amount per epoch"))?
+                .try_into()
+                .context("malformed amount per epoch")?,
+            epochs: proto.epochs,
+            cancel_if_underfunded: proto.cancel_if_underfunded,
+        })
+    }
+}
+
+impl DomainType for BudgetLineState {
+    type Proto = pb::BudgetLineState;
+}
+
+impl From<BudgetLineState> for pb::BudgetLineState {
+    fn from(state: BudgetLineState) -> Self {
+        pb::BudgetLineState {
+            id: state.id,
+            budget_line: Some(state.budget_line.into()),
+            epochs_elapsed: state.epochs_elapsed,
+            spent: Some(state.spent.into()),
+        }
+    }
+}
+
+impl TryFrom<pb::BudgetLineState> for BudgetLineState {
+    type Error = anyhow::Error;
+
+    fn try_from(proto: pb::BudgetLineState) -> anyhow::Result<Self> {
+        Ok(BudgetLineState {
+            id: proto.id,
+            budget_line: proto
+                .budget_line
+                .ok_or_else(|| anyhow::anyhow!("missing budget line"))?
+                .try_into()?,
+            epochs_elapsed: proto.epochs_elapsed,
+            spent: proto
+                .spent
+                .ok_or_else(|| anyhow::anyhow!("missing spent amount"))?
+                .try_into()?,
+        })
+    }
+}
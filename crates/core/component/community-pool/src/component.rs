@@ -1,6 +1,8 @@
-/// The Community Pool is a thin component that doesn't have any logic of its own, except for initializing
-/// its state and performing post-upgrade checks. It is primarily a collection of state that is modified by
-/// [`CommunityPoolSpend`] and [`CommunityPoolDeposit`] actions.
+/// The Community Pool is a thin component whose only logic of its own, besides initializing its state
+/// and performing post-upgrade checks, is paying recurring budget lines at the end of each epoch. It
+/// is primarily a collection of state that is modified by [`CommunityPoolSpend`] and
+/// [`CommunityPoolDeposit`] actions.
+pub mod rpc;
 pub mod state_key;
 
 mod action_handler;
@@ -47,8 +49,9 @@ impl Component for CommunityPool {
     ) {
     }
 
-    #[instrument(name = "community_pool", skip(_state))]
-    async fn end_epoch<S: StateWrite + 'static>(_state: &mut Arc<S>) -> anyhow::Result<()> {
-        Ok(())
+    #[instrument(name = "community_pool", skip(state))]
+    async fn end_epoch<S: StateWrite + 'static>(state: &mut Arc<S>) -> anyhow::Result<()> {
+        let state = Arc::get_mut(state).expect("state should be unique");
+        state.disburse_budget_lines().await
     }
 }
@@ -0,0 +1,98 @@
+use std::pin::Pin;
+
+use async_trait::async_trait;
+use cnidarium::Storage;
+use futures::StreamExt;
+use penumbra_asset::{asset, Value};
+use penumbra_proto::core::component::community_pool::v1::{
+    self as pb, query_service_server::QueryService,
+};
+use tonic::Status;
+use tracing::instrument;
+
+use super::StateReadExt;
+
+// TODO: Hide this and only expose a Router?
+pub struct Server {
+    storage: Storage,
+}
+
+impl Server {
+    pub fn new(storage: Storage) -> Self {
+        Self { storage }
+    }
+}
+
+#[async_trait]
+impl QueryService for Server {
+    type CommunityPoolAssetBalancesStream = Pin<
+        Box<
+            dyn futures::Stream<Item = Result<pb::CommunityPoolAssetBalancesResponse, Status>>
+                + Send,
+        >,
+    >;
+
+    #[instrument(skip(self, request))]
+    async fn community_pool_asset_balances(
+        &self,
+        request: tonic::Request<pb::CommunityPoolAssetBalancesRequest>,
+    ) -> Result<tonic::Response<Self::CommunityPoolAssetBalancesStream>, Status> {
+        let state = self.storage.latest_snapshot();
+
+        let asset_ids = request
+            .into_inner()
+            .asset_ids
+            .into_iter()
+            .map(asset::Id::try_from)
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|e| Status::invalid_argument(format!("invalid asset id: {e}")))?;
+
+        let balances = state
+            .community_pool_balance()
+            .await
+            .map_err(|e| Status::internal(format!("unable to fetch balances: {e}")))?;
+
+        let responses = balances
+            .into_iter()
+            .filter(|(asset_id, _)| asset_ids.is_empty() || asset_ids.contains(asset_id))
+            .map(|(asset_id, amount)| {
+                Ok(pb::CommunityPoolAssetBalancesResponse {
+                    balance: Some(Value { amount, asset_id }.into()),
+                })
+            })
+            .collect::<Vec<_>>();
+
+        Ok(tonic::Response::new(
+            futures::stream::iter(responses).boxed(),
+        ))
+    }
+
+    type BudgetLinesStream =
+        Pin<Box<dyn futures::Stream<Item = Result<pb::BudgetLinesResponse, Status>> + Send>>;
+
+    #[instrument(skip(self, _request))]
+    async fn budget_lines(
+        &self,
+        _request: tonic::Request<pb::BudgetLinesRequest>,
+    ) -> Result<tonic::Response<Self::BudgetLinesStream>, Status> {
+        let state = self.storage.latest_snapshot();
+
+        let budget_lines = state
+            .budget_lines()
+            .await
+            .map_err(|e| Status::internal(format!("unable to fetch budget lines: {e}")))?;
+
+        let responses = budget_lines
+            .into_iter()
+            .map(|budget_line| {
+                Ok(pb::BudgetLinesResponse {
+                    budget_line: Some(budget_line.into()),
+                })
+            })
+            .collect::<Vec<_>>();
+
+        Ok(tonic::Response::new(
+            futures::stream::iter(responses).boxed(),
+        ))
+    }
+}
@@ -1,5 +1,9 @@
 use penumbra_asset::asset;
 
+/// The prefixes of all of the component's state keys, for the application's
+/// state key registry.
+pub const PREFIXES: &[&str] = &["community_pool/"];
+
 pub fn community_pool_params() -> &'static str {
     "community_pool/params"
 }
@@ -16,3 +20,12 @@ pub fn all_assets_balance() -> &'static str {
     // note: this must be the prefix of the above.
     "community_pool/asset/"
 }
+
+pub fn budget_line(id: u64) -> String {
+    format!("community_pool/budget_line/{id:020}")
+}
+
+pub fn all_budget_lines() -> &'static str {
+    // note: this must be the prefix of the above.
+    "community_pool/budget_line/"
+}
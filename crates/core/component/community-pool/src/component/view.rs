@@ -8,8 +8,14 @@ use futures::{StreamExt, TryStreamExt};
 use penumbra_asset::{asset, Value};
 use penumbra_num::Amount;
 use penumbra_proto::{StateReadProto, StateWriteProto};
+use penumbra_sct::CommitmentSource;
+use penumbra_shielded_pool::component::NoteManager;
 
-use crate::params::CommunityPoolParameters;
+use crate::{
+    budget::{BudgetLine, BudgetLineState},
+    event,
+    params::CommunityPoolParameters,
+};
 
 use super::state_key;
 
@@ -47,6 +53,19 @@ pub trait StateReadExt: StateRead {
             .try_collect()
             .await
     }
+
+    /// Gets the state of the active budget line with the given ID, if there is one.
+    async fn budget_line(&self, id: u64) -> Result<Option<BudgetLineState>> {
+        self.get(&state_key::budget_line(id)).await
+    }
+
+    /// Gets the states of all active budget lines, in order of their IDs.
+    async fn budget_lines(&self) -> Result<Vec<BudgetLineState>> {
+        self.prefix(state_key::all_budget_lines())
+            .map_ok(|(_, budget_line)| budget_line)
+            .try_collect()
+            .await
+    }
 }
 
 impl<T> StateReadExt for T where T: StateRead + ?Sized {}
@@ -87,6 +106,219 @@ pub trait StateWriteExt: StateWrite {
         }
         Ok(())
     }
+
+    /// Establishes a budget line with the given ID, to be paid from the end of the current epoch.
+    fn establish_budget_line(&mut self, id: u64, budget_line: BudgetLine) {
+        self.put(
+            state_key::budget_line(id),
+            BudgetLineState::new(id, budget_line),
+        );
+    }
+
+    /// Cancels the active budget line with the given ID, returning its final state, or `None` if
+    /// there is no such budget line.
+    async fn cancel_budget_line(&mut self, id: u64) -> Result<Option<BudgetLineState>> {
+        let Some(budget_line) = self.budget_line(id).await? else {
+            return Ok(None);
+        };
+        self.delete(state_key::budget_line(id));
+        self.record_proto(event::budget_line_ended(&budget_line, false));
+        Ok(Some(budget_line))
+    }
+
+    /// Pays every active budget line its amount for the epoch which just ended, in order of their
+    /// IDs, so that older budget lines are paid first when the Community Pool can't pay them all.
+    ///
+    /// A budget line's amount per epoch caps what it can withdraw in any one epoch: a payment
+    /// skipped for lack of funds is not made up in later epochs. Budget lines are removed once
+    /// they have run for all of their epochs, or the first time they can't be paid, if they are
+    /// cancelled when underfunded.
+    ///
+    /// While Community Pool spends are disabled, budget lines are paused: nothing is paid, and the
+    /// epoch doesn't count towards their duration.
+    async fn disburse_budget_lines(&mut self) -> Result<()> {
+        if !self
+            .get_community_pool_params()
+            .await?
+            .community_pool_spend_proposals_enabled
+        {
+            tracing::info!("community pool spends are disabled, pausing budget lines");
+            return Ok(());
+        }
+
+        for mut budget_line in self.budget_lines().await? {
+            let id = budget_line.id;
+            let BudgetLine {
+                recipient,
+                amount_per_epoch,
+                cancel_if_underfunded,
+                ..
+            } = budget_line.budget_line;
+            budget_line.epochs_elapsed += 1;
+
+            let balance = self
+                .community_pool_asset_balance(amount_per_epoch.asset_id)
+                .await?;
+            if balance >= amount_per_epoch.amount {
+                self.community_pool_withdraw(amount_per_epoch).await?;
+                self.mint_note(
+                    amount_per_epoch,
+                    &recipient,
+                    CommitmentSource::CommunityPoolOutput,
+                )
+                .await?;
+                budget_line.spent = budget_line.spent.saturating_add(&amount_per_epoch.amount);
+                self.record_proto(event::budget_disbursement(id, &recipient, amount_per_epoch));
+            } else if cancel_if_underfunded {
+                tracing::info!(
+                    id,
+                    %balance,
+                    "cancelling budget line, community pool can't pay it"
+                );
+                self.delete(state_key::budget_line(id));
+                self.record_proto(event::budget_line_ended(&budget_line, false));
+                continue;
+            } else {
+                tracing::warn!(
+                    id,
+                    %balance,
+                    "skipping budget line payment, community pool can't pay it"
+                );
+            }
+
+            if budget_line.is_complete() {
+                self.delete(state_key::budget_line(id));
+                self.record_proto(event::budget_line_ended(&budget_line, true));
+            } else {
+                self.put(state_key::budget_line(id), budget_line);
+            }
+        }
+        Ok(())
+    }
 }
 
 impl<T> StateWriteExt for T where T: StateWrite + ?Sized {}
+
+#[cfg(test)]
+mod tests {
+    use cnidarium::{StateDelta, TempStorage};
+    use penumbra_asset::STAKING_TOKEN_ASSET_ID;
+    use penumbra_keys::test_keys;
+
+    use super::*;
+
+    fn upenumbra(amount: u64) -> Value {
+        Value {
+            amount: amount.into(),
+            asset_id: *STAKING_TOKEN_ASSET_ID,
+        }
+    }
+
+    fn budget_line(amount_per_epoch: u64, epochs: u64, cancel_if_underfunded: bool) -> BudgetLine {
+        BudgetLine {
+            recipient: *test_keys::ADDRESS_0,
+            amount_per_epoch: upenumbra(amount_per_epoch),
+            epochs,
+            cancel_if_underfunded,
+        }
+    }
+
+    async fn funded_state(
+        storage: &TempStorage,
+        balance: u64,
+    ) -> Result<StateDelta<cnidarium::Snapshot>> {
+        let mut state = StateDelta::new(storage.latest_snapshot());
+        state.put_community_pool_params(CommunityPoolParameters::default());
+        state.community_pool_deposit(upenumbra(balance)).await?;
+        Ok(state)
+    }
+
+    async fn balance<S: StateRead>(state: &S) -> Result<Amount> {
+        state
+            .community_pool_asset_balance(*STAKING_TOKEN_ASSET_ID)
+            .await
+    }
+
+    #[tokio::test]
+    async fn budget_lines_are_paid_each_epoch_until_complete() -> Result<()> {
+        let storage = TempStorage::new().await?;
+        let mut state = funded_state(&storage, 250).await?;
+        state.establish_budget_line(1, budget_line(100, 2, false));
+
+        state.disburse_budget_lines().await?;
+        assert_eq!(balance(&state).await?, 150u64.into());
+        let line = state.budget_line(1).await?.expect("budget line is active");
+        assert_eq!(line.epochs_elapsed, 1);
+        assert_eq!(line.spent, 100u64.into());
+
+        state.disburse_budget_lines().await?;
+        assert_eq!(balance(&state).await?, 50u64.into());
+        assert!(state.budget_line(1).await?.is_none());
+
+        // A completed budget line is never paid again.
+        state.disburse_budget_lines().await?;
+        assert_eq!(balance(&state).await?, 50u64.into());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn underfunded_budget_lines_are_skipped_or_cancelled() -> Result<()> {
+        let storage = TempStorage::new().await?;
+        let mut state = funded_state(&storage, 150).await?;
+        state.establish_budget_line(1, budget_line(100, 3, false));
+        state.establish_budget_line(2, budget_line(100, 3, true));
+        state.establish_budget_line(3, budget_line(100, 3, false));
+
+        // The oldest budget line is paid first, leaving too little for the others.
+        state.disburse_budget_lines().await?;
+        assert_eq!(balance(&state).await?, 50u64.into());
+        assert_eq!(
+            state.budget_line(1).await?.map(|line| line.spent),
+            Some(100u64.into())
+        );
+        assert!(state.budget_line(2).await?.is_none());
+        let skipped = state.budget_line(3).await?.expect("budget line is active");
+        assert_eq!(skipped.epochs_elapsed, 1);
+        assert_eq!(skipped.spent, Amount::zero());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn cancelled_budget_lines_are_no_longer_paid() -> Result<()> {
+        let storage = TempStorage::new().await?;
+        let mut state = funded_state(&storage, 500).await?;
+        state.establish_budget_line(1, budget_line(100, 3, false));
+
+        state.disburse_budget_lines().await?;
+        let cancelled = state
+            .cancel_budget_line(1)
+            .await?
+            .expect("budget line is active");
+        assert_eq!(cancelled.spent, 100u64.into());
+        assert!(state.cancel_budget_line(1).await?.is_none());
+
+        state.disburse_budget_lines().await?;
+        assert_eq!(balance(&state).await?, 400u64.into());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn budget_lines_pause_while_spends_are_disabled() -> Result<()> {
+        let storage = TempStorage::new().await?;
+        let mut state = funded_state(&storage, 500).await?;
+        state.establish_budget_line(1, budget_line(100, 3, false));
+        state.put_community_pool_params(CommunityPoolParameters {
+            community_pool_spend_proposals_enabled: false,
+        });
+
+        state.disburse_budget_lines().await?;
+        assert_eq!(balance(&state).await?, 500u64.into());
+        let paused = state.budget_line(1).await?.expect("budget line is active");
+        assert_eq!(paused.epochs_elapsed, 0);
+
+        state.put_community_pool_params(CommunityPoolParameters::default());
+        state.disburse_budget_lines().await?;
+        assert_eq!(balance(&state).await?, 400u64.into());
+        Ok(())
+    }
+}
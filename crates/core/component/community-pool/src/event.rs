@@ -1,5 +1,33 @@
+use penumbra_asset::Value;
+use penumbra_keys::Address;
+use penumbra_proto::core::component::community_pool::v1 as pb;
+
+use crate::budget::BudgetLineState;
+
 // TODO: events for
 //
 // - Community Pool spend
 // - Community Pool deposit
 // ?
+
+pub fn budget_disbursement(
+    id: u64,
+    recipient: &Address,
+    value: Value,
+) -> pb::EventBudgetDisbursement {
+    pb::EventBudgetDisbursement {
+        id,
+        recipient: Some((*recipient).into()),
+        value: Some(value.into()),
+    }
+}
+
+pub fn budget_line_ended(
+    budget_line: &BudgetLineState,
+    completed: bool,
+) -> pb::EventBudgetLineEnded {
+    pb::EventBudgetLineEnded {
+        budget_line: Some(budget_line.clone().into()),
+        completed,
+    }
+}
@@ -3,10 +3,12 @@
 #[cfg(feature = "component")]
 pub mod component;
 
+pub mod budget;
 pub mod event;
 
 mod action;
 pub use action::{CommunityPoolDeposit, CommunityPoolOutput, CommunityPoolSpend};
+pub use budget::{BudgetLine, BudgetLineState};
 
 pub mod genesis;
 pub mod params;
@@ -1,6 +1,7 @@
 use std::{collections::BTreeMap, convert::TryFrom};
 
 use anyhow::Result;
+use decaf377_fmd::{Clue, DetectionKey};
 use penumbra_dex::{BatchSwapOutputData, TradingPair};
 use penumbra_fee::GasPrices;
 use penumbra_proto::{
@@ -9,7 +10,10 @@ use penumbra_proto::{
 };
 use penumbra_sct::Nullifier;
 use penumbra_shielded_pool::fmd;
-use penumbra_tct::builder::{block, epoch};
+use penumbra_tct::{
+    builder::{block, epoch},
+    StateCommitment,
+};
 use serde::{Deserialize, Serialize};
 
 use super::StatePayload;
@@ -71,6 +75,33 @@ impl CompactBlock {
             || self.app_parameters_updated // need to save latest app parameters
             || self.gas_prices.is_some() // need to save latest gas prices
     }
+
+    /// Replace the payloads of notes which none of the given detection keys
+    /// detect with rolled-up payloads, using the clues attached to the notes.
+    ///
+    /// Notes without a clue, such as those minted by the chain itself, are
+    /// always kept, as are swap payloads.
+    pub fn retain_detected(
+        &mut self,
+        clues: &BTreeMap<StateCommitment, Clue>,
+        detection_keys: &[DetectionKey],
+    ) {
+        for payload in self.state_payloads.iter_mut() {
+            let rolled_up = match &*payload {
+                StatePayload::Note { source, note } => match clues.get(&note.note_commitment) {
+                    Some(clue) if !detection_keys.iter().any(|dtk| dtk.examine(clue)) => {
+                        StatePayload::RolledUp {
+                            source: source.clone(),
+                            commitment: note.note_commitment,
+                        }
+                    }
+                    _ => continue,
+                },
+                _ => continue,
+            };
+            *payload = rolled_up;
+        }
+    }
 }
 
 impl DomainType for CompactBlock {
@@ -154,3 +185,79 @@ impl TryFrom<CompactBlockRangeResponse> for CompactBlock {
             .try_into()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use decaf377_fmd::MAX_PRECISION;
+    use penumbra_asset::{Value, STAKING_TOKEN_ASSET_ID};
+    use penumbra_keys::test_keys;
+    use penumbra_sct::CommitmentSource;
+    use penumbra_shielded_pool::Note;
+    use rand_core::OsRng;
+
+    use super::*;
+
+    fn note_payload() -> StatePayload {
+        let note = Note::generate(
+            &mut OsRng,
+            &test_keys::ADDRESS_0,
+            Value {
+                amount: 1u64.into(),
+                asset_id: *STAKING_TOKEN_ASSET_ID,
+            },
+        );
+        StatePayload::Note {
+            source: CommitmentSource::transaction(),
+            note: Box::new(note.payload()),
+        }
+    }
+
+    fn is_rolled_up(payload: &StatePayload) -> bool {
+        matches!(payload, StatePayload::RolledUp { .. })
+    }
+
+    #[test]
+    fn only_notes_detected_by_some_key_are_retained() {
+        let ours = DetectionKey::new(OsRng);
+        let theirs = DetectionKey::new(OsRng);
+        let unknown = DetectionKey::new(OsRng);
+
+        let mut compact_block = CompactBlock {
+            state_payloads: vec![note_payload(), note_payload(), note_payload()],
+            ..Default::default()
+        };
+        let commitments: Vec<_> = compact_block
+            .state_payloads
+            .iter()
+            .map(|payload| *payload.commitment())
+            .collect();
+
+        // The first note is for us, the second for someone else, and the
+        // third, like notes minted by the chain, has no clue.
+        let clue = |dtk: &DetectionKey| {
+            dtk.clue_key()
+                .expand()
+                .unwrap()
+                .create_clue(MAX_PRECISION, OsRng)
+                .unwrap()
+        };
+        let clues = BTreeMap::from([
+            (commitments[0], clue(&ours)),
+            (commitments[1], clue(&theirs)),
+        ]);
+
+        compact_block.retain_detected(&clues, &[ours, unknown]);
+        assert!(!is_rolled_up(&compact_block.state_payloads[0]));
+        assert!(is_rolled_up(&compact_block.state_payloads[1]));
+        assert!(!is_rolled_up(&compact_block.state_payloads[2]));
+
+        // Rolled-up payloads keep their commitments, so the client can still
+        // build the state commitment tree.
+        let retained: Vec<_> = compact_block
+            .state_payloads
+            .iter()
+            .map(|payload| *payload.commitment())
+            .collect();
+        assert_eq!(retained, commitments);
+    }
+}
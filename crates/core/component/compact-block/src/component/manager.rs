@@ -2,15 +2,18 @@ use anyhow::Context;
 use anyhow::Result;
 use async_trait::async_trait;
 use cnidarium::StateWrite;
+use decaf377_fmd::Clue;
 use penumbra_dex::component::StateReadExt;
 use penumbra_dex::component::SwapManager as _;
 use penumbra_fee::component::StateReadExt as _;
 use penumbra_governance::StateReadExt as _;
-use penumbra_proto::DomainType;
+use penumbra_proto::core::component::compact_block::v1 as pb;
+use penumbra_proto::{DomainType, Message as _};
 use penumbra_sct::component::clock::EpochRead;
 use penumbra_sct::component::tree::SctManager as _;
 use penumbra_sct::component::tree::SctRead;
 use penumbra_shielded_pool::component::NoteManager as _;
+use penumbra_tct::StateCommitment;
 use tracing::instrument;
 
 use crate::{state_key, CompactBlock};
@@ -28,6 +31,14 @@ pub trait CompactBlockManager: StateWrite {
         self.finalize_compact_block(true, app_parameters_updated)
             .await
     }
+
+    /// Record the FMD clues attached to notes created in the current block, to
+    /// be stored alongside its `CompactBlock`.
+    fn record_note_clues(&mut self, clues: impl IntoIterator<Item = (StateCommitment, Clue)>) {
+        let mut pending = self.pending_note_clues();
+        pending.extend(clues);
+        self.object_put(state_key::pending_note_clues(), pending);
+    }
 }
 
 impl<T: StateWrite + ?Sized> CompactBlockManager for T {}
@@ -134,8 +145,32 @@ trait Inner: StateWrite {
             compact_block.encode_to_vec(),
         );
 
+        // Store the clues of the block's notes, so that the compact block can
+        // later be filtered by detection key.
+        let note_clues = self.pending_note_clues();
+        if !note_clues.is_empty() {
+            let note_clues = pb::NoteClues {
+                clues: note_clues
+                    .into_iter()
+                    .map(|(commitment, clue)| pb::note_clues::NoteClue {
+                        commitment: Some(commitment.into()),
+                        clue: Some(clue.into()),
+                    })
+                    .collect(),
+            };
+            self.nonverifiable_put_raw(
+                state_key::note_clues(height).into_bytes(),
+                note_clues.encode_to_vec(),
+            );
+        }
+
         Ok(())
     }
+
+    fn pending_note_clues(&self) -> im::Vector<(StateCommitment, Clue)> {
+        self.object_get(state_key::pending_note_clues())
+            .unwrap_or_default()
+    }
 }
 
 impl<T: StateWrite + ?Sized> Inner for T {}
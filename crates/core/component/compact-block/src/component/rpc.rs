@@ -1,11 +1,12 @@
 use std::pin::Pin;
 
-use anyhow::bail;
+use anyhow::{anyhow, bail};
 use cnidarium::Storage;
+use decaf377_fmd::DetectionKey;
 use futures::{StreamExt, TryFutureExt, TryStreamExt};
 use penumbra_proto::core::component::compact_block::v1::{
     query_service_server::QueryService, CompactBlockRangeRequest, CompactBlockRangeResponse,
-    CompactBlockRequest, CompactBlockResponse,
+    CompactBlockRequest, CompactBlockResponse, CompactBlockSyncRequest, CompactBlockSyncResponse,
 };
 use penumbra_sct::component::clock::EpochRead;
 use tokio::sync::mpsc;
@@ -14,6 +15,10 @@ use tracing::{instrument, Instrument};
 
 use super::{metrics, StateReadExt};
 
+/// The most detection keys a sync can be filtered by, since every clue in
+/// every block sent is examined with each of them.
+pub const MAX_DETECTION_KEYS: usize = 16;
+
 // TODO: Hide this and only expose a Router?
 pub struct Server {
     storage: Storage,
@@ -30,6 +35,9 @@ impl QueryService for Server {
     type CompactBlockRangeStream = Pin<
         Box<dyn futures::Stream<Item = Result<CompactBlockRangeResponse, tonic::Status>> + Send>,
     >;
+    type CompactBlockSyncStream = Pin<
+        Box<dyn futures::Stream<Item = Result<CompactBlockSyncResponse, tonic::Status>> + Send>,
+    >;
 
     async fn compact_block(
         &self,
@@ -231,6 +239,102 @@ impl QueryService for Server {
                 .boxed(),
         ))
     }
+
+    #[instrument(
+        skip(self, request),
+        fields(
+            start_height = request.get_ref().start_height,
+            detection_keys = request.get_ref().detection_keys.len(),
+        ),
+    )]
+    async fn compact_block_sync(
+        &self,
+        request: tonic::Request<CompactBlockSyncRequest>,
+    ) -> Result<tonic::Response<Self::CompactBlockSyncStream>, Status> {
+        let CompactBlockSyncRequest {
+            start_height,
+            detection_keys,
+        } = request.into_inner();
+
+        if detection_keys.len() > MAX_DETECTION_KEYS {
+            return Err(tonic::Status::invalid_argument(format!(
+                "at most {MAX_DETECTION_KEYS} detection keys may be given, but {} were",
+                detection_keys.len()
+            )));
+        }
+        let detection_keys = detection_keys
+            .into_iter()
+            .map(|bytes| {
+                let bytes: [u8; 32] = bytes
+                    .as_slice()
+                    .try_into()
+                    .map_err(|_| anyhow!("detection key must be 32 bytes"))?;
+                Ok(DetectionKey::from_bytes(bytes)?)
+            })
+            .collect::<anyhow::Result<Vec<_>>>()
+            .map_err(|e| tonic::Status::invalid_argument(format!("invalid detection key: {e}")))?;
+
+        let mut rx_state_snapshot = self.storage.subscribe();
+
+        // The channel is bounded, so blocks are only read from storage as fast
+        // as the client consumes them.
+        let (tx_blocks, rx_blocks) = mpsc::channel(10);
+        let tx_blocks_err = tx_blocks.clone();
+        tokio::spawn(
+            async move {
+                let _guard = CompactBlockConnectionCounter::new();
+
+                let mut next_height = start_height;
+                loop {
+                    // Send every block up to the latest one, which marks the
+                    // point at which the client has caught up.
+                    let snapshot = rx_state_snapshot.borrow_and_update().clone();
+                    let tip_height = snapshot.version();
+
+                    let mut cb_stream = snapshot.stream_compact_block(next_height);
+                    while let Some(compact_block) = cb_stream.next().await {
+                        let mut compact_block = compact_block?;
+                        if compact_block.height > tip_height {
+                            break;
+                        }
+                        if !detection_keys.is_empty() {
+                            let clues = snapshot.note_clues(compact_block.height).await?;
+                            compact_block.retain_detected(&clues, &detection_keys);
+                        }
+
+                        next_height = compact_block.height + 1;
+                        let response = CompactBlockSyncResponse {
+                            caught_up: compact_block.height == tip_height,
+                            compact_block: Some(compact_block.into()),
+                        };
+                        if tx_blocks.send(Ok(response)).await.is_err() {
+                            tracing::debug!("client closed connection");
+                            return Ok::<(), anyhow::Error>(());
+                        }
+                        metrics::counter!(metrics::COMPACT_BLOCK_RANGE_SERVED_TOTAL).increment(1);
+                    }
+
+                    // Ensure that we don't hold a reference to the snapshot
+                    // while waiting for the next block to be created.
+                    std::mem::drop(cb_stream);
+                    std::mem::drop(snapshot);
+
+                    rx_state_snapshot.changed().await?;
+                    tracing::debug!(next_height, "notifying client of new blocks");
+                }
+            }
+            .map_err(|e| async move {
+                let _ = tx_blocks_err
+                    .send(Err(tonic::Status::internal(e.to_string())))
+                    .await;
+            })
+            .instrument(tracing::Span::current()),
+        );
+
+        Ok(tonic::Response::new(
+            tokio_stream::wrappers::ReceiverStream::new(rx_blocks).boxed(),
+        ))
+    }
 }
 
 /// RAII guard used to increment and decrement an active connection counter.
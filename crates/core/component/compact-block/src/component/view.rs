@@ -4,9 +4,13 @@ use anyhow::Error;
 use anyhow::Result;
 use async_trait::async_trait;
 use cnidarium::StateRead;
+use decaf377_fmd::Clue;
 use futures::Stream;
 use futures::StreamExt;
-use penumbra_proto::DomainType;
+use penumbra_proto::core::component::compact_block::v1 as pb;
+use penumbra_proto::{DomainType, Message as _};
+use penumbra_tct::StateCommitment;
+use std::collections::BTreeMap;
 use std::pin::Pin;
 
 #[async_trait]
@@ -39,6 +43,34 @@ pub trait StateReadExt: StateRead {
                 CompactBlock::decode(&mut bytes.as_slice()).expect("failed to decode compact block")
             }))
     }
+
+    /// Returns the FMD clues attached to the notes created at the given height,
+    /// indexed by note commitment.
+    async fn note_clues(&self, height: u64) -> Result<BTreeMap<StateCommitment, Clue>> {
+        let Some(bytes) = self
+            .nonverifiable_get_raw(state_key::note_clues(height).as_bytes())
+            .await?
+        else {
+            return Ok(BTreeMap::new());
+        };
+
+        pb::NoteClues::decode(bytes.as_slice())
+            .context("failed to decode note clues")?
+            .clues
+            .into_iter()
+            .map(|note_clue| {
+                let commitment: StateCommitment = note_clue
+                    .commitment
+                    .ok_or_else(|| anyhow::anyhow!("missing note commitment"))?
+                    .try_into()?;
+                let clue: Clue = note_clue
+                    .clue
+                    .ok_or_else(|| anyhow::anyhow!("missing clue"))?
+                    .try_into()?;
+                Ok((commitment, clue))
+            })
+            .collect()
+    }
 }
 
 impl<T: StateRead + ?Sized> StateReadExt for T {}
@@ -1,3 +1,7 @@
+/// The prefixes of all of the component's state keys, for the application's
+/// state key registry.
+pub const PREFIXES: &[&str] = &["compactblock/", "compactblock_clues/"];
+
 pub fn compact_block(height: u64) -> String {
     format!(
         "{}{}",
@@ -12,3 +16,15 @@ pub fn prefix() -> &'static str {
 pub fn height(height: u64) -> String {
     format!("{height:020}")
 }
+
+/// The FMD clues of the notes created at the given height.
+///
+/// These are kept outside of the compact block prefix, so that ranges of
+/// compact blocks never include them.
+pub fn note_clues(height: u64) -> String {
+    format!("compactblock_clues/{}", crate::state_key::height(height))
+}
+
+pub fn pending_note_clues() -> &'static str {
+    "compactblock/pending_note_clues"
+}
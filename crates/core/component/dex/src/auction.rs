@@ -0,0 +1,9 @@
+mod id;
+mod nft;
+
+pub mod action;
+pub mod dutch;
+pub mod plan;
+
+pub use id::AuctionId;
+pub use nft::AuctionNft;
@@ -0,0 +1,208 @@
+use penumbra_asset::{balance, Balance, Value};
+use penumbra_proto::{penumbra::core::component::dex::v1 as pb, DomainType};
+use penumbra_txhash::{EffectHash, EffectingData};
+use serde::{Deserialize, Serialize};
+
+use super::{dutch::DutchAuctionDescription, AuctionId, AuctionNft};
+
+/// A transaction action that schedules a gradual Dutch auction.
+///
+/// This action's contribution to the transaction's value balance is to consume
+/// the auction's input and contribute an auction NFT with sequence number 0.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(
+    try_from = "pb::ActionDutchAuctionSchedule",
+    into = "pb::ActionDutchAuctionSchedule"
+)]
+pub struct ActionDutchAuctionSchedule {
+    pub description: DutchAuctionDescription,
+}
+
+impl EffectingData for ActionDutchAuctionSchedule {
+    fn effect_hash(&self) -> EffectHash {
+        EffectHash::from_proto_effecting_data(&self.to_proto())
+    }
+}
+
+impl ActionDutchAuctionSchedule {
+    /// Compute the value this action contributes to its transaction.
+    pub fn balance(&self) -> Balance {
+        let opened_auction_nft = Value {
+            amount: 1u64.into(),
+            asset_id: AuctionNft::new(self.description.id(), 0).asset_id(),
+        };
+
+        // The action consumes the input and produces an auction NFT.
+        Balance::from(opened_auction_nft) - self.description.input
+    }
+}
+
+/// A transaction action that ends a gradual Dutch auction.
+///
+/// This action's contribution to the transaction's value balance is to consume
+/// an auction NFT with sequence number 0 and contribute one with sequence
+/// number 1.
+///
+/// Auctions which run until their end height, or sell their entire input, stop
+/// trading automatically, but must still be ended by their owner before their
+/// reserves can be withdrawn.  As with closing positions, ending an auction
+/// doesn't withdraw its reserves, since they can't be known with certainty
+/// until the auction has stopped trading.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(
+    try_from = "pb::ActionDutchAuctionEnd",
+    into = "pb::ActionDutchAuctionEnd"
+)]
+pub struct ActionDutchAuctionEnd {
+    pub auction_id: AuctionId,
+}
+
+impl EffectingData for ActionDutchAuctionEnd {
+    fn effect_hash(&self) -> EffectHash {
+        EffectHash::from_proto_effecting_data(&self.to_proto())
+    }
+}
+
+impl ActionDutchAuctionEnd {
+    /// Compute the value this action contributes to its transaction.
+    pub fn balance(&self) -> Balance {
+        let opened_auction_nft = Value {
+            amount: 1u64.into(),
+            asset_id: AuctionNft::new(self.auction_id, 0).asset_id(),
+        };
+        let ended_auction_nft = Value {
+            amount: 1u64.into(),
+            asset_id: AuctionNft::new(self.auction_id, 1).asset_id(),
+        };
+
+        // The action consumes an opened auction NFT and produces an ended one.
+        Balance::from(ended_auction_nft) - opened_auction_nft
+    }
+}
+
+/// A transaction action that withdraws the reserves of an ended gradual Dutch auction.
+///
+/// This action's contribution to the transaction's value balance is to consume
+/// an auction NFT with sequence number `seq - 1` and contribute one with
+/// sequence number `seq`, as well as the auction's reserves.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(
+    try_from = "pb::ActionDutchAuctionWithdraw",
+    into = "pb::ActionDutchAuctionWithdraw"
+)]
+pub struct ActionDutchAuctionWithdraw {
+    pub auction_id: AuctionId,
+    /// The sequence number of the auction state after the withdrawal.
+    pub seq: u64,
+    /// A transparent (zero blinding factor) commitment to the auction's reserves.
+    ///
+    /// The chain will check this commitment by recomputing it with the on-chain state.
+    pub reserves_commitment: balance::Commitment,
+}
+
+impl EffectingData for ActionDutchAuctionWithdraw {
+    fn effect_hash(&self) -> EffectHash {
+        EffectHash::from_proto_effecting_data(&self.to_proto())
+    }
+}
+
+impl ActionDutchAuctionWithdraw {
+    /// Compute the value this action contributes to its transaction, excluding
+    /// the withdrawn reserves, which are only committed to.
+    pub fn balance_excluding_reserves(&self) -> Balance {
+        let prev_auction_nft = Value {
+            amount: 1u64.into(),
+            asset_id: AuctionNft::new(self.auction_id, self.seq.saturating_sub(1)).asset_id(),
+        };
+        let next_auction_nft = Value {
+            amount: 1u64.into(),
+            asset_id: AuctionNft::new(self.auction_id, self.seq).asset_id(),
+        };
+
+        Balance::from(next_auction_nft) - prev_auction_nft
+    }
+}
+
+// ==== Protobuf impls
+
+impl DomainType for ActionDutchAuctionSchedule {
+    type Proto = pb::ActionDutchAuctionSchedule;
+}
+
+impl From<ActionDutchAuctionSchedule> for pb::ActionDutchAuctionSchedule {
+    fn from(value: ActionDutchAuctionSchedule) -> Self {
+        Self {
+            description: Some(value.description.into()),
+        }
+    }
+}
+
+impl TryFrom<pb::ActionDutchAuctionSchedule> for ActionDutchAuctionSchedule {
+    type Error = anyhow::Error;
+
+    fn try_from(value: pb::ActionDutchAuctionSchedule) -> Result<Self, Self::Error> {
+        Ok(Self {
+            description: value
+                .description
+                .ok_or_else(|| anyhow::anyhow!("missing description"))?
+                .try_into()?,
+        })
+    }
+}
+
+impl DomainType for ActionDutchAuctionEnd {
+    type Proto = pb::ActionDutchAuctionEnd;
+}
+
+impl From<ActionDutchAuctionEnd> for pb::ActionDutchAuctionEnd {
+    fn from(value: ActionDutchAuctionEnd) -> Self {
+        Self {
+            auction_id: Some(value.auction_id.into()),
+        }
+    }
+}
+
+impl TryFrom<pb::ActionDutchAuctionEnd> for ActionDutchAuctionEnd {
+    type Error = anyhow::Error;
+
+    fn try_from(value: pb::ActionDutchAuctionEnd) -> Result<Self, Self::Error> {
+        Ok(Self {
+            auction_id: value
+                .auction_id
+                .ok_or_else(|| anyhow::anyhow!("missing auction_id"))?
+                .try_into()?,
+        })
+    }
+}
+
+impl DomainType for ActionDutchAuctionWithdraw {
+    type Proto = pb::ActionDutchAuctionWithdraw;
+}
+
+impl From<ActionDutchAuctionWithdraw> for pb::ActionDutchAuctionWithdraw {
+    fn from(value: ActionDutchAuctionWithdraw) -> Self {
+        Self {
+            auction_id: Some(value.auction_id.into()),
+            seq: value.seq,
+            reserves_commitment: Some(value.reserves_commitment.into()),
+        }
+    }
+}
+
+impl TryFrom<pb::ActionDutchAuctionWithdraw> for ActionDutchAuctionWithdraw {
+    type Error = anyhow::Error;
+
+    fn try_from(value: pb::ActionDutchAuctionWithdraw) -> Result<Self, Self::Error> {
+        Ok(Self {
+            auction_id: value
+                .auction_id
+                .ok_or_else(|| anyhow::anyhow!("missing auction_id"))?
+                .try_into()?,
+            seq: value.seq,
+            reserves_commitment: value
+                .reserves_commitment
+                .ok_or_else(|| anyhow::anyhow!("missing reserves_commitment"))?
+                .try_into()?,
+        })
+    }
+}
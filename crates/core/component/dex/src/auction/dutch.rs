@@ -0,0 +1,579 @@
+use anyhow::anyhow;
+use penumbra_asset::{asset, Balance, Value};
+use penumbra_num::{fixpoint::U128x128, Amount};
+use penumbra_proto::{penumbra::core::component::dex::v1 as pb, DomainType};
+use rand_core::CryptoRngCore;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    lp::{
+        position::{self, Position, MAX_RESERVE_AMOUNT},
+        Reserves, TradingFunction,
+    },
+    TradingPair,
+};
+
+use super::AuctionId;
+
+/// The maximum number of blocks a Dutch auction may run for.
+pub const MAX_AUCTION_DURATION: u64 = 1_000_000;
+
+/// The maximum number of steps a Dutch auction takes.
+///
+/// Each step replaces the auction's position on the DEX, so longer auctions
+/// don't step in every block, but at evenly spaced heights instead.
+pub const MAX_AUCTION_STEPS: u64 = 100;
+
+/// The maximum number of Dutch auctions which may be scheduled or running at once.
+///
+/// Every running auction may take a step at the end of a block, so this bounds
+/// the work done by the DEX for auctions in a single block.
+pub const MAX_ACTIVE_AUCTIONS: usize = 128;
+
+/// Describes a gradual Dutch auction.
+///
+/// The auction sells its `input` for the `output_id` asset.  The price starts
+/// at `max_output` for the entire input at `start_height`, and decays linearly
+/// with each block until it reaches `min_output` at `end_height`.  At each
+/// step of the auction, the remaining input is offered on the DEX in a
+/// position at the auction's current price, so that it can be bought by swaps
+/// and arbitrageurs until the next step.  If `fill_against_dex` is set, each
+/// step of the auction first sells against the liquidity already on the DEX,
+/// whenever it offers a price no worse than the auction's current price.
+///
+/// Descriptions are immutable, so the [`AuctionId`] is unchanged over the
+/// entire lifetime of the auction.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(
+    try_from = "pb::DutchAuctionDescription",
+    into = "pb::DutchAuctionDescription"
+)]
+pub struct DutchAuctionDescription {
+    /// The value being auctioned.
+    pub input: Value,
+    /// The asset the input is being auctioned for.
+    pub output_id: asset::Id,
+    /// The amount of output asked for the entire input at the start of the auction.
+    pub max_output: Amount,
+    /// The amount of output asked for the entire input at the end of the auction.
+    pub min_output: Amount,
+    /// The height of the first block of the auction.
+    pub start_height: u64,
+    /// The height of the last block of the auction.
+    pub end_height: u64,
+    /// A random value used to disambiguate auctions with the same parameters.
+    pub nonce: [u8; 32],
+    /// Whether each step of the auction also sells against the liquidity
+    /// already on the DEX.
+    pub fill_against_dex: bool,
+}
+
+impl DutchAuctionDescription {
+    /// Describe a new auction, with a random nonce, which fills against the
+    /// liquidity already on the DEX.
+    pub fn new(
+        rng: &mut impl CryptoRngCore,
+        input: Value,
+        output_id: asset::Id,
+        max_output: Amount,
+        min_output: Amount,
+        start_height: u64,
+        end_height: u64,
+    ) -> Self {
+        let mut nonce = [0u8; 32];
+        rng.fill_bytes(&mut nonce);
+        Self {
+            input,
+            output_id,
+            max_output,
+            min_output,
+            start_height,
+            end_height,
+            nonce,
+            fill_against_dex: true,
+        }
+    }
+
+    /// Get the ID of the auction described.
+    pub fn id(&self) -> AuctionId {
+        let mut state = blake2b_simd::Params::default()
+            .personal(b"penumbra_DA_id")
+            .to_state();
+
+        state.update(&self.nonce);
+        state.update(&self.input.asset_id.to_bytes());
+        state.update(&self.input.amount.to_le_bytes());
+        state.update(&self.output_id.to_bytes());
+        state.update(&self.max_output.to_le_bytes());
+        state.update(&self.min_output.to_le_bytes());
+        state.update(&self.start_height.to_le_bytes());
+        state.update(&self.end_height.to_le_bytes());
+        // Auctions described before they could opt out of filling against
+        // the DEX did fill against it, so the flag is only hashed when it is
+        // unset, to keep the IDs of those auctions unchanged.
+        if !self.fill_against_dex {
+            state.update(&[1]);
+        }
+
+        let hash = state.finalize();
+        let mut bytes = [0; 32];
+        bytes[0..32].copy_from_slice(&hash.as_bytes()[0..32]);
+        AuctionId(bytes)
+    }
+
+    pub fn check_stateless(&self) -> anyhow::Result<()> {
+        if self.input.amount == Amount::zero() {
+            Err(anyhow!("auction input must be nonzero"))
+        } else if self.input.amount.value() > MAX_RESERVE_AMOUNT
+            || self.max_output.value() > MAX_RESERVE_AMOUNT
+        {
+            Err(anyhow!(
+                "auction amounts are out-of-bounds (limit: {MAX_RESERVE_AMOUNT})"
+            ))
+        } else if self.input.asset_id == self.output_id {
+            Err(anyhow!("auction input and output assets must differ"))
+        } else if self.min_output == Amount::zero() {
+            Err(anyhow!("auction minimum output must be nonzero"))
+        } else if self.max_output < self.min_output {
+            Err(anyhow!(
+                "auction maximum output must be at least the minimum output"
+            ))
+        } else if self.end_height <= self.start_height {
+            Err(anyhow!("auction must end after it starts"))
+        } else if self.end_height - self.start_height > MAX_AUCTION_DURATION {
+            Err(anyhow!(
+                "auction cannot run for more than {MAX_AUCTION_DURATION} blocks"
+            ))
+        } else {
+            Ok(())
+        }
+    }
+
+    /// The number of blocks between the steps of the auction.
+    ///
+    /// Auctions running for up to [`MAX_AUCTION_STEPS`] blocks step in every
+    /// block.
+    pub fn step_interval(&self) -> u64 {
+        self.end_height
+            .saturating_sub(self.start_height)
+            .div_ceil(MAX_AUCTION_STEPS)
+            .max(1)
+    }
+
+    /// Returns whether the auction takes a step at the given height.
+    ///
+    /// The auction steps every [`step_interval`](Self::step_interval) blocks
+    /// from its start height, and at its end height.
+    pub fn is_step(&self, height: u64) -> bool {
+        height >= self.start_height
+            && (height >= self.end_height
+                || (height - self.start_height) % self.step_interval() == 0)
+    }
+
+    /// The number of steps the auction takes, from its start height through
+    /// its end height.
+    pub fn steps(&self) -> u64 {
+        self.end_height
+            .saturating_sub(self.start_height)
+            .div_ceil(self.step_interval())
+            + 1
+    }
+
+    /// The amount of output asked for the entire input at the given height.
+    ///
+    /// This decays linearly from `max_output` at the start height to
+    /// `min_output` at the end height.
+    pub fn output_at_height(&self, height: u64) -> Amount {
+        if height <= self.start_height {
+            return self.max_output;
+        }
+        if height >= self.end_height {
+            return self.min_output;
+        }
+
+        let elapsed = (height - self.start_height) as u128;
+        let duration = (self.end_height - self.start_height) as u128;
+        let decay =
+            (self.max_output.value() - self.min_output.value()).saturating_mul(elapsed) / duration;
+        Amount::from(self.max_output.value() - decay)
+    }
+
+    /// The price of the output, in terms of the input, asked at the given height.
+    ///
+    /// Fills against liquidity at a price at or below this limit are at least
+    /// as good as the auction's current price.
+    pub fn price_limit_at_height(&self, height: u64) -> anyhow::Result<U128x128> {
+        Ok(U128x128::ratio(
+            self.input.amount,
+            self.output_at_height(height),
+        )?)
+    }
+
+    /// Returns whether selling `input` for `output` meets the auction's price at the given height.
+    pub fn accepts_at_height(
+        &self,
+        height: u64,
+        input: Amount,
+        output: Amount,
+    ) -> anyhow::Result<bool> {
+        let asked = U128x128::ratio(self.output_at_height(height), self.input.amount)?;
+        let received = U128x128::ratio(output, input)?;
+        Ok(received >= asked)
+    }
+}
+
+/// The progress of a gradual Dutch auction.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(try_from = "pb::DutchAuctionState", into = "pb::DutchAuctionState")]
+pub struct DutchAuctionState {
+    /// The sequence number of the auction state, matching its current [`AuctionNft`](super::AuctionNft).
+    pub seq: u64,
+    /// The amount of the input which has not yet been sold.
+    pub input_reserves: Amount,
+    /// The amount of the output received for the input sold so far.
+    pub output_reserves: Amount,
+    /// The position on the DEX holding the auction's reserves, if any.
+    ///
+    /// While this is set, the reserves above are deposited in the position,
+    /// which may have sold some of the input since it was opened.
+    pub current_position: Option<position::Id>,
+}
+
+/// A gradual Dutch auction, along with its progress.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(try_from = "pb::DutchAuction", into = "pb::DutchAuction")]
+pub struct DutchAuction {
+    pub description: DutchAuctionDescription,
+    pub state: DutchAuctionState,
+}
+
+impl DutchAuction {
+    /// A newly scheduled auction, which has not sold any of its input.
+    pub fn new(description: DutchAuctionDescription) -> Self {
+        let state = DutchAuctionState {
+            seq: 0,
+            input_reserves: description.input.amount,
+            output_reserves: Amount::zero(),
+            current_position: None,
+        };
+        Self { description, state }
+    }
+
+    pub fn id(&self) -> AuctionId {
+        self.description.id()
+    }
+
+    /// The position offering the auction's unsold input on the DEX, at the
+    /// auction's price at the given height.
+    ///
+    /// The position's nonce is derived from the auction ID and the height, so
+    /// that each step of the auction opens a distinct position.
+    pub fn position_at_height(&self, height: u64) -> Position {
+        let input_id = self.description.input.asset_id;
+        let pair = TradingPair::new(input_id, self.description.output_id);
+
+        // As for a sell order, the position interpolates between holding the
+        // unsold input, and holding the output asked for it; the coefficient
+        // of each asset is the amount asked of the other.
+        let asked = self.description.output_at_height(height);
+        let offered = self.description.input.amount;
+        let unsold = self.state.input_reserves;
+        let (p, q, reserves) = if pair.asset_1() == input_id {
+            let reserves = Reserves {
+                r1: unsold,
+                r2: Amount::zero(),
+            };
+            (asked, offered, reserves)
+        } else {
+            let reserves = Reserves {
+                r1: Amount::zero(),
+                r2: unsold,
+            };
+            (offered, asked, reserves)
+        };
+
+        let mut nonce = [0u8; 32];
+        nonce.copy_from_slice(
+            blake2b_simd::Params::default()
+                .personal(b"penumbra_DA_pos")
+                .hash_length(32)
+                .to_state()
+                .update(&self.id().0)
+                .update(&height.to_le_bytes())
+                .finalize()
+                .as_bytes(),
+        );
+
+        Position {
+            state: position::State::Opened,
+            reserves,
+            phi: TradingFunction::new(pair, 0, p, q),
+            nonce,
+            close_on_fill: false,
+            dynamic_fee: None,
+        }
+    }
+
+    /// The auction's unsold input and the output it has received.
+    pub fn reserves(&self) -> Balance {
+        Balance::from(Value {
+            asset_id: self.description.input.asset_id,
+            amount: self.state.input_reserves,
+        }) + Value {
+            asset_id: self.description.output_id,
+            amount: self.state.output_reserves,
+        }
+    }
+}
+
+// ==== Protobuf impls
+
+impl DomainType for DutchAuctionDescription {
+    type Proto = pb::DutchAuctionDescription;
+}
+
+impl From<DutchAuctionDescription> for pb::DutchAuctionDescription {
+    fn from(value: DutchAuctionDescription) -> Self {
+        Self {
+            input: Some(value.input.into()),
+            output_id: Some(value.output_id.into()),
+            max_output: Some(value.max_output.into()),
+            min_output: Some(value.min_output.into()),
+            start_height: value.start_height,
+            end_height: value.end_height,
+            nonce: value.nonce.to_vec(),
+            skip_dex_fill: !value.fill_against_dex,
+        }
+    }
+}
+
+impl TryFrom<pb::DutchAuctionDescription> for DutchAuctionDescription {
+    type Error = anyhow::Error;
+
+    fn try_from(value: pb::DutchAuctionDescription) -> Result<Self, Self::Error> {
+        Ok(Self {
+            input: value
+                .input
+                .ok_or_else(|| anyhow!("missing input"))?
+                .try_into()?,
+            output_id: value
+                .output_id
+                .ok_or_else(|| anyhow!("missing output_id"))?
+                .try_into()?,
+            max_output: value
+                .max_output
+                .ok_or_else(|| anyhow!("missing max_output"))?
+                .try_into()?,
+            min_output: value
+                .min_output
+                .ok_or_else(|| anyhow!("missing min_output"))?
+                .try_into()?,
+            start_height: value.start_height,
+            end_height: value.end_height,
+            nonce: value
+                .nonce
+                .as_slice()
+                .try_into()
+                .map_err(|_| anyhow!("expected 32-byte nonce"))?,
+            fill_against_dex: !value.skip_dex_fill,
+        })
+    }
+}
+
+impl DomainType for DutchAuctionState {
+    type Proto = pb::DutchAuctionState;
+}
+
+impl From<DutchAuctionState> for pb::DutchAuctionState {
+    fn from(value: DutchAuctionState) -> Self {
+        Self {
+            seq: value.seq,
+            input_reserves: Some(value.input_reserves.into()),
+            output_reserves: Some(value.output_reserves.into()),
+            current_position: value.current_position.map(Into::into),
+        }
+    }
+}
+
+impl TryFrom<pb::DutchAuctionState> for DutchAuctionState {
+    type Error = anyhow::Error;
+
+    fn try_from(value: pb::DutchAuctionState) -> Result<Self, Self::Error> {
+        Ok(Self {
+            seq: value.seq,
+            input_reserves: value
+                .input_reserves
+                .ok_or_else(|| anyhow!("missing input_reserves"))?
+                .try_into()?,
+            output_reserves: value
+                .output_reserves
+                .ok_or_else(|| anyhow!("missing output_reserves"))?
+                .try_into()?,
+            current_position: value.current_position.map(TryInto::try_into).transpose()?,
+        })
+    }
+}
+
+impl DomainType for DutchAuction {
+    type Proto = pb::DutchAuction;
+}
+
+impl From<DutchAuction> for pb::DutchAuction {
+    fn from(value: DutchAuction) -> Self {
+        Self {
+            description: Some(value.description.into()),
+            state: Some(value.state.into()),
+        }
+    }
+}
+
+impl TryFrom<pb::DutchAuction> for DutchAuction {
+    type Error = anyhow::Error;
+
+    fn try_from(value: pb::DutchAuction) -> Result<Self, Self::Error> {
+        Ok(Self {
+            description: value
+                .description
+                .ok_or_else(|| anyhow!("missing description"))?
+                .try_into()?,
+            state: value
+                .state
+                .ok_or_else(|| anyhow!("missing state"))?
+                .try_into()?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use penumbra_asset::STAKING_TOKEN_ASSET_ID;
+    use rand_core::OsRng;
+
+    use super::*;
+
+    fn description() -> DutchAuctionDescription {
+        let gm = asset::Cache::with_known_assets()
+            .get_unit("gm")
+            .unwrap()
+            .id();
+        DutchAuctionDescription::new(
+            &mut OsRng,
+            Value {
+                asset_id: *STAKING_TOKEN_ASSET_ID,
+                amount: 1_000u64.into(),
+            },
+            gm,
+            2_000u64.into(),
+            1_000u64.into(),
+            100,
+            110,
+        )
+    }
+
+    #[test]
+    fn price_decays_linearly() {
+        let description = description();
+        description.check_stateless().unwrap();
+
+        assert_eq!(description.output_at_height(0), 2_000u64.into());
+        assert_eq!(description.output_at_height(100), 2_000u64.into());
+        assert_eq!(description.output_at_height(101), 1_900u64.into());
+        assert_eq!(description.output_at_height(105), 1_500u64.into());
+        assert_eq!(description.output_at_height(110), 1_000u64.into());
+        assert_eq!(description.output_at_height(1_000), 1_000u64.into());
+
+        // At height 105, the auction asks 1.5 of the output per unit of input.
+        assert!(description
+            .accepts_at_height(105, 100u64.into(), 150u64.into())
+            .unwrap());
+        assert!(!description
+            .accepts_at_height(105, 100u64.into(), 149u64.into())
+            .unwrap());
+    }
+
+    #[test]
+    fn positions_offer_unsold_input_at_current_price() {
+        let mut auction = DutchAuction::new(description());
+        auction.state.input_reserves = 500u64.into();
+
+        // At height 105, the auction asks 1.5 of the output per unit of input,
+        // so the unsold half of the input should be offered for 750.
+        let position = auction.position_at_height(105);
+        position.check_stateless().unwrap();
+        let order = position.interpret_as_sell().unwrap();
+        assert_eq!(
+            order.offered,
+            Value {
+                asset_id: auction.description.input.asset_id,
+                amount: 500u64.into(),
+            }
+        );
+        assert_eq!(
+            order.desired,
+            Value {
+                asset_id: auction.description.output_id,
+                amount: 750u64.into(),
+            }
+        );
+
+        // Each step opens a distinct position.
+        assert_eq!(position.id(), auction.position_at_height(105).id());
+        assert_ne!(position.id(), auction.position_at_height(106).id());
+    }
+
+    #[test]
+    fn long_auctions_step_at_intervals() {
+        // Short auctions step in every block.
+        let short = description();
+        assert_eq!(short.step_interval(), 1);
+        assert_eq!(short.steps(), 11);
+        assert!(!short.is_step(99));
+        assert!((100..=110).all(|height| short.is_step(height)));
+
+        let mut long = description();
+        long.end_height = 1_100;
+        assert_eq!(long.step_interval(), 10);
+        assert_eq!(long.steps(), MAX_AUCTION_STEPS + 1);
+        assert!(long.is_step(100));
+        assert!(!long.is_step(105));
+        assert!(long.is_step(110));
+        assert!(!long.is_step(1_095));
+        assert!(long.is_step(1_100));
+
+        // The last step is at the end height, even if it's off the interval.
+        long.end_height = 1_105;
+        assert_eq!(long.step_interval(), 11);
+        assert!(long.is_step(1_090));
+        assert!(!long.is_step(1_100));
+        assert!(long.is_step(1_105));
+    }
+
+    #[test]
+    fn opting_out_of_dex_fills_changes_the_id() {
+        let filling = description();
+        let mut positions_only = filling.clone();
+        positions_only.fill_against_dex = false;
+        assert_ne!(filling.id(), positions_only.id());
+
+        let decoded: DutchAuctionDescription =
+            pb::DutchAuctionDescription::from(positions_only.clone())
+                .try_into()
+                .unwrap();
+        assert_eq!(decoded, positions_only);
+    }
+
+    #[test]
+    fn invalid_descriptions_are_rejected() {
+        let mut reversed = description();
+        reversed.min_output = 3_000u64.into();
+        assert!(reversed.check_stateless().is_err());
+
+        let mut instant = description();
+        instant.end_height = instant.start_height;
+        assert!(instant.check_stateless().is_err());
+
+        let mut cyclic = description();
+        cyclic.output_id = cyclic.input.asset_id;
+        assert!(cyclic.check_stateless().is_err());
+    }
+}
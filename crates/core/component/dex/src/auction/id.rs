@@ -0,0 +1,65 @@
+use anyhow::Context;
+use penumbra_proto::{
+    penumbra::core::component::dex::v1 as pb, serializers::bech32str, DomainType,
+};
+use serde::{Deserialize, Serialize};
+
+/// A unique identifier for an auction, obtained by hashing its description.
+#[derive(PartialEq, Eq, PartialOrd, Ord, Copy, Clone, Serialize, Deserialize)]
+#[serde(try_from = "pb::AuctionId", into = "pb::AuctionId")]
+pub struct AuctionId(pub [u8; 32]);
+
+impl std::fmt::Debug for AuctionId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&bech32str::encode(
+            &self.0,
+            bech32str::auction_id::BECH32_PREFIX,
+            bech32str::Bech32m,
+        ))
+    }
+}
+
+impl std::fmt::Display for AuctionId {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.write_str(&bech32str::encode(
+            &self.0,
+            bech32str::auction_id::BECH32_PREFIX,
+            bech32str::Bech32m,
+        ))
+    }
+}
+
+impl std::str::FromStr for AuctionId {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let inner = bech32str::decode(s, bech32str::auction_id::BECH32_PREFIX, bech32str::Bech32m)?;
+        pb::AuctionId { inner }.try_into()
+    }
+}
+
+impl DomainType for AuctionId {
+    type Proto = pb::AuctionId;
+}
+
+impl TryFrom<pb::AuctionId> for AuctionId {
+    type Error = anyhow::Error;
+
+    fn try_from(value: pb::AuctionId) -> Result<Self, Self::Error> {
+        Ok(AuctionId(
+            value
+                .inner
+                .as_slice()
+                .try_into()
+                .context("expected 32-byte id")?,
+        ))
+    }
+}
+
+impl From<AuctionId> for pb::AuctionId {
+    fn from(value: AuctionId) -> Self {
+        Self {
+            inner: value.0.to_vec(),
+        }
+    }
+}
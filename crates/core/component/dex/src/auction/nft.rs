@@ -0,0 +1,160 @@
+use penumbra_asset::asset;
+use penumbra_proto::{penumbra::core::component::dex::v1 as pb, DomainType};
+use regex::Regex;
+
+use super::AuctionId;
+
+/// The denomination of an auction NFT, tracking both ownership and state of an auction.
+///
+/// As with [`LpNft`](crate::lp::LpNft)s, each state transition of an auction is
+/// modeled as spending the NFT for the previous state and minting the NFT for
+/// the next state, so that auction actions can be authorized by spending funds.
+/// The state is recorded as a sequence number: `0` while the auction is
+/// scheduled or running, `1` once it has ended, and `2` once its reserves have
+/// been withdrawn.
+#[derive(Debug, Clone)]
+pub struct AuctionNft {
+    id: AuctionId,
+    seq: u64,
+    base_denom: asset::Metadata,
+}
+
+impl AuctionNft {
+    pub fn new(id: AuctionId, seq: u64) -> Self {
+        let base_denom = asset::REGISTRY
+            .parse_denom(&format!("auctionnft_{seq}_{id}"))
+            .expect("base denom format is valid");
+
+        Self {
+            id,
+            seq,
+            base_denom,
+        }
+    }
+
+    pub fn denom(&self) -> asset::Metadata {
+        self.base_denom.clone()
+    }
+
+    pub fn asset_id(&self) -> asset::Id {
+        self.base_denom.id()
+    }
+
+    pub fn id(&self) -> AuctionId {
+        self.id
+    }
+
+    pub fn seq(&self) -> u64 {
+        self.seq
+    }
+}
+
+impl TryFrom<asset::Metadata> for AuctionNft {
+    type Error = anyhow::Error;
+
+    fn try_from(base_denom: asset::Metadata) -> Result<Self, Self::Error> {
+        // Note: this regex must be in sync with both asset::REGISTRY
+        // and the bech32 prefix for auction IDs defined in the proto crate.
+        let base_denom_string = base_denom.to_string();
+        let captures =
+            Regex::new("^auctionnft_(?P<seq>[0-9]+)_(?P<id>pauctid1[a-zA-HJ-NP-Z0-9]+)$")
+                .expect("regex is valid")
+                .captures(&base_denom_string)
+                .ok_or_else(|| {
+                    anyhow::anyhow!("base denom {} is not an auction nft", base_denom_string)
+                })?;
+
+        let id = captures
+            .name("id")
+            .expect("id is a named capture")
+            .as_str()
+            .parse()?;
+        let seq = captures
+            .name("seq")
+            .expect("seq is a named capture")
+            .as_str()
+            .parse()?;
+
+        Ok(Self {
+            id,
+            seq,
+            base_denom,
+        })
+    }
+}
+
+impl std::fmt::Display for AuctionNft {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        self.base_denom.fmt(f)
+    }
+}
+
+impl std::str::FromStr for AuctionNft {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let base_denom = asset::REGISTRY
+            .parse_denom(s)
+            .ok_or_else(|| anyhow::anyhow!("invalid denom string"))?;
+        base_denom.try_into()
+    }
+}
+
+impl std::cmp::PartialEq for AuctionNft {
+    fn eq(&self, other: &Self) -> bool {
+        self.id == other.id && self.seq == other.seq
+    }
+}
+
+impl std::cmp::Eq for AuctionNft {}
+
+impl DomainType for AuctionNft {
+    type Proto = pb::AuctionNft;
+}
+
+impl TryFrom<pb::AuctionNft> for AuctionNft {
+    type Error = anyhow::Error;
+
+    fn try_from(value: pb::AuctionNft) -> Result<Self, Self::Error> {
+        let id = value
+            .id
+            .ok_or_else(|| anyhow::anyhow!("missing auction id"))?
+            .try_into()?;
+
+        Ok(Self::new(id, value.seq))
+    }
+}
+
+impl From<AuctionNft> for pb::AuctionNft {
+    fn from(v: AuctionNft) -> Self {
+        pb::AuctionNft {
+            id: Some(v.id.into()),
+            seq: v.seq,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn auction_nft_denom_parsing_roundtrip() {
+        let id = AuctionId([7u8; 32]);
+
+        for seq in 0..3 {
+            let nft1 = AuctionNft::new(id, seq);
+            let nft1_string = nft1.denom().to_string();
+            assert_eq!(nft1.to_string(), nft1_string);
+
+            let nft2_denom = asset::REGISTRY.parse_denom(&nft1_string).unwrap();
+            let nft2 = AuctionNft::try_from(nft2_denom).unwrap();
+            assert_eq!(nft1, nft2);
+
+            let nft3: AuctionNft = nft1_string.parse().unwrap();
+            assert_eq!(nft1, nft3);
+            assert_eq!(nft3.id(), id);
+            assert_eq!(nft3.seq(), seq);
+        }
+    }
+}
@@ -0,0 +1,82 @@
+use ark_ff::Zero;
+use decaf377::Fr;
+use penumbra_asset::{balance, Balance, Value};
+use penumbra_proto::{penumbra::core::component::dex::v1 as pb, DomainType};
+use serde::{Deserialize, Serialize};
+
+use super::{action::ActionDutchAuctionWithdraw, AuctionId};
+
+/// A planned [`ActionDutchAuctionWithdraw`](ActionDutchAuctionWithdraw).
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(
+    try_from = "pb::ActionDutchAuctionWithdrawPlan",
+    into = "pb::ActionDutchAuctionWithdrawPlan"
+)]
+pub struct ActionDutchAuctionWithdrawPlan {
+    pub auction_id: AuctionId,
+    pub seq: u64,
+    pub reserves_input: Value,
+    pub reserves_output: Value,
+}
+
+impl ActionDutchAuctionWithdrawPlan {
+    /// Convenience method to construct the [`ActionDutchAuctionWithdraw`] described by this plan.
+    pub fn to_action(&self) -> ActionDutchAuctionWithdraw {
+        ActionDutchAuctionWithdraw {
+            auction_id: self.auction_id,
+            seq: self.seq,
+            reserves_commitment: self.reserves_commitment(),
+        }
+    }
+
+    pub fn reserves_balance(&self) -> Balance {
+        Balance::from(self.reserves_input) + self.reserves_output
+    }
+
+    pub fn reserves_commitment(&self) -> balance::Commitment {
+        self.reserves_balance().commit(Fr::zero())
+    }
+
+    pub fn balance(&self) -> Balance {
+        // The withdrawal outputs the auction's reserves, and exchanges the
+        // auction NFT for the previous state for one for the next state.
+        self.to_action().balance_excluding_reserves() + self.reserves_balance()
+    }
+}
+
+impl DomainType for ActionDutchAuctionWithdrawPlan {
+    type Proto = pb::ActionDutchAuctionWithdrawPlan;
+}
+
+impl From<ActionDutchAuctionWithdrawPlan> for pb::ActionDutchAuctionWithdrawPlan {
+    fn from(value: ActionDutchAuctionWithdrawPlan) -> Self {
+        Self {
+            auction_id: Some(value.auction_id.into()),
+            seq: value.seq,
+            reserves_input: Some(value.reserves_input.into()),
+            reserves_output: Some(value.reserves_output.into()),
+        }
+    }
+}
+
+impl TryFrom<pb::ActionDutchAuctionWithdrawPlan> for ActionDutchAuctionWithdrawPlan {
+    type Error = anyhow::Error;
+
+    fn try_from(value: pb::ActionDutchAuctionWithdrawPlan) -> Result<Self, Self::Error> {
+        Ok(Self {
+            auction_id: value
+                .auction_id
+                .ok_or_else(|| anyhow::anyhow!("missing auction_id"))?
+                .try_into()?,
+            seq: value.seq,
+            reserves_input: value
+                .reserves_input
+                .ok_or_else(|| anyhow::anyhow!("missing reserves_input"))?
+                .try_into()?,
+            reserves_output: value
+                .reserves_output
+                .ok_or_else(|| anyhow::anyhow!("missing reserves_output"))?
+                .try_into()?,
+        })
+    }
+}
@@ -0,0 +1,192 @@
+use std::path::{Path, PathBuf};
+
+use anyhow::Context as _;
+use once_cell::sync::OnceCell;
+use penumbra_asset::asset;
+use penumbra_num::Amount;
+use penumbra_proto::{penumbra::core::component::dex::v1 as pb, DomainType};
+use serde::{Deserialize, Serialize};
+
+use crate::{lp::position, TradingPair};
+
+/// The file in which this node records a breach when it halts the chain, if configured.
+static RECORD_PATH: OnceCell<PathBuf> = OnceCell::new();
+
+/// A record of the value circuit breaker detecting that more of an asset would flow out of
+/// liquidity positions than was deposited in them.
+///
+/// This is raised as an error by the execution which caused it, and the chain is halted with
+/// [`ValueCircuitBreakerBreach::halt`], which records it for post-mortem investigation.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, thiserror::Error)]
+#[serde(
+    try_from = "pb::ValueCircuitBreakerBreach",
+    into = "pb::ValueCircuitBreakerBreach"
+)]
+#[error(
+    "value circuit breaker breached at height {height} on pair {trading_pair}: balance for asset {asset_id} is negative: {available} available, {outflow} outflow"
+)]
+pub struct ValueCircuitBreakerBreach {
+    /// The height at which the breach was detected.
+    pub height: u64,
+    /// The trading pair whose execution breached the circuit breaker.
+    pub trading_pair: TradingPair,
+    /// The asset whose outflow exceeded its balance in liquidity positions.
+    pub asset_id: asset::Id,
+    /// The balance of the asset held in liquidity positions before the outflow.
+    pub available: Amount,
+    /// The amount of the asset that would have flowed out of liquidity positions.
+    pub outflow: Amount,
+    /// The positions involved in the offending execution, if known.
+    pub positions: Vec<position::Id>,
+}
+
+impl ValueCircuitBreakerBreach {
+    /// The amount by which the outflow exceeded the available balance.
+    pub fn deficit(&self) -> Amount {
+        self.outflow.saturating_sub(&self.available)
+    }
+
+    /// Record breaches which halt the chain in the file at `path`, such as one in the node's
+    /// data directory, so that they can be queried after the halt.
+    ///
+    /// This should be called once, when the node starts.
+    pub fn record_to(path: PathBuf) -> anyhow::Result<()> {
+        RECORD_PATH
+            .set(path)
+            .map_err(|_| anyhow::anyhow!("value circuit breaker breach record already set"))
+    }
+
+    /// The breach which halted the chain, if this node has recorded one.
+    pub fn recorded() -> anyhow::Result<Option<Self>> {
+        match RECORD_PATH.get() {
+            Some(path) => Self::read_record(path),
+            None => Ok(None),
+        }
+    }
+
+    fn write_record(&self, path: &Path) -> anyhow::Result<()> {
+        let record = serde_json::to_vec_pretty(self)?;
+        std::fs::write(path, record)
+            .with_context(|| format!("could not write breach record to {}", path.display()))
+    }
+
+    fn read_record(path: &Path) -> anyhow::Result<Option<Self>> {
+        let record = match std::fs::read(path) {
+            Ok(record) => record,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(None),
+            Err(e) => {
+                return Err(e).with_context(|| {
+                    format!("could not read breach record from {}", path.display())
+                })
+            }
+        };
+        Ok(Some(serde_json::from_slice(&record)?))
+    }
+
+    /// Halt the chain by panicking, without committing any of the block's state.
+    ///
+    /// The breach is logged, and recorded in the file set with [`record_to`](Self::record_to)
+    /// first, since nothing recorded in the state would survive.
+    pub fn halt(self) -> ! {
+        tracing::error!(
+            height = self.height,
+            trading_pair = %self.trading_pair,
+            asset_id = %self.asset_id,
+            available = %self.available,
+            outflow = %self.outflow,
+            deficit = %self.deficit(),
+            positions = ?self.positions,
+            "value circuit breaker breached, halting the chain"
+        );
+        if let Some(path) = RECORD_PATH.get() {
+            if let Err(e) = self.write_record(path) {
+                tracing::error!(?e, "failed to record value circuit breaker breach");
+            }
+        }
+        panic!("{self}")
+    }
+
+    /// Halt the chain if `error` is a breach of the value circuit breaker,
+    /// or hand it back otherwise.
+    pub fn halt_if_breach(error: anyhow::Error) -> anyhow::Error {
+        match error.downcast::<Self>() {
+            Ok(breach) => breach.halt(),
+            Err(error) => error,
+        }
+    }
+}
+
+impl DomainType for ValueCircuitBreakerBreach {
+    type Proto = pb::ValueCircuitBreakerBreach;
+}
+
+impl From<ValueCircuitBreakerBreach> for pb::ValueCircuitBreakerBreach {
+    fn from(breach: ValueCircuitBreakerBreach) -> Self {
+        Self {
+            height: breach.height,
+            trading_pair: Some(breach.trading_pair.into()),
+            asset_id: Some(breach.asset_id.into()),
+            available: Some(breach.available.into()),
+            outflow: Some(breach.outflow.into()),
+            positions: breach.positions.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+impl TryFrom<pb::ValueCircuitBreakerBreach> for ValueCircuitBreakerBreach {
+    type Error = anyhow::Error;
+
+    fn try_from(breach: pb::ValueCircuitBreakerBreach) -> Result<Self, Self::Error> {
+        Ok(Self {
+            height: breach.height,
+            trading_pair: breach
+                .trading_pair
+                .ok_or_else(|| anyhow::anyhow!("missing trading pair"))?
+                .try_into()?,
+            asset_id: breach
+                .asset_id
+                .ok_or_else(|| anyhow::anyhow!("missing asset id"))?
+                .try_into()?,
+            available: breach
+                .available
+                .ok_or_else(|| anyhow::anyhow!("missing available amount"))?
+                .try_into()?,
+            outflow: breach
+                .outflow
+                .ok_or_else(|| anyhow::anyhow!("missing outflow amount"))?
+                .try_into()?,
+            positions: breach
+                .positions
+                .into_iter()
+                .map(TryInto::try_into)
+                .collect::<Result<_, _>>()?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn breach_records_round_trip() -> anyhow::Result<()> {
+        let dir = tempfile::tempdir()?;
+        let path = dir.path().join("value_circuit_breaker_breach.json");
+        assert_eq!(ValueCircuitBreakerBreach::read_record(&path)?, None);
+
+        let gm = asset::Cache::with_known_assets().get_unit("gm").unwrap();
+        let gn = asset::Cache::with_known_assets().get_unit("gn").unwrap();
+        let breach = ValueCircuitBreakerBreach {
+            height: 10,
+            trading_pair: TradingPair::new(gm.id(), gn.id()),
+            asset_id: gn.id(),
+            available: 1u64.into(),
+            outflow: 2u64.into(),
+            positions: vec![],
+        };
+        breach.write_record(&path)?;
+        assert_eq!(ValueCircuitBreakerBreach::read_record(&path)?, Some(breach));
+
+        Ok(())
+    }
+}
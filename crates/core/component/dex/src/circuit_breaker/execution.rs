@@ -1,5 +1,8 @@
-const MAX_PATH_SEARCHES: u32 = 64;
-const MAX_EXECUTIONS: u32 = 64;
+use super::ExecutionCircuitBreakerTrip;
+use crate::DirectedTradingPair;
+
+pub(super) const MAX_PATH_SEARCHES: u32 = 64;
+pub(super) const MAX_EXECUTIONS: u32 = 64;
 
 /// Holds the state of the execution circuit breaker.
 /// Responsible for managing the conditions of halting execution of
@@ -35,6 +38,35 @@ impl ExecutionCircuitBreaker {
         self.current_path_searches > self.max_path_searches
             || self.current_executions > self.max_executions
     }
+
+    /// If the circuit breaker was triggered while routing along the given pair, record it.
+    pub fn trip(
+        &self,
+        height: u64,
+        trading_pair: DirectedTradingPair,
+    ) -> Option<ExecutionCircuitBreakerTrip> {
+        self.exceeded_limits()
+            .then_some(ExecutionCircuitBreakerTrip {
+                height,
+                trading_pair,
+                path_searches: self.current_path_searches,
+                executions: self.current_executions,
+            })
+    }
+
+    /// Export the work consumed by a single routing run, labeled by what was being routed.
+    #[cfg(feature = "component")]
+    pub fn record_metrics(&self, kind: &'static str) {
+        use crate::component::metrics;
+
+        metrics::counter!(metrics::DEX_CIRCUIT_BREAKER_PATH_SEARCHES, "kind" => kind)
+            .increment(self.current_path_searches.into());
+        metrics::counter!(metrics::DEX_CIRCUIT_BREAKER_EXECUTIONS, "kind" => kind)
+            .increment(self.current_executions.into());
+        if self.exceeded_limits() {
+            metrics::counter!(metrics::DEX_CIRCUIT_BREAKER_TRIGGERED, "kind" => kind).increment(1);
+        }
+    }
 }
 
 impl Default for ExecutionCircuitBreaker {
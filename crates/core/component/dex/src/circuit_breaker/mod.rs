@@ -1,5 +1,9 @@
+mod breach;
 mod execution;
+mod status;
 mod value;
 
+pub use breach::ValueCircuitBreakerBreach;
 pub(crate) use execution::ExecutionCircuitBreaker;
+pub use status::{CircuitBreakerStatus, ExecutionCircuitBreakerTrip};
 pub(crate) use value::ValueCircuitBreaker;
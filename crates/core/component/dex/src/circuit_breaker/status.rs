@@ -0,0 +1,106 @@
+use penumbra_proto::{penumbra::core::component::dex::v1 as pb, DomainType};
+use serde::{Deserialize, Serialize};
+
+use super::execution::{MAX_EXECUTIONS, MAX_PATH_SEARCHES};
+use crate::DirectedTradingPair;
+
+/// A record of the execution circuit breaker halting routing of a batch swap.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(
+    try_from = "pb::ExecutionCircuitBreakerTrip",
+    into = "pb::ExecutionCircuitBreakerTrip"
+)]
+pub struct ExecutionCircuitBreakerTrip {
+    /// The height at which the circuit breaker was triggered.
+    pub height: u64,
+    /// The direction of the batch swap whose routing was halted.
+    pub trading_pair: DirectedTradingPair,
+    /// The number of path searches performed before routing was halted.
+    pub path_searches: u32,
+    /// The number of executions against positions performed before routing was halted.
+    pub executions: u32,
+}
+
+/// The status of the execution circuit breaker.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(
+    try_from = "pb::CircuitBreakerStatus",
+    into = "pb::CircuitBreakerStatus"
+)]
+pub struct CircuitBreakerStatus {
+    /// The maximum number of path searches performed when routing a single input.
+    pub max_path_searches: u32,
+    /// The maximum number of executions against positions when routing a single input.
+    pub max_executions: u32,
+    /// The most recent time the circuit breaker halted routing of a batch swap, if ever.
+    pub last_trip: Option<ExecutionCircuitBreakerTrip>,
+}
+
+impl CircuitBreakerStatus {
+    /// The status of the circuit breaker with its default limits, given the last time it was
+    /// triggered.
+    pub fn new(last_trip: Option<ExecutionCircuitBreakerTrip>) -> Self {
+        Self {
+            max_path_searches: MAX_PATH_SEARCHES,
+            max_executions: MAX_EXECUTIONS,
+            last_trip,
+        }
+    }
+}
+
+impl DomainType for ExecutionCircuitBreakerTrip {
+    type Proto = pb::ExecutionCircuitBreakerTrip;
+}
+
+impl From<ExecutionCircuitBreakerTrip> for pb::ExecutionCircuitBreakerTrip {
+    fn from(trip: ExecutionCircuitBreakerTrip) -> Self {
+        Self {
+            height: trip.height,
+            trading_pair: Some(trip.trading_pair.into()),
+            path_searches: trip.path_searches,
+            executions: trip.executions,
+        }
+    }
+}
+
+impl TryFrom<pb::ExecutionCircuitBreakerTrip> for ExecutionCircuitBreakerTrip {
+    type Error = anyhow::Error;
+
+    fn try_from(trip: pb::ExecutionCircuitBreakerTrip) -> Result<Self, Self::Error> {
+        Ok(Self {
+            height: trip.height,
+            trading_pair: trip
+                .trading_pair
+                .ok_or_else(|| anyhow::anyhow!("missing trading pair"))?
+                .try_into()?,
+            path_searches: trip.path_searches,
+            executions: trip.executions,
+        })
+    }
+}
+
+impl DomainType for CircuitBreakerStatus {
+    type Proto = pb::CircuitBreakerStatus;
+}
+
+impl From<CircuitBreakerStatus> for pb::CircuitBreakerStatus {
+    fn from(status: CircuitBreakerStatus) -> Self {
+        Self {
+            max_path_searches: status.max_path_searches,
+            max_executions: status.max_executions,
+            last_trip: status.last_trip.map(Into::into),
+        }
+    }
+}
+
+impl TryFrom<pb::CircuitBreakerStatus> for CircuitBreakerStatus {
+    type Error = anyhow::Error;
+
+    fn try_from(status: pb::CircuitBreakerStatus) -> Result<Self, Self::Error> {
+        Ok(Self {
+            max_path_searches: status.max_path_searches,
+            max_executions: status.max_executions,
+            last_trip: status.last_trip.map(TryInto::try_into).transpose()?,
+        })
+    }
+}
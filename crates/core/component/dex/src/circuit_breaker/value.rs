@@ -26,6 +26,12 @@ impl ValueCircuitBreaker {
         Ok(())
     }
 
+    /// The amounts by which the outflows of any assets exceed their deposits, of which there are
+    /// none unless the circuit breaker has been breached.
+    pub fn deficits(&self) -> impl Iterator<Item = Value> + '_ {
+        self.balance.required()
+    }
+
     pub fn available(&self, asset_id: Id) -> Value {
         self.balance
             .provided()
@@ -43,18 +49,18 @@ mod tests {
 
     use crate::component::position_manager::Inner as _;
     use crate::component::router::{HandleBatchSwaps as _, RoutingParams};
-    use crate::component::{StateReadExt as _, StateWriteExt as _};
+    use crate::component::{Dex, StateReadExt as _, StateWriteExt as _};
     use crate::{
         component::{router::limit_buy, tests::TempStorageExt, PositionManager as _},
-        state_key, DirectedUnitPair,
-    };
-    use cnidarium::{
-        ArcStateDeltaExt as _, StateDelta, StateRead as _, StateWrite as _, TempStorage,
+        state_key, DirectedUnitPair, TradingPair, ValueCircuitBreakerBreach,
     };
+    use cnidarium::{ArcStateDeltaExt as _, StateDelta, StateRead as _, StateWrite, TempStorage};
+    use cnidarium_component::Component as _;
     use penumbra_asset::{asset, Value};
     use penumbra_num::Amount;
     use penumbra_proto::StateWriteProto as _;
     use rand_core::OsRng;
+    use tendermint::v0_37::abci;
 
     use crate::{
         lp::{position::Position, Reserves},
@@ -185,32 +191,27 @@ mod tests {
 
         // This should error, since there is no balance available to close out the position.
         buy_1.state = crate::lp::position::State::Closed;
-        assert!(state_tx.put_position(buy_1).await.is_err());
+        let breach = state_tx
+            .put_position(buy_1.clone())
+            .await
+            .expect_err("closing the position should breach the circuit breaker")
+            .downcast::<ValueCircuitBreakerBreach>()?;
+        assert_eq!(breach.asset_id, gm.id());
+        assert_eq!(breach.available, Amount::zero());
+        assert_eq!(breach.outflow, 2u64.into());
+        assert_eq!(breach.positions, vec![buy_1.id()]);
 
         Ok(())
     }
 
-    #[tokio::test]
-    #[should_panic(expected = "balance for asset")]
-    async fn batch_swap_circuit_breaker() {
-        let _ = tracing_subscriber::fmt::try_init();
-        let storage = TempStorage::new()
-            .await
-            .expect("able to create storage")
-            .apply_minimal_genesis()
-            .await
-            .expect("able to apply genesis");
-        let mut state = Arc::new(StateDelta::new(storage.latest_snapshot()));
-        let mut state_tx = state.try_begin_transaction().unwrap();
-
+    /// Put a position without calling `put_position`, so that the circuit breaker is
+    /// not aware of its value, and a batch swap which fills against it.
+    async fn put_unbacked_position_and_swap<S: StateWrite>(state: &mut S) -> TradingPair {
         let gm = asset::Cache::with_known_assets().get_unit("gm").unwrap();
         let gn = asset::Cache::with_known_assets().get_unit("gn").unwrap();
 
         let pair_1 = DirectedUnitPair::new(gm.clone(), gn.clone());
 
-        // Manually put a position without calling `put_position` so that the
-        // circuit breaker is not aware of the position's value. Then, handling a batch
-        // swap that fills against this position should result in an error.
         let one = 1u64.into();
         let price1 = one;
         // Create a position buying 1 gm with 1 gn (i.e. reserves will be 1gn).
@@ -218,17 +219,17 @@ mod tests {
 
         let id = buy_1.id();
 
-        let position = state_tx.handle_limit_order(&None, buy_1);
-        state_tx.index_position_by_price(&position);
-        state_tx
+        let position = state.handle_limit_order(&None, buy_1);
+        state.index_position_by_price(&position);
+        state
             .update_available_liquidity(&position, &None)
             .await
             .expect("able to update liquidity");
-        state_tx.put(state_key::position_by_id(&id), position);
+        state.put(state_key::position_by_id(&id), position);
 
         // Now there's a position in the state, but the circuit breaker is not aware of it.
         let trading_pair = pair_1.into_directed_trading_pair().into();
-        let mut swap_flow = state_tx.swap_flow(&trading_pair);
+        let mut swap_flow = state.swap_flow(&trading_pair);
 
         assert!(trading_pair.asset_1() == gm.id());
 
@@ -237,7 +238,26 @@ mod tests {
         swap_flow.1 += 0u32.into();
 
         // Set the batch swap flow for the trading pair.
-        state_tx.put_swap_flow(&trading_pair, swap_flow.clone());
+        state.put_swap_flow(&trading_pair, swap_flow);
+
+        trading_pair
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "balance for asset")]
+    async fn batch_swap_circuit_breaker() {
+        let _ = tracing_subscriber::fmt::try_init();
+        let storage = TempStorage::new()
+            .await
+            .expect("able to create storage")
+            .apply_minimal_genesis()
+            .await
+            .expect("able to apply genesis");
+        let mut state = Arc::new(StateDelta::new(storage.latest_snapshot()));
+        let mut state_tx = state.try_begin_transaction().unwrap();
+
+        let trading_pair = put_unbacked_position_and_swap(&mut state_tx).await;
+        let swap_flow = state_tx.swap_flow(&trading_pair);
         state_tx.apply();
 
         // This call should panic due to the outflow of gn not being covered by the circuit breaker.
@@ -246,4 +266,25 @@ mod tests {
             .await
             .expect("unable to process batch swaps");
     }
+
+    #[tokio::test]
+    #[should_panic(expected = "balance for asset")]
+    async fn end_block_halts_on_value_circuit_breaker_breach() {
+        let _ = tracing_subscriber::fmt::try_init();
+        let storage = TempStorage::new()
+            .await
+            .expect("able to create storage")
+            .apply_minimal_genesis()
+            .await
+            .expect("able to apply genesis");
+        let mut state = Arc::new(StateDelta::new(storage.latest_snapshot()));
+        let mut state_tx = state.try_begin_transaction().unwrap();
+
+        put_unbacked_position_and_swap(&mut state_tx).await;
+        state_tx.apply();
+
+        // Executing the batch swap at the end of the block should halt the chain,
+        // rather than committing the fills against the position.
+        Dex::end_block(&mut state, &abci::request::EndBlock { height: 0 }).await;
+    }
 }
@@ -0,0 +1,435 @@
+//! Mirroring the DEX's liquidity positions from the events emitted by the chain.
+//!
+//! Market makers and trading bots need an up-to-date view of the positions on
+//! the pairs they trade.  Rather than polling the node for every position, an
+//! [`OrderBook`] can be kept in sync by applying the position events of each
+//! block in turn, as they are delivered by a CometBFT event subscription.
+//!
+//! Blocks are applied in order of height, which serves as the sequence number
+//! of the event stream: if a block is missed, applying the next one fails with a
+//! [`Gap`], and the book must be reset from a snapshot of the open positions
+//! before it can be used again.  With the `client` feature, a [`Mirror`] does
+//! this, and resolves newly opened positions, by querying a node.
+
+use std::collections::{BTreeMap, BTreeSet};
+
+use anyhow::Result;
+use penumbra_proto::{core::component::dex::v1 as pb, event::ProtoEvent};
+use tendermint::abci;
+
+use crate::{
+    lp::{
+        position::{self, Position},
+        Reserves,
+    },
+    DirectedTradingPair,
+};
+
+/// A change to a liquidity position, decoded from an event.
+#[derive(Clone, Debug)]
+pub enum PositionEvent {
+    /// A position was opened.  The event does not include the position's
+    /// trading function, so it must be fetched separately.
+    Open(position::Id),
+    /// A position was closed, and can no longer be traded against.
+    Close(position::Id),
+    /// A position's reserves were withdrawn.
+    Withdraw(position::Id),
+    /// A position was traded against, leaving it with the given reserves.
+    Execution(position::Id, Reserves),
+}
+
+impl PositionEvent {
+    /// Decode a position event from an ABCI event, returning `None` for
+    /// events of any other kind.
+    pub fn from_abci(event: &abci::Event) -> Result<Option<Self>> {
+        let id = |id: Option<pb::PositionId>| -> Result<position::Id> {
+            id.ok_or_else(|| anyhow::anyhow!("missing position id"))?
+                .try_into()
+        };
+
+        let kind = event.kind.as_str();
+        let event = if kind == <pb::EventPositionOpen as prost::Name>::full_name() {
+            let e = pb::EventPositionOpen::from_event(event)?;
+            Self::Open(id(e.position_id)?)
+        } else if kind == <pb::EventPositionClose as prost::Name>::full_name() {
+            let e = pb::EventPositionClose::from_event(event)?;
+            Self::Close(id(e.position_id)?)
+        } else if kind == <pb::EventPositionWithdraw as prost::Name>::full_name() {
+            let e = pb::EventPositionWithdraw::from_event(event)?;
+            Self::Withdraw(id(e.position_id)?)
+        } else if kind == <pb::EventPositionExecution as prost::Name>::full_name() {
+            let e = pb::EventPositionExecution::from_event(event)?;
+            let reserves = Reserves {
+                r1: e
+                    .reserves_1
+                    .ok_or_else(|| anyhow::anyhow!("missing reserves_1"))?
+                    .try_into()?,
+                r2: e
+                    .reserves_2
+                    .ok_or_else(|| anyhow::anyhow!("missing reserves_2"))?
+                    .try_into()?,
+            };
+            Self::Execution(id(e.position_id)?, reserves)
+        } else {
+            return Ok(None);
+        };
+
+        Ok(Some(event))
+    }
+}
+
+/// The position events of a block could not be applied, because those of some
+/// earlier blocks were never applied.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, thiserror::Error)]
+#[error("received position events for block {received}, but the last block applied was {last:?}")]
+pub struct Gap {
+    /// The height of the last block applied to the book, if any.
+    pub last: Option<u64>,
+    /// The height of the block whose events were received.
+    pub received: u64,
+}
+
+/// An in-memory mirror of the open liquidity positions on the DEX.
+#[derive(Clone, Debug, Default)]
+pub struct OrderBook {
+    height: Option<u64>,
+    positions: BTreeMap<position::Id, Position>,
+    pending: BTreeSet<position::Id>,
+}
+
+impl OrderBook {
+    /// Create an empty book, which must be [`reset`](Self::reset) before any
+    /// blocks can be applied to it.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The height of the last block applied to the book, or `None` if it has
+    /// never been synchronized.
+    pub fn height(&self) -> Option<u64> {
+        self.height
+    }
+
+    /// Replace the contents of the book with a snapshot of the positions as of
+    /// (at least) the given height.
+    ///
+    /// The snapshot may be more recent than the height, in which case some of
+    /// the events replayed after it are already reflected in it.  Replaying them
+    /// is harmless, since each event sets the state it describes, so the book
+    /// is exact again once it has caught up with the snapshot.
+    pub fn reset(&mut self, height: u64, positions: impl IntoIterator<Item = Position>) {
+        self.height = Some(height);
+        self.positions.clear();
+        self.pending.clear();
+        for position in positions {
+            self.insert_position(position);
+        }
+    }
+
+    /// Apply the position events of the block at the given height.
+    ///
+    /// Blocks at or below the current height have already been applied, and
+    /// are ignored, so that redelivered blocks are harmless.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`Gap`] without applying any events if the book has never
+    /// been synchronized, or if the block is not the one after the last block
+    /// applied.
+    pub fn apply_block(
+        &mut self,
+        height: u64,
+        events: impl IntoIterator<Item = PositionEvent>,
+    ) -> Result<(), Gap> {
+        let gap = Gap {
+            last: self.height,
+            received: height,
+        };
+        match self.height {
+            Some(last) if height <= last => return Ok(()),
+            Some(last) if height == last + 1 => {}
+            _ => return Err(gap),
+        }
+
+        for event in events {
+            match event {
+                PositionEvent::Open(id) => {
+                    self.pending.insert(id);
+                }
+                PositionEvent::Close(id) | PositionEvent::Withdraw(id) => {
+                    self.positions.remove(&id);
+                    self.pending.remove(&id);
+                }
+                PositionEvent::Execution(id, reserves) => match self.positions.get_mut(&id) {
+                    Some(position) => position.reserves = reserves,
+                    // The position was opened in this block and is yet to be
+                    // fetched, which will pick up its new reserves.
+                    None => {
+                        self.pending.insert(id);
+                    }
+                },
+            }
+        }
+        self.height = Some(height);
+
+        Ok(())
+    }
+
+    /// The positions which were opened but whose trading functions are not yet
+    /// known, and must be fetched and [inserted](Self::insert_position).
+    pub fn pending(&self) -> impl Iterator<Item = &position::Id> {
+        self.pending.iter()
+    }
+
+    /// Record the current state of a position, as fetched from a node.
+    ///
+    /// Positions which are no longer open are removed from the book.
+    pub fn insert_position(&mut self, position: Position) {
+        let id = position.id();
+        self.pending.remove(&id);
+        if position.state == position::State::Opened {
+            self.positions.insert(id, position);
+        } else {
+            self.positions.remove(&id);
+        }
+    }
+
+    /// Look up an open position by its ID.
+    pub fn position(&self, id: &position::Id) -> Option<&Position> {
+        self.positions.get(id)
+    }
+
+    /// Iterate over all the open positions in the book.
+    pub fn positions(&self) -> impl Iterator<Item = &Position> {
+        self.positions.values()
+    }
+
+    /// The open positions which sell the end asset of the pair for its start
+    /// asset, from the best price to the worst.
+    pub fn positions_on(&self, pair: DirectedTradingPair) -> Vec<&Position> {
+        let mut offers = self
+            .positions
+            .values()
+            .filter_map(|position| {
+                let phi = position.effective_phi().orient_start(pair.start)?;
+                if position.reserves_for(pair.end) > Some(0u64.into()) {
+                    Some((phi.effective_price_key_bytes(), position))
+                } else {
+                    None
+                }
+            })
+            .collect::<Vec<_>>();
+        offers.sort_by_key(|(price, _)| *price);
+        offers.into_iter().map(|(_, position)| position).collect()
+    }
+}
+
+#[cfg(feature = "client")]
+pub use mirror::Mirror;
+
+#[cfg(feature = "client")]
+mod mirror {
+    use anyhow::Result;
+    use futures::TryStreamExt;
+    use penumbra_proto::core::component::dex::v1::{
+        query_service_client::QueryServiceClient, LiquidityPositionsByIdRequest,
+        LiquidityPositionsRequest,
+    };
+    use tendermint::abci;
+    use tonic::transport::Channel;
+
+    use super::{OrderBook, PositionEvent};
+    use crate::lp::position::Position;
+
+    /// An [`OrderBook`] kept in sync with the chain by querying a node.
+    #[derive(Clone, Debug)]
+    pub struct Mirror {
+        client: QueryServiceClient<Channel>,
+        book: OrderBook,
+    }
+
+    impl Mirror {
+        /// Create a mirror which queries the given node, starting out empty.
+        pub fn new(client: QueryServiceClient<Channel>) -> Self {
+            Self {
+                client,
+                book: OrderBook::new(),
+            }
+        }
+
+        /// The mirrored order book.
+        pub fn book(&self) -> &OrderBook {
+            &self.book
+        }
+
+        /// Apply the events of the block at the given height, which should be
+        /// delivered in order.
+        ///
+        /// If the events of an earlier block were missed, the book is reset
+        /// from the node's open positions instead, which must reflect at least
+        /// the given block.  Either way, any newly opened positions are then
+        /// fetched from the node.
+        #[tracing::instrument(skip(self, events))]
+        pub async fn handle_block(&mut self, height: u64, events: &[abci::Event]) -> Result<()> {
+            let events = events
+                .iter()
+                .filter_map(|event| PositionEvent::from_abci(event).transpose())
+                .collect::<Result<Vec<_>>>()?;
+
+            if let Err(gap) = self.book.apply_block(height, events) {
+                tracing::warn!(%gap, "resynchronizing order book");
+                self.resync(height).await?;
+            }
+
+            self.fetch_pending().await
+        }
+
+        /// Reset the book from the node's open positions, which must reflect at
+        /// least the block at the given height.
+        pub async fn resync(&mut self, height: u64) -> Result<()> {
+            let positions = self
+                .client
+                .liquidity_positions(LiquidityPositionsRequest {
+                    include_closed: false,
+                })
+                .await?
+                .into_inner()
+                .map_err(anyhow::Error::from)
+                .and_then(|response| async move {
+                    Position::try_from(
+                        response
+                            .data
+                            .ok_or_else(|| anyhow::anyhow!("missing position"))?,
+                    )
+                })
+                .try_collect::<Vec<_>>()
+                .await?;
+
+            tracing::debug!(height, count = positions.len(), "reset order book");
+            self.book.reset(height, positions);
+            Ok(())
+        }
+
+        /// Fetch the positions whose trading functions are not yet known.
+        async fn fetch_pending(&mut self) -> Result<()> {
+            let position_id = self
+                .book
+                .pending()
+                .map(|id| (*id).into())
+                .collect::<Vec<_>>();
+            if position_id.is_empty() {
+                return Ok(());
+            }
+
+            let mut positions = self
+                .client
+                .liquidity_positions_by_id(LiquidityPositionsByIdRequest { position_id })
+                .await?
+                .into_inner();
+            while let Some(response) = positions.try_next().await? {
+                let position: Position = response
+                    .data
+                    .ok_or_else(|| anyhow::anyhow!("missing position"))?
+                    .try_into()?;
+                self.book.insert_position(position);
+            }
+
+            Ok(())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use penumbra_asset::asset;
+    use rand_core::OsRng;
+
+    use super::*;
+
+    fn pair() -> DirectedTradingPair {
+        let gm = asset::Cache::with_known_assets()
+            .get_unit("gm")
+            .expect("gm is a known asset")
+            .id();
+        let gn = asset::Cache::with_known_assets()
+            .get_unit("gn")
+            .expect("gn is a known asset")
+            .id();
+        DirectedTradingPair::new(gm, gn)
+    }
+
+    fn position(p: u64, q: u64) -> Position {
+        Position::new(
+            OsRng,
+            pair(),
+            0,
+            p.into(),
+            q.into(),
+            Reserves {
+                r1: 0u64.into(),
+                r2: 100u64.into(),
+            },
+        )
+    }
+
+    #[test]
+    fn gaps_are_detected() {
+        let mut book = OrderBook::new();
+        assert_eq!(
+            book.apply_block(1, []),
+            Err(Gap {
+                last: None,
+                received: 1
+            })
+        );
+
+        book.reset(10, []);
+        // Redelivered blocks are ignored.
+        assert_eq!(book.apply_block(9, []), Ok(()));
+        assert_eq!(book.apply_block(11, []), Ok(()));
+        assert_eq!(
+            book.apply_block(13, []),
+            Err(Gap {
+                last: Some(11),
+                received: 13
+            })
+        );
+        assert_eq!(book.height(), Some(11));
+    }
+
+    #[test]
+    fn events_update_the_book() {
+        let cheap = position(1, 1);
+        let dear = position(1, 2);
+        let mut book = OrderBook::new();
+        book.reset(0, [dear.clone()]);
+
+        book.apply_block(1, [PositionEvent::Open(cheap.id())])
+            .expect("block is contiguous");
+        assert_eq!(book.pending().collect::<Vec<_>>(), [&cheap.id()]);
+        book.insert_position(cheap.clone());
+        assert_eq!(book.pending().count(), 0);
+
+        let ids = |book: &OrderBook| {
+            book.positions_on(pair())
+                .into_iter()
+                .map(Position::id)
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(ids(&book), [cheap.id(), dear.id()]);
+
+        // Once the cheap position runs out of the end asset, it no longer offers it.
+        let filled = Reserves {
+            r1: cheap.reserves.r2,
+            r2: cheap.reserves.r1,
+        };
+        book.apply_block(2, [PositionEvent::Execution(cheap.id(), filled)])
+            .expect("block is contiguous");
+        assert_eq!(ids(&book), [dear.id()]);
+
+        book.apply_block(3, [PositionEvent::Close(dear.id())])
+            .expect("block is contiguous");
+        assert!(book.position(&dear.id()).is_none());
+    }
+}
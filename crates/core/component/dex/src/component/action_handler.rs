@@ -1,3 +1,4 @@
+mod auction;
 mod position;
 mod swap;
 mod swap_claim;
@@ -0,0 +1,30 @@
+use std::sync::Arc;
+
+use anyhow::Result;
+use async_trait::async_trait;
+use cnidarium::{StateRead, StateWrite};
+use cnidarium_component::ActionHandler;
+
+use crate::{auction::action::ActionDutchAuctionEnd, component::DutchAuctionManager};
+
+#[async_trait]
+/// Debits an opened auction NFT and credits an ended auction NFT.
+impl ActionHandler for ActionDutchAuctionEnd {
+    type CheckStatelessContext = ();
+    async fn check_stateless(&self, _context: ()) -> Result<()> {
+        // Nothing to do: the only validation is of the state change,
+        // and that's done by the value balance mechanism.
+        Ok(())
+    }
+
+    async fn check_stateful<S: StateRead + 'static>(&self, _state: Arc<S>) -> Result<()> {
+        Ok(())
+    }
+
+    async fn execute<S: StateWrite>(&self, mut state: S) -> Result<()> {
+        // Unlike closing a position, ending an auction takes effect
+        // immediately: the auction only trades at the end of the block, so
+        // there's no intra-block ordering for its economic effects to depend on.
+        state.end_dutch_auction(&self.auction_id).await
+    }
+}
@@ -0,0 +1,41 @@
+use std::sync::Arc;
+
+use anyhow::Result;
+use async_trait::async_trait;
+use cnidarium::{StateRead, StateWrite};
+use cnidarium_component::ActionHandler;
+use penumbra_sct::component::clock::EpochRead;
+use penumbra_stake::component::StateReadExt as _;
+
+use crate::{auction::action::ActionDutchAuctionSchedule, component::DutchAuctionManager};
+
+#[async_trait]
+/// Debits the auction's input and credits an opened auction NFT.
+impl ActionHandler for ActionDutchAuctionSchedule {
+    type CheckStatelessContext = ();
+    async fn check_stateless(&self, _context: ()) -> Result<()> {
+        self.description.check_stateless()
+    }
+
+    async fn check_stateful<S: StateRead + 'static>(&self, state: Arc<S>) -> Result<()> {
+        // Auctions can't be scheduled to start in the past, since they would
+        // skip part of their price schedule.
+        let current_height = state.get_block_height().await?;
+        if self.description.start_height < current_height {
+            anyhow::bail!(
+                "auction start height {} is before the current height {}",
+                self.description.start_height,
+                current_height
+            );
+        }
+        Ok(())
+    }
+
+    async fn execute<S: StateWrite>(&self, mut state: S) -> Result<()> {
+        // As for positions, the staking component may bar delegation tokens from auctions.
+        state
+            .check_delegation_token_usable(&self.description.input.asset_id)
+            .await?;
+        state.schedule_dutch_auction(self.description.clone()).await
+    }
+}
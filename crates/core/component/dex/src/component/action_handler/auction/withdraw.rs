@@ -0,0 +1,80 @@
+use std::sync::Arc;
+
+use anyhow::{anyhow, Result};
+use ark_ff::Zero;
+use async_trait::async_trait;
+use cnidarium::{StateRead, StateWrite};
+use cnidarium_component::ActionHandler;
+use decaf377::Fr;
+use penumbra_num::Amount;
+use penumbra_proto::StateWriteProto;
+
+use crate::{
+    auction::action::ActionDutchAuctionWithdraw, component::DutchAuctionRead, event, state_key,
+};
+
+#[async_trait]
+/// Debits an ended auction NFT and credits a withdrawn auction NFT and the auction's reserves.
+impl ActionHandler for ActionDutchAuctionWithdraw {
+    type CheckStatelessContext = ();
+    async fn check_stateless(&self, _context: ()) -> Result<()> {
+        // Auctions are only withdrawn from once, after they've ended.
+        if self.seq != 2 {
+            anyhow::bail!(
+                "auction withdrawals must have sequence number 2, found {}",
+                self.seq
+            );
+        }
+        Ok(())
+    }
+
+    async fn check_stateful<S: StateRead + 'static>(&self, _state: Arc<S>) -> Result<()> {
+        // As for positions, we defer consistency checks on the reserves to
+        // execution, to avoid having to reason about parallelism in checks.
+        Ok(())
+    }
+
+    async fn execute<S: StateWrite>(&self, mut state: S) -> Result<()> {
+        let mut auction = state
+            .dutch_auction_by_id(&self.auction_id)
+            .await?
+            .ok_or_else(|| anyhow!("withdrew from unknown auction {}", self.auction_id))?;
+
+        // This should be redundant with the value balance mechanism, but we
+        // check it here for defense in depth.
+        if auction.state.seq + 1 != self.seq {
+            anyhow::bail!(
+                "attempted to withdraw auction {} with sequence {}, expected {}",
+                self.auction_id,
+                self.seq,
+                auction.state.seq + 1
+            );
+        }
+
+        // As for `PositionWithdraw`, the commitment is used for compression
+        // rather than shielding.
+        let expected_reserves_commitment = auction.reserves().commit(Fr::zero());
+        if self.reserves_commitment != expected_reserves_commitment {
+            anyhow::bail!(
+                "reserves commitment {:?} is incorrect, expected {:?}",
+                self.reserves_commitment,
+                expected_reserves_commitment
+            );
+        }
+
+        // Record an event prior to updating the auction state, so we have
+        // access to the withdrawn reserves.
+        state.record_proto(event::dutch_auction_withdrawn(
+            self.auction_id,
+            &auction.state,
+        ));
+
+        // Zero out the reserves, to prevent double-withdrawals.
+        auction.state.seq = self.seq;
+        auction.state.input_reserves = Amount::zero();
+        auction.state.output_reserves = Amount::zero();
+        state.put(state_key::dutch_auction::by_id(&self.auction_id), auction);
+
+        Ok(())
+    }
+}
@@ -1,3 +1,6 @@
 mod close;
 mod open;
+mod open_batch;
+mod rewrite;
+mod update_batch;
 mod withdraw;
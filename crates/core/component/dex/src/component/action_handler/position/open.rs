@@ -4,12 +4,19 @@ use anyhow::Result;
 use async_trait::async_trait;
 use cnidarium::{StateRead, StateWrite};
 use cnidarium_component::ActionHandler;
+use penumbra_num::Amount;
 use penumbra_proto::StateWriteProto as _;
+use penumbra_stake::component::StateReadExt as _;
 
 use crate::{
-    component::{PositionManager, PositionRead},
+    component::{PositionManager, PositionRead, StateReadExt as _},
     event,
-    lp::{action::PositionOpen, position},
+    lp::{
+        action::PositionOpen,
+        position::{self, Position},
+        MAX_STRATEGY_TAG_LEN,
+    },
+    DexParameters,
 };
 
 #[async_trait]
@@ -29,18 +36,82 @@ impl ActionHandler for PositionOpen {
         if self.position.state != position::State::Opened {
             anyhow::bail!("attempted to open a position with a state besides `Opened`");
         }
+        if self.strategy_tag.len() > MAX_STRATEGY_TAG_LEN {
+            anyhow::bail!(
+                "strategy tag is {} bytes long, longer than the maximum of {MAX_STRATEGY_TAG_LEN}",
+                self.strategy_tag.len()
+            );
+        }
         Ok(())
     }
 
-    async fn check_stateful<S: StateRead + 'static>(&self, _state: Arc<S>) -> Result<()> {
-        Ok(())
+    async fn check_stateful<S: StateRead + 'static>(&self, state: Arc<S>) -> Result<()> {
+        self.check_against_state(state.as_ref()).await
     }
 
     async fn execute<S: StateWrite>(&self, mut state: S) -> Result<()> {
+        check_reserves_usable(&state, &self.position).await?;
         // Validate that the position ID doesn't collide
         state.check_position_id_unused(&self.position.id()).await?;
         state.put_position(self.position.clone()).await?;
+        state
+            .record_position_metadata(&self.position, self.strategy_tag.clone(), None)
+            .await?;
         state.record_proto(event::position_open(self));
         Ok(())
     }
 }
+
+impl PositionOpen {
+    /// Check the position against the chain state, as
+    /// [`check_stateful`](ActionHandler::check_stateful) does.
+    ///
+    /// This doesn't need a snapshot of the state, so it can also check
+    /// positions which the chain opens on a user's behalf while executing
+    /// other actions.
+    pub async fn check_against_state<S: StateRead>(&self, state: &S) -> Result<()> {
+        let dex_params = state.get_dex_params().await?;
+        check_open_allowed(&dex_params, &self.position)
+    }
+}
+
+/// Check that a newly opened position uses one of the fee tiers allowed by governance, on a
+/// trading pair where governance hasn't paused trading.
+pub(super) fn check_open_allowed(dex_params: &DexParameters, position: &Position) -> Result<()> {
+    if dex_params.is_paused(&position.phi.pair) {
+        anyhow::bail!(
+            "trading on the pair {} is paused by governance",
+            position.phi.pair
+        );
+    }
+    let fee = position.phi.component.fee;
+    if !dex_params.allows_fee(fee) {
+        anyhow::bail!(
+            "position fee of {}bps is not one of the allowed fee tiers {:?}",
+            fee,
+            dex_params.fee_tiers
+        );
+    }
+    Ok(())
+}
+
+/// Check that the reserves of a newly opened position don't include delegation tokens which the
+/// staking component bars from the DEX.
+///
+/// This is checked during execution, since the validators whose delegation tokens are barred can
+/// change between the checks of a transaction and its execution.
+pub(super) async fn check_reserves_usable<S: StateRead + ?Sized>(
+    state: &S,
+    position: &Position,
+) -> Result<()> {
+    let pair = position.phi.pair;
+    for (asset_id, reserves) in [
+        (pair.asset_1(), position.reserves.r1),
+        (pair.asset_2(), position.reserves.r2),
+    ] {
+        if reserves > Amount::zero() {
+            state.check_delegation_token_usable(&asset_id).await?;
+        }
+    }
+    Ok(())
+}
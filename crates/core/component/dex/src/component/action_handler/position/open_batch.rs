@@ -0,0 +1,83 @@
+use std::{collections::BTreeSet, sync::Arc};
+
+use anyhow::Result;
+use async_trait::async_trait;
+use cnidarium::{StateRead, StateWrite};
+use cnidarium_component::ActionHandler;
+use penumbra_proto::StateWriteProto as _;
+
+use crate::{
+    component::{PositionManager, PositionRead, StateReadExt as _},
+    event,
+    lp::{action::PositionOpenBatch, position, MAX_STRATEGY_TAG_LEN},
+};
+
+use super::open::{check_open_allowed, check_reserves_usable};
+
+#[async_trait]
+/// Debits the combined initial reserves and credits an opened position NFT
+/// for each position in the batch.
+impl ActionHandler for PositionOpenBatch {
+    type CheckStatelessContext = ();
+    async fn check_stateless(&self, _context: ()) -> Result<()> {
+        if self.positions.is_empty() {
+            anyhow::bail!("position open batch is empty");
+        }
+        if self.positions.len() > Self::MAX_POSITIONS {
+            anyhow::bail!(
+                "position open batch has {} positions, but at most {} are allowed",
+                self.positions.len(),
+                Self::MAX_POSITIONS
+            );
+        }
+        if self.strategy_tag.len() > MAX_STRATEGY_TAG_LEN {
+            anyhow::bail!(
+                "strategy tag is {} bytes long, longer than the maximum of {MAX_STRATEGY_TAG_LEN}",
+                self.strategy_tag.len()
+            );
+        }
+
+        let mut opened = BTreeSet::new();
+        for position in &self.positions {
+            position.check_stateless()?;
+            if position.state != position::State::Opened {
+                anyhow::bail!("attempted to open a position with a state besides `Opened`");
+            }
+            if !opened.insert(position.id()) {
+                anyhow::bail!(
+                    "position {} is opened twice in the same batch",
+                    position.id()
+                );
+            }
+        }
+
+        Ok(())
+    }
+
+    async fn check_stateful<S: StateRead + 'static>(&self, state: Arc<S>) -> Result<()> {
+        let dex_params = state.get_dex_params().await?;
+        for position in &self.positions {
+            check_open_allowed(&dex_params, position)?;
+        }
+        Ok(())
+    }
+
+    async fn execute<S: StateWrite>(&self, mut state: S) -> Result<()> {
+        // Check every position ID before opening any, so that a collision
+        // leaves none of the batch half-applied.
+        for position in &self.positions {
+            state.check_position_id_unused(&position.id()).await?;
+            check_reserves_usable(&state, position).await?;
+        }
+
+        for open in self.opens() {
+            state.put_position(open.position.clone()).await?;
+            state
+                .record_position_metadata(&open.position, open.strategy_tag.clone(), None)
+                .await?;
+            state.record_proto(event::position_open(&open));
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,143 @@
+use std::sync::Arc;
+
+use anyhow::{anyhow, Result};
+use ark_ff::Zero;
+use async_trait::async_trait;
+use cnidarium::{StateRead, StateWrite};
+use cnidarium_component::ActionHandler;
+use decaf377::Fr;
+use penumbra_proto::StateWriteProto as _;
+
+use crate::{
+    component::{PositionManager, PositionRead, StateReadExt as _},
+    event,
+    lp::{
+        action::{PositionClose, PositionOpen, PositionRewrite, PositionWithdraw},
+        position, Reserves,
+    },
+};
+
+use super::open::{check_open_allowed, check_reserves_usable};
+
+#[async_trait]
+/// Debits an opened position NFT and the new position's initial reserves, and
+/// credits a withdrawn position NFT, the replaced position's final reserves,
+/// and an opened position NFT for the new position.
+impl ActionHandler for PositionRewrite {
+    type CheckStatelessContext = ();
+    async fn check_stateless(&self, _context: ()) -> Result<()> {
+        self.new_position.check_stateless()?;
+        if self.new_position.state != position::State::Opened {
+            anyhow::bail!("attempted to open a position with a state besides `Opened`");
+        }
+        if self.new_position.id() == self.position_id {
+            anyhow::bail!(
+                "attempted to replace position {} with itself",
+                self.position_id
+            );
+        }
+        Ok(())
+    }
+
+    async fn check_stateful<S: StateRead + 'static>(&self, state: Arc<S>) -> Result<()> {
+        // As with withdrawals, we defer consistency checks on the replaced
+        // position to execution, to avoid having to reason about parallelism
+        // in checks.
+        let dex_params = state.get_dex_params().await?;
+        check_open_allowed(&dex_params, &self.new_position)
+    }
+
+    async fn execute<S: StateWrite>(&self, mut state: S) -> Result<()> {
+        check_reserves_usable(&state, &self.new_position).await?;
+
+        let mut metadata = state
+            .position_by_id(&self.position_id)
+            .await?
+            .ok_or_else(|| anyhow!("rewrote unknown position {}", self.position_id))?;
+
+        // The replaced position is closed immediately, so it must still be open,
+        // and not already queued to be closed at the end of the block.
+        if metadata.state != position::State::Opened {
+            anyhow::bail!(
+                "attempted to rewrite position {} with state {}, expected Opened",
+                self.position_id,
+                metadata.state
+            );
+        }
+        if state
+            .pending_position_closures()
+            .contains(&self.position_id)
+        {
+            anyhow::bail!(
+                "attempted to rewrite position {}, which is already being closed",
+                self.position_id
+            );
+        }
+        if metadata.phi.pair != self.new_position.phi.pair {
+            anyhow::bail!(
+                "attempted to rewrite position {} on {} with a position on {}",
+                self.position_id,
+                metadata.phi.pair,
+                self.new_position.phi.pair
+            );
+        }
+
+        // As for `PositionWithdraw`, the commitment is used for compression
+        // rather than shielding, and implicitly sets the reward amount to 0.
+        let expected_reserves_commitment = metadata
+            .reserves
+            .balance(&metadata.phi.pair)
+            .commit(Fr::zero());
+        if self.reserves_commitment != expected_reserves_commitment {
+            anyhow::bail!(
+                "reserves commitment {:?} is incorrect, expected {:?}",
+                self.reserves_commitment,
+                expected_reserves_commitment
+            );
+        }
+
+        // Record the events of the equivalent close and withdrawal prior to
+        // updating the position state, so we have access to the current reserves.
+        state.record_proto(event::position_close(&PositionClose {
+            position_id: self.position_id,
+        }));
+        state.record_proto(event::position_withdraw(
+            &PositionWithdraw {
+                position_id: self.position_id,
+                reserves_commitment: self.reserves_commitment,
+                sequence: 0,
+            },
+            &metadata,
+        ));
+
+        metadata.state = position::State::Withdrawn { sequence: 0 };
+        metadata.reserves = Reserves::zero();
+        state.put_position(metadata).await?;
+
+        // The new position carries on the strategy of the position it replaces.
+        let strategy_tag = state
+            .position_metadata(&self.position_id)
+            .await?
+            .map(|metadata| metadata.strategy_tag)
+            .unwrap_or_default();
+
+        let new_position_id = self.new_position.id();
+        state.check_position_id_unused(&new_position_id).await?;
+        state.put_position(self.new_position.clone()).await?;
+        state
+            .record_position_metadata(
+                &self.new_position,
+                strategy_tag.clone(),
+                Some(self.position_id),
+            )
+            .await?;
+        state.record_proto(event::position_open(&PositionOpen {
+            position: self.new_position.clone(),
+            strategy_tag,
+        }));
+
+        state.record_position_rewrite(self.position_id, new_position_id);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,175 @@
+use std::{collections::BTreeSet, sync::Arc};
+
+use anyhow::{anyhow, Result};
+use ark_ff::Zero;
+use async_trait::async_trait;
+use cnidarium::{StateRead, StateWrite};
+use cnidarium_component::ActionHandler;
+use decaf377::Fr;
+use penumbra_asset::Balance;
+use penumbra_proto::StateWriteProto as _;
+
+use crate::{
+    component::{PositionManager, PositionRead, StateReadExt as _},
+    event,
+    lp::{
+        action::{PositionClose, PositionOpen, PositionUpdateBatch, PositionWithdraw},
+        position, Reserves,
+    },
+};
+
+use super::{
+    open::{check_open_allowed, check_reserves_usable},
+    withdraw::check_withdrawal_sequence,
+};
+
+#[async_trait]
+/// Performs each of the closes, withdrawals, and opens in the batch, as the
+/// equivalent individual position actions would.
+impl ActionHandler for PositionUpdateBatch {
+    type CheckStatelessContext = ();
+    async fn check_stateless(&self, _context: ()) -> Result<()> {
+        if self.num_updates() == 0 {
+            anyhow::bail!("position update batch is empty");
+        }
+        if self.num_updates() > Self::MAX_UPDATES {
+            anyhow::bail!(
+                "position update batch has {} updates, but at most {} are allowed",
+                self.num_updates(),
+                Self::MAX_UPDATES
+            );
+        }
+
+        for position in &self.opens {
+            position.check_stateless()?;
+            if position.state != position::State::Opened {
+                anyhow::bail!("attempted to open a position with a state besides `Opened`");
+            }
+        }
+
+        // Each position may only be closed, withdrawn from, or opened once per
+        // batch. A position may be both opened and closed, to provide liquidity
+        // for a single block, but closing is deferred to the end of the block,
+        // so a position can't be withdrawn from in the same batch.
+        let mut closed = BTreeSet::new();
+        for position_id in &self.closes {
+            if !closed.insert(*position_id) {
+                anyhow::bail!("position {} is closed twice in the same batch", position_id);
+            }
+        }
+        let mut opened = BTreeSet::new();
+        for position in &self.opens {
+            if !opened.insert(position.id()) {
+                anyhow::bail!(
+                    "position {} is opened twice in the same batch",
+                    position.id()
+                );
+            }
+        }
+        let mut withdrawn = BTreeSet::new();
+        for withdrawal in &self.withdrawals {
+            let position_id = withdrawal.position_id;
+            if !withdrawn.insert(position_id) {
+                anyhow::bail!(
+                    "position {} is withdrawn twice in the same batch",
+                    position_id
+                );
+            }
+            if closed.contains(&position_id) || opened.contains(&position_id) {
+                anyhow::bail!(
+                    "position {} is withdrawn in the same batch it is closed or opened in",
+                    position_id
+                );
+            }
+        }
+
+        Ok(())
+    }
+
+    async fn check_stateful<S: StateRead + 'static>(&self, state: Arc<S>) -> Result<()> {
+        let dex_params = state.get_dex_params().await?;
+        for position in &self.opens {
+            check_open_allowed(&dex_params, position)?;
+        }
+
+        // As with individual withdrawals, we defer consistency checks on the
+        // reserves to execution, to avoid having to reason about parallelism
+        // in checks.
+        Ok(())
+    }
+
+    async fn execute<S: StateWrite>(&self, mut state: S) -> Result<()> {
+        // Check every withdrawal against the current position state before
+        // modifying anything, accumulating the combined final reserves.
+        let mut withdrawn_positions = Vec::with_capacity(self.withdrawals.len());
+        let mut withdrawn_reserves = Balance::default();
+        for withdrawal in &self.withdrawals {
+            let metadata = state
+                .position_by_id(&withdrawal.position_id)
+                .await?
+                .ok_or_else(|| {
+                    anyhow!("withdrew from unknown position {}", withdrawal.position_id)
+                })?;
+            check_withdrawal_sequence(&metadata, withdrawal.sequence)?;
+            withdrawn_reserves += metadata.reserves.balance(&metadata.phi.pair);
+            withdrawn_positions.push((withdrawal, metadata));
+        }
+
+        for position in &self.opens {
+            check_reserves_usable(&state, position).await?;
+        }
+
+        // As for `PositionWithdraw`, the commitment is used for compression
+        // rather than shielding, and implicitly sets the reward amount to 0.
+        let expected_reserves_commitment = withdrawn_reserves.commit(Fr::zero());
+        if self.withdrawn_reserves_commitment != expected_reserves_commitment {
+            anyhow::bail!(
+                "withdrawn reserves commitment {:?} is incorrect, expected {:?}",
+                self.withdrawn_reserves_commitment,
+                expected_reserves_commitment
+            );
+        }
+
+        // Closing is deferred to the end of the block, as for `PositionClose`.
+        for position_id in &self.closes {
+            state.queue_close_position(*position_id);
+            state.record_proto(event::position_close(&PositionClose {
+                position_id: *position_id,
+            }));
+        }
+
+        for (withdrawal, mut metadata) in withdrawn_positions {
+            // Record an event prior to updating the position state, so we have
+            // access to the current reserves.
+            let position_withdraw = PositionWithdraw {
+                position_id: withdrawal.position_id,
+                reserves_commitment: metadata
+                    .reserves
+                    .balance(&metadata.phi.pair)
+                    .commit(Fr::zero()),
+                sequence: withdrawal.sequence,
+            };
+            state.record_proto(event::position_withdraw(&position_withdraw, &metadata));
+
+            metadata.state = position::State::Withdrawn {
+                sequence: withdrawal.sequence,
+            };
+            metadata.reserves = Reserves::zero();
+            state.put_position(metadata).await?;
+        }
+
+        for position in &self.opens {
+            state.check_position_id_unused(&position.id()).await?;
+            state.put_position(position.clone()).await?;
+            state
+                .record_position_metadata(position, String::new(), None)
+                .await?;
+            state.record_proto(event::position_open(&PositionOpen {
+                position: position.clone(),
+                strategy_tag: String::new(),
+            }));
+        }
+
+        Ok(())
+    }
+}
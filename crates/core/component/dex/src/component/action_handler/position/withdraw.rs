@@ -11,7 +11,11 @@ use penumbra_proto::StateWriteProto;
 use crate::{
     component::{PositionManager, PositionRead},
     event,
-    lp::{action::PositionWithdraw, position, Reserves},
+    lp::{
+        action::PositionWithdraw,
+        position::{self, Position},
+        Reserves,
+    },
 };
 
 #[async_trait]
@@ -67,32 +71,7 @@ impl ActionHandler for PositionWithdraw {
         // This should be redundant with the value balance mechanism (clients should
         // only be able to get the required input LPNFTs if the state transitions are
         // consistent), but we check it here for defense in depth.
-        if self.sequence == 0 {
-            if metadata.state != position::State::Closed {
-                anyhow::bail!(
-                    "attempted to withdraw position {} with state {}, expected Closed",
-                    self.position_id,
-                    metadata.state
-                );
-            }
-        } else {
-            if let position::State::Withdrawn { sequence } = metadata.state {
-                if sequence + 1 != self.sequence {
-                    anyhow::bail!(
-                        "attempted to withdraw position {} with sequence {}, expected {}",
-                        self.position_id,
-                        self.sequence,
-                        sequence + 1
-                    );
-                }
-            } else {
-                anyhow::bail!(
-                    "attempted to withdraw position {} with state {}, expected Withdrawn",
-                    self.position_id,
-                    metadata.state
-                );
-            }
-        }
+        check_withdrawal_sequence(&metadata, self.sequence)?;
 
         // Record an event prior to updating the position state, so we have access to
         // the current reserves.
@@ -112,3 +91,36 @@ impl ActionHandler for PositionWithdraw {
         Ok(())
     }
 }
+
+/// Check that a withdrawal with the given sequence number is consistent with
+/// the state of the position being withdrawn from.
+pub(super) fn check_withdrawal_sequence(metadata: &Position, sequence: u64) -> Result<()> {
+    if sequence == 0 {
+        if metadata.state != position::State::Closed {
+            anyhow::bail!(
+                "attempted to withdraw position {} with state {}, expected Closed",
+                metadata.id(),
+                metadata.state
+            );
+        }
+    } else {
+        if let position::State::Withdrawn { sequence: current } = metadata.state {
+            if current + 1 != sequence {
+                anyhow::bail!(
+                    "attempted to withdraw position {} with sequence {}, expected {}",
+                    metadata.id(),
+                    sequence,
+                    current + 1
+                );
+            }
+        } else {
+            anyhow::bail!(
+                "attempted to withdraw position {} with state {}, expected Withdrawn",
+                metadata.id(),
+                metadata.state
+            );
+        }
+    }
+
+    Ok(())
+}
@@ -35,7 +35,16 @@ impl ActionHandler for Swap {
         Ok(())
     }
 
-    async fn check_stateful<S: StateRead + 'static>(&self, _state: Arc<S>) -> Result<()> {
+    async fn check_stateful<S: StateRead + 'static>(&self, state: Arc<S>) -> Result<()> {
+        // Check that governance hasn't paused trading on the pair.
+        let trading_pair = self.body.trading_pair;
+        if state.get_dex_params().await?.is_paused(&trading_pair) {
+            anyhow::bail!(
+                "trading on the pair {} is paused by governance",
+                trading_pair
+            );
+        }
+
         Ok(())
     }
 
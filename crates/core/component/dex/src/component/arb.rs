@@ -51,16 +51,17 @@ pub trait Arbitrage: StateWrite + Sized {
             amount: u64::MAX.into(),
         };
 
-        let execution_circuit_breaker = ExecutionCircuitBreaker::default();
+        let mut execution_circuit_breaker = ExecutionCircuitBreaker::default();
         let swap_execution = this
             .route_and_fill(
                 arb_token,
                 arb_token,
                 flash_loan.amount,
                 params,
-                execution_circuit_breaker,
+                &mut execution_circuit_breaker,
             )
             .await?;
+        execution_circuit_breaker.record_metrics("arbitrage");
         let filled_input = swap_execution.input.amount;
         let output = swap_execution.output.amount;
         let unfilled_input = flash_loan
@@ -0,0 +1,338 @@
+use std::sync::Arc;
+
+use anyhow::{anyhow, Result};
+use async_trait::async_trait;
+use cnidarium::{StateDelta, StateRead, StateWrite};
+use futures::TryStreamExt;
+use penumbra_num::Amount;
+use penumbra_proto::{StateReadProto, StateWriteProto};
+
+use crate::{
+    auction::{
+        dutch::{DutchAuction, DutchAuctionDescription, MAX_ACTIVE_AUCTIONS},
+        AuctionId,
+    },
+    event,
+    lp::{position, Reserves},
+    state_key, ExecutionCircuitBreaker, SwapExecution, ValueCircuitBreakerBreach,
+};
+
+use super::{
+    router::{RouteAndFill, RoutingParams},
+    PositionManager, PositionRead, StateReadExt,
+};
+
+#[async_trait]
+pub trait DutchAuctionRead: StateRead {
+    /// Fetch a gradual Dutch auction by its ID.
+    async fn dutch_auction_by_id(&self, id: &AuctionId) -> Result<Option<DutchAuction>> {
+        self.get(&state_key::dutch_auction::by_id(id)).await
+    }
+
+    /// Returns the IDs of all of the auctions which are still trading.
+    async fn active_dutch_auctions(&self) -> Result<Vec<AuctionId>> {
+        self.prefix::<AuctionId>(state_key::dutch_auction::active_prefix())
+            .map_ok(|(_key, id)| id)
+            .try_collect()
+            .await
+    }
+}
+
+impl<T: StateRead + ?Sized> DutchAuctionRead for T {}
+
+#[async_trait]
+pub trait DutchAuctionManager: StateWrite + DutchAuctionRead {
+    /// Schedule a new auction, which will start trading at its start height.
+    ///
+    /// Fails if [`MAX_ACTIVE_AUCTIONS`] auctions are already scheduled or running.
+    async fn schedule_dutch_auction(&mut self, description: DutchAuctionDescription) -> Result<()> {
+        let id = description.id();
+        if self.dutch_auction_by_id(&id).await?.is_some() {
+            anyhow::bail!("auction {} already exists", id);
+        }
+        if self.active_dutch_auctions().await?.len() >= MAX_ACTIVE_AUCTIONS {
+            anyhow::bail!(
+                "cannot schedule auction {}, {} auctions are already scheduled or running",
+                id,
+                MAX_ACTIVE_AUCTIONS
+            );
+        }
+
+        self.record_proto(event::dutch_auction_schedule(id, &description));
+        self.put(
+            state_key::dutch_auction::by_id(&id),
+            DutchAuction::new(description),
+        );
+        self.put(state_key::dutch_auction::active_by_id(&id), id);
+        Ok(())
+    }
+
+    /// End an auction at the request of its owner, so that its reserves can be withdrawn.
+    async fn end_dutch_auction(&mut self, id: &AuctionId) -> Result<()> {
+        let mut auction = self
+            .dutch_auction_by_id(id)
+            .await?
+            .ok_or_else(|| anyhow!("attempted to end unknown auction {}", id))?;
+
+        if auction.state.seq != 0 {
+            anyhow::bail!("attempted to end auction {} which has already ended", id);
+        }
+
+        reclaim_position(self, &mut auction).await?;
+        auction.state.seq = 1;
+        self.delete(state_key::dutch_auction::active_by_id(id));
+        self.record_proto(event::dutch_auction_ended(*id, &auction.state));
+        self.put(state_key::dutch_auction::by_id(id), auction);
+        Ok(())
+    }
+
+    /// Take a step in each running auction which steps at the given height.
+    ///
+    /// At most [`MAX_ACTIVE_AUCTIONS`] auctions are running at once, and the
+    /// steps are paid for when the auctions are scheduled.
+    ///
+    /// Each step reclaims the reserves of the auction's previous position on
+    /// the DEX, sells against the liquidity already on the DEX if the auction
+    /// fills against it, and then offers the remaining input in a new position
+    /// at the auction's price at the given height.  Auctions which have
+    /// finished are stopped instead of opening a new position.
+    ///
+    /// If an auction's step fails, it is skipped for this block, without
+    /// affecting the other auctions.
+    async fn execute_dutch_auctions(self: &mut Arc<Self>, height: u64) -> Result<()>
+    where
+        Self: Sized + 'static,
+    {
+        for id in self.active_dutch_auctions().await? {
+            // Step each auction in a new `StateDelta`, so that if the step
+            // fails, we can discard it and move on to the other auctions,
+            // rather than halting the chain.
+            let mut this = Arc::new(StateDelta::new(self.clone()));
+            if let Err(e) = step_dutch_auction(&mut this, id, height).await {
+                let e = ValueCircuitBreakerBreach::halt_if_breach(e);
+                tracing::warn!(?e, %id, "error stepping dutch auction, skipping it for this block");
+                continue;
+            }
+
+            let (parent, cache) = Arc::try_unwrap(this)
+                .map_err(|_| ())
+                .expect("no more outstanding refs to state after stepping auction")
+                .flatten();
+            std::mem::drop(parent);
+            cache.apply_to(Arc::get_mut(self).expect("state should be uniquely referenced"));
+        }
+
+        Ok(())
+    }
+}
+
+impl<T: StateWrite + ?Sized> DutchAuctionManager for T {}
+
+/// Take a step in the auction with the given ID, if it steps at the given height.
+async fn step_dutch_auction<S: StateWrite + 'static>(
+    state: &mut Arc<S>,
+    id: AuctionId,
+    height: u64,
+) -> Result<()> {
+    let mut auction = state
+        .dutch_auction_by_id(&id)
+        .await?
+        .ok_or_else(|| anyhow!("active auction {} not found", id))?;
+
+    if !auction.description.is_step(height) {
+        return Ok(());
+    }
+
+    let state_mut = Arc::get_mut(state).expect("state should be uniquely referenced");
+    let (position_input_sold, position_output_received) =
+        reclaim_position(state_mut, &mut auction).await?;
+
+    let execution =
+        if auction.description.fill_against_dex && auction.state.input_reserves > Amount::zero() {
+            match fill_dutch_auction(state, &auction, height).await {
+                Ok(execution) => execution,
+                Err(e) => {
+                    // Failing to fill an auction shouldn't halt the chain, so
+                    // skip it for this block instead, unless filling it breached
+                    // the value circuit breaker.
+                    let e = ValueCircuitBreakerBreach::halt_if_breach(e);
+                    tracing::warn!(?e, %id, "error filling dutch auction, this is a bug");
+                    None
+                }
+            }
+        } else {
+            None
+        };
+
+    let state_mut = Arc::get_mut(state).expect("state should be uniquely referenced");
+
+    if let Some(execution) = &execution {
+        auction.state.input_reserves = auction
+            .state
+            .input_reserves
+            .checked_sub(&execution.input.amount)
+            .ok_or_else(|| anyhow!("auction {} sold more than its reserves", id))?;
+        auction.state.output_reserves += execution.output.amount;
+    }
+
+    let finished =
+        auction.state.input_reserves == Amount::zero() || height >= auction.description.end_height;
+    if !finished {
+        open_position(state_mut, &mut auction, height).await?;
+    }
+
+    if execution.is_some() || position_input_sold > Amount::zero() {
+        state_mut.record_proto(event::dutch_auction_execution(
+            id,
+            height,
+            execution,
+            position_input_sold,
+            position_output_received,
+            &auction.state,
+        ));
+    }
+
+    if finished {
+        tracing::debug!(%id, "dutch auction finished trading");
+        state_mut.delete(state_key::dutch_auction::active_by_id(&id));
+        state_mut.record_proto(event::dutch_auction_ended(id, &auction.state));
+    }
+    state_mut.put(state_key::dutch_auction::by_id(&id), auction);
+    Ok(())
+}
+
+/// Close and withdraw the auction's current position on the DEX, if any,
+/// returning its reserves to the auction.
+///
+/// Returns the amount of input sold, and the amount of output received, by
+/// the position since it was opened.
+async fn reclaim_position<S: StateWrite + ?Sized>(
+    state: &mut S,
+    auction: &mut DutchAuction,
+) -> Result<(Amount, Amount)> {
+    let Some(position_id) = auction.state.current_position.take() else {
+        return Ok((Amount::zero(), Amount::zero()));
+    };
+
+    let mut position = state
+        .position_by_id(&position_id)
+        .await?
+        .ok_or_else(|| anyhow!("auction position {} not found", position_id))?;
+    let (Some(input), Some(output)) = (
+        position.reserves_for(auction.description.input.asset_id),
+        position.reserves_for(auction.description.output_id),
+    ) else {
+        anyhow::bail!(
+            "auction position {} has the wrong trading pair",
+            position_id
+        );
+    };
+
+    // Nobody else holds an LPNFT for the position, so it can only have been
+    // closed by the auction itself.
+    if position.state == position::State::Opened {
+        position.state = position::State::Closed;
+        state.put_position(position.clone()).await?;
+    }
+    position.state = position::State::Withdrawn { sequence: 0 };
+    position.reserves = Reserves::zero();
+    state.put_position(position).await?;
+
+    let input_sold = auction
+        .state
+        .input_reserves
+        .checked_sub(&input)
+        .ok_or_else(|| anyhow!("auction position {} gained input", position_id))?;
+    auction.state.input_reserves = input;
+    auction.state.output_reserves += output;
+
+    Ok((input_sold, output))
+}
+
+/// Offer the auction's remaining input on the DEX, in a position at the
+/// auction's price at the given height.
+///
+/// If trading on the auction's pair is paused, or a position with the same ID
+/// has already been opened, the auction holds on to its reserves until the
+/// next step instead.
+async fn open_position<S: StateWrite + ?Sized>(
+    state: &mut S,
+    auction: &mut DutchAuction,
+    height: u64,
+) -> Result<()> {
+    let position = auction.position_at_height(height);
+    if state.get_dex_params().await?.is_paused(&position.phi.pair) {
+        tracing::debug!(id = %auction.id(), "trading paused, not opening auction position");
+        return Ok(());
+    }
+
+    // The position's ID is derived from public data, so anyone can open a
+    // position with the same ID ahead of the auction.
+    let position_id = position.id();
+    if state.position_by_id(&position_id).await?.is_some() {
+        tracing::warn!(id = %auction.id(), %position_id, "auction position ID already used, not opening auction position");
+        return Ok(());
+    }
+    state
+        .record_position_metadata(&position, String::new(), None)
+        .await?;
+    state.put_position(position).await?;
+    auction.state.current_position = Some(position_id);
+    Ok(())
+}
+
+/// Attempt to sell the remaining input of the auction at its price at the
+/// given height, returning the execution if it was applied to the state.
+async fn fill_dutch_auction<S: StateWrite + 'static>(
+    state: &mut Arc<S>,
+    auction: &DutchAuction,
+    height: u64,
+) -> Result<Option<SwapExecution>> {
+    let description = &auction.description;
+    let input_id = description.input.asset_id;
+    let output_id = description.output_id;
+
+    // Work in a new `StateDelta`, so that we can discard the fills if the
+    // execution doesn't meet the auction's price.
+    let mut this = Arc::new(StateDelta::new(state.clone()));
+
+    let params = RoutingParams {
+        price_limit: Some(description.price_limit_at_height(height)?),
+        ..RoutingParams::default_with_extra_candidates([input_id, output_id])
+    };
+    let mut execution_circuit_breaker = ExecutionCircuitBreaker::default();
+    let execution = this
+        .route_and_fill(
+            input_id,
+            output_id,
+            auction.state.input_reserves,
+            params,
+            &mut execution_circuit_breaker,
+        )
+        .await?;
+    execution_circuit_breaker.record_metrics("dutch_auction");
+
+    if execution.input.amount == Amount::zero() {
+        return Ok(None);
+    }
+
+    // Route-and-fill prioritizes forward progress over precise application of
+    // price limits, so check that the execution as a whole meets the price.
+    if !description.accepts_at_height(height, execution.input.amount, execution.output.amount)? {
+        tracing::debug!(
+            input = ?execution.input,
+            output = ?execution.output,
+            "dutch auction execution below asking price, discarding"
+        );
+        return Ok(None);
+    }
+
+    let (parent, cache) = Arc::try_unwrap(this)
+        .map_err(|_| ())
+        .expect("no more outstanding refs to state after routing")
+        .flatten();
+    std::mem::drop(parent);
+    cache.apply_to(Arc::get_mut(state).expect("state was unique ref"));
+
+    Ok(Some(execution))
+}
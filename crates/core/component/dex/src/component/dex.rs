@@ -1,40 +1,63 @@
-use std::{collections::BTreeMap, sync::Arc};
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    pin::Pin,
+    sync::Arc,
+};
 
 use anyhow::Result;
 use async_trait::async_trait;
 use cnidarium::{StateRead, StateWrite};
 use cnidarium_component::Component;
+use futures::{future, Stream, StreamExt as _, TryStreamExt as _};
 use penumbra_asset::{asset, Value, STAKING_TOKEN_ASSET_ID};
 use penumbra_num::Amount;
-use penumbra_proto::{StateReadProto, StateWriteProto};
+use penumbra_proto::{DomainType, StateReadProto, StateWriteProto};
 use penumbra_sct::component::clock::EpochRead;
 use tendermint::v0_37::abci;
 use tracing::instrument;
 
 use crate::{
-    component::flow::SwapFlow, event, state_key, BatchSwapOutputData, DirectedTradingPair,
-    SwapExecution, TradingPair,
+    component::flow::SwapFlow, event, genesis, state_key, BatchSwapOutputData, DexParameters,
+    DirectedTradingPair, ExecutionCircuitBreakerTrip, RouteFill, SwapExecution, SwapExecutionTrace,
+    TradingPair, ValueCircuitBreakerBreach,
 };
 
 use super::{
     router::{HandleBatchSwaps, RoutingParams},
-    Arbitrage, PositionManager,
+    Arbitrage, DutchAuctionManager, PositionManager,
 };
 
 pub struct Dex {}
 
 #[async_trait]
 impl Component for Dex {
-    type AppState = ();
+    type AppState = genesis::Content;
 
-    #[instrument(name = "dex", skip(_state, _app_state))]
-    async fn init_chain<S: StateWrite>(_state: S, _app_state: Option<&()>) {}
+    #[instrument(name = "dex", skip(state, app_state))]
+    async fn init_chain<S: StateWrite>(mut state: S, app_state: Option<&Self::AppState>) {
+        match app_state {
+            None => { /* Checkpoint -- no-op */ }
+            Some(genesis) => {
+                state.put_dex_params(genesis.dex_params.clone());
+                sync_paused_trading_pairs(&mut state)
+                    .await
+                    .expect("able to record paused trading pairs at genesis");
+            }
+        }
+    }
 
-    #[instrument(name = "dex", skip(_state, _begin_block))]
+    #[instrument(name = "dex", skip(state, _begin_block))]
     async fn begin_block<S: StateWrite + 'static>(
-        _state: &mut Arc<S>,
+        state: &mut Arc<S>,
         _begin_block: &abci::request::BeginBlock,
     ) {
+        // Governance may have paused or resumed trading on some pairs.
+        if state.dex_params_updated() {
+            let state = Arc::get_mut(state).expect("state should be unique");
+            sync_paused_trading_pairs(state)
+                .await
+                .expect("able to record paused trading pairs");
+        }
     }
 
     #[instrument(name = "dex", skip(state, end_block))]
@@ -63,11 +86,26 @@ impl Component for Dex {
                     ]),
                 )
                 .await
+                .map_err(ValueCircuitBreakerBreach::halt_if_breach)
                 .expect("handling batch swaps is infaillible");
             metrics::histogram!(crate::component::metrics::DEX_BATCH_DURATION)
                 .record(batch_start.elapsed());
         }
 
+        // Next, step any running Dutch auctions, moving their positions to
+        // the current price, after optionally selling against the liquidity
+        // remaining after the batch swaps.
+        state
+            .execute_dutch_auctions(
+                end_block
+                    .height
+                    .try_into()
+                    .expect("height is part of the end block data"),
+            )
+            .await
+            .map_err(ValueCircuitBreakerBreach::halt_if_breach)
+            .expect("should be able to execute dutch auctions");
+
         // Then, perform arbitrage:
         let arb_burn = match state
             .arbitrage(
@@ -105,7 +143,9 @@ impl Component for Dex {
             Ok(v) => v,
             Err(e) => {
                 // The arbitrage search should not error, but if it does, we should
-                // simply not perform arbitrage, rather than halting the entire chain.
+                // simply not perform arbitrage, rather than halting the entire chain,
+                // unless it breached the value circuit breaker.
+                let e = ValueCircuitBreakerBreach::halt_if_breach(e);
                 tracing::warn!(?e, "error processing arbitrage, this is a bug");
                 Value {
                     amount: Amount::zero(),
@@ -129,6 +169,22 @@ impl Component for Dex {
             .expect("state should be uniquely referenced after batch swaps complete")
             .close_queued_positions()
             .await;
+
+        // Finally, record the history of every position changed during the block,
+        // and prune the execution traces which have fallen out of the retention window.
+        let height = end_block
+            .height
+            .try_into()
+            .expect("height is part of the end block data");
+        let state = Arc::get_mut(state)
+            .expect("state should be uniquely referenced after batch swaps complete");
+        state.write_position_history(height);
+        // Fills recorded by auctions and arbitrage are not part of any batch swap.
+        state.take_route_fills();
+        state
+            .prune_swap_execution_traces(height)
+            .await
+            .expect("should be able to prune swap execution traces");
     }
 
     #[instrument(name = "dex", skip(_state))]
@@ -137,9 +193,59 @@ impl Component for Dex {
     }
 }
 
+/// Record which trading pairs are paused by the current dex parameters, emitting an event for each
+/// pair on which trading was paused or resumed since they were last recorded.
+async fn sync_paused_trading_pairs<S: StateWrite>(state: &mut S) -> Result<()> {
+    let paused: BTreeSet<TradingPair> = state
+        .get_dex_params()
+        .await?
+        .paused_trading_pairs
+        .into_iter()
+        .collect();
+    let previously_paused = state.paused_trading_pairs().await?;
+
+    for trading_pair in paused.difference(&previously_paused) {
+        tracing::info!(%trading_pair, "pausing trading");
+        state.put(
+            state_key::paused_trading_pairs::by_pair(trading_pair),
+            *trading_pair,
+        );
+        state.record_proto(event::trading_pair_paused(*trading_pair));
+    }
+    for trading_pair in previously_paused.difference(&paused) {
+        tracing::info!(%trading_pair, "resuming trading");
+        state.delete(state_key::paused_trading_pairs::by_pair(trading_pair));
+        state.record_proto(event::trading_pair_resumed(*trading_pair));
+    }
+
+    Ok(())
+}
+
 /// Extension trait providing read access to dex data.
 #[async_trait]
 pub trait StateReadExt: StateRead {
+    /// Indicates if the dex parameters have been updated in this block.
+    fn dex_params_updated(&self) -> bool {
+        self.object_get::<()>(state_key::dex_params_updated())
+            .is_some()
+    }
+
+    /// Gets the dex parameters from the JMT.
+    ///
+    /// Chains started before the dex had parameters never stored any, so they use the defaults
+    /// until governance changes them.
+    async fn get_dex_params(&self) -> Result<DexParameters> {
+        Ok(self.get(state_key::dex_params()).await?.unwrap_or_default())
+    }
+
+    /// Gets the trading pairs on which governance has paused trading, as of the start of the block.
+    async fn paused_trading_pairs(&self) -> Result<BTreeSet<TradingPair>> {
+        self.prefix::<TradingPair>(state_key::paused_trading_pairs::prefix())
+            .map_ok(|(_, trading_pair)| trading_pair)
+            .try_collect()
+            .await
+    }
+
     async fn output_data(
         &self,
         height: u64,
@@ -162,6 +268,55 @@ pub trait StateReadExt: StateRead {
         self.get(&state_key::arb_execution(height)).await
     }
 
+    /// Stream the arbitrage executions from `start_height` up to and including
+    /// `end_height`, if given, with their heights, in order of height.
+    ///
+    /// Executions are keyed by their zero-padded height, so the stream ends as
+    /// soon as it passes `end_height`, rather than reading every later one.
+    fn arb_executions(
+        &self,
+        start_height: u64,
+        end_height: Option<u64>,
+    ) -> Pin<Box<dyn Stream<Item = Result<(u64, SwapExecution)>> + Send + 'static>> {
+        let end_height = end_height.unwrap_or(u64::MAX);
+        self.prefix::<SwapExecution>(state_key::arb_executions())
+            .map(|entry| {
+                let (key, execution) = entry?;
+                let height = key
+                    .rsplit('/')
+                    .next()
+                    .and_then(|height| height.parse().ok())
+                    .ok_or_else(|| anyhow::anyhow!("malformed arb execution key {key}"))?;
+                Ok((height, execution))
+            })
+            .try_skip_while(move |(height, _)| future::ready(Ok(*height < start_height)))
+            .try_take_while(move |(height, _)| future::ready(Ok(*height <= end_height)))
+            .boxed()
+    }
+
+    /// Get the trace of the batch swap executed at the given height in the
+    /// given direction, if it is still kept.
+    ///
+    /// This is tracked in nonverifiable storage, so it is local to this node.
+    async fn swap_execution_trace(
+        &self,
+        height: u64,
+        trading_pair: DirectedTradingPair,
+    ) -> Result<Option<SwapExecutionTrace>> {
+        self.nonverifiable_get_raw(
+            state_key::execution_trace::by_pair(height, trading_pair).as_bytes(),
+        )
+        .await?
+        .map(|bytes| SwapExecutionTrace::decode(bytes.as_slice()))
+        .transpose()
+    }
+
+    /// Get the route fills recorded since they were last taken.
+    fn pending_route_fills(&self) -> Vec<RouteFill> {
+        self.object_get(state_key::pending_route_fills())
+            .unwrap_or_default()
+    }
+
     /// Get the swap flow for the given trading pair accumulated in this block so far.
     fn swap_flow(&self, pair: &TradingPair) -> SwapFlow {
         self.swap_flows().get(pair).cloned().unwrap_or_default()
@@ -176,6 +331,11 @@ pub trait StateReadExt: StateRead {
         self.object_get(state_key::pending_outputs())
             .unwrap_or_default()
     }
+
+    /// Get the most recent time the execution circuit breaker halted routing of a batch swap.
+    async fn execution_circuit_breaker_trip(&self) -> Result<Option<ExecutionCircuitBreakerTrip>> {
+        self.get(state_key::circuit_breaker_last_trip()).await
+    }
 }
 
 impl<T: StateRead + ?Sized> StateReadExt for T {}
@@ -183,6 +343,13 @@ impl<T: StateRead + ?Sized> StateReadExt for T {}
 /// Extension trait providing write access to dex data.
 #[async_trait]
 pub trait StateWriteExt: StateWrite + StateReadExt {
+    /// Set the dex parameters in the JMT.
+    fn put_dex_params(&mut self, params: DexParameters) {
+        // Note that the dex params have been updated:
+        self.object_put(state_key::dex_params_updated(), ());
+        self.put(state_key::dex_params().into(), params)
+    }
+
     fn set_output_data(
         &mut self,
         output_data: BatchSwapOutputData,
@@ -223,6 +390,63 @@ pub trait StateWriteExt: StateWrite + StateReadExt {
         ));
     }
 
+    /// Record that the execution circuit breaker halted routing of a batch swap.
+    fn set_execution_circuit_breaker_trip(&mut self, trip: ExecutionCircuitBreakerTrip) {
+        self.put(state_key::circuit_breaker_last_trip().into(), trip);
+    }
+
+    /// Record fills applied along a route, if execution traces are being kept.
+    fn record_route_fills(&mut self, fills: Vec<RouteFill>) {
+        let mut pending = self.pending_route_fills();
+        pending.extend(fills);
+        self.object_put(state_key::pending_route_fills(), pending);
+    }
+
+    /// Take the route fills recorded since they were last taken.
+    fn take_route_fills(&mut self) -> Vec<RouteFill> {
+        let fills = self.pending_route_fills();
+        self.object_delete(state_key::pending_route_fills());
+        fills
+    }
+
+    /// Keep the trace of the batch swap executed at the given height in the
+    /// given direction, until it falls out of the retention window.
+    fn put_swap_execution_trace(
+        &mut self,
+        height: u64,
+        trading_pair: DirectedTradingPair,
+        trace: SwapExecutionTrace,
+    ) {
+        self.nonverifiable_put_raw(
+            state_key::execution_trace::by_pair(height, trading_pair).into_bytes(),
+            trace.encode_to_vec(),
+        );
+    }
+
+    /// Delete the execution traces which have fallen out of the retention
+    /// window as of the given height.
+    async fn prune_swap_execution_traces(&mut self, height: u64) -> Result<()> {
+        let retention = self
+            .get_dex_params()
+            .await?
+            .execution_trace_retention_blocks;
+        // Traces at the cutoff height and below are deleted, which is every
+        // trace if they are no longer being kept.
+        let cutoff = height.saturating_sub(retention);
+        let expired: Vec<Vec<u8>> = self
+            .nonverifiable_range_raw(
+                Some(state_key::execution_trace::prefix().as_bytes()),
+                ..state_key::execution_trace::by_height(cutoff + 1).into_bytes(),
+            )?
+            .map_ok(|(key, _)| key)
+            .try_collect()
+            .await?;
+        for key in expired {
+            self.nonverifiable_delete(key);
+        }
+        Ok(())
+    }
+
     fn set_arb_execution(&mut self, height: u64, execution: SwapExecution) {
         self.put(state_key::arb_execution(height), execution);
     }
@@ -40,6 +40,26 @@ pub fn register_metrics() {
         Unit::Seconds,
         "The time spent processing swaps within the DEX"
     );
+    describe_counter!(
+        DEX_CIRCUIT_BREAKER_PATH_SEARCHES,
+        Unit::Count,
+        "The number of path searches counted by the execution circuit breaker, labeled by what was being routed"
+    );
+    describe_counter!(
+        DEX_CIRCUIT_BREAKER_EXECUTIONS,
+        Unit::Count,
+        "The number of executions against positions counted by the execution circuit breaker, labeled by what was being routed"
+    );
+    describe_counter!(
+        DEX_CIRCUIT_BREAKER_TRIGGERED,
+        Unit::Count,
+        "The number of times the execution circuit breaker halted routing, labeled by what was being routed"
+    );
+    describe_counter!(
+        DEX_VALUE_CIRCUIT_BREAKER_TRIGGERED,
+        Unit::Count,
+        "The number of times the value circuit breaker rejected a change to position reserves"
+    );
 }
 
 // We configure buckets for the DEX routing times manually, in order to ensure
@@ -53,3 +73,9 @@ pub const DEX_ROUTE_FILL_DURATION: &str = "penumbra_dex_route_fill_duration_seco
 pub const DEX_ARB_DURATION: &str = "penumbra_dex_arb_duration_seconds";
 pub const DEX_BATCH_DURATION: &str = "penumbra_dex_batch_duration_seconds";
 pub const DEX_SWAP_DURATION: &str = "penumbra_dex_swap_duration_seconds";
+pub const DEX_CIRCUIT_BREAKER_PATH_SEARCHES: &str =
+    "penumbra_dex_circuit_breaker_path_searches_total";
+pub const DEX_CIRCUIT_BREAKER_EXECUTIONS: &str = "penumbra_dex_circuit_breaker_executions_total";
+pub const DEX_CIRCUIT_BREAKER_TRIGGERED: &str = "penumbra_dex_circuit_breaker_triggered_total";
+pub const DEX_VALUE_CIRCUIT_BREAKER_TRIGGERED: &str =
+    "penumbra_dex_value_circuit_breaker_triggered_total";
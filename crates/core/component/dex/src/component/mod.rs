@@ -8,6 +8,7 @@ pub mod router;
 
 mod action_handler;
 mod arb;
+mod auction_manager;
 mod dex;
 mod flow;
 pub(crate) mod position_manager;
@@ -15,6 +16,7 @@ mod swap_manager;
 
 pub use self::metrics::register_metrics;
 pub use arb::Arbitrage;
+pub use auction_manager::{DutchAuctionManager, DutchAuctionRead};
 pub use dex::{Dex, StateReadExt, StateWriteExt};
 pub use position_manager::{PositionManager, PositionRead};
 pub use swap_manager::SwapManager;
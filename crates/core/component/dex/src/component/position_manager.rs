@@ -11,11 +11,15 @@ use penumbra_asset::{asset, Balance, Value};
 use penumbra_num::Amount;
 use penumbra_proto::DomainType;
 use penumbra_proto::{StateReadProto, StateWriteProto};
+use penumbra_sct::component::clock::EpochRead;
 
-use crate::circuit_breaker::ValueCircuitBreaker;
+use crate::circuit_breaker::{ValueCircuitBreaker, ValueCircuitBreakerBreach};
 use crate::lp::position::State;
 use crate::{
-    lp::position::{self, Position},
+    lp::{
+        position::{self, Position},
+        PositionHistoryEntry, PositionMetadata,
+    },
     state_key, DirectedTradingPair,
 };
 
@@ -61,6 +65,11 @@ pub trait PositionRead: StateRead {
         self.get(&state_key::position_by_id(id)).await
     }
 
+    /// Fetch the metadata recorded when a position was opened.
+    async fn position_metadata(&self, id: &position::Id) -> Result<Option<PositionMetadata>> {
+        self.get(&state_key::position_metadata(id)).await
+    }
+
     async fn check_position_id_unused(&self, id: &position::Id) -> Result<()> {
         match self.get_raw(&state_key::position_by_id(id)).await? {
             Some(_) => Err(anyhow::anyhow!("position id {:?} already used", id)),
@@ -79,11 +88,82 @@ pub trait PositionRead: StateRead {
         }
     }
 
+    /// Returns the total reserves of `pair.end` held by open positions on the
+    /// directed trading pair, i.e. the most that could be bought along it.
+    async fn available_liquidity(&self, pair: &DirectedTradingPair) -> Result<Amount> {
+        // The liquidity index records the reserves of the *start* asset of
+        // each pair, so the reserves of `pair.end` are found on the flipped pair.
+        Ok(self
+            .nonverifiable_get_raw(&state_key::internal::routable_assets::a_from_b(
+                &pair.flip(),
+            ))
+            .await?
+            .map(|bytes| {
+                Amount::from_be_bytes(
+                    bytes
+                        .try_into()
+                        .expect("liquidity index amount can always be parsed"),
+                )
+            })
+            .unwrap_or_default())
+    }
+
     /// Fetch the list of pending position closures.
     fn pending_position_closures(&self) -> im::Vector<position::Id> {
         self.object_get(state_key::pending_position_closures())
             .unwrap_or_default()
     }
+
+    /// Return a stream of the history of a position, ordered by height.
+    fn position_history(
+        &self,
+        id: &position::Id,
+    ) -> Pin<Box<dyn Stream<Item = Result<PositionHistoryEntry>> + Send + 'static>> {
+        self.nonverifiable_prefix_raw(state_key::position_history::prefix(id).as_bytes())
+            .map(|entry| {
+                entry.and_then(|(_, bytes)| PositionHistoryEntry::decode(bytes.as_slice()))
+            })
+            .boxed()
+    }
+
+    /// Reconstruct a position as of the end of the block at the given height,
+    /// from its history, along with the height of the last block up to then in
+    /// which it changed.
+    ///
+    /// Returns `None` if the position had not been opened by then.
+    async fn position_at_height(
+        &self,
+        id: &position::Id,
+        height: u64,
+    ) -> Result<Option<(Position, u64)>> {
+        let Some(mut position) = self.position_by_id(id).await? else {
+            return Ok(None);
+        };
+
+        // The history is ordered by height, so the last entry up to the
+        // requested height has the position's state as of that height.
+        let mut history = self.position_history(id);
+        let mut last = None;
+        while let Some(entry) = history.next().await {
+            let entry = entry?;
+            if entry.height > height {
+                break;
+            }
+            last = Some(entry);
+        }
+
+        Ok(last.map(|entry| {
+            position.state = entry.state;
+            position.reserves = entry.reserves;
+            (position, entry.height)
+        }))
+    }
+
+    /// Fetch the history entries of the positions changed during the current block.
+    fn pending_position_history(&self) -> im::OrdMap<position::Id, PositionHistoryEntry> {
+        self.object_get(state_key::pending_position_history())
+            .unwrap_or_default()
+    }
 }
 impl<T: StateRead + ?Sized> PositionRead for T {}
 
@@ -142,8 +222,12 @@ pub trait PositionManager: StateWrite + PositionRead {
             .expect("fetching position should not fail");
 
         // Clear any existing indexes of the position, since changes to the
-        // reserves or the position state might have invalidated them.
-        self.deindex_position_by_price(&position);
+        // reserves or the position state might have invalidated them.  The
+        // previous state must be used, since the price a position is indexed
+        // at depends on its reserves if it has a dynamic fee.
+        if let Some(prev) = &prev {
+            self.deindex_position_by_price(prev);
+        }
 
         let position = self.handle_limit_order(&prev, position);
 
@@ -159,10 +243,70 @@ pub trait PositionManager: StateWrite + PositionRead {
         self.update_position_aggregate_value(&position, &prev)
             .await?;
 
+        self.record_position_history(prev.as_ref(), &position);
+
         self.put(state_key::position_by_id(&id), position);
         Ok(())
     }
 
+    /// Record the metadata of a position opened in the current block.
+    async fn record_position_metadata(
+        &mut self,
+        position: &Position,
+        strategy_tag: String,
+        rewritten_from: Option<position::Id>,
+    ) -> Result<()> {
+        let metadata = PositionMetadata {
+            creation_height: self.get_block_height().await?,
+            trading_pair: position.phi.pair,
+            strategy_tag,
+            rewritten_from,
+        };
+        self.put(state_key::position_metadata(&position.id()), metadata);
+        Ok(())
+    }
+
+    /// Record a change to a position in its history entry for the current block.
+    fn record_position_history(&mut self, prev: Option<&Position>, position: &Position) {
+        let mut pending = self.pending_position_history();
+        match pending.get_mut(&position.id()) {
+            Some(entry) => entry.record(prev, position),
+            None => {
+                // The height is filled in when the entry is written at the end of the block.
+                pending.insert(position.id(), PositionHistoryEntry::new(0, prev, position));
+            }
+        }
+        self.object_put(state_key::pending_position_history(), pending);
+    }
+
+    /// Link the history entries for the current block of a position and the
+    /// position which replaced it.
+    fn record_position_rewrite(&mut self, from: position::Id, to: position::Id) {
+        let mut pending = self.pending_position_history();
+        if let Some(entry) = pending.get_mut(&from) {
+            entry.rewritten_to = Some(to);
+        }
+        if let Some(entry) = pending.get_mut(&to) {
+            entry.rewritten_from = Some(from);
+        }
+        self.object_put(state_key::pending_position_history(), pending);
+    }
+
+    /// Write the history entries of the positions changed during the block at `height`.
+    ///
+    /// The history is not part of the consensus state, since it only serves clients and would
+    /// otherwise grow the verifiable state by an entry per position change, forever.
+    fn write_position_history(&mut self, height: u64) {
+        for (id, mut entry) in self.pending_position_history() {
+            entry.height = height;
+            self.nonverifiable_put_raw(
+                state_key::position_history::by_height(&id, height).into_bytes(),
+                entry.encode_to_vec(),
+            );
+        }
+        self.object_delete(state_key::pending_position_history());
+    }
+
     /// Handle a limit order, inspecting it previous state to determine if it
     /// has been filled, and if so, marking it as closed. If the position is
     /// not a limit order, or has not been filled, it is returned unchanged.
@@ -251,7 +395,8 @@ impl<T: StateWrite + ?Sized> PositionManager for T {}
 #[async_trait]
 pub(crate) trait Inner: StateWrite {
     fn index_position_by_price(&mut self, position: &position::Position) {
-        let (pair, phi) = (position.phi.pair, &position.phi);
+        let phi = position.effective_phi();
+        let pair = phi.pair;
         let id = position.id();
         if position.reserves.r2 != 0u64.into() {
             // Index this position for trades FROM asset 1 TO asset 2, since the position has asset 2 to give out.
@@ -285,16 +430,17 @@ pub(crate) trait Inner: StateWrite {
     fn deindex_position_by_price(&mut self, position: &Position) {
         let id = position.id();
         tracing::debug!("deindexing position");
+        let phi = position.effective_phi();
         let pair12 = DirectedTradingPair {
-            start: position.phi.pair.asset_1(),
-            end: position.phi.pair.asset_2(),
+            start: phi.pair.asset_1(),
+            end: phi.pair.asset_2(),
         };
-        let phi12 = position.phi.component.clone();
+        let phi12 = phi.component.clone();
         let pair21 = DirectedTradingPair {
-            start: position.phi.pair.asset_2(),
-            end: position.phi.pair.asset_1(),
+            start: phi.pair.asset_2(),
+            end: phi.pair.asset_1(),
         };
-        let phi21 = position.phi.component.flip();
+        let phi21 = phi.component.flip();
         self.nonverifiable_delete(state_key::internal::price_index::key(&pair12, &phi12, &id));
         self.nonverifiable_delete(state_key::internal::price_index::key(&pair21, &phi21, &id));
     }
@@ -578,13 +724,39 @@ pub(crate) trait Inner: StateWrite {
             None => ValueCircuitBreaker::default(),
         };
 
+        // Keep the balances prior to the change, to explain a breach of the circuit breaker.
+        let pair = position.phi.pair;
+        let available_a = value_circuit_breaker.available(pair.asset_1).amount;
+        let available_b = value_circuit_breaker.available(pair.asset_2).amount;
+
         // Add the change to the value circuit breaker for assets A and B.
         value_circuit_breaker.tally(net_change_for_a);
         value_circuit_breaker.tally(net_change_for_b);
 
         // Confirm that the value circuit breaker is still within the limits.
-        // This call will panic if the value circuit breaker detects inflation.
-        value_circuit_breaker.check()?;
+        // If the value circuit breaker detects inflation, the error carries a record of the
+        // breach, so that the caller can keep it for investigation.
+        if let Err(e) = value_circuit_breaker.check() {
+            metrics::counter!(crate::component::metrics::DEX_VALUE_CIRCUIT_BREAKER_TRIGGERED)
+                .increment(1);
+            let Some(deficit) = value_circuit_breaker.deficits().next() else {
+                return Err(e);
+            };
+            let available = if deficit.asset_id == pair.asset_1 {
+                available_a
+            } else {
+                available_b
+            };
+            return Err(ValueCircuitBreakerBreach {
+                height: self.get_block_height().await?,
+                trading_pair: pair,
+                asset_id: deficit.asset_id,
+                available,
+                outflow: available + deficit.amount,
+                positions: vec![position.id()],
+            }
+            .into());
+        }
 
         // Store the value circuit breaker back to nonconsensus storage with the updated tallies.
         self.nonverifiable_put_raw(
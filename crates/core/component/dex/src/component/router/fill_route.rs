@@ -8,6 +8,7 @@ use async_trait::async_trait;
 use cnidarium::{StateDelta, StateRead, StateWrite};
 use futures::{Stream, StreamExt};
 use penumbra_asset::{asset, Value};
+use penumbra_community_pool::StateWriteExt as _;
 use penumbra_num::{
     fixpoint::{Error, U128x128},
     Amount,
@@ -16,13 +17,13 @@ use penumbra_proto::StateWriteProto as _;
 use tracing::instrument;
 
 use crate::{
-    component::{metrics, PositionManager, PositionRead},
+    component::{metrics, PositionManager, PositionRead, StateReadExt as _, StateWriteExt as _},
     event,
     lp::{
         position::{self, Position},
-        Reserves,
+        BareTradingFunction, Reserves,
     },
-    DirectedTradingPair, SwapExecution, TradingPair,
+    DirectedTradingPair, RouteFill, SwapExecution, TradingPair, ValueCircuitBreakerBreach,
 };
 
 /// An error that occurs during routing execution.
@@ -138,7 +139,9 @@ async fn fill_route_inner<S: StateWrite + Sized>(
         // INVARIANT: we must ensure that in each iteration of the loop, either:
         //
         // * we completely exhaust the input amount, or
-        // * we completely exhaust the reserves of one of the active positions.
+        // * we completely exhaust the fill capacity of one of the active positions,
+        //   which is either its reserves, or, for a position with a dynamic fee,
+        //   a step of at least one unit of its reserves.
 
         // Phase 1 (Sensing): determine the index of the constraining position by
         // executing along the frontier, tracking which hops are
@@ -217,10 +220,12 @@ async fn fill_route_inner<S: StateWrite + Sized>(
     }
 
     // We need to save these positions, because we mutated their state, even
-    // if we didn't fully consume their reserves.
+    // if we didn't fully consume their reserves.  This can only fail if the
+    // value circuit breaker is breached, which halts the chain.
     frontier
         .save()
         .await
+        .map_err(ValueCircuitBreakerBreach::halt_if_breach)
         .expect("writing frontier should not fail");
 
     // Input consists of the sum of the first value of each trace.
@@ -300,6 +305,15 @@ struct Frontier<S> {
     pub positions_by_price: PositionsByPrice,
     /// A trace of the execution along the route.
     pub trace: Vec<Vec<Value>>,
+    /// The share of each position's trading fees accrued by the protocol, in basis points.
+    pub protocol_fee_share_bps: u32,
+    /// The protocol fees accrued from each position during the execution.
+    pub protocol_fees: BTreeMap<position::Id, Value>,
+    /// The fills applied along the route, including the positions filled
+    /// against, if execution traces are being kept.
+    pub fills: Option<Vec<RouteFill>>,
+    /// The positions the last fill was applied against, if any.
+    pub last_fill_positions: Option<Vec<position::Id>>,
 }
 
 struct FrontierTx {
@@ -340,6 +354,10 @@ impl<S> std::fmt::Debug for Frontier<S> {
             .field("positions", &self.positions)
             .field("position_ids", &self.position_ids)
             .field("trace", &self.trace)
+            .field("protocol_fee_share_bps", &self.protocol_fee_share_bps)
+            .field("protocol_fees", &self.protocol_fees)
+            .field("fills", &self.fills)
+            .field("last_fill_positions", &self.last_fill_positions)
             .finish_non_exhaustive()
     }
 }
@@ -391,6 +409,12 @@ impl<S: StateRead + StateWrite> Frontier<S> {
         // The current trace list along the route should be initialized as empty.
         let trace: Vec<Vec<Value>> = Vec::new();
 
+        // The dex parameters are only missing if the chain was never initialized,
+        // in which case there is no protocol fee, and no traces are kept.
+        let params = state.get_dex_params().await.unwrap_or_default();
+        let protocol_fee_share_bps = params.protocol_fee_share_bps;
+        let fills = (params.execution_trace_retention_blocks > 0).then(Vec::new);
+
         Ok(Frontier {
             positions,
             position_ids,
@@ -398,6 +422,10 @@ impl<S: StateRead + StateWrite> Frontier<S> {
             state,
             positions_by_price,
             trace,
+            protocol_fee_share_bps,
+            protocol_fees: BTreeMap::new(),
+            fills,
+            last_fill_positions: None,
         })
     }
 
@@ -409,6 +437,18 @@ impl<S: StateRead + StateWrite> Frontier<S> {
             self.state
                 .record_proto(event::position_execution(position.clone()));
         }
+
+        // Deposit the protocol's share of the trading fees into the community pool.
+        for (position_id, fee) in std::mem::take(&mut self.protocol_fees) {
+            self.state.community_pool_deposit(fee).await?;
+            self.state
+                .record_proto(event::protocol_fee_accrual(position_id, fee));
+        }
+
+        // Record the fills, so that the batch swap they were part of can be traced.
+        if let Some(fills) = self.fills.take() {
+            self.state.record_route_fills(fills);
+        }
         Ok(())
     }
 
@@ -424,9 +464,14 @@ impl<S: StateRead + StateWrite> Frontier<S> {
         for (i, new_reserves) in changes.new_reserves.into_iter().enumerate() {
             let new_reserves =
                 new_reserves.expect("all new reserves must be set when applying changes");
+            let input =
+                changes.trace[i].expect("all trace amounts must be set when applying changes");
             let amount =
                 changes.trace[i + 1].expect("all trace amounts must be set when applying changes");
+            // A dynamic fee is charged at the rate in effect before the fill.
+            let phi = self.positions[i].effective_phi();
             self.positions[i].reserves = new_reserves;
+            self.accrue_protocol_fee(i, &phi.component, input);
             // Pull the asset ID from the pairs.
             trace.push(Value {
                 amount,
@@ -434,8 +479,33 @@ impl<S: StateRead + StateWrite> Frontier<S> {
             });
         }
 
-        // Add the new trace
-        self.trace.push(trace);
+        // Record the positions filled against, before any empty ones are replaced.
+        let positions: Vec<position::Id> = self.positions.iter().map(Position::id).collect();
+        if self.last_fill_positions.as_ref() == Some(&positions) {
+            // Only a position with a dynamic fee is filled against again
+            // without being replaced, one step at a time, so its steps are
+            // recorded as a single fill.
+            let last_trace = self.trace.last_mut().expect("a fill was applied");
+            for (total, value) in last_trace.iter_mut().zip(&trace) {
+                total.amount += value.amount;
+            }
+            if let Some(last_fill) = self.fills.as_mut().and_then(|fills| fills.last_mut()) {
+                for (total, value) in last_fill.amounts.iter_mut().zip(&trace) {
+                    *total += value.amount;
+                }
+            }
+        } else {
+            if let Some(fills) = &mut self.fills {
+                fills.push(RouteFill {
+                    route: trace.iter().map(|value| value.asset_id).collect(),
+                    amounts: trace.iter().map(|value| value.amount).collect(),
+                    positions: positions.clone(),
+                });
+            }
+            // Add the new trace
+            self.trace.push(trace);
+        }
+        self.last_fill_positions = Some(positions);
 
         (
             changes
@@ -451,6 +521,32 @@ impl<S: StateRead + StateWrite> Frontier<S> {
         )
     }
 
+    /// Skim the protocol's share of the trading fees charged by `phi` on the `input`
+    /// filled against the position at `index` from the position's reserves.
+    fn accrue_protocol_fee(&mut self, index: usize, phi: &BareTradingFunction, input: Amount) {
+        let asset_id = self.pairs[index].start;
+        let position = &mut self.positions[index];
+        let protocol_fee = phi.protocol_fee(input, self.protocol_fee_share_bps);
+        if protocol_fee == Amount::zero() {
+            return;
+        }
+
+        // The input was just added to the reserves, so they always cover the protocol fee.
+        if asset_id == position.phi.pair.asset_1() {
+            position.reserves.r1 = position.reserves.r1 - protocol_fee;
+        } else {
+            position.reserves.r2 = position.reserves.r2 - protocol_fee;
+        }
+
+        self.protocol_fees
+            .entry(position.id())
+            .or_insert(Value {
+                amount: Amount::zero(),
+                asset_id,
+            })
+            .amount += protocol_fee;
+    }
+
     async fn replace_empty_positions(&mut self) -> Result<bool, FillError> {
         for i in 0..self.pairs.len() {
             let desired_reserves = self.positions[i]
@@ -559,12 +655,31 @@ impl<S: StateRead + StateWrite> Frontier<S> {
                 ));
             }
 
-            let (unfilled, new_reserves, output) = position
-                .phi
+            let (unfilled, new_reserves, mut output) = position
+                .effective_phi()
                 .fill(current_input, &position.reserves)
                 .map_err(|_| FillError::ExecutionOverflow(position.id()))?;
-
-            if unfilled.amount > Amount::zero() {
+            let capacity =
+                position
+                    .fill_capacity(output.asset_id)
+                    .ok_or(FillError::AssetIdMismatch(
+                        output.asset_id,
+                        position.phi.pair,
+                    ))?;
+
+            if output.amount > capacity {
+                tracing::debug!(
+                    i,
+                    current_input = ?current_input.amount,
+                    output = ?output.amount,
+                    ?capacity,
+                    "fill would move dynamic fee by more than a step, marking as constraining"
+                );
+                // Filling past the capacity would charge a stale fee, so the
+                // position constrains the fill to a single step.
+                constraining_index = Some(i);
+                output.amount = capacity;
+            } else if unfilled.amount > Amount::zero() {
                 tracing::debug!(
                     i,
                     current_input = ?current_input.amount,
@@ -634,9 +749,11 @@ impl<S: StateRead + StateWrite> Frontier<S> {
         // and work forwards to compute the corresponding output amounts at
         // the end of the path.
 
+        // A position with a dynamic fee may only be filled up to a single
+        // step of its reserves, rather than all of them.
         let exactly_consumed_reserves = Value {
             amount: self.positions[constraining_index]
-                .reserves_for(self.pairs[constraining_index].end)
+                .fill_capacity(self.pairs[constraining_index].end)
                 .expect("asset ids should match"),
             asset_id: self.pairs[constraining_index].end,
         };
@@ -662,7 +779,7 @@ impl<S: StateRead + StateWrite> Frontier<S> {
 
         for i in start_index..self.positions.len() {
             let (unfilled, new_reserves, output) = self.positions[i]
-                .phi
+                .effective_phi()
                 .fill(current_value, &self.positions[i].reserves)
                 .expect("forward fill should not fail");
 
@@ -687,7 +804,7 @@ impl<S: StateRead + StateWrite> Frontier<S> {
             tx.trace[i + 1] = Some(current_value.amount);
 
             let (new_reserves, prev_input) = self.positions[i]
-                .phi
+                .effective_phi()
                 .fill_output(&self.positions[i].reserves, current_value)
                 .expect("backward fill should not fail")
                 .expect(
@@ -2,7 +2,7 @@ use anyhow::Result;
 
 use cnidarium::{StateDelta, StateRead};
 use penumbra_asset::asset;
-use penumbra_num::fixpoint::U128x128;
+use penumbra_num::{fixpoint::U128x128, Amount};
 use std::cmp::Ordering;
 use tracing::Instrument;
 
@@ -23,6 +23,9 @@ pub(super) struct Path<S: StateRead + 'static> {
     pub nodes: Vec<asset::Id>,
     /// An estimate of the end-to-end effective price along the path
     pub price: U128x128,
+    /// The liquidity available on the last hop of the path, in its end asset,
+    /// which bounds how much the path can fill; `None` for the empty path.
+    pub liquidity: Option<Amount>,
     /// A forked view of the state after traveling along this path.
     pub state: StateDelta<S>,
     /// A span recording information about the path, for debugging.
@@ -41,6 +44,7 @@ impl<S: StateRead + 'static> Path<S> {
             start,
             nodes: Vec::new(),
             price: 1u64.into(),
+            liquidity: None,
             state,
             span,
         }
@@ -52,6 +56,7 @@ impl<S: StateRead + 'static> Path<S> {
             start: self.start,
             nodes: self.nodes.clone(),
             price: self.price,
+            liquidity: self.liquidity,
             state: self.state.fork(),
             span: self.span.clone(),
         }
@@ -78,7 +83,7 @@ impl<S: StateRead + 'static> Path<S> {
 
         // Compute the effective price of a trade in the direction self.end()=>new_end
         let hop_price = best_price_position
-            .phi
+            .effective_phi()
             .orient_end(new_end)
             .expect("position should be contain the end asset")
             .effective_price();
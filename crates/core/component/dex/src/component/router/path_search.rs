@@ -1,15 +1,21 @@
-use std::sync::Arc;
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    sync::Arc,
+};
 
 use anyhow::Result;
 use async_trait::async_trait;
 use cnidarium::{StateDelta, StateRead};
 use futures::StreamExt;
 use penumbra_asset::asset;
-use penumbra_num::fixpoint::U128x128;
+use penumbra_num::{fixpoint::U128x128, Amount};
 use tokio::task::JoinSet;
 use tracing::{instrument, Instrument};
 
-use crate::component::PositionManager;
+use crate::{
+    component::{PositionManager, PositionRead, StateReadExt as _},
+    DirectedTradingPair, TradingPair,
+};
 
 use super::{Path, PathCache, PathEntry, RoutingParams, SharedPathCache};
 
@@ -40,9 +46,20 @@ pub trait PathSearch: StateRead + Clone + 'static {
         // at the end of routing
         let state = StateDelta::new(self.clone());
 
+        // Routes may not pass through pairs on which governance has paused trading.
+        let paused_pairs = Arc::new(self.paused_trading_pairs().await?);
+        // Hops much shallower than the best route found so far to their end are pruned.
+        let epsilon_bps = self.get_dex_params().await?.route_pruning_epsilon_bps;
+
         let cache = PathCache::begin(src, state);
         for i in 0..max_hops {
-            relax_active_paths(cache.clone(), fixed_candidates.clone()).await?;
+            relax_active_paths(
+                cache.clone(),
+                fixed_candidates.clone(),
+                paused_pairs.clone(),
+                epsilon_bps,
+            )
+            .await?;
             tracing::debug!(i, "finished relaxing all active paths");
         }
 
@@ -79,15 +96,38 @@ impl<S> PathSearch for S where S: StateRead + Clone + 'static {}
 async fn relax_active_paths<S: StateRead + 'static>(
     cache: SharedPathCache<S>,
     fixed_candidates: Arc<Vec<asset::Id>>,
+    paused_pairs: Arc<BTreeSet<TradingPair>>,
+    epsilon_bps: u32,
 ) -> Result<()> {
-    let active_paths = cache.lock().extract_active();
+    // Hops are pruned relative to the best paths as of the start of the relaxation,
+    // rather than as they are updated by its concurrent tasks, so that pruning
+    // doesn't depend on the order in which the tasks run.
+    let (active_paths, min_hop_liquidity) = {
+        let mut cache = cache.lock();
+        let min_hop_liquidity: BTreeMap<asset::Id, Amount> = cache
+            .0
+            .iter()
+            .filter_map(|(asset, entry)| {
+                let best = entry.path.liquidity?.value();
+                let min = best.saturating_mul(epsilon_bps.into()) / 10_000;
+                Some((*asset, min.into()))
+            })
+            .collect();
+        (cache.extract_active(), Arc::new(min_hop_liquidity))
+    };
     let mut js = JoinSet::new();
     tracing::debug!(
         active_paths_len = active_paths.len(),
         "relaxing active paths"
     );
     for path in active_paths {
-        js.spawn(relax_path(cache.clone(), path, fixed_candidates.clone()));
+        js.spawn(relax_path(
+            cache.clone(),
+            path,
+            fixed_candidates.clone(),
+            paused_pairs.clone(),
+            min_hop_liquidity.clone(),
+        ));
     }
     // Wait for all relaxations to complete.
     while let Some(task) = js.join_next().await {
@@ -100,6 +140,8 @@ async fn relax_path<S: StateRead + 'static>(
     cache: SharedPathCache<S>,
     mut path: Path<S>,
     fixed_candidates: Arc<Vec<asset::Id>>,
+    paused_pairs: Arc<BTreeSet<TradingPair>>,
+    min_hop_liquidity: Arc<BTreeMap<asset::Id, Amount>>,
 ) -> Result<()> {
     let mut candidates = path
         .state
@@ -115,8 +157,30 @@ async fn relax_path<S: StateRead + 'static>(
     while let Some(new_end) = candidates.inner_mut().next().await {
         let new_path = path.fork();
         let cache2 = cache.clone();
+        let paused_pairs = paused_pairs.clone();
+        let min_hop_liquidity = min_hop_liquidity.clone();
         js.spawn(async move {
-            if let Some(new_path) = new_path.extend_to(new_end?).await? {
+            let new_end = new_end?;
+            let hop = DirectedTradingPair::new(*new_path.end(), new_end);
+            if paused_pairs.contains(&hop.to_canonical()) {
+                new_path.span.in_scope(|| {
+                    tracing::debug!(?new_end, "pruning hop on paused trading pair");
+                });
+                return anyhow::Ok(());
+            }
+            // Prune hops which could improve on the best path to `new_end` by at
+            // most epsilon, since no path can fill more than its last hop carries,
+            // before doing the (more expensive) work of extending the path.
+            let liquidity = new_path.state.available_liquidity(&hop).await?;
+            let min = min_hop_liquidity.get(&new_end).copied().unwrap_or_default();
+            if liquidity <= min {
+                new_path.span.in_scope(|| {
+                    tracing::debug!(?new_end, %liquidity, %min, "pruning hop with insufficient liquidity");
+                });
+                return anyhow::Ok(());
+            }
+            if let Some(mut new_path) = new_path.extend_to(new_end).await? {
+                new_path.liquidity = Some(liquidity);
                 cache2.lock().consider(new_path)
             }
             anyhow::Ok(())
@@ -1,4 +1,4 @@
-use std::sync::Arc;
+use std::{collections::BTreeSet, sync::Arc};
 
 use anyhow::{Context, Result};
 use async_trait::async_trait;
@@ -15,7 +15,8 @@ use crate::{
         PositionManager, StateWriteExt,
     },
     lp::position::MAX_RESERVE_AMOUNT,
-    state_key, BatchSwapOutputData, ExecutionCircuitBreaker, SwapExecution, TradingPair,
+    state_key, BatchSwapOutputData, DirectedTradingPair, ExecutionCircuitBreaker, SwapExecution,
+    SwapExecutionTrace, TradingPair, ValueCircuitBreakerBreach,
 };
 
 use super::fill_route::FillError;
@@ -48,7 +49,9 @@ pub trait HandleBatchSwaps: StateWrite + Sized {
 
         tracing::debug!(?delta_1, ?delta_2, ?trading_pair, "decrypted batch swaps");
 
-        let execution_circuit_breaker = ExecutionCircuitBreaker::default();
+        // Each direction is routed with its own execution budget.
+        let mut execution_circuit_breaker_1_for_2 = ExecutionCircuitBreaker::default();
+        let mut execution_circuit_breaker_2_for_1 = ExecutionCircuitBreaker::default();
         // Fetch the ValueCircuitBreaker prior to calling `route_and_fill`, so
         // we know the total aggregate amount of each asset prior to executing and
         // can ensure the total outflows don't exceed the total balances.
@@ -63,33 +66,43 @@ pub trait HandleBatchSwaps: StateWrite + Sized {
             None => ValueCircuitBreaker::default(),
         };
 
+        // The fills recorded while routing each direction make up its trace.
+        let mut trace_1_for_2 = SwapExecutionTrace::default();
+        let mut trace_2_for_1 = SwapExecutionTrace::default();
+
         let swap_execution_1_for_2 = if delta_1.value() > 0 {
-            Some(
-                self.route_and_fill(
+            let execution = self
+                .route_and_fill(
                     trading_pair.asset_1(),
                     trading_pair.asset_2(),
                     delta_1,
                     params.clone(),
-                    execution_circuit_breaker.clone(),
+                    &mut execution_circuit_breaker_1_for_2,
                 )
-                .await?,
-            )
+                .await?;
+            trace_1_for_2.fills = Arc::get_mut(self)
+                .expect("expected state to have no other refs")
+                .take_route_fills();
+            Some(execution)
         } else {
             tracing::debug!("no input for asset 1, skipping 1=>2 routing and execution");
             None
         };
 
         let swap_execution_2_for_1 = if delta_2.value() > 0 {
-            Some(
-                self.route_and_fill(
+            let execution = self
+                .route_and_fill(
                     trading_pair.asset_2(),
                     trading_pair.asset_1(),
                     delta_2,
                     params.clone(),
-                    execution_circuit_breaker,
+                    &mut execution_circuit_breaker_2_for_1,
                 )
-                .await?,
-            )
+                .await?;
+            trace_2_for_1.fills = Arc::get_mut(self)
+                .expect("expected state to have no other refs")
+                .take_route_fills();
+            Some(execution)
         } else {
             tracing::debug!("no input for asset 2, skipping 2=>1 execution");
             None
@@ -123,16 +136,34 @@ pub trait HandleBatchSwaps: StateWrite + Sized {
 
         // Check that the output data doesn't exceed the ValueCircuitBreaker's quantities
         // (i.e. we didn't outflow more value than existed within liquidity positions).
-        let available_asset_1 = value_circuit_breaker.available(trading_pair.asset_1());
-        let available_asset_2 = value_circuit_breaker.available(trading_pair.asset_2());
-        assert!(
-            output_data.lambda_1 <= available_asset_1.amount,
-            "asset 1 outflow exceeds available balance"
-        );
-        assert!(
-            output_data.lambda_2 <= available_asset_2.amount,
-            "asset 2 outflow exceeds available balance"
-        );
+        // If it does, the chain halts.
+        for (asset_id, outflow) in [
+            (trading_pair.asset_1(), output_data.lambda_1),
+            (trading_pair.asset_2(), output_data.lambda_2),
+        ] {
+            let available = value_circuit_breaker.available(asset_id).amount;
+            if outflow > available {
+                metrics::counter!(crate::component::metrics::DEX_VALUE_CIRCUIT_BREAKER_TRIGGERED)
+                    .increment(1);
+                let positions = trace_1_for_2
+                    .fills
+                    .iter()
+                    .chain(trace_2_for_1.fills.iter())
+                    .flat_map(|fill| fill.positions.iter().copied())
+                    .collect::<BTreeSet<_>>()
+                    .into_iter()
+                    .collect();
+                ValueCircuitBreakerBreach {
+                    height: block_height,
+                    trading_pair,
+                    asset_id,
+                    available,
+                    outflow,
+                    positions,
+                }
+                .halt();
+            }
+        }
 
         // Fetch the swap execution object that should have been modified during the routing and filling.
         tracing::debug!(
@@ -140,9 +171,31 @@ pub trait HandleBatchSwaps: StateWrite + Sized {
             ?swap_execution_1_for_2,
             ?swap_execution_2_for_1
         );
-        Arc::get_mut(self)
-            .expect("expected state to have no other refs")
-            .set_output_data(output_data, swap_execution_1_for_2, swap_execution_2_for_1);
+        let state = Arc::get_mut(self).expect("expected state to have no other refs");
+        state.set_output_data(output_data, swap_execution_1_for_2, swap_execution_2_for_1);
+
+        // Keep the traces of any fills, which are only recorded if traces are being kept.
+        let pair_1_for_2 = DirectedTradingPair::new(trading_pair.asset_1(), trading_pair.asset_2());
+        for (trace, direction) in [
+            (trace_1_for_2, pair_1_for_2),
+            (trace_2_for_1, pair_1_for_2.flip()),
+        ] {
+            if !trace.fills.is_empty() {
+                state.put_swap_execution_trace(block_height, direction, trace);
+            }
+        }
+
+        // Record whether the routing of either direction was throttled.
+        for (execution_circuit_breaker, direction) in [
+            (&execution_circuit_breaker_1_for_2, pair_1_for_2),
+            (&execution_circuit_breaker_2_for_1, pair_1_for_2.flip()),
+        ] {
+            execution_circuit_breaker.record_metrics("batch_swap");
+            if let Some(trip) = execution_circuit_breaker.trip(block_height, direction) {
+                tracing::debug!(?trip, "execution circuit breaker halted batch swap routing");
+                state.set_execution_circuit_breaker_trip(trip);
+            }
+        }
 
         Ok(())
     }
@@ -160,7 +213,7 @@ pub trait RouteAndFill: StateWrite + Sized {
         asset_2: asset::Id,
         input: Amount,
         params: RoutingParams,
-        mut execution_circuit_breaker: ExecutionCircuitBreaker,
+        execution_circuit_breaker: &mut ExecutionCircuitBreaker,
     ) -> Result<SwapExecution>
     where
         Self: 'static,
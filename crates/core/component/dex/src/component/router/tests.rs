@@ -19,7 +19,7 @@ use crate::{
         position::{self, Position},
         Reserves,
     },
-    DirectedTradingPair, DirectedUnitPair,
+    DexParameters, DirectedTradingPair, DirectedUnitPair,
 };
 
 use super::{PathSearch, RoutingParams};
@@ -844,6 +844,67 @@ async fn fill_route_unconstrained() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[tokio::test]
+/// Test that a position with a dynamic fee charges the fee for its reserves as
+/// they are filled against, rather than the fee before the fill.
+async fn fill_route_charges_dynamic_fee_over_the_fill() -> anyhow::Result<()> {
+    let _ = tracing_subscriber::fmt::try_init();
+    let storage = TempStorage::new().await?.apply_minimal_genesis().await?;
+    let mut state = Arc::new(StateDelta::new(storage.latest_snapshot()));
+    let mut state_tx = state.try_begin_transaction().unwrap();
+
+    let gm = asset::Cache::with_known_assets().get_unit("gm").unwrap();
+    let gn = asset::Cache::with_known_assets().get_unit("gn").unwrap();
+    let pair = DirectedUnitPair::new(gm.clone(), gn.clone());
+
+    // A balanced position trading gm and gn at 1:1, with no fee while its
+    // reserves are balanced, and a fee of 10% once either side is empty.
+    let mut position = Position::new(
+        OsRng,
+        pair.into_directed_trading_pair(),
+        0u32,
+        gn.unit_amount(),
+        gm.unit_amount(),
+        Reserves {
+            r1: Amount::from(100u64) * gm.unit_amount(),
+            r2: Amount::from(100u64) * gn.unit_amount(),
+        },
+    );
+    position.dynamic_fee = Some(position::DynamicFee { max_fee: 1000 });
+    let id = position.id();
+    assert_eq!(position.effective_phi().component.fee, 0);
+    state_tx.put_position(position).await.unwrap();
+
+    let input = Value {
+        asset_id: gm.id(),
+        amount: Amount::from(50u64) * gm.unit_amount(),
+    };
+    let execution = FillRoute::fill_route(&mut state_tx, input, &[gn.id()], None)
+        .await
+        .unwrap();
+    assert_eq!(execution.input, input);
+    // The fill is applied in steps, but recorded as a single trace.
+    assert_eq!(execution.traces.len(), 1);
+    assert_eq!(execution.traces[0][0], input);
+
+    // Selling half of the position's gn leaves its reserves about half
+    // imbalanced, so the fee rises to about 5% over the fill.  Charging the
+    // fee before the fill would have sold 50gn, and charging the final fee
+    // throughout would have sold 47.5gn.
+    let output = execution.output.amount;
+    assert!(output < Amount::from(49u64) * gn.unit_amount());
+    assert!(output > Amount::from(475u64) * gn.unit_amount() / Amount::from(10u64));
+
+    let position = state_tx
+        .position_by_id(&id)
+        .await?
+        .expect("position still exists");
+    let final_fee = position.effective_phi().component.fee;
+    assert!((450..=500).contains(&final_fee), "final fee {final_fee}");
+
+    Ok(())
+}
+
 #[tokio::test]
 /// Test that we only fill up to the specified spill price.
 /// TODO(erwan): stub, fleshing this out later.
@@ -984,6 +1045,70 @@ async fn simple_route() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[tokio::test]
+async fn path_search_prunes_illiquid_hops() -> anyhow::Result<()> {
+    let _ = tracing_subscriber::fmt::try_init();
+    let storage = TempStorage::new().await?.apply_minimal_genesis().await?;
+    let mut state = Arc::new(StateDelta::new(storage.latest_snapshot()));
+    let mut state_tx = state.try_begin_transaction().unwrap();
+
+    let gn = asset::Cache::with_known_assets().get_unit("gn").unwrap();
+    let penumbra = asset::Cache::with_known_assets()
+        .get_unit("penumbra")
+        .unwrap();
+    let pusd = asset::Cache::with_known_assets()
+        .get_unit("test_usd")
+        .unwrap();
+
+    // A deep gn:test_usd position (buy 100 gn at 1 test_usd)...
+    let gn_pusd = limit_buy(
+        DirectedUnitPair::new(gn.clone(), pusd.clone()),
+        100u64.into(),
+        1u64.into(),
+    );
+    // ...and a route through penumbra whose first hop is deep, but whose last
+    // hop is a tiny position at a very attractive price (buy 1 penumbra at 2 test_usd).
+    let gn_penumbra = limit_buy(
+        DirectedUnitPair::new(gn.clone(), penumbra.clone()),
+        100u64.into(),
+        1u64.into(),
+    );
+    let dust = limit_buy(
+        DirectedUnitPair::new(penumbra.clone(), pusd.clone()),
+        1u64.into(),
+        2u64.into(),
+    );
+    state_tx.put_position(gn_pusd).await.unwrap();
+    state_tx.put_position(gn_penumbra).await.unwrap();
+    state_tx.put_position(dust).await.unwrap();
+    state_tx.apply();
+
+    let params = RoutingParams {
+        max_hops: 2,
+        ..Default::default()
+    };
+
+    // Without pruning, the thin hop makes the route through penumbra the best.
+    let (path, _spill) = state
+        .path_search(gn.id(), pusd.id(), params.clone())
+        .await?;
+    assert_eq!(path, Some(vec![penumbra.id(), pusd.id()]));
+
+    // The thin hop carries 2 test_usd, against the 100 test_usd of the last hop
+    // of the direct route found before it, so with an epsilon of 10% it is
+    // skipped and the direct route is used instead.
+    let mut state_tx = state.try_begin_transaction().unwrap();
+    state_tx.put_dex_params(DexParameters {
+        route_pruning_epsilon_bps: 1_000,
+        ..Default::default()
+    });
+    state_tx.apply();
+    let (path, _spill) = state.path_search(gn.id(), pusd.id(), params).await?;
+    assert_eq!(path, Some(vec![pusd.id()]));
+
+    Ok(())
+}
+
 #[tokio::test]
 async fn best_position_route_and_fill() -> anyhow::Result<()> {
     let _ = tracing_subscriber::fmt::try_init();
@@ -1,4 +1,8 @@
-use std::{pin::Pin, sync::Arc};
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    pin::Pin,
+    sync::Arc,
+};
 
 use async_stream::try_stream;
 use futures::{StreamExt, TryStreamExt};
@@ -7,33 +11,51 @@ use tracing::instrument;
 
 use cnidarium::{StateDelta, Storage};
 use penumbra_asset::{asset, Value};
+use penumbra_num::fixpoint::U128x128;
 use penumbra_proto::{
     core::component::dex::v1::{
         query_service_server::QueryService, simulate_trade_request::routing,
         simulate_trade_request::routing::Setting, simulate_trade_request::Routing,
         simulation_service_server::SimulationService, ArbExecutionRequest, ArbExecutionResponse,
         ArbExecutionsRequest, ArbExecutionsResponse, BatchSwapOutputDataRequest,
-        BatchSwapOutputDataResponse, LiquidityPositionByIdRequest, LiquidityPositionByIdResponse,
-        LiquidityPositionsByIdRequest, LiquidityPositionsByIdResponse,
-        LiquidityPositionsByPriceRequest, LiquidityPositionsByPriceResponse,
-        LiquidityPositionsRequest, LiquidityPositionsResponse, SimulateTradeRequest,
+        BatchSwapOutputDataResponse, CircuitBreakerStatusRequest, CircuitBreakerStatusResponse,
+        DutchAuctionByIdRequest, DutchAuctionByIdResponse, LiquidityPositionAnalyticsRequest,
+        LiquidityPositionAnalyticsResponse, LiquidityPositionByIdRequest,
+        LiquidityPositionByIdResponse, LiquidityPositionsByIdRequest,
+        LiquidityPositionsByIdResponse, LiquidityPositionsByPriceRequest,
+        LiquidityPositionsByPriceResponse, LiquidityPositionsRequest, LiquidityPositionsResponse,
+        PausedTradingPairsRequest, PausedTradingPairsResponse, PositionAtHeightRequest,
+        PositionAtHeightResponse, PositionHistoryRequest, PositionHistoryResponse,
+        PositionMetadataRequest, PositionMetadataResponse, SimulateTradeRequest,
         SimulateTradeResponse, SpreadRequest, SpreadResponse, SwapExecutionRequest,
-        SwapExecutionResponse, SwapExecutionsRequest, SwapExecutionsResponse,
+        SwapExecutionResponse, SwapExecutionTraceRequest, SwapExecutionTraceResponse,
+        SwapExecutionsRequest, SwapExecutionsResponse, ValueCircuitBreakerBreachRequest,
+        ValueCircuitBreakerBreachResponse,
     },
     DomainType, StateReadProto,
 };
+use penumbra_sct::component::clock::EpochRead as _;
 
 use crate::ExecutionCircuitBreaker;
 use crate::{
-    lp::position::{self, Position},
-    state_key, DirectedTradingPair, SwapExecution, TradingPair,
+    auction::AuctionId,
+    lp::{
+        position::{self, Position},
+        PositionAnalytics, PositionHistoryEntry,
+    },
+    state_key, CircuitBreakerStatus, DirectedTradingPair, SwapExecution, TradingPair,
+    ValueCircuitBreakerBreach,
 };
 
 use super::{
     router::{RouteAndFill, RoutingParams},
-    PositionRead, StateReadExt,
+    DutchAuctionRead, PositionRead, StateReadExt,
 };
 
+/// The maximum number of positions which can be summarized by a single
+/// `LiquidityPositionAnalytics` request.
+const MAX_ANALYTICS_POSITIONS: usize = 1000;
+
 // TODO: Hide this and only expose a Router?
 pub struct Server {
     storage: Storage,
@@ -66,6 +88,11 @@ impl QueryService for Server {
         Pin<Box<dyn futures::Stream<Item = Result<ArbExecutionsResponse, tonic::Status>> + Send>>;
     type SwapExecutionsStream =
         Pin<Box<dyn futures::Stream<Item = Result<SwapExecutionsResponse, tonic::Status>> + Send>>;
+    type PositionHistoryStream =
+        Pin<Box<dyn futures::Stream<Item = Result<PositionHistoryResponse, tonic::Status>> + Send>>;
+    type SwapExecutionTraceStream = Pin<
+        Box<dyn futures::Stream<Item = Result<SwapExecutionTraceResponse, tonic::Status>> + Send>,
+    >;
 
     #[instrument(skip(self, request))]
     async fn arb_execution(
@@ -98,44 +125,22 @@ impl QueryService for Server {
         let state = self.storage.latest_snapshot();
         let request_inner = request.into_inner();
         let start_height = request_inner.start_height;
-        let end_height = request_inner.end_height;
+        // An unset end height leaves the range open-ended.
+        let end_height = Some(request_inner.end_height).filter(|&height| height != 0);
 
-        let s = state.prefix(state_key::arb_executions());
         Ok(tonic::Response::new(
-            s.filter_map(
-                move |i: anyhow::Result<(String, SwapExecution)>| async move {
-                    if i.is_err() {
-                        return Some(Err(tonic::Status::unavailable(format!(
-                            "error getting prefix value from storage: {}",
-                            i.expect_err("i is_err")
-                        ))));
-                    }
-
-                    let (key, arb_execution) = i.expect("i is Ok");
-                    let height = key
-                        .split('/')
-                        .last()
-                        .expect("arb execution key has height as last part")
-                        .parse()
-                        .expect("height is a number");
-
-                    // TODO: would be great to start iteration at start_height
-                    // and stop at end_height rather than touching _every_
-                    // key, but the current storage implementation doesn't make this
-                    // easy.
-                    if height < start_height || height > end_height {
-                        None
-                    } else {
-                        Some(Ok(ArbExecutionsResponse {
-                            swap_execution: Some(arb_execution.into()),
-                            height,
-                        }))
-                    }
-                },
-            )
-            // TODO: how do we instrument a Stream
-            //.instrument(Span::current())
-            .boxed(),
+            state
+                .arb_executions(start_height, end_height)
+                .map_ok(|(height, arb_execution)| ArbExecutionsResponse {
+                    swap_execution: Some(arb_execution.into()),
+                    height,
+                })
+                .map_err(|e: anyhow::Error| {
+                    tonic::Status::unavailable(format!(
+                        "error getting arb executions from storage: {e}"
+                    ))
+                })
+                .boxed(),
         ))
     }
 
@@ -196,6 +201,40 @@ impl QueryService for Server {
         }
     }
 
+    #[instrument(skip(self, request))]
+    async fn swap_execution_trace(
+        &self,
+        request: tonic::Request<SwapExecutionTraceRequest>,
+    ) -> Result<tonic::Response<Self::SwapExecutionTraceStream>, Status> {
+        let state = self.storage.latest_snapshot();
+        let request_inner = request.into_inner();
+        let height = request_inner.height;
+        let trading_pair: DirectedTradingPair = request_inner
+            .trading_pair
+            .ok_or_else(|| Status::invalid_argument("missing trading_pair"))?
+            .try_into()
+            .map_err(|_| Status::invalid_argument("invalid trading_pair"))?;
+
+        let trace = state
+            .swap_execution_trace(height, trading_pair)
+            .await
+            .map_err(|e| tonic::Status::internal(e.to_string()))?
+            .ok_or_else(|| {
+                Status::not_found(format!(
+                    "no swap execution trace kept for {trading_pair:?} at height {height}"
+                ))
+            })?;
+
+        Ok(tonic::Response::new(
+            futures::stream::iter(trace.fills.into_iter().map(|fill| {
+                Ok(SwapExecutionTraceResponse {
+                    fill: Some(fill.into()),
+                })
+            }))
+            .boxed(),
+        ))
+    }
+
     #[instrument(skip(self, request))]
     async fn swap_executions(
         &self,
@@ -301,7 +340,7 @@ impl QueryService for Server {
         let approx_effective_price_1_to_2 = best_1_to_2_position
             .as_ref()
             .map(|p| {
-                p.phi
+                p.effective_phi()
                     .orient_start(pair.asset_1())
                     .expect("position has one end = asset 1")
                     .effective_price()
@@ -312,7 +351,7 @@ impl QueryService for Server {
         let approx_effective_price_2_to_1 = best_2_to_1_position
             .as_ref()
             .map(|p| {
-                p.phi
+                p.effective_phi()
                     .orient_start(pair.asset_2())
                     .expect("position has one end = asset 2")
                     .effective_price()
@@ -484,6 +523,270 @@ impl QueryService for Server {
             .boxed(),
         ))
     }
+
+    #[instrument(skip(self, request))]
+    async fn position_history(
+        &self,
+        request: tonic::Request<PositionHistoryRequest>,
+    ) -> Result<tonic::Response<Self::PositionHistoryStream>, Status> {
+        let state = self.storage.latest_snapshot();
+
+        let position_id: position::Id = request
+            .into_inner()
+            .position_id
+            .ok_or_else(|| Status::invalid_argument("empty message"))?
+            .try_into()
+            .map_err(|e: anyhow::Error| {
+                tonic::Status::invalid_argument(format!("error converting position_id: {e}"))
+            })?;
+
+        Ok(tonic::Response::new(
+            state
+                .position_history(&position_id)
+                .map_ok(|entry| PositionHistoryResponse {
+                    entry: Some(entry.into()),
+                })
+                .map_err(|e: anyhow::Error| {
+                    tonic::Status::unavailable(format!(
+                        "error getting position history from storage: {e}"
+                    ))
+                })
+                .boxed(),
+        ))
+    }
+
+    #[instrument(skip(self, request))]
+    async fn position_at_height(
+        &self,
+        request: tonic::Request<PositionAtHeightRequest>,
+    ) -> Result<tonic::Response<PositionAtHeightResponse>, Status> {
+        let state = self.storage.latest_snapshot();
+
+        let request = request.into_inner();
+        let position_id: position::Id = request
+            .position_id
+            .ok_or_else(|| Status::invalid_argument("empty message"))?
+            .try_into()
+            .map_err(|e: anyhow::Error| {
+                tonic::Status::invalid_argument(format!("error converting position_id: {e}"))
+            })?;
+
+        let position = state
+            .position_at_height(&position_id, request.height)
+            .await
+            .map_err(|e: anyhow::Error| {
+                tonic::Status::unavailable(format!(
+                    "error reconstructing position from storage: {e}"
+                ))
+            })?;
+
+        Ok(tonic::Response::new(match position {
+            Some((position, last_changed_height)) => PositionAtHeightResponse {
+                position: Some(position.into()),
+                last_changed_height,
+            },
+            None => PositionAtHeightResponse::default(),
+        }))
+    }
+
+    #[instrument(skip(self, request))]
+    async fn position_metadata(
+        &self,
+        request: tonic::Request<PositionMetadataRequest>,
+    ) -> Result<tonic::Response<PositionMetadataResponse>, Status> {
+        let state = self.storage.latest_snapshot();
+
+        let position_id: position::Id = request
+            .into_inner()
+            .position_id
+            .ok_or_else(|| Status::invalid_argument("empty message"))?
+            .try_into()
+            .map_err(|e: anyhow::Error| {
+                tonic::Status::invalid_argument(format!("error converting position_id: {e}"))
+            })?;
+
+        let metadata = state
+            .position_metadata(&position_id)
+            .await
+            .map_err(|e: anyhow::Error| {
+                tonic::Status::unavailable(format!(
+                    "error fetching position metadata from storage: {e}"
+                ))
+            })?
+            .ok_or_else(|| Status::not_found("position metadata not found"))?;
+
+        Ok(tonic::Response::new(PositionMetadataResponse {
+            metadata: Some(metadata.into()),
+        }))
+    }
+
+    #[instrument(skip(self, request))]
+    async fn liquidity_position_analytics(
+        &self,
+        request: tonic::Request<LiquidityPositionAnalyticsRequest>,
+    ) -> Result<tonic::Response<LiquidityPositionAnalyticsResponse>, Status> {
+        let state = self.storage.latest_snapshot();
+
+        let position_ids = request.into_inner().position_ids;
+        if position_ids.len() > MAX_ANALYTICS_POSITIONS {
+            return Err(Status::invalid_argument(format!(
+                "at most {MAX_ANALYTICS_POSITIONS} positions can be summarized at once"
+            )));
+        }
+        let position_ids: BTreeSet<position::Id> = position_ids
+            .into_iter()
+            .map(TryInto::try_into)
+            .collect::<anyhow::Result<_>>()
+            .map_err(|e: anyhow::Error| {
+                tonic::Status::invalid_argument(format!("error converting position_id: {e}"))
+            })?;
+
+        let current_height = state
+            .get_block_height()
+            .await
+            .map_err(|e| tonic::Status::unavailable(format!("error getting block height: {e}")))?;
+
+        // The analytics for each trading pair, with the heights at which any
+        // of its positions were filled.
+        let mut pairs: BTreeMap<TradingPair, (PositionAnalytics, BTreeSet<u64>)> = BTreeMap::new();
+        for id in position_ids {
+            let position = state
+                .position_by_id(&id)
+                .await
+                .map_err(|e: anyhow::Error| {
+                    tonic::Status::unavailable(format!("error fetching position from storage: {e}"))
+                })?
+                .ok_or_else(|| Status::not_found(format!("position {id} not found")))?;
+            let history: Vec<PositionHistoryEntry> = state
+                .position_history(&id)
+                .try_collect()
+                .await
+                .map_err(|e: anyhow::Error| {
+                    tonic::Status::unavailable(format!(
+                        "error getting position history from storage: {e}"
+                    ))
+                })?;
+
+            let (analytics, fill_heights) = pairs
+                .entry(position.phi.pair)
+                .or_insert_with(|| (PositionAnalytics::new(position.phi.pair), BTreeSet::new()));
+            analytics.add_history(&history, current_height);
+            fill_heights.extend(
+                history
+                    .iter()
+                    .filter(|entry| entry.fills > 0)
+                    .map(|entry| entry.height),
+            );
+        }
+
+        // Value each pair's positions at the clearing price of the most recent
+        // batch swap which filled any of them, since that is the last price at
+        // which they are known to have traded.
+        let mut response = LiquidityPositionAnalyticsResponse::default();
+        for (pair, (mut analytics, fill_heights)) in pairs {
+            let forward = DirectedTradingPair::new(pair.asset_1(), pair.asset_2());
+            for height in fill_heights.into_iter().rev() {
+                let price = match state.swap_execution(height, forward).await {
+                    Ok(Some(execution)) => {
+                        U128x128::ratio(execution.output.amount, execution.input.amount)
+                    }
+                    Ok(None) => match state.swap_execution(height, forward.flip()).await {
+                        Ok(Some(execution)) => {
+                            U128x128::ratio(execution.input.amount, execution.output.amount)
+                        }
+                        Ok(None) => continue,
+                        Err(e) => return Err(tonic::Status::internal(e.to_string())),
+                    },
+                    Err(e) => return Err(tonic::Status::internal(e.to_string())),
+                };
+                let Ok(price) = price else {
+                    continue;
+                };
+                analytics.value_at(height, price).map_err(|e| {
+                    tonic::Status::internal(format!("error valuing positions: {e}"))
+                })?;
+                break;
+            }
+            response.analytics.push(analytics.into());
+        }
+
+        Ok(tonic::Response::new(response))
+    }
+
+    #[instrument(skip(self, _request))]
+    async fn paused_trading_pairs(
+        &self,
+        _request: tonic::Request<PausedTradingPairsRequest>,
+    ) -> Result<tonic::Response<PausedTradingPairsResponse>, Status> {
+        let state = self.storage.latest_snapshot();
+
+        let trading_pairs = state.paused_trading_pairs().await.map_err(|e| {
+            tonic::Status::unavailable(format!("error fetching paused trading pairs: {e}"))
+        })?;
+
+        Ok(tonic::Response::new(PausedTradingPairsResponse {
+            trading_pairs: trading_pairs.into_iter().map(Into::into).collect(),
+        }))
+    }
+
+    #[instrument(skip(self, _request))]
+    async fn circuit_breaker_status(
+        &self,
+        _request: tonic::Request<CircuitBreakerStatusRequest>,
+    ) -> Result<tonic::Response<CircuitBreakerStatusResponse>, Status> {
+        let state = self.storage.latest_snapshot();
+
+        let last_trip = state.execution_circuit_breaker_trip().await.map_err(|e| {
+            tonic::Status::unavailable(format!("error fetching circuit breaker status: {e}"))
+        })?;
+
+        Ok(tonic::Response::new(CircuitBreakerStatusResponse {
+            status: Some(CircuitBreakerStatus::new(last_trip).into()),
+        }))
+    }
+
+    #[instrument(skip(self, _request))]
+    async fn value_circuit_breaker_breach(
+        &self,
+        _request: tonic::Request<ValueCircuitBreakerBreachRequest>,
+    ) -> Result<tonic::Response<ValueCircuitBreakerBreachResponse>, Status> {
+        let breach = ValueCircuitBreakerBreach::recorded().map_err(|e| {
+            tonic::Status::unavailable(format!("error fetching value circuit breaker breach: {e}"))
+        })?;
+
+        Ok(tonic::Response::new(ValueCircuitBreakerBreachResponse {
+            breach: breach.map(Into::into),
+        }))
+    }
+
+    #[instrument(skip(self, request))]
+    async fn dutch_auction_by_id(
+        &self,
+        request: tonic::Request<DutchAuctionByIdRequest>,
+    ) -> Result<tonic::Response<DutchAuctionByIdResponse>, Status> {
+        let state = self.storage.latest_snapshot();
+
+        let auction_id: AuctionId = request
+            .into_inner()
+            .auction_id
+            .ok_or_else(|| Status::invalid_argument("empty message"))?
+            .try_into()
+            .map_err(|e: anyhow::Error| {
+                tonic::Status::invalid_argument(format!("error converting auction_id: {e}"))
+            })?;
+
+        let auction = state
+            .dutch_auction_by_id(&auction_id)
+            .await
+            .map_err(|e: anyhow::Error| {
+                tonic::Status::unavailable(format!("error fetching auction from storage: {e}"))
+            })?
+            .ok_or_else(|| Status::not_found("auction not found"))?;
+
+        Ok(tonic::Response::new(DutchAuctionByIdResponse {
+            auction: Some(auction.into()),
+        }))
+    }
 }
 
 #[tonic::async_trait]
@@ -531,14 +834,14 @@ impl SimulationService for Server {
 
         let state = self.storage.latest_snapshot();
         let mut state_tx = Arc::new(StateDelta::new(state));
-        let execution_circuit_breaker = ExecutionCircuitBreaker::default();
+        let mut execution_circuit_breaker = ExecutionCircuitBreaker::default();
         let swap_execution = state_tx
             .route_and_fill(
                 input.asset_id,
                 output_id,
                 input.amount,
                 routing_params,
-                execution_circuit_breaker,
+                &mut execution_circuit_breaker,
             )
             .await
             .map_err(|e| tonic::Status::internal(format!("error simulating trade: {:#}", e)))?;
@@ -17,8 +17,11 @@ use crate::{
         router::{limit_buy, limit_sell, HandleBatchSwaps, RoutingParams},
         Arbitrage, PositionManager, PositionRead, StateReadExt, StateWriteExt,
     },
-    lp::{position::Position, Reserves},
-    BatchSwapOutputData, DirectedTradingPair, DirectedUnitPair,
+    lp::{
+        position::{self, Position},
+        Reserves,
+    },
+    BatchSwapOutputData, DirectedTradingPair, DirectedUnitPair, SwapExecution,
 };
 
 // TODO: what's the right way to mock genesis? if component A needs component B,
@@ -465,6 +468,7 @@ async fn empty_order_fails() -> anyhow::Result<()> {
 
     let position_action = PositionOpen {
         position: position_1,
+        strategy_tag: String::new(),
     };
 
     assert!(position_action.check_stateless(()).await.is_err());
@@ -896,3 +900,1054 @@ async fn reproduce_arbitrage_loop_testnet_53() -> anyhow::Result<()> {
     tracing::info!(?arb_execution, "fetched arb execution!");
     Ok(())
 }
+
+#[tokio::test]
+/// Checks that the history of a position records its opening, fills, and
+/// closure, with one entry for each block in which it changed.
+async fn position_history_records_lifecycle() -> anyhow::Result<()> {
+    let storage = TempStorage::new().await?.apply_minimal_genesis().await?;
+    let mut state = StateDelta::new(storage.latest_snapshot());
+
+    let gm = asset::Cache::with_known_assets().get_unit("gm").unwrap();
+    let gn = asset::Cache::with_known_assets().get_unit("gn").unwrap();
+    let pair = DirectedTradingPair::new(gm.id(), gn.id());
+
+    /* Limit Buy 100gm@1.2gn, with a 1% fee */
+    let position = Position::new(
+        OsRng,
+        pair,
+        100u32,
+        1_200_000u64.into(),
+        1_000_000u64.into(),
+        Reserves {
+            r1: 0u64.into(),
+            r2: 120_000u64.into(),
+        },
+    );
+    let id = position.id();
+    let gm_is_asset_1 = position.phi.pair.asset_1() == gm.id();
+
+    // Block 1: the position is opened.
+    state.put_position(position).await?;
+    state.write_position_history(1);
+
+    // Block 2: the position is filled against twice, then closed.
+    let mut input = Amount::zero();
+    for _ in 0..2 {
+        let delta_gm = Value {
+            amount: 10_000u64.into(),
+            asset_id: gm.id(),
+        };
+        let execution = FillRoute::fill_route(&mut state, delta_gm, &[gn.id()], None).await?;
+        input = input + execution.input.amount;
+    }
+    state.close_position_by_id(&id).await?;
+    state.write_position_history(2);
+
+    let history = state
+        .position_history(&id)
+        .collect::<Vec<_>>()
+        .await
+        .into_iter()
+        .collect::<anyhow::Result<Vec<_>>>()?;
+    assert_eq!(history.len(), 2);
+    // The history is kept out of the verifiable state.
+    assert!(
+        cnidarium::StateRead::prefix_raw(&state, "dex/position_history/")
+            .boxed()
+            .next()
+            .await
+            .is_none()
+    );
+
+    assert_eq!(history[0].height, 1);
+    assert_eq!(history[0].prev_state, None);
+    assert_eq!(history[0].state, position::State::Opened);
+    assert_eq!(history[0].fills, 0);
+
+    assert_eq!(history[1].height, 2);
+    assert_eq!(history[1].prev_state, Some(position::State::Opened));
+    assert_eq!(history[1].state, position::State::Closed);
+    assert_eq!(history[1].fills, 2);
+    let (inflow, fees) = if gm_is_asset_1 {
+        (history[1].inflow_1, history[1].fees_1)
+    } else {
+        (history[1].inflow_2, history[1].fees_2)
+    };
+    assert_eq!(inflow, input);
+    assert!(fees > Amount::zero());
+
+    // The position can be reconstructed as of each height from its history.
+    assert!(state.position_at_height(&id, 0).await?.is_none());
+    let (opened, last_changed) = state.position_at_height(&id, 1).await?.unwrap();
+    assert_eq!(last_changed, 1);
+    assert_eq!(opened.state, position::State::Opened);
+    assert_eq!(opened.reserves.r1, history[0].reserves.r1);
+    assert_eq!(opened.reserves.r2, history[0].reserves.r2);
+    let (closed, last_changed) = state.position_at_height(&id, 5).await?.unwrap();
+    assert_eq!(last_changed, 2);
+    assert_eq!(closed.state, position::State::Closed);
+    assert_eq!(closed.reserves.r1, history[1].reserves.r1);
+    assert_eq!(closed.reserves.r2, history[1].reserves.r2);
+
+    Ok(())
+}
+
+#[tokio::test]
+/// Checks that opening a position records its metadata, and that overlong
+/// strategy tags are rejected.
+async fn position_open_records_metadata() -> anyhow::Result<()> {
+    use cnidarium_component::ActionHandler as _;
+
+    use crate::lp::MAX_STRATEGY_TAG_LEN;
+
+    let storage = TempStorage::new().await?.apply_minimal_genesis().await?;
+    let mut state = StateDelta::new(storage.latest_snapshot());
+
+    let gm = asset::Cache::with_known_assets().get_unit("gm").unwrap();
+    let gn = asset::Cache::with_known_assets().get_unit("gn").unwrap();
+    let position = Position::new(
+        OsRng,
+        DirectedTradingPair::new(gm.id(), gn.id()),
+        0u32,
+        1u64.into(),
+        1u64.into(),
+        Reserves {
+            r1: 1_000u64.into(),
+            r2: 0u64.into(),
+        },
+    );
+    let id = position.id();
+
+    let mut open = PositionOpen {
+        position,
+        strategy_tag: "x".repeat(MAX_STRATEGY_TAG_LEN + 1),
+    };
+    assert!(open.check_stateless(()).await.is_err());
+
+    open.strategy_tag = "market-maker/v1".to_string();
+    open.check_stateless(()).await?;
+    open.execute(&mut state).await?;
+
+    let metadata = state.position_metadata(&id).await?.unwrap();
+    assert_eq!(metadata.creation_height, 0);
+    assert_eq!(metadata.trading_pair, open.position.phi.pair);
+    assert_eq!(metadata.strategy_tag, "market-maker/v1");
+    assert_eq!(metadata.rewritten_from, None);
+
+    Ok(())
+}
+
+#[tokio::test]
+/// Builds a batch which closes, withdraws from, and opens positions, and
+/// checks that executing it has the same effects as the individual actions.
+async fn position_update_batch_refreshes_quotes() -> anyhow::Result<()> {
+    use cnidarium_component::ActionHandler as _;
+
+    use crate::lp::{plan::PositionUpdateBatchPlan, plan::PositionWithdrawPlan};
+
+    let storage = TempStorage::new().await?.apply_minimal_genesis().await?;
+    let mut state = StateDelta::new(storage.latest_snapshot());
+
+    let gm = asset::Cache::with_known_assets().get_unit("gm").unwrap();
+    let gn = asset::Cache::with_known_assets().get_unit("gn").unwrap();
+    let pair = DirectedTradingPair::new(gm.id(), gn.id());
+    let quote = |r1: u64, r2: u64| {
+        Position::new(
+            OsRng,
+            pair,
+            100u32,
+            1u64.into(),
+            1u64.into(),
+            Reserves {
+                r1: r1.into(),
+                r2: r2.into(),
+            },
+        )
+    };
+
+    // One stale quote has already been closed, and another is still open.
+    let closed = quote(1_000, 1_000);
+    let open = quote(2_000, 2_000);
+    state.put_position(closed.clone()).await?;
+    state.close_position_by_id(&closed.id()).await?;
+    state.put_position(open.clone()).await?;
+
+    let fresh = quote(3_000, 3_000);
+    let mut plan = PositionUpdateBatchPlan {
+        closes: vec![open.id()],
+        withdrawals: vec![PositionWithdrawPlan {
+            reserves: closed.reserves.clone(),
+            position_id: closed.id(),
+            pair: closed.phi.pair,
+            sequence: 0,
+            rewards: Vec::new(),
+        }],
+        opens: vec![fresh.clone()],
+    };
+    let batch = plan.position_update_batch();
+    batch.check_stateless(()).await?;
+
+    // A batch whose withdrawn reserves don't match the chain state is rejected.
+    plan.withdrawals[0].reserves.r1 = 999u64.into();
+    assert!(plan
+        .position_update_batch()
+        .execute(&mut state)
+        .await
+        .is_err());
+
+    batch.execute(&mut state).await?;
+
+    let withdrawn = state.position_by_id(&closed.id()).await?.unwrap();
+    assert_eq!(withdrawn.state, position::State::Withdrawn { sequence: 0 });
+    assert_eq!(withdrawn.reserves.r1, Amount::zero());
+    assert_eq!(withdrawn.reserves.r2, Amount::zero());
+    assert_eq!(
+        state.position_by_id(&fresh.id()).await?.unwrap().state,
+        position::State::Opened
+    );
+    // Closing is deferred to the end of the block.
+    assert!(state.pending_position_closures().contains(&open.id()));
+
+    // Each position can only appear once in a batch.
+    let mut duplicated = batch.clone();
+    duplicated.closes.push(open.id());
+    assert!(duplicated.check_stateless(()).await.is_err());
+
+    Ok(())
+}
+
+#[tokio::test]
+/// Opens a ladder of positions in a single batch, and checks that the batch
+/// is only applied if every position in it can be opened.
+async fn position_open_batch_deploys_ladder() -> anyhow::Result<()> {
+    use cnidarium_component::ActionHandler as _;
+    use penumbra_num::fixpoint::U128x128;
+
+    use crate::{
+        lp::{action::PositionOpenBatch, Ladder},
+        DirectedUnitPair,
+    };
+
+    let storage = TempStorage::new().await?.apply_minimal_genesis().await?;
+    let mut state = StateDelta::new(storage.latest_snapshot());
+
+    let gm = asset::Cache::with_known_assets().get_unit("gm").unwrap();
+    let gn = asset::Cache::with_known_assets().get_unit("gn").unwrap();
+    let ladder = Ladder {
+        pair: DirectedUnitPair::new(gm.clone(), gn.clone()),
+        lower_price: U128x128::from(1u64),
+        upper_price: U128x128::from(3u64),
+        rungs: 5,
+        fee: 30,
+    };
+    let positions = ladder.positions(
+        OsRng,
+        U128x128::from(2u64),
+        3_000u64.into(),
+        2_000u64.into(),
+    )?;
+    let batch = PositionOpenBatch {
+        positions,
+        strategy_tag: "ladder/v1".to_string(),
+    };
+    batch.check_stateless(()).await?;
+
+    // The batch consumes the combined reserves of its positions: the three
+    // rungs at or above the current price sell gm, and the two below buy it.
+    let mut required = batch.balance().required().collect::<Vec<_>>();
+    required.sort_by_key(|value| value.amount);
+    assert_eq!(
+        required,
+        vec![
+            Value {
+                amount: 2_000u64.into(),
+                asset_id: gn.id(),
+            },
+            Value {
+                amount: 3_000u64.into(),
+                asset_id: gm.id(),
+            },
+        ]
+    );
+
+    // A position can't be opened twice, so a batch colliding with an open
+    // position opens none of its positions.
+    let existing = batch.positions[3].clone();
+    state.put_position(existing.clone()).await?;
+    assert!(batch.execute(&mut state).await.is_err());
+    assert!(state
+        .position_by_id(&batch.positions[0].id())
+        .await?
+        .is_none());
+
+    let mut state = StateDelta::new(storage.latest_snapshot());
+    batch.execute(&mut state).await?;
+    for position in &batch.positions {
+        let id = position.id();
+        assert_eq!(
+            state.position_by_id(&id).await?.unwrap().state,
+            position::State::Opened
+        );
+        assert_eq!(
+            state.position_metadata(&id).await?.unwrap().strategy_tag,
+            "ladder/v1"
+        );
+    }
+
+    // Batches are bounded, and may not open the same position twice.
+    let mut duplicated = batch.clone();
+    duplicated.positions.push(existing);
+    assert!(duplicated.check_stateless(()).await.is_err());
+    let oversized = PositionOpenBatch {
+        positions: vec![batch.positions[0].clone(); PositionOpenBatch::MAX_POSITIONS + 1],
+        strategy_tag: String::new(),
+    };
+    assert!(oversized.check_stateless(()).await.is_err());
+
+    Ok(())
+}
+
+#[tokio::test]
+/// Tests that the protocol's share of trading fees is skimmed from the position's
+/// reserves into the community pool, and that fee tiers restrict new positions.
+async fn protocol_fee_share() -> anyhow::Result<()> {
+    use cnidarium_component::ActionHandler as _;
+    use penumbra_community_pool::StateReadExt as _;
+
+    use crate::DexParameters;
+
+    let storage = TempStorage::new().await?.apply_minimal_genesis().await?;
+    let mut state = Arc::new(StateDelta::new(storage.latest_snapshot()));
+    let mut state_tx = state.try_begin_transaction().unwrap();
+
+    state_tx.put_dex_params(DexParameters {
+        fee_tiers: vec![30, 100],
+        protocol_fee_share_bps: 5_000,
+        ..Default::default()
+    });
+
+    let gm = asset::Cache::with_known_assets().get_unit("gm").unwrap();
+    let gn = asset::Cache::with_known_assets().get_unit("gn").unwrap();
+    let pair = DirectedTradingPair::new(gm.id(), gn.id());
+
+    // A position charging 1% to sell 1_000_000gn for gm at par.
+    let position = Position::new(
+        OsRng,
+        pair,
+        100u32,
+        1u64.into(),
+        1u64.into(),
+        Reserves {
+            r1: 0u64.into(),
+            r2: 1_000_000u64.into(),
+        },
+    );
+    let position_id = position.id();
+    state_tx.put_position(position).await?;
+
+    let input = Value {
+        amount: 10_000u64.into(),
+        asset_id: gm.id(),
+    };
+    let execution = FillRoute::fill_route(&mut state_tx, input, &[gn.id()], None).await?;
+
+    // The trader's execution is unaffected by the protocol fee...
+    assert_eq!(execution.input, input);
+    assert_eq!(execution.output.amount, 9_900u64.into());
+
+    // ... but half of the 100gm fee goes to the community pool instead of the position.
+    let position = state_tx.position_by_id(&position_id).await?.unwrap();
+    assert_eq!(position.reserves_for(gm.id()).unwrap(), 9_950u64.into());
+    assert_eq!(position.reserves_for(gn.id()).unwrap(), 990_100u64.into());
+    assert_eq!(
+        state_tx.community_pool_asset_balance(gm.id()).await?,
+        50u64.into()
+    );
+
+    // Positions must use one of the allowed fee tiers.
+    state_tx.apply();
+    let open = |fee| PositionOpen {
+        position: Position::new(
+            OsRng,
+            pair,
+            fee,
+            1u64.into(),
+            1u64.into(),
+            Reserves {
+                r1: 0u64.into(),
+                r2: 1_000u64.into(),
+            },
+        ),
+        strategy_tag: String::new(),
+    };
+    assert!(open(30).check_stateful(state.clone()).await.is_ok());
+    assert!(open(50).check_stateful(state.clone()).await.is_err());
+
+    Ok(())
+}
+
+#[tokio::test]
+/// Governance can pause trading on a pair, rejecting new positions on it.
+async fn paused_trading_pair_rejects_opens() -> anyhow::Result<()> {
+    use cnidarium_component::ActionHandler as _;
+
+    use crate::DexParameters;
+
+    let storage = TempStorage::new().await?.apply_minimal_genesis().await?;
+    let mut state = Arc::new(StateDelta::new(storage.latest_snapshot()));
+
+    let gm = asset::Cache::with_known_assets().get_unit("gm").unwrap();
+    let gn = asset::Cache::with_known_assets().get_unit("gn").unwrap();
+    let penumbra = asset::Cache::with_known_assets()
+        .get_unit("penumbra")
+        .unwrap();
+    let paused = DirectedTradingPair::new(gm.id(), gn.id());
+    let unpaused = DirectedTradingPair::new(gm.id(), penumbra.id());
+
+    let mut state_tx = state.try_begin_transaction().unwrap();
+    state_tx.put_dex_params(DexParameters {
+        paused_trading_pairs: vec![paused.into()],
+        ..Default::default()
+    });
+    state_tx.apply();
+
+    let open = |pair| PositionOpen {
+        position: Position::new(
+            OsRng,
+            pair,
+            30u32,
+            1u64.into(),
+            1u64.into(),
+            Reserves {
+                r1: 0u64.into(),
+                r2: 1_000u64.into(),
+            },
+        ),
+        strategy_tag: String::new(),
+    };
+    assert!(open(paused).check_stateful(state.clone()).await.is_err());
+    assert!(open(paused.flip())
+        .check_stateful(state.clone())
+        .await
+        .is_err());
+    assert!(open(unpaused).check_stateful(state.clone()).await.is_ok());
+
+    Ok(())
+}
+
+#[tokio::test]
+/// Rewrites an open position, and checks that the new position replaces it
+/// immediately, with the history of the two positions linked.
+async fn position_rewrite_replaces_quote() -> anyhow::Result<()> {
+    use cnidarium_component::ActionHandler as _;
+
+    use crate::lp::plan::PositionRewritePlan;
+
+    let storage = TempStorage::new().await?.apply_minimal_genesis().await?;
+    let mut state = StateDelta::new(storage.latest_snapshot());
+
+    let gm = asset::Cache::with_known_assets().get_unit("gm").unwrap();
+    let gn = asset::Cache::with_known_assets().get_unit("gn").unwrap();
+    let pair = DirectedTradingPair::new(gm.id(), gn.id());
+    let quote = |r1: u64, r2: u64| {
+        Position::new(
+            OsRng,
+            pair,
+            100u32,
+            1u64.into(),
+            1u64.into(),
+            Reserves {
+                r1: r1.into(),
+                r2: r2.into(),
+            },
+        )
+    };
+
+    let stale = quote(1_000, 1_000);
+    state.put_position(stale.clone()).await?;
+
+    let fresh = quote(2_000, 500);
+    let mut plan = PositionRewritePlan {
+        position_id: stale.id(),
+        pair: stale.phi.pair,
+        reserves: stale.reserves.clone(),
+        new_position: fresh.clone(),
+    };
+    let rewrite = plan.position_rewrite();
+    rewrite.check_stateless(()).await?;
+
+    // A rewrite whose final reserves don't match the chain state is rejected.
+    plan.reserves.r1 = 999u64.into();
+    assert!(plan.position_rewrite().execute(&mut state).await.is_err());
+
+    rewrite.execute(&mut state).await?;
+
+    // The stale position is withdrawn immediately, rather than at the end of the block.
+    let withdrawn = state.position_by_id(&stale.id()).await?.unwrap();
+    assert_eq!(withdrawn.state, position::State::Withdrawn { sequence: 0 });
+    assert_eq!(withdrawn.reserves.r1, Amount::zero());
+    assert_eq!(withdrawn.reserves.r2, Amount::zero());
+    assert!(state.pending_position_closures().is_empty());
+    assert_eq!(
+        state.position_by_id(&fresh.id()).await?.unwrap().state,
+        position::State::Opened
+    );
+
+    // The history of the stale position continues into the fresh one.
+    let history = state.pending_position_history();
+    let stale_entry = history.get(&stale.id()).unwrap();
+    assert_eq!(stale_entry.rewritten_to, Some(fresh.id()));
+    assert_eq!(stale_entry.fills, 0);
+    assert_eq!(
+        history.get(&fresh.id()).unwrap().rewritten_from,
+        Some(stale.id())
+    );
+
+    // The stale position can't be rewritten again, now that it's withdrawn.
+    let again = PositionRewritePlan {
+        position_id: stale.id(),
+        pair: stale.phi.pair,
+        reserves: Reserves::zero(),
+        new_position: quote(3_000, 3_000),
+    };
+    assert!(again.position_rewrite().execute(&mut state).await.is_err());
+
+    Ok(())
+}
+
+#[tokio::test]
+/// Fills are traced position by position, and old traces are pruned.
+async fn swap_execution_traces() -> anyhow::Result<()> {
+    use crate::{DexParameters, SwapExecutionTrace};
+
+    let storage = TempStorage::new().await?.apply_minimal_genesis().await?;
+    let mut state = Arc::new(StateDelta::new(storage.latest_snapshot()));
+    let mut state_tx = state.try_begin_transaction().unwrap();
+
+    state_tx.put_dex_params(DexParameters {
+        execution_trace_retention_blocks: 2,
+        ..Default::default()
+    });
+
+    let gm = asset::Cache::with_known_assets().get_unit("gm").unwrap();
+    let gn = asset::Cache::with_known_assets().get_unit("gn").unwrap();
+    let pair = DirectedTradingPair::new(gm.id(), gn.id());
+
+    // A fee-less position selling 1_000_000gn for gm at par.
+    let position = Position::new(
+        OsRng,
+        pair,
+        0u32,
+        1u64.into(),
+        1u64.into(),
+        Reserves {
+            r1: 0u64.into(),
+            r2: 1_000_000u64.into(),
+        },
+    );
+    let position_id = position.id();
+    state_tx.put_position(position).await?;
+
+    let input = Value {
+        amount: 10_000u64.into(),
+        asset_id: gm.id(),
+    };
+    FillRoute::fill_route(&mut state_tx, input, &[gn.id()], None).await?;
+
+    let fills = state_tx.take_route_fills();
+    assert_eq!(fills.len(), 1);
+    assert_eq!(fills[0].input(), input);
+    let hops: Vec<_> = fills[0].hops().collect();
+    assert_eq!(hops.len(), 1);
+    assert_eq!(hops[0].position_id, position_id);
+    assert_eq!(hops[0].input, input);
+    assert_eq!(
+        hops[0].output,
+        Value {
+            amount: 10_000u64.into(),
+            asset_id: gn.id(),
+        }
+    );
+    assert!(state_tx.take_route_fills().is_empty());
+
+    // Traces are kept for the retention window, then pruned.
+    for height in 1..=3 {
+        let trace = SwapExecutionTrace {
+            fills: fills.clone(),
+        };
+        state_tx.put_swap_execution_trace(height, pair, trace);
+    }
+    state_tx.prune_swap_execution_traces(3).await?;
+    assert!(state_tx.swap_execution_trace(1, pair).await?.is_none());
+    assert!(state_tx.swap_execution_trace(2, pair).await?.is_some());
+    assert!(state_tx.swap_execution_trace(3, pair).await?.is_some());
+
+    Ok(())
+}
+
+#[tokio::test]
+/// When the execution circuit breaker halts the routing of a batch swap, the trip is recorded in
+/// the verifiable state.
+async fn execution_circuit_breaker_trips_are_recorded() -> anyhow::Result<()> {
+    use cnidarium::StateRead as _;
+
+    use crate::{state_key, CircuitBreakerStatus};
+
+    let storage = TempStorage::new().await?.apply_minimal_genesis().await?;
+    let mut state = Arc::new(StateDelta::new(storage.latest_snapshot()));
+    let mut state_tx = state.try_begin_transaction().unwrap();
+
+    let penumbra = asset::Cache::with_known_assets()
+        .get_unit("penumbra")
+        .unwrap();
+    let gn = asset::Cache::with_known_assets().get_unit("gn").unwrap();
+    let pair_gn_penumbra = DirectedUnitPair::new(gn.clone(), penumbra.clone());
+
+    // Positions buying 1gn each at distinct prices, so that each execution fills a single one,
+    // and filling all of them would take more executions than the circuit breaker allows.
+    let max_executions = CircuitBreakerStatus::new(None).max_executions;
+    for price in 1..=u64::from(max_executions) + 10 {
+        state_tx
+            .put_position(limit_buy(
+                pair_gn_penumbra.clone(),
+                1u64.into(),
+                price.into(),
+            ))
+            .await?;
+    }
+    state_tx.apply();
+    assert!(state.execution_circuit_breaker_trip().await?.is_none());
+
+    let trading_pair = pair_gn_penumbra.into_directed_trading_pair().into();
+    let mut swap_flow = state.swap_flow(&trading_pair);
+    assert!(trading_pair.asset_1() == penumbra.id());
+    swap_flow.1 += gn.value(u64::from(max_executions + 10).into()).amount;
+    Arc::get_mut(&mut state)
+        .unwrap()
+        .put_swap_flow(&trading_pair, swap_flow.clone());
+    state
+        .handle_batch_swaps(trading_pair, swap_flow, 0, 0, RoutingParams::default())
+        .await
+        .expect("unable to process batch swaps");
+
+    let trip = state
+        .execution_circuit_breaker_trip()
+        .await?
+        .expect("the circuit breaker was tripped");
+    assert_eq!(trip.height, 0);
+    assert_eq!(
+        trip.trading_pair,
+        DirectedTradingPair::new(gn.id(), penumbra.id())
+    );
+    assert_eq!(trip.executions, max_executions + 1);
+    assert!(state
+        .get_raw(state_key::circuit_breaker_last_trip())
+        .await?
+        .is_some());
+
+    Ok(())
+}
+
+#[tokio::test]
+/// Arb executions are streamed in order of height, within the requested range.
+async fn arb_executions_are_filtered_by_height() -> anyhow::Result<()> {
+    use futures::TryStreamExt as _;
+
+    let storage = TempStorage::new().await?.apply_minimal_genesis().await?;
+    let mut state = StateDelta::new(storage.latest_snapshot());
+
+    let penumbra = asset::Cache::with_known_assets()
+        .get_unit("penumbra")
+        .unwrap();
+    // Enough heights that ordering by key would differ from ordering by height
+    // if the heights weren't zero-padded.
+    for height in [2u64, 9, 10, 11, 100] {
+        state.set_arb_execution(
+            height,
+            SwapExecution {
+                traces: vec![],
+                input: penumbra.value(height.into()),
+                output: penumbra.value(1u64.into()),
+            },
+        );
+    }
+
+    let heights = |start, end| {
+        state
+            .arb_executions(start, end)
+            .map_ok(|(height, execution)| {
+                assert_eq!(execution.input.amount, height.into());
+                height
+            })
+            .try_collect::<Vec<u64>>()
+    };
+    assert_eq!(heights(0, None).await?, vec![2, 9, 10, 11, 100]);
+    assert_eq!(heights(9, Some(11)).await?, vec![9, 10, 11]);
+    assert_eq!(heights(10, Some(99)).await?, vec![10, 11]);
+    assert_eq!(heights(101, None).await?, Vec::<u64>::new());
+
+    Ok(())
+}
+
+#[tokio::test]
+/// A Dutch auction sells against the liquidity on the DEX once its price falls
+/// to that of the liquidity, and neither creates nor destroys value while it runs.
+async fn dutch_auction_execution_conserves_value() -> anyhow::Result<()> {
+    use crate::{
+        auction::dutch::DutchAuctionDescription,
+        component::{DutchAuctionManager, DutchAuctionRead},
+        DexParameters,
+    };
+
+    let storage = TempStorage::new().await?.apply_minimal_genesis().await?;
+    let mut state = Arc::new(StateDelta::new(storage.latest_snapshot()));
+    let mut state_tx = state.try_begin_transaction().unwrap();
+    state_tx.put_dex_params(DexParameters::default());
+
+    let gm = asset::Cache::with_known_assets().get_unit("gm").unwrap();
+    let gn = asset::Cache::with_known_assets().get_unit("gn").unwrap();
+
+    // A position buying up to 40gm at 1.5gn each.
+    let bid = Position::new(
+        OsRng,
+        DirectedTradingPair::new(gm.id(), gn.id()),
+        0u32,
+        3u64.into(),
+        2u64.into(),
+        Reserves {
+            r1: 0u64.into(),
+            r2: 60u64.into(),
+        },
+    );
+    let bid_id = bid.id();
+    state_tx.put_position(bid).await?;
+
+    // An auction selling 100gm, asking 2gn each at height 1, down to 1gn each at height 11.
+    let description = DutchAuctionDescription::new(
+        &mut OsRng,
+        Value {
+            amount: 100u64.into(),
+            asset_id: gm.id(),
+        },
+        gn.id(),
+        200u64.into(),
+        100u64.into(),
+        1,
+        11,
+    );
+    let id = description.id();
+    state_tx.schedule_dutch_auction(description).await?;
+    state_tx.apply();
+
+    for height in 1..=11 {
+        state.execute_dutch_auctions(height).await?;
+
+        let auction = state.dutch_auction_by_id(&id).await?.unwrap();
+        let bid = state.position_by_id(&bid_id).await?.unwrap();
+        // The auction's reserves include those deposited in its position, so
+        // together with the bid they always add up to what was put in.
+        assert_eq!(
+            auction.state.input_reserves + bid.reserves_for(gm.id()).unwrap(),
+            100u64.into()
+        );
+        assert_eq!(
+            auction.state.output_reserves + bid.reserves_for(gn.id()).unwrap(),
+            60u64.into()
+        );
+        // The bid is only taken once the auction's price has fallen to it, at
+        // height 6.
+        if height < 6 {
+            assert_eq!(auction.state.input_reserves, 100u64.into());
+        } else if height > 6 {
+            assert_eq!(auction.state.input_reserves, 60u64.into());
+            assert_eq!(auction.state.output_reserves, 60u64.into());
+        }
+    }
+
+    // The auction stops trading at its end height, withdrawing its position.
+    let auction = state.dutch_auction_by_id(&id).await?.unwrap();
+    assert!(auction.state.current_position.is_none());
+    assert!(state.active_dutch_auctions().await?.is_empty());
+
+    Ok(())
+}
+
+#[tokio::test]
+/// Only a bounded number of Dutch auctions may be scheduled or running at once.
+async fn dutch_auctions_are_capped() -> anyhow::Result<()> {
+    use crate::{
+        auction::dutch::{DutchAuctionDescription, MAX_ACTIVE_AUCTIONS},
+        component::DutchAuctionManager,
+    };
+
+    let storage = TempStorage::new().await?.apply_minimal_genesis().await?;
+    let mut state = StateDelta::new(storage.latest_snapshot());
+
+    let gm = asset::Cache::with_known_assets().get_unit("gm").unwrap();
+    let gn = asset::Cache::with_known_assets().get_unit("gn").unwrap();
+    let description = || {
+        DutchAuctionDescription::new(
+            &mut OsRng,
+            gm.value(1u64.into()),
+            gn.id(),
+            2u64.into(),
+            1u64.into(),
+            1,
+            11,
+        )
+    };
+
+    for _ in 0..MAX_ACTIVE_AUCTIONS {
+        state.schedule_dutch_auction(description()).await?;
+    }
+    assert!(state.schedule_dutch_auction(description()).await.is_err());
+
+    Ok(())
+}
+
+#[tokio::test]
+/// Each step of a Dutch auction reclaims what its previous position sold, as
+/// does ending the auction.
+async fn dutch_auction_positions_are_reclaimed() -> anyhow::Result<()> {
+    use crate::{
+        auction::dutch::DutchAuctionDescription,
+        component::{DutchAuctionManager, DutchAuctionRead},
+        DexParameters,
+    };
+
+    let storage = TempStorage::new().await?.apply_minimal_genesis().await?;
+    let mut state = Arc::new(StateDelta::new(storage.latest_snapshot()));
+    let mut state_tx = state.try_begin_transaction().unwrap();
+    state_tx.put_dex_params(DexParameters::default());
+
+    let gm = asset::Cache::with_known_assets().get_unit("gm").unwrap();
+    let gn = asset::Cache::with_known_assets().get_unit("gn").unwrap();
+
+    // An auction selling 100gm, asking 2gn each at height 1, down to 1gn each
+    // at height 11, only through its positions.
+    let mut description = DutchAuctionDescription::new(
+        &mut OsRng,
+        Value {
+            amount: 100u64.into(),
+            asset_id: gm.id(),
+        },
+        gn.id(),
+        200u64.into(),
+        100u64.into(),
+        1,
+        11,
+    );
+    description.fill_against_dex = false;
+    let id = description.id();
+    state_tx.schedule_dutch_auction(description).await?;
+    state_tx.apply();
+
+    state.execute_dutch_auctions(1).await?;
+    let auction = state.dutch_auction_by_id(&id).await?.unwrap();
+    let first_position = auction.state.current_position.unwrap();
+
+    // A trader buys 30gm from the auction's position, at 2gn each.
+    let mut state_tx = state.try_begin_transaction().unwrap();
+    let execution = FillRoute::fill_route(
+        &mut state_tx,
+        Value {
+            amount: 60u64.into(),
+            asset_id: gn.id(),
+        },
+        &[gm.id()],
+        None,
+    )
+    .await?;
+    assert_eq!(execution.output.amount, 30u64.into());
+    state_tx.apply();
+
+    // The next step reclaims the position's reserves, and offers the rest of
+    // the input in a new position.
+    state.execute_dutch_auctions(2).await?;
+    let auction = state.dutch_auction_by_id(&id).await?.unwrap();
+    assert_eq!(auction.state.input_reserves, 70u64.into());
+    assert_eq!(auction.state.output_reserves, 60u64.into());
+    let reclaimed = state.position_by_id(&first_position).await?.unwrap();
+    assert_eq!(reclaimed.state, position::State::Withdrawn { sequence: 0 });
+    assert_eq!(reclaimed.reserves.r1, Amount::zero());
+    assert_eq!(reclaimed.reserves.r2, Amount::zero());
+    let second_position = auction.state.current_position.unwrap();
+    assert_ne!(second_position, first_position);
+    let offered = state.position_by_id(&second_position).await?.unwrap();
+    assert_eq!(offered.reserves_for(gm.id()).unwrap(), 70u64.into());
+
+    // Ending the auction reclaims its current position too.
+    let state_mut = Arc::get_mut(&mut state).unwrap();
+    state_mut.end_dutch_auction(&id).await?;
+    let auction = state.dutch_auction_by_id(&id).await?.unwrap();
+    assert_eq!(auction.state.seq, 1);
+    assert!(auction.state.current_position.is_none());
+    assert_eq!(auction.state.input_reserves, 70u64.into());
+    assert_eq!(auction.state.output_reserves, 60u64.into());
+    let reclaimed = state.position_by_id(&second_position).await?.unwrap();
+    assert_eq!(reclaimed.state, position::State::Withdrawn { sequence: 0 });
+    assert!(state.active_dutch_auctions().await?.is_empty());
+
+    Ok(())
+}
+
+#[tokio::test]
+/// Opening a position with the ID of an auction's next position skips that
+/// step of the auction, rather than failing to execute the auctions.
+async fn dutch_auction_position_id_collisions_skip_the_step() -> anyhow::Result<()> {
+    use crate::{
+        auction::dutch::{DutchAuction, DutchAuctionDescription},
+        component::{DutchAuctionManager, DutchAuctionRead},
+        DexParameters,
+    };
+
+    let storage = TempStorage::new().await?.apply_minimal_genesis().await?;
+    let mut state = Arc::new(StateDelta::new(storage.latest_snapshot()));
+    let mut state_tx = state.try_begin_transaction().unwrap();
+    state_tx.put_dex_params(DexParameters::default());
+
+    let gm = asset::Cache::with_known_assets().get_unit("gm").unwrap();
+    let gn = asset::Cache::with_known_assets().get_unit("gn").unwrap();
+
+    let mut description = DutchAuctionDescription::new(
+        &mut OsRng,
+        Value {
+            amount: 100u64.into(),
+            asset_id: gm.id(),
+        },
+        gn.id(),
+        200u64.into(),
+        100u64.into(),
+        1,
+        11,
+    );
+    description.fill_against_dex = false;
+    let id = description.id();
+
+    // Someone opens the position the auction would open at height 1.
+    let squatter = DutchAuction::new(description.clone()).position_at_height(1);
+    let squatter_id = squatter.id();
+    state_tx.put_position(squatter).await?;
+    state_tx.schedule_dutch_auction(description).await?;
+    state_tx.apply();
+
+    // The auction holds on to its reserves at height 1...
+    state.execute_dutch_auctions(1).await?;
+    let auction = state.dutch_auction_by_id(&id).await?.unwrap();
+    assert!(auction.state.current_position.is_none());
+    assert_eq!(auction.state.input_reserves, 100u64.into());
+    assert_eq!(
+        state.position_by_id(&squatter_id).await?.unwrap().state,
+        position::State::Opened
+    );
+
+    // ...and offers them in a new position at the next step.
+    state.execute_dutch_auctions(2).await?;
+    let auction = state.dutch_auction_by_id(&id).await?.unwrap();
+    let position = auction.state.current_position.unwrap();
+    assert_ne!(position, squatter_id);
+    let offered = state.position_by_id(&position).await?.unwrap();
+    assert_eq!(offered.reserves_for(gm.id()).unwrap(), 100u64.into());
+
+    Ok(())
+}
+
+#[tokio::test]
+/// Chains which predate the dex parameters read the defaults, rather than failing.
+async fn dex_params_default_on_chains_without_them() -> anyhow::Result<()> {
+    use crate::DexParameters;
+
+    let storage = TempStorage::new().await?;
+    let mut state = StateDelta::new(storage.latest_snapshot());
+    assert_eq!(state.get_dex_params().await?, DexParameters::default());
+
+    let params = DexParameters {
+        fee_tiers: vec![30, 100],
+        protocol_fee_share_bps: 1_000,
+        ..Default::default()
+    };
+    state.put_dex_params(params.clone());
+    assert_eq!(state.get_dex_params().await?, params);
+    Ok(())
+}
+
+#[tokio::test]
+/// The staking component can bar the delegation tokens of unbonding validators from the DEX, so
+/// they can't be provided as liquidity or auctioned, though they can still be bought.
+async fn at_risk_delegation_tokens_are_barred() -> anyhow::Result<()> {
+    use cnidarium_component::ActionHandler as _;
+    use decaf377_rdsa::{SigningKey, SpendAuth};
+    use penumbra_shielded_pool::component::SupplyWrite as _;
+    use penumbra_stake::{
+        component::{validator_handler::ValidatorDataWrite as _, StateWriteExt as _},
+        params::StakeParameters,
+        validator::BondingState,
+        DelegationToken, IdentityKey,
+    };
+
+    use crate::{
+        auction::{action::ActionDutchAuctionSchedule, dutch::DutchAuctionDescription},
+        DexParameters,
+    };
+
+    let storage = TempStorage::new().await?.apply_minimal_genesis().await?;
+    let mut state = StateDelta::new(storage.latest_snapshot());
+    state.put_dex_params(DexParameters::default());
+    state.put_stake_params(StakeParameters {
+        restrict_at_risk_delegation_tokens: true,
+        ..Default::default()
+    });
+
+    let identity_key = IdentityKey((&SigningKey::<SpendAuth>::new(OsRng)).into());
+    let delegation_token = DelegationToken::from(identity_key);
+    state.register_denom(&delegation_token.denom()).await?;
+    state.set_validator_bonding_state(
+        &identity_key,
+        BondingState::Unbonding {
+            unbonds_at_epoch: 1,
+        },
+    );
+
+    let gn = asset::Cache::with_known_assets().get_unit("gn").unwrap();
+    let pair = DirectedTradingPair::new(delegation_token.id(), gn.id());
+    let open = |r1: u64, r2: u64| PositionOpen {
+        position: Position::new(
+            OsRng,
+            pair,
+            30u32,
+            1u64.into(),
+            1u64.into(),
+            Reserves {
+                r1: r1.into(),
+                r2: r2.into(),
+            },
+        ),
+        strategy_tag: String::new(),
+    };
+
+    assert!(open(1_000, 0).execute(&mut state).await.is_err());
+    open(0, 1_000).execute(&mut state).await?;
+
+    let schedule = ActionDutchAuctionSchedule {
+        description: DutchAuctionDescription::new(
+            &mut OsRng,
+            Value {
+                amount: 1_000u64.into(),
+                asset_id: delegation_token.id(),
+            },
+            gn.id(),
+            2_000u64.into(),
+            1_000u64.into(),
+            1,
+            11,
+        ),
+    };
+    assert!(schedule.execute(&mut state).await.is_err());
+
+    // Once the validator is bonded again, its delegation tokens are no longer at risk.
+    state.set_validator_bonding_state(&identity_key, BondingState::Bonded);
+    open(1_000, 0).execute(&mut state).await?;
+
+    Ok(())
+}
@@ -1,13 +1,18 @@
 use crate::{
+    auction::{
+        dutch::{DutchAuctionDescription, DutchAuctionState},
+        AuctionId,
+    },
     lp::{
         action::{PositionClose, PositionOpen, PositionWithdraw},
         position::{self, Position},
     },
     swap::Swap,
     swap_claim::SwapClaim,
-    BatchSwapOutputData, SwapExecution,
+    BatchSwapOutputData, SwapExecution, TradingPair,
 };
 
+use penumbra_num::Amount;
 use penumbra_proto::penumbra::core::component::dex::v1 as pb;
 
 pub fn swap(swap: &Swap) -> pb::EventSwap {
@@ -46,6 +51,28 @@ pub fn position_close(action: &PositionClose) -> pb::EventPositionClose {
     }
 }
 
+pub fn protocol_fee_accrual(
+    position_id: position::Id,
+    fee: penumbra_asset::Value,
+) -> pb::EventProtocolFeeAccrual {
+    pb::EventProtocolFeeAccrual {
+        position_id: Some(position_id.into()),
+        fee: Some(fee.into()),
+    }
+}
+
+pub fn trading_pair_paused(trading_pair: TradingPair) -> pb::EventTradingPairPaused {
+    pb::EventTradingPairPaused {
+        trading_pair: Some(trading_pair.into()),
+    }
+}
+
+pub fn trading_pair_resumed(trading_pair: TradingPair) -> pb::EventTradingPairResumed {
+    pb::EventTradingPairResumed {
+        trading_pair: Some(trading_pair.into()),
+    }
+}
+
 pub fn position_withdraw(
     position_withdraw: &PositionWithdraw,
     final_position_state: &Position,
@@ -91,3 +118,51 @@ pub fn arb_execution(height: u64, swap_execution: SwapExecution) -> pb::EventArb
         swap_execution: Some(swap_execution.into()),
     }
 }
+
+pub fn dutch_auction_schedule(
+    auction_id: AuctionId,
+    description: &DutchAuctionDescription,
+) -> pb::EventDutchAuctionScheduled {
+    pb::EventDutchAuctionScheduled {
+        auction_id: Some(auction_id.into()),
+        description: Some(description.clone().into()),
+    }
+}
+
+pub fn dutch_auction_execution(
+    auction_id: AuctionId,
+    height: u64,
+    swap_execution: Option<SwapExecution>,
+    position_input_sold: Amount,
+    position_output_received: Amount,
+    state: &DutchAuctionState,
+) -> pb::EventDutchAuctionExecution {
+    pb::EventDutchAuctionExecution {
+        auction_id: Some(auction_id.into()),
+        height,
+        swap_execution: swap_execution.map(Into::into),
+        state: Some(state.clone().into()),
+        position_input_sold: Some(position_input_sold.into()),
+        position_output_received: Some(position_output_received.into()),
+    }
+}
+
+pub fn dutch_auction_ended(
+    auction_id: AuctionId,
+    state: &DutchAuctionState,
+) -> pb::EventDutchAuctionEnded {
+    pb::EventDutchAuctionEnded {
+        auction_id: Some(auction_id.into()),
+        state: Some(state.clone().into()),
+    }
+}
+
+pub fn dutch_auction_withdrawn(
+    auction_id: AuctionId,
+    state: &DutchAuctionState,
+) -> pb::EventDutchAuctionWithdrawn {
+    pb::EventDutchAuctionWithdrawn {
+        auction_id: Some(auction_id.into()),
+        state: Some(state.clone().into()),
+    }
+}
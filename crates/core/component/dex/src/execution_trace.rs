@@ -0,0 +1,155 @@
+use anyhow::{ensure, Result};
+use penumbra_asset::{asset, Value};
+use penumbra_num::Amount;
+use penumbra_proto::{penumbra::core::component::dex::v1 as pb, DomainType};
+use serde::{Deserialize, Serialize};
+
+use crate::lp::position;
+
+/// A compact record of how a batch swap was executed in one direction of a
+/// trading pair, position by position.
+///
+/// Unlike a [`SwapExecution`](crate::SwapExecution), which only records the
+/// amounts along each route, a trace also records which positions were
+/// filled against. Traces are kept in nonverifiable storage, and pruned after
+/// the number of blocks set by the
+/// [`execution_trace_retention_blocks`](crate::DexParameters::execution_trace_retention_blocks)
+/// parameter.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(try_from = "pb::SwapExecutionTrace", into = "pb::SwapExecutionTrace")]
+pub struct SwapExecutionTrace {
+    /// The fills along each route used by the execution, in execution order.
+    pub fills: Vec<RouteFill>,
+}
+
+/// A single fill along a route, against one liquidity position at each hop.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(try_from = "pb::RouteFill", into = "pb::RouteFill")]
+pub struct RouteFill {
+    /// The assets along the route, from the input asset to the output asset.
+    pub route: Vec<asset::Id>,
+    /// The amount of each asset along the route: the first is the input of
+    /// the fill, and each other is the output of the hop ending at that asset.
+    pub amounts: Vec<Amount>,
+    /// The position filled against at each hop of the route.
+    pub positions: Vec<position::Id>,
+}
+
+/// A fill against a single position, at one hop of a [`RouteFill`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TraceHop {
+    /// The position filled against.
+    pub position_id: position::Id,
+    /// The value traded into the position.
+    pub input: Value,
+    /// The value traded out of the position.
+    pub output: Value,
+}
+
+impl RouteFill {
+    /// The input of the fill.
+    pub fn input(&self) -> Value {
+        Value {
+            amount: self.amounts[0],
+            asset_id: self.route[0],
+        }
+    }
+
+    /// The output of the fill.
+    pub fn output(&self) -> Value {
+        Value {
+            amount: self.amounts[self.amounts.len() - 1],
+            asset_id: self.route[self.route.len() - 1],
+        }
+    }
+
+    /// The fill against each position along the route, in route order.
+    pub fn hops(&self) -> impl Iterator<Item = TraceHop> + '_ {
+        self.positions.iter().enumerate().map(|(i, position_id)| {
+            let value = |j: usize| Value {
+                amount: self.amounts[j],
+                asset_id: self.route[j],
+            };
+            TraceHop {
+                position_id: *position_id,
+                input: value(i),
+                output: value(i + 1),
+            }
+        })
+    }
+}
+
+impl DomainType for SwapExecutionTrace {
+    type Proto = pb::SwapExecutionTrace;
+}
+
+impl TryFrom<pb::SwapExecutionTrace> for SwapExecutionTrace {
+    type Error = anyhow::Error;
+    fn try_from(trace: pb::SwapExecutionTrace) -> Result<Self> {
+        Ok(Self {
+            fills: trace
+                .fills
+                .into_iter()
+                .map(TryInto::try_into)
+                .collect::<Result<_>>()?,
+        })
+    }
+}
+
+impl From<SwapExecutionTrace> for pb::SwapExecutionTrace {
+    fn from(trace: SwapExecutionTrace) -> Self {
+        pb::SwapExecutionTrace {
+            fills: trace.fills.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+impl DomainType for RouteFill {
+    type Proto = pb::RouteFill;
+}
+
+impl TryFrom<pb::RouteFill> for RouteFill {
+    type Error = anyhow::Error;
+    fn try_from(fill: pb::RouteFill) -> Result<Self> {
+        let fill = Self {
+            route: fill
+                .route
+                .into_iter()
+                .map(TryInto::try_into)
+                .collect::<Result<_>>()?,
+            amounts: fill
+                .amounts
+                .into_iter()
+                .map(TryInto::try_into)
+                .collect::<Result<_>>()?,
+            positions: fill
+                .positions
+                .into_iter()
+                .map(TryInto::try_into)
+                .collect::<Result<_>>()?,
+        };
+        ensure!(
+            fill.route.len() >= 2,
+            "route fill must have at least one hop"
+        );
+        ensure!(
+            fill.amounts.len() == fill.route.len(),
+            "route fill must have one amount per asset on the route"
+        );
+        ensure!(
+            fill.positions.len() + 1 == fill.route.len(),
+            "route fill must have one position per hop"
+        );
+        Ok(fill)
+    }
+}
+
+impl From<RouteFill> for pb::RouteFill {
+    fn from(fill: RouteFill) -> Self {
+        pb::RouteFill {
+            route: fill.route.into_iter().map(Into::into).collect(),
+            amounts: fill.amounts.into_iter().map(Into::into).collect(),
+            positions: fill.positions.into_iter().map(Into::into).collect(),
+        }
+    }
+}
@@ -1,25 +1,41 @@
 #![deny(clippy::unwrap_used)]
 // Requires nightly.
 #![cfg_attr(docsrs, feature(doc_auto_cfg))]
+pub mod client;
 #[cfg(feature = "component")]
 pub mod component;
 pub mod event;
+pub mod genesis;
+pub mod params;
 pub mod state_key;
 
 mod batch_swap_output_data;
 mod circuit_breaker;
+mod execution_trace;
 mod swap_execution;
 mod trading_pair;
 
 pub use batch_swap_output_data::BatchSwapOutputData;
 pub(crate) use circuit_breaker::ExecutionCircuitBreaker;
+pub use circuit_breaker::{
+    CircuitBreakerStatus, ExecutionCircuitBreakerTrip, ValueCircuitBreakerBreach,
+};
+pub use execution_trace::{RouteFill, SwapExecutionTrace, TraceHop};
+pub use params::DexParameters;
 pub use swap_execution::SwapExecution;
 pub use trading_pair::{DirectedTradingPair, DirectedUnitPair, TradingPair, TradingPairVar};
 
+pub mod auction;
 pub mod lp;
 pub mod swap;
 pub mod swap_claim;
 
-pub use lp::action::{PositionClose, PositionOpen, PositionWithdraw};
+pub use auction::action::{
+    ActionDutchAuctionEnd, ActionDutchAuctionSchedule, ActionDutchAuctionWithdraw,
+};
+pub use lp::action::{
+    PositionClose, PositionOpen, PositionOpenBatch, PositionRewrite, PositionUpdateBatch,
+    PositionWithdraw,
+};
 pub use swap::Swap;
 pub use swap_claim::SwapClaim;
@@ -1,3 +1,7 @@
+mod analytics;
+mod history;
+mod ladder;
+mod metadata;
 mod nft;
 mod order;
 mod reserves;
@@ -7,6 +11,10 @@ pub mod action;
 pub mod plan;
 pub mod position;
 
+pub use analytics::{PositionAnalytics, PositionValuation};
+pub use history::PositionHistoryEntry;
+pub use ladder::Ladder;
+pub use metadata::{PositionMetadata, MAX_STRATEGY_TAG_LEN};
 pub use nft::LpNft;
 pub use order::{BuyOrder, SellOrder};
 pub use reserves::Reserves;
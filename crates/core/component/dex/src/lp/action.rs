@@ -18,12 +18,17 @@ pub struct PositionOpen {
     /// Positions are immutable, so the `PositionData` (and hence the `PositionId`)
     /// are unchanged over the entire lifetime of the position.
     pub position: Position,
+    /// An optional tag identifying the strategy which opened the position,
+    /// recorded in the position's [`PositionMetadata`](super::PositionMetadata).
+    ///
+    /// The tag is at most [`MAX_STRATEGY_TAG_LEN`](super::MAX_STRATEGY_TAG_LEN) bytes long.
+    pub strategy_tag: String,
 }
 
 impl EffectingData for PositionOpen {
     fn effect_hash(&self) -> EffectHash {
-        // The position open action consists only of the position, which
-        // we consider effecting data.
+        // The position open action consists only of the position and its
+        // strategy tag, which we consider effecting data.
         EffectHash::from_proto_effecting_data(&self.to_proto())
     }
 }
@@ -105,6 +110,215 @@ impl EffectingData for PositionWithdraw {
     }
 }
 
+/// A withdrawal from a closed position, as part of a [`PositionUpdateBatch`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BatchWithdrawal {
+    pub position_id: position::Id,
+    /// The sequence number of the withdrawal, allowing multiple withdrawals from the same position.
+    pub sequence: u64,
+}
+
+impl BatchWithdrawal {
+    /// Compute the value this withdrawal contributes to its transaction,
+    /// excluding the withdrawn reserves.
+    ///
+    /// The withdrawal consumes the position NFT for the previous state and
+    /// produces one for the next withdrawn state.
+    fn nft_balance(&self) -> Balance {
+        // We treat a sequence number of -1 as "closed", the state before the first withdrawal.
+        let prev_state = if self.sequence == 0 {
+            position::State::Closed
+        } else {
+            position::State::Withdrawn {
+                sequence: self.sequence - 1,
+            }
+        };
+        let next_state = position::State::Withdrawn {
+            sequence: self.sequence,
+        };
+
+        let prev_state_nft = Value {
+            amount: 1u64.into(),
+            asset_id: LpNft::new(self.position_id, prev_state).asset_id(),
+        };
+        let next_state_nft = Value {
+            amount: 1u64.into(),
+            asset_id: LpNft::new(self.position_id, next_state).asset_id(),
+        };
+
+        Balance::from(next_state_nft) - prev_state_nft
+    }
+}
+
+/// A transaction action that closes, withdraws from, and opens several
+/// positions at once.
+///
+/// This allows a market maker to refresh many quotes with a single compact
+/// action: the withdrawals share a single commitment to their combined
+/// reserves, and the whole batch is validated together.
+///
+/// This action's contribution to the transaction's value balance is the sum of
+/// the contributions of the equivalent [`PositionClose`], [`PositionWithdraw`],
+/// and [`PositionOpen`] actions.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(try_from = "pb::PositionUpdateBatch", into = "pb::PositionUpdateBatch")]
+pub struct PositionUpdateBatch {
+    /// The positions to close.
+    pub closes: Vec<position::Id>,
+    /// The positions to withdraw from.
+    pub withdrawals: Vec<BatchWithdrawal>,
+    /// A transparent (zero blinding factor) commitment to the combined final
+    /// reserves of all of the withdrawn positions.
+    ///
+    /// The chain will check this commitment by recomputing it with the on-chain state.
+    pub withdrawn_reserves_commitment: balance::Commitment,
+    /// The positions to open.
+    pub opens: Vec<Position>,
+}
+
+impl EffectingData for PositionUpdateBatch {
+    fn effect_hash(&self) -> EffectHash {
+        EffectHash::from_proto_effecting_data(&self.to_proto())
+    }
+}
+
+impl PositionUpdateBatch {
+    /// The maximum number of positions a single batch may update.
+    pub const MAX_UPDATES: usize = 32;
+
+    /// The number of positions updated by this batch.
+    pub fn num_updates(&self) -> usize {
+        self.closes.len() + self.withdrawals.len() + self.opens.len()
+    }
+
+    /// Compute the value this action contributes to its transaction, excluding
+    /// the withdrawn reserves, which are only committed to.
+    pub fn balance_excluding_withdrawn_reserves(&self) -> Balance {
+        let mut balance = Balance::default();
+        for position_id in &self.closes {
+            balance += PositionClose {
+                position_id: *position_id,
+            }
+            .balance();
+        }
+        for withdrawal in &self.withdrawals {
+            balance += withdrawal.nft_balance();
+        }
+        for position in &self.opens {
+            balance += PositionOpen {
+                position: position.clone(),
+                strategy_tag: String::new(),
+            }
+            .balance();
+        }
+        balance
+    }
+}
+
+/// A transaction action that opens several positions at once.
+///
+/// This allows a market maker to deploy a ladder of quotes, such as one built
+/// from a [`Ladder`](super::Ladder), with a single action whose positions are
+/// validated together.
+///
+/// This action's contribution to the transaction's value balance is the sum of
+/// the contributions of the equivalent [`PositionOpen`] actions: it consumes
+/// the combined initial reserves, and contributes an opened position NFT for
+/// each position.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(try_from = "pb::PositionOpenBatch", into = "pb::PositionOpenBatch")]
+pub struct PositionOpenBatch {
+    /// The positions to open.
+    pub positions: Vec<Position>,
+    /// An optional tag identifying the strategy which opened the positions,
+    /// recorded in the [`PositionMetadata`](super::PositionMetadata) of each.
+    pub strategy_tag: String,
+}
+
+impl EffectingData for PositionOpenBatch {
+    fn effect_hash(&self) -> EffectHash {
+        EffectHash::from_proto_effecting_data(&self.to_proto())
+    }
+}
+
+impl PositionOpenBatch {
+    /// The maximum number of positions a single batch may open.
+    pub const MAX_POSITIONS: usize = 64;
+
+    /// The individual [`PositionOpen`] actions equivalent to this batch.
+    pub fn opens(&self) -> impl Iterator<Item = PositionOpen> + '_ {
+        self.positions.iter().map(|position| PositionOpen {
+            position: position.clone(),
+            strategy_tag: self.strategy_tag.clone(),
+        })
+    }
+
+    /// Compute the value this action contributes to its transaction.
+    pub fn balance(&self) -> Balance {
+        let mut balance = Balance::default();
+        for open in self.opens() {
+            balance += open.balance();
+        }
+        balance
+    }
+}
+
+/// A transaction action that atomically replaces an open position with a new
+/// position on the same trading pair.
+///
+/// Unlike a [`PositionClose`], the existing position is closed immediately, and
+/// its final reserves withdrawn, so that the new position can be opened in its
+/// place without the liquidity provider's quote ever being missing from the
+/// book. The new position's history records the position it replaced.
+///
+/// This action's contribution to the transaction's value balance is to consume
+/// the opened position NFT of the existing position and contribute its first
+/// withdrawn position NFT and its final reserves, and to consume the initial
+/// reserves of the new position and contribute its opened position NFT.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(try_from = "pb::PositionRewrite", into = "pb::PositionRewrite")]
+pub struct PositionRewrite {
+    /// The open position to replace.
+    pub position_id: position::Id,
+    /// A transparent (zero blinding factor) commitment to the replaced position's final reserves.
+    ///
+    /// The chain will check this commitment by recomputing it with the on-chain state.
+    pub reserves_commitment: balance::Commitment,
+    /// The position to open in its place.
+    pub new_position: Position,
+}
+
+impl EffectingData for PositionRewrite {
+    fn effect_hash(&self) -> EffectHash {
+        EffectHash::from_proto_effecting_data(&self.to_proto())
+    }
+}
+
+impl PositionRewrite {
+    /// Compute the value this action contributes to its transaction, excluding
+    /// the final reserves of the replaced position, which are only committed to.
+    pub fn balance_excluding_reserves(&self) -> Balance {
+        let opened_position_nft = Value {
+            amount: 1u64.into(),
+            asset_id: LpNft::new(self.position_id, position::State::Opened).asset_id(),
+        };
+        let withdrawn_position_nft = Value {
+            amount: 1u64.into(),
+            asset_id: LpNft::new(self.position_id, position::State::Withdrawn { sequence: 0 })
+                .asset_id(),
+        };
+
+        // The action consumes the opened replaced position and produces it
+        // withdrawn, as well as opening the new position.
+        Balance::from(withdrawn_position_nft) - opened_position_nft
+            + PositionOpen {
+                position: self.new_position.clone(),
+                strategy_tag: String::new(),
+            }
+            .balance()
+    }
+}
+
 impl DomainType for PositionOpen {
     type Proto = pb::PositionOpen;
 }
@@ -113,6 +327,7 @@ impl From<PositionOpen> for pb::PositionOpen {
     fn from(value: PositionOpen) -> Self {
         Self {
             position: Some(value.position.into()),
+            strategy_tag: value.strategy_tag,
         }
     }
 }
@@ -126,6 +341,7 @@ impl TryFrom<pb::PositionOpen> for PositionOpen {
                 .position
                 .ok_or_else(|| anyhow::anyhow!("missing position"))?
                 .try_into()?,
+            strategy_tag: value.strategy_tag,
         })
     }
 }
@@ -186,3 +402,124 @@ impl TryFrom<pb::PositionWithdraw> for PositionWithdraw {
         })
     }
 }
+
+impl DomainType for PositionUpdateBatch {
+    type Proto = pb::PositionUpdateBatch;
+}
+
+impl From<PositionUpdateBatch> for pb::PositionUpdateBatch {
+    fn from(value: PositionUpdateBatch) -> Self {
+        Self {
+            closes: value.closes.into_iter().map(Into::into).collect(),
+            withdrawals: value
+                .withdrawals
+                .into_iter()
+                .map(|withdrawal| pb::position_update_batch::Withdrawal {
+                    position_id: Some(withdrawal.position_id.into()),
+                    sequence: withdrawal.sequence,
+                })
+                .collect(),
+            withdrawn_reserves_commitment: Some(value.withdrawn_reserves_commitment.into()),
+            opens: value.opens.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+impl TryFrom<pb::PositionUpdateBatch> for PositionUpdateBatch {
+    type Error = anyhow::Error;
+
+    fn try_from(value: pb::PositionUpdateBatch) -> Result<Self, Self::Error> {
+        Ok(Self {
+            closes: value
+                .closes
+                .into_iter()
+                .map(TryInto::try_into)
+                .collect::<Result<_, _>>()?,
+            withdrawals: value
+                .withdrawals
+                .into_iter()
+                .map(|withdrawal| {
+                    Ok(BatchWithdrawal {
+                        position_id: withdrawal
+                            .position_id
+                            .ok_or_else(|| anyhow::anyhow!("missing position_id"))?
+                            .try_into()?,
+                        sequence: withdrawal.sequence,
+                    })
+                })
+                .collect::<anyhow::Result<_>>()?,
+            withdrawn_reserves_commitment: value
+                .withdrawn_reserves_commitment
+                .ok_or_else(|| anyhow::anyhow!("missing withdrawn_reserves_commitment"))?
+                .try_into()?,
+            opens: value
+                .opens
+                .into_iter()
+                .map(TryInto::try_into)
+                .collect::<Result<_, _>>()?,
+        })
+    }
+}
+
+impl DomainType for PositionOpenBatch {
+    type Proto = pb::PositionOpenBatch;
+}
+
+impl From<PositionOpenBatch> for pb::PositionOpenBatch {
+    fn from(value: PositionOpenBatch) -> Self {
+        Self {
+            positions: value.positions.into_iter().map(Into::into).collect(),
+            strategy_tag: value.strategy_tag,
+        }
+    }
+}
+
+impl TryFrom<pb::PositionOpenBatch> for PositionOpenBatch {
+    type Error = anyhow::Error;
+
+    fn try_from(value: pb::PositionOpenBatch) -> Result<Self, Self::Error> {
+        Ok(Self {
+            positions: value
+                .positions
+                .into_iter()
+                .map(TryInto::try_into)
+                .collect::<Result<_, _>>()?,
+            strategy_tag: value.strategy_tag,
+        })
+    }
+}
+
+impl DomainType for PositionRewrite {
+    type Proto = pb::PositionRewrite;
+}
+
+impl From<PositionRewrite> for pb::PositionRewrite {
+    fn from(value: PositionRewrite) -> Self {
+        Self {
+            position_id: Some(value.position_id.into()),
+            reserves_commitment: Some(value.reserves_commitment.into()),
+            new_position: Some(value.new_position.into()),
+        }
+    }
+}
+
+impl TryFrom<pb::PositionRewrite> for PositionRewrite {
+    type Error = anyhow::Error;
+
+    fn try_from(value: pb::PositionRewrite) -> Result<Self, Self::Error> {
+        Ok(Self {
+            position_id: value
+                .position_id
+                .ok_or_else(|| anyhow::anyhow!("missing position_id"))?
+                .try_into()?,
+            reserves_commitment: value
+                .reserves_commitment
+                .ok_or_else(|| anyhow::anyhow!("missing reserves_commitment"))?
+                .try_into()?,
+            new_position: value
+                .new_position
+                .ok_or_else(|| anyhow::anyhow!("missing new_position"))?
+                .try_into()?,
+        })
+    }
+}
@@ -0,0 +1,315 @@
+use anyhow::Context;
+use penumbra_num::{fixpoint::U128x128, Amount};
+use penumbra_proto::{penumbra::core::component::dex::v1 as pb, DomainType};
+use serde::{Deserialize, Serialize};
+
+use super::{position::State, PositionHistoryEntry, Reserves};
+use crate::TradingPair;
+
+/// Aggregate metrics for a set of liquidity positions on a single trading
+/// pair, computed from their histories.
+///
+/// A position created by a rewrite is counted from its own opening, so the
+/// reserves carried over from the position it replaced are counted in the
+/// initial and final reserves of both, if both are included.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(try_from = "pb::PositionAnalytics", into = "pb::PositionAnalytics")]
+pub struct PositionAnalytics {
+    /// The trading pair the positions were opened on.
+    pub trading_pair: TradingPair,
+    /// The number of positions aggregated.
+    pub position_count: u32,
+    /// The total fees earned by the positions in each asset.
+    pub fees_1: Amount,
+    pub fees_2: Amount,
+    /// The total amounts of each asset traded into and out of the positions.
+    pub volume_1: Amount,
+    pub volume_2: Amount,
+    /// The total number of blocks the positions were open for.
+    pub blocks_open: u64,
+    /// The total number of blocks the positions were open with reserves of
+    /// both assets.
+    pub blocks_in_range: u64,
+    /// The total reserves the positions were opened with.
+    pub initial_reserves: Reserves,
+    /// The total reserves of the positions as of the latest block, or as of
+    /// their withdrawal.
+    pub final_reserves: Reserves,
+    /// The value of the positions at a reference price, if one was found.
+    pub valuation: Option<PositionValuation>,
+}
+
+/// The value of a set of liquidity positions in terms of asset 2 of their
+/// trading pair, at the clearing price of a batch swap.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(try_from = "pb::PositionValuation", into = "pb::PositionValuation")]
+pub struct PositionValuation {
+    /// The height of the batch swap whose clearing price was used.
+    pub height: u64,
+    /// The value of the initial reserves, had they been held instead.
+    pub held_value: Amount,
+    /// The value of the final reserves.
+    pub final_value: Amount,
+    /// The value of the fees earned, which are included in the final reserves.
+    pub fees_value: Amount,
+    /// The value lost relative to holding the initial reserves, not counting
+    /// the fees earned, or zero if the positions gained value regardless.
+    pub impermanent_loss: Amount,
+}
+
+impl PositionAnalytics {
+    /// Start aggregating positions on the given trading pair.
+    pub fn new(trading_pair: TradingPair) -> Self {
+        Self {
+            trading_pair,
+            position_count: 0,
+            fees_1: Amount::zero(),
+            fees_2: Amount::zero(),
+            volume_1: Amount::zero(),
+            volume_2: Amount::zero(),
+            blocks_open: 0,
+            blocks_in_range: 0,
+            initial_reserves: Reserves::zero(),
+            final_reserves: Reserves::zero(),
+            valuation: None,
+        }
+    }
+
+    /// Add the history of a position, ordered by height, to the aggregate,
+    /// counting the blocks after its last entry up to `current_height`.
+    pub fn add_history(&mut self, history: &[PositionHistoryEntry], current_height: u64) {
+        self.position_count = self.position_count.saturating_add(1);
+        let Some(first) = history.first() else {
+            return;
+        };
+
+        // The first entry includes any fills in the block the position was
+        // opened, which are undone to recover the reserves it was opened with.
+        let opened_1 = first
+            .reserves
+            .r1
+            .saturating_add(&first.outflow_1)
+            .saturating_sub(&first.inflow_1);
+        let opened_2 = first
+            .reserves
+            .r2
+            .saturating_add(&first.outflow_2)
+            .saturating_sub(&first.inflow_2);
+        self.initial_reserves.r1 = self.initial_reserves.r1.saturating_add(&opened_1);
+        self.initial_reserves.r2 = self.initial_reserves.r2.saturating_add(&opened_2);
+
+        let mut last_reserves = &first.reserves;
+        for (i, entry) in history.iter().enumerate() {
+            self.fees_1 = self.fees_1.saturating_add(&entry.fees_1);
+            self.fees_2 = self.fees_2.saturating_add(&entry.fees_2);
+            self.volume_1 = self
+                .volume_1
+                .saturating_add(&entry.inflow_1.saturating_add(&entry.outflow_1));
+            self.volume_2 = self
+                .volume_2
+                .saturating_add(&entry.inflow_2.saturating_add(&entry.outflow_2));
+
+            if !matches!(entry.state, State::Withdrawn { .. }) {
+                last_reserves = &entry.reserves;
+            }
+
+            if entry.state == State::Opened {
+                let until = history
+                    .get(i + 1)
+                    .map_or(current_height, |next| next.height);
+                let blocks = until.saturating_sub(entry.height);
+                self.blocks_open = self.blocks_open.saturating_add(blocks);
+                if entry.reserves.r1 > Amount::zero() && entry.reserves.r2 > Amount::zero() {
+                    self.blocks_in_range = self.blocks_in_range.saturating_add(blocks);
+                }
+            }
+        }
+
+        self.final_reserves.r1 = self.final_reserves.r1.saturating_add(&last_reserves.r1);
+        self.final_reserves.r2 = self.final_reserves.r2.saturating_add(&last_reserves.r2);
+    }
+
+    /// Value the positions at the price of asset 1 in terms of asset 2 set by
+    /// the batch swap at the given height.
+    pub fn value_at(&mut self, height: u64, price: U128x128) -> anyhow::Result<()> {
+        let value = |amount_1: Amount, amount_2: Amount| -> anyhow::Result<Amount> {
+            price
+                .apply_to_amount(&amount_1)?
+                .checked_add(&amount_2)
+                .context("value overflows an amount")
+        };
+
+        let held_value = value(self.initial_reserves.r1, self.initial_reserves.r2)?;
+        let final_value = value(self.final_reserves.r1, self.final_reserves.r2)?;
+        let fees_value = value(self.fees_1, self.fees_2)?;
+        self.valuation = Some(PositionValuation {
+            height,
+            held_value,
+            final_value,
+            fees_value,
+            impermanent_loss: held_value
+                .saturating_add(&fees_value)
+                .saturating_sub(&final_value),
+        });
+        Ok(())
+    }
+}
+
+impl DomainType for PositionAnalytics {
+    type Proto = pb::PositionAnalytics;
+}
+
+impl From<PositionAnalytics> for pb::PositionAnalytics {
+    fn from(value: PositionAnalytics) -> Self {
+        Self {
+            trading_pair: Some(value.trading_pair.into()),
+            position_count: value.position_count,
+            fees_1: Some(value.fees_1.into()),
+            fees_2: Some(value.fees_2.into()),
+            volume_1: Some(value.volume_1.into()),
+            volume_2: Some(value.volume_2.into()),
+            blocks_open: value.blocks_open,
+            blocks_in_range: value.blocks_in_range,
+            initial_reserves: Some(value.initial_reserves.into()),
+            final_reserves: Some(value.final_reserves.into()),
+            valuation: value.valuation.map(Into::into),
+        }
+    }
+}
+
+impl TryFrom<pb::PositionAnalytics> for PositionAnalytics {
+    type Error = anyhow::Error;
+
+    fn try_from(value: pb::PositionAnalytics) -> Result<Self, Self::Error> {
+        Ok(Self {
+            trading_pair: value
+                .trading_pair
+                .ok_or_else(|| anyhow::anyhow!("missing trading_pair"))?
+                .try_into()?,
+            position_count: value.position_count,
+            fees_1: required_amount(value.fees_1, "fees_1")?,
+            fees_2: required_amount(value.fees_2, "fees_2")?,
+            volume_1: required_amount(value.volume_1, "volume_1")?,
+            volume_2: required_amount(value.volume_2, "volume_2")?,
+            blocks_open: value.blocks_open,
+            blocks_in_range: value.blocks_in_range,
+            initial_reserves: value
+                .initial_reserves
+                .ok_or_else(|| anyhow::anyhow!("missing initial_reserves"))?
+                .try_into()?,
+            final_reserves: value
+                .final_reserves
+                .ok_or_else(|| anyhow::anyhow!("missing final_reserves"))?
+                .try_into()?,
+            valuation: value.valuation.map(TryInto::try_into).transpose()?,
+        })
+    }
+}
+
+impl DomainType for PositionValuation {
+    type Proto = pb::PositionValuation;
+}
+
+impl From<PositionValuation> for pb::PositionValuation {
+    fn from(value: PositionValuation) -> Self {
+        Self {
+            height: value.height,
+            held_value: Some(value.held_value.into()),
+            final_value: Some(value.final_value.into()),
+            fees_value: Some(value.fees_value.into()),
+            impermanent_loss: Some(value.impermanent_loss.into()),
+        }
+    }
+}
+
+impl TryFrom<pb::PositionValuation> for PositionValuation {
+    type Error = anyhow::Error;
+
+    fn try_from(value: pb::PositionValuation) -> Result<Self, Self::Error> {
+        Ok(Self {
+            height: value.height,
+            held_value: required_amount(value.held_value, "held_value")?,
+            final_value: required_amount(value.final_value, "final_value")?,
+            fees_value: required_amount(value.fees_value, "fees_value")?,
+            impermanent_loss: required_amount(value.impermanent_loss, "impermanent_loss")?,
+        })
+    }
+}
+
+fn required_amount(
+    amount: Option<penumbra_proto::core::num::v1::Amount>,
+    name: &str,
+) -> anyhow::Result<Amount> {
+    amount
+        .ok_or_else(|| anyhow::anyhow!("missing {name}"))?
+        .try_into()
+}
+
+#[cfg(test)]
+mod tests {
+    use penumbra_asset::asset;
+
+    use super::*;
+
+    fn entry(height: u64, state: State, r1: u64, r2: u64) -> PositionHistoryEntry {
+        PositionHistoryEntry {
+            height,
+            prev_state: None,
+            state,
+            reserves: Reserves {
+                r1: r1.into(),
+                r2: r2.into(),
+            },
+            fills: 0,
+            inflow_1: Amount::zero(),
+            inflow_2: Amount::zero(),
+            outflow_1: Amount::zero(),
+            outflow_2: Amount::zero(),
+            fees_1: Amount::zero(),
+            fees_2: Amount::zero(),
+            rewritten_from: None,
+            rewritten_to: None,
+        }
+    }
+
+    #[test]
+    fn history_is_aggregated_and_valued() {
+        let cache = asset::Cache::with_known_assets();
+        let gm = cache.get_unit("gm").unwrap().id();
+        let gn = cache.get_unit("gn").unwrap().id();
+        let mut analytics = PositionAnalytics::new(TradingPair::new(gm, gn));
+
+        // Opened at height 10 with 100 of asset 1, half of which is sold for
+        // 55 of asset 2 at height 20, including a fee of 5, before the
+        // position is closed at height 30 and withdrawn at height 40.
+        let opened = entry(10, State::Opened, 100, 0);
+        let mut filled = entry(20, State::Opened, 50, 55);
+        filled.fills = 1;
+        filled.outflow_1 = 50u64.into();
+        filled.inflow_2 = 55u64.into();
+        filled.fees_2 = 5u64.into();
+        let closed = entry(30, State::Closed, 50, 55);
+        let withdrawn = entry(40, State::Withdrawn { sequence: 0 }, 0, 0);
+        analytics.add_history(&[opened, filled, closed, withdrawn], 100);
+
+        assert_eq!(analytics.position_count, 1);
+        assert_eq!(analytics.fees_2, 5u64.into());
+        assert_eq!(analytics.volume_1, 50u64.into());
+        assert_eq!(analytics.volume_2, 55u64.into());
+        assert_eq!(analytics.blocks_open, 20);
+        assert_eq!(analytics.blocks_in_range, 10);
+        assert_eq!(analytics.initial_reserves.r1, 100u64.into());
+        assert_eq!(analytics.final_reserves.r1, 50u64.into());
+        assert_eq!(analytics.final_reserves.r2, 55u64.into());
+
+        // Asset 1 then trades at 1.5 asset 2.
+        analytics
+            .value_at(50, U128x128::ratio(3u64, 2u64).unwrap())
+            .unwrap();
+        let valuation = analytics.valuation.unwrap();
+        assert_eq!(valuation.held_value, 150u64.into());
+        assert_eq!(valuation.final_value, 130u64.into());
+        assert_eq!(valuation.fees_value, 5u64.into());
+        assert_eq!(valuation.impermanent_loss, 25u64.into());
+    }
+}
@@ -0,0 +1,240 @@
+use penumbra_num::Amount;
+use penumbra_proto::{penumbra::core::component::dex::v1 as pb, DomainType};
+use serde::{Deserialize, Serialize};
+
+use super::{
+    position::{self, Position, State},
+    Reserves,
+};
+
+/// The changes to a liquidity position during a single block.
+///
+/// Entries are accumulated as the position is modified during a block, and
+/// recorded in the chain state at the end of the block, so that the history
+/// of a position can be queried without replaying blocks.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(
+    try_from = "pb::PositionHistoryEntry",
+    into = "pb::PositionHistoryEntry"
+)]
+pub struct PositionHistoryEntry {
+    /// The height of the block.
+    pub height: u64,
+    /// The state of the position before the block, or `None` if the position
+    /// was opened during the block.
+    pub prev_state: Option<State>,
+    /// The state of the position at the end of the block.
+    pub state: State,
+    /// The reserves of the position at the end of the block.
+    pub reserves: Reserves,
+    /// The number of times the position was filled against during the block.
+    pub fills: u32,
+    /// The total amounts of each asset traded into the position.
+    pub inflow_1: Amount,
+    pub inflow_2: Amount,
+    /// The total amounts of each asset traded out of the position.
+    pub outflow_1: Amount,
+    pub outflow_2: Amount,
+    /// The fees accumulated by the position in each asset.
+    pub fees_1: Amount,
+    pub fees_2: Amount,
+    /// The position this position replaced during the block, whose history
+    /// continues into this one.
+    pub rewritten_from: Option<position::Id>,
+    /// The position which replaced this position during the block, whose
+    /// history continues from this one.
+    pub rewritten_to: Option<position::Id>,
+}
+
+impl PositionHistoryEntry {
+    /// Start an entry for a position modified during the block at `height`,
+    /// given its state before the modification.
+    pub fn new(height: u64, prev: Option<&Position>, position: &Position) -> Self {
+        let mut entry = Self {
+            height,
+            prev_state: prev.map(|prev| prev.state),
+            state: position.state,
+            reserves: position.reserves.clone(),
+            fills: 0,
+            inflow_1: Amount::zero(),
+            inflow_2: Amount::zero(),
+            outflow_1: Amount::zero(),
+            outflow_2: Amount::zero(),
+            fees_1: Amount::zero(),
+            fees_2: Amount::zero(),
+            rewritten_from: None,
+            rewritten_to: None,
+        };
+        entry.record(prev, position);
+        entry
+    }
+
+    /// Record a further modification of the position during the same block.
+    pub fn record(&mut self, prev: Option<&Position>, position: &Position) {
+        self.state = position.state;
+        self.reserves = position.reserves.clone();
+
+        // Only an open position can be traded against; any other change to
+        // its reserves is a deposit or a withdrawal.
+        let Some(prev) = prev.filter(|prev| prev.state == State::Opened) else {
+            return;
+        };
+        // A position rewrite withdraws from a position which was open.
+        if matches!(position.state, State::Withdrawn { .. }) {
+            return;
+        }
+        if prev.reserves.r1 == position.reserves.r1 && prev.reserves.r2 == position.reserves.r2 {
+            return;
+        }
+
+        self.fills = self.fills.saturating_add(1);
+        // Trades are charged the fee in effect before they were filled.
+        let fee = prev.effective_phi().component.fee as u128;
+
+        if position.reserves.r1 > prev.reserves.r1 {
+            let input = position.reserves.r1 - prev.reserves.r1;
+            self.inflow_1 = self.inflow_1.saturating_add(&input);
+            self.fees_1 = self
+                .fees_1
+                .saturating_add(&(input.value().saturating_mul(fee) / 10_000).into());
+        } else {
+            let output = prev.reserves.r1 - position.reserves.r1;
+            self.outflow_1 = self.outflow_1.saturating_add(&output);
+        }
+
+        if position.reserves.r2 > prev.reserves.r2 {
+            let input = position.reserves.r2 - prev.reserves.r2;
+            self.inflow_2 = self.inflow_2.saturating_add(&input);
+            self.fees_2 = self
+                .fees_2
+                .saturating_add(&(input.value().saturating_mul(fee) / 10_000).into());
+        } else {
+            let output = prev.reserves.r2 - position.reserves.r2;
+            self.outflow_2 = self.outflow_2.saturating_add(&output);
+        }
+    }
+}
+
+impl DomainType for PositionHistoryEntry {
+    type Proto = pb::PositionHistoryEntry;
+}
+
+impl From<PositionHistoryEntry> for pb::PositionHistoryEntry {
+    fn from(value: PositionHistoryEntry) -> Self {
+        Self {
+            height: value.height,
+            prev_state: value.prev_state.map(Into::into),
+            state: Some(value.state.into()),
+            reserves: Some(value.reserves.into()),
+            fills: value.fills,
+            inflow_1: Some(value.inflow_1.into()),
+            inflow_2: Some(value.inflow_2.into()),
+            outflow_1: Some(value.outflow_1.into()),
+            outflow_2: Some(value.outflow_2.into()),
+            fees_1: Some(value.fees_1.into()),
+            fees_2: Some(value.fees_2.into()),
+            rewritten_from: value.rewritten_from.map(Into::into),
+            rewritten_to: value.rewritten_to.map(Into::into),
+        }
+    }
+}
+
+impl TryFrom<pb::PositionHistoryEntry> for PositionHistoryEntry {
+    type Error = anyhow::Error;
+
+    fn try_from(value: pb::PositionHistoryEntry) -> Result<Self, Self::Error> {
+        Ok(Self {
+            height: value.height,
+            prev_state: value.prev_state.map(TryInto::try_into).transpose()?,
+            state: value
+                .state
+                .ok_or_else(|| anyhow::anyhow!("missing state"))?
+                .try_into()?,
+            reserves: value
+                .reserves
+                .ok_or_else(|| anyhow::anyhow!("missing reserves"))?
+                .try_into()?,
+            fills: value.fills,
+            inflow_1: required_amount(value.inflow_1, "inflow_1")?,
+            inflow_2: required_amount(value.inflow_2, "inflow_2")?,
+            outflow_1: required_amount(value.outflow_1, "outflow_1")?,
+            outflow_2: required_amount(value.outflow_2, "outflow_2")?,
+            fees_1: required_amount(value.fees_1, "fees_1")?,
+            fees_2: required_amount(value.fees_2, "fees_2")?,
+            rewritten_from: value.rewritten_from.map(TryInto::try_into).transpose()?,
+            rewritten_to: value.rewritten_to.map(TryInto::try_into).transpose()?,
+        })
+    }
+}
+
+fn required_amount(
+    amount: Option<penumbra_proto::core::num::v1::Amount>,
+    name: &str,
+) -> anyhow::Result<Amount> {
+    amount
+        .ok_or_else(|| anyhow::anyhow!("missing {name}"))?
+        .try_into()
+}
+
+#[cfg(test)]
+mod tests {
+    use penumbra_asset::asset;
+    use rand_core::OsRng;
+
+    use super::*;
+    use crate::DirectedTradingPair;
+
+    #[test]
+    fn fills_accumulate_flows_and_fees() {
+        let cache = asset::Cache::with_known_assets();
+        let gm = cache.get_unit("gm").unwrap().id();
+        let gn = cache.get_unit("gn").unwrap().id();
+        let pair = DirectedTradingPair::new(gm, gn);
+
+        let opened = Position::new(
+            OsRng,
+            pair,
+            100u32,
+            1u64.into(),
+            1u64.into(),
+            Reserves {
+                r1: 1_000_000u64.into(),
+                r2: 1_000_000u64.into(),
+            },
+        );
+        let mut entry = PositionHistoryEntry::new(10, None, &opened);
+        assert_eq!(entry.prev_state, None);
+        assert_eq!(entry.fills, 0);
+
+        // Two fills in the same block, trading asset 1 for asset 2.
+        let mut first = opened.clone();
+        first.reserves = Reserves {
+            r1: opened.reserves.r1 + Amount::from(10_000u64),
+            r2: opened.reserves.r2 - Amount::from(9_900u64),
+        };
+        entry.record(Some(&opened), &first);
+
+        let mut second = first.clone();
+        second.reserves = Reserves {
+            r1: first.reserves.r1 + Amount::from(20_000u64),
+            r2: first.reserves.r2 - Amount::from(19_800u64),
+        };
+        entry.record(Some(&first), &second);
+
+        // Closing the position doesn't count as a fill.
+        let mut closed = second.clone();
+        closed.state = State::Closed;
+        entry.record(Some(&second), &closed);
+
+        assert_eq!(entry.fills, 2);
+        assert_eq!(entry.state, State::Closed);
+        assert_eq!(entry.inflow_1, 30_000u64.into());
+        assert_eq!(entry.outflow_2, 29_700u64.into());
+        assert_eq!(entry.fees_1, 300u64.into());
+        assert_eq!(entry.fees_2, Amount::zero());
+
+        let round_trip = PositionHistoryEntry::decode(entry.encode_to_vec().as_slice()).unwrap();
+        assert_eq!(round_trip.fills, entry.fills);
+        assert_eq!(round_trip.fees_1, entry.fees_1);
+    }
+}
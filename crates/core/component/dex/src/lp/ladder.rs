@@ -0,0 +1,175 @@
+use anyhow::Result;
+use penumbra_num::{fixpoint::U128x128, Amount};
+use rand_core::CryptoRngCore;
+
+use crate::DirectedUnitPair;
+
+use super::{position::Position, Reserves};
+
+/// A grid of evenly spaced prices at which to quote liquidity on a trading
+/// pair, with one position at each rung.
+///
+/// Prices are of the start asset of the pair in terms of its end asset, in
+/// display units, e.g., a price of `1.5` on `penumbra:gm` quotes one
+/// `penumbra` for one and a half `gm`.
+#[derive(Debug, Clone)]
+pub struct Ladder {
+    pub pair: DirectedUnitPair,
+    /// The price of the lowest rung.
+    pub lower_price: U128x128,
+    /// The price of the highest rung.
+    pub upper_price: U128x128,
+    /// The number of rungs, including both the lowest and the highest.
+    pub rungs: u32,
+    /// The fee charged by each position, in basis points.
+    pub fee: u32,
+}
+
+impl Ladder {
+    /// The price of each rung, from lowest to highest.
+    pub fn prices(&self) -> Result<Vec<U128x128>> {
+        anyhow::ensure!(self.rungs > 0, "a ladder must have at least one rung");
+        anyhow::ensure!(
+            self.lower_price <= self.upper_price,
+            "the lower price {} of a ladder is above its upper price {}",
+            self.lower_price,
+            self.upper_price
+        );
+        if self.rungs == 1 {
+            return Ok(vec![self.lower_price]);
+        }
+
+        let step = ((self.upper_price - self.lower_price)? / U128x128::from(self.rungs - 1))?;
+        (0..self.rungs)
+            .map(|i| Ok((self.lower_price + (step * U128x128::from(i))?)?))
+            .collect()
+    }
+
+    /// Construct the positions making up the ladder, given the current price
+    /// and the total reserves of each asset to provision.
+    ///
+    /// Rungs below the current price buy the start asset, and split
+    /// `end_reserves` evenly between them; the other rungs sell the start
+    /// asset, and split `start_reserves` evenly between them. Any remainder of
+    /// the split is left unprovisioned.
+    pub fn positions<R: CryptoRngCore>(
+        &self,
+        mut rng: R,
+        current_price: U128x128,
+        start_reserves: Amount,
+        end_reserves: Amount,
+    ) -> Result<Vec<Position>> {
+        let prices = self.prices()?;
+        let bids = prices
+            .iter()
+            .filter(|price| **price < current_price)
+            .count() as u128;
+        let asks = prices.len() as u128 - bids;
+
+        let per_bid = Amount::from(end_reserves.value().checked_div(bids).unwrap_or(0));
+        let per_ask = Amount::from(start_reserves.value().checked_div(asks).unwrap_or(0));
+        anyhow::ensure!(
+            bids == 0 || per_bid > Amount::zero(),
+            "{end_reserves} is too little to provision {bids} rungs below the current price"
+        );
+        anyhow::ensure!(
+            asks == 0 || per_ask > Amount::zero(),
+            "{start_reserves} is too little to provision {asks} rungs at or above the current price"
+        );
+
+        // As when replicating a trading function, the coefficients scale the
+        // display price into base units: `p / q` is the price of a base unit
+        // of the start asset in base units of the end asset.
+        let q = self.pair.start.unit_amount();
+        let end_unit = U128x128::from(self.pair.end.unit_amount());
+        prices
+            .into_iter()
+            .map(|price| {
+                let p: Amount = (price * end_unit)?.round_down().try_into()?;
+                anyhow::ensure!(
+                    p > Amount::zero(),
+                    "the price {price} is too small to quote on {}",
+                    self.pair
+                );
+                let reserves = if price < current_price {
+                    Reserves {
+                        r1: Amount::zero(),
+                        r2: per_bid,
+                    }
+                } else {
+                    Reserves {
+                        r1: per_ask,
+                        r2: Amount::zero(),
+                    }
+                };
+                Ok(Position::new(
+                    &mut rng,
+                    self.pair.into_directed_trading_pair(),
+                    self.fee,
+                    p,
+                    q,
+                    reserves,
+                ))
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use penumbra_asset::asset;
+    use rand_core::OsRng;
+
+    use super::*;
+
+    fn gm_gn_ladder(rungs: u32) -> Ladder {
+        let cache = asset::Cache::with_known_assets();
+        Ladder {
+            pair: DirectedUnitPair::new(
+                cache.get_unit("gm").expect("gm is a known asset"),
+                cache.get_unit("gn").expect("gn is a known asset"),
+            ),
+            lower_price: U128x128::from(1u64),
+            upper_price: U128x128::from(2u64),
+            rungs,
+            fee: 30,
+        }
+    }
+
+    #[test]
+    fn rungs_straddle_the_current_price() -> Result<()> {
+        let ladder = gm_gn_ladder(5);
+        let prices = ladder.prices()?;
+        assert_eq!(prices.len(), 5);
+        assert_eq!(prices[0], U128x128::from(1u64));
+        assert_eq!(prices[2], U128x128::ratio(3u64, 2u64)?);
+        assert_eq!(prices[4], U128x128::from(2u64));
+
+        let pair = ladder.pair.into_directed_trading_pair();
+        let positions = ladder.positions(
+            OsRng,
+            U128x128::ratio(3u64, 2u64)?,
+            1_000u64.into(),
+            600u64.into(),
+        )?;
+        assert_eq!(positions.len(), 5);
+        for position in &positions {
+            position.check_stateless()?;
+        }
+
+        // Two rungs buy the start asset with the end asset, and three sell it.
+        let start = |position: &Position| position.reserves_for(pair.start).expect("on the pair");
+        let end = |position: &Position| position.reserves_for(pair.end).expect("on the pair");
+        assert_eq!(end(&positions[0]), 300u64.into());
+        assert_eq!(start(&positions[1]), Amount::zero());
+        assert_eq!(start(&positions[2]), 333u64.into());
+        assert_eq!(end(&positions[4]), Amount::zero());
+
+        // Nothing can be provisioned with too few reserves to split.
+        assert!(ladder
+            .positions(OsRng, U128x128::from(3u64), 4u64.into(), 0u64.into())
+            .is_err());
+        assert!(gm_gn_ladder(0).prices().is_err());
+        Ok(())
+    }
+}
@@ -0,0 +1,58 @@
+use penumbra_proto::{penumbra::core::component::dex::v1 as pb, DomainType};
+use serde::{Deserialize, Serialize};
+
+use super::position;
+use crate::TradingPair;
+
+/// The maximum length of a strategy tag, in bytes.
+pub const MAX_STRATEGY_TAG_LEN: usize = 64;
+
+/// Metadata about the provenance of a liquidity position, recorded when the
+/// position is opened.
+///
+/// Unlike the [`Position`](super::position::Position) itself, the metadata
+/// never changes over the lifetime of the position.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(try_from = "pb::PositionMetadata", into = "pb::PositionMetadata")]
+pub struct PositionMetadata {
+    /// The height of the block in which the position was opened.
+    pub creation_height: u64,
+    /// The trading pair the position was opened on.
+    pub trading_pair: TradingPair,
+    /// The strategy tag supplied when the position was opened, or the empty
+    /// string if there was none.
+    pub strategy_tag: String,
+    /// The position this position replaced with a `PositionRewrite`, if any.
+    pub rewritten_from: Option<position::Id>,
+}
+
+impl DomainType for PositionMetadata {
+    type Proto = pb::PositionMetadata;
+}
+
+impl From<PositionMetadata> for pb::PositionMetadata {
+    fn from(value: PositionMetadata) -> Self {
+        Self {
+            creation_height: value.creation_height,
+            trading_pair: Some(value.trading_pair.into()),
+            strategy_tag: value.strategy_tag,
+            rewritten_from: value.rewritten_from.map(Into::into),
+        }
+    }
+}
+
+impl TryFrom<pb::PositionMetadata> for PositionMetadata {
+    type Error = anyhow::Error;
+
+    fn try_from(value: pb::PositionMetadata) -> Result<Self, Self::Error> {
+        Ok(Self {
+            creation_height: value.creation_height,
+            trading_pair: value
+                .trading_pair
+                .ok_or_else(|| anyhow::anyhow!("missing trading pair"))?
+                .try_into()?,
+            strategy_tag: value.strategy_tag,
+            rewritten_from: value.rewritten_from.map(TryInto::try_into).transpose()?,
+        })
+    }
+}
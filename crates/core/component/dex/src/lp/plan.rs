@@ -5,11 +5,14 @@ use penumbra_proto::{penumbra::core::component::dex::v1 as pb, DomainType};
 use serde::{Deserialize, Serialize};
 
 use crate::{
-    lp::{position, LpNft, Reserves},
+    lp::{
+        position::{self, Position},
+        LpNft, Reserves,
+    },
     TradingPair,
 };
 
-use super::action::PositionWithdraw;
+use super::action::{BatchWithdrawal, PositionRewrite, PositionUpdateBatch, PositionWithdraw};
 
 /// A planned [`PositionWithdraw`](PositionWithdraw).
 #[derive(Clone, Debug, Deserialize, Serialize)]
@@ -83,6 +86,86 @@ impl PositionWithdrawPlan {
     }
 }
 
+/// A planned [`PositionUpdateBatch`](PositionUpdateBatch).
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(
+    try_from = "pb::PositionUpdateBatchPlan",
+    into = "pb::PositionUpdateBatchPlan"
+)]
+pub struct PositionUpdateBatchPlan {
+    pub closes: Vec<position::Id>,
+    pub withdrawals: Vec<PositionWithdrawPlan>,
+    pub opens: Vec<Position>,
+}
+
+impl PositionUpdateBatchPlan {
+    /// Convenience method to construct the [`PositionUpdateBatch`] described by this [`PositionUpdateBatchPlan`].
+    pub fn position_update_batch(&self) -> PositionUpdateBatch {
+        PositionUpdateBatch {
+            closes: self.closes.clone(),
+            withdrawals: self
+                .withdrawals
+                .iter()
+                .map(|withdrawal| BatchWithdrawal {
+                    position_id: withdrawal.position_id,
+                    sequence: withdrawal.sequence,
+                })
+                .collect(),
+            withdrawn_reserves_commitment: self.withdrawn_reserves_balance().commit(Fr::zero()),
+            opens: self.opens.clone(),
+        }
+    }
+
+    /// The number of positions closed, withdrawn from, or opened by this batch.
+    pub fn num_updates(&self) -> usize {
+        self.closes.len() + self.withdrawals.len() + self.opens.len()
+    }
+
+    /// The combined final reserves and rewards of all of the withdrawn positions.
+    fn withdrawn_reserves_balance(&self) -> Balance {
+        let mut balance = Balance::default();
+        for withdrawal in &self.withdrawals {
+            balance += withdrawal.reserves.balance(&withdrawal.pair);
+            for reward in &withdrawal.rewards {
+                balance += *reward;
+            }
+        }
+        balance
+    }
+
+    pub fn balance(&self) -> Balance {
+        self.position_update_batch()
+            .balance_excluding_withdrawn_reserves()
+            + self.withdrawn_reserves_balance()
+    }
+}
+
+/// A planned [`PositionRewrite`](PositionRewrite).
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(try_from = "pb::PositionRewritePlan", into = "pb::PositionRewritePlan")]
+pub struct PositionRewritePlan {
+    pub position_id: position::Id,
+    pub pair: TradingPair,
+    /// The final reserves of the replaced position.
+    pub reserves: Reserves,
+    pub new_position: Position,
+}
+
+impl PositionRewritePlan {
+    /// Convenience method to construct the [`PositionRewrite`] described by this [`PositionRewritePlan`].
+    pub fn position_rewrite(&self) -> PositionRewrite {
+        PositionRewrite {
+            position_id: self.position_id,
+            reserves_commitment: self.reserves.balance(&self.pair).commit(Fr::zero()),
+            new_position: self.new_position.clone(),
+        }
+    }
+
+    pub fn balance(&self) -> Balance {
+        self.position_rewrite().balance_excluding_reserves() + self.reserves.balance(&self.pair)
+    }
+}
+
 impl DomainType for PositionWithdrawPlan {
     type Proto = pb::PositionWithdrawPlan;
 }
@@ -124,3 +207,79 @@ impl TryFrom<pb::PositionWithdrawPlan> for PositionWithdrawPlan {
         })
     }
 }
+
+impl DomainType for PositionUpdateBatchPlan {
+    type Proto = pb::PositionUpdateBatchPlan;
+}
+
+impl From<PositionUpdateBatchPlan> for pb::PositionUpdateBatchPlan {
+    fn from(msg: PositionUpdateBatchPlan) -> Self {
+        Self {
+            closes: msg.closes.into_iter().map(Into::into).collect(),
+            withdrawals: msg.withdrawals.into_iter().map(Into::into).collect(),
+            opens: msg.opens.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+impl TryFrom<pb::PositionUpdateBatchPlan> for PositionUpdateBatchPlan {
+    type Error = anyhow::Error;
+    fn try_from(msg: pb::PositionUpdateBatchPlan) -> Result<Self, Self::Error> {
+        Ok(Self {
+            closes: msg
+                .closes
+                .into_iter()
+                .map(TryInto::try_into)
+                .collect::<Result<_, _>>()?,
+            withdrawals: msg
+                .withdrawals
+                .into_iter()
+                .map(TryInto::try_into)
+                .collect::<Result<_, _>>()?,
+            opens: msg
+                .opens
+                .into_iter()
+                .map(TryInto::try_into)
+                .collect::<Result<_, _>>()?,
+        })
+    }
+}
+
+impl DomainType for PositionRewritePlan {
+    type Proto = pb::PositionRewritePlan;
+}
+
+impl From<PositionRewritePlan> for pb::PositionRewritePlan {
+    fn from(msg: PositionRewritePlan) -> Self {
+        Self {
+            position_id: Some(msg.position_id.into()),
+            pair: Some(msg.pair.into()),
+            reserves: Some(msg.reserves.into()),
+            new_position: Some(msg.new_position.into()),
+        }
+    }
+}
+
+impl TryFrom<pb::PositionRewritePlan> for PositionRewritePlan {
+    type Error = anyhow::Error;
+    fn try_from(msg: pb::PositionRewritePlan) -> Result<Self, Self::Error> {
+        Ok(Self {
+            position_id: msg
+                .position_id
+                .ok_or_else(|| anyhow::anyhow!("missing position_id"))?
+                .try_into()?,
+            pair: msg
+                .pair
+                .ok_or_else(|| anyhow::anyhow!("missing pair"))?
+                .try_into()?,
+            reserves: msg
+                .reserves
+                .ok_or_else(|| anyhow::anyhow!("missing reserves"))?
+                .try_into()?,
+            new_position: msg
+                .new_position
+                .ok_or_else(|| anyhow::anyhow!("missing new_position"))?
+                .try_into()?,
+        })
+    }
+}
@@ -1,6 +1,6 @@
 use anyhow::{anyhow, Context};
 use penumbra_asset::asset;
-use penumbra_num::Amount;
+use penumbra_num::{fixpoint::U128x128, Amount};
 use penumbra_proto::{
     penumbra::core::component::dex::v1 as pb, serializers::bech32str, DomainType,
 };
@@ -9,7 +9,10 @@ use serde::{Deserialize, Serialize};
 
 use crate::{DirectedTradingPair, TradingPair};
 
-use super::{trading_function::TradingFunction, Reserves};
+use super::{
+    trading_function::{BareTradingFunction, TradingFunction},
+    Reserves,
+};
 
 /// Reserve amounts for positions must be at most 80 bits wide.
 pub const MAX_RESERVE_AMOUNT: u128 = (1 << 80) - 1;
@@ -35,6 +38,9 @@ pub struct Position {
     /// Set to `true` if a position is a limit-order, meaning that it will be closed
     /// after being filled against.
     pub close_on_fill: bool,
+    /// If set, the position's fee increases as its reserves become imbalanced,
+    /// rather than being fixed at the fee of its trading function.
+    pub dynamic_fee: Option<DynamicFee>,
 }
 
 impl std::fmt::Debug for Position {
@@ -44,6 +50,7 @@ impl std::fmt::Debug for Position {
             .field("reserves", &self.reserves)
             .field("phi", &self.phi)
             .field("nonce", &hex::encode(self.nonce))
+            .field("dynamic_fee", &self.dynamic_fee)
             .finish()
     }
 }
@@ -96,6 +103,7 @@ impl Position {
             state: State::Opened,
             reserves,
             close_on_fill: false,
+            dynamic_fee: None,
         }
     }
 
@@ -111,6 +119,10 @@ impl Position {
         state.update(&self.phi.component.fee.to_le_bytes());
         state.update(&self.phi.component.p.to_le_bytes());
         state.update(&self.phi.component.q.to_le_bytes());
+        // Only hashed when set, so that the IDs of positions with a fixed fee are unchanged.
+        if let Some(dynamic_fee) = &self.dynamic_fee {
+            state.update(&dynamic_fee.max_fee.to_le_bytes());
+        }
 
         let hash = state.finalize();
         let mut bytes = [0; 32];
@@ -141,6 +153,18 @@ impl Position {
             Err(anyhow!("cyclical pairs aren't allowed"))
         } else if self.phi.component.fee > MAX_FEE_BPS {
             Err(anyhow!("fee cannot be greater than 50% (5000bps)"))
+        } else if let Some(dynamic_fee) = &self.dynamic_fee {
+            if dynamic_fee.max_fee < self.phi.component.fee {
+                Err(anyhow!(
+                    "maximum dynamic fee cannot be less than the base fee"
+                ))
+            } else if dynamic_fee.max_fee > MAX_FEE_BPS {
+                Err(anyhow!(
+                    "maximum dynamic fee cannot be greater than 50% (5000bps)"
+                ))
+            } else {
+                Ok(())
+            }
         } else {
             Ok(())
         }
@@ -156,6 +180,137 @@ impl Position {
             None
         }
     }
+
+    /// The trading function the position currently trades with.
+    ///
+    /// This is [`Position::phi`], except that a position with a [`DynamicFee`]
+    /// charges the fee given by its schedule for its current reserves.
+    pub fn effective_phi(&self) -> TradingFunction {
+        let mut phi = self.phi.clone();
+        if let Some(dynamic_fee) = &self.dynamic_fee {
+            phi.component.fee = dynamic_fee.fee(&self.phi.component, &self.reserves);
+        }
+        phi
+    }
+
+    /// Returns the amount of the given asset the position sells in a single fill.
+    ///
+    /// This is its reserves of the asset, except that a position with a
+    /// [`DynamicFee`] only sells enough to move its fee by at most a basis
+    /// point, so that filling against it in steps charges the fee for the
+    /// reserves each step is filled against, rather than the fee before the
+    /// whole fill.
+    pub fn fill_capacity(&self, asset: asset::Id) -> Option<Amount> {
+        let reserves = self.reserves_for(asset)?;
+        match &self.dynamic_fee {
+            Some(dynamic_fee) => {
+                let sells_asset_1 = asset == self.phi.pair.asset_1();
+                let step =
+                    dynamic_fee.step_capacity(&self.phi.component, &self.reserves, sells_asset_1);
+                Some(step.map_or(reserves, |step| step.min(reserves)))
+            }
+            None => Some(reserves),
+        }
+    }
+}
+
+/// A schedule raising a position's fee as its reserves become imbalanced.
+///
+/// The reserves of a position are balanced when they hold the same value at
+/// the position's price, that is, when `p * r1 == q * r2`.  As trades drain
+/// one side of the position, the fee increases linearly with the imbalance
+/// `|p * r1 - q * r2| / (p * r1 + q * r2)`, from the fee of the trading
+/// function when the reserves are balanced, up to `max_fee` when one side is
+/// empty.  This compensates passive liquidity providers for the adverse
+/// selection they face when the price is moving against them.
+#[derive(Debug, PartialEq, Eq, Copy, Clone, Serialize, Deserialize)]
+#[serde(try_from = "pb::DynamicFee", into = "pb::DynamicFee")]
+pub struct DynamicFee {
+    /// The fee charged, in basis points, when one side of the reserves is empty.
+    pub max_fee: u32,
+}
+
+impl DynamicFee {
+    /// The fee, in basis points, charged by a position with the trading
+    /// function `phi` on the given reserves.
+    pub fn fee(&self, phi: &BareTradingFunction, reserves: &Reserves) -> u32 {
+        let base_fee = phi.fee.min(self.max_fee);
+        let spread = U128x128::from(self.max_fee - base_fee);
+        // Reserves too large to compute the imbalance of are treated as fully
+        // imbalanced, erring on the side of the position.
+        let imbalance = Self::imbalance(phi, reserves).unwrap_or_else(|_| 1u64.into());
+        let increase = (spread * imbalance)
+            .and_then(|increase| u64::try_from(increase.round_down()))
+            .unwrap_or(0);
+        base_fee + increase.min(u64::from(self.max_fee - base_fee)) as u32
+    }
+
+    /// The amount of one side of the reserves which can be sold before the fee
+    /// moves by more than a basis point, or `None` if the fee never moves.
+    ///
+    /// Trades preserve the value `p * r1 + q * r2` of the reserves, up to the
+    /// fee, so selling a value `d` of one side moves the imbalance by at most
+    /// `2 * d / (p * r1 + q * r2)`, and the fee by at most `spread` times that.
+    /// The step is always at least 1, so that filling in steps makes progress.
+    pub fn step_capacity(
+        &self,
+        phi: &BareTradingFunction,
+        reserves: &Reserves,
+        sells_asset_1: bool,
+    ) -> Option<Amount> {
+        let spread = self.max_fee - phi.fee.min(self.max_fee);
+        if spread == 0 {
+            return None;
+        }
+        // The price of the asset sold, `p` for asset 1 and `q` for asset 2.
+        let price = if sells_asset_1 { phi.p } else { phi.q };
+        let step = (|| {
+            let total_weight = U128x128::from(phi.p).checked_add(&phi.q.into())?;
+            let total_value = U128x128::from(phi.p)
+                .checked_div(&total_weight)?
+                .checked_mul(&reserves.r1.into())?
+                .checked_add(
+                    &U128x128::from(phi.q)
+                        .checked_div(&total_weight)?
+                        .checked_mul(&reserves.r2.into())?,
+                )?;
+            total_value
+                .checked_div(&U128x128::from(price).checked_div(&total_weight)?)?
+                .checked_div(&U128x128::from(2 * u64::from(spread)))
+        })();
+        // Reserves too large to compute the step for are filled in steps of
+        // the largest amount which can be represented.
+        let step = step
+            .ok()
+            .and_then(|step| u128::try_from(step.round_down()).ok())
+            .unwrap_or(u128::MAX);
+        Some(step.max(1).into())
+    }
+
+    /// The imbalance of the reserves, between 0 (balanced) and 1 (one-sided).
+    fn imbalance(
+        phi: &BareTradingFunction,
+        reserves: &Reserves,
+    ) -> Result<U128x128, penumbra_num::fixpoint::Error> {
+        // Weight the reserves by `p / (p + q)` and `q / (p + q)` rather than
+        // by `p` and `q`, so that their values fit in the fixed-point range.
+        let total_weight = U128x128::from(phi.p).checked_add(&phi.q.into())?;
+        let value_1 = U128x128::from(phi.p)
+            .checked_div(&total_weight)?
+            .checked_mul(&reserves.r1.into())?;
+        let value_2 = U128x128::from(phi.q)
+            .checked_div(&total_weight)?
+            .checked_mul(&reserves.r2.into())?;
+
+        let total_value = value_1.checked_add(&value_2)?;
+        if total_value == U128x128::default() {
+            return Ok(U128x128::default());
+        }
+        let difference = value_1.max(value_2).checked_sub(&value_1.min(value_2))?;
+        Ok(difference
+            .checked_div(&total_value)?
+            .min(U128x128::from(1u64)))
+    }
 }
 
 /// A hash of a [`Position`].
@@ -340,6 +495,7 @@ impl From<Position> for pb::Position {
             phi: Some(p.phi.into()),
             nonce: p.nonce.to_vec(),
             close_on_fill: p.close_on_fill,
+            dynamic_fee: p.dynamic_fee.map(Into::into),
         }
     }
 }
@@ -366,10 +522,28 @@ impl TryFrom<pb::Position> for Position {
                 .try_into()
                 .context("expected 32-byte nonce")?,
             close_on_fill: p.close_on_fill,
+            dynamic_fee: p.dynamic_fee.map(TryInto::try_into).transpose()?,
         })
     }
 }
 
+impl DomainType for DynamicFee {
+    type Proto = pb::DynamicFee;
+}
+
+impl From<DynamicFee> for pb::DynamicFee {
+    fn from(v: DynamicFee) -> Self {
+        pb::DynamicFee { max_fee: v.max_fee }
+    }
+}
+
+impl TryFrom<pb::DynamicFee> for DynamicFee {
+    type Error = anyhow::Error;
+    fn try_from(v: pb::DynamicFee) -> Result<Self, Self::Error> {
+        Ok(DynamicFee { max_fee: v.max_fee })
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -534,4 +708,49 @@ mod test {
         assert_position_similar(b_position_3, a_position_2);
         assert_position_similar(b_position_4, a_position_1);
     }
+
+    #[test]
+    fn dynamic_fee_grows_with_imbalance() {
+        let phi = BareTradingFunction::new(30, 1u64.into(), 3u64.into());
+        let dynamic_fee = DynamicFee { max_fee: 130 };
+        let fee = |r1: u64, r2: u64| {
+            dynamic_fee.fee(
+                &phi,
+                &Reserves {
+                    r1: r1.into(),
+                    r2: r2.into(),
+                },
+            )
+        };
+
+        // Balanced reserves are charged the base fee, one-sided reserves the maximum fee.
+        assert_eq!(fee(300, 100), 30);
+        assert_eq!(fee(500, 100), 55);
+        assert_eq!(fee(100, 100), 80);
+        assert_eq!(fee(0, 100), 130);
+        assert_eq!(fee(300, 0), 130);
+        assert_eq!(fee(0, 0), 30);
+
+        let mut position = Position::new(
+            OsRng,
+            DirectedTradingPair::new(asset::Id(Fq::zero()), asset::Id(Fq::from(1u64))),
+            30,
+            1u64.into(),
+            3u64.into(),
+            Reserves {
+                r1: 0u64.into(),
+                r2: 100u64.into(),
+            },
+        );
+        let fixed_id = position.id();
+        assert_eq!(position.effective_phi(), position.phi);
+
+        position.dynamic_fee = Some(dynamic_fee);
+        assert_ne!(position.id(), fixed_id);
+        assert_eq!(position.effective_phi().component.fee, 130);
+        assert!(position.check_stateless().is_ok());
+
+        position.dynamic_fee = Some(DynamicFee { max_fee: 20 });
+        assert!(position.check_stateless().is_err());
+    }
 }
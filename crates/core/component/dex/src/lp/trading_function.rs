@@ -444,6 +444,23 @@ impl BareTradingFunction {
         (U128x128::from(10_000 - self.fee) / U128x128::from(10_000u64)).expect("10_000 != 0")
     }
 
+    /// Returns the share of the fee charged on an input of `delta_1` that is owed to the
+    /// protocol, given the protocol's share of trading fees in basis points.
+    ///
+    /// The result is rounded down, so that rounding error is kept by the position.
+    pub fn protocol_fee(&self, delta_1: Amount, protocol_fee_share_bps: u32) -> Amount {
+        // The protocol's share of `delta_1` is `fee * protocol_fee_share_bps / 10_000^2`, which
+        // is at most 1. We compute `floor(delta_1 * share / 10_000^2)` exactly by splitting
+        // `delta_1` into a quotient and remainder, so that no intermediate result overflows.
+        const DENOMINATOR: u128 = 10_000 * 10_000;
+        let share =
+            u128::from(self.fee.min(10_000)) * u128::from(protocol_fee_share_bps.min(10_000));
+        let delta_1 = delta_1.value();
+        let quotient = delta_1 / DENOMINATOR;
+        let remainder = delta_1 % DENOMINATOR;
+        (quotient * share + remainder * share / DENOMINATOR).into()
+    }
+
     /// Compose two trading functions together
     #[deprecated(note = "this method is not yet implemented")]
     pub fn compose(&self, _phi: BareTradingFunction) -> BareTradingFunction {
@@ -711,4 +728,33 @@ mod tests {
         assert_eq!(new_reserves.r1, Amount::zero());
         assert_eq!(new_reserves.r2, Amount::from(50u64));
     }
+
+    #[test]
+    fn protocol_fee_is_a_share_of_the_trading_fee() {
+        let btf = BareTradingFunction {
+            fee: 30,
+            p: 1u32.into(),
+            q: 1u32.into(),
+        };
+
+        // 30bps of 1_000_000 is 3_000, and 20% of that is 600.
+        assert_eq!(btf.protocol_fee(1_000_000u64.into(), 2_000), 600u64.into());
+        // Without a protocol share, or without a fee, nothing is owed.
+        assert_eq!(btf.protocol_fee(1_000_000u64.into(), 0), Amount::zero());
+        assert_eq!(
+            BareTradingFunction {
+                fee: 0,
+                ..btf.clone()
+            }
+            .protocol_fee(1_000_000u64.into(), 2_000),
+            Amount::zero()
+        );
+        // The protocol fee is rounded down.
+        assert_eq!(btf.protocol_fee(1_000u64.into(), 2_000), Amount::zero());
+        // The protocol can't take more than the whole fee.
+        assert_eq!(
+            btf.protocol_fee(1_000_000u64.into(), 20_000),
+            3_000u64.into()
+        );
+    }
 }
@@ -0,0 +1,86 @@
+use penumbra_proto::core::component::dex::v1 as pb;
+use penumbra_proto::DomainType;
+use serde::{Deserialize, Serialize};
+
+use crate::TradingPair;
+
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq, Default)]
+#[serde(try_from = "pb::DexParameters", into = "pb::DexParameters")]
+pub struct DexParameters {
+    /// The fee tiers, in basis points, that new positions may use.
+    ///
+    /// If empty, any fee up to [`MAX_FEE_BPS`](crate::lp::position::MAX_FEE_BPS) is allowed.
+    pub fee_tiers: Vec<u32>,
+    /// The share of each position's trading fees accrued by the protocol and
+    /// deposited into the community pool, in basis points.
+    pub protocol_fee_share_bps: u32,
+    /// The trading pairs on which trading is paused.
+    ///
+    /// Swaps and new positions on a paused pair are rejected, and routing avoids it, but existing
+    /// positions can still be closed and withdrawn.
+    pub paused_trading_pairs: Vec<TradingPair>,
+    /// The number of blocks for which nodes keep the [`SwapExecutionTrace`](crate::SwapExecutionTrace)
+    /// of each batch swap execution.
+    ///
+    /// If zero, traces are not kept.
+    pub execution_trace_retention_blocks: u64,
+    /// How much shallower than the best route found so far to an asset a hop into it must be to
+    /// be pruned during route search, in basis points of the liquidity of the last hop of that
+    /// route.
+    ///
+    /// Since a route can fill no more than the liquidity of its last hop, a pruned hop could have
+    /// improved on the best route by at most this share of what the best route can fill, while
+    /// pruning bounds the number of paths considered on orderbooks stuffed with dust positions.
+    /// If zero, only hops with no liquidity at all are pruned.
+    pub route_pruning_epsilon_bps: u32,
+}
+
+impl DexParameters {
+    /// Returns `true` if new positions may charge the given fee, in basis points.
+    pub fn allows_fee(&self, fee: u32) -> bool {
+        self.fee_tiers.is_empty() || self.fee_tiers.contains(&fee)
+    }
+
+    /// Returns `true` if trading on the given pair is paused.
+    pub fn is_paused(&self, trading_pair: &TradingPair) -> bool {
+        self.paused_trading_pairs.contains(trading_pair)
+    }
+}
+
+impl DomainType for DexParameters {
+    type Proto = pb::DexParameters;
+}
+
+impl TryFrom<pb::DexParameters> for DexParameters {
+    type Error = anyhow::Error;
+
+    fn try_from(msg: pb::DexParameters) -> anyhow::Result<Self> {
+        Ok(DexParameters {
+            fee_tiers: msg.fee_tiers,
+            protocol_fee_share_bps: msg.protocol_fee_share_bps,
+            paused_trading_pairs: msg
+                .paused_trading_pairs
+                .into_iter()
+                .map(TryInto::try_into)
+                .collect::<Result<_, _>>()?,
+            execution_trace_retention_blocks: msg.execution_trace_retention_blocks,
+            route_pruning_epsilon_bps: msg.route_pruning_epsilon_bps,
+        })
+    }
+}
+
+impl From<DexParameters> for pb::DexParameters {
+    fn from(params: DexParameters) -> Self {
+        pb::DexParameters {
+            fee_tiers: params.fee_tiers,
+            protocol_fee_share_bps: params.protocol_fee_share_bps,
+            paused_trading_pairs: params
+                .paused_trading_pairs
+                .into_iter()
+                .map(Into::into)
+                .collect(),
+            execution_trace_retention_blocks: params.execution_trace_retention_blocks,
+            route_pruning_epsilon_bps: params.route_pruning_epsilon_bps,
+        }
+    }
+}
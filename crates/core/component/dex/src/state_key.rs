@@ -2,6 +2,10 @@ use std::string::String;
 
 use crate::{lp::position, DirectedTradingPair, TradingPair};
 
+/// The prefixes of all of the component's state keys, for the application's
+/// state key registry.
+pub const PREFIXES: &[&str] = &["dex/"];
+
 pub fn positions(trading_pair: &TradingPair, position_id: &str) -> String {
     format!("dex/positions/{trading_pair}/opened/{position_id}")
 }
@@ -15,15 +19,61 @@ pub fn all_positions() -> &'static str {
     "dex/position/"
 }
 
+/// Looks up the `PositionMetadata` recorded when a position was opened.
+pub fn position_metadata(id: &position::Id) -> String {
+    format!("{}{id}", all_position_metadata())
+}
+
+pub fn all_position_metadata() -> &'static str {
+    "dex/position_metadata/"
+}
+
+/// The history of a position, one entry for each block in which it changed.
+///
+/// This is only served to clients, so it is kept in nonverifiable storage.
+pub mod position_history {
+    use super::*;
+
+    pub fn prefix(id: &position::Id) -> String {
+        format!("dex/position_history/{id}/")
+    }
+
+    pub fn by_height(id: &position::Id, height: u64) -> String {
+        format!("dex/position_history/{id}/{height:020}")
+    }
+}
+
+/// Gradual Dutch auctions, and the index of those which are still trading.
+pub mod dutch_auction {
+    use crate::auction::AuctionId;
+
+    pub fn by_id(id: &AuctionId) -> String {
+        format!("dex/dutch_auction/{id}")
+    }
+
+    pub fn active_prefix() -> &'static str {
+        "dex/dutch_auction_active/"
+    }
+
+    pub fn active_by_id(id: &AuctionId) -> String {
+        format!("{}{id}", active_prefix())
+    }
+}
+
 pub fn output_data(height: u64, trading_pair: TradingPair) -> String {
     format!(
-        "dex/output/{:020}/{}/{}",
+        "{}{:020}/{}/{}",
+        outputs(),
         height,
         &trading_pair.asset_1(),
         &trading_pair.asset_2()
     )
 }
 
+pub fn outputs() -> &'static str {
+    "dex/output/"
+}
+
 pub fn swap_execution(height: u64, trading_pair: DirectedTradingPair) -> String {
     format!(
         "dex/swap_execution/{:020}/{}/{}",
@@ -35,6 +85,30 @@ pub fn swap_executions() -> &'static str {
     "dex/swap_execution/"
 }
 
+/// Traces of batch swap executions, kept in nonverifiable storage.
+pub mod execution_trace {
+    use super::*;
+
+    pub fn prefix() -> &'static str {
+        "dex/execution_trace/"
+    }
+
+    pub fn by_height(height: u64) -> String {
+        format!("dex/execution_trace/{height:020}/")
+    }
+
+    pub fn by_pair(height: u64, trading_pair: DirectedTradingPair) -> String {
+        format!(
+            "dex/execution_trace/{:020}/{}/{}",
+            height, &trading_pair.start, &trading_pair.end
+        )
+    }
+}
+
+pub fn pending_route_fills() -> &'static str {
+    "dex/pending_route_fills"
+}
+
 pub fn arb_execution(height: u64) -> String {
     format!("dex/arb_execution/{height:020}")
 }
@@ -51,6 +125,10 @@ pub fn pending_position_closures() -> &'static str {
     "dex/pending_position_closures"
 }
 
+pub fn pending_position_history() -> &'static str {
+    "dex/pending_position_history"
+}
+
 pub fn pending_payloads() -> &'static str {
     "dex/pending_payloads"
 }
@@ -63,6 +141,31 @@ pub fn aggregate_value() -> &'static str {
     "dex/aggregate_value"
 }
 
+pub fn circuit_breaker_last_trip() -> &'static str {
+    "dex/circuit_breaker/last_trip"
+}
+
+pub fn dex_params() -> &'static str {
+    "dex/params"
+}
+
+pub fn dex_params_updated() -> &'static str {
+    "dex/params_updated"
+}
+
+/// The trading pairs on which trading is paused, as of the last time the dex parameters changed.
+pub mod paused_trading_pairs {
+    use super::*;
+
+    pub fn prefix() -> &'static str {
+        "dex/paused_trading_pairs/"
+    }
+
+    pub fn by_pair(trading_pair: &TradingPair) -> String {
+        format!("{}{trading_pair}", prefix())
+    }
+}
+
 /// Encompasses non-consensus state keys.
 pub(crate) mod internal {
     use super::*;
@@ -4,6 +4,7 @@ use once_cell::sync::Lazy;
 
 mod action;
 mod ciphertext;
+mod claim_key;
 mod payload;
 mod plaintext;
 mod plan;
@@ -13,15 +14,20 @@ pub mod proof;
 
 pub use action::{Body, Swap};
 pub use ciphertext::SwapCiphertext;
+pub use claim_key::{derive_claim_key, ClaimKeyCommitment, ClaimKeyCommitmentVar};
 pub use payload::SwapPayload;
 pub use plaintext::{SwapPlaintext, SwapPlaintextVar};
 pub use plan::SwapPlan;
 pub use view::SwapView;
 
 // Swap ciphertext byte length.
-pub const SWAP_CIPHERTEXT_BYTES: usize = 272;
+pub const SWAP_CIPHERTEXT_BYTES: usize = 304;
 // Swap plaintext byte length.
-pub const SWAP_LEN_BYTES: usize = 256;
+pub const SWAP_LEN_BYTES: usize = 288;
+// Swap ciphertext byte length, for swaps without a claim key commitment.
+pub const LEGACY_SWAP_CIPHERTEXT_BYTES: usize = 272;
+// Swap plaintext byte length, for swaps without a claim key commitment.
+pub const LEGACY_SWAP_LEN_BYTES: usize = 256;
 
 pub static DOMAIN_SEPARATOR: Lazy<Fq> =
     Lazy::new(|| Fq::from_le_bytes_mod_order(blake2b_simd::blake2b(b"penumbra.swap").as_bytes()));
@@ -3,10 +3,14 @@ use anyhow::Result;
 use penumbra_keys::{keys::OutgoingViewingKey, PayloadKey};
 use penumbra_shielded_pool::note;
 
-use super::{SwapPlaintext, SWAP_CIPHERTEXT_BYTES, SWAP_LEN_BYTES};
+use super::{SwapPlaintext, LEGACY_SWAP_CIPHERTEXT_BYTES, SWAP_CIPHERTEXT_BYTES};
 
+/// An encrypted swap plaintext.
+///
+/// This is either [`SWAP_CIPHERTEXT_BYTES`] long, or [`LEGACY_SWAP_CIPHERTEXT_BYTES`]
+/// long for swaps created before claim keys were introduced.
 #[derive(Debug, Clone)]
-pub struct SwapCiphertext(pub [u8; SWAP_CIPHERTEXT_BYTES]);
+pub struct SwapCiphertext(pub Vec<u8>);
 
 impl SwapCiphertext {
     pub fn decrypt(
@@ -23,19 +27,14 @@ impl SwapCiphertext {
         payload_key: &PayloadKey,
         commitment: note::StateCommitment,
     ) -> Result<SwapPlaintext> {
-        let swap_ciphertext = self.0;
         let decryption_result = payload_key
-            .decrypt_swap(swap_ciphertext.to_vec(), commitment)
+            .decrypt_swap(self.0.clone(), commitment)
             .map_err(|_| anyhow::anyhow!("unable to decrypt swap ciphertext"))?;
 
         // TODO: encapsulate plaintext encoding by making this a
         // pub(super) parse_decryption method on SwapPlaintext
         // and removing the TryFrom impls
-        let plaintext: [u8; SWAP_LEN_BYTES] = decryption_result
-            .try_into()
-            .map_err(|_| anyhow::anyhow!("swap decryption result did not fit in plaintext len"))?;
-
-        plaintext.try_into().map_err(|_| {
+        decryption_result[..].try_into().map_err(|_| {
             anyhow::anyhow!("unable to convert swap plaintext bytes into SwapPlaintext")
         })
     }
@@ -45,7 +44,7 @@ impl TryFrom<[u8; SWAP_CIPHERTEXT_BYTES]> for SwapCiphertext {
     type Error = anyhow::Error;
 
     fn try_from(bytes: [u8; SWAP_CIPHERTEXT_BYTES]) -> Result<SwapCiphertext, Self::Error> {
-        Ok(SwapCiphertext(bytes))
+        Ok(SwapCiphertext(bytes.to_vec()))
     }
 }
 
@@ -53,6 +52,9 @@ impl TryFrom<&[u8]> for SwapCiphertext {
     type Error = anyhow::Error;
 
     fn try_from(slice: &[u8]) -> Result<SwapCiphertext, Self::Error> {
-        Ok(SwapCiphertext(slice[..].try_into()?))
+        if slice.len() != SWAP_CIPHERTEXT_BYTES && slice.len() != LEGACY_SWAP_CIPHERTEXT_BYTES {
+            anyhow::bail!("incorrect length for swap ciphertext");
+        }
+        Ok(SwapCiphertext(slice.to_vec()))
     }
 }
@@ -0,0 +1,106 @@
+use ark_ff::PrimeField;
+use ark_r1cs_std::prelude::*;
+use ark_relations::r1cs::SynthesisError;
+use decaf377::{r1cs::FqVar, FieldExt, Fq};
+use once_cell::sync::Lazy;
+use penumbra_keys::keys::{NullifierKey, NullifierKeyVar};
+use penumbra_shielded_pool::Rseed;
+
+pub static CLAIM_KEY_DOMAIN_SEPARATOR: Lazy<Fq> = Lazy::new(|| {
+    Fq::from_le_bytes_mod_order(blake2b_simd::blake2b(b"penumbra.swap.claimkey").as_bytes())
+});
+
+pub static CLAIM_KEY_COMMITMENT_DOMAIN_SEPARATOR: Lazy<Fq> = Lazy::new(|| {
+    Fq::from_le_bytes_mod_order(
+        blake2b_simd::blake2b(b"penumbra.swap.claimkey.commitment").as_bytes(),
+    )
+});
+
+/// Derive the claim key of a swap, which is the nullifier key used to claim it.
+///
+/// The claim key is derived from the swapper's nullifier key and the swap's rseed, so
+/// that the swapper's wallet can recover it from the swap plaintext, but it reveals nothing
+/// about the wallet's keys.  This means it can be handed out of band to a third party, along
+/// with the swap plaintext, to claim the swap on the swapper's behalf.
+pub fn derive_claim_key(nk: &NullifierKey, rseed: &Rseed) -> NullifierKey {
+    NullifierKey(poseidon377::hash_2(
+        &CLAIM_KEY_DOMAIN_SEPARATOR,
+        (nk.0, Fq::from_le_bytes_mod_order(&rseed.to_bytes()[..])),
+    ))
+}
+
+/// A commitment to the claim key of a swap, included in the swap plaintext so that the
+/// swap can only be claimed with that key.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ClaimKeyCommitment(pub Fq);
+
+impl ClaimKeyCommitment {
+    pub fn new(claim_key: &NullifierKey) -> Self {
+        Self(poseidon377::hash_1(
+            &CLAIM_KEY_COMMITMENT_DOMAIN_SEPARATOR,
+            claim_key.0,
+        ))
+    }
+
+    pub fn to_bytes(&self) -> [u8; 32] {
+        self.0.to_bytes()
+    }
+}
+
+impl TryFrom<&[u8]> for ClaimKeyCommitment {
+    type Error = anyhow::Error;
+
+    fn try_from(bytes: &[u8]) -> Result<Self, Self::Error> {
+        let bytes: [u8; 32] = bytes
+            .try_into()
+            .map_err(|_| anyhow::anyhow!("claim key commitment must be 32 bytes"))?;
+        Ok(Self(Fq::from_bytes(bytes)?))
+    }
+}
+
+/// Represents the [`ClaimKeyCommitment`] as a variable in an R1CS constraint system.
+pub struct ClaimKeyCommitmentVar {
+    pub inner: FqVar,
+}
+
+impl ClaimKeyCommitmentVar {
+    /// Enforce that this is a commitment to the given claim key, if `should_enforce` is true.
+    pub fn conditional_enforce_opens_to(
+        &self,
+        claim_key: &NullifierKeyVar,
+        should_enforce: &Boolean<Fq>,
+    ) -> Result<(), SynthesisError> {
+        let cs = self.inner.cs();
+        let domain_sep = FqVar::new_constant(cs.clone(), *CLAIM_KEY_COMMITMENT_DOMAIN_SEPARATOR)?;
+        let commitment = poseidon377::r1cs::hash_1(cs, &domain_sep, claim_key.inner.clone())?;
+        self.inner
+            .conditional_enforce_equal(&commitment, should_enforce)
+    }
+}
+
+impl AllocVar<ClaimKeyCommitment, Fq> for ClaimKeyCommitmentVar {
+    fn new_variable<T: std::borrow::Borrow<ClaimKeyCommitment>>(
+        cs: impl Into<ark_relations::r1cs::Namespace<Fq>>,
+        f: impl FnOnce() -> Result<T, SynthesisError>,
+        mode: AllocationMode,
+    ) -> Result<Self, SynthesisError> {
+        let ns = cs.into();
+        let cs = ns.cs();
+        let inner: ClaimKeyCommitment = *f()?.borrow();
+        Ok(Self {
+            inner: FqVar::new_variable(cs, || Ok(inner.0), mode)?,
+        })
+    }
+}
+
+impl R1CSVar<Fq> for ClaimKeyCommitmentVar {
+    type Value = ClaimKeyCommitment;
+
+    fn cs(&self) -> ark_relations::r1cs::ConstraintSystemRef<Fq> {
+        self.inner.cs()
+    }
+
+    fn value(&self) -> Result<Self::Value, SynthesisError> {
+        Ok(ClaimKeyCommitment(self.inner.value()?))
+    }
+}
@@ -11,19 +11,25 @@ use penumbra_proto::{
     core::keys::v1 as pb_keys, penumbra::core::component::dex::v1 as pb, DomainType,
 };
 use penumbra_tct::StateCommitment;
-use poseidon377::{hash_1, hash_4, hash_7};
+use poseidon377::{hash_1, hash_4, hash_5, hash_7};
 use rand_core::{CryptoRng, RngCore};
 
 use decaf377_ka as ka;
 use penumbra_asset::{asset, Value, ValueVar};
-use penumbra_keys::{keys::OutgoingViewingKey, Address, AddressVar, PayloadKey};
+use penumbra_keys::{
+    keys::{NullifierKey, OutgoingViewingKey},
+    Address, AddressVar, PayloadKey,
+};
 use penumbra_num::{Amount, AmountVar};
 use penumbra_shielded_pool::{Note, Rseed};
 use penumbra_tct::r1cs::StateCommitmentVar;
 
 use crate::{BatchSwapOutputData, TradingPair, TradingPairVar};
 
-use super::{SwapCiphertext, SwapPayload, DOMAIN_SEPARATOR, SWAP_CIPHERTEXT_BYTES, SWAP_LEN_BYTES};
+use super::{
+    derive_claim_key, ClaimKeyCommitment, ClaimKeyCommitmentVar, SwapCiphertext, SwapPayload,
+    DOMAIN_SEPARATOR, LEGACY_SWAP_LEN_BYTES, SWAP_LEN_BYTES,
+};
 
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct SwapPlaintext {
@@ -39,6 +45,10 @@ pub struct SwapPlaintext {
     pub claim_address: Address,
     // Swap rseed
     pub rseed: Rseed,
+    // Commitment to the key authorizing the SwapClaim, absent for swaps created
+    // before claim keys were introduced, which are claimed with the swapper's
+    // nullifier key.
+    pub claim_key_commitment: Option<ClaimKeyCommitment>,
 }
 
 pub static OUTPUT_1_BLINDING_DOMAIN_SEPARATOR: Lazy<Fq> = Lazy::new(|| {
@@ -96,6 +106,27 @@ impl SwapPlaintext {
     //
     // https://protocol.penumbra.zone/main/zswap/swap.html#swap-actions
     pub fn swap_commitment(&self) -> StateCommitment {
+        let swap_data = match self.claim_key_commitment {
+            Some(claim_key_commitment) => hash_5(
+                &DOMAIN_SEPARATOR,
+                (
+                    self.trading_pair.asset_1().0,
+                    self.trading_pair.asset_2().0,
+                    self.delta_1_i.into(),
+                    self.delta_2_i.into(),
+                    claim_key_commitment.0,
+                ),
+            ),
+            None => hash_4(
+                &DOMAIN_SEPARATOR,
+                (
+                    self.trading_pair.asset_1().0,
+                    self.trading_pair.asset_2().0,
+                    self.delta_1_i.into(),
+                    self.delta_2_i.into(),
+                ),
+            ),
+        };
         let inner = hash_7(
             &DOMAIN_SEPARATOR,
             (
@@ -107,21 +138,40 @@ impl SwapPlaintext {
                     .vartime_compress_to_field(),
                 *self.claim_address.transmission_key_s(),
                 Fq::from_le_bytes_mod_order(&self.claim_address.clue_key().0[..]),
-                hash_4(
-                    &DOMAIN_SEPARATOR,
-                    (
-                        self.trading_pair.asset_1().0,
-                        self.trading_pair.asset_2().0,
-                        self.delta_1_i.into(),
-                        self.delta_2_i.into(),
-                    ),
-                ),
+                swap_data,
             ),
         );
 
         StateCommitment(inner)
     }
 
+    /// The key authorizing the claim of this swap, if it was created by the holder of `nk`.
+    ///
+    /// The claim key derives the nullifier of the swap, and can be given to a third party
+    /// to claim the swap without revealing the swapper's viewing key.  Swaps without a claim
+    /// key commitment are claimed with `nk` itself, which must never be handed out.
+    pub fn claim_key(&self, nk: &NullifierKey) -> NullifierKey {
+        match self.claim_key_commitment {
+            Some(_) => derive_claim_key(nk, &self.rseed),
+            None => *nk,
+        }
+    }
+
+    /// Whether the swap is bound to a claim key, so that its claim can be delegated.
+    pub fn has_claim_key(&self) -> bool {
+        self.claim_key_commitment.is_some()
+    }
+
+    /// Whether the swap can be claimed with the given claim key.
+    ///
+    /// Swaps without a claim key commitment don't constrain the key, so this is
+    /// always true for them.
+    pub fn is_claimable_with(&self, claim_key: &NullifierKey) -> bool {
+        self.claim_key_commitment.map_or(true, |commitment| {
+            ClaimKeyCommitment::new(claim_key) == commitment
+        })
+    }
+
     pub fn diversified_generator(&self) -> &decaf377::Element {
         self.claim_address.diversified_generator()
     }
@@ -133,15 +183,12 @@ impl SwapPlaintext {
     pub fn encrypt(&self, ovk: &OutgoingViewingKey) -> SwapPayload {
         let commitment = self.swap_commitment();
         let key = PayloadKey::derive_swap(ovk, commitment);
-        let swap_plaintext: [u8; SWAP_LEN_BYTES] = self.into();
-        let encryption_result = key.encrypt_swap(swap_plaintext.to_vec(), commitment);
-
-        let ciphertext: [u8; SWAP_CIPHERTEXT_BYTES] = encryption_result
-            .try_into()
-            .expect("swap encryption result fits in ciphertext len");
+        let swap_plaintext: Vec<u8> = self.into();
+        let encryption_result = key.encrypt_swap(swap_plaintext, commitment);
 
         SwapPayload {
-            encrypted_swap: SwapCiphertext(ciphertext),
+            encrypted_swap: SwapCiphertext::try_from(&encryption_result[..])
+                .expect("swap encryption result fits in ciphertext len"),
             commitment,
         }
     }
@@ -167,8 +214,10 @@ impl SwapPlaintext {
         delta_2_i: Amount,
         claim_fee: Fee,
         claim_address: Address,
+        nk: &NullifierKey,
     ) -> SwapPlaintext {
         let rseed = Rseed::generate(rng);
+        let claim_key_commitment = Some(ClaimKeyCommitment::new(&derive_claim_key(nk, &rseed)));
 
         Self {
             trading_pair,
@@ -177,6 +226,7 @@ impl SwapPlaintext {
             claim_fee,
             claim_address,
             rseed,
+            claim_key_commitment,
         }
     }
 }
@@ -188,6 +238,10 @@ pub struct SwapPlaintextVar {
     pub delta_2_i: AmountVar,
     pub claim_address: AddressVar,
     pub rseed: FqVar,
+    pub claim_key_commitment: ClaimKeyCommitmentVar,
+    /// Whether the swap commits to its claim key, false for swaps created before
+    /// claim keys were introduced.
+    pub has_claim_key: Boolean<Fq>,
 }
 
 impl SwapPlaintextVar {
@@ -215,6 +269,17 @@ impl SwapPlaintextVar {
             .diversified_generator()
             .compress_to_field()?;
 
+        let inner_hash5 = poseidon377::r1cs::hash_5(
+            cs.clone(),
+            &domain_sep,
+            (
+                self.trading_pair.asset_1.asset_id.clone(),
+                self.trading_pair.asset_2.asset_id.clone(),
+                self.delta_1_i.amount.clone(),
+                self.delta_2_i.amount.clone(),
+                self.claim_key_commitment.inner.clone(),
+            ),
+        )?;
         let inner_hash4 = poseidon377::r1cs::hash_4(
             cs.clone(),
             &domain_sep,
@@ -225,6 +290,8 @@ impl SwapPlaintextVar {
                 self.delta_2_i.amount.clone(),
             ),
         )?;
+        let swap_data =
+            FqVar::conditionally_select(&self.has_claim_key, &inner_hash5, &inner_hash4)?;
 
         let inner = poseidon377::r1cs::hash_7(
             cs,
@@ -236,7 +303,7 @@ impl SwapPlaintextVar {
                 compressed_g_d,
                 self.claim_address.transmission_key().compress_to_field()?,
                 self.claim_address.clue_key(),
-                inner_hash4,
+                swap_data,
             ),
         )?;
 
@@ -270,7 +337,7 @@ impl AllocVar<SwapPlaintext, Fq> for SwapPlaintextVar {
         let claim_address =
             AddressVar::new_variable(cs.clone(), || Ok(swap_plaintext.claim_address), mode)?;
         let rseed = FqVar::new_variable(
-            cs,
+            cs.clone(),
             || {
                 Ok(Fq::from_le_bytes_mod_order(
                     &swap_plaintext.rseed.to_bytes()[..],
@@ -278,6 +345,20 @@ impl AllocVar<SwapPlaintext, Fq> for SwapPlaintextVar {
             },
             mode,
         )?;
+        let claim_key_commitment = ClaimKeyCommitmentVar::new_variable(
+            cs.clone(),
+            || {
+                Ok(swap_plaintext
+                    .claim_key_commitment
+                    .unwrap_or(ClaimKeyCommitment(Fq::from(0u64))))
+            },
+            mode,
+        )?;
+        let has_claim_key = Boolean::new_variable(
+            cs,
+            || Ok(swap_plaintext.claim_key_commitment.is_some()),
+            mode,
+        )?;
         Ok(Self {
             claim_fee,
             delta_1_i,
@@ -285,6 +366,8 @@ impl AllocVar<SwapPlaintext, Fq> for SwapPlaintextVar {
             delta_2_i,
             claim_address,
             rseed,
+            claim_key_commitment,
+            has_claim_key,
         })
     }
 }
@@ -319,6 +402,11 @@ impl TryFrom<pb::SwapPlaintext> for SwapPlaintext {
                 .ok_or_else(|| anyhow::anyhow!("missing trading pair in SwapPlaintext"))?
                 .try_into()?,
             rseed: Rseed(plaintext.rseed.as_slice().try_into()?),
+            claim_key_commitment: if plaintext.claim_key_commitment.is_empty() {
+                None
+            } else {
+                Some(plaintext.claim_key_commitment.as_slice().try_into()?)
+            },
         })
     }
 }
@@ -332,13 +420,17 @@ impl From<SwapPlaintext> for pb::SwapPlaintext {
             claim_address: Some(plaintext.claim_address.into()),
             trading_pair: Some(plaintext.trading_pair.into()),
             rseed: plaintext.rseed.to_bytes().to_vec(),
+            claim_key_commitment: plaintext
+                .claim_key_commitment
+                .map(|commitment| commitment.to_bytes().to_vec())
+                .unwrap_or_default(),
         }
     }
 }
 
-impl From<&SwapPlaintext> for [u8; SWAP_LEN_BYTES] {
-    fn from(swap: &SwapPlaintext) -> [u8; SWAP_LEN_BYTES] {
-        let mut bytes = [0u8; SWAP_LEN_BYTES];
+impl From<&SwapPlaintext> for Vec<u8> {
+    fn from(swap: &SwapPlaintext) -> Vec<u8> {
+        let mut bytes = vec![0u8; SWAP_LEN_BYTES];
         bytes[0..64].copy_from_slice(&swap.trading_pair.to_bytes());
         bytes[64..80].copy_from_slice(&swap.delta_1_i.to_le_bytes());
         bytes[80..96].copy_from_slice(&swap.delta_2_i.to_le_bytes());
@@ -347,12 +439,18 @@ impl From<&SwapPlaintext> for [u8; SWAP_LEN_BYTES] {
         let pb_address = pb_keys::Address::from(swap.claim_address);
         bytes[144..224].copy_from_slice(&pb_address.inner);
         bytes[224..256].copy_from_slice(&swap.rseed.to_bytes());
+        match swap.claim_key_commitment {
+            Some(claim_key_commitment) => {
+                bytes[256..288].copy_from_slice(&claim_key_commitment.to_bytes())
+            }
+            None => bytes.truncate(LEGACY_SWAP_LEN_BYTES),
+        }
         bytes
     }
 }
 
-impl From<SwapPlaintext> for [u8; SWAP_LEN_BYTES] {
-    fn from(swap: SwapPlaintext) -> [u8; SWAP_LEN_BYTES] {
+impl From<SwapPlaintext> for Vec<u8> {
+    fn from(swap: SwapPlaintext) -> Vec<u8> {
         (&swap).into()
     }
 }
@@ -361,7 +459,7 @@ impl TryFrom<&[u8]> for SwapPlaintext {
     type Error = Error;
 
     fn try_from(bytes: &[u8]) -> Result<Self, Self::Error> {
-        if bytes.len() != SWAP_LEN_BYTES {
+        if bytes.len() != SWAP_LEN_BYTES && bytes.len() != LEGACY_SWAP_LEN_BYTES {
             anyhow::bail!("incorrect length for serialized swap plaintext");
         }
 
@@ -390,6 +488,10 @@ impl TryFrom<&[u8]> for SwapPlaintext {
         let rseed: [u8; 32] = bytes[224..256]
             .try_into()
             .map_err(|_| anyhow!("error fetching rseed bytes"))?;
+        let claim_key_commitment = bytes
+            .get(256..288)
+            .map(ClaimKeyCommitment::try_from)
+            .transpose()?;
 
         Ok(SwapPlaintext {
             trading_pair: tp_bytes
@@ -403,6 +505,7 @@ impl TryFrom<&[u8]> for SwapPlaintext {
             }),
             claim_address: pb_address.try_into()?,
             rseed: Rseed(rseed),
+            claim_key_commitment,
         })
     }
 }
@@ -421,6 +524,7 @@ mod tests {
     use rand_core::OsRng;
 
     use super::*;
+    use crate::swap::LEGACY_SWAP_CIPHERTEXT_BYTES;
     use penumbra_asset::{asset, Value};
     use penumbra_keys::keys::{Bip44Path, SeedPhrase, SpendKey};
 
@@ -459,6 +563,7 @@ mod tests {
                     .id(),
             }),
             dest,
+            fvk.nullifier_key(),
         );
 
         let ciphertext = swap.encrypt(ovk).encrypted_swap;
@@ -467,4 +572,105 @@ mod tests {
 
         assert_eq!(plaintext, swap);
     }
+
+    #[test]
+    /// Check the swap can only be claimed with the claim key it was created with.
+    fn swap_claim_key_binding() {
+        let mut rng = OsRng;
+
+        let sk = SpendKey::from_seed_phrase_bip44(SeedPhrase::generate(rng), &Bip44Path::new(0));
+        let fvk = sk.full_viewing_key();
+        let (dest, _dtk_d) = fvk.incoming().payment_address(0u32.into());
+        let other_sk =
+            SpendKey::from_seed_phrase_bip44(SeedPhrase::generate(rng), &Bip44Path::new(0));
+        let other_fvk = other_sk.full_viewing_key();
+
+        let trading_pair = TradingPair::new(
+            asset::Cache::with_known_assets()
+                .get_unit("upenumbra")
+                .unwrap()
+                .id(),
+            asset::Cache::with_known_assets()
+                .get_unit("nala")
+                .unwrap()
+                .id(),
+        );
+        let swap = SwapPlaintext::new(
+            &mut rng,
+            trading_pair,
+            100000u64.into(),
+            1u64.into(),
+            Fee::default(),
+            dest,
+            fvk.nullifier_key(),
+        );
+
+        let claim_key = swap.claim_key(fvk.nullifier_key());
+        assert!(swap.is_claimable_with(&claim_key));
+        assert!(!swap.is_claimable_with(fvk.nullifier_key()));
+        assert!(!swap.is_claimable_with(&swap.claim_key(other_fvk.nullifier_key())));
+    }
+
+    #[test]
+    /// Check swaps created before claim keys were introduced still decrypt and commit as before.
+    fn legacy_swap_encryption_and_decryption() {
+        let mut rng = OsRng;
+
+        let sk = SpendKey::from_seed_phrase_bip44(SeedPhrase::generate(rng), &Bip44Path::new(0));
+        let fvk = sk.full_viewing_key();
+        let ovk = fvk.outgoing();
+        let (dest, _dtk_d) = fvk.incoming().payment_address(0u32.into());
+        let trading_pair = TradingPair::new(
+            asset::Cache::with_known_assets()
+                .get_unit("upenumbra")
+                .unwrap()
+                .id(),
+            asset::Cache::with_known_assets()
+                .get_unit("nala")
+                .unwrap()
+                .id(),
+        );
+        let mut swap = SwapPlaintext::new(
+            &mut rng,
+            trading_pair,
+            100000u64.into(),
+            1u64.into(),
+            Fee::default(),
+            dest,
+            fvk.nullifier_key(),
+        );
+        swap.claim_key_commitment = None;
+
+        let swap_data = hash_4(
+            &DOMAIN_SEPARATOR,
+            (
+                trading_pair.asset_1().0,
+                trading_pair.asset_2().0,
+                swap.delta_1_i.into(),
+                swap.delta_2_i.into(),
+            ),
+        );
+        let legacy_commitment = hash_7(
+            &DOMAIN_SEPARATOR,
+            (
+                Fq::from_le_bytes_mod_order(&swap.rseed.to_bytes()[..]),
+                swap.claim_fee.0.amount.into(),
+                swap.claim_fee.0.asset_id.0,
+                dest.diversified_generator().vartime_compress_to_field(),
+                *dest.transmission_key_s(),
+                Fq::from_le_bytes_mod_order(&dest.clue_key().0[..]),
+                swap_data,
+            ),
+        );
+        assert_eq!(swap.swap_commitment(), StateCommitment(legacy_commitment));
+
+        let ciphertext = swap.encrypt(ovk).encrypted_swap;
+        assert_eq!(ciphertext.0.len(), LEGACY_SWAP_CIPHERTEXT_BYTES);
+        let plaintext = SwapCiphertext::decrypt(&ciphertext, ovk, swap.swap_commitment())
+            .expect("can decrypt legacy swap");
+        assert_eq!(plaintext, swap);
+
+        // Legacy swaps are claimed with the swapper's nullifier key.
+        assert_eq!(swap.claim_key(fvk.nullifier_key()), *fvk.nullifier_key());
+    }
 }
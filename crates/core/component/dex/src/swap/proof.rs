@@ -25,7 +25,7 @@ use penumbra_keys::{keys::Diversifier, Address};
 use penumbra_shielded_pool::Rseed;
 
 use crate::{
-    swap::{SwapPlaintext, SwapPlaintextVar},
+    swap::{ClaimKeyCommitment, SwapPlaintext, SwapPlaintextVar},
     TradingPair,
 };
 
@@ -60,6 +60,10 @@ fn check_satisfaction(public: &SwapProofPublic, private: &SwapProofPrivate) -> R
         anyhow::bail!("swap commitment did not match public input");
     }
 
+    if !private.swap_plaintext.has_claim_key() {
+        anyhow::bail!("swap did not commit to a claim key");
+    }
+
     let fee_balance = -Balance::from(private.swap_plaintext.claim_fee.0);
     let fee_commitment = fee_balance.commit(private.fee_blinding);
     if fee_commitment != public.fee_commitment {
@@ -122,6 +126,11 @@ impl ConstraintSynthesizer<Fq> for SwapCircuit {
         let swap_commitment = swap_plaintext_var.commit()?;
         claimed_swap_commitment.enforce_equal(&swap_commitment)?;
 
+        // New swaps must commit to a claim key.
+        swap_plaintext_var
+            .has_claim_key
+            .enforce_equal(&Boolean::TRUE)?;
+
         // Fee commitment integrity check
         let fee_balance = BalanceVar::from_negative_value_var(swap_plaintext_var.claim_fee.clone());
         let fee_commitment = fee_balance.commit(fee_blinding_var)?;
@@ -175,6 +184,7 @@ impl DummyWitness for SwapCircuit {
             }),
             claim_address: address,
             rseed: Rseed([1u8; 32]),
+            claim_key_commitment: Some(ClaimKeyCommitment(Fq::from(1u64))),
         };
 
         Self {
@@ -293,6 +303,7 @@ impl TryFrom<pb::ZkSwapProof> for SwapProof {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::swap::derive_claim_key;
     use ark_ff::PrimeField;
     use penumbra_asset::{Balance, Value};
     use penumbra_keys::keys::{Bip44Path, SeedPhrase, SpendKey};
@@ -329,6 +340,7 @@ mod tests {
                 claim_fee: fee,
                 claim_address,
                 rseed,
+                claim_key_commitment: Some(ClaimKeyCommitment::new(&derive_claim_key(fvk_trader.nullifier_key(), &rseed))),
             };
             let fee_commitment = swap_plaintext.claim_fee.commit(fee_blinding);
             let swap_commitment = swap_plaintext.swap_commitment();
@@ -364,6 +376,14 @@ mod tests {
             assert!(check_satisfaction(&public, &private).is_ok());
             assert!(check_circuit_satisfaction(public, private).is_ok());
         }
+
+        #[test]
+        fn swap_proof_without_claim_key((mut public, mut private) in arb_valid_swap_statement()) {
+            private.swap_plaintext.claim_key_commitment = None;
+            public.swap_commitment = private.swap_plaintext.swap_commitment();
+            assert!(check_satisfaction(&public, &private).is_err());
+            assert!(check_circuit_satisfaction(public, private).is_err());
+        }
     }
 
     prop_compose! {
@@ -391,6 +411,7 @@ mod tests {
                 claim_fee: fee,
                 claim_address,
                 rseed,
+                claim_key_commitment: Some(ClaimKeyCommitment::new(&derive_claim_key(fvk_trader.nullifier_key(), &rseed))),
             };
             let swap_commitment = swap_plaintext.swap_commitment();
 
@@ -32,6 +32,10 @@ pub struct SwapClaimPlan {
     pub proof_blinding_r: Fq,
     /// The second blinding factor used for generating the ZK proof.
     pub proof_blinding_s: Fq,
+    /// The key authorizing the claim, if the swap is claimed on behalf of someone else.
+    ///
+    /// Otherwise, the claim key is derived from the full viewing key building the claim.
+    pub claim_key: Option<NullifierKey>,
 }
 
 impl SwapClaimPlan {
@@ -44,17 +48,23 @@ impl SwapClaimPlan {
     ) -> SwapClaim {
         SwapClaim {
             body: self.swap_claim_body(fvk),
-            proof: self.swap_claim_proof(state_commitment_proof, fvk.nullifier_key()),
+            proof: self.swap_claim_proof(state_commitment_proof, &self.claim_key(fvk)),
             epoch_duration: self.epoch_duration,
         }
     }
 
+    /// The key authorizing this claim, which derives the nullifier of the swap.
+    pub fn claim_key(&self, fvk: &FullViewingKey) -> NullifierKey {
+        self.claim_key
+            .unwrap_or_else(|| self.swap_plaintext.claim_key(fvk.nullifier_key()))
+    }
+
     /// Construct the [`SwapClaimProof`] required by the [`swap_claim::Body`] described
-    /// by this plan.
+    /// by this plan, using the swap's claim key.
     pub fn swap_claim_proof(
         &self,
         state_commitment_proof: &tct::Proof,
-        nk: &NullifierKey,
+        claim_key: &NullifierKey,
     ) -> SwapClaimProof {
         let (lambda_1, lambda_2) = self
             .output_data
@@ -66,8 +76,11 @@ impl SwapClaimPlan {
         let note_commitment_1 = output_1_note.commit();
         let note_commitment_2 = output_2_note.commit();
 
-        let nullifier =
-            Nullifier::derive(nk, self.position, &self.swap_plaintext.swap_commitment());
+        let nullifier = Nullifier::derive(
+            claim_key,
+            self.position,
+            &self.swap_plaintext.swap_commitment(),
+        );
         SwapClaimProof::prove(
             self.proof_blinding_r,
             self.proof_blinding_s,
@@ -83,7 +96,7 @@ impl SwapClaimPlan {
             SwapClaimProofPrivate {
                 swap_plaintext: self.swap_plaintext.clone(),
                 state_commitment_proof: state_commitment_proof.clone(),
-                nk: *nk,
+                claim_key: *claim_key,
                 lambda_1,
                 lambda_2,
                 note_blinding_1,
@@ -103,7 +116,7 @@ impl SwapClaimPlan {
         let output_2_commitment = output_2_note.commit();
 
         let nullifier = Nullifier::derive(
-            fvk.nullifier_key(),
+            &self.claim_key(fvk),
             self.position,
             &self.swap_plaintext.swap_commitment(),
         );
@@ -147,6 +160,10 @@ impl From<SwapClaimPlan> for pb::SwapClaimPlan {
             epoch_duration: msg.epoch_duration,
             proof_blinding_r: msg.proof_blinding_r.to_bytes().to_vec(),
             proof_blinding_s: msg.proof_blinding_s.to_bytes().to_vec(),
+            claim_key: msg
+                .claim_key
+                .map(|claim_key| claim_key.0.to_bytes().to_vec())
+                .unwrap_or_default(),
         }
     }
 }
@@ -176,6 +193,15 @@ impl TryFrom<pb::SwapClaimPlan> for SwapClaimPlan {
             epoch_duration: msg.epoch_duration,
             proof_blinding_r: Fq::from_bytes(proof_blinding_r_bytes)?,
             proof_blinding_s: Fq::from_bytes(proof_blinding_s_bytes)?,
+            claim_key: if msg.claim_key.is_empty() {
+                None
+            } else {
+                let claim_key_bytes: [u8; 32] = msg
+                    .claim_key
+                    .try_into()
+                    .map_err(|_| anyhow::anyhow!("malformed claim key in `SwapClaimPlan`"))?;
+                Some(NullifierKey(Fq::from_bytes(claim_key_bytes)?))
+            },
         })
     }
 }
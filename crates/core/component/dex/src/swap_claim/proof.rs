@@ -29,7 +29,7 @@ use tct::{Root, StateCommitment};
 
 use crate::{
     batch_swap_output_data::BatchSwapOutputDataVar,
-    swap::{SwapPlaintext, SwapPlaintextVar},
+    swap::{derive_claim_key, ClaimKeyCommitment, SwapPlaintext, SwapPlaintextVar},
     BatchSwapOutputData, TradingPair,
 };
 
@@ -59,8 +59,8 @@ pub struct SwapClaimProofPrivate {
     pub swap_plaintext: SwapPlaintext,
     /// Inclusion proof for the swap commitment
     pub state_commitment_proof: tct::Proof,
-    // The nullifier deriving key for the Swap NFT note.
-    pub nk: NullifierKey,
+    /// The key authorizing the claim, which derives the swap's nullifier
+    pub claim_key: NullifierKey,
     /// Output amount 1
     pub lambda_1: Amount,
     /// Output amount 2
@@ -83,8 +83,12 @@ fn check_satisfaction(
 
     private.state_commitment_proof.verify(public.anchor)?;
 
+    if !private.swap_plaintext.is_claimable_with(&private.claim_key) {
+        anyhow::bail!("claim key did not match the swap's claim key commitment");
+    }
+
     let nullifier = Nullifier::derive(
-        &private.nk,
+        &private.claim_key,
         private.state_commitment_proof.position(),
         &swap_commitment,
     );
@@ -176,7 +180,8 @@ impl ConstraintSynthesizer<Fq> for SwapClaimCircuit {
         let merkle_path_var = tct::r1cs::MerkleAuthPathVar::new_witness(cs.clone(), || {
             Ok(self.private.state_commitment_proof)
         })?;
-        let nk_var = NullifierKeyVar::new_witness(cs.clone(), || Ok(self.private.nk))?;
+        let claim_key_var =
+            NullifierKeyVar::new_witness(cs.clone(), || Ok(self.private.claim_key))?;
         let lambda_1_i_var = AmountVar::new_witness(cs.clone(), || Ok(self.private.lambda_1))?;
         let lambda_2_i_var = AmountVar::new_witness(cs.clone(), || Ok(self.private.lambda_2))?;
         let note_blinding_1 = FqVar::new_witness(cs.clone(), || Ok(self.private.note_blinding_1))?;
@@ -207,8 +212,15 @@ impl ConstraintSynthesizer<Fq> for SwapClaimCircuit {
             claimed_swap_commitment.inner(),
         )?;
 
+        // Claim key integrity. Ensure the claim key is the one the swap commits to, if
+        // it commits to one.
+        swap_plaintext_var
+            .claim_key_commitment
+            .conditional_enforce_opens_to(&claim_key_var, &swap_plaintext_var.has_claim_key)?;
+
         // Nullifier integrity.
-        let nullifier_var = NullifierVar::derive(&nk_var, &position_var, &claimed_swap_commitment)?;
+        let nullifier_var =
+            NullifierVar::derive(&claim_key_var, &position_var, &claimed_swap_commitment)?;
         nullifier_var.enforce_equal(&claimed_nullifier_var)?;
 
         // Fee consistency check.
@@ -282,6 +294,8 @@ impl DummyWitness for SwapClaimCircuit {
         let ivk_sender = fvk_sender.incoming();
         let (address, _dtk_d) = ivk_sender.payment_address(0u32.into());
         let nk = *sk_sender.nullifier_key();
+        let rseed = Rseed([1u8; 32]);
+        let claim_key = derive_claim_key(&nk, &rseed);
 
         let delta_1_i = 10u64.into();
         let delta_2_i = 1u64.into();
@@ -297,7 +311,8 @@ impl DummyWitness for SwapClaimCircuit {
                     .id(),
             }),
             claim_address: address,
-            rseed: Rseed([1u8; 32]),
+            rseed,
+            claim_key_commitment: Some(ClaimKeyCommitment::new(&claim_key)),
         };
         let mut sct = tct::Tree::new();
         let swap_commitment = swap_plaintext.swap_commitment();
@@ -337,7 +352,7 @@ impl DummyWitness for SwapClaimCircuit {
         let private = SwapClaimProofPrivate {
             swap_plaintext,
             state_commitment_proof,
-            nk,
+            claim_key,
             lambda_1,
             lambda_2,
             note_blinding_1,
@@ -497,6 +512,7 @@ impl TryFrom<pb::ZkSwapClaimProof> for SwapClaimProof {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use ark_ff::PrimeField;
     use penumbra_keys::keys::{SeedPhrase, SpendKey};
     use penumbra_num::Amount;
     use proptest::prelude::*;
@@ -554,6 +570,7 @@ mod tests {
         rseed_randomness: [u8; 32],
         value1_amount: u64,
         test_bsod: TestBatchSwapOutputData,
+        with_claim_key: bool,
     ) -> (SwapClaimProofPublic, SwapClaimProofPrivate) {
         let seed_phrase = SeedPhrase::from_randomness(&seed_phrase_randomness);
         let sk_recipient = SpendKey::from_seed_phrase_bip44(seed_phrase, &Bip44Path::new(0));
@@ -571,6 +588,14 @@ mod tests {
         let fee = Fee::default();
 
         let rseed = Rseed(rseed_randomness);
+        // Swaps created before claim keys were introduced are claimed with the
+        // swapper's nullifier key.
+        let (claim_key, claim_key_commitment) = if with_claim_key {
+            let claim_key = derive_claim_key(&nk, &rseed);
+            (claim_key, Some(ClaimKeyCommitment::new(&claim_key)))
+        } else {
+            (nk, None)
+        };
         let swap_plaintext = SwapPlaintext {
             trading_pair,
             delta_1_i,
@@ -578,6 +603,7 @@ mod tests {
             claim_fee: fee,
             claim_address,
             rseed,
+            claim_key_commitment,
         };
         let fee = swap_plaintext.clone().claim_fee;
         let mut sct = tct::Tree::new();
@@ -586,7 +612,7 @@ mod tests {
         let anchor = sct.root();
         let state_commitment_proof = sct.witness(swap_commitment).unwrap();
         let position = state_commitment_proof.position();
-        let nullifier = Nullifier::derive(&nk, position, &swap_commitment);
+        let nullifier = Nullifier::derive(&claim_key, position, &swap_commitment);
         let epoch_duration = 20;
         let height = epoch_duration * position.epoch() + position.block();
 
@@ -621,7 +647,7 @@ mod tests {
         let private = SwapClaimProofPrivate {
             swap_plaintext,
             state_commitment_proof,
-            nk,
+            claim_key,
             lambda_1,
             lambda_2,
             note_blinding_1,
@@ -633,7 +659,7 @@ mod tests {
 
     prop_compose! {
         fn arb_valid_swapclaim_statement_filled()(seed_phrase_randomness in any::<[u8; 32]>(), rseed_randomness in any::<[u8; 32]>(), value1_amount in 2..200u64, test_bsod in filled_bsod_strategy()) -> (SwapClaimProofPublic, SwapClaimProofPrivate) {
-            swapclaim_statement(seed_phrase_randomness, rseed_randomness, value1_amount, test_bsod)
+            swapclaim_statement(seed_phrase_randomness, rseed_randomness, value1_amount, test_bsod, true)
         }
     }
 
@@ -645,6 +671,35 @@ mod tests {
         }
     }
 
+    prop_compose! {
+        fn arb_valid_legacy_swapclaim_statement()(seed_phrase_randomness in any::<[u8; 32]>(), rseed_randomness in any::<[u8; 32]>(), value1_amount in 2..200u64, test_bsod in filled_bsod_strategy()) -> (SwapClaimProofPublic, SwapClaimProofPrivate) {
+            swapclaim_statement(seed_phrase_randomness, rseed_randomness, value1_amount, test_bsod, false)
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn swap_claim_proof_happy_path_legacy((public, private) in arb_valid_legacy_swapclaim_statement()) {
+            assert!(check_satisfaction(&public, &private).is_ok());
+            assert!(check_circuit_satisfaction(public, private).is_ok());
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn swap_claim_proof_wrong_claim_key((mut public, mut private) in arb_valid_swapclaim_statement_filled(), other_claim_key in any::<[u8; 32]>()) {
+            // A consistent nullifier derived from a key the swap doesn't commit to.
+            private.claim_key = NullifierKey(Fq::from_le_bytes_mod_order(&other_claim_key));
+            public.nullifier = Nullifier::derive(
+                &private.claim_key,
+                private.state_commitment_proof.position(),
+                &private.swap_plaintext.swap_commitment(),
+            );
+            assert!(check_satisfaction(&public, &private).is_err());
+            assert!(check_circuit_satisfaction(public, private).is_err());
+        }
+    }
+
     fn unfilled_bsod_strategy() -> BoxedStrategy<TestBatchSwapOutputData> {
         let delta_1: Amount = 0u64.into();
         let delta_2 = (4001..2000000000u128).prop_map(Amount::from);
@@ -670,7 +725,7 @@ mod tests {
 
     prop_compose! {
         fn arb_valid_swapclaim_statement_unfilled()(seed_phrase_randomness in any::<[u8; 32]>(), rseed_randomness in any::<[u8; 32]>(), value1_amount in 2..200u64, test_bsod in unfilled_bsod_strategy()) -> (SwapClaimProofPublic, SwapClaimProofPrivate) {
-            swapclaim_statement(seed_phrase_randomness, rseed_randomness, value1_amount, test_bsod)
+            swapclaim_statement(seed_phrase_randomness, rseed_randomness, value1_amount, test_bsod, true)
         }
     }
 
@@ -701,6 +756,7 @@ mod tests {
         let fee = Fee::default();
 
         let rseed = Rseed(rseed_randomness);
+        let claim_key = derive_claim_key(&nk, &rseed);
         let swap_plaintext = SwapPlaintext {
             trading_pair,
             delta_1_i,
@@ -708,6 +764,7 @@ mod tests {
             claim_fee: fee,
             claim_address,
             rseed,
+            claim_key_commitment: Some(ClaimKeyCommitment::new(&claim_key)),
         };
         let incorrect_fee = Fee::from_staking_token_amount(Amount::from(fee_amount));
         let mut sct = tct::Tree::new();
@@ -716,7 +773,7 @@ mod tests {
         let anchor = sct.root();
         let state_commitment_proof = sct.witness(swap_commitment).unwrap();
         let position = state_commitment_proof.position();
-        let nullifier = Nullifier::derive(&nk, position, &swap_commitment);
+        let nullifier = Nullifier::derive(&claim_key, position, &swap_commitment);
         let epoch_duration = 20;
         let height = epoch_duration * position.epoch() + position.block();
 
@@ -751,7 +808,7 @@ mod tests {
         let private = SwapClaimProofPrivate {
             swap_plaintext,
             state_commitment_proof,
-            nk,
+            claim_key,
             lambda_1,
             lambda_2,
             note_blinding_1,
@@ -790,6 +847,7 @@ mod tests {
         let fee = Fee::default();
 
         let rseed = Rseed(rseed_randomness);
+        let claim_key = derive_claim_key(&nk, &rseed);
         let swap_plaintext = SwapPlaintext {
             trading_pair,
             delta_1_i,
@@ -797,6 +855,7 @@ mod tests {
             claim_fee: fee,
             claim_address,
             rseed,
+            claim_key_commitment: Some(ClaimKeyCommitment::new(&claim_key)),
         };
         let incorrect_fee = Fee::from_staking_token_amount(Amount::from(fee_amount));
         let mut sct = tct::Tree::new();
@@ -805,7 +864,7 @@ mod tests {
         let anchor = sct.root();
         let state_commitment_proof = sct.witness(swap_commitment).unwrap();
         let position = state_commitment_proof.position();
-        let nullifier = Nullifier::derive(&nk, position, &swap_commitment);
+        let nullifier = Nullifier::derive(&claim_key, position, &swap_commitment);
 
         // End the block, and then add a dummy commitment that we'll use
         // to compute the position and block height that the BSOD corresponds to.
@@ -849,7 +908,7 @@ mod tests {
         let private = SwapClaimProofPrivate {
             swap_plaintext,
             state_commitment_proof,
-            nk,
+            claim_key,
             lambda_1,
             lambda_2,
             note_blinding_1,
@@ -80,25 +80,13 @@ trait DistributionManager: StateWriteExt {
             "calculating issuance per epoch"
         );
 
-        let new_issuance_for_epoch = staking_issuance_per_block
-            .checked_mul(num_blocks as u128) /* Safe to cast a `u64` to `u128` */
-            .expect("infaillible unless issuance is pathological");
-
-        tracing::debug!(
-            ?new_issuance_for_epoch,
-            "computed new issuance for epoch (pre-scaled)"
-        );
-
-        let new_issuance_for_epoch = STAKING_TOKEN_DENOM
-            .default_unit()
-            .value(new_issuance_for_epoch.into())
-            .amount;
+        let new_issuance_for_epoch = staking_issuance(staking_issuance_per_block, num_blocks);
 
         tracing::debug!(
             ?new_issuance_for_epoch,
             "computed new issuance for epoch (scaled)"
         );
-        Ok(Amount::from(new_issuance_for_epoch))
+        Ok(new_issuance_for_epoch)
     }
 
     /// Update the object store with the new issuance of staking tokens for this epoch.
@@ -108,3 +96,16 @@ trait DistributionManager: StateWriteExt {
 }
 
 impl<T: StateWrite + ?Sized> DistributionManager for T {}
+
+/// The staking token issuance over the given number of blocks, scaled to the
+/// base unit of the staking token.
+fn staking_issuance(staking_issuance_per_block: u128, num_blocks: u64) -> Amount {
+    let issuance = staking_issuance_per_block
+        .checked_mul(num_blocks as u128) /* Safe to cast a `u64` to `u128` */
+        .expect("infaillible unless issuance is pathological");
+
+    STAKING_TOKEN_DENOM
+        .default_unit()
+        .value(issuance.into())
+        .amount
+}
@@ -1,3 +1,7 @@
+/// The prefixes of all of the component's state keys, for the application's
+/// state key registry.
+pub const PREFIXES: &[&str] = &["distributions/"];
+
 // The amount of staking tokens issued for this epoch.
 pub fn staking_token_issuance_for_epoch() -> &'static str {
     "distributions/staking_token_issuance_for_epoch"
@@ -5,6 +5,7 @@ use anyhow::Result;
 use cnidarium::{StateRead, StateWrite};
 use penumbra_num::Amount;
 use penumbra_proto::{StateReadProto, StateWriteProto};
+use penumbra_sct::component::StateReadExt as _;
 
 #[async_trait]
 pub trait StateReadExt: StateRead {
@@ -24,6 +25,24 @@ pub trait StateReadExt: StateRead {
     fn get_staking_token_issuance_for_epoch(&self) -> Option<Amount> {
         self.object_get(&state_key::staking_token_issuance_for_epoch())
     }
+
+    /// Estimates the staking token issuance for the current epoch, assuming
+    /// that it lasts for the full epoch duration.
+    ///
+    /// The actual issuance is only computed once the epoch ends.
+    async fn projected_staking_token_issuance_for_epoch(&self) -> Result<Amount> {
+        let staking_issuance_per_block = self
+            .get_distributions_params()
+            .await?
+            .staking_issuance_per_block as u128;
+        // An epoch ends at the block `epoch_duration - 1` blocks after its start, and the issuance
+        // is computed from that difference.
+        let num_blocks = self.get_epoch_duration_parameter().await?.saturating_sub(1);
+        Ok(super::staking_issuance(
+            staking_issuance_per_block,
+            num_blocks,
+        ))
+    }
 }
 
 impl<T: StateRead + ?Sized> StateReadExt for T {}
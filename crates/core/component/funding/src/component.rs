@@ -1,11 +1,12 @@
+mod event;
 pub mod metrics;
-mod state_key;
+pub mod state_key;
 pub mod view;
 use ::metrics::{gauge, histogram};
 pub use metrics::register_metrics;
 
 /* Component implementation */
-use penumbra_asset::{Value, STAKING_TOKEN_ASSET_ID};
+use penumbra_asset::STAKING_TOKEN_ASSET_ID;
 use penumbra_stake::component::validator_handler::ValidatorDataRead;
 pub use view::{StateReadExt, StateWriteExt};
 
@@ -60,6 +61,7 @@ impl Component for Funding {
         use penumbra_sct::CommitmentSource;
         use penumbra_shielded_pool::component::NoteManager;
         use penumbra_stake::funding_stream::Recipient;
+        use penumbra_stake::validator::State;
         use penumbra_stake::StateReadExt as _;
 
         let state = Arc::get_mut(state).expect("state should be unique");
@@ -95,6 +97,19 @@ impl Component for Funding {
                 continue;
             };
 
+            // The rewards of funding streams which opted in are re-delegated to the validator's
+            // pool, at the rate a delegation in the upcoming epoch would get, as long as it can
+            // still be delegated to. The compounded delegation tokens count towards the
+            // validator's voting power from the following epoch on.
+            let compounding_rate = if matches!(
+                state.get_validator_state(&validator_identity).await?,
+                Some(State::Defined | State::Inactive | State::Active)
+            ) {
+                state.get_validator_rate(&validator_identity).await?
+            } else {
+                None
+            };
+
             for stream in funding_streams {
                 // We compute the reward amount for this specific funding stream, it is based
                 // on the ending epoch's rate data.
@@ -107,15 +122,23 @@ impl Component for Funding {
                 total_staking_rewards_for_epoch = total_staking_rewards_for_epoch
                     .saturating_add(reward_amount_for_stream.value());
 
+                let reward =
+                    stream.reward_value(reward_amount_for_stream, compounding_rate.as_ref());
+                if reward.asset_id != *STAKING_TOKEN_ASSET_ID {
+                    state.record(event::funding_stream_reward_compounded(
+                        &validator_identity,
+                        base_rate.epoch_index,
+                        reward_amount_for_stream,
+                        reward.amount,
+                    ));
+                }
+
                 match stream.recipient() {
                     // If the recipient is an address, mint a note to that address
                     Recipient::Address(address) => {
                         state
                             .mint_note(
-                                Value {
-                                    amount: reward_amount_for_stream.into(),
-                                    asset_id: *STAKING_TOKEN_ASSET_ID,
-                                },
+                                reward,
                                 &address,
                                 CommitmentSource::FundingStreamReward {
                                     epoch_index: base_rate.epoch_index,
@@ -125,12 +148,7 @@ impl Component for Funding {
                     }
                     // If the recipient is the Community Pool, deposit the funds into the Community Pool
                     Recipient::CommunityPool => {
-                        state
-                            .community_pool_deposit(Value {
-                                amount: reward_amount_for_stream.into(),
-                                asset_id: *STAKING_TOKEN_ASSET_ID,
-                            })
-                            .await?;
+                        state.community_pool_deposit(reward).await?;
                     }
                 }
             }
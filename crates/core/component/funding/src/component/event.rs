@@ -0,0 +1,20 @@
+use penumbra_num::Amount;
+use penumbra_stake::IdentityKey;
+use tendermint::abci::{Event, EventAttributeIndexExt};
+
+pub fn funding_stream_reward_compounded(
+    validator: &IdentityKey,
+    epoch_index: u64,
+    unbonded_amount: Amount,
+    delegation_amount: Amount,
+) -> Event {
+    Event::new(
+        "funding_stream_reward_compounded",
+        [
+            ("validator", validator.to_string()).index(),
+            ("epoch", epoch_index.to_string()).index(),
+            ("unbonded_amount", unbonded_amount.to_string()).no_index(),
+            ("delegation_amount", delegation_amount.to_string()).no_index(),
+        ],
+    )
+}
@@ -1,3 +1,7 @@
+/// The prefixes of all of the component's state keys, for the application's
+/// state key registry.
+pub const PREFIXES: &[&str] = &["funding/"];
+
 pub fn funding_parameters() -> &'static str {
     "funding/parameters"
 }
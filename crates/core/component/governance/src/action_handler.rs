@@ -1,5 +1,8 @@
 use cnidarium_component::ActionHandler;
 
+pub mod ballot_key_complaint;
+pub mod ballot_key_deal;
+pub mod ballot_key_reveal;
 pub mod delegator_vote;
 pub mod deposit_claim;
 pub mod validator_vote;
@@ -0,0 +1,386 @@
+use std::sync::Arc;
+
+use anyhow::{Context, Result};
+use async_trait::async_trait;
+use cnidarium::{StateRead, StateWrite};
+use penumbra_proto::{DomainType, StateWriteProto as _};
+
+use crate::{
+    action_handler::ActionHandler,
+    component::StateWriteExt,
+    event,
+    proposal_state::State as ProposalState,
+    sealed_ballot::{self, BallotKeyComplaint, BallotKeyComplaintBody},
+    StateReadExt,
+};
+
+#[async_trait]
+impl ActionHandler for BallotKeyComplaint {
+    type CheckStatelessContext = ();
+    async fn check_stateless(&self, _context: ()) -> Result<()> {
+        let BallotKeyComplaint { body, auth_sig } = self;
+
+        // Check the signature using the GOVERNANCE KEY:
+        let body_bytes = body.encode_to_vec();
+        body.governance_key
+            .0
+            .verify(&body_bytes, auth_sig)
+            .context("ballot key complaint signature failed to verify")?;
+
+        Ok(())
+    }
+
+    async fn check_stateful<S: StateRead + 'static>(&self, state: Arc<S>) -> Result<()> {
+        let BallotKeyComplaint {
+            body:
+                BallotKeyComplaintBody {
+                    proposal,
+                    identity_key,
+                    governance_key,
+                    dealer,
+                    position,
+                    shared_secret,
+                    proof,
+                },
+            auth_sig: _, // We already checked this in stateless verification
+        } = self;
+
+        if state.proposal_state(*proposal).await? != Some(ProposalState::Voting) {
+            anyhow::bail!("proposal {} is not being voted on", proposal);
+        }
+        let setup_end = state.ballot_key_setup_end(*proposal).await?;
+        if state.get_block_height().await? > setup_end {
+            anyhow::bail!(
+                "the ballot key for proposal {} could only be complained about until block {}",
+                proposal,
+                setup_end
+            );
+        }
+
+        let allocation = state.ballot_key_share_allocation(*proposal).await?;
+        let Some(positions) = allocation.positions(identity_key) else {
+            anyhow::bail!(
+                "validator {} was not active at the start of proposal {}",
+                identity_key,
+                proposal
+            );
+        };
+        if !positions.contains(position) {
+            anyhow::bail!(
+                "share position {} is not allocated to validator {}",
+                position,
+                identity_key
+            );
+        }
+        state
+            .check_governance_key_matches_validator(identity_key, governance_key)
+            .await?;
+
+        let Some(deal) = state.ballot_key_deals(*proposal).await?.remove(dealer) else {
+            anyhow::bail!(
+                "validator {} did not deal the ballot key for proposal {}",
+                dealer,
+                proposal
+            );
+        };
+        if state
+            .ballot_key_complaints(*proposal)
+            .await?
+            .contains_key(dealer)
+        {
+            anyhow::bail!(
+                "the ballot key deal of validator {} for proposal {} was already complained about",
+                dealer,
+                proposal
+            );
+        }
+
+        // The deal handler checked that there is an encrypted share for every position.
+        let encrypted_share = deal.body.encrypted_shares[*position];
+        proof
+            .verify(
+                governance_key,
+                encrypted_share.ephemeral_key,
+                *shared_secret,
+            )
+            .context("ballot key complaint has an invalid shared secret")?;
+        let valid = encrypted_share
+            .decrypt_with_shared_secret(*proposal, *shared_secret)
+            .map(|share| {
+                sealed_ballot::verify_share(
+                    &deal.body.commitments,
+                    sealed_ballot::share_index(*position),
+                    &share,
+                )
+            })
+            .unwrap_or(false);
+        if valid {
+            anyhow::bail!(
+                "share {} dealt by validator {} matches its commitments",
+                position,
+                dealer
+            );
+        }
+
+        Ok(())
+    }
+
+    async fn execute<S: StateWrite>(&self, mut state: S) -> Result<()> {
+        tracing::debug!(validator_identity = %self.body.identity_key, dealer = %self.body.dealer, proposal = %self.body.proposal, "complained about ballot key deal");
+        state.put_ballot_key_complaint(self.clone());
+        state.record_proto(event::ballot_key_complaint(self));
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use ark_ff::UniformRand;
+    use cnidarium::{StateDelta, TempStorage};
+    use decaf377::Fr;
+    use decaf377_rdsa::{SigningKey, SpendAuth};
+    use penumbra_sct::component::clock::EpochManager as _;
+    use penumbra_stake::{validator::Validator, GovernanceKey, IdentityKey};
+    use rand_core::OsRng;
+
+    use super::*;
+    use crate::{
+        params::GovernanceParameters,
+        sealed_ballot::{
+            BallotKey, BallotKeyDeal, BallotKeyDealBody, BallotKeyReveal, BallotKeyRevealBody,
+            EncryptedShare, ShareAllocation, SharedSecretProof,
+        },
+        state_key, Proposal, ProposalPayload,
+    };
+
+    const PROPOSAL: u64 = 0;
+
+    /// Check and execute an action against the latest state, committing its effects.
+    async fn apply<A: ActionHandler<CheckStatelessContext = ()>>(
+        storage: &TempStorage,
+        action: &A,
+    ) -> Result<()> {
+        action.check_stateless(()).await?;
+        action
+            .check_stateful(Arc::new(storage.latest_snapshot()))
+            .await?;
+        let mut state = StateDelta::new(storage.latest_snapshot());
+        action.execute(&mut state).await?;
+        storage.commit(state).await?;
+        Ok(())
+    }
+
+    async fn set_block_height(storage: &TempStorage, height: u64) -> Result<()> {
+        let mut state = StateDelta::new(storage.latest_snapshot());
+        state.put_block_height(height);
+        storage.commit(state).await?;
+        Ok(())
+    }
+
+    /// A proposal with sealed ballots, voted on from block 1 to block 100 by three validators
+    /// with equal voting power, whose ballot key is dealt until block 5 and complained about
+    /// until block 10.
+    async fn setup(storage: &TempStorage) -> Result<Vec<(IdentityKey, SigningKey<SpendAuth>)>> {
+        let mut state = StateDelta::new(storage.latest_snapshot());
+        state.put_governance_params(GovernanceParameters {
+            sealed_ballot_setup_blocks: 10,
+            ..Default::default()
+        });
+        state.put(
+            state_key::proposal_definition(PROPOSAL),
+            Proposal {
+                id: PROPOSAL,
+                title: "sealed".to_string(),
+                description: String::new(),
+                sealed_ballots: true,
+                payload: ProposalPayload::Signaling { commit: None },
+            },
+        );
+        state.put_proposal_state(PROPOSAL, ProposalState::Voting);
+        state.put_proposal_voting_start(PROPOSAL, 1);
+        state.put_proposal_voting_end(PROPOSAL, 100);
+
+        // The ed25519 basepoint, which is a valid consensus key.
+        let mut consensus_key = [0x66; 32];
+        consensus_key[0] = 0x58;
+        let mut validators = Vec::new();
+        for _ in 0..3 {
+            let signing_key = SigningKey::<SpendAuth>::new(OsRng);
+            let identity_key = IdentityKey((&signing_key).into());
+            state.put(
+                penumbra_stake::state_key::validators::definitions::by_id(&identity_key),
+                Validator {
+                    identity_key,
+                    governance_key: GovernanceKey((&signing_key).into()),
+                    consensus_key: tendermint::PublicKey::from_raw_ed25519(&consensus_key)
+                        .expect("basepoint is a valid key"),
+                    name: String::new(),
+                    website: String::new(),
+                    description: String::new(),
+                    enabled: true,
+                    funding_streams: Default::default(),
+                    funding_streams_effective_epoch: None,
+                    sequence_number: 0,
+                },
+            );
+            state.put(
+                state_key::voting_power_at_proposal_start(PROPOSAL, identity_key),
+                1u64,
+            );
+            validators.push((identity_key, signing_key));
+        }
+        validators.sort_by_key(|(identity_key, _)| *identity_key);
+        state.put_block_height(2);
+        storage.commit(state).await?;
+
+        Ok(validators)
+    }
+
+    fn deal(
+        (identity_key, signing_key): &(IdentityKey, SigningKey<SpendAuth>),
+        allocation: &ShareAllocation,
+        governance_keys: &BTreeMap<IdentityKey, GovernanceKey>,
+    ) -> BallotKeyDeal {
+        let body = BallotKeyDealBody::deal(
+            &mut OsRng,
+            PROPOSAL,
+            *identity_key,
+            signing_key,
+            allocation,
+            governance_keys,
+        )
+        .expect("all governance keys are known");
+        let auth_sig = signing_key.sign(OsRng, &body.encode_to_vec());
+        BallotKeyDeal { body, auth_sig }
+    }
+
+    fn complaint(
+        signing_key: &SigningKey<SpendAuth>,
+        body: BallotKeyComplaintBody,
+    ) -> BallotKeyComplaint {
+        let auth_sig = signing_key.sign(OsRng, &body.encode_to_vec());
+        BallotKeyComplaint { body, auth_sig }
+    }
+
+    #[tokio::test]
+    async fn complaints_exclude_dealers_of_invalid_shares() -> Result<()> {
+        let storage = TempStorage::new().await?;
+        let validators = setup(&storage).await?;
+        let snapshot = storage.latest_snapshot();
+        let allocation = snapshot.ballot_key_share_allocation(PROPOSAL).await?;
+        let governance_keys: BTreeMap<_, _> = validators
+            .iter()
+            .map(|(identity_key, signing_key)| (*identity_key, GovernanceKey(signing_key.into())))
+            .collect();
+        let (honest, cheater, victim) = (&validators[0], &validators[1], &validators[2]);
+
+        // The cheating dealer encrypts garbage to one of the victim's share positions.
+        let position = allocation
+            .positions(&victim.0)
+            .expect("victim is active")
+            .start;
+        let mut cheat = deal(cheater, &allocation, &governance_keys);
+        cheat.body.encrypted_shares[position] = EncryptedShare::encrypt(
+            &mut OsRng,
+            PROPOSAL,
+            &Fr::rand(&mut OsRng),
+            &governance_keys[&victim.0],
+        )?;
+        cheat.auth_sig = cheater.1.sign(OsRng, &cheat.body.encode_to_vec());
+        let honest_deals = [
+            deal(honest, &allocation, &governance_keys),
+            deal(victim, &allocation, &governance_keys),
+        ];
+        for deal in honest_deals.iter().chain([&cheat]) {
+            apply(&storage, deal).await?;
+        }
+        assert!(apply(&storage, &honest_deals[0]).await.is_err());
+
+        // The victim can't falsely complain about an honest deal.
+        let encrypted_share = honest_deals[0].body.encrypted_shares[position];
+        let (shared_secret, proof) =
+            SharedSecretProof::prove(&mut OsRng, &victim.1, encrypted_share.ephemeral_key)?;
+        let false_complaint = complaint(
+            &victim.1,
+            BallotKeyComplaintBody {
+                proposal: PROPOSAL,
+                identity_key: victim.0,
+                governance_key: governance_keys[&victim.0],
+                dealer: honest.0,
+                position,
+                shared_secret,
+                proof,
+            },
+        );
+        assert!(apply(&storage, &false_complaint).await.is_err());
+
+        // The victim's complaint about the cheating deal is accepted once.
+        set_block_height(&storage, 7).await?;
+        let valid_complaint = complaint(
+            &victim.1,
+            BallotKeyComplaintBody::complain(
+                &mut OsRng,
+                victim.0,
+                &victim.1,
+                &allocation,
+                &cheat.body,
+            )?
+            .expect("the cheating deal has an invalid share"),
+        );
+        apply(&storage, &valid_complaint).await?;
+        assert!(apply(&storage, &valid_complaint).await.is_err());
+
+        // The ballot key is established without the cheating dealer.
+        let mut state = StateDelta::new(storage.latest_snapshot());
+        let ballot_key = state
+            .finalize_ballot_key(PROPOSAL)
+            .await?
+            .expect("two thirds of the voting power dealt validly");
+        storage.commit(state).await?;
+        assert_eq!(
+            ballot_key,
+            BallotKey::from_dealer_commitments(
+                honest_deals.iter().map(|deal| &deal.body.commitments[0])
+            )
+        );
+
+        // Once voting has closed, the honest dealer and the cheater reveal, and the victim
+        // withholds its secret. A corrupted share doesn't stop the reveal from being accepted,
+        // and the victim's secret is recovered from the remaining valid shares.
+        set_block_height(&storage, 101).await?;
+        let deals = storage
+            .latest_snapshot()
+            .qualified_ballot_key_deals(PROPOSAL)
+            .await?;
+        for (identity_key, signing_key) in [honest, cheater] {
+            let mut body = BallotKeyRevealBody::reveal(
+                PROPOSAL,
+                *identity_key,
+                signing_key,
+                &allocation,
+                deals.values(),
+            );
+            if identity_key == &cheater.0 {
+                let share = body
+                    .shares
+                    .iter_mut()
+                    .find(|share| share.dealer == victim.0)
+                    .expect("the cheater holds shares of the victim's deal");
+                share.share += Fr::from(1u64);
+            }
+            let auth_sig = signing_key.sign(OsRng, &body.encode_to_vec());
+            apply(&storage, &BallotKeyReveal { body, auth_sig }).await?;
+        }
+        let secret = storage
+            .latest_snapshot()
+            .recover_ballot_secret(PROPOSAL)
+            .await?
+            .expect("enough valid shares were revealed");
+        assert_eq!(secret * decaf377::basepoint(), ballot_key.0);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,123 @@
+use std::sync::Arc;
+
+use anyhow::{Context, Result};
+use async_trait::async_trait;
+use cnidarium::{StateRead, StateWrite};
+use penumbra_proto::{DomainType, StateWriteProto as _};
+
+use crate::{
+    action_handler::ActionHandler,
+    component::StateWriteExt,
+    event,
+    proposal_state::State as ProposalState,
+    sealed_ballot::{BallotKeyDeal, BallotKeyDealBody},
+    StateReadExt,
+};
+
+#[async_trait]
+impl ActionHandler for BallotKeyDeal {
+    type CheckStatelessContext = ();
+    async fn check_stateless(&self, _context: ()) -> Result<()> {
+        let BallotKeyDeal { body, auth_sig } = self;
+
+        // Check the signature using the GOVERNANCE KEY:
+        let body_bytes = body.encode_to_vec();
+        body.governance_key
+            .0
+            .verify(&body_bytes, auth_sig)
+            .context("ballot key deal signature failed to verify")?;
+
+        if body.commitments.is_empty() {
+            anyhow::bail!("ballot key deal must commit to at least one coefficient");
+        }
+        // A zero secret would let anyone who learns the other dealers' secrets open the ballots.
+        if body.commitments[0] == decaf377::Element::default() {
+            anyhow::bail!("ballot key deal must commit to a nonzero secret");
+        }
+
+        Ok(())
+    }
+
+    async fn check_stateful<S: StateRead + 'static>(&self, state: Arc<S>) -> Result<()> {
+        let BallotKeyDeal {
+            body:
+                BallotKeyDealBody {
+                    proposal,
+                    identity_key,
+                    governance_key,
+                    commitments,
+                    encrypted_shares,
+                },
+            auth_sig: _, // We already checked this in stateless verification
+        } = self;
+
+        let Some(definition) = state.proposal_definition(*proposal).await? else {
+            anyhow::bail!("proposal {} does not exist", proposal);
+        };
+        if !definition.sealed_ballots {
+            anyhow::bail!("proposal {} does not have sealed ballots", proposal);
+        }
+        if state.proposal_state(*proposal).await? != Some(ProposalState::Voting) {
+            anyhow::bail!("proposal {} is not being voted on", proposal);
+        }
+
+        let deal_end = state.ballot_key_deal_end(*proposal).await?;
+        if state.get_block_height().await? > deal_end {
+            anyhow::bail!(
+                "the ballot key for proposal {} could only be dealt until block {}",
+                proposal,
+                deal_end
+            );
+        }
+
+        let allocation = state.ballot_key_share_allocation(*proposal).await?;
+        if allocation.positions(identity_key).is_none() {
+            anyhow::bail!(
+                "validator {} was not active at the start of proposal {}",
+                identity_key,
+                proposal
+            );
+        }
+        if state
+            .ballot_key_deals(*proposal)
+            .await?
+            .contains_key(identity_key)
+        {
+            anyhow::bail!(
+                "validator {} has already dealt the ballot key for proposal {}",
+                identity_key,
+                proposal
+            );
+        }
+        state
+            .check_governance_key_matches_validator(identity_key, governance_key)
+            .await?;
+
+        // The shares themselves can only be checked by their recipients, who complain about
+        // those which don't match the commitments.
+        if commitments.len() != allocation.threshold() {
+            anyhow::bail!(
+                "ballot key deal commits to {} coefficients, but the threshold is {}",
+                commitments.len(),
+                allocation.threshold()
+            );
+        }
+        if encrypted_shares.len() != allocation.total() {
+            anyhow::bail!(
+                "ballot key deal has {} shares, but there are {} share positions",
+                encrypted_shares.len(),
+                allocation.total()
+            );
+        }
+
+        Ok(())
+    }
+
+    async fn execute<S: StateWrite>(&self, mut state: S) -> Result<()> {
+        tracing::debug!(validator_identity = %self.body.identity_key, proposal = %self.body.proposal, "dealt ballot key");
+        state.put_ballot_key_deal(self.clone());
+        state.record_proto(event::ballot_key_deal(self));
+
+        Ok(())
+    }
+}
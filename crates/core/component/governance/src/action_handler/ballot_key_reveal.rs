@@ -0,0 +1,146 @@
+use std::sync::Arc;
+
+use anyhow::{Context, Result};
+use async_trait::async_trait;
+use cnidarium::{StateRead, StateWrite};
+use penumbra_proto::{DomainType, StateWriteProto as _};
+
+use crate::{
+    action_handler::ActionHandler,
+    component::StateWriteExt,
+    event,
+    proposal_state::State as ProposalState,
+    sealed_ballot::{BallotKeyReveal, BallotKeyRevealBody},
+    StateReadExt,
+};
+
+#[async_trait]
+impl ActionHandler for BallotKeyReveal {
+    type CheckStatelessContext = ();
+    async fn check_stateless(&self, _context: ()) -> Result<()> {
+        let BallotKeyReveal { body, auth_sig } = self;
+
+        // Check the signature using the GOVERNANCE KEY:
+        let body_bytes = body.encode_to_vec();
+        body.governance_key
+            .0
+            .verify(&body_bytes, auth_sig)
+            .context("ballot key reveal signature failed to verify")?;
+
+        Ok(())
+    }
+
+    async fn check_stateful<S: StateRead + 'static>(&self, state: Arc<S>) -> Result<()> {
+        let BallotKeyReveal {
+            body:
+                BallotKeyRevealBody {
+                    proposal,
+                    identity_key,
+                    governance_key,
+                    dealer_secret,
+                    shares,
+                },
+            auth_sig: _, // We already checked this in stateless verification
+        } = self;
+
+        if state.proposal_state(*proposal).await? != Some(ProposalState::Voting) {
+            anyhow::bail!(
+                "proposal {} is not awaiting the opening of its ballots",
+                proposal
+            );
+        }
+        if state.ballot_key(*proposal).await?.is_none() {
+            anyhow::bail!("no ballot key was established for proposal {}", proposal);
+        }
+
+        let voting_end = state
+            .proposal_voting_end(*proposal)
+            .await?
+            .ok_or_else(|| anyhow::anyhow!("proposal {} does not exist", proposal))?;
+        let reveal_blocks = state
+            .get_governance_params()
+            .await?
+            .sealed_ballot_reveal_blocks;
+        let height = state.get_block_height().await?;
+        if height <= voting_end {
+            anyhow::bail!(
+                "the ballot key for proposal {} can't be revealed until voting ends at block {}",
+                proposal,
+                voting_end
+            );
+        }
+        if height > voting_end + reveal_blocks {
+            anyhow::bail!(
+                "the ballot key for proposal {} could only be revealed until block {}",
+                proposal,
+                voting_end + reveal_blocks
+            );
+        }
+
+        let allocation = state.ballot_key_share_allocation(*proposal).await?;
+        let Some(positions) = allocation.positions(identity_key) else {
+            anyhow::bail!(
+                "validator {} was not active at the start of proposal {}",
+                identity_key,
+                proposal
+            );
+        };
+        if state
+            .ballot_key_reveals(*proposal)
+            .await?
+            .contains_key(identity_key)
+        {
+            anyhow::bail!(
+                "validator {} has already revealed its ballot key secrets for proposal {}",
+                identity_key,
+                proposal
+            );
+        }
+        state
+            .check_governance_key_matches_validator(identity_key, governance_key)
+            .await?;
+
+        let deals = state.qualified_ballot_key_deals(*proposal).await?;
+        match (dealer_secret, deals.get(identity_key)) {
+            (None, _) => {}
+            (Some(secret), Some(deal)) => {
+                if *secret * decaf377::basepoint() != deal.body.commitments[0] {
+                    anyhow::bail!(
+                        "revealed secret of validator {} does not match its ballot key deal",
+                        identity_key
+                    );
+                }
+            }
+            (Some(_), None) => {
+                anyhow::bail!(
+                    "validator {} did not deal the ballot key for proposal {}",
+                    identity_key,
+                    proposal
+                );
+            }
+        }
+
+        // The revealed shares are checked against their dealers' commitments when the secret
+        // ballot key is recovered, which ignores those that don't match, so that a validator
+        // holding an invalid share can still reveal the rest.  We only bound how many there are.
+        let max_shares = positions.len() * deals.len();
+        if shares.len() > max_shares {
+            anyhow::bail!(
+                "ballot key reveal has {} shares, but validator {} holds at most {}",
+                shares.len(),
+                identity_key,
+                max_shares
+            );
+        }
+
+        Ok(())
+    }
+
+    async fn execute<S: StateWrite>(&self, mut state: S) -> Result<()> {
+        tracing::debug!(validator_identity = %self.body.identity_key, proposal = %self.body.proposal, "revealed ballot key secrets");
+        state.put_ballot_key_reveal(self.clone());
+        state.record_proto(event::ballot_key_reveal(self));
+
+        Ok(())
+    }
+}
@@ -10,7 +10,9 @@ use penumbra_proto::StateWriteProto as _;
 use penumbra_txhash::TransactionContext;
 
 use crate::{
-    event, DelegatorVote, DelegatorVoteBody, DelegatorVoteProofPublic,
+    event,
+    vote::Vote,
+    DelegatorVote, DelegatorVoteBody, DelegatorVoteProofPublic,
     {component::StateWriteExt, StateReadExt},
 };
 use cnidarium_component::ActionHandler;
@@ -31,11 +33,17 @@ impl ActionHandler for DelegatorVote {
                     value,
                     // Unused in stateless checks:
                     unbonded_amount: _,
-                    vote: _,     // Only used when executing the vote
                     proposal: _, // Checked against the current open proposals statefully
+                    vote,
+                    encrypted_vote,
                 },
         } = self;
 
+        // The plaintext vote of a sealed ballot must not reveal anything.
+        if encrypted_vote.is_some() && *vote != Vote::Abstain {
+            anyhow::bail!("sealed delegator vote must have an abstaining plaintext vote");
+        }
+
         // 1. Check spend auth signature using provided spend auth key.
         rk.verify(context.effect_hash.as_ref(), auth_sig)
             .context("delegator vote auth signature failed to verify")?;
@@ -66,12 +74,20 @@ impl ActionHandler for DelegatorVote {
                     unbonded_amount,
                     nullifier,
                     rk: _, // We already used this to check the auth sig in stateless verification
+                    encrypted_vote,
                 },
             auth_sig: _, // We already checked this in stateless verification
             proof: _,    // We already checked this in stateless verification
         } = self;
 
         state.check_proposal_votable(*proposal).await?;
+        let sealed = state.check_proposal_ballots_sealed(*proposal).await?;
+        if sealed != encrypted_vote.is_some() {
+            anyhow::bail!(
+                "delegator vote must be sealed if and only if proposal {} has sealed ballots",
+                proposal
+            );
+        }
         state
             .check_proposal_started_at_position(*proposal, *start_position)
             .await?;
@@ -97,6 +113,7 @@ impl ActionHandler for DelegatorVote {
                     nullifier,
                     unbonded_amount,
                     value,
+                    encrypted_vote,
                     start_position: _, // Not needed to execute: used to check validity of vote
                     rk: _,             // Not needed to execute: used to check auth sig
                 },
@@ -107,9 +124,14 @@ impl ActionHandler for DelegatorVote {
             .mark_nullifier_voted_on_proposal(*proposal, nullifier)
             .await;
         let identity_key = state.validator_by_delegation_asset(value.asset_id).await?;
-        state
-            .cast_delegator_vote(*proposal, identity_key, *vote, nullifier, *unbonded_amount)
-            .await?;
+        if encrypted_vote.is_some() {
+            // Sealed votes are only cast once the proposal's ballots are opened.
+            state.cast_sealed_delegator_vote(identity_key, self.body);
+        } else {
+            state
+                .cast_delegator_vote(*proposal, identity_key, *vote, nullifier, *unbonded_amount)
+                .await?;
+        }
 
         state.record_proto(event::delegator_vote(self));
 
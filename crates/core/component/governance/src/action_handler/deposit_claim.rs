@@ -3,6 +3,7 @@ use std::sync::Arc;
 use anyhow::Result;
 use async_trait::async_trait;
 use cnidarium::{StateRead, StateWrite};
+use penumbra_asset::Value;
 use penumbra_proto::StateWriteProto as _;
 use penumbra_shielded_pool::component::SupplyWrite;
 
@@ -27,7 +28,13 @@ impl ActionHandler for ProposalDepositClaim {
         state.check_proposal_claimable(self.proposal).await?;
         // Check that the deposit amount matches the proposal being claimed
         state
-            .check_proposal_claim_valid_deposit(self.proposal, self.deposit_amount)
+            .check_proposal_claim_valid_deposit(
+                self.proposal,
+                Value {
+                    amount: self.deposit_amount,
+                    asset_id: self.deposit_asset_id,
+                },
+            )
             .await?;
         Ok(())
     }
@@ -36,6 +43,7 @@ impl ActionHandler for ProposalDepositClaim {
         let ProposalDepositClaim {
             proposal,
             deposit_amount: _, // not needed to transition state; deposit is self-minted in tx
+            deposit_asset_id: _,
             outcome: resupplied_outcome,
         } = self;
 
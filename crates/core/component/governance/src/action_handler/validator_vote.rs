@@ -11,6 +11,7 @@ use crate::{action_handler::ActionHandler, StateReadExt};
 use crate::{
     proposal_state::Outcome,
     proposal_state::State as ProposalState,
+    vote::Vote,
     {ValidatorVote, ValidatorVoteBody, MAX_VALIDATOR_VOTE_REASON_LENGTH},
 };
 
@@ -32,6 +33,11 @@ impl ActionHandler for ValidatorVote {
             anyhow::bail!("validator vote reason is too long");
         }
 
+        // The plaintext vote of a sealed ballot must not reveal anything.
+        if body.encrypted_vote.is_some() && body.vote != Vote::Abstain {
+            anyhow::bail!("sealed validator vote must have an abstaining plaintext vote");
+        }
+
         // This is stateless verification, so we still need to check that the proposal being voted
         // on exists, and that this validator hasn't voted on it already.
 
@@ -47,11 +53,19 @@ impl ActionHandler for ValidatorVote {
                     identity_key,
                     governance_key,
                     reason: _, // Checked the length in the stateless verification
+                    encrypted_vote,
                 },
             auth_sig: _, // We already checked this in stateless verification
         } = self;
 
         state.check_proposal_votable(*proposal).await?;
+        let sealed = state.check_proposal_ballots_sealed(*proposal).await?;
+        if sealed != encrypted_vote.is_some() {
+            anyhow::bail!(
+                "validator vote must be sealed if and only if proposal {} has sealed ballots",
+                proposal
+            );
+        }
         state
             .check_validator_active_at_proposal_start(*proposal, identity_key)
             .await?;
@@ -75,6 +89,7 @@ impl ActionHandler for ValidatorVote {
                     identity_key,
                     governance_key: _, // This is only used for checks so that stateless verification can be done on the signature
                     reason,
+                    encrypted_vote,
                 },
         } = self;
 
@@ -93,18 +108,31 @@ impl ActionHandler for ValidatorVote {
             return Ok(());
         }
 
+        // Sealed votes are only cast once the proposal's ballots are opened, so they can't cause
+        // it to pass early.
+        if encrypted_vote.is_some() {
+            tracing::debug!(validator_identity = %identity_key, proposal = %proposal, "cast sealed validator vote");
+            state.cast_sealed_validator_vote(self.body.clone());
+            state.record_proto(event::validator_vote(self));
+            return Ok(());
+        }
+
         tracing::debug!(validator_identity = %identity_key, proposal = %proposal, "cast validator vote");
         state.cast_validator_vote(*proposal, *identity_key, *vote, reason.clone());
 
         // Emergency proposals are passed immediately after receiving +2/3 of
         // validator votes. These include the eponymous `Emergency` proposal but
-        // also `IbcFreeze` and `IbcUnfreeze`.
+        // also `IbcFreeze`, `IbcUnfreeze`, and parameter changes which only pause
+        // or resume trading on some pairs.
         let proposal_payload = state
             .proposal_payload(*proposal)
             .await?
             .expect("proposal missing payload");
 
-        if proposal_payload.is_emergency() || proposal_payload.is_ibc_freeze() {
+        if proposal_payload.is_emergency()
+            || proposal_payload.is_ibc_freeze()
+            || proposal_payload.is_trading_pause()
+        {
             tracing::debug!(proposal = %proposal, "detected an emergency-tier proposal, checking pass conditions");
             let tally = state.current_tally(*proposal).await?;
             let total_voting_power = state
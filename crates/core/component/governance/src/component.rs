@@ -14,7 +14,7 @@ use crate::{
     proposal_state::{
         Outcome as ProposalOutcome, State as ProposalState, Withdrawn as ProposalWithdrawn,
     },
-    tally,
+    tally, DepositRate,
 };
 
 mod view;
@@ -24,6 +24,9 @@ pub mod rpc;
 pub use view::StateReadExt;
 pub use view::StateWriteExt;
 
+use penumbra_asset::STAKING_TOKEN_ASSET_ID;
+use penumbra_dex::{component::StateReadExt as _, DirectedTradingPair};
+use penumbra_num::Amount;
 use penumbra_sct::component::clock::EpochRead;
 
 pub struct Governance {}
@@ -45,11 +48,18 @@ impl Component for Governance {
         }
     }
 
-    #[instrument(name = "governance", skip(_state, _begin_block))]
+    #[instrument(name = "governance", skip(state, _begin_block))]
     async fn begin_block<S: StateWrite + 'static>(
-        _state: &mut Arc<S>,
+        state: &mut Arc<S>,
         _begin_block: &abci::request::BeginBlock,
     ) {
+        let state = Arc::get_mut(state).expect("state should be unique");
+        // The block height has already been set by the SCT component, so this is the first block
+        // run past an upgrade height if the plan is still around.
+        state
+            .clear_completed_upgrade()
+            .await
+            .expect("clearing a completed upgrade should never fail");
     }
 
     #[instrument(name = "governance", skip(state, _end_block))]
@@ -58,6 +68,16 @@ impl Component for Governance {
         _end_block: &abci::request::EndBlock,
     ) {
         let mut state = Arc::get_mut(state).expect("state should be unique");
+        // Record the rates of accepted deposit assets from this block's batch swaps, which have
+        // already been executed by the DEX.
+        record_deposit_rates(&mut state)
+            .await
+            .expect("recording deposit rates should never fail");
+        // Establish the ballot keys of proposals with sealed ballots whose dealing period ends in
+        // this block.
+        finalize_ballot_keys(&mut state)
+            .await
+            .expect("finalizing ballot keys should never fail");
         // Then, enact any proposals that have passed, after considering the tallies to determine what
         // proposals have passed. Note that this occurs regardless of whether it's the end of an
         // epoch, because proposals can finish at any time.
@@ -74,6 +94,59 @@ impl Component for Governance {
     }
 }
 
+#[instrument(skip(state))]
+pub async fn record_deposit_rates<S: StateWrite>(mut state: S) -> Result<()> {
+    let height = state.get_block_height().await?;
+    let params = state.get_governance_params().await?;
+    for asset_id in params.accepted_deposit_assets {
+        let pair = DirectedTradingPair::new(asset_id, *STAKING_TOKEN_ASSET_ID);
+        let Some(execution) = state.swap_execution(height, pair).await? else {
+            continue;
+        };
+        // A batch swap which traded too little isn't a reliable rate, since it would be cheap to
+        // trade at a manipulated rate.
+        if execution.input.amount == Amount::zero()
+            || execution.output.amount == Amount::zero()
+            || execution.output.amount < params.deposit_rate_min_volume
+        {
+            continue;
+        }
+        let mut history = state.deposit_rate_history(&asset_id).await?;
+        history.record(
+            DepositRate {
+                input: execution.input,
+                output: execution.output.amount,
+                height,
+            },
+            params.deposit_rate_window_blocks,
+        );
+        state.put_deposit_rate_history(&asset_id, history);
+    }
+
+    Ok(())
+}
+
+#[instrument(skip(state))]
+pub async fn finalize_ballot_keys<S: StateWrite>(mut state: S) -> Result<()> {
+    let height = state.get_block_height().await?;
+    for proposal_id in state
+        .unfinished_proposals()
+        .await
+        .context("can get unfinished proposals")?
+    {
+        let sealed_ballots = state
+            .proposal_definition(proposal_id)
+            .await?
+            .context("proposal has definition")?
+            .sealed_ballots;
+        if sealed_ballots && state.ballot_key_setup_end(proposal_id).await? == height {
+            state.finalize_ballot_key(proposal_id).await?;
+        }
+    }
+
+    Ok(())
+}
+
 #[instrument(skip(state))]
 pub async fn enact_all_passed_proposals<S: StateWrite>(mut state: S) -> Result<()> {
     // For every unfinished proposal, conclude those that finish in this block
@@ -96,6 +169,37 @@ pub async fn enact_all_passed_proposals<S: StateWrite>(mut state: S) -> Result<(
             continue;
         }
 
+        let proposal = state
+            .proposal_definition(proposal_id)
+            .await?
+            .context("proposal has definition")?;
+        if proposal.sealed_ballots
+            && state.proposal_state(proposal_id).await? == Some(ProposalState::Voting)
+        {
+            // The sealed ballots of a proposal can only be tallied once they are opened, which
+            // happens as soon as the secret ballot key can be recovered from the validators'
+            // reveals, or when the reveal period ends, whichever comes first.
+            let has_ballot_key = state.ballot_key(proposal_id).await?.is_some();
+            let secret = if has_ballot_key {
+                state.recover_ballot_secret(proposal_id).await?
+            } else {
+                None
+            };
+            let reveal_end = state
+                .proposal_voting_end(proposal_id)
+                .await?
+                .context("proposal has voting end")?
+                + state
+                    .get_governance_params()
+                    .await?
+                    .sealed_ballot_reveal_blocks;
+            let height = state.get_block_height().await?;
+            if has_ballot_key && secret.is_none() && height < reveal_end {
+                continue;
+            }
+            state.open_sealed_ballots(proposal_id, secret).await?;
+        }
+
         // Do a final tally of any pending delegator votes for the proposal
         state.tally_delegator_votes(Some(proposal_id)).await?;
 
@@ -108,6 +212,8 @@ pub async fn enact_all_passed_proposals<S: StateWrite>(mut state: S) -> Result<(
             ProposalState::Voting => {
                 // If the proposal is still in the voting state, tally and conclude it (this will
                 // automatically remove it from the list of unfinished proposals)
+                state.record_validator_abstentions(proposal_id).await?;
+
                 let outcome = state.current_tally(proposal_id).await?.outcome(
                     state
                         .total_voting_power_at_proposal_start(proposal_id)
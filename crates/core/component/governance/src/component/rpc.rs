@@ -8,19 +8,28 @@ use futures::{StreamExt, TryStreamExt};
 use penumbra_num::Amount;
 use penumbra_proto::core::component::governance::v1::AllTalliedDelegatorVotesForProposalRequest;
 use penumbra_proto::core::component::governance::v1::AllTalliedDelegatorVotesForProposalResponse;
+use penumbra_proto::core::component::governance::v1::BallotKeyParticipant;
+use penumbra_proto::core::component::governance::v1::BallotKeyRequest;
+use penumbra_proto::core::component::governance::v1::BallotKeyResponse;
+use penumbra_proto::core::component::governance::v1::BallotKeySetupRequest;
+use penumbra_proto::core::component::governance::v1::BallotKeySetupResponse;
 use penumbra_proto::core::component::governance::v1::NextProposalIdRequest;
 use penumbra_proto::core::component::governance::v1::NextProposalIdResponse;
+use penumbra_proto::core::component::governance::v1::ScheduledUpgradeRequest;
+use penumbra_proto::core::component::governance::v1::ScheduledUpgradeResponse;
 use penumbra_proto::core::component::governance::v1::VotingPowerAtProposalStartRequest;
 use penumbra_proto::core::component::governance::v1::VotingPowerAtProposalStartResponse;
 use penumbra_proto::{
     core::component::governance::v1::{
         query_service_server::QueryService, ProposalDataRequest, ProposalDataResponse,
         ProposalInfoRequest, ProposalInfoResponse, ProposalListRequest, ProposalListResponse,
-        ProposalRateDataRequest, ProposalRateDataResponse, ValidatorVotesRequest,
-        ValidatorVotesResponse,
+        ProposalRateDataRequest, ProposalRateDataResponse, ValidatorAbstentionsRequest,
+        ValidatorAbstentionsResponse, ValidatorParticipationRequest,
+        ValidatorParticipationResponse, ValidatorVotesRequest, ValidatorVotesResponse,
     },
     StateReadProto,
 };
+use penumbra_stake::component::validator_handler::ValidatorDataRead as _;
 use penumbra_stake::rate::RateData;
 use penumbra_stake::IdentityKey;
 use tonic::Status;
@@ -144,6 +153,13 @@ impl QueryService for Server {
                 ))
             })?;
 
+        let proposal_deposit_asset_id = state
+            .proposal_deposit_asset_id(proposal_id)
+            .await
+            .map_err(|e| {
+                tonic::Status::internal(format!("unable to fetch proposal deposit asset: {e}"))
+            })?;
+
         Ok(tonic::Response::new(ProposalDataResponse {
             start_block_height,
             end_block_height,
@@ -151,6 +167,7 @@ impl QueryService for Server {
             state: Some(proposal_state.into()),
             proposal: Some(proposal.into()),
             proposal_deposit_amount: Some(proposal_deposit_amount.into()),
+            proposal_deposit_asset_id: Some(proposal_deposit_asset_id.into()),
         }))
     }
 
@@ -410,4 +427,152 @@ impl QueryService for Server {
             .boxed(),
         ))
     }
+
+    type ValidatorAbstentionsStream = Pin<
+        Box<dyn futures::Stream<Item = Result<ValidatorAbstentionsResponse, tonic::Status>> + Send>,
+    >;
+
+    #[instrument(skip(self, request))]
+    async fn validator_abstentions(
+        &self,
+        request: tonic::Request<ValidatorAbstentionsRequest>,
+    ) -> Result<tonic::Response<Self::ValidatorAbstentionsStream>, Status> {
+        let state = self.storage.latest_snapshot();
+        let proposal_id = request.into_inner().proposal_id;
+
+        let s = state
+            .prefix_proto::<u64>(&state_key::all_validator_abstentions_for_proposal(
+                proposal_id,
+            ))
+            .and_then(|r| async move {
+                Ok((
+                    IdentityKey::from_str(r.0.rsplit('/').next().context("invalid key")?)?,
+                    r.1,
+                ))
+            })
+            .map_ok(|i: (IdentityKey, u64)| ValidatorAbstentionsResponse {
+                identity_key: Some(i.0.into()),
+                voting_power: i.1,
+            });
+
+        Ok(tonic::Response::new(
+            s.map_err(|e: anyhow::Error| {
+                tonic::Status::unavailable(format!(
+                    "error getting validator abstentions from storage: {e}"
+                ))
+            })
+            // TODO: how do we instrument a Stream
+            //.instrument(Span::current())
+            .boxed(),
+        ))
+    }
+
+    #[instrument(skip(self, request))]
+    async fn validator_participation(
+        &self,
+        request: tonic::Request<ValidatorParticipationRequest>,
+    ) -> Result<tonic::Response<ValidatorParticipationResponse>, Status> {
+        let state = self.storage.latest_snapshot();
+        let identity_key: IdentityKey = request
+            .into_inner()
+            .identity_key
+            .ok_or_else(|| tonic::Status::invalid_argument("missing identity key"))?
+            .try_into()
+            .map_err(|_| {
+                tonic::Status::invalid_argument(
+                    "identity key in request was bad protobuf".to_string(),
+                )
+            })?;
+
+        let proposals_eligible = state
+            .validator_proposals_eligible(&identity_key)
+            .await
+            .map_err(|e| tonic::Status::internal(format!("error accessing storage: {}", e)))?;
+        let proposals_abstained = state
+            .validator_proposals_abstained(&identity_key)
+            .await
+            .map_err(|e| tonic::Status::internal(format!("error accessing storage: {}", e)))?;
+
+        Ok(tonic::Response::new(ValidatorParticipationResponse {
+            proposals_eligible,
+            proposals_abstained,
+        }))
+    }
+
+    #[instrument(skip(self, _request))]
+    async fn scheduled_upgrade(
+        &self,
+        _request: tonic::Request<ScheduledUpgradeRequest>,
+    ) -> Result<tonic::Response<ScheduledUpgradeResponse>, Status> {
+        let state = self.storage.latest_snapshot();
+
+        let upgrade_plan = state
+            .scheduled_upgrade()
+            .await
+            .map_err(|e| tonic::Status::internal(format!("error accessing storage: {}", e)))?;
+
+        Ok(tonic::Response::new(ScheduledUpgradeResponse {
+            upgrade_plan: upgrade_plan.map(Into::into),
+        }))
+    }
+
+    #[instrument(skip(self, request))]
+    async fn ballot_key(
+        &self,
+        request: tonic::Request<BallotKeyRequest>,
+    ) -> Result<tonic::Response<BallotKeyResponse>, Status> {
+        let state = self.storage.latest_snapshot();
+        let proposal_id = request.into_inner().proposal_id;
+
+        let ballot_key = state
+            .ballot_key(proposal_id)
+            .await
+            .map_err(|e| tonic::Status::internal(format!("error accessing storage: {}", e)))?;
+
+        Ok(tonic::Response::new(BallotKeyResponse {
+            ballot_key: ballot_key.map(Into::into),
+        }))
+    }
+
+    #[instrument(skip(self, request))]
+    async fn ballot_key_setup(
+        &self,
+        request: tonic::Request<BallotKeySetupRequest>,
+    ) -> Result<tonic::Response<BallotKeySetupResponse>, Status> {
+        let state = self.storage.latest_snapshot();
+        let proposal_id = request.into_inner().proposal_id;
+
+        let voting_power = state
+            .validator_voting_power_at_proposal_start(proposal_id)
+            .await
+            .map_err(|e| tonic::Status::internal(format!("error accessing storage: {}", e)))?;
+        let mut participants = Vec::with_capacity(voting_power.len());
+        for (identity_key, voting_power) in voting_power {
+            let governance_key = state
+                .get_validator_definition(&identity_key)
+                .await
+                .map_err(|e| tonic::Status::internal(format!("error accessing storage: {}", e)))?
+                .map(|validator| validator.governance_key.into());
+            participants.push(BallotKeyParticipant {
+                identity_key: Some(identity_key.into()),
+                governance_key,
+                voting_power,
+            });
+        }
+
+        let deals = state
+            .ballot_key_deals(proposal_id)
+            .await
+            .map_err(|e| tonic::Status::internal(format!("error accessing storage: {}", e)))?;
+        let complaints = state
+            .ballot_key_complaints(proposal_id)
+            .await
+            .map_err(|e| tonic::Status::internal(format!("error accessing storage: {}", e)))?;
+
+        Ok(tonic::Response::new(BallotKeySetupResponse {
+            participants,
+            deals: deals.into_values().map(Into::into).collect(),
+            disqualified_dealers: complaints.into_keys().map(Into::into).collect(),
+        }))
+    }
 }
@@ -8,7 +8,7 @@ use async_trait::async_trait;
 use cnidarium::{StateRead, StateWrite};
 use futures::StreamExt;
 use ibc_types::core::client::ClientId;
-use penumbra_asset::{asset, Value, STAKING_TOKEN_DENOM};
+use penumbra_asset::{asset, Value, STAKING_TOKEN_ASSET_ID, STAKING_TOKEN_DENOM};
 use penumbra_ibc::component::ClientStateReadExt as _;
 use penumbra_ibc::component::ClientStateWriteExt as _;
 use penumbra_num::Amount;
@@ -28,14 +28,20 @@ use tracing::instrument;
 
 use penumbra_stake::{rate::RateData, validator};
 
+use crate::{event, state_key, tally::Tally};
 use crate::{
     params::GovernanceParameters,
-    proposal::{ChangedAppParameters, ChangedAppParametersSet, Proposal, ProposalPayload},
+    proposal::{
+        ChangedAppParameters, ChangedAppParametersSet, Proposal, ProposalPayload, UpgradePlan,
+    },
     proposal_state::State as ProposalState,
+    sealed_ballot::{
+        self, BallotKey, BallotKeyComplaint, BallotKeyDeal, BallotKeyReveal, ShareAllocation,
+    },
     validator_vote::action::ValidatorVoteReason,
     vote::Vote,
+    DelegatorVoteBody, DepositRateHistory, ValidatorVoteBody,
 };
-use crate::{state_key, tally::Tally};
 
 #[async_trait]
 pub trait StateReadExt: StateRead + penumbra_stake::StateReadExt {
@@ -98,6 +104,86 @@ pub trait StateReadExt: StateRead + penumbra_stake::StateReadExt {
             .await
     }
 
+    /// Get the asset a proposal's deposit was paid in.
+    async fn proposal_deposit_asset_id(&self, proposal_id: u64) -> Result<asset::Id> {
+        // Proposals which don't record a deposit asset were paid for in the staking token.
+        Ok(self
+            .get(&state_key::proposal_deposit_asset_id(proposal_id))
+            .await?
+            .unwrap_or(*STAKING_TOKEN_ASSET_ID))
+    }
+
+    /// Get the recent batch swaps selling the given asset for the staking token, from which the
+    /// rate at which proposal deposits in the asset are converted into the staking token is
+    /// computed.
+    async fn deposit_rate_history(&self, asset_id: &asset::Id) -> Result<DepositRateHistory> {
+        Ok(self
+            .get(&state_key::deposit_rate_history(asset_id))
+            .await?
+            .unwrap_or_default())
+    }
+
+    /// Check that a proposal deposit is acceptable under the current governance parameters.
+    ///
+    /// Deposits in the staking token must be exactly the required deposit amount. Deposits in
+    /// another accepted asset must be worth at least the required deposit amount, at the
+    /// time-weighted average rate of the batch swaps selling that asset for the staking token.
+    async fn check_proposal_deposit(&self, deposit: Value) -> Result<()> {
+        let governance_parameters = self.get_governance_params().await?;
+        let required = governance_parameters.proposal_deposit_amount;
+
+        if deposit.asset_id == *STAKING_TOKEN_ASSET_ID {
+            if deposit.amount != required {
+                anyhow::bail!(
+                    "submitted proposal deposit of {}{} does not match required proposal deposit of {}{}",
+                    deposit.amount,
+                    *STAKING_TOKEN_DENOM,
+                    required,
+                    *STAKING_TOKEN_DENOM,
+                );
+            }
+            return Ok(());
+        }
+
+        if !governance_parameters
+            .accepted_deposit_assets
+            .contains(&deposit.asset_id)
+        {
+            anyhow::bail!(
+                "proposal deposits cannot be paid in asset {}",
+                deposit.asset_id
+            );
+        }
+        let staking_value = self
+            .deposit_rate_history(&deposit.asset_id)
+            .await?
+            .staking_value(
+                deposit.amount,
+                self.get_block_height().await?,
+                governance_parameters.deposit_rate_window_blocks,
+                governance_parameters.deposit_rate_max_age_blocks,
+            )
+            .with_context(|| {
+                format!(
+                    "asset {} has no reliable rate, so it cannot be used for proposal deposits",
+                    deposit.asset_id
+                )
+            })?;
+        if staking_value < required {
+            anyhow::bail!(
+                "submitted proposal deposit of {} of asset {} is worth {}{}, less than the required proposal deposit of {}{}",
+                deposit.amount,
+                deposit.asset_id,
+                staking_value,
+                *STAKING_TOKEN_DENOM,
+                required,
+                *STAKING_TOKEN_DENOM,
+            );
+        }
+
+        Ok(())
+    }
+
     /// Get the state of a proposal.
     async fn proposal_state(&self, proposal_id: u64) -> Result<Option<ProposalState>> {
         Ok(self
@@ -208,7 +294,16 @@ pub trait StateReadExt: StateRead + penumbra_stake::StateReadExt {
             use crate::proposal_state::State::*;
             match proposal_state {
                 Voting => {
-                    // This is when you can vote on a proposal
+                    // This is when you can vote on a proposal, unless its voting period is over
+                    // and it is only waiting for its sealed ballots to be opened
+                    let height = self.get_block_height().await?;
+                    let voting_end = self
+                        .proposal_voting_end(proposal_id)
+                        .await?
+                        .context("proposal has voting end")?;
+                    if height > voting_end {
+                        anyhow::bail!("voting on proposal {} has already closed", proposal_id);
+                    }
                 }
                 Withdrawn { .. } => {
                     anyhow::bail!("proposal {} has already been withdrawn", proposal_id)
@@ -224,6 +319,222 @@ pub trait StateReadExt: StateRead + penumbra_stake::StateReadExt {
         Ok(())
     }
 
+    /// Throw an error if sealed ballots can't currently be cast on the proposal, and otherwise
+    /// return whether its ballots are sealed.
+    ///
+    /// Ballots on a proposal with sealed ballots can only be cast once its ballot key has been
+    /// established, and ballots on any other proposal must not be sealed.
+    async fn check_proposal_ballots_sealed(&self, proposal_id: u64) -> Result<bool> {
+        let proposal = self
+            .proposal_definition(proposal_id)
+            .await?
+            .ok_or_else(|| anyhow::anyhow!("proposal {} does not exist", proposal_id))?;
+        if !proposal.sealed_ballots {
+            return Ok(false);
+        }
+
+        if self.ballot_key(proposal_id).await?.is_none() {
+            let setup_end = self.ballot_key_setup_end(proposal_id).await?;
+            if self.get_block_height().await? <= setup_end {
+                anyhow::bail!(
+                    "the ballot key for proposal {} is still being dealt, until block {}",
+                    proposal_id,
+                    setup_end
+                );
+            }
+            anyhow::bail!(
+                "no ballot key was established for proposal {}, so it can't be voted on",
+                proposal_id
+            );
+        }
+
+        Ok(true)
+    }
+
+    /// Get the last block in which the ballot key of a proposal with sealed ballots can be
+    /// complained about, at the end of which it is established.
+    async fn ballot_key_setup_end(&self, proposal_id: u64) -> Result<u64> {
+        let voting_start = self
+            .proposal_voting_start(proposal_id)
+            .await?
+            .ok_or_else(|| anyhow::anyhow!("proposal {} does not exist", proposal_id))?;
+        let setup_blocks = self
+            .get_governance_params()
+            .await?
+            .sealed_ballot_setup_blocks;
+        Ok(voting_start + setup_blocks - 1)
+    }
+
+    /// Get the last block in which the ballot key of a proposal with sealed ballots can be dealt.
+    ///
+    /// Deals are made in the first half of the setup period, so that there is time left to
+    /// complain about them.
+    async fn ballot_key_deal_end(&self, proposal_id: u64) -> Result<u64> {
+        let voting_start = self
+            .proposal_voting_start(proposal_id)
+            .await?
+            .ok_or_else(|| anyhow::anyhow!("proposal {} does not exist", proposal_id))?;
+        let setup_blocks = self
+            .get_governance_params()
+            .await?
+            .sealed_ballot_setup_blocks;
+        Ok(voting_start + (setup_blocks - 1) / 2)
+    }
+
+    /// Get the allocation of the share positions of the ballot key of a proposal with sealed
+    /// ballots to the validators active at its start.
+    async fn ballot_key_share_allocation(&self, proposal_id: u64) -> Result<ShareAllocation> {
+        Ok(ShareAllocation::new(
+            &self
+                .validator_voting_power_at_proposal_start(proposal_id)
+                .await?,
+        ))
+    }
+
+    /// Get the ballot key of a proposal with sealed ballots, if it has been established.
+    async fn ballot_key(&self, proposal_id: u64) -> Result<Option<BallotKey>> {
+        self.get(&state_key::ballot_key(proposal_id)).await
+    }
+
+    /// Get all the ballot key deals made for a proposal with sealed ballots, by dealer.
+    async fn ballot_key_deals(
+        &self,
+        proposal_id: u64,
+    ) -> Result<BTreeMap<IdentityKey, BallotKeyDeal>> {
+        let mut deals = BTreeMap::new();
+
+        let prefix = state_key::all_ballot_key_deals(proposal_id);
+        let mut stream = self.prefix(&prefix);
+
+        while let Some((key, deal)) = stream.next().await.transpose()? {
+            let identity_key = key
+                .rsplit('/')
+                .next()
+                .ok_or_else(|| anyhow::anyhow!("incorrect key format for ballot key deal"))?
+                .parse()?;
+            deals.insert(identity_key, deal);
+        }
+
+        Ok(deals)
+    }
+
+    /// Get the valid complaints made about the ballot key deals of a proposal with sealed
+    /// ballots, by dealer.
+    async fn ballot_key_complaints(
+        &self,
+        proposal_id: u64,
+    ) -> Result<BTreeMap<IdentityKey, BallotKeyComplaint>> {
+        let mut complaints = BTreeMap::new();
+
+        let prefix = state_key::all_ballot_key_complaints(proposal_id);
+        let mut stream = self.prefix(&prefix);
+
+        while let Some((key, complaint)) = stream.next().await.transpose()? {
+            let dealer = key
+                .rsplit('/')
+                .next()
+                .ok_or_else(|| anyhow::anyhow!("incorrect key format for ballot key complaint"))?
+                .parse()?;
+            complaints.insert(dealer, complaint);
+        }
+
+        Ok(complaints)
+    }
+
+    /// Get the ballot key deals of a proposal with sealed ballots which weren't validly
+    /// complained about, by dealer.
+    async fn qualified_ballot_key_deals(
+        &self,
+        proposal_id: u64,
+    ) -> Result<BTreeMap<IdentityKey, BallotKeyDeal>> {
+        let mut deals = self.ballot_key_deals(proposal_id).await?;
+        for dealer in self.ballot_key_complaints(proposal_id).await?.keys() {
+            deals.remove(dealer);
+        }
+        Ok(deals)
+    }
+
+    /// Get all the ballot key reveals made for a proposal with sealed ballots, by revealer.
+    async fn ballot_key_reveals(
+        &self,
+        proposal_id: u64,
+    ) -> Result<BTreeMap<IdentityKey, BallotKeyReveal>> {
+        let mut reveals = BTreeMap::new();
+
+        let prefix = state_key::all_ballot_key_reveals(proposal_id);
+        let mut stream = self.prefix(&prefix);
+
+        while let Some((key, reveal)) = stream.next().await.transpose()? {
+            let identity_key = key
+                .rsplit('/')
+                .next()
+                .ok_or_else(|| anyhow::anyhow!("incorrect key format for ballot key reveal"))?
+                .parse()?;
+            reveals.insert(identity_key, reveal);
+        }
+
+        Ok(reveals)
+    }
+
+    /// Recover the secret ballot key of a proposal with sealed ballots from the secrets revealed
+    /// so far, if possible.
+    async fn recover_ballot_secret(&self, proposal_id: u64) -> Result<Option<decaf377::Fr>> {
+        let allocation = self.ballot_key_share_allocation(proposal_id).await?;
+        let deals = self.qualified_ballot_key_deals(proposal_id).await?;
+        let reveals = self.ballot_key_reveals(proposal_id).await?;
+        Ok(sealed_ballot::recover_ballot_secret(
+            &allocation,
+            &deals,
+            &reveals,
+        ))
+    }
+
+    /// Get all the sealed validator votes cast on a proposal, by validator.
+    async fn sealed_validator_votes(
+        &self,
+        proposal_id: u64,
+    ) -> Result<BTreeMap<IdentityKey, ValidatorVoteBody>> {
+        let mut votes = BTreeMap::new();
+
+        let prefix = state_key::all_sealed_validator_votes_for_proposal(proposal_id);
+        let mut stream = self.prefix(&prefix);
+
+        while let Some((key, vote)) = stream.next().await.transpose()? {
+            let identity_key = key
+                .rsplit('/')
+                .next()
+                .ok_or_else(|| anyhow::anyhow!("incorrect key format for sealed validator vote"))?
+                .parse()?;
+            votes.insert(identity_key, vote);
+        }
+
+        Ok(votes)
+    }
+
+    /// Get all the sealed delegator votes cast on a proposal, along with the validator each was
+    /// delegated to.
+    async fn sealed_delegator_votes(
+        &self,
+        proposal_id: u64,
+    ) -> Result<Vec<(IdentityKey, DelegatorVoteBody)>> {
+        let mut votes = Vec::new();
+
+        let prefix = state_key::all_sealed_delegator_votes_for_proposal(proposal_id);
+        let mut stream = self.prefix(&prefix);
+
+        while let Some((key, vote)) = stream.next().await.transpose()? {
+            let mut reverse_path_elements = key.rsplit('/');
+            reverse_path_elements.next(); // skip the nullifier element of the key
+            let identity_key = reverse_path_elements
+                .next()
+                .ok_or_else(|| anyhow::anyhow!("incorrect key format for sealed delegator vote"))?
+                .parse()?;
+            votes.push((identity_key, vote));
+        }
+
+        Ok(votes)
+    }
+
     /// Throw an error if the proposal was not started at the claimed position.
     async fn check_proposal_started_at_position(
         &self,
@@ -341,7 +652,18 @@ pub trait StateReadExt: StateRead + penumbra_stake::StateReadExt {
         proposal_id: u64,
         identity_key: &IdentityKey,
     ) -> Result<()> {
-        if let Some(_vote) = self.validator_vote(proposal_id, *identity_key).await? {
+        let sealed_vote = self
+            .get::<ValidatorVoteBody>(&state_key::sealed_validator_vote(
+                proposal_id,
+                *identity_key,
+            ))
+            .await?;
+        if self
+            .validator_vote(proposal_id, *identity_key)
+            .await?
+            .is_some()
+            || sealed_vote.is_some()
+        {
             anyhow::bail!(
                 "validator {} has already voted on proposal {}",
                 identity_key,
@@ -403,20 +725,26 @@ pub trait StateReadExt: StateRead + penumbra_stake::StateReadExt {
         Ok(())
     }
 
-    /// Check that the deposit claim amount matches the proposal's deposit amount.
+    /// Check that the deposit claim matches the proposal's deposit, in both amount and asset.
     async fn check_proposal_claim_valid_deposit(
         &self,
         proposal_id: u64,
-        claim_deposit_amount: Amount,
+        claim_deposit: Value,
     ) -> Result<()> {
         if let Some(proposal_deposit_amount) = self.proposal_deposit_amount(proposal_id).await? {
-            if claim_deposit_amount != proposal_deposit_amount {
+            let proposal_deposit_asset_id = self.proposal_deposit_asset_id(proposal_id).await?;
+            if claim_deposit.asset_id != proposal_deposit_asset_id {
                 anyhow::bail!(
-                    "proposal deposit claim for {}{} does not match proposal deposit of {}{}",
-                    claim_deposit_amount,
-                    *STAKING_TOKEN_DENOM,
+                    "proposal deposit claim in asset {} does not match proposal deposit in asset {}",
+                    claim_deposit.asset_id,
+                    proposal_deposit_asset_id,
+                );
+            }
+            if claim_deposit.amount != proposal_deposit_amount {
+                anyhow::bail!(
+                    "proposal deposit claim for {} does not match proposal deposit of {}",
+                    claim_deposit.amount,
                     proposal_deposit_amount,
-                    *STAKING_TOKEN_DENOM,
                 );
             }
         } else {
@@ -495,6 +823,43 @@ pub trait StateReadExt: StateRead + penumbra_stake::StateReadExt {
         Ok(votes)
     }
 
+    /// Get all the validators which abstained from voting on a finished proposal, along with
+    /// their voting power at the start of the proposal.
+    async fn validator_abstentions(&self, proposal_id: u64) -> Result<BTreeMap<IdentityKey, u64>> {
+        let mut abstentions = BTreeMap::new();
+
+        let prefix = state_key::all_validator_abstentions_for_proposal(proposal_id);
+        let mut stream = self.prefix_proto(&prefix);
+
+        while let Some((key, power)) = stream.next().await.transpose()? {
+            let identity_key = key
+                .rsplit('/')
+                .next()
+                .ok_or_else(|| anyhow::anyhow!("incorrect key format for validator abstention"))?
+                .parse()?;
+            abstentions.insert(identity_key, power);
+        }
+
+        Ok(abstentions)
+    }
+
+    /// Get the number of finished proposals for which the validator was active at proposal start.
+    async fn validator_proposals_eligible(&self, identity_key: &IdentityKey) -> Result<u64> {
+        Ok(self
+            .get_proto(&state_key::validator_proposals_eligible(*identity_key))
+            .await?
+            .unwrap_or_default())
+    }
+
+    /// Get the number of finished proposals on which the validator was eligible to vote, but did
+    /// not.
+    async fn validator_proposals_abstained(&self, identity_key: &IdentityKey) -> Result<u64> {
+        Ok(self
+            .get_proto(&state_key::validator_proposals_abstained(*identity_key))
+            .await?
+            .unwrap_or_default())
+    }
+
     /// Get all the *tallied* delegator votes for the proposal (excluding those which have been
     /// cast but not tallied).
     async fn tallied_delegator_votes(
@@ -587,6 +952,14 @@ pub trait StateReadExt: StateRead + penumbra_stake::StateReadExt {
                 .unwrap_or_default())
     }
 
+    /// Returns the upgrade scheduled by the most recently passed upgrade plan, if any.
+    ///
+    /// The upgrade plan remains recorded after the upgrade height, so that nodes can check that
+    /// they are running the release it specifies.
+    async fn scheduled_upgrade(&self) -> Result<Option<UpgradePlan>> {
+        self.get(state_key::upgrades::scheduled_upgrade()).await
+    }
+
     async fn halt_count(&self) -> Result<u64> {
         Ok(self
             .get_proto(state_key::halt::halt_count())
@@ -704,6 +1077,16 @@ pub trait StateWriteExt: StateWrite + penumbra_ibc::component::ConnectionStateWr
         self.put(state_key::proposal_deposit_amount(proposal_id), amount);
     }
 
+    /// Store the asset the proposal deposit was paid in.
+    fn put_deposit_asset_id(&mut self, proposal_id: u64, asset_id: asset::Id) {
+        self.put(state_key::proposal_deposit_asset_id(proposal_id), asset_id);
+    }
+
+    /// Store the recent batch swaps selling the given asset for the staking token.
+    fn put_deposit_rate_history(&mut self, asset_id: &asset::Id, history: DepositRateHistory) {
+        self.put(state_key::deposit_rate_history(asset_id), history);
+    }
+
     /// Set the state of a proposal.
     fn put_proposal_state(&mut self, proposal_id: u64, state: ProposalState) {
         // Set the state of the proposal
@@ -740,6 +1123,170 @@ pub trait StateWriteExt: StateWrite + penumbra_ibc::component::ConnectionStateWr
         );
     }
 
+    /// Record a ballot key deal for a proposal with sealed ballots.
+    fn put_ballot_key_deal(&mut self, deal: BallotKeyDeal) {
+        self.put(
+            state_key::ballot_key_deal(deal.body.proposal, deal.body.identity_key),
+            deal,
+        );
+    }
+
+    /// Record a valid complaint about a ballot key deal for a proposal with sealed ballots, which
+    /// excludes its dealer from the ballot key.
+    fn put_ballot_key_complaint(&mut self, complaint: BallotKeyComplaint) {
+        self.put(
+            state_key::ballot_key_complaint(complaint.body.proposal, complaint.body.dealer),
+            complaint,
+        );
+    }
+
+    /// Record a ballot key reveal for a proposal with sealed ballots.
+    fn put_ballot_key_reveal(&mut self, reveal: BallotKeyReveal) {
+        self.put(
+            state_key::ballot_key_reveal(reveal.body.proposal, reveal.body.identity_key),
+            reveal,
+        );
+    }
+
+    /// Establish the ballot key of a proposal with sealed ballots from the deals made for it which
+    /// weren't validly complained about, if validators with enough voting power made them,
+    /// emitting an event either way.
+    ///
+    /// This should be called exactly once per proposal with sealed ballots, at the end of the
+    /// last block in which its ballot key can be complained about.
+    async fn finalize_ballot_key(&mut self, proposal_id: u64) -> Result<Option<BallotKey>> {
+        let allocation = self.ballot_key_share_allocation(proposal_id).await?;
+        let deals = self.qualified_ballot_key_deals(proposal_id).await?;
+
+        let ballot_key = if allocation.weight(deals.keys()) >= allocation.threshold() {
+            let ballot_key = BallotKey::from_dealer_commitments(
+                deals.values().map(|deal| &deal.body.commitments[0]),
+            );
+            self.put(state_key::ballot_key(proposal_id), ballot_key);
+            Some(ballot_key)
+        } else {
+            tracing::info!(
+                proposal = %proposal_id,
+                dealers = deals.len(),
+                weight = allocation.weight(deals.keys()),
+                threshold = allocation.threshold(),
+                "not enough validators dealt a ballot key for proposal with sealed ballots"
+            );
+            None
+        };
+
+        self.record_proto(event::ballot_key_finalized(proposal_id, ballot_key));
+        Ok(ballot_key)
+    }
+
+    /// Record a sealed validator vote for a proposal, to be cast once its ballots are opened.
+    fn cast_sealed_validator_vote(&mut self, vote: ValidatorVoteBody) {
+        self.put(
+            state_key::sealed_validator_vote(vote.proposal, vote.identity_key),
+            vote,
+        );
+    }
+
+    /// Record a sealed delegator vote for a proposal, to be cast once its ballots are opened.
+    fn cast_sealed_delegator_vote(&mut self, identity_key: IdentityKey, vote: DelegatorVoteBody) {
+        self.put(
+            state_key::sealed_delegator_vote(vote.proposal, identity_key, &vote.nullifier),
+            vote,
+        );
+    }
+
+    /// Open the sealed ballots of a proposal with the secret ballot key, casting every ballot
+    /// which decrypts to a valid vote and discarding the rest, and emit an event recording how
+    /// many were opened.
+    ///
+    /// If the secret ballot key couldn't be recovered, every sealed ballot is discarded.
+    async fn open_sealed_ballots(
+        &mut self,
+        proposal_id: u64,
+        secret: Option<decaf377::Fr>,
+    ) -> Result<()> {
+        let (mut opened, mut discarded) = (0u64, 0u64);
+
+        for (identity_key, vote) in self.sealed_validator_votes(proposal_id).await? {
+            self.delete(state_key::sealed_validator_vote(proposal_id, identity_key));
+            match secret
+                .zip(vote.encrypted_vote)
+                .and_then(|(secret, encrypted)| encrypted.decrypt(&secret))
+            {
+                Some(decrypted) => {
+                    self.cast_validator_vote(proposal_id, identity_key, decrypted, vote.reason);
+                    opened += 1;
+                }
+                None => discarded += 1,
+            }
+        }
+
+        for (identity_key, vote) in self.sealed_delegator_votes(proposal_id).await? {
+            self.delete(state_key::sealed_delegator_vote(
+                proposal_id,
+                identity_key,
+                &vote.nullifier,
+            ));
+            match secret
+                .zip(vote.encrypted_vote)
+                .and_then(|(secret, encrypted)| encrypted.decrypt(&secret))
+            {
+                Some(decrypted) => {
+                    self.cast_delegator_vote(
+                        proposal_id,
+                        identity_key,
+                        decrypted,
+                        &vote.nullifier,
+                        vote.unbonded_amount,
+                    )
+                    .await?;
+                    opened += 1;
+                }
+                None => discarded += 1,
+            }
+        }
+
+        tracing::info!(proposal = %proposal_id, opened, discarded, "opened sealed ballots");
+        self.record_proto(event::sealed_ballots_opened(proposal_id, opened, discarded));
+        Ok(())
+    }
+
+    /// Record which validators active at the start of a proposal did not vote on it, updating
+    /// their participation counters and emitting an event for each abstention.
+    ///
+    /// This should be called exactly once per proposal, when it concludes.
+    async fn record_validator_abstentions(&mut self, proposal_id: u64) -> Result<()> {
+        let validator_powers = self
+            .validator_voting_power_at_proposal_start(proposal_id)
+            .await?;
+        let validator_votes = self.validator_votes(proposal_id).await?;
+
+        for (identity_key, power) in validator_powers {
+            let eligible = self.validator_proposals_eligible(&identity_key).await?;
+            self.put_proto(
+                state_key::validator_proposals_eligible(identity_key),
+                eligible + 1,
+            );
+
+            if validator_votes.contains_key(&identity_key) {
+                continue;
+            }
+
+            let abstained = self.validator_proposals_abstained(&identity_key).await?;
+            self.put_proto(
+                state_key::validator_proposals_abstained(identity_key),
+                abstained + 1,
+            );
+            self.put_proto(
+                state_key::validator_abstention(proposal_id, identity_key),
+                power,
+            );
+            self.record_proto(event::validator_abstained(proposal_id, identity_key, power));
+        }
+
+        Ok(())
+    }
+
     /// Set the proposal voting start block height for a proposal.
     fn put_proposal_voting_start(&mut self, proposal_id: u64, end_block: u64) {
         self.put_proto(state_key::proposal_voting_start(proposal_id), end_block);
@@ -917,9 +1464,14 @@ pub trait StateWriteExt: StateWrite + penumbra_ibc::component::ConnectionStateWr
                 // be slotted in at the end of the block:
                 self.deliver_community_pool_transaction(proposal_id).await?;
             }
-            ProposalPayload::UpgradePlan { height } => {
-                tracing::info!(target_height = height, "upgrade plan proposal passed");
-                self.signal_upgrade(*height).await?;
+            ProposalPayload::UpgradePlan(upgrade_plan) => {
+                tracing::info!(
+                    target_height = upgrade_plan.height,
+                    version = ?upgrade_plan.version,
+                    binary_hash = ?upgrade_plan.binary_hash,
+                    "upgrade plan proposal passed"
+                );
+                self.signal_upgrade(upgrade_plan.clone()).await?;
             }
             ProposalPayload::FreezeIbcClient { client_id } => {
                 let client_id = &ClientId::from_str(client_id)
@@ -938,6 +1490,29 @@ pub trait StateWriteExt: StateWrite + penumbra_ibc::component::ConnectionStateWr
                 let unfrozen_client = client_state.unfrozen();
                 self.put_client(client_id, unfrozen_client);
             }
+            ProposalPayload::RecurringBudget {
+                budget_line,
+                cancel,
+            } => {
+                use penumbra_community_pool::StateWriteExt as _;
+
+                // A budget line may have run its course while the proposal was being voted on, in
+                // which case there is nothing left to cancel.
+                for id in cancel {
+                    if self.cancel_budget_line(*id).await?.is_none() {
+                        tracing::info!(id, "budget line to cancel is no longer active");
+                    }
+                }
+                if let Some(budget_line) = budget_line {
+                    tracing::info!(
+                        proposal_id,
+                        recipient = %budget_line.recipient,
+                        epochs = budget_line.epochs,
+                        "recurring budget proposal passed, establishing budget line"
+                    );
+                    self.establish_budget_line(proposal_id, budget_line.clone());
+                }
+            }
         }
         Ok(Ok(()))
     }
@@ -989,15 +1564,41 @@ pub trait StateWriteExt: StateWrite + penumbra_ibc::component::ConnectionStateWr
         Ok(())
     }
 
-    /// Records the next upgrade height.
+    /// Records the next upgrade height, along with the upgrade plan.
     /// After commititng the height, the chain should halt and wait for an upgrade.
     /// It re-uses the same mechanism as emergency halting that prevents the chain from
     /// restarting without incrementing the application `TOTAL_HALT_COUNT`.
-    async fn signal_upgrade(&mut self, height: u64) -> Result<()> {
+    async fn signal_upgrade(&mut self, upgrade_plan: UpgradePlan) -> Result<()> {
         self.nonverifiable_put_raw(
             state_key::upgrades::next_upgrade().into(),
-            height.to_be_bytes().to_vec(),
+            upgrade_plan.height.to_be_bytes().to_vec(),
         );
+        self.put(
+            state_key::upgrades::scheduled_upgrade().into(),
+            upgrade_plan,
+        );
+        Ok(())
+    }
+
+    /// Forgets the scheduled upgrade once the chain has reached its height.
+    ///
+    /// A node only runs blocks at or past the upgrade height after it restarted on a release
+    /// accepted by the plan, so from then on the plan must not gate later restarts, e.g. onto the
+    /// release for a subsequent upgrade.
+    async fn clear_completed_upgrade(&mut self) -> Result<()> {
+        let Some(upgrade_plan) = self.scheduled_upgrade().await? else {
+            return Ok(());
+        };
+        let current_height = self.get_block_height().await?;
+        if current_height >= upgrade_plan.height {
+            tracing::info!(
+                upgrade_height = upgrade_plan.height,
+                ?current_height,
+                "upgrade completed, clearing upgrade plan"
+            );
+            self.nonverifiable_delete(state_key::upgrades::next_upgrade().into());
+            self.delete(state_key::upgrades::scheduled_upgrade().into());
+        }
         Ok(())
     }
 
@@ -1011,3 +1612,77 @@ pub trait StateWriteExt: StateWrite + penumbra_ibc::component::ConnectionStateWr
 }
 
 impl<T: StateWrite + StateReadExt> StateWriteExt for T {}
+
+#[cfg(test)]
+mod tests {
+    use cnidarium::{StateDelta, TempStorage};
+    use decaf377_rdsa::{SigningKey, SpendAuth};
+    use rand_core::OsRng;
+
+    use super::*;
+
+    fn identity_key() -> IdentityKey {
+        IdentityKey((&SigningKey::<SpendAuth>::new(OsRng)).into())
+    }
+
+    /// Snapshot the voting power of the given validators at the start of a proposal.
+    fn start_proposal(
+        state: &mut StateDelta<cnidarium::Snapshot>,
+        id: u64,
+        powers: &[(IdentityKey, u64)],
+    ) {
+        for (identity_key, power) in powers {
+            state.put_proto(
+                state_key::voting_power_at_proposal_start(id, *identity_key),
+                *power,
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn validator_abstentions_are_recorded_on_conclusion() -> Result<()> {
+        let storage = TempStorage::new().await?;
+        let mut state = StateDelta::new(storage.latest_snapshot());
+        let (a, b, c) = (identity_key(), identity_key(), identity_key());
+        let vote = |state: &mut StateDelta<_>, id, identity_key| {
+            state.cast_validator_vote(
+                id,
+                identity_key,
+                Vote::Yes,
+                ValidatorVoteReason(String::new()),
+            )
+        };
+
+        // All three validators are active for the first proposal, but only one votes.
+        start_proposal(&mut state, 0, &[(a, 10), (b, 20), (c, 30)]);
+        vote(&mut state, 0, a);
+        state.record_validator_abstentions(0).await?;
+        assert_eq!(
+            state.validator_abstentions(0).await?,
+            BTreeMap::from([(b, 20), (c, 30)])
+        );
+
+        // The third is inactive for the second proposal, which only the second validator votes on.
+        start_proposal(&mut state, 1, &[(a, 10), (b, 20)]);
+        vote(&mut state, 1, b);
+        state.record_validator_abstentions(1).await?;
+        assert_eq!(
+            state.validator_abstentions(1).await?,
+            BTreeMap::from([(a, 10)])
+        );
+
+        // Only proposals on which a validator was active count towards its participation.
+        for (identity_key, eligible, abstained) in [(a, 2, 1), (b, 2, 1), (c, 1, 1)] {
+            assert_eq!(
+                state.validator_proposals_eligible(&identity_key).await?,
+                eligible
+            );
+            assert_eq!(
+                state.validator_proposals_abstained(&identity_key).await?,
+                abstained
+            );
+        }
+
+        Ok(())
+    }
+}
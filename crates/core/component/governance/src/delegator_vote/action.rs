@@ -1,6 +1,6 @@
 use anyhow::Context;
 
-use crate::{vote::Vote, DelegatorVoteProof};
+use crate::{sealed_ballot::EncryptedVote, vote::Vote, DelegatorVoteProof};
 use decaf377_rdsa::{Signature, SpendAuth, VerificationKey};
 use penumbra_asset::Value;
 use penumbra_num::Amount;
@@ -39,6 +39,10 @@ pub struct DelegatorVoteBody {
     pub nullifier: Nullifier,
     /// The randomized validating key for the spend authorization signature.
     pub rk: VerificationKey<SpendAuth>,
+    /// The vote, encrypted to the proposal's ballot key, if the proposal has sealed ballots.
+    ///
+    /// When this is set, `vote` must be [`Vote::Abstain`], and is ignored.
+    pub encrypted_vote: Option<EncryptedVote>,
 }
 
 impl EffectingData for DelegatorVoteBody {
@@ -57,6 +61,7 @@ impl From<DelegatorVoteBody> for pb::DelegatorVoteBody {
             unbonded_amount: Some(value.unbonded_amount.into()),
             nullifier: Some(value.nullifier.into()),
             rk: Some(value.rk.into()),
+            encrypted_vote: value.encrypted_vote.map(Into::into),
         }
     }
 }
@@ -93,6 +98,11 @@ impl TryFrom<pb::DelegatorVoteBody> for DelegatorVoteBody {
                 .ok_or_else(|| anyhow::anyhow!("missing rk in `DelegatorVote`"))?
                 .try_into()
                 .context("invalid rk in `DelegatorVote`")?,
+            encrypted_vote: msg
+                .encrypted_vote
+                .map(TryInto::try_into)
+                .transpose()
+                .context("invalid encrypted vote in `DelegatorVote`")?,
         })
     }
 }
@@ -17,7 +17,11 @@ use crate::delegator_vote::action::{DelegatorVote, DelegatorVoteBody};
 use crate::delegator_vote::proof::DelegatorVoteProof;
 use crate::DelegatorVoteProofPrivate;
 use crate::DelegatorVoteProofPublic;
-use crate::{vote::Vote, VotingReceiptToken};
+use crate::{
+    sealed_ballot::{BallotKey, EncryptedVote},
+    vote::Vote,
+    VotingReceiptToken,
+};
 
 /// A plan to vote as a delegator.
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -41,6 +45,8 @@ pub struct DelegatorVotePlan {
     pub proof_blinding_r: Fq,
     /// The second blinding factor used for generating the ZK proof.
     pub proof_blinding_s: Fq,
+    /// The vote, encrypted to the proposal's ballot key, if the proposal has sealed ballots.
+    pub encrypted_vote: Option<EncryptedVote>,
 }
 
 impl DelegatorVotePlan {
@@ -65,9 +71,15 @@ impl DelegatorVotePlan {
             randomizer: Fr::rand(rng),
             proof_blinding_r: Fq::rand(rng),
             proof_blinding_s: Fq::rand(rng),
+            encrypted_vote: None,
         }
     }
 
+    /// Seal the vote, encrypting it to the ballot key of a proposal with sealed ballots.
+    pub fn seal<R: CryptoRng + RngCore>(&mut self, rng: &mut R, ballot_key: &BallotKey) {
+        self.encrypted_vote = Some(EncryptedVote::encrypt(rng, self.vote, ballot_key));
+    }
+
     /// Convenience method to construct the [`DelegatorVote`] described by this [`DelegatorVotePlan`].
     pub fn delegator_vote(
         &self,
@@ -87,7 +99,13 @@ impl DelegatorVotePlan {
         DelegatorVoteBody {
             proposal: self.proposal,
             start_position: self.start_position,
-            vote: self.vote,
+            // The plaintext vote of a sealed ballot is always an abstention, so as not to reveal
+            // the sealed vote.
+            vote: if self.encrypted_vote.is_some() {
+                Vote::Abstain
+            } else {
+                self.vote
+            },
             value: self.staked_note.value(),
             unbonded_amount: self.unbonded_amount,
             nullifier: Nullifier::derive(
@@ -96,6 +114,7 @@ impl DelegatorVotePlan {
                 &self.staked_note.commit(),
             ),
             rk: fvk.spend_verification_key().randomize(&self.randomizer),
+            encrypted_vote: self.encrypted_vote,
         }
     }
 
@@ -165,6 +184,7 @@ impl From<DelegatorVotePlan> for pb::DelegatorVotePlan {
             randomizer: inner.randomizer.to_bytes().to_vec(),
             proof_blinding_r: inner.proof_blinding_r.to_bytes().to_vec(),
             proof_blinding_s: inner.proof_blinding_s.to_bytes().to_vec(),
+            encrypted_vote: inner.encrypted_vote.map(Into::into),
         }
     }
 }
@@ -206,6 +226,7 @@ impl TryFrom<pb::DelegatorVotePlan> for DelegatorVotePlan {
             )?,
             proof_blinding_r: Fq::from_bytes(proof_blinding_r_bytes)?,
             proof_blinding_s: Fq::from_bytes(proof_blinding_s_bytes)?,
+            encrypted_vote: value.encrypted_vote.map(TryInto::try_into).transpose()?,
         })
     }
 }
@@ -0,0 +1,251 @@
+use anyhow::Context;
+use penumbra_asset::Value;
+use penumbra_num::{fixpoint::U128x128, Amount};
+use penumbra_proto::{penumbra::core::component::governance::v1 as pb, DomainType};
+use serde::{Deserialize, Serialize};
+
+/// A batch swap selling an accepted deposit asset for the staking token, which sets the asset's
+/// rate from its height until the next such batch swap.
+///
+/// Only batch swaps which sold the asset for at least the governance parameters'
+/// `deposit_rate_min_volume` of the staking token are recorded, so that the rate reflects what
+/// the asset could actually be sold for on chain.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(try_from = "pb::DepositRate", into = "pb::DepositRate")]
+pub struct DepositRate {
+    /// The value of the asset sold by the batch swap.
+    pub input: Value,
+    /// The amount of the staking token it was sold for.
+    pub output: Amount,
+    /// The height of the batch swap.
+    pub height: u64,
+}
+
+impl DepositRate {
+    /// The amount of the staking token each unit of the asset was sold for.
+    pub fn price(&self) -> anyhow::Result<U128x128> {
+        U128x128::ratio(self.output, self.input.amount)
+            .context("could not compute the rate of the batch swap")
+    }
+}
+
+/// The recent batch swaps selling an accepted deposit asset for the staking token, from which
+/// the rate at which proposal deposits in the asset are converted into the staking token is
+/// computed.
+///
+/// The rate is the average of the batch swaps' rates over a window of blocks, each weighted by
+/// the number of blocks it was the latest rate for. Moving it therefore takes trading at the
+/// moved rate, in volume, for a large part of the window, rather than in a single block.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(try_from = "pb::DepositRateHistory", into = "pb::DepositRateHistory")]
+pub struct DepositRateHistory {
+    /// The batch swaps, in order of height, starting with the last one at or before the start of
+    /// the window.
+    pub rates: Vec<DepositRate>,
+}
+
+impl DepositRateHistory {
+    /// Record a batch swap, forgetting those which no longer affect the rate over the window of
+    /// `window_blocks` blocks ending at its height.
+    pub fn record(&mut self, rate: DepositRate, window_blocks: u64) {
+        self.rates.push(rate);
+        // The last batch swap at or before the start of the window sets the rate at its start,
+        // so only the ones before it can be forgotten.
+        let window_start = rate.height.saturating_sub(window_blocks);
+        if let Some(first_kept) = self
+            .rates
+            .iter()
+            .rposition(|rate| rate.height <= window_start)
+        {
+            self.rates.drain(..first_kept);
+        }
+    }
+
+    /// The time-weighted average rate over the window of `window_blocks` blocks ending at
+    /// `height`.
+    ///
+    /// Fails if the batch swaps recorded so far don't cover the whole window, or if the last one
+    /// is more than `max_age_blocks` blocks old.
+    pub fn time_weighted_price(
+        &self,
+        height: u64,
+        window_blocks: u64,
+        max_age_blocks: u64,
+    ) -> anyhow::Result<U128x128> {
+        let (Some(first), Some(last)) = (self.rates.first(), self.rates.last()) else {
+            anyhow::bail!("no batch swaps have been recorded");
+        };
+        anyhow::ensure!(
+            height.saturating_sub(last.height) <= max_age_blocks,
+            "the last batch swap, at height {}, is more than {} blocks old",
+            last.height,
+            max_age_blocks,
+        );
+        let window_start = height.saturating_sub(window_blocks);
+        anyhow::ensure!(
+            first.height <= window_start,
+            "batch swaps have only been recorded since height {}, less than {} blocks ago",
+            first.height,
+            window_blocks,
+        );
+
+        if window_blocks == 0 {
+            // There's no window to average over, so the last rate applies.
+            return last.price();
+        }
+
+        let mut weighted_sum = U128x128::from(0u64);
+        for (rate, next_height) in self.rates.iter().zip(
+            self.rates
+                .iter()
+                .skip(1)
+                .map(|next| next.height)
+                .chain(std::iter::once(height)),
+        ) {
+            let blocks = next_height
+                .max(window_start)
+                .saturating_sub(rate.height.max(window_start));
+            if blocks == 0 {
+                continue;
+            }
+            weighted_sum =
+                weighted_sum.checked_add(&rate.price()?.checked_mul(&U128x128::from(blocks))?)?;
+        }
+        Ok(weighted_sum.checked_div(&U128x128::from(window_blocks))?)
+    }
+
+    /// The value of the given amount of the asset, in the staking token, rounded down, at the
+    /// time-weighted average rate over the window of `window_blocks` blocks ending at `height`.
+    pub fn staking_value(
+        &self,
+        amount: Amount,
+        height: u64,
+        window_blocks: u64,
+        max_age_blocks: u64,
+    ) -> anyhow::Result<Amount> {
+        self.time_weighted_price(height, window_blocks, max_age_blocks)?
+            .apply_to_amount(&amount)
+            .context("could not convert deposit into the staking token")
+    }
+}
+
+impl DomainType for DepositRate {
+    type Proto = pb::DepositRate;
+}
+
+impl From<DepositRate> for pb::DepositRate {
+    fn from(rate: DepositRate) -> Self {
+        pb::DepositRate {
+            input: Some(rate.input.into()),
+            output: Some(rate.output.into()),
+            height: rate.height,
+        }
+    }
+}
+
+impl TryFrom<pb::DepositRate> for DepositRate {
+    type Error = anyhow::Error;
+
+    fn try_from(msg: pb::DepositRate) -> Result<Self, Self::Error> {
+        Ok(DepositRate {
+            input: msg
+                .input
+                .ok_or_else(|| anyhow::anyhow!("missing input in `DepositRate`"))?
+                .try_into()?,
+            output: msg
+                .output
+                .ok_or_else(|| anyhow::anyhow!("missing output in `DepositRate`"))?
+                .try_into()?,
+            height: msg.height,
+        })
+    }
+}
+
+impl DomainType for DepositRateHistory {
+    type Proto = pb::DepositRateHistory;
+}
+
+impl From<DepositRateHistory> for pb::DepositRateHistory {
+    fn from(history: DepositRateHistory) -> Self {
+        pb::DepositRateHistory {
+            rates: history.rates.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+impl TryFrom<pb::DepositRateHistory> for DepositRateHistory {
+    type Error = anyhow::Error;
+
+    fn try_from(msg: pb::DepositRateHistory) -> Result<Self, Self::Error> {
+        Ok(DepositRateHistory {
+            rates: msg
+                .rates
+                .into_iter()
+                .map(TryInto::try_into)
+                .collect::<anyhow::Result<_>>()?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use penumbra_asset::STAKING_TOKEN_ASSET_ID;
+
+    use super::*;
+
+    fn rate(height: u64, input: u64, output: u64) -> DepositRate {
+        DepositRate {
+            input: Value {
+                amount: input.into(),
+                asset_id: *STAKING_TOKEN_ASSET_ID,
+            },
+            output: output.into(),
+            height,
+        }
+    }
+
+    #[test]
+    fn staking_value_rounds_down() {
+        let history = DepositRateHistory {
+            rates: vec![rate(1, 3, 2)],
+        };
+        assert_eq!(
+            history.staking_value(10u64.into(), 1, 0, 0).unwrap(),
+            6u64.into()
+        );
+    }
+
+    #[test]
+    fn rates_are_time_weighted_over_the_window() {
+        let mut history = DepositRateHistory::default();
+        history.record(rate(10, 1, 2), 10);
+        history.record(rate(15, 1, 4), 10);
+
+        // The window hasn't been covered yet.
+        assert!(history.time_weighted_price(19, 10, 10).is_err());
+
+        // A rate of 2 for 5 blocks and a rate of 4 for 5 blocks.
+        assert_eq!(
+            history.time_weighted_price(20, 10, 10).unwrap(),
+            U128x128::from(3u64)
+        );
+
+        // A spike in the last block only moves the average by a tenth of its size.
+        history.record(rate(24, 1, 24), 10);
+        assert_eq!(
+            history.time_weighted_price(25, 10, 10).unwrap(),
+            U128x128::from(6u64)
+        );
+
+        // Once the first rate no longer affects the window, it is forgotten.
+        history.record(rate(30, 1, 6), 10);
+        assert_eq!(history.rates[0].height, 15);
+        assert_eq!(
+            history.time_weighted_price(35, 10, 10).unwrap(),
+            U128x128::from(15u64)
+        );
+
+        // Rates which haven't been updated for too long aren't used.
+        assert!(history.time_weighted_price(35, 10, 4).is_err());
+    }
+}
@@ -1,6 +1,8 @@
 use penumbra_proto::penumbra::core::component::governance::v1 as pb;
+use penumbra_stake::IdentityKey;
 
 use crate::{
+    sealed_ballot::{BallotKey, BallotKeyComplaint, BallotKeyDeal, BallotKeyReveal},
     DelegatorVote, Proposal, ProposalDepositClaim, ProposalSubmit, ProposalWithdraw, ValidatorVote,
 };
 
@@ -53,3 +55,55 @@ pub fn proposal_slashed(proposal: &Proposal) -> pb::EventProposalSlashed {
         proposal: Some(pb::Proposal::from(proposal.clone())),
     }
 }
+
+pub fn validator_abstained(
+    proposal_id: u64,
+    identity_key: IdentityKey,
+    voting_power: u64,
+) -> pb::EventValidatorAbstained {
+    pb::EventValidatorAbstained {
+        proposal_id,
+        identity_key: Some(identity_key.into()),
+        voting_power,
+    }
+}
+
+pub fn ballot_key_deal(deal: &BallotKeyDeal) -> pb::EventBallotKeyDeal {
+    pb::EventBallotKeyDeal {
+        deal: Some(pb::BallotKeyDeal::from(deal.clone())),
+    }
+}
+
+pub fn ballot_key_complaint(complaint: &BallotKeyComplaint) -> pb::EventBallotKeyComplaint {
+    pb::EventBallotKeyComplaint {
+        complaint: Some(pb::BallotKeyComplaint::from(complaint.clone())),
+    }
+}
+
+pub fn ballot_key_reveal(reveal: &BallotKeyReveal) -> pb::EventBallotKeyReveal {
+    pb::EventBallotKeyReveal {
+        reveal: Some(pb::BallotKeyReveal::from(reveal.clone())),
+    }
+}
+
+pub fn ballot_key_finalized(
+    proposal_id: u64,
+    ballot_key: Option<BallotKey>,
+) -> pb::EventBallotKeyFinalized {
+    pb::EventBallotKeyFinalized {
+        proposal_id,
+        ballot_key: ballot_key.map(Into::into),
+    }
+}
+
+pub fn sealed_ballots_opened(
+    proposal_id: u64,
+    opened: u64,
+    discarded: u64,
+) -> pb::EventSealedBallotsOpened {
+    pb::EventSealedBallotsOpened {
+        proposal_id,
+        opened,
+        discarded,
+    }
+}
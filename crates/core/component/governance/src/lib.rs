@@ -12,6 +12,9 @@ pub use delegator_vote::{
 pub mod proposal_deposit_claim;
 pub use proposal_deposit_claim::ProposalDepositClaim;
 
+pub mod deposit_rate;
+pub use deposit_rate::{DepositRate, DepositRateHistory};
+
 pub mod validator_vote;
 pub use validator_vote::{
     ValidatorVote, ValidatorVoteBody, ValidatorVoteReason, MAX_VALIDATOR_VOTE_REASON_LENGTH,
@@ -23,8 +26,13 @@ pub use proposal_submit::ProposalSubmit;
 pub mod proposal_withdraw;
 pub use proposal_withdraw::ProposalWithdraw;
 
+pub mod sealed_ballot;
+pub use sealed_ballot::{
+    BallotKey, BallotKeyComplaint, BallotKeyDeal, BallotKeyReveal, EncryptedVote,
+};
+
 pub mod proposal;
-pub use proposal::{Proposal, ProposalKind, ProposalPayload};
+pub use proposal::{Proposal, ProposalKind, ProposalPayload, UpgradePlan};
 
 pub mod proposal_nft;
 pub mod proposal_state;
@@ -1,4 +1,5 @@
 use anyhow::Context;
+use penumbra_asset::asset;
 use penumbra_num::Amount;
 use penumbra_proto::core::component::governance::v1 as pb;
 use penumbra_proto::DomainType;
@@ -23,6 +24,23 @@ pub struct GovernanceParameters {
     pub proposal_pass_threshold: Ratio,
     /// The threshold for a proposal to be slashed, as a ratio of "no" votes over all total votes.
     pub proposal_slash_threshold: Ratio,
+    /// The number of blocks at the start of voting on a proposal with sealed ballots, during which
+    /// validators deal its ballot key.
+    pub sealed_ballot_setup_blocks: u64,
+    /// The number of blocks after voting on a proposal with sealed ballots has closed, during which
+    /// validators reveal the secrets needed to open its ballots.
+    pub sealed_ballot_reveal_blocks: u64,
+    /// Assets other than the staking token which proposal deposits may be paid in, at the
+    /// time-weighted average rate of the batch swaps selling them for the staking token.
+    pub accepted_deposit_assets: Vec<asset::Id>,
+    /// The number of blocks over which the rates of accepted deposit assets are averaged.
+    pub deposit_rate_window_blocks: u64,
+    /// The maximum number of blocks since the last batch swap selling an accepted deposit asset
+    /// for the staking token, for the asset to be used for deposits.
+    pub deposit_rate_max_age_blocks: u64,
+    /// The minimum amount of the staking token a batch swap must sell an accepted deposit asset
+    /// for, to count towards the asset's rate.
+    pub deposit_rate_min_volume: Amount,
 }
 
 impl DomainType for GovernanceParameters {
@@ -51,6 +69,20 @@ impl TryFrom<pb::GovernanceParameters> for GovernanceParameters {
                 .proposal_slash_threshold
                 .parse()
                 .context("couldn't parse proposal_slash_threshold")?,
+            sealed_ballot_setup_blocks: msg.sealed_ballot_setup_blocks,
+            sealed_ballot_reveal_blocks: msg.sealed_ballot_reveal_blocks,
+            accepted_deposit_assets: msg
+                .accepted_deposit_assets
+                .into_iter()
+                .map(TryInto::try_into)
+                .collect::<anyhow::Result<_>>()?,
+            deposit_rate_window_blocks: msg.deposit_rate_window_blocks,
+            deposit_rate_max_age_blocks: msg.deposit_rate_max_age_blocks,
+            deposit_rate_min_volume: msg
+                .deposit_rate_min_volume
+                .map(TryInto::try_into)
+                .transpose()?
+                .unwrap_or_default(),
         })
     }
 }
@@ -63,6 +95,16 @@ impl From<GovernanceParameters> for pb::GovernanceParameters {
             proposal_valid_quorum: params.proposal_valid_quorum.to_string(),
             proposal_pass_threshold: params.proposal_pass_threshold.to_string(),
             proposal_slash_threshold: params.proposal_slash_threshold.to_string(),
+            sealed_ballot_setup_blocks: params.sealed_ballot_setup_blocks,
+            sealed_ballot_reveal_blocks: params.sealed_ballot_reveal_blocks,
+            accepted_deposit_assets: params
+                .accepted_deposit_assets
+                .into_iter()
+                .map(Into::into)
+                .collect(),
+            deposit_rate_window_blocks: params.deposit_rate_window_blocks,
+            deposit_rate_max_age_blocks: params.deposit_rate_max_age_blocks,
+            deposit_rate_min_volume: Some(params.deposit_rate_min_volume.into()),
         }
     }
 }
@@ -78,6 +120,12 @@ impl Default for GovernanceParameters {
             proposal_pass_threshold: Ratio::new(50, 100),
             // slash threshold means if (no / no + yes + abstain) > slash_threshold, then proposal is slashed
             proposal_slash_threshold: Ratio::new(80, 100),
+            sealed_ballot_setup_blocks: 720, // 1 hour, at a 5 second block time
+            sealed_ballot_reveal_blocks: 720, // 1 hour, at a 5 second block time
+            accepted_deposit_assets: Vec::new(),
+            deposit_rate_window_blocks: 720, // 1 hour, at a 5 second block time
+            deposit_rate_max_age_blocks: 720, // 1 hour, at a 5 second block time
+            deposit_rate_min_volume: 1_000_000_000u64.into(), // 1,000 penumbra
         }
     }
 }
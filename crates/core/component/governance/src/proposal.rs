@@ -5,7 +5,8 @@ use serde::{Deserialize, Serialize};
 use std::str::FromStr;
 
 use crate::params::GovernanceParameters;
-use penumbra_community_pool::params::CommunityPoolParameters;
+use penumbra_community_pool::{params::CommunityPoolParameters, BudgetLine};
+use penumbra_dex::DexParameters;
 use penumbra_distributions::params::DistributionsParameters;
 use penumbra_fee::params::FeeParameters;
 use penumbra_ibc::params::IBCParameters;
@@ -27,6 +28,10 @@ pub struct Proposal {
     /// A natural-language description of the effect of the proposal and its justification.
     pub description: String,
 
+    /// Whether votes on the proposal are sealed: encrypted to a ballot key dealt by the
+    /// validators, and only opened once voting has closed.
+    pub sealed_ballots: bool,
+
     /// The specific kind and attributes of the proposal.
     pub payload: ProposalPayload,
 }
@@ -40,6 +45,7 @@ impl From<Proposal> for pb::Proposal {
             id: inner.id,
             title: inner.title,
             description: inner.description,
+            sealed_ballots: inner.sealed_ballots,
             ..Default::default() // We're about to fill in precisely one of the fields for the payload
         };
         use pb::proposal::Payload;
@@ -70,8 +76,8 @@ impl From<Proposal> for pb::Proposal {
                     }),
                 }),
             ),
-            ProposalPayload::UpgradePlan { height } => {
-                Some(Payload::UpgradePlan(pb::proposal::UpgradePlan { height }))
+            ProposalPayload::UpgradePlan(upgrade_plan) => {
+                Some(Payload::UpgradePlan(upgrade_plan.into()))
             }
             ProposalPayload::FreezeIbcClient { client_id } => {
                 Some(Payload::FreezeIbcClient(pb::proposal::FreezeIbcClient {
@@ -83,6 +89,13 @@ impl From<Proposal> for pb::Proposal {
                     client_id: client_id.into(),
                 },
             )),
+            ProposalPayload::RecurringBudget {
+                budget_line,
+                cancel,
+            } => Some(Payload::RecurringBudget(pb::proposal::RecurringBudget {
+                budget_line: budget_line.map(Into::into),
+                cancel,
+            })),
         };
         proposal.payload = payload;
         proposal
@@ -98,6 +111,7 @@ impl TryFrom<pb::Proposal> for Proposal {
             id: inner.id,
             title: inner.title,
             description: inner.description,
+            sealed_ballots: inner.sealed_ballots,
             payload: match inner
                 .payload
                 .ok_or_else(|| anyhow::anyhow!("missing proposal payload"))?
@@ -142,9 +156,9 @@ impl TryFrom<pb::Proposal> for Proposal {
                         },
                     }
                 }
-                Payload::UpgradePlan(upgrade_plan) => ProposalPayload::UpgradePlan {
-                    height: upgrade_plan.height,
-                },
+                Payload::UpgradePlan(upgrade_plan) => {
+                    ProposalPayload::UpgradePlan(upgrade_plan.try_into()?)
+                }
                 Payload::FreezeIbcClient(freeze_ibc_client) => ProposalPayload::FreezeIbcClient {
                     client_id: freeze_ibc_client.client_id,
                 },
@@ -153,6 +167,13 @@ impl TryFrom<pb::Proposal> for Proposal {
                         client_id: unfreeze_ibc_client.client_id,
                     }
                 }
+                Payload::RecurringBudget(recurring_budget) => ProposalPayload::RecurringBudget {
+                    budget_line: recurring_budget
+                        .budget_line
+                        .map(TryInto::try_into)
+                        .transpose()?,
+                    cancel: recurring_budget.cancel,
+                },
             },
         })
     }
@@ -168,6 +189,8 @@ pub struct ProposalToml {
     pub id: u64,
     pub title: String,
     pub description: String,
+    #[serde(default)]
+    pub sealed_ballots: bool,
     #[serde(flatten)]
     pub payload: ProposalPayloadToml,
 }
@@ -178,6 +201,7 @@ impl From<Proposal> for ProposalToml {
             id: proposal.id,
             title: proposal.title,
             description: proposal.description,
+            sealed_ballots: proposal.sealed_ballots,
             payload: proposal.payload.into(),
         }
     }
@@ -191,6 +215,7 @@ impl TryFrom<ProposalToml> for Proposal {
             id: proposal.id,
             title: proposal.title,
             description: proposal.description,
+            sealed_ballots: proposal.sealed_ballots,
             payload: proposal.payload.try_into()?,
         })
     }
@@ -221,6 +246,9 @@ pub enum ProposalKind {
     /// A proposal to unfreeze an IBC client.
     #[cfg_attr(feature = "clap", clap(display_order = 700))]
     UnfreezeIbcClient,
+    /// A proposal to establish or cancel recurring Community Pool budget lines.
+    #[cfg_attr(feature = "clap", clap(display_order = 800))]
+    RecurringBudget,
 }
 
 impl FromStr for ProposalKind {
@@ -233,6 +261,7 @@ impl FromStr for ProposalKind {
             "parameter_change" => Ok(ProposalKind::ParameterChange),
             "community_pool_spend" => Ok(ProposalKind::CommunityPoolSpend),
             "upgrade_plan" => Ok(ProposalKind::UpgradePlan),
+            "recurring_budget" => Ok(ProposalKind::RecurringBudget),
             _ => Err(anyhow::anyhow!("invalid proposal kind: {}", s)),
         }
     }
@@ -246,9 +275,10 @@ impl Proposal {
             ProposalPayload::Emergency { .. } => ProposalKind::Emergency,
             ProposalPayload::ParameterChange { .. } => ProposalKind::ParameterChange,
             ProposalPayload::CommunityPoolSpend { .. } => ProposalKind::CommunityPoolSpend,
-            ProposalPayload::UpgradePlan { .. } => ProposalKind::UpgradePlan,
+            ProposalPayload::UpgradePlan(_) => ProposalKind::UpgradePlan,
             ProposalPayload::FreezeIbcClient { .. } => ProposalKind::FreezeIbcClient,
             ProposalPayload::UnfreezeIbcClient { .. } => ProposalKind::UnfreezeIbcClient,
+            ProposalPayload::RecurringBudget { .. } => ProposalKind::RecurringBudget,
         }
     }
 }
@@ -296,7 +326,7 @@ pub enum ProposalPayload {
     },
     /// An upgrade plan proposal describes a planned upgrade to the chain. If ratified, the chain
     /// will halt at the specified height, trigger an epoch transition, and halt the chain.
-    UpgradePlan { height: u64 },
+    UpgradePlan(UpgradePlan),
     /// A proposal to freeze a specific IBC client.
     FreezeIbcClient {
         /// The identifier of the client to freeze.
@@ -307,6 +337,15 @@ pub enum ProposalPayload {
         /// The identifier of the client to unfreeze.
         client_id: String,
     },
+    /// A recurring budget proposal establishes a budget line, which the Community Pool pays
+    /// automatically at the end of each epoch once the proposal is passed, and/or cancels budget
+    /// lines established by previous proposals.
+    RecurringBudget {
+        /// The budget line to establish, identified by the ID of the proposal, if any.
+        budget_line: Option<BudgetLine>,
+        /// The IDs of the active budget lines to cancel.
+        cancel: Vec<u64>,
+    },
 }
 
 /// A TOML-serializable version of `ProposalPayload`, meant for human consumption.
@@ -328,6 +367,8 @@ pub enum ProposalPayloadToml {
     },
     UpgradePlan {
         height: u64,
+        version: Option<String>,
+        binary_hash: Option<String>,
     },
     FreezeIbcClient {
         client_id: String,
@@ -335,6 +376,55 @@ pub enum ProposalPayloadToml {
     UnfreezeIbcClient {
         client_id: String,
     },
+    RecurringBudget {
+        budget_line: Option<BudgetLineToml>,
+        #[serde(default)]
+        cancel: Vec<u64>,
+    },
+}
+
+/// A TOML-serializable version of a [`BudgetLine`], with the recipient as an address string and
+/// the amount per epoch as a value string, e.g. `100penumbra`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BudgetLineToml {
+    pub recipient: String,
+    pub amount_per_epoch: String,
+    pub epochs: u64,
+    #[serde(default)]
+    pub cancel_if_underfunded: bool,
+}
+
+impl TryFrom<BudgetLineToml> for BudgetLine {
+    type Error = anyhow::Error;
+
+    fn try_from(toml: BudgetLineToml) -> Result<Self, Self::Error> {
+        Ok(BudgetLine {
+            recipient: toml
+                .recipient
+                .parse()
+                .context("couldn't parse budget line recipient")?,
+            amount_per_epoch: toml
+                .amount_per_epoch
+                .parse()
+                .context("couldn't parse budget line amount per epoch")?,
+            epochs: toml.epochs,
+            cancel_if_underfunded: toml.cancel_if_underfunded,
+        })
+    }
+}
+
+impl From<BudgetLine> for BudgetLineToml {
+    fn from(budget_line: BudgetLine) -> Self {
+        BudgetLineToml {
+            recipient: budget_line.recipient.to_string(),
+            amount_per_epoch: format!(
+                "{}{}",
+                budget_line.amount_per_epoch.amount, budget_line.amount_per_epoch.asset_id
+            ),
+            epochs: budget_line.epochs,
+            cancel_if_underfunded: budget_line.cancel_if_underfunded,
+        }
+    }
 }
 
 impl TryFrom<ProposalPayloadToml> for ProposalPayload {
@@ -361,13 +451,28 @@ impl TryFrom<ProposalPayloadToml> for ProposalPayload {
                     .to_vec(),
                 }
             }
-            ProposalPayloadToml::UpgradePlan { height } => ProposalPayload::UpgradePlan { height },
+            ProposalPayloadToml::UpgradePlan {
+                height,
+                version,
+                binary_hash,
+            } => ProposalPayload::UpgradePlan(UpgradePlan {
+                height,
+                version,
+                binary_hash,
+            }),
             ProposalPayloadToml::FreezeIbcClient { client_id } => {
                 ProposalPayload::FreezeIbcClient { client_id }
             }
             ProposalPayloadToml::UnfreezeIbcClient { client_id } => {
                 ProposalPayload::UnfreezeIbcClient { client_id }
             }
+            ProposalPayloadToml::RecurringBudget {
+                budget_line,
+                cancel,
+            } => ProposalPayload::RecurringBudget {
+                budget_line: budget_line.map(TryInto::try_into).transpose()?,
+                cancel,
+            },
         })
     }
 }
@@ -390,13 +495,28 @@ impl From<ProposalPayload> for ProposalPayloadToml {
                     ),
                 }
             }
-            ProposalPayload::UpgradePlan { height } => ProposalPayloadToml::UpgradePlan { height },
+            ProposalPayload::UpgradePlan(UpgradePlan {
+                height,
+                version,
+                binary_hash,
+            }) => ProposalPayloadToml::UpgradePlan {
+                height,
+                version,
+                binary_hash,
+            },
             ProposalPayload::FreezeIbcClient { client_id } => {
                 ProposalPayloadToml::FreezeIbcClient { client_id }
             }
             ProposalPayload::UnfreezeIbcClient { client_id } => {
                 ProposalPayloadToml::UnfreezeIbcClient { client_id }
             }
+            ProposalPayload::RecurringBudget {
+                budget_line,
+                cancel,
+            } => ProposalPayloadToml::RecurringBudget {
+                budget_line: budget_line.map(Into::into),
+                cancel,
+            },
         }
     }
 }
@@ -419,9 +539,125 @@ impl ProposalPayload {
         matches!(self, ProposalPayload::ParameterChange { .. })
     }
 
+    /// Whether this is a parameter change which only pauses or resumes trading on some pairs.
+    ///
+    /// Like IBC client freezes, these can be enacted by an emergency supermajority of validators.
+    pub fn is_trading_pause(&self) -> bool {
+        let ProposalPayload::ParameterChange { old, new } = self else {
+            return false;
+        };
+        let ChangedAppParameters {
+            community_pool_params: None,
+            dex_params: Some(new_dex_params),
+            distributions_params: None,
+            ibc_params: None,
+            fee_params: None,
+            funding_params: None,
+            governance_params: None,
+            sct_params: None,
+            shielded_pool_params: None,
+            stake_params: None,
+        } = new.as_ref()
+        else {
+            return false;
+        };
+        let Some(old_dex_params) = &old.dex_params else {
+            return false;
+        };
+
+        // Every other dex parameter must be left unchanged.
+        let new_dex_params = DexParameters {
+            paused_trading_pairs: old_dex_params.paused_trading_pairs.clone(),
+            ..new_dex_params.clone()
+        };
+        new_dex_params == *old_dex_params
+    }
+
     pub fn is_community_pool_spend(&self) -> bool {
         matches!(self, ProposalPayload::CommunityPoolSpend { .. })
     }
+
+    pub fn is_recurring_budget(&self) -> bool {
+        matches!(self, ProposalPayload::RecurringBudget { .. })
+    }
+}
+
+/// A planned upgrade to the chain.
+///
+/// Besides the height at which the chain halts, an upgrade plan can pin the release which must be
+/// run after the upgrade, so that operators can check they are running the agreed-upon binary, and
+/// nodes can refuse to continue past the upgrade height with any other release.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(
+    try_from = "pb::proposal::UpgradePlan",
+    into = "pb::proposal::UpgradePlan"
+)]
+pub struct UpgradePlan {
+    /// The height at which the chain will halt for the upgrade.
+    pub height: u64,
+    /// The version string of the `pd` release which must be run after the upgrade, if any.
+    pub version: Option<String>,
+    /// The hex-encoded SHA-256 hash of the upgraded `pd` binary, if any.
+    pub binary_hash: Option<String>,
+}
+
+impl UpgradePlan {
+    /// Check that the upgrade plan is well-formed.
+    pub fn check_stateless(&self) -> anyhow::Result<()> {
+        if let Some(version) = &self.version {
+            anyhow::ensure!(
+                !version.trim().is_empty() && version.trim() == version,
+                "upgrade plan version must be non-empty and have no surrounding whitespace"
+            );
+        }
+        if let Some(binary_hash) = &self.binary_hash {
+            let bytes =
+                hex::decode(binary_hash).context("upgrade plan binary hash must be hex-encoded")?;
+            anyhow::ensure!(
+                bytes.len() == 32,
+                "upgrade plan binary hash must be a SHA-256 hash"
+            );
+        }
+        Ok(())
+    }
+
+    /// Returns true if the given release version satisfies this upgrade plan.
+    ///
+    /// A plan which doesn't specify a version is satisfied by any release. Versions are compared
+    /// ignoring any leading `v`, so that `v1.2.3` and `1.2.3` are considered equal.
+    pub fn accepts_version(&self, version: &str) -> bool {
+        match &self.version {
+            None => true,
+            Some(expected) => expected.trim_start_matches('v') == version.trim_start_matches('v'),
+        }
+    }
+}
+
+impl DomainType for UpgradePlan {
+    type Proto = pb::proposal::UpgradePlan;
+}
+
+impl From<UpgradePlan> for pb::proposal::UpgradePlan {
+    fn from(plan: UpgradePlan) -> Self {
+        pb::proposal::UpgradePlan {
+            height: plan.height,
+            version: plan.version.unwrap_or_default(),
+            binary_hash: plan.binary_hash.unwrap_or_default(),
+        }
+    }
+}
+
+impl TryFrom<pb::proposal::UpgradePlan> for UpgradePlan {
+    type Error = anyhow::Error;
+
+    fn try_from(msg: pb::proposal::UpgradePlan) -> anyhow::Result<Self> {
+        let non_empty = |s: String| if s.is_empty() { None } else { Some(s) };
+        Ok(UpgradePlan {
+            height: msg.height,
+            version: non_empty(msg.version),
+            binary_hash: non_empty(msg.binary_hash),
+        })
+    }
 }
 
 /// Indicates which app parameters have changed during the
@@ -436,6 +672,7 @@ impl ProposalPayload {
 )]
 pub struct ChangedAppParameters {
     pub community_pool_params: Option<CommunityPoolParameters>,
+    pub dex_params: Option<DexParameters>,
     pub distributions_params: Option<DistributionsParameters>,
     pub ibc_params: Option<IBCParameters>,
     pub fee_params: Option<FeeParameters>,
@@ -459,6 +696,7 @@ impl TryFrom<pb::ChangedAppParameters> for ChangedAppParameters {
                 .community_pool_params
                 .map(TryInto::try_into)
                 .transpose()?,
+            dex_params: msg.dex_params.map(TryInto::try_into).transpose()?,
             distributions_params: msg
                 .distributions_params
                 .map(TryInto::try_into)
@@ -481,6 +719,7 @@ impl From<ChangedAppParameters> for pb::ChangedAppParameters {
     fn from(params: ChangedAppParameters) -> Self {
         pb::ChangedAppParameters {
             community_pool_params: params.community_pool_params.map(Into::into),
+            dex_params: params.dex_params.map(Into::into),
             distributions_params: params.distributions_params.map(Into::into),
             fee_params: params.fee_params.map(Into::into),
             funding_params: params.funding_params.map(Into::into),
@@ -534,3 +773,63 @@ impl From<ChangedAppParametersSet> for pb::ChangedAppParametersSet {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use penumbra_asset::STAKING_TOKEN_ASSET_ID;
+    use penumbra_keys::test_keys;
+
+    use super::*;
+
+    #[test]
+    fn recurring_budget_round_trips() -> anyhow::Result<()> {
+        let payload = ProposalPayload::RecurringBudget {
+            budget_line: Some(BudgetLine {
+                recipient: *test_keys::ADDRESS_0,
+                amount_per_epoch: penumbra_asset::Value {
+                    // More than fits in a u64, to check amounts aren't truncated in TOML.
+                    amount: (u128::from(u64::MAX) + 1_000).into(),
+                    asset_id: *STAKING_TOKEN_ASSET_ID,
+                },
+                epochs: 12,
+                cancel_if_underfunded: true,
+            }),
+            cancel: vec![3, 5],
+        };
+        let proposal = Proposal {
+            id: 7,
+            title: "Fund the block explorer".to_string(),
+            description: String::new(),
+            sealed_ballots: false,
+            payload: payload.clone(),
+        };
+        assert_eq!(proposal.kind(), ProposalKind::RecurringBudget);
+        assert_eq!(
+            Proposal::decode(proposal.encode_to_vec().as_slice())?,
+            proposal
+        );
+
+        let toml = ProposalPayloadToml::from(payload.clone());
+        assert_eq!(ProposalPayload::try_from(toml)?, payload);
+        Ok(())
+    }
+
+    #[test]
+    fn upgrade_plan_accepts_only_the_pinned_version() {
+        let unpinned = UpgradePlan {
+            height: 100,
+            ..Default::default()
+        };
+        assert!(unpinned.accepts_version("0.80.0"));
+
+        let pinned = UpgradePlan {
+            height: 100,
+            version: Some("v0.80.1".to_string()),
+            binary_hash: None,
+        };
+        assert!(pinned.accepts_version("0.80.1"));
+        assert!(pinned.accepts_version("v0.80.1"));
+        assert!(!pinned.accepts_version("0.80.0"));
+        assert!(!pinned.accepts_version("0.80.10"));
+    }
+}
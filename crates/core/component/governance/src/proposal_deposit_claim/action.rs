@@ -23,6 +23,8 @@ pub struct ProposalDepositClaim {
     pub proposal: u64,
     /// The amount of the deposit.
     pub deposit_amount: Amount,
+    /// The asset the deposit was paid in, and is refunded in.
+    pub deposit_asset_id: asset::Id,
     /// The outcome of the proposal.
     pub outcome: Outcome<()>,
 }
@@ -43,6 +45,9 @@ impl From<ProposalDepositClaim> for pb::ProposalDepositClaim {
             proposal: value.proposal,
             deposit_amount: Some(value.deposit_amount.into()),
             outcome: Some(value.outcome.into()),
+            // Deposits in the staking token leave the asset unset, as they always have.
+            deposit_asset_id: (value.deposit_asset_id != *STAKING_TOKEN_ASSET_ID)
+                .then(|| value.deposit_asset_id.into()),
         }
     }
 }
@@ -61,6 +66,11 @@ impl TryFrom<pb::ProposalDepositClaim> for ProposalDepositClaim {
                 .outcome
                 .ok_or_else(|| anyhow::anyhow!("missing outcome in `ProposalDepositClaim`"))?
                 .try_into()?,
+            deposit_asset_id: msg
+                .deposit_asset_id
+                .map(TryInto::try_into)
+                .transpose()?
+                .unwrap_or(*STAKING_TOKEN_ASSET_ID),
         })
     }
 }
@@ -70,7 +80,7 @@ impl ProposalDepositClaim {
     pub fn balance(&self) -> Balance {
         let deposit = Value {
             amount: self.deposit_amount,
-            asset_id: *STAKING_TOKEN_ASSET_ID,
+            asset_id: self.deposit_asset_id,
         };
 
         let (voting_or_withdrawn_proposal_denom, claimed_proposal_denom): (Metadata, Metadata) =
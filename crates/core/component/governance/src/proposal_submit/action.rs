@@ -1,6 +1,6 @@
 use serde::{Deserialize, Serialize};
 
-use penumbra_asset::{Balance, Value, STAKING_TOKEN_ASSET_ID};
+use penumbra_asset::{asset, Balance, Value, STAKING_TOKEN_ASSET_ID};
 use penumbra_num::Amount;
 use penumbra_proto::{penumbra::core::component::governance::v1 as pb, DomainType};
 use penumbra_txhash::{EffectHash, EffectingData};
@@ -19,6 +19,8 @@ pub struct ProposalSubmit {
     pub proposal: Proposal,
     /// The amount deposited for the proposal.
     pub deposit_amount: Amount,
+    /// The asset the deposit is paid in.
+    pub deposit_asset_id: asset::Id,
 }
 
 impl EffectingData for ProposalSubmit {
@@ -32,7 +34,7 @@ impl ProposalSubmit {
     pub fn balance(&self) -> Balance {
         let deposit = Value {
             amount: self.deposit_amount,
-            asset_id: *STAKING_TOKEN_ASSET_ID,
+            asset_id: self.deposit_asset_id,
         };
 
         let proposal_nft = Value {
@@ -52,6 +54,9 @@ impl From<ProposalSubmit> for pb::ProposalSubmit {
         pb::ProposalSubmit {
             proposal: Some(value.proposal.into()),
             deposit_amount: Some(value.deposit_amount.into()),
+            // Deposits in the staking token leave the asset unset, as they always have.
+            deposit_asset_id: (value.deposit_asset_id != *STAKING_TOKEN_ASSET_ID)
+                .then(|| value.deposit_asset_id.into()),
         }
     }
 }
@@ -69,6 +74,11 @@ impl TryFrom<pb::ProposalSubmit> for ProposalSubmit {
                 .deposit_amount
                 .ok_or_else(|| anyhow::anyhow!("missing deposit amount in `Propose`"))?
                 .try_into()?,
+            deposit_asset_id: msg
+                .deposit_asset_id
+                .map(TryInto::try_into)
+                .transpose()?
+                .unwrap_or(*STAKING_TOKEN_ASSET_ID),
         })
     }
 }
@@ -0,0 +1,898 @@
+//! Sealed ballots, which are encrypted to a per-proposal ballot key and only opened once voting on
+//! the proposal has closed, so that nobody can see how the vote is going while it is still open.
+//!
+//! The ballot key of a proposal with sealed ballots is dealt jointly by the validators active at
+//! the start of the proposal, during the first blocks of its voting period:
+//!
+//! 1. Each validator derives a secret polynomial from its governance key, and publishes a [`BallotKeyDeal`]
+//!    committing to its coefficients, along with a share of the polynomial for every share
+//!    position, encrypted to the governance key of the validator the position is allocated to.
+//!    Share positions are allocated in proportion to voting power by a [`ShareAllocation`].
+//! 2. The shares can only be checked against the dealer's commitments by their recipient, so a
+//!    validator which receives an invalid share publishes a [`BallotKeyComplaint`], proving which
+//!    key the share was encrypted with so that anyone can check it.  A dealer with a valid
+//!    complaint against it is excluded, and the ballot key is the sum of the commitments to the
+//!    constant terms of the polynomials of the remaining dealers.
+//! 3. Votes are cast with their [`Vote`] encrypted to the ballot key, as an [`EncryptedVote`].
+//! 4. Once voting has closed, each validator publishes a [`BallotKeyReveal`], revealing the
+//!    constant term of its own polynomial, along with the shares it received from the other
+//!    dealers.  The secret of a dealer which fails to reveal it can be recovered from the
+//!    [`ShareAllocation::threshold`] number of its shares, held by validators with more than a
+//!    third of the voting power; revealed shares which don't match the dealer's commitments are
+//!    ignored.
+//! 5. Once every dealer's secret is known, the ballots are decrypted and tallied as usual.
+
+use std::{collections::BTreeMap, ops::Range};
+
+use anyhow::{anyhow, Context};
+use ark_ff::{Field as _, One, PrimeField as _, UniformRand, Zero};
+use decaf377::{Element, Encoding, FieldExt, Fr};
+use decaf377_rdsa::{SigningKey, SpendAuth};
+use penumbra_proto::{penumbra::core::component::governance::v1 as pb, DomainType};
+use penumbra_stake::{GovernanceKey, IdentityKey};
+use rand_core::{CryptoRng, RngCore};
+use serde::{Deserialize, Serialize};
+
+use crate::vote::Vote;
+
+pub mod action;
+pub use action::{
+    BallotKeyComplaint, BallotKeyComplaintBody, BallotKeyDeal, BallotKeyDealBody, BallotKeyReveal,
+    BallotKeyRevealBody, RevealedShare,
+};
+
+/// The number of share positions at which each dealer's polynomial is evaluated, before rounding
+/// down the share of each validator.
+pub const TOTAL_SHARES: u64 = 128;
+
+/// The allocation of the share positions of each dealer's polynomial to the validators active at
+/// the start of a proposal.
+///
+/// Each validator is allocated a contiguous range of positions, in order of identity key, whose
+/// length is proportional to its voting power, so that recovering a dealer's secret requires the
+/// cooperation of validators holding more than a third of the voting power (up to rounding), and
+/// the ballots of a proposal can't be opened early unless that much voting power colludes.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ShareAllocation {
+    positions: BTreeMap<IdentityKey, Range<usize>>,
+    total: usize,
+}
+
+impl ShareAllocation {
+    /// Allocate share positions to the validators active at the start of a proposal, given their
+    /// voting power.
+    pub fn new(voting_power: &BTreeMap<IdentityKey, u64>) -> Self {
+        let total_power: u128 = voting_power.values().map(|power| *power as u128).sum();
+        let mut positions = BTreeMap::new();
+        let mut total = 0;
+        for (identity_key, power) in voting_power {
+            let count = (*power as u128 * TOTAL_SHARES as u128)
+                .checked_div(total_power)
+                .unwrap_or_default() as usize;
+            positions.insert(*identity_key, total..total + count);
+            total += count;
+        }
+        ShareAllocation { positions, total }
+    }
+
+    /// The number of share positions allocated in total.
+    pub fn total(&self) -> usize {
+        self.total
+    }
+
+    /// The number of shares of a dealer's secret needed to recover it, which is also the number
+    /// of coefficients of each dealer's polynomial.
+    pub fn threshold(&self) -> usize {
+        self.total / 3 + 1
+    }
+
+    /// The share positions allocated to a validator, or `None` if it wasn't active at the start
+    /// of the proposal.
+    pub fn positions(&self, identity_key: &IdentityKey) -> Option<Range<usize>> {
+        self.positions.get(identity_key).cloned()
+    }
+
+    /// The validators active at the start of the proposal, in order of identity key, along with
+    /// their share positions.
+    pub fn participants(&self) -> impl Iterator<Item = (&IdentityKey, Range<usize>)> {
+        self.positions
+            .iter()
+            .map(|(identity_key, positions)| (identity_key, positions.clone()))
+    }
+
+    /// The number of share positions allocated to the given validators.
+    pub fn weight<'a>(&self, validators: impl IntoIterator<Item = &'a IdentityKey>) -> usize {
+        validators
+            .into_iter()
+            .filter_map(|identity_key| self.positions.get(identity_key))
+            .map(|positions| positions.len())
+            .sum()
+    }
+}
+
+/// The scalar at which a dealer's polynomial is evaluated for the share at the given position.
+pub fn share_index(position: usize) -> Fr {
+    Fr::from(position as u64 + 1)
+}
+
+/// Decode a decaf377 element from its encoding.
+pub(crate) fn decode_element(bytes: &[u8]) -> anyhow::Result<Element> {
+    let bytes: [u8; 32] = bytes
+        .try_into()
+        .map_err(|_| anyhow!("element encoding must be 32 bytes"))?;
+    Encoding(bytes)
+        .vartime_decompress()
+        .map_err(|_| anyhow!("invalid element encoding"))
+}
+
+/// Decode a decaf377 scalar from its encoding.
+pub(crate) fn decode_scalar(bytes: &[u8]) -> anyhow::Result<Fr> {
+    let bytes: [u8; 32] = bytes
+        .try_into()
+        .map_err(|_| anyhow!("scalar encoding must be 32 bytes"))?;
+    Fr::from_bytes(bytes).map_err(|_| anyhow!("invalid scalar encoding"))
+}
+
+/// The key to which the ballots of a proposal with sealed ballots are encrypted.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(try_from = "pb::BallotKey", into = "pb::BallotKey")]
+pub struct BallotKey(pub Element);
+
+impl BallotKey {
+    /// Combine the commitments to the secrets of each dealer into the ballot key.
+    pub fn from_dealer_commitments<'a>(commitments: impl IntoIterator<Item = &'a Element>) -> Self {
+        BallotKey(
+            commitments
+                .into_iter()
+                .fold(Element::default(), |key, commitment| key + commitment),
+        )
+    }
+}
+
+impl DomainType for BallotKey {
+    type Proto = pb::BallotKey;
+}
+
+impl From<BallotKey> for pb::BallotKey {
+    fn from(key: BallotKey) -> Self {
+        pb::BallotKey {
+            inner: key.0.vartime_compress().0.to_vec(),
+        }
+    }
+}
+
+impl TryFrom<pb::BallotKey> for BallotKey {
+    type Error = anyhow::Error;
+
+    fn try_from(msg: pb::BallotKey) -> Result<Self, Self::Error> {
+        Ok(BallotKey(
+            decode_element(&msg.inner).context("invalid ballot key")?,
+        ))
+    }
+}
+
+/// The message encrypted by an [`EncryptedVote`]: the vote's protobuf enum value times the
+/// generator.
+fn vote_element(vote: Vote) -> Element {
+    let value = pb::Vote::from(vote).vote as u64;
+    Fr::from(value) * decaf377::basepoint()
+}
+
+/// A vote, ElGamal-encrypted to a proposal's [`BallotKey`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(try_from = "pb::EncryptedVote", into = "pb::EncryptedVote")]
+pub struct EncryptedVote {
+    /// The ephemeral key, `rG`.
+    pub ephemeral_key: Element,
+    /// The encrypted vote, `rK + vG`.
+    pub ciphertext: Element,
+}
+
+impl EncryptedVote {
+    /// Encrypt the vote to the ballot key.
+    pub fn encrypt<R: RngCore + CryptoRng>(
+        rng: &mut R,
+        vote: Vote,
+        ballot_key: &BallotKey,
+    ) -> Self {
+        let r = Fr::rand(rng);
+        EncryptedVote {
+            ephemeral_key: r * decaf377::basepoint(),
+            ciphertext: r * ballot_key.0 + vote_element(vote),
+        }
+    }
+
+    /// Decrypt the vote with the secret ballot key, returning `None` if it doesn't encrypt a
+    /// valid vote.
+    pub fn decrypt(&self, secret: &Fr) -> Option<Vote> {
+        let message = self.ciphertext - *secret * self.ephemeral_key;
+        [Vote::Yes, Vote::No, Vote::Abstain]
+            .into_iter()
+            .find(|vote| vote_element(*vote) == message)
+    }
+}
+
+impl DomainType for EncryptedVote {
+    type Proto = pb::EncryptedVote;
+}
+
+impl From<EncryptedVote> for pb::EncryptedVote {
+    fn from(vote: EncryptedVote) -> Self {
+        pb::EncryptedVote {
+            ephemeral_key: vote.ephemeral_key.vartime_compress().0.to_vec(),
+            ciphertext: vote.ciphertext.vartime_compress().0.to_vec(),
+        }
+    }
+}
+
+impl TryFrom<pb::EncryptedVote> for EncryptedVote {
+    type Error = anyhow::Error;
+
+    fn try_from(msg: pb::EncryptedVote) -> Result<Self, Self::Error> {
+        Ok(EncryptedVote {
+            ephemeral_key: decode_element(&msg.ephemeral_key)
+                .context("invalid ephemeral key in encrypted vote")?,
+            ciphertext: decode_element(&msg.ciphertext)
+                .context("invalid ciphertext in encrypted vote")?,
+        })
+    }
+}
+
+/// A share of a dealer's secret polynomial, encrypted to a validator's governance key.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct EncryptedShare {
+    /// The ephemeral key, `eG`.
+    pub ephemeral_key: Element,
+    /// The share, masked with a key derived from the shared secret `eP`, where `P` is the
+    /// recipient's governance key.
+    pub ciphertext: [u8; 32],
+}
+
+/// Derive the key masking a share of the given proposal's ballot key from the shared secret.
+fn share_mask(proposal: u64, shared_secret: Element) -> [u8; 32] {
+    let hash = blake2b_simd::Params::default()
+        .personal(b"Penumbra_BKShare")
+        .hash_length(32)
+        .to_state()
+        .update(&proposal.to_le_bytes())
+        .update(&shared_secret.vartime_compress().0)
+        .finalize();
+    hash.as_bytes().try_into().expect("hash length is 32 bytes")
+}
+
+fn xor(mut bytes: [u8; 32], mask: [u8; 32]) -> [u8; 32] {
+    for (byte, mask) in bytes.iter_mut().zip(mask) {
+        *byte ^= mask;
+    }
+    bytes
+}
+
+impl EncryptedShare {
+    /// Encrypt a share of the given proposal's ballot key to the recipient's governance key.
+    pub fn encrypt<R: RngCore + CryptoRng>(
+        rng: &mut R,
+        proposal: u64,
+        share: &Fr,
+        recipient: &GovernanceKey,
+    ) -> anyhow::Result<Self> {
+        let recipient = decode_element(&recipient.0.to_bytes())
+            .context("invalid governance key for share recipient")?;
+        let e = Fr::rand(rng);
+        Ok(EncryptedShare {
+            ephemeral_key: e * decaf377::basepoint(),
+            ciphertext: xor(share.to_bytes(), share_mask(proposal, e * recipient)),
+        })
+    }
+
+    /// Decrypt a share of the given proposal's ballot key with the recipient's governance
+    /// signing key.
+    pub fn decrypt(
+        &self,
+        proposal: u64,
+        governance_signing_key: &SigningKey<SpendAuth>,
+    ) -> anyhow::Result<Fr> {
+        let secret = governance_secret(governance_signing_key)?;
+        self.decrypt_with_shared_secret(proposal, secret * self.ephemeral_key)
+    }
+
+    /// Decrypt a share of the given proposal's ballot key with the secret shared by its dealer
+    /// and its recipient.
+    pub fn decrypt_with_shared_secret(
+        &self,
+        proposal: u64,
+        shared_secret: Element,
+    ) -> anyhow::Result<Fr> {
+        let mask = share_mask(proposal, shared_secret);
+        Fr::from_bytes(xor(self.ciphertext, mask)).map_err(|_| anyhow!("share did not decrypt"))
+    }
+}
+
+/// The scalar of a governance signing key.
+fn governance_secret(governance_signing_key: &SigningKey<SpendAuth>) -> anyhow::Result<Fr> {
+    Fr::from_bytes(governance_signing_key.to_bytes())
+        .map_err(|_| anyhow!("invalid governance signing key"))
+}
+
+/// A proof that the secret shared by the dealer and the recipient of an [`EncryptedShare`] is the
+/// product of the share's ephemeral key and the recipient's secret governance key.
+///
+/// This is a Chaum-Pedersen proof that the shared secret has the same discrete logarithm with
+/// respect to the ephemeral key as the recipient's governance key has with respect to the
+/// generator.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct SharedSecretProof {
+    challenge: Fr,
+    response: Fr,
+}
+
+impl SharedSecretProof {
+    /// Compute the secret shared with the dealer of an encrypted share, along with a proof of
+    /// its correctness.
+    pub fn prove<R: RngCore + CryptoRng>(
+        rng: &mut R,
+        governance_signing_key: &SigningKey<SpendAuth>,
+        ephemeral_key: Element,
+    ) -> anyhow::Result<(Element, Self)> {
+        let secret = governance_secret(governance_signing_key)?;
+        let governance_key = secret * decaf377::basepoint();
+        let shared_secret = secret * ephemeral_key;
+
+        let nonce = Fr::rand(rng);
+        let challenge = Self::challenge(
+            governance_key,
+            ephemeral_key,
+            shared_secret,
+            nonce * decaf377::basepoint(),
+            nonce * ephemeral_key,
+        );
+        Ok((
+            shared_secret,
+            SharedSecretProof {
+                challenge,
+                response: nonce + challenge * secret,
+            },
+        ))
+    }
+
+    /// Check that the shared secret is the product of the ephemeral key and the secret
+    /// corresponding to the governance key.
+    pub fn verify(
+        &self,
+        governance_key: &GovernanceKey,
+        ephemeral_key: Element,
+        shared_secret: Element,
+    ) -> anyhow::Result<()> {
+        let governance_key =
+            decode_element(&governance_key.0.to_bytes()).context("invalid governance key")?;
+        let challenge = Self::challenge(
+            governance_key,
+            ephemeral_key,
+            shared_secret,
+            self.response * decaf377::basepoint() - self.challenge * governance_key,
+            self.response * ephemeral_key - self.challenge * shared_secret,
+        );
+        if challenge != self.challenge {
+            anyhow::bail!("shared secret proof did not verify");
+        }
+        Ok(())
+    }
+
+    fn challenge(
+        governance_key: Element,
+        ephemeral_key: Element,
+        shared_secret: Element,
+        basepoint_commitment: Element,
+        ephemeral_key_commitment: Element,
+    ) -> Fr {
+        let mut state = blake2b_simd::Params::default()
+            .personal(b"Penumbra_BKDLEQ")
+            .to_state();
+        for element in [
+            governance_key,
+            ephemeral_key,
+            shared_secret,
+            basepoint_commitment,
+            ephemeral_key_commitment,
+        ] {
+            state.update(&element.vartime_compress().0);
+        }
+        Fr::from_le_bytes_mod_order(state.finalize().as_bytes())
+    }
+
+    /// Encode the proof as its challenge followed by its response.
+    pub fn to_bytes(&self) -> [u8; 64] {
+        let mut bytes = [0u8; 64];
+        bytes[..32].copy_from_slice(&self.challenge.to_bytes());
+        bytes[32..].copy_from_slice(&self.response.to_bytes());
+        bytes
+    }
+}
+
+impl TryFrom<&[u8]> for SharedSecretProof {
+    type Error = anyhow::Error;
+
+    fn try_from(bytes: &[u8]) -> Result<Self, Self::Error> {
+        if bytes.len() != 64 {
+            anyhow::bail!("shared secret proof must be 64 bytes");
+        }
+        Ok(SharedSecretProof {
+            challenge: decode_scalar(&bytes[..32])?,
+            response: decode_scalar(&bytes[32..])?,
+        })
+    }
+}
+
+impl From<EncryptedShare> for pb::EncryptedBallotKeyShare {
+    fn from(share: EncryptedShare) -> Self {
+        pb::EncryptedBallotKeyShare {
+            ephemeral_key: share.ephemeral_key.vartime_compress().0.to_vec(),
+            ciphertext: share.ciphertext.to_vec(),
+        }
+    }
+}
+
+impl TryFrom<pb::EncryptedBallotKeyShare> for EncryptedShare {
+    type Error = anyhow::Error;
+
+    fn try_from(msg: pb::EncryptedBallotKeyShare) -> Result<Self, Self::Error> {
+        Ok(EncryptedShare {
+            ephemeral_key: decode_element(&msg.ephemeral_key)
+                .context("invalid ephemeral key in encrypted share")?,
+            ciphertext: msg
+                .ciphertext
+                .try_into()
+                .map_err(|_| anyhow!("encrypted share must be 32 bytes"))?,
+        })
+    }
+}
+
+/// A dealer's secret polynomial, whose constant term is the dealer's contribution to the secret
+/// ballot key.
+pub struct SecretPolynomial {
+    coefficients: Vec<Fr>,
+}
+
+impl SecretPolynomial {
+    /// Pick a random polynomial whose shares can be combined to recover its secret once
+    /// `threshold` of them are known.
+    pub fn random<R: RngCore + CryptoRng>(rng: &mut R, threshold: usize) -> Self {
+        SecretPolynomial {
+            coefficients: (0..threshold.max(1)).map(|_| Fr::rand(rng)).collect(),
+        }
+    }
+
+    /// Derive the dealer's polynomial for the given proposal from its governance signing key, so
+    /// that the dealer doesn't need to remember it until the ballots are opened.
+    pub fn derive(
+        governance_signing_key: &SigningKey<SpendAuth>,
+        proposal: u64,
+        threshold: usize,
+    ) -> Self {
+        SecretPolynomial {
+            coefficients: (0..threshold.max(1) as u64)
+                .map(|i| {
+                    let hash = blake2b_simd::Params::default()
+                        .personal(b"Penumbra_BKPoly")
+                        .to_state()
+                        .update(&governance_signing_key.to_bytes())
+                        .update(&proposal.to_le_bytes())
+                        .update(&i.to_le_bytes())
+                        .finalize();
+                    Fr::from_le_bytes_mod_order(hash.as_bytes())
+                })
+                .collect(),
+        }
+    }
+
+    /// The dealer's contribution to the secret ballot key.
+    pub fn secret(&self) -> Fr {
+        self.coefficients[0]
+    }
+
+    /// Evaluate the polynomial at the given share index.
+    pub fn share(&self, index: Fr) -> Fr {
+        self.coefficients
+            .iter()
+            .rev()
+            .fold(Fr::zero(), |acc, coefficient| acc * index + coefficient)
+    }
+
+    /// The public commitments to the polynomial's coefficients.
+    pub fn commitments(&self) -> Vec<Element> {
+        self.coefficients
+            .iter()
+            .map(|coefficient| *coefficient * decaf377::basepoint())
+            .collect()
+    }
+}
+
+/// Check a share of a dealer's polynomial at the given index against the dealer's commitments to
+/// its coefficients.
+pub fn verify_share(commitments: &[Element], index: Fr, share: &Fr) -> bool {
+    let (expected, _) = commitments.iter().fold(
+        (Element::default(), Fr::one()),
+        |(acc, power), commitment| (acc + power * *commitment, power * index),
+    );
+    *share * decaf377::basepoint() == expected
+}
+
+/// Recover a dealer's secret from shares of its polynomial at distinct indices, by Lagrange
+/// interpolation at zero.
+///
+/// The shares must have been verified against the dealer's commitments, and there must be at least
+/// as many of them as the polynomial has coefficients.
+pub fn recover_secret(shares: &[(Fr, Fr)]) -> anyhow::Result<Fr> {
+    let mut secret = Fr::zero();
+    for (i, (x_i, y_i)) in shares.iter().enumerate() {
+        let mut numerator = Fr::one();
+        let mut denominator = Fr::one();
+        for (j, (x_j, _)) in shares.iter().enumerate() {
+            if i != j {
+                numerator *= x_j;
+                denominator *= *x_j - x_i;
+            }
+        }
+        let denominator = denominator
+            .inverse()
+            .ok_or_else(|| anyhow!("share indices must be distinct"))?;
+        secret += *y_i * numerator * denominator;
+    }
+    Ok(secret)
+}
+
+/// Recover the secret ballot key of a proposal from the deals of the dealers it was established
+/// from and the reveals made for it.
+///
+/// Revealed shares are checked against the dealer's commitments, and those which don't match, or
+/// which weren't allocated to the validator revealing them, are ignored.  Returns `None` if the
+/// secret of some dealer is neither revealed nor recoverable from the valid revealed shares.
+pub fn recover_ballot_secret(
+    allocation: &ShareAllocation,
+    deals: &BTreeMap<IdentityKey, BallotKeyDeal>,
+    reveals: &BTreeMap<IdentityKey, BallotKeyReveal>,
+) -> Option<Fr> {
+    let mut secret = Fr::zero();
+    for (dealer, deal) in deals {
+        let commitments = &deal.body.commitments;
+        if let Some(dealer_secret) = reveals
+            .get(dealer)
+            .and_then(|reveal| reveal.body.dealer_secret)
+            .filter(|dealer_secret| *dealer_secret * decaf377::basepoint() == commitments[0])
+        {
+            secret += dealer_secret;
+            continue;
+        }
+
+        let mut shares = BTreeMap::new();
+        for (revealer, reveal) in reveals {
+            let Some(positions) = allocation.positions(revealer) else {
+                continue;
+            };
+            for share in reveal
+                .body
+                .shares
+                .iter()
+                .filter(|share| share.dealer == *dealer && positions.contains(&share.position))
+            {
+                if shares.len() == commitments.len() {
+                    break;
+                }
+                if !shares.contains_key(&share.position)
+                    && verify_share(commitments, share_index(share.position), &share.share)
+                {
+                    shares.insert(share.position, share.share);
+                }
+            }
+        }
+        if shares.len() < commitments.len() {
+            return None;
+        }
+        let shares: Vec<_> = shares
+            .into_iter()
+            .map(|(position, share)| (share_index(position), share))
+            .collect();
+        secret += recover_secret(&shares).ok()?;
+    }
+    Some(secret)
+}
+
+#[cfg(test)]
+mod tests {
+    use rand_core::OsRng;
+
+    use super::*;
+
+    #[test]
+    fn votes_decrypt_with_combined_secrets() {
+        let polynomials: Vec<_> = (0..3)
+            .map(|_| SecretPolynomial::random(&mut OsRng, 2))
+            .collect();
+        let ballot_key = BallotKey::from_dealer_commitments(
+            polynomials
+                .iter()
+                .map(|polynomial| polynomial.commitments()[0])
+                .collect::<Vec<_>>()
+                .iter(),
+        );
+        let secret = polynomials.iter().fold(Fr::zero(), |secret, polynomial| {
+            secret + polynomial.secret()
+        });
+
+        for vote in [Vote::Yes, Vote::No, Vote::Abstain] {
+            let encrypted = EncryptedVote::encrypt(&mut OsRng, vote, &ballot_key);
+            assert_eq!(encrypted.decrypt(&secret), Some(vote));
+            assert_eq!(encrypted.decrypt(&polynomials[0].secret()), None);
+        }
+    }
+
+    #[test]
+    fn withheld_secret_is_recovered_from_threshold_shares() {
+        let total = 7;
+        let threshold = total / 3 + 1;
+        let polynomial = SecretPolynomial::random(&mut OsRng, threshold);
+        let commitments = polynomial.commitments();
+
+        let shares: Vec<_> = (0..total)
+            .map(|position| {
+                let index = share_index(position);
+                (index, polynomial.share(index))
+            })
+            .collect();
+        for (index, share) in &shares {
+            assert!(verify_share(&commitments, *index, share));
+        }
+        assert!(!verify_share(
+            &commitments,
+            shares[0].0,
+            &(shares[0].1 + Fr::one())
+        ));
+
+        let recovered = recover_secret(&shares[total - threshold..]).unwrap();
+        assert_eq!(recovered, polynomial.secret());
+    }
+
+    /// Validators with the given voting power, ordered by identity key.
+    fn validators(
+        powers: &[u64],
+    ) -> (
+        Vec<(IdentityKey, SigningKey<SpendAuth>)>,
+        BTreeMap<IdentityKey, u64>,
+        BTreeMap<IdentityKey, GovernanceKey>,
+    ) {
+        let mut validators: Vec<_> = powers
+            .iter()
+            .map(|_| {
+                let signing_key = SigningKey::<SpendAuth>::new(OsRng);
+                (IdentityKey((&signing_key).into()), signing_key)
+            })
+            .collect();
+        validators.sort_by_key(|(identity_key, _)| *identity_key);
+        let voting_power = validators
+            .iter()
+            .zip(powers)
+            .map(|((identity_key, _), power)| (*identity_key, *power))
+            .collect();
+        let governance_keys = validators
+            .iter()
+            .map(|(identity_key, signing_key)| (*identity_key, GovernanceKey(signing_key.into())))
+            .collect();
+        (validators, voting_power, governance_keys)
+    }
+
+    fn deal(
+        proposal: u64,
+        (identity_key, signing_key): &(IdentityKey, SigningKey<SpendAuth>),
+        allocation: &ShareAllocation,
+        governance_keys: &BTreeMap<IdentityKey, GovernanceKey>,
+    ) -> BallotKeyDeal {
+        let body = BallotKeyDealBody::deal(
+            &mut OsRng,
+            proposal,
+            *identity_key,
+            signing_key,
+            allocation,
+            governance_keys,
+        )
+        .unwrap();
+        let auth_sig = signing_key.sign(OsRng, &body.encode_to_vec());
+        BallotKeyDeal { body, auth_sig }
+    }
+
+    fn reveal(
+        proposal: u64,
+        (identity_key, signing_key): &(IdentityKey, SigningKey<SpendAuth>),
+        allocation: &ShareAllocation,
+        deals: &BTreeMap<IdentityKey, BallotKeyDeal>,
+    ) -> BallotKeyReveal {
+        let body = BallotKeyRevealBody::reveal(
+            proposal,
+            *identity_key,
+            signing_key,
+            allocation,
+            deals.values(),
+        );
+        let auth_sig = signing_key.sign(OsRng, &body.encode_to_vec());
+        BallotKeyReveal { body, auth_sig }
+    }
+
+    #[test]
+    fn shares_are_allocated_by_voting_power() {
+        let (validators, voting_power, _) = validators(&[50, 25, 25, 0]);
+        let allocation = ShareAllocation::new(&voting_power);
+
+        assert_eq!(allocation.total(), TOTAL_SHARES as usize);
+        assert_eq!(allocation.threshold(), TOTAL_SHARES as usize / 3 + 1);
+        let counts: Vec<_> = validators
+            .iter()
+            .map(|(identity_key, _)| allocation.positions(identity_key).unwrap().len())
+            .collect();
+        let expected: Vec<_> = voting_power
+            .values()
+            .map(|power| (*power * TOTAL_SHARES / 100) as usize)
+            .collect();
+        assert_eq!(counts, expected);
+
+        // The validator with half the voting power can recover secrets on its own, as can the two
+        // with a quarter each together, but neither of them alone.
+        let largest = voting_power
+            .iter()
+            .find(|(_, power)| **power == 50)
+            .map(|(identity_key, _)| *identity_key)
+            .unwrap();
+        assert!(allocation.weight([&largest]) >= allocation.threshold());
+        let others: Vec<_> = voting_power
+            .iter()
+            .filter(|(_, power)| **power == 25)
+            .map(|(identity_key, _)| *identity_key)
+            .collect();
+        assert!(allocation.weight(&others) >= allocation.threshold());
+        assert!(allocation.weight(&others[..1]) < allocation.threshold());
+    }
+
+    #[test]
+    fn ballots_open_when_a_dealer_withholds_its_secret() {
+        let proposal = 3;
+        let (validators, voting_power, governance_keys) = validators(&[10, 10, 10, 10]);
+        let allocation = ShareAllocation::new(&voting_power);
+
+        let deals: BTreeMap<_, _> = validators
+            .iter()
+            .map(|validator| {
+                (
+                    validator.0,
+                    deal(proposal, validator, &allocation, &governance_keys),
+                )
+            })
+            .collect();
+        let ballot_key = BallotKey::from_dealer_commitments(
+            deals.values().map(|deal| &deal.body.commitments[0]),
+        );
+        let ballot = EncryptedVote::encrypt(&mut OsRng, Vote::Yes, &ballot_key);
+
+        // The first validator never reveals its secret, so it must be recovered from its shares.
+        let mut reveals: BTreeMap<_, _> = validators
+            .iter()
+            .skip(1)
+            .map(|validator| {
+                let reveal = reveal(proposal, validator, &allocation, &deals);
+                assert_eq!(reveal.body.shares.len(), 3 * 32);
+                (validator.0, reveal)
+            })
+            .collect();
+
+        let secret = recover_ballot_secret(&allocation, &deals, &reveals).unwrap();
+        assert_eq!(secret * decaf377::basepoint(), ballot_key.0);
+        assert_eq!(ballot.decrypt(&secret), Some(Vote::Yes));
+
+        // Revealed shares which don't match the dealer's commitments are ignored, rather than
+        // spoiling the recovery.
+        let withholder = validators[0].0;
+        let revealer = validators[1].0;
+        for share in reveals
+            .get_mut(&revealer)
+            .unwrap()
+            .body
+            .shares
+            .iter_mut()
+            .filter(|share| share.dealer == withholder)
+        {
+            share.share += Fr::one();
+        }
+        let secret = recover_ballot_secret(&allocation, &deals, &reveals).unwrap();
+        assert_eq!(ballot.decrypt(&secret), Some(Vote::Yes));
+
+        // With only one reveal, the withheld secret can't be recovered.
+        let reveals: BTreeMap<_, _> = reveals.into_iter().take(1).collect();
+        assert_eq!(recover_ballot_secret(&allocation, &deals, &reveals), None);
+    }
+
+    #[test]
+    fn complaints_prove_invalid_shares() {
+        let proposal = 5;
+        let (validators, voting_power, governance_keys) = validators(&[1, 1, 1]);
+        let allocation = ShareAllocation::new(&voting_power);
+        let (dealer, recipient) = (&validators[0], &validators[1]);
+
+        // Honest deals can't be complained about.
+        let mut deal = deal(proposal, dealer, &allocation, &governance_keys);
+        assert!(BallotKeyComplaintBody::complain(
+            &mut OsRng,
+            recipient.0,
+            &recipient.1,
+            &allocation,
+            &deal.body,
+        )
+        .unwrap()
+        .is_none());
+
+        // The dealer encrypts garbage to the recipient's last share position.
+        let position = allocation.positions(&recipient.0).unwrap().end - 1;
+        deal.body.encrypted_shares[position] = EncryptedShare::encrypt(
+            &mut OsRng,
+            proposal,
+            &Fr::rand(&mut OsRng),
+            &governance_keys[&recipient.0],
+        )
+        .unwrap();
+        let complaint = BallotKeyComplaintBody::complain(
+            &mut OsRng,
+            recipient.0,
+            &recipient.1,
+            &allocation,
+            &deal.body,
+        )
+        .unwrap()
+        .unwrap();
+        assert_eq!(complaint.position, position);
+
+        // Anyone can check the complaint, and decrypt the share with the shared secret.
+        let encrypted_share = deal.body.encrypted_shares[position];
+        complaint
+            .proof
+            .verify(
+                &complaint.governance_key,
+                encrypted_share.ephemeral_key,
+                complaint.shared_secret,
+            )
+            .unwrap();
+        let share = encrypted_share
+            .decrypt_with_shared_secret(proposal, complaint.shared_secret)
+            .unwrap();
+        assert!(!verify_share(
+            &deal.body.commitments,
+            share_index(position),
+            &share
+        ));
+
+        // The proof doesn't verify for another validator's key, or another shared secret.
+        assert!(complaint
+            .proof
+            .verify(
+                &governance_keys[&dealer.0],
+                encrypted_share.ephemeral_key,
+                complaint.shared_secret,
+            )
+            .is_err());
+        assert!(complaint
+            .proof
+            .verify(
+                &complaint.governance_key,
+                encrypted_share.ephemeral_key,
+                complaint.shared_secret + decaf377::basepoint(),
+            )
+            .is_err());
+        let proof = SharedSecretProof::try_from(&complaint.proof.to_bytes()[..]).unwrap();
+        assert_eq!(proof, complaint.proof);
+    }
+
+    #[test]
+    fn shares_decrypt_with_governance_key() {
+        let signing_key = SigningKey::<SpendAuth>::new(OsRng);
+        let governance_key = GovernanceKey((&signing_key).into());
+        let share = Fr::rand(&mut OsRng);
+
+        let encrypted = EncryptedShare::encrypt(&mut OsRng, 1, &share, &governance_key).unwrap();
+        assert_eq!(encrypted.decrypt(1, &signing_key).unwrap(), share);
+        assert_ne!(encrypted.decrypt(2, &signing_key).ok(), Some(share));
+    }
+}
@@ -0,0 +1,539 @@
+use std::collections::BTreeMap;
+
+use anyhow::Context;
+use decaf377::{Element, FieldExt, Fr};
+use decaf377_rdsa::{Signature, SigningKey, SpendAuth};
+use penumbra_proto::{penumbra::core::component::governance::v1 as pb, DomainType};
+use penumbra_stake::{GovernanceKey, IdentityKey};
+use penumbra_txhash::{EffectHash, EffectingData};
+use rand_core::{CryptoRng, RngCore};
+use serde::{Deserialize, Serialize};
+
+use super::{
+    decode_element, decode_scalar, share_index, verify_share, EncryptedShare, SecretPolynomial,
+    ShareAllocation, SharedSecretProof,
+};
+
+/// A validator's contribution to the ballot key of a proposal with sealed ballots.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(try_from = "pb::BallotKeyDeal", into = "pb::BallotKeyDeal")]
+pub struct BallotKeyDeal {
+    /// The body of the deal.
+    pub body: BallotKeyDealBody,
+    /// The signature authorizing the deal (signed with governance key over the body).
+    pub auth_sig: Signature<SpendAuth>,
+}
+
+impl EffectingData for BallotKeyDeal {
+    fn effect_hash(&self) -> EffectHash {
+        EffectHash::from_proto_effecting_data(&self.to_proto())
+    }
+}
+
+impl DomainType for BallotKeyDeal {
+    type Proto = pb::BallotKeyDeal;
+}
+
+impl From<BallotKeyDeal> for pb::BallotKeyDeal {
+    fn from(msg: BallotKeyDeal) -> Self {
+        Self {
+            body: Some(msg.body.into()),
+            auth_sig: Some(msg.auth_sig.into()),
+        }
+    }
+}
+
+impl TryFrom<pb::BallotKeyDeal> for BallotKeyDeal {
+    type Error = anyhow::Error;
+
+    fn try_from(msg: pb::BallotKeyDeal) -> Result<Self, Self::Error> {
+        Ok(Self {
+            body: msg
+                .body
+                .ok_or_else(|| anyhow::anyhow!("missing ballot key deal body"))?
+                .try_into()?,
+            auth_sig: msg
+                .auth_sig
+                .ok_or_else(|| anyhow::anyhow!("missing ballot key deal auth sig"))?
+                .try_into()?,
+        })
+    }
+}
+
+/// The body of a [`BallotKeyDeal`].
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(try_from = "pb::BallotKeyDealBody", into = "pb::BallotKeyDealBody")]
+pub struct BallotKeyDealBody {
+    /// The proposal whose ballot key is being dealt.
+    pub proposal: u64,
+    /// The identity of the dealing validator.
+    pub identity_key: IdentityKey,
+    /// The governance key of the dealing validator.
+    pub governance_key: GovernanceKey,
+    /// Commitments to the coefficients of the dealer's secret polynomial, starting with the
+    /// constant term.
+    pub commitments: Vec<Element>,
+    /// The share of the polynomial at each share position, encrypted to the governance key of the
+    /// validator the position is allocated to.
+    pub encrypted_shares: Vec<EncryptedShare>,
+}
+
+impl BallotKeyDealBody {
+    /// Deal the validator's share of the ballot key of a proposal, given the allocation of share
+    /// positions to the validators active at its start, and their governance keys.
+    ///
+    /// The dealt polynomial is derived from the governance signing key, so that it can be derived
+    /// again to reveal its secret once voting has closed.
+    pub fn deal<R: RngCore + CryptoRng>(
+        rng: &mut R,
+        proposal: u64,
+        identity_key: IdentityKey,
+        governance_signing_key: &SigningKey<SpendAuth>,
+        allocation: &ShareAllocation,
+        governance_keys: &BTreeMap<IdentityKey, GovernanceKey>,
+    ) -> anyhow::Result<Self> {
+        let polynomial =
+            SecretPolynomial::derive(governance_signing_key, proposal, allocation.threshold());
+        let mut encrypted_shares = Vec::with_capacity(allocation.total());
+        for (participant, positions) in allocation.participants() {
+            let recipient = governance_keys.get(participant).ok_or_else(|| {
+                anyhow::anyhow!("missing governance key of validator {}", participant)
+            })?;
+            for position in positions {
+                let share = polynomial.share(share_index(position));
+                encrypted_shares.push(EncryptedShare::encrypt(rng, proposal, &share, recipient)?);
+            }
+        }
+
+        Ok(BallotKeyDealBody {
+            proposal,
+            identity_key,
+            governance_key: GovernanceKey(governance_signing_key.into()),
+            commitments: polynomial.commitments(),
+            encrypted_shares,
+        })
+    }
+}
+
+impl DomainType for BallotKeyDealBody {
+    type Proto = pb::BallotKeyDealBody;
+}
+
+impl From<BallotKeyDealBody> for pb::BallotKeyDealBody {
+    fn from(value: BallotKeyDealBody) -> Self {
+        pb::BallotKeyDealBody {
+            proposal: value.proposal,
+            identity_key: Some(value.identity_key.into()),
+            governance_key: Some(value.governance_key.into()),
+            commitments: value
+                .commitments
+                .into_iter()
+                .map(|commitment| commitment.vartime_compress().0.to_vec())
+                .collect(),
+            encrypted_shares: value.encrypted_shares.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+impl TryFrom<pb::BallotKeyDealBody> for BallotKeyDealBody {
+    type Error = anyhow::Error;
+
+    fn try_from(msg: pb::BallotKeyDealBody) -> Result<Self, Self::Error> {
+        Ok(BallotKeyDealBody {
+            proposal: msg.proposal,
+            identity_key: msg
+                .identity_key
+                .ok_or_else(|| anyhow::anyhow!("missing validator identity in `BallotKeyDeal`"))?
+                .try_into()?,
+            governance_key: msg
+                .governance_key
+                .ok_or_else(|| {
+                    anyhow::anyhow!("missing validator governance key in `BallotKeyDeal`")
+                })?
+                .try_into()?,
+            commitments: msg
+                .commitments
+                .iter()
+                .map(|commitment| decode_element(commitment))
+                .collect::<anyhow::Result<_>>()
+                .context("invalid commitment in `BallotKeyDeal`")?,
+            encrypted_shares: msg
+                .encrypted_shares
+                .into_iter()
+                .map(TryInto::try_into)
+                .collect::<anyhow::Result<_>>()?,
+        })
+    }
+}
+
+/// A validator's reveal of the secrets needed to open the sealed ballots of a proposal, once
+/// voting has closed.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(try_from = "pb::BallotKeyReveal", into = "pb::BallotKeyReveal")]
+pub struct BallotKeyReveal {
+    /// The body of the reveal.
+    pub body: BallotKeyRevealBody,
+    /// The signature authorizing the reveal (signed with governance key over the body).
+    pub auth_sig: Signature<SpendAuth>,
+}
+
+impl EffectingData for BallotKeyReveal {
+    fn effect_hash(&self) -> EffectHash {
+        EffectHash::from_proto_effecting_data(&self.to_proto())
+    }
+}
+
+impl DomainType for BallotKeyReveal {
+    type Proto = pb::BallotKeyReveal;
+}
+
+impl From<BallotKeyReveal> for pb::BallotKeyReveal {
+    fn from(msg: BallotKeyReveal) -> Self {
+        Self {
+            body: Some(msg.body.into()),
+            auth_sig: Some(msg.auth_sig.into()),
+        }
+    }
+}
+
+impl TryFrom<pb::BallotKeyReveal> for BallotKeyReveal {
+    type Error = anyhow::Error;
+
+    fn try_from(msg: pb::BallotKeyReveal) -> Result<Self, Self::Error> {
+        Ok(Self {
+            body: msg
+                .body
+                .ok_or_else(|| anyhow::anyhow!("missing ballot key reveal body"))?
+                .try_into()?,
+            auth_sig: msg
+                .auth_sig
+                .ok_or_else(|| anyhow::anyhow!("missing ballot key reveal auth sig"))?
+                .try_into()?,
+        })
+    }
+}
+
+/// The body of a [`BallotKeyReveal`].
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(try_from = "pb::BallotKeyRevealBody", into = "pb::BallotKeyRevealBody")]
+pub struct BallotKeyRevealBody {
+    /// The proposal whose ballot key secrets are being revealed.
+    pub proposal: u64,
+    /// The identity of the revealing validator.
+    pub identity_key: IdentityKey,
+    /// The governance key of the revealing validator.
+    pub governance_key: GovernanceKey,
+    /// The validator's own contribution to the secret ballot key, if it dealt one.
+    pub dealer_secret: Option<Fr>,
+    /// The decrypted shares the validator received from other dealers.
+    pub shares: Vec<RevealedShare>,
+}
+
+impl BallotKeyRevealBody {
+    /// Reveal the validator's secrets for the ballot key of a proposal, given the allocation of
+    /// share positions to the validators active at its start, and the deals the ballot key was
+    /// established from.
+    ///
+    /// Shares which fail to decrypt or to verify against their dealer's commitments are omitted.
+    pub fn reveal<'a>(
+        proposal: u64,
+        identity_key: IdentityKey,
+        governance_signing_key: &SigningKey<SpendAuth>,
+        allocation: &ShareAllocation,
+        deals: impl IntoIterator<Item = &'a BallotKeyDeal>,
+    ) -> Self {
+        let positions = allocation.positions(&identity_key).unwrap_or_default();
+        let mut dealer_secret = None;
+        let mut shares = Vec::new();
+        for BallotKeyDeal { body: deal, .. } in deals {
+            if deal.identity_key == identity_key {
+                dealer_secret = Some(
+                    SecretPolynomial::derive(
+                        governance_signing_key,
+                        proposal,
+                        deal.commitments.len(),
+                    )
+                    .secret(),
+                );
+                continue;
+            }
+            for position in positions.clone() {
+                let Some(share) = deal
+                    .encrypted_shares
+                    .get(position)
+                    .and_then(|share| share.decrypt(proposal, governance_signing_key).ok())
+                else {
+                    continue;
+                };
+                if verify_share(&deal.commitments, share_index(position), &share) {
+                    shares.push(RevealedShare {
+                        dealer: deal.identity_key,
+                        position,
+                        share,
+                    });
+                }
+            }
+        }
+
+        BallotKeyRevealBody {
+            proposal,
+            identity_key,
+            governance_key: GovernanceKey(governance_signing_key.into()),
+            dealer_secret,
+            shares,
+        }
+    }
+}
+
+impl DomainType for BallotKeyRevealBody {
+    type Proto = pb::BallotKeyRevealBody;
+}
+
+impl From<BallotKeyRevealBody> for pb::BallotKeyRevealBody {
+    fn from(value: BallotKeyRevealBody) -> Self {
+        pb::BallotKeyRevealBody {
+            proposal: value.proposal,
+            identity_key: Some(value.identity_key.into()),
+            governance_key: Some(value.governance_key.into()),
+            dealer_secret: value
+                .dealer_secret
+                .map(|secret| secret.to_bytes().to_vec())
+                .unwrap_or_default(),
+            shares: value.shares.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+impl TryFrom<pb::BallotKeyRevealBody> for BallotKeyRevealBody {
+    type Error = anyhow::Error;
+
+    fn try_from(msg: pb::BallotKeyRevealBody) -> Result<Self, Self::Error> {
+        Ok(BallotKeyRevealBody {
+            proposal: msg.proposal,
+            identity_key: msg
+                .identity_key
+                .ok_or_else(|| anyhow::anyhow!("missing validator identity in `BallotKeyReveal`"))?
+                .try_into()?,
+            governance_key: msg
+                .governance_key
+                .ok_or_else(|| {
+                    anyhow::anyhow!("missing validator governance key in `BallotKeyReveal`")
+                })?
+                .try_into()?,
+            dealer_secret: if msg.dealer_secret.is_empty() {
+                None
+            } else {
+                Some(
+                    decode_scalar(&msg.dealer_secret)
+                        .context("invalid dealer secret in `BallotKeyReveal`")?,
+                )
+            },
+            shares: msg
+                .shares
+                .into_iter()
+                .map(TryInto::try_into)
+                .collect::<anyhow::Result<_>>()?,
+        })
+    }
+}
+
+/// A decrypted share of a dealer's secret polynomial.
+#[derive(Debug, Clone, Copy)]
+pub struct RevealedShare {
+    /// The identity of the dealer.
+    pub dealer: IdentityKey,
+    /// The share position, which must be allocated to the revealing validator.
+    pub position: usize,
+    /// The share of the dealer's polynomial at the position.
+    pub share: Fr,
+}
+
+impl From<RevealedShare> for pb::RevealedBallotKeyShare {
+    fn from(value: RevealedShare) -> Self {
+        pb::RevealedBallotKeyShare {
+            dealer: Some(value.dealer.into()),
+            share: value.share.to_bytes().to_vec(),
+            position: value.position as u32,
+        }
+    }
+}
+
+impl TryFrom<pb::RevealedBallotKeyShare> for RevealedShare {
+    type Error = anyhow::Error;
+
+    fn try_from(msg: pb::RevealedBallotKeyShare) -> Result<Self, Self::Error> {
+        Ok(RevealedShare {
+            dealer: msg
+                .dealer
+                .ok_or_else(|| anyhow::anyhow!("missing dealer in revealed ballot key share"))?
+                .try_into()?,
+            position: msg.position as usize,
+            share: decode_scalar(&msg.share).context("invalid revealed ballot key share")?,
+        })
+    }
+}
+
+/// A validator's complaint that a dealer encrypted an invalid share of its secret polynomial to
+/// it, which excludes the dealer from the ballot key of the proposal.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(try_from = "pb::BallotKeyComplaint", into = "pb::BallotKeyComplaint")]
+pub struct BallotKeyComplaint {
+    /// The body of the complaint.
+    pub body: BallotKeyComplaintBody,
+    /// The signature authorizing the complaint (signed with governance key over the body).
+    pub auth_sig: Signature<SpendAuth>,
+}
+
+impl EffectingData for BallotKeyComplaint {
+    fn effect_hash(&self) -> EffectHash {
+        EffectHash::from_proto_effecting_data(&self.to_proto())
+    }
+}
+
+impl DomainType for BallotKeyComplaint {
+    type Proto = pb::BallotKeyComplaint;
+}
+
+impl From<BallotKeyComplaint> for pb::BallotKeyComplaint {
+    fn from(msg: BallotKeyComplaint) -> Self {
+        Self {
+            body: Some(msg.body.into()),
+            auth_sig: Some(msg.auth_sig.into()),
+        }
+    }
+}
+
+impl TryFrom<pb::BallotKeyComplaint> for BallotKeyComplaint {
+    type Error = anyhow::Error;
+
+    fn try_from(msg: pb::BallotKeyComplaint) -> Result<Self, Self::Error> {
+        Ok(Self {
+            body: msg
+                .body
+                .ok_or_else(|| anyhow::anyhow!("missing ballot key complaint body"))?
+                .try_into()?,
+            auth_sig: msg
+                .auth_sig
+                .ok_or_else(|| anyhow::anyhow!("missing ballot key complaint auth sig"))?
+                .try_into()?,
+        })
+    }
+}
+
+/// The body of a [`BallotKeyComplaint`].
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(
+    try_from = "pb::BallotKeyComplaintBody",
+    into = "pb::BallotKeyComplaintBody"
+)]
+pub struct BallotKeyComplaintBody {
+    /// The proposal whose ballot key is being dealt.
+    pub proposal: u64,
+    /// The identity of the complaining validator.
+    pub identity_key: IdentityKey,
+    /// The governance key of the complaining validator.
+    pub governance_key: GovernanceKey,
+    /// The identity of the dealer of the invalid share.
+    pub dealer: IdentityKey,
+    /// The share position of the invalid share, which must be allocated to the complaining
+    /// validator.
+    pub position: usize,
+    /// The secret the share was encrypted with, with which anyone can decrypt it.
+    pub shared_secret: Element,
+    /// The proof that the shared secret was computed with the complaining validator's governance
+    /// key.
+    pub proof: SharedSecretProof,
+}
+
+impl BallotKeyComplaintBody {
+    /// Check the shares a deal encrypted to the validator, returning a complaint about the first
+    /// one which fails to decrypt or to verify against the dealer's commitments, if any.
+    pub fn complain<R: RngCore + CryptoRng>(
+        rng: &mut R,
+        identity_key: IdentityKey,
+        governance_signing_key: &SigningKey<SpendAuth>,
+        allocation: &ShareAllocation,
+        deal: &BallotKeyDealBody,
+    ) -> anyhow::Result<Option<Self>> {
+        for position in allocation.positions(&identity_key).unwrap_or_default() {
+            let Some(encrypted_share) = deal.encrypted_shares.get(position) else {
+                continue;
+            };
+            let valid = encrypted_share
+                .decrypt(deal.proposal, governance_signing_key)
+                .map(|share| verify_share(&deal.commitments, share_index(position), &share))
+                .unwrap_or(false);
+            if valid {
+                continue;
+            }
+
+            let (shared_secret, proof) = SharedSecretProof::prove(
+                rng,
+                governance_signing_key,
+                encrypted_share.ephemeral_key,
+            )?;
+            return Ok(Some(BallotKeyComplaintBody {
+                proposal: deal.proposal,
+                identity_key,
+                governance_key: GovernanceKey(governance_signing_key.into()),
+                dealer: deal.identity_key,
+                position,
+                shared_secret,
+                proof,
+            }));
+        }
+        Ok(None)
+    }
+}
+
+impl DomainType for BallotKeyComplaintBody {
+    type Proto = pb::BallotKeyComplaintBody;
+}
+
+impl From<BallotKeyComplaintBody> for pb::BallotKeyComplaintBody {
+    fn from(value: BallotKeyComplaintBody) -> Self {
+        pb::BallotKeyComplaintBody {
+            proposal: value.proposal,
+            identity_key: Some(value.identity_key.into()),
+            governance_key: Some(value.governance_key.into()),
+            dealer: Some(value.dealer.into()),
+            position: value.position as u32,
+            shared_secret: value.shared_secret.vartime_compress().0.to_vec(),
+            proof: value.proof.to_bytes().to_vec(),
+        }
+    }
+}
+
+impl TryFrom<pb::BallotKeyComplaintBody> for BallotKeyComplaintBody {
+    type Error = anyhow::Error;
+
+    fn try_from(msg: pb::BallotKeyComplaintBody) -> Result<Self, Self::Error> {
+        Ok(BallotKeyComplaintBody {
+            proposal: msg.proposal,
+            identity_key: msg
+                .identity_key
+                .ok_or_else(|| {
+                    anyhow::anyhow!("missing validator identity in `BallotKeyComplaint`")
+                })?
+                .try_into()?,
+            governance_key: msg
+                .governance_key
+                .ok_or_else(|| {
+                    anyhow::anyhow!("missing validator governance key in `BallotKeyComplaint`")
+                })?
+                .try_into()?,
+            dealer: msg
+                .dealer
+                .ok_or_else(|| anyhow::anyhow!("missing dealer in `BallotKeyComplaint`"))?
+                .try_into()?,
+            position: msg.position as usize,
+            shared_secret: decode_element(&msg.shared_secret)
+                .context("invalid shared secret in `BallotKeyComplaint`")?,
+            proof: msg
+                .proof
+                .as_slice()
+                .try_into()
+                .context("invalid proof in `BallotKeyComplaint`")?,
+        })
+    }
+}
@@ -1,6 +1,15 @@
+use penumbra_asset::asset;
 use penumbra_sct::Nullifier;
 use penumbra_stake::IdentityKey;
 
+/// The prefixes of all of the component's state keys, for the application's
+/// state key registry.
+pub const PREFIXES: &[&str] = &[
+    "governance/",
+    // See `change_app_params_at_height`.
+    "app/change_app_params/",
+];
+
 pub fn governance_params() -> &'static str {
     "governance/params"
 }
@@ -25,6 +34,14 @@ pub fn proposal_deposit_amount(proposal_id: u64) -> String {
     format!("governance/proposal/{proposal_id:020}/deposit_amount")
 }
 
+pub fn proposal_deposit_asset_id(proposal_id: u64) -> String {
+    format!("governance/proposal/{proposal_id:020}/deposit_asset_id")
+}
+
+pub fn deposit_rate_history(asset_id: &asset::Id) -> String {
+    format!("governance/deposit_rate_history/{asset_id}")
+}
+
 pub fn proposal_voting_start(proposal_id: u64) -> String {
     format!("governance/proposal/{proposal_id:020}/voting_start")
 }
@@ -68,6 +85,59 @@ pub fn all_voting_power_at_proposal_start(proposal_id: u64) -> String {
     format!("governance/proposal/{proposal_id:020}/voting_power_at_start/")
 }
 
+pub fn ballot_key_deal(proposal_id: u64, identity_key: IdentityKey) -> String {
+    format!("governance/proposal/{proposal_id:020}/ballot_key/deal/{identity_key}")
+}
+
+pub fn all_ballot_key_deals(proposal_id: u64) -> String {
+    // Note: this has to be the prefix of the `ballot_key_deal` function above.
+    format!("governance/proposal/{proposal_id:020}/ballot_key/deal/")
+}
+
+pub fn ballot_key_complaint(proposal_id: u64, dealer: IdentityKey) -> String {
+    format!("governance/proposal/{proposal_id:020}/ballot_key/complaint/{dealer}")
+}
+
+pub fn all_ballot_key_complaints(proposal_id: u64) -> String {
+    // Note: this has to be the prefix of the `ballot_key_complaint` function above.
+    format!("governance/proposal/{proposal_id:020}/ballot_key/complaint/")
+}
+
+pub fn ballot_key_reveal(proposal_id: u64, identity_key: IdentityKey) -> String {
+    format!("governance/proposal/{proposal_id:020}/ballot_key/reveal/{identity_key}")
+}
+
+pub fn all_ballot_key_reveals(proposal_id: u64) -> String {
+    // Note: this has to be the prefix of the `ballot_key_reveal` function above.
+    format!("governance/proposal/{proposal_id:020}/ballot_key/reveal/")
+}
+
+pub fn ballot_key(proposal_id: u64) -> String {
+    format!("governance/proposal/{proposal_id:020}/ballot_key/key")
+}
+
+pub fn sealed_validator_vote(proposal_id: u64, identity_key: IdentityKey) -> String {
+    format!("governance/sealed_validator_vote/{proposal_id:020}/{identity_key}")
+}
+
+pub fn all_sealed_validator_votes_for_proposal(proposal_id: u64) -> String {
+    // Note: this has to be the prefix of the `sealed_validator_vote` function above.
+    format!("governance/sealed_validator_vote/{proposal_id:020}/")
+}
+
+pub fn sealed_delegator_vote(
+    proposal_id: u64,
+    identity_key: IdentityKey,
+    nullifier: &Nullifier,
+) -> String {
+    format!("governance/sealed_delegator_vote/{proposal_id:020}/{identity_key}/{nullifier}")
+}
+
+pub fn all_sealed_delegator_votes_for_proposal(proposal_id: u64) -> String {
+    // Note: this has to be the prefix of the `sealed_delegator_vote` function above.
+    format!("governance/sealed_delegator_vote/{proposal_id:020}/")
+}
+
 pub fn validator_vote(proposal_id: u64, identity_key: IdentityKey) -> String {
     format!("governance/validator_vote/{proposal_id:020}/{identity_key}")
 }
@@ -81,6 +151,23 @@ pub fn all_validator_votes_for_proposal(proposal_id: u64) -> String {
     format!("governance/validator_vote/{proposal_id:020}/")
 }
 
+pub fn validator_abstention(proposal_id: u64, identity_key: IdentityKey) -> String {
+    format!("governance/validator_abstention/{proposal_id:020}/{identity_key}")
+}
+
+pub fn all_validator_abstentions_for_proposal(proposal_id: u64) -> String {
+    // Note: this has to be the prefix of the `validator_abstention` function above.
+    format!("governance/validator_abstention/{proposal_id:020}/")
+}
+
+pub fn validator_proposals_eligible(identity_key: IdentityKey) -> String {
+    format!("governance/validator_participation/{identity_key}/eligible")
+}
+
+pub fn validator_proposals_abstained(identity_key: IdentityKey) -> String {
+    format!("governance/validator_participation/{identity_key}/abstained")
+}
+
 pub fn tallied_delegator_votes(proposal_id: u64, identity_key: IdentityKey) -> String {
     format!("governance/tallied_delegator_votes/{proposal_id:020}/{identity_key}")
 }
@@ -140,6 +227,10 @@ pub mod upgrades {
     pub fn next_upgrade() -> &'static str {
         "governance/upgrades/next_upgrade"
     }
+
+    pub fn scheduled_upgrade() -> &'static str {
+        "governance/upgrades/scheduled_upgrade"
+    }
 }
 
 pub mod halt {
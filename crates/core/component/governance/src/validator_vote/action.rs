@@ -4,7 +4,7 @@ use penumbra_stake::{GovernanceKey, IdentityKey};
 use penumbra_txhash::{EffectHash, EffectingData};
 use serde::{Deserialize, Serialize};
 
-use crate::vote::Vote;
+use crate::{sealed_ballot::EncryptedVote, vote::Vote};
 
 /// A vote by a validator.
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -75,6 +75,10 @@ pub struct ValidatorVoteBody {
     pub governance_key: GovernanceKey,
     /// A comment or justification of the vote. Limited to 1 KB.
     pub reason: ValidatorVoteReason,
+    /// The vote, encrypted to the proposal's ballot key, if the proposal has sealed ballots.
+    ///
+    /// When this is set, `vote` must be [`Vote::Abstain`], and is ignored.
+    pub encrypted_vote: Option<EncryptedVote>,
 }
 
 impl From<ValidatorVoteBody> for pb::ValidatorVoteBody {
@@ -85,6 +89,7 @@ impl From<ValidatorVoteBody> for pb::ValidatorVoteBody {
             identity_key: Some(value.identity_key.into()),
             governance_key: Some(value.governance_key.into()),
             reason: Some(value.reason.into()),
+            encrypted_vote: value.encrypted_vote.map(Into::into),
         }
     }
 }
@@ -115,6 +120,7 @@ impl TryFrom<pb::ValidatorVoteBody> for ValidatorVoteBody {
                     anyhow::anyhow!("missing validator governance key in `ValidatorVote`")
                 })?
                 .try_into()?,
+            encrypted_vote: msg.encrypted_vote.map(TryInto::try_into).transpose()?,
         })
     }
 }
@@ -4,6 +4,17 @@ use penumbra_asset::asset;
 
 use std::string::String;
 
+/// The prefixes of all of the component's state keys, for the application's
+/// state key registry.
+pub const PREFIXES: &[&str] = &[
+    "ibc/",
+    // The substore holding the state proven to counterparties.
+    "ibc-data/",
+    "ibc_channel_counter",
+    "ibc_client_counter",
+    "penumbra_consensus_states/",
+];
+
 pub fn ibc_params() -> &'static str {
     "ibc/params"
 }
@@ -47,6 +47,24 @@ pub trait SctRead: StateRead {
         .await
     }
 
+    /// Return metadata on each of the specified nullifiers, in order, for those which have
+    /// been spent.
+    ///
+    /// The lookups are issued together rather than one after another, so this should be
+    /// preferred over repeated calls to [`spend_info`](Self::spend_info) when checking many
+    /// nullifiers at once.
+    async fn spend_infos(
+        &self,
+        nullifiers: &[Nullifier],
+    ) -> Result<Vec<Option<NullificationInfo>>> {
+        futures::future::try_join_all(
+            nullifiers
+                .iter()
+                .map(|nullifier| self.spend_info(*nullifier)),
+        )
+        .await
+    }
+
     /// Return the set of nullifiers that have been spent in the current block.
     fn pending_nullifiers(&self) -> im::Vector<Nullifier> {
         self.object_get(state_key::nullifier_set::pending_nullifiers())
@@ -223,17 +241,30 @@ pub trait VerificationExt: StateRead {
     }
 
     async fn check_nullifier_unspent(&self, nullifier: Nullifier) -> Result<()> {
-        if let Some(info) = self
-            .get::<NullificationInfo>(&state_key::nullifier_set::spent_nullifier_lookup(
-                &nullifier,
-            ))
-            .await?
-        {
-            anyhow::bail!(
-                "nullifier {} was already spent in {:?}",
-                nullifier,
-                hex::encode(info.id),
-            );
+        self.check_nullifiers_unspent(&[nullifier]).await
+    }
+
+    /// Check that none of the given nullifiers have been spent, looking them all up at once.
+    ///
+    /// Since the nullifiers are checked together, this also fails if any of them is
+    /// repeated, as spending them all would spend it twice.
+    async fn check_nullifiers_unspent(&self, nullifiers: &[Nullifier]) -> Result<()> {
+        let mut seen = std::collections::BTreeSet::new();
+        for nullifier in nullifiers {
+            if !seen.insert(nullifier) {
+                anyhow::bail!("nullifier {} is spent more than once", nullifier);
+            }
+        }
+
+        let spend_infos = self.spend_infos(nullifiers).await?;
+        for (nullifier, info) in nullifiers.iter().zip(spend_infos) {
+            if let Some(info) = info {
+                anyhow::bail!(
+                    "nullifier {} was already spent in {:?}",
+                    nullifier,
+                    hex::encode(info.id),
+                );
+            }
         }
         Ok(())
     }
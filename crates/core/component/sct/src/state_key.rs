@@ -1,3 +1,7 @@
+/// The prefixes of all of the component's state keys, for the application's
+/// state key registry.
+pub const PREFIXES: &[&str] = &["sct/"];
+
 pub mod config {
     pub fn sct_params() -> &'static str {
         "sct/config/sct_params"
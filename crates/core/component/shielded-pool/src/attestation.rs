@@ -0,0 +1,384 @@
+//! Attestations that a viewing key controlled some amount of an asset.
+//!
+//! An auditor or an OTC counterparty may want to know that someone controls
+//! at least some amount of an asset, without learning anything else about
+//! their wallet.  A [`FundsAttestation`] proves this for the state at a given
+//! height, by including for each attested note:
+//!
+//! - a spend proof, which shows that the note is included in the state
+//!   commitment tree at that height, and that its nullifier was derived by the
+//!   viewing key controlling it, without revealing the note or the key;
+//! - a proof that its nullifier is not in the nullifier set at that height.
+//!
+//! The value commitment in each spend proof is blinded by a factor derived
+//! from a context chosen by the verifier, such as a challenge string, so that
+//! an attestation made for one verifier can't be replayed to another.  Only
+//! the full viewing key is needed to make an attestation, not the spend key.
+//!
+//! # Privacy
+//!
+//! An attestation reveals the amount held by each attested note, and its
+//! nullifier.  Since a note's nullifier is published when the note is spent,
+//! anyone holding the attestation can recognize the transaction that later
+//! spends each attested note, and so link that transaction to whoever made the
+//! attestation.  Provers who want to avoid this can move the funds to attest
+//! into fresh notes, and accept that those notes' spends will be linked.
+
+use anyhow::{Context as _, Result};
+use ark_ff::UniformRand;
+use decaf377::{FieldExt, Fq, Fr};
+use decaf377_rdsa::{SpendAuth, VerificationKey};
+use ibc_types::core::commitment::{MerklePath, MerkleProof, MerkleRoot};
+use penumbra_asset::{asset, Balance, Value};
+use penumbra_keys::FullViewingKey;
+use penumbra_num::Amount;
+use penumbra_proof_params::{SPEND_PROOF_PROVING_KEY, SPEND_PROOF_VERIFICATION_KEY};
+use penumbra_proto::{core::component::shielded_pool::v1 as pb, DomainType};
+use penumbra_sct::Nullifier;
+use penumbra_tct as tct;
+use rand_core::{CryptoRng, RngCore};
+
+use crate::{Note, SpendProof, SpendProofPrivate, SpendProofPublic};
+
+/// An attestation that a full viewing key controlled at least some amount of
+/// an asset at a given height.
+#[derive(Clone, Debug)]
+pub struct FundsAttestation {
+    /// The height of the state the attestation is made against.
+    pub height: u64,
+    /// The root of the state commitment tree at that height.
+    pub anchor: tct::Root,
+    /// A proof that the anchor is the root of the state commitment tree at
+    /// that height.
+    pub anchor_proof: MerkleProof,
+    /// The asset whose ownership is attested.
+    pub asset_id: asset::Id,
+    /// The attested notes.
+    pub notes: Vec<AttestedNote>,
+}
+
+/// A single note included in a [`FundsAttestation`].
+#[derive(Clone, Debug)]
+pub struct AttestedNote {
+    /// The amount of the attested asset held by the note.
+    pub amount: Amount,
+    /// The nullifier of the note.
+    pub nullifier: Nullifier,
+    /// The randomized spend verification key used in the proof.
+    pub rk: VerificationKey<SpendAuth>,
+    /// A proof of the note's inclusion in the anchor, and of the correct
+    /// derivation of its nullifier.
+    pub proof: SpendProof,
+    /// A proof that the nullifier was not spent at the attested height.
+    pub unspent_proof: MerkleProof,
+}
+
+/// A note to include in a [`FundsAttestation`], along with the proofs of its
+/// inclusion and of its nullifier not being spent.
+#[derive(Clone, Debug)]
+pub struct NoteToAttest {
+    /// The note.
+    pub note: Note,
+    /// A proof of the note's inclusion in the state commitment tree, against
+    /// the anchor of the attestation.
+    pub state_commitment_proof: tct::Proof,
+    /// A proof that the note's nullifier is not in the nullifier set, as
+    /// returned by a node for the key
+    /// [`spent_nullifier_lookup`](penumbra_sct::state_key::nullifier_set::spent_nullifier_lookup).
+    pub unspent_proof: MerkleProof,
+}
+
+/// The blinding factor for the value commitment of the attested note with the
+/// given nullifier, in the given context.
+fn value_blinding(context: &[u8], nullifier: &Nullifier) -> Fr {
+    let hash = blake2b_simd::Params::new()
+        .personal(b"Penumbra_FundsAt")
+        .to_state()
+        .update(&(context.len() as u64).to_le_bytes())
+        .update(context)
+        .update(&nullifier.to_bytes())
+        .finalize();
+    Fr::from_le_bytes_mod_order(hash.as_bytes())
+}
+
+/// Verify a proof of a key's value, or of its absence, against an app hash.
+fn verify_state_proof(
+    proof: &MerkleProof,
+    app_hash: &[u8],
+    key: String,
+    value: Option<Vec<u8>>,
+) -> Result<()> {
+    let specs = [cnidarium::ics23_spec()];
+    let root = MerkleRoot {
+        hash: app_hash.to_vec(),
+    };
+    let path = MerklePath {
+        key_path: vec![key],
+    };
+    match value {
+        Some(value) => proof.verify_membership(&specs, root, path, value, 0)?,
+        None => proof.verify_non_membership(&specs, root, path)?,
+    }
+    Ok(())
+}
+
+impl FundsAttestation {
+    /// Attest that the given viewing key controls the given notes, which
+    /// must all hold the same asset, in the given context.
+    ///
+    /// The notes' state commitment proofs must all be against `anchor`, and
+    /// `anchor_proof` must prove that `anchor` is the
+    /// [anchor at `height`](penumbra_sct::state_key::tree::anchor_by_height).
+    /// This makes a spend proof for every note, which takes a while.
+    pub fn prove<R: RngCore + CryptoRng>(
+        rng: &mut R,
+        fvk: &FullViewingKey,
+        context: &[u8],
+        height: u64,
+        anchor: tct::Root,
+        anchor_proof: MerkleProof,
+        notes: Vec<NoteToAttest>,
+    ) -> Result<Self> {
+        let asset_id = notes
+            .first()
+            .map(|note| note.note.asset_id())
+            .ok_or_else(|| anyhow::anyhow!("no notes to attest"))?;
+
+        let mut attested = Vec::with_capacity(notes.len());
+        for NoteToAttest {
+            note,
+            state_commitment_proof,
+            unspent_proof,
+        } in notes
+        {
+            anyhow::ensure!(
+                note.asset_id() == asset_id,
+                "all attested notes must hold the same asset"
+            );
+            anyhow::ensure!(
+                state_commitment_proof.commitment() == note.commit(),
+                "state commitment proof is not for the note"
+            );
+            state_commitment_proof
+                .verify(anchor)
+                .context("state commitment proof is not against the anchor")?;
+
+            let nullifier = Nullifier::derive(
+                fvk.nullifier_key(),
+                state_commitment_proof.position(),
+                &note.commit(),
+            );
+            let v_blinding = value_blinding(context, &nullifier);
+            let spend_auth_randomizer = Fr::rand(rng);
+            let rk = fvk
+                .spend_verification_key()
+                .randomize(&spend_auth_randomizer);
+            let public = SpendProofPublic {
+                anchor,
+                balance_commitment: Balance::from(note.value()).commit(v_blinding),
+                nullifier,
+                rk,
+            };
+            let private = SpendProofPrivate {
+                state_commitment_proof,
+                note: note.clone(),
+                v_blinding,
+                spend_auth_randomizer,
+                ak: *fvk.spend_verification_key(),
+                nk: *fvk.nullifier_key(),
+            };
+            let proof = SpendProof::prove(
+                Fq::rand(rng),
+                Fq::rand(rng),
+                &SPEND_PROOF_PROVING_KEY,
+                public,
+                private,
+            )?;
+
+            attested.push(AttestedNote {
+                amount: note.amount(),
+                nullifier,
+                rk,
+                proof,
+                unspent_proof,
+            });
+        }
+
+        Ok(Self {
+            height,
+            anchor,
+            anchor_proof,
+            asset_id,
+            notes: attested,
+        })
+    }
+
+    /// Verify the attestation in the given context, against the app hash of
+    /// the state at the attested height, returning the total attested amount.
+    ///
+    /// The app hash must come from a trusted source, such as the header of the
+    /// block after the attested height, as verified by a light client.
+    pub fn verify(&self, context: &[u8], app_hash: &[u8]) -> Result<Amount> {
+        verify_state_proof(
+            &self.anchor_proof,
+            app_hash,
+            penumbra_sct::state_key::tree::anchor_by_height(self.height),
+            Some(self.anchor.encode_to_vec()),
+        )
+        .with_context(|| format!("invalid anchor for height {}", self.height))?;
+
+        let mut nullifiers = std::collections::BTreeSet::new();
+        let mut total = Amount::zero();
+        for note in &self.notes {
+            anyhow::ensure!(
+                note.amount > Amount::zero(),
+                "attested notes must have a nonzero amount"
+            );
+            anyhow::ensure!(
+                nullifiers.insert(note.nullifier.to_bytes()),
+                "note with nullifier {} is attested twice",
+                note.nullifier
+            );
+
+            let balance = Balance::from(Value {
+                amount: note.amount,
+                asset_id: self.asset_id,
+            });
+            note.proof
+                .verify(
+                    &SPEND_PROOF_VERIFICATION_KEY,
+                    SpendProofPublic {
+                        anchor: self.anchor,
+                        balance_commitment: balance
+                            .commit(value_blinding(context, &note.nullifier)),
+                        nullifier: note.nullifier,
+                        rk: note.rk,
+                    },
+                )
+                .with_context(|| {
+                    format!("invalid proof for note with nullifier {}", note.nullifier)
+                })?;
+            verify_state_proof(
+                &note.unspent_proof,
+                app_hash,
+                penumbra_sct::state_key::nullifier_set::spent_nullifier_lookup(&note.nullifier),
+                None,
+            )
+            .with_context(|| format!("nullifier {} may have been spent", note.nullifier))?;
+
+            total += note.amount;
+        }
+
+        Ok(total)
+    }
+}
+
+impl DomainType for FundsAttestation {
+    type Proto = pb::FundsAttestation;
+}
+
+impl From<FundsAttestation> for pb::FundsAttestation {
+    fn from(attestation: FundsAttestation) -> Self {
+        Self {
+            height: attestation.height,
+            anchor: Some(attestation.anchor.into()),
+            anchor_proof: ibc_types::DomainType::encode_to_vec(&attestation.anchor_proof),
+            asset_id: Some(attestation.asset_id.into()),
+            notes: attestation.notes.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+impl TryFrom<pb::FundsAttestation> for FundsAttestation {
+    type Error = anyhow::Error;
+
+    fn try_from(proto: pb::FundsAttestation) -> Result<Self, Self::Error> {
+        Ok(Self {
+            height: proto.height,
+            anchor: proto
+                .anchor
+                .ok_or_else(|| anyhow::anyhow!("missing anchor"))?
+                .try_into()?,
+            anchor_proof: <MerkleProof as ibc_types::DomainType>::decode(
+                proto.anchor_proof.as_slice(),
+            )
+            .context("malformed anchor proof")?,
+            asset_id: proto
+                .asset_id
+                .ok_or_else(|| anyhow::anyhow!("missing asset id"))?
+                .try_into()?,
+            notes: proto
+                .notes
+                .into_iter()
+                .map(TryInto::try_into)
+                .collect::<Result<_>>()?,
+        })
+    }
+}
+
+impl DomainType for AttestedNote {
+    type Proto = pb::AttestedNote;
+}
+
+impl From<AttestedNote> for pb::AttestedNote {
+    fn from(note: AttestedNote) -> Self {
+        Self {
+            amount: Some(note.amount.into()),
+            nullifier: Some(note.nullifier.into()),
+            rk: Some(note.rk.into()),
+            proof: Some(note.proof.into()),
+            unspent_proof: ibc_types::DomainType::encode_to_vec(&note.unspent_proof),
+        }
+    }
+}
+
+impl TryFrom<pb::AttestedNote> for AttestedNote {
+    type Error = anyhow::Error;
+
+    fn try_from(proto: pb::AttestedNote) -> Result<Self, Self::Error> {
+        Ok(Self {
+            amount: proto
+                .amount
+                .ok_or_else(|| anyhow::anyhow!("missing amount"))?
+                .try_into()?,
+            nullifier: proto
+                .nullifier
+                .ok_or_else(|| anyhow::anyhow!("missing nullifier"))?
+                .try_into()?,
+            rk: proto
+                .rk
+                .ok_or_else(|| anyhow::anyhow!("missing rk"))?
+                .try_into()?,
+            proof: proto
+                .proof
+                .ok_or_else(|| anyhow::anyhow!("missing proof"))?
+                .try_into()?,
+            unspent_proof: <MerkleProof as ibc_types::DomainType>::decode(
+                proto.unspent_proof.as_slice(),
+            )
+            .context("malformed unspent proof")?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn value_blinding_is_bound_to_context() {
+        let nullifier = Nullifier(Fq::from(1u64));
+        assert_eq!(
+            value_blinding(b"audit", &nullifier),
+            value_blinding(b"audit", &nullifier)
+        );
+        assert_ne!(
+            value_blinding(b"audit", &nullifier),
+            value_blinding(b"other audit", &nullifier)
+        );
+        assert_ne!(
+            value_blinding(b"audit", &nullifier),
+            value_blinding(b"audit", &Nullifier(Fq::from(2u64)))
+        );
+    }
+}
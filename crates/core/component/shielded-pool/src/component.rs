@@ -11,6 +11,6 @@ pub use self::metrics::register_metrics;
 pub use note_manager::NoteManager;
 pub use shielded_pool::{ShieldedPool, StateReadExt, StateWriteExt};
 pub use supply::{SupplyRead, SupplyWrite};
-pub use transfer::Ics20Transfer;
+pub use transfer::{Ics20Callbacks, Ics20Transfer};
 
 pub mod rpc;
@@ -30,7 +30,24 @@ pub trait NoteManager: StateWrite {
         source: CommitmentSource,
     ) -> Result<()> {
         tracing::debug!(?value, ?address, "minting tokens");
+        self.increase_token_supply(&value.asset_id, value.amount)
+            .await?;
+        self.add_public_note(value, address, source).await
+    }
 
+    /// Add a new (public) note to the shielded pool, without changing the
+    /// token supply.
+    ///
+    /// This is for value which is already accounted for in the supply, such as
+    /// value minted earlier and converted into another asset by the chain on
+    /// the recipient's behalf.
+    #[instrument(skip(self, value, address, source))]
+    async fn add_public_note(
+        &mut self,
+        value: Value,
+        address: &Address,
+        source: CommitmentSource,
+    ) -> Result<()> {
         // These notes are public, so we don't need a blinding factor for
         // privacy, but since the note commitments are determined by the note
         // contents, we need to have unique (deterministic) blinding factors for
@@ -56,9 +73,6 @@ pub trait NoteManager: StateWrite {
             .try_into()?;
 
         let note = Note::from_parts(*address, value, Rseed(rseed_bytes))?;
-        // Now record the note and update the total supply:
-        self.increase_token_supply(&value.asset_id, value.amount)
-            .await?;
         self.add_note_payload(note.payload(), source).await;
 
         Ok(())
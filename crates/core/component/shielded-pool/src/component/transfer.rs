@@ -1,4 +1,4 @@
-use std::str::FromStr;
+use std::{marker::PhantomData, str::FromStr};
 
 use crate::{
     component::{NoteManager, SupplyWrite},
@@ -6,7 +6,7 @@ use crate::{
 };
 use anyhow::{Context, Result};
 use async_trait::async_trait;
-use cnidarium::{StateRead, StateWrite};
+use cnidarium::{StateDelta, StateRead, StateWrite};
 use ibc_types::{
     core::channel::{
         channel::Order as ChannelOrder,
@@ -62,8 +62,88 @@ fn is_source(
     }
 }
 
-#[derive(Clone)]
-pub struct Ics20Transfer {}
+/// The ICS-20 transfer application, which executes the follow-up actions
+/// requested by inbound transfers with the callbacks `C`.
+pub struct Ics20Transfer<C = ()> {
+    _callbacks: PhantomData<C>,
+}
+
+impl<C> Clone for Ics20Transfer<C> {
+    fn clone(&self) -> Self {
+        Self {
+            _callbacks: PhantomData,
+        }
+    }
+}
+
+/// Follow-up actions which inbound ICS-20 transfers can request, to be
+/// executed atomically with the transfer itself.
+///
+/// In the style of ADR-8, a transfer requests a callback by setting its memo
+/// to a JSON object with a `dest_callback` field, whose contents are
+/// interpreted by the implementation.  Rather than minting the transferred
+/// value to the receiver, the shielded pool adds it to the token supply and
+/// hands it to the callback, which must credit the receiver with whatever it
+/// converts the value into, using [`NoteManager::add_public_note`].
+///
+/// If the callback fails, so does the transfer, so that the sender is
+/// refunded rather than the receiver holding something they didn't ask for.
+#[async_trait]
+pub trait Ics20Callbacks: Send + Sync + 'static {
+    async fn execute_recv_callback<S: StateWrite>(
+        state: S,
+        callback: &serde_json::Value,
+        value: Value,
+        receiver: &Address,
+        source: CommitmentSource,
+    ) -> Result<()>;
+}
+
+/// No callbacks: any transfer requesting one is rejected.
+#[async_trait]
+impl Ics20Callbacks for () {
+    async fn execute_recv_callback<S: StateWrite>(
+        _state: S,
+        _callback: &serde_json::Value,
+        _value: Value,
+        _receiver: &Address,
+        _source: CommitmentSource,
+    ) -> Result<()> {
+        anyhow::bail!("ICS-20 callbacks are not supported")
+    }
+}
+
+/// Extract the callback requested by the memo of an inbound transfer, if any.
+///
+/// Memos which aren't JSON objects are free text, and request no callback.
+fn dest_callback(memo: &str) -> Option<serde_json::Value> {
+    match serde_json::from_str::<serde_json::Value>(memo) {
+        Ok(serde_json::Value::Object(mut memo)) => memo.remove("dest_callback"),
+        _ => None,
+    }
+}
+
+/// Credit the receiver of an inbound transfer with its value, executing the
+/// callback requested by the transfer, if any.
+async fn credit_receiver<C: Ics20Callbacks, S: StateWrite>(
+    mut state: S,
+    callback: Option<&serde_json::Value>,
+    value: Value,
+    receiver: &Address,
+    source: CommitmentSource,
+) -> Result<()> {
+    match callback {
+        None => state.mint_note(value, receiver, source).await,
+        Some(callback) => {
+            state
+                .increase_token_supply(&value.asset_id, value.amount)
+                .await?;
+            C::execute_recv_callback(&mut state, callback, value, receiver, source)
+                .await
+                .context("ICS-20 callback failed")
+        }
+    }
+}
 
 #[async_trait]
 pub trait Ics20TransferReadExt: StateRead {
@@ -154,7 +234,7 @@ impl<T: StateWrite + ?Sized> Ics20TransferWriteExt for T {}
 
 // see: https://github.com/cosmos/ibc/tree/master/spec/app/ics-020-fungible-token-transfer
 #[async_trait]
-impl AppHandlerCheck for Ics20Transfer {
+impl<C: Ics20Callbacks> AppHandlerCheck for Ics20Transfer<C> {
     async fn chan_open_init_check<S: StateRead>(_state: S, msg: &MsgChannelOpenInit) -> Result<()> {
         if msg.ordering != ChannelOrder::Unordered {
             anyhow::bail!("channel order must be unordered for Ics20 transfer");
@@ -251,7 +331,7 @@ impl AppHandlerCheck for Ics20Transfer {
 }
 
 // the main entry point for ICS20 transfer packet handling
-async fn recv_transfer_packet_inner<S: StateWrite>(
+async fn recv_transfer_packet_inner<C: Ics20Callbacks, S: StateWrite>(
     mut state: S,
     msg: &MsgRecvPacket,
 ) -> Result<()> {
@@ -274,6 +354,7 @@ async fn recv_transfer_packet_inner<S: StateWrite>(
         .try_into()
         .context("couldnt decode amount in ICS20 transfer")?;
     let receiver_address = Address::from_str(&packet_data.receiver)?;
+    let callback = dest_callback(&packet_data.memo);
 
     // NOTE: here we assume we are chain A.
 
@@ -317,19 +398,20 @@ async fn recv_transfer_packet_inner<S: StateWrite>(
             anyhow::bail!("transfer coins failed");
         }
 
-        state
-            .mint_note(
-                value,
-                &receiver_address,
-                CommitmentSource::Ics20Transfer {
-                    packet_seq: msg.packet.sequence.0,
-                    // We are chain A
-                    channel_id: msg.packet.chan_on_a.0.clone(),
-                    sender: packet_data.sender.clone(),
-                },
-            )
-            .await
-            .context("unable to mint note when receiving ics20 transfer packet")?;
+        credit_receiver::<C, _>(
+            &mut state,
+            callback.as_ref(),
+            value,
+            &receiver_address,
+            CommitmentSource::Ics20Transfer {
+                packet_seq: msg.packet.sequence.0,
+                // We are chain A
+                channel_id: msg.packet.chan_on_a.0.clone(),
+                sender: packet_data.sender.clone(),
+            },
+        )
+        .await
+        .context("unable to mint note when receiving ics20 transfer packet")?;
 
         // update the value balance
         let value_balance: Amount = state
@@ -374,19 +456,20 @@ async fn recv_transfer_packet_inner<S: StateWrite>(
             asset_id: denom.id(),
         };
 
-        state
-            .mint_note(
-                value,
-                &receiver_address,
-                CommitmentSource::Ics20Transfer {
-                    packet_seq: msg.packet.sequence.0,
-                    // We are chain A
-                    channel_id: msg.packet.chan_on_a.0.clone(),
-                    sender: packet_data.sender.clone(),
-                },
-            )
-            .await
-            .context("failed to mint notes in ibc transfer")?;
+        credit_receiver::<C, _>(
+            &mut state,
+            callback.as_ref(),
+            value,
+            &receiver_address,
+            CommitmentSource::Ics20Transfer {
+                packet_seq: msg.packet.sequence.0,
+                // We are chain A
+                channel_id: msg.packet.chan_on_a.0.clone(),
+                sender: packet_data.sender.clone(),
+            },
+        )
+        .await
+        .context("failed to mint notes in ibc transfer")?;
 
         // update the value balance
         let value_balance: Amount = state
@@ -511,7 +594,7 @@ async fn timeout_packet_inner<S: StateWrite>(mut state: S, msg: &MsgTimeout) ->
 
 // NOTE: should these be fallible, now that our enclosing state machine is fallible in execution?
 #[async_trait]
-impl AppHandlerExecute for Ics20Transfer {
+impl<C: Ics20Callbacks> AppHandlerExecute for Ics20Transfer<C> {
     async fn chan_open_init_execute<S: StateWrite>(_state: S, _msg: &MsgChannelOpenInit) {}
     async fn chan_open_try_execute<S: StateWrite>(_state: S, _msg: &MsgChannelOpenTry) {}
     async fn chan_open_ack_execute<S: StateWrite>(_state: S, _msg: &MsgChannelOpenAck) {}
@@ -520,15 +603,25 @@ impl AppHandlerExecute for Ics20Transfer {
     async fn chan_close_init_execute<S: StateWrite>(_state: S, _msg: &MsgChannelCloseInit) {}
     async fn recv_packet_execute<S: StateWrite>(mut state: S, msg: &MsgRecvPacket) -> Result<()> {
         // recv packet should never fail a transaction, but it should record a failure acknowledgement.
-        let ack: Vec<u8> = match recv_transfer_packet_inner(&mut state, msg).await {
-            Ok(_) => {
-                // record packet acknowledgement without error
-                TokenTransferAcknowledgement::success().into()
-            }
-            Err(e) => {
-                tracing::debug!("couldnt execute transfer: {:#}", e);
-                // record packet acknowledgement with error
-                TokenTransferAcknowledgement::Error(e.to_string()).into()
+        //
+        // The transfer is executed in its own `StateDelta`, so that if it fails partway through
+        // (e.g. in a callback), none of its effects are applied, and the sender can be refunded.
+        let ack: Vec<u8> = {
+            let mut delta = StateDelta::new(&mut state);
+            match recv_transfer_packet_inner::<C, _>(&mut delta, msg).await {
+                Ok(_) => {
+                    let (parent, events) = delta.apply();
+                    for event in events {
+                        parent.record(event);
+                    }
+                    // record packet acknowledgement without error
+                    TokenTransferAcknowledgement::success().into()
+                }
+                Err(e) => {
+                    tracing::debug!("couldnt execute transfer: {:#}", e);
+                    // record packet acknowledgement with error
+                    TokenTransferAcknowledgement::Error(e.to_string()).into()
+                }
             }
         };
 
@@ -552,4 +645,20 @@ impl AppHandlerExecute for Ics20Transfer {
     async fn acknowledge_packet_execute<S: StateWrite>(_state: S, _msg: &MsgAcknowledgement) {}
 }
 
-impl AppHandler for Ics20Transfer {}
+impl<C: Ics20Callbacks> AppHandler for Ics20Transfer<C> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn only_json_memos_request_callbacks() {
+        assert_eq!(dest_callback(""), None);
+        assert_eq!(dest_callback("gm from osmosis"), None);
+        assert_eq!(dest_callback(r#"{"forward": {}}"#), None);
+        assert_eq!(
+            dest_callback(r#"{"dest_callback": {"delegate": {}}}"#),
+            Some(serde_json::json!({ "delegate": {} }))
+        );
+    }
+}
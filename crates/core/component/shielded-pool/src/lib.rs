@@ -6,6 +6,8 @@ pub mod component;
 pub mod ics20_withdrawal;
 pub use ics20_withdrawal::Ics20Withdrawal;
 
+#[cfg(feature = "component")]
+pub mod attestation;
 pub mod event;
 pub mod fmd;
 pub mod genesis;
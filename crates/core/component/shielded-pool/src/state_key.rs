@@ -1,6 +1,10 @@
 use penumbra_asset::asset;
 use std::string::String;
 
+/// The prefixes of all of the component's state keys, for the application's
+/// state key registry.
+pub const PREFIXES: &[&str] = &["shielded_pool/"];
+
 pub fn token_supply(asset_id: &asset::Id) -> String {
     format!("shielded_pool/assets/{asset_id}/token_supply")
 }
@@ -1,6 +1,7 @@
 use cnidarium_component::ActionHandler;
 
 mod delegate;
+mod redelegate;
 mod undelegate;
 mod undelegate_claim;
 mod validator_definition;
@@ -7,7 +7,10 @@ use cnidarium_component::ActionHandler;
 use penumbra_num::Amount;
 
 use crate::{
-    component::{validator_handler::ValidatorDataRead, StateWriteExt as _},
+    component::{
+        validator_handler::{ValidatorDataRead, ValidatorDataWrite},
+        StateWriteExt as _,
+    },
     event,
     validator::State::*,
     Delegate, StateReadExt as _,
@@ -17,11 +20,77 @@ use crate::{
 impl ActionHandler for Delegate {
     type CheckStatelessContext = ();
     async fn check_stateless(&self, _context: ()) -> Result<()> {
-        // There are no stateless checks specific to this action.
-        Ok(())
+        self.verify_self_bond()
     }
 
     async fn check_stateful<S: StateRead + 'static>(&self, state: Arc<S>) -> Result<()> {
+        self.check_against_state(state.as_ref()).await
+    }
+
+    async fn execute<S: StateWrite>(&self, mut state: S) -> Result<()> {
+        let validator = self.validator_identity;
+        let unbonded_delegation = self.unbonded_amount;
+        // This action is executed in two phases:
+        // 1. We check if the self-delegation requirement is met.
+        // 2. We queue the delegation for the next epoch.
+
+        let validator_state = state
+            .get_validator_state(&self.validator_identity)
+            .await?
+            .ok_or_else(|| anyhow::anyhow!("missing state for validator"))?;
+
+        // When a validator definition is published, it starts in a `Defined` state
+        // where it is unindexed by the staking module. We transition validator with
+        // too little stake to the `Defined` state as well. See #2921 for more details.
+        if validator_state == Defined {
+            let min_stake = state.get_stake_params().await?.min_validator_stake;
+            // With #3853, we impose a minimum self-delegation requirement to simplify
+            // end-epoch handling. The first delegation" to a `Defined` validator must
+            // be at least `min_validator_stake`.
+            //
+            // Note: Validators can be demoted to `Defined` if they have too little stake,
+            // if we don't check that the pool is empty, we could trap delegations.
+            let validator_pool_size = state
+                .get_validator_pool_size(&validator)
+                .await
+                .unwrap_or_else(Amount::zero);
+
+            if validator_pool_size == Amount::zero() {
+                ensure!(
+                    unbonded_delegation >= min_stake,
+                    "first delegation to a `Defined` validator must be at least {min_stake}"
+                );
+                tracing::debug!(%validator, %unbonded_delegation, "first delegation to validator recorded");
+            }
+        }
+
+        // Self-bonded delegation tokens are held by the chain on behalf of the
+        // validator, counting towards its self-delegation requirement.
+        if self.is_self_bond() {
+            let self_bond = state.get_validator_self_bond(&validator).await?;
+            let self_bond = self_bond
+                .checked_add(&self.delegation_amount)
+                .ok_or_else(|| anyhow::anyhow!("validator self-bond overflowed"))?;
+            tracing::debug!(%validator, %self_bond, "increasing validator self-bond");
+            state.set_validator_self_bond(&validator, self_bond);
+        }
+
+        // We queue the delegation so it can be processed at the epoch boundary.
+        tracing::debug!(?self, "queuing delegation for next epoch");
+        state.push_delegation(self.clone());
+        state.record(event::delegate(self));
+        Ok(())
+    }
+}
+
+impl Delegate {
+    /// Check the delegation against the chain state, as
+    /// [`check_stateful`](ActionHandler::check_stateful) does.
+    ///
+    /// This doesn't need a snapshot of the state, so it can also check
+    /// delegations which the chain makes on a user's behalf while executing
+    /// other actions.
+    pub async fn check_against_state<S: StateRead>(&self, state: &S) -> Result<()> {
         let d = self;
         let next_rate_data = state
             .get_validator_rate(&d.validator_identity)
@@ -93,48 +162,4 @@ impl ActionHandler for Delegate {
 
         Ok(())
     }
-
-    async fn execute<S: StateWrite>(&self, mut state: S) -> Result<()> {
-        let validator = self.validator_identity;
-        let unbonded_delegation = self.unbonded_amount;
-        // This action is executed in two phases:
-        // 1. We check if the self-delegation requirement is met.
-        // 2. We queue the delegation for the next epoch.
-
-        let validator_state = state
-            .get_validator_state(&self.validator_identity)
-            .await?
-            .ok_or_else(|| anyhow::anyhow!("missing state for validator"))?;
-
-        // When a validator definition is published, it starts in a `Defined` state
-        // where it is unindexed by the staking module. We transition validator with
-        // too little stake to the `Defined` state as well. See #2921 for more details.
-        if validator_state == Defined {
-            let min_stake = state.get_stake_params().await?.min_validator_stake;
-            // With #3853, we impose a minimum self-delegation requirement to simplify
-            // end-epoch handling. The first delegation" to a `Defined` validator must
-            // be at least `min_validator_stake`.
-            //
-            // Note: Validators can be demoted to `Defined` if they have too little stake,
-            // if we don't check that the pool is empty, we could trap delegations.
-            let validator_pool_size = state
-                .get_validator_pool_size(&validator)
-                .await
-                .unwrap_or_else(Amount::zero);
-
-            if validator_pool_size == Amount::zero() {
-                ensure!(
-                    unbonded_delegation >= min_stake,
-                    "first delegation to a `Defined` validator must be at least {min_stake}"
-                );
-                tracing::debug!(%validator, %unbonded_delegation, "first delegation to validator recorded");
-            }
-        }
-
-        // We queue the delegation so it can be processed at the epoch boundary.
-        tracing::debug!(?self, "queuing delegation for next epoch");
-        state.push_delegation(self.clone());
-        state.record(event::delegate(self));
-        Ok(())
-    }
 }
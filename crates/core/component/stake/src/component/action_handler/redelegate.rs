@@ -0,0 +1,305 @@
+use std::sync::Arc;
+
+use anyhow::{ensure, Result};
+use async_trait::async_trait;
+use cnidarium::{StateRead, StateWrite};
+use cnidarium_component::ActionHandler;
+use penumbra_num::Amount;
+use penumbra_sct::component::{clock::EpochRead as _, StateReadExt as _};
+
+use crate::{
+    component::{
+        validator_handler::{ValidatorDataRead, ValidatorDataWrite},
+        StateWriteExt as _,
+    },
+    event,
+    validator::State::*,
+    Redelegate, StateReadExt as _,
+};
+
+/// Check that the redelegation fits within the source validator's redelegation
+/// limit for the current epoch, returning the validator's new redelegated amount.
+///
+/// Delegation tokens issued by a redelegation can't be told apart from any
+/// others, so a validator which has received redelegations in the current epoch
+/// can't be redelegated away from until the next epoch. Otherwise, stake could
+/// hop through any number of validators in a single epoch.
+///
+/// The limit is released gradually over the course of the epoch, so that no one
+/// can use up a validator's whole limit for everyone else at the start of it.
+async fn check_redelegation_limit<S: StateRead>(state: &S, r: &Redelegate) -> Result<Amount> {
+    let validator = &r.from_validator_identity;
+    let limit_bps = state.get_stake_params().await?.redelegation_limit_bps;
+    ensure!(limit_bps > 0, "redelegation is disabled");
+
+    ensure!(
+        state.get_redelegated_to_amount(validator).await? == Amount::zero(),
+        "{} received redelegations this epoch, so it can't be redelegated away from until the next epoch",
+        validator,
+    );
+
+    let pool_size = state
+        .get_validator_pool_size(validator)
+        .await
+        .unwrap_or_else(Amount::zero);
+    let epoch = state.get_current_epoch().await?;
+    let height = state.get_block_height().await?;
+    let epoch_duration = state.get_epoch_duration_parameter().await?.max(1);
+    let elapsed = height
+        .saturating_sub(epoch.start_height)
+        .saturating_add(1)
+        .min(epoch_duration);
+    let limit = Amount::from(
+        pool_size
+            .value()
+            .saturating_mul(limit_bps.into())
+            .saturating_mul(elapsed.into())
+            / (10_000 * u128::from(epoch_duration)),
+    );
+
+    let redelegated = state
+        .get_redelegated_amount(validator)
+        .await?
+        .checked_add(&r.from_delegation_amount)
+        .ok_or_else(|| anyhow::anyhow!("redelegated amount overflowed"))?;
+    ensure!(
+        redelegated <= limit,
+        "redelegating {} delegation tokens away from {} would exceed its limit of {} so far this epoch",
+        r.from_delegation_amount,
+        validator,
+        limit,
+    );
+
+    Ok(redelegated)
+}
+
+#[async_trait]
+impl ActionHandler for Redelegate {
+    type CheckStatelessContext = ();
+    async fn check_stateless(&self, _context: ()) -> Result<()> {
+        ensure!(
+            self.from_validator_identity != self.to_validator_identity,
+            "cannot redelegate from a validator to itself"
+        );
+        ensure!(
+            self.from_delegation_amount > Amount::zero(),
+            "cannot redelegate zero delegation tokens"
+        );
+        Ok(())
+    }
+
+    async fn check_stateful<S: StateRead + 'static>(&self, state: Arc<S>) -> Result<()> {
+        let r = self;
+        let from_rate_data = state
+            .get_validator_rate(&r.from_validator_identity)
+            .await?
+            .ok_or_else(|| {
+                anyhow::anyhow!("unknown validator identity {}", r.from_validator_identity)
+            })?;
+        let to_rate_data = state
+            .get_validator_rate(&r.to_validator_identity)
+            .await?
+            .ok_or_else(|| {
+                anyhow::anyhow!("unknown validator identity {}", r.to_validator_identity)
+            })?;
+
+        // Check whether the epoch is correct first, to give a more helpful
+        // error message if it's wrong.
+        if r.epoch_index != from_rate_data.epoch_index {
+            anyhow::bail!(
+                "redelegation was prepared for epoch {} but the next epoch is {}",
+                r.epoch_index,
+                from_rate_data.epoch_index
+            );
+        }
+
+        // Redelegations are only allowed to validators which would accept a
+        // delegation, except that the destination can't be `Defined`, so that
+        // redelegations never have to meet the minimum first delegation.
+        let to_validator = state
+            .get_validator_definition(&r.to_validator_identity)
+            .await?
+            .ok_or_else(|| anyhow::anyhow!("missing definition for validator"))?;
+        let to_state = state
+            .get_validator_state(&r.to_validator_identity)
+            .await?
+            .ok_or_else(|| anyhow::anyhow!("missing state for validator"))?;
+        if !to_validator.enabled {
+            anyhow::bail!(
+                "redelegations are only allowed to enabled validators, but {} is disabled",
+                r.to_validator_identity,
+            );
+        }
+        if !matches!(to_state, Inactive | Active) {
+            anyhow::bail!(
+                "redelegations are only allowed to active or inactive validators, but {} is in state {:?}",
+                r.to_validator_identity,
+                to_state,
+            );
+        }
+
+        // The amounts are computed in the same direction as for an
+        // undelegation from the source, followed by a delegation to the
+        // destination: from the delegation tokens the user holds, to the
+        // unbonded stake they represent, to the delegation tokens it buys.
+        let expected_unbonded_amount = from_rate_data.unbonded_amount(r.from_delegation_amount);
+        ensure!(
+            r.unbonded_amount == expected_unbonded_amount,
+            "redelegation unbonded amount {} does not match expected amount {}",
+            r.unbonded_amount,
+            expected_unbonded_amount,
+        );
+        let expected_to_delegation_amount = to_rate_data.delegation_amount(r.unbonded_amount);
+        ensure!(
+            r.to_delegation_amount == expected_to_delegation_amount,
+            "given {} unbonded stake, expected {} delegation tokens but description produces {}",
+            r.unbonded_amount,
+            expected_to_delegation_amount,
+            r.to_delegation_amount,
+        );
+
+        check_redelegation_limit(state.as_ref(), r).await?;
+
+        Ok(())
+    }
+
+    async fn execute<S: StateWrite>(&self, mut state: S) -> Result<()> {
+        // The limit is checked again, since other redelegations in the same
+        // block may have already used it up.
+        let redelegated = check_redelegation_limit(&state, self).await?;
+        state.set_redelegated_amount(&self.from_validator_identity, redelegated);
+        let redelegated_to = state
+            .get_redelegated_to_amount(&self.to_validator_identity)
+            .await?
+            .saturating_add(&self.to_delegation_amount);
+        state.set_redelegated_to_amount(&self.to_validator_identity, redelegated_to);
+
+        // The stake moves between the pools at the epoch boundary, like any
+        // other undelegation and delegation.
+        tracing::debug!(?self, "queuing redelegation for next epoch");
+        state.push_undelegation(self.undelegation());
+        state.push_delegation(self.delegation());
+        state.record(event::redelegate(self));
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use cnidarium::{StateDelta, TempStorage};
+    use decaf377_rdsa::{SigningKey, SpendAuth};
+    use penumbra_sct::{
+        component::{clock::EpochManager as _, StateWriteExt as _},
+        epoch::Epoch,
+        params::SctParameters,
+    };
+    use penumbra_shielded_pool::component::SupplyWrite as _;
+    use rand_core::OsRng;
+
+    use super::*;
+    use crate::{params::StakeParameters, DelegationToken, IdentityKey};
+
+    /// Set up an epoch of ten blocks starting at height zero, with a 5%
+    /// redelegation limit, and three validators with pools of a million
+    /// delegation tokens each.
+    async fn setup(state: &mut StateDelta<cnidarium::Snapshot>) -> Result<[IdentityKey; 3]> {
+        state.put_stake_params(StakeParameters {
+            redelegation_limit_bps: 500,
+            ..Default::default()
+        });
+        state.put_sct_params(SctParameters { epoch_duration: 10 });
+
+        let mut validators = Vec::new();
+        for _ in 0..3 {
+            let identity_key = IdentityKey((&SigningKey::<SpendAuth>::new(OsRng)).into());
+            state
+                .increase_token_supply(
+                    &DelegationToken::from(identity_key).id(),
+                    1_000_000u64.into(),
+                )
+                .await?;
+            validators.push(identity_key);
+        }
+        Ok(validators.try_into().expect("three validators"))
+    }
+
+    fn redelegation(from: IdentityKey, to: IdentityKey, amount: u64) -> Redelegate {
+        Redelegate {
+            from_validator_identity: from,
+            to_validator_identity: to,
+            epoch_index: 0,
+            from_delegation_amount: amount.into(),
+            unbonded_amount: amount.into(),
+            to_delegation_amount: amount.into(),
+        }
+    }
+
+    /// Move to the given height within the first epoch.
+    fn at_height(state: &mut StateDelta<cnidarium::Snapshot>, height: u64) {
+        state.put_block_height(height);
+        state.put_epoch_by_height(
+            height,
+            Epoch {
+                index: 0,
+                start_height: 0,
+            },
+        );
+    }
+
+    #[tokio::test]
+    async fn redelegated_stake_cannot_hop_again_in_the_same_epoch() -> Result<()> {
+        let storage = TempStorage::new().await?;
+        let mut state = StateDelta::new(storage.latest_snapshot());
+        let [a, b, c] = setup(&mut state).await?;
+        at_height(&mut state, 9);
+
+        redelegation(a, b, 1_000).execute(&mut state).await?;
+
+        // The tokens issued by the redelegation to `b` can't be told apart from
+        // the rest of its pool, so none of it can be redelegated this epoch.
+        assert!(check_redelegation_limit(&state, &redelegation(b, c, 1))
+            .await
+            .is_err());
+        assert!(redelegation(b, c, 1).execute(&mut state).await.is_err());
+
+        // Redelegating to `b`, or away from `a`, is still allowed.
+        redelegation(c, b, 1_000).execute(&mut state).await?;
+        redelegation(a, c, 1_000).execute(&mut state).await?;
+
+        // Once the epoch ends, `b` can be redelegated away from again.
+        state.set_redelegated_to_amount(&b, Amount::zero());
+        redelegation(b, a, 1).execute(&mut state).await?;
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn one_redelegator_cannot_use_up_the_limit_for_the_whole_epoch() -> Result<()> {
+        let storage = TempStorage::new().await?;
+        let mut state = StateDelta::new(storage.latest_snapshot());
+        let [a, b, c] = setup(&mut state).await?;
+
+        // In the first of ten blocks, a tenth of the 50,000 token limit is
+        // released.
+        at_height(&mut state, 0);
+        assert!(redelegation(a, b, 50_000)
+            .execute(&mut state)
+            .await
+            .is_err());
+        redelegation(a, b, 5_000).execute(&mut state).await?;
+        assert!(redelegation(a, c, 1).execute(&mut state).await.is_err());
+
+        // Each block releases more of the limit for others to use.
+        at_height(&mut state, 1);
+        redelegation(a, c, 5_000).execute(&mut state).await?;
+        assert!(redelegation(a, c, 1).execute(&mut state).await.is_err());
+
+        // By the last block, the whole limit is released, but no more.
+        at_height(&mut state, 9);
+        redelegation(a, c, 40_000).execute(&mut state).await?;
+        assert!(redelegation(a, c, 1).execute(&mut state).await.is_err());
+        assert_eq!(state.get_redelegated_amount(&a).await?, 50_000u64.into());
+
+        Ok(())
+    }
+}
@@ -3,19 +3,56 @@ use std::sync::Arc;
 use anyhow::{ensure, Result};
 use async_trait::async_trait;
 use cnidarium::{StateRead, StateWrite};
+use penumbra_num::Amount;
 use penumbra_shielded_pool::component::SupplyWrite;
 
 use crate::{
     component::action_handler::ActionHandler,
-    component::{validator_handler::ValidatorDataRead, StateWriteExt as _},
+    component::{
+        validator_handler::{ValidatorDataRead, ValidatorDataWrite},
+        StateWriteExt as _,
+    },
     event, Undelegate,
 };
 
+/// Check that a release from the validator's self-bond follows its previous
+/// release, and doesn't exceed its self-bond, returning the remaining self-bond.
+async fn check_self_bond_release<S: StateRead>(
+    state: &S,
+    undelegate: &Undelegate,
+) -> Result<Option<(u64, Amount)>> {
+    let Some(release) = &undelegate.self_bond_release else {
+        return Ok(None);
+    };
+    let validator = &undelegate.validator_identity;
+
+    let previous_sequence = state.get_self_bond_release_sequence(validator).await?;
+    ensure!(
+        release.sequence == previous_sequence + 1,
+        "self-bond release has sequence number {} but the next sequence number is {}",
+        release.sequence,
+        previous_sequence + 1,
+    );
+
+    let self_bond = state.get_validator_self_bond(validator).await?;
+    let remaining = self_bond
+        .checked_sub(&undelegate.delegation_amount)
+        .ok_or_else(|| {
+            anyhow::anyhow!(
+                "cannot release {} delegation tokens from a self-bond of {}",
+                undelegate.delegation_amount,
+                self_bond,
+            )
+        })?;
+
+    Ok(Some((release.sequence, remaining)))
+}
+
 #[async_trait]
 impl ActionHandler for Undelegate {
     type CheckStatelessContext = ();
     async fn check_stateless(&self, _context: ()) -> Result<()> {
-        Ok(())
+        self.verify_self_bond_release()
     }
 
     async fn check_stateful<S: StateRead + 'static>(&self, state: Arc<S>) -> Result<()> {
@@ -60,10 +97,20 @@ impl ActionHandler for Undelegate {
             expected_unbonded_amount,
         );
 
+        check_self_bond_release(state.as_ref(), u).await?;
+
         Ok(())
     }
 
     async fn execute<S: StateWrite>(&self, mut state: S) -> Result<()> {
+        // Releases from the self-bond are checked again, since another release
+        // in the same block may have already consumed the sequence number.
+        if let Some((sequence, self_bond)) = check_self_bond_release(&state, self).await? {
+            tracing::debug!(validator = %self.validator_identity, %self_bond, "releasing validator self-bond");
+            state.set_validator_self_bond(&self.validator_identity, self_bond);
+            state.set_self_bond_release_sequence(&self.validator_identity, sequence);
+        }
+
         tracing::debug!(?self, "queuing undelegation for next epoch");
         state.push_undelegation(self.clone());
         // Register the undelegation's denom, so clients can look it up later.
@@ -10,7 +10,7 @@ use crate::component::validator_handler::ValidatorDataRead;
 use crate::component::SlashingData;
 use crate::undelegate_claim::UndelegateClaimProofPublic;
 use crate::UndelegateClaim;
-use crate::{component::action_handler::ActionHandler, UnbondingToken};
+use crate::{component::action_handler::ActionHandler, event, UnbondingToken};
 
 #[async_trait]
 impl ActionHandler for UndelegateClaim {
@@ -63,8 +63,16 @@ impl ActionHandler for UndelegateClaim {
         Ok(())
     }
 
-    async fn execute<S: StateWrite>(&self, _state: S) -> Result<()> {
+    async fn execute<S: StateWrite>(&self, mut state: S) -> Result<()> {
         // TODO: where should we be tracking token supply changes?
+
+        // The claimed amount is shielded, but the terms of the claim aren't, so
+        // record them for accounting purposes.
+        let receipt = state
+            .delegation_receipt(&self.body.validator_identity, self.body.start_epoch_index)
+            .await?;
+        state.record(event::undelegate_claim_receipt(&receipt));
+
         Ok(())
     }
 }
@@ -9,7 +9,8 @@ use penumbra_proto::DomainType;
 
 use crate::{
     component::action_handler::ActionHandler, component::validator_handler::ValidatorDataRead,
-    component::validator_handler::ValidatorManager, rate::RateData, validator,
+    component::validator_handler::ValidatorManager, component::StateReadExt as _, event,
+    rate::RateData, state_key, validator,
 };
 
 #[async_trait]
@@ -78,6 +79,31 @@ impl ActionHandler for validator::Definition {
                     current_seq
                 );
             }
+        } else if let Some(tombstone) = state
+            .get_validator_tombstone(&v.validator.identity_key)
+            .await?
+        {
+            // The validator was pruned for inactivity, so its last definition
+            // is only recorded in its tombstone. Checking it here prevents old
+            // definitions from being replayed to revive the validator.
+            if v.validator.sequence_number <= tombstone.sequence_number {
+                anyhow::bail!(
+                    "expected sequence numbers to be increasing: sequence number of pruned validator is {}",
+                    tombstone.sequence_number
+                );
+            }
+        }
+
+        // Check that funding streams are only scheduled to take effect in a future epoch.
+        if let Some(effective_epoch) = v.validator.funding_streams_effective_epoch {
+            let current_epoch = state.get_current_epoch().await?;
+            if effective_epoch <= current_epoch.index {
+                anyhow::bail!(
+                    "funding streams must take effect after the current epoch {}, but were scheduled for epoch {}",
+                    current_epoch.index,
+                    effective_epoch
+                );
+            }
         }
 
         // Check whether the consensus key has already been used by another validator.
@@ -103,6 +129,28 @@ impl ActionHandler for validator::Definition {
             }
         }
 
+        // Check whether the name is already used by another validator, if names are required to
+        // be unique, so that well-known validators can't be impersonated.
+        if state
+            .get_stake_params()
+            .await?
+            .enforce_unique_validator_names
+        {
+            let name = validator::normalize_name(&v.validator.name);
+            if let Some(existing_id) = state
+                .validators_with_normalized_name(&name)
+                .await?
+                .into_iter()
+                .find(|existing_id| *existing_id != v.validator.identity_key)
+            {
+                anyhow::bail!(
+                    "validator name {:?} is already in use by validator {}",
+                    v.validator.name,
+                    existing_id,
+                );
+            }
+        }
+
         Ok(())
     }
 
@@ -114,13 +162,44 @@ impl ActionHandler for validator::Definition {
             .await
             .context("should be able to get current epoch during validator definition execution")?;
 
-        let validator_exists = state
+        let existing_v = state
             .get_validator_definition(&v.validator.identity_key)
             .await
-            .context("should be able to fetch validator during validator definition execution")?
-            .is_some();
+            .context("should be able to fetch validator during validator definition execution")?;
 
-        if validator_exists {
+        // Flag a new or changed name which is easily mistaken for another validator's. Such names
+        // are allowed, since they may be legitimate, but delegators should be warned of them.
+        if existing_v
+            .as_ref()
+            .map_or(true, |existing_v| existing_v.name != v.validator.name)
+        {
+            let skeleton = validator::name_skeleton(&v.validator.name);
+            if !skeleton.is_empty() {
+                for other_id in state
+                    .validators_with_name_skeleton(&skeleton)
+                    .await?
+                    .into_iter()
+                    .filter(|other_id| *other_id != v.validator.identity_key)
+                {
+                    let other = state
+                        .get_validator_definition(&other_id)
+                        .await?
+                        .ok_or_else(|| {
+                            anyhow::anyhow!("indexed validator {other_id} has no definition")
+                        })?;
+                    tracing::warn!(
+                        validator = %v.validator.identity_key,
+                        name = %v.validator.name,
+                        similar_to = %other.identity_key,
+                        similar_name = %other.name,
+                        "validator name is similar to another validator's"
+                    );
+                    state.record(event::validator_name_similar(&v.validator, &other));
+                }
+            }
+        }
+
+        if existing_v.is_some() {
             state
                 .update_validator_definition(v.validator.clone())
                 .await
@@ -132,6 +211,11 @@ impl ActionHandler for validator::Definition {
             // rate data with an initial exchange rate of 1:1.
             let validator_key = v.validator.identity_key;
 
+            // A new validator has no delegators who need notice of its
+            // commission, so its funding streams take effect immediately.
+            let mut validator = v.validator.clone();
+            validator.funding_streams_effective_epoch = None;
+
             let initial_rate_data = RateData {
                 identity_key: validator_key,
                 epoch_index: current_epoch.index,
@@ -139,8 +223,14 @@ impl ActionHandler for validator::Definition {
                 validator_exchange_rate: 1_0000_0000u128.into(), // 1 represented as 1e8
             };
 
+            // A validator redefined after being pruned starts afresh, so its
+            // tombstone no longer applies.
+            state.nonverifiable_delete(
+                state_key::validators::tombstones::by_id(&validator_key).into_bytes(),
+            );
+
             state
-                .add_validator(v.validator.clone(), initial_rate_data)
+                .add_validator(validator, initial_rate_data)
                 .await
                 .context("should be able to add validator during validator definition execution")?;
         }
@@ -148,3 +238,97 @@ impl ActionHandler for validator::Definition {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use cnidarium::{StateDelta, TempStorage};
+    use decaf377_rdsa::{SigningKey, SpendAuth};
+    use penumbra_sct::{
+        component::{clock::EpochManager as _, StateWriteExt as _},
+        epoch::Epoch,
+        params::SctParameters,
+    };
+    use rand_core::OsRng;
+
+    use super::*;
+    use crate::{
+        component::StateWriteExt as _, params::StakeParameters, validator::Validator,
+        GovernanceKey, IdentityKey,
+    };
+
+    fn definition(
+        signing_key: &SigningKey<SpendAuth>,
+        name: &str,
+        sequence_number: u32,
+    ) -> validator::Definition {
+        let consensus_key = ed25519_consensus::SigningKey::new(OsRng).verification_key();
+        let validator = Validator {
+            identity_key: IdentityKey(signing_key.into()),
+            governance_key: GovernanceKey(signing_key.into()),
+            consensus_key: tendermint::PublicKey::from_raw_ed25519(consensus_key.as_bytes())
+                .expect("consensus key is valid"),
+            name: name.to_string(),
+            website: String::new(),
+            description: String::new(),
+            enabled: true,
+            funding_streams: Default::default(),
+            funding_streams_effective_epoch: None,
+            sequence_number,
+        };
+        let auth_sig = signing_key.sign(OsRng, &validator.encode_to_vec());
+        validator::Definition {
+            validator,
+            auth_sig,
+        }
+    }
+
+    #[tokio::test]
+    async fn validator_names_are_unique_until_renamed() -> Result<()> {
+        let storage = TempStorage::new().await?;
+        let mut state = StateDelta::new(storage.latest_snapshot());
+        state.put_stake_params(StakeParameters {
+            enforce_unique_validator_names: true,
+            ..Default::default()
+        });
+        state.put_sct_params(SctParameters { epoch_duration: 10 });
+        state.put_block_height(0);
+        state.put_epoch_by_height(
+            0,
+            Epoch {
+                index: 0,
+                start_height: 0,
+            },
+        );
+
+        let a = SigningKey::<SpendAuth>::new(OsRng);
+        definition(&a, "Penumbra Labs", 0)
+            .execute(&mut state)
+            .await?;
+        storage.commit(state).await?;
+
+        // A name which differs only in case and whitespace is the same name.
+        let b = SigningKey::<SpendAuth>::new(OsRng);
+        let impersonation = definition(&b, "penumbra  LABS", 0);
+        assert!(impersonation
+            .check_stateful(Arc::new(storage.latest_snapshot()))
+            .await
+            .is_err());
+
+        // The validator may keep its own name when redefining itself.
+        definition(&a, "PENUMBRA LABS", 1)
+            .check_stateful(Arc::new(storage.latest_snapshot()))
+            .await?;
+
+        // Once the validator is renamed, its old name is free for others.
+        let mut state = StateDelta::new(storage.latest_snapshot());
+        definition(&a, "Penumbra Labs (old)", 1)
+            .execute(&mut state)
+            .await?;
+        storage.commit(state).await?;
+        impersonation
+            .check_stateful(Arc::new(storage.latest_snapshot()))
+            .await?;
+
+        Ok(())
+    }
+}
@@ -7,7 +7,7 @@ use async_trait::async_trait;
 use futures::StreamExt;
 use penumbra_asset::STAKING_TOKEN_ASSET_ID;
 
-use cnidarium::StateWrite;
+use cnidarium::{StateRead, StateWrite};
 use futures::TryStreamExt;
 use penumbra_num::{fixpoint::U128x128, Amount};
 use penumbra_proto::{StateReadProto, StateWriteProto};
@@ -21,8 +21,10 @@ use crate::state_key;
 use crate::BPS_SQUARED_SCALING_FACTOR;
 use crate::{
     component::{
-        stake::{ConsensusUpdateWrite, InternalStakingData, RateDataWrite},
-        validator_handler::{ValidatorDataRead, ValidatorDataWrite, ValidatorManager},
+        stake::{ConsensusUpdateWrite, InternalStakingData, RateDataWrite, VALIDATOR_UPDATE_DELAY},
+        validator_handler::{
+            PowerCheckpointWrite, ValidatorDataRead, ValidatorDataWrite, ValidatorManager,
+        },
         SlashingData,
     },
     rate::BaseRateData,
@@ -87,6 +89,11 @@ pub trait EpochHandler: StateWriteExt + ConsensusIndexRead {
             "collected delegation changes for the epoch"
         );
 
+        // Apply any funding streams scheduled to take effect in the upcoming epoch,
+        // so that they are used to compute the validators' rates for it.
+        self.apply_scheduled_funding_streams(epoch_to_end.index + 1)
+            .await?;
+
         // Compute and set the chain base rate for the upcoming epoch.
         let next_base_rate = self.process_chain_base_rate().await?;
 
@@ -99,6 +106,17 @@ pub trait EpochHandler: StateWriteExt + ConsensusIndexRead {
             .keys()
             .cloned()
             .collect::<BTreeSet<_>>();
+
+        // Every redelegation queues an undelegation from its source and a
+        // delegation to its destination, so this resets the redelegations of
+        // every validator which was either.
+        for validator_identity in &undelegation_set {
+            self.set_redelegated_amount(validator_identity, Amount::zero());
+        }
+        for validator_identity in &delegation_set {
+            self.set_redelegated_to_amount(validator_identity, Amount::zero());
+        }
+
         let validators_with_delegation_changes = delegation_set
             .union(&undelegation_set)
             .cloned()
@@ -163,6 +181,14 @@ pub trait EpochHandler: StateWriteExt + ConsensusIndexRead {
         // Now that the consensus set voting power has been calculated, we can select the
         // top N validators to be active for the next epoch.
         self.set_active_and_inactive_validators().await?;
+
+        // Checkpoint the voting power of the validators for the upcoming epoch, so that
+        // it can be proven to light clients once the epoch is over.
+        self.checkpoint_validator_power(epoch_to_end.index + 1)
+            .await?;
+
+        // Finally, prune the validators which have been idle for too long.
+        self.prune_inactive_validators(epoch_to_end.index).await?;
         Ok(())
     }
 
@@ -308,6 +334,16 @@ pub trait EpochHandler: StateWriteExt + ConsensusIndexRead {
         // Update the state of the validator within the validator set
         // with the newly starting epoch's calculated voting rate and power.
         self.set_validator_rate_data(&validator.identity_key, next_validator_rate.clone());
+        // Only the rates of epochs whose undelegations may still be unbonding
+        // are kept in the verifiable state.
+        let unbonding_epochs = self.get_stake_params().await?.unbonding_epochs;
+        self.prune_validator_rate_data(
+            &validator.identity_key,
+            next_validator_rate
+                .epoch_index
+                .saturating_sub(unbonding_epochs),
+        )
+        .await?;
         self.set_validator_power(&validator.identity_key, voting_power)?;
 
         // The epoch is ending, so we check if this validator was active and if so
@@ -363,6 +399,45 @@ pub trait EpochHandler: StateWriteExt + ConsensusIndexRead {
         Ok(reward_queue_entry)
     }
 
+    /// Replace the funding streams of each validator which scheduled a change
+    /// to take effect in the given epoch.
+    async fn apply_scheduled_funding_streams(&mut self, epoch_index: u64) -> Result<()> {
+        for scheduled in self
+            .scheduled_funding_streams_for_epoch(epoch_index)
+            .await?
+        {
+            let identity_key = scheduled.identity_key;
+            self.cancel_scheduled_funding_streams(&identity_key).await?;
+
+            let current_definition = self
+                .get_validator_definition(&identity_key)
+                .await?
+                .ok_or_else(|| {
+                    anyhow::anyhow!("validator (identity={}) has scheduled funding streams but its definition was not found in the JMT", &identity_key)
+                })?;
+            let validator = validator::Validator {
+                funding_streams: scheduled.funding_streams,
+                ..current_definition
+            };
+
+            tracing::debug!(
+                ?identity_key,
+                epoch_index,
+                "applying scheduled funding streams"
+            );
+            self.record(crate::event::funding_streams_applied(
+                &validator,
+                epoch_index,
+            ));
+            self.put(
+                state_key::validators::definitions::by_id(&identity_key),
+                validator,
+            );
+        }
+
+        Ok(())
+    }
+
     async fn process_chain_base_rate(&mut self) -> Result<BaseRateData> {
         // We are transitioning to the next epoch, so the "current" base rate in
         // the state is now the previous base rate.
@@ -386,13 +461,7 @@ pub trait EpochHandler: StateWriteExt + ConsensusIndexRead {
         );
 
         let base_reward_rate =
-            U128x128::ratio(issuance_budget_for_epoch, total_active_stake_previous_epoch)
-                .expect("total active stake is nonzero");
-        let base_reward_rate: Amount = (base_reward_rate * *BPS_SQUARED_SCALING_FACTOR)
-            .expect("base reward rate is around one")
-            .round_down()
-            .try_into()
-            .expect("rounded to an integral value");
+            base_reward_rate(issuance_budget_for_epoch, total_active_stake_previous_epoch)?;
         tracing::debug!(%base_reward_rate, "base reward rate for the upcoming epoch");
 
         let next_base_rate = prev_base_rate.next_epoch(base_reward_rate);
@@ -418,9 +487,12 @@ pub trait EpochHandler: StateWriteExt + ConsensusIndexRead {
     async fn set_active_and_inactive_validators(&mut self) -> Result<()> {
         // A list of all active and inactive validators, with nonzero voting power.
         let mut validators_by_power = Vec::new();
-        // A list of validators with zero power, who must be inactive.
+        // A list of validators with zero power, or with too little stake bonded
+        // to themselves, who must be inactive.
         let mut zero_power = Vec::new();
 
+        let min_self_delegation = self.get_stake_params().await?.min_validator_self_delegation;
+
         let mut validator_identity_stream = self.consensus_set_stream()?;
         while let Some(identity_key) = validator_identity_stream.next().await {
             let identity_key = identity_key?;
@@ -435,6 +507,13 @@ pub trait EpochHandler: StateWriteExt + ConsensusIndexRead {
             if matches!(state, validator::State::Active | validator::State::Inactive) {
                 if power == Amount::zero() {
                     zero_power.push((identity_key, power));
+                } else if self.self_bond_value(&identity_key).await? < min_self_delegation {
+                    tracing::debug!(
+                        validator = %identity_key,
+                        %min_self_delegation,
+                        "validator self-bond is below the minimum, keeping it out of the active set"
+                    );
+                    zero_power.push((identity_key, power));
                 } else {
                     validators_by_power.push((identity_key, power));
                 }
@@ -465,6 +544,17 @@ pub trait EpochHandler: StateWriteExt + ConsensusIndexRead {
         Ok(())
     }
 
+    /// Returns the value of the validator's self-bond, in staking tokens, at the
+    /// validator's current exchange rate.
+    async fn self_bond_value(&self, identity_key: &IdentityKey) -> Result<Amount> {
+        let self_bond = self.get_validator_self_bond(identity_key).await?;
+        let rate = self
+            .get_validator_rate(identity_key)
+            .await?
+            .context("validator rate data should be present")?;
+        Ok(rate.unbonded_amount(self_bond))
+    }
+
     /// Materializes the entire current validator set as a CometBFT update.
     ///
     /// This re-defines all validators every time, to simplify the code compared to
@@ -565,11 +655,207 @@ pub trait EpochHandler: StateWriteExt + ConsensusIndexRead {
         tracing::debug!(?updated_consensus_keys);
         self.put(
             state_key::consensus_update::consensus_keys().to_owned(),
-            updated_consensus_keys,
+            updated_consensus_keys.clone(),
         );
 
+        // Keep a history of the keys, for as long as evidence of misbehavior
+        // can be submitted: the keys in effect during the last
+        // `unbonding_epochs` epochs, and the two blocks before them.
+        let height = self.get_block_height().await?;
+        let effective_height = if height == 0 {
+            // The genesis validators are in effect from the very first block.
+            0
+        } else {
+            height + VALIDATOR_UPDATE_DELAY
+        };
+        let retained = self.get_stake_params().await?.unbonding_epochs as usize + 2;
+        self.record_consensus_keys(effective_height, updated_consensus_keys, retained)
+            .await?;
+
         Ok(())
     }
 }
 
 impl<T: StateWrite + ConsensusIndexRead + ?Sized> EpochHandler for T {}
+
+/// Computes the base reward rate for the epoch following one with the given
+/// staking token issuance budget and total active stake.
+pub(crate) fn base_reward_rate(
+    issuance_budget: Amount,
+    total_active_stake: Amount,
+) -> Result<Amount> {
+    let base_reward_rate = U128x128::ratio(issuance_budget, total_active_stake)
+        .context("total active stake should be nonzero")?;
+    Ok((base_reward_rate * *BPS_SQUARED_SCALING_FACTOR)
+        .context("base reward rate should be around one")?
+        .round_down()
+        .try_into()
+        .expect("rounded to an integral value"))
+}
+
+/// Project the base rate for the next epoch, as it would be computed if the
+/// current epoch lasted for its full duration, with the active stake as it
+/// stands.
+pub(crate) async fn project_base_rate<S: StateRead + ?Sized>(state: &S) -> Result<BaseRateData> {
+    let current_base_rate = state.get_current_base_rate().await?;
+    let issuance_budget = state.projected_staking_token_issuance_for_epoch().await?;
+    let total_active_stake = state.total_active_stake().await?;
+    let base_reward_rate = base_reward_rate(issuance_budget, total_active_stake)?;
+    Ok(current_base_rate.next_epoch(base_reward_rate))
+}
+
+#[cfg(test)]
+mod tests {
+    use cnidarium::{StateDelta, TempStorage};
+    use decaf377_rdsa::{SigningKey, SpendAuth};
+    use penumbra_sct::component::clock::EpochManager as _;
+    use rand_core::OsRng;
+
+    use super::*;
+    use crate::{params::StakeParameters, rate::RateData, validator::Validator, GovernanceKey};
+
+    async fn add_inactive_validator<S: StateWrite>(
+        state: &mut S,
+        power: u64,
+        self_bond: u64,
+    ) -> Result<IdentityKey> {
+        let signing_key = SigningKey::<SpendAuth>::new(OsRng);
+        let identity_key = IdentityKey((&signing_key).into());
+        let consensus_key = ed25519_consensus::SigningKey::new(OsRng).verification_key();
+        state
+            .add_validator(
+                Validator {
+                    identity_key,
+                    governance_key: GovernanceKey((&signing_key).into()),
+                    consensus_key: PublicKey::from_raw_ed25519(consensus_key.as_bytes())
+                        .expect("consensus key is valid"),
+                    name: String::new(),
+                    website: String::new(),
+                    description: String::new(),
+                    enabled: true,
+                    funding_streams: Default::default(),
+                    funding_streams_effective_epoch: None,
+                    sequence_number: 0,
+                },
+                RateData {
+                    identity_key,
+                    epoch_index: 0,
+                    validator_reward_rate: 0u128.into(),
+                    validator_exchange_rate: 2_0000_0000u128.into(),
+                },
+            )
+            .await?;
+        state
+            .set_validator_state(&identity_key, validator::State::Inactive)
+            .await?;
+        state.set_validator_power(&identity_key, power.into())?;
+        state.set_validator_self_bond(&identity_key, self_bond.into());
+        Ok(identity_key)
+    }
+
+    #[tokio::test]
+    async fn validators_below_the_self_bond_minimum_stay_inactive() -> Result<()> {
+        let storage = TempStorage::new().await?;
+        let mut state = StateDelta::new(storage.latest_snapshot());
+        state.put_stake_params(StakeParameters {
+            min_validator_self_delegation: 100u64.into(),
+            ..Default::default()
+        });
+        state.put_block_height(0);
+        state.put_epoch_by_height(
+            0,
+            Epoch {
+                index: 0,
+                start_height: 0,
+            },
+        );
+
+        // At an exchange rate of 2, a self-bond of 50 delegation tokens is
+        // worth exactly the minimum, and one of 49 isn't.
+        let bonded = add_inactive_validator(&mut state, 1_000, 50).await?;
+        let underbonded = add_inactive_validator(&mut state, 2_000, 49).await?;
+
+        state.set_active_and_inactive_validators().await?;
+        assert_eq!(
+            state.get_validator_state(&bonded).await?,
+            Some(validator::State::Active)
+        );
+        // Having more voting power doesn't make up for the missing self-bond.
+        assert_eq!(
+            state.get_validator_state(&underbonded).await?,
+            Some(validator::State::Inactive)
+        );
+
+        // Once its self-bond falls below the minimum, an active validator
+        // leaves the active set.
+        state.set_validator_self_bond(&bonded, 10u64.into());
+        state.set_active_and_inactive_validators().await?;
+        assert_eq!(
+            state.get_validator_state(&bonded).await?,
+            Some(validator::State::Inactive)
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn projected_base_rate_matches_the_end_of_a_full_epoch() -> Result<()> {
+        use cnidarium_component::Component as _;
+        use penumbra_distributions::{
+            component::{Distributions, StateWriteExt as _},
+            DistributionsParameters,
+        };
+        use penumbra_sct::{component::StateWriteExt as _, params::SctParameters};
+        use std::sync::Arc;
+
+        let storage = TempStorage::new().await?;
+        let mut state = StateDelta::new(storage.latest_snapshot());
+        state.put_stake_params(StakeParameters::default());
+        state.put_sct_params(SctParameters { epoch_duration: 10 });
+        state.put_distributions_params(DistributionsParameters {
+            staking_issuance_per_block: 3,
+        });
+        state.set_base_rate(BaseRateData {
+            epoch_index: 0,
+            base_reward_rate: 0u128.into(),
+            base_exchange_rate: 1_0000_0000u128.into(),
+        });
+        // The last block of an epoch which lasts for the full duration.
+        state.put_block_height(9);
+        state.put_epoch_by_height(
+            9,
+            Epoch {
+                index: 0,
+                start_height: 0,
+            },
+        );
+
+        let identity_key = add_inactive_validator(&mut state, 1_000, 0).await?;
+        state.put(
+            state_key::validators::state::by_id(&identity_key),
+            validator::State::Active,
+        );
+        state.add_consensus_set_index(&identity_key);
+        state
+            .increase_token_supply(
+                &DelegationToken::from(identity_key).id(),
+                1_000_000_000_000u64.into(),
+            )
+            .await?;
+
+        // The projection, made before the epoch ends, doesn't assume that the
+        // base reward rate stays the same.
+        let projected = project_base_rate(&state).await?;
+        assert_ne!(projected.base_reward_rate, Amount::zero());
+
+        let mut state = Arc::new(state);
+        Distributions::end_epoch(&mut state).await?;
+        let next = Arc::get_mut(&mut state)
+            .expect("state should be unique")
+            .process_chain_base_rate()
+            .await?;
+        assert_eq!(projected, next);
+
+        Ok(())
+    }
+}
@@ -1,22 +1,40 @@
-use std::pin::Pin;
+use std::{collections::BTreeMap, pin::Pin};
 
+use anyhow::anyhow;
 use async_stream::try_stream;
-use cnidarium::Storage;
+use cnidarium::{Snapshot, Storage};
 use futures::{StreamExt, TryStreamExt};
+use penumbra_num::Amount;
 use penumbra_proto::{
     core::component::stake::v1::{
         query_service_server::QueryService, CurrentValidatorRateRequest,
-        CurrentValidatorRateResponse, ValidatorInfoRequest, ValidatorInfoResponse,
-        ValidatorPenaltyRequest, ValidatorPenaltyResponse, ValidatorStatusRequest,
-        ValidatorStatusResponse,
+        CurrentValidatorRateResponse, DelegationChangeQueueRequest, DelegationChangeQueueResponse,
+        DelegationReceiptRequest, DelegationReceiptResponse, ValidatorDiagnosisRequest,
+        ValidatorDiagnosisResponse, ValidatorInfoRequest, ValidatorInfoResponse,
+        ValidatorParticipationRequest, ValidatorParticipationResponse, ValidatorPenaltyRequest,
+        ValidatorPenaltyResponse, ValidatorPowerProofRequest, ValidatorPowerProofResponse,
+        ValidatorRateHistoryRequest, ValidatorRateHistoryResponse, ValidatorSetSnapshotRequest,
+        ValidatorSetSnapshotResponse, ValidatorStatusRequest, ValidatorStatusResponse,
+        ValidatorTombstoneRequest, ValidatorTombstoneResponse,
     },
     DomainType,
 };
+use penumbra_sct::component::clock::EpochRead;
+use penumbra_shielded_pool::component::SupplyRead;
 use tonic::Status;
 use tracing::instrument;
 
-use super::{validator_handler::ValidatorDataRead, SlashingData};
-use crate::validator;
+use super::{
+    epoch_handler::project_base_rate,
+    validator_handler::{
+        power_checkpoint::prove_validator_power, snapshot_sync::prove_validator_set_snapshot,
+        ValidatorDataRead,
+    },
+    SlashingData, StateReadExt,
+};
+use crate::{
+    rate::BaseRateData, validator, DelegationChanges, DelegationToken, IdentityKey, Penalty,
+};
 
 // TODO: Hide this and only expose a Router?
 pub struct Server {
@@ -146,4 +164,362 @@ impl QueryService for Server {
             None => Err(Status::not_found("current validator rate not found")),
         }
     }
+
+    type DelegationChangeQueueStream = Pin<
+        Box<
+            dyn futures::Stream<Item = Result<DelegationChangeQueueResponse, tonic::Status>> + Send,
+        >,
+    >;
+
+    #[instrument(skip(self, request))]
+    async fn delegation_change_queue(
+        &self,
+        request: tonic::Request<DelegationChangeQueueRequest>,
+    ) -> Result<tonic::Response<Self::DelegationChangeQueueStream>, Status> {
+        let state = self.storage.latest_snapshot();
+        let identity_key: Option<IdentityKey> = request
+            .into_inner()
+            .identity_key
+            .map(TryInto::try_into)
+            .transpose()
+            .map_err(|_| Status::invalid_argument("invalid identity key"))?;
+
+        let pending = match identity_key {
+            Some(identity_key) => state
+                .get_pending_delegation_changes_for(&identity_key)
+                .await
+                .map(|changes| {
+                    let mut pending = BTreeMap::new();
+                    if !changes.delegations.is_empty() || !changes.undelegations.is_empty() {
+                        pending.insert(identity_key, changes);
+                    }
+                    pending
+                }),
+            None => state.get_pending_delegation_changes().await,
+        }
+        .map_err(|e| {
+            Status::unavailable(format!("error getting pending delegation changes: {e}"))
+        })?;
+
+        let epoch = state
+            .get_current_epoch()
+            .await
+            .map_err(|e| Status::unavailable(format!("error getting current epoch: {e}")))?;
+        // The staking issuance budget is only known at the epoch boundary itself, so we project
+        // the next base rate from the issuance of a full-length epoch, and the active stake as it
+        // stands.
+        let projected_base_rate = project_base_rate(&state)
+            .await
+            .map_err(|e| Status::unavailable(format!("error projecting base rate: {e}")))?;
+
+        let s = try_stream! {
+            for (identity_key, changes) in pending {
+                yield project_delegation_changes(
+                    &state,
+                    identity_key,
+                    changes,
+                    epoch.index,
+                    &projected_base_rate,
+                )
+                .await?;
+            }
+        };
+
+        Ok(tonic::Response::new(
+            s.map_err(|e: anyhow::Error| {
+                tonic::Status::unavailable(format!("error projecting delegation changes: {e}"))
+            })
+            .boxed(),
+        ))
+    }
+
+    #[instrument(skip(self, request))]
+    async fn validator_set_snapshot(
+        &self,
+        request: tonic::Request<ValidatorSetSnapshotRequest>,
+    ) -> Result<tonic::Response<ValidatorSetSnapshotResponse>, Status> {
+        let ValidatorSetSnapshotRequest { height, proof } = request.into_inner();
+        // A height of zero requests a snapshot of the latest state.
+        let state = if height == 0 {
+            self.storage.latest_snapshot()
+        } else {
+            self.storage.snapshot(height).ok_or_else(|| {
+                Status::not_found(format!("no state available at height {height}"))
+            })?
+        };
+
+        let snapshot = state.validator_set_snapshot().await.map_err(|e| {
+            Status::unavailable(format!("error assembling validator set snapshot: {e}"))
+        })?;
+
+        let proof = if proof {
+            let proof = prove_validator_set_snapshot(&state, &snapshot)
+                .await
+                .map_err(|e| {
+                    Status::unavailable(format!("error proving validator set snapshot: {e}"))
+                })?;
+            Some(proof.into())
+        } else {
+            None
+        };
+
+        Ok(tonic::Response::new(ValidatorSetSnapshotResponse {
+            snapshot: Some(snapshot.into()),
+            proof,
+        }))
+    }
+
+    #[cfg(feature = "vote-extensions")]
+    #[instrument(skip(self, request))]
+    async fn validator_participation(
+        &self,
+        request: tonic::Request<ValidatorParticipationRequest>,
+    ) -> Result<tonic::Response<ValidatorParticipationResponse>, Status> {
+        use super::validator_handler::ParticipationRead;
+
+        let state = self.storage.latest_snapshot();
+        let identity_key: IdentityKey = request
+            .into_inner()
+            .identity_key
+            .ok_or_else(|| Status::invalid_argument("missing identity key"))?
+            .try_into()
+            .map_err(|_| Status::invalid_argument("invalid identity key"))?;
+
+        let participation = state
+            .get_validator_participation(&identity_key)
+            .await
+            .map_err(|e| Status::internal(e.to_string()))?
+            .ok_or_else(|| Status::not_found("no participation recorded for validator"))?;
+
+        Ok(tonic::Response::new(ValidatorParticipationResponse {
+            participation: Some(participation.into()),
+        }))
+    }
+
+    #[cfg(not(feature = "vote-extensions"))]
+    async fn validator_participation(
+        &self,
+        _request: tonic::Request<ValidatorParticipationRequest>,
+    ) -> Result<tonic::Response<ValidatorParticipationResponse>, Status> {
+        Err(Status::unimplemented(
+            "this node does not track validator participation",
+        ))
+    }
+
+    #[instrument(skip(self, request))]
+    async fn delegation_receipt(
+        &self,
+        request: tonic::Request<DelegationReceiptRequest>,
+    ) -> Result<tonic::Response<DelegationReceiptResponse>, Status> {
+        let state = self.storage.latest_snapshot();
+        let request = request.into_inner();
+        let identity_key: IdentityKey = request
+            .identity_key
+            .ok_or_else(|| Status::invalid_argument("missing identity key"))?
+            .try_into()
+            .map_err(|_| Status::invalid_argument("invalid identity key"))?;
+
+        let receipt = state
+            .delegation_receipt(&identity_key, request.start_epoch_index)
+            .await
+            .map_err(|e| Status::unavailable(format!("error computing delegation receipt: {e}")))?;
+
+        Ok(tonic::Response::new(DelegationReceiptResponse {
+            receipt: Some(receipt.into()),
+        }))
+    }
+
+    type ValidatorRateHistoryStream = Pin<
+        Box<dyn futures::Stream<Item = Result<ValidatorRateHistoryResponse, tonic::Status>> + Send>,
+    >;
+
+    #[instrument(skip(self, request))]
+    async fn validator_rate_history(
+        &self,
+        request: tonic::Request<ValidatorRateHistoryRequest>,
+    ) -> Result<tonic::Response<Self::ValidatorRateHistoryStream>, Status> {
+        let state = self.storage.latest_snapshot();
+        let request = request.into_inner();
+        let identity_key: IdentityKey = request
+            .identity_key
+            .ok_or_else(|| Status::invalid_argument("missing identity key"))?
+            .try_into()
+            .map_err(|_| Status::invalid_argument("invalid identity key"))?;
+
+        let end_epoch_index = if request.end_epoch_index == 0 {
+            state
+                .get_current_epoch()
+                .await
+                .map_err(|e| Status::unavailable(format!("error getting current epoch: {e}")))?
+                .index
+        } else {
+            request.end_epoch_index
+        };
+        if request.start_epoch_index > end_epoch_index {
+            return Err(Status::invalid_argument(format!(
+                "start epoch {} is after end epoch {end_epoch_index}",
+                request.start_epoch_index
+            )));
+        }
+
+        let history = state
+            .validator_rate_history(&identity_key, request.start_epoch_index, end_epoch_index)
+            .await
+            .map_err(|e| Status::unavailable(format!("error getting rate history: {e}")))?;
+
+        Ok(tonic::Response::new(
+            futures::stream::iter(history.into_iter().map(|rate_data| {
+                Ok(ValidatorRateHistoryResponse {
+                    rate_data: Some(rate_data.into()),
+                })
+            }))
+            .boxed(),
+        ))
+    }
+
+    #[instrument(skip(self, request))]
+    async fn validator_tombstone(
+        &self,
+        request: tonic::Request<ValidatorTombstoneRequest>,
+    ) -> Result<tonic::Response<ValidatorTombstoneResponse>, Status> {
+        let state = self.storage.latest_snapshot();
+        let identity_key: IdentityKey = request
+            .into_inner()
+            .identity_key
+            .ok_or_else(|| Status::invalid_argument("missing identity key"))?
+            .try_into()
+            .map_err(|_| Status::invalid_argument("invalid identity key"))?;
+
+        let tombstone = state
+            .get_validator_tombstone(&identity_key)
+            .await
+            .map_err(|e| Status::unavailable(format!("error getting validator tombstone: {e}")))?
+            .ok_or_else(|| Status::not_found("validator has not been pruned"))?;
+
+        Ok(tonic::Response::new(ValidatorTombstoneResponse {
+            tombstone: Some(tombstone.into()),
+        }))
+    }
+
+    #[instrument(skip(self, request))]
+    async fn validator_diagnosis(
+        &self,
+        request: tonic::Request<ValidatorDiagnosisRequest>,
+    ) -> Result<tonic::Response<ValidatorDiagnosisResponse>, Status> {
+        let state = self.storage.latest_snapshot();
+        let identity_key: IdentityKey = request
+            .into_inner()
+            .identity_key
+            .ok_or_else(|| Status::invalid_argument("missing identity key"))?
+            .try_into()
+            .map_err(|_| Status::invalid_argument("invalid identity key"))?;
+
+        let diagnosis = state
+            .diagnose_validator(&identity_key)
+            .await
+            .map_err(|e| Status::unavailable(format!("error diagnosing validator: {e}")))?
+            .ok_or_else(|| Status::not_found("validator not found"))?;
+
+        Ok(tonic::Response::new(ValidatorDiagnosisResponse {
+            diagnosis: Some(diagnosis.into()),
+        }))
+    }
+
+    #[instrument(skip(self, request))]
+    async fn validator_power_proof(
+        &self,
+        request: tonic::Request<ValidatorPowerProofRequest>,
+    ) -> Result<tonic::Response<ValidatorPowerProofResponse>, Status> {
+        let state = self.storage.latest_snapshot();
+        let request = request.into_inner();
+        let identity_key: IdentityKey = request
+            .identity_key
+            .ok_or_else(|| Status::invalid_argument("missing identity key"))?
+            .try_into()
+            .map_err(|_| Status::invalid_argument("invalid identity key"))?;
+
+        let proof = prove_validator_power(&state, &identity_key, request.epoch_index)
+            .await
+            .map_err(|e| Status::unavailable(format!("error proving validator power: {e}")))?
+            .ok_or_else(|| {
+                Status::not_found(format!(
+                    "no power recorded for validator in epoch {}",
+                    request.epoch_index
+                ))
+            })?;
+
+        Ok(tonic::Response::new(ValidatorPowerProofResponse {
+            proof: Some(proof.into()),
+        }))
+    }
+}
+
+/// Project the effects of a validator's queued delegation changes at the end of the current epoch.
+///
+/// This mirrors the computation performed by the epoch handler, except that the next base rate is
+/// supplied by the caller, since it can't be known in advance.
+async fn project_delegation_changes(
+    state: &Snapshot,
+    identity_key: IdentityKey,
+    changes: DelegationChanges,
+    epoch_index: u64,
+    projected_base_rate: &BaseRateData,
+) -> anyhow::Result<DelegationChangeQueueResponse> {
+    let validator = state
+        .get_validator_definition(&identity_key)
+        .await?
+        .ok_or_else(|| anyhow!("validator {identity_key} has no definition"))?;
+    let validator_state = state
+        .get_validator_state(&identity_key)
+        .await?
+        .ok_or_else(|| anyhow!("validator {identity_key} has no state"))?;
+    let current_rate = state
+        .get_validator_rate(&identity_key)
+        .await?
+        .ok_or_else(|| anyhow!("validator {identity_key} has no rate data"))?;
+    let current_power = state
+        .get_validator_power(&identity_key)
+        .await?
+        .unwrap_or_else(Amount::zero);
+
+    // Any penalty recorded in this epoch is applied before computing the next rate.
+    let penalty = state
+        .get_penalty_in_epoch(&identity_key, epoch_index)
+        .await
+        .unwrap_or(Penalty::from_percent(0));
+    let projected_rate = current_rate.slash(penalty).next_epoch(
+        projected_base_rate,
+        validator.funding_streams.as_ref(),
+        &validator_state,
+    );
+
+    // The delegation pool grows by the queued delegations and shrinks by the queued
+    // undelegations, both of which are denominated in delegation tokens.
+    let total_delegations = changes.delegations.iter().fold(Amount::zero(), |total, d| {
+        total.saturating_add(&d.delegation_amount)
+    });
+    let total_undelegations = changes
+        .undelegations
+        .iter()
+        .fold(Amount::zero(), |total, u| {
+            total.saturating_add(&u.delegation_amount)
+        });
+    let delegation_token_supply = state
+        .token_supply(&DelegationToken::from(&identity_key).id())
+        .await?
+        .unwrap_or_else(Amount::zero);
+    let projected_supply = delegation_token_supply
+        .saturating_add(&total_delegations)
+        .saturating_sub(&total_undelegations);
+    let projected_power = projected_rate.voting_power(projected_supply);
+
+    Ok(DelegationChangeQueueResponse {
+        identity_key: Some(identity_key.into()),
+        delegations: changes.delegations.into_iter().map(Into::into).collect(),
+        undelegations: changes.undelegations.into_iter().map(Into::into).collect(),
+        current_rate_data: Some(current_rate.into()),
+        projected_rate_data: Some(projected_rate.into()),
+        current_voting_power: Some(current_power.into()),
+        projected_voting_power: Some(projected_power.into()),
+    })
 }
@@ -11,8 +11,9 @@ use async_trait::async_trait;
 use cnidarium::{StateRead, StateWrite};
 use cnidarium_component::Component;
 use futures::{StreamExt, TryStreamExt};
+use penumbra_asset::asset;
 use penumbra_num::Amount;
-use penumbra_proto::{StateReadProto, StateWriteProto};
+use penumbra_proto::{DomainType, StateReadProto, StateWriteProto};
 use penumbra_sct::component::clock::EpochRead;
 use penumbra_shielded_pool::component::SupplyRead;
 use sha2::{Digest, Sha256};
@@ -26,7 +27,9 @@ use tendermint::{block, PublicKey};
 use tracing::{instrument, trace};
 
 use crate::component::epoch_handler::EpochHandler;
-use crate::component::validator_handler::{ValidatorDataRead, ValidatorManager};
+use crate::component::validator_handler::{
+    EvidenceHandler, PowerCheckpointWrite, ValidatorDataRead, ValidatorDataWrite, ValidatorManager,
+};
 
 pub struct Staking {}
 
@@ -87,6 +90,24 @@ impl Component for Staking {
                         .expect("should be able to add genesis validator to state");
                 }
 
+                if let Some(snapshot) = &staking_genesis.validator_snapshot {
+                    trace!(height = snapshot.height, "importing validator snapshot");
+                    state
+                        .import_validator_set_snapshot(
+                            starting_height,
+                            &genesis_base_rate,
+                            snapshot.clone(),
+                        )
+                        .await
+                        .expect("should be able to import validator snapshot");
+                }
+
+                // Checkpoint the voting power of the genesis validators for the first epoch.
+                state
+                    .checkpoint_validator_power(epoch_index)
+                    .await
+                    .expect("should be able to checkpoint genesis validator power");
+
                 // First, "prime" the state with an empty set, so the build_ function can read it.
                 state.put(
                     state_key::consensus_update::consensus_keys().to_owned(),
@@ -122,11 +143,37 @@ impl Component for Staking {
         // state to be slashed. If the validator is not tracked in the JMT, this
         // will be a no-op. See #2919 for more details.
         for evidence in begin_block.byzantine_validators.iter() {
-            let _ = state.process_evidence(evidence).await.map_err(|e| {
+            let _ = state.process_misbehavior(evidence).await.map_err(|e| {
                 tracing::warn!(?e, "failed to process byzantine misbehavior evidence")
             });
         }
 
+        // If governance changed the signed blocks window length, resize the
+        // existing uptime trackers before recording this block's signatures.
+        if state.stake_params_updated() {
+            let signed_blocks_window_len = state
+                .signed_blocks_window_len()
+                .await
+                .expect("should be able to read stake params");
+            state
+                .resize_validator_uptimes(signed_blocks_window_len as usize)
+                .await
+                .expect("should be able to resize validator uptimes");
+        }
+
+        // Record participation before uptime, since tracking uptime may jail
+        // validators who voted in this commit.
+        #[cfg(feature = "vote-extensions")]
+        {
+            use crate::component::validator_handler::ParticipationTracker as _;
+            if let Err(e) = state
+                .track_participation(&begin_block.last_commit_info)
+                .await
+            {
+                tracing::warn!(?e, "failed to track validator participation");
+            }
+        }
+
         state
             .track_uptime(&begin_block.last_commit_info)
             .await
@@ -159,6 +206,7 @@ impl Component for Staking {
             .await
             .context("should be able to get current epoch during end_epoch")?;
         state.end_epoch(epoch_ending).await?;
+        state.clear_pending_delegation_changes().await?;
         // Since we only update the validator set at epoch boundaries,
         // we only need to build the validator set updates here in end_epoch.
         state
@@ -169,6 +217,11 @@ impl Component for Staking {
     }
 }
 
+/// The number of blocks after which CometBFT applies the validator updates
+/// returned at the end of a block.
+pub const VALIDATOR_UPDATE_DELAY: u64 = 2;
+
+#[async_trait]
 pub trait ConsensusUpdateRead: StateRead {
     /// Returns a list of validator updates to send to Tendermint.
     ///
@@ -177,10 +230,27 @@ pub trait ConsensusUpdateRead: StateRead {
         self.object_get(state_key::internal::cometbft_validator_updates())
             .unwrap_or(None)
     }
+
+    /// Returns the consensus keys CometBFT knew about at the given height, if
+    /// that height is recent enough for them to still be recorded.
+    async fn consensus_keys_at_height(&self, height: u64) -> Result<Option<CurrentConsensusKeys>> {
+        // The keys in effect are the last ones recorded at or before the height.
+        let last_key = state_key::consensus_update::consensus_keys_by_height(height);
+        let recorded: Vec<(String, CurrentConsensusKeys)> = self
+            .prefix(state_key::consensus_update::consensus_keys_by_height_prefix())
+            .try_collect()
+            .await?;
+        Ok(recorded
+            .into_iter()
+            .take_while(|(key, _)| *key <= last_key)
+            .map(|(_key, consensus_keys)| consensus_keys)
+            .last())
+    }
 }
 
 impl<T: StateRead + ?Sized> ConsensusUpdateRead for T {}
 
+#[async_trait]
 pub(crate) trait ConsensusUpdateWrite: StateWrite {
     fn put_cometbft_validator_updates(&mut self, updates: Vec<Update>) {
         tracing::debug!(?updates);
@@ -189,6 +259,34 @@ pub(crate) trait ConsensusUpdateWrite: StateWrite {
             Some(updates),
         )
     }
+
+    /// Record the consensus keys CometBFT will know about from the given
+    /// height on, so that evidence of misbehavior can be checked against the
+    /// keys of the height it happened at.
+    ///
+    /// Only the last `retained` sets of keys are kept.
+    async fn record_consensus_keys(
+        &mut self,
+        effective_height: u64,
+        consensus_keys: CurrentConsensusKeys,
+        retained: usize,
+    ) -> Result<()> {
+        self.put(
+            state_key::consensus_update::consensus_keys_by_height(effective_height),
+            consensus_keys,
+        );
+        let recorded: Vec<String> = self
+            .prefix_keys(state_key::consensus_update::consensus_keys_by_height_prefix())
+            .try_collect()
+            .await?;
+        for key in recorded
+            .iter()
+            .take(recorded.len().saturating_sub(retained))
+        {
+            self.delete(key.clone());
+        }
+        Ok(())
+    }
 }
 
 impl<T: StateWrite + ?Sized> ConsensusUpdateWrite for T {}
@@ -249,6 +347,94 @@ pub trait StateReadExt: StateRead {
             .await?
             .ok_or_else(|| anyhow!("missing delegation changes for block {}", height))?)
     }
+
+    /// Returns the delegation changes persisted so far in the current epoch,
+    /// grouped by the validator they apply to.
+    ///
+    /// These are the changes which will be processed at the next epoch boundary.
+    async fn get_pending_delegation_changes(
+        &self,
+    ) -> Result<BTreeMap<IdentityKey, DelegationChanges>> {
+        let prefix = state_key::chain::pending_delegation_changes::prefix();
+        let mut stream = self.nonverifiable_prefix_raw(prefix.as_bytes());
+
+        let mut pending = BTreeMap::<IdentityKey, DelegationChanges>::new();
+        while let Some((key, bytes)) = stream.next().await.transpose()? {
+            let key = std::str::from_utf8(&key[prefix.len()..])
+                .context("pending delegation change keys should be valid utf-8")?;
+            let (identity_key, _height) = key
+                .split_once('/')
+                .context("pending delegation change keys should include a height")?;
+            let changes = DelegationChanges::decode(bytes.as_slice())?;
+
+            let entry = pending
+                .entry(IdentityKey::from_str(identity_key)?)
+                .or_default();
+            entry.delegations.extend(changes.delegations);
+            entry.undelegations.extend(changes.undelegations);
+        }
+
+        Ok(pending)
+    }
+
+    /// Returns the delegation changes to the given validator persisted so far in
+    /// the current epoch.
+    async fn get_pending_delegation_changes_for(
+        &self,
+        identity_key: &IdentityKey,
+    ) -> Result<DelegationChanges> {
+        let mut stream = self.nonverifiable_prefix_raw(
+            state_key::chain::pending_delegation_changes::by_id(identity_key).as_bytes(),
+        );
+
+        let mut pending = DelegationChanges::default();
+        while let Some((_, bytes)) = stream.next().await.transpose()? {
+            let changes = DelegationChanges::decode(bytes.as_slice())?;
+            pending.delegations.extend(changes.delegations);
+            pending.undelegations.extend(changes.undelegations);
+        }
+
+        Ok(pending)
+    }
+
+    /// Checks that the given asset may be committed to the DEX, as the
+    /// reserves of a position or the input of an auction.
+    ///
+    /// If the `restrict_at_risk_delegation_tokens` parameter is set, this
+    /// rejects the delegation tokens of validators which are jailed or
+    /// unbonding, since their value is at risk until the validator's fate is
+    /// decided.
+    async fn check_delegation_token_usable(&self, asset_id: &asset::Id) -> Result<()> {
+        // Delegation tokens are always registered when they are first minted,
+        // so an unregistered asset can't be one.
+        let Some(denom) = self.denom_by_asset(asset_id).await? else {
+            return Ok(());
+        };
+        let Ok(delegation_token) = DelegationToken::try_from(denom) else {
+            return Ok(());
+        };
+        if !self
+            .get_stake_params()
+            .await?
+            .restrict_at_risk_delegation_tokens
+        {
+            return Ok(());
+        }
+        let validator = delegation_token.validator();
+
+        let state = self.get_validator_state(&validator).await?;
+        let bonding_state = self.get_validator_bonding_state(&validator).await;
+        let reason = match (state, bonding_state) {
+            (Some(validator::State::Jailed), _) => "jailed",
+            (_, Some(validator::BondingState::Unbonding { .. })) => "unbonding",
+            _ => return Ok(()),
+        };
+        anyhow::bail!(
+            "delegation tokens of validator {} cannot be committed to the DEX while it is {}",
+            validator,
+            reason
+        )
+    }
 }
 
 impl<T: StateRead + ?Sized> StateReadExt for T {}
@@ -305,19 +491,6 @@ pub trait StateWriteExt: StateWrite {
         identity_key: &IdentityKey,
         consensus_key: &PublicKey,
     ) {
-        /// Translates from consensus keys to the truncated sha256 hashes in last_commit_info
-        /// This should really be a refined type upstream, but we can't currently upstream
-        /// to tendermint-rs, for process reasons, and shouldn't do our own tendermint data
-        /// modeling, so this is an interim hack.
-        fn validator_address(ck: &PublicKey) -> [u8; 20] {
-            let ck_bytes = ck.to_bytes();
-            let addr: [u8; 20] = Sha256::digest(ck_bytes).as_slice()[0..20]
-                .try_into()
-                .expect("Sha256 digest should be 20-bytes long");
-
-            addr
-        }
-
         let address = validator_address(consensus_key);
         tracing::debug!(?identity_key, ?consensus_key, hash = ?hex::encode(address), "registering consensus key");
         self.put(
@@ -329,10 +502,33 @@ pub trait StateWriteExt: StateWrite {
             identity_key.clone(),
         );
     }
+
+    /// Remove the lookups from a consensus key to the validator which declared it.
+    fn unregister_consensus_key(&mut self, consensus_key: &PublicKey) {
+        let address = validator_address(consensus_key);
+        tracing::debug!(?consensus_key, hash = ?hex::encode(address), "unregistering consensus key");
+        self.delete(state_key::validators::lookup_by::cometbft_address(&address));
+        self.delete(state_key::validators::lookup_by::consensus_key(
+            consensus_key,
+        ));
+    }
 }
 
 impl<T: StateWrite + ?Sized> StateWriteExt for T {}
 
+/// Translates from consensus keys to the truncated sha256 hashes in last_commit_info
+/// This should really be a refined type upstream, but we can't currently upstream
+/// to tendermint-rs, for process reasons, and shouldn't do our own tendermint data
+/// modeling, so this is an interim hack.
+fn validator_address(ck: &PublicKey) -> [u8; 20] {
+    let ck_bytes = ck.to_bytes();
+    let addr: [u8; 20] = Sha256::digest(ck_bytes).as_slice()[0..20]
+        .try_into()
+        .expect("Sha256 digest should be 20-bytes long");
+
+    addr
+}
+
 #[async_trait]
 pub trait SlashingData: StateRead {
     async fn get_penalty_in_epoch(&self, id: &IdentityKey, epoch_index: u64) -> Option<Penalty> {
@@ -464,11 +660,55 @@ pub trait RateDataWrite: StateWrite {
     }
 
     async fn set_delegation_changes(&mut self, height: block::Height, changes: DelegationChanges) {
+        // Index the changes by validator too, so that the changes pending in the
+        // current epoch can be found without reading every block of the epoch.
+        let mut by_validator = BTreeMap::<IdentityKey, DelegationChanges>::new();
+        for d in &changes.delegations {
+            by_validator
+                .entry(d.validator_identity)
+                .or_default()
+                .delegations
+                .push(d.clone());
+        }
+        for u in &changes.undelegations {
+            by_validator
+                .entry(u.validator_identity)
+                .or_default()
+                .undelegations
+                .push(u.clone());
+        }
+        for (identity_key, changes) in by_validator {
+            self.nonverifiable_put_raw(
+                state_key::chain::pending_delegation_changes::by_id_and_height(
+                    &identity_key,
+                    height.value(),
+                )
+                .into_bytes(),
+                changes.encode_to_vec(),
+            );
+        }
+
         self.put(
             state_key::chain::delegation_changes::by_height(height.value()),
             changes,
         );
     }
+
+    /// Clear the index of delegation changes pending in the current epoch, once
+    /// they have been processed at the end of the epoch.
+    async fn clear_pending_delegation_changes(&mut self) -> Result<()> {
+        let keys: Vec<Vec<u8>> = self
+            .nonverifiable_prefix_raw(
+                state_key::chain::pending_delegation_changes::prefix().as_bytes(),
+            )
+            .map_ok(|(key, _)| key)
+            .try_collect()
+            .await?;
+        for key in keys {
+            self.nonverifiable_delete(key);
+        }
+        Ok(())
+    }
 }
 
 impl<T: StateWrite + ?Sized> RateDataWrite for T {}
@@ -553,3 +793,109 @@ pub trait ConsensusIndexWrite: StateWrite {
 }
 
 impl<T: StateWrite + ?Sized> ConsensusIndexWrite for T {}
+
+#[cfg(test)]
+mod tests {
+    use cnidarium::{StateDelta, TempStorage};
+    use decaf377_rdsa::{SigningKey, SpendAuth};
+    use rand_core::OsRng;
+
+    use super::*;
+
+    fn delegation(validator_identity: IdentityKey, amount: u64) -> Delegate {
+        Delegate {
+            validator_identity,
+            epoch_index: 0,
+            unbonded_amount: amount.into(),
+            delegation_amount: amount.into(),
+        }
+    }
+
+    fn undelegation(validator_identity: IdentityKey, amount: u64) -> Undelegate {
+        Undelegate {
+            validator_identity,
+            start_epoch_index: 0,
+            unbonded_amount: amount.into(),
+            delegation_amount: amount.into(),
+            self_bond_release: None,
+        }
+    }
+
+    fn amounts(changes: &DelegationChanges) -> (Vec<Amount>, Vec<Amount>) {
+        (
+            changes
+                .delegations
+                .iter()
+                .map(|d| d.delegation_amount)
+                .collect(),
+            changes
+                .undelegations
+                .iter()
+                .map(|u| u.delegation_amount)
+                .collect(),
+        )
+    }
+
+    #[tokio::test]
+    async fn pending_delegation_changes_are_indexed_by_validator_until_the_epoch_ends() -> Result<()>
+    {
+        let storage = TempStorage::new().await?;
+        let mut state = StateDelta::new(storage.latest_snapshot());
+        let a = IdentityKey((&SigningKey::<SpendAuth>::new(OsRng)).into());
+        let b = IdentityKey((&SigningKey::<SpendAuth>::new(OsRng)).into());
+
+        state
+            .set_delegation_changes(
+                1u32.into(),
+                DelegationChanges {
+                    delegations: vec![delegation(a, 1), delegation(b, 2)],
+                    undelegations: vec![],
+                },
+            )
+            .await;
+        // A block with no changes leaves nothing in the index.
+        state
+            .set_delegation_changes(2u32.into(), Default::default())
+            .await;
+        state
+            .set_delegation_changes(
+                3u32.into(),
+                DelegationChanges {
+                    delegations: vec![delegation(a, 3)],
+                    undelegations: vec![undelegation(a, 4)],
+                },
+            )
+            .await;
+        storage.commit(state).await?;
+
+        let snapshot = storage.latest_snapshot();
+        let pending = snapshot.get_pending_delegation_changes().await?;
+        assert_eq!(pending.len(), 2);
+        assert_eq!(
+            amounts(&pending[&a]),
+            (vec![1u64.into(), 3u64.into()], vec![4u64.into()])
+        );
+        assert_eq!(amounts(&pending[&b]), (vec![2u64.into()], vec![]));
+        assert_eq!(
+            amounts(&snapshot.get_pending_delegation_changes_for(&b).await?),
+            (vec![2u64.into()], vec![])
+        );
+
+        // Once the epoch's changes are processed, none are pending.
+        let mut state = StateDelta::new(snapshot);
+        state.clear_pending_delegation_changes().await?;
+        assert!(state.get_pending_delegation_changes().await?.is_empty());
+        assert!(
+            amounts(&state.get_pending_delegation_changes_for(&a).await?)
+                .0
+                .is_empty()
+        );
+        // The changes for each block are still kept, as before.
+        assert_eq!(
+            amounts(&state.get_delegation_changes(3u32.into()).await?),
+            (vec![3u64.into()], vec![4u64.into()])
+        );
+
+        Ok(())
+    }
+}
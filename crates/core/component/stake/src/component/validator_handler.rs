@@ -1,6 +1,20 @@
 pub(crate) mod validator_manager;
 pub(crate) use validator_manager::ValidatorManager;
 
+pub mod evidence;
+pub use evidence::EvidenceHandler;
+
+pub mod power_checkpoint;
+pub use power_checkpoint::{PowerCheckpointRead, PowerCheckpointWrite};
+
+pub mod snapshot_sync;
+pub use snapshot_sync::SnapshotSync;
+
 pub mod validator_store;
 pub use validator_store::ValidatorDataRead;
 pub use validator_store::ValidatorDataWrite;
+
+#[cfg(feature = "vote-extensions")]
+pub mod participation;
+#[cfg(feature = "vote-extensions")]
+pub use participation::{ParticipationRead, ParticipationTracker};
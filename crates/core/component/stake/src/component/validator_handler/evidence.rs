@@ -0,0 +1,540 @@
+//! Slashing validators for misbehavior.
+//!
+//! CometBFT reports the misbehavior it detects itself to the application as
+//! part of each block, which is handled by
+//! [`process_misbehavior`](EvidenceHandler::process_misbehavior).  Evidence of
+//! a validator signing conflicting votes can also be observed elsewhere, for
+//! instance by a light client or a monitoring service, in which case it is
+//! submitted to the staking component as raw [`DuplicateVoteEvidence`], and
+//! must be verified before the validator is slashed.
+//!
+//! Either way, the misbehavior is attributed to the validator whose consensus
+//! key was in the validator set at the height it happened, even if the
+//! validator has since rotated its key, and misbehavior older than the
+//! unbonding period is ignored, since the stake it could be slashed from may
+//! already have been withdrawn.
+
+use anyhow::{Context as _, Result};
+use async_trait::async_trait;
+use cnidarium::StateWrite;
+use penumbra_proto::{Message as _, StateReadProto as _, StateWriteProto as _};
+use penumbra_sct::component::clock::EpochRead as _;
+use sha2::{Digest as _, Sha256};
+use tendermint::{abci::types::Misbehavior, chain, evidence::DuplicateVoteEvidence, PublicKey};
+
+use crate::{
+    component::{
+        stake::ConsensusUpdateRead as _,
+        validator_handler::{ValidatorDataRead as _, ValidatorManager as _},
+        StateReadExt as _,
+    },
+    event, state_key, validator, IdentityKey, Penalty,
+};
+
+/// Check that the given evidence proves that the validator with the given
+/// consensus key signed two conflicting votes on the chain with the given id.
+pub fn verify_duplicate_vote_evidence(
+    chain_id: &chain::Id,
+    consensus_key: &PublicKey,
+    evidence: &DuplicateVoteEvidence,
+) -> Result<()> {
+    let (vote_a, vote_b) = (&evidence.vote_a, &evidence.vote_b);
+    anyhow::ensure!(
+        vote_a.height == vote_b.height
+            && vote_a.round == vote_b.round
+            && vote_a.vote_type == vote_b.vote_type,
+        "votes are not for the same height, round, and step"
+    );
+    anyhow::ensure!(
+        vote_a.block_id != vote_b.block_id,
+        "votes are for the same block"
+    );
+    anyhow::ensure!(
+        vote_a.validator_address == vote_b.validator_address,
+        "votes are from different validators"
+    );
+
+    let address = Sha256::digest(consensus_key.to_bytes());
+    anyhow::ensure!(
+        vote_a.validator_address.as_bytes() == &address[0..20],
+        "votes are not from the validator with consensus key {}",
+        hex::encode(consensus_key.to_bytes())
+    );
+
+    let verification_key =
+        ed25519_consensus::VerificationKey::try_from(consensus_key.to_bytes().as_slice())
+            .context("consensus key is not an ed25519 key")?;
+    for vote in [vote_a, vote_b] {
+        let signature = vote
+            .signature
+            .as_ref()
+            .ok_or_else(|| anyhow::anyhow!("vote is not signed"))?;
+        let signature = ed25519_consensus::Signature::try_from(signature.as_bytes())
+            .context("invalid vote signature encoding")?;
+        let sign_bytes = vote
+            .to_signable_vec(chain_id.clone())
+            .context("could not encode vote for signing")?;
+        verification_key
+            .verify(&signature, &sign_bytes)
+            .context("invalid vote signature")?;
+    }
+
+    Ok(())
+}
+
+/// The hash of duplicate vote evidence, as CometBFT computes it.
+pub fn evidence_hash(evidence: &DuplicateVoteEvidence) -> [u8; 32] {
+    let raw = tendermint_proto::types::DuplicateVoteEvidence::from(evidence.clone());
+    Sha256::digest(raw.encode_to_vec()).into()
+}
+
+#[async_trait]
+pub trait EvidenceHandler: StateWrite {
+    /// Slash a validator for misbehavior reported by CometBFT.
+    ///
+    /// Evidence *MUST* be processed before `end_block` is called, because
+    /// the evidence may trigger a validator state transition requiring
+    /// an early epoch change.
+    ///
+    /// # Errors
+    /// Returns an error if the misbehavior is from a future height or is too
+    /// old, or if the address isn't that of a validator in the validator set
+    /// at its height.
+    async fn process_misbehavior(&mut self, misbehavior: &Misbehavior) -> Result<()> {
+        let misbehavior_height = misbehavior.height.value();
+        let (_consensus_key, identity_key) = self
+            .misbehaving_validator(&misbehavior.validator.address, misbehavior_height)
+            .await?;
+        self.slash_misbehavior(&identity_key, misbehavior_height, None)
+            .await
+    }
+
+    /// Verify evidence that a validator signed conflicting votes, and slash
+    /// the validator for it.
+    ///
+    /// The validator is tombstoned, exactly as if CometBFT had reported the
+    /// misbehavior, which applies the misbehavior penalty to its delegation
+    /// pool and unbonds it.  An `EventSlashed` recording the hash of the
+    /// evidence is emitted, unless the validator was already tombstoned.
+    ///
+    /// # Errors
+    /// Returns an error if the evidence is invalid, is from a future height or
+    /// is too old, or is not signed by a validator in the validator set at its
+    /// height.
+    async fn process_duplicate_vote_evidence(
+        &mut self,
+        chain_id: &chain::Id,
+        evidence: &DuplicateVoteEvidence,
+    ) -> Result<()> {
+        let misbehavior_height = evidence.vote_a.height.value();
+        let address: [u8; 20] = evidence
+            .vote_a
+            .validator_address
+            .as_bytes()
+            .try_into()
+            .context("validator address must be 20 bytes")?;
+        let (consensus_key, identity_key) = self
+            .misbehaving_validator(&address, misbehavior_height)
+            .await?;
+        verify_duplicate_vote_evidence(chain_id, &consensus_key, evidence)?;
+
+        self.slash_misbehavior(
+            &identity_key,
+            misbehavior_height,
+            Some(evidence_hash(evidence)),
+        )
+        .await
+    }
+
+    /// Find the consensus key and identity key of the validator with the
+    /// given address in the validator set at the height of a misbehavior.
+    async fn misbehaving_validator(
+        &self,
+        address: &[u8; 20],
+        misbehavior_height: u64,
+    ) -> Result<(PublicKey, IdentityKey)> {
+        let current_height = self.get_block_height().await?;
+        anyhow::ensure!(
+            misbehavior_height <= current_height,
+            "evidence is from height {misbehavior_height}, after the current height {current_height}"
+        );
+
+        let current_epoch = self.get_current_epoch().await?;
+        let misbehavior_epoch = self.get_epoch_by_height(misbehavior_height).await?;
+        let unbonding_epochs = self.get_stake_params().await?.unbonding_epochs;
+        anyhow::ensure!(
+            current_epoch.index - misbehavior_epoch.index <= unbonding_epochs,
+            "evidence is from epoch {}, more than {unbonding_epochs} epochs before the current epoch {}",
+            misbehavior_epoch.index,
+            current_epoch.index,
+        );
+
+        // The lookup by address is kept when a validator rotates its
+        // consensus key, so this finds the key used at the time.
+        let consensus_key: PublicKey = self
+            .get(&state_key::validators::lookup_by::cometbft_address(address))
+            .await?
+            .ok_or_else(|| {
+                anyhow::anyhow!(
+                    "attempted to slash unknown validator with address {}",
+                    hex::encode(address)
+                )
+            })?;
+        let validator_set = self
+            .consensus_keys_at_height(misbehavior_height)
+            .await?
+            .ok_or_else(|| {
+                anyhow::anyhow!("no validator set is recorded for height {misbehavior_height}")
+            })?;
+        anyhow::ensure!(
+            validator_set.consensus_keys.contains(&consensus_key),
+            "validator with address {} was not in the validator set at height {misbehavior_height}",
+            hex::encode(address)
+        );
+
+        let validator = self
+            .get_validator_by_consensus_key(&consensus_key)
+            .await?
+            .ok_or_else(|| {
+                anyhow::anyhow!(
+                    "no validator declared the consensus key {}",
+                    hex::encode(consensus_key.to_bytes())
+                )
+            })?;
+        Ok((consensus_key, validator.identity_key))
+    }
+
+    /// Tombstone a validator for misbehavior at the given height, emitting an
+    /// `EventSlashed` unless it was already tombstoned.
+    async fn slash_misbehavior(
+        &mut self,
+        identity_key: &IdentityKey,
+        misbehavior_height: u64,
+        evidence_hash: Option<[u8; 32]>,
+    ) -> Result<()> {
+        if let Some(validator::State::Tombstoned) = self.get_validator_state(identity_key).await? {
+            tracing::debug!(%identity_key, "validator is already tombstoned");
+            return Ok(());
+        }
+        self.set_validator_state(identity_key, validator::State::Tombstoned)
+            .await?;
+
+        let penalty =
+            Penalty::from_bps_squared(self.get_stake_params().await?.slashing_penalty_misbehavior);
+        self.record_proto(event::slashed(
+            identity_key,
+            misbehavior_height,
+            penalty,
+            evidence_hash,
+        ));
+
+        Ok(())
+    }
+}
+
+impl<T: StateWrite + ?Sized> EvidenceHandler for T {}
+
+#[cfg(test)]
+mod tests {
+    use cnidarium::{StateDelta, TempStorage};
+    use decaf377_rdsa::{SigningKey, SpendAuth};
+    use penumbra_sct::{component::clock::EpochManager as _, epoch::Epoch};
+    use rand_core::OsRng;
+    use tendermint::{
+        abci::types::{self, MisbehaviorKind},
+        account, block, vote, Hash, Signature, Time, Vote,
+    };
+
+    use super::*;
+    use crate::{
+        component::{stake::ConsensusUpdateWrite as _, StateWriteExt as _},
+        params::StakeParameters,
+        rate::RateData,
+        validator::Validator,
+        CurrentConsensusKeys, GovernanceKey,
+    };
+
+    fn signed_vote(
+        signing_key: &ed25519_consensus::SigningKey,
+        chain_id: &chain::Id,
+        block_hash: [u8; 32],
+    ) -> Vote {
+        let consensus_key =
+            PublicKey::from_raw_ed25519(signing_key.verification_key().as_bytes()).unwrap();
+        let mut vote = Vote {
+            vote_type: vote::Type::Precommit,
+            height: block::Height::from(5u32),
+            round: block::Round::from(0u8),
+            block_id: Some(block::Id {
+                hash: Hash::Sha256(block_hash),
+                part_set_header: Default::default(),
+            }),
+            timestamp: Some(Time::unix_epoch()),
+            validator_address: account::Id::new(
+                Sha256::digest(consensus_key.to_bytes())[0..20]
+                    .try_into()
+                    .unwrap(),
+            ),
+            validator_index: 0u32.try_into().unwrap(),
+            signature: None,
+            extension: Vec::new(),
+            extension_signature: None,
+        };
+        let sign_bytes = vote.to_signable_vec(chain_id.clone()).unwrap();
+        let signature = signing_key.sign(&sign_bytes).to_bytes();
+        vote.signature = Some(Signature::try_from(signature.as_slice()).unwrap());
+        vote
+    }
+
+    #[test]
+    fn duplicate_votes_are_verified() {
+        let chain_id = chain::Id::try_from("penumbra-test").unwrap();
+        let signing_key = ed25519_consensus::SigningKey::new(OsRng);
+        let consensus_key =
+            PublicKey::from_raw_ed25519(signing_key.verification_key().as_bytes()).unwrap();
+
+        let evidence = DuplicateVoteEvidence::new(
+            signed_vote(&signing_key, &chain_id, [1; 32]),
+            signed_vote(&signing_key, &chain_id, [2; 32]),
+        )
+        .unwrap();
+        verify_duplicate_vote_evidence(&chain_id, &consensus_key, &evidence)
+            .expect("conflicting votes are valid evidence");
+
+        // The votes are bound to the chain they were signed for
+        let other_chain = chain::Id::try_from("penumbra-other").unwrap();
+        assert!(verify_duplicate_vote_evidence(&other_chain, &consensus_key, &evidence).is_err());
+
+        // The votes must be signed by the accused validator
+        let other_key = ed25519_consensus::SigningKey::new(OsRng);
+        let other_consensus_key =
+            PublicKey::from_raw_ed25519(other_key.verification_key().as_bytes()).unwrap();
+        assert!(
+            verify_duplicate_vote_evidence(&chain_id, &other_consensus_key, &evidence).is_err()
+        );
+
+        // Two votes for the same block are not a misbehavior
+        let mut same_block = evidence.clone();
+        same_block.vote_b = same_block.vote_a.clone();
+        assert!(verify_duplicate_vote_evidence(&chain_id, &consensus_key, &same_block).is_err());
+
+        // A forged vote does not count
+        let mut forged = evidence;
+        forged.vote_b = signed_vote(&other_key, &chain_id, [2; 32]);
+        forged.vote_b.validator_address = forged.vote_a.validator_address;
+        assert!(verify_duplicate_vote_evidence(&chain_id, &consensus_key, &forged).is_err());
+    }
+
+    fn validator(consensus_key: PublicKey) -> (Validator, RateData) {
+        let signing_key = SigningKey::<SpendAuth>::new(OsRng);
+        let identity_key = IdentityKey((&signing_key).into());
+        let validator = Validator {
+            identity_key,
+            governance_key: GovernanceKey((&signing_key).into()),
+            consensus_key,
+            name: String::new(),
+            website: String::new(),
+            description: String::new(),
+            enabled: true,
+            funding_streams: Default::default(),
+            funding_streams_effective_epoch: None,
+            sequence_number: 0,
+        };
+        let rate_data = RateData {
+            identity_key,
+            epoch_index: 0,
+            validator_reward_rate: 0u128.into(),
+            validator_exchange_rate: 1_0000_0000u128.into(),
+        };
+        (validator, rate_data)
+    }
+
+    fn misbehavior(consensus_key: &PublicKey, height: u32) -> Misbehavior {
+        Misbehavior {
+            kind: MisbehaviorKind::DuplicateVote,
+            validator: types::Validator {
+                address: Sha256::digest(consensus_key.to_bytes())[0..20]
+                    .try_into()
+                    .expect("address is 20 bytes"),
+                power: Default::default(),
+            },
+            height: block::Height::from(height),
+            time: Time::unix_epoch(),
+            total_voting_power: Default::default(),
+        }
+    }
+
+    fn set_height<S: StateWrite>(state: &mut S, height: u64) {
+        state.put_block_height(height);
+        state.put_epoch_by_height(
+            height,
+            Epoch {
+                index: height / 10,
+                start_height: height / 10 * 10,
+            },
+        );
+    }
+
+    #[tokio::test]
+    async fn misbehavior_is_checked_against_the_validator_set_at_its_height() -> Result<()> {
+        let storage = TempStorage::new().await?;
+        let mut state = StateDelta::new(storage.latest_snapshot());
+        state.put_stake_params(StakeParameters {
+            unbonding_epochs: 1,
+            ..Default::default()
+        });
+        // Each epoch is ten blocks long.
+        for height in [5, 14] {
+            set_height(&mut state, height);
+        }
+        set_height(&mut state, 15);
+
+        let chain_id = chain::Id::try_from("penumbra-test").unwrap();
+        let old_signing_key = ed25519_consensus::SigningKey::new(OsRng);
+        let old_key =
+            PublicKey::from_raw_ed25519(old_signing_key.verification_key().as_bytes()).unwrap();
+        let new_key = PublicKey::from_raw_ed25519(
+            ed25519_consensus::SigningKey::new(OsRng)
+                .verification_key()
+                .as_bytes(),
+        )
+        .unwrap();
+        let outsider_key = PublicKey::from_raw_ed25519(
+            ed25519_consensus::SigningKey::new(OsRng)
+                .verification_key()
+                .as_bytes(),
+        )
+        .unwrap();
+
+        let (definition, rate_data) = validator(old_key);
+        let identity_key = definition.identity_key;
+        state.add_validator(definition.clone(), rate_data).await?;
+        let (outsider, outsider_rate_data) = validator(outsider_key);
+        state.add_validator(outsider, outsider_rate_data).await?;
+
+        // The validator is in the validator set from genesis, and rotates its
+        // consensus key from height 12 on.  The outsider never joins it.
+        state
+            .record_consensus_keys(
+                0,
+                CurrentConsensusKeys {
+                    consensus_keys: vec![old_key],
+                },
+                3,
+            )
+            .await?;
+        state
+            .update_validator_definition(Validator {
+                consensus_key: new_key,
+                sequence_number: 1,
+                ..definition
+            })
+            .await?;
+        state
+            .record_consensus_keys(
+                12,
+                CurrentConsensusKeys {
+                    consensus_keys: vec![new_key],
+                },
+                3,
+            )
+            .await?;
+
+        // Only misbehavior by validators in the validator set counts.
+        assert!(state
+            .process_misbehavior(&misbehavior(&outsider_key, 5))
+            .await
+            .is_err());
+        assert!(state
+            .process_misbehavior(&misbehavior(&old_key, 14))
+            .await
+            .is_err());
+        assert!(state
+            .process_misbehavior(&misbehavior(&new_key, 5))
+            .await
+            .is_err());
+        // Misbehavior can't be from the future.
+        assert!(state
+            .process_misbehavior(&misbehavior(&new_key, 16))
+            .await
+            .is_err());
+        assert_eq!(
+            state.get_validator_state(&identity_key).await?,
+            Some(validator::State::Defined)
+        );
+
+        // Evidence signed with the key the validator used at the time is
+        // accepted after it rotated its key.
+        let evidence = DuplicateVoteEvidence::new(
+            signed_vote(&old_signing_key, &chain_id, [1; 32]),
+            signed_vote(&old_signing_key, &chain_id, [2; 32]),
+        )
+        .unwrap();
+        state
+            .process_duplicate_vote_evidence(&chain_id, &evidence)
+            .await?;
+        assert_eq!(
+            state.get_validator_state(&identity_key).await?,
+            Some(validator::State::Tombstoned)
+        );
+
+        // Once the unbonding period has passed, the misbehavior can't be
+        // punished anymore.
+        set_height(&mut state, 25);
+        assert!(state
+            .process_duplicate_vote_evidence(&chain_id, &evidence)
+            .await
+            .is_err());
+        assert!(state
+            .process_misbehavior(&misbehavior(&old_key, 5))
+            .await
+            .is_err());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn only_recent_consensus_keys_are_kept() -> Result<()> {
+        let storage = TempStorage::new().await?;
+        let mut state = StateDelta::new(storage.latest_snapshot());
+        let keys = |n: u8| CurrentConsensusKeys {
+            consensus_keys: vec![PublicKey::from_raw_ed25519(
+                ed25519_consensus::SigningKey::from([n; 32])
+                    .verification_key()
+                    .as_bytes(),
+            )
+            .unwrap()],
+        };
+        for (height, n) in [(0, 0), (12, 1), (22, 2), (32, 3)] {
+            state.record_consensus_keys(height, keys(n), 3).await?;
+        }
+
+        assert!(state.consensus_keys_at_height(11).await?.is_none());
+        assert_eq!(
+            state
+                .consensus_keys_at_height(12)
+                .await?
+                .unwrap()
+                .consensus_keys,
+            keys(1).consensus_keys
+        );
+        assert_eq!(
+            state
+                .consensus_keys_at_height(31)
+                .await?
+                .unwrap()
+                .consensus_keys,
+            keys(2).consensus_keys
+        );
+        assert_eq!(
+            state
+                .consensus_keys_at_height(100)
+                .await?
+                .unwrap()
+                .consensus_keys,
+            keys(3).consensus_keys
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,275 @@
+//! Tracking validator participation through the decided commit of each block.
+//!
+//! The uptime used for jailing validators counts any vote in a block's last
+//! commit as a signature, including votes for `nil`. Participation only counts
+//! the votes a validator cast for the block that was decided, so it gives a
+//! finer-grained view of which validators are keeping up with consensus.
+//!
+//! Every node records participation as it executes each block, from the same
+//! commit info, but it is kept in nonverifiable storage, since it is
+//! informational only: it is never used for slashing.
+
+use std::collections::BTreeMap;
+
+use anyhow::Result;
+use async_trait::async_trait;
+use cnidarium::{StateRead, StateWrite};
+use futures::StreamExt as _;
+use penumbra_proto::DomainType;
+use penumbra_sct::component::clock::EpochRead as _;
+use sha2::{Digest as _, Sha256};
+use tendermint::abci::types::{BlockSignatureInfo, CommitInfo};
+use tendermint::block::BlockIdFlag;
+use tracing::instrument;
+
+use crate::{
+    component::{stake::ConsensusIndexRead, validator_handler::ValidatorDataRead, StateReadExt},
+    state_key, validator, IdentityKey, Uptime,
+};
+
+#[async_trait]
+pub trait ParticipationRead: StateRead {
+    /// Returns the participation of the validator with the given identity key,
+    /// if this node has recorded it.
+    async fn get_validator_participation(
+        &self,
+        identity_key: &IdentityKey,
+    ) -> Result<Option<Uptime>> {
+        self.nonverifiable_get_raw(
+            state_key::validators::participation::by_id(identity_key).as_bytes(),
+        )
+        .await?
+        .map(|bytes| Uptime::decode(bytes.as_slice()))
+        .transpose()
+    }
+}
+
+impl<T: StateRead + ?Sized> ParticipationRead for T {}
+
+#[async_trait]
+pub trait ParticipationTracker: StateWrite {
+    /// Record the participation of the active validators in the commit for the
+    /// previous block, as decided in the last commit info of the current block.
+    ///
+    /// This must be called while executing every block, so that the record
+    /// covers consecutive blocks.
+    #[instrument(skip(self, last_commit_info))]
+    async fn track_participation(&mut self, last_commit_info: &CommitInfo) -> Result<()> {
+        // The last commit info of the block at `height` is the commit for the
+        // block before it, and the genesis block has no commit at all.
+        let height = self.get_block_height().await?.saturating_sub(1);
+        if height == 0 {
+            return Ok(());
+        }
+
+        // Build a mapping from addresses (20-byte truncated SHA256(pubkey)) to vote statuses.
+        let did_address_vote = last_commit_info
+            .votes
+            .iter()
+            .map(|vote| (vote.validator.address, voted_for_block(&vote.sig_info)))
+            .collect::<BTreeMap<[u8; 20], bool>>();
+        let window_len = self.signed_blocks_window_len().await? as usize;
+
+        let mut validator_identity_stream = self.consensus_set_stream()?;
+        while let Some(identity_key) = validator_identity_stream.next().await {
+            let identity_key = identity_key?;
+            if self.get_validator_state(&identity_key).await? != Some(validator::State::Active) {
+                continue;
+            }
+
+            let consensus_key = self
+                .fetch_validator_consensus_key(&identity_key)
+                .await?
+                .expect("every known validator must have a recorded consensus key");
+            let addr: [u8; 20] =
+                Sha256::digest(&consensus_key.to_bytes()).as_slice()[0..20].try_into()?;
+            let voted = did_address_vote.get(&addr).cloned().unwrap_or(false);
+
+            let previous = self.get_validator_participation(&identity_key).await?;
+            let Some(participation) = record_participation(previous, height, window_len, voted)?
+            else {
+                continue;
+            };
+
+            tracing::debug!(
+                ?voted,
+                num_missed_blocks = ?participation.num_missed_blocks(),
+                ?identity_key,
+                "recorded participation"
+            );
+            self.nonverifiable_put_raw(
+                state_key::validators::participation::by_id(&identity_key).into_bytes(),
+                participation.encode_to_vec(),
+            );
+        }
+
+        Ok(())
+    }
+}
+
+impl<T: StateWrite + ?Sized> ParticipationTracker for T {}
+
+/// Whether a vote in a commit was cast for the block which was decided, rather
+/// than for `nil`, or missing altogether.
+fn voted_for_block(sig_info: &BlockSignatureInfo) -> bool {
+    matches!(
+        sig_info,
+        BlockSignatureInfo::Flag(BlockIdFlag::Commit) | BlockSignatureInfo::LegacySigned
+    )
+}
+
+/// Record whether a validator voted for the block at `height` in its
+/// participation, returning the updated participation, or `None` if the block
+/// was already recorded.
+///
+/// If there is a gap between the last recorded block and this one, as when
+/// this node starts tracking participation partway through the chain, the
+/// blocks in between were never observed, so the record starts over from this
+/// block, rather than treating the blocks on either side of the gap as
+/// consecutive.
+fn record_participation(
+    previous: Option<Uptime>,
+    height: u64,
+    window_len: usize,
+    voted: bool,
+) -> Result<Option<Uptime>> {
+    let mut participation = match previous {
+        Some(previous) if previous.as_of_block_height() >= height => return Ok(None),
+        Some(previous) if previous.as_of_block_height() + 1 == height => previous,
+        _ => Uptime::new(height - 1, window_len),
+    };
+    participation.resize(window_len);
+    participation.mark_height_as_signed(height, voted)?;
+    Ok(Some(participation))
+}
+
+#[cfg(test)]
+mod tests {
+    use cnidarium::{StateDelta, TempStorage};
+    use decaf377_rdsa::{SigningKey, SpendAuth};
+    use penumbra_proto::StateWriteProto as _;
+    use penumbra_sct::component::clock::EpochManager as _;
+    use rand_core::OsRng;
+    use tendermint::abci::types::{Validator as VoteValidator, VoteInfo};
+
+    use super::*;
+    use crate::{
+        component::{
+            stake::ConsensusIndexWrite as _, validator_handler::ValidatorManager as _,
+            StateWriteExt as _,
+        },
+        params::StakeParameters,
+        rate::RateData,
+        GovernanceKey,
+    };
+
+    #[test]
+    fn only_votes_for_the_block_count_as_participation() {
+        assert!(voted_for_block(&BlockSignatureInfo::Flag(
+            BlockIdFlag::Commit
+        )));
+        assert!(!voted_for_block(&BlockSignatureInfo::Flag(
+            BlockIdFlag::Nil
+        )));
+        assert!(!voted_for_block(&BlockSignatureInfo::Flag(
+            BlockIdFlag::Absent
+        )));
+    }
+
+    #[test]
+    fn gaps_restart_the_record_instead_of_being_treated_as_consecutive() -> Result<()> {
+        let window_len = 10;
+
+        let participation = record_participation(None, 5, window_len, false)?.unwrap();
+        assert_eq!(participation.as_of_block_height(), 5);
+        assert_eq!(participation.num_missed_blocks(), 1);
+
+        let participation =
+            record_participation(Some(participation), 6, window_len, false)?.unwrap();
+        assert_eq!(participation.num_missed_blocks(), 2);
+
+        // Recording the same block again changes nothing.
+        assert!(record_participation(Some(participation.clone()), 6, window_len, true)?.is_none());
+
+        // The blocks from 7 to 8 were never observed, so the misses before them
+        // are not carried over to block 9 as though it followed block 6.
+        let participation =
+            record_participation(Some(participation), 9, window_len, true)?.unwrap();
+        assert_eq!(participation.as_of_block_height(), 9);
+        assert_eq!(participation.num_missed_blocks(), 0);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn every_block_records_participation_from_the_decided_commit() -> Result<()> {
+        let storage = TempStorage::new().await?;
+        let mut state = StateDelta::new(storage.latest_snapshot());
+        state.put_stake_params(StakeParameters::default());
+
+        let signing_key = SigningKey::<SpendAuth>::new(OsRng);
+        let identity_key = IdentityKey((&signing_key).into());
+        let consensus_key = ed25519_consensus::SigningKey::new(OsRng).verification_key();
+        let consensus_key = tendermint::PublicKey::from_raw_ed25519(consensus_key.as_bytes())
+            .expect("consensus key is valid");
+        state
+            .add_validator(
+                validator::Validator {
+                    identity_key,
+                    governance_key: GovernanceKey((&signing_key).into()),
+                    consensus_key,
+                    name: String::new(),
+                    website: String::new(),
+                    description: String::new(),
+                    enabled: true,
+                    funding_streams: Default::default(),
+                    funding_streams_effective_epoch: None,
+                    sequence_number: 0,
+                },
+                RateData {
+                    identity_key,
+                    epoch_index: 0,
+                    validator_reward_rate: 0u128.into(),
+                    validator_exchange_rate: 1_0000_0000u128.into(),
+                },
+            )
+            .await?;
+        state.put(
+            state_key::validators::state::by_id(&identity_key),
+            validator::State::Active,
+        );
+        state.add_consensus_set_index(&identity_key);
+
+        let address: [u8; 20] =
+            Sha256::digest(&consensus_key.to_bytes()).as_slice()[0..20].try_into()?;
+        let commit = |flag| CommitInfo {
+            round: Default::default(),
+            votes: vec![VoteInfo {
+                validator: VoteValidator {
+                    address,
+                    power: 1u32.into(),
+                },
+                sig_info: BlockSignatureInfo::Flag(flag),
+            }],
+        };
+
+        // The validator votes for block 1, then for nil on block 2.
+        state.put_block_height(2);
+        state
+            .track_participation(&commit(BlockIdFlag::Commit))
+            .await?;
+        state.put_block_height(3);
+        state.track_participation(&commit(BlockIdFlag::Nil)).await?;
+        storage.commit(state).await?;
+
+        let participation = storage
+            .latest_snapshot()
+            .get_validator_participation(&identity_key)
+            .await?
+            .expect("participation was recorded");
+        assert_eq!(participation.as_of_block_height(), 2);
+        assert_eq!(participation.num_missed_blocks(), 1);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,283 @@
+//! Checkpointing the validator power table of each epoch.
+//!
+//! When an epoch begins, the staking component records a commitment to the
+//! voting power of every validator in the consensus set in verifiable storage,
+//! and the table itself in nonverifiable storage.  This lets light clients and
+//! bridges check a validator's power in a past epoch against an app hash they
+//! trust, without trusting the node serving the proof, while keeping the
+//! verifiable state to a single hash per epoch.
+
+use anyhow::{Context, Result};
+use async_trait::async_trait;
+use cnidarium::{Snapshot, StateRead, StateWrite};
+use futures::StreamExt as _;
+use ibc_types::core::commitment::{MerklePath, MerkleProof, MerkleRoot};
+use penumbra_num::Amount;
+use penumbra_proto::{DomainType, StateReadProto, StateWriteProto};
+
+use crate::{
+    component::{stake::ConsensusIndexRead, validator_handler::ValidatorDataRead},
+    state_key,
+    validator::{self, KeyProof, Power, PowerProof, PowerTable, PowerTableCommitment},
+    IdentityKey,
+};
+
+#[async_trait]
+pub trait PowerCheckpointRead: StateRead {
+    /// Returns the commitment to the power table of the given epoch, if one was recorded.
+    async fn get_power_table_commitment(
+        &self,
+        epoch_index: u64,
+    ) -> Result<Option<PowerTableCommitment>> {
+        self.get(&state_key::validators::power_checkpoints::by_epoch(
+            epoch_index,
+        ))
+        .await
+    }
+
+    /// Returns the power table of the given epoch, if one was recorded.
+    async fn get_power_table(&self, epoch_index: u64) -> Result<Option<PowerTable>> {
+        self.nonverifiable_get_raw(
+            state_key::validators::power_checkpoints::by_epoch(epoch_index).as_bytes(),
+        )
+        .await?
+        .map(|bytes| PowerTable::decode(bytes.as_slice()))
+        .transpose()
+    }
+}
+
+impl<T: StateRead + ?Sized> PowerCheckpointRead for T {}
+
+#[async_trait]
+pub trait PowerCheckpointWrite: StateWrite {
+    /// Record the power table of the given epoch from the current voting power
+    /// of every validator in the consensus set.
+    ///
+    /// Validators which are not active are recorded with zero voting power,
+    /// matching their power in consensus.
+    async fn checkpoint_validator_power(
+        &mut self,
+        epoch_index: u64,
+    ) -> Result<PowerTableCommitment> {
+        let mut entries = Vec::new();
+        let mut validator_identity_stream = self.consensus_set_stream()?;
+        while let Some(identity_key) = validator_identity_stream.next().await {
+            let identity_key = identity_key?;
+            let state = self
+                .get_validator_state(&identity_key)
+                .await?
+                .context("should be able to fetch validator state")?;
+            let voting_power = if matches!(state, validator::State::Active) {
+                self.get_validator_power(&identity_key)
+                    .await?
+                    .unwrap_or_default()
+            } else {
+                Amount::zero()
+            };
+            entries.push(Power {
+                identity_key,
+                voting_power,
+            });
+        }
+
+        let table = PowerTable::new(epoch_index, entries);
+        let commitment = table.commitment();
+        tracing::debug!(
+            epoch_index,
+            validators = table.entries.len(),
+            commitment = hex::encode(commitment.0),
+            "checkpointed validator power table"
+        );
+
+        let key = state_key::validators::power_checkpoints::by_epoch(epoch_index);
+        self.nonverifiable_put_raw(key.clone().into_bytes(), table.encode_to_vec());
+        self.put(key, commitment);
+
+        Ok(commitment)
+    }
+}
+
+impl<T: StateWrite + ?Sized> PowerCheckpointWrite for T {}
+
+/// Prove the validator's voting power in the given epoch against the app hash
+/// of the state.
+///
+/// Returns `None` if no power table was recorded for the epoch, or the
+/// validator was not in the consensus set when the epoch began.
+pub async fn prove_validator_power(
+    state: &Snapshot,
+    identity_key: &IdentityKey,
+    epoch_index: u64,
+) -> Result<Option<PowerProof>> {
+    let Some(table) = state.get_power_table(epoch_index).await? else {
+        return Ok(None);
+    };
+    let Some((power, leaf_index, siblings)) = table.path(identity_key) else {
+        return Ok(None);
+    };
+
+    let key = state_key::validators::power_checkpoints::by_epoch(epoch_index);
+    let (value, proof) = state.get_with_proof(key.clone().into_bytes()).await?;
+    let value = value.ok_or_else(|| anyhow::anyhow!("missing value for key {key}"))?;
+    anyhow::ensure!(
+        PowerTableCommitment::decode(value.as_slice())? == table.commitment(),
+        "recorded power table for epoch {epoch_index} does not match its commitment"
+    );
+
+    Ok(Some(PowerProof {
+        epoch_index,
+        power,
+        leaf_index,
+        leaf_count: table.entries.len() as u64,
+        siblings,
+        commitment_proof: KeyProof {
+            key,
+            value,
+            proof: ibc_types::DomainType::encode_to_vec(&proof),
+        },
+        height: state.version(),
+        app_hash: state.root_hash().await?.0.to_vec(),
+    }))
+}
+
+/// Verify a proof of a validator's voting power against a trusted app hash,
+/// such as the one in the header of the block following the proof's height.
+pub fn verify_validator_power(proof: &PowerProof, app_hash: &[u8]) -> Result<()> {
+    anyhow::ensure!(
+        proof.app_hash == app_hash,
+        "power proof is against app hash {}, not the trusted app hash {}",
+        hex::encode(&proof.app_hash),
+        hex::encode(app_hash)
+    );
+
+    let key_proof = &proof.commitment_proof;
+    anyhow::ensure!(
+        key_proof.key == state_key::validators::power_checkpoints::by_epoch(proof.epoch_index),
+        "power proof for epoch {} proves the wrong key {}",
+        proof.epoch_index,
+        key_proof.key
+    );
+    let merkle_proof = <MerkleProof as ibc_types::DomainType>::decode(key_proof.proof.as_slice())
+        .context("invalid proof encoding for power table commitment")?;
+    merkle_proof
+        .verify_membership(
+            &[cnidarium::ics23_spec()],
+            MerkleRoot {
+                hash: app_hash.to_vec(),
+            },
+            MerklePath {
+                key_path: vec![key_proof.key.clone()],
+            },
+            key_proof.value.clone(),
+            0,
+        )
+        .context("invalid proof for power table commitment")?;
+
+    let commitment = PowerTableCommitment::decode(key_proof.value.as_slice())?;
+    proof.verify_inclusion(&commitment)
+}
+
+#[cfg(test)]
+mod tests {
+    use cnidarium::{StateDelta, TempStorage};
+    use decaf377_rdsa::{SigningKey, SpendAuth};
+    use rand_core::OsRng;
+
+    use super::*;
+    use crate::{
+        component::{
+            stake::ConsensusIndexWrite as _,
+            validator_handler::{ValidatorDataWrite as _, ValidatorManager as _},
+            StateWriteExt as _,
+        },
+        params::StakeParameters,
+        rate::RateData,
+        GovernanceKey,
+    };
+
+    /// Add an active validator with the given voting power to the consensus set.
+    async fn add_active_validator(
+        state: &mut StateDelta<Snapshot>,
+        voting_power: u64,
+    ) -> Result<IdentityKey> {
+        let signing_key = SigningKey::<SpendAuth>::new(OsRng);
+        let identity_key = IdentityKey((&signing_key).into());
+        let consensus_key = ed25519_consensus::SigningKey::new(OsRng).verification_key();
+        state
+            .add_validator(
+                validator::Validator {
+                    identity_key,
+                    governance_key: GovernanceKey((&signing_key).into()),
+                    consensus_key: tendermint::PublicKey::from_raw_ed25519(
+                        consensus_key.as_bytes(),
+                    )
+                    .expect("consensus key is valid"),
+                    name: String::new(),
+                    website: String::new(),
+                    description: String::new(),
+                    enabled: true,
+                    funding_streams: Default::default(),
+                    funding_streams_effective_epoch: None,
+                    sequence_number: 0,
+                },
+                RateData {
+                    identity_key,
+                    epoch_index: 0,
+                    validator_reward_rate: 0u128.into(),
+                    validator_exchange_rate: 1_0000_0000u128.into(),
+                },
+            )
+            .await?;
+        state.put(
+            state_key::validators::state::by_id(&identity_key),
+            validator::State::Active,
+        );
+        state.set_validator_power(&identity_key, voting_power.into())?;
+        state.add_consensus_set_index(&identity_key);
+        Ok(identity_key)
+    }
+
+    #[tokio::test]
+    async fn power_proofs_verify_against_the_app_hash() -> Result<()> {
+        let storage = TempStorage::new().await?;
+        let mut state = StateDelta::new(storage.latest_snapshot());
+        state.put_stake_params(StakeParameters::default());
+        let alice = add_active_validator(&mut state, 5).await?;
+        let bob = add_active_validator(&mut state, 7).await?;
+        state.checkpoint_validator_power(3).await?;
+        storage.commit(state).await?;
+
+        let snapshot = storage.latest_snapshot();
+        let app_hash = snapshot.root_hash().await?.0;
+
+        let proof = prove_validator_power(&snapshot, &alice, 3)
+            .await?
+            .expect("alice is in the power table");
+        assert_eq!(proof.power.identity_key, alice);
+        assert_eq!(proof.power.voting_power, 5u64.into());
+        verify_validator_power(&proof, &app_hash)?;
+
+        // Nothing can be proven for epochs which weren't checkpointed, or for
+        // validators outside the consensus set.
+        assert!(prove_validator_power(&snapshot, &alice, 4).await?.is_none());
+        let outsider = IdentityKey((&SigningKey::<SpendAuth>::new(OsRng)).into());
+        assert!(prove_validator_power(&snapshot, &outsider, 3)
+            .await?
+            .is_none());
+
+        // The proof doesn't verify against another app hash...
+        assert!(verify_validator_power(&proof, &[0u8; 32]).is_err());
+
+        // ... nor once attributed to another validator...
+        let mut wrong_key = proof.clone();
+        wrong_key.power.identity_key = bob;
+        assert!(verify_validator_power(&wrong_key, &app_hash).is_err());
+
+        // ... nor once moved to another epoch.
+        let mut wrong_epoch = proof.clone();
+        wrong_epoch.epoch_index = 4;
+        assert!(verify_validator_power(&wrong_epoch, &app_hash).is_err());
+
+        Ok(())
+    }
+}
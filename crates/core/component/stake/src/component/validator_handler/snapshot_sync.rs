@@ -0,0 +1,332 @@
+//! Quick-syncing the validator set from a snapshot exported by another node.
+//!
+//! Replaying the chain to rebuild the validator set takes a long time, which
+//! is wasteful for replica nodes that only serve queries.  Instead, such a node
+//! can fetch a [`SetSnapshot`] together with a [`SetSnapshotProof`] from any
+//! peer, check it against an app hash it trusts, and write the proven state
+//! directly.
+//!
+//! Besides each validator's state, the proof covers the number of defined
+//! validators, so that a peer can't leave validators out of the snapshot.
+
+use anyhow::{Context, Result};
+use async_trait::async_trait;
+use cnidarium::{Snapshot, StateWrite};
+use ibc_types::core::commitment::{MerklePath, MerkleProof, MerkleRoot};
+use penumbra_num::Amount;
+use penumbra_proto::{DomainType, Message};
+use std::collections::{BTreeMap, BTreeSet};
+
+use crate::{
+    rate::RateData,
+    state_key,
+    validator::{self, KeyProof, SetSnapshot, SetSnapshotProof, Validator},
+    Uptime,
+};
+
+/// The state keys backing the recorded state of a validator in a snapshot.
+fn validator_keys(snapshot: &validator::Snapshot) -> Vec<String> {
+    let id = &snapshot.info.validator.identity_key;
+    let mut keys = vec![
+        state_key::validators::definitions::by_id(id),
+        state_key::validators::state::by_id(id),
+        state_key::validators::power::by_id(id),
+        state_key::validators::bonding_state::by_id(id),
+        state_key::validators::rate::current_by_id(id),
+    ];
+    if snapshot.uptime.is_some() {
+        keys.push(state_key::validators::uptime::by_id(id));
+    }
+    keys
+}
+
+/// Prove the contents of a snapshot of the validator set against the app hash
+/// of the state it was taken from.
+pub async fn prove_validator_set_snapshot(
+    state: &Snapshot,
+    snapshot: &SetSnapshot,
+) -> Result<SetSnapshotProof> {
+    let keys = [
+        penumbra_sct::state_key::block_manager::block_height().to_string(),
+        state_key::validators::count().to_string(),
+    ]
+    .into_iter()
+    .chain(snapshot.validators.iter().flat_map(validator_keys));
+
+    let mut key_proofs = Vec::new();
+    for key in keys {
+        let (value, proof) = state.get_with_proof(key.clone().into_bytes()).await?;
+        let value = value.ok_or_else(|| anyhow::anyhow!("missing value for key {key}"))?;
+        key_proofs.push(KeyProof {
+            key,
+            value,
+            proof: ibc_types::DomainType::encode_to_vec(&proof),
+        });
+    }
+
+    Ok(SetSnapshotProof {
+        app_hash: state.root_hash().await?.0.to_vec(),
+        key_proofs,
+    })
+}
+
+/// Verify a snapshot of the validator set against a trusted app hash.
+///
+/// This checks that every validator in the snapshot has exactly the recorded
+/// state in the snapshot, as of the snapshot's height, and that the snapshot
+/// holds as many distinct validators as were defined then, so none were left
+/// out.
+pub fn verify_validator_set_snapshot(
+    snapshot: &SetSnapshot,
+    proof: &SetSnapshotProof,
+    app_hash: &[u8],
+) -> Result<()> {
+    anyhow::ensure!(
+        proof.app_hash == app_hash,
+        "snapshot proof is against app hash {}, not the trusted app hash {}",
+        hex::encode(&proof.app_hash),
+        hex::encode(app_hash)
+    );
+
+    let root = MerkleRoot {
+        hash: app_hash.to_vec(),
+    };
+    let proof_specs = [cnidarium::ics23_spec()];
+    let mut proven = BTreeMap::new();
+    for key_proof in &proof.key_proofs {
+        let merkle_proof =
+            <MerkleProof as ibc_types::DomainType>::decode(key_proof.proof.as_slice())
+                .with_context(|| format!("invalid proof encoding for key {}", key_proof.key))?;
+        merkle_proof
+            .verify_membership(
+                &proof_specs,
+                root.clone(),
+                MerklePath {
+                    key_path: vec![key_proof.key.clone()],
+                },
+                key_proof.value.clone(),
+                0,
+            )
+            .with_context(|| format!("invalid proof for key {}", key_proof.key))?;
+        proven.insert(key_proof.key.as_str(), key_proof.value.as_slice());
+    }
+    let proven_value = |key: &str| {
+        proven
+            .get(key)
+            .copied()
+            .ok_or_else(|| anyhow::anyhow!("snapshot proof is missing key {key}"))
+    };
+
+    let height = u64::decode(proven_value(
+        penumbra_sct::state_key::block_manager::block_height(),
+    )?)?;
+    anyhow::ensure!(
+        height == snapshot.height,
+        "snapshot is at height {}, but the proof is at height {height}",
+        snapshot.height
+    );
+
+    let count = u64::decode(proven_value(state_key::validators::count())?)?;
+    let ids = snapshot
+        .validators
+        .iter()
+        .map(|validator| &validator.info.validator.identity_key)
+        .collect::<BTreeSet<_>>();
+    anyhow::ensure!(
+        ids.len() == snapshot.validators.len(),
+        "snapshot lists some validators more than once"
+    );
+    anyhow::ensure!(
+        count == ids.len() as u64,
+        "snapshot has {} validators, but {count} were defined at height {}",
+        ids.len(),
+        snapshot.height
+    );
+
+    for validator in &snapshot.validators {
+        let id = &validator.info.validator.identity_key;
+        let info = &validator.info;
+        let mismatch = |field: &str| anyhow::anyhow!("snapshot has the wrong {field} for {id}");
+
+        if Validator::decode(proven_value(&state_key::validators::definitions::by_id(
+            id,
+        ))?)?
+            != info.validator
+        {
+            return Err(mismatch("definition"));
+        }
+        if validator::State::decode(proven_value(&state_key::validators::state::by_id(id))?)?
+            != info.status.state
+        {
+            return Err(mismatch("state"));
+        }
+        if Amount::decode(proven_value(&state_key::validators::power::by_id(id))?)?
+            != info.status.voting_power
+        {
+            return Err(mismatch("voting power"));
+        }
+        if validator::BondingState::decode(proven_value(
+            &state_key::validators::bonding_state::by_id(id),
+        )?)? != info.status.bonding_state
+        {
+            return Err(mismatch("bonding state"));
+        }
+        if RateData::decode(proven_value(&state_key::validators::rate::current_by_id(
+            id,
+        ))?)?
+            != info.rate_data
+        {
+            return Err(mismatch("rate data"));
+        }
+        if let Some(uptime) = &validator.uptime {
+            if &Uptime::decode(proven_value(&state_key::validators::uptime::by_id(id))?)? != uptime
+            {
+                return Err(mismatch("uptime"));
+            }
+        }
+    }
+
+    Ok(())
+}
+
+#[async_trait]
+pub trait SnapshotSync: StateWrite {
+    /// Bootstrap the validator set from a snapshot exported by another node,
+    /// after verifying it against a trusted app hash.
+    ///
+    /// Nothing is written unless the whole snapshot verifies.  This writes
+    /// each validator's definition, state, voting power, bonding state,
+    /// current rates, and uptime, along with the validator count, so that the validator queries can be
+    /// served without replaying the chain.  It does not register the
+    /// validators with consensus, so it is only suitable for nodes which serve
+    /// reads.
+    async fn sync_validator_set_snapshot(
+        &mut self,
+        snapshot: &SetSnapshot,
+        proof: SetSnapshotProof,
+        app_hash: &[u8],
+    ) -> Result<()> {
+        verify_validator_set_snapshot(snapshot, &proof, app_hash)?;
+
+        tracing::debug!(
+            height = snapshot.height,
+            validators = snapshot.validators.len(),
+            "syncing validator set from snapshot"
+        );
+        let keys = std::iter::once(state_key::validators::count().to_string())
+            .chain(snapshot.validators.iter().flat_map(validator_keys))
+            .collect::<BTreeSet<_>>();
+        for key_proof in proof.key_proofs {
+            if keys.contains(&key_proof.key) {
+                self.put_raw(key_proof.key, key_proof.value);
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl<T: StateWrite + ?Sized> SnapshotSync for T {}
+
+#[cfg(test)]
+mod tests {
+    use cnidarium::{StateDelta, TempStorage};
+    use decaf377_rdsa::{SigningKey, SpendAuth};
+    use penumbra_sct::{component::clock::EpochManager as _, epoch::Epoch};
+    use rand_core::OsRng;
+    use tendermint::PublicKey;
+
+    use super::*;
+    use crate::{
+        component::{
+            validator_handler::{ValidatorDataRead as _, ValidatorManager as _},
+            StateWriteExt as _,
+        },
+        params::StakeParameters,
+        GovernanceKey, IdentityKey,
+    };
+
+    async fn add_validator<S: StateWrite>(state: &mut S) -> Result<IdentityKey> {
+        let signing_key = SigningKey::<SpendAuth>::new(OsRng);
+        let identity_key = IdentityKey((&signing_key).into());
+        let consensus_key = ed25519_consensus::SigningKey::new(OsRng).verification_key();
+        state
+            .add_validator(
+                Validator {
+                    identity_key,
+                    governance_key: GovernanceKey((&signing_key).into()),
+                    consensus_key: PublicKey::from_raw_ed25519(consensus_key.as_bytes())
+                        .expect("consensus key is valid"),
+                    name: String::new(),
+                    website: String::new(),
+                    description: String::new(),
+                    enabled: true,
+                    funding_streams: Default::default(),
+                    funding_streams_effective_epoch: None,
+                    sequence_number: 0,
+                },
+                RateData {
+                    identity_key,
+                    epoch_index: 0,
+                    validator_reward_rate: 0u128.into(),
+                    validator_exchange_rate: 1_0000_0000u128.into(),
+                },
+            )
+            .await?;
+        Ok(identity_key)
+    }
+
+    #[tokio::test]
+    async fn validator_set_snapshots_round_trip() -> Result<()> {
+        let source = TempStorage::new().await?;
+        let mut state = StateDelta::new(source.latest_snapshot());
+        state.put_stake_params(StakeParameters::default());
+        state.put_block_height(7);
+        state.put_epoch_by_height(
+            7,
+            Epoch {
+                index: 0,
+                start_height: 0,
+            },
+        );
+        for _ in 0..3 {
+            add_validator(&mut state).await?;
+        }
+        source.commit(state).await?;
+
+        let exported = source.latest_snapshot();
+        let snapshot = exported.validator_set_snapshot().await?;
+        assert_eq!(snapshot.validators.len(), 3);
+        let proof = prove_validator_set_snapshot(&exported, &snapshot).await?;
+        let app_hash = exported.root_hash().await?.0.to_vec();
+
+        let replica = TempStorage::new().await?;
+        let mut state = StateDelta::new(replica.latest_snapshot());
+
+        // A snapshot leaving out a validator, or proven against another app
+        // hash, is refused without writing anything.
+        let mut partial = snapshot.clone();
+        partial.validators.pop();
+        assert!(state
+            .sync_validator_set_snapshot(&partial, proof.clone(), &app_hash)
+            .await
+            .is_err());
+        assert!(state
+            .sync_validator_set_snapshot(&snapshot, proof.clone(), &[0; 32])
+            .await
+            .is_err());
+        assert!(state.validator_definitions().await?.is_empty());
+
+        state
+            .sync_validator_set_snapshot(&snapshot, proof, &app_hash)
+            .await?;
+        state.put_block_height(7);
+        replica.commit(state).await?;
+        assert_eq!(
+            replica.latest_snapshot().validator_set_snapshot().await?,
+            snapshot
+        );
+
+        Ok(())
+    }
+}
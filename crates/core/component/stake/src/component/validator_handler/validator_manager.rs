@@ -19,20 +19,20 @@ use penumbra_sct::{
 };
 use penumbra_shielded_pool::component::{SupplyRead as _, SupplyWrite};
 use sha2::{Digest as _, Sha256};
-use tendermint::abci::types::{CommitInfo, Misbehavior};
+use tendermint::abci::types::CommitInfo;
 use tokio::task::JoinSet;
 use validator::BondingState::*;
 use validator::State::*;
 
 use cnidarium::StateWrite;
-use penumbra_proto::StateWriteProto;
+use penumbra_proto::{DomainType as _, StateReadProto, StateWriteProto};
 use tracing::{instrument, Instrument};
 
 use crate::{
     component::validator_handler::ValidatorDataRead,
     component::StateReadExt as _,
     component::StateWriteExt as _,
-    state_key,
+    event, state_key,
     validator::{self},
     IdentityKey, Penalty, Uptime,
 };
@@ -54,7 +54,8 @@ use penumbra_asset::asset;
 ///
 /// ## Validator-specific logic
 /// - Tracking a validator's uptime via [`track_uptime`].
-/// - Process byzantine behavior evidence via [`process_evidence`].
+/// - Process byzantine behavior evidence via [`EvidenceHandler`].
+/// - Prune validators which have been inactive for too long via [`prune_inactive_validators`].
 ///
 /// # State machine diagram:
 /// ```plaintext
@@ -95,7 +96,8 @@ use penumbra_asset::asset;
 /// [`set_validator_state`]: Self::set_validator_state
 /// [`try_precursor_transition`]: Self::try_precursor_transition
 /// [`track_uptime`]: Self::track_uptime
-/// [`process_evidence`]: Self::process_evidence
+/// [`EvidenceHandler`]: super::EvidenceHandler
+/// [`prune_inactive_validators`]: Self::prune_inactive_validators
 pub trait ValidatorManager: StateWrite {
     /// Execute a legal state transition, updating the validator records and
     /// implementing the necessary side effects.
@@ -460,6 +462,143 @@ pub trait ValidatorManager: StateWrite {
         Ok(())
     }
 
+    /// Import every validator in a snapshot of another chain's validator set at genesis.
+    ///
+    /// The active validator limit applies to the imported validators along with any
+    /// validators already added at genesis: if the snapshot has more active validators
+    /// than there are slots left, those with the most voting power stay active, and the
+    /// rest are imported as inactive.
+    async fn import_validator_set_snapshot(
+        &mut self,
+        starting_height: u64,
+        genesis_base_rate: &BaseRateData,
+        snapshot: validator::SetSnapshot,
+    ) -> Result<()> {
+        let limit = self.get_stake_params().await?.active_validator_limit as usize;
+        let mut active = 0;
+        let mut consensus_set = self.consensus_set_stream()?;
+        while let Some(identity_key) = consensus_set.next().await {
+            if self.get_validator_state(&identity_key?).await? == Some(Active) {
+                active += 1;
+            }
+        }
+
+        // Fill the remaining slots in order of voting power, breaking ties by identity key.
+        let mut validators = snapshot.validators;
+        validators.sort_by(|a, b| b.info.status.voting_power.cmp(&a.info.status.voting_power));
+        for mut validator in validators {
+            if validator.info.status.state == Active {
+                if active < limit {
+                    active += 1;
+                } else {
+                    tracing::debug!(identity_key = %validator.info.validator.identity_key, "importing validator as inactive, the active set is full");
+                    validator.info.status.state = Inactive;
+                }
+            }
+            self.import_validator_snapshot(starting_height, genesis_base_rate, validator)
+                .await?;
+        }
+
+        Ok(())
+    }
+
+    /// Import a validator at genesis from a snapshot of its state on another chain.
+    ///
+    /// Unlike [`add_genesis_validator`](Self::add_genesis_validator), this keeps the
+    /// validator's recorded state, bonding state, voting power, and rates. Anything tied
+    /// to the original chain's epochs and heights is shifted so that the snapshot's epoch
+    /// and height correspond to the genesis epoch and height. The delegation tokens backing
+    /// the validator's voting power are not imported, and should be allocated separately.
+    async fn import_validator_snapshot(
+        &mut self,
+        starting_height: u64,
+        genesis_base_rate: &BaseRateData,
+        snapshot: validator::Snapshot,
+    ) -> Result<()> {
+        let validator::Info {
+            validator,
+            status,
+            mut rate_data,
+        } = snapshot.info;
+        let identity_key = validator.identity_key.clone();
+
+        if self
+            .get_validator_definition(&identity_key)
+            .await?
+            .is_some()
+        {
+            anyhow::bail!("imported validator {identity_key} is already defined");
+        }
+
+        tracing::debug!(%identity_key, state = ?status.state, bonding_state = ?status.bonding_state, voting_power = ?status.voting_power, "importing validator");
+
+        // The snapshot's rates are for the epoch in which it was taken, which
+        // becomes the genesis epoch.
+        let snapshot_epoch_index = rate_data.epoch_index;
+        rate_data.epoch_index = genesis_base_rate.epoch_index;
+        let bonding_state = match status.bonding_state {
+            Unbonding { unbonds_at_epoch } => Unbonding {
+                unbonds_at_epoch: genesis_base_rate.epoch_index
+                    + unbonds_at_epoch.saturating_sub(snapshot_epoch_index),
+            },
+            bonding_state => bonding_state,
+        };
+
+        self.put(
+            state_key::validators::definitions::by_id(&identity_key),
+            validator.clone(),
+        );
+        self.index_validator_name(&identity_key, &validator.name);
+        self.adjust_validator_count(true).await?;
+        self.register_consensus_key(&identity_key, &validator.consensus_key)
+            .await;
+        self.register_denom(&DelegationToken::from(&identity_key).denom())
+            .await?;
+        self.set_validator_rate_data(&identity_key, rate_data);
+
+        // Imported validators can be in any state, so we bypass the checks on
+        // the initial state of new validators.
+        self.put(
+            state_key::validators::state::by_id(&identity_key),
+            status.state,
+        );
+        self.set_validator_power(&identity_key, status.voting_power)?;
+        self.set_validator_bonding_state(&identity_key, bonding_state);
+        if matches!(status.state, Active | Inactive) {
+            self.add_consensus_set_index(&identity_key);
+        }
+
+        // Carry over the validator's recent signing record, if it has one.
+        let signed_blocks_window_len = self.signed_blocks_window_len().await? as usize;
+        let uptime = match snapshot.uptime {
+            Some(mut uptime) => {
+                uptime.rebase(starting_height);
+                uptime.resize(signed_blocks_window_len);
+                Some(uptime)
+            }
+            // Active validators must have their uptime tracked.
+            None if status.state == Active => {
+                Some(Uptime::new(starting_height, signed_blocks_window_len))
+            }
+            None => None,
+        };
+        if let Some(uptime) = uptime {
+            self.set_validator_uptime(&identity_key, uptime);
+        }
+
+        let gauge = match status.state {
+            Defined => metrics::DEFINED_VALIDATORS,
+            Inactive => metrics::INACTIVE_VALIDATORS,
+            Active => metrics::ACTIVE_VALIDATORS,
+            Jailed => metrics::JAILED_VALIDATORS,
+            Tombstoned => metrics::TOMBSTONED_VALIDATORS,
+            Disabled => metrics::DISABLED_VALIDATORS,
+        };
+        metrics::gauge!(gauge).increment(1.0);
+
+        Ok(())
+    }
+
     /// Add a validator after genesis, which will start in a [`validator::State::Defined`]
     /// state with zero voting power, and unbonded delegation tokens. This is the default
     /// "initial" state for a validator.
@@ -513,6 +652,8 @@ pub trait ValidatorManager: StateWrite {
             state_key::validators::definitions::by_id(&validator_identity),
             validator.clone(),
         );
+        self.index_validator_name(&validator_identity, &validator.name);
+        self.adjust_validator_count(true).await?;
         // Then, we create a mapping from the validator's consensus key to its
         // identity key, so we can look up the validator by its consensus key, and
         // vice-versa.
@@ -552,11 +693,46 @@ pub trait ValidatorManager: StateWrite {
     }
 
     /// Update a validator definition
+    ///
+    /// If the new definition schedules its funding streams for a future epoch,
+    /// the funding streams currently in effect are kept until then, and the
+    /// schedule supersedes any previously scheduled funding streams. Otherwise,
+    /// funding streams scheduled previously are only cancelled if the new
+    /// definition changes the funding streams in effect, so that updating other
+    /// parts of the definition doesn't undo a scheduled change.
     #[tracing::instrument(skip(self, validator), fields(id = ?validator.identity_key))]
     async fn update_validator_definition(&mut self, validator: Validator) -> Result<()> {
         use validator::State::*;
 
         tracing::debug!(definition = ?validator, "updating validator definition");
+        let current_definition = self
+            .get_validator_definition(&validator.identity_key)
+            .await?
+            .ok_or_else(|| anyhow::anyhow!("updated validator not found in JMT"))?;
+        let validator = match validator.funding_streams_effective_epoch {
+            Some(effective_epoch) => {
+                self.cancel_scheduled_funding_streams(&validator.identity_key)
+                    .await?;
+                self.record(event::funding_streams_scheduled(
+                    &validator,
+                    effective_epoch,
+                ));
+                self.schedule_funding_streams(validator.clone(), effective_epoch);
+                Validator {
+                    funding_streams: current_definition.funding_streams,
+                    funding_streams_effective_epoch: None,
+                    ..validator
+                }
+            }
+            None => {
+                if validator.funding_streams != current_definition.funding_streams {
+                    self.cancel_scheduled_funding_streams(&validator.identity_key)
+                        .await?;
+                }
+                validator
+            }
+        };
+
         let id = &validator.identity_key;
         let current_state = self
             .get_validator_state(id)
@@ -628,6 +804,20 @@ pub trait ValidatorManager: StateWrite {
         self.register_consensus_key(&validator.identity_key, &validator.consensus_key)
             .await;
 
+        // Record which fields of the definition changed, so that indexers can follow the
+        // validator's history without comparing whole definitions.
+        let previous_definition = self
+            .get_validator_definition(id)
+            .await?
+            .ok_or_else(|| anyhow::anyhow!("updated validator not found in JMT"))?;
+        for change in event::validator_definition_changes(&previous_definition, &validator) {
+            self.record(change);
+        }
+        if previous_definition.name != validator.name {
+            self.unindex_validator_name(id, &previous_definition.name);
+            self.index_validator_name(id, &validator.name);
+        }
+
         self.put(state_key::validators::definitions::by_id(id), validator);
 
         Ok(())
@@ -757,27 +947,133 @@ pub trait ValidatorManager: StateWrite {
         Ok(())
     }
 
-    /// Process evidence of byzantine behavior from CometBFT.
+    /// Prune the state of validators which have been defined or disabled with no stake for
+    /// longer than the retention period, leaving a [`validator::Tombstone`] in its place.
+    ///
+    /// This is called at the end of each epoch, once the delegation changes of the epoch have
+    /// been applied.  A validator is only considered idle once its delegation pool is unbonded
+    /// and empty, so that no delegation or unbonding tokens refer to it anymore.
+    #[instrument(skip(self))]
+    async fn prune_inactive_validators(&mut self, epoch_index: u64) -> Result<()> {
+        let retention_epochs = self
+            .get_stake_params()
+            .await?
+            .inactive_validator_retention_epochs;
+        if retention_epochs == 0 {
+            return Ok(());
+        }
+
+        for validator in self.validator_definitions().await? {
+            let id = validator.identity_key.clone();
+            let state = self
+                .get_validator_state(&id)
+                .await?
+                .ok_or_else(|| anyhow::anyhow!("validator (identity={id}) has no state"))?;
+            let bonding_state = self.get_validator_bonding_state(&id).await;
+            let pool_size = self.get_validator_pool_size(&id).await.unwrap_or_default();
+            let idle = matches!(state, Defined | Disabled)
+                && matches!(bonding_state, Some(Unbonded))
+                && pool_size == Amount::zero();
+
+            let inactive_since_key = state_key::validators::inactive_since::by_id(&id);
+            let inactive_since = self.get_proto::<u64>(&inactive_since_key).await?;
+            match (idle, inactive_since) {
+                (false, None) => {}
+                (false, Some(_)) => self.delete(inactive_since_key),
+                (true, None) => self.put_proto(inactive_since_key, epoch_index),
+                (true, Some(since)) if epoch_index.saturating_sub(since) >= retention_epochs => {
+                    self.prune_validator(validator, state, since, epoch_index)
+                        .await?
+                }
+                (true, Some(_)) => {}
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Record that a validator definition was added or removed.
     ///
-    /// Evidence *MUST* be processed before `end_block` is called, because
-    /// the evidence may trigger a validator state transition requiring
-    /// an early epoch change.
+    /// The number of defined validators is kept in the JMT, so that a snapshot of the validator
+    /// set can be proven to be complete. Chains which predate the count need a migration to record
+    /// it before their snapshots can be verified.
+    async fn adjust_validator_count(&mut self, added: bool) -> Result<()> {
+        let count: u64 = self
+            .get_proto(state_key::validators::count())
+            .await?
+            .unwrap_or_default();
+        let count = if added {
+            count + 1
+        } else {
+            count.saturating_sub(1)
+        };
+        self.put_proto(state_key::validators::count().to_string(), count);
+        Ok(())
+    }
+
+    /// Replace the state of an idle validator with a [`validator::Tombstone`].
     ///
-    /// # Errors
-    /// Returns an error if the validator is not found in the JMT.
-    async fn process_evidence(&mut self, evidence: &Misbehavior) -> Result<()> {
-        let validator = self
-            .get_validator_by_cometbft_address(&evidence.validator.address)
+    /// The validator's per-epoch rate data and penalties are kept, since they are still needed
+    /// to compute historical returns and delegation receipts. Its bonding state is dropped with
+    /// the rest of its state, since undelegations from a pruned validator are treated as unbonded.
+    /// The tombstone is kept in the nonverifiable state, so that pruned validators don't
+    /// accumulate in the JMT.
+    async fn prune_validator(
+        &mut self,
+        validator: Validator,
+        state: validator::State,
+        inactive_since_epoch_index: u64,
+        pruned_epoch_index: u64,
+    ) -> Result<()> {
+        let id = validator.identity_key.clone();
+        tracing::debug!(validator_identity = %id, inactive_since_epoch_index, "pruning inactive validator");
+
+        let rate_data = self
+            .get_validator_rate(&id)
             .await?
-            .ok_or_else(|| {
-                anyhow::anyhow!(
-                    "attempted to slash unknown validator with evidence={:?}",
-                    evidence
-                )
-            })?;
+            .ok_or_else(|| anyhow::anyhow!("validator (identity={id}) has no rate data"))?;
+
+        self.cancel_scheduled_funding_streams(&id).await?;
+        self.unregister_consensus_key(&validator.consensus_key);
+        self.delete(state_key::validators::definitions::by_id(&id));
+        self.unindex_validator_name(&id, &validator.name);
+        self.adjust_validator_count(false).await?;
+        self.delete(state_key::validators::state::by_id(&id));
+        self.delete(state_key::validators::bonding_state::by_id(&id));
+        self.delete(state_key::validators::power::by_id(&id));
+        self.delete(state_key::validators::rate::current_by_id(&id));
+        self.delete(state_key::validators::rate::previous_by_id(&id));
+        self.delete(state_key::validators::uptime::by_id(&id));
+        self.delete(state_key::validators::self_bond::by_id(&id));
+        self.delete(state_key::validators::inactive_since::by_id(&id));
+        self.nonverifiable_delete(
+            state_key::validators::participation::by_id(&id)
+                .as_bytes()
+                .to_vec(),
+        );
 
-        self.set_validator_state(&validator.identity_key, validator::State::Tombstoned)
-            .await
+        let tombstone = validator::Tombstone {
+            identity_key: id.clone(),
+            name: validator.name,
+            sequence_number: validator.sequence_number,
+            state,
+            rate_data,
+            inactive_since_epoch_index,
+            pruned_epoch_index,
+        };
+        self.record(event::validator_pruned(&tombstone));
+        self.nonverifiable_put_raw(
+            state_key::validators::tombstones::by_id(&id).into_bytes(),
+            tombstone.encode_to_vec(),
+        );
+
+        match state {
+            Defined => metrics::gauge!(metrics::DEFINED_VALIDATORS).decrement(1.0),
+            Disabled => metrics::gauge!(metrics::DISABLED_VALIDATORS).decrement(1.0),
+            _ => {}
+        }
+
+        Ok(())
     }
 
     fn state_machine_metrics(old_state: validator::State, new_state: validator::State) {
@@ -802,3 +1098,270 @@ pub trait ValidatorManager: StateWrite {
 }
 
 impl<T: StateWrite + ?Sized> ValidatorManager for T {}
+
+#[cfg(test)]
+mod tests {
+    use cnidarium::{StateDelta, StateRead as _, TempStorage};
+    use decaf377_rdsa::{SigningKey, SpendAuth};
+    use rand_core::OsRng;
+
+    use super::*;
+    use crate::{
+        component::{SlashingData as _, StateWriteExt as _},
+        params::StakeParameters,
+        GovernanceKey,
+    };
+
+    #[tokio::test]
+    async fn undelegations_from_pruned_validators_can_be_claimed() -> anyhow::Result<()> {
+        let storage = TempStorage::new().await?;
+        let mut state = StateDelta::new(storage.latest_snapshot());
+        state.put_stake_params(StakeParameters {
+            inactive_validator_retention_epochs: 2,
+            ..Default::default()
+        });
+
+        let signing_key = SigningKey::<SpendAuth>::new(OsRng);
+        let identity_key = IdentityKey((&signing_key).into());
+        let consensus_key = ed25519_consensus::SigningKey::new(OsRng).verification_key();
+        state
+            .add_validator(
+                Validator {
+                    identity_key,
+                    governance_key: GovernanceKey((&signing_key).into()),
+                    consensus_key: tendermint::PublicKey::from_raw_ed25519(
+                        consensus_key.as_bytes(),
+                    )
+                    .expect("consensus key is valid"),
+                    name: "idle".to_string(),
+                    website: String::new(),
+                    description: String::new(),
+                    enabled: true,
+                    funding_streams: Default::default(),
+                    funding_streams_effective_epoch: None,
+                    sequence_number: 3,
+                },
+                RateData {
+                    identity_key,
+                    epoch_index: 0,
+                    validator_reward_rate: 0u128.into(),
+                    validator_exchange_rate: 1_0000_0000u128.into(),
+                },
+            )
+            .await?;
+
+        // The validator is pruned once it has been idle for the retention period.
+        for epoch_index in 0..2 {
+            state.prune_inactive_validators(epoch_index).await?;
+            assert!(state
+                .get_validator_definition(&identity_key)
+                .await?
+                .is_some());
+        }
+        state.prune_inactive_validators(2).await?;
+        storage.commit(state).await?;
+
+        let state = storage.latest_snapshot();
+        assert!(state
+            .get_validator_definition(&identity_key)
+            .await?
+            .is_none());
+        assert!(state
+            .get_validator_bonding_state(&identity_key)
+            .await
+            .is_none());
+        let tombstone = state
+            .get_validator_tombstone(&identity_key)
+            .await?
+            .expect("pruned validator has a tombstone");
+        assert_eq!(tombstone.sequence_number, 3);
+        assert_eq!(tombstone.pruned_epoch_index, 2);
+        // The tombstone isn't part of the verifiable state.
+        assert!(state
+            .get_raw(&state_key::validators::tombstones::by_id(&identity_key))
+            .await?
+            .is_none());
+
+        // Undelegations from the pruned validator are unbonded, so they can be claimed right away.
+        assert_eq!(state.compute_unbonding_epoch(&identity_key, 1).await?, 1);
+        assert_eq!(
+            state
+                .compounded_penalty_over_range(&identity_key, 1, 1)
+                .await?,
+            Penalty::from_percent(0)
+        );
+
+        // Other validators' bonding state is still required.
+        let unknown = IdentityKey((&SigningKey::<SpendAuth>::new(OsRng)).into());
+        assert!(state.compute_unbonding_epoch(&unknown, 1).await.is_err());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn scheduled_funding_streams_survive_unrelated_updates() -> anyhow::Result<()> {
+        use crate::{component::epoch_handler::EpochHandler as _, FundingStream, FundingStreams};
+
+        let storage = TempStorage::new().await?;
+        let mut state = StateDelta::new(storage.latest_snapshot());
+        state.put_stake_params(StakeParameters::default());
+
+        let signing_key = SigningKey::<SpendAuth>::new(OsRng);
+        let identity_key = IdentityKey((&signing_key).into());
+        let consensus_key = ed25519_consensus::SigningKey::new(OsRng).verification_key();
+        let definition = Validator {
+            identity_key,
+            governance_key: GovernanceKey((&signing_key).into()),
+            consensus_key: tendermint::PublicKey::from_raw_ed25519(consensus_key.as_bytes())
+                .expect("consensus key is valid"),
+            name: "scheduler".to_string(),
+            website: String::new(),
+            description: String::new(),
+            enabled: true,
+            funding_streams: Default::default(),
+            funding_streams_effective_epoch: None,
+            sequence_number: 0,
+        };
+        state
+            .add_validator(
+                definition.clone(),
+                RateData {
+                    identity_key,
+                    epoch_index: 0,
+                    validator_reward_rate: 0u128.into(),
+                    validator_exchange_rate: 1_0000_0000u128.into(),
+                },
+            )
+            .await?;
+
+        // Schedule a commission for epoch 3.
+        let commission = FundingStreams::try_from(vec![FundingStream::ToAddress {
+            address: *penumbra_keys::test_keys::ADDRESS_0,
+            rate_bps: 500,
+            auto_compound: false,
+        }])?;
+        state
+            .update_validator_definition(Validator {
+                funding_streams: commission.clone(),
+                funding_streams_effective_epoch: Some(3),
+                sequence_number: 1,
+                ..definition.clone()
+            })
+            .await?;
+        let current = state
+            .get_validator_definition(&identity_key)
+            .await?
+            .unwrap();
+        assert_eq!(current.funding_streams, FundingStreams::default());
+
+        // Updating the website, from the definition as it is stored, keeps the schedule.
+        state
+            .update_validator_definition(Validator {
+                website: "https://example.com".to_string(),
+                sequence_number: 2,
+                ..current
+            })
+            .await?;
+        assert!(state
+            .get_scheduled_funding_streams(&identity_key)
+            .await?
+            .is_some());
+
+        // The commission takes effect at the start of epoch 3, along with the later update.
+        state.apply_scheduled_funding_streams(3).await?;
+        let current = state
+            .get_validator_definition(&identity_key)
+            .await?
+            .unwrap();
+        assert_eq!(current.funding_streams, commission);
+        assert_eq!(current.website, "https://example.com");
+        assert_eq!(current.sequence_number, 2);
+        assert!(state
+            .get_scheduled_funding_streams(&identity_key)
+            .await?
+            .is_none());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn imported_snapshots_respect_the_active_validator_limit() -> anyhow::Result<()> {
+        use penumbra_sct::component::clock::EpochManager as _;
+
+        let source = TempStorage::new().await?;
+        let mut state = StateDelta::new(source.latest_snapshot());
+        state.put_stake_params(StakeParameters::default());
+        state.put_block_height(7);
+        let window = state.signed_blocks_window_len().await? as usize;
+        for power in [10u128, 30, 20] {
+            let signing_key = SigningKey::<SpendAuth>::new(OsRng);
+            let identity_key = IdentityKey((&signing_key).into());
+            let consensus_key = ed25519_consensus::SigningKey::new(OsRng).verification_key();
+            state
+                .add_validator(
+                    Validator {
+                        identity_key,
+                        governance_key: GovernanceKey((&signing_key).into()),
+                        consensus_key: tendermint::PublicKey::from_raw_ed25519(
+                            consensus_key.as_bytes(),
+                        )
+                        .expect("consensus key is valid"),
+                        name: format!("validator with power {power}"),
+                        website: String::new(),
+                        description: String::new(),
+                        enabled: true,
+                        funding_streams: Default::default(),
+                        funding_streams_effective_epoch: None,
+                        sequence_number: 0,
+                    },
+                    RateData {
+                        identity_key,
+                        epoch_index: 0,
+                        validator_reward_rate: 0u128.into(),
+                        validator_exchange_rate: 1_0000_0000u128.into(),
+                    },
+                )
+                .await?;
+            state.put(state_key::validators::state::by_id(&identity_key), Active);
+            state.set_validator_power(&identity_key, power.into())?;
+            state.set_validator_uptime(&identity_key, Uptime::new(7, window));
+        }
+        source.commit(state).await?;
+        let exported = source.latest_snapshot().validator_set_snapshot().await?;
+
+        // Import the snapshot into a chain with room for only two active validators.
+        let replica = TempStorage::new().await?;
+        let mut state = StateDelta::new(replica.latest_snapshot());
+        state.put_stake_params(StakeParameters {
+            active_validator_limit: 2,
+            ..Default::default()
+        });
+        state.put_block_height(7);
+        state
+            .import_validator_set_snapshot(
+                7,
+                &BaseRateData {
+                    epoch_index: 0,
+                    base_reward_rate: 0u128.into(),
+                    base_exchange_rate: 1_0000_0000u128.into(),
+                },
+                exported.clone(),
+            )
+            .await?;
+        replica.commit(state).await?;
+
+        // Everything round-trips, except that the least powerful validator is now inactive.
+        let mut expected = exported;
+        for validator in expected.validators.iter_mut() {
+            if validator.info.status.voting_power == Amount::from(10u128) {
+                validator.info.status.state = Inactive;
+            }
+        }
+        assert_eq!(
+            replica.latest_snapshot().validator_set_snapshot().await?,
+            expected
+        );
+
+        Ok(())
+    }
+}
@@ -1,17 +1,20 @@
 use crate::{
-    component::{StateReadExt as _, MAX_VOTING_POWER},
+    component::{ConsensusIndexRead as _, SlashingData as _, StateReadExt as _, MAX_VOTING_POWER},
     rate::RateData,
     state_key,
     validator::{self, BondingState::*, State, Validator},
-    DelegationToken, IdentityKey, Uptime,
+    DelegationReceipt, DelegationToken, IdentityKey, Penalty, Uptime,
 };
 use anyhow::Result;
 use async_trait::async_trait;
 use cnidarium::{StateRead, StateWrite};
-use futures::{Future, FutureExt, TryStreamExt};
+use futures::{Future, FutureExt, StreamExt, TryStreamExt};
 use penumbra_num::Amount;
-use penumbra_proto::{state::future::DomainFuture, StateReadProto, StateWriteProto};
-use std::pin::Pin;
+use penumbra_proto::{
+    state::future::DomainFuture, DomainType as _, StateReadProto, StateWriteProto,
+};
+use penumbra_sct::component::clock::EpochRead;
+use std::{ops::Bound, pin::Pin};
 use tendermint::PublicKey;
 use tracing::instrument;
 
@@ -79,6 +82,54 @@ pub trait ValidatorDataRead: StateRead {
             .boxed()
     }
 
+    /// Returns the validator's rate data in the given epoch, if it was recorded.
+    ///
+    /// The rate data for each epoch is recorded when the epoch begins.  Rates
+    /// were not always recorded per-epoch, so this may be missing for older
+    /// epochs.  It is read from nonverifiable storage, which keeps the rates
+    /// of epochs pruned from the verifiable state.
+    async fn get_validator_rate_at(
+        &self,
+        identity_key: &IdentityKey,
+        epoch_index: u64,
+    ) -> Result<Option<RateData>> {
+        self.nonverifiable_get_raw(
+            state_key::validators::rate::by_id_in_epoch(identity_key, epoch_index).as_bytes(),
+        )
+        .await?
+        .map(|bytes| RateData::decode(bytes.as_slice()))
+        .transpose()
+    }
+
+    /// Returns the validator's recorded rate data for each epoch from
+    /// `start_epoch_index` through `end_epoch_index` inclusive, in order.
+    ///
+    /// The history is read from the copy of each epoch's rate data kept in
+    /// nonverifiable storage, which can be scanned from the start epoch
+    /// onwards.  Epochs in which no rate was recorded are skipped.
+    async fn validator_rate_history(
+        &self,
+        identity_key: &IdentityKey,
+        start_epoch_index: u64,
+        end_epoch_index: u64,
+    ) -> Result<Vec<RateData>> {
+        let prefix = state_key::validators::rate::by_epoch_prefix(identity_key);
+        let start = state_key::validators::rate::by_id_in_epoch(identity_key, start_epoch_index);
+        let end = match end_epoch_index.checked_add(1) {
+            Some(after_end) => Bound::Excluded(
+                state_key::validators::rate::by_id_in_epoch(identity_key, after_end).into_bytes(),
+            ),
+            None => Bound::Unbounded,
+        };
+        self.nonverifiable_range_raw(
+            Some(prefix.as_bytes()),
+            (Bound::Included(start.into_bytes()), end),
+        )?
+        .map(|entry| entry.and_then(|(_key, bytes)| RateData::decode(bytes.as_slice())))
+        .try_collect()
+        .await
+    }
+
     async fn get_prev_validator_rate(&self, identity_key: &IdentityKey) -> Option<RateData> {
         self.get(&state_key::validators::rate::previous_by_id(identity_key))
             .await
@@ -148,11 +199,15 @@ pub trait ValidatorDataRead: StateRead {
     /// This can be used to check if the undelegation is allowed, or to compute the
     /// epoch at which a delegation pool will be unbonded.
     async fn compute_unbonding_epoch(&self, id: &IdentityKey, starting_epoch: u64) -> Result<u64> {
-        let Some(val_bonding_state) = self.get_validator_bonding_state(id).await else {
-            anyhow::bail!(
+        let val_bonding_state = match self.get_validator_bonding_state(id).await {
+            Some(bonding_state) => bonding_state,
+            // Validators are only pruned once their delegation pool is unbonded,
+            // so undelegations from a pruned validator can be claimed right away.
+            None if self.get_validator_tombstone(id).await?.is_some() => Unbonded,
+            None => anyhow::bail!(
                 "validator bonding state not tracked (validator_identity={})",
                 id
-            )
+            ),
         };
 
         let min_epoch_delay = self.get_stake_params().await?.unbonding_epochs;
@@ -170,6 +225,34 @@ pub trait ValidatorDataRead: StateRead {
         Ok(unbonding_epoch)
     }
 
+    /// Compute the [`DelegationReceipt`] for claiming unbonding tokens from an
+    /// undelegation initiated at `start_epoch_index`, in the current epoch.
+    async fn delegation_receipt(
+        &self,
+        id: &IdentityKey,
+        start_epoch_index: u64,
+    ) -> Result<DelegationReceipt> {
+        let claim_epoch_index = self.get_current_epoch().await?.index;
+        let unbonding_epoch_index = self.compute_unbonding_epoch(id, start_epoch_index).await?;
+        let penalty = self
+            .compounded_penalty_over_range(id, start_epoch_index, unbonding_epoch_index)
+            .await?;
+        let entry_rate = self.get_validator_rate_at(id, start_epoch_index).await?;
+        let exit_rate = self
+            .get_validator_rate_at(id, unbonding_epoch_index)
+            .await?;
+
+        Ok(DelegationReceipt {
+            validator_identity: id.clone(),
+            start_epoch_index,
+            unbonding_epoch_index,
+            claim_epoch_index,
+            entry_rate,
+            exit_rate,
+            penalty,
+        })
+    }
+
     // TODO(erwan): we pull the entire validator definition instead of tracking
     // the consensus key separately.  If we did, not only could we save on deserialization
     // but we could also make this a clean [`DomainFuture`].
@@ -183,6 +266,40 @@ pub trait ValidatorDataRead: StateRead {
             .boxed()
     }
 
+    /// Returns the tombstone left by the validator, if its state was pruned
+    /// after being inactive for too long.
+    async fn get_validator_tombstone(
+        &self,
+        identity_key: &IdentityKey,
+    ) -> Result<Option<validator::Tombstone>> {
+        self.nonverifiable_get_raw(
+            state_key::validators::tombstones::by_id(identity_key).as_bytes(),
+        )
+        .await?
+        .map(|bytes| validator::Tombstone::decode(bytes.as_slice()))
+        .transpose()
+    }
+
+    /// Returns the identity keys of the validators whose names have the given
+    /// [normalized](validator::normalize_name) form.
+    async fn validators_with_normalized_name(&self, normalized: &str) -> Result<Vec<IdentityKey>> {
+        let prefix = state_key::validators::names::normalized_prefix(normalized);
+        self.prefix(&prefix)
+            .map_ok(|(_key, identity_key)| identity_key)
+            .try_collect()
+            .await
+    }
+
+    /// Returns the identity keys of the validators whose names have the given
+    /// [skeleton](validator::name_skeleton).
+    async fn validators_with_name_skeleton(&self, skeleton: &str) -> Result<Vec<IdentityKey>> {
+        let prefix = state_key::validators::names::skeleton_prefix(skeleton);
+        self.prefix(&prefix)
+            .map_ok(|(_key, identity_key)| identity_key)
+            .try_collect()
+            .await
+    }
+
     /// Returns a list of **all** known validators metadata.
     async fn validator_definitions(&self) -> Result<Vec<Validator>> {
         self.prefix(state_key::validators::definitions::prefix())
@@ -190,16 +307,295 @@ pub trait ValidatorDataRead: StateRead {
             .try_collect()
             .await
     }
+
+    /// Assemble a deterministic snapshot of the state of every known validator.
+    async fn validator_set_snapshot(&self) -> Result<validator::SetSnapshot> {
+        let height = self.get_block_height().await?;
+
+        let mut validators = Vec::new();
+        for definition in self.validator_definitions().await? {
+            let identity_key = definition.identity_key;
+            let info = self
+                .get_validator_info(&identity_key)
+                .await?
+                .ok_or_else(|| anyhow::anyhow!("validator {identity_key} has incomplete state"))?;
+            let uptime = self.get_validator_uptime(&identity_key).await?;
+            validators.push(validator::Snapshot { info, uptime });
+        }
+
+        Ok(validator::SetSnapshot::new(height, validators))
+    }
+
+    /// Returns the amount of delegation tokens the validator has bonded to itself.
+    async fn get_validator_self_bond(&self, identity_key: &IdentityKey) -> Result<Amount> {
+        Ok(self
+            .get(&state_key::validators::self_bond::by_id(identity_key))
+            .await?
+            .unwrap_or_default())
+    }
+
+    /// Returns the amount of the validator's delegation tokens which have been
+    /// redelegated to other validators in the current epoch.
+    async fn get_redelegated_amount(&self, identity_key: &IdentityKey) -> Result<Amount> {
+        Ok(self
+            .get(&state_key::validators::redelegated::by_id(identity_key))
+            .await?
+            .unwrap_or_default())
+    }
+
+    /// Returns the amount of the validator's delegation tokens which have been
+    /// issued by redelegations to it from other validators in the current epoch.
+    async fn get_redelegated_to_amount(&self, identity_key: &IdentityKey) -> Result<Amount> {
+        Ok(self
+            .get(&state_key::validators::redelegated_to::by_id(identity_key))
+            .await?
+            .unwrap_or_default())
+    }
+
+    /// Returns the sequence number of the validator's last self-bond release,
+    /// or zero if it has never released any of its self-bond.
+    async fn get_self_bond_release_sequence(&self, identity_key: &IdentityKey) -> Result<u64> {
+        Ok(self
+            .get_proto::<u64>(&state_key::validators::self_bond::release_sequence_by_id(
+                identity_key,
+            ))
+            .await?
+            .unwrap_or(0))
+    }
+
+    /// Returns the validator definition whose funding streams are scheduled to
+    /// take effect in a future epoch, along with the index of that epoch, if any.
+    async fn get_scheduled_funding_streams(
+        &self,
+        identity_key: &IdentityKey,
+    ) -> Result<Option<(u64, Validator)>> {
+        let epoch_index = match self
+            .get_proto::<u64>(
+                &state_key::validators::scheduled_funding_streams::epoch_by_id(identity_key),
+            )
+            .await?
+        {
+            Some(epoch_index) => epoch_index,
+            None => return Ok(None),
+        };
+
+        let validator = self
+            .get(
+                &state_key::validators::scheduled_funding_streams::by_epoch_and_id(
+                    epoch_index,
+                    identity_key,
+                ),
+            )
+            .await?
+            .ok_or_else(|| {
+                anyhow::anyhow!("scheduled funding streams for {identity_key} not found in JMT")
+            })?;
+
+        Ok(Some((epoch_index, validator)))
+    }
+
+    /// Returns the validator definitions whose funding streams are scheduled to
+    /// take effect in the given epoch.
+    async fn scheduled_funding_streams_for_epoch(
+        &self,
+        epoch_index: u64,
+    ) -> Result<Vec<Validator>> {
+        self.prefix(
+            &state_key::validators::scheduled_funding_streams::prefix_for_epoch(epoch_index),
+        )
+        .map_ok(|(_key, validator)| validator)
+        .try_collect()
+        .await
+    }
+
+    /// Explains why the validator is, or is not, in the active set, from its
+    /// state, uptime, penalties, stake, and its ranking by voting power among
+    /// the validators eligible for the active set.
+    ///
+    /// Returns `None` if the validator is unknown.
+    async fn diagnose_validator(
+        &self,
+        identity_key: &IdentityKey,
+    ) -> Result<Option<validator::Diagnosis>> {
+        let params = self.get_stake_params().await?;
+
+        // A pruned validator has no state left, besides its tombstone.
+        let (state, rate_data, pruned) = match (
+            self.get_validator_state(identity_key).await?,
+            self.get_validator_rate(identity_key).await?,
+        ) {
+            (Some(state), Some(rate_data)) => (state, rate_data, false),
+            _ => match self.get_validator_tombstone(identity_key).await? {
+                Some(tombstone) => (tombstone.state, tombstone.rate_data, true),
+                None => return Ok(None),
+            },
+        };
+
+        let uptime = self.get_validator_uptime(identity_key).await?;
+        let penalties = self
+            .prefix::<Penalty>(&state_key::penalty::prefix(identity_key))
+            .try_collect::<Vec<_>>()
+            .await?
+            .into_iter()
+            .map(|(key, penalty)| {
+                let epoch_index = key
+                    .rsplit('/')
+                    .next()
+                    .and_then(|epoch| epoch.parse().ok())
+                    .ok_or_else(|| anyhow::anyhow!("malformed penalty key {key}"))?;
+                Ok((epoch_index, penalty))
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        let pool_value = rate_data.unbonded_amount(
+            self.get_validator_pool_size(identity_key)
+                .await
+                .unwrap_or_default(),
+        );
+        let self_bond_value =
+            rate_data.unbonded_amount(self.get_validator_self_bond(identity_key).await?);
+        let voting_power = self
+            .get_validator_power(identity_key)
+            .await?
+            .unwrap_or_default();
+
+        // Rank the validators the same way the end of the epoch does, to see
+        // where this one would land.
+        let mut eligible = Vec::new();
+        let mut consensus_set = self.consensus_set_stream()?;
+        while let Some(id) = consensus_set.next().await {
+            let id = id?;
+            if !matches!(
+                self.get_validator_state(&id).await?,
+                Some(State::Active | State::Inactive)
+            ) {
+                continue;
+            }
+            let power = self.get_validator_power(&id).await?.unwrap_or_default();
+            let Some(rate) = self.get_validator_rate(&id).await? else {
+                continue;
+            };
+            let self_bond = rate.unbonded_amount(self.get_validator_self_bond(&id).await?);
+            if power > Amount::zero() && self_bond >= params.min_validator_self_delegation {
+                eligible.push((id, power));
+            }
+        }
+        eligible.sort_by(|a, b| b.1.cmp(&a.1));
+
+        let power_rank = eligible
+            .iter()
+            .position(|(id, _)| id == identity_key)
+            .map(|index| index as u64 + 1);
+        let active_set_threshold = usize::try_from(params.active_validator_limit)
+            .ok()
+            .and_then(|limit| limit.checked_sub(1))
+            .and_then(|index| eligible.get(index))
+            .map(|(_, power)| *power);
+
+        let reason = if pruned {
+            validator::InactivityReason::Pruned
+        } else {
+            validator::Diagnosis::reason_for(
+                state,
+                pool_value,
+                params.min_validator_stake,
+                self_bond_value,
+                params.min_validator_self_delegation,
+                power_rank,
+                params.active_validator_limit,
+            )
+        };
+
+        Ok(Some(validator::Diagnosis {
+            identity_key: identity_key.clone(),
+            state,
+            reason,
+            missed_blocks: uptime
+                .as_ref()
+                .map(|uptime| uptime.num_missed_blocks() as u64)
+                .unwrap_or_default(),
+            missed_blocks_maximum: params.missed_blocks_maximum,
+            signed_blocks_window_len: params.signed_blocks_window_len,
+            penalties,
+            pool_value,
+            min_validator_stake: params.min_validator_stake,
+            self_bond_value,
+            min_validator_self_delegation: params.min_validator_self_delegation,
+            voting_power,
+            power_rank,
+            active_validator_limit: params.active_validator_limit,
+            active_set_threshold,
+        }))
+    }
 }
 
 impl<T: StateRead + ?Sized> ValidatorDataRead for T {}
 
 #[async_trait]
 pub trait ValidatorDataWrite: StateWrite {
+    /// Index the validator's name, so that validators with the same or similar
+    /// names can be found without reading every validator definition.
+    fn index_validator_name(&mut self, identity_key: &IdentityKey, name: &str) {
+        self.put(
+            state_key::validators::names::normalized_by_id(
+                &validator::normalize_name(name),
+                identity_key,
+            ),
+            *identity_key,
+        );
+        // Names with nothing to compare aren't flagged as similar to anything.
+        let skeleton = validator::name_skeleton(name);
+        if !skeleton.is_empty() {
+            self.put(
+                state_key::validators::names::skeleton_by_id(&skeleton, identity_key),
+                *identity_key,
+            );
+        }
+    }
+
+    /// Remove the validator's name from the index of names.
+    fn unindex_validator_name(&mut self, identity_key: &IdentityKey, name: &str) {
+        self.delete(state_key::validators::names::normalized_by_id(
+            &validator::normalize_name(name),
+            identity_key,
+        ));
+        self.delete(state_key::validators::names::skeleton_by_id(
+            &validator::name_skeleton(name),
+            identity_key,
+        ));
+    }
+
     fn set_validator_uptime(&mut self, identity_key: &IdentityKey, uptime: Uptime) {
         self.put(state_key::validators::uptime::by_id(identity_key), uptime);
     }
 
+    /// Resize the uptime tracker of every validator to the given signed blocks
+    /// window length, preserving the record of the most recent blocks.
+    ///
+    /// This migrates the existing uptime trackers when the window length is
+    /// changed by governance.
+    #[instrument(skip(self))]
+    async fn resize_validator_uptimes(&mut self, signed_blocks_window_len: usize) -> Result<()> {
+        let uptimes: Vec<(String, Uptime)> = self
+            .prefix(state_key::validators::uptime::prefix())
+            .try_collect()
+            .await?;
+
+        for (key, mut uptime) in uptimes {
+            if uptime.window_len() != signed_blocks_window_len {
+                tracing::debug!(
+                    %key,
+                    old_window_len = uptime.window_len(),
+                    "resizing validator uptime"
+                );
+                uptime.resize(signed_blocks_window_len);
+                self.put(key, uptime);
+            }
+        }
+
+        Ok(())
+    }
+
     fn set_validator_bonding_state(
         &mut self,
         identity_key: &IdentityKey,
@@ -248,18 +644,235 @@ pub trait ValidatorDataWrite: StateWrite {
     #[instrument(skip(self))]
     fn set_validator_rate_data(&mut self, identity_key: &IdentityKey, rate_data: RateData) {
         tracing::debug!("setting validator rate data");
+        // The rate data is also kept in nonverifiable storage, where it can be
+        // scanned by epoch to serve a validator's rate history.
+        let key = state_key::validators::rate::by_id_in_epoch(identity_key, rate_data.epoch_index);
+        self.nonverifiable_put_raw(key.clone().into_bytes(), rate_data.encode_to_vec());
+        self.put(key, rate_data.clone());
         self.put(
             state_key::validators::rate::current_by_id(identity_key),
             rate_data,
         );
     }
 
+    /// Remove the validator's rate data for the epochs before `epoch_index`
+    /// from the verifiable state, keeping it in nonverifiable storage.
+    #[instrument(skip(self))]
+    async fn prune_validator_rate_data(
+        &mut self,
+        identity_key: &IdentityKey,
+        epoch_index: u64,
+    ) -> Result<()> {
+        let prefix = state_key::validators::rate::by_epoch_prefix(identity_key);
+        let end = state_key::validators::rate::by_id_in_epoch(identity_key, epoch_index);
+        // The keys are ordered by epoch, so the ones to prune come first.
+        let pruned: Vec<String> = self
+            .prefix_keys(&prefix)
+            .try_take_while(|key| futures::future::ready(Ok::<_, anyhow::Error>(*key < end)))
+            .try_collect()
+            .await?;
+        for key in pruned {
+            self.delete(key);
+        }
+        Ok(())
+    }
+
     #[instrument(skip(self))]
     /// Persist the previous validator rate data, inclusive of accumulated penalties.
     fn set_prev_validator_rate(&mut self, identity_key: &IdentityKey, rate_data: RateData) {
         let path = state_key::validators::rate::previous_by_id(identity_key);
         self.put(path, rate_data)
     }
+
+    /// Record the amount of delegation tokens the validator has bonded to itself.
+    #[instrument(skip(self))]
+    fn set_validator_self_bond(&mut self, identity_key: &IdentityKey, self_bond: Amount) {
+        let key = state_key::validators::self_bond::by_id(identity_key);
+        if self_bond == Amount::zero() {
+            self.delete(key);
+        } else {
+            self.put(key, self_bond);
+        }
+    }
+
+    /// Record the amount of the validator's delegation tokens which have been
+    /// redelegated to other validators in the current epoch.
+    #[instrument(skip(self))]
+    fn set_redelegated_amount(&mut self, identity_key: &IdentityKey, amount: Amount) {
+        let key = state_key::validators::redelegated::by_id(identity_key);
+        if amount == Amount::zero() {
+            self.delete(key);
+        } else {
+            self.put(key, amount);
+        }
+    }
+
+    /// Record the amount of the validator's delegation tokens which have been
+    /// issued by redelegations to it from other validators in the current epoch.
+    #[instrument(skip(self))]
+    fn set_redelegated_to_amount(&mut self, identity_key: &IdentityKey, amount: Amount) {
+        let key = state_key::validators::redelegated_to::by_id(identity_key);
+        if amount == Amount::zero() {
+            self.delete(key);
+        } else {
+            self.put(key, amount);
+        }
+    }
+
+    /// Record the sequence number of the validator's last self-bond release.
+    #[instrument(skip(self))]
+    fn set_self_bond_release_sequence(&mut self, identity_key: &IdentityKey, sequence: u64) {
+        let key = state_key::validators::self_bond::release_sequence_by_id(identity_key);
+        self.put_proto(key, sequence);
+    }
+
+    /// Schedule the funding streams of the given validator definition to take
+    /// effect at the start of the given epoch.
+    ///
+    /// Any funding streams previously scheduled for the validator must have
+    /// been cancelled first.
+    #[instrument(skip(self, validator), fields(id = %validator.identity_key))]
+    fn schedule_funding_streams(&mut self, validator: Validator, effective_epoch: u64) {
+        tracing::debug!(effective_epoch, "scheduling validator funding streams");
+        let id = validator.identity_key;
+        self.put_proto(
+            state_key::validators::scheduled_funding_streams::epoch_by_id(&id),
+            effective_epoch,
+        );
+        self.put(
+            state_key::validators::scheduled_funding_streams::by_epoch_and_id(effective_epoch, &id),
+            validator,
+        );
+    }
+
+    /// Cancel the funding streams scheduled for the given validator, if any.
+    #[instrument(skip(self))]
+    async fn cancel_scheduled_funding_streams(&mut self, identity_key: &IdentityKey) -> Result<()> {
+        let epoch_key = state_key::validators::scheduled_funding_streams::epoch_by_id(identity_key);
+        if let Some(epoch_index) = self.get_proto::<u64>(&epoch_key).await? {
+            tracing::debug!(
+                epoch_index,
+                "cancelling scheduled validator funding streams"
+            );
+            self.delete(epoch_key);
+            self.delete(
+                state_key::validators::scheduled_funding_streams::by_epoch_and_id(
+                    epoch_index,
+                    identity_key,
+                ),
+            );
+        }
+        Ok(())
+    }
 }
 
 impl<T: StateWrite + ?Sized> ValidatorDataWrite for T {}
+
+#[cfg(test)]
+mod tests {
+    use cnidarium::{StateDelta, TempStorage};
+    use decaf377_rdsa::{SigningKey, SpendAuth};
+    use rand_core::OsRng;
+
+    use super::*;
+
+    fn rate_data(identity_key: IdentityKey, epoch_index: u64) -> RateData {
+        RateData {
+            identity_key,
+            epoch_index,
+            validator_reward_rate: 0u128.into(),
+            validator_exchange_rate: (1_0000_0000u128 + epoch_index as u128).into(),
+        }
+    }
+
+    #[tokio::test]
+    async fn rate_history_covers_only_the_requested_epochs() -> Result<()> {
+        let storage = TempStorage::new().await?;
+        let mut state = StateDelta::new(storage.latest_snapshot());
+
+        let identity_key = IdentityKey((&SigningKey::<SpendAuth>::new(OsRng)).into());
+        let other = IdentityKey((&SigningKey::<SpendAuth>::new(OsRng)).into());
+        for epoch_index in [0, 1, 2, 4, 5] {
+            state.set_validator_rate_data(&identity_key, rate_data(identity_key, epoch_index));
+        }
+        state.set_validator_rate_data(&other, rate_data(other, 3));
+        storage.commit(state).await?;
+
+        let state = storage.latest_snapshot();
+        let epochs = |history: Vec<RateData>| {
+            history
+                .into_iter()
+                .map(|rate_data| rate_data.epoch_index)
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(
+            epochs(state.validator_rate_history(&identity_key, 1, 4).await?),
+            vec![1, 2, 4]
+        );
+        assert_eq!(
+            epochs(state.validator_rate_history(&identity_key, 3, 3).await?),
+            Vec::<u64>::new()
+        );
+        assert_eq!(
+            epochs(state.validator_rate_history(&identity_key, 5, 10).await?),
+            vec![5]
+        );
+        assert_eq!(
+            epochs(
+                state
+                    .validator_rate_history(&identity_key, 0, u64::MAX)
+                    .await?
+            ),
+            vec![0, 1, 2, 4, 5]
+        );
+        assert_eq!(
+            state.validator_rate_history(&other, 0, u64::MAX).await?,
+            vec![rate_data(other, 3)]
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn pruned_rates_are_kept_out_of_the_verifiable_state() -> Result<()> {
+        let storage = TempStorage::new().await?;
+        let mut state = StateDelta::new(storage.latest_snapshot());
+
+        let identity_key = IdentityKey((&SigningKey::<SpendAuth>::new(OsRng)).into());
+        for epoch_index in 0..6 {
+            state.set_validator_rate_data(&identity_key, rate_data(identity_key, epoch_index));
+        }
+        state.prune_validator_rate_data(&identity_key, 4).await?;
+        storage.commit(state).await?;
+
+        let state = storage.latest_snapshot();
+        let verifiable: Vec<String> = state
+            .prefix_keys(&state_key::validators::rate::by_epoch_prefix(&identity_key))
+            .try_collect()
+            .await?;
+        assert_eq!(
+            verifiable,
+            [4, 5]
+                .map(|epoch_index| state_key::validators::rate::by_id_in_epoch(
+                    &identity_key,
+                    epoch_index
+                ))
+                .to_vec()
+        );
+
+        // The pruned rates can still be looked up, and are still part of the history.
+        assert_eq!(
+            state.get_validator_rate_at(&identity_key, 0).await?,
+            Some(rate_data(identity_key, 0))
+        );
+        assert_eq!(
+            state
+                .validator_rate_history(&identity_key, 0, u64::MAX)
+                .await?
+                .len(),
+            6
+        );
+
+        Ok(())
+    }
+}
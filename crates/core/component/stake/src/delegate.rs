@@ -1,6 +1,8 @@
+use anyhow::Context;
+use decaf377_rdsa::{Signature, SpendAuth};
 use penumbra_asset::{Balance, Value, STAKING_TOKEN_ASSET_ID};
 use penumbra_num::Amount;
-use penumbra_proto::{penumbra::core::component::stake::v1 as pb, DomainType};
+use penumbra_proto::{penumbra::core::component::stake::v1 as pb, DomainType, Message as _};
 use penumbra_txhash::{EffectHash, EffectingData};
 use serde::{Deserialize, Serialize};
 
@@ -24,8 +26,21 @@ pub struct Delegate {
     /// (and should be checked in transaction validation!), but including it allows
     /// stateless verification that the transaction is internally consistent.
     pub delegation_amount: Amount,
+    /// If set, this delegation adds to the validator's self-bond, authorized
+    /// by this signature by the validator's identity key over the
+    /// [`self_bond_auth_bytes`](Delegate::self_bond_auth_bytes) of the delegation.
+    ///
+    /// Self-bonded delegations don't produce delegation tokens: the delegation
+    /// is locked in the validator's self-bond instead, and can only be
+    /// undelegated with a [`SelfBondRelease`](crate::SelfBondRelease) signed
+    /// by the validator's identity key.
+    pub self_bond_auth_sig: Option<Signature<SpendAuth>>,
 }
 
+/// The domain separator for the bytes signed to authorize a self-bond, which
+/// would otherwise be the encoding of an ordinary delegation.
+const SELF_BOND_DOMAIN_SEP: &[u8] = b"penumbra_self_bond";
+
 impl EffectingData for Delegate {
     fn effect_hash(&self) -> EffectHash {
         // For delegations, the entire action is considered effecting data.
@@ -35,12 +50,18 @@ impl EffectingData for Delegate {
 
 impl Delegate {
     /// Return the balance resulting from issuing delegation tokens from staking tokens.
+    ///
+    /// Self-bonded delegations only consume the staking tokens.
     pub fn balance(&self) -> Balance {
         let stake = Balance::from(Value {
             amount: self.unbonded_amount,
             asset_id: STAKING_TOKEN_ASSET_ID.clone(),
         });
 
+        if self.is_self_bond() {
+            return -stake;
+        }
+
         let delegation = Balance::from(Value {
             amount: self.delegation_amount,
             asset_id: DelegationToken::new(self.validator_identity.clone()).id(),
@@ -49,6 +70,34 @@ impl Delegate {
         // We produce the delegation tokens and consume the staking tokens.
         delegation - stake
     }
+
+    /// Whether this delegation adds to the validator's self-bond.
+    pub fn is_self_bond(&self) -> bool {
+        self.self_bond_auth_sig.is_some()
+    }
+
+    /// The bytes signed by the validator's identity key to add this delegation
+    /// to its self-bond.
+    ///
+    /// This is the encoding of the delegation, with the signature left empty,
+    /// after a domain separator.
+    pub fn self_bond_auth_bytes(&self) -> Vec<u8> {
+        let mut proto = pb::Delegate::from(self.clone());
+        proto.self_bond_auth_sig = Vec::new();
+        [SELF_BOND_DOMAIN_SEP, &proto.encode_to_vec()].concat()
+    }
+
+    /// Check that the delegation, if it adds to the validator's self-bond, is
+    /// signed by the validator's identity key.
+    pub fn verify_self_bond(&self) -> anyhow::Result<()> {
+        if let Some(auth_sig) = &self.self_bond_auth_sig {
+            self.validator_identity
+                .0
+                .verify(&self.self_bond_auth_bytes(), auth_sig)
+                .context("self-bond signature failed to verify")?;
+        }
+        Ok(())
+    }
 }
 
 impl DomainType for Delegate {
@@ -62,6 +111,10 @@ impl From<Delegate> for pb::Delegate {
             epoch_index: d.epoch_index,
             unbonded_amount: Some(d.unbonded_amount.into()),
             delegation_amount: Some(d.delegation_amount.into()),
+            self_bond_auth_sig: d
+                .self_bond_auth_sig
+                .map(|sig| sig.to_bytes().to_vec())
+                .unwrap_or_default(),
         }
     }
 }
@@ -83,6 +136,11 @@ impl TryFrom<pb::Delegate> for Delegate {
                 .delegation_amount
                 .ok_or_else(|| anyhow::anyhow!("missing delegation amount"))?
                 .try_into()?,
+            self_bond_auth_sig: if d.self_bond_auth_sig.is_empty() {
+                None
+            } else {
+                Some(d.self_bond_auth_sig.as_slice().try_into()?)
+            },
         })
     }
 }
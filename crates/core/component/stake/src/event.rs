@@ -1,4 +1,8 @@
-use crate::{Delegate, Undelegate};
+use crate::{
+    funding_stream::Recipient, validator::Tombstone, validator::Validator, Delegate,
+    DelegationReceipt, IdentityKey, Penalty, Redelegate, Undelegate,
+};
+use penumbra_proto::penumbra::core::component::stake::v1 as pb;
 use tendermint::abci::{Event, EventAttributeIndexExt};
 
 pub fn delegate(delegate: &Delegate) -> Event {
@@ -20,3 +24,245 @@ pub fn undelegate(undelegate: &Undelegate) -> Event {
         ],
     )
 }
+
+pub fn redelegate(redelegate: &Redelegate) -> Event {
+    Event::new(
+        "action_redelegate",
+        [
+            (
+                "from_validator",
+                redelegate.from_validator_identity.to_string(),
+            )
+                .index(),
+            ("to_validator", redelegate.to_validator_identity.to_string()).index(),
+            ("amount", redelegate.unbonded_amount.to_string()).no_index(),
+        ],
+    )
+}
+
+pub fn undelegate_claim_receipt(receipt: &DelegationReceipt) -> Event {
+    let exchange_rate = |rate: &Option<crate::rate::RateData>| {
+        rate.as_ref()
+            .map(|rate| rate.validator_exchange_rate.to_string())
+            .unwrap_or_default()
+    };
+    Event::new(
+        "action_undelegate_claim_receipt",
+        [
+            ("validator", receipt.validator_identity.to_string()).index(),
+            ("start_epoch", receipt.start_epoch_index.to_string()).index(),
+            ("unbonding_epoch", receipt.unbonding_epoch_index.to_string()).no_index(),
+            ("claim_epoch", receipt.claim_epoch_index.to_string()).no_index(),
+            ("entry_exchange_rate", exchange_rate(&receipt.entry_rate)).no_index(),
+            ("exit_exchange_rate", exchange_rate(&receipt.exit_rate)).no_index(),
+            ("penalty_kept_rate", receipt.penalty.kept_rate().to_string()).no_index(),
+        ],
+    )
+}
+
+pub fn funding_streams_scheduled(validator: &Validator, effective_epoch: u64) -> Event {
+    Event::new(
+        "validator_funding_streams_scheduled",
+        [
+            ("validator", validator.identity_key.to_string()).index(),
+            ("effective_epoch", effective_epoch.to_string()).index(),
+            ("commission_bps", commission_bps(validator).to_string()).no_index(),
+        ],
+    )
+}
+
+pub fn funding_streams_applied(validator: &Validator, epoch_index: u64) -> Event {
+    Event::new(
+        "validator_funding_streams_applied",
+        [
+            ("validator", validator.identity_key.to_string()).index(),
+            ("epoch", epoch_index.to_string()).index(),
+            ("commission_bps", commission_bps(validator).to_string()).no_index(),
+        ],
+    )
+}
+
+/// Events recording each field of a validator's definition which differs between its old and new
+/// definitions, with the old and new values of the field.
+///
+/// The fields are the ones which describe the validator: its name, website, description, keys,
+/// funding streams, and whether it is enabled.
+pub fn validator_definition_changes(old: &Validator, new: &Validator) -> Vec<Event> {
+    let funding_streams = |validator: &Validator| {
+        validator
+            .funding_streams
+            .iter()
+            .map(|fs| {
+                let recipient = match fs.recipient() {
+                    Recipient::Address(address) => address.to_string(),
+                    Recipient::CommunityPool => "community_pool".to_string(),
+                };
+                let compounding = if fs.auto_compound() { ":compound" } else { "" };
+                format!("{}:{}bps{}", recipient, fs.rate_bps(), compounding)
+            })
+            .collect::<Vec<_>>()
+            .join(",")
+    };
+
+    let fields = [
+        ("name", old.name.clone(), new.name.clone()),
+        ("website", old.website.clone(), new.website.clone()),
+        (
+            "description",
+            old.description.clone(),
+            new.description.clone(),
+        ),
+        (
+            "governance_key",
+            old.governance_key.to_string(),
+            new.governance_key.to_string(),
+        ),
+        (
+            "consensus_key",
+            old.consensus_key.to_hex(),
+            new.consensus_key.to_hex(),
+        ),
+        (
+            "funding_streams",
+            funding_streams(old),
+            funding_streams(new),
+        ),
+        ("enabled", old.enabled.to_string(), new.enabled.to_string()),
+    ];
+
+    fields
+        .into_iter()
+        .filter(|(_, old_value, new_value)| old_value != new_value)
+        .map(|(field, old_value, new_value)| {
+            Event::new(
+                "validator_definition_changed",
+                [
+                    ("validator", new.identity_key.to_string()).index(),
+                    ("field", field.to_string()).index(),
+                    ("sequence_number", new.sequence_number.to_string()).no_index(),
+                    ("old_value", old_value).no_index(),
+                    ("new_value", new_value).no_index(),
+                ],
+            )
+        })
+        .collect()
+}
+
+/// An event flagging a validator whose name is easily mistaken for another validator's, so that
+/// delegators can be warned of possible impersonation.
+pub fn validator_name_similar(validator: &Validator, other: &Validator) -> Event {
+    Event::new(
+        "validator_name_similar",
+        [
+            ("validator", validator.identity_key.to_string()).index(),
+            ("similar_to", other.identity_key.to_string()).index(),
+            ("name", validator.name.clone()).no_index(),
+            ("similar_name", other.name.clone()).no_index(),
+        ],
+    )
+}
+
+pub fn validator_pruned(tombstone: &Tombstone) -> Event {
+    Event::new(
+        "validator_pruned",
+        [
+            ("validator", tombstone.identity_key.to_string()).index(),
+            ("epoch", tombstone.pruned_epoch_index.to_string()).index(),
+            (
+                "inactive_since_epoch",
+                tombstone.inactive_since_epoch_index.to_string(),
+            )
+                .no_index(),
+        ],
+    )
+}
+
+pub fn slashed(
+    identity_key: &IdentityKey,
+    misbehavior_height: u64,
+    penalty: Penalty,
+    evidence_hash: Option<[u8; 32]>,
+) -> pb::EventSlashed {
+    pb::EventSlashed {
+        identity_key: Some(identity_key.clone().into()),
+        misbehavior_height,
+        penalty: Some(penalty.into()),
+        evidence_hash: evidence_hash.map(|hash| hash.to_vec()).unwrap_or_default(),
+    }
+}
+
+/// The validator's total commission, the sum of the rates of its funding streams.
+fn commission_bps(validator: &Validator) -> u64 {
+    validator
+        .funding_streams
+        .iter()
+        .map(|fs| fs.rate_bps() as u64)
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use decaf377_rdsa::{SigningKey, SpendAuth};
+    use rand_core::OsRng;
+
+    use super::*;
+    use crate::GovernanceKey;
+
+    fn validator() -> Validator {
+        let signing_key = SigningKey::<SpendAuth>::new(OsRng);
+        let consensus_key = ed25519_consensus::SigningKey::new(OsRng).verification_key();
+        Validator {
+            identity_key: IdentityKey((&signing_key).into()),
+            governance_key: GovernanceKey((&signing_key).into()),
+            consensus_key: tendermint::PublicKey::from_raw_ed25519(consensus_key.as_bytes())
+                .expect("consensus key is valid"),
+            name: "alice".to_string(),
+            website: String::new(),
+            description: String::new(),
+            enabled: true,
+            funding_streams: Default::default(),
+            funding_streams_effective_epoch: None,
+            sequence_number: 0,
+        }
+    }
+
+    /// The value of the event's attribute with the given key.
+    fn attribute<'a>(event: &'a Event, key: &str) -> &'a str {
+        &event
+            .attributes
+            .iter()
+            .find(|attr| attr.key == key)
+            .expect("event has the attribute")
+            .value
+    }
+
+    #[test]
+    fn only_changed_fields_are_recorded() {
+        let old = validator();
+        assert!(validator_definition_changes(&old, &old).is_empty());
+
+        let new = Validator {
+            name: "bob".to_string(),
+            enabled: false,
+            sequence_number: 1,
+            ..old.clone()
+        };
+        let events = validator_definition_changes(&old, &new);
+        let fields: Vec<_> = events
+            .iter()
+            .map(|event| attribute(event, "field"))
+            .collect();
+        assert_eq!(fields, ["name", "enabled"]);
+
+        let name = &events[0];
+        assert_eq!(name.kind, "validator_definition_changed");
+        assert_eq!(attribute(name, "validator"), new.identity_key.to_string());
+        assert_eq!(attribute(name, "sequence_number"), "1");
+        assert_eq!(attribute(name, "old_value"), "alice");
+        assert_eq!(attribute(name, "new_value"), "bob");
+
+        let enabled = &events[1];
+        assert_eq!(attribute(enabled, "old_value"), "true");
+        assert_eq!(attribute(enabled, "new_value"), "false");
+    }
+}
@@ -1,4 +1,5 @@
-use crate::BPS_SQUARED_SCALING_FACTOR;
+use crate::{rate::RateData, DelegationToken, BPS_SQUARED_SCALING_FACTOR};
+use penumbra_asset::{Value, STAKING_TOKEN_ASSET_ID};
 use penumbra_keys::Address;
 use penumbra_num::{fixpoint::U128x128, Amount};
 use penumbra_proto::{penumbra::core::component::stake::v1 as pb, DomainType};
@@ -16,11 +17,17 @@ pub enum FundingStream {
         /// The portion (in terms of [basis points](https://en.wikipedia.org/wiki/Basis_point)) of the
         /// validator's total staking reward that goes to this funding stream.
         rate_bps: u16,
+
+        /// Whether the rewards for this funding stream are re-delegated to the validator.
+        auto_compound: bool,
     },
     ToCommunityPool {
         /// The portion (in terms of [basis points](https://en.wikipedia.org/wiki/Basis_point)) of the
         /// validator's total staking reward that goes to this funding stream.
         rate_bps: u16,
+
+        /// Whether the rewards for this funding stream are re-delegated to the validator.
+        auto_compound: bool,
     },
 }
 
@@ -35,7 +42,17 @@ impl FundingStream {
     pub fn rate_bps(&self) -> u16 {
         match self {
             FundingStream::ToAddress { rate_bps, .. } => *rate_bps,
-            FundingStream::ToCommunityPool { rate_bps } => *rate_bps,
+            FundingStream::ToCommunityPool { rate_bps, .. } => *rate_bps,
+        }
+    }
+
+    /// Whether the rewards for this funding stream are automatically re-delegated to the
+    /// validator's delegation pool at each epoch boundary, so that the recipient receives
+    /// delegation tokens rather than staking tokens.
+    pub fn auto_compound(&self) -> bool {
+        match self {
+            FundingStream::ToAddress { auto_compound, .. } => *auto_compound,
+            FundingStream::ToCommunityPool { auto_compound, .. } => *auto_compound,
         }
     }
 
@@ -102,6 +119,24 @@ impl FundingStream {
             .try_into()
             .expect("does not overflow")
     }
+
+    /// The value paid out for a reward of `reward_amount` staking tokens to this funding stream.
+    ///
+    /// If the stream compounds, and the validator's `rate_data` for the upcoming epoch is given
+    /// because it can be delegated to, the reward is paid as the delegation tokens it would buy at
+    /// that rate. Otherwise, it is paid as staking tokens.
+    pub fn reward_value(&self, reward_amount: Amount, rate_data: Option<&RateData>) -> Value {
+        match rate_data {
+            Some(rate_data) if self.auto_compound() => Value {
+                amount: rate_data.delegation_amount(reward_amount),
+                asset_id: DelegationToken::from(&rate_data.identity_key).id(),
+            },
+            _ => Value {
+                amount: reward_amount,
+                asset_id: *STAKING_TOKEN_ASSET_ID,
+            },
+        }
+    }
 }
 
 impl DomainType for FundingStream {
@@ -112,19 +147,26 @@ impl From<FundingStream> for pb::FundingStream {
     fn from(fs: FundingStream) -> Self {
         pb::FundingStream {
             recipient: match fs {
-                FundingStream::ToAddress { address, rate_bps } => Some(
-                    pb::funding_stream::Recipient::ToAddress(pb::funding_stream::ToAddress {
+                FundingStream::ToAddress {
+                    address,
+                    rate_bps,
+                    auto_compound,
+                } => Some(pb::funding_stream::Recipient::ToAddress(
+                    pb::funding_stream::ToAddress {
                         address: address.to_string(),
                         rate_bps: rate_bps.into(),
-                    }),
-                ),
-                FundingStream::ToCommunityPool { rate_bps } => {
-                    Some(pb::funding_stream::Recipient::ToCommunityPool(
-                        pb::funding_stream::ToCommunityPool {
-                            rate_bps: rate_bps.into(),
-                        },
-                    ))
-                }
+                        auto_compound,
+                    },
+                )),
+                FundingStream::ToCommunityPool {
+                    rate_bps,
+                    auto_compound,
+                } => Some(pb::funding_stream::Recipient::ToCommunityPool(
+                    pb::funding_stream::ToCommunityPool {
+                        rate_bps: rate_bps.into(),
+                        auto_compound,
+                    },
+                )),
             },
         }
     }
@@ -150,7 +192,11 @@ impl TryFrom<pb::FundingStream> for FundingStream {
                 if rate_bps > 10_000 {
                     anyhow::bail!("funding stream rate exceeds 100% (10,000bps)");
                 }
-                Ok(FundingStream::ToAddress { address, rate_bps })
+                Ok(FundingStream::ToAddress {
+                    address,
+                    rate_bps,
+                    auto_compound: to_address.auto_compound,
+                })
             }
             pb::funding_stream::Recipient::ToCommunityPool(to_community_pool) => {
                 let rate_bps = to_community_pool
@@ -160,7 +206,10 @@ impl TryFrom<pb::FundingStream> for FundingStream {
                 if rate_bps > 10_000 {
                     anyhow::bail!("funding stream rate exceeds 100% (10,000bps)");
                 }
-                Ok(FundingStream::ToCommunityPool { rate_bps })
+                Ok(FundingStream::ToCommunityPool {
+                    rate_bps,
+                    auto_compound: to_community_pool.auto_compound,
+                })
             }
         }
     }
@@ -240,3 +289,70 @@ impl<'a> IntoIterator for &'a FundingStreams {
         (self.funding_streams).iter()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use decaf377_rdsa::{SigningKey, SpendAuth};
+    use rand_core::OsRng;
+
+    use super::*;
+    use crate::{validator::FundingStreamToml, IdentityKey};
+
+    #[test]
+    fn only_compounding_streams_are_paid_in_delegation_tokens() {
+        let identity_key = IdentityKey((&SigningKey::<SpendAuth>::new(OsRng)).into());
+        // Each delegation token is worth two staking tokens.
+        let rate_data = RateData {
+            identity_key,
+            epoch_index: 1,
+            validator_reward_rate: 0u128.into(),
+            validator_exchange_rate: (2 * 1_0000_0000u128).into(),
+        };
+        let address = *penumbra_keys::test_keys::ADDRESS_0;
+        let compounding = FundingStream::ToAddress {
+            address,
+            rate_bps: 100,
+            auto_compound: true,
+        };
+        let paid_out = FundingStream::ToAddress {
+            address,
+            rate_bps: 100,
+            auto_compound: false,
+        };
+        let to_community_pool = FundingStream::ToCommunityPool {
+            rate_bps: 100,
+            auto_compound: true,
+        };
+
+        let delegation = Value {
+            amount: 50u128.into(),
+            asset_id: DelegationToken::from(&identity_key).id(),
+        };
+        let staking = Value {
+            amount: 100u128.into(),
+            asset_id: *STAKING_TOKEN_ASSET_ID,
+        };
+        assert_eq!(
+            compounding.reward_value(100u128.into(), Some(&rate_data)),
+            delegation
+        );
+        assert_eq!(
+            to_community_pool.reward_value(100u128.into(), Some(&rate_data)),
+            delegation
+        );
+        assert_eq!(
+            paid_out.reward_value(100u128.into(), Some(&rate_data)),
+            staking
+        );
+        // Rewards can't be re-delegated to a validator which can't be delegated to.
+        assert_eq!(compounding.reward_value(100u128.into(), None), staking);
+
+        // The preference of each stream survives encoding.
+        for stream in [compounding, paid_out, to_community_pool] {
+            let decoded = FundingStream::decode(stream.encode_to_vec().as_slice())
+                .expect("funding stream decodes");
+            assert_eq!(decoded, stream);
+            assert_eq!(FundingStream::from(FundingStreamToml::from(stream)), stream);
+        }
+    }
+}
@@ -2,7 +2,10 @@ use anyhow::Context;
 use penumbra_proto::{penumbra::core::component::stake::v1 as pb, DomainType};
 use serde::{Deserialize, Serialize};
 
-use crate::params::StakeParameters;
+use crate::{params::StakeParameters, validator};
+
+mod builder;
+pub use builder::{Builder, DelegationAllocation, ValidatorAllocation};
 
 #[derive(Deserialize, Serialize, Debug, Clone, Default)]
 #[serde(try_from = "pb::GenesisContent", into = "pb::GenesisContent")]
@@ -11,6 +14,8 @@ pub struct Content {
     pub stake_params: StakeParameters,
     /// The initial validator set.
     pub validators: Vec<pb::Validator>,
+    /// A snapshot of validators to import with their recorded state, if any.
+    pub validator_snapshot: Option<validator::SetSnapshot>,
 }
 
 impl DomainType for Content {
@@ -22,6 +27,7 @@ impl From<Content> for pb::GenesisContent {
         pb::GenesisContent {
             stake_params: Some(value.stake_params.into()),
             validators: value.validators.into_iter().map(Into::into).collect(),
+            validator_snapshot: value.validator_snapshot.map(Into::into),
         }
     }
 }
@@ -40,6 +46,7 @@ impl TryFrom<pb::GenesisContent> for Content {
                 .into_iter()
                 .map(TryInto::try_into)
                 .collect::<Result<_, _>>()?,
+            validator_snapshot: msg.validator_snapshot.map(TryInto::try_into).transpose()?,
         })
     }
 }
@@ -0,0 +1,363 @@
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    io::Read,
+};
+
+use anyhow::Context;
+use base64::prelude::*;
+use penumbra_keys::Address;
+use penumbra_num::Amount;
+use penumbra_shielded_pool::genesis::Allocation;
+use serde::Deserialize;
+use serde_with::{serde_as, DisplayFromStr, PickFirst};
+
+use crate::{
+    params::StakeParameters,
+    validator::{FundingStreamToml, Validator},
+    DelegationToken, FundingStreams, GovernanceKey, IdentityKey,
+};
+
+use super::Content;
+
+/// A genesis validator, as listed in an allocation file.
+#[serde_as]
+#[derive(Debug, Clone, Deserialize)]
+pub struct ValidatorAllocation {
+    /// The validator's identity verification key.
+    #[serde_as(as = "DisplayFromStr")]
+    pub identity_key: IdentityKey,
+    /// The validator's governance verification key, which defaults to the
+    /// identity key.
+    #[serde_as(as = "Option<DisplayFromStr>")]
+    #[serde(default)]
+    pub governance_key: Option<GovernanceKey>,
+    /// The validator's Ed25519 consensus key, as base64, in the same encoding
+    /// as CometBFT's `priv_validator_key.json`.
+    pub consensus_key: String,
+    pub name: String,
+    #[serde(default)]
+    pub website: String,
+    #[serde(default)]
+    pub description: String,
+    #[serde(default)]
+    pub funding_streams: Vec<FundingStreamToml>,
+}
+
+/// A genesis delegation, as listed in an allocation file.
+///
+/// The amount is of the validator's delegation token, in base units; at
+/// genesis, this is the same as the amount of staking token delegated.
+#[serde_as]
+#[derive(Debug, Clone, Deserialize)]
+pub struct DelegationAllocation {
+    #[serde_as(as = "DisplayFromStr")]
+    pub validator: IdentityKey,
+    #[serde_as(as = "PickFirst<(_, DisplayFromStr)>")]
+    pub amount: u128,
+    #[serde_as(as = "DisplayFromStr")]
+    pub address: Address,
+}
+
+/// Builds the staking component's genesis validators, and the delegation
+/// token allocations backing their initial voting power, from simple
+/// allocation files.
+///
+/// Validators are read from a JSON array of [`ValidatorAllocation`]s, and
+/// delegations from a JSON array of [`DelegationAllocation`]s or, with the
+/// `genesis-csv` feature, from a CSV file with the columns
+///
+///   validator,amount,address
+///
+/// Everything is checked when the genesis content is [built](Builder::build),
+/// so that a mistake is reported before a network is started with it.
+#[derive(Debug, Clone)]
+pub struct Builder {
+    stake_params: StakeParameters,
+    validators: Vec<ValidatorAllocation>,
+    delegations: Vec<DelegationAllocation>,
+    max_voting_power_bps: Option<u64>,
+}
+
+impl Builder {
+    /// Start building genesis content with the given staking parameters.
+    pub fn new(stake_params: StakeParameters) -> Self {
+        Self {
+            stake_params,
+            validators: Vec::new(),
+            delegations: Vec::new(),
+            max_voting_power_bps: None,
+        }
+    }
+
+    /// Cap the share of the total genesis delegations which any one validator
+    /// may hold, in basis points.
+    pub fn max_voting_power_bps(mut self, bps: u64) -> Self {
+        self.max_voting_power_bps = Some(bps);
+        self
+    }
+
+    pub fn add_validator(&mut self, validator: ValidatorAllocation) -> &mut Self {
+        self.validators.push(validator);
+        self
+    }
+
+    pub fn add_delegation(&mut self, delegation: DelegationAllocation) -> &mut Self {
+        self.delegations.push(delegation);
+        self
+    }
+
+    /// Read validators from a reader emitting a JSON array.
+    pub fn read_validators_json(&mut self, input: impl Read) -> anyhow::Result<&mut Self> {
+        let validators: Vec<ValidatorAllocation> =
+            serde_json::from_reader(input).context("could not parse validators JSON")?;
+        self.validators.extend(validators);
+        Ok(self)
+    }
+
+    /// Read delegations from a reader emitting a JSON array.
+    pub fn read_delegations_json(&mut self, input: impl Read) -> anyhow::Result<&mut Self> {
+        let delegations: Vec<DelegationAllocation> =
+            serde_json::from_reader(input).context("could not parse delegations JSON")?;
+        self.delegations.extend(delegations);
+        Ok(self)
+    }
+
+    /// Read delegations from a reader emitting CSV, with a header row.
+    #[cfg(feature = "genesis-csv")]
+    pub fn read_delegations_csv(&mut self, input: impl Read) -> anyhow::Result<&mut Self> {
+        let mut reader = csv::Reader::from_reader(input);
+        for (line, record) in reader.deserialize().enumerate() {
+            let delegation: DelegationAllocation = record
+                .with_context(|| format!("invalid delegation in entry {line} of CSV input"))?;
+            self.delegations.push(delegation);
+        }
+        Ok(self)
+    }
+
+    /// Check the validators and delegations, and build the staking
+    /// component's genesis content, along with the delegation token
+    /// allocations to add to the shielded pool's genesis content.
+    pub fn build(&self) -> anyhow::Result<(Content, Vec<Allocation>)> {
+        anyhow::ensure!(
+            !self.validators.is_empty(),
+            "at least one genesis validator is required"
+        );
+        anyhow::ensure!(
+            self.validators.len() as u64 <= self.stake_params.active_validator_limit,
+            "{} genesis validators were given, but the active validator limit is {}",
+            self.validators.len(),
+            self.stake_params.active_validator_limit
+        );
+
+        let mut validators = Vec::with_capacity(self.validators.len());
+        let mut consensus_keys = BTreeSet::new();
+        let mut delegation_pools = BTreeMap::<IdentityKey, Amount>::new();
+        for allocation in &self.validators {
+            let validator = allocation
+                .to_validator()
+                .with_context(|| format!("invalid genesis validator {}", allocation.name))?;
+            anyhow::ensure!(
+                delegation_pools
+                    .insert(validator.identity_key, Amount::zero())
+                    .is_none(),
+                "validator {} is listed more than once",
+                validator.identity_key
+            );
+            // CometBFT cannot handle two validators with the same consensus key.
+            anyhow::ensure!(
+                consensus_keys.insert(validator.consensus_key.to_bytes()),
+                "consensus key of validator {} is already used by another validator",
+                validator.identity_key
+            );
+            validators.push(validator);
+        }
+
+        let mut allocations = Vec::with_capacity(self.delegations.len());
+        for delegation in &self.delegations {
+            let pool = delegation_pools
+                .get_mut(&delegation.validator)
+                .ok_or_else(|| {
+                    anyhow::anyhow!(
+                        "delegation to {} is not to a genesis validator",
+                        delegation.validator
+                    )
+                })?;
+            anyhow::ensure!(
+                delegation.amount > 0,
+                "delegation to {} from {} is empty",
+                delegation.validator,
+                delegation.address
+            );
+            let amount = Amount::from(delegation.amount);
+            *pool = pool
+                .checked_add(&amount)
+                .context("delegations to a single validator overflow")?;
+            allocations.push(Allocation {
+                raw_amount: amount,
+                raw_denom: DelegationToken::from(&delegation.validator)
+                    .denom()
+                    .to_string(),
+                address: delegation.address,
+            });
+        }
+
+        // Genesis validators start active, so each must be backed by enough
+        // stake to have voting power.
+        let mut total = Amount::zero();
+        for (identity_key, pool) in &delegation_pools {
+            anyhow::ensure!(
+                *pool > Amount::zero() && *pool >= self.stake_params.min_validator_stake,
+                "validator {identity_key} is delegated {pool}, less than the minimum stake of {}",
+                self.stake_params.min_validator_stake
+            );
+            total = total
+                .checked_add(pool)
+                .context("total genesis delegations overflow")?;
+        }
+        if let Some(max_bps) = self.max_voting_power_bps {
+            for (identity_key, pool) in &delegation_pools {
+                anyhow::ensure!(
+                    pool.value().saturating_mul(10_000)
+                        <= total.value().saturating_mul(max_bps.into()),
+                    "validator {identity_key} is delegated {pool} of {total} in total, more than \
+                     the cap of {max_bps}bps"
+                );
+            }
+        }
+
+        let content = Content {
+            stake_params: self.stake_params.clone(),
+            validators: validators.into_iter().map(Into::into).collect(),
+            validator_snapshot: None,
+        };
+        Ok((content, allocations))
+    }
+}
+
+impl ValidatorAllocation {
+    /// Check the validator's fields, as its definition would be checked if it
+    /// were uploaded after genesis, and convert it into a [`Validator`].
+    fn to_validator(&self) -> anyhow::Result<Validator> {
+        anyhow::ensure!(
+            self.website.len() <= 70,
+            "validator website field must be less than 70 characters"
+        );
+        anyhow::ensure!(
+            self.name.len() <= 140,
+            "validator name must be less than 140 characters"
+        );
+        anyhow::ensure!(
+            self.description.len() <= 280,
+            "validator description must be less than 280 characters"
+        );
+
+        let consensus_key = BASE64_STANDARD
+            .decode(&self.consensus_key)
+            .context("consensus key is not valid base64")?;
+        let consensus_key = tendermint::PublicKey::from_raw_ed25519(&consensus_key)
+            .context("consensus key is not a valid Ed25519 public key")?;
+
+        Ok(Validator {
+            identity_key: self.identity_key,
+            governance_key: self
+                .governance_key
+                .unwrap_or(GovernanceKey(self.identity_key.0)),
+            consensus_key,
+            name: self.name.clone(),
+            website: self.website.clone(),
+            description: self.description.clone(),
+            enabled: true,
+            funding_streams: FundingStreams::try_from(
+                self.funding_streams
+                    .iter()
+                    .cloned()
+                    .map(Into::into)
+                    .collect::<Vec<_>>(),
+            )?,
+            funding_streams_effective_epoch: None,
+            auto_compound: false,
+            sequence_number: 0,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use decaf377_rdsa::{SigningKey, SpendAuth};
+    use penumbra_keys::test_keys;
+    use rand_core::OsRng;
+
+    use super::*;
+
+    fn validator_json(identity_key: &IdentityKey, consensus_key: &[u8]) -> String {
+        format!(
+            r#"[{{"identity_key":"{identity_key}","consensus_key":"{}","name":"test"}}]"#,
+            BASE64_STANDARD.encode(consensus_key)
+        )
+    }
+
+    #[test]
+    fn builds_validators_and_delegations() -> anyhow::Result<()> {
+        let identity_key = IdentityKey(SigningKey::<SpendAuth>::new(OsRng).into());
+        let consensus_key = ed25519_consensus::SigningKey::new(OsRng).verification_key();
+        let address = test_keys::ADDRESS_0.to_string();
+
+        let mut builder = Builder::new(StakeParameters::default());
+        builder
+            .read_validators_json(
+                validator_json(&identity_key, consensus_key.as_bytes()).as_bytes(),
+            )?
+            .read_delegations_json(
+                format!(
+                    r#"[{{"validator":"{identity_key}","amount":"1000000","address":"{address}"}}]"#
+                )
+                .as_bytes(),
+            )?;
+        let (content, allocations) = builder.build()?;
+
+        assert_eq!(content.validators.len(), 1);
+        assert_eq!(allocations.len(), 1);
+        assert_eq!(
+            allocations[0].raw_denom,
+            DelegationToken::from(&identity_key).denom().to_string()
+        );
+
+        // A validator which nobody delegates to would start without voting power.
+        let mut builder = Builder::new(StakeParameters::default());
+        builder.read_validators_json(
+            validator_json(&identity_key, consensus_key.as_bytes()).as_bytes(),
+        )?;
+        assert!(builder.build().is_err());
+
+        // The consensus key must be an Ed25519 key.
+        let mut builder = Builder::new(StakeParameters::default());
+        builder.read_validators_json(validator_json(&identity_key, &[1, 2, 3]).as_bytes())?;
+        assert!(builder.build().is_err());
+
+        Ok(())
+    }
+
+    #[cfg(feature = "genesis-csv")]
+    #[test]
+    fn reads_delegations_from_csv() -> anyhow::Result<()> {
+        let identity_key = IdentityKey(SigningKey::<SpendAuth>::new(OsRng).into());
+        let address = test_keys::ADDRESS_0.to_string();
+
+        let mut builder = Builder::new(StakeParameters::default());
+        builder.read_delegations_csv(
+            format!("validator,amount,address\n{identity_key},1000000,{address}\n").as_bytes(),
+        )?;
+        assert_eq!(builder.delegations.len(), 1);
+        assert_eq!(builder.delegations[0].validator, identity_key);
+        assert_eq!(builder.delegations[0].amount, 1_000_000);
+
+        // Rows which don't parse are rejected.
+        assert!(builder
+            .read_delegations_csv(
+                format!("validator,amount,address\n{identity_key},lots,{address}\n").as_bytes()
+            )
+            .is_err());
+
+        Ok(())
+    }
+}
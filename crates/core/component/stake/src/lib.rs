@@ -20,6 +20,8 @@ pub static BPS_SQUARED_SCALING_FACTOR: Lazy<penumbra_num::fixpoint::U128x128> =
 pub mod delegate;
 pub mod funding_stream;
 pub mod rate;
+pub mod receipt;
+pub mod redelegate;
 pub mod state_key;
 pub mod undelegate;
 pub mod undelegate_claim;
@@ -27,7 +29,8 @@ pub mod validator;
 
 pub use delegate::Delegate;
 use once_cell::sync::Lazy;
-pub use undelegate::Undelegate;
+pub use redelegate::Redelegate;
+pub use undelegate::{SelfBondRelease, Undelegate};
 pub use undelegate_claim::{
     UndelegateClaim, UndelegateClaimBody, UndelegateClaimPlan, UndelegateClaimProof,
 };
@@ -42,6 +45,7 @@ pub use delegation_token::DelegationToken;
 pub use governance_key::GovernanceKey;
 pub use identity_key::IdentityKey;
 pub use penalty::Penalty;
+pub use receipt::DelegationReceipt;
 pub use unbonding_token::UnbondingToken;
 
 pub use changes::DelegationChanges;
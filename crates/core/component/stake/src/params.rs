@@ -22,6 +22,25 @@ pub struct StakeParameters {
     pub missed_blocks_maximum: u64,
     /// The minimum amount of stake required for a validator to be indexed.
     pub min_validator_stake: Amount,
+    /// The minimum value of a validator's self-bond, in staking tokens, for it
+    /// to be in the active set.
+    pub min_validator_self_delegation: Amount,
+    /// The number of epochs a validator can stay defined or disabled with no
+    /// stake before its state is pruned, or zero to never prune validators.
+    pub inactive_validator_retention_epochs: u64,
+    /// The largest share of a validator's delegation pool which can be
+    /// redelegated to other validators in a single epoch, in basis points, or
+    /// zero to disable redelegation.
+    ///
+    /// The limit is released gradually over the course of the epoch.
+    pub redelegation_limit_bps: u64,
+    /// Whether the delegation tokens of validators which are jailed or
+    /// unbonding are barred from being committed to the DEX.
+    pub restrict_at_risk_delegation_tokens: bool,
+    /// Whether validator definitions are rejected if their name is the same
+    /// as another validator's, ignoring differences of case, width and
+    /// whitespace.
+    pub enforce_unique_validator_names: bool,
 }
 
 impl DomainType for StakeParameters {
@@ -44,6 +63,17 @@ impl TryFrom<pb::StakeParameters> for StakeParameters {
                 .min_validator_stake
                 .ok_or_else(|| anyhow::anyhow!("missing min_validator_stake"))?
                 .try_into()?,
+            // Parameters from before the self-delegation requirement was
+            // introduced don't set it, which disables it.
+            min_validator_self_delegation: msg
+                .min_validator_self_delegation
+                .map(TryInto::try_into)
+                .transpose()?
+                .unwrap_or_default(),
+            inactive_validator_retention_epochs: msg.inactive_validator_retention_epochs,
+            redelegation_limit_bps: msg.redelegation_limit_bps,
+            restrict_at_risk_delegation_tokens: msg.restrict_at_risk_delegation_tokens,
+            enforce_unique_validator_names: msg.enforce_unique_validator_names,
         })
     }
 }
@@ -59,6 +89,11 @@ impl From<StakeParameters> for pb::StakeParameters {
             slashing_penalty_misbehavior: params.slashing_penalty_misbehavior,
             base_reward_rate: params.base_reward_rate,
             min_validator_stake: Some(params.min_validator_stake.into()),
+            min_validator_self_delegation: Some(params.min_validator_self_delegation.into()),
+            inactive_validator_retention_epochs: params.inactive_validator_retention_epochs,
+            redelegation_limit_bps: params.redelegation_limit_bps,
+            restrict_at_risk_delegation_tokens: params.restrict_at_risk_delegation_tokens,
+            enforce_unique_validator_names: params.enforce_unique_validator_names,
         }
     }
 }
@@ -80,6 +115,17 @@ impl Default for StakeParameters {
             base_reward_rate: 3_0000,
             // 1 penumbra
             min_validator_stake: 1_000_000u128.into(),
+            // No self-bond is required by default.
+            min_validator_self_delegation: Amount::zero(),
+            // Prune validators which have been inactive with no stake for
+            // about a month of daily epochs.
+            inactive_validator_retention_epochs: 30,
+            // 500 basis points = 5% of each validator's pool per epoch.
+            redelegation_limit_bps: 500,
+            // Delegation tokens are unrestricted by default.
+            restrict_at_risk_delegation_tokens: false,
+            // Validator names are not required to be unique by default.
+            enforce_unique_validator_names: false,
         }
     }
 }
@@ -1,13 +1,17 @@
 //! Staking reward and delegation token exchange rates.
 
+use decaf377_rdsa::{SigningKey, SpendAuth};
 use penumbra_num::fixpoint::U128x128;
 use penumbra_num::Amount;
 use penumbra_proto::core::component::stake::v1::CurrentValidatorRateResponse;
 use penumbra_proto::{penumbra::core::component::stake::v1 as pb, DomainType};
+use rand_core::CryptoRngCore;
 use serde::{Deserialize, Serialize};
 
 use crate::{validator::State, FundingStream, IdentityKey};
-use crate::{Delegate, Penalty, Undelegate, BPS_SQUARED_SCALING_FACTOR};
+use crate::{
+    Delegate, Penalty, Redelegate, SelfBondRelease, Undelegate, BPS_SQUARED_SCALING_FACTOR,
+};
 
 /// Describes a validator's reward rate and voting power in some epoch.
 #[derive(Debug, PartialEq, Eq, Clone, Serialize, Deserialize)]
@@ -241,9 +245,25 @@ impl RateData {
             epoch_index: self.epoch_index,
             unbonded_amount,
             validator_identity: self.identity_key.clone(),
+            self_bond_auth_sig: None,
         }
     }
 
+    /// Uses this `RateData` to build a `Delegate` transaction action that
+    /// adds `unbonded_amount` of the staking token to the validator's self-bond,
+    /// signed by the validator's identity key.
+    pub fn build_self_bond(
+        &self,
+        unbonded_amount: Amount,
+        identity_signing_key: &SigningKey<SpendAuth>,
+        rng: impl CryptoRngCore,
+    ) -> Delegate {
+        let mut delegate = self.build_delegate(unbonded_amount);
+        delegate.self_bond_auth_sig =
+            Some(identity_signing_key.sign(rng, &delegate.self_bond_auth_bytes()));
+        delegate
+    }
+
     /// Uses this `RateData` to build an `Undelegate` transaction action that
     /// undelegates `delegation_amount` of the validator's delegation tokens.
     pub fn build_undelegate(&self, delegation_amount: Amount) -> Undelegate {
@@ -252,6 +272,42 @@ impl RateData {
             delegation_amount,
             unbonded_amount: self.unbonded_amount(delegation_amount),
             validator_identity: self.identity_key.clone(),
+            self_bond_release: None,
+        }
+    }
+
+    /// Uses this `RateData` to build an `Undelegate` transaction action that
+    /// releases `delegation_amount` from the validator's self-bond, signed by
+    /// the validator's identity key.
+    ///
+    /// The `sequence` must be one more than that of the validator's previous
+    /// release, or 1 if this is its first.
+    pub fn build_self_bond_release(
+        &self,
+        delegation_amount: Amount,
+        sequence: u64,
+        identity_signing_key: &SigningKey<SpendAuth>,
+        rng: impl CryptoRngCore,
+    ) -> Undelegate {
+        let mut undelegate = self.build_undelegate(delegation_amount);
+        let auth_sig = identity_signing_key.sign(rng, &undelegate.self_bond_auth_bytes(sequence));
+        undelegate.self_bond_release = Some(SelfBondRelease { sequence, auth_sig });
+        undelegate
+    }
+
+    /// Uses this `RateData` and that of the destination validator, which must
+    /// be for the same epoch, to build a `Redelegate` transaction action that
+    /// moves `delegation_amount` of this validator's delegation tokens to the
+    /// destination validator.
+    pub fn build_redelegate(&self, to: &RateData, delegation_amount: Amount) -> Redelegate {
+        let unbonded_amount = self.unbonded_amount(delegation_amount);
+        Redelegate {
+            from_validator_identity: self.identity_key.clone(),
+            to_validator_identity: to.identity_key.clone(),
+            epoch_index: self.epoch_index,
+            from_delegation_amount: delegation_amount,
+            unbonded_amount,
+            to_delegation_amount: to.delegation_amount(unbonded_amount),
         }
     }
 }
@@ -398,6 +454,7 @@ impl TryFrom<CurrentValidatorRateResponse> for RateData {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::DelegationToken;
     use decaf377_rdsa as rdsa;
     use rand_core::OsRng;
 
@@ -417,4 +474,94 @@ mod tests {
         let slashed = rate_data.slash(penalty);
         assert_eq!(slashed.validator_exchange_rate, 1_8000_0000u128.into());
     }
+
+    #[test]
+    fn self_bond_release_is_authorized_by_identity_key() {
+        let sk = rdsa::SigningKey::new(OsRng);
+        let ik = IdentityKey((&sk).into());
+
+        let rate_data = RateData {
+            identity_key: ik.clone(),
+            epoch_index: 0,
+            validator_reward_rate: 1_0000_0000u128.into(),
+            validator_exchange_rate: 2_0000_0000u128.into(),
+        };
+
+        let mut self_bond = rate_data.build_self_bond(100u64.into(), &sk, OsRng);
+        self_bond.verify_self_bond().unwrap();
+        assert!(self_bond.is_self_bond());
+        assert_eq!(self_bond.balance().provided().count(), 0);
+
+        // Only the validator can add to its self-bond.
+        let other_sk = rdsa::SigningKey::new(OsRng);
+        assert!(rate_data
+            .build_self_bond(100u64.into(), &other_sk, OsRng)
+            .verify_self_bond()
+            .is_err());
+
+        // The signature doesn't authorize a different amount.
+        self_bond.unbonded_amount = 200u64.into();
+        assert!(self_bond.verify_self_bond().is_err());
+
+        let mut release = rate_data.build_self_bond_release(50u64.into(), 1, &sk, OsRng);
+        release.verify_self_bond_release().unwrap();
+        assert_eq!(release.balance().required().count(), 0);
+
+        // The signature doesn't authorize releasing a different amount.
+        release.delegation_amount = 60u64.into();
+        assert!(release.verify_self_bond_release().is_err());
+
+        // Nor may it be made by anyone other than the validator.
+        let other_sk = rdsa::SigningKey::new(OsRng);
+        let forged = rate_data.build_self_bond_release(50u64.into(), 1, &other_sk, OsRng);
+        assert!(forged.verify_self_bond_release().is_err());
+    }
+
+    #[test]
+    fn redelegation_converts_between_exchange_rates() {
+        let from = RateData {
+            identity_key: IdentityKey((&rdsa::SigningKey::new(OsRng)).into()),
+            epoch_index: 3,
+            validator_reward_rate: 1_0000_0000u128.into(),
+            validator_exchange_rate: 2_0000_0000u128.into(),
+        };
+        let to = RateData {
+            identity_key: IdentityKey((&rdsa::SigningKey::new(OsRng)).into()),
+            epoch_index: 3,
+            validator_reward_rate: 1_0000_0000u128.into(),
+            validator_exchange_rate: 1_0000_0000u128.into(),
+        };
+
+        let redelegate = from.build_redelegate(&to, 100u64.into());
+        assert_eq!(redelegate.unbonded_amount, 200u64.into());
+        assert_eq!(redelegate.to_delegation_amount, 200u64.into());
+
+        // The queued pool changes match an undelegation and a delegation of
+        // the same stake.
+        assert_eq!(
+            redelegate.undelegation(),
+            from.build_undelegate(100u64.into())
+        );
+        assert_eq!(
+            redelegate.delegation().delegation_amount,
+            to.build_delegate(200u64.into()).delegation_amount
+        );
+
+        // Only delegation tokens change hands.
+        let balance = redelegate.balance();
+        let required = balance.required().collect::<Vec<_>>();
+        let provided = balance.provided().collect::<Vec<_>>();
+        assert_eq!(required.len(), 1);
+        assert_eq!(
+            required[0].asset_id,
+            DelegationToken::new(from.identity_key).id()
+        );
+        assert_eq!(required[0].amount, 100u64.into());
+        assert_eq!(provided.len(), 1);
+        assert_eq!(
+            provided[0].asset_id,
+            DelegationToken::new(to.identity_key).id()
+        );
+        assert_eq!(provided[0].amount, 200u64.into());
+    }
 }
@@ -0,0 +1,148 @@
+use penumbra_num::Amount;
+use penumbra_proto::{penumbra::core::component::stake::v1 as pb, DomainType};
+use serde::{Deserialize, Serialize};
+
+use crate::{rate::RateData, IdentityKey, Penalty};
+
+/// A record of the terms on which unbonding tokens were claimed, for accounting purposes.
+///
+/// The amounts of unbonding tokens in an `UndelegateClaim` are shielded, so the chain
+/// cannot record them. Instead, a receipt records the rates and penalty that applied
+/// to every claim of unbonding tokens for a given validator and start epoch, from
+/// which the holder of the tokens can compute the exact amounts involved.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(try_from = "pb::DelegationReceipt", into = "pb::DelegationReceipt")]
+pub struct DelegationReceipt {
+    /// The validator the tokens were delegated to.
+    pub validator_identity: IdentityKey,
+    /// The epoch in which the undelegation was initiated.
+    pub start_epoch_index: u64,
+    /// The epoch at which the unbonding tokens became claimable.
+    pub unbonding_epoch_index: u64,
+    /// The epoch in which the unbonding tokens were claimed.
+    pub claim_epoch_index: u64,
+    /// The validator's rate data in the epoch the undelegation was initiated, if recorded.
+    pub entry_rate: Option<RateData>,
+    /// The validator's rate data in the epoch the unbonding tokens became claimable, if recorded.
+    pub exit_rate: Option<RateData>,
+    /// The penalty applied to the unbonding tokens over `[start_epoch_index, unbonding_epoch_index)`.
+    pub penalty: Penalty,
+}
+
+impl DelegationReceipt {
+    /// The amount of delegation tokens that were undelegated to produce the given
+    /// amount of unbonding tokens, if the entry rate is known.
+    pub fn delegation_amount(&self, unbonding_amount: Amount) -> Option<Amount> {
+        self.entry_rate
+            .as_ref()
+            .map(|rate| rate.delegation_amount(unbonding_amount))
+    }
+
+    /// The amount of staking tokens received for claiming the given amount of unbonding tokens.
+    pub fn claimed_amount(&self, unbonding_amount: Amount) -> Amount {
+        self.penalty.apply_to_amount(unbonding_amount)
+    }
+
+    /// The amount of staking tokens lost to slashing when claiming the given amount of
+    /// unbonding tokens.
+    pub fn penalty_amount(&self, unbonding_amount: Amount) -> Amount {
+        unbonding_amount.saturating_sub(&self.claimed_amount(unbonding_amount))
+    }
+
+    /// The amount of staking tokens the delegation would have been worth at the exit
+    /// rate, had it not been undelegated, if both the entry and exit rates are known.
+    ///
+    /// The difference between this and the claimed amount is the cost of unbonding.
+    pub fn exit_value(&self, unbonding_amount: Amount) -> Option<Amount> {
+        let delegation_amount = self.delegation_amount(unbonding_amount)?;
+        self.exit_rate
+            .as_ref()
+            .map(|rate| rate.unbonded_amount(delegation_amount))
+    }
+}
+
+impl DomainType for DelegationReceipt {
+    type Proto = pb::DelegationReceipt;
+}
+
+impl From<DelegationReceipt> for pb::DelegationReceipt {
+    fn from(receipt: DelegationReceipt) -> Self {
+        pb::DelegationReceipt {
+            validator_identity: Some(receipt.validator_identity.into()),
+            start_epoch_index: receipt.start_epoch_index,
+            unbonding_epoch_index: receipt.unbonding_epoch_index,
+            claim_epoch_index: receipt.claim_epoch_index,
+            entry_rate: receipt.entry_rate.map(Into::into),
+            exit_rate: receipt.exit_rate.map(Into::into),
+            penalty: Some(receipt.penalty.into()),
+        }
+    }
+}
+
+impl TryFrom<pb::DelegationReceipt> for DelegationReceipt {
+    type Error = anyhow::Error;
+
+    fn try_from(receipt: pb::DelegationReceipt) -> Result<Self, Self::Error> {
+        Ok(DelegationReceipt {
+            validator_identity: receipt
+                .validator_identity
+                .ok_or_else(|| anyhow::anyhow!("missing validator identity"))?
+                .try_into()?,
+            start_epoch_index: receipt.start_epoch_index,
+            unbonding_epoch_index: receipt.unbonding_epoch_index,
+            claim_epoch_index: receipt.claim_epoch_index,
+            entry_rate: receipt.entry_rate.map(TryInto::try_into).transpose()?,
+            exit_rate: receipt.exit_rate.map(TryInto::try_into).transpose()?,
+            penalty: receipt
+                .penalty
+                .ok_or_else(|| anyhow::anyhow!("missing penalty"))?
+                .try_into()?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use decaf377_rdsa as rdsa;
+    use rand_core::OsRng;
+
+    use super::*;
+
+    #[test]
+    fn receipt_accounts_for_rates_and_penalty() {
+        let sk = rdsa::SigningKey::new(OsRng);
+        let ik = IdentityKey((&sk).into());
+        let rate = |epoch_index, exchange_rate: u128| RateData {
+            identity_key: ik.clone(),
+            epoch_index,
+            validator_reward_rate: 0u128.into(),
+            validator_exchange_rate: (exchange_rate * 1_0000_0000).into(),
+        };
+
+        let receipt = DelegationReceipt {
+            validator_identity: ik.clone(),
+            start_epoch_index: 10,
+            unbonding_epoch_index: 12,
+            claim_epoch_index: 15,
+            entry_rate: Some(rate(10, 2)),
+            exit_rate: Some(rate(12, 3)),
+            penalty: Penalty::from_percent(50),
+        };
+
+        let unbonding_amount = Amount::from(1_000u64);
+        assert_eq!(
+            receipt.delegation_amount(unbonding_amount),
+            Some(500u64.into())
+        );
+        assert_eq!(receipt.claimed_amount(unbonding_amount), 500u64.into());
+        assert_eq!(receipt.penalty_amount(unbonding_amount), 500u64.into());
+        assert_eq!(receipt.exit_value(unbonding_amount), Some(1_500u64.into()));
+
+        let receipt = DelegationReceipt {
+            entry_rate: None,
+            ..receipt
+        };
+        assert_eq!(receipt.delegation_amount(unbonding_amount), None);
+        assert_eq!(receipt.exit_value(unbonding_amount), None);
+    }
+}
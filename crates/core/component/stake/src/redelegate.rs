@@ -0,0 +1,146 @@
+use penumbra_asset::{Balance, Value};
+use penumbra_num::Amount;
+use penumbra_proto::{penumbra::core::component::stake::v1 as pb, DomainType};
+use penumbra_txhash::{EffectHash, EffectingData};
+use serde::{Deserialize, Serialize};
+
+use crate::{Delegate, DelegationToken, IdentityKey, Undelegate};
+
+/// A transaction action moving stake from one validator's delegation pool to
+/// another's, without waiting out the unbonding period.
+///
+/// The stake leaves the source validator's pool and enters the destination
+/// validator's pool at the next epoch boundary, exactly as if it had been
+/// undelegated from one and delegated to the other in the same epoch. Since
+/// redelegated stake escapes any penalty the source validator incurs while it
+/// would otherwise have been unbonding, the amount which can be redelegated
+/// away from each validator per epoch is limited by the
+/// [`redelegation_limit_bps`](crate::params::StakeParameters::redelegation_limit_bps)
+/// parameter.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(try_from = "pb::Redelegate", into = "pb::Redelegate")]
+pub struct Redelegate {
+    /// The identity key of the validator to undelegate from.
+    pub from_validator_identity: IdentityKey,
+    /// The identity key of the validator to delegate to.
+    pub to_validator_identity: IdentityKey,
+    /// The index of the epoch in which this redelegation was performed.
+    /// The redelegation takes effect in the next epoch.
+    pub epoch_index: u64,
+    /// The amount of the source validator's delegation tokens consumed by this action.
+    pub from_delegation_amount: Amount,
+    /// The amount of stake moved, in units of unbonded stake.
+    ///
+    /// This is implied by the source validator's exchange rate in the
+    /// specified epoch (and is checked in transaction validation).
+    pub unbonded_amount: Amount,
+    /// The amount of the destination validator's delegation tokens produced by this action.
+    ///
+    /// This is implied by the destination validator's exchange rate in the
+    /// specified epoch (and is checked in transaction validation).
+    pub to_delegation_amount: Amount,
+}
+
+impl EffectingData for Redelegate {
+    fn effect_hash(&self) -> EffectHash {
+        // For redelegations, the entire action is considered effecting data.
+        EffectHash::from_proto_effecting_data(&self.to_proto())
+    }
+}
+
+impl Redelegate {
+    /// Return the balance after consuming the source validator's delegation
+    /// tokens, and producing the destination validator's.
+    pub fn balance(&self) -> Balance {
+        let from = Balance::from(Value {
+            amount: self.from_delegation_amount,
+            asset_id: self.from_delegation_token().id(),
+        });
+        let to = Balance::from(Value {
+            amount: self.to_delegation_amount,
+            asset_id: self.to_delegation_token().id(),
+        });
+
+        to - from
+    }
+
+    pub fn from_delegation_token(&self) -> DelegationToken {
+        DelegationToken::new(self.from_validator_identity.clone())
+    }
+
+    pub fn to_delegation_token(&self) -> DelegationToken {
+        DelegationToken::new(self.to_validator_identity.clone())
+    }
+
+    /// The undelegation from the source validator's pool queued by this action.
+    ///
+    /// Unlike an [`Undelegate`] action, this produces no unbonding tokens.
+    pub fn undelegation(&self) -> Undelegate {
+        Undelegate {
+            validator_identity: self.from_validator_identity,
+            start_epoch_index: self.epoch_index,
+            unbonded_amount: self.unbonded_amount,
+            delegation_amount: self.from_delegation_amount,
+            self_bond_release: None,
+        }
+    }
+
+    /// The delegation to the destination validator's pool queued by this action.
+    ///
+    /// Unlike a [`Delegate`] action, this consumes no staking tokens.
+    pub fn delegation(&self) -> Delegate {
+        Delegate {
+            validator_identity: self.to_validator_identity,
+            epoch_index: self.epoch_index,
+            unbonded_amount: self.unbonded_amount,
+            delegation_amount: self.to_delegation_amount,
+            self_bond_auth_sig: None,
+        }
+    }
+}
+
+impl DomainType for Redelegate {
+    type Proto = pb::Redelegate;
+}
+
+impl From<Redelegate> for pb::Redelegate {
+    fn from(r: Redelegate) -> Self {
+        pb::Redelegate {
+            from_validator_identity: Some(r.from_validator_identity.into()),
+            to_validator_identity: Some(r.to_validator_identity.into()),
+            epoch_index: r.epoch_index,
+            from_delegation_amount: Some(r.from_delegation_amount.into()),
+            unbonded_amount: Some(r.unbonded_amount.into()),
+            to_delegation_amount: Some(r.to_delegation_amount.into()),
+        }
+    }
+}
+
+impl TryFrom<pb::Redelegate> for Redelegate {
+    type Error = anyhow::Error;
+    fn try_from(r: pb::Redelegate) -> Result<Self, Self::Error> {
+        Ok(Self {
+            from_validator_identity: r
+                .from_validator_identity
+                .ok_or_else(|| anyhow::anyhow!("missing source validator identity"))?
+                .try_into()?,
+            to_validator_identity: r
+                .to_validator_identity
+                .ok_or_else(|| anyhow::anyhow!("missing destination validator identity"))?
+                .try_into()?,
+            epoch_index: r.epoch_index,
+            from_delegation_amount: r
+                .from_delegation_amount
+                .ok_or_else(|| anyhow::anyhow!("missing source delegation amount"))?
+                .try_into()?,
+            unbonded_amount: r
+                .unbonded_amount
+                .ok_or_else(|| anyhow::anyhow!("missing unbonded amount"))?
+                .try_into()?,
+            to_delegation_amount: r
+                .to_delegation_amount
+                .ok_or_else(|| anyhow::anyhow!("missing destination delegation amount"))?
+                .try_into()?,
+        })
+    }
+}
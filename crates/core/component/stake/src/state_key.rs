@@ -1,3 +1,7 @@
+/// The prefixes of all of the component's state keys, for the application's
+/// state key registry.
+pub const PREFIXES: &[&str] = &["staking/"];
+
 pub mod parameters {
     pub fn key() -> &'static str {
         "staking/parameters"
@@ -9,6 +13,10 @@ pub mod parameters {
 }
 
 pub mod validators {
+    pub fn count() -> &'static str {
+        "staking/validators/count"
+    }
+
     pub mod consensus_set_index {
         pub fn prefix() -> &'static str {
             "staking/validators/consensus_set_index/"
@@ -42,36 +50,175 @@ pub mod validators {
         }
     }
 
+    /// Indexes validators by the forms of their names which are compared to
+    /// tell them apart. The forms are hex-encoded, since they may contain any
+    /// character.
+    pub mod names {
+        use crate::IdentityKey;
+
+        pub fn normalized_prefix(normalized: &str) -> String {
+            format!(
+                "staking/validators/names/normalized/{}/",
+                hex::encode(normalized)
+            )
+        }
+        pub fn normalized_by_id(normalized: &str, id: &IdentityKey) -> String {
+            format!("{}{id}", normalized_prefix(normalized))
+        }
+
+        pub fn skeleton_prefix(skeleton: &str) -> String {
+            format!(
+                "staking/validators/names/skeleton/{}/",
+                hex::encode(skeleton)
+            )
+        }
+        pub fn skeleton_by_id(skeleton: &str, id: &IdentityKey) -> String {
+            format!("{}{id}", skeleton_prefix(skeleton))
+        }
+    }
+
     pub mod state {
+        pub fn prefix() -> &'static str {
+            "staking/validators/data/state/"
+        }
         pub fn by_id(id: &crate::IdentityKey) -> String {
-            format!("staking/validators/data/state/{id}")
+            format!("{}{id}", prefix())
         }
     }
 
     pub mod rate {
+        pub fn prefix() -> &'static str {
+            "staking/validators/data/rate/"
+        }
+
         pub fn current_by_id(id: &crate::IdentityKey) -> String {
-            format!("staking/validators/data/rate/current/{id}")
+            format!("{}current/{id}", prefix())
         }
 
         pub fn previous_by_id(id: &crate::IdentityKey) -> String {
-            format!("staking/validators/data/rate/previous/{id}")
+            format!("{}previous/{id}", prefix())
+        }
+
+        /// The rate data of each epoch is kept under the same key in both
+        /// verifiable and nonverifiable storage, so that it can be proven, and
+        /// scanned by epoch.  The verifiable copy is only kept for the last
+        /// `unbonding_epochs` epochs, while the nonverifiable copy is kept for
+        /// good.
+        pub fn by_epoch_prefix(id: &crate::IdentityKey) -> String {
+            format!("{}by_epoch/{id}/", prefix())
+        }
+
+        pub fn by_id_in_epoch(id: &crate::IdentityKey, epoch_index: u64) -> String {
+            // Padded so that the lex order agrees with the numeric order on epochs.
+            format!("{}{epoch_index:020}", by_epoch_prefix(id))
         }
     }
 
     pub mod power {
+        pub fn prefix() -> &'static str {
+            "staking/validators/data/power/"
+        }
         pub fn by_id(id: &crate::IdentityKey) -> String {
-            format!("staking/validators/data/power/{id}")
+            format!("{}{id}", prefix())
+        }
+    }
+
+    /// Tracks a commitment to the power table of each epoch in verifiable
+    /// storage, and the table itself under the same key in nonverifiable
+    /// storage, so that proofs of past voting power can be served.
+    pub mod power_checkpoints {
+        pub fn by_epoch(epoch_index: u64) -> String {
+            // Padded so that the lex order agrees with the numeric order on epochs.
+            format!("staking/validators/power_checkpoints/{epoch_index:020}")
         }
     }
     pub mod bonding_state {
+        pub fn prefix() -> &'static str {
+            "staking/validators/data/bonding_state/"
+        }
         pub fn by_id(id: &crate::IdentityKey) -> String {
-            format!("staking/validators/data/bonding_state/{id}")
+            format!("{}{id}", prefix())
         }
     }
 
     pub mod uptime {
+        pub fn prefix() -> &'static str {
+            "staking/validators/data/uptime/"
+        }
+        pub fn by_id(id: &crate::IdentityKey) -> String {
+            format!("{}{id}", prefix())
+        }
+    }
+
+    /// Tracks validator participation observed through vote extensions, in
+    /// nonverifiable storage.
+    pub mod participation {
+        pub fn prefix() -> &'static str {
+            "staking/validators/data/participation/"
+        }
         pub fn by_id(id: &crate::IdentityKey) -> String {
-            format!("staking/validators/data/uptime/{id}")
+            format!("{}{id}", prefix())
+        }
+    }
+
+    /// Tracks the delegation tokens each validator has bonded to itself, and
+    /// the sequence number of its last authorized self-bond release.
+    pub mod self_bond {
+        pub fn by_id(id: &crate::IdentityKey) -> String {
+            format!("staking/validators/data/self_bond/{id}")
+        }
+
+        pub fn release_sequence_by_id(id: &crate::IdentityKey) -> String {
+            format!("staking/validators/data/self_bond_release_sequence/{id}")
+        }
+    }
+
+    /// Tracks the delegation tokens redelegated away from each validator in
+    /// the current epoch, which are cleared at the end of the epoch.
+    pub mod redelegated {
+        pub fn by_id(id: &crate::IdentityKey) -> String {
+            format!("staking/validators/data/redelegated/{id}")
+        }
+    }
+
+    /// Tracks the delegation tokens issued to each validator's pool by
+    /// redelegations in the current epoch, which are cleared at the end of the
+    /// epoch.
+    pub mod redelegated_to {
+        pub fn by_id(id: &crate::IdentityKey) -> String {
+            format!("staking/validators/data/redelegated_to/{id}")
+        }
+    }
+
+    /// Tracks validator definitions whose funding streams are scheduled to
+    /// take effect in a future epoch.
+    pub mod scheduled_funding_streams {
+        pub fn prefix_for_epoch(epoch_index: u64) -> String {
+            format!("staking/validators/scheduled_funding_streams/by_epoch/{epoch_index:020}/")
+        }
+
+        pub fn by_epoch_and_id(epoch_index: u64, id: &crate::IdentityKey) -> String {
+            format!("{}{id}", prefix_for_epoch(epoch_index))
+        }
+
+        pub fn epoch_by_id(id: &crate::IdentityKey) -> String {
+            format!("staking/validators/scheduled_funding_streams/epoch_by_id/{id}")
+        }
+    }
+
+    /// Tracks the epoch since which each validator has been defined or
+    /// disabled with no stake delegated to it.
+    pub mod inactive_since {
+        pub fn by_id(id: &crate::IdentityKey) -> String {
+            format!("staking/validators/data/inactive_since/{id}")
+        }
+    }
+
+    /// Tracks the tombstones of validators whose state was pruned after
+    /// being inactive for too long, in nonverifiable storage.
+    pub mod tombstones {
+        pub fn by_id(id: &crate::IdentityKey) -> String {
+            format!("staking/validators/tombstones/{id}")
         }
     }
 
@@ -104,6 +251,25 @@ pub mod chain {
             format!("staking/delegation_changes/{height}")
         }
     }
+
+    /// The delegation changes persisted in the current epoch, indexed by
+    /// validator and height in the nonverifiable storage.
+    pub mod pending_delegation_changes {
+        use crate::IdentityKey;
+
+        pub fn prefix() -> &'static str {
+            "staking/pending_delegation_changes/"
+        }
+
+        pub fn by_id(id: &IdentityKey) -> String {
+            format!("{}{id}/", prefix())
+        }
+
+        pub fn by_id_and_height(id: &IdentityKey, height: u64) -> String {
+            // Padded so that the lex order agrees with the numeric order on heights.
+            format!("{}{height:020}", by_id(id))
+        }
+    }
 }
 
 pub mod penalty {
@@ -127,6 +293,19 @@ pub mod consensus_update {
     pub fn consensus_keys() -> &'static str {
         "staking/cometbft_data/consensus_keys"
     }
+
+    pub fn consensus_keys_by_height_prefix() -> &'static str {
+        "staking/cometbft_data/consensus_keys_by_height/"
+    }
+
+    pub fn consensus_keys_by_height(effective_height: u64) -> String {
+        // Load-bearing format string: the heights are padded so that the lex
+        // order agrees with the numeric order.
+        format!(
+            "{}{effective_height:020}",
+            consensus_keys_by_height_prefix()
+        )
+    }
 }
 
 pub(super) mod internal {
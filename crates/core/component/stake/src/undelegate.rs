@@ -1,6 +1,8 @@
+use anyhow::Context;
+use decaf377_rdsa::{Signature, SpendAuth};
 use penumbra_asset::{Balance, Value};
 use penumbra_num::Amount;
-use penumbra_proto::{penumbra::core::component::stake::v1 as pb, DomainType};
+use penumbra_proto::{penumbra::core::component::stake::v1 as pb, DomainType, Message as _};
 use penumbra_txhash::{EffectHash, EffectingData};
 use serde::{Deserialize, Serialize};
 
@@ -23,6 +25,21 @@ pub struct Undelegate {
     /// (and should be checked in transaction validation!), but including it allows
     /// stateless verification that the transaction is internally consistent.
     pub delegation_amount: Amount,
+    /// If set, the undelegation is released from the validator's self-bond,
+    /// rather than consuming delegation tokens.
+    pub self_bond_release: Option<SelfBondRelease>,
+}
+
+/// Authorizes an [`Undelegate`] from a validator's self-bond.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SelfBondRelease {
+    /// The sequence number of the release, which must be one more than that of
+    /// the validator's previous release, so that releases can't be replayed.
+    pub sequence: u64,
+    /// A signature by the validator's identity key over the
+    /// [`self_bond_auth_bytes`](Undelegate::self_bond_auth_bytes) of the
+    /// undelegation.
+    pub auth_sig: Signature<SpendAuth>,
 }
 
 impl EffectingData for Undelegate {
@@ -34,12 +51,18 @@ impl EffectingData for Undelegate {
 
 impl Undelegate {
     /// Return the balance after consuming delegation tokens, and producing unbonding tokens.
+    ///
+    /// Releases from the validator's self-bond only produce the unbonding tokens.
     pub fn balance(&self) -> Balance {
         let stake = Balance::from(Value {
             amount: self.unbonded_amount,
             asset_id: self.unbonding_token().id(),
         });
 
+        if self.self_bond_release.is_some() {
+            return stake;
+        }
+
         let delegation = Balance::from(Value {
             amount: self.delegation_amount,
             asset_id: self.delegation_token().id(),
@@ -56,6 +79,34 @@ impl Undelegate {
     pub fn delegation_token(&self) -> DelegationToken {
         DelegationToken::new(self.validator_identity.clone())
     }
+
+    /// The bytes signed by the validator's identity key to release this
+    /// undelegation from its self-bond with the given sequence number.
+    ///
+    /// This is the encoding of the undelegation, with the signature left empty.
+    pub fn self_bond_auth_bytes(&self, sequence: u64) -> Vec<u8> {
+        let mut proto = pb::Undelegate::from(self.clone());
+        proto.self_bond_release = Some(pb::SelfBondRelease {
+            sequence,
+            auth_sig: Vec::new(),
+        });
+        proto.encode_to_vec()
+    }
+
+    /// Check that the release from the validator's self-bond, if any, is
+    /// signed by the validator's identity key.
+    pub fn verify_self_bond_release(&self) -> anyhow::Result<()> {
+        if let Some(release) = &self.self_bond_release {
+            self.validator_identity
+                .0
+                .verify(
+                    &self.self_bond_auth_bytes(release.sequence),
+                    &release.auth_sig,
+                )
+                .context("self-bond release signature failed to verify")?;
+        }
+        Ok(())
+    }
 }
 
 impl DomainType for Undelegate {
@@ -69,6 +120,10 @@ impl From<Undelegate> for pb::Undelegate {
             start_epoch_index: d.start_epoch_index,
             unbonded_amount: Some(d.unbonded_amount.into()),
             delegation_amount: Some(d.delegation_amount.into()),
+            self_bond_release: d.self_bond_release.map(|release| pb::SelfBondRelease {
+                sequence: release.sequence,
+                auth_sig: release.auth_sig.to_bytes().to_vec(),
+            }),
         }
     }
 }
@@ -90,6 +145,15 @@ impl TryFrom<pb::Undelegate> for Undelegate {
                 .delegation_amount
                 .ok_or_else(|| anyhow::anyhow!("missing delegation amount"))?
                 .try_into()?,
+            self_bond_release: d
+                .self_bond_release
+                .map(|release| -> anyhow::Result<_> {
+                    Ok(SelfBondRelease {
+                        sequence: release.sequence,
+                        auth_sig: release.auth_sig.as_slice().try_into()?,
+                    })
+                })
+                .transpose()?,
         })
     }
 }
@@ -21,9 +21,6 @@ pub struct Uptime {
     // Note: tracking this means we *could* in principle answer queries by
     // height, they just might be surprising for new validators (we just report
     // *failures* to sign, not didn't sign)
-    //
-    // can also possibly extend this impl to support resizing the window when we
-    // get to that
     as_of_block_height: u64,
     signatures: BitVec<u8, Lsb0>,
 }
@@ -73,6 +70,63 @@ impl Uptime {
         Ok(())
     }
 
+    /// The height of the most recent block recorded by the tracker.
+    pub fn as_of_block_height(&self) -> u64 {
+        self.as_of_block_height
+    }
+
+    /// The number of blocks in the window.
+    pub fn window_len(&self) -> usize {
+        self.signatures.len()
+    }
+
+    /// Resize the window to `new_len` blocks.
+    ///
+    /// The record of the most recent blocks is preserved, up to the size of
+    /// the smaller of the two windows.  If the window grows, the additional
+    /// blocks are marked as signed, as for the grace period of a new validator.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `new_len` is zero.
+    pub fn resize(&mut self, new_len: usize) {
+        assert!(new_len > 0, "uptime window length must be nonzero");
+        let old_len = self.signatures.len();
+        if new_len == old_len {
+            return;
+        }
+
+        self.remap(new_len, self.as_of_block_height);
+    }
+
+    /// Move the tracker to the given block height, as though the most recent
+    /// blocks it recorded were the blocks leading up to that height.
+    ///
+    /// This is used to carry a validator's uptime over to a different chain,
+    /// such as when importing a validator set snapshot at genesis.
+    pub fn rebase(&mut self, as_of_block_height: u64) {
+        self.remap(self.signatures.len(), as_of_block_height);
+    }
+
+    /// Copy the record of the most recent blocks into a window of `new_len`
+    /// blocks ending at `new_height`, marking any other blocks as signed.
+    fn remap(&mut self, new_len: usize, new_height: u64) {
+        // The index of the block `blocks_ago` blocks before `height` in a ring
+        // buffer of length `len`, where `blocks_ago < len`.
+        let ring_index = |height: u64, blocks_ago: usize, len: usize| {
+            ((height as usize) % len + len - blocks_ago) % len
+        };
+
+        let old_len = self.signatures.len();
+        let mut signatures = bitvec![u8, Lsb0; 1; new_len];
+        for blocks_ago in 0..old_len.min(new_len) {
+            let signed = self.signatures[ring_index(self.as_of_block_height, blocks_ago, old_len)];
+            signatures.set(ring_index(new_height, blocks_ago, new_len), signed);
+        }
+        self.signatures = signatures;
+        self.as_of_block_height = new_height;
+    }
+
     /// Counts the number of missed blocks over the window.
     pub fn num_missed_blocks(&self) -> usize {
         self.signatures.iter_zeros().len()
@@ -137,6 +191,63 @@ mod tests {
         assert!(uptime.mark_height_as_signed(0, true).is_err());
     }
 
+    #[test]
+    fn resizing_preserves_recent_blocks() {
+        let mut uptime = Uptime::new(0, 100);
+
+        // Miss every block from 51 to 100.
+        for h in 1..=100u64 {
+            uptime.mark_height_as_signed(h, h <= 50).unwrap();
+        }
+        assert_eq!(uptime.num_missed_blocks(), 50);
+
+        // Shrinking the window keeps only the most recent blocks.
+        let mut shrunk = uptime.clone();
+        shrunk.resize(30);
+        assert_eq!(shrunk.window_len(), 30);
+        assert_eq!(shrunk.num_missed_blocks(), 30);
+
+        // Growing the window keeps the whole history, and pads it with signed blocks.
+        let mut grown = uptime.clone();
+        grown.resize(150);
+        assert_eq!(grown.window_len(), 150);
+        assert_eq!(grown.num_missed_blocks(), 50);
+
+        // The resized trackers keep tracking as usual.
+        for h in 101..=130u64 {
+            shrunk.mark_height_as_signed(h, true).unwrap();
+            grown.mark_height_as_signed(h, true).unwrap();
+        }
+        assert_eq!(shrunk.num_missed_blocks(), 0);
+        assert_eq!(grown.num_missed_blocks(), 50);
+        for h in 131..=220u64 {
+            grown.mark_height_as_signed(h, true).unwrap();
+        }
+        assert_eq!(grown.num_missed_blocks(), 30);
+    }
+
+    #[test]
+    fn rebasing_preserves_recent_blocks() {
+        let mut uptime = Uptime::new(0, 10);
+        for h in 1..=1_000u64 {
+            uptime.mark_height_as_signed(h, h % 5 != 0).unwrap();
+        }
+        assert_eq!(uptime.num_missed_blocks(), 2);
+
+        uptime.rebase(3);
+        assert_eq!(uptime.num_missed_blocks(), 2);
+
+        // The rebased tracker continues from its new height.
+        assert!(uptime.mark_height_as_signed(1_001, true).is_err());
+        for h in 4..=8u64 {
+            uptime.mark_height_as_signed(h, true).unwrap();
+        }
+        // Block 1000 (missed) was the most recent block before rebasing, so it
+        // now occupies height 3 and is still within the window, while block 995
+        // has been overwritten.
+        assert_eq!(uptime.num_missed_blocks(), 1);
+    }
+
     #[test]
     fn proto_round_trip() {
         // make a weird size window
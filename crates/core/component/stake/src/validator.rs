@@ -10,15 +10,25 @@ use crate::{FundingStream, FundingStreams, GovernanceKey, IdentityKey};
 
 mod bonding;
 mod definition;
+mod diagnosis;
 mod info;
+mod name;
+mod power_table;
+mod snapshot;
 mod state;
 mod status;
+mod tombstone;
 
 pub use bonding::State as BondingState;
 pub use definition::Definition;
+pub use diagnosis::{Diagnosis, InactivityReason};
 pub use info::Info;
+pub use name::{name_skeleton, normalize_name};
+pub use power_table::{Power, PowerProof, PowerTable, PowerTableCommitment};
+pub use snapshot::{KeyProof, SetSnapshot, SetSnapshotProof, Snapshot};
 pub use state::State;
 pub use status::Status;
+pub use tombstone::Tombstone;
 
 /// Describes a Penumbra validator's configuration data.
 ///
@@ -62,6 +72,13 @@ pub struct Validator {
     // SCT at the beginning of each epoch
     pub funding_streams: FundingStreams,
 
+    /// The index of the epoch from which the [`funding_streams`](Self::funding_streams) in this
+    /// definition take effect, if they should not take effect immediately.
+    ///
+    /// Scheduling a change gives delegators advance notice of a change in commission. Until the
+    /// scheduled epoch, the validator's previous funding streams remain in effect.
+    pub funding_streams_effective_epoch: Option<u64>,
+
     /// The sequence number determines which validator data takes priority, and
     /// prevents replay attacks.  The chain only accepts new
     /// [`ValidatorDefinition`]s with increasing sequence numbers, preventing a
@@ -106,6 +123,13 @@ pub struct ValidatorToml {
     /// other consensus operations.
     pub consensus_key: tendermint::PublicKey,
 
+    /// The index of the epoch from which the funding streams take effect, if they should not
+    /// take effect immediately.
+    // NOTE: this must precede the funding streams, since TOML requires values to be emitted
+    // before tables.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub funding_streams_effective_epoch: Option<u64>,
+
     /// The destinations for the validator's staking reward. The commission is implicitly defined
     /// by the configuration of funding_streams, the sum of FundingStream.rate_bps.
     ///
@@ -126,6 +150,7 @@ impl From<Validator> for ValidatorToml {
             description: v.description,
             enabled: v.enabled,
             funding_streams: v.funding_streams.into_iter().map(Into::into).collect(),
+            funding_streams_effective_epoch: v.funding_streams_effective_epoch,
             sequence_number: v.sequence_number,
         }
     }
@@ -149,6 +174,7 @@ impl TryFrom<ValidatorToml> for Validator {
                     .map(Into::into)
                     .collect::<Vec<_>>(),
             )?,
+            funding_streams_effective_epoch: v.funding_streams_effective_epoch,
             sequence_number: v.sequence_number,
         })
     }
@@ -165,10 +191,14 @@ pub enum FundingStreamToml {
         #[serde_as(as = "DisplayFromStr")]
         address: Address,
         rate_bps: u16,
+        #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+        auto_compound: bool,
     },
     CommunityPool {
         recipient: CommunityPool,
         rate_bps: u16,
+        #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+        auto_compound: bool,
     },
 }
 
@@ -179,12 +209,22 @@ pub struct CommunityPool;
 impl From<FundingStream> for FundingStreamToml {
     fn from(f: FundingStream) -> Self {
         match f {
-            FundingStream::ToAddress { address, rate_bps } => {
-                FundingStreamToml::Address { address, rate_bps }
-            }
-            FundingStream::ToCommunityPool { rate_bps } => FundingStreamToml::CommunityPool {
+            FundingStream::ToAddress {
+                address,
+                rate_bps,
+                auto_compound,
+            } => FundingStreamToml::Address {
+                address,
+                rate_bps,
+                auto_compound,
+            },
+            FundingStream::ToCommunityPool {
+                rate_bps,
+                auto_compound,
+            } => FundingStreamToml::CommunityPool {
                 rate_bps,
                 recipient: CommunityPool,
+                auto_compound,
             },
         }
     }
@@ -193,12 +233,23 @@ impl From<FundingStream> for FundingStreamToml {
 impl From<FundingStreamToml> for FundingStream {
     fn from(f: FundingStreamToml) -> Self {
         match f {
-            FundingStreamToml::Address { address, rate_bps } => {
-                FundingStream::ToAddress { address, rate_bps }
-            }
-            FundingStreamToml::CommunityPool { rate_bps, .. } => {
-                FundingStream::ToCommunityPool { rate_bps }
-            }
+            FundingStreamToml::Address {
+                address,
+                rate_bps,
+                auto_compound,
+            } => FundingStream::ToAddress {
+                address,
+                rate_bps,
+                auto_compound,
+            },
+            FundingStreamToml::CommunityPool {
+                rate_bps,
+                auto_compound,
+                ..
+            } => FundingStream::ToCommunityPool {
+                rate_bps,
+                auto_compound,
+            },
         }
     }
 }
@@ -219,6 +270,7 @@ impl From<Validator> for pb::Validator {
             enabled: v.enabled,
             funding_streams: v.funding_streams.into_iter().map(Into::into).collect(),
             sequence_number: v.sequence_number,
+            funding_streams_effective_epoch: v.funding_streams_effective_epoch.unwrap_or(0),
         }
     }
 }
@@ -247,6 +299,10 @@ impl TryFrom<pb::Validator> for Validator {
                 .map(TryInto::try_into)
                 .collect::<Result<Vec<FundingStream>, _>>()?
                 .try_into()?,
+            funding_streams_effective_epoch: match v.funding_streams_effective_epoch {
+                0 => None,
+                epoch => Some(epoch),
+            },
             sequence_number: v.sequence_number,
         })
     }
@@ -0,0 +1,380 @@
+use anyhow::anyhow;
+use penumbra_num::Amount;
+use penumbra_proto::{penumbra::core::component::stake::v1 as pb, DomainType};
+use serde::{Deserialize, Serialize};
+
+use super::State;
+use crate::{IdentityKey, Penalty};
+
+/// Why a validator is not in the active set.
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum InactivityReason {
+    /// The validator is active.
+    None,
+    /// The validator's delegation pool is worth less than the minimum validator stake.
+    InsufficientStake,
+    /// The validator's self-bond is worth less than the minimum self-delegation.
+    InsufficientSelfBond,
+    /// The validator has too little voting power to rank within the active validator limit.
+    OutsideActiveSet,
+    /// The validator qualifies for the active set, which is only updated at the end of an epoch.
+    PendingEpoch,
+    /// The validator's operator disabled it.
+    Disabled,
+    /// The validator was jailed for missing too many blocks.
+    JailedForDowntime,
+    /// The validator was tombstoned for signing conflicting votes.
+    TombstonedForMisbehavior,
+    /// The validator's state was pruned after it spent too long inactive with no stake.
+    Pruned,
+}
+
+/// An explanation of why a validator is, or is not, in the active set, computed from the staking
+/// component's state, so that its operator can debug its inactivity without scraping logs.
+#[derive(Debug, PartialEq, Eq, Clone, Serialize, Deserialize)]
+#[serde(try_from = "pb::ValidatorDiagnosis", into = "pb::ValidatorDiagnosis")]
+pub struct Diagnosis {
+    pub identity_key: IdentityKey,
+    /// The validator's state, or the state it was in when it was pruned.
+    pub state: State,
+    pub reason: InactivityReason,
+    /// The number of blocks the validator missed signing in the current uptime window.
+    pub missed_blocks: u64,
+    /// The number of missed blocks in the window at which the validator is jailed.
+    pub missed_blocks_maximum: u64,
+    /// The length of the uptime window, in blocks.
+    pub signed_blocks_window_len: u64,
+    /// The penalties applied to the validator, by epoch index.
+    pub penalties: Vec<(u64, Penalty)>,
+    /// The value of the validator's delegation pool, in staking tokens.
+    pub pool_value: Amount,
+    pub min_validator_stake: Amount,
+    /// The value of the validator's self-bond, in staking tokens.
+    pub self_bond_value: Amount,
+    pub min_validator_self_delegation: Amount,
+    /// The validator's voting power in the current epoch.
+    pub voting_power: Amount,
+    /// The validator's rank by voting power among the validators eligible for the active set,
+    /// starting from 1, if it is eligible.
+    pub power_rank: Option<u64>,
+    pub active_validator_limit: u64,
+    /// The voting power of the least powerful validator within the active validator limit, if
+    /// the limit is reached.
+    pub active_set_threshold: Option<Amount>,
+}
+
+impl Diagnosis {
+    /// The reason a validator in the given state, with the given stake and ranking, is not in the
+    /// active set.
+    ///
+    /// Validators only move between the defined, inactive and active states at the end of an
+    /// epoch, so a validator which would move into the active set then is reported as pending.
+    pub fn reason_for(
+        state: State,
+        pool_value: Amount,
+        min_validator_stake: Amount,
+        self_bond_value: Amount,
+        min_validator_self_delegation: Amount,
+        power_rank: Option<u64>,
+        active_validator_limit: u64,
+    ) -> InactivityReason {
+        match state {
+            State::Active => InactivityReason::None,
+            State::Disabled => InactivityReason::Disabled,
+            State::Jailed => InactivityReason::JailedForDowntime,
+            State::Tombstoned => InactivityReason::TombstonedForMisbehavior,
+            State::Defined | State::Inactive if pool_value < min_validator_stake => {
+                InactivityReason::InsufficientStake
+            }
+            State::Defined | State::Inactive if self_bond_value < min_validator_self_delegation => {
+                InactivityReason::InsufficientSelfBond
+            }
+            State::Inactive if power_rank.is_some_and(|rank| rank > active_validator_limit) => {
+                InactivityReason::OutsideActiveSet
+            }
+            State::Defined | State::Inactive => InactivityReason::PendingEpoch,
+        }
+    }
+
+    /// How much more stake the validator's delegation pool needs to reach the minimum validator
+    /// stake.
+    pub fn stake_shortfall(&self) -> Amount {
+        self.min_validator_stake.saturating_sub(&self.pool_value)
+    }
+
+    /// How much more the validator's self-bond needs to be worth to reach the minimum
+    /// self-delegation.
+    pub fn self_bond_shortfall(&self) -> Amount {
+        self.min_validator_self_delegation
+            .saturating_sub(&self.self_bond_value)
+    }
+
+    /// Steps the validator's operator can take to bring it back into the active set.
+    pub fn recovery_hints(&self) -> Vec<String> {
+        let mut hints = Vec::new();
+        match self.reason {
+            InactivityReason::None => {}
+            InactivityReason::InsufficientStake => hints.push(format!(
+                "the delegation pool is worth {}, {} short of the minimum validator stake of {}: \
+                 attract more delegations, and the validator becomes inactive at the end of the \
+                 epoch in which it reaches the minimum",
+                self.pool_value,
+                self.stake_shortfall(),
+                self.min_validator_stake
+            )),
+            InactivityReason::InsufficientSelfBond => hints.push(format!(
+                "the self-bond is worth {}, {} short of the minimum self-delegation of {}: bond \
+                 more delegation tokens to the validator itself",
+                self.self_bond_value,
+                self.self_bond_shortfall(),
+                self.min_validator_self_delegation
+            )),
+            InactivityReason::OutsideActiveSet => {
+                let rank = self.power_rank.unwrap_or_default();
+                match self.active_set_threshold {
+                    Some(threshold) => hints.push(format!(
+                        "the validator ranks {rank} by voting power, outside the active validator \
+                         limit of {}: it needs more than {} voting power, {} more than it has, to \
+                         displace the least powerful active validator",
+                        self.active_validator_limit,
+                        threshold,
+                        threshold.saturating_sub(&self.voting_power)
+                    )),
+                    None => hints.push(format!(
+                        "the validator ranks {rank} by voting power, outside the active validator \
+                         limit of {}",
+                        self.active_validator_limit
+                    )),
+                }
+            }
+            InactivityReason::PendingEpoch => hints.push(
+                "the validator qualifies for the active set, and is considered for it at the end \
+                 of the current epoch"
+                    .to_string(),
+            ),
+            InactivityReason::Disabled => hints.push(
+                "the validator was disabled by its operator: upload its definition with \
+                 `enabled = true` and a higher sequence number to re-enable it"
+                    .to_string(),
+            ),
+            InactivityReason::JailedForDowntime => {
+                hints.push(format!(
+                    "the validator was jailed for missing {} or more of {} blocks: make sure its \
+                     node is running and signing, then upload its definition again with a higher \
+                     sequence number to unjail it",
+                    self.missed_blocks_maximum, self.signed_blocks_window_len
+                ));
+                if self.pool_value < self.min_validator_stake {
+                    hints.push(format!(
+                        "once unjailed, the validator stays defined until its delegation pool \
+                         reaches the minimum validator stake of {}, {} more than it is worth",
+                        self.min_validator_stake,
+                        self.stake_shortfall()
+                    ));
+                }
+            }
+            InactivityReason::TombstonedForMisbehavior => hints.push(
+                "the validator was tombstoned for signing conflicting votes, which is permanent: \
+                 its delegators can only undelegate, and its operator must define a new validator \
+                 with a new identity key"
+                    .to_string(),
+            ),
+            InactivityReason::Pruned => hints.push(
+                "the validator's state was pruned after it was inactive with no stake for too \
+                 long: upload its definition with a higher sequence number than the one in its \
+                 tombstone to define it again"
+                    .to_string(),
+            ),
+        }
+
+        // An active validator close to being jailed is worth warning about too.
+        if self.state == State::Active
+            && self.missed_blocks_maximum > 0
+            && self.missed_blocks.saturating_mul(2) >= self.missed_blocks_maximum
+        {
+            hints.push(format!(
+                "the validator missed {} of the last {} blocks, and is jailed at {}: check that \
+                 its node is signing",
+                self.missed_blocks, self.signed_blocks_window_len, self.missed_blocks_maximum
+            ));
+        }
+
+        hints
+    }
+}
+
+impl From<InactivityReason> for pb::validator_diagnosis::InactivityReason {
+    fn from(reason: InactivityReason) -> Self {
+        use pb::validator_diagnosis::InactivityReason as R;
+        match reason {
+            InactivityReason::None => R::None,
+            InactivityReason::InsufficientStake => R::InsufficientStake,
+            InactivityReason::InsufficientSelfBond => R::InsufficientSelfBond,
+            InactivityReason::OutsideActiveSet => R::OutsideActiveSet,
+            InactivityReason::PendingEpoch => R::PendingEpoch,
+            InactivityReason::Disabled => R::Disabled,
+            InactivityReason::JailedForDowntime => R::JailedForDowntime,
+            InactivityReason::TombstonedForMisbehavior => R::TombstonedForMisbehavior,
+            InactivityReason::Pruned => R::Pruned,
+        }
+    }
+}
+
+impl TryFrom<pb::validator_diagnosis::InactivityReason> for InactivityReason {
+    type Error = anyhow::Error;
+    fn try_from(reason: pb::validator_diagnosis::InactivityReason) -> Result<Self, Self::Error> {
+        use pb::validator_diagnosis::InactivityReason as R;
+        Ok(match reason {
+            R::None => InactivityReason::None,
+            R::InsufficientStake => InactivityReason::InsufficientStake,
+            R::InsufficientSelfBond => InactivityReason::InsufficientSelfBond,
+            R::OutsideActiveSet => InactivityReason::OutsideActiveSet,
+            R::PendingEpoch => InactivityReason::PendingEpoch,
+            R::Disabled => InactivityReason::Disabled,
+            R::JailedForDowntime => InactivityReason::JailedForDowntime,
+            R::TombstonedForMisbehavior => InactivityReason::TombstonedForMisbehavior,
+            R::Pruned => InactivityReason::Pruned,
+            R::Unspecified => return Err(anyhow!("unspecified inactivity reason")),
+        })
+    }
+}
+
+impl DomainType for Diagnosis {
+    type Proto = pb::ValidatorDiagnosis;
+}
+
+impl From<Diagnosis> for pb::ValidatorDiagnosis {
+    fn from(v: Diagnosis) -> Self {
+        let recovery_hints = v.recovery_hints();
+        pb::ValidatorDiagnosis {
+            identity_key: Some(v.identity_key.into()),
+            state: Some(v.state.into()),
+            reason: pb::validator_diagnosis::InactivityReason::from(v.reason) as i32,
+            missed_blocks: v.missed_blocks,
+            missed_blocks_maximum: v.missed_blocks_maximum,
+            signed_blocks_window_len: v.signed_blocks_window_len,
+            penalties: v
+                .penalties
+                .into_iter()
+                .map(
+                    |(epoch_index, penalty)| pb::validator_diagnosis::EpochPenalty {
+                        epoch_index,
+                        penalty: Some(penalty.into()),
+                    },
+                )
+                .collect(),
+            pool_value: Some(v.pool_value.into()),
+            min_validator_stake: Some(v.min_validator_stake.into()),
+            self_bond_value: Some(v.self_bond_value.into()),
+            min_validator_self_delegation: Some(v.min_validator_self_delegation.into()),
+            voting_power: Some(v.voting_power.into()),
+            power_rank: v.power_rank.unwrap_or(0),
+            active_validator_limit: v.active_validator_limit,
+            active_set_threshold: v.active_set_threshold.map(Into::into),
+            recovery_hints,
+        }
+    }
+}
+
+impl TryFrom<pb::ValidatorDiagnosis> for Diagnosis {
+    type Error = anyhow::Error;
+    fn try_from(v: pb::ValidatorDiagnosis) -> Result<Self, Self::Error> {
+        let amount = |amount: Option<penumbra_proto::core::num::v1::Amount>,
+                      field: &str|
+         -> anyhow::Result<Amount> {
+            amount
+                .ok_or_else(|| anyhow!("missing {field} field in proto"))?
+                .try_into()
+        };
+
+        // The recovery hints are derived from the other fields, so they are not decoded.
+        Ok(Diagnosis {
+            identity_key: v
+                .identity_key
+                .ok_or_else(|| anyhow!("missing identity key field in proto"))?
+                .try_into()?,
+            state: v
+                .state
+                .ok_or_else(|| anyhow!("missing state field in proto"))?
+                .try_into()?,
+            reason: pb::validator_diagnosis::InactivityReason::try_from(v.reason)
+                .map_err(|e| anyhow!("invalid inactivity reason, error: {e}"))?
+                .try_into()?,
+            missed_blocks: v.missed_blocks,
+            missed_blocks_maximum: v.missed_blocks_maximum,
+            signed_blocks_window_len: v.signed_blocks_window_len,
+            penalties: v
+                .penalties
+                .into_iter()
+                .map(|p| {
+                    Ok((
+                        p.epoch_index,
+                        p.penalty
+                            .ok_or_else(|| anyhow!("missing penalty field in proto"))?
+                            .try_into()?,
+                    ))
+                })
+                .collect::<anyhow::Result<_>>()?,
+            pool_value: amount(v.pool_value, "pool_value")?,
+            min_validator_stake: amount(v.min_validator_stake, "min_validator_stake")?,
+            self_bond_value: amount(v.self_bond_value, "self_bond_value")?,
+            min_validator_self_delegation: amount(
+                v.min_validator_self_delegation,
+                "min_validator_self_delegation",
+            )?,
+            voting_power: amount(v.voting_power, "voting_power")?,
+            power_rank: (v.power_rank > 0).then_some(v.power_rank),
+            active_validator_limit: v.active_validator_limit,
+            active_set_threshold: v.active_set_threshold.map(TryInto::try_into).transpose()?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn inactive_validators_are_explained() {
+        let reason = |state, pool: u64, self_bond: u64, rank| {
+            Diagnosis::reason_for(
+                state,
+                pool.into(),
+                100u64.into(),
+                self_bond.into(),
+                10u64.into(),
+                rank,
+                3,
+            )
+        };
+
+        assert_eq!(
+            reason(State::Active, 500, 50, Some(1)),
+            InactivityReason::None
+        );
+        assert_eq!(
+            reason(State::Defined, 50, 50, None),
+            InactivityReason::InsufficientStake
+        );
+        assert_eq!(
+            reason(State::Defined, 500, 50, None),
+            InactivityReason::PendingEpoch
+        );
+        assert_eq!(
+            reason(State::Inactive, 500, 5, None),
+            InactivityReason::InsufficientSelfBond
+        );
+        assert_eq!(
+            reason(State::Inactive, 500, 50, Some(4)),
+            InactivityReason::OutsideActiveSet
+        );
+        assert_eq!(
+            reason(State::Inactive, 500, 50, Some(3)),
+            InactivityReason::PendingEpoch
+        );
+        assert_eq!(
+            reason(State::Jailed, 0, 0, None),
+            InactivityReason::JailedForDowntime
+        );
+    }
+}
@@ -0,0 +1,77 @@
+//! Comparison of validator names, to protect delegators from validators impersonating others.
+//!
+//! Names are compared in two ways. Their [normalized](normalize_name) forms fold case, width and
+//! whitespace, so that names which differ only in these ways are considered the same name, and can
+//! be required to be unique. Their [skeletons](name_skeleton) go further, ignoring punctuation and
+//! folding characters which are easily mistaken for one another, so that near-duplicates can be
+//! flagged without being rejected, since they may be legitimate.
+
+/// Normalize a validator name for comparison, folding fullwidth characters to their ASCII forms,
+/// lowercasing, and collapsing runs of whitespace.
+pub fn normalize_name(name: &str) -> String {
+    name.chars()
+        .map(fold_width)
+        .flat_map(char::to_lowercase)
+        .collect::<String>()
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Reduce a validator name to a skeleton which is the same for names that are easily mistaken for
+/// one another, ignoring everything but letters and digits, and folding digits and letters which
+/// look alike.
+pub fn name_skeleton(name: &str) -> String {
+    normalize_name(name)
+        .chars()
+        .filter(|c| c.is_alphanumeric())
+        .map(|c| match c {
+            '0' => 'o',
+            '1' | 'i' => 'l',
+            '3' => 'e',
+            '5' => 's',
+            '8' => 'b',
+            c => c,
+        })
+        .collect::<String>()
+        .replace("rn", "m")
+        .replace("vv", "w")
+}
+
+/// Fold a fullwidth form of an ASCII character, or the ideographic space, to its ASCII form.
+fn fold_width(c: char) -> char {
+    match c {
+        '\u{3000}' => ' ',
+        '\u{FF01}'..='\u{FF5E}' => char::from_u32(c as u32 - 0xFEE0).unwrap_or(c),
+        c => c,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn names_are_folded() {
+        assert_eq!(normalize_name("  Penumbra   Labs "), "penumbra labs");
+        assert_eq!(
+            normalize_name("ＰＥＮＵＭＢＲＡ\u{3000}Labs"),
+            "penumbra labs"
+        );
+        assert_ne!(
+            normalize_name("Penumbra-Labs"),
+            normalize_name("Penumbra Labs")
+        );
+
+        assert_eq!(
+            name_skeleton("Penumbra-Labs"),
+            name_skeleton("penumbra labs")
+        );
+        assert_eq!(name_skeleton("C0SM0STATI0N"), name_skeleton("cosmostation"));
+        assert_eq!(name_skeleton("Modulus"), name_skeleton("rnodulus"));
+        assert_ne!(
+            name_skeleton("Penumbra Labs"),
+            name_skeleton("Penumbra Lab")
+        );
+    }
+}
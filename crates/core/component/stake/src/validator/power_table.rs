@@ -0,0 +1,399 @@
+use penumbra_num::Amount;
+use penumbra_proto::{
+    core::component::stake::v1::validator_set_snapshot_proof::KeyProof as PbKeyProof,
+    penumbra::core::component::stake::v1 as pb, DomainType,
+};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+use super::KeyProof;
+use crate::IdentityKey;
+
+/// Domain separators for the hashes of the Merkle tree over a [`PowerTable`].
+const LEAF_TAG: &[u8] = b"penumbra.stake.power_table.leaf";
+const NODE_TAG: &[u8] = b"penumbra.stake.power_table.node";
+const EMPTY_TAG: &[u8] = b"penumbra.stake.power_table.empty";
+
+/// The voting power of a single validator, as recorded in a [`PowerTable`].
+#[derive(Debug, PartialEq, Eq, Clone, Serialize, Deserialize)]
+#[serde(try_from = "pb::ValidatorPower", into = "pb::ValidatorPower")]
+pub struct Power {
+    pub identity_key: IdentityKey,
+    pub voting_power: Amount,
+}
+
+impl Power {
+    fn leaf_hash(&self) -> [u8; 32] {
+        Sha256::new()
+            .chain_update(LEAF_TAG)
+            .chain_update(self.identity_key.0.to_bytes())
+            .chain_update(self.voting_power.value().to_le_bytes())
+            .finalize()
+            .into()
+    }
+}
+
+fn node_hash(left: &[u8; 32], right: &[u8; 32]) -> [u8; 32] {
+    Sha256::new()
+        .chain_update(NODE_TAG)
+        .chain_update(left)
+        .chain_update(right)
+        .finalize()
+        .into()
+}
+
+/// The voting power of every validator in the consensus set during an epoch, as recorded when
+/// the epoch began.
+///
+/// The chain state only records a [`PowerTableCommitment`] to each epoch's table, which is the
+/// root of a Merkle tree over its entries, so that a validator's power in a past epoch can be
+/// proven with a [`PowerProof`] without keeping every table in the verifiable state.
+#[derive(Debug, PartialEq, Eq, Clone, Default, Serialize, Deserialize)]
+#[serde(try_from = "pb::ValidatorPowerTable", into = "pb::ValidatorPowerTable")]
+pub struct PowerTable {
+    /// The index of the epoch the table applies to.
+    pub epoch_index: u64,
+    /// The validators' voting power, ordered by identity key.
+    pub entries: Vec<Power>,
+}
+
+impl PowerTable {
+    /// Assemble a power table from the given entries, in canonical order.
+    pub fn new(epoch_index: u64, mut entries: Vec<Power>) -> Self {
+        entries.sort_by(|a, b| a.identity_key.cmp(&b.identity_key));
+        Self {
+            epoch_index,
+            entries,
+        }
+    }
+
+    /// The levels of the Merkle tree over the table's entries, from the leaves to the root.
+    ///
+    /// A node without a sibling is carried up to the next level unchanged.
+    fn levels(&self) -> Vec<Vec<[u8; 32]>> {
+        let mut levels = vec![self
+            .entries
+            .iter()
+            .map(Power::leaf_hash)
+            .collect::<Vec<_>>()];
+        while levels.last().expect("there is always a level").len() > 1 {
+            let next = levels
+                .last()
+                .expect("there is always a level")
+                .chunks(2)
+                .map(|pair| match pair {
+                    [left, right] => node_hash(left, right),
+                    [node] => *node,
+                    _ => unreachable!("chunks have one or two nodes"),
+                })
+                .collect();
+            levels.push(next);
+        }
+        levels
+    }
+
+    /// Compute the commitment to the table.
+    pub fn commitment(&self) -> PowerTableCommitment {
+        match self.levels().last().and_then(|root| root.first()) {
+            Some(root) => PowerTableCommitment(*root),
+            None => PowerTableCommitment(Sha256::digest(EMPTY_TAG).into()),
+        }
+    }
+
+    /// Returns the validator's entry in the table, with the position of the entry and the sibling
+    /// hashes on its path to the table commitment, if the validator is in the table.
+    pub fn path(&self, identity_key: &IdentityKey) -> Option<(Power, u64, Vec<[u8; 32]>)> {
+        let index = self
+            .entries
+            .binary_search_by(|entry| entry.identity_key.cmp(identity_key))
+            .ok()?;
+
+        let mut siblings = Vec::new();
+        let mut position = index;
+        for level in self.levels() {
+            let sibling = if position % 2 == 1 {
+                Some(position - 1)
+            } else {
+                Some(position + 1).filter(|&sibling| sibling < level.len())
+            };
+            if let Some(sibling) = sibling {
+                siblings.push(level[sibling]);
+            }
+            position /= 2;
+        }
+
+        Some((self.entries[index].clone(), index as u64, siblings))
+    }
+}
+
+/// A commitment to a [`PowerTable`], recorded in the chain state for each epoch.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Serialize, Deserialize)]
+#[serde(
+    try_from = "pb::ValidatorPowerTableCommitment",
+    into = "pb::ValidatorPowerTableCommitment"
+)]
+pub struct PowerTableCommitment(pub [u8; 32]);
+
+/// A proof that a validator had some voting power in an epoch, against the app hash of the state
+/// it was taken from.
+///
+/// The proof consists of the validator's entry in the epoch's [`PowerTable`] with its Merkle path
+/// to the table's commitment, and an ICS23 proof of that commitment in the chain state.
+#[derive(Debug, PartialEq, Eq, Clone, Serialize, Deserialize)]
+#[serde(try_from = "pb::ValidatorPowerProof", into = "pb::ValidatorPowerProof")]
+pub struct PowerProof {
+    /// The index of the epoch the power applies to.
+    pub epoch_index: u64,
+    /// The validator's voting power in the epoch.
+    pub power: Power,
+    /// The position of the validator's entry in the power table.
+    pub leaf_index: u64,
+    /// The number of entries in the power table.
+    pub leaf_count: u64,
+    /// The sibling hashes on the path from the entry to the table commitment.
+    pub siblings: Vec<[u8; 32]>,
+    /// A proof of the epoch's table commitment against the app hash.
+    pub commitment_proof: KeyProof,
+    /// The height of the state the proof was taken from.
+    pub height: u64,
+    /// The app hash of that state, which is committed to by the header of the following block.
+    pub app_hash: Vec<u8>,
+}
+
+impl PowerProof {
+    /// Check that the proven entry is included in the power table with the given commitment.
+    ///
+    /// This does not check the commitment itself against the app hash.
+    pub fn verify_inclusion(&self, commitment: &PowerTableCommitment) -> anyhow::Result<()> {
+        anyhow::ensure!(
+            self.leaf_index < self.leaf_count,
+            "entry {} is out of bounds of a power table with {} entries",
+            self.leaf_index,
+            self.leaf_count
+        );
+
+        let mut siblings = self.siblings.iter();
+        let mut hash = self.power.leaf_hash();
+        let mut position = self.leaf_index;
+        let mut width = self.leaf_count;
+        while width > 1 {
+            if position % 2 == 1 {
+                let sibling = siblings
+                    .next()
+                    .ok_or_else(|| anyhow::anyhow!("power table path is too short"))?;
+                hash = node_hash(sibling, &hash);
+            } else if position + 1 < width {
+                let sibling = siblings
+                    .next()
+                    .ok_or_else(|| anyhow::anyhow!("power table path is too short"))?;
+                hash = node_hash(&hash, sibling);
+            }
+            position /= 2;
+            width = (width + 1) / 2;
+        }
+        anyhow::ensure!(siblings.next().is_none(), "power table path is too long");
+        anyhow::ensure!(
+            hash == commitment.0,
+            "power table path does not lead to the table commitment"
+        );
+
+        Ok(())
+    }
+}
+
+impl DomainType for Power {
+    type Proto = pb::ValidatorPower;
+}
+
+impl From<Power> for pb::ValidatorPower {
+    fn from(v: Power) -> Self {
+        pb::ValidatorPower {
+            identity_key: Some(v.identity_key.into()),
+            voting_power: Some(v.voting_power.into()),
+        }
+    }
+}
+
+impl TryFrom<pb::ValidatorPower> for Power {
+    type Error = anyhow::Error;
+    fn try_from(v: pb::ValidatorPower) -> Result<Self, Self::Error> {
+        Ok(Power {
+            identity_key: v
+                .identity_key
+                .ok_or_else(|| anyhow::anyhow!("missing identity key field in proto"))?
+                .try_into()?,
+            voting_power: v
+                .voting_power
+                .ok_or_else(|| anyhow::anyhow!("missing voting power field in proto"))?
+                .try_into()?,
+        })
+    }
+}
+
+impl DomainType for PowerTable {
+    type Proto = pb::ValidatorPowerTable;
+}
+
+impl From<PowerTable> for pb::ValidatorPowerTable {
+    fn from(v: PowerTable) -> Self {
+        pb::ValidatorPowerTable {
+            epoch_index: v.epoch_index,
+            entries: v.entries.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+impl TryFrom<pb::ValidatorPowerTable> for PowerTable {
+    type Error = anyhow::Error;
+    fn try_from(v: pb::ValidatorPowerTable) -> Result<Self, Self::Error> {
+        Ok(PowerTable::new(
+            v.epoch_index,
+            v.entries
+                .into_iter()
+                .map(TryInto::try_into)
+                .collect::<Result<_, _>>()?,
+        ))
+    }
+}
+
+impl DomainType for PowerTableCommitment {
+    type Proto = pb::ValidatorPowerTableCommitment;
+}
+
+impl From<PowerTableCommitment> for pb::ValidatorPowerTableCommitment {
+    fn from(v: PowerTableCommitment) -> Self {
+        pb::ValidatorPowerTableCommitment {
+            inner: v.0.to_vec(),
+        }
+    }
+}
+
+impl TryFrom<pb::ValidatorPowerTableCommitment> for PowerTableCommitment {
+    type Error = anyhow::Error;
+    fn try_from(v: pb::ValidatorPowerTableCommitment) -> Result<Self, Self::Error> {
+        Ok(PowerTableCommitment(v.inner.try_into().map_err(|_| {
+            anyhow::anyhow!("power table commitment must be 32 bytes")
+        })?))
+    }
+}
+
+impl DomainType for PowerProof {
+    type Proto = pb::ValidatorPowerProof;
+}
+
+impl From<PowerProof> for pb::ValidatorPowerProof {
+    fn from(v: PowerProof) -> Self {
+        pb::ValidatorPowerProof {
+            epoch_index: v.epoch_index,
+            power: Some(v.power.into()),
+            leaf_index: v.leaf_index,
+            leaf_count: v.leaf_count,
+            siblings: v.siblings.into_iter().map(|s| s.to_vec()).collect(),
+            commitment_proof: Some(PbKeyProof {
+                key: v.commitment_proof.key,
+                value: v.commitment_proof.value,
+                proof: v.commitment_proof.proof,
+            }),
+            height: v.height,
+            app_hash: v.app_hash,
+        }
+    }
+}
+
+impl TryFrom<pb::ValidatorPowerProof> for PowerProof {
+    type Error = anyhow::Error;
+    fn try_from(v: pb::ValidatorPowerProof) -> Result<Self, Self::Error> {
+        let commitment_proof = v
+            .commitment_proof
+            .ok_or_else(|| anyhow::anyhow!("missing commitment proof field in proto"))?;
+        Ok(PowerProof {
+            epoch_index: v.epoch_index,
+            power: v
+                .power
+                .ok_or_else(|| anyhow::anyhow!("missing power field in proto"))?
+                .try_into()?,
+            leaf_index: v.leaf_index,
+            leaf_count: v.leaf_count,
+            siblings: v
+                .siblings
+                .into_iter()
+                .map(|s| {
+                    s.try_into()
+                        .map_err(|_| anyhow::anyhow!("power table path hashes must be 32 bytes"))
+                })
+                .collect::<Result<_, _>>()?,
+            commitment_proof: KeyProof {
+                key: commitment_proof.key,
+                value: commitment_proof.value,
+                proof: commitment_proof.proof,
+            },
+            height: v.height,
+            app_hash: v.app_hash,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use decaf377_rdsa as rdsa;
+    use rand_core::OsRng;
+
+    use super::*;
+
+    fn proof_for(table: &PowerTable, identity_key: &IdentityKey) -> PowerProof {
+        let (power, leaf_index, siblings) = table.path(identity_key).expect("validator in table");
+        PowerProof {
+            epoch_index: table.epoch_index,
+            power,
+            leaf_index,
+            leaf_count: table.entries.len() as u64,
+            siblings,
+            commitment_proof: KeyProof {
+                key: String::new(),
+                value: Vec::new(),
+                proof: Vec::new(),
+            },
+            height: 0,
+            app_hash: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn power_table_paths_lead_to_commitment() {
+        // Check tables of every size up to a few levels deep, so that the paths of
+        // entries carried up without a sibling are exercised.
+        for size in 1..=9u64 {
+            let table = PowerTable::new(
+                7,
+                (0..size)
+                    .map(|i| {
+                        let sk = rdsa::SigningKey::new(OsRng);
+                        Power {
+                            identity_key: IdentityKey((&sk).into()),
+                            voting_power: Amount::from(i * 100),
+                        }
+                    })
+                    .collect(),
+            );
+            let commitment = table.commitment();
+
+            for entry in &table.entries {
+                let proof = proof_for(&table, &entry.identity_key);
+                proof.verify_inclusion(&commitment).expect("valid proof");
+
+                let mut wrong_power = proof.clone();
+                wrong_power.power.voting_power = Amount::from(1_000_000u64);
+                assert!(wrong_power.verify_inclusion(&commitment).is_err());
+
+                let mut wrong_index = proof.clone();
+                wrong_index.leaf_index = (proof.leaf_index + 1) % size;
+                if size > 1 {
+                    assert!(wrong_index.verify_inclusion(&commitment).is_err());
+                }
+            }
+
+            let sk = rdsa::SigningKey::new(OsRng);
+            assert!(table.path(&IdentityKey((&sk).into())).is_none());
+        }
+    }
+}
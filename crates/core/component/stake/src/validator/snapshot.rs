@@ -0,0 +1,191 @@
+use penumbra_proto::{
+    core::component::stake::v1::{
+        validator_set_snapshot_proof::KeyProof as PbKeyProof, ValidatorSetSnapshotResponse,
+    },
+    penumbra::core::component::stake::v1 as pb,
+    DomainType,
+};
+use serde::{Deserialize, Serialize};
+
+use super::Info;
+use crate::Uptime;
+
+/// The recorded state of a single validator, as part of a [`SetSnapshot`].
+#[derive(Debug, PartialEq, Eq, Clone, Serialize, Deserialize)]
+#[serde(try_from = "pb::ValidatorSnapshot", into = "pb::ValidatorSnapshot")]
+pub struct Snapshot {
+    /// The validator's definition, status, and current rate data.
+    pub info: Info,
+    /// The validator's uptime tracker, if it has one.
+    pub uptime: Option<Uptime>,
+}
+
+/// A snapshot of the state of every known validator at some height.
+///
+/// Snapshots are deterministic: the validators are ordered by identity key, so
+/// two snapshots of the same state are identical.
+#[derive(Debug, PartialEq, Eq, Clone, Default, Serialize, Deserialize)]
+#[serde(
+    try_from = "pb::ValidatorSetSnapshot",
+    into = "pb::ValidatorSetSnapshot"
+)]
+pub struct SetSnapshot {
+    /// The height at which the snapshot was taken.
+    pub height: u64,
+    /// The validators, ordered by identity key.
+    pub validators: Vec<Snapshot>,
+}
+
+impl SetSnapshot {
+    /// Assemble a snapshot from the given validators, in canonical order.
+    pub fn new(height: u64, mut validators: Vec<Snapshot>) -> Self {
+        validators.sort_by(|a, b| {
+            a.info
+                .validator
+                .identity_key
+                .cmp(&b.info.validator.identity_key)
+        });
+        Self { height, validators }
+    }
+}
+
+/// A proof of the contents of a [`SetSnapshot`] against the app hash of the
+/// state it was taken from.
+///
+/// The proof consists of ICS23 proofs of the raw values of the state keys
+/// backing the snapshot, so that a node can check a snapshot exported by an
+/// untrusted peer against an app hash it trusts, such as one from a verified
+/// block header.
+#[derive(Debug, PartialEq, Eq, Clone, Default, Serialize, Deserialize)]
+#[serde(
+    try_from = "pb::ValidatorSetSnapshotProof",
+    into = "pb::ValidatorSetSnapshotProof"
+)]
+pub struct SetSnapshotProof {
+    /// The app hash the proofs are against.
+    pub app_hash: Vec<u8>,
+    /// Proofs of the block height and of each validator's recorded state.
+    pub key_proofs: Vec<KeyProof>,
+}
+
+/// A proof of the value stored under a single state key.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct KeyProof {
+    /// The state key.
+    pub key: String,
+    /// The raw value stored under the key.
+    pub value: Vec<u8>,
+    /// The encoded ICS23 `MerkleProof` of the value against the app hash.
+    pub proof: Vec<u8>,
+}
+
+impl DomainType for Snapshot {
+    type Proto = pb::ValidatorSnapshot;
+}
+
+impl From<Snapshot> for pb::ValidatorSnapshot {
+    fn from(v: Snapshot) -> Self {
+        pb::ValidatorSnapshot {
+            info: Some(v.info.into()),
+            uptime: v.uptime.map(Into::into),
+        }
+    }
+}
+
+impl TryFrom<pb::ValidatorSnapshot> for Snapshot {
+    type Error = anyhow::Error;
+    fn try_from(v: pb::ValidatorSnapshot) -> Result<Self, Self::Error> {
+        Ok(Snapshot {
+            info: v
+                .info
+                .ok_or_else(|| anyhow::anyhow!("missing info field in proto"))?
+                .try_into()?,
+            uptime: v.uptime.map(TryInto::try_into).transpose()?,
+        })
+    }
+}
+
+impl DomainType for SetSnapshot {
+    type Proto = pb::ValidatorSetSnapshot;
+}
+
+impl From<SetSnapshot> for pb::ValidatorSetSnapshot {
+    fn from(v: SetSnapshot) -> Self {
+        pb::ValidatorSetSnapshot {
+            height: v.height,
+            validators: v.validators.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+impl TryFrom<pb::ValidatorSetSnapshot> for SetSnapshot {
+    type Error = anyhow::Error;
+    fn try_from(v: pb::ValidatorSetSnapshot) -> Result<Self, Self::Error> {
+        Ok(SetSnapshot::new(
+            v.height,
+            v.validators
+                .into_iter()
+                .map(TryInto::try_into)
+                .collect::<Result<_, _>>()?,
+        ))
+    }
+}
+
+impl DomainType for SetSnapshotProof {
+    type Proto = pb::ValidatorSetSnapshotProof;
+}
+
+impl From<SetSnapshotProof> for pb::ValidatorSetSnapshotProof {
+    fn from(v: SetSnapshotProof) -> Self {
+        pb::ValidatorSetSnapshotProof {
+            app_hash: v.app_hash,
+            key_proofs: v
+                .key_proofs
+                .into_iter()
+                .map(|p| PbKeyProof {
+                    key: p.key,
+                    value: p.value,
+                    proof: p.proof,
+                })
+                .collect(),
+        }
+    }
+}
+
+impl TryFrom<pb::ValidatorSetSnapshotProof> for SetSnapshotProof {
+    type Error = anyhow::Error;
+    fn try_from(v: pb::ValidatorSetSnapshotProof) -> Result<Self, Self::Error> {
+        Ok(SetSnapshotProof {
+            app_hash: v.app_hash,
+            key_proofs: v
+                .key_proofs
+                .into_iter()
+                .map(|p| KeyProof {
+                    key: p.key,
+                    value: p.value,
+                    proof: p.proof,
+                })
+                .collect(),
+        })
+    }
+}
+
+impl From<SetSnapshot> for ValidatorSetSnapshotResponse {
+    fn from(v: SetSnapshot) -> Self {
+        ValidatorSetSnapshotResponse {
+            snapshot: Some(v.into()),
+            proof: None,
+        }
+    }
+}
+
+impl TryFrom<ValidatorSetSnapshotResponse> for SetSnapshot {
+    type Error = anyhow::Error;
+
+    fn try_from(response: ValidatorSetSnapshotResponse) -> Result<Self, Self::Error> {
+        response
+            .snapshot
+            .ok_or_else(|| anyhow::anyhow!("empty ValidatorSetSnapshotResponse message"))?
+            .try_into()
+    }
+}
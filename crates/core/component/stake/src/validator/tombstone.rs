@@ -0,0 +1,70 @@
+use penumbra_proto::{penumbra::core::component::stake::v1 as pb, DomainType};
+use serde::{Deserialize, Serialize};
+
+use super::State;
+use crate::{rate::RateData, IdentityKey};
+
+/// A compact record of a validator whose state was pruned after it spent too long defined or
+/// disabled with no stake delegated to it.
+///
+/// This is unrelated to [`State::Tombstoned`], which results from misbehavior.
+#[derive(Debug, PartialEq, Eq, Clone, Serialize, Deserialize)]
+#[serde(try_from = "pb::ValidatorTombstone", into = "pb::ValidatorTombstone")]
+pub struct Tombstone {
+    pub identity_key: IdentityKey,
+    /// The name from the validator's last definition.
+    pub name: String,
+    /// The sequence number of the validator's last definition, which any later definition must
+    /// exceed.
+    pub sequence_number: u32,
+    /// The state the validator was in when it was pruned.
+    pub state: State,
+    /// The validator's rate data when it was pruned.
+    pub rate_data: RateData,
+    /// The epoch since which the validator had been inactive with no stake.
+    pub inactive_since_epoch_index: u64,
+    /// The epoch at the end of which the validator was pruned.
+    pub pruned_epoch_index: u64,
+}
+
+impl DomainType for Tombstone {
+    type Proto = pb::ValidatorTombstone;
+}
+
+impl From<Tombstone> for pb::ValidatorTombstone {
+    fn from(v: Tombstone) -> Self {
+        pb::ValidatorTombstone {
+            identity_key: Some(v.identity_key.into()),
+            name: v.name,
+            sequence_number: v.sequence_number,
+            state: Some(v.state.into()),
+            rate_data: Some(v.rate_data.into()),
+            inactive_since_epoch_index: v.inactive_since_epoch_index,
+            pruned_epoch_index: v.pruned_epoch_index,
+        }
+    }
+}
+
+impl TryFrom<pb::ValidatorTombstone> for Tombstone {
+    type Error = anyhow::Error;
+    fn try_from(v: pb::ValidatorTombstone) -> Result<Self, Self::Error> {
+        Ok(Tombstone {
+            identity_key: v
+                .identity_key
+                .ok_or_else(|| anyhow::anyhow!("missing identity key field in proto"))?
+                .try_into()?,
+            name: v.name,
+            sequence_number: v.sequence_number,
+            state: v
+                .state
+                .ok_or_else(|| anyhow::anyhow!("missing state field in proto"))?
+                .try_into()?,
+            rate_data: v
+                .rate_data
+                .ok_or_else(|| anyhow::anyhow!("missing rate_data field in proto"))?
+                .try_into()?,
+            inactive_since_epoch_index: v.inactive_since_epoch_index,
+            pruned_epoch_index: v.pruned_epoch_index,
+        })
+    }
+}
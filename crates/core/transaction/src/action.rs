@@ -23,15 +23,26 @@ pub enum Action {
     ProposalWithdraw(penumbra_governance::ProposalWithdraw),
     DelegatorVote(penumbra_governance::DelegatorVote),
     ValidatorVote(penumbra_governance::ValidatorVote),
+    BallotKeyDeal(penumbra_governance::BallotKeyDeal),
+    BallotKeyReveal(penumbra_governance::BallotKeyReveal),
+    BallotKeyComplaint(penumbra_governance::BallotKeyComplaint),
     ProposalDepositClaim(penumbra_governance::ProposalDepositClaim),
 
     PositionOpen(penumbra_dex::lp::action::PositionOpen),
+    PositionOpenBatch(penumbra_dex::lp::action::PositionOpenBatch),
     PositionClose(penumbra_dex::lp::action::PositionClose),
     PositionWithdraw(penumbra_dex::lp::action::PositionWithdraw),
+    PositionUpdateBatch(penumbra_dex::lp::action::PositionUpdateBatch),
+    PositionRewrite(penumbra_dex::lp::action::PositionRewrite),
+
+    ActionDutchAuctionSchedule(penumbra_dex::ActionDutchAuctionSchedule),
+    ActionDutchAuctionEnd(penumbra_dex::ActionDutchAuctionEnd),
+    ActionDutchAuctionWithdraw(penumbra_dex::ActionDutchAuctionWithdraw),
 
     Delegate(penumbra_stake::Delegate),
     Undelegate(penumbra_stake::Undelegate),
     UndelegateClaim(penumbra_stake::UndelegateClaim),
+    Redelegate(penumbra_stake::Redelegate),
 
     Ics20Withdrawal(penumbra_shielded_pool::Ics20Withdrawal),
 
@@ -48,18 +59,28 @@ impl EffectingData for Action {
             Action::Delegate(delegate) => delegate.effect_hash(),
             Action::Undelegate(undelegate) => undelegate.effect_hash(),
             Action::UndelegateClaim(claim) => claim.effect_hash(),
+            Action::Redelegate(redelegate) => redelegate.effect_hash(),
             Action::ProposalSubmit(submit) => submit.effect_hash(),
             Action::ProposalWithdraw(withdraw) => withdraw.effect_hash(),
             Action::ProposalDepositClaim(claim) => claim.effect_hash(),
             Action::DelegatorVote(vote) => vote.effect_hash(),
             Action::ValidatorVote(vote) => vote.effect_hash(),
+            Action::BallotKeyDeal(deal) => deal.effect_hash(),
+            Action::BallotKeyReveal(reveal) => reveal.effect_hash(),
+            Action::BallotKeyComplaint(complaint) => complaint.effect_hash(),
             Action::SwapClaim(swap_claim) => swap_claim.effect_hash(),
             Action::Swap(swap) => swap.effect_hash(),
             Action::ValidatorDefinition(defn) => defn.effect_hash(),
             Action::IbcRelay(payload) => payload.effect_hash(),
             Action::PositionOpen(p) => p.effect_hash(),
+            Action::PositionOpenBatch(p) => p.effect_hash(),
             Action::PositionClose(p) => p.effect_hash(),
             Action::PositionWithdraw(p) => p.effect_hash(),
+            Action::PositionUpdateBatch(p) => p.effect_hash(),
+            Action::PositionRewrite(p) => p.effect_hash(),
+            Action::ActionDutchAuctionSchedule(a) => a.effect_hash(),
+            Action::ActionDutchAuctionEnd(a) => a.effect_hash(),
+            Action::ActionDutchAuctionWithdraw(a) => a.effect_hash(),
             Action::Ics20Withdrawal(w) => w.effect_hash(),
             Action::CommunityPoolSpend(d) => d.effect_hash(),
             Action::CommunityPoolOutput(d) => d.effect_hash(),
@@ -93,17 +114,39 @@ impl Action {
             }
             Action::DelegatorVote(_) => tracing::info_span!("DelegatorVote", ?idx),
             Action::ValidatorVote(_) => tracing::info_span!("ValidatorVote", ?idx),
+            Action::BallotKeyDeal(_) => tracing::info_span!("BallotKeyDeal", ?idx),
+            Action::BallotKeyReveal(_) => tracing::info_span!("BallotKeyReveal", ?idx),
+            Action::BallotKeyComplaint(_) => tracing::info_span!("BallotKeyComplaint", ?idx),
             Action::ProposalDepositClaim(_) => {
                 tracing::info_span!("ProposalDepositClaim", ?idx)
             }
             Action::PositionOpen(_) => tracing::info_span!("PositionOpen", ?idx),
+            Action::PositionOpenBatch(_) => {
+                tracing::info_span!("PositionOpenBatch", ?idx)
+            }
             Action::PositionClose(_) => tracing::info_span!("PositionClose", ?idx),
             Action::PositionWithdraw(_) => {
                 tracing::info_span!("PositionWithdraw", ?idx)
             }
+            Action::PositionUpdateBatch(_) => {
+                tracing::info_span!("PositionUpdateBatch", ?idx)
+            }
+            Action::PositionRewrite(_) => {
+                tracing::info_span!("PositionRewrite", ?idx)
+            }
+            Action::ActionDutchAuctionSchedule(_) => {
+                tracing::info_span!("ActionDutchAuctionSchedule", ?idx)
+            }
+            Action::ActionDutchAuctionEnd(_) => {
+                tracing::info_span!("ActionDutchAuctionEnd", ?idx)
+            }
+            Action::ActionDutchAuctionWithdraw(_) => {
+                tracing::info_span!("ActionDutchAuctionWithdraw", ?idx)
+            }
             Action::Delegate(_) => tracing::info_span!("Delegate", ?idx),
             Action::Undelegate(_) => tracing::info_span!("Undelegate", ?idx),
             Action::UndelegateClaim(_) => tracing::info_span!("UndelegateClaim", ?idx),
+            Action::Redelegate(_) => tracing::info_span!("Redelegate", ?idx),
             Action::Ics20Withdrawal(_) => tracing::info_span!("Ics20Withdrawal", ?idx),
             Action::CommunityPoolDeposit(_) => tracing::info_span!("CommunityPoolDeposit", ?idx),
             Action::CommunityPoolSpend(_) => tracing::info_span!("CommunityPoolSpend", ?idx),
@@ -120,16 +163,26 @@ impl IsAction for Action {
             Action::Delegate(delegate) => delegate.balance_commitment(),
             Action::Undelegate(undelegate) => undelegate.balance_commitment(),
             Action::UndelegateClaim(undelegate_claim) => undelegate_claim.balance_commitment(),
+            Action::Redelegate(redelegate) => redelegate.balance_commitment(),
             Action::Swap(swap) => swap.balance_commitment(),
             Action::SwapClaim(swap_claim) => swap_claim.balance_commitment(),
             Action::ProposalSubmit(submit) => submit.balance_commitment(),
             Action::ProposalWithdraw(withdraw) => withdraw.balance_commitment(),
             Action::DelegatorVote(delegator_vote) => delegator_vote.balance_commitment(),
             Action::ValidatorVote(validator_vote) => validator_vote.balance_commitment(),
+            Action::BallotKeyDeal(deal) => deal.balance_commitment(),
+            Action::BallotKeyReveal(reveal) => reveal.balance_commitment(),
+            Action::BallotKeyComplaint(complaint) => complaint.balance_commitment(),
             Action::ProposalDepositClaim(p) => p.balance_commitment(),
             Action::PositionOpen(p) => p.balance_commitment(),
+            Action::PositionOpenBatch(p) => p.balance_commitment(),
             Action::PositionClose(p) => p.balance_commitment(),
             Action::PositionWithdraw(p) => p.balance_commitment(),
+            Action::PositionUpdateBatch(p) => p.balance_commitment(),
+            Action::PositionRewrite(p) => p.balance_commitment(),
+            Action::ActionDutchAuctionSchedule(a) => a.balance_commitment(),
+            Action::ActionDutchAuctionEnd(a) => a.balance_commitment(),
+            Action::ActionDutchAuctionWithdraw(a) => a.balance_commitment(),
             Action::Ics20Withdrawal(withdrawal) => withdrawal.balance_commitment(),
             Action::CommunityPoolDeposit(deposit) => deposit.balance_commitment(),
             Action::CommunityPoolSpend(spend) => spend.balance_commitment(),
@@ -150,14 +203,24 @@ impl IsAction for Action {
             Action::Delegate(x) => x.view_from_perspective(txp),
             Action::Undelegate(x) => x.view_from_perspective(txp),
             Action::UndelegateClaim(x) => x.view_from_perspective(txp),
+            Action::Redelegate(x) => x.view_from_perspective(txp),
             Action::ProposalSubmit(x) => x.view_from_perspective(txp),
             Action::ProposalWithdraw(x) => x.view_from_perspective(txp),
             Action::DelegatorVote(x) => x.view_from_perspective(txp),
             Action::ValidatorVote(x) => x.view_from_perspective(txp),
+            Action::BallotKeyDeal(x) => x.view_from_perspective(txp),
+            Action::BallotKeyReveal(x) => x.view_from_perspective(txp),
+            Action::BallotKeyComplaint(x) => x.view_from_perspective(txp),
             Action::ProposalDepositClaim(x) => x.view_from_perspective(txp),
             Action::PositionOpen(x) => x.view_from_perspective(txp),
+            Action::PositionOpenBatch(x) => x.view_from_perspective(txp),
             Action::PositionClose(x) => x.view_from_perspective(txp),
             Action::PositionWithdraw(x) => x.view_from_perspective(txp),
+            Action::PositionUpdateBatch(x) => x.view_from_perspective(txp),
+            Action::PositionRewrite(x) => x.view_from_perspective(txp),
+            Action::ActionDutchAuctionSchedule(x) => x.view_from_perspective(txp),
+            Action::ActionDutchAuctionEnd(x) => x.view_from_perspective(txp),
+            Action::ActionDutchAuctionWithdraw(x) => x.view_from_perspective(txp),
             Action::Ics20Withdrawal(x) => x.view_from_perspective(txp),
             Action::CommunityPoolSpend(x) => x.view_from_perspective(txp),
             Action::CommunityPoolOutput(x) => x.view_from_perspective(txp),
@@ -191,6 +254,9 @@ impl From<Action> for pb::Action {
             Action::UndelegateClaim(inner) => pb::Action {
                 action: Some(pb::action::Action::UndelegateClaim(inner.into())),
             },
+            Action::Redelegate(inner) => pb::Action {
+                action: Some(pb::action::Action::Redelegate(inner.into())),
+            },
             Action::ValidatorDefinition(inner) => pb::Action {
                 action: Some(pb::action::Action::ValidatorDefinition(inner.into())),
             },
@@ -215,18 +281,45 @@ impl From<Action> for pb::Action {
             Action::ValidatorVote(inner) => pb::Action {
                 action: Some(pb::action::Action::ValidatorVote(inner.into())),
             },
+            Action::BallotKeyDeal(inner) => pb::Action {
+                action: Some(pb::action::Action::BallotKeyDeal(inner.into())),
+            },
+            Action::BallotKeyReveal(inner) => pb::Action {
+                action: Some(pb::action::Action::BallotKeyReveal(inner.into())),
+            },
+            Action::BallotKeyComplaint(inner) => pb::Action {
+                action: Some(pb::action::Action::BallotKeyComplaint(inner.into())),
+            },
             Action::ProposalDepositClaim(inner) => pb::Action {
                 action: Some(pb::action::Action::ProposalDepositClaim(inner.into())),
             },
             Action::PositionOpen(inner) => pb::Action {
                 action: Some(pb::action::Action::PositionOpen(inner.into())),
             },
+            Action::PositionOpenBatch(inner) => pb::Action {
+                action: Some(pb::action::Action::PositionOpenBatch(inner.into())),
+            },
             Action::PositionClose(inner) => pb::Action {
                 action: Some(pb::action::Action::PositionClose(inner.into())),
             },
             Action::PositionWithdraw(inner) => pb::Action {
                 action: Some(pb::action::Action::PositionWithdraw(inner.into())),
             },
+            Action::PositionUpdateBatch(inner) => pb::Action {
+                action: Some(pb::action::Action::PositionUpdateBatch(inner.into())),
+            },
+            Action::PositionRewrite(inner) => pb::Action {
+                action: Some(pb::action::Action::PositionRewrite(inner.into())),
+            },
+            Action::ActionDutchAuctionSchedule(inner) => pb::Action {
+                action: Some(pb::action::Action::ActionDutchAuctionSchedule(inner.into())),
+            },
+            Action::ActionDutchAuctionEnd(inner) => pb::Action {
+                action: Some(pb::action::Action::ActionDutchAuctionEnd(inner.into())),
+            },
+            Action::ActionDutchAuctionWithdraw(inner) => pb::Action {
+                action: Some(pb::action::Action::ActionDutchAuctionWithdraw(inner.into())),
+            },
             Action::Ics20Withdrawal(withdrawal) => pb::Action {
                 action: Some(pb::action::Action::Ics20Withdrawal(withdrawal.into())),
             },
@@ -260,6 +353,7 @@ impl TryFrom<pb::Action> for Action {
             pb::action::Action::UndelegateClaim(inner) => {
                 Ok(Action::UndelegateClaim(inner.try_into()?))
             }
+            pb::action::Action::Redelegate(inner) => Ok(Action::Redelegate(inner.try_into()?)),
             pb::action::Action::ValidatorDefinition(inner) => {
                 Ok(Action::ValidatorDefinition(inner.try_into()?))
             }
@@ -278,17 +372,44 @@ impl TryFrom<pb::Action> for Action {
             pb::action::Action::ValidatorVote(inner) => {
                 Ok(Action::ValidatorVote(inner.try_into()?))
             }
+            pb::action::Action::BallotKeyDeal(inner) => {
+                Ok(Action::BallotKeyDeal(inner.try_into()?))
+            }
+            pb::action::Action::BallotKeyReveal(inner) => {
+                Ok(Action::BallotKeyReveal(inner.try_into()?))
+            }
+            pb::action::Action::BallotKeyComplaint(inner) => {
+                Ok(Action::BallotKeyComplaint(inner.try_into()?))
+            }
             pb::action::Action::ProposalDepositClaim(inner) => {
                 Ok(Action::ProposalDepositClaim(inner.try_into()?))
             }
 
             pb::action::Action::PositionOpen(inner) => Ok(Action::PositionOpen(inner.try_into()?)),
+            pb::action::Action::PositionOpenBatch(inner) => {
+                Ok(Action::PositionOpenBatch(inner.try_into()?))
+            }
             pb::action::Action::PositionClose(inner) => {
                 Ok(Action::PositionClose(inner.try_into()?))
             }
             pb::action::Action::PositionWithdraw(inner) => {
                 Ok(Action::PositionWithdraw(inner.try_into()?))
             }
+            pb::action::Action::PositionUpdateBatch(inner) => {
+                Ok(Action::PositionUpdateBatch(inner.try_into()?))
+            }
+            pb::action::Action::PositionRewrite(inner) => {
+                Ok(Action::PositionRewrite(inner.try_into()?))
+            }
+            pb::action::Action::ActionDutchAuctionSchedule(inner) => {
+                Ok(Action::ActionDutchAuctionSchedule(inner.try_into()?))
+            }
+            pb::action::Action::ActionDutchAuctionEnd(inner) => {
+                Ok(Action::ActionDutchAuctionEnd(inner.try_into()?))
+            }
+            pb::action::Action::ActionDutchAuctionWithdraw(inner) => {
+                Ok(Action::ActionDutchAuctionWithdraw(inner.try_into()?))
+            }
             pb::action::Action::PositionRewardClaim(_) => {
                 Err(anyhow!("PositionRewardClaim is deprecated and unsupported"))
             }
@@ -1,14 +1,21 @@
 use penumbra_community_pool::{CommunityPoolDeposit, CommunityPoolOutput, CommunityPoolSpend};
-use penumbra_dex::{PositionClose, PositionOpen, PositionWithdraw, Swap, SwapClaim};
+use penumbra_dex::{
+    auction::{dutch::DutchAuctionDescription, plan::ActionDutchAuctionWithdrawPlan},
+    lp::plan::{PositionRewritePlan, PositionUpdateBatchPlan},
+    ActionDutchAuctionEnd, ActionDutchAuctionSchedule, ActionDutchAuctionWithdraw, PositionClose,
+    PositionOpen, PositionOpenBatch, PositionRewrite, PositionUpdateBatch, PositionWithdraw, Swap,
+    SwapClaim,
+};
 use penumbra_fee::Gas;
 use penumbra_ibc::IbcRelay;
 use penumbra_shielded_pool::{Ics20Withdrawal, Output, Spend};
 use penumbra_stake::{
-    validator::Definition as ValidatorDefinition, Delegate, Undelegate, UndelegateClaim,
+    validator::Definition as ValidatorDefinition, Delegate, Redelegate, Undelegate, UndelegateClaim,
 };
 
 use penumbra_governance::{
-    DelegatorVote, ProposalDepositClaim, ProposalSubmit, ProposalWithdraw, ValidatorVote,
+    BallotKeyComplaint, BallotKeyDeal, BallotKeyReveal, DelegatorVote, ProposalDepositClaim,
+    ProposalSubmit, ProposalWithdraw, ValidatorVote,
 };
 
 use crate::{
@@ -18,7 +25,7 @@ use crate::{
 
 const NULLIFIER_SIZE: u64 = 2 + 32;
 const NOTEPAYLOAD_SIZE: u64 = 2 + 32 + 2 + 32 + 2 + 132;
-const SWAPPAYLOAD_SIZE: u64 = 2 + 32 + 2 + 272;
+const SWAPPAYLOAD_SIZE: u64 = 2 + 32 + 2 + 304;
 // This is an approximation, the actual size is variable
 const BSOD_SIZE: u64 = 16 + 16 + 0 + 4 + 64 + 4;
 
@@ -94,6 +101,23 @@ fn undelegate_gas_cost() -> Gas {
     }
 }
 
+fn redelegate_gas_cost() -> Gas {
+    Gas {
+        // Each [`Action`] has a `0` `block_space` cost, since the [`Transaction`] itself
+        // will use the encoded size of the complete transaction to calculate the block space.
+        block_space: 0,
+        // The compact block space cost is based on the byte size of the data the [`Action`] adds
+        // to the compact block.
+        // For a Redelegate, nothing is added to the compact block directly. The associated [`Action::Spend`]
+        // and [`Action::Output`] actions will add their costs, but there's nothing to add here.
+        compact_block_space: 0u64,
+        // Does not include a zk-SNARK proof, so there's no verification cost.
+        verification: 0,
+        // Execution cost is currently hardcoded at 10 for all Action variants.
+        execution: 10,
+    }
+}
+
 fn undelegate_claim_gas_cost() -> Gas {
     Gas {
         // Each [`Action`] has a `0` `block_space` cost, since the [`Transaction`] itself
@@ -196,6 +220,67 @@ fn position_withdraw_gas_cost() -> Gas {
     }
 }
 
+fn position_update_batch_gas_cost(num_opens: usize, num_updates: usize) -> Gas {
+    Gas {
+        // Each [`Action`] has a `0` `block_space` cost, since the [`Transaction`] itself
+        // will use the encoded size of the complete transaction to calculate the block space.
+        block_space: 0,
+        // The compact block space cost is based on the byte size of the data the [`Action`] adds
+        // to the compact block.
+        // For a PositionUpdateBatch the compact block is not modified.
+        compact_block_space: 0u64,
+        // Each opened position undergoes the same small validations as a PositionOpen.
+        verification: 50 * num_opens as u64,
+        // Each close, withdrawal and open costs as much to execute as the corresponding action.
+        execution: 10 * num_updates as u64,
+    }
+}
+
+fn position_rewrite_gas_cost() -> Gas {
+    Gas {
+        // Each [`Action`] has a `0` `block_space` cost, since the [`Transaction`] itself
+        // will use the encoded size of the complete transaction to calculate the block space.
+        block_space: 0,
+        // The compact block space cost is based on the byte size of the data the [`Action`] adds
+        // to the compact block.
+        // For a PositionRewrite the compact block is not modified.
+        compact_block_space: 0u64,
+        // The new position undergoes the same small validations as a PositionOpen.
+        verification: 50,
+        // Execution cost is currently hardcoded at 10 for all Action variants.
+        execution: 10,
+    }
+}
+
+fn dutch_auction_gas_cost() -> Gas {
+    Gas {
+        // Each [`Action`] has a `0` `block_space` cost, since the [`Transaction`] itself
+        // will use the encoded size of the complete transaction to calculate the block space.
+        block_space: 0,
+        // The compact block space cost is based on the byte size of the data the [`Action`] adds
+        // to the compact block.
+        // For the Dutch auction actions the compact block is not modified.
+        compact_block_space: 0u64,
+        // Does not include a zk-SNARK proof, so there's no verification cost.
+        verification: 0,
+        // Execution cost is currently hardcoded at 10 for all Action variants.
+        execution: 10,
+    }
+}
+
+fn dutch_auction_schedule_gas_cost(description: &DutchAuctionDescription) -> Gas {
+    // The auction takes a step at the end of every block it runs for, which
+    // moves its position on the DEX, and routes its input through the DEX if
+    // it fills against the liquidity there.  The steps are paid for up front.
+    let step_cost = if description.fill_against_dex { 20 } else { 10 };
+    Gas {
+        execution: dutch_auction_gas_cost()
+            .execution
+            .saturating_add(step_cost * description.steps()),
+        ..dutch_auction_gas_cost()
+    }
+}
+
 impl GasCost for Transaction {
     fn gas_cost(&self) -> Gas {
         self.actions().map(GasCost::gas_cost).sum()
@@ -227,6 +312,7 @@ impl GasCost for ActionPlan {
             ActionPlan::Delegate(d) => d.gas_cost(),
             ActionPlan::Undelegate(u) => u.gas_cost(),
             ActionPlan::UndelegateClaim(_) => undelegate_claim_gas_cost(),
+            ActionPlan::Redelegate(r) => r.gas_cost(),
             ActionPlan::ValidatorDefinition(vd) => vd.gas_cost(),
             ActionPlan::Swap(_) => swap_gas_cost(),
             ActionPlan::SwapClaim(_) => swap_claim_gas_cost(),
@@ -235,10 +321,19 @@ impl GasCost for ActionPlan {
             ActionPlan::ProposalWithdraw(pw) => pw.gas_cost(),
             ActionPlan::DelegatorVote(_) => delegator_vote_gas_cost(),
             ActionPlan::ValidatorVote(v) => v.gas_cost(),
+            ActionPlan::BallotKeyDeal(deal) => deal.gas_cost(),
+            ActionPlan::BallotKeyReveal(reveal) => reveal.gas_cost(),
+            ActionPlan::BallotKeyComplaint(complaint) => complaint.gas_cost(),
             ActionPlan::ProposalDepositClaim(pdc) => pdc.gas_cost(),
             ActionPlan::PositionOpen(po) => po.gas_cost(),
+            ActionPlan::PositionOpenBatch(batch) => batch.gas_cost(),
             ActionPlan::PositionClose(pc) => pc.gas_cost(),
             ActionPlan::PositionWithdraw(_) => position_withdraw_gas_cost(),
+            ActionPlan::PositionUpdateBatch(batch) => batch.gas_cost(),
+            ActionPlan::PositionRewrite(rewrite) => rewrite.gas_cost(),
+            ActionPlan::ActionDutchAuctionSchedule(a) => a.gas_cost(),
+            ActionPlan::ActionDutchAuctionEnd(a) => a.gas_cost(),
+            ActionPlan::ActionDutchAuctionWithdraw(plan) => plan.gas_cost(),
             ActionPlan::CommunityPoolSpend(ds) => ds.gas_cost(),
             ActionPlan::CommunityPoolOutput(d) => d.gas_cost(),
             ActionPlan::CommunityPoolDeposit(dd) => dd.gas_cost(),
@@ -255,16 +350,26 @@ impl GasCost for Action {
             Action::Delegate(delegate) => delegate.gas_cost(),
             Action::Undelegate(undelegate) => undelegate.gas_cost(),
             Action::UndelegateClaim(undelegate_claim) => undelegate_claim.gas_cost(),
+            Action::Redelegate(redelegate) => redelegate.gas_cost(),
             Action::Swap(swap) => swap.gas_cost(),
             Action::SwapClaim(swap_claim) => swap_claim.gas_cost(),
             Action::ProposalSubmit(submit) => submit.gas_cost(),
             Action::ProposalWithdraw(withdraw) => withdraw.gas_cost(),
             Action::DelegatorVote(delegator_vote) => delegator_vote.gas_cost(),
             Action::ValidatorVote(validator_vote) => validator_vote.gas_cost(),
+            Action::BallotKeyDeal(deal) => deal.gas_cost(),
+            Action::BallotKeyReveal(reveal) => reveal.gas_cost(),
+            Action::BallotKeyComplaint(complaint) => complaint.gas_cost(),
             Action::ProposalDepositClaim(p) => p.gas_cost(),
             Action::PositionOpen(p) => p.gas_cost(),
+            Action::PositionOpenBatch(p) => p.gas_cost(),
             Action::PositionClose(p) => p.gas_cost(),
             Action::PositionWithdraw(p) => p.gas_cost(),
+            Action::PositionUpdateBatch(p) => p.gas_cost(),
+            Action::PositionRewrite(p) => p.gas_cost(),
+            Action::ActionDutchAuctionSchedule(a) => a.gas_cost(),
+            Action::ActionDutchAuctionEnd(a) => a.gas_cost(),
+            Action::ActionDutchAuctionWithdraw(a) => a.gas_cost(),
             Action::Ics20Withdrawal(withdrawal) => withdrawal.gas_cost(),
             Action::CommunityPoolDeposit(deposit) => deposit.gas_cost(),
             Action::CommunityPoolSpend(spend) => spend.gas_cost(),
@@ -299,6 +404,12 @@ impl GasCost for Undelegate {
     }
 }
 
+impl GasCost for Redelegate {
+    fn gas_cost(&self) -> Gas {
+        redelegate_gas_cost()
+    }
+}
+
 impl GasCost for UndelegateClaim {
     fn gas_cost(&self) -> Gas {
         undelegate_claim_gas_cost()
@@ -378,6 +489,62 @@ impl GasCost for ValidatorVote {
     }
 }
 
+impl GasCost for BallotKeyDeal {
+    fn gas_cost(&self) -> Gas {
+        Gas {
+            // Each [`Action`] has a `0` `block_space` cost, since the [`Transaction`] itself
+            // will use the encoded size of the complete transaction to calculate the block space.
+            block_space: 0,
+            // The compact block space cost is based on the byte size of the data the [`Action`] adds
+            // to the compact block.
+            // For a BallotKeyDeal the compact block is not modified.
+            compact_block_space: 0u64,
+            // Includes a signature verification, so we include a small constant verification cost.
+            verification: 200,
+            // Execution cost is currently hardcoded at 10 for all Action variants.
+            execution: 10,
+        }
+    }
+}
+
+impl GasCost for BallotKeyReveal {
+    fn gas_cost(&self) -> Gas {
+        Gas {
+            // Each [`Action`] has a `0` `block_space` cost, since the [`Transaction`] itself
+            // will use the encoded size of the complete transaction to calculate the block space.
+            block_space: 0,
+            // The compact block space cost is based on the byte size of the data the [`Action`] adds
+            // to the compact block.
+            // For a BallotKeyReveal the compact block is not modified.
+            compact_block_space: 0u64,
+            // Includes a signature verification, along with a check of each revealed share
+            // against its dealer's commitments.
+            verification: 200 + 50 * self.body.shares.len() as u64,
+            // Execution cost is currently hardcoded at 10 for all Action variants.
+            execution: 10,
+        }
+    }
+}
+
+impl GasCost for BallotKeyComplaint {
+    fn gas_cost(&self) -> Gas {
+        Gas {
+            // Each [`Action`] has a `0` `block_space` cost, since the [`Transaction`] itself
+            // will use the encoded size of the complete transaction to calculate the block space.
+            block_space: 0,
+            // The compact block space cost is based on the byte size of the data the [`Action`] adds
+            // to the compact block.
+            // For a BallotKeyComplaint the compact block is not modified.
+            compact_block_space: 0u64,
+            // Includes a signature verification, a check of the shared secret's proof, and a
+            // check of the decrypted share against its dealer's commitments.
+            verification: 300,
+            // Execution cost is currently hardcoded at 10 for all Action variants.
+            execution: 10,
+        }
+    }
+}
+
 impl GasCost for ProposalDepositClaim {
     fn gas_cost(&self) -> Gas {
         Gas {
@@ -414,6 +581,24 @@ impl GasCost for PositionOpen {
     }
 }
 
+impl GasCost for PositionOpenBatch {
+    fn gas_cost(&self) -> Gas {
+        Gas {
+            // Each [`Action`] has a `0` `block_space` cost, since the [`Transaction`] itself
+            // will use the encoded size of the complete transaction to calculate the block space.
+            block_space: 0,
+            // The compact block space cost is based on the byte size of the data the [`Action`] adds
+            // to the compact block.
+            // For a PositionOpenBatch the compact block is not modified.
+            compact_block_space: 0u64,
+            // Each opened position undergoes the same small validations as a PositionOpen.
+            verification: 50 * self.positions.len() as u64,
+            // Each opened position costs as much to execute as a PositionOpen.
+            execution: 10 * self.positions.len() as u64,
+        }
+    }
+}
+
 impl GasCost for PositionClose {
     fn gas_cost(&self) -> Gas {
         Gas {
@@ -438,6 +623,54 @@ impl GasCost for PositionWithdraw {
     }
 }
 
+impl GasCost for PositionUpdateBatch {
+    fn gas_cost(&self) -> Gas {
+        position_update_batch_gas_cost(self.opens.len(), self.num_updates())
+    }
+}
+
+impl GasCost for PositionUpdateBatchPlan {
+    fn gas_cost(&self) -> Gas {
+        position_update_batch_gas_cost(self.opens.len(), self.num_updates())
+    }
+}
+
+impl GasCost for PositionRewrite {
+    fn gas_cost(&self) -> Gas {
+        position_rewrite_gas_cost()
+    }
+}
+
+impl GasCost for PositionRewritePlan {
+    fn gas_cost(&self) -> Gas {
+        position_rewrite_gas_cost()
+    }
+}
+
+impl GasCost for ActionDutchAuctionSchedule {
+    fn gas_cost(&self) -> Gas {
+        dutch_auction_schedule_gas_cost(&self.description)
+    }
+}
+
+impl GasCost for ActionDutchAuctionEnd {
+    fn gas_cost(&self) -> Gas {
+        dutch_auction_gas_cost()
+    }
+}
+
+impl GasCost for ActionDutchAuctionWithdraw {
+    fn gas_cost(&self) -> Gas {
+        dutch_auction_gas_cost()
+    }
+}
+
+impl GasCost for ActionDutchAuctionWithdrawPlan {
+    fn gas_cost(&self) -> Gas {
+        dutch_auction_gas_cost()
+    }
+}
+
 impl GasCost for Ics20Withdrawal {
     fn gas_cost(&self) -> Gas {
         Gas {
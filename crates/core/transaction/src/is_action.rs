@@ -3,20 +3,26 @@ use decaf377::Fr;
 use penumbra_asset::{balance, Value};
 use penumbra_community_pool::{CommunityPoolDeposit, CommunityPoolOutput, CommunityPoolSpend};
 use penumbra_dex::{
+    auction::action::{
+        ActionDutchAuctionEnd, ActionDutchAuctionSchedule, ActionDutchAuctionWithdraw,
+    },
     lp::{
-        action::{PositionClose, PositionOpen, PositionWithdraw},
+        action::{
+            PositionClose, PositionOpen, PositionOpenBatch, PositionRewrite, PositionUpdateBatch,
+            PositionWithdraw,
+        },
         position, LpNft,
     },
     swap::{Swap, SwapCiphertext, SwapView},
     swap_claim::{SwapClaim, SwapClaimView},
 };
 use penumbra_governance::{
-    DelegatorVote, DelegatorVoteView, ProposalDepositClaim, ProposalSubmit, ProposalWithdraw,
-    ValidatorVote, VotingReceiptToken,
+    BallotKeyComplaint, BallotKeyDeal, BallotKeyReveal, DelegatorVote, DelegatorVoteView,
+    ProposalDepositClaim, ProposalSubmit, ProposalWithdraw, ValidatorVote, VotingReceiptToken,
 };
 use penumbra_ibc::IbcRelay;
 use penumbra_shielded_pool::{Ics20Withdrawal, Note, Output, OutputView, Spend, SpendView};
-use penumbra_stake::{Delegate, Undelegate, UndelegateClaim};
+use penumbra_stake::{Delegate, Redelegate, Undelegate, UndelegateClaim};
 
 use crate::{Action, ActionView, TransactionPerspective};
 
@@ -101,6 +107,36 @@ impl IsAction for ValidatorVote {
     }
 }
 
+impl IsAction for BallotKeyDeal {
+    fn balance_commitment(&self) -> balance::Commitment {
+        Default::default()
+    }
+
+    fn view_from_perspective(&self, _txp: &TransactionPerspective) -> ActionView {
+        ActionView::BallotKeyDeal(self.to_owned())
+    }
+}
+
+impl IsAction for BallotKeyComplaint {
+    fn balance_commitment(&self) -> balance::Commitment {
+        Default::default()
+    }
+
+    fn view_from_perspective(&self, _txp: &TransactionPerspective) -> ActionView {
+        ActionView::BallotKeyComplaint(self.to_owned())
+    }
+}
+
+impl IsAction for BallotKeyReveal {
+    fn balance_commitment(&self) -> balance::Commitment {
+        Default::default()
+    }
+
+    fn view_from_perspective(&self, _txp: &TransactionPerspective) -> ActionView {
+        ActionView::BallotKeyReveal(self.to_owned())
+    }
+}
+
 impl IsAction for Output {
     fn balance_commitment(&self) -> balance::Commitment {
         self.body.balance_commitment
@@ -185,6 +221,16 @@ impl IsAction for Undelegate {
     }
 }
 
+impl IsAction for Redelegate {
+    fn balance_commitment(&self) -> balance::Commitment {
+        self.balance().commit(Fr::zero())
+    }
+
+    fn view_from_perspective(&self, _txp: &TransactionPerspective) -> ActionView {
+        ActionView::Redelegate(self.to_owned())
+    }
+}
+
 impl IsAction for UndelegateClaim {
     fn balance_commitment(&self) -> balance::Commitment {
         self.body.balance_commitment
@@ -256,6 +302,16 @@ impl IsAction for PositionOpen {
     }
 }
 
+impl IsAction for PositionOpenBatch {
+    fn balance_commitment(&self) -> balance::Commitment {
+        self.balance().commit(Fr::zero())
+    }
+
+    fn view_from_perspective(&self, _txp: &TransactionPerspective) -> ActionView {
+        ActionView::PositionOpenBatch(self.to_owned())
+    }
+}
+
 impl IsAction for PositionClose {
     fn balance_commitment(&self) -> balance::Commitment {
         self.balance().commit(Fr::zero())
@@ -308,6 +364,62 @@ impl IsAction for PositionWithdraw {
     }
 }
 
+impl IsAction for PositionUpdateBatch {
+    fn balance_commitment(&self) -> balance::Commitment {
+        // The withdrawn reserves are only committed to, and everything else is transparent.
+        self.withdrawn_reserves_commitment
+            + self
+                .balance_excluding_withdrawn_reserves()
+                .commit(Fr::zero())
+    }
+
+    fn view_from_perspective(&self, _txp: &TransactionPerspective) -> ActionView {
+        ActionView::PositionUpdateBatch(self.to_owned())
+    }
+}
+
+impl IsAction for PositionRewrite {
+    fn balance_commitment(&self) -> balance::Commitment {
+        // The final reserves are only committed to, and everything else is transparent.
+        self.reserves_commitment + self.balance_excluding_reserves().commit(Fr::zero())
+    }
+
+    fn view_from_perspective(&self, _txp: &TransactionPerspective) -> ActionView {
+        ActionView::PositionRewrite(self.to_owned())
+    }
+}
+
+impl IsAction for ActionDutchAuctionSchedule {
+    fn balance_commitment(&self) -> balance::Commitment {
+        self.balance().commit(Fr::zero())
+    }
+
+    fn view_from_perspective(&self, _txp: &TransactionPerspective) -> ActionView {
+        ActionView::ActionDutchAuctionSchedule(self.to_owned())
+    }
+}
+
+impl IsAction for ActionDutchAuctionEnd {
+    fn balance_commitment(&self) -> balance::Commitment {
+        self.balance().commit(Fr::zero())
+    }
+
+    fn view_from_perspective(&self, _txp: &TransactionPerspective) -> ActionView {
+        ActionView::ActionDutchAuctionEnd(self.to_owned())
+    }
+}
+
+impl IsAction for ActionDutchAuctionWithdraw {
+    fn balance_commitment(&self) -> balance::Commitment {
+        // The withdrawn reserves are only committed to, and the auction NFTs are transparent.
+        self.reserves_commitment + self.balance_excluding_reserves().commit(Fr::zero())
+    }
+
+    fn view_from_perspective(&self, _txp: &TransactionPerspective) -> ActionView {
+        ActionView::ActionDutchAuctionWithdraw(self.to_owned())
+    }
+}
+
 impl IsAction for Swap {
     /// Compute a commitment to the value contributed to a transaction by this swap.
     /// Will subtract (v1,t1), (v2,t2), and (f,fee_token)
@@ -16,7 +16,7 @@ use penumbra_ibc::IbcRelay;
 use penumbra_keys::{Address, FullViewingKey, PayloadKey};
 use penumbra_proto::{core::transaction::v1 as pb, DomainType};
 use penumbra_shielded_pool::{Ics20Withdrawal, OutputPlan, SpendPlan};
-use penumbra_stake::{Delegate, Undelegate, UndelegateClaimPlan};
+use penumbra_stake::{Delegate, Redelegate, Undelegate, UndelegateClaimPlan};
 use penumbra_txhash::{EffectHash, EffectingData};
 use rand::{CryptoRng, Rng};
 use serde::{Deserialize, Serialize};
@@ -144,6 +144,16 @@ impl TransactionPlan {
         })
     }
 
+    pub fn redelegations(&self) -> impl Iterator<Item = &Redelegate> {
+        self.actions.iter().filter_map(|action| {
+            if let ActionPlan::Redelegate(r) = action {
+                Some(r)
+            } else {
+                None
+            }
+        })
+    }
+
     pub fn undelegate_claim_plans(&self) -> impl Iterator<Item = &UndelegateClaimPlan> {
         self.actions.iter().filter_map(|action| {
             if let ActionPlan::UndelegateClaim(d) = action {
@@ -477,6 +487,7 @@ mod tests {
                     .id(),
             }),
             addr,
+            fvk.nullifier_key(),
         );
 
         let mut rng = OsRng;
@@ -8,23 +8,25 @@ use penumbra_community_pool::{CommunityPoolDeposit, CommunityPoolOutput, Communi
 use penumbra_txhash::{EffectHash, EffectingData};
 
 use penumbra_dex::{
+    auction::plan::ActionDutchAuctionWithdrawPlan,
     lp::{
-        action::{PositionClose, PositionOpen},
-        plan::PositionWithdrawPlan,
+        action::{PositionClose, PositionOpen, PositionOpenBatch},
+        plan::{PositionRewritePlan, PositionUpdateBatchPlan, PositionWithdrawPlan},
     },
     swap::SwapPlan,
     swap_claim::SwapClaimPlan,
+    ActionDutchAuctionEnd, ActionDutchAuctionSchedule,
 };
 use penumbra_governance::{
-    delegator_vote::DelegatorVotePlan, ProposalDepositClaim, ProposalSubmit, ProposalWithdraw,
-    ValidatorVote,
+    delegator_vote::DelegatorVotePlan, BallotKeyComplaint, BallotKeyDeal, BallotKeyReveal,
+    ProposalDepositClaim, ProposalSubmit, ProposalWithdraw, ValidatorVote,
 };
 
 use penumbra_ibc::IbcRelay;
 use penumbra_keys::{symmetric::PayloadKey, FullViewingKey};
 use penumbra_proto::{core::transaction::v1 as pb_t, DomainType};
 use penumbra_shielded_pool::{Ics20Withdrawal, OutputPlan, SpendPlan};
-use penumbra_stake::{Delegate, Undelegate, UndelegateClaimPlan};
+use penumbra_stake::{Delegate, Redelegate, Undelegate, UndelegateClaimPlan};
 use serde::{Deserialize, Serialize};
 
 /// A declaration of a planned [`Action`], for use in transaction creation.
@@ -47,6 +49,8 @@ pub enum ActionPlan {
     /// because we don't yet use flow encryption.
     Undelegate(Undelegate),
     UndelegateClaim(UndelegateClaimPlan),
+    /// Redelegations need no extra information either.
+    Redelegate(Redelegate),
     ValidatorDefinition(penumbra_stake::validator::Definition),
     /// Describes a proposed swap.
     Swap(SwapPlan),
@@ -61,14 +65,27 @@ pub enum ActionPlan {
     DelegatorVote(DelegatorVotePlan),
     /// Vote on a proposal as a validator.
     ValidatorVote(ValidatorVote),
+    BallotKeyDeal(BallotKeyDeal),
+    BallotKeyReveal(BallotKeyReveal),
+    BallotKeyComplaint(BallotKeyComplaint),
     /// Claim the deposit for a finished proposal.
     ProposalDepositClaim(ProposalDepositClaim),
 
     PositionOpen(PositionOpen),
+    PositionOpenBatch(PositionOpenBatch),
     PositionClose(PositionClose),
     // PositionWithdrawPlan requires the balance of the funds to be withdrawn, so
     // a plan must be used.
     PositionWithdraw(PositionWithdrawPlan),
+    // Likewise, batched withdrawals require the balance of the funds to be withdrawn.
+    PositionUpdateBatch(PositionUpdateBatchPlan),
+    // A rewrite withdraws the replaced position's reserves, so it also needs their balance.
+    PositionRewrite(PositionRewritePlan),
+
+    ActionDutchAuctionSchedule(ActionDutchAuctionSchedule),
+    ActionDutchAuctionEnd(ActionDutchAuctionEnd),
+    // Withdrawing an auction's reserves requires their balance, so a plan must be used.
+    ActionDutchAuctionWithdraw(ActionDutchAuctionWithdrawPlan),
 
     CommunityPoolSpend(CommunityPoolSpend),
     CommunityPoolOutput(CommunityPoolOutput),
@@ -131,6 +148,7 @@ impl ActionPlan {
             Delegate(plan) => Action::Delegate(plan.clone()),
             Undelegate(plan) => Action::Undelegate(plan.clone()),
             UndelegateClaim(plan) => Action::UndelegateClaim(plan.undelegate_claim()),
+            Redelegate(plan) => Action::Redelegate(plan.clone()),
             ValidatorDefinition(plan) => Action::ValidatorDefinition(plan.clone()),
             // Fixme: action name
             IbcAction(plan) => Action::IbcRelay(plan.clone()),
@@ -145,10 +163,21 @@ impl ActionPlan {
                 Action::DelegatorVote(plan.delegator_vote(fvk, [0; 64].into(), auth_path.clone()))
             }
             ValidatorVote(plan) => Action::ValidatorVote(plan.clone()),
+            BallotKeyDeal(plan) => Action::BallotKeyDeal(plan.clone()),
+            BallotKeyReveal(plan) => Action::BallotKeyReveal(plan.clone()),
+            BallotKeyComplaint(plan) => Action::BallotKeyComplaint(plan.clone()),
             ProposalDepositClaim(plan) => Action::ProposalDepositClaim(plan.clone()),
             PositionOpen(plan) => Action::PositionOpen(plan.clone()),
+            PositionOpenBatch(plan) => Action::PositionOpenBatch(plan.clone()),
             PositionClose(plan) => Action::PositionClose(plan.clone()),
             PositionWithdraw(plan) => Action::PositionWithdraw(plan.position_withdraw()),
+            PositionUpdateBatch(plan) => Action::PositionUpdateBatch(plan.position_update_batch()),
+            PositionRewrite(plan) => Action::PositionRewrite(plan.position_rewrite()),
+            ActionDutchAuctionSchedule(plan) => Action::ActionDutchAuctionSchedule(plan.clone()),
+            ActionDutchAuctionEnd(plan) => Action::ActionDutchAuctionEnd(plan.clone()),
+            ActionDutchAuctionWithdraw(plan) => {
+                Action::ActionDutchAuctionWithdraw(plan.to_action())
+            }
             CommunityPoolSpend(plan) => Action::CommunityPoolSpend(plan.clone()),
             CommunityPoolOutput(plan) => Action::CommunityPoolOutput(plan.clone()),
             CommunityPoolDeposit(plan) => Action::CommunityPoolDeposit(plan.clone()),
@@ -166,6 +195,7 @@ impl ActionPlan {
             Delegate(delegate) => delegate.balance(),
             Undelegate(undelegate) => undelegate.balance(),
             UndelegateClaim(undelegate_claim) => undelegate_claim.balance(),
+            Redelegate(redelegate) => redelegate.balance(),
             Swap(swap) => swap.balance(),
             SwapClaim(swap_claim) => swap_claim.balance(),
             ProposalSubmit(proposal_submit) => proposal_submit.balance(),
@@ -176,11 +206,22 @@ impl ActionPlan {
             CommunityPoolOutput(community_pool_output) => community_pool_output.balance(),
             CommunityPoolDeposit(community_pool_deposit) => community_pool_deposit.balance(),
             PositionOpen(position_open) => position_open.balance(),
+            PositionOpenBatch(position_open_batch) => position_open_batch.balance(),
             PositionClose(position_close) => position_close.balance(),
             PositionWithdraw(position_withdraw) => position_withdraw.balance(),
+            PositionUpdateBatch(position_update_batch) => position_update_batch.balance(),
+            PositionRewrite(position_rewrite) => position_rewrite.balance(),
+            ActionDutchAuctionSchedule(action) => action.balance(),
+            ActionDutchAuctionEnd(action) => action.balance(),
+            ActionDutchAuctionWithdraw(plan) => plan.balance(),
             Ics20Withdrawal(withdrawal) => withdrawal.balance(),
             // None of these contribute to transaction balance:
-            IbcAction(_) | ValidatorDefinition(_) | ValidatorVote(_) => Balance::default(),
+            IbcAction(_)
+            | ValidatorDefinition(_)
+            | ValidatorVote(_)
+            | BallotKeyDeal(_)
+            | BallotKeyReveal(_)
+            | BallotKeyComplaint(_) => Balance::default(),
         }
     }
 
@@ -193,6 +234,7 @@ impl ActionPlan {
             Delegate(_) => Fr::zero(),
             Undelegate(_) => Fr::zero(),
             UndelegateClaim(undelegate_claim) => undelegate_claim.balance_blinding,
+            Redelegate(_) => Fr::zero(),
             ValidatorDefinition(_) => Fr::zero(),
             Swap(swap) => swap.fee_blinding,
             SwapClaim(_) => Fr::zero(),
@@ -201,10 +243,19 @@ impl ActionPlan {
             ProposalWithdraw(_) => Fr::zero(),
             DelegatorVote(_) => Fr::zero(),
             ValidatorVote(_) => Fr::zero(),
+            BallotKeyDeal(_) => Fr::zero(),
+            BallotKeyReveal(_) => Fr::zero(),
+            BallotKeyComplaint(_) => Fr::zero(),
             ProposalDepositClaim(_) => Fr::zero(),
             PositionOpen(_) => Fr::zero(),
+            PositionOpenBatch(_) => Fr::zero(),
             PositionClose(_) => Fr::zero(),
             PositionWithdraw(_) => Fr::zero(),
+            PositionUpdateBatch(_) => Fr::zero(),
+            PositionRewrite(_) => Fr::zero(),
+            ActionDutchAuctionSchedule(_) => Fr::zero(),
+            ActionDutchAuctionEnd(_) => Fr::zero(),
+            ActionDutchAuctionWithdraw(_) => Fr::zero(),
             CommunityPoolSpend(_) => Fr::zero(),
             CommunityPoolOutput(_) => Fr::zero(),
             CommunityPoolDeposit(_) => Fr::zero(),
@@ -222,6 +273,7 @@ impl ActionPlan {
             Delegate(plan) => plan.effect_hash(),
             Undelegate(plan) => plan.effect_hash(),
             UndelegateClaim(plan) => plan.undelegate_claim_body().effect_hash(),
+            Redelegate(plan) => plan.effect_hash(),
             ValidatorDefinition(plan) => plan.effect_hash(),
             Swap(plan) => plan.swap_body(fvk).effect_hash(),
             SwapClaim(plan) => plan.swap_claim_body(fvk).effect_hash(),
@@ -230,10 +282,19 @@ impl ActionPlan {
             ProposalWithdraw(plan) => plan.effect_hash(),
             DelegatorVote(plan) => plan.delegator_vote_body(fvk).effect_hash(),
             ValidatorVote(plan) => plan.effect_hash(),
+            BallotKeyDeal(plan) => plan.effect_hash(),
+            BallotKeyReveal(plan) => plan.effect_hash(),
+            BallotKeyComplaint(plan) => plan.effect_hash(),
             ProposalDepositClaim(plan) => plan.effect_hash(),
             PositionOpen(plan) => plan.effect_hash(),
+            PositionOpenBatch(plan) => plan.effect_hash(),
             PositionClose(plan) => plan.effect_hash(),
             PositionWithdraw(plan) => plan.position_withdraw().effect_hash(),
+            PositionUpdateBatch(plan) => plan.position_update_batch().effect_hash(),
+            PositionRewrite(plan) => plan.position_rewrite().effect_hash(),
+            ActionDutchAuctionSchedule(plan) => plan.effect_hash(),
+            ActionDutchAuctionEnd(plan) => plan.effect_hash(),
+            ActionDutchAuctionWithdraw(plan) => plan.to_action().effect_hash(),
             CommunityPoolSpend(plan) => plan.effect_hash(),
             CommunityPoolOutput(plan) => plan.effect_hash(),
             CommunityPoolDeposit(plan) => plan.effect_hash(),
@@ -280,6 +341,12 @@ impl From<Undelegate> for ActionPlan {
     }
 }
 
+impl From<Redelegate> for ActionPlan {
+    fn from(inner: Redelegate) -> ActionPlan {
+        ActionPlan::Redelegate(inner)
+    }
+}
+
 impl From<penumbra_stake::validator::Definition> for ActionPlan {
     fn from(inner: penumbra_stake::validator::Definition) -> ActionPlan {
         ActionPlan::ValidatorDefinition(inner)
@@ -310,6 +377,24 @@ impl From<ValidatorVote> for ActionPlan {
     }
 }
 
+impl From<BallotKeyDeal> for ActionPlan {
+    fn from(inner: BallotKeyDeal) -> ActionPlan {
+        ActionPlan::BallotKeyDeal(inner)
+    }
+}
+
+impl From<BallotKeyReveal> for ActionPlan {
+    fn from(inner: BallotKeyReveal) -> ActionPlan {
+        ActionPlan::BallotKeyReveal(inner)
+    }
+}
+
+impl From<BallotKeyComplaint> for ActionPlan {
+    fn from(inner: BallotKeyComplaint) -> ActionPlan {
+        ActionPlan::BallotKeyComplaint(inner)
+    }
+}
+
 impl From<PositionOpen> for ActionPlan {
     fn from(inner: PositionOpen) -> ActionPlan {
         ActionPlan::PositionOpen(inner)
@@ -328,6 +413,42 @@ impl From<PositionWithdrawPlan> for ActionPlan {
     }
 }
 
+impl From<PositionOpenBatch> for ActionPlan {
+    fn from(inner: PositionOpenBatch) -> ActionPlan {
+        ActionPlan::PositionOpenBatch(inner)
+    }
+}
+
+impl From<PositionUpdateBatchPlan> for ActionPlan {
+    fn from(inner: PositionUpdateBatchPlan) -> ActionPlan {
+        ActionPlan::PositionUpdateBatch(inner)
+    }
+}
+
+impl From<PositionRewritePlan> for ActionPlan {
+    fn from(inner: PositionRewritePlan) -> ActionPlan {
+        ActionPlan::PositionRewrite(inner)
+    }
+}
+
+impl From<ActionDutchAuctionSchedule> for ActionPlan {
+    fn from(inner: ActionDutchAuctionSchedule) -> ActionPlan {
+        ActionPlan::ActionDutchAuctionSchedule(inner)
+    }
+}
+
+impl From<ActionDutchAuctionEnd> for ActionPlan {
+    fn from(inner: ActionDutchAuctionEnd) -> ActionPlan {
+        ActionPlan::ActionDutchAuctionEnd(inner)
+    }
+}
+
+impl From<ActionDutchAuctionWithdrawPlan> for ActionPlan {
+    fn from(inner: ActionDutchAuctionWithdrawPlan) -> ActionPlan {
+        ActionPlan::ActionDutchAuctionWithdraw(inner)
+    }
+}
+
 impl From<Ics20Withdrawal> for ActionPlan {
     fn from(inner: Ics20Withdrawal) -> ActionPlan {
         ActionPlan::Ics20Withdrawal(inner)
@@ -356,6 +477,9 @@ impl From<ActionPlan> for pb_t::ActionPlan {
             ActionPlan::UndelegateClaim(inner) => pb_t::ActionPlan {
                 action: Some(pb_t::action_plan::Action::UndelegateClaim(inner.into())),
             },
+            ActionPlan::Redelegate(inner) => pb_t::ActionPlan {
+                action: Some(pb_t::action_plan::Action::Redelegate(inner.into())),
+            },
             ActionPlan::ValidatorDefinition(inner) => pb_t::ActionPlan {
                 action: Some(pb_t::action_plan::Action::ValidatorDefinition(inner.into())),
             },
@@ -380,6 +504,15 @@ impl From<ActionPlan> for pb_t::ActionPlan {
             ActionPlan::ValidatorVote(inner) => pb_t::ActionPlan {
                 action: Some(pb_t::action_plan::Action::ValidatorVote(inner.into())),
             },
+            ActionPlan::BallotKeyDeal(inner) => pb_t::ActionPlan {
+                action: Some(pb_t::action_plan::Action::BallotKeyDeal(inner.into())),
+            },
+            ActionPlan::BallotKeyReveal(inner) => pb_t::ActionPlan {
+                action: Some(pb_t::action_plan::Action::BallotKeyReveal(inner.into())),
+            },
+            ActionPlan::BallotKeyComplaint(inner) => pb_t::ActionPlan {
+                action: Some(pb_t::action_plan::Action::BallotKeyComplaint(inner.into())),
+            },
             ActionPlan::ProposalDepositClaim(inner) => pb_t::ActionPlan {
                 action: Some(pb_t::action_plan::Action::ProposalDepositClaim(
                     inner.into(),
@@ -388,6 +521,9 @@ impl From<ActionPlan> for pb_t::ActionPlan {
             ActionPlan::PositionOpen(inner) => pb_t::ActionPlan {
                 action: Some(pb_t::action_plan::Action::PositionOpen(inner.into())),
             },
+            ActionPlan::PositionOpenBatch(inner) => pb_t::ActionPlan {
+                action: Some(pb_t::action_plan::Action::PositionOpenBatch(inner.into())),
+            },
             ActionPlan::PositionClose(inner) => pb_t::ActionPlan {
                 action: Some(pb_t::action_plan::Action::PositionClose(inner.into())),
             },
@@ -398,6 +534,27 @@ impl From<ActionPlan> for pb_t::ActionPlan {
                     inner
                 ))),
             },
+            ActionPlan::PositionUpdateBatch(inner) => pb_t::ActionPlan {
+                action: Some(pb_t::action_plan::Action::PositionUpdateBatch(inner.into())),
+            },
+            ActionPlan::PositionRewrite(inner) => pb_t::ActionPlan {
+                action: Some(pb_t::action_plan::Action::PositionRewrite(inner.into())),
+            },
+            ActionPlan::ActionDutchAuctionSchedule(inner) => pb_t::ActionPlan {
+                action: Some(pb_t::action_plan::Action::ActionDutchAuctionSchedule(
+                    inner.into(),
+                )),
+            },
+            ActionPlan::ActionDutchAuctionEnd(inner) => pb_t::ActionPlan {
+                action: Some(pb_t::action_plan::Action::ActionDutchAuctionEnd(
+                    inner.into(),
+                )),
+            },
+            ActionPlan::ActionDutchAuctionWithdraw(inner) => pb_t::ActionPlan {
+                action: Some(pb_t::action_plan::Action::ActionDutchAuctionWithdraw(
+                    inner.into(),
+                )),
+            },
             ActionPlan::CommunityPoolDeposit(inner) => pb_t::ActionPlan {
                 action: Some(pb_t::action_plan::Action::CommunityPoolDeposit(
                     inner.into(),
@@ -439,6 +596,9 @@ impl TryFrom<pb_t::ActionPlan> for ActionPlan {
             pb_t::action_plan::Action::UndelegateClaim(inner) => {
                 Ok(ActionPlan::UndelegateClaim(inner.try_into()?))
             }
+            pb_t::action_plan::Action::Redelegate(inner) => {
+                Ok(ActionPlan::Redelegate(inner.try_into()?))
+            }
             pb_t::action_plan::Action::ValidatorDefinition(inner) => {
                 Ok(ActionPlan::ValidatorDefinition(inner.try_into()?))
             }
@@ -458,6 +618,15 @@ impl TryFrom<pb_t::ActionPlan> for ActionPlan {
             pb_t::action_plan::Action::ValidatorVote(inner) => {
                 Ok(ActionPlan::ValidatorVote(inner.try_into()?))
             }
+            pb_t::action_plan::Action::BallotKeyDeal(inner) => {
+                Ok(ActionPlan::BallotKeyDeal(inner.try_into()?))
+            }
+            pb_t::action_plan::Action::BallotKeyReveal(inner) => {
+                Ok(ActionPlan::BallotKeyReveal(inner.try_into()?))
+            }
+            pb_t::action_plan::Action::BallotKeyComplaint(inner) => {
+                Ok(ActionPlan::BallotKeyComplaint(inner.try_into()?))
+            }
             pb_t::action_plan::Action::DelegatorVote(inner) => {
                 Ok(ActionPlan::DelegatorVote(inner.try_into()?))
             }
@@ -467,12 +636,30 @@ impl TryFrom<pb_t::ActionPlan> for ActionPlan {
             pb_t::action_plan::Action::PositionOpen(inner) => {
                 Ok(ActionPlan::PositionOpen(inner.try_into()?))
             }
+            pb_t::action_plan::Action::PositionOpenBatch(inner) => {
+                Ok(ActionPlan::PositionOpenBatch(inner.try_into()?))
+            }
             pb_t::action_plan::Action::PositionClose(inner) => {
                 Ok(ActionPlan::PositionClose(inner.try_into()?))
             }
             pb_t::action_plan::Action::PositionWithdraw(inner) => {
                 Ok(ActionPlan::PositionWithdraw(inner.try_into()?))
             }
+            pb_t::action_plan::Action::PositionUpdateBatch(inner) => {
+                Ok(ActionPlan::PositionUpdateBatch(inner.try_into()?))
+            }
+            pb_t::action_plan::Action::PositionRewrite(inner) => {
+                Ok(ActionPlan::PositionRewrite(inner.try_into()?))
+            }
+            pb_t::action_plan::Action::ActionDutchAuctionSchedule(inner) => {
+                Ok(ActionPlan::ActionDutchAuctionSchedule(inner.try_into()?))
+            }
+            pb_t::action_plan::Action::ActionDutchAuctionEnd(inner) => {
+                Ok(ActionPlan::ActionDutchAuctionEnd(inner.try_into()?))
+            }
+            pb_t::action_plan::Action::ActionDutchAuctionWithdraw(inner) => {
+                Ok(ActionPlan::ActionDutchAuctionWithdraw(inner.try_into()?))
+            }
             pb_t::action_plan::Action::PositionRewardClaim(_) => {
                 Err(anyhow!("PositionRewardClaim is deprecated and unsupported"))
             }
@@ -21,7 +21,7 @@ use penumbra_proto::{
 };
 use penumbra_sct::Nullifier;
 use penumbra_shielded_pool::{Note, Output, Spend};
-use penumbra_stake::{Delegate, Undelegate, UndelegateClaim};
+use penumbra_stake::{Delegate, Redelegate, Undelegate, UndelegateClaim};
 use penumbra_tct as tct;
 use penumbra_tct::StateCommitment;
 use penumbra_txhash::{
@@ -244,16 +244,26 @@ impl Transaction {
                 | Action::Delegate(_)
                 | Action::Undelegate(_)
                 | Action::UndelegateClaim(_)
+                | Action::Redelegate(_)
                 | Action::ValidatorDefinition(_)
                 | Action::IbcRelay(_)
                 | Action::ProposalSubmit(_)
                 | Action::ProposalWithdraw(_)
                 | Action::ValidatorVote(_)
+                | Action::BallotKeyDeal(_)
+                | Action::BallotKeyReveal(_)
+                | Action::BallotKeyComplaint(_)
                 | Action::DelegatorVote(_)
                 | Action::ProposalDepositClaim(_)
                 | Action::PositionOpen(_)
+                | Action::PositionOpenBatch(_)
                 | Action::PositionClose(_)
                 | Action::PositionWithdraw(_)
+                | Action::PositionUpdateBatch(_)
+                | Action::PositionRewrite(_)
+                | Action::ActionDutchAuctionSchedule(_)
+                | Action::ActionDutchAuctionEnd(_)
+                | Action::ActionDutchAuctionWithdraw(_)
                 | Action::Ics20Withdrawal(_)
                 | Action::CommunityPoolSpend(_)
                 | Action::CommunityPoolOutput(_)
@@ -357,6 +367,16 @@ impl Transaction {
         })
     }
 
+    pub fn redelegations(&self) -> impl Iterator<Item = &Redelegate> {
+        self.actions().filter_map(|action| {
+            if let Action::Redelegate(r) = action {
+                Some(r)
+            } else {
+                None
+            }
+        })
+    }
+
     pub fn undelegate_claims(&self) -> impl Iterator<Item = &UndelegateClaim> {
         self.actions().filter_map(|action| {
             if let Action::UndelegateClaim(d) = action {
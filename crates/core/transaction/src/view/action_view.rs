@@ -1,14 +1,23 @@
 use penumbra_community_pool::{CommunityPoolDeposit, CommunityPoolOutput, CommunityPoolSpend};
 use penumbra_dex::{
-    lp::action::{PositionClose, PositionOpen, PositionWithdraw},
+    auction::action::{
+        ActionDutchAuctionEnd, ActionDutchAuctionSchedule, ActionDutchAuctionWithdraw,
+    },
+    lp::action::{
+        PositionClose, PositionOpen, PositionOpenBatch, PositionRewrite, PositionUpdateBatch,
+        PositionWithdraw,
+    },
     swap::SwapView,
     swap_claim::SwapClaimView,
 };
-use penumbra_governance::{ProposalDepositClaim, ProposalSubmit, ProposalWithdraw, ValidatorVote};
+use penumbra_governance::{
+    BallotKeyComplaint, BallotKeyDeal, BallotKeyReveal, ProposalDepositClaim, ProposalSubmit,
+    ProposalWithdraw, ValidatorVote,
+};
 use penumbra_ibc::IbcRelay;
 use penumbra_proto::{core::transaction::v1 as pbt, DomainType};
 use penumbra_shielded_pool::Ics20Withdrawal;
-use penumbra_stake::{Delegate, Undelegate, UndelegateClaim};
+use penumbra_stake::{Delegate, Redelegate, Undelegate, UndelegateClaim};
 use serde::{Deserialize, Serialize};
 
 pub use penumbra_governance::DelegatorVoteView;
@@ -33,13 +42,23 @@ pub enum ActionView {
     ProposalSubmit(ProposalSubmit),
     ProposalWithdraw(ProposalWithdraw),
     ValidatorVote(ValidatorVote),
+    BallotKeyDeal(BallotKeyDeal),
+    BallotKeyReveal(BallotKeyReveal),
+    BallotKeyComplaint(BallotKeyComplaint),
     ProposalDepositClaim(ProposalDepositClaim),
     PositionOpen(PositionOpen),
+    PositionOpenBatch(PositionOpenBatch),
     PositionClose(PositionClose),
     PositionWithdraw(PositionWithdraw),
+    PositionUpdateBatch(PositionUpdateBatch),
+    PositionRewrite(PositionRewrite),
+    ActionDutchAuctionSchedule(ActionDutchAuctionSchedule),
+    ActionDutchAuctionEnd(ActionDutchAuctionEnd),
+    ActionDutchAuctionWithdraw(ActionDutchAuctionWithdraw),
     Delegate(Delegate),
     Undelegate(Undelegate),
     UndelegateClaim(UndelegateClaim),
+    Redelegate(Redelegate),
     Ics20Withdrawal(Ics20Withdrawal),
     CommunityPoolDeposit(CommunityPoolDeposit),
     CommunityPoolSpend(CommunityPoolSpend),
@@ -65,6 +84,7 @@ impl TryFrom<pbt::ActionView> for ActionView {
                 AV::Output(x) => ActionView::Output(x.try_into()?),
                 AV::Undelegate(x) => ActionView::Undelegate(x.try_into()?),
                 AV::UndelegateClaim(x) => ActionView::UndelegateClaim(x.try_into()?),
+                AV::Redelegate(x) => ActionView::Redelegate(x.try_into()?),
                 AV::Swap(x) => ActionView::Swap(x.try_into()?),
                 AV::SwapClaim(x) => ActionView::SwapClaim(x.try_into()?),
                 AV::ValidatorDefinition(x) => ActionView::ValidatorDefinition(x.try_into()?),
@@ -73,10 +93,23 @@ impl TryFrom<pbt::ActionView> for ActionView {
                 AV::ProposalWithdraw(x) => ActionView::ProposalWithdraw(x.try_into()?),
                 AV::ProposalDepositClaim(x) => ActionView::ProposalDepositClaim(x.try_into()?),
                 AV::ValidatorVote(x) => ActionView::ValidatorVote(x.try_into()?),
+                AV::BallotKeyDeal(x) => ActionView::BallotKeyDeal(x.try_into()?),
+                AV::BallotKeyReveal(x) => ActionView::BallotKeyReveal(x.try_into()?),
+                AV::BallotKeyComplaint(x) => ActionView::BallotKeyComplaint(x.try_into()?),
                 AV::DelegatorVote(x) => ActionView::DelegatorVote(x.try_into()?),
                 AV::PositionOpen(x) => ActionView::PositionOpen(x.try_into()?),
+                AV::PositionOpenBatch(x) => ActionView::PositionOpenBatch(x.try_into()?),
                 AV::PositionClose(x) => ActionView::PositionClose(x.try_into()?),
                 AV::PositionWithdraw(x) => ActionView::PositionWithdraw(x.try_into()?),
+                AV::PositionUpdateBatch(x) => ActionView::PositionUpdateBatch(x.try_into()?),
+                AV::PositionRewrite(x) => ActionView::PositionRewrite(x.try_into()?),
+                AV::ActionDutchAuctionSchedule(x) => {
+                    ActionView::ActionDutchAuctionSchedule(x.try_into()?)
+                }
+                AV::ActionDutchAuctionEnd(x) => ActionView::ActionDutchAuctionEnd(x.try_into()?),
+                AV::ActionDutchAuctionWithdraw(x) => {
+                    ActionView::ActionDutchAuctionWithdraw(x.try_into()?)
+                }
                 AV::PositionRewardClaim(_) => {
                     return Err(anyhow::anyhow!(
                         "PositionRewardClaim is deprecated and unsupported"
@@ -103,16 +136,30 @@ impl From<ActionView> for pbt::ActionView {
                 ActionView::Delegate(x) => AV::Delegate(x.into()),
                 ActionView::Undelegate(x) => AV::Undelegate(x.into()),
                 ActionView::UndelegateClaim(x) => AV::UndelegateClaim(x.into()),
+                ActionView::Redelegate(x) => AV::Redelegate(x.into()),
                 ActionView::ValidatorDefinition(x) => AV::ValidatorDefinition(x.into()),
                 ActionView::IbcRelay(x) => AV::IbcRelayAction(x.into()),
                 ActionView::ProposalSubmit(x) => AV::ProposalSubmit(x.into()),
                 ActionView::ProposalWithdraw(x) => AV::ProposalWithdraw(x.into()),
                 ActionView::ValidatorVote(x) => AV::ValidatorVote(x.into()),
+                ActionView::BallotKeyDeal(x) => AV::BallotKeyDeal(x.into()),
+                ActionView::BallotKeyReveal(x) => AV::BallotKeyReveal(x.into()),
+                ActionView::BallotKeyComplaint(x) => AV::BallotKeyComplaint(x.into()),
                 ActionView::DelegatorVote(x) => AV::DelegatorVote(x.into()),
                 ActionView::ProposalDepositClaim(x) => AV::ProposalDepositClaim(x.into()),
                 ActionView::PositionOpen(x) => AV::PositionOpen(x.into()),
+                ActionView::PositionOpenBatch(x) => AV::PositionOpenBatch(x.into()),
                 ActionView::PositionClose(x) => AV::PositionClose(x.into()),
                 ActionView::PositionWithdraw(x) => AV::PositionWithdraw(x.into()),
+                ActionView::PositionUpdateBatch(x) => AV::PositionUpdateBatch(x.into()),
+                ActionView::PositionRewrite(x) => AV::PositionRewrite(x.into()),
+                ActionView::ActionDutchAuctionSchedule(x) => {
+                    AV::ActionDutchAuctionSchedule(x.into())
+                }
+                ActionView::ActionDutchAuctionEnd(x) => AV::ActionDutchAuctionEnd(x.into()),
+                ActionView::ActionDutchAuctionWithdraw(x) => {
+                    AV::ActionDutchAuctionWithdraw(x.into())
+                }
                 ActionView::Ics20Withdrawal(x) => AV::Ics20Withdrawal(x.into()),
                 ActionView::CommunityPoolDeposit(x) => AV::CommunityPoolDeposit(x.into()),
                 ActionView::CommunityPoolSpend(x) => AV::CommunityPoolSpend(x.into()),
@@ -132,16 +179,26 @@ impl From<ActionView> for Action {
             ActionView::Delegate(x) => Action::Delegate(x),
             ActionView::Undelegate(x) => Action::Undelegate(x),
             ActionView::UndelegateClaim(x) => Action::UndelegateClaim(x),
+            ActionView::Redelegate(x) => Action::Redelegate(x),
             ActionView::ValidatorDefinition(x) => Action::ValidatorDefinition(x),
             ActionView::IbcRelay(x) => Action::IbcRelay(x),
             ActionView::ProposalSubmit(x) => Action::ProposalSubmit(x),
             ActionView::ProposalWithdraw(x) => Action::ProposalWithdraw(x),
             ActionView::ValidatorVote(x) => Action::ValidatorVote(x),
+            ActionView::BallotKeyDeal(x) => Action::BallotKeyDeal(x),
+            ActionView::BallotKeyReveal(x) => Action::BallotKeyReveal(x),
+            ActionView::BallotKeyComplaint(x) => Action::BallotKeyComplaint(x),
             ActionView::DelegatorVote(x) => Action::DelegatorVote(x.into()),
             ActionView::ProposalDepositClaim(x) => Action::ProposalDepositClaim(x),
             ActionView::PositionOpen(x) => Action::PositionOpen(x),
+            ActionView::PositionOpenBatch(x) => Action::PositionOpenBatch(x),
             ActionView::PositionClose(x) => Action::PositionClose(x),
             ActionView::PositionWithdraw(x) => Action::PositionWithdraw(x),
+            ActionView::PositionUpdateBatch(x) => Action::PositionUpdateBatch(x),
+            ActionView::PositionRewrite(x) => Action::PositionRewrite(x),
+            ActionView::ActionDutchAuctionSchedule(x) => Action::ActionDutchAuctionSchedule(x),
+            ActionView::ActionDutchAuctionEnd(x) => Action::ActionDutchAuctionEnd(x),
+            ActionView::ActionDutchAuctionWithdraw(x) => Action::ActionDutchAuctionWithdraw(x),
             ActionView::Ics20Withdrawal(x) => Action::Ics20Withdrawal(x),
             ActionView::CommunityPoolDeposit(x) => Action::CommunityPoolDeposit(x),
             ActionView::CommunityPoolSpend(x) => Action::CommunityPoolSpend(x),
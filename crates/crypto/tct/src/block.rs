@@ -167,6 +167,63 @@ impl Builder {
         Ok(())
     }
 
+    /// Add a batch of new [`Commitment`]s to this [`block::Builder`](Builder).
+    ///
+    /// This is equivalent to [`insert`](Builder::insert)ing each commitment in turn, but is faster
+    /// for large batches, because the index of witnessed commitments is only updated once, at the
+    /// end of the batch.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`InsertError`] if the block becomes full, in which case all the commitments in the
+    /// batch preceding the one which could not be inserted remain inserted, and the rest of the
+    /// batch is discarded.
+    pub fn insert_batch(
+        &mut self,
+        commitments: impl IntoIterator<Item = (Witness, StateCommitment)>,
+    ) -> Result<(), InsertError> {
+        let commitments = commitments.into_iter();
+        let mut kept = Vec::with_capacity(commitments.size_hint().0);
+
+        let inner = Arc::make_mut(&mut self.inner);
+        let mut result = Ok(());
+        for (witness, commitment) in commitments {
+            let item = match witness {
+                Witness::Keep => commitment.into(),
+                Witness::Forget => Hash::of(commitment).into(),
+            };
+
+            // Get the position of the insertion, if it would succeed
+            let position = if let Some(position) = inner.position() {
+                u16::try_from(position).expect("position of block is never greater than `u16::MAX`")
+            } else {
+                result = Err(InsertError);
+                break;
+            };
+
+            inner
+                .insert(item)
+                .expect("inserting a commitment must succeed when block has a position");
+
+            if let Witness::Keep = witness {
+                kept.push((commitment, position.into()));
+            }
+        }
+
+        // Index all the kept commitments at once, including those inserted before any error
+        self.index.reserve(kept.len());
+        for (commitment, position) in kept {
+            if let Some(replaced) = self.index.insert(commitment, position) {
+                // This case is handled for completeness, but should not happen in
+                // practice because commitments should be unique
+                let forgotten = Arc::make_mut(&mut self.inner).forget(replaced);
+                debug_assert!(forgotten);
+            }
+        }
+
+        result
+    }
+
     /// Get the root hash of this block builder.
     ///
     /// Note that this root hash will differ from the root hash of the finalized block.
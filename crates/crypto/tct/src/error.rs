@@ -110,6 +110,23 @@ impl From<InsertEpochError> for builder::epoch::Finalized {
     }
 }
 
+/// An error occurred when building a [`Tree`] from a stream of blocks.
+#[derive(Debug, Clone, Error)]
+pub enum StreamError<E> {
+    /// The next block could not be read from the stream.
+    #[error("could not read the next block from the stream")]
+    Stream(#[source] E),
+    /// A commitment in a block could not be inserted into the [`Tree`].
+    #[error(transparent)]
+    Insert(#[from] InsertError),
+    /// A block could not be inserted into the [`Tree`].
+    #[error(transparent)]
+    InsertBlock(#[from] InsertBlockError),
+    /// An epoch could not be ended in the [`Tree`].
+    #[error(transparent)]
+    InsertEpoch(#[from] InsertEpochError),
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -0,0 +1,144 @@
+use crate::{
+    builder::{block, epoch},
+    error::{InsertBlockError, InsertEpochError, InsertError},
+    Position, Root, StateCommitment, Tree, Witness,
+};
+
+/// A [`Tree`] which only keeps its frontier: enough to insert new [`StateCommitment`]s and compute
+/// its [`Root`], but never enough to witness any of them.
+///
+/// Because nothing is ever witnessed, every completed subtree is collapsed into its hash as soon
+/// as it is finished, so a [`FrontierTree`] uses a small, constant amount of memory no matter how
+/// many commitments have been inserted into it. This makes it suitable for services which need to
+/// track or validate anchors, but never need to prove inclusion of anything in them.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct FrontierTree {
+    // Invariant: this tree never witnesses any commitment.
+    inner: Tree,
+}
+
+impl FrontierTree {
+    /// Create a new empty [`FrontierTree`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Get the root hash of this [`FrontierTree`], which is the same as the root of a [`Tree`] into
+    /// which the same commitments, blocks and epochs had been inserted.
+    pub fn root(&self) -> Root {
+        self.inner.root()
+    }
+
+    /// The position at which the next [`StateCommitment`] would be inserted.
+    ///
+    /// If the [`FrontierTree`] is full, returns `None`.
+    pub fn position(&self) -> Option<Position> {
+        self.inner.position()
+    }
+
+    /// Add a new [`StateCommitment`] to the most recent block of the most recent epoch of this
+    /// [`FrontierTree`].
+    ///
+    /// If successful, returns the [`Position`] at which the commitment was inserted.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`InsertError`] under the same conditions as [`Tree::insert`].
+    pub fn insert(&mut self, commitment: StateCommitment) -> Result<Position, InsertError> {
+        self.inner.insert(Witness::Forget, commitment)
+    }
+
+    /// Add a whole block to the most recent epoch of this [`FrontierTree`], by its root.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`InsertBlockError`] under the same conditions as [`Tree::insert_block`].
+    pub fn insert_block(&mut self, root: block::Root) -> Result<block::Root, InsertBlockError> {
+        self.inner.insert_block(root)
+    }
+
+    /// Add a whole epoch to this [`FrontierTree`], by its root.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`InsertEpochError`] under the same conditions as [`Tree::insert_epoch`].
+    pub fn insert_epoch(&mut self, root: epoch::Root) -> Result<epoch::Root, InsertEpochError> {
+        self.inner.insert_epoch(root)
+    }
+
+    /// Explicitly mark the end of the current block, returning the root of the block which was
+    /// just finalized.
+    pub fn end_block(&mut self) -> Result<block::Root, InsertBlockError> {
+        self.inner.end_block()
+    }
+
+    /// Explicitly mark the end of the current epoch, returning the root of the epoch which was
+    /// just finalized.
+    pub fn end_epoch(&mut self) -> Result<epoch::Root, InsertEpochError> {
+        self.inner.end_epoch()
+    }
+
+    /// Get the root hash of the most recent block in the most recent epoch.
+    pub fn current_block_root(&self) -> block::Root {
+        self.inner.current_block_root()
+    }
+
+    /// Get the root hash of the most recent epoch.
+    pub fn current_epoch_root(&self) -> epoch::Root {
+        self.inner.current_epoch_root()
+    }
+}
+
+impl From<Tree> for FrontierTree {
+    /// Forget every witnessed commitment in the [`Tree`], keeping only its frontier.
+    fn from(mut tree: Tree) -> Self {
+        let mut witnessed: Vec<(StateCommitment, Position)> =
+            tree.commitments_unordered().collect();
+        // Forgetting in order of position lets each finished block and epoch collapse into its
+        // hash as soon as its last witness is forgotten.
+        witnessed.sort_unstable_by_key(|(_, position)| *position);
+        for (commitment, _) in witnessed {
+            tree.forget(commitment);
+        }
+        Self { inner: tree }
+    }
+}
+
+impl From<FrontierTree> for Tree {
+    /// A [`FrontierTree`] is a [`Tree`] that witnesses nothing, to which new witnessed commitments
+    /// can then be added.
+    fn from(frontier: FrontierTree) -> Self {
+        frontier.inner
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn frontier_root_matches_tree() {
+        let mut tree = Tree::new();
+        let mut frontier = FrontierTree::new();
+
+        for i in 0..50u64 {
+            let commitment = StateCommitment(i.into());
+            tree.insert(Witness::Keep, commitment).unwrap();
+            frontier.insert(commitment).unwrap();
+            if i % 5 == 4 {
+                assert_eq!(tree.end_block().unwrap(), frontier.end_block().unwrap());
+            }
+            if i % 20 == 19 {
+                assert_eq!(tree.end_epoch().unwrap(), frontier.end_epoch().unwrap());
+            }
+            assert_eq!(tree.root(), frontier.root());
+            assert_eq!(tree.position(), frontier.position());
+        }
+
+        let converted = FrontierTree::from(tree.clone());
+        assert_eq!(converted, frontier);
+        assert_eq!(converted.root(), tree.root());
+        assert_eq!(Tree::from(converted).witnessed_count(), 0);
+    }
+}
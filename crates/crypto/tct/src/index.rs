@@ -20,9 +20,10 @@ pub struct Commitment(u16);
 impl Commitment {
     /// Increment the commitment.
     pub fn increment(&mut self) {
-        self.0
+        self.0 = self
+            .0
             .checked_add(1)
-            .expect("block index should never overflow");
+            .expect("commitment index should never overflow");
     }
 
     /// The maximum representable commitment index.
@@ -66,7 +67,8 @@ impl From<Block> for u16 {
 impl Block {
     /// Increment the block.
     pub fn increment(&mut self) {
-        self.0
+        self.0 = self
+            .0
             .checked_add(1)
             .expect("block index should never overflow");
     }
@@ -100,9 +102,10 @@ impl From<Epoch> for u16 {
 impl Epoch {
     /// Increment the epoch.
     pub fn increment(&mut self) {
-        self.0
+        self.0 = self
+            .0
             .checked_add(1)
-            .expect("block index should never overflow");
+            .expect("epoch index should never overflow");
     }
 
     /// The maximum epoch index representable.
@@ -223,6 +226,15 @@ pub mod within {
             block: super::Block::MAX,
             commitment: Commitment::MAX,
         };
+
+        /// The number of positions in a tree, one more than the packed [`MAX`](Self::MAX).
+        pub const CAPACITY: u64 = 1 << 48;
+
+        /// Unpack an index from a `u64`, or return `None` if it is too large to be an index within
+        /// a tree, rather than silently discarding its upper bits as `From<u64>` does.
+        pub fn checked_from(position: u64) -> Option<Self> {
+            (position < Self::CAPACITY).then(|| position.into())
+        }
     }
 
     impl From<Tree> for u64 {
@@ -237,6 +249,9 @@ pub mod within {
         }
     }
 
+    /// Unpack an index from a `u64`, ignoring any bits above the lower 48.
+    ///
+    /// Use [`Tree::checked_from`] where the `u64` is not known to be in range.
     impl From<u64> for Tree {
         fn from(position: u64) -> Self {
             let epoch = (position >> 32) as u16;
@@ -258,7 +273,7 @@ mod test {
 
     proptest! {
         #[test]
-        fn u64_convert_eternity_inverse(e in 0u16..u16::MAX, b in 0u16..u16::MAX, c in 0u16..u16::MAX) {
+        fn u64_convert_eternity_inverse(e in 0u16..=u16::MAX, b in 0u16..=u16::MAX, c in 0u16..=u16::MAX) {
             let tree = within::Tree { epoch: e.into(), block: b.into(), commitment: c.into() };
             let position: u64 = tree.into();
             let back_again = position.into();
@@ -266,7 +281,7 @@ mod test {
         }
 
         #[test]
-        fn u32_convert_epoch_inverse(b in 0u16..u16::MAX, c in 0u16..u16::MAX) {
+        fn u32_convert_epoch_inverse(b in 0u16..=u16::MAX, c in 0u16..=u16::MAX) {
             let epoch = within::Epoch { block: b.into(), commitment: c.into() };
             let position: u32 = epoch.into();
             let back_again = position.into();
@@ -274,11 +289,57 @@ mod test {
         }
 
         #[test]
-        fn u16_convert_block_inverse(c in 0u16..u16::MAX) {
+        fn u16_convert_block_inverse(c in 0u16..=u16::MAX) {
             let block = within::Block { commitment: c.into() };
             let position: u16 = block.into();
             let back_again = position.into();
             assert_eq!(block, back_again);
         }
+
+        #[test]
+        fn checked_from_rejects_out_of_range(position in within::Tree::CAPACITY..=u64::MAX) {
+            assert_eq!(within::Tree::checked_from(position), None);
+        }
+    }
+
+    #[test]
+    fn packing_at_tier_limits() {
+        let max: u64 = within::Tree::MAX.into();
+        assert_eq!(max, within::Tree::CAPACITY - 1);
+        assert_eq!(within::Tree::checked_from(max), Some(within::Tree::MAX));
+        assert_eq!(within::Tree::checked_from(within::Tree::CAPACITY), None);
+
+        // The last commitment of a block is followed by the first of the next block, and the last
+        // block of an epoch by the first of the next epoch
+        let end_of_block = within::Tree {
+            epoch: 0.into(),
+            block: 0.into(),
+            commitment: u16::MAX.into(),
+        };
+        assert_eq!(
+            within::Tree::from(u64::from(end_of_block) + 1),
+            within::Tree {
+                epoch: 0.into(),
+                block: 1.into(),
+                commitment: 0.into(),
+            }
+        );
+        let end_of_epoch = within::Tree {
+            epoch: 0.into(),
+            block: u16::MAX.into(),
+            commitment: u16::MAX.into(),
+        };
+        assert_eq!(
+            within::Tree::from(u64::from(end_of_epoch) + 1),
+            within::Tree {
+                epoch: 1.into(),
+                block: 0.into(),
+                commitment: 0.into(),
+            }
+        );
+
+        let mut commitment = Commitment::from(u16::MAX - 1);
+        commitment.increment();
+        assert_eq!(commitment, Commitment::MAX);
     }
 }
@@ -0,0 +1,100 @@
+//! Counters of the hashing work done by the tree, for performance investigation.
+//!
+//! When the `instrumentation` feature is enabled, every hash computed for a commitment or an
+//! internal node of a tree is counted, and the spans emitted by [`Tree`](crate::Tree) operations
+//! which may hash (such as [`root`](crate::Tree::root), [`witness`](crate::Tree::witness), and
+//! [`insert`](crate::Tree::insert)) record how many hashes were computed during the operation in
+//! their `nodes_hashed` and `commitments_hashed` fields. Hashes which are cached or precomputed are
+//! not counted, so the counts measure the work actually done.
+//!
+//! The counters are global to the process, so an operation's counts also include any hashing done
+//! concurrently by other threads, such as by other trees.
+
+use std::sync::atomic::{AtomicU64, Ordering};
+
+static NODES_HASHED: AtomicU64 = AtomicU64::new(0);
+static COMMITMENTS_HASHED: AtomicU64 = AtomicU64::new(0);
+
+/// The number of hashes computed by all trees in this process.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct HashCounts {
+    /// The number of internal node hashes computed.
+    pub nodes: u64,
+    /// The number of commitment (leaf) hashes computed.
+    pub commitments: u64,
+}
+
+impl HashCounts {
+    /// The number of hashes computed since these counts were taken.
+    pub fn elapsed(&self) -> HashCounts {
+        let now = hash_counts();
+        HashCounts {
+            nodes: now.nodes.wrapping_sub(self.nodes),
+            commitments: now.commitments.wrapping_sub(self.commitments),
+        }
+    }
+}
+
+/// Get the number of hashes computed by all trees in this process so far.
+pub fn hash_counts() -> HashCounts {
+    HashCounts {
+        nodes: NODES_HASHED.load(Ordering::Relaxed),
+        commitments: COMMITMENTS_HASHED.load(Ordering::Relaxed),
+    }
+}
+
+/// Count the computation of an internal node hash.
+#[inline]
+pub(crate) fn count_node() {
+    NODES_HASHED.fetch_add(1, Ordering::Relaxed);
+}
+
+/// Count the computation of a commitment hash.
+#[inline]
+pub(crate) fn count_commitment() {
+    COMMITMENTS_HASHED.fetch_add(1, Ordering::Relaxed);
+}
+
+/// A guard which, when dropped, records the hashes computed during its lifetime in the
+/// `nodes_hashed` and `commitments_hashed` fields of the current span.
+pub(crate) struct SpanCounts(HashCounts);
+
+impl SpanCounts {
+    /// Start counting the hashes computed within the current span.
+    pub(crate) fn start() -> Self {
+        Self(hash_counts())
+    }
+}
+
+impl Drop for SpanCounts {
+    fn drop(&mut self) {
+        let elapsed = self.0.elapsed();
+        let span = tracing::Span::current();
+        span.record("nodes_hashed", elapsed.nodes);
+        span.record("commitments_hashed", elapsed.commitments);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{StateCommitment, Tree, Witness};
+
+    use super::*;
+
+    // Other tests may be hashing concurrently, so these only check lower bounds.
+    #[test]
+    fn hashing_is_counted() {
+        let mut tree = Tree::new();
+
+        let before = hash_counts();
+        for i in 0..16u64 {
+            tree.insert(Witness::Keep, StateCommitment(i.into()))
+                .expect("tree is not full");
+        }
+        assert!(before.elapsed().commitments >= 16);
+
+        let before = hash_counts();
+        tree.root();
+        assert!(before.elapsed().nodes > 0);
+    }
+}
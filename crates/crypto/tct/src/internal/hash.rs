@@ -153,6 +153,8 @@ impl Hash {
     /// Hash an individual commitment to be inserted into the tree.
     #[inline]
     pub fn of(item: StateCommitment) -> Hash {
+        #[cfg(feature = "instrumentation")]
+        crate::instrumentation::count_commitment();
         Self(hash_1(&DOMAIN_SEPARATOR, item.0))
     }
 
@@ -207,6 +209,8 @@ impl Hash {
         }
 
         // Otherwise, hash the node normally
+        #[cfg(feature = "instrumentation")]
+        crate::instrumentation::count_node();
         hash_node(height, a, b, c, d)
     }
 }
@@ -58,6 +58,10 @@ pub struct VerifyError {
 }
 
 impl VerifyError {
+    pub(crate) fn new(root: Hash) -> Self {
+        Self { root }
+    }
+
     /// Get the root hash against which the proof failed to verify.
     pub fn root(&self) -> Hash {
         self.root
@@ -92,7 +96,11 @@ where
     type Error = ProofDecodeError;
 
     fn try_from(proof: pb::StateCommitmentProof) -> Result<Self, Self::Error> {
-        let position = proof.position;
+        // Bits above the position's 48 would be ignored when verifying, so a proof carrying them
+        // would claim a position other than the one it proves.
+        let position = index::within::Tree::checked_from(proof.position)
+            .ok_or(ProofDecodeError)?
+            .into();
         let auth_path = proof.auth_path.try_into().map_err(|_| ProofDecodeError)?;
         let leaf = StateCommitment(
             Fq::from_bytes(
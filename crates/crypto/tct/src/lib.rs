@@ -53,13 +53,19 @@ extern crate thiserror;
 extern crate async_trait;
 
 mod commitment;
+mod frontier_tree;
 mod index;
 mod proof;
 mod random;
+mod stream;
+mod tag;
 mod tree;
 mod witness;
 
 pub mod error;
+#[cfg(feature = "instrumentation")]
+pub mod instrumentation;
+pub mod shared;
 pub mod storage;
 pub mod structure;
 pub mod validate;
@@ -67,9 +73,12 @@ pub mod validate;
 #[doc(inline)]
 pub use {
     commitment::StateCommitment,
+    frontier_tree::FrontierTree,
     internal::hash::Forgotten,
     internal::hash::DOMAIN_SEPARATOR,
     proof::Proof,
+    stream::StreamedBlock,
+    tag::Tag,
     tree::{Position, Root, Tree},
     witness::Witness,
 };
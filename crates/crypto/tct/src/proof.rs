@@ -1,3 +1,5 @@
+use std::collections::HashMap;
+
 use ark_ff::UniformRand;
 use poseidon377::Fq;
 
@@ -72,6 +74,51 @@ impl Proof {
         self.0.verify(root.0)
     }
 
+    /// Verify many [`Proof`]s of inclusion against the same [`Root`], returning the result for
+    /// each, in the same order.
+    ///
+    /// This gives the same results as calling [`verify`](Proof::verify) on each proof, but the
+    /// proofs are verified in order of position, so that the upper nodes shared by the paths of
+    /// nearby commitments are hashed only once: once a path reaches a node which an earlier proof
+    /// authenticated, it only needs to compare the rest of its siblings, rather than hash them.
+    /// When the `parallel` feature is enabled, the proofs are also verified in parallel.
+    pub fn verify_batch(root: Root, proofs: &[Proof]) -> Vec<Result<(), VerifyError>> {
+        // Each chunk of proofs is verified sequentially, sharing the nodes it authenticates
+        const CHUNK_SIZE: usize = 64;
+
+        let mut order = (0..proofs.len()).collect::<Vec<_>>();
+        order.sort_by_key(|&i| proofs[i].0.index());
+
+        let verify_chunk = |chunk: &[usize]| {
+            let mut authenticated = Authenticated::default();
+            chunk
+                .iter()
+                .map(|&i| (i, authenticated.verify(root, &proofs[i])))
+                .collect::<Vec<_>>()
+        };
+
+        #[cfg(feature = "parallel")]
+        let verified = {
+            use rayon::prelude::*;
+            order
+                .par_chunks(CHUNK_SIZE)
+                .flat_map_iter(verify_chunk)
+                .collect::<Vec<_>>()
+        };
+
+        #[cfg(not(feature = "parallel"))]
+        let verified = order
+            .chunks(CHUNK_SIZE)
+            .flat_map(verify_chunk)
+            .collect::<Vec<_>>();
+
+        let mut results = vec![Ok(()); proofs.len()];
+        for (i, result) in verified {
+            results[i] = result;
+        }
+        results
+    }
+
     /// Get the commitment whose inclusion is witnessed by the proof.
     pub fn commitment(&self) -> StateCommitment {
         self.0.leaf
@@ -122,6 +169,77 @@ impl Proof {
     }
 }
 
+/// The hashes of the nodes on the paths of proofs which verified against a root, keyed by height
+/// and index within that height.
+#[derive(Default)]
+struct Authenticated(HashMap<(u8, u64), Hash>);
+
+impl Authenticated {
+    /// The height of the eternity tree, in which every proof's path has this many nodes.
+    const HEIGHT: u8 = 24;
+
+    /// Verify a proof against the root, stopping early if its path joins that of a proof which
+    /// was already verified, and recording the nodes of its path if it verifies.
+    fn verify(&mut self, root: Root, proof: &Proof) -> Result<(), VerifyError> {
+        let position = proof.0.index();
+        // Ordered from the root to the leaf, so the siblings at height `h` are at `24 - h`
+        let auth_path = proof.auth_path();
+        let siblings = |height: u8| *auth_path[(Self::HEIGHT - height) as usize];
+
+        let mut path = Vec::with_capacity(4 * Self::HEIGHT as usize);
+        let mut hash = Hash::of(proof.commitment());
+        for height in 1..=Self::HEIGHT {
+            if self.joins(position, height - 1, hash, siblings) {
+                return Ok(());
+            }
+
+            // Hash this node's parent, remembering the children in case the proof verifies
+            let children = WhichWay::at(height, position)
+                .0
+                .insert(hash, siblings(height));
+            let first_child = (position >> (2 * height)) << 2;
+            path.extend(
+                (first_child..)
+                    .zip(children)
+                    .map(|(i, h)| ((height - 1, i), h)),
+            );
+            hash = Hash::node(height, children[0], children[1], children[2], children[3]);
+        }
+
+        if hash == root.0 {
+            self.0.extend(path);
+            Ok(())
+        } else {
+            Err(VerifyError::new(root.0))
+        }
+    }
+
+    /// Check whether the node with the given hash, at the given height on the path to the
+    /// position, has already been authenticated, and so have all the siblings above it, in
+    /// which case the rest of the path must hash to the root.
+    fn joins(
+        &self,
+        position: u64,
+        height: u8,
+        hash: Hash,
+        siblings: impl Fn(u8) -> [Hash; 3],
+    ) -> bool {
+        let is_authenticated =
+            |height: u8, index: u64, hash: Hash| self.0.get(&(height, index)) == Some(&hash);
+
+        is_authenticated(height, position >> (2 * height), hash)
+            && (height + 1..=Self::HEIGHT).all(|parent| {
+                // The siblings are the other children of the parent, in order
+                let own = position >> (2 * (parent - 1));
+                let first_child = own & !0b11;
+                (first_child..first_child + 4)
+                    .filter(|&index| index != own)
+                    .zip(siblings(parent))
+                    .all(|(index, hash)| is_authenticated(parent - 1, index, hash))
+            })
+    }
+}
+
 use penumbra_proto::penumbra::crypto::tct::v1 as pb;
 use rand::Rng;
 
@@ -142,3 +260,70 @@ impl TryFrom<pb::StateCommitmentProof> for Proof {
 impl penumbra_proto::DomainType for Proof {
     type Proto = pb::StateCommitmentProof;
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::Witness;
+
+    fn commitment(i: u64) -> StateCommitment {
+        StateCommitment(i.into())
+    }
+
+    /// Rebuild a proof with some of its parts replaced.
+    fn tampered(proof: &Proof, leaf: StateCommitment, auth_path: [[Hash; 3]; 24]) -> Proof {
+        Proof::new(leaf, proof.position(), auth_path)
+    }
+
+    #[test]
+    fn verify_batch_matches_verify() {
+        let mut tree = Tree::new();
+        for i in 0..100 {
+            tree.insert(Witness::Keep, commitment(i)).unwrap();
+            if i % 30 == 29 {
+                tree.end_block().unwrap();
+            }
+        }
+        let root = tree.root();
+
+        let mut proofs = (0..100)
+            .rev()
+            .map(|i| tree.witness(commitment(i)).unwrap())
+            .collect::<Vec<_>>();
+
+        // A proof of a commitment which isn't in the tree, along an authentic path
+        let auth_path = proofs[10].auth_path().map(|siblings| *siblings);
+        proofs.push(tampered(&proofs[10], commitment(1000), auth_path));
+
+        // A proof whose path joins an authentic one, but with a forged sibling above that
+        let mut auth_path = proofs[20].auth_path().map(|siblings| *siblings);
+        auth_path[0][1] = Hash::of(commitment(1000));
+        proofs.push(tampered(&proofs[20], proofs[20].commitment(), auth_path));
+
+        // A proof verified more than once
+        proofs.push(proofs[30].clone());
+
+        let results = Proof::verify_batch(root, &proofs);
+        let expected = proofs
+            .iter()
+            .map(|proof| proof.verify(root))
+            .collect::<Vec<_>>();
+        assert_eq!(results, expected);
+        assert_eq!(results.iter().filter(|result| result.is_err()).count(), 2);
+    }
+
+    #[test]
+    fn verify_batch_against_wrong_root() {
+        let mut tree = Tree::new();
+        for i in 0..10 {
+            tree.insert(Witness::Keep, commitment(i)).unwrap();
+        }
+        let proofs = (0..10)
+            .map(|i| tree.witness(commitment(i)).unwrap())
+            .collect::<Vec<_>>();
+
+        tree.insert(Witness::Keep, commitment(10)).unwrap();
+        let results = Proof::verify_batch(tree.root(), &proofs);
+        assert!(results.iter().all(|result| result.is_err()));
+    }
+}
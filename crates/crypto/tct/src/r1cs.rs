@@ -96,7 +96,8 @@ impl R1CSVar<Fq> for PositionVar {
         let position_bytes: [u8; 8] = inner_bytes
             .try_into()
             .expect("should be able to fit in 16 bytes");
-        Ok(Position::from(u64::from_le_bytes(position_bytes)))
+        Position::checked_from(u64::from_le_bytes(position_bytes))
+            .ok_or(SynthesisError::Unsatisfiable)
     }
 }
 
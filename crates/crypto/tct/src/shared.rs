@@ -0,0 +1,208 @@
+//! Sharing a [`Tree`] between a single writer and many concurrent readers.
+//!
+//! A [`Writer`] owns a [`Tree`], and [`publish`](Writer::publish)es snapshots of it. Any number of
+//! [`Reader`]s can be cheaply cloned and handed to other tasks, each of which sees the most recently
+//! published snapshot. This lets many tasks generate proofs concurrently while the writer continues
+//! to insert commitments.
+//!
+//! Publishing a snapshot is cheap, because the internal structure of a [`Tree`] is persistent: a
+//! snapshot shares all of its structure with the writer's tree, which copies only the parts it
+//! subsequently modifies. Neither reading nor publishing takes a lock, so readers never wait for the
+//! writer, nor the writer for readers.
+
+use std::sync::Arc;
+
+use arc_swap::ArcSwap;
+
+use crate::prelude::*;
+
+/// The sole writer of a [`Tree`] shared with some [`Reader`]s.
+///
+/// Changes made to the tree are only visible to readers once they are
+/// [`publish`](Writer::publish)ed.
+#[derive(Debug)]
+pub struct Writer {
+    tree: Tree,
+    published: Arc<ArcSwap<Tree>>,
+}
+
+impl Default for Writer {
+    fn default() -> Self {
+        Self::new(Tree::new())
+    }
+}
+
+impl From<Tree> for Writer {
+    fn from(tree: Tree) -> Self {
+        Self::new(tree)
+    }
+}
+
+impl Writer {
+    /// Share a [`Tree`], publishing its current state.
+    pub fn new(tree: Tree) -> Self {
+        let published = Arc::new(ArcSwap::from_pointee(tree.clone()));
+        Self { tree, published }
+    }
+
+    /// Get a new [`Reader`] of the snapshots published by this writer.
+    pub fn reader(&self) -> Reader {
+        Reader {
+            published: self.published.clone(),
+        }
+    }
+
+    /// Make the current state of the tree visible to all readers.
+    pub fn publish(&self) {
+        self.published.store(Arc::new(self.tree.clone()));
+    }
+
+    /// Get the current state of the tree, including any unpublished changes.
+    pub fn tree(&self) -> &Tree {
+        &self.tree
+    }
+
+    /// Get mutable access to the tree.
+    ///
+    /// Changes made through this reference are not visible to readers until they are
+    /// [`publish`](Writer::publish)ed.
+    pub fn tree_mut(&mut self) -> &mut Tree {
+        &mut self.tree
+    }
+
+    /// Stop writing to the shared tree, returning its current state.
+    ///
+    /// Existing readers continue to see the last published snapshot.
+    pub fn into_inner(self) -> Tree {
+        self.tree
+    }
+}
+
+/// A cheaply cloneable, read-only handle to the snapshots of a [`Tree`] published by a [`Writer`].
+///
+/// Each method of a [`Reader`] queries the most recently published snapshot, so consecutive calls
+/// may observe different snapshots. To make several queries of the same snapshot, use
+/// [`snapshot`](Reader::snapshot).
+#[derive(Debug, Clone)]
+pub struct Reader {
+    published: Arc<ArcSwap<Tree>>,
+}
+
+impl Reader {
+    /// Get the most recently published snapshot of the tree.
+    ///
+    /// The snapshot is unaffected by any subsequent changes to the shared tree.
+    pub fn snapshot(&self) -> Arc<Tree> {
+        self.published.load_full()
+    }
+
+    /// Get the root hash of the most recently published snapshot of the tree.
+    pub fn root(&self) -> Root {
+        self.published.load().root()
+    }
+
+    /// Get a [`Proof`] of inclusion for the commitment in the most recently published snapshot of
+    /// the tree, if it is witnessed there.
+    ///
+    /// The proof is for the root of that snapshot, which may differ from the [`root`](Reader::root)
+    /// returned by a later call, so use [`snapshot`](Reader::snapshot) when both are needed.
+    pub fn witness(&self, commitment: StateCommitment) -> Option<Proof> {
+        self.published.load().witness(commitment)
+    }
+
+    /// Get a [`Proof`] of inclusion for each of the given commitments in the most recently published
+    /// snapshot of the tree, in the same order.
+    ///
+    /// All the proofs are for the root of the same snapshot. See [`Tree::witness_batch`].
+    pub fn witness_batch(&self, commitments: &[StateCommitment]) -> Vec<Option<Proof>> {
+        self.published.load().witness_batch(commitments)
+    }
+
+    /// The number of commitments witnessed in the most recently published snapshot of the tree.
+    pub fn len(&self) -> usize {
+        self.published.load().witnessed_count()
+    }
+
+    /// Check whether the most recently published snapshot of the tree witnesses no commitments.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::Witness;
+
+    #[test]
+    fn readers_see_published_snapshots() {
+        let mut writer = Writer::default();
+        let reader = writer.reader();
+
+        let commitment = StateCommitment(1u64.into());
+        writer.tree_mut().insert(Witness::Keep, commitment).unwrap();
+
+        // Nothing has been published since the insertion
+        assert!(reader.is_empty());
+        assert!(reader.witness(commitment).is_none());
+        assert_eq!(reader.root(), Tree::new().root());
+
+        writer.publish();
+
+        assert_eq!(reader.len(), 1);
+        assert_eq!(reader.root(), writer.tree().root());
+        let proof = reader.witness(commitment).unwrap();
+        assert!(proof.verify(reader.root()).is_ok());
+    }
+
+    #[test]
+    fn snapshots_are_unaffected_by_later_changes() {
+        let mut writer = Writer::default();
+        let reader = writer.reader();
+        let snapshot = reader.snapshot();
+
+        for i in 0..4u64 {
+            writer
+                .tree_mut()
+                .insert(Witness::Keep, StateCommitment(i.into()))
+                .unwrap();
+        }
+        writer.publish();
+
+        assert!(snapshot.is_empty());
+        assert_eq!(reader.len(), 4);
+    }
+
+    #[test]
+    fn readers_are_usable_across_threads() {
+        let mut writer = Writer::default();
+        let commitments: Vec<_> = (0..16u64).map(|i| StateCommitment(i.into())).collect();
+        for &commitment in &commitments {
+            writer.tree_mut().insert(Witness::Keep, commitment).unwrap();
+        }
+        writer.publish();
+
+        let handles: Vec<_> = commitments
+            .into_iter()
+            .map(|commitment| {
+                let reader = writer.reader();
+                std::thread::spawn(move || {
+                    let snapshot = reader.snapshot();
+                    let proof = snapshot.witness(commitment).unwrap();
+                    proof.verify(snapshot.root()).is_ok()
+                })
+            })
+            .collect();
+
+        // The writer can keep inserting while the readers generate proofs
+        writer
+            .tree_mut()
+            .insert(Witness::Keep, StateCommitment(16u64.into()))
+            .unwrap();
+        writer.publish();
+
+        for handle in handles {
+            assert!(handle.join().unwrap());
+        }
+    }
+}
@@ -8,14 +8,22 @@ use std::{
 
 use futures::Stream;
 
-use crate::prelude::*;
+use crate::{prelude::*, Tag};
 
 pub(crate) mod deserialize;
 pub(crate) mod serialize;
 
+pub mod format;
 pub mod in_memory;
+#[cfg(feature = "mmap")]
+pub mod memory_mapped;
+pub mod snapshot;
 pub use deserialize::{LoadCommitments, LoadHashes};
+pub use format::Versioned;
 pub use in_memory::InMemory;
+#[cfg(feature = "mmap")]
+pub use memory_mapped::MemoryMapped;
+pub use snapshot::{Delta, Snapshot};
 
 /// A stored position for the tree: either the position of the tree, or a marker indicating that it
 /// is full, and therefore does not have a position.
@@ -90,6 +98,9 @@ pub trait AsyncRead {
 
     /// Get the full list of all commitments stored, indexed by position.
     fn commitments(&mut self) -> Self::CommitmentsStream<'_>;
+
+    /// Get the full list of all [`Tag`]s stored, indexed by the position of their commitment.
+    async fn tags(&mut self) -> Result<Vec<(Position, Tag)>, Self::Error>;
 }
 
 /// An `async` storage backend capable of writing [`struct@Hash`]es and [`Commitment`]s, and
@@ -120,9 +131,12 @@ pub trait AsyncWrite: AsyncRead {
         commitment: StateCommitment,
     ) -> Result<(), Self::Error>;
 
+    /// Write the [`Tag`] of the commitment at the given position into storage.
+    async fn add_tag(&mut self, position: Position, tag: Tag) -> Result<(), Self::Error>;
+
     /// Delete every stored [`struct@Hash`] whose height is less than `below_height` and whose
     /// position is within the half-open [`Range`] of `positions`, as well as every [`Commitment`]
-    /// whose position is within the range.
+    /// (and its [`Tag`]) whose position is within the range.
     async fn delete_range(
         &mut self,
         below_height: u8,
@@ -175,6 +189,13 @@ pub trait Read {
     /// Get the full list of all commitments stored, indexed by position.
     #[allow(clippy::type_complexity)]
     fn commitments(&mut self) -> Self::CommitmentsIter<'_>;
+
+    /// Get the full list of all [`Tag`]s stored, indexed by the position of their commitment.
+    ///
+    /// Backends which never store tagged trees may rely on the default, which returns no tags.
+    fn tags(&mut self) -> Result<Vec<(Position, Tag)>, Self::Error> {
+        Ok(Vec::new())
+    }
 }
 
 /// A synchronous storage backend capable of writing [`struct@Hash`]es and [`Commitment`]s, and
@@ -204,9 +225,16 @@ pub trait Write: Read {
         commitment: StateCommitment,
     ) -> Result<(), Self::Error>;
 
+    /// Write the [`Tag`] of the commitment at the given position into storage.
+    ///
+    /// Backends which never store tagged trees may rely on the default, which discards the tag.
+    fn add_tag(&mut self, _position: Position, _tag: Tag) -> Result<(), Self::Error> {
+        Ok(())
+    }
+
     /// Delete every stored [`struct@Hash`] whose height is less than `below_height` and whose
     /// position is within the half-open [`Range`] of `positions`, as well as every [`Commitment`]
-    /// whose position is within the range.
+    /// (and its [`Tag`]) whose position is within the range.
     fn delete_range(
         &mut self,
         below_height: u8,
@@ -259,6 +287,9 @@ pub struct StoreCommitment {
     pub position: Position,
     /// The commitment itself.
     pub commitment: StateCommitment,
+    /// The tag attached to the commitment, if any.
+    #[serde(default)]
+    pub tag: Option<Tag>,
 }
 
 /// An update to the underlying storage that constitutes deleting a range of hashes and commitments.
@@ -1,17 +1,23 @@
 //! Non-incremental deserialization for the [`Tree`](crate::Tree).
 
+use std::collections::BTreeMap;
+
 use futures::StreamExt;
 
-use crate::prelude::*;
+use crate::{prelude::*, Tag};
 
 /// Deserialize a [`Tree`] from an asynchronous storage backend.
 pub async fn from_async_reader<R: AsyncRead>(reader: &mut R) -> Result<Tree, R::Error> {
     let position = reader.position().await?;
     let forgotten = reader.forgotten().await?;
+    let mut tags: BTreeMap<Position, Tag> = reader.tags().await?.into_iter().collect();
     let mut load_commitments = LoadCommitments::new(position, forgotten);
     let mut commitments = reader.commitments();
     while let Some((position, commitment)) = commitments.next().await.transpose()? {
-        load_commitments.insert(position, commitment);
+        match tags.remove(&position) {
+            Some(tag) => load_commitments.insert_tagged(position, commitment, tag),
+            None => load_commitments.insert(position, commitment),
+        }
     }
     drop(commitments);
     let mut hashes = reader.hashes();
@@ -26,10 +32,14 @@ pub async fn from_async_reader<R: AsyncRead>(reader: &mut R) -> Result<Tree, R::
 pub fn from_reader<R: Read>(reader: &mut R) -> Result<Tree, R::Error> {
     let position = reader.position()?;
     let forgotten = reader.forgotten()?;
+    let mut tags: BTreeMap<Position, Tag> = reader.tags()?.into_iter().collect();
     let mut load_commitments = LoadCommitments::new(position, forgotten);
     let mut commitments = reader.commitments();
     while let Some((position, commitment)) = commitments.next().transpose()? {
-        load_commitments.insert(position, commitment);
+        match tags.remove(&position) {
+            Some(tag) => load_commitments.insert_tagged(position, commitment, tag),
+            None => load_commitments.insert(position, commitment),
+        }
     }
     drop(commitments);
     let mut load_hashes = load_commitments.load_hashes();
@@ -47,6 +57,7 @@ pub fn from_reader<R: Read>(reader: &mut R) -> Result<Tree, R::Error> {
 pub struct LoadCommitments {
     inner: frontier::Top<frontier::Tier<frontier::Tier<frontier::Item>>>,
     index: HashedMap<StateCommitment, index::within::Tree>,
+    tags: Vec<(StateCommitment, Tag)>,
 }
 
 impl LoadCommitments {
@@ -59,6 +70,7 @@ impl LoadCommitments {
         Self {
             inner: OutOfOrder::uninitialized(position, forgotten),
             index: HashedMap::default(),
+            tags: Vec::new(),
         }
     }
 
@@ -69,11 +81,18 @@ impl LoadCommitments {
         self.index.insert(commitment, u64::from(position).into());
     }
 
+    /// Insert a commitment at a given position, along with the [`Tag`] attached to it.
+    pub fn insert_tagged(&mut self, position: Position, commitment: StateCommitment, tag: Tag) {
+        self.insert(position, commitment);
+        self.tags.push((commitment, tag));
+    }
+
     /// Start loading the hashes for the inside of the tree.
     pub fn load_hashes(self) -> LoadHashes {
         LoadHashes {
             inner: self.inner,
             index: self.index,
+            tags: self.tags,
         }
     }
 }
@@ -90,6 +109,7 @@ impl Extend<(Position, StateCommitment)> for LoadCommitments {
 pub struct LoadHashes {
     inner: frontier::Top<frontier::Tier<frontier::Tier<frontier::Item>>>,
     index: HashedMap<StateCommitment, index::within::Tree>,
+    tags: Vec<(StateCommitment, Tag)>,
 }
 
 impl LoadHashes {
@@ -101,7 +121,11 @@ impl LoadHashes {
     /// Finish loading the tree.
     pub fn finish(mut self) -> Tree {
         self.inner.finish_initialize();
-        Tree::unchecked_from_parts(self.index, self.inner)
+        let mut tree = Tree::unchecked_from_parts(self.index, self.inner);
+        for (commitment, tag) in self.tags {
+            tree.restore_tag(commitment, tag);
+        }
+        tree
     }
 }
 
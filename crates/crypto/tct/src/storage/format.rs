@@ -0,0 +1,257 @@
+//! A versioned container format for persisted trees, such as [`Snapshot`](super::Snapshot)s and
+//! [`Delta`](super::Delta)s.
+//!
+//! The internal representation of the tree may change between releases. A [`Versioned`] container
+//! records which version of the format its payload was written with, so that a reader never
+//! silently misinterprets data written by a different version of this crate: it either reads the
+//! payload correctly, or reports an [`UnsupportedVersion`] error.
+//!
+//! # Compatibility rules
+//!
+//! Each container records two numbers: the [`version`](Header::version) of the format it was
+//! written with, and the [`min_reader_version`](Header::min_reader_version), which is the oldest
+//! version of the format whose readers can correctly read it. A reader accepts a container if and
+//! only if:
+//!
+//! - its `min_reader_version` is at most [`CURRENT_VERSION`], so the reader understands enough of
+//!   the payload to read it correctly, and
+//! - its `version` is at least [`MIN_SUPPORTED_VERSION`], so the reader still knows how to read the
+//!   payload.
+//!
+//! When changing the serialized representation of a persisted type:
+//!
+//! - If the change only adds data that older readers can safely ignore (such as a new field marked
+//!   `#[serde(default)]`), increment [`CURRENT_VERSION`] and leave [`MIN_READER_VERSION`] alone.
+//!   Older readers will continue to read newer containers, ignoring the new data.
+//! - Otherwise, increment [`CURRENT_VERSION`] and set [`MIN_READER_VERSION`] to match it, so that
+//!   older readers reject newer containers rather than misinterpreting them.
+//! - To stop reading containers written by old versions, increase [`MIN_SUPPORTED_VERSION`].
+//!
+//! Ignoring unknown data relies on the `serde` format being self-describing (as JSON is), so
+//! [`Versioned`] containers should only be persisted using self-describing formats.
+
+use std::{fmt, marker::PhantomData};
+
+use serde::{
+    de::{self, IgnoredAny, SeqAccess, Visitor},
+    ser::{self, SerializeTuple},
+    Deserialize, Deserializer, Serialize, Serializer,
+};
+
+/// The version of the format written by this version of the crate.
+pub const CURRENT_VERSION: u32 = 1;
+
+/// The oldest version of the format whose readers can correctly read what this version writes.
+pub const MIN_READER_VERSION: u32 = 1;
+
+/// The oldest version of the format which this version of the crate can read.
+pub const MIN_SUPPORTED_VERSION: u32 = 1;
+
+/// The version information recorded in a [`Versioned`] container.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Header {
+    /// The version of the format the payload was written with.
+    pub version: u32,
+    /// The oldest version of the format whose readers can correctly read the payload.
+    pub min_reader_version: u32,
+}
+
+impl Header {
+    /// The header written by this version of the crate.
+    pub fn current() -> Self {
+        Header {
+            version: CURRENT_VERSION,
+            min_reader_version: MIN_READER_VERSION,
+        }
+    }
+
+    /// Check whether a payload with this header can be read by this version of the crate.
+    pub fn check(&self) -> Result<(), UnsupportedVersion> {
+        if self.min_reader_version > CURRENT_VERSION || self.version < MIN_SUPPORTED_VERSION {
+            Err(UnsupportedVersion {
+                version: self.version,
+                min_reader_version: self.min_reader_version,
+            })
+        } else {
+            Ok(())
+        }
+    }
+}
+
+/// The payload of a [`Versioned`] container was written with a version of the format which this
+/// version of the crate can't read.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Error)]
+#[error(
+    "unsupported format version {version} (readable since version {min_reader_version}): \
+     this version reads versions {MIN_SUPPORTED_VERSION} through {CURRENT_VERSION}"
+)]
+pub struct UnsupportedVersion {
+    /// The version of the format the payload was written with.
+    pub version: u32,
+    /// The oldest version of the format whose readers can correctly read the payload.
+    pub min_reader_version: u32,
+}
+
+/// A payload, tagged with the version of the format it was written with.
+///
+/// Deserializing a container written with an unsupported version of the format succeeds, but skips
+/// the payload entirely; [`Versioned::into_inner`] then returns an [`UnsupportedVersion`] error.
+/// This means the payload is never interpreted unless it is known to be readable.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Versioned<T> {
+    header: Header,
+    payload: Result<T, UnsupportedVersion>,
+}
+
+impl<T> Versioned<T> {
+    /// Wrap a payload in a container for the current version of the format.
+    pub fn new(payload: T) -> Self {
+        Versioned {
+            header: Header::current(),
+            payload: Ok(payload),
+        }
+    }
+
+    /// The version information of this container.
+    pub fn header(&self) -> Header {
+        self.header
+    }
+
+    /// Get the payload of this container.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the container was written with a version of the format which this
+    /// version of the crate can't read.
+    pub fn into_inner(self) -> Result<T, UnsupportedVersion> {
+        self.payload
+    }
+}
+
+impl<T> From<T> for Versioned<T> {
+    fn from(payload: T) -> Self {
+        Versioned::new(payload)
+    }
+}
+
+impl<T: Serialize> Serialize for Versioned<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        // An unreadable payload was never deserialized, so there is nothing to write back
+        let payload = self.payload.as_ref().map_err(ser::Error::custom)?;
+
+        // The header is always written before the payload, so that it can be checked before the
+        // payload is read
+        let mut tuple = serializer.serialize_tuple(3)?;
+        tuple.serialize_element(&self.header.version)?;
+        tuple.serialize_element(&self.header.min_reader_version)?;
+        tuple.serialize_element(payload)?;
+        tuple.end()
+    }
+}
+
+impl<'de, T: Deserialize<'de>> Deserialize<'de> for Versioned<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct VersionedVisitor<T>(PhantomData<T>);
+
+        impl<'de, T: Deserialize<'de>> Visitor<'de> for VersionedVisitor<T> {
+            type Value = Versioned<T>;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("a versioned container")
+            }
+
+            fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+                let version = seq
+                    .next_element()?
+                    .ok_or_else(|| de::Error::invalid_length(0, &self))?;
+                let min_reader_version = seq
+                    .next_element()?
+                    .ok_or_else(|| de::Error::invalid_length(1, &self))?;
+                let header = Header {
+                    version,
+                    min_reader_version,
+                };
+
+                let payload = match header.check() {
+                    Ok(()) => Ok(seq
+                        .next_element()?
+                        .ok_or_else(|| de::Error::invalid_length(2, &self))?),
+                    Err(error) => {
+                        // Skip the payload without interpreting it
+                        seq.next_element::<IgnoredAny>()?
+                            .ok_or_else(|| de::Error::invalid_length(2, &self))?;
+                        Err(error)
+                    }
+                };
+
+                Ok(Versioned { header, payload })
+            }
+        }
+
+        deserializer.deserialize_tuple(3, VersionedVisitor(PhantomData))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{storage::Snapshot, Tree, Witness};
+
+    fn snapshot() -> Snapshot {
+        let mut tree = Tree::new();
+        for i in 0..4u64 {
+            tree.insert(Witness::Keep, crate::StateCommitment(i.into()))
+                .unwrap();
+        }
+        Snapshot::new(&tree)
+    }
+
+    /// Write a container by hand, with an arbitrary header.
+    fn container(version: u32, min_reader_version: u32, payload: serde_json::Value) -> String {
+        serde_json::json!([version, min_reader_version, payload]).to_string()
+    }
+
+    #[test]
+    fn versioned_round_trip() {
+        let snapshot = snapshot();
+        let json = serde_json::to_string(&Versioned::new(snapshot.clone())).unwrap();
+        let versioned: Versioned<Snapshot> = serde_json::from_str(&json).unwrap();
+        assert_eq!(versioned.header(), Header::current());
+        assert_eq!(versioned.into_inner().unwrap(), snapshot);
+    }
+
+    #[test]
+    fn newer_compatible_version_is_read() {
+        let snapshot = snapshot();
+        let mut payload = serde_json::to_value(&snapshot).unwrap();
+        payload
+            .as_object_mut()
+            .unwrap()
+            .insert("added_in_a_later_version".to_string(), 42.into());
+
+        let json = container(CURRENT_VERSION + 1, CURRENT_VERSION, payload);
+        let versioned: Versioned<Snapshot> = serde_json::from_str(&json).unwrap();
+        assert_eq!(versioned.into_inner().unwrap(), snapshot);
+    }
+
+    #[test]
+    fn newer_incompatible_version_is_rejected() {
+        // The payload is garbage, but it should never be interpreted
+        let json = container(CURRENT_VERSION + 1, CURRENT_VERSION + 1, "garbage".into());
+        let versioned: Versioned<Snapshot> = serde_json::from_str(&json).unwrap();
+        assert_eq!(
+            versioned.into_inner(),
+            Err(UnsupportedVersion {
+                version: CURRENT_VERSION + 1,
+                min_reader_version: CURRENT_VERSION + 1,
+            })
+        );
+    }
+
+    #[test]
+    fn older_unsupported_version_is_rejected() {
+        let json = container(MIN_SUPPORTED_VERSION - 1, 0, "garbage".into());
+        let versioned: Versioned<Snapshot> = serde_json::from_str(&json).unwrap();
+        assert!(versioned.into_inner().is_err());
+    }
+}
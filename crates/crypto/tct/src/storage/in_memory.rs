@@ -10,6 +10,8 @@ pub struct InMemory {
     forgotten: Forgotten,
     hashes: BTreeMap<Position, BTreeMap<u8, Hash>>,
     commitments: BTreeMap<Position, StateCommitment>,
+    #[serde(default)]
+    tags: BTreeMap<Position, Tag>,
 }
 
 impl InMemory {
@@ -138,6 +140,14 @@ impl Read for InMemory {
     fn commitments(&mut self) -> Self::CommitmentsIter<'_> {
         Box::new(InMemory::commitments(self).map(Ok))
     }
+
+    fn tags(&mut self) -> Result<Vec<(Position, Tag)>, Self::Error> {
+        Ok(self
+            .tags
+            .iter()
+            .map(|(position, tag)| (*position, *tag))
+            .collect())
+    }
 }
 
 impl Write for InMemory {
@@ -184,6 +194,11 @@ impl Write for InMemory {
         Ok(())
     }
 
+    fn add_tag(&mut self, position: Position, tag: Tag) -> Result<(), Self::Error> {
+        self.tags.insert(position, tag);
+        Ok(())
+    }
+
     fn delete_range(
         &mut self,
         below_height: u8,
@@ -215,9 +230,10 @@ impl Write for InMemory {
             .map(|(&position, _)| position)
             .collect();
 
-        // Remove all the commitments within the range
+        // Remove all the commitments within the range, along with their tags
         for position in commitments_to_delete {
             self.commitments.remove(&position);
+            self.tags.remove(&position);
         }
 
         Ok(())
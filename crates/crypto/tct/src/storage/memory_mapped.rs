@@ -0,0 +1,408 @@
+//! A storage backend which persists the tree in memory-mapped files.
+
+use std::{fs, io, num::NonZeroUsize, ops::Range, path::Path};
+
+use lru::LruCache;
+
+use super::{Read, StoredPosition, Write};
+use crate::{
+    internal::hash::{Forgotten, Hash},
+    Position, StateCommitment, Tag,
+};
+
+mod table;
+use table::Table;
+
+/// The key in the metadata table of the stored position.
+const POSITION: [u8; 1] = [0];
+/// The key in the metadata table of the stored forgotten version.
+const FORGOTTEN: [u8; 1] = [1];
+/// The encoding of [`StoredPosition::Full`], which no position can collide with.
+const FULL: u64 = u64::MAX;
+
+/// A storage backend which keeps the hashes and commitments of a [`Tree`](crate::Tree) in
+/// memory-mapped files in a directory, caching the most recently read hashes in memory.
+///
+/// The tree is persisted with [`Tree::to_writer`](crate::Tree::to_writer) and restored with
+/// [`Tree::from_reader`](crate::Tree::from_reader) exactly as with any other backend, and
+/// individual hashes can be looked up through [`Read::hash`] without reading the whole tree back
+/// into memory.
+///
+/// This is only a persistence backend: it does not page the nodes of a [`Tree`](crate::Tree) in
+/// and out of memory. A tree restored from it is held entirely in memory, like any other, so the
+/// memory used by the tree is not bounded by the size of the cache.
+///
+/// The files persist: reopening the same directory restores what was stored in it. Writes go to
+/// the operating system as soon as they are made, so they survive the process exiting, but they
+/// are only guaranteed to survive the machine crashing once [`flush`](MemoryMapped::flush) has
+/// returned.
+pub struct MemoryMapped {
+    // The stored position and forgotten version.
+    meta: Table<1, 8>,
+    // Hashes, by height and position.
+    hashes: Table<8, 32>,
+    // Commitments, by position.
+    commitments: Table<8, 32>,
+    // Tags, by the position of their commitment.
+    tags: Table<8, 8>,
+    cache: LruCache<u64, Hash>,
+}
+
+impl MemoryMapped {
+    /// Open the storage kept in the given directory, caching up to `cache_size` hashes in memory.
+    ///
+    /// The directory is created, and the storage in it is empty, if it does not exist yet.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the directory can't be created, or if its files can't be opened or do
+    /// not hold storage written by this backend.
+    pub fn open(dir: impl AsRef<Path>, cache_size: NonZeroUsize) -> io::Result<Self> {
+        let dir = dir.as_ref();
+        fs::create_dir_all(dir)?;
+        Ok(Self {
+            meta: Table::open(dir.join("meta"))?,
+            hashes: Table::open(dir.join("hashes"))?,
+            commitments: Table::open(dir.join("commitments"))?,
+            tags: Table::open(dir.join("tags"))?,
+            cache: LruCache::new(cache_size),
+        })
+    }
+
+    /// Wait for every write made so far to reach the disk.
+    pub fn flush(&self) -> io::Result<()> {
+        self.hashes.flush()?;
+        self.commitments.flush()?;
+        self.tags.flush()?;
+        self.meta.flush()
+    }
+
+    /// The number of hashes stored.
+    pub fn hash_count(&self) -> usize {
+        self.hashes.len()
+    }
+
+    /// The number of commitments stored.
+    pub fn commitment_count(&self) -> usize {
+        self.commitments.len()
+    }
+}
+
+/// An error which can occur when using the memory-mapped storage backend.
+#[derive(Debug, Error)]
+pub enum Error {
+    /// The files could not be read or written.
+    #[error(transparent)]
+    Io(#[from] io::Error),
+    /// A write was attempted over an existing commitment.
+    #[error("refusing to overwrite existing commitment at position {position:?}")]
+    DuplicateWriteCommitment {
+        /// The position of the existing commitment.
+        position: Position,
+    },
+    /// A hash was overwritten with a different hash.
+    #[error("hash overwritten with different hash at position {position:?}, height {height}")]
+    OverwrittenHash {
+        /// The position of the hash.
+        position: Position,
+        /// The height of the hash.
+        height: u8,
+    },
+    /// The position was set, but it did not increase.
+    #[error("set position did not increase from {previous:?} to {new:?}")]
+    PositionDidNotIncrease {
+        /// The previous position.
+        previous: StoredPosition,
+        /// The new position.
+        new: StoredPosition,
+    },
+    /// The forgotten version was set, but it did not increase.
+    #[error("set forgotten version did not increase from {previous:?} to {new:?}")]
+    ForgottenDidNotIncrease {
+        /// The previous forgotten version.
+        previous: Forgotten,
+        /// The new forgotten version.
+        new: Forgotten,
+    },
+    /// A stored hash was not a valid hash, so the files have been corrupted.
+    #[error("invalid hash stored at position {position:?}, height {height}")]
+    InvalidHash {
+        /// The position of the hash.
+        position: Position,
+        /// The height of the hash.
+        height: u8,
+    },
+    /// A stored commitment was not a valid commitment, so the files have been corrupted.
+    #[error("invalid commitment stored at position {position:?}")]
+    InvalidCommitment {
+        /// The position of the commitment.
+        position: Position,
+    },
+}
+
+/// The key of the hash at the given position and height: positions are less than `4^24`, so the
+/// height always fits above them.
+fn hash_key(position: Position, height: u8) -> u64 {
+    (u64::from(height) << 56) | u64::from(position)
+}
+
+fn decode_hash(key: u64, bytes: [u8; 32]) -> Result<(Position, u8, Hash), Error> {
+    let position = Position::from(key & ((1 << 56) - 1));
+    let height = (key >> 56) as u8;
+    let hash = Hash::from_bytes(bytes).map_err(|_| Error::InvalidHash { position, height })?;
+    Ok((position, height, hash))
+}
+
+fn decode_commitment(position: Position, bytes: [u8; 32]) -> Result<StateCommitment, Error> {
+    StateCommitment::try_from(bytes).map_err(|_| Error::InvalidCommitment { position })
+}
+
+impl Read for MemoryMapped {
+    type Error = Error;
+
+    type HashesIter<'a> = Box<dyn Iterator<Item = Result<(Position, u8, Hash), Self::Error>> + 'a>;
+    type CommitmentsIter<'a> =
+        Box<dyn Iterator<Item = Result<(Position, StateCommitment), Self::Error>> + 'a>;
+
+    fn position(&mut self) -> Result<StoredPosition, Self::Error> {
+        Ok(match self.meta.get(&POSITION).map(u64::from_le_bytes) {
+            None => StoredPosition::default(),
+            Some(FULL) => StoredPosition::Full,
+            Some(position) => StoredPosition::Position(position.into()),
+        })
+    }
+
+    fn forgotten(&mut self) -> Result<Forgotten, Self::Error> {
+        Ok(self
+            .meta
+            .get(&FORGOTTEN)
+            .map(|forgotten| u64::from_le_bytes(forgotten).into())
+            .unwrap_or_default())
+    }
+
+    fn hash(&mut self, position: Position, height: u8) -> Result<Option<Hash>, Self::Error> {
+        let key = hash_key(position, height);
+        if let Some(hash) = self.cache.get(&key) {
+            return Ok(Some(*hash));
+        }
+        let Some(bytes) = self.hashes.get(&key.to_le_bytes()) else {
+            return Ok(None);
+        };
+        let (_, _, hash) = decode_hash(key, bytes)?;
+        self.cache.put(key, hash);
+        Ok(Some(hash))
+    }
+
+    fn hashes(&mut self) -> Self::HashesIter<'_> {
+        Box::new(
+            self.hashes
+                .iter()
+                .map(|(key, bytes)| decode_hash(u64::from_le_bytes(key), bytes)),
+        )
+    }
+
+    fn commitment(&mut self, position: Position) -> Result<Option<StateCommitment>, Self::Error> {
+        self.commitments
+            .get(&u64::from(position).to_le_bytes())
+            .map(|bytes| decode_commitment(position, bytes))
+            .transpose()
+    }
+
+    fn commitments(&mut self) -> Self::CommitmentsIter<'_> {
+        Box::new(self.commitments.iter().map(|(key, bytes)| {
+            let position = Position::from(u64::from_le_bytes(key));
+            Ok((position, decode_commitment(position, bytes)?))
+        }))
+    }
+
+    fn tags(&mut self) -> Result<Vec<(Position, Tag)>, Self::Error> {
+        Ok(self
+            .tags
+            .iter()
+            .map(|(key, tag)| (u64::from_le_bytes(key).into(), Tag(tag)))
+            .collect())
+    }
+}
+
+impl Write for MemoryMapped {
+    fn add_hash(
+        &mut self,
+        position: Position,
+        height: u8,
+        hash: Hash,
+        _essential: bool,
+    ) -> Result<(), Self::Error> {
+        let key = hash_key(position, height);
+        // Essential hashes may be rewritten when their children are forgotten, but never changed
+        if let Some(previous) = self.hashes.insert(key.to_le_bytes(), hash.to_bytes())? {
+            if previous != hash.to_bytes() {
+                self.hashes.insert(key.to_le_bytes(), previous)?;
+                return Err(Error::OverwrittenHash { position, height });
+            }
+        }
+        self.cache.put(key, hash);
+        Ok(())
+    }
+
+    fn add_commitment(
+        &mut self,
+        position: Position,
+        commitment: StateCommitment,
+    ) -> Result<(), Self::Error> {
+        let key = u64::from(position).to_le_bytes();
+        if self.commitments.get(&key).is_some() {
+            return Err(Error::DuplicateWriteCommitment { position });
+        }
+        self.commitments.insert(key, commitment.into())?;
+        Ok(())
+    }
+
+    fn add_tag(&mut self, position: Position, tag: Tag) -> Result<(), Self::Error> {
+        self.tags.insert(u64::from(position).to_le_bytes(), tag.0)?;
+        Ok(())
+    }
+
+    fn delete_range(
+        &mut self,
+        below_height: u8,
+        range: Range<Position>,
+    ) -> Result<(), Self::Error> {
+        let (start, end) = (u64::from(range.start), u64::from(range.end));
+
+        // Every hash is stored at the first position beneath it, a multiple of `4^height`, so the
+        // hashes in range can be looked up one by one, unless that is slower than a full scan
+        let probes: u64 = (0..below_height)
+            .map(|height| (end.saturating_sub(start) >> (2 * height)) + 1)
+            .sum();
+        if probes < self.hashes.capacity() as u64 {
+            for height in 0..below_height {
+                let stride = 4u64.pow(height.into());
+                let mut position = start.div_ceil(stride) * stride;
+                while position < end {
+                    let key = hash_key(position.into(), height);
+                    self.hashes.remove(&key.to_le_bytes());
+                    self.cache.pop(&key);
+                    position += stride;
+                }
+            }
+        } else {
+            self.hashes.retain(|key, _| {
+                let key = u64::from_le_bytes(*key);
+                let position = key & ((1 << 56) - 1);
+                ((key >> 56) as u8) >= below_height || !(start..end).contains(&position)
+            });
+            self.cache.clear();
+        }
+
+        if end.saturating_sub(start) < self.commitments.capacity() as u64 {
+            for position in start..end {
+                self.commitments.remove(&position.to_le_bytes());
+                self.tags.remove(&position.to_le_bytes());
+            }
+        } else {
+            self.commitments
+                .retain(|key, _| !(start..end).contains(&u64::from_le_bytes(*key)));
+            self.tags
+                .retain(|key, _| !(start..end).contains(&u64::from_le_bytes(*key)));
+        }
+
+        Ok(())
+    }
+
+    fn set_position(&mut self, position: StoredPosition) -> Result<(), Self::Error> {
+        let previous = Read::position(self)?;
+        if previous >= position {
+            return Err(Error::PositionDidNotIncrease {
+                previous,
+                new: position,
+            });
+        }
+        let encoded = match position {
+            StoredPosition::Position(position) => u64::from(position),
+            StoredPosition::Full => FULL,
+        };
+        self.meta.insert(POSITION, encoded.to_le_bytes())?;
+        Ok(())
+    }
+
+    fn set_forgotten(&mut self, forgotten: Forgotten) -> Result<(), Self::Error> {
+        let previous = Read::forgotten(self)?;
+        if previous >= forgotten {
+            return Err(Error::ForgottenDidNotIncrease {
+                previous,
+                new: forgotten,
+            });
+        }
+        self.meta
+            .insert(FORGOTTEN, u64::from(forgotten).to_le_bytes())?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{storage::InMemory, Tree, Witness};
+
+    #[test]
+    fn round_trips_through_to_writer_and_from_reader() {
+        let dir = std::env::temp_dir().join(format!("tct-mmap-test-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        let cache_size = NonZeroUsize::new(64).unwrap();
+
+        let mut tree = Tree::new();
+        let mut commitments = Vec::new();
+        for i in 0..300u64 {
+            let commitment = StateCommitment(i.into());
+            if i % 3 == 0 {
+                tree.insert(Witness::Forget, commitment).unwrap();
+            } else if i % 3 == 1 {
+                tree.insert(Witness::Keep, commitment).unwrap();
+            } else {
+                tree.insert_tagged(commitment, Tag::from(i % 4)).unwrap();
+            }
+            commitments.push(commitment);
+
+            if i % 7 == 6 {
+                tree.end_block().unwrap();
+            }
+            if i % 50 == 49 {
+                tree.end_epoch().unwrap();
+            }
+            if i % 20 == 19 {
+                tree.forget(commitments[i as usize - 10]);
+            }
+            // Store incrementally, reopening the storage each time to check that it persists
+            if i % 25 == 24 {
+                let mut storage = MemoryMapped::open(&dir, cache_size).unwrap();
+                tree.to_writer(&mut storage).unwrap();
+                storage.flush().unwrap();
+            }
+        }
+
+        let mut storage = MemoryMapped::open(&dir, cache_size).unwrap();
+        tree.to_writer(&mut storage).unwrap();
+        let restored = Tree::from_reader(&mut storage).unwrap();
+        assert_eq!(tree, restored);
+        for commitment in commitments {
+            assert_eq!(tree.witness(commitment), restored.witness(commitment));
+        }
+
+        // The backend holds exactly what the in-memory backend would
+        let mut in_memory = InMemory::new();
+        tree.to_writer(&mut in_memory).unwrap();
+        let mut hashes: Vec<_> = Read::hashes(&mut storage)
+            .collect::<Result<_, _>>()
+            .unwrap();
+        hashes.sort_by_key(|(position, height, _)| (*position, *height));
+        assert_eq!(hashes, in_memory.hashes().collect::<Vec<_>>());
+        let mut stored: Vec<_> = Read::commitments(&mut storage)
+            .collect::<Result<_, _>>()
+            .unwrap();
+        stored.sort_by_key(|(position, _)| *position);
+        assert_eq!(stored, in_memory.commitments().collect::<Vec<_>>());
+
+        drop(storage);
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
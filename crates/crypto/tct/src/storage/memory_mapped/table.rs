@@ -0,0 +1,306 @@
+//! A fixed-width hash table stored in a memory-mapped file.
+
+use std::{
+    fs::{self, File, OpenOptions},
+    io,
+    path::{Path, PathBuf},
+};
+
+use memmap2::MmapMut;
+
+/// The number of slots in a newly created table.
+const INITIAL_CAPACITY: usize = 1 << 10;
+
+/// The tag byte of a slot which has never been occupied.
+const EMPTY: u8 = 0;
+/// The tag byte of a slot which is occupied.
+const OCCUPIED: u8 = 1;
+/// The tag byte of a slot whose entry was removed: lookups must probe past it.
+const REMOVED: u8 = 2;
+
+/// An open-addressed hash table from `K`-byte keys to `V`-byte values, stored in a memory-mapped
+/// file, so that the operating system rather than the process decides how much of it is resident.
+///
+/// Each slot is a tag byte followed by the key and the value, and the file is nothing but the
+/// slots, so a table can be reopened from its file. Keys are expected to be distinct in their first
+/// eight bytes, which are mixed to choose the slot to probe from.
+pub(super) struct Table<const K: usize, const V: usize> {
+    path: PathBuf,
+    map: MmapMut,
+    // The file must outlive the map of it.
+    _file: File,
+    capacity: usize,
+    len: usize,
+    removed: usize,
+}
+
+impl<const K: usize, const V: usize> Table<K, V> {
+    const SLOT: usize = 1 + K + V;
+
+    /// Open the table at the given path, creating it empty if there is no file there.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error of kind [`io::ErrorKind::InvalidData`] if the file is not a table with
+    /// these widths of key and value.
+    pub fn open(path: impl AsRef<Path>) -> io::Result<Self> {
+        let path = path.as_ref().to_owned();
+        let file = OpenOptions::new()
+            .read(true)
+            .write(true)
+            .create(true)
+            .truncate(false)
+            .open(&path)?;
+
+        let size = file.metadata()?.len() as usize;
+        if size == 0 {
+            drop(file);
+            return Self::create(path, INITIAL_CAPACITY);
+        }
+        let capacity = size / Self::SLOT;
+        if size % Self::SLOT != 0 || !capacity.is_power_of_two() {
+            return Err(invalid(&path, "its size is not a whole number of slots"));
+        }
+
+        // SAFETY: the file belongs to this table, and is not resized or modified by anything else
+        // while it is mapped.
+        let map = unsafe { MmapMut::map_mut(&file)? };
+        let mut table = Self {
+            path,
+            map,
+            _file: file,
+            capacity,
+            len: 0,
+            removed: 0,
+        };
+        for slot in 0..capacity {
+            match table.slot(slot)[0] {
+                EMPTY => {}
+                OCCUPIED => table.len += 1,
+                REMOVED => table.removed += 1,
+                _ => return Err(invalid(&table.path, "it has a slot with an unknown tag")),
+            }
+        }
+        Ok(table)
+    }
+
+    /// Create a new empty table at the given path, overwriting any file already there.
+    fn create(path: PathBuf, capacity: usize) -> io::Result<Self> {
+        debug_assert!(capacity.is_power_of_two());
+        let file = OpenOptions::new()
+            .read(true)
+            .write(true)
+            .create(true)
+            .truncate(true)
+            .open(&path)?;
+        file.set_len((capacity * Self::SLOT) as u64)?;
+        // SAFETY: the file belongs to this table, created above, and is not resized or modified by
+        // anything else while it is mapped.
+        let map = unsafe { MmapMut::map_mut(&file)? };
+        Ok(Self {
+            path,
+            map,
+            _file: file,
+            capacity,
+            len: 0,
+            removed: 0,
+        })
+    }
+
+    /// The number of entries in the table.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// The number of slots in the table, which bounds the cost of a full scan of it.
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Get the value for the key, if there is one.
+    pub fn get(&self, key: &[u8; K]) -> Option<[u8; V]> {
+        let slot = self.find(key).ok()?;
+        Some(self.entry(slot).1)
+    }
+
+    /// Set the value for the key, returning the value it replaced, if there was one.
+    pub fn insert(&mut self, key: [u8; K], value: [u8; V]) -> io::Result<Option<[u8; V]>> {
+        if (self.len + self.removed + 1) * 4 > self.capacity * 3 {
+            self.grow()?;
+        }
+
+        let (slot, previous) = match self.find(&key) {
+            Ok(slot) => (slot, Some(self.entry(slot).1)),
+            Err(slot) => {
+                if self.slot(slot)[0] == REMOVED {
+                    self.removed -= 1;
+                }
+                self.len += 1;
+                (slot, None)
+            }
+        };
+
+        let slot = self.slot_mut(slot);
+        slot[0] = OCCUPIED;
+        slot[1..1 + K].copy_from_slice(&key);
+        slot[1 + K..].copy_from_slice(&value);
+        Ok(previous)
+    }
+
+    /// Remove the entry for the key, returning `true` if there was one.
+    pub fn remove(&mut self, key: &[u8; K]) -> bool {
+        match self.find(key) {
+            Ok(slot) => {
+                self.slot_mut(slot)[0] = REMOVED;
+                self.len -= 1;
+                self.removed += 1;
+                true
+            }
+            Err(_) => false,
+        }
+    }
+
+    /// Remove every entry for which the predicate returns `false`, by scanning the whole table.
+    pub fn retain(&mut self, mut keep: impl FnMut(&[u8; K], &[u8; V]) -> bool) {
+        for slot in 0..self.capacity {
+            if self.slot(slot)[0] == OCCUPIED {
+                let (key, value) = self.entry(slot);
+                if !keep(&key, &value) {
+                    self.slot_mut(slot)[0] = REMOVED;
+                    self.len -= 1;
+                    self.removed += 1;
+                }
+            }
+        }
+    }
+
+    /// Iterate over the entries in the table, in no particular order.
+    pub fn iter(&self) -> impl Iterator<Item = ([u8; K], [u8; V])> + '_ {
+        (0..self.capacity)
+            .filter(|&slot| self.slot(slot)[0] == OCCUPIED)
+            .map(|slot| self.entry(slot))
+    }
+
+    /// Write any changes to the table back to its file.
+    pub fn flush(&self) -> io::Result<()> {
+        self.map.flush()
+    }
+
+    /// Find the slot holding the key, or if it is absent, the slot where it should be inserted.
+    fn find(&self, key: &[u8; K]) -> Result<usize, usize> {
+        let mask = self.capacity - 1;
+        let mut slot = self.start(key);
+        let mut vacant = None;
+        loop {
+            let contents = self.slot(slot);
+            match contents[0] {
+                EMPTY => return Err(vacant.unwrap_or(slot)),
+                OCCUPIED if contents[1..1 + K] == key[..] => return Ok(slot),
+                REMOVED => {
+                    vacant.get_or_insert(slot);
+                }
+                _ => {}
+            }
+            slot = (slot + 1) & mask;
+        }
+    }
+
+    /// The slot from which to start probing for the key.
+    fn start(&self, key: &[u8; K]) -> usize {
+        let mut prefix = [0; 8];
+        let len = K.min(8);
+        prefix[..len].copy_from_slice(&key[..len]);
+        // Fibonacci hashing: the high bits of the product are well mixed even if the key isn't
+        let mixed = u64::from_le_bytes(prefix).wrapping_mul(0x9e37_79b9_7f4a_7c15);
+        (mixed >> (64 - self.capacity.trailing_zeros())) as usize
+    }
+
+    fn entry(&self, slot: usize) -> ([u8; K], [u8; V]) {
+        let contents = self.slot(slot);
+        let mut key = [0; K];
+        let mut value = [0; V];
+        key.copy_from_slice(&contents[1..1 + K]);
+        value.copy_from_slice(&contents[1 + K..]);
+        (key, value)
+    }
+
+    fn slot(&self, slot: usize) -> &[u8] {
+        &self.map[slot * Self::SLOT..(slot + 1) * Self::SLOT]
+    }
+
+    fn slot_mut(&mut self, slot: usize) -> &mut [u8] {
+        &mut self.map[slot * Self::SLOT..(slot + 1) * Self::SLOT]
+    }
+
+    /// Rehash every entry into a new file twice the size, which then replaces this one.
+    ///
+    /// The new file is only renamed over the old one once it is complete, so the table is intact
+    /// on disk whether or not growing it is interrupted.
+    fn grow(&mut self) -> io::Result<()> {
+        let path = self.path.clone();
+        let mut grown = Self::create(path.with_extension("grow"), self.capacity * 2)?;
+        for (key, value) in self.iter() {
+            grown.insert(key, value)?;
+        }
+        grown.flush()?;
+
+        fs::rename(&grown.path, &path)?;
+        grown.path = path;
+        *self = grown;
+        Ok(())
+    }
+}
+
+fn invalid(path: &Path, reason: &str) -> io::Error {
+    io::Error::new(
+        io::ErrorKind::InvalidData,
+        format!("{} is not a valid table: {reason}", path.display()),
+    )
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn insert_get_remove_across_growth_and_reopening() {
+        let path = std::env::temp_dir().join(format!("tct-table-test-{}", std::process::id()));
+        let _ = fs::remove_file(&path);
+        let mut table = Table::<8, 8>::open(&path).unwrap();
+
+        let count = 4 * INITIAL_CAPACITY as u64;
+        for i in 0..count {
+            assert_eq!(
+                table
+                    .insert(i.to_le_bytes(), (i * 2).to_le_bytes())
+                    .unwrap(),
+                None
+            );
+        }
+        assert_eq!(
+            table
+                .insert(0u64.to_le_bytes(), 1u64.to_le_bytes())
+                .unwrap(),
+            Some(0u64.to_le_bytes())
+        );
+        assert_eq!(table.len(), count as usize);
+
+        for i in (0..count).step_by(2) {
+            assert!(table.remove(&i.to_le_bytes()));
+        }
+        table.flush().unwrap();
+        drop(table);
+
+        let table = Table::<8, 8>::open(&path).unwrap();
+        assert_eq!(table.len(), count as usize / 2);
+        for i in 1..count {
+            let expected = (i % 2 == 1).then(|| (i * 2).to_le_bytes());
+            assert_eq!(table.get(&i.to_le_bytes()), expected);
+        }
+        assert_eq!(table.get(&0u64.to_le_bytes()), None);
+
+        drop(table);
+        assert!(Table::<8, 4>::open(&path).is_err());
+        fs::remove_file(path).unwrap();
+    }
+}
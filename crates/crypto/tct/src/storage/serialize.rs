@@ -291,8 +291,12 @@ pub async fn to_async_writer<W: AsyncWrite>(
             Update::StoreCommitment(StoreCommitment {
                 position,
                 commitment,
+                tag,
             }) => {
                 writer.add_commitment(position, commitment).await?;
+                if let Some(tag) = tag {
+                    writer.add_tag(position, tag).await?;
+                }
             }
             Update::DeleteRange(DeleteRange {
                 below_height,
@@ -330,8 +334,12 @@ pub fn to_writer<W: Write>(writer: &mut W, tree: &crate::Tree) -> Result<(), W::
             Update::StoreCommitment(StoreCommitment {
                 position,
                 commitment,
+                tag,
             }) => {
                 writer.add_commitment(position, commitment)?;
+                if let Some(tag) = tag {
+                    writer.add_tag(position, tag)?;
+                }
             }
             Update::DeleteRange(DeleteRange {
                 below_height,
@@ -376,12 +384,15 @@ pub fn updates(
             .filter(move |&forgotten| forgotten != last_forgotten)
             .map(storage::Update::SetForgotten);
 
-        let commitment_updates = serializer.commitments(tree).map(|(position, commitment)| {
-            storage::Update::StoreCommitment(storage::StoreCommitment {
-                position,
-                commitment,
-            })
-        });
+        let commitment_updates = serializer
+            .commitments(tree)
+            .map(move |(position, commitment)| {
+                storage::Update::StoreCommitment(storage::StoreCommitment {
+                    position,
+                    commitment,
+                    tag: tree.tag_of(commitment),
+                })
+            });
 
         let hash_and_deletion_updates = serializer
             .forgotten(tree)
@@ -398,8 +409,9 @@ pub fn updates(
                         // Calculate the range of positions to delete, based on the height
                         let position = u64::from(position);
                         let stride = 4u64.pow(height.into());
-                        let positions =
-                            position.into()..(position + stride).min(4u64.pow(24) - 1).into();
+                        // As for `structure::Node::range`, there is no position after the last
+                        let positions = position.into()
+                            ..(position + stride).min(u64::from(Position::MAX)).into();
 
                         // Delete the range of positions
                         Some(storage::Update::DeleteRange(storage::DeleteRange {
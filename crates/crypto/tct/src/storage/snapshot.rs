@@ -0,0 +1,408 @@
+//! Compact, self-contained snapshots of a [`Tree`](crate::Tree), and incremental deltas between
+//! them.
+//!
+//! A [`Snapshot`] records only what is necessary to reconstruct a tree exactly: its position and
+//! forgotten version, its witnessed commitments (along with their [`Tag`]s), and the essential
+//! hashes of its forgotten subtrees and frontier. Everything else is recomputed when the tree is
+//! [restored](Snapshot::to_tree).
+//!
+//! Once a snapshot has been taken, a [`Delta`] captures only the changes to the tree since that
+//! snapshot, and can be [applied](Snapshot::apply) to it to produce a snapshot of the newer tree.
+//! Together, these allow a client to checkpoint and resume witnessing without replaying every
+//! commitment, and without rewriting the whole snapshot each time it checkpoints.
+//!
+//! Both types implement [`Serialize`] and [`Deserialize`], so they can be persisted with any
+//! `serde` format. When persisting them across releases, wrap them in a
+//! [`Versioned`](super::Versioned) container, so that changes to their representation are detected
+//! rather than misinterpreted.
+
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    ops::Range,
+};
+
+use super::{in_memory, InMemory};
+use crate::{prelude::*, Tag};
+
+/// A compact snapshot of a [`Tree`], from which the tree can be exactly reconstructed.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(into = "SnapshotData", try_from = "SnapshotData")]
+pub struct Snapshot {
+    storage: InMemory,
+    tags: BTreeMap<StateCommitment, Tag>,
+}
+
+/// The serialized form of a [`Snapshot`].
+///
+/// Positions can't be used as keys in some `serde` formats (such as JSON), so everything is
+/// flattened into sequences.
+#[derive(Serialize, Deserialize)]
+struct SnapshotData {
+    position: StoredPosition,
+    forgotten: Forgotten,
+    commitments: Vec<(Position, StateCommitment)>,
+    hashes: Vec<(Position, u8, Hash)>,
+    #[serde(default)]
+    tags: Vec<(StateCommitment, Tag)>,
+}
+
+impl From<Snapshot> for SnapshotData {
+    fn from(snapshot: Snapshot) -> Self {
+        Self {
+            position: snapshot.storage.position(),
+            forgotten: snapshot.storage.forgotten(),
+            commitments: snapshot.storage.commitments().collect(),
+            hashes: snapshot.storage.hashes().collect(),
+            tags: snapshot.tags.into_iter().collect(),
+        }
+    }
+}
+
+impl TryFrom<SnapshotData> for Snapshot {
+    type Error = in_memory::Error;
+
+    fn try_from(data: SnapshotData) -> Result<Self, Self::Error> {
+        let mut storage = InMemory::new_sparse();
+        if data.position != storage.position() {
+            storage.set_position(data.position)?;
+        }
+        if data.forgotten != storage.forgotten() {
+            storage.set_forgotten(data.forgotten)?;
+        }
+        for (position, commitment) in data.commitments {
+            storage.add_commitment(position, commitment)?;
+        }
+        for (position, height, hash) in data.hashes {
+            // Only essential hashes are ever stored in a snapshot
+            storage.add_hash(position, height, hash, true)?;
+        }
+
+        Ok(Self {
+            storage,
+            tags: data.tags.into_iter().collect(),
+        })
+    }
+}
+
+impl Default for Snapshot {
+    fn default() -> Self {
+        Self {
+            storage: InMemory::new_sparse(),
+            tags: BTreeMap::new(),
+        }
+    }
+}
+
+/// An incremental change to a [`Tree`], relative to an earlier [`Snapshot`] of it.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Delta {
+    base_position: StoredPosition,
+    base_forgotten: Forgotten,
+    updates: Vec<Update>,
+    #[serde(default, with = "tags_as_seq")]
+    tags: BTreeMap<StateCommitment, Tag>,
+}
+
+/// An error which can occur when applying a [`Delta`] to a [`Snapshot`].
+#[derive(Clone, Debug, PartialEq, Eq, Error)]
+pub enum ApplyError {
+    /// The delta was not computed relative to the snapshot it was applied to.
+    #[error(
+        "delta is relative to position {delta_position:?} and forgotten version {delta_forgotten:?}, \
+         but snapshot is at position {snapshot_position:?} and forgotten version {snapshot_forgotten:?}"
+    )]
+    BaseMismatch {
+        /// The position of the tree the delta was computed relative to.
+        delta_position: StoredPosition,
+        /// The forgotten version of the tree the delta was computed relative to.
+        delta_forgotten: Forgotten,
+        /// The position of the snapshot.
+        snapshot_position: StoredPosition,
+        /// The forgotten version of the snapshot.
+        snapshot_forgotten: Forgotten,
+    },
+    /// The delta was inconsistent with the contents of the snapshot.
+    #[error("delta is inconsistent with snapshot: {0}")]
+    Storage(#[from] in_memory::Error),
+}
+
+impl Snapshot {
+    /// Take a snapshot of the given tree.
+    pub fn new(tree: &Tree) -> Self {
+        let mut snapshot = Snapshot::default();
+        let delta = snapshot.delta(tree);
+        snapshot
+            .apply(&delta)
+            .expect("a delta relative to an empty snapshot can always be applied to it");
+        snapshot
+    }
+
+    /// The position of the tree when this snapshot was taken.
+    pub fn position(&self) -> StoredPosition {
+        self.storage.position()
+    }
+
+    /// The forgotten version of the tree when this snapshot was taken.
+    pub fn forgotten(&self) -> Forgotten {
+        self.storage.forgotten()
+    }
+
+    /// Reconstruct the tree from this snapshot.
+    pub fn to_tree(&self) -> Tree {
+        let mut storage = self.storage.clone();
+        let mut tree =
+            Tree::from_reader(&mut storage).expect("reading from in-memory storage is infallible");
+
+        for (&commitment, &tag) in self.tags.iter() {
+            tree.restore_tag(commitment, tag);
+        }
+
+        tree
+    }
+
+    /// Compute the [`Delta`] between this snapshot and the given tree, which should be a later
+    /// version of the tree from which this snapshot was taken.
+    pub fn delta(&self, tree: &Tree) -> Delta {
+        Delta::between(self.position(), self.forgotten(), tree)
+    }
+
+    /// Apply a [`Delta`] to this snapshot, so that it becomes a snapshot of the newer tree.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error, leaving the snapshot unchanged, if the delta was not computed relative to
+    /// this snapshot.
+    ///
+    /// Returns an error if the delta is inconsistent with the contents of the snapshot. In this
+    /// case, the snapshot may have been partially modified, and should be discarded.
+    pub fn apply(&mut self, delta: &Delta) -> Result<(), ApplyError> {
+        if delta.base_position != self.position() || delta.base_forgotten != self.forgotten() {
+            return Err(ApplyError::BaseMismatch {
+                delta_position: delta.base_position,
+                delta_forgotten: delta.base_forgotten,
+                snapshot_position: self.position(),
+                snapshot_forgotten: self.forgotten(),
+            });
+        }
+
+        for update in delta.updates.iter().cloned() {
+            match update {
+                Update::SetPosition(position) => self.storage.set_position(position)?,
+                Update::SetForgotten(forgotten) => self.storage.set_forgotten(forgotten)?,
+                Update::StoreCommitment(StoreCommitment {
+                    position,
+                    commitment,
+                    ..
+                }) => {
+                    self.storage.add_commitment(position, commitment)?;
+                    // A re-inserted commitment only keeps the tag it was most recently given
+                    self.tags.remove(&commitment);
+                }
+                Update::StoreHash(StoreHash {
+                    position,
+                    height,
+                    hash,
+                    essential,
+                }) => self.storage.add_hash(position, height, hash, essential)?,
+                Update::DeleteRange(DeleteRange {
+                    below_height,
+                    positions,
+                }) => self.storage.delete_range(below_height, positions)?,
+            }
+        }
+
+        self.tags.extend(delta.tags.iter());
+
+        // Drop the tags of any commitments which were forgotten
+        let witnessed: BTreeSet<StateCommitment> = self
+            .storage
+            .commitments()
+            .map(|(_, commitment)| commitment)
+            .collect();
+        self.tags
+            .retain(|commitment, _| witnessed.contains(commitment));
+
+        Ok(())
+    }
+}
+
+impl Delta {
+    /// Compute the delta bringing a version of the tree at the given position and forgotten
+    /// version up to date with `tree`, a later version of it.
+    pub(crate) fn between(
+        base_position: StoredPosition,
+        base_forgotten: Forgotten,
+        tree: &Tree,
+    ) -> Self {
+        let mut tags = BTreeMap::new();
+        let updates = tree
+            .updates(base_position, base_forgotten)
+            // Snapshots only need essential hashes, so don't bother recording the others
+            .filter(|update| {
+                !matches!(
+                    update,
+                    Update::StoreHash(StoreHash {
+                        essential: false,
+                        ..
+                    })
+                )
+            })
+            .inspect(|update| {
+                if let Update::StoreCommitment(StoreCommitment {
+                    commitment,
+                    tag: Some(tag),
+                    ..
+                }) = update
+                {
+                    tags.insert(*commitment, *tag);
+                }
+            })
+            .collect();
+
+        Delta {
+            base_position,
+            base_forgotten,
+            updates,
+            tags,
+        }
+    }
+
+    /// The position of the tree this delta was computed relative to.
+    pub fn base_position(&self) -> StoredPosition {
+        self.base_position
+    }
+
+    /// The forgotten version of the tree this delta was computed relative to.
+    pub fn base_forgotten(&self) -> Forgotten {
+        self.base_forgotten
+    }
+
+    /// The updates to storage which make up this delta.
+    pub fn updates(&self) -> &[Update] {
+        &self.updates
+    }
+
+    /// The commitments witnessed by the tree since the version this delta is relative to, with
+    /// their positions.
+    pub fn appended_commitments(&self) -> impl Iterator<Item = (Position, StateCommitment)> + '_ {
+        self.updates.iter().filter_map(|update| match update {
+            Update::StoreCommitment(StoreCommitment {
+                position,
+                commitment,
+                ..
+            }) => Some((*position, *commitment)),
+            _ => None,
+        })
+    }
+
+    /// The ranges of positions in which commitments were forgotten since the version this delta
+    /// is relative to, and whose contents are replaced by the hashes of their subtrees.
+    pub fn forgotten_ranges(&self) -> impl Iterator<Item = Range<Position>> + '_ {
+        self.updates.iter().filter_map(|update| match update {
+            Update::DeleteRange(DeleteRange { positions, .. }) => Some(positions.clone()),
+            _ => None,
+        })
+    }
+
+    /// Returns `true` if the tree has not changed since the snapshot this delta is relative to.
+    pub fn is_empty(&self) -> bool {
+        self.updates.is_empty()
+    }
+}
+
+/// Like positions, commitments can't be used as keys in some `serde` formats, so serialize the tags
+/// of a [`Delta`] as a sequence of pairs instead.
+mod tags_as_seq {
+    use std::collections::BTreeMap;
+
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    use crate::{StateCommitment, Tag};
+
+    pub fn serialize<S: Serializer>(
+        tags: &BTreeMap<StateCommitment, Tag>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        tags.iter().collect::<Vec<_>>().serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<BTreeMap<StateCommitment, Tag>, D::Error> {
+        Ok(Vec::<(StateCommitment, Tag)>::deserialize(deserializer)?
+            .into_iter()
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::Witness;
+
+    fn commitment(i: u64) -> StateCommitment {
+        StateCommitment(i.into())
+    }
+
+    #[test]
+    fn snapshot_round_trip() {
+        let mut tree = Tree::new();
+        for i in 0..10 {
+            tree.insert(Witness::Keep, commitment(i)).unwrap();
+        }
+        tree.end_block().unwrap();
+        tree.insert_tagged(commitment(10), Tag::from(1)).unwrap();
+        tree.insert(Witness::Forget, commitment(11)).unwrap();
+        tree.end_epoch().unwrap();
+        tree.forget(commitment(3));
+
+        let snapshot = Snapshot::new(&tree);
+        assert_eq!(snapshot.to_tree(), tree);
+
+        let json = serde_json::to_string(&snapshot).unwrap();
+        let snapshot: Snapshot = serde_json::from_str(&json).unwrap();
+        assert_eq!(snapshot.to_tree(), tree);
+    }
+
+    #[test]
+    fn incremental_deltas() {
+        let mut tree = Tree::new();
+        let mut snapshot = Snapshot::new(&tree);
+
+        for round in 0..4u64 {
+            for i in 0..5 {
+                let c = commitment(round * 10 + i);
+                if i % 2 == 0 {
+                    tree.insert_tagged(c, Tag::from(round)).unwrap();
+                } else {
+                    tree.insert(Witness::Keep, c).unwrap();
+                }
+            }
+            tree.forget(commitment(round * 10 + 1));
+            tree.forget_by_tag(Tag::from(round.saturating_sub(1)));
+            tree.end_block().unwrap();
+
+            let delta = snapshot.delta(&tree);
+            let json = serde_json::to_string(&delta).unwrap();
+            let delta: Delta = serde_json::from_str(&json).unwrap();
+            snapshot.apply(&delta).unwrap();
+
+            assert_eq!(snapshot, Snapshot::new(&tree));
+            assert_eq!(snapshot.to_tree(), tree);
+        }
+    }
+
+    #[test]
+    fn delta_base_mismatch() {
+        let mut tree = Tree::new();
+        let snapshot = Snapshot::new(&tree);
+        tree.insert(Witness::Keep, commitment(0)).unwrap();
+        let delta = snapshot.delta(&tree);
+
+        let mut later = Snapshot::new(&tree);
+        assert!(matches!(
+            later.apply(&delta),
+            Err(ApplyError::BaseMismatch { .. })
+        ));
+    }
+}
@@ -0,0 +1,176 @@
+//! Building a [`Tree`] from a stream of compact blocks, as a wallet does when it syncs.
+
+use futures::{pin_mut, Stream, TryStreamExt};
+
+use crate::{builder::block, error::StreamError, StateCommitment, Tree, Witness};
+
+/// A block of the chain, as seen by a client building its [`Tree`] from a stream of compact
+/// blocks.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum StreamedBlock {
+    /// A block containing no commitments of interest to the client, represented by its root.
+    Root {
+        /// The root of the block.
+        root: block::Root,
+        /// Whether this is the last block of its epoch.
+        ends_epoch: bool,
+    },
+    /// A block which may contain commitments of interest to the client.
+    Commitments {
+        /// Every commitment in the block, in the order they were inserted.
+        commitments: Vec<StateCommitment>,
+        /// Whether this is the last block of its epoch.
+        ends_epoch: bool,
+    },
+}
+
+impl StreamedBlock {
+    /// Whether this is the last block of its epoch.
+    pub fn ends_epoch(&self) -> bool {
+        match self {
+            StreamedBlock::Root { ends_epoch, .. }
+            | StreamedBlock::Commitments { ends_epoch, .. } => *ends_epoch,
+        }
+    }
+}
+
+impl Tree {
+    /// Build a [`Tree`] from a stream of blocks, starting at the first block of the chain, and
+    /// witnessing only the commitments for which `witness` returns `true`.
+    ///
+    /// See [`extend_from_compact_block_stream`](Tree::extend_from_compact_block_stream).
+    pub async fn from_compact_block_stream<E>(
+        blocks: impl Stream<Item = Result<StreamedBlock, E>>,
+        witness: impl FnMut(&StateCommitment) -> bool,
+    ) -> Result<Tree, StreamError<E>> {
+        let mut tree = Tree::new();
+        tree.extend_from_compact_block_stream(blocks, witness)
+            .await?;
+        Ok(tree)
+    }
+
+    /// Add every block in a stream of blocks to this [`Tree`], witnessing only the commitments for
+    /// which `witness` returns `true`.
+    ///
+    /// Blocks given by their [`StreamedBlock::Root`] are inserted whole, so the only hashing
+    /// required for them is to slot their root into the epoch. Each block given by its
+    /// [`StreamedBlock::Commitments`] has its commitments inserted one at a time, and is then
+    /// ended, so that the next block starts afresh. Epochs are ended after their last block.
+    ///
+    /// The stream must start at the block following the last one in this tree.
+    ///
+    /// # Errors
+    ///
+    /// Stops at the first error reading from the stream, or at the first block which can't be
+    /// inserted because the tree, its current epoch, or the block is full. The blocks preceding
+    /// the error remain inserted.
+    #[instrument(level = "debug", skip(self, blocks, witness))]
+    pub async fn extend_from_compact_block_stream<E>(
+        &mut self,
+        blocks: impl Stream<Item = Result<StreamedBlock, E>>,
+        mut witness: impl FnMut(&StateCommitment) -> bool,
+    ) -> Result<(), StreamError<E>> {
+        pin_mut!(blocks);
+        let mut count = 0u64;
+        while let Some(block) = blocks.try_next().await.map_err(StreamError::Stream)? {
+            let ends_epoch = block.ends_epoch();
+            match block {
+                StreamedBlock::Root { root, .. } => {
+                    self.insert_block(root)?;
+                }
+                StreamedBlock::Commitments { commitments, .. } => {
+                    for commitment in commitments {
+                        let keep = if witness(&commitment) {
+                            Witness::Keep
+                        } else {
+                            Witness::Forget
+                        };
+                        self.insert(keep, commitment)?;
+                    }
+                    self.end_block()?;
+                }
+            }
+            if ends_epoch {
+                self.end_epoch()?;
+            }
+            count += 1;
+        }
+        debug!(blocks = count, "extended tree from block stream");
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use futures::executor::block_on;
+
+    use super::*;
+
+    fn commitment(i: u64) -> StateCommitment {
+        StateCommitment(i.into())
+    }
+
+    #[test]
+    fn stream_matches_explicit_construction() {
+        // Three blocks of four commitments each, the second ending the epoch, of which only one
+        // commitment in each of the first and last blocks is of interest
+        let blocks: Vec<Vec<StateCommitment>> = (0..3)
+            .map(|b| (b * 4..b * 4 + 4).map(commitment).collect())
+            .collect();
+        let relevant = |c: &StateCommitment| *c == commitment(0) || *c == commitment(10);
+
+        let mut expected = Tree::new();
+        for (i, block) in blocks.iter().enumerate() {
+            for &c in block {
+                let keep = if relevant(&c) {
+                    Witness::Keep
+                } else {
+                    Witness::Forget
+                };
+                expected.insert(keep, c).unwrap();
+            }
+            expected.end_block().unwrap();
+            if i == 1 {
+                expected.end_epoch().unwrap();
+            }
+        }
+
+        // The middle block has nothing of interest, so is given only by its root
+        let mut middle = block::Builder::default();
+        for &c in &blocks[1] {
+            middle.insert(Witness::Forget, c).unwrap();
+        }
+        let streamed = vec![
+            Ok::<_, std::convert::Infallible>(StreamedBlock::Commitments {
+                commitments: blocks[0].clone(),
+                ends_epoch: false,
+            }),
+            Ok(StreamedBlock::Root {
+                root: middle.root(),
+                ends_epoch: true,
+            }),
+            Ok(StreamedBlock::Commitments {
+                commitments: blocks[2].clone(),
+                ends_epoch: false,
+            }),
+        ];
+        let tree = block_on(Tree::from_compact_block_stream(
+            futures::stream::iter(streamed),
+            relevant,
+        ))
+        .unwrap();
+
+        assert_eq!(tree.root(), expected.root());
+        assert_eq!(tree.witnessed_count(), 2);
+        assert_eq!(
+            tree.position_of(commitment(0)),
+            expected.position_of(commitment(0))
+        );
+        assert_eq!(
+            tree.position_of(commitment(10)),
+            expected.position_of(commitment(10))
+        );
+        assert!(tree.witness(commitment(10)).is_some());
+        assert!(tree.position_of(commitment(1)).is_none());
+    }
+}
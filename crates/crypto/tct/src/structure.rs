@@ -286,7 +286,12 @@ impl<'tree> Node<'tree> {
     /// represented in the tree).
     pub fn range(&self) -> Range<Position> {
         let position: u64 = self.position().into();
-        position.into()..(position + self.stride()).min(4u64.pow(24) - 1).into()
+        // The end of a range is exclusive, and there is no position after the last, so the range
+        // beneath a node at the very end of the tree is cut short by one.
+        position.into()
+            ..(position + self.stride())
+                .min(u64::from(Position::MAX))
+                .into()
     }
 
     /// The global position of the tree inside of which this node exists.
@@ -0,0 +1,129 @@
+use std::fmt::{Debug, Display};
+
+/// An opaque 8-byte tag which can be attached to a witnessed [`StateCommitment`](crate::StateCommitment)
+/// when it is inserted into a [`Tree`](crate::Tree).
+///
+/// Tags are stored alongside the index of witnessed commitments, and are never hashed into the
+/// tree, so they have no effect on its root. They allow callers (such as wallets) to group
+/// witnessed commitments by account, purpose, or any other application-level criterion, and then
+/// [`iterate`](crate::Tree::iter_by_tag) over or [`forget`](crate::Tree::forget_by_tag) all the
+/// commitments in a group at once.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default, Serialize, Deserialize)]
+#[cfg_attr(any(test, feature = "arbitrary"), derive(proptest_derive::Arbitrary))]
+pub struct Tag(pub [u8; 8]);
+
+impl Debug for Tag {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Tag({})", hex::encode(self.0))
+    }
+}
+
+impl Display for Tag {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", hex::encode(self.0))
+    }
+}
+
+impl From<[u8; 8]> for Tag {
+    fn from(bytes: [u8; 8]) -> Self {
+        Tag(bytes)
+    }
+}
+
+impl From<Tag> for [u8; 8] {
+    fn from(tag: Tag) -> Self {
+        tag.0
+    }
+}
+
+impl From<u64> for Tag {
+    fn from(tag: u64) -> Self {
+        Tag(tag.to_be_bytes())
+    }
+}
+
+impl From<Tag> for u64 {
+    fn from(tag: Tag) -> Self {
+        u64::from_be_bytes(tag.0)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{storage::InMemory, StateCommitment, Tree, Witness};
+
+    fn commitment(i: u64) -> StateCommitment {
+        StateCommitment(i.into())
+    }
+
+    #[test]
+    fn tags_do_not_change_root() {
+        let mut tagged = Tree::new();
+        let mut untagged = Tree::new();
+
+        for i in 0..4 {
+            tagged
+                .insert_tagged(commitment(i), Tag::from(i % 2))
+                .unwrap();
+            untagged.insert(Witness::Keep, commitment(i)).unwrap();
+        }
+
+        assert_eq!(tagged.root(), untagged.root());
+    }
+
+    #[test]
+    fn iter_and_forget_by_tag() {
+        let mut tree = Tree::new();
+
+        for i in 0..6 {
+            tree.insert_tagged(commitment(i), Tag::from(i % 3)).unwrap();
+        }
+        tree.insert(Witness::Keep, commitment(6)).unwrap();
+
+        let mut tagged: Vec<_> = tree.iter_by_tag(Tag::from(1)).map(|(c, _)| c).collect();
+        tagged.sort_by_key(|c| tree.position_of(*c));
+        assert_eq!(tagged, vec![commitment(1), commitment(4)]);
+
+        assert_eq!(tree.forget_by_tag(Tag::from(1)), 2);
+        assert_eq!(tree.iter_by_tag(Tag::from(1)).count(), 0);
+        assert!(tree.witness(commitment(1)).is_none());
+        assert!(tree.witness(commitment(4)).is_none());
+        assert_eq!(tree.witnessed_count(), 5);
+
+        // Forgetting a commitment individually also forgets its tag
+        assert!(tree.forget(commitment(0)));
+        assert_eq!(tree.tag_of(commitment(0)), None);
+        assert_eq!(tree.iter_by_tag(Tag::from(0)).count(), 1);
+    }
+
+    #[test]
+    fn tags_survive_storage() {
+        let mut tree = Tree::new();
+        let mut storage = InMemory::new();
+
+        for i in 0..6 {
+            tree.insert_tagged(commitment(i), Tag::from(i % 3)).unwrap();
+            // Store incrementally, so that tags are written along with each batch of commitments
+            if i % 2 == 1 {
+                tree.end_block().unwrap();
+                tree.to_writer(&mut storage).unwrap();
+            }
+        }
+        tree.insert(Witness::Keep, commitment(6)).unwrap();
+        tree.to_writer(&mut storage).unwrap();
+
+        let restored = Tree::from_reader(&mut storage).unwrap();
+        assert_eq!(restored, tree);
+        assert_eq!(restored.tag_of(commitment(4)), Some(Tag::from(1)));
+        assert_eq!(restored.tag_of(commitment(6)), None);
+
+        // Forgetting commitments by tag removes their tags from storage too
+        assert_eq!(tree.forget_by_tag(Tag::from(1)), 2);
+        tree.to_writer(&mut storage).unwrap();
+        let restored = Tree::from_reader(&mut storage).unwrap();
+        assert_eq!(restored, tree);
+        assert_eq!(restored.iter_by_tag(Tag::from(1)).count(), 0);
+        assert_eq!(restored.iter_by_tag(Tag::from(2)).count(), 2);
+    }
+}
@@ -8,7 +8,7 @@ use penumbra_proto::{penumbra::crypto::tct::v1 as pb, DomainType};
 
 use crate::error::*;
 use crate::prelude::{Witness as _, *};
-use crate::Witness;
+use crate::{Tag, Witness};
 
 #[path = "epoch.rs"]
 pub(crate) mod epoch;
@@ -19,6 +19,9 @@ pub(crate) use epoch::block;
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Tree {
     index: HashedMap<StateCommitment, index::within::Tree>,
+    // Tags are kept separately from the index because they are optional and not hashed.
+    #[serde(default)]
+    tags: HashedMap<StateCommitment, Tag>,
     inner: Arc<frontier::Top<frontier::Tier<frontier::Tier<frontier::Item>>>>,
 }
 
@@ -26,6 +29,7 @@ impl Default for Tree {
     fn default() -> Self {
         Self {
             index: HashedMap::default(),
+            tags: HashedMap::default(),
             inner: Arc::new(frontier::Top::new(frontier::TrackForgotten::Yes)),
         }
     }
@@ -36,6 +40,7 @@ impl PartialEq for Tree {
         self.position() == other.position() // two trees could have identical contents but different positions
             && self.root() == other.root() // if the roots match, they represent the same commitments, but may witness different ones
             && self.index == other.index // we ensure they witness the same commitments by checking equality of indices
+            && self.tags == other.tags // and that those commitments are grouped under the same tags
     }
 }
 
@@ -101,6 +106,19 @@ impl Display for Root {
 pub struct Position(index::within::Tree);
 
 impl Position {
+    /// The last position in a [`Tree`]: the last commitment of the last block of the last epoch.
+    pub const MAX: Position = Position(index::within::Tree::MAX);
+
+    /// Convert a `u64` to a [`Position`], or return `None` if it is beyond the last position in a
+    /// [`Tree`].
+    ///
+    /// Unlike `From<u64>`, which ignores all but the lower 48 bits of the `u64`, this never
+    /// silently maps an out-of-range number to an unrelated position, so it should be used for any
+    /// number which is not already known to be a position.
+    pub fn checked_from(position: u64) -> Option<Position> {
+        index::within::Tree::checked_from(position).map(Position)
+    }
+
     /// The index of the [`Commitment`] to which this [`Position`] refers within its own block.
     pub fn commitment(&self) -> u16 {
         self.0.commitment.into()
@@ -158,6 +176,7 @@ impl Tree {
     ) -> Self {
         Self {
             index,
+            tags: HashedMap::default(),
             inner: Arc::new(inner),
         }
     }
@@ -170,8 +189,14 @@ impl Tree {
     ///
     /// Computed hashes are cached so that subsequent calls without further modification are very
     /// fast.
-    #[instrument(level = "trace", skip(self))]
+    #[instrument(
+        level = "trace",
+        skip(self),
+        fields(nodes_hashed = tracing::field::Empty, commitments_hashed = tracing::field::Empty)
+    )]
     pub fn root(&self) -> Root {
+        #[cfg(feature = "instrumentation")]
+        let _counts = crate::instrumentation::SpanCounts::start();
         let root = Root(self.inner.hash());
         trace!(?root);
         root
@@ -188,12 +213,112 @@ impl Tree {
     /// - the [`Tree`] is full,
     /// - the current epoch is full, or
     /// - the current block is full.
-    #[instrument(level = "trace", skip(self))]
+    #[instrument(
+        level = "trace",
+        skip(self),
+        fields(nodes_hashed = tracing::field::Empty, commitments_hashed = tracing::field::Empty)
+    )]
     pub fn insert(
         &mut self,
         witness: Witness,
         commitment: StateCommitment,
     ) -> Result<Position, InsertError> {
+        #[cfg(feature = "instrumentation")]
+        let _counts = crate::instrumentation::SpanCounts::start();
+        let position = self.insert_unindexed(witness, commitment)?;
+
+        // Keep track of the position of this just-inserted commitment in the index, if it was
+        // slated to be kept
+        if let Witness::Keep = witness {
+            self.index_commitment(commitment, position);
+        }
+
+        let position = Position(position);
+        trace!(?position);
+        Ok(position)
+    }
+
+    /// Add a batch of new [`Commitment`]s to the most recent block of the most recent epoch of this
+    /// [`Tree`].
+    ///
+    /// This is equivalent to [`insert`](Tree::insert)ing each commitment in turn, but is
+    /// considerably faster for large batches: after the first commitment, every subsequent one is
+    /// inserted directly into the latest block, and the index of witnessed commitments is only
+    /// updated once, at the end of the batch. As with individual insertions, no hashing occurs
+    /// until the [`root`](Tree::root) or a [`witness`](Tree::witness) is requested.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`InsertError`] under the same conditions as [`insert`](Tree::insert), in which case
+    /// all the commitments in the batch preceding the one which could not be inserted remain
+    /// inserted, and the rest of the batch is discarded.
+    #[instrument(
+        level = "trace",
+        skip(self, commitments),
+        fields(nodes_hashed = tracing::field::Empty, commitments_hashed = tracing::field::Empty)
+    )]
+    pub fn insert_batch(
+        &mut self,
+        commitments: impl IntoIterator<Item = (Witness, StateCommitment)>,
+    ) -> Result<(), InsertError> {
+        #[cfg(feature = "instrumentation")]
+        let _counts = crate::instrumentation::SpanCounts::start();
+        let mut commitments = commitments.into_iter();
+        let mut kept = Vec::with_capacity(commitments.size_hint().0);
+
+        // The first insertion may need to create a new block or epoch, so it goes through the
+        // usual path
+        let (witness, commitment) = if let Some(first) = commitments.next() {
+            first
+        } else {
+            return Ok(());
+        };
+        let first = self.insert_unindexed(witness, commitment)?;
+        if let Witness::Keep = witness {
+            kept.push((commitment, first));
+        }
+
+        // Every subsequent insertion goes directly into the block we just inserted into, and is
+        // positioned immediately after the previous one
+        let mut position = u64::from(first);
+        let result = Arc::make_mut(&mut self.inner)
+            .update(|epoch| {
+                epoch.update(|block| {
+                    for (witness, commitment) in commitments {
+                        let item = match witness {
+                            Witness::Keep => commitment.into(),
+                            Witness::Forget => Hash::of(commitment).into(),
+                        };
+                        block.insert(item).map_err(|_| InsertError::BlockFull)?;
+                        position += 1;
+                        if let Witness::Keep = witness {
+                            kept.push((commitment, position.into()));
+                        }
+                    }
+                    Ok::<_, InsertError>(())
+                })
+            })
+            .flatten()
+            .expect("latest block must exist because a commitment was just inserted into it");
+
+        // Index all the kept commitments at once, including those inserted before any error
+        self.index.reserve(kept.len());
+        for (commitment, position) in kept {
+            self.index_commitment(commitment, position);
+        }
+
+        result.map_err(|error| {
+            error!(%error);
+            error
+        })
+    }
+
+    /// Insert a commitment into the tree without updating the index, returning its position.
+    fn insert_unindexed(
+        &mut self,
+        witness: Witness,
+        commitment: StateCommitment,
+    ) -> Result<index::within::Tree, InsertError> {
         let item = match witness {
             Witness::Keep => commitment.into(),
             Witness::Forget => Hash::of(commitment).into(),
@@ -245,27 +370,56 @@ impl Tree {
                 error!(%error); error
             })?;
 
-        // Keep track of the position of this just-inserted commitment in the index, if it was
-        // slated to be kept
-        if let Witness::Keep = witness {
-            if let Some(replaced) = self.index.insert(commitment, position) {
-                // This case is handled for completeness, but should not happen in
-                // practice because commitments should be unique
-                let forgotten = Arc::make_mut(&mut self.inner).forget(replaced);
-                debug_assert!(forgotten);
-            }
+        Ok(position)
+    }
+
+    /// Record the position of a just-inserted, witnessed commitment in the index.
+    fn index_commitment(&mut self, commitment: StateCommitment, position: index::within::Tree) {
+        // Any tag previously attached to this commitment does not apply to the new insertion
+        self.tags.remove(&commitment);
+        if let Some(replaced) = self.index.insert(commitment, position) {
+            // This case is handled for completeness, but should not happen in
+            // practice because commitments should be unique
+            let forgotten = Arc::make_mut(&mut self.inner).forget(replaced);
+            debug_assert!(forgotten);
         }
+    }
 
-        let position = Position(position);
-        trace!(?position);
+    /// Add a new witnessed [`Commitment`] to the most recent block of the most recent epoch of this
+    /// [`Tree`], attaching the given [`Tag`] to it.
+    ///
+    /// This is equivalent to [`insert`](Tree::insert)ing the commitment with [`Witness::Keep`],
+    /// except that the commitment can later be found using [`iter_by_tag`](Tree::iter_by_tag) and
+    /// forgotten using [`forget_by_tag`](Tree::forget_by_tag). The tag is not hashed, so it does
+    /// not affect the root of the tree.
+    ///
+    /// If successful, returns the [`Position`] at which the commitment was inserted.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`InsertError`] under the same conditions as [`insert`](Tree::insert).
+    #[instrument(level = "trace", skip(self))]
+    pub fn insert_tagged(
+        &mut self,
+        commitment: StateCommitment,
+        tag: Tag,
+    ) -> Result<Position, InsertError> {
+        let position = self.insert(Witness::Keep, commitment)?;
+        self.tags.insert(commitment, tag);
         Ok(position)
     }
 
     /// Get a [`Proof`] of inclusion for the commitment at this index in the tree.
     ///
     /// If the index is not witnessed in this tree, return `None`.
-    #[instrument(level = "trace", skip(self))]
+    #[instrument(
+        level = "trace",
+        skip(self),
+        fields(nodes_hashed = tracing::field::Empty, commitments_hashed = tracing::field::Empty)
+    )]
     pub fn witness(&self, commitment: StateCommitment) -> Option<Proof> {
+        #[cfg(feature = "instrumentation")]
+        let _counts = crate::instrumentation::SpanCounts::start();
         let &index = if let Some(index) = self.index.get(&commitment) {
             index
         } else {
@@ -292,12 +446,53 @@ impl Tree {
         Some(proof)
     }
 
+    /// Get a [`Proof`] of inclusion for each of the given commitments, in the same order.
+    ///
+    /// This is equivalent to calling [`witness`](Tree::witness) for each commitment, but when the
+    /// `parallel` feature is enabled, the proofs are computed in parallel. Either way, every
+    /// interior hash is computed at most once and shared between all the proofs.
+    #[instrument(
+        level = "trace",
+        skip(self, commitments),
+        fields(count = commitments.len(), nodes_hashed = tracing::field::Empty, commitments_hashed = tracing::field::Empty)
+    )]
+    pub fn witness_batch(&self, commitments: &[StateCommitment]) -> Vec<Option<Proof>> {
+        #[cfg(feature = "instrumentation")]
+        let _counts = crate::instrumentation::SpanCounts::start();
+        // Computing the root caches every interior hash of the tree, so the authentication paths
+        // below only read cached hashes, rather than contending to compute the same ones
+        self.root();
+
+        #[cfg(feature = "parallel")]
+        {
+            use rayon::prelude::*;
+            commitments
+                .par_iter()
+                .map(|&commitment| self.witness(commitment))
+                .collect()
+        }
+
+        #[cfg(not(feature = "parallel"))]
+        {
+            commitments
+                .iter()
+                .map(|&commitment| self.witness(commitment))
+                .collect()
+        }
+    }
+
     /// Forget about the witness for the given [`Commitment`].
     ///
     /// Returns `true` if the commitment was previously witnessed (and now is forgotten), and `false` if
     /// it was not witnessed.
-    #[instrument(level = "trace", skip(self))]
+    #[instrument(
+        level = "trace",
+        skip(self),
+        fields(nodes_hashed = tracing::field::Empty, commitments_hashed = tracing::field::Empty)
+    )]
     pub fn forget(&mut self, commitment: StateCommitment) -> bool {
+        #[cfg(feature = "instrumentation")]
+        let _counts = crate::instrumentation::SpanCounts::start();
         let mut forgotten = false;
 
         if let Some(&within_epoch) = self.index.get(&commitment) {
@@ -306,14 +501,103 @@ impl Tree {
             // Forget the index for this element in the tree
             let forgotten = Arc::make_mut(&mut self.inner).forget(within_epoch);
             debug_assert!(forgotten);
-            // Remove this entry from the index
+            // Remove this entry from the index, along with its tag, if any
             self.index.remove(&commitment);
+            self.tags.remove(&commitment);
         }
 
         trace!(?forgotten);
         forgotten
     }
 
+    /// Forget about the witnesses for all [`Commitment`]s which were inserted with the given
+    /// [`Tag`].
+    ///
+    /// Returns the number of commitments which were forgotten.
+    #[instrument(level = "trace", skip(self))]
+    pub fn forget_by_tag(&mut self, tag: Tag) -> usize {
+        let tagged: Vec<StateCommitment> = self
+            .tags
+            .iter()
+            .filter(|(_, t)| **t == tag)
+            .map(|(c, _)| *c)
+            .collect();
+
+        let mut count = 0;
+        for commitment in tagged {
+            if self.forget(commitment) {
+                count += 1;
+            }
+        }
+
+        trace!(?count);
+        count
+    }
+
+    /// Forget about the witnesses for all [`Commitment`]s positioned before the given block of the
+    /// given epoch, keeping only those inserted into that block or any later one.
+    ///
+    /// Commitments are forgotten in order of position, so each block and epoch which no longer
+    /// contains any witnesses is collapsed into its hash as soon as its last witness is forgotten.
+    ///
+    /// Returns the number of commitments which were forgotten.
+    #[instrument(level = "trace", skip(self))]
+    pub fn forget_before(&mut self, epoch: u16, block: u16) -> usize {
+        let cutoff = Position::from((epoch, block, 0));
+
+        let mut expired: Vec<(Position, StateCommitment)> = self
+            .index
+            .iter()
+            .map(|(c, index)| (Position(*index), *c))
+            .filter(|(position, _)| *position < cutoff)
+            .collect();
+        expired.sort_unstable_by_key(|(position, _)| *position);
+
+        let mut count = 0;
+        for (_, commitment) in expired {
+            if self.forget(commitment) {
+                count += 1;
+            }
+        }
+
+        trace!(?count);
+        count
+    }
+
+    /// Get an iterator over all commitments currently witnessed in the tree which were inserted
+    /// with the given [`Tag`].
+    ///
+    /// Like [`commitments_unordered`](Tree::commitments_unordered), this **does not** guarantee
+    /// that commitments will be returned in order.
+    #[instrument(level = "trace", skip(self))]
+    pub fn iter_by_tag(
+        &self,
+        tag: Tag,
+    ) -> impl Iterator<Item = (StateCommitment, Position)> + Send + Sync + '_ {
+        self.tags
+            .iter()
+            .filter(move |(_, t)| **t == tag)
+            .filter_map(move |(c, _)| Some((*c, Position(*self.index.get(c)?))))
+    }
+
+    /// Get the [`Tag`] attached to the given [`Commitment`], if it is currently witnessed and was
+    /// inserted with a tag.
+    #[instrument(level = "trace", skip(self))]
+    pub fn tag_of(&self, commitment: StateCommitment) -> Option<Tag> {
+        let tag = self.tags.get(&commitment).copied();
+        trace!(?tag);
+        tag
+    }
+
+    /// Re-attach a [`Tag`] to a witnessed commitment, when restoring a tree from storage.
+    ///
+    /// Does nothing if the commitment is not witnessed.
+    pub(crate) fn restore_tag(&mut self, commitment: StateCommitment, tag: Tag) {
+        if self.index.contains_key(&commitment) {
+            self.tags.insert(commitment, tag);
+        }
+    }
+
     /// Get the position in this [`Tree`] of the given [`Commitment`], if it is currently witnessed.
     #[instrument(level = "trace", skip(self))]
     pub fn position_of(&self, commitment: StateCommitment) -> Option<Position> {
@@ -347,11 +631,17 @@ impl Tree {
     ///
     /// Returns [`InsertBlockError`] containing the inserted block without adding it to the [`Tree`]
     /// if the [`Tree`] is full or the current epoch is full.
-    #[instrument(level = "trace", skip(self, block))]
+    #[instrument(
+        level = "trace",
+        skip(self, block),
+        fields(nodes_hashed = tracing::field::Empty, commitments_hashed = tracing::field::Empty)
+    )]
     pub fn insert_block(
         &mut self,
         block: impl Into<block::Finalized>,
     ) -> Result<block::Root, InsertBlockError> {
+        #[cfg(feature = "instrumentation")]
+        let _counts = crate::instrumentation::SpanCounts::start();
         // We split apart the inside so that we get the right instrumentation when this is called as
         // an inner function in `end_block`
         let block_root = self.insert_block_uninstrumented(block).map_err(|error| {
@@ -457,6 +747,7 @@ impl Tree {
         {
             // If any commitment is repeated, forget the previous one within the tree, since it is
             // now inaccessible
+            self.tags.remove(&c);
             if let Some(replaced) = self.index.insert(
                 c,
                 index::within::Tree {
@@ -477,8 +768,14 @@ impl Tree {
 
     /// Explicitly mark the end of the current block in this tree, advancing the position to the
     /// next block, and returning the root of the block which was just finalized.
-    #[instrument(level = "trace", skip(self))]
+    #[instrument(
+        level = "trace",
+        skip(self),
+        fields(nodes_hashed = tracing::field::Empty, commitments_hashed = tracing::field::Empty)
+    )]
     pub fn end_block(&mut self) -> Result<block::Root, InsertBlockError> {
+        #[cfg(feature = "instrumentation")]
+        let _counts = crate::instrumentation::SpanCounts::start();
         // Check to see if the latest block is already finalized, and finalize it if
         // it is not
         let (already_finalized, finalized_root) = Arc::make_mut(&mut self.inner)
@@ -552,11 +849,17 @@ impl Tree {
     ///
     /// Returns [`InsertEpochError`] containing the epoch without adding it to the [`Tree`] if the
     /// [`Tree`] is full.
-    #[instrument(level = "trace", skip(self, epoch))]
+    #[instrument(
+        level = "trace",
+        skip(self, epoch),
+        fields(nodes_hashed = tracing::field::Empty, commitments_hashed = tracing::field::Empty)
+    )]
     pub fn insert_epoch(
         &mut self,
         epoch: impl Into<epoch::Finalized>,
     ) -> Result<epoch::Root, InsertEpochError> {
+        #[cfg(feature = "instrumentation")]
+        let _counts = crate::instrumentation::SpanCounts::start();
         // We split apart the inside so that we get the right instrumention when this is called as
         // an inner function in `end_epoch`
         let epoch_root = self.insert_epoch_uninstrumented(epoch).map_err(|error| {
@@ -608,6 +911,7 @@ impl Tree {
         for (c, index::within::Epoch { block, commitment }) in index {
             // If any commitment is repeated, forget the previous one within the tree, since it is
             // now inaccessible
+            self.tags.remove(&c);
             if let Some(replaced) = self.index.insert(
                 c,
                 index::within::Tree {
@@ -628,8 +932,14 @@ impl Tree {
 
     /// Explicitly mark the end of the current epoch in this tree, advancing the position to the
     /// next epoch, and returning the root of the epoch which was just finalized.
-    #[instrument(level = "trace", skip(self))]
+    #[instrument(
+        level = "trace",
+        skip(self),
+        fields(nodes_hashed = tracing::field::Empty, commitments_hashed = tracing::field::Empty)
+    )]
     pub fn end_epoch(&mut self) -> Result<epoch::Root, InsertEpochError> {
+        #[cfg(feature = "instrumentation")]
+        let _counts = crate::instrumentation::SpanCounts::start();
         // Check to see if the latest block is already finalized, and finalize it if
         // it is not
         let (already_finalized, finalized_root) = Arc::make_mut(&mut self.inner)
@@ -674,6 +984,66 @@ impl Tree {
         root
     }
 
+    /// Get the root hash of a finished epoch in this [`Tree`], by its index.
+    ///
+    /// Returns `None` if the epoch has not been finished yet, because it is still being built or
+    /// has not been started.  The roots of finished epochs are kept even when all their
+    /// commitments have been forgotten, so they can be checked against published anchors.
+    #[instrument(level = "trace", skip(self))]
+    pub fn epoch_root(&self, epoch: u16) -> Option<epoch::Root> {
+        if !self.is_finished(Position::from((epoch, u16::MAX, u16::MAX))) {
+            return None;
+        }
+        let root = self
+            .finished_node(16, Position::from((epoch, 0, 0)))
+            .map(|node| epoch::Root(node.hash()));
+        trace!(?root);
+        root
+    }
+
+    /// Get the root hash of a finished block in this [`Tree`], by the index of its epoch and its
+    /// index within that epoch.
+    ///
+    /// Returns `None` if the block has not been finished yet, or if it is no longer represented in
+    /// the tree because everything in its epoch has been forgotten, in which case only the
+    /// [`epoch_root`](Tree::epoch_root) is available.
+    #[instrument(level = "trace", skip(self))]
+    pub fn block_root(&self, epoch: u16, block: u16) -> Option<block::Root> {
+        if !self.is_finished(Position::from((epoch, block, u16::MAX))) {
+            return None;
+        }
+        let root = self
+            .finished_node(8, Position::from((epoch, block, 0)))
+            .map(|node| block::Root(node.hash()));
+        trace!(?root);
+        root
+    }
+
+    /// Whether the given position is behind the position at which the next [`Commitment`] would
+    /// be inserted, so that the subtrees containing it are finished if they end before then.
+    fn is_finished(&self, last: Position) -> bool {
+        match self.position() {
+            Some(next) => u64::from(last) < u64::from(next),
+            // If the tree is full, everything in it is finished.
+            None => true,
+        }
+    }
+
+    /// Find the node at the given height whose subtree contains the given position, if it is
+    /// still represented in the tree.
+    fn finished_node(&self, height: u8, position: Position) -> Option<structure::Node> {
+        let position = u64::from(position);
+        let mut node = self.structure();
+        while node.height() > height {
+            let child_height = node.height() - 1;
+            node = node
+                .children()
+                .into_iter()
+                .find(|child| child.index() == position >> (2 * child_height))?;
+        }
+        Some(node)
+    }
+
     /// The position in this [`Tree`] at which the next [`Commitment`] would be inserted.
     ///
     /// If the [`Tree`] is full, returns `None`.
@@ -802,6 +1172,48 @@ impl Tree {
         storage::serialize::to_async_writer(writer, self).await
     }
 
+    /// Take a compact [`Snapshot`](storage::Snapshot) of the tree, from which it can later be
+    /// [restored](storage::Snapshot::to_tree).
+    ///
+    /// Subsequent changes to the tree can be recorded as a [`Delta`](storage::Delta) relative to
+    /// the snapshot, using [`Snapshot::delta`](storage::Snapshot::delta).
+    pub fn snapshot(&self) -> storage::Snapshot {
+        storage::Snapshot::new(self)
+    }
+
+    /// Compute a patch which brings this tree up to date with `later`, a later version of it.
+    ///
+    /// The patch contains only the commitments appended and the ranges forgotten since this
+    /// version, along with the hashes needed to summarize them, so it is suitable for replicating
+    /// a tree from a primary to read replicas which hold an earlier version of it, using
+    /// [`apply_patch`](Tree::apply_patch).
+    ///
+    /// The patch is only meaningful if `later` was derived from this tree by inserting and
+    /// forgetting; otherwise, applying it will fail.
+    pub fn diff(&self, later: &Tree) -> storage::Delta {
+        storage::Delta::between(self.position().into(), self.forgotten(), later)
+    }
+
+    /// Apply a patch computed by [`diff`](Tree::diff), so that this tree becomes the later version
+    /// the patch was computed against.
+    ///
+    /// Applying a patch rebuilds the tree, so the cost is proportional to the size of the tree,
+    /// rather than to the size of the patch.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error, leaving the tree unchanged, if the patch was not computed relative to
+    /// this version of the tree, or if it is inconsistent with its contents.
+    pub fn apply_patch(
+        &mut self,
+        patch: &storage::Delta,
+    ) -> Result<(), storage::snapshot::ApplyError> {
+        let mut snapshot = self.snapshot();
+        snapshot.apply(patch)?;
+        *self = snapshot.to_tree();
+        Ok(())
+    }
+
     /// Deserialize a tree using externally driven iteration, without checking for internal
     /// consistency.
     ///
@@ -879,6 +1291,299 @@ impl From<frontier::Top<frontier::Tier<frontier::Tier<frontier::Item>>>> for Tre
         Self {
             inner: Arc::new(inner),
             index,
+            tags: HashedMap::default(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn commitment(i: u64) -> StateCommitment {
+        StateCommitment(i.into())
+    }
+
+    fn batch(range: std::ops::Range<u64>) -> Vec<(Witness, StateCommitment)> {
+        range
+            .map(|i| {
+                let witness = if i % 3 == 0 {
+                    Witness::Forget
+                } else {
+                    Witness::Keep
+                };
+                (witness, commitment(i))
+            })
+            .collect()
+    }
+
+    #[test]
+    fn insert_batch_matches_sequential_insert() {
+        let mut batched = Tree::new();
+        let mut sequential = Tree::new();
+
+        // Include a repeated commitment to check that the deferred indexing still forgets the
+        // earlier copy
+        let batches = [batch(0..10), batch(10..20), batch(5..8), vec![]];
+        for (i, commitments) in batches.into_iter().enumerate() {
+            batched.insert_batch(commitments.iter().copied()).unwrap();
+            for (witness, commitment) in commitments {
+                sequential.insert(witness, commitment).unwrap();
+            }
+
+            // Alternate between ending blocks and epochs, so that batches start in fresh ones
+            if i % 2 == 0 {
+                batched.end_block().unwrap();
+                sequential.end_block().unwrap();
+            } else {
+                batched.end_epoch().unwrap();
+                sequential.end_epoch().unwrap();
+            }
+        }
+
+        assert_eq!(batched, sequential);
+        assert_eq!(batched.root(), sequential.root());
+        assert_eq!(batched.witnessed_count(), sequential.witnessed_count());
+        for i in 0..20 {
+            assert_eq!(
+                batched.position_of(commitment(i)),
+                sequential.position_of(commitment(i))
+            );
+        }
+    }
+
+    #[test]
+    fn patch_replicates_later_version() {
+        let mut primary = Tree::new();
+        for i in 0..6 {
+            primary.insert(Witness::Keep, commitment(i)).unwrap();
+        }
+        primary.end_block().unwrap();
+        let mut replica = primary.clone();
+
+        primary.insert_batch(batch(6..12)).unwrap();
+        primary.insert_tagged(commitment(12), Tag::from(7)).unwrap();
+        primary.end_epoch().unwrap();
+        primary.forget(commitment(1));
+        primary.forget(commitment(2));
+
+        let patch = replica.diff(&primary);
+        assert_eq!(
+            patch
+                .appended_commitments()
+                .map(|(_, commitment)| commitment)
+                .collect::<std::collections::BTreeSet<_>>(),
+            primary
+                .commitments_unordered()
+                .map(|(commitment, _)| commitment)
+                .filter(|commitment| replica.position_of(*commitment).is_none())
+                .collect()
+        );
+        assert!(patch.forgotten_ranges().next().is_some());
+
+        replica.apply_patch(&patch).unwrap();
+        assert_eq!(replica, primary);
+        assert_eq!(replica.root(), primary.root());
+        assert_eq!(replica.tag_of(commitment(12)), Some(Tag::from(7)));
+
+        // The patch is relative to the earlier version, so can't be applied again
+        assert!(replica.apply_patch(&patch).is_err());
+        assert_eq!(replica, primary);
+    }
+
+    #[test]
+    fn tiers_hold_exactly_65536() {
+        let mut tree = Tree::new();
+
+        // A block holds 65,536 commitments, the last at commitment index 65,535
+        for i in 0..(1 << 16) {
+            tree.insert(Witness::Forget, commitment(i)).unwrap();
+        }
+        assert_eq!(
+            tree.insert(Witness::Forget, commitment(0)),
+            Err(InsertError::BlockFull)
+        );
+        tree.end_block().unwrap();
+        assert_eq!(tree.position(), Some(Position::from((0, 1, 0))));
+
+        // An epoch holds 65,536 blocks
+        for i in 1..(1 << 16) {
+            tree.insert_block(block::Root(Hash::of(commitment(i))))
+                .unwrap();
+        }
+        assert!(matches!(
+            tree.insert_block(block::Root(Hash::of(commitment(0)))),
+            Err(InsertBlockError::EpochFull(_))
+        ));
+        assert_eq!(
+            tree.insert(Witness::Forget, commitment(0)),
+            Err(InsertError::EpochFull)
+        );
+        tree.end_epoch().unwrap();
+        assert_eq!(tree.position(), Some(Position::from((1, 0, 0))));
+
+        // A tree holds 65,536 epochs, after which it has no position
+        for i in 1..(1 << 16) {
+            assert_eq!(tree.position(), Some(Position::from((i as u16, 0, 0))));
+            tree.insert_epoch(epoch::Root(Hash::of(commitment(i))))
+                .unwrap();
         }
+        assert_eq!(tree.position(), None);
+        assert!(tree
+            .insert_epoch(epoch::Root(Hash::of(commitment(0))))
+            .is_err());
+        assert_eq!(
+            tree.insert(Witness::Forget, commitment(0)),
+            Err(InsertError::Full)
+        );
+    }
+
+    #[test]
+    fn position_checked_from() {
+        assert_eq!(u64::from(Position::MAX), (1 << 48) - 1);
+        assert_eq!(
+            Position::MAX,
+            Position::from((u16::MAX, u16::MAX, u16::MAX))
+        );
+        assert_eq!(Position::checked_from((1 << 48) - 1), Some(Position::MAX));
+        assert_eq!(Position::checked_from(1 << 48), None);
+        assert_eq!(Position::checked_from(u64::MAX), None);
+    }
+
+    #[test]
+    fn forget_before_keeps_later_blocks() {
+        let mut tree = Tree::new();
+        let mut forgetful = Tree::new();
+
+        // Epoch 0 has blocks 0 and 1, and epoch 1 has blocks 0 and 1
+        for i in 0..8 {
+            tree.insert(Witness::Keep, commitment(i)).unwrap();
+            forgetful.insert(Witness::Keep, commitment(i)).unwrap();
+            if i % 2 == 1 {
+                if i % 4 == 3 {
+                    tree.end_epoch().unwrap();
+                    forgetful.end_epoch().unwrap();
+                } else {
+                    tree.end_block().unwrap();
+                    forgetful.end_block().unwrap();
+                }
+            }
+        }
+
+        // Nothing comes before the first block
+        assert_eq!(tree.forget_before(0, 0), 0);
+        assert_eq!(tree.witnessed_count(), 8);
+
+        // Forget all of epoch 0, and the first block of epoch 1
+        assert_eq!(tree.forget_before(1, 1), 6);
+        assert_eq!(tree.witnessed_count(), 2);
+        for i in 0..6 {
+            assert!(tree.witness(commitment(i)).is_none());
+            assert!(forgetful.forget(commitment(i)));
+        }
+        for i in 6..8 {
+            assert!(tree.witness(commitment(i)).is_some());
+        }
+
+        // Forgetting by age is the same as forgetting each commitment individually
+        assert_eq!(tree, forgetful);
+        assert_eq!(tree.root(), forgetful.root());
+
+        // Forgetting again forgets nothing more
+        assert_eq!(tree.forget_before(1, 1), 0);
+    }
+
+    #[test]
+    fn witness_batch_matches_sequential_witness() {
+        let mut tree = Tree::new();
+        tree.insert_batch(batch(0..100)).unwrap();
+        tree.end_epoch().unwrap();
+        tree.insert_batch(batch(100..150)).unwrap();
+
+        let commitments: Vec<_> = (0..160).map(commitment).collect();
+        let proofs = tree.witness_batch(&commitments);
+
+        assert_eq!(proofs.len(), commitments.len());
+        for (&commitment, proof) in commitments.iter().zip(proofs) {
+            assert_eq!(proof, tree.witness(commitment));
+            if let Some(proof) = proof {
+                assert!(proof.verify(tree.root()).is_ok());
+            }
+        }
+    }
+
+    #[test]
+    fn block_insert_batch_matches_sequential_insert() {
+        let mut batched = block::Builder::new();
+        let mut sequential = block::Builder::new();
+
+        batched.insert_batch(batch(0..100)).unwrap();
+        for (witness, commitment) in batch(0..100) {
+            sequential.insert(witness, commitment).unwrap();
+        }
+        assert_eq!(batched.root(), sequential.root());
+
+        let mut batched_tree = Tree::new();
+        let mut sequential_tree = Tree::new();
+        batched_tree.insert_block(batched).unwrap();
+        sequential_tree.insert_block(sequential).unwrap();
+        assert_eq!(batched_tree, sequential_tree);
+    }
+
+    #[test]
+    fn epoch_from_blocks_matches_sequential_insert() {
+        // Include an empty block in the middle and at the end of the epoch
+        let blocks = vec![batch(0..10), vec![], batch(10..30), batch(30..31), vec![]];
+
+        let mut sequential = epoch::Builder::new();
+        for commitments in blocks.iter() {
+            for &(witness, commitment) in commitments {
+                sequential.insert(witness, commitment).unwrap();
+            }
+            sequential.end_block().unwrap();
+        }
+        let sequential = sequential.finalize();
+        let from_blocks = epoch::Finalized::from_blocks(blocks).unwrap();
+        assert_eq!(from_blocks.root(), sequential.root());
+
+        let mut from_blocks_tree = Tree::new();
+        let mut sequential_tree = Tree::new();
+        from_blocks_tree.insert_epoch(from_blocks).unwrap();
+        sequential_tree.insert_epoch(sequential).unwrap();
+        assert_eq!(from_blocks_tree, sequential_tree);
+        for i in 0..31 {
+            assert_eq!(
+                from_blocks_tree.witness(commitment(i)),
+                sequential_tree.witness(commitment(i))
+            );
+        }
+    }
+
+    #[test]
+    fn roots_of_finished_blocks_and_epochs() {
+        let mut tree = Tree::new();
+
+        // Epoch 0 has blocks 0 and 1, and epoch 1 is still being built
+        tree.insert_batch(batch(0..4)).unwrap();
+        let block_0 = tree.end_block().unwrap();
+        tree.insert_batch(batch(4..8)).unwrap();
+        let block_1 = tree.end_block().unwrap();
+        let epoch_0 = tree.end_epoch().unwrap();
+        tree.insert_batch(batch(8..12)).unwrap();
+
+        assert_eq!(tree.block_root(0, 0), Some(block_0));
+        assert_eq!(tree.block_root(0, 1), Some(block_1));
+        assert_eq!(tree.epoch_root(0), Some(epoch_0));
+
+        // The current block and epoch are not finished, and neither is anything after them
+        assert_eq!(tree.block_root(1, 0), None);
+        assert_eq!(tree.block_root(1, 1), None);
+        assert_eq!(tree.epoch_root(1), None);
+        assert_eq!(tree.epoch_root(2), None);
+
+        // Once the epoch's commitments are forgotten, only its root remains
+        assert_eq!(tree.forget_before(1, 0), 5);
+        assert_eq!(tree.epoch_root(0), Some(epoch_0));
+        assert_eq!(tree.block_root(0, 0), None);
     }
 }
@@ -0,0 +1,577 @@
+//! An append-only log of the authorization decisions made by a custodian.
+//!
+//! Every [`AuthorizeRequest`] a custodian receives is recorded in its [`AuditLog`], together with
+//! the result of evaluating the custodian's policies and its final decision. Each [`Entry`] is
+//! signed by the custodian's identity key, and commits to the hash of the entry before it, so that
+//! anyone holding the identity key's verification key can check that a run of entries is authentic
+//! and that none were removed or reordered.
+//!
+//! Entries identify the plan that was decided by the hash of its [`PlanText`], rather than
+//! including the plan itself, so that the log can be handed to auditors without revealing the
+//! custodian's transactions. Whoever holds a plan can check whether it was decided by comparing
+//! hashes.
+//!
+//! Logs are kept in memory by default, and can be persisted to a file with [`AuditLog::open`], in
+//! which case each entry is appended to the file as a line of JSON before the decision takes
+//! effect. Custodians persist their log to the file named by their `audit_log` configuration.
+
+use std::{
+    fs::{File, OpenOptions},
+    io::{BufRead, BufReader, Write},
+    path::Path,
+    sync::Mutex,
+};
+
+use anyhow::{anyhow, Context};
+use ed25519_consensus::{Signature, SigningKey, VerificationKey};
+use penumbra_asset::asset;
+use penumbra_proto::{custody::v1 as pb, DomainType};
+
+use crate::{plan_text::PlanText, Approver, AuthorizeRequest};
+
+/// The number of entries returned by a query which doesn't specify a limit.
+pub const DEFAULT_QUERY_LIMIT: u32 = 100;
+
+/// The maximum number of entries returned by a single query.
+pub const MAX_QUERY_LIMIT: u32 = 1000;
+
+/// The custodian's final decision about an authorization request.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Decision {
+    /// The request was authorized.
+    Approved,
+    /// The request was not authorized, for the given reason.
+    Denied(String),
+}
+
+impl<T, E: std::fmt::Display> From<&Result<T, E>> for Decision {
+    fn from(result: &Result<T, E>) -> Self {
+        match result {
+            Ok(_) => Decision::Approved,
+            Err(e) => Decision::Denied(format!("{e:#}")),
+        }
+    }
+}
+
+/// A record of a single authorization decision.
+#[derive(Clone, Debug)]
+pub struct Entry {
+    /// The position of this entry in the log, starting from 0.
+    pub index: u64,
+    /// The time the decision was made, in seconds since the Unix epoch.
+    pub timestamp: u64,
+    /// The [`hash`](PlanText::hash) of the plan text of the transaction plan that was decided.
+    pub plan_text_hash: [u8; 32],
+    /// If the request was rejected by the custodian's policies, the reason for the rejection.
+    pub policy_denial: Option<String>,
+    /// The distinct pre-authorizers whose signatures were verified by the custodian's policies,
+    /// if the request satisfied them.
+    pub approvers: Vec<Approver>,
+    /// The custodian's final decision.
+    pub decision: Decision,
+    /// The hash of the previous entry in the log, if this isn't the first.
+    pub previous_hash: Option<[u8; 32]>,
+}
+
+impl Entry {
+    /// The hash of this entry, which is signed by the custodian and recorded in the next entry.
+    pub fn hash(&self) -> [u8; 32] {
+        blake2b_simd::Params::default()
+            .personal(b"Penumbra_AuditLg")
+            .hash_length(32)
+            .hash(&self.encode_to_vec())
+            .as_bytes()
+            .try_into()
+            .expect("hash length is 32 bytes")
+    }
+}
+
+impl DomainType for Entry {
+    type Proto = pb::AuditLogEntry;
+}
+
+impl From<Entry> for pb::AuditLogEntry {
+    fn from(entry: Entry) -> Self {
+        let (approved, denial_reason) = match entry.decision {
+            Decision::Approved => (true, String::new()),
+            Decision::Denied(reason) => (false, reason),
+        };
+        pb::AuditLogEntry {
+            index: entry.index,
+            timestamp: entry.timestamp,
+            plan_text_hash: entry.plan_text_hash.to_vec(),
+            policy_denial: entry.policy_denial.unwrap_or_default(),
+            approvers: entry.approvers.into_iter().map(Into::into).collect(),
+            approved,
+            denial_reason,
+            previous_hash: entry
+                .previous_hash
+                .map(|hash| hash.to_vec())
+                .unwrap_or_default(),
+        }
+    }
+}
+
+impl TryFrom<pb::AuditLogEntry> for Entry {
+    type Error = anyhow::Error;
+
+    fn try_from(entry: pb::AuditLogEntry) -> Result<Self, Self::Error> {
+        Ok(Entry {
+            index: entry.index,
+            timestamp: entry.timestamp,
+            plan_text_hash: entry.plan_text_hash.as_slice().try_into().map_err(|_| {
+                anyhow!(
+                    "plan text hash must be 32 bytes, got {}",
+                    entry.plan_text_hash.len()
+                )
+            })?,
+            policy_denial: Some(entry.policy_denial).filter(|denial| !denial.is_empty()),
+            approvers: entry
+                .approvers
+                .into_iter()
+                .map(TryInto::try_into)
+                .collect::<anyhow::Result<_>>()?,
+            decision: if entry.approved {
+                Decision::Approved
+            } else {
+                Decision::Denied(entry.denial_reason)
+            },
+            previous_hash: if entry.previous_hash.is_empty() {
+                None
+            } else {
+                Some(entry.previous_hash.as_slice().try_into().map_err(|_| {
+                    anyhow!(
+                        "previous hash must be 32 bytes, got {}",
+                        entry.previous_hash.len()
+                    )
+                })?)
+            },
+        })
+    }
+}
+
+/// An [`Entry`], signed by the custodian's identity key.
+#[derive(Clone, Debug)]
+pub struct SignedEntry {
+    pub entry: Entry,
+    /// A signature over the [`hash`](Entry::hash) of the entry.
+    pub signature: Signature,
+}
+
+impl SignedEntry {
+    /// Check the signature on this entry.
+    pub fn verify(&self, identity_key: &VerificationKey) -> anyhow::Result<()> {
+        identity_key
+            .verify(&self.signature, &self.entry.hash())
+            .with_context(|| format!("invalid signature on audit log entry {}", self.entry.index))
+    }
+}
+
+impl DomainType for SignedEntry {
+    type Proto = pb::SignedAuditLogEntry;
+}
+
+impl From<SignedEntry> for pb::SignedAuditLogEntry {
+    fn from(signed: SignedEntry) -> Self {
+        pb::SignedAuditLogEntry {
+            entry: Some(signed.entry.into()),
+            signature: signed.signature.to_bytes().to_vec(),
+        }
+    }
+}
+
+impl TryFrom<pb::SignedAuditLogEntry> for SignedEntry {
+    type Error = anyhow::Error;
+
+    fn try_from(signed: pb::SignedAuditLogEntry) -> Result<Self, Self::Error> {
+        Ok(SignedEntry {
+            entry: signed
+                .entry
+                .ok_or_else(|| anyhow!("missing entry in signed audit log entry"))?
+                .try_into()?,
+            signature: signed.signature.as_slice().try_into()?,
+        })
+    }
+}
+
+/// Check that a run of consecutive entries from an audit log is authentic and complete.
+///
+/// Each entry must be signed by the given identity key, and must follow on from the one before it.
+/// If the run starts at the beginning of the log, the first entry must not refer to a previous one.
+pub fn verify(entries: &[SignedEntry], identity_key: &VerificationKey) -> anyhow::Result<()> {
+    let mut previous: Option<&Entry> = None;
+    for signed in entries {
+        signed.verify(identity_key)?;
+        let entry = &signed.entry;
+        match previous {
+            Some(previous) => {
+                anyhow::ensure!(
+                    entry.index == previous.index + 1,
+                    "audit log entry {} follows entry {}",
+                    entry.index,
+                    previous.index
+                );
+                anyhow::ensure!(
+                    entry.previous_hash == Some(previous.hash()),
+                    "audit log entry {} does not commit to the entry before it",
+                    entry.index
+                );
+            }
+            None if entry.index == 0 => anyhow::ensure!(
+                entry.previous_hash.is_none(),
+                "the first audit log entry refers to a previous entry"
+            ),
+            None => {}
+        }
+        previous = Some(entry);
+    }
+    Ok(())
+}
+
+/// A query for the entries in an [`AuditLog`].
+#[derive(Clone, Debug, Default)]
+pub struct Query {
+    /// The index of the first entry to consider.
+    pub start_index: u64,
+    /// The maximum number of entries to return, capped at [`MAX_QUERY_LIMIT`].
+    pub limit: Option<u32>,
+    /// If set, only return entries recorded at or after this time.
+    pub start_time: Option<u64>,
+    /// If set, only return entries recorded before this time.
+    pub end_time: Option<u64>,
+}
+
+impl Query {
+    fn matches(&self, entry: &Entry) -> bool {
+        entry.timestamp >= self.start_time.unwrap_or(0)
+            && entry.timestamp < self.end_time.unwrap_or(u64::MAX)
+    }
+}
+
+impl From<pb::QueryAuditLogRequest> for Query {
+    fn from(request: pb::QueryAuditLogRequest) -> Self {
+        Query {
+            start_index: request.start_index,
+            limit: Some(request.limit).filter(|&limit| limit != 0),
+            start_time: Some(request.start_time).filter(|&t| t != 0),
+            end_time: Some(request.end_time).filter(|&t| t != 0),
+        }
+    }
+}
+
+/// The result of a [`Query`].
+#[derive(Clone, Debug)]
+pub struct QueryResponse {
+    /// The matching entries, in order of increasing index.
+    pub entries: Vec<SignedEntry>,
+    /// The index at which to resume the query, if there may be further matching entries.
+    pub next_index: Option<u64>,
+    /// The custodian's identity key, which signed the entries.
+    pub identity_key: VerificationKey,
+}
+
+impl From<QueryResponse> for pb::QueryAuditLogResponse {
+    fn from(response: QueryResponse) -> Self {
+        pb::QueryAuditLogResponse {
+            entries: response.entries.into_iter().map(Into::into).collect(),
+            next_index: response.next_index.unwrap_or_default(),
+            identity_key: response.identity_key.to_bytes().to_vec(),
+        }
+    }
+}
+
+/// An append-only log of authorization decisions, signed by the custodian's identity key.
+pub struct AuditLog {
+    identity_key: SigningKey,
+    state: Mutex<State>,
+}
+
+struct State {
+    entries: Vec<SignedEntry>,
+    /// The file the log is persisted to, if any.
+    file: Option<File>,
+}
+
+impl std::fmt::Debug for AuditLog {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("AuditLog")
+            .field("identity_key", &self.identity_key.verification_key())
+            .finish_non_exhaustive()
+    }
+}
+
+impl AuditLog {
+    /// Create a new, empty log which is only kept in memory.
+    pub fn in_memory(identity_key: SigningKey) -> Self {
+        Self {
+            identity_key,
+            state: Mutex::new(State {
+                entries: Vec::new(),
+                file: None,
+            }),
+        }
+    }
+
+    /// Open the log persisted at the given path, creating it if it doesn't exist.
+    ///
+    /// The existing entries must form a complete log signed by the given identity key, so that new
+    /// entries can continue it.
+    pub fn open(path: impl AsRef<Path>, identity_key: SigningKey) -> anyhow::Result<Self> {
+        let path = path.as_ref();
+        let mut file = OpenOptions::new()
+            .read(true)
+            .append(true)
+            .create(true)
+            .open(path)
+            .with_context(|| format!("failed to open audit log at {}", path.display()))?;
+
+        let mut entries = Vec::new();
+        for line in BufReader::new(&mut file).lines() {
+            let line = line?;
+            if line.trim().is_empty() {
+                continue;
+            }
+            let signed: SignedEntry = serde_json::from_str::<pb::SignedAuditLogEntry>(&line)?
+                .try_into()
+                .with_context(|| format!("invalid entry in audit log at {}", path.display()))?;
+            entries.push(signed);
+        }
+        if let Some(first) = entries.first() {
+            anyhow::ensure!(
+                first.entry.index == 0,
+                "audit log at {} does not start at the first entry",
+                path.display()
+            );
+        }
+        verify(&entries, &identity_key.verification_key())
+            .with_context(|| format!("audit log at {} failed verification", path.display()))?;
+
+        Ok(Self {
+            identity_key,
+            state: Mutex::new(State {
+                entries,
+                file: Some(file),
+            }),
+        })
+    }
+
+    /// The verification key of the custodian's identity key, which signs every entry.
+    pub fn identity_key(&self) -> VerificationKey {
+        self.identity_key.verification_key()
+    }
+
+    /// Record a decision about the given request, returning the new entry.
+    ///
+    /// If the log is persisted, the entry is written out before this returns; if that fails, the
+    /// entry is not recorded, and the decision should not take effect.
+    pub fn record(
+        &self,
+        request: &AuthorizeRequest,
+        policy_denial: Option<String>,
+        approvers: Vec<Approver>,
+        decision: Decision,
+    ) -> anyhow::Result<SignedEntry> {
+        let timestamp = unix_timestamp();
+        let plan_text_hash =
+            PlanText::render(&request.plan, &asset::Cache::with_known_assets()).hash();
+
+        let mut state = self.state.lock().expect("audit log lock is not poisoned");
+        let previous = state.entries.last().map(|signed| &signed.entry);
+        let entry = Entry {
+            index: previous.map_or(0, |entry| entry.index + 1),
+            timestamp,
+            plan_text_hash,
+            policy_denial,
+            approvers,
+            decision,
+            previous_hash: previous.map(Entry::hash),
+        };
+        let signature = self.identity_key.sign(&entry.hash());
+        let signed = SignedEntry { entry, signature };
+
+        if let Some(file) = state.file.as_mut() {
+            let mut line = serde_json::to_string(&signed.to_proto())?;
+            line.push('\n');
+            file.write_all(line.as_bytes())
+                .and_then(|()| file.sync_data())
+                .context("failed to write to audit log")?;
+        }
+        state.entries.push(signed.clone());
+
+        Ok(signed)
+    }
+
+    /// Query the entries in the log.
+    pub fn query(&self, query: &Query) -> QueryResponse {
+        let limit = query
+            .limit
+            .unwrap_or(DEFAULT_QUERY_LIMIT)
+            .min(MAX_QUERY_LIMIT) as usize;
+
+        let state = self.state.lock().expect("audit log lock is not poisoned");
+        let start = usize::try_from(query.start_index)
+            .unwrap_or(usize::MAX)
+            .min(state.entries.len());
+
+        let mut entries = Vec::new();
+        let mut next_index = None;
+        for signed in &state.entries[start..] {
+            if entries.len() == limit {
+                next_index = Some(signed.entry.index);
+                break;
+            }
+            if query.matches(&signed.entry) {
+                entries.push(signed.clone());
+            }
+        }
+
+        QueryResponse {
+            entries,
+            next_index,
+            identity_key: self.identity_key(),
+        }
+    }
+}
+
+/// The current time, in seconds since the Unix epoch.
+#[cfg(not(target_arch = "wasm32"))]
+pub(crate) fn unix_timestamp() -> u64 {
+    use std::time::{SystemTime, UNIX_EPOCH};
+
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|t| t.as_secs())
+        .unwrap_or_default()
+}
+
+/// The current time, in seconds since the Unix epoch.
+///
+/// `SystemTime` is unavailable in the browser, so we ask JavaScript instead.
+#[cfg(target_arch = "wasm32")]
+pub(crate) fn unix_timestamp() -> u64 {
+    (js_sys::Date::now() / 1000.0) as u64
+}
+
+#[cfg(test)]
+mod tests {
+    use penumbra_transaction::TransactionPlan;
+    use rand_core::OsRng;
+
+    use super::*;
+
+    fn request() -> AuthorizeRequest {
+        AuthorizeRequest {
+            plan: TransactionPlan::default(),
+            pre_authorizations: Vec::new(),
+            nonce: None,
+        }
+    }
+
+    #[test]
+    fn entries_form_a_verifiable_chain() {
+        let log = AuditLog::in_memory(SigningKey::new(OsRng));
+        log.record(&request(), None, Vec::new(), Decision::Approved)
+            .unwrap();
+        log.record(
+            &request(),
+            Some("spend limit exceeded".to_string()),
+            Vec::new(),
+            Decision::Denied("spend limit exceeded".to_string()),
+        )
+        .unwrap();
+        log.record(&request(), None, Vec::new(), Decision::Approved)
+            .unwrap();
+
+        let response = log.query(&Query::default());
+        assert_eq!(response.entries.len(), 3);
+        assert_eq!(response.next_index, None);
+        verify(&response.entries, &response.identity_key).unwrap();
+
+        // Dropping an entry breaks the chain.
+        let mut entries = response.entries.clone();
+        entries.remove(1);
+        assert!(verify(&entries, &response.identity_key).is_err());
+
+        // So does altering one.
+        let mut entries = response.entries.clone();
+        entries[1].entry.decision = Decision::Approved;
+        assert!(verify(&entries, &response.identity_key).is_err());
+
+        // And entries signed by another key are rejected.
+        let other_key = SigningKey::new(OsRng).verification_key();
+        assert!(verify(&response.entries, &other_key).is_err());
+    }
+
+    #[test]
+    fn queries_are_paginated_and_filtered() {
+        let log = AuditLog::in_memory(SigningKey::new(OsRng));
+        for _ in 0..5 {
+            log.record(&request(), None, Vec::new(), Decision::Approved)
+                .unwrap();
+        }
+
+        let first = log.query(&Query {
+            limit: Some(2),
+            ..Default::default()
+        });
+        assert_eq!(first.entries.len(), 2);
+        assert_eq!(first.next_index, Some(2));
+
+        let rest = log.query(&Query {
+            start_index: 2,
+            limit: Some(10),
+            ..Default::default()
+        });
+        assert_eq!(rest.entries.len(), 3);
+        assert_eq!(rest.entries[0].entry.index, 2);
+        assert_eq!(rest.next_index, None);
+
+        let timestamp = first.entries[0].entry.timestamp;
+        let before = log.query(&Query {
+            end_time: Some(timestamp),
+            ..Default::default()
+        });
+        assert!(before.entries.is_empty());
+        let after = log.query(&Query {
+            start_time: Some(timestamp),
+            ..Default::default()
+        });
+        assert_eq!(after.entries.len(), 5);
+    }
+
+    #[test]
+    fn persisted_logs_are_continued_when_reopened() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("audit.jsonl");
+        let identity_key = SigningKey::new(OsRng);
+
+        let log = AuditLog::open(&path, identity_key.clone()).unwrap();
+        log.record(&request(), None, Vec::new(), Decision::Approved)
+            .unwrap();
+        drop(log);
+
+        let log = AuditLog::open(&path, identity_key.clone()).unwrap();
+        let signed = log
+            .record(
+                &request(),
+                None,
+                Vec::new(),
+                Decision::Denied("declined".to_string()),
+            )
+            .unwrap();
+        assert_eq!(signed.entry.index, 1);
+        drop(log);
+
+        let log = AuditLog::open(&path, identity_key).unwrap();
+        let response = log.query(&Query::default());
+        assert_eq!(response.entries.len(), 2);
+        verify(&response.entries, &response.identity_key).unwrap();
+
+        // Plans are identified by the hash of their text, rather than recorded in full.
+        let plan_text = PlanText::render(&request().plan, &asset::Cache::with_known_assets());
+        assert_eq!(response.entries[0].entry.plan_text_hash, plan_text.hash());
+
+        // A log signed by a different key can't be continued.
+        assert!(AuditLog::open(&path, SigningKey::new(OsRng)).is_err());
+    }
+}
@@ -0,0 +1,85 @@
+#![deny(clippy::unwrap_used)]
+//! A remote signer daemon, serving a soft KMS to clients connecting over
+//! mutually-authenticated TLS.
+
+use std::{io::IsTerminal as _, path::PathBuf};
+
+use anyhow::{Context as _, Result};
+use clap::Parser;
+use penumbra_custody::{
+    remote::{self, TlsIdentity},
+    soft_kms::{self, SoftKms},
+};
+use tokio::net::TcpListener;
+use tracing_subscriber::{prelude::*, EnvFilter};
+
+#[derive(Debug, Parser)]
+#[clap(
+    name = "penumbra-remote-signer",
+    about = "Serves a soft KMS to remote signing clients over mutually-authenticated TLS.",
+    version
+)]
+struct Opt {
+    /// The soft KMS configuration, as TOML, holding the spend key and the
+    /// authorization policies to enforce.  Policy bundles imported into the
+    /// signer are persisted to its `policy_state`, or else to `policy.json`
+    /// next to it, and signing decisions are recorded in its `audit_log`, or
+    /// else in `audit.jsonl` next to it.
+    #[clap(long, env = "PENUMBRA_REMOTE_SIGNER_CONFIG")]
+    config: PathBuf,
+    /// The address to listen on.
+    #[clap(long, default_value = "127.0.0.1:8087")]
+    bind_addr: String,
+    /// The PEM-encoded certificates of the CAs whose clients may connect.
+    #[clap(long)]
+    client_ca: PathBuf,
+    /// The PEM-encoded certificate chain the signer presents to clients.
+    #[clap(long)]
+    cert: PathBuf,
+    /// The PEM-encoded private key for the signer's certificate.
+    #[clap(long)]
+    key: PathBuf,
+    /// A file to persist the freeze switch in, so that a freeze survives
+    /// restarts of the signer.  Defaults to the `freeze_state` in the soft KMS
+    /// configuration, or else to `freeze.json` next to it.
+    #[clap(long)]
+    freeze_state: Option<PathBuf>,
+}
+
+#[tokio::main]
+async fn main() -> Result<()> {
+    let fmt_layer = tracing_subscriber::fmt::layer()
+        .with_ansi(std::io::stdout().is_terminal())
+        .with_target(true);
+    let filter_layer = EnvFilter::try_from_default_env().or_else(|_| EnvFilter::try_new("info"))?;
+    tracing_subscriber::registry()
+        .with(filter_layer)
+        .with(fmt_layer)
+        .init();
+
+    let opt = Opt::parse();
+
+    let config = std::fs::read_to_string(&opt.config)
+        .with_context(|| format!("could not read {}", opt.config.display()))?;
+    let mut config: soft_kms::Config = toml::from_str(&config)
+        .with_context(|| format!("invalid soft KMS config in {}", opt.config.display()))?;
+    let identity = TlsIdentity::from_pem_files(&opt.client_ca, &opt.cert, &opt.key)?;
+
+    let listener = TcpListener::bind(&opt.bind_addr)
+        .await
+        .with_context(|| format!("could not listen on {}", opt.bind_addr))?;
+    config.freeze_state = opt
+        .freeze_state
+        .or(config.freeze_state)
+        .or_else(|| Some(opt.config.with_file_name("freeze.json")));
+    config
+        .policy_state
+        .get_or_insert_with(|| opt.config.with_file_name("policy.json"));
+    config
+        .audit_log
+        .get_or_insert_with(|| opt.config.with_file_name("audit.jsonl"));
+    let kms = SoftKms::new(config)?;
+
+    tracing::info!(bind_addr = %opt.bind_addr, "serving remote signing requests");
+    remote::serve(listener, &identity, kms).await
+}
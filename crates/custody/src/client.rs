@@ -1,12 +1,30 @@
 use anyhow::Result;
+#[cfg(feature = "rpc")]
 use futures::FutureExt;
+#[cfg(feature = "rpc")]
 use penumbra_proto::custody::v1::custody_service_client::CustodyServiceClient;
-use penumbra_proto::custody::v1::AuthorizeResponse;
+use penumbra_proto::custody::v1::{self as pb, AuthorizeResponse, ChallengeRequest};
+use penumbra_proto::Message;
 use std::{future::Future, pin::Pin};
 
+#[cfg(feature = "rpc")]
 use tonic::codegen::Bytes;
 
-use crate::AuthorizeRequest;
+use crate::{replay::Nonce, AuthorizeRequest};
+
+/// The future returned by a [`CustodyClient`] or a [`CustodyChannel`].
+///
+/// Futures in the browser generally aren't `Send`, so this is only required
+/// to be `Send` outside of `wasm32`.
+#[cfg(not(target_arch = "wasm32"))]
+pub type CustodyFuture<T> = Pin<Box<dyn Future<Output = Result<T>> + Send + 'static>>;
+
+/// The future returned by a [`CustodyClient`] or a [`CustodyChannel`].
+///
+/// Futures in the browser generally aren't `Send`, so this is only required
+/// to be `Send` outside of `wasm32`.
+#[cfg(target_arch = "wasm32")]
+pub type CustodyFuture<T> = Pin<Box<dyn Future<Output = Result<T>> + 'static>>;
 
 /// A well-typed wrapper around the GRPC custody protocol that uses Rust domain types rather than proto types.
 ///
@@ -22,7 +40,8 @@ use crate::AuthorizeRequest;
 /// understand the transaction and determine whether or not it should be
 /// authorized.
 ///
-/// This trait is a wrapper around the proto-generated [`CustodyServiceClient`] that serves two goals:
+/// This trait is a wrapper around the proto-generated `CustodyServiceClient`,
+/// or a [`ChannelClient`] where that is unavailable, that serves two goals:
 ///
 /// 1. It works on domain types rather than proto-generated types, avoiding conversions;
 /// 2. It's easier to write as a trait bound than the `CustodyServiceClient`,
@@ -30,12 +49,15 @@ use crate::AuthorizeRequest;
 ///   tower `Service`
 pub trait CustodyClient {
     /// Requests authorization of the transaction with the given description.
-    fn authorize(
-        &mut self,
-        request: AuthorizeRequest,
-    ) -> Pin<Box<dyn Future<Output = Result<AuthorizeResponse>> + Send + 'static>>;
+    fn authorize(&mut self, request: AuthorizeRequest) -> CustodyFuture<AuthorizeResponse>;
+
+    /// Requests a single-use nonce to include in the next authorization request.
+    ///
+    /// Returns `None` if the custodian doesn't support challenges.
+    fn challenge(&mut self) -> CustodyFuture<Option<Nonce>>;
 }
 
+#[cfg(feature = "rpc")]
 impl<T> CustodyClient for CustodyServiceClient<T>
 where
     T: tonic::client::GrpcService<tonic::body::BoxBody> + Send + Clone + 'static,
@@ -44,10 +66,7 @@ where
     T::Error: Into<tonic::codegen::StdError>,
     <T::ResponseBody as tonic::codegen::Body>::Error: Into<tonic::codegen::StdError> + Send,
 {
-    fn authorize(
-        &mut self,
-        request: AuthorizeRequest,
-    ) -> Pin<Box<dyn Future<Output = Result<AuthorizeResponse>> + Send + 'static>> {
+    fn authorize(&mut self, request: AuthorizeRequest) -> CustodyFuture<AuthorizeResponse> {
         let mut self2 = self.clone();
         async move {
             Ok(self2
@@ -57,4 +76,141 @@ where
         }
         .boxed()
     }
+
+    fn challenge(&mut self) -> CustodyFuture<Option<Nonce>> {
+        let mut self2 = self.clone();
+        async move {
+            match self2
+                .challenge(tonic::Request::new(ChallengeRequest {}))
+                .await
+            {
+                Ok(response) => Ok(Some(response.into_inner().nonce.as_slice().try_into()?)),
+                Err(status) if status.code() == tonic::Code::Unimplemented => Ok(None),
+                Err(status) => Err(status.into()),
+            }
+        }
+        .boxed()
+    }
+}
+
+/// A channel to a custodian, over which encoded custody requests are sent.
+///
+/// This lets the custody protocol be carried over transports other than the
+/// tonic gRPC transport, such as gRPC-web from a browser, or the messages
+/// passed between a web page and a browser extension.
+pub trait CustodyChannel {
+    /// Send the encoded request to the custody service method with the given
+    /// gRPC path, such as `/penumbra.custody.v1.CustodyService/Authorize`, and
+    /// return the encoded response.
+    ///
+    /// If the custodian doesn't implement the method, the error should wrap
+    /// [`Unimplemented`].
+    fn unary(&mut self, path: &'static str, request: Vec<u8>) -> CustodyFuture<Vec<u8>>;
+}
+
+/// The error returned by a [`CustodyChannel`] when the custodian doesn't
+/// implement the requested method.
+#[derive(Clone, Copy, Debug, thiserror::Error)]
+#[error("custody method is not implemented")]
+pub struct Unimplemented;
+
+/// A [`CustodyClient`] which sends requests over a [`CustodyChannel`].
+#[derive(Clone, Debug)]
+pub struct ChannelClient<C> {
+    channel: C,
+}
+
+impl<C: CustodyChannel> ChannelClient<C> {
+    /// Construct a client which sends requests over the given channel.
+    pub fn new(channel: C) -> Self {
+        Self { channel }
+    }
+
+    /// Return the underlying channel.
+    pub fn into_inner(self) -> C {
+        self.channel
+    }
+}
+
+impl<C: CustodyChannel> CustodyClient for ChannelClient<C> {
+    fn authorize(&mut self, request: AuthorizeRequest) -> CustodyFuture<AuthorizeResponse> {
+        let request = pb::AuthorizeRequest::from(request).encode_to_vec();
+        let response = self
+            .channel
+            .unary("/penumbra.custody.v1.CustodyService/Authorize", request);
+        Box::pin(async move { Ok(AuthorizeResponse::decode(response.await?.as_slice())?) })
+    }
+
+    fn challenge(&mut self) -> CustodyFuture<Option<Nonce>> {
+        let request = ChallengeRequest {}.encode_to_vec();
+        let response = self
+            .channel
+            .unary("/penumbra.custody.v1.CustodyService/Challenge", request);
+        Box::pin(async move {
+            match response.await {
+                Ok(response) => {
+                    let response = pb::ChallengeResponse::decode(response.as_slice())?;
+                    Ok(Some(response.nonce.as_slice().try_into()?))
+                }
+                Err(e) if e.is::<Unimplemented>() => Ok(None),
+                Err(e) => Err(e),
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use futures::executor::block_on;
+    use penumbra_keys::keys::{Bip44Path, SeedPhrase, SpendKey};
+    use penumbra_proto::DomainType;
+    use penumbra_transaction::TransactionPlan;
+
+    use super::*;
+    use crate::soft_kms::SoftKms;
+
+    /// A channel which hands requests directly to an in-process soft KMS, as a
+    /// browser extension embedding the signer would.
+    struct Loopback(Arc<SoftKms>);
+
+    impl CustodyChannel for Loopback {
+        fn unary(&mut self, path: &'static str, request: Vec<u8>) -> CustodyFuture<Vec<u8>> {
+            let kms = self.0.clone();
+            Box::pin(async move {
+                match path {
+                    "/penumbra.custody.v1.CustodyService/Authorize" => {
+                        let request = AuthorizeRequest::decode(request.as_slice())?;
+                        let data = kms.sign(&request)?;
+                        Ok(AuthorizeResponse {
+                            data: Some(data.into()),
+                            summary: None,
+                        }
+                        .encode_to_vec())
+                    }
+                    _ => Err(Unimplemented.into()),
+                }
+            })
+        }
+    }
+
+    #[test]
+    fn channel_client_authorizes_through_soft_kms() -> Result<()> {
+        let seed_phrase = SeedPhrase::from_randomness(&[1; 32]);
+        let spend_key = SpendKey::from_seed_phrase_bip44(seed_phrase, &Bip44Path::new(0));
+        let mut client = ChannelClient::new(Loopback(Arc::new(SoftKms::new(spend_key.into())?)));
+
+        // The loopback doesn't implement challenges.
+        assert!(block_on(client.challenge())?.is_none());
+
+        let response = block_on(client.authorize(AuthorizeRequest {
+            plan: TransactionPlan::default(),
+            pre_authorizations: Vec::new(),
+            nonce: None,
+        }))?;
+        assert!(response.data.is_some());
+
+        Ok(())
+    }
 }
@@ -0,0 +1,98 @@
+//! Password-based encryption of custody secrets at rest.
+//!
+//! A [`Sealed`] box holds a secret, such as a serialized custody backend
+//! configuration, encrypted with ChaCha20-Poly1305 under a key derived from a
+//! password with PBKDF2-HMAC-SHA512, so that configuration files can be stored
+//! without exposing the keys they contain.
+
+use anyhow::Result;
+use chacha20poly1305::{
+    aead::{Aead, NewAead},
+    ChaCha20Poly1305, Key, Nonce,
+};
+use hmac::Hmac;
+use rand_core::CryptoRngCore;
+use serde::{Deserialize, Serialize};
+use serde_with::hex::Hex;
+use zeroize::Zeroizing;
+
+/// The number of PBKDF2 rounds used to derive the key of newly sealed boxes.
+pub const DEFAULT_ROUNDS: u32 = 210_000;
+
+/// A secret encrypted under a password.
+#[serde_as]
+#[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq)]
+pub struct Sealed {
+    /// The number of PBKDF2 rounds used to derive the key from the password.
+    pub rounds: u32,
+    /// The salt used to derive the key from the password.
+    #[serde_as(as = "Hex")]
+    pub salt: [u8; 32],
+    /// The nonce used to encrypt the secret.
+    #[serde_as(as = "Hex")]
+    pub nonce: [u8; 12],
+    /// The encrypted secret, including its authentication tag.
+    #[serde_as(as = "Hex")]
+    pub ciphertext: Vec<u8>,
+}
+
+impl Sealed {
+    /// Encrypt the secret under the password.
+    pub fn seal<R: CryptoRngCore>(rng: &mut R, password: &str, secret: &[u8]) -> Result<Self> {
+        let mut salt = [0u8; 32];
+        rng.fill_bytes(&mut salt);
+        let mut nonce = [0u8; 12];
+        rng.fill_bytes(&mut nonce);
+
+        let key = derive_key(password, &salt, DEFAULT_ROUNDS)?;
+        let ciphertext = ChaCha20Poly1305::new(&key)
+            .encrypt(Nonce::from_slice(&nonce), secret)
+            .map_err(|_| anyhow::anyhow!("failed to encrypt secret"))?;
+
+        Ok(Self {
+            rounds: DEFAULT_ROUNDS,
+            salt,
+            nonce,
+            ciphertext,
+        })
+    }
+
+    /// Decrypt the secret with the password, failing if the password is wrong
+    /// or the box was tampered with.
+    ///
+    /// The secret is zeroized once the returned buffer is dropped.
+    pub fn open(&self, password: &str) -> Result<Zeroizing<Vec<u8>>> {
+        let key = derive_key(password, &self.salt, self.rounds)?;
+        ChaCha20Poly1305::new(&key)
+            .decrypt(Nonce::from_slice(&self.nonce), self.ciphertext.as_slice())
+            .map(Zeroizing::new)
+            .map_err(|_| anyhow::anyhow!("incorrect password, or corrupted secret"))
+    }
+}
+
+fn derive_key(password: &str, salt: &[u8; 32], rounds: u32) -> Result<Key> {
+    anyhow::ensure!(rounds > 0, "key derivation must use at least one round");
+    let mut key = Zeroizing::new([0u8; 32]);
+    pbkdf2::pbkdf2::<Hmac<sha2::Sha512>>(password.as_bytes(), salt, rounds, key.as_mut())
+        .map_err(|_| anyhow::anyhow!("failed to derive key from password"))?;
+    Ok(*Key::from_slice(key.as_ref()))
+}
+
+#[cfg(test)]
+mod tests {
+    use rand_core::OsRng;
+
+    use super::*;
+
+    #[test]
+    fn sealed_secrets_open_only_with_their_password() -> Result<()> {
+        let sealed = Sealed::seal(&mut OsRng, "correct horse", b"battery staple")?;
+        assert_eq!(sealed.open("correct horse")?.as_slice(), b"battery staple");
+        assert!(sealed.open("incorrect horse").is_err());
+
+        let mut tampered = sealed.clone();
+        tampered.ciphertext[0] ^= 1;
+        assert!(tampered.open("correct horse").is_err());
+        Ok(())
+    }
+}
@@ -0,0 +1,482 @@
+//! An emergency kill switch for custodians.
+//!
+//! An operator who suspects that a key or a client has been compromised can freeze a custodian
+//! through its [`FreezeSwitch`], after which it refuses every authorization request.  Freezing is
+//! purely defensive, so any client allowed to request authorizations may do it.  Lifting a freeze
+//! is deliberately harder: an [`Unfreeze`] must be signed by a quorum of the custodian's recovery
+//! keys, and names the custodian and the generation of the freeze it lifts, so that it can't be
+//! replayed to lift a later one, or a freeze of another custodian sharing its recovery keys.
+//!
+//! Custodians persist the switch to the file named by their `freeze_state` configuration with
+//! [`FreezeSwitch::open`], so that a freeze survives restarts. Only a switch without a file, as
+//! created by [`FreezeSwitch::in_memory`], is cleared by restarting the custodian.
+
+use std::{
+    path::{Path, PathBuf},
+    sync::Mutex,
+};
+
+use anyhow::anyhow;
+use ed25519_consensus::{SigningKey, VerificationKey};
+use penumbra_proto::custody::v1 as pb;
+use serde::{Deserialize, Serialize};
+
+use crate::{audit::unix_timestamp, policy::PolicySigners, state_file};
+
+/// The reason an authorization request was refused by a frozen custodian.
+#[derive(Clone, Debug, Eq, PartialEq, thiserror::Error)]
+#[error("custodian is frozen (generation {generation}): {reason}")]
+pub struct Frozen {
+    /// The generation of the freeze.
+    pub generation: u64,
+    /// Why the custodian was frozen.
+    pub reason: String,
+}
+
+/// A request to lift a freeze, approved by the custodian's recovery keys.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Unfreeze {
+    /// The verification key of the identity key of the custodian to unfreeze.
+    pub custodian: [u8; 32],
+    /// The generation of the freeze to lift.
+    pub generation: u64,
+    /// Signatures over the [`hash`](Unfreeze::hash) of the unfreeze.
+    pub signatures: Vec<RecoverySignature>,
+}
+
+/// A signature from a recovery key approving an [`Unfreeze`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RecoverySignature {
+    /// The verification key of the signer.
+    pub signer: [u8; 32],
+    /// A signature over the [`hash`](Unfreeze::hash) of the unfreeze.
+    pub signature: [u8; 64],
+}
+
+impl Unfreeze {
+    /// Create a request to lift the given freeze of the custodian with the given identity key,
+    /// with no signatures.
+    pub fn new(custodian: &VerificationKey, generation: u64) -> Self {
+        Self {
+            custodian: custodian.to_bytes(),
+            generation,
+            signatures: Vec::new(),
+        }
+    }
+
+    /// The hash signed by the recovery keys to lift the freeze.
+    pub fn hash(&self) -> [u8; 32] {
+        blake2b_simd::Params::default()
+            .personal(b"Penumbra_Unfreez")
+            .hash_length(32)
+            .to_state()
+            .update(&self.custodian)
+            .update(&self.generation.to_le_bytes())
+            .finalize()
+            .as_bytes()
+            .try_into()
+            .expect("hash length is 32 bytes")
+    }
+
+    /// Add a signature approving the unfreeze.
+    pub fn sign(&mut self, signing_key: &SigningKey) {
+        self.signatures.push(RecoverySignature {
+            signer: signing_key.verification_key().to_bytes(),
+            signature: signing_key.sign(&self.hash()).to_bytes(),
+        });
+    }
+}
+
+/// The reason an [`Unfreeze`] was refused by a custodian.
+#[derive(Clone, Debug, Eq, PartialEq, thiserror::Error)]
+pub enum UnfreezeRejection {
+    /// The custodian has no recovery keys configured, so it can't be unfrozen remotely.
+    #[error("no recovery keys are configured")]
+    NoRecoveryKeys,
+    /// The custodian is not frozen.
+    #[error("custodian is not frozen")]
+    NotFrozen,
+    /// The unfreeze is for another custodian.
+    #[error("unfreeze is for custodian {custodian}, not this custodian")]
+    WrongCustodian {
+        /// The hex-encoded identity key of the custodian named by the unfreeze.
+        custodian: String,
+    },
+    /// The unfreeze names a different freeze than the current one.
+    #[error(
+        "unfreeze is for generation {generation}, but the current freeze is generation {current}"
+    )]
+    WrongGeneration {
+        /// The generation named by the unfreeze.
+        generation: u64,
+        /// The generation of the current freeze.
+        current: u64,
+    },
+    /// Fewer valid signatures were provided than required.
+    #[error("required {required} recovery signatures but only saw {seen}")]
+    InsufficientSignatures {
+        /// The number of signatures required.
+        required: u32,
+        /// The number of valid signatures from distinct recovery keys.
+        seen: u32,
+    },
+    /// A signature from a recovery key failed to verify.
+    #[error("invalid recovery signature from signer {signer}")]
+    InvalidSignature {
+        /// The hex-encoded verification key of the signer.
+        signer: String,
+    },
+}
+
+/// The persisted state of a [`FreezeSwitch`].
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+struct State {
+    /// The number of times the custodian has been frozen.
+    generation: u64,
+    /// The current freeze, if the custodian is frozen.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    freeze: Option<Freeze>,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+struct Freeze {
+    /// Why the custodian was frozen.
+    reason: String,
+    /// The time the custodian was frozen, in seconds since the Unix epoch.
+    timestamp: u64,
+}
+
+/// A persistent flag which, while set, refuses every authorization request.
+#[derive(Debug)]
+pub struct FreezeSwitch {
+    /// The keys allowed to unfreeze the custodian, if any.
+    recovery_signers: Option<PolicySigners>,
+    state: Mutex<State>,
+    /// The file the switch is persisted to, if any.
+    path: Option<PathBuf>,
+}
+
+impl FreezeSwitch {
+    /// Create an unfrozen switch which is only kept in memory.
+    pub fn in_memory(recovery_signers: Option<PolicySigners>) -> Self {
+        Self {
+            recovery_signers,
+            state: Mutex::new(State::default()),
+            path: None,
+        }
+    }
+
+    /// Open the switch persisted at the given path, creating it unfrozen if it doesn't exist.
+    ///
+    /// Without recovery keys, a persisted freeze can only be lifted by deleting the file.
+    pub fn open(
+        path: impl AsRef<Path>,
+        recovery_signers: Option<PolicySigners>,
+    ) -> anyhow::Result<Self> {
+        let path = path.as_ref();
+        let state: State = state_file::load(path, "freeze")?.unwrap_or_default();
+        if let Some(freeze) = &state.freeze {
+            tracing::warn!(
+                generation = state.generation,
+                reason = %freeze.reason,
+                "custodian is frozen"
+            );
+        }
+
+        let switch = Self {
+            recovery_signers,
+            state: Mutex::new(state),
+            path: Some(path.to_owned()),
+        };
+        switch.persist(&switch.state.lock().expect("freeze lock is not poisoned"))?;
+        Ok(switch)
+    }
+
+    /// Open the switch persisted at the given path, if any, or create one in memory.
+    pub fn from_config(
+        path: Option<&Path>,
+        recovery_signers: Option<PolicySigners>,
+    ) -> anyhow::Result<Self> {
+        match path {
+            Some(path) => Self::open(path, recovery_signers),
+            None => Ok(Self::in_memory(recovery_signers)),
+        }
+    }
+
+    /// Check that the custodian is not frozen.
+    pub fn check(&self) -> Result<(), Frozen> {
+        let state = self.state.lock().expect("freeze lock is not poisoned");
+        match &state.freeze {
+            Some(freeze) => Err(Frozen {
+                generation: state.generation,
+                reason: freeze.reason.clone(),
+            }),
+            None => Ok(()),
+        }
+    }
+
+    /// Freeze the custodian, returning the generation of the freeze.
+    ///
+    /// If the custodian is already frozen, this returns the generation of the existing freeze.
+    /// The freeze takes effect immediately, even if it can't be persisted, in which case an error
+    /// is returned and the freeze only lasts until the custodian restarts.
+    pub fn freeze(&self, reason: String) -> anyhow::Result<u64> {
+        let mut state = self.state.lock().expect("freeze lock is not poisoned");
+        if state.freeze.is_none() {
+            state.generation += 1;
+            tracing::warn!(generation = state.generation, %reason, "freezing custodian");
+            state.freeze = Some(Freeze {
+                reason,
+                timestamp: unix_timestamp(),
+            });
+            self.persist(&state)?;
+        }
+        Ok(state.generation)
+    }
+
+    /// Lift the current freeze of the custodian with the given identity key, if the unfreeze is
+    /// approved by enough recovery keys.
+    pub fn unfreeze(&self, unfreeze: &Unfreeze, custodian: &VerificationKey) -> anyhow::Result<()> {
+        let signers = self
+            .recovery_signers
+            .as_ref()
+            .ok_or(UnfreezeRejection::NoRecoveryKeys)?;
+        if unfreeze.custodian != custodian.to_bytes() {
+            return Err(UnfreezeRejection::WrongCustodian {
+                custodian: hex::encode(unfreeze.custodian),
+            }
+            .into());
+        }
+
+        let mut state = self.state.lock().expect("freeze lock is not poisoned");
+        if state.freeze.is_none() {
+            return Err(UnfreezeRejection::NotFrozen.into());
+        }
+        if unfreeze.generation != state.generation {
+            return Err(UnfreezeRejection::WrongGeneration {
+                generation: unfreeze.generation,
+                current: state.generation,
+            }
+            .into());
+        }
+
+        let seen = signers
+            .count_signatures(
+                &unfreeze.hash(),
+                unfreeze
+                    .signatures
+                    .iter()
+                    .map(|signature| (&signature.signer, &signature.signature)),
+            )
+            .map_err(|signer| UnfreezeRejection::InvalidSignature { signer })?;
+        // Unfreezing always requires at least one signature, even if none are configured.
+        let required = signers.required_signatures.max(1);
+        if seen < required {
+            return Err(UnfreezeRejection::InsufficientSignatures { required, seen }.into());
+        }
+
+        // Only lift the freeze once the change is persisted, so that a restart can't revive it.
+        let mut unfrozen = state.clone();
+        unfrozen.freeze = None;
+        self.persist(&unfrozen)?;
+        *state = unfrozen;
+        tracing::info!(generation = state.generation, "unfroze custodian");
+        Ok(())
+    }
+
+    /// Write the state out to the file, if the switch is persisted.
+    fn persist(&self, state: &State) -> anyhow::Result<()> {
+        match &self.path {
+            Some(path) => state_file::store(path, "freeze", state),
+            None => Ok(()),
+        }
+    }
+}
+
+impl TryFrom<pb::UnfreezeRequest> for Unfreeze {
+    type Error = anyhow::Error;
+
+    fn try_from(request: pb::UnfreezeRequest) -> Result<Self, Self::Error> {
+        Ok(Unfreeze {
+            custodian: request.custodian.as_slice().try_into().map_err(|_| {
+                anyhow!(
+                    "custodian must be 32 bytes, got {}",
+                    request.custodian.len()
+                )
+            })?,
+            generation: request.generation,
+            signatures: request
+                .signatures
+                .into_iter()
+                .map(TryInto::try_into)
+                .collect::<anyhow::Result<_>>()?,
+        })
+    }
+}
+
+impl From<Unfreeze> for pb::UnfreezeRequest {
+    fn from(unfreeze: Unfreeze) -> Self {
+        pb::UnfreezeRequest {
+            custodian: unfreeze.custodian.to_vec(),
+            generation: unfreeze.generation,
+            signatures: unfreeze.signatures.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+impl TryFrom<pb::RecoverySignature> for RecoverySignature {
+    type Error = anyhow::Error;
+
+    fn try_from(signature: pb::RecoverySignature) -> Result<Self, Self::Error> {
+        Ok(RecoverySignature {
+            signer: signature.signer.as_slice().try_into().map_err(|_| {
+                anyhow!(
+                    "recovery signer must be 32 bytes, got {}",
+                    signature.signer.len()
+                )
+            })?,
+            signature: signature.signature.as_slice().try_into().map_err(|_| {
+                anyhow!(
+                    "recovery signature must be 64 bytes, got {}",
+                    signature.signature.len()
+                )
+            })?,
+        })
+    }
+}
+
+impl From<RecoverySignature> for pb::RecoverySignature {
+    fn from(signature: RecoverySignature) -> Self {
+        pb::RecoverySignature {
+            signer: signature.signer.to_vec(),
+            signature: signature.signature.to_vec(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand_core::OsRng;
+
+    use super::*;
+
+    #[test]
+    fn unfreezing_requires_recovery_quorum() -> anyhow::Result<()> {
+        let custodian = SigningKey::new(OsRng).verification_key();
+        let alice = SigningKey::new(OsRng);
+        let bob = SigningKey::new(OsRng);
+        let signers = PolicySigners {
+            required_signatures: 2,
+            allowed_signers: vec![alice.verification_key(), bob.verification_key()],
+        };
+        let dir = tempfile::tempdir()?;
+        let path = dir.path().join("freeze.json");
+
+        let switch = FreezeSwitch::open(&path, Some(signers.clone()))?;
+        assert!(switch.check().is_ok());
+        assert_eq!(switch.freeze("suspected compromise".to_string())?, 1);
+        // Freezing again doesn't start a new freeze.
+        assert_eq!(switch.freeze("again".to_string())?, 1);
+        assert!(switch.check().is_err());
+
+        // The freeze survives a restart.
+        let switch = FreezeSwitch::open(&path, Some(signers.clone()))?;
+        assert_eq!(
+            switch.check(),
+            Err(Frozen {
+                generation: 1,
+                reason: "suspected compromise".to_string()
+            })
+        );
+
+        let mut unfreeze = Unfreeze::new(&custodian, 1);
+        unfreeze.sign(&alice);
+        let rejection = switch.unfreeze(&unfreeze, &custodian).unwrap_err();
+        assert_eq!(
+            rejection.downcast_ref(),
+            Some(&UnfreezeRejection::InsufficientSignatures {
+                required: 2,
+                seen: 1
+            })
+        );
+
+        unfreeze.sign(&bob);
+        switch.unfreeze(&unfreeze, &custodian)?;
+        assert!(switch.check().is_ok());
+
+        // The unfreeze survives a restart.
+        let switch = FreezeSwitch::open(&path, Some(signers))?;
+        assert!(switch.check().is_ok());
+
+        // The signatures can't be replayed to lift a later freeze.
+        assert_eq!(switch.freeze("compromised again".to_string())?, 2);
+        let rejection = switch.unfreeze(&unfreeze, &custodian).unwrap_err();
+        assert_eq!(
+            rejection.downcast_ref(),
+            Some(&UnfreezeRejection::WrongGeneration {
+                generation: 1,
+                current: 2
+            })
+        );
+        assert!(switch.check().is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn unfreezing_is_bound_to_the_custodian() -> anyhow::Result<()> {
+        let (first, second) = (
+            SigningKey::new(OsRng).verification_key(),
+            SigningKey::new(OsRng).verification_key(),
+        );
+        let recovery_key = SigningKey::new(OsRng);
+        let signers = PolicySigners {
+            required_signatures: 1,
+            allowed_signers: vec![recovery_key.verification_key()],
+        };
+        let (first_dir, second_dir) = (tempfile::tempdir()?, tempfile::tempdir()?);
+        let first_path = first_dir.path().join("freeze.json");
+        let first_switch = FreezeSwitch::from_config(Some(&first_path), Some(signers.clone()))?;
+        let second_switch = FreezeSwitch::from_config(
+            Some(&second_dir.path().join("freeze.json")),
+            Some(signers.clone()),
+        )?;
+
+        // Two custodians share recovery keys, and are frozen at the same generation, but an
+        // unfreeze for the first one can't be replayed against the second.
+        first_switch.freeze("suspected compromise".to_string())?;
+        second_switch.freeze("suspected compromise".to_string())?;
+        let mut unfreeze = Unfreeze::new(&first, 1);
+        unfreeze.sign(&recovery_key);
+        let rejection = second_switch.unfreeze(&unfreeze, &second).unwrap_err();
+        assert_eq!(
+            rejection.downcast_ref(),
+            Some(&UnfreezeRejection::WrongCustodian {
+                custodian: hex::encode(first.to_bytes())
+            })
+        );
+        assert!(second_switch.check().is_err());
+
+        // Changing the custodian named by the unfreeze invalidates the signature.
+        let mut redirected = unfreeze.clone();
+        redirected.custodian = second.to_bytes();
+        assert!(second_switch.unfreeze(&redirected, &second).is_err());
+        assert!(second_switch.check().is_err());
+
+        first_switch.unfreeze(&unfreeze, &first)?;
+        assert!(first_switch.check().is_ok());
+
+        // Only a switch without a file forgets its freeze when the custodian restarts.
+        first_switch.freeze("compromised again".to_string())?;
+        assert!(
+            FreezeSwitch::from_config(Some(&first_path), Some(signers.clone()))?
+                .check()
+                .is_err()
+        );
+        let in_memory = FreezeSwitch::from_config(None, Some(signers.clone()))?;
+        in_memory.freeze("suspected compromise".to_string())?;
+        assert!(FreezeSwitch::from_config(None, Some(signers))?
+            .check()
+            .is_ok());
+        Ok(())
+    }
+}
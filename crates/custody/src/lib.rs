@@ -3,6 +3,15 @@
 //! This crate currently focuses on the [`soft_kms`] implementation, a basic
 //! software key management system that can perform basic policy-based
 //! authorization or blind signing.
+//!
+//! The gRPC custody services and the gRPC client are behind the default `rpc`
+//! feature.  Without it, the crate builds for `wasm32`, so that browser
+//! extensions can use the soft signer and the authorization policies, and talk
+//! to custodians over a [`CustodyChannel`] of their choosing.
+//!
+//! The `remote` feature adds a [`CustodyChannel`] to a remote signer over
+//! mutually-authenticated TLS, and the `penumbra-remote-signer` daemon serving
+//! a soft KMS over it.
 
 #![deny(clippy::unwrap_used)]
 // Requires nightly.
@@ -11,14 +20,36 @@
 extern crate serde_with;
 
 mod client;
+mod message;
 mod pre_auth;
 mod request;
+mod simulation;
+mod state_file;
+#[cfg(feature = "rpc")]
+mod status;
 
+pub mod audit;
+pub mod encrypted;
+pub mod freeze;
+#[cfg(feature = "rpc")]
 pub mod null_kms;
+#[cfg(feature = "pkcs11")]
+pub mod pkcs11;
+pub mod plan_summary;
+pub mod plan_text;
 pub mod policy;
+#[cfg(feature = "remote")]
+pub mod remote;
+pub mod replay;
+pub mod rotation;
 pub mod soft_kms;
+#[cfg(feature = "rpc")]
 pub mod threshold;
 
-pub use client::CustodyClient;
-pub use pre_auth::PreAuthorization;
+pub use client::{ChannelClient, CustodyChannel, CustodyClient, CustodyFuture, Unimplemented};
+pub use message::AuthorizeMessageRequest;
+pub use plan_summary::PlanSummary;
+pub use plan_text::PlanText;
+pub use pre_auth::{Approver, PreAuthorization};
 pub use request::AuthorizeRequest;
+pub use simulation::{AuthorizeSimulation, SimulationCheck};
@@ -1,7 +1,10 @@
 //! A basic software key management system that stores keys in memory but
 //! presents as an asynchronous signer.
 
+use std::pin::Pin;
+
 use penumbra_proto::custody::v1::{self as pb};
+use penumbra_proto::error::{ErrorDetail, ViewOnly};
 use tonic::{async_trait, Request, Response, Status};
 
 /// A "null KMS" that has no keys and errors on any requests.
@@ -10,22 +13,123 @@ use tonic::{async_trait, Request, Response, Status};
 #[derive(Debug, Default)]
 pub struct NullKms {}
 
+/// A status refusing a request because the null KMS holds no keys.
+fn view_only(message: &str) -> Status {
+    ErrorDetail::from(ViewOnly {}).into_status(message)
+}
+
 #[async_trait]
 impl pb::custody_service_server::CustodyService for NullKms {
+    type AuthorizeStreamStream =
+        Pin<Box<dyn futures::Stream<Item = Result<pb::AuthorizeStreamResponse, Status>> + Send>>;
+
     async fn authorize(
         &self,
         _request: Request<pb::AuthorizeRequest>,
     ) -> Result<Response<pb::AuthorizeResponse>, Status> {
-        Err(tonic::Status::failed_precondition(
+        Err(view_only(
+            "Got authorization request in view-only mode to null KMS.",
+        ))
+    }
+
+    async fn authorize_stream(
+        &self,
+        _request: Request<pb::AuthorizeRequest>,
+    ) -> Result<Response<Self::AuthorizeStreamStream>, Status> {
+        Err(view_only(
+            "Got authorization request in view-only mode to null KMS.",
+        ))
+    }
+
+    async fn simulate_authorization(
+        &self,
+        _request: Request<pb::AuthorizeRequest>,
+    ) -> Result<Response<pb::SimulateAuthorizationResponse>, Status> {
+        Err(view_only(
             "Got authorization request in view-only mode to null KMS.",
         ))
     }
 
+    async fn authorize_message(
+        &self,
+        _request: Request<pb::AuthorizeMessageRequest>,
+    ) -> Result<Response<pb::AuthorizeMessageResponse>, Status> {
+        Err(view_only(
+            "Got message authorization request in view-only mode to null KMS.",
+        ))
+    }
+
+    async fn challenge(
+        &self,
+        _request: Request<pb::ChallengeRequest>,
+    ) -> Result<Response<pb::ChallengeResponse>, Status> {
+        // Clients treat custodians which don't issue challenges as not
+        // requiring nonces, so that they go on to make the authorization
+        // request, which is refused as view-only.
+        Err(Status::unimplemented(
+            "null KMS does not issue challenges in view-only mode",
+        ))
+    }
+
+    async fn query_audit_log(
+        &self,
+        _request: Request<pb::QueryAuditLogRequest>,
+    ) -> Result<Response<pb::QueryAuditLogResponse>, Status> {
+        Err(view_only(
+            "Got audit log query in view-only mode to null KMS.",
+        ))
+    }
+
+    async fn freeze(
+        &self,
+        _request: Request<pb::FreezeRequest>,
+    ) -> Result<Response<pb::FreezeResponse>, Status> {
+        Err(view_only(
+            "Got freeze request in view-only mode to null KMS.",
+        ))
+    }
+
+    async fn unfreeze(
+        &self,
+        _request: Request<pb::UnfreezeRequest>,
+    ) -> Result<Response<pb::UnfreezeResponse>, Status> {
+        Err(view_only(
+            "Got unfreeze request in view-only mode to null KMS.",
+        ))
+    }
+
+    async fn rotate_authorization_key(
+        &self,
+        _request: Request<pb::RotateAuthorizationKeyRequest>,
+    ) -> Result<Response<pb::RotateAuthorizationKeyResponse>, Status> {
+        Err(view_only(
+            "Got key rotation request in view-only mode to null KMS.",
+        ))
+    }
+
+    async fn export_policy(
+        &self,
+        _request: Request<pb::ExportPolicyRequest>,
+    ) -> Result<Response<pb::ExportPolicyResponse>, Status> {
+        Err(view_only(
+            "Got policy export request in view-only mode to null KMS.",
+        ))
+    }
+
+    async fn import_policy(
+        &self,
+        _request: Request<pb::ImportPolicyRequest>,
+    ) -> Result<Response<pb::ImportPolicyResponse>, Status> {
+        Err(view_only(
+            "Got policy import request in view-only mode to null KMS.",
+        ))
+    }
+
     async fn export_full_viewing_key(
         &self,
         _request: Request<pb::ExportFullViewingKeyRequest>,
     ) -> Result<Response<pb::ExportFullViewingKeyResponse>, Status> {
-        Err(tonic::Status::failed_precondition(
+        Err(view_only(
             "Got authorization request in view-only mode to null KMS.",
         ))
     }
@@ -34,7 +138,7 @@ impl pb::custody_service_server::CustodyService for NullKms {
         &self,
         _request: Request<pb::ConfirmAddressRequest>,
     ) -> Result<Response<pb::ConfirmAddressResponse>, Status> {
-        Err(tonic::Status::failed_precondition(
+        Err(view_only(
             "Got authorization request in view-only mode to null KMS.",
         ))
     }
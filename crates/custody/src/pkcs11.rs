@@ -0,0 +1,631 @@
+//! A custody backend that keeps the spend key in a PKCS#11 hardware security module.
+//!
+//! PKCS#11 has no mechanisms for `decaf377-rdsa`, so the HSM can't sign with the spend key itself.
+//! Instead, the spend key is stored in the HSM wrapped by an AES key which never leaves it, using
+//! only standard mechanisms described by [`Pkcs11Token`]. To sign, the custodian has the HSM
+//! unwrap the spend key, signs in software, and drops the unwrapped key, so that it's only kept in
+//! host memory for the duration of a signing request. This is weaker than signing in the HSM, but
+//! works with any HSM. Since the wrapped key is kept in the HSM, the [`Config`] can be recovered
+//! from it alone.
+//!
+//! Authorization requests are checked against the configured policies, protected against replays,
+//! refused while the custodian is frozen or its authorization key is overdue for rotation, and
+//! recorded in an audit log, in the same way as by the
+//! [`SoftKms`](crate::soft_kms::SoftKms).
+
+use std::{pin::Pin, sync::Arc};
+
+use anyhow::Result;
+use ed25519_consensus::SigningKey;
+use futures::{stream, StreamExt};
+use penumbra_keys::keys::SpendKey;
+use penumbra_proto::custody::v1 as pb;
+use penumbra_transaction::{AuthorizationData, TransactionPlan};
+use rand_core::OsRng;
+use tonic::{async_trait, Request, Response, Status};
+
+use crate::{
+    audit::{AuditLog, Decision},
+    freeze::FreezeSwitch,
+    plan_text,
+    policy::{PolicyEngine, SpendLedger},
+    replay::ReplayGuard,
+    rotation::RotationSwitch,
+    soft_kms::identity_key,
+    status::authorization_status,
+    AuthorizeRequest, PlanSummary,
+};
+
+mod config;
+mod token;
+
+pub use config::{Config, TokenConfig};
+pub use token::{HsmError, Pkcs11Token, Token};
+
+/// A custody backend that signs with a spend key wrapped by a PKCS#11 HSM.
+pub struct Pkcs11Kms<T = Pkcs11Token> {
+    config: Config,
+    token: T,
+    identity_key: SigningKey,
+    policy: PolicyEngine,
+    replay: ReplayGuard,
+    audit_log: AuditLog,
+    freeze: FreezeSwitch,
+    rotation: RotationSwitch,
+}
+
+impl Pkcs11Kms<Pkcs11Token> {
+    /// Generate a new spend key wrapped by the HSM, returning the
+    /// configuration of a custodian using it.
+    ///
+    /// This fails rather than replacing an existing key with the same label.
+    pub fn initialize(token_config: TokenConfig) -> Result<Config> {
+        let token = Pkcs11Token::open(&token_config)?;
+        Ok(Config::generate(OsRng, token_config, &token)?)
+    }
+
+    /// Recover the configuration of a custodian from the spend key already
+    /// wrapped by the HSM.
+    pub fn recover(token_config: TokenConfig) -> Result<Config> {
+        let token = Pkcs11Token::open(&token_config)?;
+        Ok(Config::recover(token_config, &token)?)
+    }
+
+    /// Initialize with the given [`Config`], logging in to the HSM it describes.
+    pub fn new(config: Config) -> Result<Self> {
+        let token = Pkcs11Token::open(&config.token)?;
+        Self::with_token(config, token)
+    }
+}
+
+impl<T: Token> Pkcs11Kms<T> {
+    /// Initialize with the given [`Config`], signing with the spend key
+    /// wrapped by the given token.
+    ///
+    /// Fails if the token doesn't hold the spend key of the configured full
+    /// viewing key.
+    pub fn with_token(config: Config, token: T) -> Result<Self> {
+        let spend_key = Self::unwrap_spend_key(&config, &token)?;
+        let identity_key = identity_key(&spend_key);
+
+        let ledger = match &config.spend_ledger {
+            Some(path) => SpendLedger::open(path)?,
+            None => SpendLedger::in_memory(),
+        };
+        let policy = PolicyEngine::new(config.fvk.clone(), config.auth_policy.clone())
+            .with_ledger(Arc::new(ledger));
+        let replay = ReplayGuard::new(config.require_nonce, config.reject_duplicate_effect_hashes);
+        let audit_log = match &config.audit_log {
+            Some(path) => AuditLog::open(path, identity_key.clone())?,
+            None => AuditLog::in_memory(identity_key.clone()),
+        };
+        let freeze = FreezeSwitch::from_config(
+            config.freeze_state.as_deref(),
+            config.recovery_signers.clone(),
+        )?;
+        let rotation = RotationSwitch::from_config(config.rotation.as_ref())?;
+        Ok(Self {
+            config,
+            token,
+            identity_key,
+            policy,
+            replay,
+            audit_log,
+            freeze,
+            rotation,
+        })
+    }
+
+    /// Record decisions in the given audit log, rather than an in-memory one.
+    ///
+    /// The log should be signed by [`Pkcs11Kms::identity_key`].
+    pub fn with_audit_log(mut self, audit_log: AuditLog) -> Self {
+        self.audit_log = audit_log;
+        self
+    }
+
+    /// Use the given freeze switch, rather than the configured one.
+    ///
+    /// The switch should be opened with the configured `recovery_signers`.
+    pub fn with_freeze_switch(mut self, freeze: FreezeSwitch) -> Self {
+        self.freeze = freeze;
+        self
+    }
+
+    /// The identity key used to sign the audit log, derived from the spend key
+    /// as by the [`SoftKms`](crate::soft_kms::SoftKms).
+    pub fn identity_key(&self) -> SigningKey {
+        self.identity_key.clone()
+    }
+
+    /// Attempt to authorize the requested [`TransactionPlan`].
+    #[tracing::instrument(skip(self, request), name = "pkcs11_sign")]
+    pub fn sign(&self, request: &AuthorizeRequest) -> Result<AuthorizationData> {
+        tracing::debug!(?request.plan);
+
+        self.decide(request, || self.authorize(&request.plan))
+    }
+
+    /// Decide whether to authorize the request and, if so, sign it with `sign`,
+    /// recording the outcome in the audit log.
+    ///
+    /// A request which is approved but can't be signed is recorded as denied.
+    /// If the outcome can't be recorded, the request is not authorized.
+    fn decide<R>(&self, request: &AuthorizeRequest, sign: impl FnOnce() -> Result<R>) -> Result<R> {
+        let (policy_denial, approvers, decision) = {
+            // Hold the ledger until the request is recorded in it, so that concurrent
+            // requests can't each stay under a daily limit they exceed together.
+            let mut ledger = self.policy.ledger().lock();
+            let policy_result = self.policy.evaluate_locked(request, &ledger);
+            plan_text::audit_decision(
+                &request.plan,
+                policy_result
+                    .as_ref()
+                    .err()
+                    .map(|e| e as &dyn std::fmt::Display),
+            );
+            let policy_denial = policy_result.as_ref().err().map(ToString::to_string);
+            let approvers = policy_result.clone().unwrap_or_default();
+            let decision = self
+                .freeze
+                .check()
+                .map_err(anyhow::Error::from)
+                .and_then(|()| self.rotation.check().map_err(anyhow::Error::from))
+                .and_then(|()| policy_result.map(drop).map_err(anyhow::Error::from))
+                .and_then(|()| self.check_replay(request))
+                .and_then(|()| self.policy.record(request, &mut ledger));
+            (policy_denial, approvers, decision)
+        };
+        let outcome = decision.and_then(|()| sign());
+        self.audit_log
+            .record(request, policy_denial, approvers, Decision::from(&outcome))?;
+        outcome
+    }
+
+    /// Check that the request is not a replay of an earlier one, consuming its nonce.
+    fn check_replay(&self, request: &AuthorizeRequest) -> Result<()> {
+        let effect_hash = request.plan.effect_hash(&self.config.fvk)?;
+        self.replay.check(request.nonce.as_ref(), &effect_hash)?;
+        Ok(())
+    }
+
+    /// Have the HSM unwrap the spend key, and sign every spend and delegator
+    /// vote in the plan with it.
+    fn authorize(&self, plan: &TransactionPlan) -> Result<AuthorizationData> {
+        let spend_key = Self::unwrap_spend_key(&self.config, &self.token)?;
+        plan.authorize(OsRng, &spend_key)
+    }
+
+    /// Have the token unwrap the spend key, checking that it's the key of the
+    /// configured full viewing key, so that a faulty HSM can't cause us to
+    /// sign with another key.
+    fn unwrap_spend_key(config: &Config, token: &T) -> Result<SpendKey> {
+        let spend_key = token.unwrap_spend_key()?;
+        if spend_key.full_viewing_key() != &config.fvk {
+            return Err(HsmError::InvalidResponse("spend key").into());
+        }
+        Ok(spend_key)
+    }
+}
+
+/// Map an authorization failure to a status, distinguishing HSM failures,
+/// which may be transient, from policy denials, replays, freezes and overdue
+/// rotations.
+fn error_status(error: anyhow::Error) -> Status {
+    if let Some(hsm_error) = error.downcast_ref::<HsmError>() {
+        hsm_error.clone().into()
+    } else {
+        authorization_status(error, tonic::Code::Internal)
+    }
+}
+
+#[async_trait]
+impl<T: Token + 'static> pb::custody_service_server::CustodyService for Pkcs11Kms<T> {
+    type AuthorizeStreamStream =
+        Pin<Box<dyn futures::Stream<Item = Result<pb::AuthorizeStreamResponse, Status>> + Send>>;
+
+    async fn authorize(
+        &self,
+        request: Request<pb::AuthorizeRequest>,
+    ) -> Result<Response<pb::AuthorizeResponse>, Status> {
+        let request: AuthorizeRequest = request
+            .into_inner()
+            .try_into()
+            .map_err(|e: anyhow::Error| Status::invalid_argument(e.to_string()))?;
+
+        let authorization_data = self.sign(&request).map_err(error_status)?;
+
+        Ok(Response::new(pb::AuthorizeResponse {
+            data: Some(authorization_data.into()),
+            summary: Some(PlanSummary::new(&request.plan, &self.config.fvk).into()),
+        }))
+    }
+
+    async fn simulate_authorization(
+        &self,
+        _request: Request<pb::AuthorizeRequest>,
+    ) -> Result<Response<pb::SimulateAuthorizationResponse>, Status> {
+        Err(Status::unimplemented(
+            "the PKCS#11 custodian can't simulate authorization requests",
+        ))
+    }
+
+    async fn authorize_stream(
+        &self,
+        request: Request<pb::AuthorizeRequest>,
+    ) -> Result<Response<Self::AuthorizeStreamStream>, Status> {
+        use pb::authorize_stream_response::{Approved, Denied, Status as AuthorizeStatus};
+
+        let request: AuthorizeRequest = request
+            .into_inner()
+            .try_into()
+            .map_err(|e: anyhow::Error| Status::invalid_argument(e.to_string()))?;
+
+        // Signing with the HSM is non-interactive, so the only update we ever
+        // send is the final decision. HSM failures are reported as errors
+        // rather than denials, since retrying may succeed.
+        let status = match self.sign(&request) {
+            Ok(authorization_data) => AuthorizeStatus::Approved(Approved {
+                data: Some(authorization_data.into()),
+            }),
+            Err(e) if e.downcast_ref::<HsmError>().is_some() => return Err(error_status(e)),
+            Err(e) => AuthorizeStatus::Denied(Denied {
+                reason: format!("{e:#}"),
+            }),
+        };
+
+        let response = pb::AuthorizeStreamResponse {
+            status: Some(status),
+        };
+
+        Ok(Response::new(
+            stream::once(async move { Ok(response) }).boxed(),
+        ))
+    }
+
+    async fn authorize_message(
+        &self,
+        _request: Request<pb::AuthorizeMessageRequest>,
+    ) -> Result<Response<pb::AuthorizeMessageResponse>, Status> {
+        Err(Status::unimplemented(
+            "message signing is not supported by the PKCS#11 custodian, whose tokens are only used to authorize transaction plans",
+        ))
+    }
+
+    async fn challenge(
+        &self,
+        _request: Request<pb::ChallengeRequest>,
+    ) -> Result<Response<pb::ChallengeResponse>, Status> {
+        Ok(Response::new(pb::ChallengeResponse {
+            nonce: self.replay.challenge().0.to_vec(),
+        }))
+    }
+
+    async fn query_audit_log(
+        &self,
+        request: Request<pb::QueryAuditLogRequest>,
+    ) -> Result<Response<pb::QueryAuditLogResponse>, Status> {
+        let query = request.into_inner().into();
+        Ok(Response::new(self.audit_log.query(&query).into()))
+    }
+
+    async fn freeze(
+        &self,
+        request: Request<pb::FreezeRequest>,
+    ) -> Result<Response<pb::FreezeResponse>, Status> {
+        let generation = self
+            .freeze
+            .freeze(request.into_inner().reason)
+            .map_err(|e| Status::internal(format!("{e:#}")))?;
+        Ok(Response::new(pb::FreezeResponse { generation }))
+    }
+
+    async fn unfreeze(
+        &self,
+        request: Request<pb::UnfreezeRequest>,
+    ) -> Result<Response<pb::UnfreezeResponse>, Status> {
+        let unfreeze = request
+            .into_inner()
+            .try_into()
+            .map_err(|e: anyhow::Error| Status::invalid_argument(e.to_string()))?;
+        self.freeze
+            .unfreeze(&unfreeze, &self.identity_key().verification_key())
+            .map_err(|e| authorization_status(e, tonic::Code::Internal))?;
+        Ok(Response::new(pb::UnfreezeResponse {}))
+    }
+
+    async fn rotate_authorization_key(
+        &self,
+        request: Request<pb::RotateAuthorizationKeyRequest>,
+    ) -> Result<Response<pb::RotateAuthorizationKeyResponse>, Status> {
+        let rotation = request
+            .into_inner()
+            .try_into()
+            .map_err(|e: anyhow::Error| Status::invalid_argument(e.to_string()))?;
+        let signed = self
+            .rotation
+            .rotate(&rotation, &self.identity_key())
+            .map_err(|e| authorization_status(e, tonic::Code::Internal))?;
+        Ok(Response::new(signed.into()))
+    }
+
+    async fn export_policy(
+        &self,
+        _request: Request<pb::ExportPolicyRequest>,
+    ) -> Result<Response<pb::ExportPolicyResponse>, Status> {
+        Err(Status::unimplemented(
+            "policy bundles are not supported by the PKCS#11 custodian, whose policy is only read from its configuration",
+        ))
+    }
+
+    async fn import_policy(
+        &self,
+        _request: Request<pb::ImportPolicyRequest>,
+    ) -> Result<Response<pb::ImportPolicyResponse>, Status> {
+        Err(Status::unimplemented(
+            "policy bundles are not supported by the PKCS#11 custodian, whose policy is only read from its configuration",
+        ))
+    }
+
+    async fn export_full_viewing_key(
+        &self,
+        _request: Request<pb::ExportFullViewingKeyRequest>,
+    ) -> Result<Response<pb::ExportFullViewingKeyResponse>, Status> {
+        Ok(Response::new(pb::ExportFullViewingKeyResponse {
+            full_viewing_key: Some(self.config.fvk.clone().into()),
+        }))
+    }
+
+    async fn confirm_address(
+        &self,
+        request: Request<pb::ConfirmAddressRequest>,
+    ) -> Result<Response<pb::ConfirmAddressResponse>, Status> {
+        let address_index = request
+            .into_inner()
+            .address_index
+            .ok_or_else(|| {
+                Status::invalid_argument("missing address index in confirm address request")
+            })?
+            .try_into()
+            .map_err(|e| {
+                Status::invalid_argument(format!(
+                    "invalid address index in confirm address request: {e:#}"
+                ))
+            })?;
+
+        let (address, _dtk) = self.config.fvk.payment_address(address_index);
+
+        Ok(Response::new(pb::ConfirmAddressResponse {
+            address: Some(address.into()),
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Mutex;
+
+    use penumbra_asset::{Value, STAKING_TOKEN_ASSET_ID};
+    use penumbra_shielded_pool::{Note, SpendPlan};
+    use rand_core::RngCore;
+
+    use super::*;
+    use crate::audit::Query;
+
+    /// A token which holds its keys in memory, standing in for an HSM.
+    ///
+    /// It "encrypts" by XORing with its wrapping key, and a faulty token
+    /// flips a bit of everything it decrypts.
+    #[derive(Default)]
+    struct SoftToken {
+        wrapping_key: Mutex<Option<[u8; 32]>>,
+        wrapped_key: Mutex<Option<Vec<u8>>>,
+        faulty: bool,
+    }
+
+    impl SoftToken {
+        fn xor(&self, data: &[u8]) -> Result<Vec<u8>, HsmError> {
+            let key = self
+                .wrapping_key
+                .lock()
+                .unwrap()
+                .ok_or_else(|| HsmError::KeyNotFound("spend".to_string()))?;
+            Ok(data
+                .iter()
+                .zip(key.iter().cycle())
+                .map(|(byte, key)| byte ^ key)
+                .collect())
+        }
+    }
+
+    impl Token for SoftToken {
+        fn generate_key(&self) -> Result<(), HsmError> {
+            let mut wrapping_key = self.wrapping_key.lock().unwrap();
+            if wrapping_key.is_some() {
+                return Err(HsmError::KeyExists("spend".to_string()));
+            }
+            let mut key = [0u8; 32];
+            OsRng.fill_bytes(&mut key);
+            *wrapping_key = Some(key);
+            Ok(())
+        }
+
+        fn encrypt(&self, plaintext: &[u8]) -> Result<Vec<u8>, HsmError> {
+            self.xor(plaintext)
+        }
+
+        fn decrypt(&self, ciphertext: &[u8]) -> Result<Vec<u8>, HsmError> {
+            let mut plaintext = self.xor(ciphertext)?;
+            if self.faulty {
+                plaintext[0] ^= 1;
+            }
+            Ok(plaintext)
+        }
+
+        fn store_wrapped_key(&self, wrapped_key: &[u8]) -> Result<(), HsmError> {
+            let mut stored = self.wrapped_key.lock().unwrap();
+            if stored.is_some() {
+                return Err(HsmError::KeyExists("spend".to_string()));
+            }
+            *stored = Some(wrapped_key.to_vec());
+            Ok(())
+        }
+
+        fn wrapped_key(&self) -> Result<Vec<u8>, HsmError> {
+            self.wrapped_key
+                .lock()
+                .unwrap()
+                .clone()
+                .ok_or_else(|| HsmError::KeyNotFound("spend".to_string()))
+        }
+    }
+
+    fn token_config() -> TokenConfig {
+        TokenConfig {
+            module: "/usr/lib/softhsm/libsofthsm2.so".into(),
+            token_label: "penumbra".to_string(),
+            user_pin: "1234".to_string(),
+            key_label: "spend".to_string(),
+        }
+    }
+
+    fn spend_request(config: &Config) -> AuthorizeRequest {
+        let (address, _dtk) = config.fvk.payment_address(0u32.into());
+        let note = Note::generate(
+            &mut OsRng,
+            &address,
+            Value {
+                amount: 1_000_000u64.into(),
+                asset_id: *STAKING_TOKEN_ASSET_ID,
+            },
+        );
+        let mut plan = TransactionPlan::default();
+        plan.actions
+            .push(SpendPlan::new(&mut OsRng, note, 0u64.into()).into());
+        AuthorizeRequest {
+            plan,
+            pre_authorizations: Vec::new(),
+            nonce: None,
+        }
+    }
+
+    #[test]
+    fn signs_with_key_wrapped_by_token() {
+        let token = SoftToken::default();
+        let config = Config::generate(OsRng, token_config(), &token).unwrap();
+
+        // The key can't be generated twice.
+        assert_eq!(
+            Config::generate(OsRng, token_config(), &token).unwrap_err(),
+            HsmError::KeyExists("spend".to_string())
+        );
+        // The wrapped key never appears in the clear.
+        let spend_key = token.unwrap_spend_key().unwrap();
+        assert_ne!(
+            token.wrapped_key().unwrap(),
+            spend_key.to_bytes().0.to_vec()
+        );
+
+        let kms = Pkcs11Kms::with_token(config.clone(), token).unwrap();
+        let request = spend_request(&config);
+        let data = kms.sign(&request).unwrap();
+
+        let effect_hash = request.plan.effect_hash(&config.fvk).unwrap();
+        assert_eq!(data.effect_hash, Some(effect_hash));
+        assert_eq!(data.spend_auths.len(), 1);
+        let randomizer = request.plan.spend_plans().next().unwrap().randomizer;
+        assert!(config
+            .fvk
+            .spend_verification_key()
+            .randomize(&randomizer)
+            .verify(effect_hash.as_ref(), &data.spend_auths[0])
+            .is_ok());
+    }
+
+    #[test]
+    fn rejects_mismatched_and_faulty_tokens() {
+        let token = SoftToken::default();
+        let config = Config::generate(OsRng, token_config(), &token).unwrap();
+
+        // A token holding a different key is refused at startup.
+        let other = SoftToken::default();
+        Config::generate(OsRng, token_config(), &other).unwrap();
+        assert!(Pkcs11Kms::with_token(config.clone(), other).is_err());
+
+        // A token which unwraps the key correctly at startup, but not when
+        // signing, is caught before anything is signed.
+        let kms = Pkcs11Kms::with_token(config.clone(), token).unwrap();
+        let faulty = SoftToken {
+            wrapping_key: Mutex::new(kms.token.wrapping_key.lock().unwrap().take()),
+            wrapped_key: Mutex::new(kms.token.wrapped_key.lock().unwrap().take()),
+            faulty: true,
+        };
+        let kms = Pkcs11Kms {
+            token: faulty,
+            ..kms
+        };
+        let error = kms.sign(&spend_request(&config)).unwrap_err();
+        assert_eq!(error_status(error).code(), tonic::Code::Internal);
+
+        // Since nothing was authorized, the request is recorded as denied.
+        let entries = kms.audit_log.query(&Query::default()).entries;
+        assert!(matches!(entries[0].entry.decision, Decision::Denied(_)));
+    }
+
+    #[test]
+    fn config_is_recovered_from_token() {
+        let token = SoftToken::default();
+        let config = Config::generate(OsRng, token_config(), &token).unwrap();
+        let identity_key = Pkcs11Kms::with_token(
+            config.clone(),
+            SoftToken {
+                wrapping_key: Mutex::new(*token.wrapping_key.lock().unwrap()),
+                wrapped_key: Mutex::new(token.wrapped_key.lock().unwrap().clone()),
+                faulty: false,
+            },
+        )
+        .unwrap()
+        .identity_key();
+
+        // Losing the configuration loses only its policies, not the key, or
+        // the identity signing the audit log.
+        let recovered = Config::recover(token_config(), &token).unwrap();
+        assert_eq!(recovered.fvk, config.fvk);
+        let kms = Pkcs11Kms::with_token(recovered, token).unwrap();
+        assert_eq!(
+            kms.identity_key().verification_key(),
+            identity_key.verification_key()
+        );
+
+        // There's nothing to recover from an empty token.
+        assert_eq!(
+            Config::recover(token_config(), &SoftToken::default()).unwrap_err(),
+            HsmError::KeyNotFound("spend".to_string())
+        );
+    }
+
+    #[test]
+    fn hsm_errors_map_to_status_codes() {
+        let cases = [
+            (
+                HsmError::Unavailable("removed".to_string()),
+                tonic::Code::Unavailable,
+            ),
+            (
+                HsmError::LoginFailed("locked".to_string()),
+                tonic::Code::Unauthenticated,
+            ),
+            (
+                HsmError::KeyNotFound("spend".to_string()),
+                tonic::Code::FailedPrecondition,
+            ),
+            (
+                HsmError::Unsupported("mechanism".to_string()),
+                tonic::Code::Unimplemented,
+            ),
+        ];
+        for (error, code) in cases {
+            assert_eq!(error_status(error.into()).code(), code);
+        }
+    }
+}
@@ -0,0 +1,145 @@
+use std::path::PathBuf;
+
+use penumbra_keys::{
+    keys::{SpendKey, SpendKeyBytes},
+    FullViewingKey,
+};
+use rand_core::CryptoRngCore;
+use serde::{Deserialize, Serialize};
+use serde_with::DisplayFromStr;
+use zeroize::Zeroizing;
+
+use super::{HsmError, Token};
+use crate::{
+    policy::{AuthPolicy, PolicySigners},
+    rotation::RotationConfig,
+};
+
+/// Configuration data for the [`Pkcs11Kms`](super::Pkcs11Kms).
+///
+/// The configuration holds the full viewing key, but not the spend key, which is only stored in
+/// the HSM, wrapped by a key which never leaves it. If the configuration is lost, it can be
+/// recovered from the HSM with [`Config::recover`].
+#[serde_as]
+#[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq)]
+pub struct Config {
+    #[serde_as(as = "DisplayFromStr")]
+    pub fvk: FullViewingKey,
+    #[serde(default, skip_serializing_if = "is_default")]
+    pub auth_policy: Vec<AuthPolicy>,
+    /// Whether to reject authorization requests that don't include a nonce
+    /// obtained from a prior challenge.
+    #[serde(default, skip_serializing_if = "is_default")]
+    pub require_nonce: bool,
+    /// Whether to reject requests to authorize a transaction which was
+    /// already authorized recently.  This also refuses legitimate requests to
+    /// authorize the same plan again, for instance after a failed submission.
+    #[serde(default, skip_serializing_if = "is_default")]
+    pub reject_duplicate_effect_hashes: bool,
+    /// The keys allowed to unfreeze the custodian after it was frozen; if
+    /// unset, it can't be unfrozen remotely.
+    #[serde(default, skip_serializing_if = "is_default")]
+    pub recovery_signers: Option<PolicySigners>,
+    /// The file the freeze switch is persisted to, so that a freeze survives
+    /// restarts; if unset, it is only kept in memory.  `pcli` and the remote
+    /// signer default it to a file next to their configuration.
+    #[serde(default, skip_serializing_if = "is_default")]
+    pub freeze_state: Option<PathBuf>,
+    /// The authorization key which must be rotated periodically for the
+    /// custodian to keep authorizing requests; if unset, it never has to be.
+    #[serde(default, skip_serializing_if = "is_default")]
+    pub rotation: Option<RotationConfig>,
+    /// The file the amounts sent under daily limits are recorded in, so that
+    /// the limits survive restarts; if unset, they are only kept in memory.
+    #[serde(default, skip_serializing_if = "is_default")]
+    pub spend_ledger: Option<PathBuf>,
+    /// The file the signed audit log of authorization decisions is persisted
+    /// to; if unset, it is only kept in memory.
+    #[serde(default, skip_serializing_if = "is_default")]
+    pub audit_log: Option<PathBuf>,
+    /// How to reach the wrapped spend key in the HSM.
+    pub token: TokenConfig,
+}
+
+impl Config {
+    /// Generate a new spend key, and store it in the token wrapped by a newly
+    /// generated wrapping key, returning a configuration for a custodian using
+    /// it.
+    ///
+    /// The spend key is generated in software, and only kept in memory until
+    /// it's wrapped.
+    pub fn generate(
+        mut rng: impl CryptoRngCore,
+        token_config: TokenConfig,
+        token: &impl Token,
+    ) -> Result<Self, HsmError> {
+        let mut seed = Zeroizing::new([0u8; 32]);
+        rng.fill_bytes(seed.as_mut());
+
+        token.generate_key()?;
+        token.store_wrapped_key(&token.encrypt(seed.as_ref())?)?;
+
+        let spend_key = SpendKey::from(SpendKeyBytes(*seed));
+        Ok(Self::with_defaults(
+            spend_key.full_viewing_key().clone(),
+            token_config,
+        ))
+    }
+
+    /// Recover the configuration of a custodian from the spend key wrapped in
+    /// the token, with every policy and setting at its default.
+    pub fn recover(token_config: TokenConfig, token: &impl Token) -> Result<Self, HsmError> {
+        let spend_key = token.unwrap_spend_key()?;
+        Ok(Self::with_defaults(
+            spend_key.full_viewing_key().clone(),
+            token_config,
+        ))
+    }
+
+    fn with_defaults(fvk: FullViewingKey, token_config: TokenConfig) -> Self {
+        Self {
+            fvk,
+            auth_policy: Default::default(),
+            require_nonce: false,
+            reject_duplicate_effect_hashes: false,
+            recovery_signers: None,
+            freeze_state: None,
+            rotation: None,
+            spend_ledger: None,
+            audit_log: None,
+            token: token_config,
+        }
+    }
+}
+
+/// How to reach a spend key wrapped by a PKCS#11 token.
+#[derive(Serialize, Deserialize, Clone, Eq, PartialEq)]
+pub struct TokenConfig {
+    /// The path to the PKCS#11 module provided by the HSM vendor.
+    pub module: PathBuf,
+    /// The label of the token holding the wrapped key.
+    pub token_label: String,
+    /// The PIN used to log in to the token.
+    pub user_pin: String,
+    /// The label of the wrapping key, and of the wrapped spend key, within
+    /// the token.
+    pub key_label: String,
+}
+
+// Implemented by hand, so that the PIN isn't logged.
+impl std::fmt::Debug for TokenConfig {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("TokenConfig")
+            .field("module", &self.module)
+            .field("token_label", &self.token_label)
+            .field("key_label", &self.key_label)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Helper function for Serde serialization, allowing us to skip serialization
+/// of default config values, as in the [`SoftKms`](crate::soft_kms::SoftKms)
+/// config.
+fn is_default<T: Default + Eq>(value: &T) -> bool {
+    *value == T::default()
+}
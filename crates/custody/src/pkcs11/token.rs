@@ -0,0 +1,265 @@
+use std::sync::Mutex;
+
+use cryptoki::{
+    context::{CInitializeArgs, Pkcs11},
+    error::{Error as Pkcs11Error, RvError},
+    mechanism::Mechanism,
+    object::{Attribute, AttributeType, KeyType, ObjectClass, ObjectHandle},
+    session::{Session, UserType},
+    types::AuthPin,
+};
+use penumbra_keys::keys::{SpendKey, SpendKeyBytes};
+use rand_core::{OsRng, RngCore};
+use tonic::Status;
+use zeroize::Zeroizing;
+
+use super::TokenConfig;
+
+/// An error from the HSM holding the spend authorization key.
+#[derive(Clone, Debug, Eq, PartialEq, thiserror::Error)]
+pub enum HsmError {
+    /// The HSM, or the token holding the key, could not be reached.
+    #[error("HSM is unavailable: {0}")]
+    Unavailable(String),
+    /// The HSM refused to log in with the configured PIN.
+    #[error("HSM login failed: {0}")]
+    LoginFailed(String),
+    /// The token does not hold the configured wrapping key, or the spend key wrapped by it.
+    #[error("key {0:?} not found in HSM")]
+    KeyNotFound(String),
+    /// The token already holds a key with the configured label.
+    #[error("a key labeled {0:?} already exists in HSM")]
+    KeyExists(String),
+    /// The HSM does not support the mechanisms used to wrap the spend key.
+    #[error("HSM does not support the required mechanism: {0}")]
+    Unsupported(String),
+    /// The HSM returned a malformed key or ciphertext.
+    #[error("HSM returned an invalid {0}")]
+    InvalidResponse(&'static str),
+    /// Any other failure reported by the HSM.
+    #[error("HSM error: {0}")]
+    Other(String),
+}
+
+impl From<Pkcs11Error> for HsmError {
+    fn from(error: Pkcs11Error) -> Self {
+        let message = error.to_string();
+        match error {
+            Pkcs11Error::Pkcs11(rv, _) => match rv {
+                RvError::DeviceError
+                | RvError::DeviceMemory
+                | RvError::DeviceRemoved
+                | RvError::TokenNotPresent
+                | RvError::TokenNotRecognized
+                | RvError::SessionClosed
+                | RvError::SessionHandleInvalid
+                | RvError::SessionCount => HsmError::Unavailable(message),
+                RvError::PinIncorrect
+                | RvError::PinExpired
+                | RvError::PinLocked
+                | RvError::PinInvalid
+                | RvError::PinLenRange
+                | RvError::UserNotLoggedIn => HsmError::LoginFailed(message),
+                RvError::MechanismInvalid
+                | RvError::MechanismParamInvalid
+                | RvError::FunctionNotSupported
+                | RvError::KeyTypeInconsistent
+                | RvError::KeyFunctionNotPermitted => HsmError::Unsupported(message),
+                _ => HsmError::Other(message),
+            },
+            Pkcs11Error::LibraryLoading(_) | Pkcs11Error::NotSupported => {
+                HsmError::Unavailable(message)
+            }
+            _ => HsmError::Other(message),
+        }
+    }
+}
+
+impl From<HsmError> for Status {
+    fn from(error: HsmError) -> Self {
+        let message = error.to_string();
+        match error {
+            HsmError::Unavailable(_) => Status::unavailable(message),
+            HsmError::LoginFailed(_) => Status::unauthenticated(message),
+            HsmError::KeyNotFound(_) => Status::failed_precondition(message),
+            HsmError::KeyExists(_) => Status::already_exists(message),
+            HsmError::Unsupported(_) => Status::unimplemented(message),
+            HsmError::InvalidResponse(_) | HsmError::Other(_) => Status::internal(message),
+        }
+    }
+}
+
+/// A token holding a single wrapping key, which can encrypt and decrypt with it but never reveals
+/// it, along with the spend key it wraps.
+pub trait Token: Send + Sync {
+    /// Generate the wrapping key.
+    ///
+    /// Fails with [`HsmError::KeyExists`] rather than replacing an existing key.
+    fn generate_key(&self) -> Result<(), HsmError>;
+
+    /// Encrypt the plaintext with the wrapping key.
+    fn encrypt(&self, plaintext: &[u8]) -> Result<Vec<u8>, HsmError>;
+
+    /// Decrypt a ciphertext produced by [`Token::encrypt`] with the wrapping key.
+    fn decrypt(&self, ciphertext: &[u8]) -> Result<Vec<u8>, HsmError>;
+
+    /// Store the wrapped spend key in the token, alongside the wrapping key.
+    ///
+    /// Fails with [`HsmError::KeyExists`] rather than replacing an existing wrapped key.
+    fn store_wrapped_key(&self, wrapped_key: &[u8]) -> Result<(), HsmError>;
+
+    /// Get the wrapped spend key stored in the token.
+    fn wrapped_key(&self) -> Result<Vec<u8>, HsmError>;
+
+    /// Unwrap the spend key stored in the token.
+    fn unwrap_spend_key(&self) -> Result<SpendKey, HsmError> {
+        let seed = Zeroizing::new(self.decrypt(&self.wrapped_key()?)?);
+        let seed = SpendKeyBytes::try_from(seed.as_slice())
+            .map_err(|_| HsmError::InvalidResponse("spend key"))?;
+        Ok(seed.into())
+    }
+}
+
+/// The length of the initialization vector prepended to each ciphertext.
+const IV_LEN: usize = 16;
+
+/// A [`Token`] in an HSM, accessed through its vendor's PKCS#11 module.
+///
+/// PKCS#11 defines no mechanisms for `decaf377-rdsa`, so the HSM can't sign with the spend key
+/// itself. Instead, it holds an AES-256 wrapping key, generated with `CKM_AES_KEY_GEN` and never
+/// extractable, and a data object holding the spend key encrypted with it using `CKM_AES_CBC_PAD`.
+/// These are standard mechanisms, supported by every HSM.
+pub struct Pkcs11Token {
+    // Sessions can be shared between threads, but not used concurrently.
+    session: Mutex<Session>,
+    key_label: String,
+}
+
+impl Pkcs11Token {
+    /// Load the configured PKCS#11 module, and log in to the configured token.
+    pub fn open(config: &TokenConfig) -> Result<Self, HsmError> {
+        let pkcs11 = Pkcs11::new(&config.module)?;
+        pkcs11.initialize(CInitializeArgs::OsThreads)?;
+
+        let mut slot = None;
+        for candidate in pkcs11.get_slots_with_token()? {
+            if pkcs11.get_token_info(candidate)?.label() == config.token_label {
+                slot = Some(candidate);
+                break;
+            }
+        }
+        let slot = slot.ok_or_else(|| {
+            HsmError::Unavailable(format!("no token labeled {:?}", config.token_label))
+        })?;
+
+        let session = pkcs11.open_rw_session(slot)?;
+        session.login(UserType::User, Some(&AuthPin::new(config.user_pin.clone())))?;
+
+        Ok(Self {
+            session: Mutex::new(session),
+            key_label: config.key_label.clone(),
+        })
+    }
+
+    fn session(&self) -> std::sync::MutexGuard<'_, Session> {
+        self.session
+            .lock()
+            .expect("HSM session lock is not poisoned")
+    }
+
+    /// Find the single object of the given class labeled with the key label.
+    fn find_object(&self, session: &Session, class: ObjectClass) -> Result<ObjectHandle, HsmError> {
+        let mut objects = session.find_objects(&[
+            Attribute::Class(class),
+            Attribute::Label(self.key_label.as_bytes().to_vec()),
+        ])?;
+        match (objects.pop(), objects.is_empty()) {
+            (Some(object), true) => Ok(object),
+            (Some(_), false) => Err(HsmError::Other(format!(
+                "multiple objects labeled {:?}",
+                self.key_label
+            ))),
+            (None, _) => Err(HsmError::KeyNotFound(self.key_label.clone())),
+        }
+    }
+
+    /// Fail with [`HsmError::KeyExists`] if there is an object of the given class labeled with
+    /// the key label.
+    fn ensure_absent(&self, session: &Session, class: ObjectClass) -> Result<(), HsmError> {
+        match self.find_object(session, class) {
+            Err(HsmError::KeyNotFound(_)) => Ok(()),
+            Ok(_) => Err(HsmError::KeyExists(self.key_label.clone())),
+            Err(e) => Err(e),
+        }
+    }
+}
+
+impl Token for Pkcs11Token {
+    fn generate_key(&self) -> Result<(), HsmError> {
+        let session = self.session();
+        self.ensure_absent(&session, ObjectClass::SECRET_KEY)?;
+
+        // The wrapping key must never be readable outside the HSM.
+        let template = [
+            Attribute::Class(ObjectClass::SECRET_KEY),
+            Attribute::KeyType(KeyType::AES),
+            Attribute::ValueLen(32.into()),
+            Attribute::Label(self.key_label.as_bytes().to_vec()),
+            Attribute::Token(true),
+            Attribute::Private(true),
+            Attribute::Sensitive(true),
+            Attribute::Extractable(false),
+            Attribute::Encrypt(true),
+            Attribute::Decrypt(true),
+        ];
+        session.generate_key(&Mechanism::AesKeyGen, &template)?;
+        Ok(())
+    }
+
+    fn encrypt(&self, plaintext: &[u8]) -> Result<Vec<u8>, HsmError> {
+        let session = self.session();
+        let key = self.find_object(&session, ObjectClass::SECRET_KEY)?;
+
+        let mut iv = [0u8; IV_LEN];
+        OsRng.fill_bytes(&mut iv);
+        let ciphertext = session.encrypt(&Mechanism::AesCbcPad(iv), key, plaintext)?;
+        Ok([iv.as_slice(), &ciphertext].concat())
+    }
+
+    fn decrypt(&self, ciphertext: &[u8]) -> Result<Vec<u8>, HsmError> {
+        let session = self.session();
+        let key = self.find_object(&session, ObjectClass::SECRET_KEY)?;
+
+        if ciphertext.len() < IV_LEN {
+            return Err(HsmError::InvalidResponse("ciphertext"));
+        }
+        let (iv, ciphertext) = ciphertext.split_at(IV_LEN);
+        let iv = iv.try_into().expect("IV has the right length");
+        Ok(session.decrypt(&Mechanism::AesCbcPad(iv), key, ciphertext)?)
+    }
+
+    fn store_wrapped_key(&self, wrapped_key: &[u8]) -> Result<(), HsmError> {
+        let session = self.session();
+        self.ensure_absent(&session, ObjectClass::DATA)?;
+
+        session.create_object(&[
+            Attribute::Class(ObjectClass::DATA),
+            Attribute::Label(self.key_label.as_bytes().to_vec()),
+            Attribute::Token(true),
+            Attribute::Private(true),
+            Attribute::Value(wrapped_key.to_vec()),
+        ])?;
+        Ok(())
+    }
+
+    fn wrapped_key(&self) -> Result<Vec<u8>, HsmError> {
+        let session = self.session();
+        let object = self.find_object(&session, ObjectClass::DATA)?;
+
+        let attributes = session.get_attributes(object, &[AttributeType::Value])?;
+        match attributes.as_slice() {
+            [Attribute::Value(bytes)] => Ok(bytes.clone()),
+            _ => Err(HsmError::InvalidResponse("wrapped key")),
+        }
+    }
+}
@@ -0,0 +1,196 @@
+//! Structured summaries of the effects of transaction plans.
+//!
+//! The [`PlanText`] of a plan describes every action it contains, which is
+//! what a custodian needs to make an authorization decision, but is hard for a
+//! client to reason about programmatically.  A [`PlanSummary`] instead nets out
+//! the value the plan moves between addresses of the custodian's own wallet,
+//! leaving only what enters and leaves the wallet, and where it goes.  Custody
+//! backends return the summary of each plan they authorize, so that clients
+//! can show the user a diff-style view of the transaction.
+
+use anyhow::Context;
+use penumbra_asset::{asset, Balance, Value};
+use penumbra_fee::Fee;
+use penumbra_keys::{Address, FullViewingKey};
+use penumbra_proto::{custody::v1 as pb, DomainType};
+use penumbra_transaction::{memo::MemoPlaintext, TransactionPlan};
+
+use crate::PlanText;
+
+/// A structured summary of the effects of a [`TransactionPlan`], from the
+/// perspective of the wallet whose funds it spends.
+#[derive(Clone, Debug, PartialEq)]
+pub struct PlanSummary {
+    /// The net value received by the wallet, per asset.
+    pub received: Vec<Value>,
+    /// The net value sent out of the wallet, per asset, including the fee.
+    pub sent: Vec<Value>,
+    /// The addresses outside the wallet which receive outputs of the plan, in
+    /// the order in which they first appear.
+    pub destinations: Vec<Address>,
+    /// The counterparty chain addresses which receive ICS-20 withdrawals, in
+    /// the order in which they first appear.
+    pub ics20_destinations: Vec<String>,
+    /// The fee paid by the plan.
+    pub fee: Fee,
+    /// The plaintext of the plan's memo, if any.
+    pub memo: Option<MemoPlaintext>,
+    /// The hash of the [`PlanText`] of the plan, rendered using the known
+    /// asset metadata.
+    pub plan_text_hash: [u8; 32],
+}
+
+impl PlanSummary {
+    /// Summarize the effects of the given plan on the wallet with the given
+    /// full viewing key.
+    ///
+    /// Notes spent by the plan count as leaving the wallet, and outputs to any
+    /// of the wallet's addresses count as entering it, so that change and
+    /// transfers between the wallet's own accounts cancel out.  Value consumed
+    /// or produced by other actions (swaps, delegations, and so on) shows up
+    /// as the difference between what was spent and what was output.
+    pub fn new(plan: &TransactionPlan, fvk: &FullViewingKey) -> Self {
+        // The balance is positive for value leaving the wallet, like the
+        // balance of a transaction, which spends provide to.
+        let mut balance = Balance::zero();
+        for spend in plan.spend_plans() {
+            balance += spend.note.value();
+        }
+
+        let mut destinations = Vec::new();
+        for output in plan.output_plans() {
+            if fvk.address_index(&output.dest_address).is_some() {
+                balance -= output.value;
+            } else if !destinations.contains(&output.dest_address) {
+                destinations.push(output.dest_address.clone());
+            }
+        }
+
+        let mut ics20_destinations = Vec::new();
+        for withdrawal in plan.ics20_withdrawals() {
+            if !ics20_destinations.contains(&withdrawal.destination_chain_address) {
+                ics20_destinations.push(withdrawal.destination_chain_address.clone());
+            }
+        }
+
+        Self {
+            received: balance.required().collect(),
+            sent: balance.provided().collect(),
+            destinations,
+            ics20_destinations,
+            fee: plan.transaction_parameters.fee,
+            memo: plan.memo.as_ref().map(|memo| memo.plaintext.clone()),
+            plan_text_hash: PlanText::render(plan, &asset::Cache::with_known_assets()).hash(),
+        }
+    }
+}
+
+impl DomainType for PlanSummary {
+    type Proto = pb::PlanSummary;
+}
+
+impl From<PlanSummary> for pb::PlanSummary {
+    fn from(value: PlanSummary) -> Self {
+        Self {
+            received: value.received.into_iter().map(Into::into).collect(),
+            sent: value.sent.into_iter().map(Into::into).collect(),
+            destinations: value.destinations.into_iter().map(Into::into).collect(),
+            ics20_destinations: value.ics20_destinations,
+            fee: Some(value.fee.into()),
+            memo: value.memo.map(Into::into),
+            plan_text_hash: value.plan_text_hash.to_vec(),
+        }
+    }
+}
+
+impl TryFrom<pb::PlanSummary> for PlanSummary {
+    type Error = anyhow::Error;
+
+    fn try_from(value: pb::PlanSummary) -> Result<Self, Self::Error> {
+        Ok(Self {
+            received: value
+                .received
+                .into_iter()
+                .map(TryInto::try_into)
+                .collect::<Result<_, _>>()?,
+            sent: value
+                .sent
+                .into_iter()
+                .map(TryInto::try_into)
+                .collect::<Result<_, _>>()?,
+            destinations: value
+                .destinations
+                .into_iter()
+                .map(TryInto::try_into)
+                .collect::<Result<_, _>>()?,
+            ics20_destinations: value.ics20_destinations,
+            fee: value
+                .fee
+                .ok_or_else(|| anyhow::anyhow!("missing fee"))?
+                .try_into()?,
+            memo: value.memo.map(TryInto::try_into).transpose()?,
+            plan_text_hash: value
+                .plan_text_hash
+                .as_slice()
+                .try_into()
+                .context("plan text hash must be 32 bytes")?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use penumbra_asset::STAKING_TOKEN_ASSET_ID;
+    use penumbra_keys::keys::{Bip44Path, SeedPhrase, SpendKey};
+    use penumbra_shielded_pool::{Note, OutputPlan, SpendPlan};
+    use rand_core::OsRng;
+
+    use super::*;
+
+    fn fvk(seed: u8) -> FullViewingKey {
+        let seed_phrase = SeedPhrase::from_randomness(&[seed; 32]);
+        SpendKey::from_seed_phrase_bip44(seed_phrase, &Bip44Path::new(0))
+            .full_viewing_key()
+            .clone()
+    }
+
+    fn um(amount: u64) -> Value {
+        Value {
+            amount: amount.into(),
+            asset_id: *STAKING_TOKEN_ASSET_ID,
+        }
+    }
+
+    #[test]
+    fn change_to_own_addresses_cancels_out() {
+        let ours = fvk(1);
+        let (own, _) = ours.payment_address(0u32.into());
+        let (own_subaccount, _) = ours.payment_address(1u32.into());
+        let (other, _) = fvk(2).payment_address(0u32.into());
+
+        let mut plan = TransactionPlan::default();
+        let note = Note::generate(&mut OsRng, &own, um(5));
+        plan.actions
+            .push(SpendPlan::new(&mut OsRng, note, 0u64.into()).into());
+        plan.actions
+            .push(OutputPlan::new(&mut OsRng, um(2), other.clone()).into());
+        plan.actions
+            .push(OutputPlan::new(&mut OsRng, um(1), other.clone()).into());
+        plan.actions
+            .push(OutputPlan::new(&mut OsRng, um(2), own_subaccount).into());
+
+        let summary = PlanSummary::new(&plan, &ours);
+        assert_eq!(summary.sent, vec![um(3)]);
+        assert!(summary.received.is_empty());
+        assert_eq!(summary.destinations, vec![other]);
+        assert!(summary.ics20_destinations.is_empty());
+        assert_eq!(
+            summary.plan_text_hash,
+            PlanText::render(&plan, &asset::Cache::with_known_assets()).hash()
+        );
+
+        let decoded =
+            PlanSummary::decode(summary.encode_to_vec().as_slice()).expect("summary round-trips");
+        assert_eq!(decoded, summary);
+    }
+}
@@ -0,0 +1,445 @@
+//! Canonical, human-readable renderings of transaction plans.
+//!
+//! A custodian which asks a human to approve a [`TransactionPlan`] should show
+//! them its [`PlanText`], rather than an ad-hoc rendering of the plan.  The
+//! rendering is a deterministic function of the plan and the asset metadata
+//! used to format amounts, so every custodian of a threshold key shows
+//! byte-for-byte identical summaries, which can be compared out-of-band using
+//! [`PlanText::hash`].  The same hash is recorded in the audit log whenever a
+//! custody backend makes an authorization decision.
+//!
+//! The rendering is versioned: any change to its format must increment
+//! [`VERSION`], so that hashes of renderings with different formats are never
+//! mistaken for one another.
+
+use std::fmt::{self, Display, Write as _};
+
+use penumbra_asset::{asset, Value, STAKING_TOKEN_ASSET_ID};
+use penumbra_num::Amount;
+use penumbra_transaction::{plan::ActionPlan, TransactionPlan};
+
+use crate::policy::ActionKind;
+
+/// The version of the plan text format produced by [`PlanText::render`].
+pub const VERSION: u32 = 1;
+
+/// The `tracing` target under which authorization decisions are recorded.
+pub const AUDIT_TARGET: &str = "penumbra_custody::audit";
+
+/// The canonical, human-readable rendering of a [`TransactionPlan`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PlanText {
+    text: String,
+}
+
+impl PlanText {
+    /// Render the given plan, formatting amounts using the given asset metadata.
+    ///
+    /// Actions are listed in the order in which they appear in the plan, which
+    /// is also the order in which they are committed to by its effect hash.
+    /// Free-form text chosen by whoever built the plan (such as memos and
+    /// proposal titles) is quoted and escaped, so that it can't masquerade as
+    /// part of the rendering.
+    pub fn render(plan: &TransactionPlan, cache: &asset::Cache) -> Self {
+        // Writing to a `String` never fails, so the results of `writeln!` are ignored.
+        let mut text = String::new();
+        let params = &plan.transaction_parameters;
+
+        let _ = writeln!(text, "Penumbra transaction plan (plan text v{VERSION})");
+        let _ = writeln!(text, "Chain ID: {:?}", params.chain_id);
+        if params.expiry_height == 0 {
+            let _ = writeln!(text, "Expiry height: none");
+        } else {
+            let _ = writeln!(text, "Expiry height: {}", params.expiry_height);
+        }
+        let _ = writeln!(text, "Fee: {}", params.fee.0.format(cache));
+
+        let _ = writeln!(text, "Actions ({}):", plan.actions.len());
+        for (i, action) in plan.actions.iter().enumerate() {
+            let _ = writeln!(
+                text,
+                "  {}. {}: {}",
+                i + 1,
+                ActionKind::of(action),
+                describe_action(action, cache)
+            );
+        }
+
+        match &plan.memo {
+            Some(memo) => {
+                let _ = writeln!(text, "Memo: {:?}", memo.plaintext.text());
+                let _ = writeln!(
+                    text,
+                    "Memo return address: {}",
+                    memo.plaintext.return_address()
+                );
+            }
+            None => {
+                let _ = writeln!(text, "Memo: none");
+            }
+        }
+
+        Self { text }
+    }
+
+    /// The rendered text.
+    pub fn as_str(&self) -> &str {
+        &self.text
+    }
+
+    /// A hash of the rendered text, suitable for comparing renderings
+    /// out-of-band and for recording in the audit log.
+    pub fn hash(&self) -> [u8; 32] {
+        blake2b_simd::Params::default()
+            .personal(b"Penumbra_PlnText")
+            .hash_length(32)
+            .hash(self.text.as_bytes())
+            .as_bytes()
+            .try_into()
+            .expect("hash length is 32 bytes")
+    }
+}
+
+impl Display for PlanText {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.text)
+    }
+}
+
+/// Record an authorization decision about the given plan in the audit log.
+///
+/// The plan is identified by the hash of its [`PlanText`], rendered using the
+/// known asset metadata, which is what interactive custodians display.
+pub(crate) fn audit_decision(plan: &TransactionPlan, denial: Option<&dyn Display>) {
+    let plan_text = PlanText::render(plan, &asset::Cache::with_known_assets());
+    let plan_text_hash = hex::encode(plan_text.hash());
+
+    match denial {
+        None => tracing::info!(
+            target: AUDIT_TARGET,
+            plan_text_version = VERSION,
+            %plan_text_hash,
+            "approved transaction plan"
+        ),
+        Some(reason) => tracing::info!(
+            target: AUDIT_TARGET,
+            plan_text_version = VERSION,
+            %plan_text_hash,
+            %reason,
+            "denied transaction plan"
+        ),
+    }
+}
+
+/// Describe the effect of a single action, on one line.
+fn describe_action(action: &ActionPlan, cache: &asset::Cache) -> String {
+    let staking = |amount: Amount| {
+        Value {
+            amount,
+            asset_id: *STAKING_TOKEN_ASSET_ID,
+        }
+        .format(cache)
+    };
+    let value = |amount: Amount, asset_id: asset::Id| Value { amount, asset_id }.format(cache);
+
+    match action {
+        ActionPlan::Spend(spend) => format!(
+            "{} from {}",
+            spend.note.value().format(cache),
+            spend.note.address()
+        ),
+        ActionPlan::Output(output) => {
+            format!("{} to {}", output.value.format(cache), output.dest_address)
+        }
+        ActionPlan::Delegate(delegate) => format!(
+            "{} to validator {}",
+            staking(delegate.unbonded_amount),
+            delegate.validator_identity
+        ),
+        ActionPlan::Undelegate(undelegate) => format!(
+            "{} from validator {}, unbonding {}",
+            value(
+                undelegate.delegation_amount,
+                undelegate.delegation_token().id()
+            ),
+            undelegate.validator_identity,
+            staking(undelegate.unbonded_amount)
+        ),
+        ActionPlan::UndelegateClaim(claim) => format!(
+            "{} from validator {}, undelegated in epoch {}",
+            value(claim.unbonding_amount, claim.unbonding_id()),
+            claim.validator_identity,
+            claim.start_epoch_index
+        ),
+        ActionPlan::Redelegate(redelegate) => format!(
+            "{} from validator {} to validator {}, moving {}",
+            value(
+                redelegate.from_delegation_amount,
+                redelegate.from_delegation_token().id()
+            ),
+            redelegate.from_validator_identity,
+            redelegate.to_validator_identity,
+            staking(redelegate.unbonded_amount)
+        ),
+        ActionPlan::ValidatorDefinition(definition) => format!(
+            "validator {} named {:?}, sequence number {}",
+            definition.validator.identity_key,
+            definition.validator.name,
+            definition.validator.sequence_number
+        ),
+        ActionPlan::Swap(swap) => {
+            let plaintext = &swap.swap_plaintext;
+            format!(
+                "{} and {}, claimable by {} with prepaid fee {}",
+                value(plaintext.delta_1_i, plaintext.trading_pair.asset_1()),
+                value(plaintext.delta_2_i, plaintext.trading_pair.asset_2()),
+                plaintext.claim_address,
+                plaintext.claim_fee.0.format(cache)
+            )
+        }
+        ActionPlan::SwapClaim(claim) => {
+            let plaintext = &claim.swap_plaintext;
+            format!(
+                "outputs of swapping {} and {}, to {} with prepaid fee {}",
+                value(plaintext.delta_1_i, plaintext.trading_pair.asset_1()),
+                value(plaintext.delta_2_i, plaintext.trading_pair.asset_2()),
+                plaintext.claim_address,
+                plaintext.claim_fee.0.format(cache)
+            )
+        }
+        ActionPlan::IbcAction(_) => "IBC relay message".to_string(),
+        ActionPlan::ProposalSubmit(submit) => format!(
+            "proposal {} titled {:?}, with deposit {}",
+            submit.proposal.id,
+            submit.proposal.title,
+            value(submit.deposit_amount, submit.deposit_asset_id)
+        ),
+        ActionPlan::ProposalWithdraw(withdraw) => format!(
+            "proposal {} with reason {:?}",
+            withdraw.proposal, withdraw.reason
+        ),
+        ActionPlan::DelegatorVote(vote) => format!(
+            "{} on proposal {} with {}",
+            vote.vote,
+            vote.proposal,
+            staking(vote.unbonded_amount)
+        ),
+        ActionPlan::ValidatorVote(vote) => format!(
+            "{} on proposal {} as validator {}, with reason {:?}",
+            vote.body.vote, vote.body.proposal, vote.body.identity_key, vote.body.reason.0
+        ),
+        ActionPlan::BallotKeyDeal(deal) => format!(
+            "deal of the ballot key for proposal {} as validator {}",
+            deal.body.proposal, deal.body.identity_key
+        ),
+        ActionPlan::BallotKeyComplaint(complaint) => format!(
+            "complaint about the ballot key deal of validator {} for proposal {} as validator {}",
+            complaint.body.dealer, complaint.body.proposal, complaint.body.identity_key
+        ),
+        ActionPlan::BallotKeyReveal(reveal) => format!(
+            "reveal of {} ballot key shares for proposal {} as validator {}",
+            reveal.body.shares.len(),
+            reveal.body.proposal,
+            reveal.body.identity_key
+        ),
+        ActionPlan::ProposalDepositClaim(claim) => format!(
+            "deposit of {} for proposal {}",
+            value(claim.deposit_amount, claim.deposit_asset_id),
+            claim.proposal
+        ),
+        ActionPlan::PositionOpen(open) => {
+            let position = &open.position;
+            format!(
+                "position {} with reserves {} and {}, fee {}bps",
+                position.id(),
+                value(position.reserves.r1, position.phi.pair.asset_1()),
+                value(position.reserves.r2, position.phi.pair.asset_2()),
+                position.phi.component.fee
+            )
+        }
+        ActionPlan::PositionOpenBatch(batch) => {
+            let opens = batch
+                .positions
+                .iter()
+                .map(|position| {
+                    format!(
+                        "position {} with reserves {} and {}, fee {}bps",
+                        position.id(),
+                        value(position.reserves.r1, position.phi.pair.asset_1()),
+                        value(position.reserves.r2, position.phi.pair.asset_2()),
+                        position.phi.component.fee
+                    )
+                })
+                .collect::<Vec<_>>();
+            format!("open [{}]", opens.join(", "))
+        }
+        ActionPlan::PositionClose(close) => format!("position {}", close.position_id),
+        ActionPlan::PositionWithdraw(withdraw) => format!(
+            "position {} with reserves {} and {}",
+            withdraw.position_id,
+            value(withdraw.reserves.r1, withdraw.pair.asset_1()),
+            value(withdraw.reserves.r2, withdraw.pair.asset_2())
+        ),
+        ActionPlan::PositionUpdateBatch(batch) => {
+            let closes = batch
+                .closes
+                .iter()
+                .map(|position_id| format!("position {position_id}"))
+                .collect::<Vec<_>>();
+            let withdrawals = batch
+                .withdrawals
+                .iter()
+                .map(|withdrawal| {
+                    format!(
+                        "position {} with reserves {} and {}",
+                        withdrawal.position_id,
+                        value(withdrawal.reserves.r1, withdrawal.pair.asset_1()),
+                        value(withdrawal.reserves.r2, withdrawal.pair.asset_2())
+                    )
+                })
+                .collect::<Vec<_>>();
+            let opens = batch
+                .opens
+                .iter()
+                .map(|position| {
+                    format!(
+                        "position {} with reserves {} and {}, fee {}bps",
+                        position.id(),
+                        value(position.reserves.r1, position.phi.pair.asset_1()),
+                        value(position.reserves.r2, position.phi.pair.asset_2()),
+                        position.phi.component.fee
+                    )
+                })
+                .collect::<Vec<_>>();
+            format!(
+                "close [{}]; withdraw [{}]; open [{}]",
+                closes.join(", "),
+                withdrawals.join(", "),
+                opens.join(", ")
+            )
+        }
+        ActionPlan::PositionRewrite(rewrite) => format!(
+            "position {} with reserves {} and {}, replaced by position {} with reserves {} and {}, fee {}bps",
+            rewrite.position_id,
+            value(rewrite.reserves.r1, rewrite.pair.asset_1()),
+            value(rewrite.reserves.r2, rewrite.pair.asset_2()),
+            rewrite.new_position.id(),
+            value(
+                rewrite.new_position.reserves.r1,
+                rewrite.new_position.phi.pair.asset_1()
+            ),
+            value(
+                rewrite.new_position.reserves.r2,
+                rewrite.new_position.phi.pair.asset_2()
+            ),
+            rewrite.new_position.phi.component.fee
+        ),
+        ActionPlan::ActionDutchAuctionSchedule(schedule) => {
+            let description = &schedule.description;
+            format!(
+                "auction {} selling {} for {} to {} between heights {} and {}",
+                description.id(),
+                description.input.format(cache),
+                value(description.max_output, description.output_id),
+                value(description.min_output, description.output_id),
+                description.start_height,
+                description.end_height
+            )
+        }
+        ActionPlan::ActionDutchAuctionEnd(end) => format!("auction {}", end.auction_id),
+        ActionPlan::ActionDutchAuctionWithdraw(withdraw) => format!(
+            "auction {} with reserves {} and {}",
+            withdraw.auction_id,
+            withdraw.reserves_input.format(cache),
+            withdraw.reserves_output.format(cache)
+        ),
+        ActionPlan::CommunityPoolSpend(spend) => spend.value.format(cache),
+        ActionPlan::CommunityPoolOutput(output) => {
+            format!("{} to {}", output.value.format(cache), output.address)
+        }
+        ActionPlan::CommunityPoolDeposit(deposit) => deposit.value.format(cache),
+        ActionPlan::Ics20Withdrawal(withdrawal) => format!(
+            "{} to {:?} via {}, returning to {}",
+            value(withdrawal.amount, withdrawal.denom.id()),
+            withdrawal.destination_chain_address,
+            withdrawal.source_channel,
+            withdrawal.return_address
+        ),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use penumbra_keys::keys::{Bip44Path, SeedPhrase, SpendKey};
+    use penumbra_shielded_pool::OutputPlan;
+    use penumbra_transaction::plan::MemoPlan;
+    use rand_core::OsRng;
+
+    use super::*;
+
+    fn plan_with_memo(memo: &str) -> TransactionPlan {
+        let seed_phrase = SeedPhrase::from_randomness(&[7u8; 32]);
+        let sk = SpendKey::from_seed_phrase_bip44(seed_phrase, &Bip44Path::new(0));
+        let (address, _dtk) = sk.full_viewing_key().payment_address(0u32.into());
+
+        let mut plan = TransactionPlan::default();
+        plan.transaction_parameters.chain_id = "penumbra-testnet".to_string();
+        plan.transaction_parameters.expiry_height = 100;
+        plan.actions.push(
+            OutputPlan::new(
+                &mut OsRng,
+                Value {
+                    amount: 1_000_000u64.into(),
+                    asset_id: *STAKING_TOKEN_ASSET_ID,
+                },
+                address,
+            )
+            .into(),
+        );
+        plan.memo = Some(
+            MemoPlan::new(
+                &mut OsRng,
+                penumbra_transaction::memo::MemoPlaintext::new(address, memo.to_string())
+                    .expect("memo is short enough"),
+            )
+            .expect("can create memo plan"),
+        );
+        plan
+    }
+
+    #[test]
+    fn rendering_ignores_randomness() {
+        let cache = asset::Cache::with_known_assets();
+
+        // The two plans differ in their blinding factors and memo keys, but
+        // describe the same effects, so they must render identically.
+        let a = PlanText::render(&plan_with_memo("hello"), &cache);
+        let b = PlanText::render(&plan_with_memo("hello"), &cache);
+        assert_eq!(a, b);
+        assert_eq!(a.hash(), b.hash());
+
+        let text = a.as_str();
+        assert!(text.starts_with("Penumbra transaction plan (plan text v1)\n"));
+        assert!(text.contains("Chain ID: \"penumbra-testnet\"\n"));
+        assert!(text.contains("Expiry height: 100\n"));
+        assert!(text.contains("Actions (1):\n  1. output: 1penumbra to penumbra1"));
+        assert!(text.contains("Memo: \"hello\"\n"));
+
+        let c = PlanText::render(&plan_with_memo("goodbye"), &cache);
+        assert_ne!(a.hash(), c.hash());
+    }
+
+    #[test]
+    fn free_form_text_is_escaped() {
+        let cache = asset::Cache::with_known_assets();
+        let honest = PlanText::render(&plan_with_memo("hi"), &cache);
+        let sneaky = PlanText::render(&plan_with_memo("hi\"\nMemo: none"), &cache);
+
+        // The memo can't add lines to the rendering.
+        assert_eq!(
+            honest.as_str().lines().count(),
+            sneaky.as_str().lines().count()
+        );
+        assert!(sneaky.as_str().contains("Memo: \"hi\\\"\\nMemo: none\"\n"));
+    }
+}
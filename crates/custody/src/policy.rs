@@ -1,24 +1,211 @@
 //! A set of basic spend authorization policies.
 
-use std::collections::HashSet;
+use std::{collections::BTreeMap, sync::Arc};
 
-use penumbra_keys::Address;
-use penumbra_transaction::plan::ActionPlan;
+use penumbra_asset::{asset, Value};
+use penumbra_keys::{Address, FullViewingKey};
+use penumbra_num::Amount;
+use penumbra_transaction::{plan::ActionPlan, TransactionPlan};
 use serde::{Deserialize, Serialize};
+use serde_with::DisplayFromStr;
 
-use crate::{AuthorizeRequest, PreAuthorization};
+use crate::{Approver, AuthorizeRequest, PreAuthorization};
+
+pub mod address_index;
+pub mod bundle;
+
+pub use address_index::{DailyLimit, LockedLedger, SpendLedger};
+pub use bundle::{BundleRejection, PolicyBundle, PolicySigners, SignedPolicyBundle};
 
 /// A trait for checking whether a transaction plan is allowed by a policy.
 pub trait Policy {
     /// Checks whether the proposed transaction plan is allowed by this policy.
+    ///
+    /// Policies provided by this crate return a [`Denial`] as the error, so
+    /// that callers can recover the structured reason by downcasting.
     fn check(&self, request: &AuthorizeRequest) -> anyhow::Result<()>;
 }
 
+/// The reason an [`AuthorizeRequest`] was denied by a policy.
+#[derive(Clone, Debug, Eq, PartialEq, thiserror::Error)]
+pub enum Denial {
+    /// An output or swap would send funds to an address outside of the allow list.
+    #[error("{action} has destination address {address} not in allow list")]
+    DestinationNotAllowed {
+        /// The kind of action with the disallowed destination.
+        action: ActionKind,
+        /// The disallowed destination address.
+        address: Box<Address>,
+    },
+    /// The plan contains an action of a kind which is not allowed.
+    #[error("action of kind {action} is not allowed by policy")]
+    ActionNotAllowed {
+        /// The kind of action which is not allowed.
+        action: ActionKind,
+    },
+    /// The plan would send more of an asset than the spend limit allows.
+    #[error("plan sends {amount} of asset {asset_id}, exceeding spend limit of {limit}")]
+    SpendLimitExceeded {
+        /// The asset whose spend limit was exceeded.
+        asset_id: asset::Id,
+        /// The amount the plan would send.
+        amount: Amount,
+        /// The configured spend limit.
+        limit: Amount,
+    },
+    /// The plan spends funds from an account which is frozen by policy.
+    #[error("spends from {} are frozen by policy", describe_account(*.account))]
+    AccountFrozen {
+        /// The frozen account, or `None` if the account of the spent notes
+        /// couldn't be resolved.
+        account: Option<u32>,
+    },
+    /// The plan would send more of an asset from an account than its daily limit allows.
+    #[error(
+        "{} would send {amount} of asset {asset_id} in a day, exceeding daily limit of {limit}",
+        describe_account(*.account)
+    )]
+    DailyLimitExceeded {
+        /// The account whose daily limit was exceeded, or `None` if the account
+        /// of the spent notes couldn't be resolved.
+        account: Option<u32>,
+        /// The asset whose daily limit was exceeded.
+        asset_id: asset::Id,
+        /// The amount the account would have sent in the last day, including this plan.
+        amount: Amount,
+        /// The configured daily limit.
+        limit: Amount,
+    },
+    /// The ledger of recent spends is too full to record the plan, so daily
+    /// limits can't be enforced on it.
+    #[error("too many recent spends are tracked to enforce daily limits")]
+    SpendLedgerFull,
+    /// Fewer valid pre-authorizations were provided than required.
+    #[error("required {required} pre-authorization signatures but only saw {seen}")]
+    InsufficientPreAuthorizations {
+        /// The number of pre-authorizations required.
+        required: u32,
+        /// The number of valid, distinct pre-authorizations provided.
+        seen: u32,
+    },
+    /// A pre-authorization from an allowed signer failed to verify.
+    #[error("invalid pre-authorization: {reason}")]
+    InvalidPreAuthorization {
+        /// A description of the verification failure.
+        reason: String,
+    },
+    /// The policy needs to know which addresses belong to the wallet, but was
+    /// evaluated outside of a [`PolicyEngine`].
+    #[error("{policy} policies can only be enforced for a known wallet")]
+    WalletRequired {
+        /// The name of the policy.
+        policy: &'static str,
+    },
+    /// An off-chain message was submitted for signing under a domain which is not allowed.
+    #[error("signing messages under domain {domain:?} is not allowed by policy")]
+    MessageDomainNotAllowed {
+        /// The disallowed domain.
+        domain: String,
+    },
+}
+
+fn describe_account(account: Option<u32>) -> String {
+    match account {
+        Some(account) => format!("account {account}"),
+        None => "an unknown account".to_string(),
+    }
+}
+
+/// The kind of an [`ActionPlan`], used to name actions in policies.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, Eq, PartialEq, Hash)]
+#[serde(rename_all = "snake_case")]
+pub enum ActionKind {
+    Spend,
+    Output,
+    Delegate,
+    Undelegate,
+    UndelegateClaim,
+    Redelegate,
+    ValidatorDefinition,
+    Swap,
+    SwapClaim,
+    IbcAction,
+    ProposalSubmit,
+    ProposalWithdraw,
+    DelegatorVote,
+    ValidatorVote,
+    BallotKeyDeal,
+    BallotKeyReveal,
+    BallotKeyComplaint,
+    ProposalDepositClaim,
+    PositionOpen,
+    PositionOpenBatch,
+    PositionClose,
+    PositionWithdraw,
+    PositionUpdateBatch,
+    PositionRewrite,
+    DutchAuctionSchedule,
+    DutchAuctionEnd,
+    DutchAuctionWithdraw,
+    CommunityPoolSpend,
+    CommunityPoolOutput,
+    CommunityPoolDeposit,
+    Ics20Withdrawal,
+}
+
+impl ActionKind {
+    /// Returns the kind of the given [`ActionPlan`].
+    pub fn of(action: &ActionPlan) -> Self {
+        match action {
+            ActionPlan::Spend(_) => ActionKind::Spend,
+            ActionPlan::Output(_) => ActionKind::Output,
+            ActionPlan::Delegate(_) => ActionKind::Delegate,
+            ActionPlan::Undelegate(_) => ActionKind::Undelegate,
+            ActionPlan::UndelegateClaim(_) => ActionKind::UndelegateClaim,
+            ActionPlan::Redelegate(_) => ActionKind::Redelegate,
+            ActionPlan::ValidatorDefinition(_) => ActionKind::ValidatorDefinition,
+            ActionPlan::Swap(_) => ActionKind::Swap,
+            ActionPlan::SwapClaim(_) => ActionKind::SwapClaim,
+            ActionPlan::IbcAction(_) => ActionKind::IbcAction,
+            ActionPlan::ProposalSubmit(_) => ActionKind::ProposalSubmit,
+            ActionPlan::ProposalWithdraw(_) => ActionKind::ProposalWithdraw,
+            ActionPlan::DelegatorVote(_) => ActionKind::DelegatorVote,
+            ActionPlan::ValidatorVote(_) => ActionKind::ValidatorVote,
+            ActionPlan::BallotKeyDeal(_) => ActionKind::BallotKeyDeal,
+            ActionPlan::BallotKeyReveal(_) => ActionKind::BallotKeyReveal,
+            ActionPlan::BallotKeyComplaint(_) => ActionKind::BallotKeyComplaint,
+            ActionPlan::ProposalDepositClaim(_) => ActionKind::ProposalDepositClaim,
+            ActionPlan::PositionOpen(_) => ActionKind::PositionOpen,
+            ActionPlan::PositionOpenBatch(_) => ActionKind::PositionOpenBatch,
+            ActionPlan::PositionClose(_) => ActionKind::PositionClose,
+            ActionPlan::PositionWithdraw(_) => ActionKind::PositionWithdraw,
+            ActionPlan::PositionUpdateBatch(_) => ActionKind::PositionUpdateBatch,
+            ActionPlan::PositionRewrite(_) => ActionKind::PositionRewrite,
+            ActionPlan::ActionDutchAuctionSchedule(_) => ActionKind::DutchAuctionSchedule,
+            ActionPlan::ActionDutchAuctionEnd(_) => ActionKind::DutchAuctionEnd,
+            ActionPlan::ActionDutchAuctionWithdraw(_) => ActionKind::DutchAuctionWithdraw,
+            ActionPlan::CommunityPoolSpend(_) => ActionKind::CommunityPoolSpend,
+            ActionPlan::CommunityPoolOutput(_) => ActionKind::CommunityPoolOutput,
+            ActionPlan::CommunityPoolDeposit(_) => ActionKind::CommunityPoolDeposit,
+            ActionPlan::Ics20Withdrawal(_) => ActionKind::Ics20Withdrawal,
+        }
+    }
+}
+
+impl std::fmt::Display for ActionKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        // Use the same snake_case names as in policy configuration.
+        let name = serde_json::to_value(self).map_err(|_| std::fmt::Error)?;
+        f.write_str(name.as_str().ok_or(std::fmt::Error)?)
+    }
+}
+
 /// A set of basic spend authorization policies.
 ///
 /// These policies are intended to be simple enough that they can be written by
 /// hand in a config file.  More complex policy logic than than should be
 /// implemented by a custom implementation of the [`Policy`] trait.
+#[serde_as]
 #[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq)]
 #[serde(tag = "type")]
 pub enum AuthPolicy {
@@ -36,6 +223,167 @@ pub enum AuthPolicy {
     OnlyIbcRelay,
     /// Require specific pre-authorizations for submitted [`TransactionPlan`](penumbra_transaction::TransactionPlan)s.
     PreAuthorization(PreAuthorizationPolicy),
+    /// Disallow transactions containing any of the listed kinds of actions.
+    ActionDenyList { denied_actions: Vec<ActionKind> },
+    /// Limit the total amount of an asset that a single transaction may send
+    /// out of the wallet.
+    ///
+    /// The amount sent is everything the plan's actions consume, such as the
+    /// inputs of swaps and auctions, the reserves of positions, delegated stake
+    /// and ICS-20 withdrawals, plus its fee and its outputs to addresses outside
+    /// the wallet, but not its outputs back to the wallet, such as change.
+    /// Since telling those apart needs the wallet's full viewing key, this
+    /// policy can only be evaluated by a [`PolicyEngine`].
+    SpendLimit {
+        #[serde_as(as = "DisplayFromStr")]
+        asset_id: asset::Id,
+        #[serde_as(as = "DisplayFromStr")]
+        max_amount: u128,
+    },
+    /// Restrict spending from a range of accounts of the wallet, by freezing
+    /// them or limiting the amount of assets each may send per day.
+    ///
+    /// The accounts funding a plan are resolved from the addresses of the
+    /// notes it spends, so this policy only sees funds leave an account when
+    /// evaluated by a [`PolicyEngine`], which also remembers what each account
+    /// sent in earlier authorizations. Otherwise, every spend is conservatively
+    /// counted against the restriction, and only the plan itself is limited.
+    AddressIndexRestriction {
+        /// The first account the restriction applies to.
+        first_index: u32,
+        /// The last account the restriction applies to, or `first_index` if omitted.
+        #[serde(default)]
+        last_index: Option<u32>,
+        /// Whether spends from these accounts are refused outright.
+        #[serde(default)]
+        frozen: bool,
+        /// The amount of each asset that each of these accounts may send per day.
+        #[serde(default)]
+        daily_limits: Vec<DailyLimit>,
+    },
+}
+
+/// A configured set of [`AuthPolicy`]s, evaluated on behalf of a particular
+/// wallet.
+///
+/// Unlike checking each [`AuthPolicy`] individually, the engine knows which
+/// addresses belong to the wallet, so that spend limits only count funds which
+/// actually leave the wallet, and it keeps a [`SpendLedger`] of what each
+/// account sent, so that daily limits apply across requests.
+#[derive(Clone, Debug)]
+pub struct PolicyEngine {
+    fvk: FullViewingKey,
+    policies: Vec<AuthPolicy>,
+    ledger: Arc<SpendLedger>,
+}
+
+impl PolicyEngine {
+    /// Create a new policy engine for the wallet with the given full viewing key.
+    pub fn new(fvk: FullViewingKey, policies: Vec<AuthPolicy>) -> Self {
+        Self {
+            fvk,
+            policies,
+            ledger: Arc::default(),
+        }
+    }
+
+    /// Use the given ledger of recent spends, such as the one of the engine
+    /// this one replaces, rather than an empty one.
+    pub fn with_ledger(mut self, ledger: Arc<SpendLedger>) -> Self {
+        self.ledger = ledger;
+        self
+    }
+
+    /// The ledger of recent spends used to enforce daily limits.
+    pub fn ledger(&self) -> &Arc<SpendLedger> {
+        &self.ledger
+    }
+
+    /// Record that the request was authorized in the locked ledger, counting
+    /// what it sends from each account towards the daily limits it is under.
+    ///
+    /// Fails if the ledger is full or can't be persisted, in which case the
+    /// request must be refused.
+    pub fn record(
+        &self,
+        request: &AuthorizeRequest,
+        ledger: &mut LockedLedger,
+    ) -> anyhow::Result<()> {
+        let limited = address_index::outflows(request, Some(&self.fvk))
+            .into_iter()
+            .filter(|((account, asset_id), _)| {
+                self.policies
+                    .iter()
+                    .any(|policy| policy.limits(*account, asset_id))
+            })
+            .collect();
+        ledger.record(&limited)
+    }
+
+    /// Evaluate all policies against the request, returning the first denial, if any.
+    ///
+    /// If the request is allowed, returns the distinct approvers whose
+    /// pre-authorizations were counted towards the pre-authorization policies,
+    /// so that they can be recorded in the audit log.
+    pub fn evaluate(&self, request: &AuthorizeRequest) -> Result<Vec<Approver>, Denial> {
+        self.evaluate_locked(request, &self.ledger.lock())
+    }
+
+    /// Like [`Self::evaluate`], but against a ledger which is already locked,
+    /// so that the request can be [`record`](Self::record)ed in it before
+    /// another request is evaluated.
+    pub fn evaluate_locked(
+        &self,
+        request: &AuthorizeRequest,
+        ledger: &LockedLedger,
+    ) -> Result<Vec<Approver>, Denial> {
+        let mut approvers = Vec::new();
+        for (_name, outcome) in self.outcomes(request, ledger) {
+            for approver in outcome? {
+                if !approvers.contains(&approver) {
+                    approvers.push(approver);
+                }
+            }
+        }
+        Ok(approvers)
+    }
+
+    /// Evaluate each policy against the request, without stopping at the first
+    /// denial, returning the name of each policy with its outcome.
+    ///
+    /// The outcome of a pre-authorization policy which allows the request
+    /// carries the approvers it counted.
+    pub fn evaluate_each(
+        &self,
+        request: &AuthorizeRequest,
+    ) -> Vec<(&'static str, Result<Vec<Approver>, Denial>)> {
+        self.outcomes(request, &self.ledger.lock()).collect()
+    }
+
+    /// Lazily evaluate each policy against the request in turn, so that
+    /// [`Self::evaluate_locked`] can stop at the first denial, while
+    /// [`Self::evaluate_each`] goes on to evaluate every policy.
+    fn outcomes<'a>(
+        &'a self,
+        request: &'a AuthorizeRequest,
+        ledger: &'a LockedLedger,
+    ) -> impl Iterator<Item = (&'static str, Result<Vec<Approver>, Denial>)> + 'a {
+        self.policies.iter().map(move |policy| {
+            let outcome = match policy {
+                AuthPolicy::PreAuthorization(policy) => policy.evaluate(request),
+                policy => policy
+                    .evaluate(request, Some(&self.fvk), Some(ledger))
+                    .map(|()| Vec::new()),
+            };
+            (policy.name(), outcome)
+        })
+    }
+}
+
+impl Policy for PolicyEngine {
+    fn check(&self, request: &AuthorizeRequest) -> anyhow::Result<()> {
+        self.evaluate(request).map(drop).map_err(Into::into)
+    }
 }
 
 /// A set of pre-authorization policies.
@@ -56,6 +404,30 @@ pub enum PreAuthorizationPolicy {
         #[serde(with = "ed25519_vec_base64")]
         allowed_signers: Vec<ed25519_consensus::VerificationKey>,
     },
+    Secp256k1 {
+        /// The number of distinct pre-authorizations required to authorize a transaction plan.
+        ///
+        /// Each `allowed_signer`'s contributions count only once towards this total.
+        required_signatures: u32,
+        /// A list of SEC1-encoded pre-authorization keys that can be used to authorize a
+        /// transaction plan.
+        #[serde(with = "sec1_vec_base64")]
+        allowed_signers: Vec<k256::ecdsa::VerifyingKey>,
+    },
+    WebAuthn {
+        /// The number of distinct pre-authorizations required to authorize a transaction plan.
+        ///
+        /// Each `allowed_credential`'s contributions count only once towards this total.
+        required_signatures: u32,
+        /// The relying party ID the credentials were registered with, e.g. `example.com`.
+        ///
+        /// Assertions must also have been made from the origin `https://<relying_party_id>`.
+        relying_party_id: String,
+        /// A list of SEC1-encoded P-256 credential public keys that can be used to authorize a
+        /// transaction plan.
+        #[serde(with = "sec1_vec_base64")]
+        allowed_credentials: Vec<p256::ecdsa::VerifyingKey>,
+    },
 }
 
 mod address_as_string {
@@ -129,8 +501,88 @@ mod ed25519_vec_base64 {
     }
 }
 
-impl Policy for AuthPolicy {
-    fn check(&self, request: &AuthorizeRequest) -> anyhow::Result<()> {
+/// A serde helper to serialize SEC1-encoded ECDSA verification keys as
+/// base64-encoded data, in the same way as [`ed25519_vec_base64`].
+mod sec1_vec_base64 {
+    use base64::prelude::*;
+    use ecdsa::elliptic_curve::{
+        sec1::{FromEncodedPoint, ModulusSize, ToEncodedPoint},
+        AffinePoint, CurveArithmetic, FieldBytesSize,
+    };
+
+    pub fn serialize<S: serde::Serializer, C>(
+        keys: &[ecdsa::VerifyingKey<C>],
+        serializer: S,
+    ) -> Result<S::Ok, S::Error>
+    where
+        C: ecdsa::PrimeCurve + CurveArithmetic,
+        AffinePoint<C>: FromEncodedPoint<C> + ToEncodedPoint<C>,
+        FieldBytesSize<C>: ModulusSize,
+    {
+        use serde::Serialize;
+        let mut base64_keys = Vec::with_capacity(keys.len());
+        for key in keys {
+            base64_keys.push(BASE64_STANDARD.encode(key.to_encoded_point(true).as_bytes()));
+        }
+        base64_keys.serialize(serializer)
+    }
+    pub fn deserialize<'de, D, C>(deserializer: D) -> Result<Vec<ecdsa::VerifyingKey<C>>, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+        C: ecdsa::PrimeCurve + CurveArithmetic,
+        AffinePoint<C>: FromEncodedPoint<C> + ToEncodedPoint<C>,
+        FieldBytesSize<C>: ModulusSize,
+    {
+        use serde::Deserialize;
+        let base64_keys: Vec<String> = Vec::deserialize(deserializer)?;
+        let mut vks = Vec::with_capacity(base64_keys.len());
+        for base64_key in base64_keys {
+            let bytes = BASE64_STANDARD
+                .decode(base64_key)
+                .map_err(serde::de::Error::custom)?;
+            let vk =
+                ecdsa::VerifyingKey::from_sec1_bytes(&bytes).map_err(serde::de::Error::custom)?;
+            vks.push(vk);
+        }
+        Ok(vks)
+    }
+}
+
+impl AuthPolicy {
+    /// Whether this policy limits how much of the asset the account may send per day.
+    fn limits(&self, account: Option<u32>, asset_id: &asset::Id) -> bool {
+        match self {
+            AuthPolicy::AddressIndexRestriction {
+                first_index,
+                last_index,
+                daily_limits,
+                ..
+            } => address_index::limits(*first_index, *last_index, daily_limits, account, asset_id),
+            _ => false,
+        }
+    }
+
+    /// The name of this kind of policy, as in its `type` in configuration.
+    pub fn name(&self) -> &'static str {
+        match self {
+            AuthPolicy::DestinationAllowList { .. } => "DestinationAllowList",
+            AuthPolicy::OnlyIbcRelay => "OnlyIbcRelay",
+            AuthPolicy::PreAuthorization(_) => "PreAuthorization",
+            AuthPolicy::ActionDenyList { .. } => "ActionDenyList",
+            AuthPolicy::SpendLimit { .. } => "SpendLimit",
+            AuthPolicy::AddressIndexRestriction { .. } => "AddressIndexRestriction",
+        }
+    }
+
+    /// Evaluate this policy against the request, optionally using the full
+    /// viewing key of the wallet to recognize funds which stay in the wallet,
+    /// and a ledger of what its accounts sent recently.
+    fn evaluate(
+        &self,
+        request: &AuthorizeRequest,
+        fvk: Option<&FullViewingKey>,
+        ledger: Option<&LockedLedger>,
+    ) -> Result<(), Denial> {
         let plan = &request.plan;
         match self {
             AuthPolicy::DestinationAllowList {
@@ -138,12 +590,18 @@ impl Policy for AuthPolicy {
             } => {
                 for output in plan.output_plans() {
                     if !allowed_destination_addresses.contains(&output.dest_address) {
-                        anyhow::bail!("output {:?} has dest_address not in allow list", output);
+                        return Err(Denial::DestinationNotAllowed {
+                            action: ActionKind::Output,
+                            address: Box::new(output.dest_address),
+                        });
                     }
                 }
                 for swap in plan.swap_plans() {
                     if !allowed_destination_addresses.contains(&swap.swap_plaintext.claim_address) {
-                        anyhow::bail!("swap {:?} has claim_address not in allow list", swap);
+                        return Err(Denial::DestinationNotAllowed {
+                            action: ActionKind::Swap,
+                            address: Box::new(swap.swap_plaintext.claim_address),
+                        });
                     }
                 }
                 Ok(())
@@ -155,55 +613,388 @@ impl Policy for AuthPolicy {
                         | ActionPlan::Output { .. }
                         | ActionPlan::IbcAction { .. } => {}
                         _ => {
-                            anyhow::bail!("action {:?} not allowed by OnlyRelay policy", action);
+                            return Err(Denial::ActionNotAllowed {
+                                action: ActionKind::of(action),
+                            });
                         }
                     }
                 }
                 Ok(())
             }
-            AuthPolicy::PreAuthorization(policy) => policy.check(request),
+            AuthPolicy::PreAuthorization(policy) => policy.evaluate(request).map(drop),
+            AuthPolicy::ActionDenyList { denied_actions } => {
+                for action in &plan.actions {
+                    let kind = ActionKind::of(action);
+                    if denied_actions.contains(&kind) {
+                        return Err(Denial::ActionNotAllowed { action: kind });
+                    }
+                }
+                Ok(())
+            }
+            AuthPolicy::SpendLimit {
+                asset_id,
+                max_amount,
+            } => {
+                let Some(fvk) = fvk else {
+                    return Err(Denial::WalletRequired {
+                        policy: self.name(),
+                    });
+                };
+                let limit = Amount::from(*max_amount);
+                let amount = sent_amounts(&request.plan, fvk)
+                    .remove(asset_id)
+                    .unwrap_or_default();
+                if amount > limit {
+                    return Err(Denial::SpendLimitExceeded {
+                        asset_id: *asset_id,
+                        amount,
+                        limit,
+                    });
+                }
+                Ok(())
+            }
+            AuthPolicy::AddressIndexRestriction {
+                first_index,
+                last_index,
+                frozen,
+                daily_limits,
+            } => address_index::check(
+                *first_index,
+                *last_index,
+                *frozen,
+                daily_limits,
+                request,
+                fvk,
+                ledger,
+            ),
         }
     }
 }
 
-impl Policy for PreAuthorizationPolicy {
+/// Sum the amounts of each asset the plan sends out of the wallet with the
+/// given full viewing key: its fee, whatever its actions other than spends and
+/// outputs consume, and its outputs to addresses outside the wallet.
+fn sent_amounts(plan: &TransactionPlan, fvk: &FullViewingKey) -> BTreeMap<asset::Id, Amount> {
+    let fee = plan.transaction_parameters.fee.0;
+    let mut sent = BTreeMap::<asset::Id, Amount>::from([(fee.asset_id, fee.amount)]);
+    let mut send = |value: Value| *sent.entry(value.asset_id).or_default() += value.amount;
+    for action in &plan.actions {
+        match action {
+            // Spends are what the rest is paid with.
+            ActionPlan::Spend(_) => {}
+            ActionPlan::Output(output) => {
+                if fvk.address_index(&output.dest_address).is_none() {
+                    send(output.value);
+                }
+            }
+            action => action.balance().required().for_each(&mut send),
+        }
+    }
+    sent
+}
+
+impl Policy for AuthPolicy {
     fn check(&self, request: &AuthorizeRequest) -> anyhow::Result<()> {
+        self.evaluate(request, None, None).map_err(Into::into)
+    }
+}
+
+impl PreAuthorizationPolicy {
+    /// Evaluate this policy against the request, returning the approvers whose
+    /// pre-authorizations were verified.
+    fn evaluate(&self, request: &AuthorizeRequest) -> Result<Vec<Approver>, Denial> {
         match self {
             PreAuthorizationPolicy::Ed25519 {
                 required_signatures,
                 allowed_signers,
-            } => {
-                #[allow(clippy::unnecessary_filter_map)]
-                let ed25519_pre_auths =
-                    request
-                        .pre_authorizations
-                        .iter()
-                        .filter_map(|pre_auth| match pre_auth {
-                            PreAuthorization::Ed25519(pre_auth) => Some(pre_auth),
-                            // _ => None,
-                        });
+            } => check_pre_authorizations(
+                *required_signatures,
+                allowed_signers,
+                request
+                    .pre_authorizations
+                    .iter()
+                    .filter_map(|pre_auth| match pre_auth {
+                        PreAuthorization::Ed25519(pre_auth) => Some(pre_auth),
+                        _ => None,
+                    }),
+                |pre_auth| &pre_auth.vk,
+                |pre_auth| pre_auth.verify_plan(&request.plan),
+            )
+            .map(|signers| {
+                signers
+                    .into_iter()
+                    .cloned()
+                    .map(Approver::Ed25519)
+                    .collect()
+            }),
+            PreAuthorizationPolicy::Secp256k1 {
+                required_signatures,
+                allowed_signers,
+            } => check_pre_authorizations(
+                *required_signatures,
+                allowed_signers,
+                request
+                    .pre_authorizations
+                    .iter()
+                    .filter_map(|pre_auth| match pre_auth {
+                        PreAuthorization::Secp256k1(pre_auth) => Some(pre_auth),
+                        _ => None,
+                    }),
+                |pre_auth| &pre_auth.vk,
+                |pre_auth| pre_auth.verify_plan(&request.plan),
+            )
+            .map(|signers| {
+                signers
+                    .into_iter()
+                    .cloned()
+                    .map(Approver::Secp256k1)
+                    .collect()
+            }),
+            PreAuthorizationPolicy::WebAuthn {
+                required_signatures,
+                relying_party_id,
+                allowed_credentials,
+            } => check_pre_authorizations(
+                *required_signatures,
+                allowed_credentials,
+                request
+                    .pre_authorizations
+                    .iter()
+                    .filter_map(|pre_auth| match pre_auth {
+                        PreAuthorization::WebAuthn(pre_auth) => Some(pre_auth),
+                        _ => None,
+                    }),
+                |pre_auth| &pre_auth.public_key,
+                |pre_auth| pre_auth.verify_plan(&request.plan, relying_party_id),
+            )
+            .map(|signers| {
+                signers
+                    .into_iter()
+                    .cloned()
+                    .map(Approver::WebAuthn)
+                    .collect()
+            }),
+        }
+    }
+}
 
-                let mut allowed_signers = allowed_signers.iter().cloned().collect::<HashSet<_>>();
-                let mut seen_signers = HashSet::new();
+/// Check that at least `required_signatures` distinct `allowed_signers` have
+/// submitted valid pre-authorizations, returning the signers which did.
+///
+/// Pre-authorizations from signers which are not allowed are ignored, but an
+/// invalid pre-authorization from an allowed signer denies the request. A
+/// signer listed more than once among the `allowed_signers` still only counts
+/// once.
+fn check_pre_authorizations<'a, K: PartialEq + 'a, P: 'a>(
+    required_signatures: u32,
+    allowed_signers: &'a [K],
+    pre_auths: impl Iterator<Item = &'a P>,
+    signer: impl Fn(&P) -> &K,
+    verify: impl Fn(&P) -> anyhow::Result<()>,
+) -> Result<Vec<&'a K>, Denial> {
+    let mut allowed_signers = allowed_signers.iter().collect::<Vec<_>>();
+    let mut seen_signers = Vec::new();
 
-                for pre_auth in ed25519_pre_auths {
-                    // Remove the signer from the allowed signers set, so that
-                    // each signer can only submit one pre-authorization.
-                    if let Some(signer) = allowed_signers.take(&pre_auth.vk) {
-                        pre_auth.verify_plan(&request.plan)?;
-                        seen_signers.insert(signer);
-                    }
-                }
+    for pre_auth in pre_auths {
+        let key = signer(pre_auth);
+        if let Some(index) = allowed_signers.iter().position(|allowed| *allowed == key) {
+            // Remove every copy of the signer from the allowed signers set, so
+            // that each signer can only submit one pre-authorization.
+            let allowed = allowed_signers.swap_remove(index);
+            allowed_signers.retain(|other| *other != allowed);
+            verify(pre_auth).map_err(|e| Denial::InvalidPreAuthorization {
+                reason: format!("{e:#}"),
+            })?;
+            seen_signers.push(allowed);
+        }
+    }
 
-                if seen_signers.len() < *required_signatures as usize {
-                    anyhow::bail!(
-                        "required {} pre-authorization signatures but only saw {}",
-                        required_signatures,
-                        seen_signers.len(),
-                    );
-                }
-                Ok(())
-            }
+    let seen = u32::try_from(seen_signers.len()).unwrap_or(u32::MAX);
+    if seen < required_signatures {
+        return Err(Denial::InsufficientPreAuthorizations {
+            required: required_signatures,
+            seen,
+        });
+    }
+    Ok(seen_signers)
+}
+
+impl Policy for PreAuthorizationPolicy {
+    fn check(&self, request: &AuthorizeRequest) -> anyhow::Result<()> {
+        self.evaluate(request).map(drop).map_err(Into::into)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ed25519_consensus::SigningKey;
+    use penumbra_asset::STAKING_TOKEN_ASSET_ID;
+    use penumbra_fee::Fee;
+    use penumbra_keys::keys::{Bip44Path, SeedPhrase, SpendKey};
+    use penumbra_proto::{
+        core::{
+            component::{governance::v1 as pb_governance, stake::v1 as pb_stake},
+            keys::v1::IdentityKey,
+            transaction::v1::{self as pb, action_plan::Action},
+        },
+        DomainType,
+    };
+    use penumbra_shielded_pool::{Note, OutputPlan, SpendPlan};
+    use rand_core::OsRng;
+
+    use super::*;
+    use crate::pre_auth::Ed25519;
+
+    fn pre_authorize(sk: &SigningKey, plan: &TransactionPlan) -> PreAuthorization {
+        PreAuthorization::Ed25519(Ed25519 {
+            vk: sk.verification_key(),
+            sig: sk.sign(&plan.encode_to_vec()),
+        })
+    }
+
+    #[test]
+    fn ed25519_m_of_n_counts_distinct_approvers() {
+        let signers: Vec<SigningKey> = (0..3).map(|_| SigningKey::new(OsRng)).collect();
+        let outsider = SigningKey::new(OsRng);
+        let plan = TransactionPlan::default();
+
+        // The first signer is listed twice, but must still only count once.
+        let mut allowed_signers: Vec<_> = signers.iter().map(|sk| sk.verification_key()).collect();
+        allowed_signers.push(signers[0].verification_key());
+        let policy = PreAuthorizationPolicy::Ed25519 {
+            required_signatures: 2,
+            allowed_signers,
+        };
+
+        let request = |pre_authorizations| AuthorizeRequest {
+            plan: plan.clone(),
+            pre_authorizations,
+            nonce: None,
+        };
+
+        // Repeating a pre-authorization, or adding one from a signer outside
+        // the set, doesn't make up the numbers.
+        let denied = request(vec![
+            pre_authorize(&signers[0], &plan),
+            pre_authorize(&signers[0], &plan),
+            pre_authorize(&outsider, &plan),
+        ]);
+        assert_eq!(
+            policy.evaluate(&denied),
+            Err(Denial::InsufficientPreAuthorizations {
+                required: 2,
+                seen: 1
+            })
+        );
+
+        let approved = request(vec![
+            pre_authorize(&signers[2], &plan),
+            pre_authorize(&outsider, &plan),
+            pre_authorize(&signers[0], &plan),
+        ]);
+        assert_eq!(
+            policy.evaluate(&approved),
+            Ok(vec![
+                Approver::Ed25519(signers[2].verification_key()),
+                Approver::Ed25519(signers[0].verification_key()),
+            ])
+        );
+
+        // A signature over a different plan from an allowed signer is refused.
+        let mut invalid = request(vec![pre_authorize(&signers[1], &plan)]);
+        invalid.plan.transaction_parameters.expiry_height = 1;
+        assert!(matches!(
+            policy.evaluate(&invalid),
+            Err(Denial::InvalidPreAuthorization { .. })
+        ));
+    }
+
+    fn fvk(seed: u8) -> FullViewingKey {
+        let seed_phrase = SeedPhrase::from_randomness(&[seed; 32]);
+        SpendKey::from_seed_phrase_bip44(seed_phrase, &Bip44Path::new(0))
+            .full_viewing_key()
+            .clone()
+    }
+
+    fn um(amount: u64) -> Value {
+        Value {
+            amount: amount.into(),
+            asset_id: *STAKING_TOKEN_ASSET_ID,
+        }
+    }
+
+    fn action(action: Action) -> ActionPlan {
+        pb::ActionPlan {
+            action: Some(action),
         }
+        .try_into()
+        .expect("valid action plan")
+    }
+
+    #[test]
+    fn spend_limit_counts_everything_leaving_the_wallet() {
+        let ours = fvk(1);
+        let (own, _) = ours.payment_address(0u32.into());
+        let (own_subaccount, _) = ours.payment_address(1u32.into());
+        let (other, _) = fvk(2).payment_address(0u32.into());
+
+        // Spend 100, pay 10 to someone else, delegate 20, deposit 30 into the
+        // community pool, pay a fee of 5, and keep the rest in the wallet.
+        let mut plan = TransactionPlan::default();
+        let note = Note::generate(&mut OsRng, &own, um(100));
+        plan.actions
+            .push(SpendPlan::new(&mut OsRng, note, 0u64.into()).into());
+        plan.actions
+            .push(OutputPlan::new(&mut OsRng, um(10), other).into());
+        plan.actions
+            .push(action(Action::Delegate(pb_stake::Delegate {
+                validator_identity: Some(IdentityKey {
+                    ik: fvk(3).spend_verification_key().to_bytes().to_vec(),
+                }),
+                epoch_index: 0,
+                unbonded_amount: Some(Amount::from(20u64).into()),
+                delegation_amount: Some(Amount::from(20u64).into()),
+            })));
+        plan.actions.push(action(Action::CommunityPoolDeposit(
+            pb_governance::CommunityPoolDeposit {
+                value: Some(um(30).into()),
+            },
+        )));
+        plan.actions
+            .push(OutputPlan::new(&mut OsRng, um(15), own_subaccount).into());
+        plan.actions
+            .push(OutputPlan::new(&mut OsRng, um(20), own).into());
+        plan.transaction_parameters.fee = Fee(um(5));
+        let request = AuthorizeRequest {
+            plan,
+            pre_authorizations: Vec::new(),
+            nonce: None,
+        };
+
+        let limit = |max_amount| AuthPolicy::SpendLimit {
+            asset_id: *STAKING_TOKEN_ASSET_ID,
+            max_amount,
+        };
+        assert_eq!(
+            PolicyEngine::new(ours.clone(), vec![limit(65)]).evaluate(&request),
+            Ok(Vec::new())
+        );
+        assert_eq!(
+            PolicyEngine::new(ours.clone(), vec![limit(64)]).evaluate(&request),
+            Err(Denial::SpendLimitExceeded {
+                asset_id: *STAKING_TOKEN_ASSET_ID,
+                amount: 65u64.into(),
+                limit: 64u64.into(),
+            })
+        );
+
+        // Without the wallet's full viewing key, change can't be told apart
+        // from payments, so the limit can't be enforced.
+        assert_eq!(
+            limit(1_000).evaluate(&request, None, None),
+            Err(Denial::WalletRequired {
+                policy: "SpendLimit"
+            })
+        );
     }
 }
@@ -0,0 +1,492 @@
+//! Spending policies scoped to the accounts of a wallet.
+//!
+//! An [`AuthPolicy::AddressIndexRestriction`](super::AuthPolicy::AddressIndexRestriction)
+//! applies to a range of account numbers, the `account` component of an
+//! [`AddressIndex`](penumbra_keys::keys::AddressIndex). The accounts which fund a plan are
+//! resolved from the addresses of the notes consumed by its spends, and the amount each account
+//! sends elsewhere is counted against its daily limits, together with the amounts recorded in a
+//! [`SpendLedger`] for recently authorized plans.
+
+use std::{
+    collections::BTreeMap,
+    path::{Path, PathBuf},
+    sync::{Mutex, MutexGuard},
+    time::Duration,
+};
+
+use penumbra_asset::asset;
+use penumbra_keys::{Address, FullViewingKey};
+use penumbra_num::Amount;
+use serde::{Deserialize, Serialize};
+use serde_with::DisplayFromStr;
+
+use super::Denial;
+use crate::{audit::unix_timestamp, state_file, AuthorizeRequest};
+
+/// The window over which daily limits are enforced.
+pub const DAILY_LIMIT_WINDOW: Duration = Duration::from_secs(24 * 60 * 60);
+
+/// The length of the periods over which a [`SpendLedger`] sums up outflows.
+///
+/// A period counts towards the daily limits for as long as any part of it is within the last
+/// [`DAILY_LIMIT_WINDOW`], so an outflow is remembered for up to this much longer than a day.
+pub const LEDGER_PERIOD: Duration = Duration::from_secs(60 * 60);
+
+/// The maximum number of totals a [`SpendLedger`] keeps at once.
+///
+/// Once it is full, plans sending assets under a daily limit are refused until older totals fall
+/// out of the window, rather than forgetting outflows which still count towards a limit.
+pub const MAX_LEDGER_ENTRIES: usize = 16 * 1024;
+
+/// A limit on the amount of an asset an account may send in any [`DAILY_LIMIT_WINDOW`].
+#[serde_as]
+#[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq)]
+pub struct DailyLimit {
+    #[serde_as(as = "DisplayFromStr")]
+    pub asset_id: asset::Id,
+    #[serde_as(as = "DisplayFromStr")]
+    pub max_amount: u128,
+}
+
+/// The amount of each asset sent by each account funding a plan.
+///
+/// An account of `None` stands for spends whose account couldn't be resolved,
+/// which are in the scope of every restriction.
+pub type Outflows = BTreeMap<(Option<u32>, asset::Id), Amount>;
+
+/// Compute the amount of each asset each account funding the plan sends elsewhere.
+///
+/// Each spend is attributed to the account of the note it consumes, and outputs
+/// back to an address of the same account, such as change, are deducted from
+/// it. Funds moved to another account of the same wallet count as sent by the
+/// account they came from. Without a full viewing key, spends can't be
+/// attributed to accounts, and are all counted under `None`.
+pub fn outflows(request: &AuthorizeRequest, fvk: Option<&FullViewingKey>) -> Outflows {
+    let account_of = |address: &Address| {
+        fvk.and_then(|fvk| fvk.address_index(address))
+            .map(|index| index.account)
+    };
+
+    let mut spent = Outflows::new();
+    for spend in request.plan.spend_plans() {
+        let value = spend.note.value();
+        *spent
+            .entry((account_of(&spend.note.address()), value.asset_id))
+            .or_default() += value.amount;
+    }
+
+    let mut returned = Outflows::new();
+    for output in request.plan.output_plans() {
+        if let Some(account) = account_of(&output.dest_address) {
+            *returned
+                .entry((Some(account), output.value.asset_id))
+                .or_default() += output.value.amount;
+        }
+    }
+
+    spent
+        .into_iter()
+        .map(|(key, amount)| {
+            let returned = returned.get(&key).copied().unwrap_or_default();
+            (key, amount.saturating_sub(&returned))
+        })
+        .filter(|(_, amount)| *amount > Amount::zero())
+        .collect()
+}
+
+/// Check the request against an address index restriction covering the
+/// accounts from `first_index` to `last_index`, inclusive.
+pub(super) fn check(
+    first_index: u32,
+    last_index: Option<u32>,
+    frozen: bool,
+    daily_limits: &[DailyLimit],
+    request: &AuthorizeRequest,
+    fvk: Option<&FullViewingKey>,
+    ledger: Option<&LockedLedger>,
+) -> Result<(), Denial> {
+    for ((account, asset_id), amount) in outflows(request, fvk) {
+        if !in_scope(first_index, last_index, account) {
+            continue;
+        }
+        if frozen {
+            return Err(Denial::AccountFrozen { account });
+        }
+        for limit in daily_limits
+            .iter()
+            .filter(|limit| limit.asset_id == asset_id)
+        {
+            let limit = Amount::from(limit.max_amount);
+            let recent = ledger
+                .map(|ledger| ledger.recently_sent(account, &asset_id))
+                .unwrap_or_default();
+            let amount = recent.saturating_add(&amount);
+            if amount > limit {
+                return Err(Denial::DailyLimitExceeded {
+                    account,
+                    asset_id,
+                    amount,
+                    limit,
+                });
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Whether an address index restriction covering the accounts from `first_index` to
+/// `last_index`, inclusive, limits how much of the asset the account may send per day.
+pub(super) fn limits(
+    first_index: u32,
+    last_index: Option<u32>,
+    daily_limits: &[DailyLimit],
+    account: Option<u32>,
+    asset_id: &asset::Id,
+) -> bool {
+    in_scope(first_index, last_index, account)
+        && daily_limits.iter().any(|limit| &limit.asset_id == asset_id)
+}
+
+/// Whether the account is covered by a restriction from `first_index` to `last_index`.
+///
+/// Spends whose account couldn't be resolved are covered by every restriction.
+fn in_scope(first_index: u32, last_index: Option<u32>, account: Option<u32>) -> bool {
+    let last_index = last_index.unwrap_or(first_index);
+    account.map_or(true, |account| {
+        (first_index..=last_index).contains(&account)
+    })
+}
+
+/// Remembers how much of each asset each account sent in recently authorized
+/// plans, so that daily limits can be enforced across requests.
+///
+/// Outflows are summed per account, asset and [`LEDGER_PERIOD`], so the ledger
+/// only grows with the number of distinct accounts and assets sent recently,
+/// and it can be persisted to a file with [`SpendLedger::open`], so that
+/// restarting the custodian doesn't reset the limits.
+#[derive(Debug, Default)]
+pub struct SpendLedger {
+    state: Mutex<LedgerState>,
+    /// The file the ledger is persisted to, if any.
+    path: Option<PathBuf>,
+}
+
+/// The persisted state of a [`SpendLedger`].
+#[serde_as]
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+struct LedgerState {
+    #[serde_as(as = "Vec<(_, _)>")]
+    totals: BTreeMap<LedgerKey, Amount>,
+}
+
+/// The period, account and asset an amount in a [`SpendLedger`] was sent in.
+///
+/// Keys are ordered by period first, so that expired totals come first.
+#[serde_as]
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Serialize, Deserialize)]
+struct LedgerKey {
+    /// The start of the period, in seconds since the Unix epoch.
+    period: u64,
+    account: Option<u32>,
+    #[serde_as(as = "DisplayFromStr")]
+    asset_id: asset::Id,
+}
+
+impl LedgerState {
+    /// Forget about totals for periods entirely before the window ending at `now`.
+    fn prune(&mut self, now: u64) {
+        let window_start = now.saturating_sub(DAILY_LIMIT_WINDOW.as_secs());
+        while let Some(entry) = self.totals.first_entry() {
+            if entry.key().period + LEDGER_PERIOD.as_secs() > window_start {
+                break;
+            }
+            entry.remove();
+        }
+    }
+}
+
+impl SpendLedger {
+    /// Create an empty ledger which is only kept in memory.
+    pub fn in_memory() -> Self {
+        Self::default()
+    }
+
+    /// Open the ledger persisted at the given path, creating it empty if it doesn't exist.
+    pub fn open(path: impl AsRef<Path>) -> anyhow::Result<Self> {
+        let path = path.as_ref();
+        let mut state: LedgerState = state_file::load(path, "spend ledger")?.unwrap_or_default();
+        state.prune(unix_timestamp());
+        state_file::store(path, "spend ledger", &state)?;
+        Ok(Self {
+            state: Mutex::new(state),
+            path: Some(path.to_owned()),
+        })
+    }
+
+    /// Lock the ledger, so that a request can be checked against it and
+    /// recorded in it without another request being authorized in between.
+    pub fn lock(&self) -> LockedLedger<'_> {
+        let mut state = self.state.lock().expect("ledger lock is not poisoned");
+        let now = unix_timestamp();
+        state.prune(now);
+        LockedLedger {
+            path: self.path.as_deref(),
+            state,
+            now,
+        }
+    }
+}
+
+/// A [`SpendLedger`] locked by [`SpendLedger::lock`].
+pub struct LockedLedger<'a> {
+    path: Option<&'a Path>,
+    state: MutexGuard<'a, LedgerState>,
+    /// The time the ledger was locked at, in seconds since the Unix epoch.
+    now: u64,
+}
+
+impl LockedLedger<'_> {
+    /// The total amount of the asset sent by the account in the last [`DAILY_LIMIT_WINDOW`].
+    pub fn recently_sent(&self, account: Option<u32>, asset_id: &asset::Id) -> Amount {
+        self.state
+            .totals
+            .iter()
+            .filter(|(key, _)| key.account == account && &key.asset_id == asset_id)
+            .fold(Amount::zero(), |total, (_, amount)| {
+                total.saturating_add(amount)
+            })
+    }
+
+    /// Record the outflows of a plan which was authorized.
+    ///
+    /// Fails, without recording anything, if the ledger is full or the
+    /// outflows can't be persisted, in which case the plan must be refused.
+    pub fn record(&mut self, outflows: &Outflows) -> anyhow::Result<()> {
+        if outflows.is_empty() {
+            return Ok(());
+        }
+        let period = self.now - self.now % LEDGER_PERIOD.as_secs();
+        let mut recorded = self.state.clone();
+        for (&(account, asset_id), amount) in outflows {
+            let total = recorded
+                .totals
+                .entry(LedgerKey {
+                    period,
+                    account,
+                    asset_id,
+                })
+                .or_default();
+            *total = total.saturating_add(amount);
+        }
+        if recorded.totals.len() > MAX_LEDGER_ENTRIES {
+            return Err(Denial::SpendLedgerFull.into());
+        }
+
+        if let Some(path) = self.path {
+            state_file::store(path, "spend ledger", &recorded)?;
+        }
+        *self.state = recorded;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use penumbra_asset::{Value, STAKING_TOKEN_ASSET_ID};
+    use penumbra_keys::keys::{Bip44Path, SeedPhrase, SpendKey};
+    use penumbra_shielded_pool::{Note, OutputPlan, SpendPlan};
+    use penumbra_transaction::TransactionPlan;
+    use rand_core::OsRng;
+
+    use super::*;
+    use crate::policy::{AuthPolicy, PolicyEngine};
+
+    fn fvk(seed: u8) -> FullViewingKey {
+        let seed_phrase = SeedPhrase::from_randomness(&[seed; 32]);
+        SpendKey::from_seed_phrase_bip44(seed_phrase, &Bip44Path::new(0))
+            .full_viewing_key()
+            .clone()
+    }
+
+    fn um(amount: u64) -> Value {
+        Value {
+            amount: amount.into(),
+            asset_id: *STAKING_TOKEN_ASSET_ID,
+        }
+    }
+
+    /// A request to send `amount` from a note of `funds` in the given account,
+    /// returning the rest to that account as change.
+    fn send_from(ours: &FullViewingKey, account: u32, funds: u64, amount: u64) -> AuthorizeRequest {
+        let (source, _) = ours.payment_address(account.into());
+        let (other, _) = fvk(2).payment_address(0u32.into());
+
+        let mut plan = TransactionPlan::default();
+        let note = Note::generate(&mut OsRng, &source, um(funds));
+        plan.actions
+            .push(SpendPlan::new(&mut OsRng, note, 0u64.into()).into());
+        plan.actions
+            .push(OutputPlan::new(&mut OsRng, um(amount), other).into());
+        plan.actions
+            .push(OutputPlan::new(&mut OsRng, um(funds - amount), source).into());
+        AuthorizeRequest {
+            plan,
+            pre_authorizations: Vec::new(),
+            nonce: None,
+        }
+    }
+
+    #[test]
+    fn restrictions_apply_to_the_accounts_funding_the_plan() {
+        let ours = fvk(1);
+        let engine = PolicyEngine::new(
+            ours.clone(),
+            vec![
+                AuthPolicy::AddressIndexRestriction {
+                    first_index: 1,
+                    last_index: Some(10),
+                    frozen: false,
+                    daily_limits: vec![DailyLimit {
+                        asset_id: *STAKING_TOKEN_ASSET_ID,
+                        max_amount: 5,
+                    }],
+                },
+                AuthPolicy::AddressIndexRestriction {
+                    first_index: 99,
+                    last_index: None,
+                    frozen: true,
+                    daily_limits: Vec::new(),
+                },
+            ],
+        );
+
+        // Account 0 is unrestricted, and change isn't counted as sent.
+        let unrestricted = send_from(&ours, 0, 100, 50);
+        assert_eq!(engine.evaluate(&unrestricted), Ok(Vec::new()));
+
+        let frozen = send_from(&ours, 99, 100, 1);
+        assert_eq!(
+            engine.evaluate(&frozen),
+            Err(Denial::AccountFrozen { account: Some(99) })
+        );
+
+        // Each limited account may send up to its limit per day, across requests.
+        let first = send_from(&ours, 3, 100, 3);
+        assert_eq!(engine.evaluate(&first), Ok(Vec::new()));
+        engine
+            .record(&first, &mut engine.ledger().lock())
+            .expect("ledger has room");
+        let second = send_from(&ours, 3, 100, 3);
+        assert_eq!(
+            engine.evaluate(&second),
+            Err(Denial::DailyLimitExceeded {
+                account: Some(3),
+                asset_id: *STAKING_TOKEN_ASSET_ID,
+                amount: 6u64.into(),
+                limit: 5u64.into(),
+            })
+        );
+        let other_account = send_from(&ours, 4, 100, 3);
+        assert_eq!(engine.evaluate(&other_account), Ok(Vec::new()));
+
+        // Without the full viewing key, the accounts can't be resolved, so
+        // every spend is counted against the restriction.
+        assert!(matches!(
+            AuthPolicy::AddressIndexRestriction {
+                first_index: 99,
+                last_index: None,
+                frozen: true,
+                daily_limits: Vec::new(),
+            }
+            .evaluate(&unrestricted, None, None),
+            Err(Denial::AccountFrozen { account: None })
+        ));
+    }
+
+    #[test]
+    fn ledger_aggregates_outflows_and_survives_restarts() -> anyhow::Result<()> {
+        let dir = tempfile::tempdir()?;
+        let path = dir.path().join("spend_ledger.json");
+        let outflows = Outflows::from([((Some(3), *STAKING_TOKEN_ASSET_ID), 2u64.into())]);
+
+        let ledger = SpendLedger::open(&path)?;
+        for _ in 0..10 {
+            ledger.lock().record(&outflows)?;
+        }
+        // Outflows within a period are summed, rather than remembered one by one.
+        assert_eq!(ledger.lock().state.totals.len(), 1);
+
+        let ledger = SpendLedger::open(&path)?;
+        let ledger = ledger.lock();
+        assert_eq!(
+            ledger.recently_sent(Some(3), &STAKING_TOKEN_ASSET_ID),
+            20u64.into()
+        );
+        assert_eq!(
+            ledger.recently_sent(Some(4), &STAKING_TOKEN_ASSET_ID),
+            Amount::zero()
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn full_ledger_refuses_new_outflows() -> anyhow::Result<()> {
+        let ledger = SpendLedger::in_memory();
+        let outflows = |accounts: std::ops::Range<u32>| -> Outflows {
+            accounts
+                .map(|account| ((Some(account), *STAKING_TOKEN_ASSET_ID), 1u64.into()))
+                .collect()
+        };
+        ledger
+            .lock()
+            .record(&outflows(0..MAX_LEDGER_ENTRIES as u32))?;
+
+        // Outflows of accounts which are already tracked are still recorded.
+        ledger.lock().record(&outflows(0..1))?;
+        let rejection = ledger
+            .lock()
+            .record(&outflows(
+                MAX_LEDGER_ENTRIES as u32..MAX_LEDGER_ENTRIES as u32 + 1,
+            ))
+            .unwrap_err();
+        assert_eq!(rejection.downcast_ref(), Some(&Denial::SpendLedgerFull));
+        assert_eq!(
+            ledger
+                .lock()
+                .recently_sent(Some(0), &STAKING_TOKEN_ASSET_ID),
+            2u64.into()
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn only_outflows_under_a_daily_limit_are_recorded() {
+        let ours = fvk(1);
+        let engine = PolicyEngine::new(
+            ours.clone(),
+            vec![AuthPolicy::AddressIndexRestriction {
+                first_index: 1,
+                last_index: None,
+                frozen: false,
+                daily_limits: vec![DailyLimit {
+                    asset_id: *STAKING_TOKEN_ASSET_ID,
+                    max_amount: 5,
+                }],
+            }],
+        );
+        let mut ledger = engine.ledger().lock();
+        for account in [0, 1] {
+            engine
+                .record(&send_from(&ours, account, 100, 3), &mut ledger)
+                .expect("ledger has room");
+        }
+        assert_eq!(
+            ledger.recently_sent(Some(0), &STAKING_TOKEN_ASSET_ID),
+            Amount::zero()
+        );
+        assert_eq!(
+            ledger.recently_sent(Some(1), &STAKING_TOKEN_ASSET_ID),
+            3u64.into()
+        );
+    }
+}
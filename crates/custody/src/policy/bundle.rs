@@ -0,0 +1,330 @@
+//! Signed, versioned bundles of policy configuration.
+//!
+//! A [`PolicyBundle`] is an export of a custodian's [`AuthPolicy`] configuration, tagged with a
+//! version number. Bundles serialize to TOML or JSON, so that a proposed change can be reviewed and
+//! audited like any other configuration change, and then signed by the parties who approve it.
+//!
+//! A custodian only imports a [`SignedPolicyBundle`] if it carries enough valid signatures from the
+//! configured [`PolicySigners`], and if its version is newer than the version of the policy it
+//! replaces, so that an old bundle can't be replayed to roll back a later change.
+
+use ed25519_consensus::{Signature, SigningKey, VerificationKey};
+use serde::{Deserialize, Serialize};
+
+use super::AuthPolicy;
+
+/// A versioned set of [`AuthPolicy`]s, as exported from or imported into a custodian.
+#[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq)]
+pub struct PolicyBundle {
+    /// The version of the policy configuration, which must increase with every change.
+    pub version: u64,
+    /// The policies enforced by the custodian.
+    #[serde(default)]
+    pub auth_policy: Vec<AuthPolicy>,
+}
+
+impl PolicyBundle {
+    /// The hash of this bundle, which is signed to approve it.
+    ///
+    /// The hash is computed over the JSON encoding of the bundle, so it does not depend on how the
+    /// bundle was formatted when it was reviewed.
+    pub fn hash(&self) -> [u8; 32] {
+        let json = serde_json::to_vec(self).expect("policy bundles can always be encoded as JSON");
+        blake2b_simd::Params::default()
+            .personal(b"Penumbra_PolBndl")
+            .hash_length(32)
+            .hash(&json)
+            .as_bytes()
+            .try_into()
+            .expect("hash length is 32 bytes")
+    }
+
+    /// Sign this bundle, approving it for import.
+    pub fn sign(&self, signing_key: &SigningKey) -> BundleSignature {
+        BundleSignature {
+            signer: signing_key.verification_key().to_bytes(),
+            signature: signing_key.sign(&self.hash()).to_bytes(),
+        }
+    }
+}
+
+/// A signature approving a [`PolicyBundle`].
+#[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq)]
+pub struct BundleSignature {
+    /// The verification key of the signer.
+    #[serde(with = "base64_bytes")]
+    pub signer: [u8; 32],
+    /// A signature over the [`hash`](PolicyBundle::hash) of the bundle.
+    #[serde(with = "base64_bytes")]
+    pub signature: [u8; 64],
+}
+
+/// A [`PolicyBundle`], together with the signatures approving it.
+#[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq)]
+pub struct SignedPolicyBundle {
+    pub bundle: PolicyBundle,
+    #[serde(default)]
+    pub signatures: Vec<BundleSignature>,
+}
+
+/// The keys allowed to approve [`PolicyBundle`]s for import into a custodian.
+#[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq)]
+pub struct PolicySigners {
+    /// The number of distinct signatures required to import a bundle.
+    ///
+    /// Bundles always require at least one signature, even if this is zero.
+    pub required_signatures: u32,
+    /// A list of keys that can be used to approve a bundle.
+    #[serde(with = "super::ed25519_vec_base64")]
+    pub allowed_signers: Vec<VerificationKey>,
+}
+
+impl PolicySigners {
+    /// Count the distinct allowed signers with a signature over `message`.
+    ///
+    /// Signatures from signers which are not allowed are ignored, but an invalid signature from an
+    /// allowed signer is an error, carrying the hex-encoded key of the signer.
+    pub(crate) fn count_signatures<'a>(
+        &self,
+        message: &[u8],
+        signatures: impl IntoIterator<Item = (&'a [u8; 32], &'a [u8; 64])>,
+    ) -> Result<u32, String> {
+        let mut allowed_signers = self.allowed_signers.iter().collect::<Vec<_>>();
+        let mut seen = 0u32;
+
+        for (signer, signature) in signatures {
+            // Remove the signer from the allowed signers, so that each signer can only count once.
+            if let Some(index) = allowed_signers
+                .iter()
+                .position(|allowed| allowed.as_bytes() == signer)
+            {
+                let allowed = allowed_signers.swap_remove(index);
+                allowed
+                    .verify(&Signature::from(*signature), message)
+                    .map_err(|_| hex::encode(signer))?;
+                seen += 1;
+            }
+        }
+
+        Ok(seen)
+    }
+}
+
+/// The reason a [`SignedPolicyBundle`] was refused by a custodian.
+#[derive(Clone, Debug, Eq, PartialEq, thiserror::Error)]
+pub enum BundleRejection {
+    /// The custodian has no policy signers configured, so it can't import bundles.
+    #[error("no policy signers are configured")]
+    NoSigners,
+    /// The bundle carries no signatures from allowed signers.
+    #[error("policy bundle is not signed by any allowed signer")]
+    Unsigned,
+    /// Fewer valid signatures were provided than required.
+    #[error("required {required} policy bundle signatures but only saw {seen}")]
+    InsufficientSignatures {
+        /// The number of signatures required.
+        required: u32,
+        /// The number of valid signatures from distinct allowed signers.
+        seen: u32,
+    },
+    /// A signature from an allowed signer failed to verify.
+    #[error("invalid policy bundle signature from signer {signer}")]
+    InvalidSignature {
+        /// The hex-encoded verification key of the signer.
+        signer: String,
+    },
+    /// The bundle's version is not newer than the current policy's version.
+    #[error("policy bundle version {version} is not newer than current version {current}")]
+    Downgrade {
+        /// The version of the bundle.
+        version: u64,
+        /// The version of the custodian's current policy.
+        current: u64,
+    },
+}
+
+impl SignedPolicyBundle {
+    /// Create a bundle with no signatures.
+    pub fn new(bundle: PolicyBundle) -> Self {
+        Self {
+            bundle,
+            signatures: Vec::new(),
+        }
+    }
+
+    /// Add a signature approving the bundle.
+    pub fn sign(&mut self, signing_key: &SigningKey) {
+        self.signatures.push(self.bundle.sign(signing_key));
+    }
+
+    /// Check that the bundle is approved by the given signers, and may replace a policy with the
+    /// given version.
+    ///
+    /// Signatures from signers which are not allowed are ignored, but an invalid signature from an
+    /// allowed signer causes the bundle to be refused.
+    pub fn verify(
+        &self,
+        signers: &PolicySigners,
+        current_version: u64,
+    ) -> Result<&PolicyBundle, BundleRejection> {
+        let seen = signers
+            .count_signatures(
+                &self.bundle.hash(),
+                self.signatures
+                    .iter()
+                    .map(|signature| (&signature.signer, &signature.signature)),
+            )
+            .map_err(|signer| BundleRejection::InvalidSignature { signer })?;
+
+        if seen == 0 {
+            return Err(BundleRejection::Unsigned);
+        }
+        if seen < signers.required_signatures {
+            return Err(BundleRejection::InsufficientSignatures {
+                required: signers.required_signatures,
+                seen,
+            });
+        }
+        if self.bundle.version <= current_version {
+            return Err(BundleRejection::Downgrade {
+                version: self.bundle.version,
+                current: current_version,
+            });
+        }
+
+        Ok(&self.bundle)
+    }
+}
+
+/// A serde helper to serialize fixed-length byte arrays as base64-encoded data, in the same way as
+/// the keys in [`PolicySigners`].
+mod base64_bytes {
+    use base64::prelude::*;
+
+    pub fn serialize<S: serde::Serializer, const N: usize>(
+        bytes: &[u8; N],
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&BASE64_STANDARD.encode(bytes))
+    }
+
+    pub fn deserialize<'de, D, const N: usize>(deserializer: D) -> Result<[u8; N], D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        use serde::Deserialize;
+        let bytes = BASE64_STANDARD
+            .decode(String::deserialize(deserializer)?)
+            .map_err(serde::de::Error::custom)?;
+        bytes.as_slice().try_into().map_err(|_| {
+            serde::de::Error::custom(format!("expected {N} bytes, got {}", bytes.len()))
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand_core::OsRng;
+
+    use super::*;
+    use crate::policy::ActionKind;
+
+    fn bundle(version: u64) -> PolicyBundle {
+        PolicyBundle {
+            version,
+            auth_policy: vec![
+                AuthPolicy::ActionDenyList {
+                    denied_actions: vec![ActionKind::ProposalSubmit],
+                },
+                AuthPolicy::SpendLimit {
+                    asset_id: *penumbra_asset::STAKING_TOKEN_ASSET_ID,
+                    max_amount: 1_000_000,
+                },
+            ],
+        }
+    }
+
+    #[test]
+    fn bundles_require_allowed_signatures() {
+        let alice = SigningKey::new(OsRng);
+        let bob = SigningKey::new(OsRng);
+        let mallory = SigningKey::new(OsRng);
+        let signers = PolicySigners {
+            required_signatures: 2,
+            allowed_signers: vec![alice.verification_key(), bob.verification_key()],
+        };
+
+        let mut signed = SignedPolicyBundle::new(bundle(1));
+        assert_eq!(signed.verify(&signers, 0), Err(BundleRejection::Unsigned));
+
+        // Signatures from other keys don't count.
+        signed.sign(&mallory);
+        assert_eq!(signed.verify(&signers, 0), Err(BundleRejection::Unsigned));
+
+        // Nor do repeated signatures from the same key.
+        signed.sign(&alice);
+        signed.sign(&alice);
+        assert_eq!(
+            signed.verify(&signers, 0),
+            Err(BundleRejection::InsufficientSignatures {
+                required: 2,
+                seen: 1
+            })
+        );
+
+        signed.sign(&bob);
+        assert_eq!(signed.verify(&signers, 0), Ok(&signed.bundle));
+
+        // Changing the bundle after it was signed invalidates the signatures.
+        let mut tampered = signed.clone();
+        tampered.bundle.auth_policy.pop();
+        assert!(matches!(
+            tampered.verify(&signers, 0),
+            Err(BundleRejection::InvalidSignature { .. })
+        ));
+    }
+
+    #[test]
+    fn bundles_cannot_be_downgraded() {
+        let signing_key = SigningKey::new(OsRng);
+        let signers = PolicySigners {
+            required_signatures: 1,
+            allowed_signers: vec![signing_key.verification_key()],
+        };
+
+        let mut signed = SignedPolicyBundle::new(bundle(3));
+        signed.sign(&signing_key);
+
+        assert!(signed.verify(&signers, 2).is_ok());
+        for current in [3, 4] {
+            assert_eq!(
+                signed.verify(&signers, current),
+                Err(BundleRejection::Downgrade {
+                    version: 3,
+                    current
+                })
+            );
+        }
+    }
+
+    #[test]
+    fn signed_bundles_round_trip() {
+        let signing_key = SigningKey::new(OsRng);
+        let signers = PolicySigners {
+            required_signatures: 1,
+            allowed_signers: vec![signing_key.verification_key()],
+        };
+
+        let mut signed = SignedPolicyBundle::new(bundle(1));
+        signed.sign(&signing_key);
+
+        let encoded = toml::to_string_pretty(&signed).unwrap();
+        let decoded: SignedPolicyBundle = toml::from_str(&encoded).unwrap();
+        assert_eq!(decoded, signed);
+        assert!(decoded.verify(&signers, 0).is_ok());
+
+        let encoded = serde_json::to_string(&signed).unwrap();
+        let decoded: SignedPolicyBundle = serde_json::from_str(&encoded).unwrap();
+        assert!(decoded.verify(&signers, 0).is_ok());
+    }
+}
@@ -1,6 +1,8 @@
+use base64::prelude::*;
 use penumbra_proto::{custody::v1 as pb, DomainType};
 use penumbra_transaction::TransactionPlan;
 use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
 
 /// A pre-authorization packet.  This allows a custodian to delegate (partial)
 /// signing authority to other authorization mechanisms.  Details of how a
@@ -10,6 +12,8 @@ use serde::{Deserialize, Serialize};
 #[serde(try_from = "pb::PreAuthorization", into = "pb::PreAuthorization")]
 pub enum PreAuthorization {
     Ed25519(Ed25519),
+    Secp256k1(Secp256k1),
+    WebAuthn(WebAuthn),
 }
 
 /// An Ed25519-based preauthorization, containing an Ed25519 signature over the
@@ -34,6 +38,120 @@ impl Ed25519 {
     }
 }
 
+/// A secp256k1-based preauthorization, containing an ECDSA signature over the
+/// SHA-256 hash of the `TransactionPlan`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(
+    try_from = "pb::pre_authorization::Secp256k1",
+    into = "pb::pre_authorization::Secp256k1"
+)]
+pub struct Secp256k1 {
+    /// The verification key used to pre-authorize the `TransactionPlan`.
+    pub vk: k256::ecdsa::VerifyingKey,
+    /// An ECDSA signature over the `TransactionPlan`.
+    pub sig: k256::ecdsa::Signature,
+}
+
+impl Secp256k1 {
+    /// Verifies the provided `TransactionPlan`.
+    pub fn verify_plan(&self, plan: &TransactionPlan) -> anyhow::Result<()> {
+        use k256::ecdsa::signature::Verifier;
+
+        let plan_bytes = plan.encode_to_vec();
+        self.vk.verify(&plan_bytes, &self.sig).map_err(Into::into)
+    }
+}
+
+/// A WebAuthn (passkey) preauthorization, containing an ES256 assertion whose
+/// challenge is the SHA-256 hash of the `TransactionPlan`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(
+    try_from = "pb::pre_authorization::WebAuthn",
+    into = "pb::pre_authorization::WebAuthn"
+)]
+pub struct WebAuthn {
+    /// The public key of the credential used to pre-authorize the `TransactionPlan`.
+    pub public_key: p256::ecdsa::VerifyingKey,
+    /// The raw `authenticatorData` returned by the authenticator.
+    pub authenticator_data: Vec<u8>,
+    /// The raw `clientDataJSON` returned by the client.
+    pub client_data_json: Vec<u8>,
+    /// An ECDSA signature over `authenticatorData || SHA-256(clientDataJSON)`.
+    pub sig: p256::ecdsa::Signature,
+}
+
+/// A pre-authorizer whose signature was verified by a
+/// [`PreAuthorizationPolicy`](crate::policy::PreAuthorizationPolicy),
+/// identified by its verification key, for the audit log.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Approver {
+    Ed25519(ed25519_consensus::VerificationKey),
+    Secp256k1(k256::ecdsa::VerifyingKey),
+    WebAuthn(p256::ecdsa::VerifyingKey),
+}
+
+/// The subset of the WebAuthn `CollectedClientData` that we check.
+#[derive(Deserialize)]
+struct ClientData {
+    #[serde(rename = "type")]
+    ty: String,
+    challenge: String,
+    origin: String,
+}
+
+impl WebAuthn {
+    /// The length of the fixed-size prefix of `authenticatorData`: the
+    /// relying party ID hash, the flags byte, and the signature counter.
+    const AUTHENTICATOR_DATA_MIN_LEN: usize = 37;
+
+    /// The "user present" bit of the `authenticatorData` flags.
+    const USER_PRESENT: u8 = 0x01;
+
+    /// Verifies the provided `TransactionPlan`, checking that the assertion
+    /// was made for the given relying party ID, from the origin
+    /// `https://<relying_party_id>`.
+    pub fn verify_plan(
+        &self,
+        plan: &TransactionPlan,
+        relying_party_id: &str,
+    ) -> anyhow::Result<()> {
+        use p256::ecdsa::signature::Verifier;
+
+        let client_data: ClientData = serde_json::from_slice(&self.client_data_json)?;
+        if client_data.ty != "webauthn.get" {
+            anyhow::bail!("unexpected client data type {}", client_data.ty);
+        }
+        let expected_challenge =
+            BASE64_URL_SAFE_NO_PAD.encode(Sha256::digest(plan.encode_to_vec()));
+        if client_data.challenge != expected_challenge {
+            anyhow::bail!("challenge does not match transaction plan");
+        }
+        let expected_origin = format!("https://{relying_party_id}");
+        if client_data.origin != expected_origin {
+            anyhow::bail!(
+                "assertion was made from origin {}, not {expected_origin}",
+                client_data.origin
+            );
+        }
+
+        if self.authenticator_data.len() < Self::AUTHENTICATOR_DATA_MIN_LEN {
+            anyhow::bail!("authenticator data is too short");
+        }
+        if self.authenticator_data[..32] != Sha256::digest(relying_party_id.as_bytes())[..] {
+            anyhow::bail!("assertion was not made for relying party {relying_party_id}");
+        }
+        if self.authenticator_data[32] & Self::USER_PRESENT == 0 {
+            anyhow::bail!("assertion was made without user presence");
+        }
+
+        let mut signed_data = self.authenticator_data.clone();
+        signed_data.extend_from_slice(&Sha256::digest(&self.client_data_json));
+        self.public_key
+            .verify(&signed_data, &self.sig)
+            .map_err(Into::into)
+    }
+}
+
 impl DomainType for PreAuthorization {
     type Proto = pb::PreAuthorization;
 }
@@ -45,6 +163,12 @@ impl TryFrom<pb::PreAuthorization> for PreAuthorization {
             Some(pb::pre_authorization::PreAuthorization::Ed25519(ed)) => {
                 Self::Ed25519(ed.try_into()?)
             }
+            Some(pb::pre_authorization::PreAuthorization::Secp256k1(secp)) => {
+                Self::Secp256k1(secp.try_into()?)
+            }
+            Some(pb::pre_authorization::PreAuthorization::WebAuthn(webauthn)) => {
+                Self::WebAuthn(webauthn.try_into()?)
+            }
             None => {
                 anyhow::bail!("missing pre-authorization");
             }
@@ -59,6 +183,12 @@ impl From<PreAuthorization> for pb::PreAuthorization {
                 PreAuthorization::Ed25519(ed) => {
                     pb::pre_authorization::PreAuthorization::Ed25519(ed.into())
                 }
+                PreAuthorization::Secp256k1(secp) => {
+                    pb::pre_authorization::PreAuthorization::Secp256k1(secp.into())
+                }
+                PreAuthorization::WebAuthn(webauthn) => {
+                    pb::pre_authorization::PreAuthorization::WebAuthn(webauthn.into())
+                }
             }),
         }
     }
@@ -86,3 +216,183 @@ impl From<Ed25519> for pb::pre_authorization::Ed25519 {
         }
     }
 }
+
+impl DomainType for Secp256k1 {
+    type Proto = pb::pre_authorization::Secp256k1;
+}
+
+impl TryFrom<pb::pre_authorization::Secp256k1> for Secp256k1 {
+    type Error = anyhow::Error;
+    fn try_from(value: pb::pre_authorization::Secp256k1) -> Result<Self, Self::Error> {
+        Ok(Self {
+            vk: k256::ecdsa::VerifyingKey::from_sec1_bytes(&value.vk)?,
+            sig: k256::ecdsa::Signature::from_slice(&value.sig)?,
+        })
+    }
+}
+
+impl From<Secp256k1> for pb::pre_authorization::Secp256k1 {
+    fn from(value: Secp256k1) -> pb::pre_authorization::Secp256k1 {
+        Self {
+            vk: value.vk.to_encoded_point(true).as_bytes().to_vec(),
+            sig: value.sig.to_bytes().to_vec(),
+        }
+    }
+}
+
+impl DomainType for WebAuthn {
+    type Proto = pb::pre_authorization::WebAuthn;
+}
+
+impl TryFrom<pb::pre_authorization::WebAuthn> for WebAuthn {
+    type Error = anyhow::Error;
+    fn try_from(value: pb::pre_authorization::WebAuthn) -> Result<Self, Self::Error> {
+        Ok(Self {
+            public_key: p256::ecdsa::VerifyingKey::from_sec1_bytes(&value.public_key)?,
+            authenticator_data: value.authenticator_data,
+            client_data_json: value.client_data_json,
+            sig: p256::ecdsa::Signature::from_der(&value.sig)?,
+        })
+    }
+}
+
+impl From<WebAuthn> for pb::pre_authorization::WebAuthn {
+    fn from(value: WebAuthn) -> pb::pre_authorization::WebAuthn {
+        Self {
+            public_key: value.public_key.to_encoded_point(true).as_bytes().to_vec(),
+            authenticator_data: value.authenticator_data,
+            client_data_json: value.client_data_json,
+            sig: value.sig.to_der().as_bytes().to_vec(),
+        }
+    }
+}
+
+impl DomainType for Approver {
+    type Proto = pb::Approver;
+}
+
+impl TryFrom<pb::Approver> for Approver {
+    type Error = anyhow::Error;
+    fn try_from(value: pb::Approver) -> Result<Self, Self::Error> {
+        Ok(match value.key {
+            Some(pb::approver::Key::Ed25519(vk)) => Approver::Ed25519(vk.as_slice().try_into()?),
+            Some(pb::approver::Key::Secp256k1(vk)) => {
+                Approver::Secp256k1(k256::ecdsa::VerifyingKey::from_sec1_bytes(&vk)?)
+            }
+            Some(pb::approver::Key::WebAuthn(public_key)) => {
+                Approver::WebAuthn(p256::ecdsa::VerifyingKey::from_sec1_bytes(&public_key)?)
+            }
+            None => anyhow::bail!("missing key in approver"),
+        })
+    }
+}
+
+impl From<Approver> for pb::Approver {
+    fn from(value: Approver) -> pb::Approver {
+        Self {
+            key: Some(match value {
+                Approver::Ed25519(vk) => pb::approver::Key::Ed25519(vk.to_bytes().into()),
+                Approver::Secp256k1(vk) => {
+                    pb::approver::Key::Secp256k1(vk.to_encoded_point(true).as_bytes().to_vec())
+                }
+                Approver::WebAuthn(public_key) => pb::approver::Key::WebAuthn(
+                    public_key.to_encoded_point(true).as_bytes().to_vec(),
+                ),
+            }),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const RP_ID: &str = "custody.example.com";
+
+    fn webauthn_assertion(
+        plan: &TransactionPlan,
+        rp_id: &str,
+        origin: &str,
+        flags: u8,
+    ) -> WebAuthn {
+        use p256::ecdsa::{signature::Signer, SigningKey};
+
+        let sk = SigningKey::random(&mut rand_core::OsRng);
+        let challenge = BASE64_URL_SAFE_NO_PAD.encode(Sha256::digest(plan.encode_to_vec()));
+        let client_data_json = serde_json::json!({
+            "type": "webauthn.get",
+            "challenge": challenge,
+            "origin": origin,
+        })
+        .to_string()
+        .into_bytes();
+
+        let mut authenticator_data = Sha256::digest(rp_id.as_bytes()).to_vec();
+        authenticator_data.push(flags);
+        authenticator_data.extend_from_slice(&0u32.to_be_bytes());
+
+        let mut signed_data = authenticator_data.clone();
+        signed_data.extend_from_slice(&Sha256::digest(&client_data_json));
+
+        WebAuthn {
+            public_key: *sk.verifying_key(),
+            authenticator_data,
+            client_data_json,
+            sig: sk.sign(&signed_data),
+        }
+    }
+
+    #[test]
+    fn secp256k1_verifies_plan() {
+        use k256::ecdsa::{signature::Signer, SigningKey};
+
+        let plan = TransactionPlan::default();
+        let sk = SigningKey::random(&mut rand_core::OsRng);
+        let pre_auth = Secp256k1 {
+            vk: *sk.verifying_key(),
+            sig: sk.sign(&plan.encode_to_vec()),
+        };
+        pre_auth.verify_plan(&plan).unwrap();
+
+        // Round-trip through the proto encoding.
+        let pre_auth = Secp256k1::decode(pre_auth.encode_to_vec().as_slice()).unwrap();
+        pre_auth.verify_plan(&plan).unwrap();
+    }
+
+    #[test]
+    fn webauthn_verifies_plan() {
+        let plan = TransactionPlan::default();
+        let pre_auth = webauthn_assertion(
+            &plan,
+            RP_ID,
+            &format!("https://{RP_ID}"),
+            WebAuthn::USER_PRESENT,
+        );
+        pre_auth.verify_plan(&plan, RP_ID).unwrap();
+
+        // Round-trip through the proto encoding.
+        let pre_auth = WebAuthn::decode(pre_auth.encode_to_vec().as_slice()).unwrap();
+        pre_auth.verify_plan(&plan, RP_ID).unwrap();
+
+        assert!(pre_auth.verify_plan(&plan, "evil.example.com").is_err());
+    }
+
+    #[test]
+    fn webauthn_rejects_missing_user_presence() {
+        let plan = TransactionPlan::default();
+        let pre_auth = webauthn_assertion(&plan, RP_ID, &format!("https://{RP_ID}"), 0);
+        assert!(pre_auth.verify_plan(&plan, RP_ID).is_err());
+    }
+
+    #[test]
+    fn webauthn_rejects_mismatched_origin() {
+        let plan = TransactionPlan::default();
+        let pre_auth = webauthn_assertion(
+            &plan,
+            RP_ID,
+            "https://evil.example.com",
+            WebAuthn::USER_PRESENT,
+        );
+        assert!(pre_auth.verify_plan(&plan, RP_ID).is_err());
+    }
+}
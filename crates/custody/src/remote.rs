@@ -0,0 +1,627 @@
+//! Remote signing over a mutually-authenticated TLS connection.
+//!
+//! Some operators keep their signer on a separate machine, but cannot expose a
+//! gRPC endpoint for it, for instance because the signer sits behind a proxy
+//! that only forwards raw TCP.  This module carries the custody protocol over a
+//! plain TLS stream instead, in which both ends present certificates issued by
+//! a CA the other trusts:
+//!
+//! - a [`RemoteSigner`] is a [`CustodyChannel`] which connects to the signer,
+//!   reconnecting with backoff when the connection drops;
+//! - [`serve`] accepts connections from authenticated clients, and dispatches
+//!   their requests to any [`CustodyService`].
+//!
+//! Each request is sent as a length-prefixed frame holding the gRPC path of the
+//! custody method and the encoded request, and answered by a frame holding a
+//! status byte and the encoded response, or an error message.  Only the unary
+//! custody methods are supported.
+
+use std::{io::BufReader, net::SocketAddr, path::Path, sync::Arc, time::Duration};
+
+use anyhow::{Context as _, Result};
+use penumbra_proto::{
+    custody::v1::custody_service_server::CustodyService, rpc::RetryPolicy, Message,
+};
+use tokio::{
+    io::{AsyncRead, AsyncReadExt as _, AsyncWrite, AsyncWriteExt as _},
+    net::{TcpListener, TcpStream},
+    sync::Mutex,
+};
+use tokio_rustls::{
+    client::TlsStream,
+    rustls::{
+        self, server::AllowAnyAuthenticatedClient, Certificate, PrivateKey, RootCertStore,
+        ServerName,
+    },
+    TlsAcceptor, TlsConnector,
+};
+
+use crate::{CustodyChannel, CustodyFuture, Unimplemented};
+
+/// The largest frame either end will accept, in bytes.
+pub const MAX_FRAME_LEN: usize = 16 * 1024 * 1024;
+
+/// The status byte of a response frame holding a response.
+const STATUS_OK: u8 = 0;
+/// The status byte of a response frame for a method the signer doesn't
+/// implement.
+const STATUS_UNIMPLEMENTED: u8 = 1;
+/// The status byte of a response frame holding an error message.
+const STATUS_ERROR: u8 = 2;
+
+/// The certificates and key one end of a remote signing connection presents,
+/// along with the certificates of the CAs it trusts to issue the other end's.
+#[derive(Clone, Debug)]
+pub struct TlsIdentity {
+    /// The certificates of the CAs trusted to authenticate the peer.
+    pub ca_certs: Vec<Certificate>,
+    /// This end's certificate chain, starting with its own certificate.
+    pub cert_chain: Vec<Certificate>,
+    /// The private key for this end's certificate.
+    pub key: PrivateKey,
+}
+
+impl TlsIdentity {
+    /// Load an identity from PEM files holding the trusted CA certificates,
+    /// this end's certificate chain, and its private key.
+    pub fn from_pem_files(
+        ca_certs: impl AsRef<Path>,
+        cert_chain: impl AsRef<Path>,
+        key: impl AsRef<Path>,
+    ) -> Result<Self> {
+        Ok(Self {
+            ca_certs: load_certs(ca_certs.as_ref())?,
+            cert_chain: load_certs(cert_chain.as_ref())?,
+            key: load_private_key(key.as_ref())?,
+        })
+    }
+
+    fn roots(&self) -> Result<RootCertStore> {
+        let mut roots = RootCertStore::empty();
+        for cert in &self.ca_certs {
+            roots.add(cert).context("invalid CA certificate")?;
+        }
+        Ok(roots)
+    }
+
+    /// The TLS configuration for a client presenting this identity.
+    pub fn client_config(&self) -> Result<rustls::ClientConfig> {
+        rustls::ClientConfig::builder()
+            .with_safe_defaults()
+            .with_root_certificates(self.roots()?)
+            .with_client_auth_cert(self.cert_chain.clone(), self.key.clone())
+            .context("invalid client certificate or key")
+    }
+
+    /// The TLS configuration for a server presenting this identity, which only
+    /// accepts clients with a certificate issued by one of the trusted CAs.
+    pub fn server_config(&self) -> Result<rustls::ServerConfig> {
+        let verifier = AllowAnyAuthenticatedClient::new(self.roots()?).boxed();
+        rustls::ServerConfig::builder()
+            .with_safe_defaults()
+            .with_client_cert_verifier(verifier)
+            .with_single_cert(self.cert_chain.clone(), self.key.clone())
+            .context("invalid server certificate or key")
+    }
+}
+
+fn load_certs(path: &Path) -> Result<Vec<Certificate>> {
+    let file = std::fs::File::open(path)
+        .with_context(|| format!("could not open certificates at {}", path.display()))?;
+    let certs = rustls_pemfile::certs(&mut BufReader::new(file))
+        .with_context(|| format!("invalid certificates at {}", path.display()))?;
+    anyhow::ensure!(!certs.is_empty(), "no certificates in {}", path.display());
+    Ok(certs.into_iter().map(Certificate).collect())
+}
+
+fn load_private_key(path: &Path) -> Result<PrivateKey> {
+    let file = std::fs::File::open(path)
+        .with_context(|| format!("could not open private key at {}", path.display()))?;
+    for item in rustls_pemfile::read_all(&mut BufReader::new(file))
+        .with_context(|| format!("invalid private key at {}", path.display()))?
+    {
+        match item {
+            rustls_pemfile::Item::PKCS8Key(key)
+            | rustls_pemfile::Item::RSAKey(key)
+            | rustls_pemfile::Item::ECKey(key) => return Ok(PrivateKey(key)),
+            _ => {}
+        }
+    }
+    anyhow::bail!("no private key in {}", path.display())
+}
+
+async fn read_frame<R: AsyncRead + Unpin>(reader: &mut R) -> std::io::Result<Vec<u8>> {
+    let len = reader.read_u32().await? as usize;
+    if len > MAX_FRAME_LEN {
+        return Err(std::io::Error::new(
+            std::io::ErrorKind::InvalidData,
+            format!("frame of {len} bytes exceeds the maximum of {MAX_FRAME_LEN}"),
+        ));
+    }
+    let mut frame = vec![0; len];
+    reader.read_exact(&mut frame).await?;
+    Ok(frame)
+}
+
+async fn write_frame<W: AsyncWrite + Unpin>(writer: &mut W, frame: &[u8]) -> std::io::Result<()> {
+    if frame.len() > MAX_FRAME_LEN {
+        return Err(std::io::Error::new(
+            std::io::ErrorKind::InvalidInput,
+            format!(
+                "frame of {} bytes exceeds the maximum of {MAX_FRAME_LEN}",
+                frame.len()
+            ),
+        ));
+    }
+    writer.write_u32(frame.len() as u32).await?;
+    writer.write_all(frame).await?;
+    writer.flush().await
+}
+
+/// Encode a request for the given method as the body of a frame.
+fn encode_request(path: &str, request: &[u8]) -> Vec<u8> {
+    let mut frame = Vec::with_capacity(2 + path.len() + request.len());
+    frame.extend_from_slice(&(path.len() as u16).to_be_bytes());
+    frame.extend_from_slice(path.as_bytes());
+    frame.extend_from_slice(request);
+    frame
+}
+
+/// Split the body of a request frame into the method path and the request.
+fn decode_request(frame: &[u8]) -> Result<(&str, &[u8])> {
+    anyhow::ensure!(frame.len() >= 2, "request frame is too short");
+    let (len, rest) = frame.split_at(2);
+    let len = u16::from_be_bytes([len[0], len[1]]) as usize;
+    anyhow::ensure!(rest.len() >= len, "request frame is too short");
+    let (path, request) = rest.split_at(len);
+    Ok((
+        std::str::from_utf8(path).context("method path is not utf-8")?,
+        request,
+    ))
+}
+
+/// Decode the body of a response frame into the encoded response.
+fn decode_response(frame: &[u8]) -> Result<Vec<u8>> {
+    match frame.split_first() {
+        Some((&STATUS_OK, response)) => Ok(response.to_vec()),
+        Some((&STATUS_UNIMPLEMENTED, _)) => Err(Unimplemented.into()),
+        Some((&STATUS_ERROR, message)) => Err(anyhow::anyhow!(
+            "remote signer returned an error: {}",
+            String::from_utf8_lossy(message)
+        )),
+        Some((status, _)) => Err(anyhow::anyhow!("unknown response status {status}")),
+        None => Err(anyhow::anyhow!("empty response frame")),
+    }
+}
+
+/// Whether the method at the given path leaves the signer's state unchanged,
+/// so that it can be resent when it's unknown whether the signer received it.
+///
+/// Authorizations consume nonces and count against spend limits, and the other
+/// methods missing here change the signer's safeguards, so resending them
+/// could act twice on one request.
+fn is_idempotent(path: &str) -> bool {
+    matches!(
+        path,
+        "/penumbra.custody.v1.CustodyService/ExportFullViewingKey"
+            | "/penumbra.custody.v1.CustodyService/ConfirmAddress"
+            | "/penumbra.custody.v1.CustodyService/QueryAuditLog"
+            | "/penumbra.custody.v1.CustodyService/ExportPolicy"
+    )
+}
+
+/// A [`CustodyChannel`] to a remote signer, over a mutually-authenticated TLS
+/// connection.
+///
+/// The connection is opened when the first request is sent, and is shared by
+/// all clones of the channel, so requests are sent one at a time.  If the
+/// connection can't be opened, it is retried according to the channel's
+/// [`RetryPolicy`].  If a request which only reads from the signer fails on a
+/// connection that was already open, before any part of the response was
+/// received and without timing out, it is assumed that the signer dropped the
+/// idle connection, and the request is sent once more over a new connection.
+/// Other requests, like authorizations, are never resent, since the signer may
+/// have acted on them before the connection broke.
+#[derive(Clone)]
+pub struct RemoteSigner {
+    address: String,
+    server_name: ServerName,
+    connector: TlsConnector,
+    retry: RetryPolicy,
+    timeout: Option<Duration>,
+    connection: Arc<Mutex<Option<TlsStream<TcpStream>>>>,
+}
+
+impl std::fmt::Debug for RemoteSigner {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("RemoteSigner")
+            .field("address", &self.address)
+            .field("server_name", &self.server_name)
+            .field("retry", &self.retry)
+            .field("timeout", &self.timeout)
+            .finish_non_exhaustive()
+    }
+}
+
+impl RemoteSigner {
+    /// Configure a channel to the signer at the given `host:port` address,
+    /// whose certificate must be valid for `server_name`.
+    ///
+    /// This does not connect to the signer until the first request is sent.
+    pub fn new(
+        address: impl Into<String>,
+        server_name: &str,
+        identity: &TlsIdentity,
+    ) -> Result<Self> {
+        Ok(Self {
+            address: address.into(),
+            server_name: ServerName::try_from(server_name)
+                .with_context(|| format!("invalid server name {server_name}"))?,
+            connector: TlsConnector::from(Arc::new(identity.client_config()?)),
+            retry: RetryPolicy::default(),
+            timeout: None,
+            connection: Default::default(),
+        })
+    }
+
+    /// Retry failed connection attempts according to the given policy.
+    pub fn retry_policy(mut self, retry: RetryPolicy) -> Self {
+        self.retry = retry;
+        self
+    }
+
+    /// Give up on each connection attempt and each request after the given
+    /// duration.
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    async fn with_timeout<T>(
+        &self,
+        future: impl std::future::Future<Output = std::io::Result<T>>,
+    ) -> std::io::Result<T> {
+        match self.timeout {
+            Some(timeout) => tokio::time::timeout(timeout, future)
+                .await
+                .map_err(|_| std::io::ErrorKind::TimedOut.into())
+                .and_then(|result| result),
+            None => future.await,
+        }
+    }
+
+    async fn connect_once(&self) -> std::io::Result<TlsStream<TcpStream>> {
+        self.with_timeout(async {
+            let stream = TcpStream::connect(&self.address).await?;
+            stream.set_nodelay(true)?;
+            self.connector
+                .connect(self.server_name.clone(), stream)
+                .await
+        })
+        .await
+    }
+
+    async fn connect(&self) -> Result<TlsStream<TcpStream>> {
+        let mut backoff = self.retry.initial_backoff;
+        let mut retries = 0;
+        loop {
+            match self.connect_once().await {
+                Ok(stream) => return Ok(stream),
+                Err(error) if retries < self.retry.max_retries => {
+                    tracing::debug!(address = %self.address, ?error, ?backoff, "retrying connection to remote signer");
+                    tokio::time::sleep(backoff).await;
+                    backoff = (backoff * 2).min(self.retry.max_backoff);
+                    retries += 1;
+                }
+                Err(error) => {
+                    return Err(error).with_context(|| {
+                        format!("could not connect to remote signer at {}", self.address)
+                    })
+                }
+            }
+        }
+    }
+
+    /// Send a request frame and read the response frame, returning whether
+    /// any of the response was received if it fails.
+    async fn exchange(
+        &self,
+        stream: &mut TlsStream<TcpStream>,
+        frame: &[u8],
+    ) -> Result<Vec<u8>, (std::io::Error, bool)> {
+        self.with_timeout(write_frame(stream, frame))
+            .await
+            .map_err(|error| (error, false))?;
+        // Wait for the first byte of the response separately, to tell a
+        // connection that was closed before the signer saw the request from
+        // one that broke while it was answering.
+        let mut first = [0u8; 1];
+        self.with_timeout(stream.read_exact(&mut first))
+            .await
+            .map_err(|error| (error, false))?;
+        self.with_timeout(async {
+            let mut rest = [0u8; 3];
+            stream.read_exact(&mut rest).await?;
+            let len = u32::from_be_bytes([first[0], rest[0], rest[1], rest[2]]) as usize;
+            if len > MAX_FRAME_LEN {
+                return Err(std::io::Error::new(
+                    std::io::ErrorKind::InvalidData,
+                    format!("frame of {len} bytes exceeds the maximum of {MAX_FRAME_LEN}"),
+                ));
+            }
+            let mut response = vec![0; len];
+            stream.read_exact(&mut response).await?;
+            Ok(response)
+        })
+        .await
+        .map_err(|error| (error, true))
+    }
+
+    async fn request(&self, path: &str, request: &[u8]) -> Result<Vec<u8>> {
+        let frame = encode_request(path, request);
+        let mut connection = self.connection.lock().await;
+
+        let (mut stream, mut reused) = match connection.take() {
+            Some(stream) => (stream, true),
+            None => (self.connect().await?, false),
+        };
+        loop {
+            match self.exchange(&mut stream, &frame).await {
+                Ok(response) => {
+                    *connection = Some(stream);
+                    return decode_response(&response);
+                }
+                Err((error, false))
+                    if reused
+                        && is_idempotent(path)
+                        && error.kind() != std::io::ErrorKind::TimedOut =>
+                {
+                    tracing::debug!(address = %self.address, ?error, "remote signer connection was closed, reconnecting");
+                    stream = self.connect().await?;
+                    reused = false;
+                }
+                Err((error, _)) => {
+                    return Err(error).with_context(|| {
+                        format!("request to remote signer at {} failed", self.address)
+                    })
+                }
+            }
+        }
+    }
+}
+
+impl CustodyChannel for RemoteSigner {
+    fn unary(&mut self, path: &'static str, request: Vec<u8>) -> CustodyFuture<Vec<u8>> {
+        let signer = self.clone();
+        Box::pin(async move { signer.request(path, &request).await })
+    }
+}
+
+/// Decode a request, pass it to the given method of the service, and encode
+/// its response.
+macro_rules! unary {
+    ($service:expr, $method:ident, $request:expr) => {
+        $service
+            .$method(tonic::Request::new(
+                Message::decode($request)
+                    .map_err(|e| tonic::Status::invalid_argument(e.to_string()))?,
+            ))
+            .await?
+            .into_inner()
+            .encode_to_vec()
+    };
+}
+
+async fn dispatch<S: CustodyService>(
+    service: &S,
+    path: &str,
+    request: &[u8],
+) -> Result<Vec<u8>, tonic::Status> {
+    Ok(match path {
+        "/penumbra.custody.v1.CustodyService/Authorize" => unary!(service, authorize, request),
+        "/penumbra.custody.v1.CustodyService/SimulateAuthorization" => {
+            unary!(service, simulate_authorization, request)
+        }
+        "/penumbra.custody.v1.CustodyService/ExportFullViewingKey" => {
+            unary!(service, export_full_viewing_key, request)
+        }
+        "/penumbra.custody.v1.CustodyService/ConfirmAddress" => {
+            unary!(service, confirm_address, request)
+        }
+        "/penumbra.custody.v1.CustodyService/Challenge" => unary!(service, challenge, request),
+        "/penumbra.custody.v1.CustodyService/QueryAuditLog" => {
+            unary!(service, query_audit_log, request)
+        }
+        "/penumbra.custody.v1.CustodyService/Freeze" => unary!(service, freeze, request),
+        "/penumbra.custody.v1.CustodyService/Unfreeze" => unary!(service, unfreeze, request),
+        "/penumbra.custody.v1.CustodyService/RotateAuthorizationKey" => {
+            unary!(service, rotate_authorization_key, request)
+        }
+        "/penumbra.custody.v1.CustodyService/ExportPolicy" => {
+            unary!(service, export_policy, request)
+        }
+        "/penumbra.custody.v1.CustodyService/ImportPolicy" => {
+            unary!(service, import_policy, request)
+        }
+        _ => {
+            return Err(tonic::Status::unimplemented(format!(
+                "unknown method {path}"
+            )))
+        }
+    })
+}
+
+/// Encode the result of a request as the body of a response frame.
+fn encode_response(result: Result<Vec<u8>, tonic::Status>) -> Vec<u8> {
+    match result {
+        Ok(response) => [&[STATUS_OK][..], &response].concat(),
+        Err(status) if status.code() == tonic::Code::Unimplemented => vec![STATUS_UNIMPLEMENTED],
+        Err(status) => [&[STATUS_ERROR][..], status.message().as_bytes()].concat(),
+    }
+}
+
+async fn serve_connection<S: CustodyService>(
+    acceptor: TlsAcceptor,
+    stream: TcpStream,
+    peer: SocketAddr,
+    service: Arc<S>,
+) -> Result<()> {
+    let mut stream = acceptor
+        .accept(stream)
+        .await
+        .context("TLS handshake failed")?;
+    tracing::debug!(%peer, "accepted remote signing connection");
+
+    loop {
+        let frame = match read_frame(&mut stream).await {
+            Ok(frame) => frame,
+            Err(error) if error.kind() == std::io::ErrorKind::UnexpectedEof => {
+                tracing::debug!(%peer, "remote signing client disconnected");
+                return Ok(());
+            }
+            Err(error) => return Err(error.into()),
+        };
+        let result = match decode_request(&frame) {
+            Ok((path, request)) => {
+                tracing::debug!(%peer, path, "dispatching remote signing request");
+                dispatch(service.as_ref(), path, request).await
+            }
+            Err(error) => Err(tonic::Status::invalid_argument(error.to_string())),
+        };
+        write_frame(&mut stream, &encode_response(result)).await?;
+    }
+}
+
+/// Serve the given custody service to remote signing clients connecting to
+/// the listener, which must present a certificate issued by one of the CAs
+/// trusted by the identity.
+///
+/// Each connection is served on its own task, until the client disconnects.
+/// This only returns if accepting a connection fails.
+pub async fn serve<S: CustodyService>(
+    listener: TcpListener,
+    identity: &TlsIdentity,
+    service: S,
+) -> Result<()> {
+    let acceptor = TlsAcceptor::from(Arc::new(identity.server_config()?));
+    let service = Arc::new(service);
+    loop {
+        let (stream, peer) = listener.accept().await?;
+        let (acceptor, service) = (acceptor.clone(), service.clone());
+        tokio::spawn(async move {
+            if let Err(error) = serve_connection(acceptor, stream, peer, service).await {
+                tracing::warn!(%peer, ?error, "remote signing connection failed");
+            }
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use penumbra_keys::keys::{Bip44Path, SeedPhrase, SpendKey};
+    use penumbra_transaction::TransactionPlan;
+
+    use super::*;
+    use crate::{soft_kms::SoftKms, AuthorizeRequest, ChannelClient, CustodyClient as _};
+
+    #[test]
+    fn frames_round_trip() -> Result<()> {
+        let path = "/penumbra.custody.v1.CustodyService/Authorize";
+        let frame = encode_request(path, b"request");
+        assert_eq!(decode_request(&frame)?, (path, &b"request"[..]));
+        assert!(decode_request(&frame[..4]).is_err());
+
+        assert_eq!(
+            decode_response(&encode_response(Ok(b"ok".to_vec())))?,
+            b"ok"
+        );
+        assert!(matches!(
+            decode_response(&encode_response(Err(tonic::Status::unimplemented("")))),
+            Err(e) if e.is::<Unimplemented>()
+        ));
+        assert!(matches!(
+            decode_response(&encode_response(Err(tonic::Status::internal("oops")))),
+            Err(e) if e.to_string().contains("oops")
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn only_read_only_methods_are_resent() {
+        for path in [
+            "/penumbra.custody.v1.CustodyService/Authorize",
+            "/penumbra.custody.v1.CustodyService/Challenge",
+            "/penumbra.custody.v1.CustodyService/Freeze",
+            "/penumbra.custody.v1.CustodyService/Unfreeze",
+            "/penumbra.custody.v1.CustodyService/RotateAuthorizationKey",
+            "/penumbra.custody.v1.CustodyService/ImportPolicy",
+        ] {
+            assert!(!is_idempotent(path), "{path} must not be resent");
+        }
+        assert!(is_idempotent(
+            "/penumbra.custody.v1.CustodyService/ExportFullViewingKey"
+        ));
+        assert!(is_idempotent(
+            "/penumbra.custody.v1.CustodyService/QueryAuditLog"
+        ));
+    }
+
+    /// Issue certificates for a signer and a client from a fresh CA.
+    fn identities() -> Result<(TlsIdentity, TlsIdentity)> {
+        let mut ca_params = rcgen::CertificateParams::new(Vec::new());
+        ca_params.is_ca = rcgen::IsCa::Ca(rcgen::BasicConstraints::Unconstrained);
+        let ca = rcgen::Certificate::from_params(ca_params)?;
+        let ca_cert = Certificate(ca.serialize_der()?);
+
+        let issue = |name: &str| -> Result<TlsIdentity> {
+            let cert = rcgen::generate_simple_self_signed(vec![name.to_string()])?;
+            Ok(TlsIdentity {
+                ca_certs: vec![ca_cert.clone()],
+                cert_chain: vec![Certificate(cert.serialize_der_with_signer(&ca)?)],
+                key: PrivateKey(cert.serialize_private_key_der()),
+            })
+        };
+        Ok((issue("signer.local")?, issue("client.local")?))
+    }
+
+    #[tokio::test]
+    async fn authorizes_through_remote_signer() -> Result<()> {
+        let (signer_identity, client_identity) = identities()?;
+
+        let seed_phrase = SeedPhrase::from_randomness(&[1; 32]);
+        let spend_key = SpendKey::from_seed_phrase_bip44(seed_phrase, &Bip44Path::new(0));
+        let listener = TcpListener::bind("127.0.0.1:0").await?;
+        let address = listener.local_addr()?.to_string();
+        let kms = SoftKms::new(spend_key.into())?;
+        tokio::spawn(async move { serve(listener, &signer_identity, kms).await });
+
+        let mut client = ChannelClient::new(RemoteSigner::new(
+            address.clone(),
+            "signer.local",
+            &client_identity,
+        )?);
+        let request = AuthorizeRequest {
+            plan: TransactionPlan::default(),
+            pre_authorizations: Vec::new(),
+            nonce: None,
+        };
+        assert!(client.authorize(request.clone()).await?.data.is_some());
+        // The connection is reused for subsequent requests.
+        assert!(client.authorize(request).await?.data.is_some());
+
+        // A client whose certificate isn't issued by the trusted CA is
+        // rejected.
+        let (_, untrusted_identity) = identities()?;
+        let mut untrusted = ChannelClient::new(RemoteSigner::new(
+            address,
+            "signer.local",
+            &TlsIdentity {
+                ca_certs: client_identity.ca_certs,
+                ..untrusted_identity
+            },
+        )?);
+        assert!(untrusted.challenge().await.is_err());
+
+        Ok(())
+    }
+}
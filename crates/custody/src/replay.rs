@@ -0,0 +1,299 @@
+//! Protection against replayed authorization requests.
+//!
+//! A custodian hands out single-use [`Nonce`]s through the `Challenge` RPC, which
+//! clients include in their next [`AuthorizeRequest`](crate::AuthorizeRequest).
+//! The [`ReplayGuard`] checks that each nonce was issued recently and has not been
+//! used before, and can additionally reject requests to authorize a transaction whose
+//! effect hash it has already authorized recently.
+
+use std::{
+    collections::{BTreeMap, BTreeSet, VecDeque},
+    sync::Mutex,
+    time::Duration,
+};
+
+// `std::time::Instant` is unavailable in the browser, so we use a shim which
+// is backed by `performance.now()` there.
+use instant::Instant;
+
+use penumbra_txhash::EffectHash;
+use rand_core::{CryptoRngCore, OsRng};
+
+/// How long an issued nonce remains valid.
+pub const NONCE_LIFETIME: Duration = Duration::from_secs(5 * 60);
+
+/// How long the effect hash of an authorized transaction is remembered.
+pub const EFFECT_HASH_RETENTION: Duration = Duration::from_secs(60 * 60);
+
+/// The maximum number of nonces which can be outstanding at once.
+///
+/// When more nonces are issued, the oldest outstanding nonces are invalidated.
+pub const MAX_OUTSTANDING_NONCES: usize = 1024;
+
+/// The maximum number of effect hashes which are remembered at once.
+pub const MAX_REMEMBERED_EFFECT_HASHES: usize = 16 * 1024;
+
+/// A single-use nonce issued by a custodian, binding an authorization request to a session.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Nonce(pub [u8; 32]);
+
+impl Nonce {
+    /// Generate a new random nonce.
+    pub fn generate<R: CryptoRngCore>(rng: &mut R) -> Self {
+        let mut bytes = [0u8; 32];
+        rng.fill_bytes(&mut bytes);
+        Nonce(bytes)
+    }
+}
+
+impl std::fmt::Debug for Nonce {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("Nonce").field(&hex::encode(self.0)).finish()
+    }
+}
+
+impl TryFrom<&[u8]> for Nonce {
+    type Error = anyhow::Error;
+
+    fn try_from(bytes: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Nonce(bytes.try_into().map_err(|_| {
+            anyhow::anyhow!("nonce must be 32 bytes, got {}", bytes.len())
+        })?))
+    }
+}
+
+/// The reason an authorization request was rejected as a possible replay.
+#[derive(Clone, Debug, Eq, PartialEq, thiserror::Error)]
+pub enum Replay {
+    /// The custodian requires a nonce, but the request didn't include one.
+    #[error("authorization request is missing a nonce from a challenge")]
+    MissingNonce,
+    /// The nonce was never issued by the custodian, has already been used, or has expired.
+    #[error("authorization request nonce is unknown, already used, or expired")]
+    UnknownNonce,
+    /// A transaction with the same effect hash was already authorized recently.
+    #[error("a transaction with the same effect hash was already authorized")]
+    DuplicateEffectHash,
+}
+
+/// Tracks issued nonces and recently authorized effect hashes, to reject replayed requests.
+#[derive(Debug, Default)]
+pub struct ReplayGuard {
+    require_nonce: bool,
+    reject_duplicate_effect_hashes: bool,
+    state: Mutex<State>,
+}
+
+#[derive(Debug, Default)]
+struct State {
+    /// Outstanding nonces, by the time they were issued.
+    nonces: BTreeMap<Nonce, Instant>,
+    /// Outstanding nonces, in the order they were issued.
+    nonce_queue: VecDeque<(Instant, Nonce)>,
+    /// Recently authorized effect hashes.
+    effect_hashes: BTreeSet<[u8; 64]>,
+    /// Recently authorized effect hashes, in the order they were authorized.
+    effect_hash_queue: VecDeque<(Instant, [u8; 64])>,
+}
+
+impl State {
+    /// Forget about expired nonces and effect hashes, and any in excess of the limits.
+    fn prune(&mut self, now: Instant) {
+        while let Some(&(issued, nonce)) = self.nonce_queue.front() {
+            let expired = now.duration_since(issued) > NONCE_LIFETIME;
+            if !expired && self.nonce_queue.len() <= MAX_OUTSTANDING_NONCES {
+                break;
+            }
+            self.nonce_queue.pop_front();
+            self.nonces.remove(&nonce);
+        }
+        while let Some(&(authorized, effect_hash)) = self.effect_hash_queue.front() {
+            let expired = now.duration_since(authorized) > EFFECT_HASH_RETENTION;
+            if !expired && self.effect_hash_queue.len() <= MAX_REMEMBERED_EFFECT_HASHES {
+                break;
+            }
+            self.effect_hash_queue.pop_front();
+            self.effect_hashes.remove(&effect_hash);
+        }
+    }
+}
+
+impl ReplayGuard {
+    /// Create a new replay guard.
+    ///
+    /// If `require_nonce` is set, every request must include a nonce issued by
+    /// [`challenge`](Self::challenge).  If `reject_duplicate_effect_hashes` is
+    /// set, requests to authorize a transaction which was already authorized
+    /// recently are rejected, even with a fresh nonce; this is off by default,
+    /// since it also refuses to authorize the same plan again after the first
+    /// transaction failed to land.
+    pub fn new(require_nonce: bool, reject_duplicate_effect_hashes: bool) -> Self {
+        Self {
+            require_nonce,
+            reject_duplicate_effect_hashes,
+            state: Mutex::default(),
+        }
+    }
+
+    /// Issue a new single-use nonce.
+    pub fn challenge(&self) -> Nonce {
+        let nonce = Nonce::generate(&mut OsRng);
+        let now = Instant::now();
+
+        let mut state = self
+            .state
+            .lock()
+            .expect("replay guard lock is not poisoned");
+        state.nonces.insert(nonce, now);
+        state.nonce_queue.push_back((now, nonce));
+        state.prune(now);
+
+        nonce
+    }
+
+    /// Check that a request with the given nonce, authorizing a transaction with the given effect
+    /// hash, is not a replay.
+    ///
+    /// If the check succeeds, the nonce is consumed, and if duplicate effect hashes are rejected,
+    /// the effect hash is remembered, so that neither can be used again. A nonce is consumed even
+    /// if the check fails on account of the effect hash.
+    pub fn check(&self, nonce: Option<&Nonce>, effect_hash: &EffectHash) -> Result<(), Replay> {
+        self.check_inner(nonce, effect_hash, true)
+    }
+
+    /// Check whether [`check`](Self::check) would accept a request with the given nonce and
+    /// effect hash, without consuming the nonce or remembering the effect hash.
+    pub fn peek(&self, nonce: Option<&Nonce>, effect_hash: &EffectHash) -> Result<(), Replay> {
+        self.check_inner(nonce, effect_hash, false)
+    }
+
+    fn check_inner(
+        &self,
+        nonce: Option<&Nonce>,
+        effect_hash: &EffectHash,
+        consume: bool,
+    ) -> Result<(), Replay> {
+        let now = Instant::now();
+        let mut state = self
+            .state
+            .lock()
+            .expect("replay guard lock is not poisoned");
+        state.prune(now);
+
+        match nonce {
+            Some(nonce) => {
+                // The nonce stays in the queue until it is pruned, which is harmless.
+                let known = if consume {
+                    state.nonces.remove(nonce).is_some()
+                } else {
+                    state.nonces.contains_key(nonce)
+                };
+                if !known {
+                    return Err(Replay::UnknownNonce);
+                }
+            }
+            None if self.require_nonce => return Err(Replay::MissingNonce),
+            None => {}
+        }
+
+        if !self.reject_duplicate_effect_hashes {
+            return Ok(());
+        }
+        if state.effect_hashes.contains(&effect_hash.0) {
+            return Err(Replay::DuplicateEffectHash);
+        }
+        if consume {
+            state.effect_hashes.insert(effect_hash.0);
+            state.effect_hash_queue.push_back((now, effect_hash.0));
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn nonces_are_single_use() {
+        let guard = ReplayGuard::new(true, false);
+        let nonce = guard.challenge();
+
+        assert_eq!(guard.check(Some(&nonce), &EffectHash([1; 64])), Ok(()));
+        assert_eq!(
+            guard.check(Some(&nonce), &EffectHash([2; 64])),
+            Err(Replay::UnknownNonce)
+        );
+    }
+
+    #[test]
+    fn peeking_consumes_nothing() {
+        let guard = ReplayGuard::new(true, false);
+        let nonce = guard.challenge();
+
+        assert_eq!(guard.peek(Some(&nonce), &EffectHash([1; 64])), Ok(()));
+        assert_eq!(guard.peek(Some(&nonce), &EffectHash([1; 64])), Ok(()));
+        assert_eq!(guard.check(Some(&nonce), &EffectHash([1; 64])), Ok(()));
+        assert_eq!(
+            guard.peek(Some(&nonce), &EffectHash([2; 64])),
+            Err(Replay::UnknownNonce)
+        );
+    }
+
+    #[test]
+    fn unissued_and_missing_nonces_are_rejected() {
+        let guard = ReplayGuard::new(true, false);
+
+        assert_eq!(
+            guard.check(Some(&Nonce([0; 32])), &EffectHash([1; 64])),
+            Err(Replay::UnknownNonce)
+        );
+        assert_eq!(
+            guard.check(None, &EffectHash([1; 64])),
+            Err(Replay::MissingNonce)
+        );
+    }
+
+    #[test]
+    fn the_same_plan_can_be_authorized_again_by_default() {
+        let guard = ReplayGuard::new(true, false);
+
+        for _ in 0..2 {
+            let nonce = guard.challenge();
+            assert_eq!(guard.check(Some(&nonce), &EffectHash([1; 64])), Ok(()));
+        }
+    }
+
+    #[test]
+    fn duplicate_effect_hashes_are_rejected_if_configured() {
+        let guard = ReplayGuard::new(false, true);
+
+        assert_eq!(guard.check(None, &EffectHash([1; 64])), Ok(()));
+        assert_eq!(
+            guard.check(None, &EffectHash([1; 64])),
+            Err(Replay::DuplicateEffectHash)
+        );
+
+        // A fresh nonce doesn't make a duplicate request acceptable.
+        let nonce = guard.challenge();
+        assert_eq!(
+            guard.check(Some(&nonce), &EffectHash([1; 64])),
+            Err(Replay::DuplicateEffectHash)
+        );
+    }
+
+    #[test]
+    fn oldest_nonces_are_invalidated_beyond_the_limit() {
+        let guard = ReplayGuard::new(true, false);
+        let first = guard.challenge();
+        for _ in 0..MAX_OUTSTANDING_NONCES {
+            guard.challenge();
+        }
+
+        assert_eq!(
+            guard.check(Some(&first), &EffectHash([1; 64])),
+            Err(Replay::UnknownNonce)
+        );
+    }
+}
@@ -1,7 +1,7 @@
 use penumbra_proto::{custody::v1 as pb, DomainType};
 use penumbra_transaction::TransactionPlan;
 
-use crate::PreAuthorization;
+use crate::{replay::Nonce, PreAuthorization};
 
 /// A transaction authorization request submitted to a custody service for approval.
 #[derive(Debug, Clone)]
@@ -10,6 +10,8 @@ pub struct AuthorizeRequest {
     pub plan: TransactionPlan,
     /// Optionally, pre-authorization data, if required by the custodian.
     pub pre_authorizations: Vec<PreAuthorization>,
+    /// Optionally, a nonce obtained from the custodian's challenge, binding this request to a session.
+    pub nonce: Option<Nonce>,
 }
 
 impl DomainType for AuthorizeRequest {
@@ -29,6 +31,11 @@ impl TryFrom<pb::AuthorizeRequest> for AuthorizeRequest {
                 .into_iter()
                 .map(TryInto::try_into)
                 .collect::<Result<Vec<_>, _>>()?,
+            nonce: if value.nonce.is_empty() {
+                None
+            } else {
+                Some(value.nonce.as_slice().try_into()?)
+            },
         })
     }
 }
@@ -42,6 +49,10 @@ impl From<AuthorizeRequest> for pb::AuthorizeRequest {
                 .into_iter()
                 .map(Into::into)
                 .collect(),
+            nonce: value
+                .nonce
+                .map(|nonce| nonce.0.to_vec())
+                .unwrap_or_default(),
         }
     }
 }
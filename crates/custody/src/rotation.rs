@@ -0,0 +1,594 @@
+//! A dead-man's switch for custodians, disarmed by rotating the authorization key.
+//!
+//! A custodian can be configured with an *authorization key*, an ed25519 key held by its operators,
+//! and an inactivity timeout. Operators show that they are still in control of the custodian by
+//! periodically replacing the authorization key with a new one. If the key goes unrotated for
+//! longer than the timeout, the [`RotationSwitch`] trips, and the custodian refuses every
+//! authorization request until a rotation is confirmed.
+//!
+//! A [`KeyRotation`] must be signed by both the old and the new key, so that only the holder of
+//! the current key can rotate it, and it can't be rotated to a key nobody holds. Rotations are
+//! numbered and name the custodian they are for, so that one can't be replayed, against the same
+//! custodian or another one sharing its authorization key. The custodian countersigns each
+//! confirmed rotation with its identity key, so the signed rotations form a verifiable chain of
+//! custody of the authorization key.
+//!
+//! The switch is persisted to the file named by its [`RotationConfig`], so that neither its
+//! timer nor the current authorization key are reset by restarting the custodian.
+
+use std::{path::PathBuf, sync::Mutex};
+
+use anyhow::{anyhow, Context};
+use ed25519_consensus::{Signature, SigningKey, VerificationKey};
+use penumbra_proto::custody::v1 as pb;
+use serde::{Deserialize, Serialize};
+use serde_with::hex::Hex;
+
+use crate::{audit::unix_timestamp, state_file};
+
+/// Configuration of the dead-man's switch of a custodian.
+#[serde_as]
+#[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq)]
+pub struct RotationConfig {
+    /// The verification key of the initial authorization key.
+    #[serde_as(as = "Hex")]
+    pub authorization_key: [u8; 32],
+    /// How long after the last rotation, in seconds, the custodian keeps authorizing requests.
+    pub inactivity_timeout_secs: u64,
+    /// The file the state of the switch is persisted to.
+    pub state_path: PathBuf,
+}
+
+/// The reason an authorization request was refused by a custodian whose authorization key is
+/// overdue for rotation.
+#[derive(Clone, Debug, Eq, PartialEq, thiserror::Error)]
+#[error(
+    "authorization key was last rotated at {last_rotation}, and must be rotated every {inactivity_timeout_secs} seconds"
+)]
+pub struct RotationOverdue {
+    /// The time of the last rotation, in seconds since the Unix epoch.
+    pub last_rotation: u64,
+    /// The configured inactivity timeout, in seconds.
+    pub inactivity_timeout_secs: u64,
+}
+
+/// A statement replacing the authorization key with a new one.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct KeyRotation {
+    /// The number of the rotation, one more than the number of the previous rotation.
+    pub sequence: u64,
+    /// The verification key of the identity key of the custodian whose authorization key is
+    /// rotated.
+    pub custodian: [u8; 32],
+    /// The verification key of the current authorization key.
+    pub old_key: [u8; 32],
+    /// The verification key of the new authorization key.
+    pub new_key: [u8; 32],
+}
+
+impl KeyRotation {
+    /// The hash of the rotation, which is signed by the old and new keys, and by the custodian.
+    pub fn hash(&self) -> [u8; 32] {
+        blake2b_simd::Params::default()
+            .personal(b"Penumbra_KeyRotn")
+            .hash_length(32)
+            .to_state()
+            .update(&self.sequence.to_le_bytes())
+            .update(&self.custodian)
+            .update(&self.old_key)
+            .update(&self.new_key)
+            .finalize()
+            .as_bytes()
+            .try_into()
+            .expect("hash length is 32 bytes")
+    }
+}
+
+/// A request to rotate the authorization key, signed by the old and new keys.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RotateAuthorizationKey {
+    /// The rotation to perform.
+    pub rotation: KeyRotation,
+    /// A signature over the [`hash`](KeyRotation::hash) of the rotation by the old key.
+    pub old_signature: [u8; 64],
+    /// A signature over the [`hash`](KeyRotation::hash) of the rotation by the new key.
+    pub new_signature: [u8; 64],
+}
+
+impl RotateAuthorizationKey {
+    /// Sign a rotation from `old_key` to `new_key` of the authorization key of the custodian with
+    /// the given identity key.
+    pub fn sign(
+        custodian: &VerificationKey,
+        sequence: u64,
+        old_key: &SigningKey,
+        new_key: &SigningKey,
+    ) -> Self {
+        let rotation = KeyRotation {
+            sequence,
+            custodian: custodian.to_bytes(),
+            old_key: old_key.verification_key().to_bytes(),
+            new_key: new_key.verification_key().to_bytes(),
+        };
+        let hash = rotation.hash();
+        Self {
+            old_signature: old_key.sign(&hash).to_bytes(),
+            new_signature: new_key.sign(&hash).to_bytes(),
+            rotation,
+        }
+    }
+}
+
+/// A confirmed [`KeyRotation`], countersigned by the custodian's identity key.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SignedKeyRotation {
+    /// The rotation which was performed.
+    pub rotation: KeyRotation,
+    /// The verification key of the custodian's identity key.
+    pub identity_key: VerificationKey,
+    /// A signature over the [`hash`](KeyRotation::hash) of the rotation by the identity key.
+    pub signature: Signature,
+}
+
+impl SignedKeyRotation {
+    /// Check the custodian's signature on the rotation.
+    pub fn verify(&self) -> anyhow::Result<()> {
+        anyhow::ensure!(
+            self.rotation.custodian == self.identity_key.to_bytes(),
+            "key rotation was signed by a different custodian than it names"
+        );
+        self.identity_key
+            .verify(&self.signature, &self.rotation.hash())
+            .context("invalid custodian signature on key rotation")
+    }
+}
+
+/// The reason a [`RotateAuthorizationKey`] was refused by a custodian.
+#[derive(Clone, Debug, Eq, PartialEq, thiserror::Error)]
+pub enum RotationRejection {
+    /// The custodian has no authorization key configured.
+    #[error("no authorization key is configured")]
+    NotConfigured,
+    /// The rotation is for another custodian.
+    #[error("rotation is for custodian {custodian}, not this custodian")]
+    WrongCustodian {
+        /// The hex-encoded identity key of the custodian named by the rotation.
+        custodian: String,
+    },
+    /// The rotation is numbered out of sequence.
+    #[error("rotation has sequence number {sequence}, but the next rotation is {expected}")]
+    WrongSequence {
+        /// The sequence number of the rotation.
+        sequence: u64,
+        /// The sequence number of the next rotation.
+        expected: u64,
+    },
+    /// The rotation replaces a key other than the current authorization key.
+    #[error("rotation replaces key {old_key}, but the current authorization key is {current}")]
+    WrongKey {
+        /// The hex-encoded key replaced by the rotation.
+        old_key: String,
+        /// The hex-encoded current authorization key.
+        current: String,
+    },
+    /// The rotation doesn't change the key.
+    #[error("rotation must replace the authorization key with a different key")]
+    SameKey,
+    /// A signature on the rotation failed to verify.
+    #[error("invalid signature on rotation from key {signer}")]
+    InvalidSignature {
+        /// The hex-encoded key whose signature was invalid.
+        signer: String,
+    },
+}
+
+/// The persisted state of a [`RotationSwitch`].
+#[serde_as]
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+struct State {
+    /// The number of rotations performed so far.
+    sequence: u64,
+    /// The current authorization key.
+    #[serde_as(as = "Hex")]
+    authorization_key: [u8; 32],
+    /// The time of the last rotation, or of when the switch was first armed, in seconds since the
+    /// Unix epoch.
+    last_rotation: u64,
+}
+
+impl State {
+    fn new(config: &RotationConfig) -> Self {
+        Self {
+            sequence: 0,
+            authorization_key: config.authorization_key,
+            last_rotation: unix_timestamp(),
+        }
+    }
+}
+
+/// A dead-man's switch which refuses every authorization request once the authorization key has
+/// gone unrotated for longer than the inactivity timeout.
+///
+/// A switch without a [`RotationConfig`] never trips, and refuses every rotation.
+#[derive(Debug)]
+pub struct RotationSwitch {
+    inactivity_timeout_secs: u64,
+    /// The state of the switch, if it is configured.
+    state: Option<Mutex<State>>,
+    /// The file the switch is persisted to, if it is configured.
+    path: Option<PathBuf>,
+}
+
+impl RotationSwitch {
+    /// Create a switch which never trips.
+    pub fn unconfigured() -> Self {
+        Self {
+            inactivity_timeout_secs: 0,
+            state: None,
+            path: None,
+        }
+    }
+
+    /// Open the switch persisted at the configured path, starting its timer now if it doesn't
+    /// exist yet.
+    ///
+    /// Once the switch is persisted, the authorization key in the file takes precedence over the
+    /// configured one, which is only the initial key.
+    pub fn open(config: &RotationConfig) -> anyhow::Result<Self> {
+        let state =
+            state_file::load(&config.state_path, "rotation")?.unwrap_or_else(|| State::new(config));
+        state_file::store(&config.state_path, "rotation", &state)?;
+        Ok(Self {
+            inactivity_timeout_secs: config.inactivity_timeout_secs,
+            state: Some(Mutex::new(state)),
+            path: Some(config.state_path.clone()),
+        })
+    }
+
+    /// Open the switch for the given configuration, if any, or create an unconfigured one.
+    pub fn from_config(config: Option<&RotationConfig>) -> anyhow::Result<Self> {
+        match config {
+            Some(config) => Self::open(config),
+            None => Ok(Self::unconfigured()),
+        }
+    }
+
+    /// Check that the authorization key is not overdue for rotation.
+    pub fn check(&self) -> Result<(), RotationOverdue> {
+        self.check_at(unix_timestamp())
+    }
+
+    fn check_at(&self, now: u64) -> Result<(), RotationOverdue> {
+        let Some(state) = &self.state else {
+            return Ok(());
+        };
+        let state = state.lock().expect("rotation lock is not poisoned");
+        if now.saturating_sub(state.last_rotation) > self.inactivity_timeout_secs {
+            return Err(RotationOverdue {
+                last_rotation: state.last_rotation,
+                inactivity_timeout_secs: self.inactivity_timeout_secs,
+            });
+        }
+        Ok(())
+    }
+
+    /// The current authorization key, and the number of rotations performed so far, if the
+    /// switch is configured.
+    pub fn authorization_key(&self) -> Option<([u8; 32], u64)> {
+        let state = self
+            .state
+            .as_ref()?
+            .lock()
+            .expect("rotation lock is not poisoned");
+        Some((state.authorization_key, state.sequence))
+    }
+
+    /// Rotate the authorization key, restarting the timer, and countersign the rotation with the
+    /// custodian's identity key.
+    pub fn rotate(
+        &self,
+        request: &RotateAuthorizationKey,
+        identity_key: &SigningKey,
+    ) -> anyhow::Result<SignedKeyRotation> {
+        let state = self
+            .state
+            .as_ref()
+            .ok_or(RotationRejection::NotConfigured)?;
+        let mut state = state.lock().expect("rotation lock is not poisoned");
+
+        let rotation = &request.rotation;
+        let custodian = identity_key.verification_key().to_bytes();
+        if rotation.custodian != custodian {
+            return Err(RotationRejection::WrongCustodian {
+                custodian: hex::encode(rotation.custodian),
+            }
+            .into());
+        }
+        if rotation.sequence != state.sequence + 1 {
+            return Err(RotationRejection::WrongSequence {
+                sequence: rotation.sequence,
+                expected: state.sequence + 1,
+            }
+            .into());
+        }
+        if rotation.old_key != state.authorization_key {
+            return Err(RotationRejection::WrongKey {
+                old_key: hex::encode(rotation.old_key),
+                current: hex::encode(state.authorization_key),
+            }
+            .into());
+        }
+        if rotation.new_key == rotation.old_key {
+            return Err(RotationRejection::SameKey.into());
+        }
+
+        let hash = rotation.hash();
+        for (key, signature) in [
+            (&rotation.old_key, &request.old_signature),
+            (&rotation.new_key, &request.new_signature),
+        ] {
+            VerificationKey::try_from(*key)
+                .and_then(|key| key.verify(&Signature::from(*signature), &hash))
+                .map_err(|_| RotationRejection::InvalidSignature {
+                    signer: hex::encode(key),
+                })?;
+        }
+
+        // Only confirm the rotation once it is persisted, so that a restart can't revert it.
+        let rotated = State {
+            sequence: rotation.sequence,
+            authorization_key: rotation.new_key,
+            last_rotation: unix_timestamp(),
+        };
+        self.persist(&rotated)?;
+        *state = rotated;
+        tracing::info!(
+            sequence = rotation.sequence,
+            new_key = %hex::encode(rotation.new_key),
+            "rotated authorization key"
+        );
+
+        Ok(SignedKeyRotation {
+            rotation: rotation.clone(),
+            identity_key: identity_key.verification_key(),
+            signature: identity_key.sign(&hash),
+        })
+    }
+
+    /// Write the state out to the file, if the switch is configured.
+    fn persist(&self, state: &State) -> anyhow::Result<()> {
+        match &self.path {
+            Some(path) => state_file::store(path, "rotation", state),
+            None => Ok(()),
+        }
+    }
+}
+
+impl TryFrom<pb::KeyRotation> for KeyRotation {
+    type Error = anyhow::Error;
+
+    fn try_from(rotation: pb::KeyRotation) -> Result<Self, Self::Error> {
+        Ok(KeyRotation {
+            sequence: rotation.sequence,
+            custodian: rotation.custodian.as_slice().try_into().map_err(|_| {
+                anyhow!(
+                    "custodian must be 32 bytes, got {}",
+                    rotation.custodian.len()
+                )
+            })?,
+            old_key: rotation
+                .old_key
+                .as_slice()
+                .try_into()
+                .map_err(|_| anyhow!("old key must be 32 bytes, got {}", rotation.old_key.len()))?,
+            new_key: rotation
+                .new_key
+                .as_slice()
+                .try_into()
+                .map_err(|_| anyhow!("new key must be 32 bytes, got {}", rotation.new_key.len()))?,
+        })
+    }
+}
+
+impl From<KeyRotation> for pb::KeyRotation {
+    fn from(rotation: KeyRotation) -> Self {
+        pb::KeyRotation {
+            sequence: rotation.sequence,
+            custodian: rotation.custodian.to_vec(),
+            old_key: rotation.old_key.to_vec(),
+            new_key: rotation.new_key.to_vec(),
+        }
+    }
+}
+
+impl TryFrom<pb::RotateAuthorizationKeyRequest> for RotateAuthorizationKey {
+    type Error = anyhow::Error;
+
+    fn try_from(request: pb::RotateAuthorizationKeyRequest) -> Result<Self, Self::Error> {
+        Ok(RotateAuthorizationKey {
+            rotation: request
+                .rotation
+                .ok_or_else(|| anyhow!("missing rotation"))?
+                .try_into()?,
+            old_signature: request.old_signature.as_slice().try_into().map_err(|_| {
+                anyhow!(
+                    "old key signature must be 64 bytes, got {}",
+                    request.old_signature.len()
+                )
+            })?,
+            new_signature: request.new_signature.as_slice().try_into().map_err(|_| {
+                anyhow!(
+                    "new key signature must be 64 bytes, got {}",
+                    request.new_signature.len()
+                )
+            })?,
+        })
+    }
+}
+
+impl From<RotateAuthorizationKey> for pb::RotateAuthorizationKeyRequest {
+    fn from(request: RotateAuthorizationKey) -> Self {
+        pb::RotateAuthorizationKeyRequest {
+            rotation: Some(request.rotation.into()),
+            old_signature: request.old_signature.to_vec(),
+            new_signature: request.new_signature.to_vec(),
+        }
+    }
+}
+
+impl TryFrom<pb::RotateAuthorizationKeyResponse> for SignedKeyRotation {
+    type Error = anyhow::Error;
+
+    fn try_from(response: pb::RotateAuthorizationKeyResponse) -> Result<Self, Self::Error> {
+        Ok(SignedKeyRotation {
+            rotation: response
+                .rotation
+                .ok_or_else(|| anyhow!("missing rotation"))?
+                .try_into()?,
+            identity_key: response
+                .identity_key
+                .as_slice()
+                .try_into()
+                .context("invalid identity key")?,
+            signature: response
+                .signature
+                .as_slice()
+                .try_into()
+                .context("invalid signature")?,
+        })
+    }
+}
+
+impl From<SignedKeyRotation> for pb::RotateAuthorizationKeyResponse {
+    fn from(signed: SignedKeyRotation) -> Self {
+        pb::RotateAuthorizationKeyResponse {
+            rotation: Some(signed.rotation.into()),
+            identity_key: signed.identity_key.to_bytes().to_vec(),
+            signature: signed.signature.to_bytes().to_vec(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand_core::OsRng;
+
+    use super::*;
+
+    fn config(dir: &tempfile::TempDir, authorization_key: &SigningKey) -> RotationConfig {
+        RotationConfig {
+            authorization_key: authorization_key.verification_key().to_bytes(),
+            inactivity_timeout_secs: 60,
+            state_path: dir.path().join("rotation.json"),
+        }
+    }
+
+    #[test]
+    fn rotation_disarms_the_switch() -> anyhow::Result<()> {
+        let identity_key = SigningKey::new(OsRng);
+        let custodian = identity_key.verification_key();
+        let first = SigningKey::new(OsRng);
+        let second = SigningKey::new(OsRng);
+        let dir = tempfile::tempdir()?;
+        let config = config(&dir, &first);
+
+        let switch = RotationSwitch::open(&config)?;
+        let armed_at = unix_timestamp();
+        assert!(switch.check_at(armed_at).is_ok());
+        assert!(switch.check_at(armed_at + 3600).is_err());
+
+        // Restarting the custodian doesn't restart the timer.
+        drop(switch);
+        let switch = RotationSwitch::open(&config)?;
+        assert!(switch.check_at(armed_at + 3600).is_err());
+
+        // A rotation must be signed by the current key.
+        let forged = RotateAuthorizationKey::sign(&custodian, 1, &second, &SigningKey::new(OsRng));
+        let rejection = switch.rotate(&forged, &identity_key).unwrap_err();
+        assert!(matches!(
+            rejection.downcast_ref(),
+            Some(RotationRejection::WrongKey { .. })
+        ));
+
+        let rotation = RotateAuthorizationKey::sign(&custodian, 1, &first, &second);
+        let signed = switch.rotate(&rotation, &identity_key)?;
+        signed.verify()?;
+        assert_eq!(signed.rotation, rotation.rotation);
+        assert_eq!(signed.identity_key, custodian);
+
+        // The rotation can't be replayed, and survives a restart.
+        let switch = RotationSwitch::open(&config)?;
+        assert_eq!(
+            switch.authorization_key(),
+            Some((second.verification_key().to_bytes(), 1))
+        );
+        let rejection = switch.rotate(&rotation, &identity_key).unwrap_err();
+        assert_eq!(
+            rejection.downcast_ref(),
+            Some(&RotationRejection::WrongSequence {
+                sequence: 1,
+                expected: 2
+            })
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn rotation_is_bound_to_the_custodian() -> anyhow::Result<()> {
+        let authorization_key = SigningKey::new(OsRng);
+        let new = SigningKey::new(OsRng);
+        let (first, second) = (SigningKey::new(OsRng), SigningKey::new(OsRng));
+        let (first_dir, second_dir) = (tempfile::tempdir()?, tempfile::tempdir()?);
+        let first_switch = RotationSwitch::open(&config(&first_dir, &authorization_key))?;
+        let second_switch = RotationSwitch::open(&config(&second_dir, &authorization_key))?;
+
+        // Two custodians share an authorization key, and a rotation for the first one can't be
+        // replayed against the second.
+        let rotation =
+            RotateAuthorizationKey::sign(&first.verification_key(), 1, &authorization_key, &new);
+        first_switch.rotate(&rotation, &first)?;
+        let rejection = second_switch.rotate(&rotation, &second).unwrap_err();
+        assert_eq!(
+            rejection.downcast_ref(),
+            Some(&RotationRejection::WrongCustodian {
+                custodian: hex::encode(first.verification_key().to_bytes())
+            })
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn rotation_requires_both_signatures() -> anyhow::Result<()> {
+        let identity_key = SigningKey::new(OsRng);
+        let custodian = identity_key.verification_key();
+        let old = SigningKey::new(OsRng);
+        let new = SigningKey::new(OsRng);
+        let dir = tempfile::tempdir()?;
+        let switch = RotationSwitch::open(&config(&dir, &old))?;
+
+        let mut rotation = RotateAuthorizationKey::sign(&custodian, 1, &old, &new);
+        rotation.new_signature = rotation.old_signature;
+        let rejection = switch.rotate(&rotation, &identity_key).unwrap_err();
+        assert_eq!(
+            rejection.downcast_ref(),
+            Some(&RotationRejection::InvalidSignature {
+                signer: hex::encode(new.verification_key().to_bytes())
+            })
+        );
+
+        // Without a configuration, the switch never trips but can't be rotated.
+        let unconfigured = RotationSwitch::unconfigured();
+        assert!(unconfigured.check_at(u64::MAX).is_ok());
+        let rejection = unconfigured
+            .rotate(
+                &RotateAuthorizationKey::sign(&custodian, 1, &old, &new),
+                &identity_key,
+            )
+            .unwrap_err();
+        assert_eq!(
+            rejection.downcast_ref(),
+            Some(&RotationRejection::NotConfigured)
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,102 @@
+use penumbra_proto::{custody::v1 as pb, DomainType};
+
+use crate::Approver;
+
+/// The outcome of simulating an [`AuthorizeRequest`](crate::AuthorizeRequest)
+/// against a custodian's checks, without producing any signatures.
+///
+/// Unlike an authorization, a simulation doesn't stop at the first failing
+/// check, so that it reports all of the reasons the request would be denied.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct AuthorizeSimulation {
+    /// The outcome of every check, in the order the custodian performs them.
+    pub checks: Vec<SimulationCheck>,
+    /// The distinct pre-authorizers whose signatures were verified by the
+    /// custodian's policies.
+    pub approvers: Vec<Approver>,
+}
+
+/// The outcome of a single check in an [`AuthorizeSimulation`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SimulationCheck {
+    /// The name of the check, such as `freeze`, `replay`, or the type of an
+    /// authorization policy.
+    pub name: String,
+    /// If the request fails the check, an explanation of why.
+    pub denial: Option<String>,
+}
+
+impl SimulationCheck {
+    /// Record the outcome of the named check.
+    pub fn new<E: std::fmt::Display>(name: impl Into<String>, outcome: Result<(), E>) -> Self {
+        Self {
+            name: name.into(),
+            denial: outcome.err().map(|e| e.to_string()),
+        }
+    }
+}
+
+impl AuthorizeSimulation {
+    /// Whether the custodian would authorize the request, which is the case if
+    /// it passes every check.
+    pub fn approved(&self) -> bool {
+        self.checks.iter().all(|check| check.denial.is_none())
+    }
+
+    /// The reasons the request would be denied, one per failing check.
+    pub fn denials(&self) -> impl Iterator<Item = String> + '_ {
+        self.checks.iter().filter_map(|check| {
+            check
+                .denial
+                .as_ref()
+                .map(|denial| format!("{}: {denial}", check.name))
+        })
+    }
+}
+
+impl DomainType for AuthorizeSimulation {
+    type Proto = pb::AuthorizeSimulation;
+}
+
+impl From<AuthorizeSimulation> for pb::AuthorizeSimulation {
+    fn from(value: AuthorizeSimulation) -> Self {
+        Self {
+            approved: value.approved(),
+            checks: value
+                .checks
+                .into_iter()
+                .map(|check| pb::authorize_simulation::Check {
+                    name: check.name,
+                    denial: check.denial.unwrap_or_default(),
+                })
+                .collect(),
+            approvers: value.approvers.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+impl TryFrom<pb::AuthorizeSimulation> for AuthorizeSimulation {
+    type Error = anyhow::Error;
+    fn try_from(value: pb::AuthorizeSimulation) -> Result<Self, Self::Error> {
+        let simulation = Self {
+            checks: value
+                .checks
+                .into_iter()
+                .map(|check| SimulationCheck {
+                    name: check.name,
+                    denial: (!check.denial.is_empty()).then_some(check.denial),
+                })
+                .collect(),
+            approvers: value
+                .approvers
+                .into_iter()
+                .map(TryInto::try_into)
+                .collect::<Result<Vec<_>, _>>()?,
+        };
+        anyhow::ensure!(
+            simulation.approved() == value.approved,
+            "simulation approval does not match the outcome of its checks"
+        );
+        Ok(simulation)
+    }
+}
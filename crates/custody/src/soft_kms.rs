@@ -1,14 +1,30 @@
 //! A basic software key management system that stores keys in memory but
 //! presents as an asynchronous signer.
 
-use penumbra_proto::custody::v1::{self as pb, AuthorizeResponse};
+use std::sync::{Arc, RwLock};
+
+use anyhow::Context as _;
+use decaf377_rdsa::{Signature, SpendAuth};
+use ed25519_consensus::SigningKey;
+use penumbra_keys::keys::SpendKey;
 use penumbra_transaction::AuthorizationData;
 use rand_core::OsRng;
-use tonic::{async_trait, Request, Response, Status};
 
-use crate::{policy::Policy, AuthorizeRequest};
+use crate::{
+    audit::{AuditLog, Decision},
+    freeze::FreezeSwitch,
+    plan_text,
+    policy::{
+        BundleRejection, Denial, PolicyBundle, PolicyEngine, SignedPolicyBundle, SpendLedger,
+    },
+    replay::ReplayGuard,
+    rotation::RotationSwitch,
+    state_file, AuthorizeMessageRequest, AuthorizeRequest, AuthorizeSimulation, SimulationCheck,
+};
 
 mod config;
+#[cfg(feature = "rpc")]
+mod rpc;
 
 pub use config::Config;
 
@@ -16,12 +32,175 @@ pub use config::Config;
 /// presents as an asynchronous signer.
 pub struct SoftKms {
     config: Config,
+    policy: RwLock<ActivePolicy>,
+    replay: ReplayGuard,
+    audit_log: AuditLog,
+    freeze: FreezeSwitch,
+    rotation: RotationSwitch,
+}
+
+/// The policy currently enforced by a [`SoftKms`], either from its [`Config`]
+/// or from the most recently imported [`SignedPolicyBundle`].
+struct ActivePolicy {
+    bundle: PolicyBundle,
+    engine: PolicyEngine,
+}
+
+impl ActivePolicy {
+    /// Enforce the policies in the bundle, continuing from the given ledger of
+    /// recent spends, so that replacing the policy doesn't reset daily limits.
+    fn new(config: &Config, bundle: PolicyBundle, ledger: Arc<SpendLedger>) -> Self {
+        let engine = PolicyEngine::new(
+            config.spend_key.full_viewing_key().clone(),
+            bundle.auth_policy.clone(),
+        )
+        .with_ledger(ledger);
+        Self { bundle, engine }
+    }
+}
+
+/// Derive the identity key used to sign the audit log from the spend key.
+pub(crate) fn identity_key(spend_key: &SpendKey) -> SigningKey {
+    let seed: [u8; 32] = blake2b_simd::Params::default()
+        .personal(b"Penumbra_CustIdK")
+        .hash_length(32)
+        .hash(&spend_key.to_bytes().0)
+        .as_bytes()
+        .try_into()
+        .expect("hash length is 32 bytes");
+    SigningKey::from(seed)
+}
+
+/// Load the policy bundle last imported into a custodian with the given
+/// config, unless the configured policy has since caught up with it.
+///
+/// The bundle is verified again, so that tampering with the state file can't
+/// loosen the policy.
+fn imported_policy(config: &Config) -> anyhow::Result<Option<PolicyBundle>> {
+    let Some(path) = &config.policy_state else {
+        return Ok(None);
+    };
+    let Some(signed) = state_file::load::<SignedPolicyBundle>(path, "policy")? else {
+        return Ok(None);
+    };
+    if signed.bundle.version <= config.policy_version {
+        return Ok(None);
+    }
+    let signers = config
+        .policy_signers
+        .as_ref()
+        .ok_or(BundleRejection::NoSigners)?;
+    let bundle = signed
+        .verify(signers, config.policy_version)
+        .with_context(|| format!("invalid policy state at {}", path.display()))?;
+    Ok(Some(bundle.clone()))
 }
 
 impl SoftKms {
     /// Initialize with the given [`Config`].
-    pub fn new(config: Config) -> Self {
-        Self { config }
+    ///
+    /// The policy last imported into the custodian is enforced instead of the
+    /// configured one, if it is newer.  Fails if the configured policy state,
+    /// freeze switch, rotation switch, spend ledger or audit log can't be
+    /// opened.
+    pub fn new(config: Config) -> anyhow::Result<Self> {
+        let ledger = match &config.spend_ledger {
+            Some(path) => SpendLedger::open(path)?,
+            None => SpendLedger::in_memory(),
+        };
+        let bundle = imported_policy(&config)?.unwrap_or_else(|| PolicyBundle {
+            version: config.policy_version,
+            auth_policy: config.auth_policy.clone(),
+        });
+        let policy = RwLock::new(ActivePolicy::new(&config, bundle, Arc::new(ledger)));
+        let replay = ReplayGuard::new(config.require_nonce, config.reject_duplicate_effect_hashes);
+        let audit_log = match &config.audit_log {
+            Some(path) => AuditLog::open(path, identity_key(&config.spend_key))?,
+            None => AuditLog::in_memory(identity_key(&config.spend_key)),
+        };
+        let freeze = FreezeSwitch::from_config(
+            config.freeze_state.as_deref(),
+            config.recovery_signers.clone(),
+        )?;
+        let rotation = RotationSwitch::from_config(config.rotation.as_ref())?;
+        Ok(Self {
+            config,
+            policy,
+            replay,
+            audit_log,
+            freeze,
+            rotation,
+        })
+    }
+
+    /// Record decisions in the given audit log, rather than an in-memory one.
+    ///
+    /// The log should be signed by [`SoftKms::identity_key`].
+    pub fn with_audit_log(mut self, audit_log: AuditLog) -> Self {
+        self.audit_log = audit_log;
+        self
+    }
+
+    /// Use the given freeze switch, rather than the configured one.
+    ///
+    /// The switch should be opened with the configured `recovery_signers`.
+    pub fn with_freeze_switch(mut self, freeze: FreezeSwitch) -> Self {
+        self.freeze = freeze;
+        self
+    }
+
+    /// The freeze switch which, while frozen, refuses every authorization request.
+    pub fn freeze_switch(&self) -> &FreezeSwitch {
+        &self.freeze
+    }
+
+    /// The rotation switch which refuses every authorization request once the
+    /// authorization key is overdue for rotation.
+    pub fn rotation_switch(&self) -> &RotationSwitch {
+        &self.rotation
+    }
+
+    /// The identity key used to sign the audit log, derived from the spend key.
+    pub fn identity_key(&self) -> SigningKey {
+        identity_key(&self.config.spend_key)
+    }
+
+    /// Export the policy currently enforced, so that changes to it can be
+    /// reviewed and signed.
+    pub fn export_policy(&self) -> PolicyBundle {
+        self.policy
+            .read()
+            .expect("policy lock is not poisoned")
+            .bundle
+            .clone()
+    }
+
+    /// Replace the enforced policy with the one in a signed policy bundle.
+    ///
+    /// The bundle is refused with a [`BundleRejection`] unless it is signed
+    /// by the configured policy signers and its version is newer than the
+    /// current policy's.  The imported bundle is written to the configured
+    /// `policy_state` before it is enforced, so that it survives restarts.
+    pub fn import_policy(&self, signed: &SignedPolicyBundle) -> anyhow::Result<()> {
+        let signers = self
+            .config
+            .policy_signers
+            .as_ref()
+            .ok_or(BundleRejection::NoSigners)?;
+
+        let mut policy = self.policy.write().expect("policy lock is not poisoned");
+        let bundle = signed.verify(signers, policy.bundle.version)?;
+        if let Some(path) = &self.config.policy_state {
+            state_file::store(path, "policy", signed)?;
+        }
+        tracing::info!(
+            version = bundle.version,
+            previous_version = policy.bundle.version,
+            "imported policy bundle"
+        );
+        let ledger = policy.engine.ledger().clone();
+        *policy = ActivePolicy::new(&self.config, bundle.clone(), ledger);
+        Ok(())
     }
 
     /// Attempt to authorize the requested [`TransactionPlan`](penumbra_transaction::TransactionPlan).
@@ -29,70 +208,297 @@ impl SoftKms {
     pub fn sign(&self, request: &AuthorizeRequest) -> anyhow::Result<AuthorizationData> {
         tracing::debug!(?request.plan);
 
-        for policy in &self.config.auth_policy {
-            policy.check(request)?;
+        self.decide(request, || {
+            Ok(request.plan.authorize(OsRng, &self.config.spend_key)?)
+        })
+    }
+
+    /// Check whether the requested [`TransactionPlan`](penumbra_transaction::TransactionPlan)
+    /// would be authorized, reporting the outcome of every check, without signing it.
+    ///
+    /// A simulation doesn't consume the request's nonce, and isn't recorded in
+    /// the audit log, so the same request can be authorized afterwards.
+    #[tracing::instrument(skip(self, request), name = "softhsm_simulate")]
+    pub fn simulate(&self, request: &AuthorizeRequest) -> anyhow::Result<AuthorizeSimulation> {
+        let mut simulation = AuthorizeSimulation::default();
+        simulation
+            .checks
+            .push(SimulationCheck::new("freeze", self.freeze.check()));
+        simulation
+            .checks
+            .push(SimulationCheck::new("rotation", self.rotation.check()));
+
+        let outcomes = self
+            .policy
+            .read()
+            .expect("policy lock is not poisoned")
+            .engine
+            .evaluate_each(request);
+        for (name, outcome) in outcomes {
+            let outcome = outcome.map(|approvers| {
+                for approver in approvers {
+                    if !simulation.approvers.contains(&approver) {
+                        simulation.approvers.push(approver);
+                    }
+                }
+            });
+            simulation.checks.push(SimulationCheck::new(name, outcome));
         }
 
-        Ok(request.plan.authorize(OsRng, &self.config.spend_key)?)
+        let effect_hash = request
+            .plan
+            .effect_hash(self.config.spend_key.full_viewing_key())?;
+        simulation.checks.push(SimulationCheck::new(
+            "replay",
+            self.replay.peek(request.nonce.as_ref(), &effect_hash),
+        ));
+
+        tracing::debug!(approved = simulation.approved(), "simulated authorization");
+        Ok(simulation)
     }
-}
 
-#[async_trait]
-impl pb::custody_service_server::CustodyService for SoftKms {
-    async fn authorize(
+    /// Sign an off-chain message with the spend authorization key, if its
+    /// domain is allowed by the configured `allowed_message_domains`.
+    ///
+    /// Like transaction authorizations, messages are refused while the
+    /// custodian is frozen or its authorization key is overdue for rotation.
+    #[tracing::instrument(skip(self, request), fields(domain = %request.domain))]
+    pub fn sign_message(
         &self,
-        request: Request<pb::AuthorizeRequest>,
-    ) -> Result<Response<AuthorizeResponse>, Status> {
-        let request = request
-            .into_inner()
-            .try_into()
-            .map_err(|e: anyhow::Error| Status::invalid_argument(e.to_string()))?;
-
-        let authorization_data = self
-            .sign(&request)
-            .map_err(|e| Status::unauthenticated(format!("{e:#}")))?;
-
-        let authorization_response = AuthorizeResponse {
-            data: Some(authorization_data.into()),
-        };
+        request: &AuthorizeMessageRequest,
+    ) -> anyhow::Result<Signature<SpendAuth>> {
+        self.freeze.check()?;
+        self.rotation.check()?;
+        if !self
+            .config
+            .allowed_message_domains
+            .contains(&request.domain)
+        {
+            return Err(Denial::MessageDomainNotAllowed {
+                domain: request.domain.clone(),
+            }
+            .into());
+        }
 
-        Ok(Response::new(authorization_response))
+        tracing::info!("signing off-chain message");
+        Ok(self
+            .config
+            .spend_key
+            .spend_auth_key()
+            .sign(OsRng, &request.signing_hash()))
     }
 
-    async fn export_full_viewing_key(
+    /// Decide whether to authorize the request and, if so, sign it with `sign`,
+    /// recording the outcome in the audit log.
+    ///
+    /// A request which is approved but can't be signed is recorded as denied.
+    /// If the outcome can't be recorded, the request is not authorized.
+    fn decide<T>(
         &self,
-        _request: Request<pb::ExportFullViewingKeyRequest>,
-    ) -> Result<Response<pb::ExportFullViewingKeyResponse>, Status> {
-        Ok(Response::new(pb::ExportFullViewingKeyResponse {
-            full_viewing_key: Some(self.config.spend_key.full_viewing_key().clone().into()),
-        }))
+        request: &AuthorizeRequest,
+        sign: impl FnOnce() -> anyhow::Result<T>,
+    ) -> anyhow::Result<T> {
+        let (policy_denial, approvers, decision) = {
+            let policy = self.policy.read().expect("policy lock is not poisoned");
+            // Hold the ledger until the request is recorded in it, so that concurrent
+            // requests can't each stay under a daily limit they exceed together.
+            let mut ledger = policy.engine.ledger().lock();
+            let policy_result = policy.engine.evaluate_locked(request, &ledger);
+            plan_text::audit_decision(
+                &request.plan,
+                policy_result
+                    .as_ref()
+                    .err()
+                    .map(|e| e as &dyn std::fmt::Display),
+            );
+            let policy_denial = policy_result.as_ref().err().map(ToString::to_string);
+            let approvers = policy_result.clone().unwrap_or_default();
+            let decision = self
+                .freeze
+                .check()
+                .map_err(anyhow::Error::from)
+                .and_then(|()| self.rotation.check().map_err(anyhow::Error::from))
+                .and_then(|()| policy_result.map(drop).map_err(anyhow::Error::from))
+                .and_then(|()| self.check_replay(request))
+                .and_then(|()| policy.engine.record(request, &mut ledger));
+            (policy_denial, approvers, decision)
+        };
+        let outcome = decision.and_then(|()| sign());
+        self.audit_log
+            .record(request, policy_denial, approvers, Decision::from(&outcome))?;
+        outcome
     }
 
-    async fn confirm_address(
-        &self,
-        request: Request<pb::ConfirmAddressRequest>,
-    ) -> Result<Response<pb::ConfirmAddressResponse>, Status> {
-        let address_index = request
-            .into_inner()
-            .address_index
-            .ok_or_else(|| {
-                Status::invalid_argument("missing address index in confirm address request")
-            })?
-            .try_into()
-            .map_err(|e| {
-                Status::invalid_argument(format!(
-                    "invalid address index in confirm address request: {e:#}"
-                ))
-            })?;
-
-        let (address, _dtk) = self
-            .config
-            .spend_key
-            .full_viewing_key()
-            .payment_address(address_index);
+    /// Check that the request is not a replay of an earlier one, consuming its nonce.
+    fn check_replay(&self, request: &AuthorizeRequest) -> anyhow::Result<()> {
+        let effect_hash = request
+            .plan
+            .effect_hash(self.config.spend_key.full_viewing_key())?;
+        self.replay.check(request.nonce.as_ref(), &effect_hash)?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use penumbra_keys::keys::{Bip44Path, SeedPhrase};
+    use penumbra_transaction::TransactionPlan;
+
+    use super::*;
+    use crate::{
+        audit::{verify, Query},
+        policy::{ActionKind, AuthPolicy, PolicySigners},
+    };
+
+    #[test]
+    fn simulation_reports_denials_without_consuming_the_nonce() -> anyhow::Result<()> {
+        let seed_phrase = SeedPhrase::from_randomness(&[1; 32]);
+        let spend_key = SpendKey::from_seed_phrase_bip44(seed_phrase, &Bip44Path::new(0));
+        let kms = SoftKms::new(Config {
+            auth_policy: vec![AuthPolicy::DestinationAllowList {
+                allowed_destination_addresses: Vec::new(),
+            }],
+            require_nonce: true,
+            ..Config::from(spend_key)
+        })?;
+
+        let mut request = AuthorizeRequest {
+            plan: TransactionPlan::default(),
+            pre_authorizations: Vec::new(),
+            nonce: None,
+        };
+
+        // Without a nonce, only the replay check fails.
+        let simulation = kms.simulate(&request)?;
+        assert!(!simulation.approved());
+        let denied: Vec<_> = simulation
+            .checks
+            .iter()
+            .filter(|check| check.denial.is_some())
+            .map(|check| check.name.as_str())
+            .collect();
+        assert_eq!(denied, vec!["replay"]);
+        assert!(simulation
+            .checks
+            .iter()
+            .any(|check| check.name == "DestinationAllowList"));
+
+        // Simulations leave the nonce for the real request.
+        request.nonce = Some(kms.replay.challenge());
+        assert!(kms.simulate(&request)?.approved());
+        kms.sign(&request)?;
+
+        Ok(())
+    }
+
+    #[test]
+    fn outcomes_are_persisted_to_the_audit_log() -> anyhow::Result<()> {
+        let dir = tempfile::tempdir()?;
+        let seed_phrase = SeedPhrase::from_randomness(&[1; 32]);
+        let spend_key = SpendKey::from_seed_phrase_bip44(seed_phrase, &Bip44Path::new(0));
+        let config = Config {
+            audit_log: Some(dir.path().join("audit.jsonl")),
+            ..Config::from(spend_key)
+        };
+        let request = AuthorizeRequest {
+            plan: TransactionPlan::default(),
+            pre_authorizations: Vec::new(),
+            nonce: None,
+        };
+
+        let kms = SoftKms::new(config.clone())?;
+        kms.sign(&request)?;
+        // A request the policies allow is still recorded as denied if signing it fails.
+        assert!(kms
+            .decide(&request, || -> anyhow::Result<()> {
+                anyhow::bail!("signing failed")
+            })
+            .is_err());
+        drop(kms);
+
+        let kms = SoftKms::new(config)?;
+        let response = kms.audit_log.query(&Query::default());
+        verify(&response.entries, &response.identity_key)?;
+        let decisions: Vec<_> = response
+            .entries
+            .iter()
+            .map(|signed| signed.entry.decision.clone())
+            .collect();
+        assert_eq!(
+            decisions,
+            vec![
+                Decision::Approved,
+                Decision::Denied("signing failed".to_string())
+            ]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn imported_policy_survives_restarts() -> anyhow::Result<()> {
+        let dir = tempfile::tempdir()?;
+        let seed_phrase = SeedPhrase::from_randomness(&[1; 32]);
+        let spend_key = SpendKey::from_seed_phrase_bip44(seed_phrase, &Bip44Path::new(0));
+        let signing_key = ed25519_consensus::SigningKey::new(OsRng);
+        let config = Config {
+            policy_version: 1,
+            policy_signers: Some(PolicySigners {
+                required_signatures: 1,
+                allowed_signers: vec![signing_key.verification_key()],
+            }),
+            policy_state: Some(dir.path().join("policy.json")),
+            ..Config::from(spend_key)
+        };
+        let signed = |version| {
+            let mut signed = SignedPolicyBundle::new(PolicyBundle {
+                version,
+                auth_policy: vec![AuthPolicy::ActionDenyList {
+                    denied_actions: vec![ActionKind::ProposalSubmit],
+                }],
+            });
+            signed.sign(&signing_key);
+            signed
+        };
+
+        let kms = SoftKms::new(config.clone())?;
+        kms.import_policy(&signed(2))?;
+        kms.import_policy(&signed(3))?;
+        drop(kms);
+
+        // After a restart, the last imported bundle is still enforced, so the
+        // earlier one can't be replayed to roll it back.
+        let kms = SoftKms::new(config.clone())?;
+        assert_eq!(kms.export_policy(), signed(3).bundle);
+        let error = kms.import_policy(&signed(2)).unwrap_err();
+        assert_eq!(
+            error.downcast_ref::<BundleRejection>(),
+            Some(&BundleRejection::Downgrade {
+                version: 2,
+                current: 3
+            })
+        );
+        drop(kms);
+
+        // Once the configured policy catches up, it takes precedence.
+        let kms = SoftKms::new(Config {
+            policy_version: 3,
+            ..config.clone()
+        })?;
+        assert_eq!(kms.export_policy().version, 3);
+        assert!(kms.export_policy().auth_policy.is_empty());
+        drop(kms);
+
+        // A tampered bundle is refused, rather than loosening the policy.
+        let mut tampered = signed(4);
+        tampered.bundle.auth_policy.clear();
+        state_file::store(
+            dir.path().join("policy.json").as_path(),
+            "policy",
+            &tampered,
+        )?;
+        assert!(SoftKms::new(config).is_err());
 
-        Ok(Response::new(pb::ConfirmAddressResponse {
-            address: Some(address.into()),
-        }))
+        Ok(())
     }
 }
@@ -1,4 +1,9 @@
-use crate::policy::AuthPolicy;
+use std::path::PathBuf;
+
+use crate::{
+    policy::{AuthPolicy, PolicySigners},
+    rotation::RotationConfig,
+};
 use penumbra_keys::keys::SpendKey;
 use serde::{Deserialize, Serialize};
 use serde_with::DisplayFromStr;
@@ -14,6 +19,56 @@ pub struct Config {
     pub spend_key: SpendKey,
     #[serde(default, skip_serializing_if = "is_default")]
     pub auth_policy: Vec<AuthPolicy>,
+    /// Whether to reject authorization requests that don't include a nonce
+    /// obtained from a prior challenge.
+    #[serde(default, skip_serializing_if = "is_default")]
+    pub require_nonce: bool,
+    /// Whether to reject requests to authorize a transaction which was
+    /// already authorized recently.  This also refuses legitimate requests to
+    /// authorize the same plan again, for instance after a failed submission.
+    #[serde(default, skip_serializing_if = "is_default")]
+    pub reject_duplicate_effect_hashes: bool,
+    /// The version of the configured `auth_policy`, which signed policy
+    /// bundles must exceed to replace it.
+    #[serde(default, skip_serializing_if = "is_default")]
+    pub policy_version: u64,
+    /// The keys allowed to sign policy bundles; if unset, policy bundles
+    /// can't be imported.
+    #[serde(default, skip_serializing_if = "is_default")]
+    pub policy_signers: Option<PolicySigners>,
+    /// The file the most recently imported policy bundle is persisted to, so
+    /// that it is still enforced after a restart, and older bundles can't be
+    /// replayed then; if unset, imported bundles are only kept in memory.
+    /// `pcli` and the remote signer default it to a file next to their
+    /// configuration.
+    #[serde(default, skip_serializing_if = "is_default")]
+    pub policy_state: Option<PathBuf>,
+    /// The keys allowed to unfreeze the custodian after it was frozen; if
+    /// unset, it can't be unfrozen remotely.
+    #[serde(default, skip_serializing_if = "is_default")]
+    pub recovery_signers: Option<PolicySigners>,
+    /// The file the freeze switch is persisted to, so that a freeze survives
+    /// restarts; if unset, it is only kept in memory.  `pcli` and the remote
+    /// signer default it to a file next to their configuration.
+    #[serde(default, skip_serializing_if = "is_default")]
+    pub freeze_state: Option<PathBuf>,
+    /// The authorization key which must be rotated periodically for the
+    /// custodian to keep authorizing requests; if unset, it never has to be.
+    #[serde(default, skip_serializing_if = "is_default")]
+    pub rotation: Option<RotationConfig>,
+    /// The file the amounts sent under daily limits are recorded in, so that
+    /// the limits survive restarts; if unset, they are only kept in memory.
+    #[serde(default, skip_serializing_if = "is_default")]
+    pub spend_ledger: Option<PathBuf>,
+    /// The file the signed audit log of authorization decisions is persisted
+    /// to; if unset, it is only kept in memory.  `pcli` and the remote signer
+    /// default it to a file next to their configuration.
+    #[serde(default, skip_serializing_if = "is_default")]
+    pub audit_log: Option<PathBuf>,
+    /// The domains under which off-chain messages may be signed; if empty,
+    /// no messages are signed.
+    #[serde(default, skip_serializing_if = "is_default")]
+    pub allowed_message_domains: Vec<String>,
 }
 
 impl From<SpendKey> for Config {
@@ -21,6 +76,17 @@ impl From<SpendKey> for Config {
         Self {
             spend_key,
             auth_policy: Default::default(),
+            require_nonce: false,
+            reject_duplicate_effect_hashes: false,
+            policy_version: 0,
+            policy_signers: None,
+            policy_state: None,
+            recovery_signers: None,
+            freeze_state: None,
+            rotation: None,
+            spend_ledger: None,
+            audit_log: None,
+            allowed_message_domains: Vec::new(),
         }
     }
 }
@@ -40,7 +106,7 @@ fn is_default<T: Default + Eq>(value: &T) -> bool {
 mod tests {
     use penumbra_keys::keys::{Bip44Path, SeedPhrase};
 
-    use crate::policy::PreAuthorizationPolicy;
+    use crate::policy::{ActionKind, DailyLimit, PreAuthorizationPolicy};
 
     use super::*;
 
@@ -51,6 +117,8 @@ mod tests {
 
         let pak = ed25519_consensus::SigningKey::new(rand_core::OsRng);
         let pvk = pak.verification_key();
+        let secp_vk = *k256::ecdsa::SigningKey::random(&mut rand_core::OsRng).verifying_key();
+        let webauthn_vk = *p256::ecdsa::SigningKey::random(&mut rand_core::OsRng).verifying_key();
 
         let auth_policy = vec![
             AuthPolicy::OnlyIbcRelay,
@@ -66,11 +134,63 @@ mod tests {
                 required_signatures: 1,
                 allowed_signers: vec![pvk],
             }),
+            AuthPolicy::PreAuthorization(PreAuthorizationPolicy::Secp256k1 {
+                required_signatures: 1,
+                allowed_signers: vec![secp_vk],
+            }),
+            AuthPolicy::PreAuthorization(PreAuthorizationPolicy::WebAuthn {
+                required_signatures: 1,
+                relying_party_id: "custody.example.com".to_string(),
+                allowed_credentials: vec![webauthn_vk],
+            }),
+            AuthPolicy::ActionDenyList {
+                denied_actions: vec![ActionKind::ProposalSubmit, ActionKind::Ics20Withdrawal],
+            },
+            AuthPolicy::SpendLimit {
+                asset_id: *penumbra_asset::STAKING_TOKEN_ASSET_ID,
+                max_amount: 1_000_000,
+            },
+            AuthPolicy::AddressIndexRestriction {
+                first_index: 1,
+                last_index: Some(10),
+                frozen: false,
+                daily_limits: vec![DailyLimit {
+                    asset_id: *penumbra_asset::STAKING_TOKEN_ASSET_ID,
+                    max_amount: 100_000_000,
+                }],
+            },
+            AuthPolicy::AddressIndexRestriction {
+                first_index: 99,
+                last_index: None,
+                frozen: true,
+                daily_limits: vec![],
+            },
         ];
 
         let example = Config {
             spend_key: spend_key.clone(),
             auth_policy,
+            require_nonce: true,
+            reject_duplicate_effect_hashes: true,
+            policy_version: 3,
+            policy_signers: Some(PolicySigners {
+                required_signatures: 1,
+                allowed_signers: vec![pvk],
+            }),
+            policy_state: Some("/var/lib/penumbra/policy.json".into()),
+            recovery_signers: Some(PolicySigners {
+                required_signatures: 1,
+                allowed_signers: vec![pvk],
+            }),
+            freeze_state: Some("/var/lib/penumbra/freeze.json".into()),
+            rotation: Some(RotationConfig {
+                authorization_key: pvk.to_bytes(),
+                inactivity_timeout_secs: 30 * 24 * 60 * 60,
+                state_path: "/var/lib/penumbra/rotation.json".into(),
+            }),
+            spend_ledger: Some("/var/lib/penumbra/spend_ledger.json".into()),
+            audit_log: Some("/var/lib/penumbra/audit.jsonl".into()),
+            allowed_message_domains: vec!["forum.penumbra.zone/identity".to_string()],
         };
 
         let encoded = toml::to_string_pretty(&example).unwrap();
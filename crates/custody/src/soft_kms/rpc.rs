@@ -0,0 +1,237 @@
+//! The gRPC custody service for the [`SoftKms`].
+
+use std::pin::Pin;
+
+use futures::{stream, StreamExt};
+use penumbra_proto::custody::v1::{self as pb, AuthorizeResponse};
+use tonic::{async_trait, Request, Response, Status};
+
+use super::SoftKms;
+use crate::{
+    policy::SignedPolicyBundle, status::authorization_status, AuthorizeMessageRequest,
+    AuthorizeRequest, PlanSummary,
+};
+
+#[async_trait]
+impl pb::custody_service_server::CustodyService for SoftKms {
+    type AuthorizeStreamStream =
+        Pin<Box<dyn futures::Stream<Item = Result<pb::AuthorizeStreamResponse, Status>> + Send>>;
+
+    async fn authorize(
+        &self,
+        request: Request<pb::AuthorizeRequest>,
+    ) -> Result<Response<AuthorizeResponse>, Status> {
+        let request: AuthorizeRequest = request
+            .into_inner()
+            .try_into()
+            .map_err(|e: anyhow::Error| Status::invalid_argument(e.to_string()))?;
+
+        let summary = PlanSummary::new(&request.plan, self.config.spend_key.full_viewing_key());
+
+        // Policy denials, replays, freezes and overdue rotations are reported
+        // distinctly from other signing failures.
+        let authorization_data = self
+            .sign(&request)
+            .map_err(|e| authorization_status(e, tonic::Code::Unauthenticated))?;
+
+        let authorization_response = AuthorizeResponse {
+            data: Some(authorization_data.into()),
+            summary: Some(summary.into()),
+        };
+
+        Ok(Response::new(authorization_response))
+    }
+
+    async fn authorize_stream(
+        &self,
+        request: Request<pb::AuthorizeRequest>,
+    ) -> Result<Response<Self::AuthorizeStreamStream>, Status> {
+        use pb::authorize_stream_response::{Approved, Denied, Status as AuthorizeStatus};
+
+        let request: AuthorizeRequest = request
+            .into_inner()
+            .try_into()
+            .map_err(|e: anyhow::Error| Status::invalid_argument(e.to_string()))?;
+
+        // The soft KMS is non-interactive, so the only update it ever sends is
+        // the final decision.
+        let status = match self.sign(&request) {
+            Ok(authorization_data) => AuthorizeStatus::Approved(Approved {
+                data: Some(authorization_data.into()),
+            }),
+            Err(e) => AuthorizeStatus::Denied(Denied {
+                reason: format!("{e:#}"),
+            }),
+        };
+
+        let response = pb::AuthorizeStreamResponse {
+            status: Some(status),
+        };
+
+        Ok(Response::new(
+            stream::once(async move { Ok(response) }).boxed(),
+        ))
+    }
+
+    async fn simulate_authorization(
+        &self,
+        request: Request<pb::AuthorizeRequest>,
+    ) -> Result<Response<pb::SimulateAuthorizationResponse>, Status> {
+        let request: AuthorizeRequest = request
+            .into_inner()
+            .try_into()
+            .map_err(|e: anyhow::Error| Status::invalid_argument(e.to_string()))?;
+
+        let simulation = self
+            .simulate(&request)
+            .map_err(|e| Status::invalid_argument(format!("{e:#}")))?;
+        let summary = PlanSummary::new(&request.plan, self.config.spend_key.full_viewing_key());
+
+        Ok(Response::new(pb::SimulateAuthorizationResponse {
+            simulation: Some(simulation.into()),
+            summary: Some(summary.into()),
+        }))
+    }
+
+    async fn authorize_message(
+        &self,
+        request: Request<pb::AuthorizeMessageRequest>,
+    ) -> Result<Response<pb::AuthorizeMessageResponse>, Status> {
+        let request: AuthorizeMessageRequest = request
+            .into_inner()
+            .try_into()
+            .map_err(|e: anyhow::Error| Status::invalid_argument(e.to_string()))?;
+
+        let signature = self
+            .sign_message(&request)
+            .map_err(|e| authorization_status(e, tonic::Code::Unauthenticated))?;
+
+        Ok(Response::new(pb::AuthorizeMessageResponse {
+            signature: Some(signature.into()),
+            verification_key: Some(
+                (*self
+                    .config
+                    .spend_key
+                    .full_viewing_key()
+                    .spend_verification_key())
+                .into(),
+            ),
+        }))
+    }
+
+    async fn challenge(
+        &self,
+        _request: Request<pb::ChallengeRequest>,
+    ) -> Result<Response<pb::ChallengeResponse>, Status> {
+        Ok(Response::new(pb::ChallengeResponse {
+            nonce: self.replay.challenge().0.to_vec(),
+        }))
+    }
+
+    async fn query_audit_log(
+        &self,
+        request: Request<pb::QueryAuditLogRequest>,
+    ) -> Result<Response<pb::QueryAuditLogResponse>, Status> {
+        let query = request.into_inner().into();
+        Ok(Response::new(self.audit_log.query(&query).into()))
+    }
+
+    async fn freeze(
+        &self,
+        request: Request<pb::FreezeRequest>,
+    ) -> Result<Response<pb::FreezeResponse>, Status> {
+        let generation = self
+            .freeze
+            .freeze(request.into_inner().reason)
+            .map_err(|e| Status::internal(format!("{e:#}")))?;
+        Ok(Response::new(pb::FreezeResponse { generation }))
+    }
+
+    async fn unfreeze(
+        &self,
+        request: Request<pb::UnfreezeRequest>,
+    ) -> Result<Response<pb::UnfreezeResponse>, Status> {
+        let unfreeze = request
+            .into_inner()
+            .try_into()
+            .map_err(|e: anyhow::Error| Status::invalid_argument(e.to_string()))?;
+        self.freeze
+            .unfreeze(&unfreeze, &self.identity_key().verification_key())
+            .map_err(|e| authorization_status(e, tonic::Code::Internal))?;
+        Ok(Response::new(pb::UnfreezeResponse {}))
+    }
+
+    async fn rotate_authorization_key(
+        &self,
+        request: Request<pb::RotateAuthorizationKeyRequest>,
+    ) -> Result<Response<pb::RotateAuthorizationKeyResponse>, Status> {
+        let rotation = request
+            .into_inner()
+            .try_into()
+            .map_err(|e: anyhow::Error| Status::invalid_argument(e.to_string()))?;
+        let signed = self
+            .rotation
+            .rotate(&rotation, &self.identity_key())
+            .map_err(|e| authorization_status(e, tonic::Code::Internal))?;
+        Ok(Response::new(signed.into()))
+    }
+
+    async fn export_policy(
+        &self,
+        _request: Request<pb::ExportPolicyRequest>,
+    ) -> Result<Response<pb::ExportPolicyResponse>, Status> {
+        let bundle = serde_json::to_string(&self.export_policy())
+            .map_err(|e| Status::internal(format!("could not encode policy bundle: {e}")))?;
+        Ok(Response::new(pb::ExportPolicyResponse { bundle }))
+    }
+
+    async fn import_policy(
+        &self,
+        request: Request<pb::ImportPolicyRequest>,
+    ) -> Result<Response<pb::ImportPolicyResponse>, Status> {
+        let signed: SignedPolicyBundle = serde_json::from_str(&request.into_inner().signed_bundle)
+            .map_err(|e| Status::invalid_argument(format!("invalid signed policy bundle: {e}")))?;
+        self.import_policy(&signed)
+            .map_err(|e| authorization_status(e, tonic::Code::Internal))?;
+        Ok(Response::new(pb::ImportPolicyResponse {
+            version: signed.bundle.version,
+        }))
+    }
+
+    async fn export_full_viewing_key(
+        &self,
+        _request: Request<pb::ExportFullViewingKeyRequest>,
+    ) -> Result<Response<pb::ExportFullViewingKeyResponse>, Status> {
+        Ok(Response::new(pb::ExportFullViewingKeyResponse {
+            full_viewing_key: Some(self.config.spend_key.full_viewing_key().clone().into()),
+        }))
+    }
+
+    async fn confirm_address(
+        &self,
+        request: Request<pb::ConfirmAddressRequest>,
+    ) -> Result<Response<pb::ConfirmAddressResponse>, Status> {
+        let address_index = request
+            .into_inner()
+            .address_index
+            .ok_or_else(|| {
+                Status::invalid_argument("missing address index in confirm address request")
+            })?
+            .try_into()
+            .map_err(|e| {
+                Status::invalid_argument(format!(
+                    "invalid address index in confirm address request: {e:#}"
+                ))
+            })?;
+
+        let (address, _dtk) = self
+            .config
+            .spend_key
+            .full_viewing_key()
+            .payment_address(address_index);
+
+        Ok(Response::new(pb::ConfirmAddressResponse {
+            address: Some(address.into()),
+        }))
+    }
+}
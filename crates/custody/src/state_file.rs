@@ -0,0 +1,27 @@
+//! Small JSON files holding the state of a custodian's safeguards across restarts.
+
+use std::{fs, path::Path};
+
+use anyhow::Context;
+use serde::{de::DeserializeOwned, Serialize};
+
+/// Read the `what` state persisted at the given path, if the file exists.
+pub(crate) fn load<T: DeserializeOwned>(path: &Path, what: &str) -> anyhow::Result<Option<T>> {
+    if !path.exists() {
+        return Ok(None);
+    }
+    let contents = fs::read(path)
+        .with_context(|| format!("failed to read {what} state at {}", path.display()))?;
+    let state = serde_json::from_slice(&contents)
+        .with_context(|| format!("invalid {what} state at {}", path.display()))?;
+    Ok(Some(state))
+}
+
+/// Write the `what` state out to the given path.
+pub(crate) fn store<T: Serialize>(path: &Path, what: &str, state: &T) -> anyhow::Result<()> {
+    // Write to a temporary file and rename it, so that the file is never left half-written.
+    let tmp = path.with_extension("tmp");
+    fs::write(&tmp, serde_json::to_vec(state)?)
+        .and_then(|()| fs::rename(&tmp, path))
+        .with_context(|| format!("failed to write {what} state to {}", path.display()))
+}
@@ -0,0 +1,171 @@
+//! Statuses for refused requests, carrying machine-readable [`ErrorDetail`]s
+//! so that clients can tell the causes of a refusal apart.
+
+use penumbra_proto::error::{
+    self, ControlRejected, CustodianFrozen, ErrorDetail, PolicyDenied, ReplayCause, RequestReplayed,
+};
+use tonic::Status;
+
+use crate::{
+    freeze::{Frozen, UnfreezeRejection},
+    policy::{BundleRejection, Denial},
+    replay::Replay,
+    rotation::{RotationOverdue, RotationRejection},
+    threshold::Declined,
+};
+
+/// The detail of a refusal, or `None` if the error is not one.
+///
+/// Authorizations are refused by policy, by the user, as a replay, by a frozen
+/// custodian or by one whose authorization key is overdue for rotation, and
+/// requests to unfreeze the custodian, to rotate its key or to import a policy
+/// bundle may be rejected too.
+pub(crate) fn refusal_detail(error: &anyhow::Error) -> Option<ErrorDetail> {
+    if let Some(rejection) = error.downcast_ref::<UnfreezeRejection>() {
+        return Some(control_rejected(
+            rejection,
+            matches!(rejection, UnfreezeRejection::NoRecoveryKeys),
+        ));
+    }
+    if let Some(rejection) = error.downcast_ref::<RotationRejection>() {
+        return Some(control_rejected(
+            rejection,
+            matches!(rejection, RotationRejection::NotConfigured),
+        ));
+    }
+    if let Some(rejection) = error.downcast_ref::<BundleRejection>() {
+        return Some(control_rejected(
+            rejection,
+            matches!(rejection, BundleRejection::NoSigners),
+        ));
+    }
+
+    if let Some(declined) = error.downcast_ref::<Declined>() {
+        Some(
+            PolicyDenied {
+                reason: declined.to_string(),
+            }
+            .into(),
+        )
+    } else if let Some(denial) = error.downcast_ref::<Denial>() {
+        Some(
+            PolicyDenied {
+                reason: denial.to_string(),
+            }
+            .into(),
+        )
+    } else if let Some(replay) = error.downcast_ref::<Replay>() {
+        let cause = match replay {
+            Replay::MissingNonce => ReplayCause::MissingNonce,
+            Replay::UnknownNonce => ReplayCause::UnknownNonce,
+            Replay::DuplicateEffectHash => ReplayCause::DuplicateEffectHash,
+        };
+        Some(
+            RequestReplayed {
+                cause: cause.into(),
+            }
+            .into(),
+        )
+    } else if let Some(frozen) = error.downcast_ref::<Frozen>() {
+        Some(
+            CustodianFrozen {
+                generation: frozen.generation,
+                reason: frozen.reason.clone(),
+            }
+            .into(),
+        )
+    } else {
+        error.downcast_ref::<RotationOverdue>().map(|overdue| {
+            error::RotationOverdue {
+                last_rotation: overdue.last_rotation,
+                inactivity_timeout_secs: overdue.inactivity_timeout_secs,
+            }
+            .into()
+        })
+    }
+}
+
+fn control_rejected(rejection: &impl std::fmt::Display, not_configured: bool) -> ErrorDetail {
+    ControlRejected {
+        reason: rejection.to_string(),
+        not_configured,
+    }
+    .into()
+}
+
+/// Map a failure to a status, with an [`ErrorDetail`] if it was a refusal, or
+/// else with the given fallback code.
+pub(crate) fn authorization_status(error: anyhow::Error, fallback: tonic::Code) -> Status {
+    let message = format!("{error:#}");
+    match refusal_detail(&error) {
+        Some(detail) => detail.into_status(message),
+        None => Status::new(fallback, message),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn refusals_carry_details() {
+        let status = authorization_status(Replay::UnknownNonce.into(), tonic::Code::Internal);
+        assert_eq!(status.code(), tonic::Code::PermissionDenied);
+        assert_eq!(
+            ErrorDetail::from_status(&status),
+            Some(
+                RequestReplayed {
+                    cause: ReplayCause::UnknownNonce.into(),
+                }
+                .into()
+            )
+        );
+
+        let status = authorization_status(anyhow::anyhow!("oops"), tonic::Code::Internal);
+        assert_eq!(status.code(), tonic::Code::Internal);
+        assert_eq!(ErrorDetail::from_status(&status), None);
+    }
+
+    #[test]
+    fn control_rejections_carry_details() {
+        let status = authorization_status(
+            anyhow::Error::from(UnfreezeRejection::NotFrozen).context("could not unfreeze"),
+            tonic::Code::Internal,
+        );
+        assert_eq!(status.code(), tonic::Code::PermissionDenied);
+        assert_eq!(
+            ErrorDetail::from_status(&status),
+            Some(
+                ControlRejected {
+                    reason: UnfreezeRejection::NotFrozen.to_string(),
+                    not_configured: false,
+                }
+                .into()
+            )
+        );
+
+        let status = authorization_status(
+            RotationRejection::NotConfigured.into(),
+            tonic::Code::Internal,
+        );
+        assert_eq!(status.code(), tonic::Code::FailedPrecondition);
+        assert!(matches!(
+            ErrorDetail::from_status(&status),
+            Some(ErrorDetail {
+                detail: Some(error::Detail::ControlRejected(ControlRejected {
+                    not_configured: true,
+                    ..
+                })),
+            })
+        ));
+
+        let status = authorization_status(Declined.into(), tonic::Code::Internal);
+        assert_eq!(status.code(), tonic::Code::PermissionDenied);
+        assert!(matches!(
+            ErrorDetail::from_status(&status),
+            Some(ErrorDetail {
+                detail: Some(error::Detail::PolicyDenied(_)),
+            })
+        ));
+    }
+}
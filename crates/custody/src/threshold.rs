@@ -1,4 +1,7 @@
+use std::{pin::Pin, sync::Arc};
+
 use anyhow::{anyhow, Result};
+use futures::{channel::mpsc, StreamExt};
 use rand_core::OsRng;
 use serde::{Deserialize, Serialize};
 use tonic::{async_trait, Request, Response, Status};
@@ -7,7 +10,16 @@ use penumbra_keys::{keys::AddressIndex, Address, FullViewingKey};
 use penumbra_proto::{custody::v1 as pb, DomainType};
 use penumbra_transaction::{AuthorizationData, TransactionPlan};
 
-use crate::AuthorizeRequest;
+use crate::{
+    audit::{AuditLog, Decision},
+    freeze::FreezeSwitch,
+    plan_text,
+    policy::PolicyEngine,
+    replay::ReplayGuard,
+    rotation::RotationSwitch,
+    status::{authorization_status, refusal_detail},
+    AuthorizeRequest, PlanSummary,
+};
 
 pub use self::config::Config;
 
@@ -41,8 +53,9 @@ where
 pub trait Terminal {
     /// Have a user confirm that they want to sign this transaction.
     ///
-    /// In an actual terminal, this should display the transaction in a human readable
-    /// form, and then get feedback from the user.
+    /// In an actual terminal, this should display the transaction's canonical
+    /// [`PlanText`](crate::PlanText), so that every signer sees the same summary, and then
+    /// get feedback from the user.
     async fn confirm_transaction(&self, transaction: &TransactionPlan) -> Result<bool>;
 
     /// Push an explanatory message to the terminal.
@@ -80,8 +93,10 @@ pub async fn follow(config: &Config, terminal: &impl Terminal) -> Result<()> {
         from_json(&string)?
     };
     if !terminal.confirm_transaction(&round1_message.plan()).await? {
+        plan_text::audit_decision(round1_message.plan(), Some(&"declined by user"));
         return Ok(());
     }
+    plan_text::audit_decision(round1_message.plan(), None);
     let (round1_reply, round1_state) = sign::follower_round1(&mut OsRng, config, round1_message)?;
     terminal
         .explain("Send this message to the coordinator:")
@@ -173,20 +188,150 @@ pub async fn dkg(t: u16, n: u16, terminal: &impl Terminal) -> Result<Config> {
 /// other signers with the same type of configuration need to cooperate
 /// to help produce a signature.
 pub struct Threshold<T> {
-    config: Config,
-    terminal: T,
+    config: Arc<Config>,
+    terminal: Arc<T>,
+    replay: Arc<ReplayGuard>,
+    audit_log: Arc<AuditLog>,
+    freeze: Arc<FreezeSwitch>,
+    rotation: Arc<RotationSwitch>,
+    policy: Arc<PolicyEngine>,
 }
 
 impl<T> Threshold<T> {
     pub fn new(config: Config, terminal: T) -> Self {
-        Threshold { config, terminal }
+        let audit_log = AuditLog::in_memory(config.signing_key().clone());
+        let policy = PolicyEngine::new(config.fvk().clone(), Vec::new());
+        Threshold {
+            config: Arc::new(config),
+            terminal: Arc::new(terminal),
+            replay: Arc::new(ReplayGuard::new(false, false)),
+            audit_log: Arc::new(audit_log),
+            freeze: Arc::new(FreezeSwitch::in_memory(config.recovery_signers().cloned())),
+            rotation: Arc::new(RotationSwitch::unconfigured()),
+            policy: Arc::new(policy),
+        }
+    }
+
+    /// Record decisions in the given audit log, rather than an in-memory one.
+    ///
+    /// The log should be signed by this signer's [`signing_key`](Config::signing_key).
+    pub fn with_audit_log(mut self, audit_log: AuditLog) -> Self {
+        self.audit_log = Arc::new(audit_log);
+        self
+    }
+
+    /// Use the given freeze switch, rather than an in-memory one.
+    ///
+    /// The switch should be opened with the configured
+    /// [`recovery_signers`](Config::recovery_signers), and persisted to the
+    /// configured [`freeze_state`](Config::freeze_state), if any.
+    pub fn with_freeze_switch(mut self, freeze: FreezeSwitch) -> Self {
+        self.freeze = Arc::new(freeze);
+        self
+    }
+
+    /// Use the given rotation switch, rather than an unconfigured one which
+    /// never requires the authorization key to be rotated.
+    pub fn with_rotation_switch(mut self, rotation: RotationSwitch) -> Self {
+        self.rotation = Arc::new(rotation);
+        self
+    }
+
+    /// Enforce the given policies before starting a signing ceremony, rather
+    /// than none, recording what each authorized request sends in their spend
+    /// ledger.
+    pub fn with_policy(mut self, policy: PolicyEngine) -> Self {
+        self.policy = Arc::new(policy);
+        self
     }
 }
 
+// Implemented by hand, since the terminal itself need not be `Clone`.
+impl<T> Clone for Threshold<T> {
+    fn clone(&self) -> Self {
+        Threshold {
+            config: self.config.clone(),
+            terminal: self.terminal.clone(),
+            replay: self.replay.clone(),
+            audit_log: self.audit_log.clone(),
+            freeze: self.freeze.clone(),
+            rotation: self.rotation.clone(),
+            policy: self.policy.clone(),
+        }
+    }
+}
+
+/// The progress of a signing ceremony, as reported to the caller of
+/// [`Threshold::authorize_with_progress`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Progress {
+    /// Waiting for the user to confirm the transaction on the terminal.
+    PendingUserApproval,
+    /// Waiting for the other signers' replies, of which `received` out of `required` have been
+    /// received, counting the replies for both rounds of the ceremony.
+    AwaitingCosigners { received: u32, required: u32 },
+}
+
+/// The user declined to sign the transaction when asked to confirm it.
+#[derive(Debug, thiserror::Error)]
+#[error("the user declined to authorize the transaction")]
+pub(crate) struct Declined;
+
 impl<T: Terminal> Threshold<T> {
     /// Try and create the necessary signatures to authorize the transaction plan.
     async fn authorize(&self, request: AuthorizeRequest) -> Result<AuthorizationData> {
-        let plan = request.plan;
+        self.authorize_with_progress(request, false, |_| {}).await
+    }
+
+    /// Like [`Self::authorize`], but calls `progress` whenever the ceremony makes progress, first
+    /// asking the user to confirm the transaction on the terminal if `confirm` is set.
+    ///
+    /// The outcome is recorded in the audit log, and if it can't be, the request is not authorized.
+    async fn authorize_with_progress(
+        &self,
+        request: AuthorizeRequest,
+        confirm: bool,
+        progress: impl Fn(Progress) + Send + Sync,
+    ) -> Result<AuthorizationData> {
+        let result = self.sign_with_progress(&request, confirm, progress).await;
+        self.audit_log
+            .record(&request, None, Vec::new(), Decision::from(&result))?;
+        result
+    }
+
+    /// Run the signing ceremony for the request with the other signers.
+    async fn sign_with_progress(
+        &self,
+        request: &AuthorizeRequest,
+        confirm: bool,
+        progress: impl Fn(Progress) + Send + Sync,
+    ) -> Result<AuthorizationData> {
+        self.freeze.check()?;
+        self.rotation.check()?;
+        let plan = request.plan.clone();
+        if confirm {
+            progress(Progress::PendingUserApproval);
+            if !self.terminal.confirm_transaction(&plan).await? {
+                return Err(Declined.into());
+            }
+        }
+        // Reject denied and replayed requests before involving the other signers,
+        // and record the request in the ledger before another one is checked
+        // against it. The ledger is released before the ceremony, so a ceremony
+        // which fails still counts towards the daily limits.
+        {
+            let mut ledger = self.policy.ledger().lock();
+            self.policy.evaluate_locked(request, &ledger)?;
+            let effect_hash = plan.effect_hash(self.config.fvk())?;
+            self.replay.check(request.nonce.as_ref(), &effect_hash)?;
+            self.policy.record(request, &mut ledger)?;
+        }
+        // We need 1 less reply than the threshold in each round, since we've already included
+        // ourselves.
+        let per_round = u32::from(self.config.threshold()).saturating_sub(1);
+        let required = 2 * per_round;
+        let mut received = 0;
+        progress(Progress::AwaitingCosigners { received, required });
 
         // Round 1
         let (round1_message, state1) = sign::coordinator_round1(&mut OsRng, &self.config, plan)?;
@@ -202,8 +347,7 @@ impl<T: Terminal> Threshold<T> {
             .await?;
         let round1_replies = {
             let mut acc = Vec::new();
-            // We need 1 less, since we've already included ourselves.
-            for _ in 1..self.config.threshold() {
+            for _ in 0..per_round {
                 let reply_str = self
                     .terminal
                     .next_response()
@@ -211,6 +355,8 @@ impl<T: Terminal> Threshold<T> {
                     .ok_or(anyhow!("expected round1 reply"))?;
                 let reply = from_json::<sign::FollowerRound1>(&reply_str)?;
                 acc.push(reply);
+                received += 1;
+                progress(Progress::AwaitingCosigners { received, required });
             }
             acc
         };
@@ -228,8 +374,7 @@ impl<T: Terminal> Threshold<T> {
             .await?;
         let round2_replies = {
             let mut acc = Vec::new();
-            // We need 1 less, since we've already included ourselves.
-            for _ in 1..self.config.threshold() {
+            for _ in 0..per_round {
                 let reply_str = self
                     .terminal
                     .next_response()
@@ -237,6 +382,8 @@ impl<T: Terminal> Threshold<T> {
                     .ok_or(anyhow!("expected round2 reply"))?;
                 let reply = from_json::<sign::FollowerRound2>(&reply_str)?;
                 acc.push(reply);
+                received += 1;
+                progress(Progress::AwaitingCosigners { received, required });
             }
             acc
         };
@@ -261,22 +408,173 @@ impl<T: Terminal> Threshold<T> {
 impl<T: Terminal + Sync + Send + 'static> pb::custody_service_server::CustodyService
     for Threshold<T>
 {
+    type AuthorizeStreamStream =
+        Pin<Box<dyn futures::Stream<Item = Result<pb::AuthorizeStreamResponse, Status>> + Send>>;
+
     async fn authorize(
         &self,
         request: Request<pb::AuthorizeRequest>,
     ) -> Result<Response<pb::AuthorizeResponse>, Status> {
-        let request = request
+        let request: AuthorizeRequest = request
             .into_inner()
             .try_into()
             .map_err(|e| Status::invalid_argument(format!("{e}")))?;
-        let data = self.authorize(request).await.map_err(|e| {
-            Status::internal(format!("Failed to process authorization request: {e}"))
-        })?;
+        let summary = PlanSummary::new(&request.plan, self.config.fvk());
+        let data = self
+            .authorize(request)
+            .await
+            .map_err(|e| authorization_status(e, tonic::Code::Internal))?;
         Ok(Response::new(pb::AuthorizeResponse {
             data: Some(data.into()),
+            summary: Some(summary.into()),
+        }))
+    }
+
+    async fn simulate_authorization(
+        &self,
+        _request: Request<pb::AuthorizeRequest>,
+    ) -> Result<Response<pb::SimulateAuthorizationResponse>, Status> {
+        Err(Status::unimplemented(
+            "threshold custody can't simulate authorization requests",
+        ))
+    }
+
+    async fn authorize_stream(
+        &self,
+        request: Request<pb::AuthorizeRequest>,
+    ) -> Result<Response<Self::AuthorizeStreamStream>, Status> {
+        use pb::authorize_stream_response::{
+            Approved, AwaitingCosigner, Denied, PendingUserApproval, Status as AuthorizeStatus,
+        };
+
+        let request: AuthorizeRequest = request
+            .into_inner()
+            .try_into()
+            .map_err(|e| Status::invalid_argument(format!("{e}")))?;
+
+        // The signing ceremony may take a long time, so we run it in the
+        // background, asking the user to approve the request first, and then
+        // reporting each cosigner reply as it comes in.
+        let (tx, rx) = mpsc::unbounded();
+        let this = self.clone();
+        tokio::spawn(async move {
+            let progress_tx = tx.clone();
+            let progress = move |progress| {
+                let status = match progress {
+                    Progress::PendingUserApproval => {
+                        AuthorizeStatus::PendingUserApproval(PendingUserApproval {})
+                    }
+                    Progress::AwaitingCosigners { received, required } => {
+                        AuthorizeStatus::AwaitingCosigner(AwaitingCosigner { received, required })
+                    }
+                };
+                let _ = progress_tx.unbounded_send(Ok(pb::AuthorizeStreamResponse {
+                    status: Some(status),
+                }));
+            };
+            let update = match this.authorize_with_progress(request, true, progress).await {
+                Ok(data) => Ok(AuthorizeStatus::Approved(Approved {
+                    data: Some(data.into()),
+                })),
+                // Refusals are part of the stream, like approvals, while any
+                // other failure ends it with an error.
+                Err(e) if refusal_detail(&e).is_some() => Ok(AuthorizeStatus::Denied(Denied {
+                    reason: format!("{e:#}"),
+                })),
+                Err(e) => Err(authorization_status(e, tonic::Code::Internal)),
+            };
+            let _ = tx.unbounded_send(update.map(|status| pb::AuthorizeStreamResponse {
+                status: Some(status),
+            }));
+        });
+
+        Ok(Response::new(rx.boxed()))
+    }
+
+    async fn authorize_message(
+        &self,
+        _request: Request<pb::AuthorizeMessageRequest>,
+    ) -> Result<Response<pb::AuthorizeMessageResponse>, Status> {
+        Err(Status::unimplemented(
+            "message signing is not supported by the threshold custodian, whose signing ceremony only covers transaction plans",
+        ))
+    }
+
+    async fn challenge(
+        &self,
+        _request: Request<pb::ChallengeRequest>,
+    ) -> Result<Response<pb::ChallengeResponse>, Status> {
+        Ok(Response::new(pb::ChallengeResponse {
+            nonce: self.replay.challenge().0.to_vec(),
         }))
     }
 
+    async fn query_audit_log(
+        &self,
+        request: Request<pb::QueryAuditLogRequest>,
+    ) -> Result<Response<pb::QueryAuditLogResponse>, Status> {
+        let query = request.into_inner().into();
+        Ok(Response::new(self.audit_log.query(&query).into()))
+    }
+
+    async fn freeze(
+        &self,
+        request: Request<pb::FreezeRequest>,
+    ) -> Result<Response<pb::FreezeResponse>, Status> {
+        let generation = self
+            .freeze
+            .freeze(request.into_inner().reason)
+            .map_err(|e| Status::internal(format!("{e:#}")))?;
+        Ok(Response::new(pb::FreezeResponse { generation }))
+    }
+
+    async fn unfreeze(
+        &self,
+        request: Request<pb::UnfreezeRequest>,
+    ) -> Result<Response<pb::UnfreezeResponse>, Status> {
+        let unfreeze = request
+            .into_inner()
+            .try_into()
+            .map_err(|e: anyhow::Error| Status::invalid_argument(e.to_string()))?;
+        self.freeze
+            .unfreeze(&unfreeze, &self.config.signing_key().verification_key())
+            .map_err(|e| authorization_status(e, tonic::Code::Internal))?;
+        Ok(Response::new(pb::UnfreezeResponse {}))
+    }
+
+    async fn rotate_authorization_key(
+        &self,
+        request: Request<pb::RotateAuthorizationKeyRequest>,
+    ) -> Result<Response<pb::RotateAuthorizationKeyResponse>, Status> {
+        let rotation = request
+            .into_inner()
+            .try_into()
+            .map_err(|e: anyhow::Error| Status::invalid_argument(e.to_string()))?;
+        let signed = self
+            .rotation
+            .rotate(&rotation, self.config.signing_key())
+            .map_err(|e| authorization_status(e, tonic::Code::Internal))?;
+        Ok(Response::new(signed.into()))
+    }
+
+    async fn export_policy(
+        &self,
+        _request: Request<pb::ExportPolicyRequest>,
+    ) -> Result<Response<pb::ExportPolicyResponse>, Status> {
+        Err(Status::unimplemented(
+            "policy bundles are not supported by the threshold custodian, whose policy is set when it is constructed",
+        ))
+    }
+
+    async fn import_policy(
+        &self,
+        _request: Request<pb::ImportPolicyRequest>,
+    ) -> Result<Response<pb::ImportPolicyResponse>, Status> {
+        Err(Status::unimplemented(
+            "policy bundles are not supported by the threshold custodian, whose policy is set when it is constructed",
+        ))
+    }
+
     async fn export_full_viewing_key(
         &self,
         _request: Request<pb::ExportFullViewingKeyRequest>,
@@ -456,9 +754,7 @@ mod test {
         Ok(())
     }
 
-    #[tokio::test]
-    async fn test_transaction_signing() -> Result<()> {
-        const TEST_PLAN: &'static str = r#"
+    const TEST_PLAN: &'static str = r#"
 {
     "actions": [
         {
@@ -554,7 +850,10 @@ mod test {
         "key": "3plOcPZzKKj8KT3sVdKnblUUFDRzCmMWYtgwB3BqfXQ="
     }
 }
-        "#;
+    "#;
+
+    #[tokio::test]
+    async fn test_transaction_signing() -> Result<()> {
         const T: u16 = 3;
         const N: u16 = 3;
 
@@ -575,6 +874,7 @@ mod test {
             .authorize(AuthorizeRequest {
                 plan: plan.clone(),
                 pre_authorizations: Vec::new(),
+                nonce: None,
             })
             .await?;
         assert_eq!(
@@ -600,4 +900,117 @@ mod test {
         }
         Ok(())
     }
+
+    struct DecliningTerminal;
+
+    #[async_trait]
+    impl Terminal for DecliningTerminal {
+        async fn confirm_transaction(&self, _transaction: &TransactionPlan) -> Result<bool> {
+            Ok(false)
+        }
+
+        async fn explain(&self, _msg: &str) -> Result<()> {
+            Ok(())
+        }
+
+        async fn broadcast(&self, _data: &str) -> Result<()> {
+            Ok(())
+        }
+
+        async fn next_response(&self) -> Result<Option<String>> {
+            Ok(None)
+        }
+    }
+
+    async fn stream_statuses<T: Terminal + Sync + Send + 'static>(
+        custody: Threshold<T>,
+        plan: TransactionPlan,
+    ) -> Result<Vec<pb::authorize_stream_response::Status>> {
+        use pb::custody_service_server::CustodyService as _;
+
+        let request = AuthorizeRequest {
+            plan,
+            pre_authorizations: Vec::new(),
+            nonce: None,
+        };
+        let stream = custody
+            .authorize_stream(Request::new(request.into()))
+            .await?
+            .into_inner();
+        let responses = stream.collect::<Vec<_>>().await;
+        responses
+            .into_iter()
+            .map(|response| Ok(response?.status.expect("status is present")))
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn authorize_stream_reports_approval_and_monotonic_progress() -> Result<()> {
+        use pb::authorize_stream_response::{AwaitingCosigner, Status as AuthorizeStatus};
+
+        const T: u16 = 3;
+        const N: u16 = 3;
+
+        let (coordinator_config, follower_configs) = {
+            let mut configs = run_dkg(T, N).await?;
+            (configs.pop().unwrap(), configs)
+        };
+        let (coordinator_terminal, follower_terminals) = make_terminals((N - 1) as usize);
+        for (config, terminal) in follower_configs
+            .into_iter()
+            .zip(follower_terminals.into_iter())
+        {
+            tokio::spawn(async move { follow(&config, &terminal).await });
+        }
+        let plan = serde_json::from_str::<TransactionPlan>(TEST_PLAN)?;
+        let statuses = stream_statuses(
+            Threshold::new(coordinator_config, coordinator_terminal),
+            plan,
+        )
+        .await?;
+
+        // The user is asked first, then each of the two replies from both other signers is
+        // counted, without starting over for the second round, and finally the request is
+        // approved.
+        let required = 2 * u32::from(T - 1);
+        assert!(matches!(
+            statuses.first(),
+            Some(AuthorizeStatus::PendingUserApproval(_))
+        ));
+        let progress = statuses[1..statuses.len() - 1]
+            .iter()
+            .map(|status| match status {
+                AuthorizeStatus::AwaitingCosigner(progress) => progress.clone(),
+                other => panic!("unexpected status {other:?}"),
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            progress,
+            (0..=required)
+                .map(|received| AwaitingCosigner { received, required })
+                .collect::<Vec<_>>()
+        );
+        assert!(matches!(
+            statuses.last(),
+            Some(AuthorizeStatus::Approved(_))
+        ));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn authorize_stream_reports_declined_requests_as_denied() -> Result<()> {
+        use pb::authorize_stream_response::Status as AuthorizeStatus;
+
+        let config = run_dkg(2, 2).await?.pop().unwrap();
+        let plan = serde_json::from_str::<TransactionPlan>(TEST_PLAN)?;
+        let statuses = stream_statuses(Threshold::new(config, DecliningTerminal), plan).await?;
+
+        assert_eq!(statuses.len(), 2);
+        assert!(matches!(
+            statuses[0],
+            AuthorizeStatus::PendingUserApproval(_)
+        ));
+        assert!(matches!(statuses[1], AuthorizeStatus::Denied(_)));
+        Ok(())
+    }
 }
@@ -7,7 +7,12 @@ use penumbra_keys::{keys::NullifierKey, FullViewingKey};
 use rand_core::CryptoRngCore;
 use serde::{Deserialize, Serialize};
 use serde_with::{formats::Uppercase, hex::Hex, DisplayFromStr, TryFromInto};
-use std::collections::{HashMap, HashSet};
+use std::{
+    collections::{HashMap, HashSet},
+    path::PathBuf,
+};
+
+use crate::policy::PolicySigners;
 
 /// A shim to serialize frost::keys::SigningShare
 #[serde_as]
@@ -99,6 +104,18 @@ pub struct Config {
         as = "HashMap<TryFromInto<VerificationKeyWrapper>, TryFromInto<VerifyingShareWrapper>>"
     )]
     verifying_shares: HashMap<VerificationKey, frost::keys::VerifyingShare>,
+    /// The keys allowed to unfreeze this signer after it was frozen; if
+    /// unset, it can't be unfrozen remotely.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    recovery_signers: Option<PolicySigners>,
+    /// The file this signer's freeze switch is persisted to; if unset, `pcli`
+    /// persists it next to its configuration.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    freeze_state: Option<PathBuf>,
+    /// The file this signer's signed audit log is persisted to; if unset,
+    /// `pcli` persists it next to its configuration.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    audit_log: Option<PathBuf>,
 }
 
 impl PartialEq for Config {
@@ -109,6 +126,9 @@ impl PartialEq for Config {
             // TIMING LEAK
             && self.signing_key.as_bytes() == other.signing_key.as_bytes()
             && self.verifying_shares == other.verifying_shares
+            && self.recovery_signers == other.recovery_signers
+            && self.freeze_state == other.freeze_state
+            && self.audit_log == other.audit_log
     }
 }
 
@@ -147,6 +167,9 @@ impl Config {
             spend_key_share,
             signing_key,
             verifying_shares,
+            recovery_signers: None,
+            freeze_state: None,
+            audit_log: None,
         }
     }
 
@@ -203,6 +226,9 @@ impl Config {
                     fvk: fvk.clone(),
                     spend_key_share: signing_share,
                     verifying_shares: verifying_shares.clone(),
+                    recovery_signers: None,
+                    freeze_state: None,
+                    audit_log: None,
                 }
             })
             .collect())
@@ -256,6 +282,21 @@ impl Config {
         &self.fvk
     }
 
+    /// The keys allowed to unfreeze this signer, if any.
+    pub fn recovery_signers(&self) -> Option<&PolicySigners> {
+        self.recovery_signers.as_ref()
+    }
+
+    /// The file this signer's freeze switch is persisted to, if configured.
+    pub fn freeze_state(&self) -> Option<&PathBuf> {
+        self.freeze_state.as_ref()
+    }
+
+    /// The file this signer's audit log is persisted to, if configured.
+    pub fn audit_log(&self) -> Option<&PathBuf> {
+        self.audit_log.as_ref()
+    }
+
     pub fn verification_keys(&self) -> HashSet<VerificationKey> {
         self.verifying_shares.keys().cloned().collect()
     }
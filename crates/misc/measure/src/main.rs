@@ -14,10 +14,11 @@ use penumbra_proto::{
     penumbra::util::tendermint_proxy::v1::{
         tendermint_proxy_service_client::TendermintProxyServiceClient, GetStatusRequest,
     },
+    rpc::ClientBuilder,
     Message,
 };
 
-use tonic::transport::{Channel, ClientTlsConfig};
+use tonic::transport::Channel;
 use url::Url;
 
 #[derive(Debug, Parser)]
@@ -268,21 +269,12 @@ impl Opt {
     }
 }
 
-// This code is ripped from the pcli code, and could be split out into something common.
 async fn get_tendermint_proxy_client(
     pd_url: Url,
 ) -> anyhow::Result<TendermintProxyServiceClient<Channel>> {
-    let pd_channel: Channel = match pd_url.scheme() {
-        "http" => Channel::from_shared(pd_url.to_string())?.connect().await?,
-        "https" => {
-            Channel::from_shared(pd_url.to_string())?
-                .tls_config(ClientTlsConfig::new())?
-                .connect()
-                .await?
-        }
-        other => anyhow::bail!(format!("unknown url scheme {other}")),
-    };
-    Ok(TendermintProxyServiceClient::new(pd_channel))
+    ClientBuilder::new(pd_url.to_string())
+        .connect_client()
+        .await
 }
 
 #[tokio::main]
@@ -0,0 +1,173 @@
+//! Machine-readable error details for the gRPC services.
+//!
+//! A service reporting an error whose cause a client may want to act on
+//! attaches an [`ErrorDetail`] to the status it returns, with
+//! [`ErrorDetail::into_status`], and the client recovers it with
+//! [`ErrorDetail::from_status`], rather than matching on the status message.
+//!
+//! The detail is packed in a `google.rpc.Status`, which is what gRPC clients
+//! expect to find in the `grpc-status-details-bin` metadata of a status.
+
+pub use crate::penumbra::util::error::v1::{
+    error_detail::Detail, request_replayed::Cause as ReplayCause, AnchorUnknown, ControlRejected,
+    CustodianFrozen, ErrorDetail, InsufficientBalance, PolicyDenied, RequestReplayed,
+    RotationOverdue, ViewOnly,
+};
+
+/// The `google.rpc.Status` message of gRPC's rich error model, which carries
+/// the details of a status.
+#[cfg(feature = "rpc")]
+#[derive(Clone, PartialEq, prost::Message)]
+struct RpcStatus {
+    /// The status code, which should be the same as the status's own.
+    #[prost(int32, tag = "1")]
+    code: i32,
+    /// The status message, which should be the same as the status's own.
+    #[prost(string, tag = "2")]
+    message: String,
+    /// The details of the status, each packed with its type.
+    #[prost(message, repeated, tag = "3")]
+    details: Vec<pbjson_types::Any>,
+}
+
+/// The type URL of an [`ErrorDetail`] packed in a `google.protobuf.Any`.
+#[cfg(feature = "rpc")]
+fn error_detail_type_url() -> String {
+    use prost::Name;
+
+    format!("type.googleapis.com/{}", ErrorDetail::full_name())
+}
+
+impl ErrorDetail {
+    /// The status code for an error with this detail.
+    #[cfg(feature = "rpc")]
+    pub fn code(&self) -> tonic::Code {
+        match self.detail {
+            Some(Detail::InsufficientBalance(_))
+            | Some(Detail::AnchorUnknown(_))
+            | Some(Detail::ViewOnly(_)) => tonic::Code::FailedPrecondition,
+            Some(Detail::PolicyDenied(_))
+            | Some(Detail::RequestReplayed(_))
+            | Some(Detail::CustodianFrozen(_))
+            | Some(Detail::RotationOverdue(_)) => tonic::Code::PermissionDenied,
+            Some(Detail::ControlRejected(ControlRejected {
+                not_configured: true,
+                ..
+            })) => tonic::Code::FailedPrecondition,
+            Some(Detail::ControlRejected(_)) => tonic::Code::PermissionDenied,
+            None => tonic::Code::Unknown,
+        }
+    }
+
+    /// Create a status with the given message, carrying this detail, with the
+    /// [`code`](Self::code) for it.
+    #[cfg(feature = "rpc")]
+    pub fn into_status(self, message: impl Into<String>) -> tonic::Status {
+        use prost::Message;
+
+        let code = self.code();
+        let message = message.into();
+        let envelope = RpcStatus {
+            code: code as i32,
+            message: message.clone(),
+            details: vec![pbjson_types::Any {
+                type_url: error_detail_type_url(),
+                value: self.encode_to_vec().into(),
+            }],
+        };
+        tonic::Status::with_details(code, message, envelope.encode_to_vec().into())
+    }
+
+    /// Recover the detail attached to a status, if it has one.
+    #[cfg(feature = "rpc")]
+    pub fn from_status(status: &tonic::Status) -> Option<Self> {
+        use prost::Message;
+
+        if status.details().is_empty() {
+            return None;
+        }
+        let type_url = error_detail_type_url();
+        RpcStatus::decode(status.details())
+            .ok()?
+            .details
+            .into_iter()
+            .filter(|any| any.type_url == type_url)
+            .find_map(|any| Self::decode(any.value.as_ref()).ok())
+            .filter(|detail| detail.detail.is_some())
+    }
+}
+
+macro_rules! impl_into_error_detail {
+    ($($variant:ident),* $(,)?) => {
+        $(
+            impl From<$variant> for ErrorDetail {
+                fn from(detail: $variant) -> Self {
+                    Self {
+                        detail: Some(Detail::$variant(detail)),
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_into_error_detail!(
+    InsufficientBalance,
+    AnchorUnknown,
+    PolicyDenied,
+    RequestReplayed,
+    CustodianFrozen,
+    RotationOverdue,
+    ViewOnly,
+    ControlRejected,
+);
+
+#[cfg(all(test, feature = "rpc"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn detail_round_trips_through_status() {
+        let detail = ErrorDetail::from(CustodianFrozen {
+            generation: 3,
+            reason: "suspected compromise".to_string(),
+        });
+        let status = detail.clone().into_status("custodian is frozen");
+
+        assert_eq!(status.code(), tonic::Code::PermissionDenied);
+        assert_eq!(status.message(), "custodian is frozen");
+        assert_eq!(ErrorDetail::from_status(&status), Some(detail));
+
+        assert_eq!(
+            ErrorDetail::from_status(&tonic::Status::internal("oops")),
+            None
+        );
+    }
+
+    #[test]
+    fn detail_is_packed_in_a_google_rpc_status() {
+        use prost::Message;
+
+        let detail = ErrorDetail::from(ControlRejected {
+            reason: "no recovery keys are configured".to_string(),
+            not_configured: true,
+        });
+        let status = detail.clone().into_status("unfreeze refused");
+        assert_eq!(status.code(), tonic::Code::FailedPrecondition);
+
+        // Generic gRPC clients find the detail as an `Any` in the envelope.
+        let envelope =
+            RpcStatus::decode(status.details()).expect("details are a google.rpc.Status");
+        assert_eq!(envelope.code, tonic::Code::FailedPrecondition as i32);
+        assert_eq!(envelope.message, "unfreeze refused");
+        assert_eq!(envelope.details.len(), 1);
+        assert_eq!(
+            envelope.details[0].type_url,
+            "type.googleapis.com/penumbra.util.error.v1.ErrorDetail"
+        );
+        assert_eq!(
+            ErrorDetail::decode(envelope.details[0].value.as_ref()).ok(),
+            Some(detail)
+        );
+    }
+}
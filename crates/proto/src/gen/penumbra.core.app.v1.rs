@@ -84,6 +84,11 @@ pub struct AppParameters {
     pub shielded_pool_params: ::core::option::Option<
         super::super::component::shielded_pool::v1::ShieldedPoolParameters,
     >,
+    /// Dex module parameters.
+    #[prost(message, optional, tag = "11")]
+    pub dex_params: ::core::option::Option<
+        super::super::component::dex::v1::DexParameters,
+    >,
 }
 impl ::prost::Name for AppParameters {
     const NAME: &'static str = "AppParameters";
@@ -116,6 +121,214 @@ impl ::prost::Name for AppParametersResponse {
         ::prost::alloc::format!("penumbra.core.app.v1.{}", Self::NAME)
     }
 }
+/// Requests a preview of the effects of enacting a proposal.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ProposalEffectsRequest {
+    /// The proposal id to preview.
+    #[prost(uint64, tag = "1")]
+    pub proposal_id: u64,
+}
+impl ::prost::Name for ProposalEffectsRequest {
+    const NAME: &'static str = "ProposalEffectsRequest";
+    const PACKAGE: &'static str = "penumbra.core.app.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.core.app.v1.{}", Self::NAME)
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ProposalEffectsResponse {
+    /// The height of the state the proposal's enactment was simulated against.
+    #[prost(uint64, tag = "1")]
+    pub height: u64,
+    /// Whether enacting the proposal against that state would succeed.
+    #[prost(bool, tag = "2")]
+    pub enactable: bool,
+    /// Why enacting the proposal would fail, if it would.
+    #[prost(string, tag = "3")]
+    pub failure_reason: ::prost::alloc::string::String,
+    /// The app parameters the proposal would change.
+    #[prost(message, repeated, tag = "4")]
+    pub parameter_changes: ::prost::alloc::vec::Vec<ParameterChange>,
+    /// The consequences of enacting the proposal, derived from its payload and the state.
+    #[prost(message, repeated, tag = "5")]
+    pub effects: ::prost::alloc::vec::Vec<ProposalEffect>,
+}
+impl ::prost::Name for ProposalEffectsResponse {
+    const NAME: &'static str = "ProposalEffectsResponse";
+    const PACKAGE: &'static str = "penumbra.core.app.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.core.app.v1.{}", Self::NAME)
+    }
+}
+/// A change to a single app parameter.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ParameterChange {
+    /// The component the parameter belongs to, such as `fee`.
+    #[prost(string, tag = "1")]
+    pub component: ::prost::alloc::string::String,
+    /// The path of the parameter within the component's parameters, such as
+    /// `fixedGasPrices.blockSpacePrice`.
+    #[prost(string, tag = "2")]
+    pub parameter: ::prost::alloc::string::String,
+    /// The current value of the parameter, as JSON.
+    #[prost(string, tag = "3")]
+    pub old_value: ::prost::alloc::string::String,
+    /// The value of the parameter after enactment, as JSON.
+    #[prost(string, tag = "4")]
+    pub new_value: ::prost::alloc::string::String,
+}
+impl ::prost::Name for ParameterChange {
+    const NAME: &'static str = "ParameterChange";
+    const PACKAGE: &'static str = "penumbra.core.app.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.core.app.v1.{}", Self::NAME)
+    }
+}
+/// A consequence of enacting a proposal, which wallets can describe to voters.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ProposalEffect {
+    #[prost(oneof = "proposal_effect::Effect", tags = "1, 2, 3, 4, 5, 6")]
+    pub effect: ::core::option::Option<proposal_effect::Effect>,
+}
+/// Nested message and enum types in `ProposalEffect`.
+pub mod proposal_effect {
+    /// The fee schedule changes.
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct FeeSchedule {
+        /// The gas prices before enactment.
+        #[prost(message, optional, tag = "1")]
+        pub old_gas_prices: ::core::option::Option<
+            super::super::super::component::fee::v1::GasPrices,
+        >,
+        /// The gas prices after enactment.
+        #[prost(message, optional, tag = "2")]
+        pub new_gas_prices: ::core::option::Option<
+            super::super::super::component::fee::v1::GasPrices,
+        >,
+    }
+    impl ::prost::Name for FeeSchedule {
+        const NAME: &'static str = "FeeSchedule";
+        const PACKAGE: &'static str = "penumbra.core.app.v1";
+        fn full_name() -> ::prost::alloc::string::String {
+            ::prost::alloc::format!("penumbra.core.app.v1.ProposalEffect.{}", Self::NAME)
+        }
+    }
+    /// The chain halts immediately, until it is restarted by its operators.
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct ChainHalt {}
+    impl ::prost::Name for ChainHalt {
+        const NAME: &'static str = "ChainHalt";
+        const PACKAGE: &'static str = "penumbra.core.app.v1";
+        fn full_name() -> ::prost::alloc::string::String {
+            ::prost::alloc::format!("penumbra.core.app.v1.ProposalEffect.{}", Self::NAME)
+        }
+    }
+    /// The chain halts at a given height, to be upgraded.
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct ChainUpgrade {
+        /// The height at which the chain halts.
+        #[prost(uint64, tag = "1")]
+        pub height: u64,
+        /// The number of blocks from the simulated state until the chain halts.
+        #[prost(uint64, tag = "2")]
+        pub blocks_remaining: u64,
+    }
+    impl ::prost::Name for ChainUpgrade {
+        const NAME: &'static str = "ChainUpgrade";
+        const PACKAGE: &'static str = "penumbra.core.app.v1";
+        fn full_name() -> ::prost::alloc::string::String {
+            ::prost::alloc::format!("penumbra.core.app.v1.ProposalEffect.{}", Self::NAME)
+        }
+    }
+    /// Funds are withdrawn from the community pool.
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct CommunityPoolWithdrawal {
+        /// The value withdrawn.
+        #[prost(message, optional, tag = "1")]
+        pub value: ::core::option::Option<super::super::super::asset::v1::Value>,
+        /// The community pool's current balance of the withdrawn asset.
+        #[prost(message, optional, tag = "2")]
+        pub balance: ::core::option::Option<super::super::super::num::v1::Amount>,
+    }
+    impl ::prost::Name for CommunityPoolWithdrawal {
+        const NAME: &'static str = "CommunityPoolWithdrawal";
+        const PACKAGE: &'static str = "penumbra.core.app.v1";
+        fn full_name() -> ::prost::alloc::string::String {
+            ::prost::alloc::format!("penumbra.core.app.v1.ProposalEffect.{}", Self::NAME)
+        }
+    }
+    /// An IBC client is frozen or unfrozen.
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct IbcClientStatus {
+        /// The client's identifier.
+        #[prost(string, tag = "1")]
+        pub client_id: ::prost::alloc::string::String,
+        /// Whether the client is frozen after enactment.
+        #[prost(bool, tag = "2")]
+        pub frozen: bool,
+    }
+    impl ::prost::Name for IbcClientStatus {
+        const NAME: &'static str = "IbcClientStatus";
+        const PACKAGE: &'static str = "penumbra.core.app.v1";
+        fn full_name() -> ::prost::alloc::string::String {
+            ::prost::alloc::format!("penumbra.core.app.v1.ProposalEffect.{}", Self::NAME)
+        }
+    }
+    /// Recurring budget lines are established or cancelled.
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct RecurringBudget {
+        /// The budget line established, if any.
+        #[prost(message, optional, tag = "1")]
+        pub established: ::core::option::Option<
+            super::super::super::component::community_pool::v1::BudgetLine,
+        >,
+        /// The active budget lines cancelled, as of the simulated state.
+        #[prost(message, repeated, tag = "2")]
+        pub cancelled: ::prost::alloc::vec::Vec<
+            super::super::super::component::community_pool::v1::BudgetLineState,
+        >,
+    }
+    impl ::prost::Name for RecurringBudget {
+        const NAME: &'static str = "RecurringBudget";
+        const PACKAGE: &'static str = "penumbra.core.app.v1";
+        fn full_name() -> ::prost::alloc::string::String {
+            ::prost::alloc::format!("penumbra.core.app.v1.ProposalEffect.{}", Self::NAME)
+        }
+    }
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Oneof)]
+    pub enum Effect {
+        #[prost(message, tag = "1")]
+        FeeSchedule(FeeSchedule),
+        #[prost(message, tag = "2")]
+        ChainHalt(ChainHalt),
+        #[prost(message, tag = "3")]
+        ChainUpgrade(ChainUpgrade),
+        #[prost(message, tag = "4")]
+        CommunityPoolWithdrawal(CommunityPoolWithdrawal),
+        #[prost(message, tag = "5")]
+        IbcClientStatus(IbcClientStatus),
+        #[prost(message, tag = "6")]
+        RecurringBudget(RecurringBudget),
+    }
+}
+impl ::prost::Name for ProposalEffect {
+    const NAME: &'static str = "ProposalEffect";
+    const PACKAGE: &'static str = "penumbra.core.app.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.core.app.v1.{}", Self::NAME)
+    }
+}
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct GenesisAppState {
@@ -191,6 +404,11 @@ pub struct GenesisContent {
     pub funding_content: ::core::option::Option<
         super::super::component::funding::v1::GenesisContent,
     >,
+    /// Dex module genesis state.
+    #[prost(message, optional, tag = "11")]
+    pub dex_content: ::core::option::Option<
+        super::super::component::dex::v1::GenesisContent,
+    >,
 }
 impl ::prost::Name for GenesisContent {
     const NAME: &'static str = "GenesisContent";
@@ -345,6 +563,38 @@ pub mod query_service_client {
                 );
             self.inner.unary(req, path, codec).await
         }
+        /// Previews the effects of enacting a proposal which is still being voted on,
+        /// by simulating its enactment against the latest state.
+        pub async fn proposal_effects(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ProposalEffectsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ProposalEffectsResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/penumbra.core.app.v1.QueryService/ProposalEffects",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "penumbra.core.app.v1.QueryService",
+                        "ProposalEffects",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
     }
 }
 /// Generated server implementations.
@@ -371,6 +621,15 @@ pub mod query_service_server {
             tonic::Response<super::TransactionsByHeightResponse>,
             tonic::Status,
         >;
+        /// Previews the effects of enacting a proposal which is still being voted on,
+        /// by simulating its enactment against the latest state.
+        async fn proposal_effects(
+            &self,
+            request: tonic::Request<super::ProposalEffectsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ProposalEffectsResponse>,
+            tonic::Status,
+        >;
     }
     /// Query operations for the overall Penumbra application.
     #[derive(Debug)]
@@ -545,6 +804,52 @@ pub mod query_service_server {
                     };
                     Box::pin(fut)
                 }
+                "/penumbra.core.app.v1.QueryService/ProposalEffects" => {
+                    #[allow(non_camel_case_types)]
+                    struct ProposalEffectsSvc<T: QueryService>(pub Arc<T>);
+                    impl<
+                        T: QueryService,
+                    > tonic::server::UnaryService<super::ProposalEffectsRequest>
+                    for ProposalEffectsSvc<T> {
+                        type Response = super::ProposalEffectsResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::ProposalEffectsRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as QueryService>::proposal_effects(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = ProposalEffectsSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
                 _ => {
                     Box::pin(async move {
                         Ok(
@@ -36,6 +36,9 @@ impl serde::Serialize for AppParameters {
         if self.shielded_pool_params.is_some() {
             len += 1;
         }
+        if self.dex_params.is_some() {
+            len += 1;
+        }
         let mut struct_ser = serializer.serialize_struct("penumbra.core.app.v1.AppParameters", len)?;
         if !self.chain_id.is_empty() {
             struct_ser.serialize_field("chainId", &self.chain_id)?;
@@ -67,6 +70,9 @@ impl serde::Serialize for AppParameters {
         if let Some(v) = self.shielded_pool_params.as_ref() {
             struct_ser.serialize_field("shieldedPoolParams", v)?;
         }
+        if let Some(v) = self.dex_params.as_ref() {
+            struct_ser.serialize_field("dexParams", v)?;
+        }
         struct_ser.end()
     }
 }
@@ -97,6 +103,8 @@ impl<'de> serde::Deserialize<'de> for AppParameters {
             "fundingParams",
             "shielded_pool_params",
             "shieldedPoolParams",
+            "dex_params",
+            "dexParams",
         ];
 
         #[allow(clippy::enum_variant_names)]
@@ -111,6 +119,7 @@ impl<'de> serde::Deserialize<'de> for AppParameters {
             DistributionsParams,
             FundingParams,
             ShieldedPoolParams,
+            DexParams,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -143,6 +152,7 @@ impl<'de> serde::Deserialize<'de> for AppParameters {
                             "distributionsParams" | "distributions_params" => Ok(GeneratedField::DistributionsParams),
                             "fundingParams" | "funding_params" => Ok(GeneratedField::FundingParams),
                             "shieldedPoolParams" | "shielded_pool_params" => Ok(GeneratedField::ShieldedPoolParams),
+                            "dexParams" | "dex_params" => Ok(GeneratedField::DexParams),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -172,6 +182,7 @@ impl<'de> serde::Deserialize<'de> for AppParameters {
                 let mut distributions_params__ = None;
                 let mut funding_params__ = None;
                 let mut shielded_pool_params__ = None;
+                let mut dex_params__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
                         GeneratedField::ChainId => {
@@ -234,6 +245,12 @@ impl<'de> serde::Deserialize<'de> for AppParameters {
                             }
                             shielded_pool_params__ = map_.next_value()?;
                         }
+                        GeneratedField::DexParams => {
+                            if dex_params__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("dexParams"));
+                            }
+                            dex_params__ = map_.next_value()?;
+                        }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
@@ -250,6 +267,7 @@ impl<'de> serde::Deserialize<'de> for AppParameters {
                     distributions_params: distributions_params__,
                     funding_params: funding_params__,
                     shielded_pool_params: shielded_pool_params__,
+                    dex_params: dex_params__,
                 })
             }
         }
@@ -577,6 +595,9 @@ impl serde::Serialize for GenesisContent {
         if self.funding_content.is_some() {
             len += 1;
         }
+        if self.dex_content.is_some() {
+            len += 1;
+        }
         let mut struct_ser = serializer.serialize_struct("penumbra.core.app.v1.GenesisContent", len)?;
         if !self.chain_id.is_empty() {
             struct_ser.serialize_field("chainId", &self.chain_id)?;
@@ -608,6 +629,9 @@ impl serde::Serialize for GenesisContent {
         if let Some(v) = self.funding_content.as_ref() {
             struct_ser.serialize_field("fundingContent", v)?;
         }
+        if let Some(v) = self.dex_content.as_ref() {
+            struct_ser.serialize_field("dexContent", v)?;
+        }
         struct_ser.end()
     }
 }
@@ -638,6 +662,8 @@ impl<'de> serde::Deserialize<'de> for GenesisContent {
             "distributionsContent",
             "funding_content",
             "fundingContent",
+            "dex_content",
+            "dexContent",
         ];
 
         #[allow(clippy::enum_variant_names)]
@@ -652,6 +678,7 @@ impl<'de> serde::Deserialize<'de> for GenesisContent {
             FeeContent,
             DistributionsContent,
             FundingContent,
+            DexContent,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -684,6 +711,7 @@ impl<'de> serde::Deserialize<'de> for GenesisContent {
                             "feeContent" | "fee_content" => Ok(GeneratedField::FeeContent),
                             "distributionsContent" | "distributions_content" => Ok(GeneratedField::DistributionsContent),
                             "fundingContent" | "funding_content" => Ok(GeneratedField::FundingContent),
+                            "dexContent" | "dex_content" => Ok(GeneratedField::DexContent),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -713,6 +741,7 @@ impl<'de> serde::Deserialize<'de> for GenesisContent {
                 let mut fee_content__ = None;
                 let mut distributions_content__ = None;
                 let mut funding_content__ = None;
+                let mut dex_content__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
                         GeneratedField::ChainId => {
@@ -775,6 +804,12 @@ impl<'de> serde::Deserialize<'de> for GenesisContent {
                             }
                             funding_content__ = map_.next_value()?;
                         }
+                        GeneratedField::DexContent => {
+                            if dex_content__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("dexContent"));
+                            }
+                            dex_content__ = map_.next_value()?;
+                        }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
@@ -791,12 +826,1241 @@ impl<'de> serde::Deserialize<'de> for GenesisContent {
                     fee_content: fee_content__,
                     distributions_content: distributions_content__,
                     funding_content: funding_content__,
+                    dex_content: dex_content__,
                 })
             }
         }
         deserializer.deserialize_struct("penumbra.core.app.v1.GenesisContent", FIELDS, GeneratedVisitor)
     }
 }
+impl serde::Serialize for ParameterChange {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.component.is_empty() {
+            len += 1;
+        }
+        if !self.parameter.is_empty() {
+            len += 1;
+        }
+        if !self.old_value.is_empty() {
+            len += 1;
+        }
+        if !self.new_value.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.app.v1.ParameterChange", len)?;
+        if !self.component.is_empty() {
+            struct_ser.serialize_field("component", &self.component)?;
+        }
+        if !self.parameter.is_empty() {
+            struct_ser.serialize_field("parameter", &self.parameter)?;
+        }
+        if !self.old_value.is_empty() {
+            struct_ser.serialize_field("oldValue", &self.old_value)?;
+        }
+        if !self.new_value.is_empty() {
+            struct_ser.serialize_field("newValue", &self.new_value)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for ParameterChange {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "component",
+            "parameter",
+            "old_value",
+            "oldValue",
+            "new_value",
+            "newValue",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Component,
+            Parameter,
+            OldValue,
+            NewValue,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "component" => Ok(GeneratedField::Component),
+                            "parameter" => Ok(GeneratedField::Parameter),
+                            "oldValue" | "old_value" => Ok(GeneratedField::OldValue),
+                            "newValue" | "new_value" => Ok(GeneratedField::NewValue),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = ParameterChange;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.app.v1.ParameterChange")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ParameterChange, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut component__ = None;
+                let mut parameter__ = None;
+                let mut old_value__ = None;
+                let mut new_value__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Component => {
+                            if component__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("component"));
+                            }
+                            component__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Parameter => {
+                            if parameter__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("parameter"));
+                            }
+                            parameter__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::OldValue => {
+                            if old_value__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("oldValue"));
+                            }
+                            old_value__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::NewValue => {
+                            if new_value__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("newValue"));
+                            }
+                            new_value__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(ParameterChange {
+                    component: component__.unwrap_or_default(),
+                    parameter: parameter__.unwrap_or_default(),
+                    old_value: old_value__.unwrap_or_default(),
+                    new_value: new_value__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.app.v1.ParameterChange", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for ProposalEffect {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.effect.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.app.v1.ProposalEffect", len)?;
+        if let Some(v) = self.effect.as_ref() {
+            match v {
+                proposal_effect::Effect::FeeSchedule(v) => {
+                    struct_ser.serialize_field("feeSchedule", v)?;
+                }
+                proposal_effect::Effect::ChainHalt(v) => {
+                    struct_ser.serialize_field("chainHalt", v)?;
+                }
+                proposal_effect::Effect::ChainUpgrade(v) => {
+                    struct_ser.serialize_field("chainUpgrade", v)?;
+                }
+                proposal_effect::Effect::CommunityPoolWithdrawal(v) => {
+                    struct_ser.serialize_field("communityPoolWithdrawal", v)?;
+                }
+                proposal_effect::Effect::IbcClientStatus(v) => {
+                    struct_ser.serialize_field("ibcClientStatus", v)?;
+                }
+                proposal_effect::Effect::RecurringBudget(v) => {
+                    struct_ser.serialize_field("recurringBudget", v)?;
+                }
+            }
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for ProposalEffect {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "fee_schedule",
+            "feeSchedule",
+            "chain_halt",
+            "chainHalt",
+            "chain_upgrade",
+            "chainUpgrade",
+            "community_pool_withdrawal",
+            "communityPoolWithdrawal",
+            "ibc_client_status",
+            "ibcClientStatus",
+            "recurring_budget",
+            "recurringBudget",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            FeeSchedule,
+            ChainHalt,
+            ChainUpgrade,
+            CommunityPoolWithdrawal,
+            IbcClientStatus,
+            RecurringBudget,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "feeSchedule" | "fee_schedule" => Ok(GeneratedField::FeeSchedule),
+                            "chainHalt" | "chain_halt" => Ok(GeneratedField::ChainHalt),
+                            "chainUpgrade" | "chain_upgrade" => Ok(GeneratedField::ChainUpgrade),
+                            "communityPoolWithdrawal" | "community_pool_withdrawal" => Ok(GeneratedField::CommunityPoolWithdrawal),
+                            "ibcClientStatus" | "ibc_client_status" => Ok(GeneratedField::IbcClientStatus),
+                            "recurringBudget" | "recurring_budget" => Ok(GeneratedField::RecurringBudget),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = ProposalEffect;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.app.v1.ProposalEffect")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ProposalEffect, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut effect__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::FeeSchedule => {
+                            if effect__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("feeSchedule"));
+                            }
+                            effect__ = map_.next_value::<::std::option::Option<_>>()?.map(proposal_effect::Effect::FeeSchedule)
+;
+                        }
+                        GeneratedField::ChainHalt => {
+                            if effect__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("chainHalt"));
+                            }
+                            effect__ = map_.next_value::<::std::option::Option<_>>()?.map(proposal_effect::Effect::ChainHalt)
+;
+                        }
+                        GeneratedField::ChainUpgrade => {
+                            if effect__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("chainUpgrade"));
+                            }
+                            effect__ = map_.next_value::<::std::option::Option<_>>()?.map(proposal_effect::Effect::ChainUpgrade)
+;
+                        }
+                        GeneratedField::CommunityPoolWithdrawal => {
+                            if effect__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("communityPoolWithdrawal"));
+                            }
+                            effect__ = map_.next_value::<::std::option::Option<_>>()?.map(proposal_effect::Effect::CommunityPoolWithdrawal)
+;
+                        }
+                        GeneratedField::IbcClientStatus => {
+                            if effect__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("ibcClientStatus"));
+                            }
+                            effect__ = map_.next_value::<::std::option::Option<_>>()?.map(proposal_effect::Effect::IbcClientStatus)
+;
+                        }
+                        GeneratedField::RecurringBudget => {
+                            if effect__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("recurringBudget"));
+                            }
+                            effect__ = map_.next_value::<::std::option::Option<_>>()?.map(proposal_effect::Effect::RecurringBudget)
+;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(ProposalEffect {
+                    effect: effect__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.app.v1.ProposalEffect", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for proposal_effect::ChainHalt {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let len = 0;
+        let struct_ser = serializer.serialize_struct("penumbra.core.app.v1.ProposalEffect.ChainHalt", len)?;
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for proposal_effect::ChainHalt {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                            Ok(GeneratedField::__SkipField__)
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = proposal_effect::ChainHalt;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.app.v1.ProposalEffect.ChainHalt")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<proposal_effect::ChainHalt, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                while map_.next_key::<GeneratedField>()?.is_some() {
+                    let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                }
+                Ok(proposal_effect::ChainHalt {
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.app.v1.ProposalEffect.ChainHalt", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for proposal_effect::ChainUpgrade {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.height != 0 {
+            len += 1;
+        }
+        if self.blocks_remaining != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.app.v1.ProposalEffect.ChainUpgrade", len)?;
+        if self.height != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("height", ToString::to_string(&self.height).as_str())?;
+        }
+        if self.blocks_remaining != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("blocksRemaining", ToString::to_string(&self.blocks_remaining).as_str())?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for proposal_effect::ChainUpgrade {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "height",
+            "blocks_remaining",
+            "blocksRemaining",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Height,
+            BlocksRemaining,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "height" => Ok(GeneratedField::Height),
+                            "blocksRemaining" | "blocks_remaining" => Ok(GeneratedField::BlocksRemaining),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = proposal_effect::ChainUpgrade;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.app.v1.ProposalEffect.ChainUpgrade")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<proposal_effect::ChainUpgrade, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut height__ = None;
+                let mut blocks_remaining__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Height => {
+                            if height__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("height"));
+                            }
+                            height__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::BlocksRemaining => {
+                            if blocks_remaining__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("blocksRemaining"));
+                            }
+                            blocks_remaining__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(proposal_effect::ChainUpgrade {
+                    height: height__.unwrap_or_default(),
+                    blocks_remaining: blocks_remaining__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.app.v1.ProposalEffect.ChainUpgrade", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for proposal_effect::CommunityPoolWithdrawal {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.value.is_some() {
+            len += 1;
+        }
+        if self.balance.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.app.v1.ProposalEffect.CommunityPoolWithdrawal", len)?;
+        if let Some(v) = self.value.as_ref() {
+            struct_ser.serialize_field("value", v)?;
+        }
+        if let Some(v) = self.balance.as_ref() {
+            struct_ser.serialize_field("balance", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for proposal_effect::CommunityPoolWithdrawal {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "value",
+            "balance",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Value,
+            Balance,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "value" => Ok(GeneratedField::Value),
+                            "balance" => Ok(GeneratedField::Balance),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = proposal_effect::CommunityPoolWithdrawal;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.app.v1.ProposalEffect.CommunityPoolWithdrawal")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<proposal_effect::CommunityPoolWithdrawal, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut value__ = None;
+                let mut balance__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Value => {
+                            if value__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("value"));
+                            }
+                            value__ = map_.next_value()?;
+                        }
+                        GeneratedField::Balance => {
+                            if balance__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("balance"));
+                            }
+                            balance__ = map_.next_value()?;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(proposal_effect::CommunityPoolWithdrawal {
+                    value: value__,
+                    balance: balance__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.app.v1.ProposalEffect.CommunityPoolWithdrawal", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for proposal_effect::FeeSchedule {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.old_gas_prices.is_some() {
+            len += 1;
+        }
+        if self.new_gas_prices.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.app.v1.ProposalEffect.FeeSchedule", len)?;
+        if let Some(v) = self.old_gas_prices.as_ref() {
+            struct_ser.serialize_field("oldGasPrices", v)?;
+        }
+        if let Some(v) = self.new_gas_prices.as_ref() {
+            struct_ser.serialize_field("newGasPrices", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for proposal_effect::FeeSchedule {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "old_gas_prices",
+            "oldGasPrices",
+            "new_gas_prices",
+            "newGasPrices",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            OldGasPrices,
+            NewGasPrices,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "oldGasPrices" | "old_gas_prices" => Ok(GeneratedField::OldGasPrices),
+                            "newGasPrices" | "new_gas_prices" => Ok(GeneratedField::NewGasPrices),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = proposal_effect::FeeSchedule;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.app.v1.ProposalEffect.FeeSchedule")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<proposal_effect::FeeSchedule, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut old_gas_prices__ = None;
+                let mut new_gas_prices__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::OldGasPrices => {
+                            if old_gas_prices__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("oldGasPrices"));
+                            }
+                            old_gas_prices__ = map_.next_value()?;
+                        }
+                        GeneratedField::NewGasPrices => {
+                            if new_gas_prices__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("newGasPrices"));
+                            }
+                            new_gas_prices__ = map_.next_value()?;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(proposal_effect::FeeSchedule {
+                    old_gas_prices: old_gas_prices__,
+                    new_gas_prices: new_gas_prices__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.app.v1.ProposalEffect.FeeSchedule", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for proposal_effect::IbcClientStatus {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.client_id.is_empty() {
+            len += 1;
+        }
+        if self.frozen {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.app.v1.ProposalEffect.IbcClientStatus", len)?;
+        if !self.client_id.is_empty() {
+            struct_ser.serialize_field("clientId", &self.client_id)?;
+        }
+        if self.frozen {
+            struct_ser.serialize_field("frozen", &self.frozen)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for proposal_effect::IbcClientStatus {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "client_id",
+            "clientId",
+            "frozen",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            ClientId,
+            Frozen,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "clientId" | "client_id" => Ok(GeneratedField::ClientId),
+                            "frozen" => Ok(GeneratedField::Frozen),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = proposal_effect::IbcClientStatus;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.app.v1.ProposalEffect.IbcClientStatus")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<proposal_effect::IbcClientStatus, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut client_id__ = None;
+                let mut frozen__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::ClientId => {
+                            if client_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("clientId"));
+                            }
+                            client_id__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Frozen => {
+                            if frozen__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("frozen"));
+                            }
+                            frozen__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(proposal_effect::IbcClientStatus {
+                    client_id: client_id__.unwrap_or_default(),
+                    frozen: frozen__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.app.v1.ProposalEffect.IbcClientStatus", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for proposal_effect::RecurringBudget {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.established.is_some() {
+            len += 1;
+        }
+        if !self.cancelled.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.app.v1.ProposalEffect.RecurringBudget", len)?;
+        if let Some(v) = self.established.as_ref() {
+            struct_ser.serialize_field("established", v)?;
+        }
+        if !self.cancelled.is_empty() {
+            struct_ser.serialize_field("cancelled", &self.cancelled)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for proposal_effect::RecurringBudget {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "established",
+            "cancelled",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Established,
+            Cancelled,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "established" => Ok(GeneratedField::Established),
+                            "cancelled" => Ok(GeneratedField::Cancelled),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = proposal_effect::RecurringBudget;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.app.v1.ProposalEffect.RecurringBudget")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<proposal_effect::RecurringBudget, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut established__ = None;
+                let mut cancelled__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Established => {
+                            if established__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("established"));
+                            }
+                            established__ = map_.next_value()?;
+                        }
+                        GeneratedField::Cancelled => {
+                            if cancelled__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("cancelled"));
+                            }
+                            cancelled__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(proposal_effect::RecurringBudget {
+                    established: established__,
+                    cancelled: cancelled__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.app.v1.ProposalEffect.RecurringBudget", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for ProposalEffectsRequest {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.proposal_id != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.app.v1.ProposalEffectsRequest", len)?;
+        if self.proposal_id != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("proposalId", ToString::to_string(&self.proposal_id).as_str())?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for ProposalEffectsRequest {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "proposal_id",
+            "proposalId",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            ProposalId,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "proposalId" | "proposal_id" => Ok(GeneratedField::ProposalId),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = ProposalEffectsRequest;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.app.v1.ProposalEffectsRequest")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ProposalEffectsRequest, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut proposal_id__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::ProposalId => {
+                            if proposal_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("proposalId"));
+                            }
+                            proposal_id__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(ProposalEffectsRequest {
+                    proposal_id: proposal_id__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.app.v1.ProposalEffectsRequest", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for ProposalEffectsResponse {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.height != 0 {
+            len += 1;
+        }
+        if self.enactable {
+            len += 1;
+        }
+        if !self.failure_reason.is_empty() {
+            len += 1;
+        }
+        if !self.parameter_changes.is_empty() {
+            len += 1;
+        }
+        if !self.effects.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.app.v1.ProposalEffectsResponse", len)?;
+        if self.height != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("height", ToString::to_string(&self.height).as_str())?;
+        }
+        if self.enactable {
+            struct_ser.serialize_field("enactable", &self.enactable)?;
+        }
+        if !self.failure_reason.is_empty() {
+            struct_ser.serialize_field("failureReason", &self.failure_reason)?;
+        }
+        if !self.parameter_changes.is_empty() {
+            struct_ser.serialize_field("parameterChanges", &self.parameter_changes)?;
+        }
+        if !self.effects.is_empty() {
+            struct_ser.serialize_field("effects", &self.effects)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for ProposalEffectsResponse {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "height",
+            "enactable",
+            "failure_reason",
+            "failureReason",
+            "parameter_changes",
+            "parameterChanges",
+            "effects",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Height,
+            Enactable,
+            FailureReason,
+            ParameterChanges,
+            Effects,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "height" => Ok(GeneratedField::Height),
+                            "enactable" => Ok(GeneratedField::Enactable),
+                            "failureReason" | "failure_reason" => Ok(GeneratedField::FailureReason),
+                            "parameterChanges" | "parameter_changes" => Ok(GeneratedField::ParameterChanges),
+                            "effects" => Ok(GeneratedField::Effects),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = ProposalEffectsResponse;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.app.v1.ProposalEffectsResponse")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ProposalEffectsResponse, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut height__ = None;
+                let mut enactable__ = None;
+                let mut failure_reason__ = None;
+                let mut parameter_changes__ = None;
+                let mut effects__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Height => {
+                            if height__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("height"));
+                            }
+                            height__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::Enactable => {
+                            if enactable__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("enactable"));
+                            }
+                            enactable__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::FailureReason => {
+                            if failure_reason__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("failureReason"));
+                            }
+                            failure_reason__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::ParameterChanges => {
+                            if parameter_changes__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("parameterChanges"));
+                            }
+                            parameter_changes__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Effects => {
+                            if effects__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("effects"));
+                            }
+                            effects__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(ProposalEffectsResponse {
+                    height: height__.unwrap_or_default(),
+                    enactable: enactable__.unwrap_or_default(),
+                    failure_reason: failure_reason__.unwrap_or_default(),
+                    parameter_changes: parameter_changes__.unwrap_or_default(),
+                    effects: effects__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.app.v1.ProposalEffectsResponse", FIELDS, GeneratedVisitor)
+    }
+}
 impl serde::Serialize for TransactionsByHeightRequest {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
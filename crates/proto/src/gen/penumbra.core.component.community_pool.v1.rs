@@ -66,6 +66,133 @@ impl ::prost::Name for CommunityPoolAssetBalancesResponse {
         )
     }
 }
+/// A recurring budget line, paying a fixed amount from the Community Pool to a
+/// recipient at the end of each epoch, for a fixed number of epochs.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct BudgetLine {
+    /// The address paid by the budget line.
+    #[prost(message, optional, tag = "1")]
+    pub recipient: ::core::option::Option<super::super::super::keys::v1::Address>,
+    /// The value paid at the end of each epoch.
+    #[prost(message, optional, tag = "2")]
+    pub amount_per_epoch: ::core::option::Option<super::super::super::asset::v1::Value>,
+    /// The number of epochs the budget line lasts for.
+    #[prost(uint64, tag = "3")]
+    pub epochs: u64,
+    /// Whether the budget line is cancelled the first time the Community Pool
+    /// can't pay it, rather than skipping that epoch's payment.
+    #[prost(bool, tag = "4")]
+    pub cancel_if_underfunded: bool,
+}
+impl ::prost::Name for BudgetLine {
+    const NAME: &'static str = "BudgetLine";
+    const PACKAGE: &'static str = "penumbra.core.component.community_pool.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!(
+            "penumbra.core.component.community_pool.v1.{}", Self::NAME
+        )
+    }
+}
+/// The state of an active budget line.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct BudgetLineState {
+    /// The budget line's identifier, which is the ID of the proposal establishing it.
+    #[prost(uint64, tag = "1")]
+    pub id: u64,
+    /// The terms of the budget line.
+    #[prost(message, optional, tag = "2")]
+    pub budget_line: ::core::option::Option<BudgetLine>,
+    /// The number of epochs which have ended since the budget line was
+    /// established, including those whose payment was skipped.
+    #[prost(uint64, tag = "3")]
+    pub epochs_elapsed: u64,
+    /// The total amount paid by the budget line so far.
+    #[prost(message, optional, tag = "4")]
+    pub spent: ::core::option::Option<super::super::super::num::v1::Amount>,
+}
+impl ::prost::Name for BudgetLineState {
+    const NAME: &'static str = "BudgetLineState";
+    const PACKAGE: &'static str = "penumbra.core.component.community_pool.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!(
+            "penumbra.core.component.community_pool.v1.{}", Self::NAME
+        )
+    }
+}
+/// Requests the list of all active budget lines.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct BudgetLinesRequest {}
+impl ::prost::Name for BudgetLinesRequest {
+    const NAME: &'static str = "BudgetLinesRequest";
+    const PACKAGE: &'static str = "penumbra.core.component.community_pool.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!(
+            "penumbra.core.component.community_pool.v1.{}", Self::NAME
+        )
+    }
+}
+/// A single active budget line.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct BudgetLinesResponse {
+    #[prost(message, optional, tag = "1")]
+    pub budget_line: ::core::option::Option<BudgetLineState>,
+}
+impl ::prost::Name for BudgetLinesResponse {
+    const NAME: &'static str = "BudgetLinesResponse";
+    const PACKAGE: &'static str = "penumbra.core.component.community_pool.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!(
+            "penumbra.core.component.community_pool.v1.{}", Self::NAME
+        )
+    }
+}
+/// Event emitted when a budget line pays its recipient.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct EventBudgetDisbursement {
+    /// The budget line's identifier.
+    #[prost(uint64, tag = "1")]
+    pub id: u64,
+    /// The address paid.
+    #[prost(message, optional, tag = "2")]
+    pub recipient: ::core::option::Option<super::super::super::keys::v1::Address>,
+    /// The value paid.
+    #[prost(message, optional, tag = "3")]
+    pub value: ::core::option::Option<super::super::super::asset::v1::Value>,
+}
+impl ::prost::Name for EventBudgetDisbursement {
+    const NAME: &'static str = "EventBudgetDisbursement";
+    const PACKAGE: &'static str = "penumbra.core.component.community_pool.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!(
+            "penumbra.core.component.community_pool.v1.{}", Self::NAME
+        )
+    }
+}
+/// Event emitted when a budget line ends.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct EventBudgetLineEnded {
+    /// The final state of the budget line.
+    #[prost(message, optional, tag = "1")]
+    pub budget_line: ::core::option::Option<BudgetLineState>,
+    /// Whether the budget line ran for all of its epochs, rather than being cancelled.
+    #[prost(bool, tag = "2")]
+    pub completed: bool,
+}
+impl ::prost::Name for EventBudgetLineEnded {
+    const NAME: &'static str = "EventBudgetLineEnded";
+    const PACKAGE: &'static str = "penumbra.core.component.community_pool.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!(
+            "penumbra.core.component.community_pool.v1.{}", Self::NAME
+        )
+    }
+}
 /// Generated client implementations.
 #[cfg(feature = "rpc")]
 pub mod query_service_client {
@@ -185,6 +312,37 @@ pub mod query_service_client {
                 );
             self.inner.server_streaming(req, path, codec).await
         }
+        /// Lists the active recurring budget lines, with their spend to date.
+        pub async fn budget_lines(
+            &mut self,
+            request: impl tonic::IntoRequest<super::BudgetLinesRequest>,
+        ) -> std::result::Result<
+            tonic::Response<tonic::codec::Streaming<super::BudgetLinesResponse>>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/penumbra.core.component.community_pool.v1.QueryService/BudgetLines",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "penumbra.core.component.community_pool.v1.QueryService",
+                        "BudgetLines",
+                    ),
+                );
+            self.inner.server_streaming(req, path, codec).await
+        }
     }
 }
 /// Generated server implementations.
@@ -211,6 +369,20 @@ pub mod query_service_server {
             tonic::Response<Self::CommunityPoolAssetBalancesStream>,
             tonic::Status,
         >;
+        /// Server streaming response type for the BudgetLines method.
+        type BudgetLinesStream: tonic::codegen::tokio_stream::Stream<
+                Item = std::result::Result<super::BudgetLinesResponse, tonic::Status>,
+            >
+            + Send
+            + 'static;
+        /// Lists the active recurring budget lines, with their spend to date.
+        async fn budget_lines(
+            &self,
+            request: tonic::Request<super::BudgetLinesRequest>,
+        ) -> std::result::Result<
+            tonic::Response<Self::BudgetLinesStream>,
+            tonic::Status,
+        >;
     }
     /// Query operations for the community_pool component.
     #[derive(Debug)]
@@ -346,6 +518,53 @@ pub mod query_service_server {
                     };
                     Box::pin(fut)
                 }
+                "/penumbra.core.component.community_pool.v1.QueryService/BudgetLines" => {
+                    #[allow(non_camel_case_types)]
+                    struct BudgetLinesSvc<T: QueryService>(pub Arc<T>);
+                    impl<
+                        T: QueryService,
+                    > tonic::server::ServerStreamingService<super::BudgetLinesRequest>
+                    for BudgetLinesSvc<T> {
+                        type Response = super::BudgetLinesResponse;
+                        type ResponseStream = T::BudgetLinesStream;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::ResponseStream>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::BudgetLinesRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as QueryService>::budget_lines(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = BudgetLinesSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.server_streaming(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
                 _ => {
                     Box::pin(async move {
                         Ok(
@@ -1,3 +1,476 @@
+impl serde::Serialize for BudgetLine {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.recipient.is_some() {
+            len += 1;
+        }
+        if self.amount_per_epoch.is_some() {
+            len += 1;
+        }
+        if self.epochs != 0 {
+            len += 1;
+        }
+        if self.cancel_if_underfunded {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.community_pool.v1.BudgetLine", len)?;
+        if let Some(v) = self.recipient.as_ref() {
+            struct_ser.serialize_field("recipient", v)?;
+        }
+        if let Some(v) = self.amount_per_epoch.as_ref() {
+            struct_ser.serialize_field("amountPerEpoch", v)?;
+        }
+        if self.epochs != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("epochs", ToString::to_string(&self.epochs).as_str())?;
+        }
+        if self.cancel_if_underfunded {
+            struct_ser.serialize_field("cancelIfUnderfunded", &self.cancel_if_underfunded)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for BudgetLine {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "recipient",
+            "amount_per_epoch",
+            "amountPerEpoch",
+            "epochs",
+            "cancel_if_underfunded",
+            "cancelIfUnderfunded",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Recipient,
+            AmountPerEpoch,
+            Epochs,
+            CancelIfUnderfunded,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "recipient" => Ok(GeneratedField::Recipient),
+                            "amountPerEpoch" | "amount_per_epoch" => Ok(GeneratedField::AmountPerEpoch),
+                            "epochs" => Ok(GeneratedField::Epochs),
+                            "cancelIfUnderfunded" | "cancel_if_underfunded" => Ok(GeneratedField::CancelIfUnderfunded),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = BudgetLine;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.community_pool.v1.BudgetLine")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<BudgetLine, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut recipient__ = None;
+                let mut amount_per_epoch__ = None;
+                let mut epochs__ = None;
+                let mut cancel_if_underfunded__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Recipient => {
+                            if recipient__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("recipient"));
+                            }
+                            recipient__ = map_.next_value()?;
+                        }
+                        GeneratedField::AmountPerEpoch => {
+                            if amount_per_epoch__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("amountPerEpoch"));
+                            }
+                            amount_per_epoch__ = map_.next_value()?;
+                        }
+                        GeneratedField::Epochs => {
+                            if epochs__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("epochs"));
+                            }
+                            epochs__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::CancelIfUnderfunded => {
+                            if cancel_if_underfunded__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("cancelIfUnderfunded"));
+                            }
+                            cancel_if_underfunded__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(BudgetLine {
+                    recipient: recipient__,
+                    amount_per_epoch: amount_per_epoch__,
+                    epochs: epochs__.unwrap_or_default(),
+                    cancel_if_underfunded: cancel_if_underfunded__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.community_pool.v1.BudgetLine", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for BudgetLineState {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.id != 0 {
+            len += 1;
+        }
+        if self.budget_line.is_some() {
+            len += 1;
+        }
+        if self.epochs_elapsed != 0 {
+            len += 1;
+        }
+        if self.spent.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.community_pool.v1.BudgetLineState", len)?;
+        if self.id != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("id", ToString::to_string(&self.id).as_str())?;
+        }
+        if let Some(v) = self.budget_line.as_ref() {
+            struct_ser.serialize_field("budgetLine", v)?;
+        }
+        if self.epochs_elapsed != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("epochsElapsed", ToString::to_string(&self.epochs_elapsed).as_str())?;
+        }
+        if let Some(v) = self.spent.as_ref() {
+            struct_ser.serialize_field("spent", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for BudgetLineState {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "id",
+            "budget_line",
+            "budgetLine",
+            "epochs_elapsed",
+            "epochsElapsed",
+            "spent",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Id,
+            BudgetLine,
+            EpochsElapsed,
+            Spent,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "id" => Ok(GeneratedField::Id),
+                            "budgetLine" | "budget_line" => Ok(GeneratedField::BudgetLine),
+                            "epochsElapsed" | "epochs_elapsed" => Ok(GeneratedField::EpochsElapsed),
+                            "spent" => Ok(GeneratedField::Spent),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = BudgetLineState;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.community_pool.v1.BudgetLineState")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<BudgetLineState, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut id__ = None;
+                let mut budget_line__ = None;
+                let mut epochs_elapsed__ = None;
+                let mut spent__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Id => {
+                            if id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("id"));
+                            }
+                            id__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::BudgetLine => {
+                            if budget_line__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("budgetLine"));
+                            }
+                            budget_line__ = map_.next_value()?;
+                        }
+                        GeneratedField::EpochsElapsed => {
+                            if epochs_elapsed__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("epochsElapsed"));
+                            }
+                            epochs_elapsed__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::Spent => {
+                            if spent__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("spent"));
+                            }
+                            spent__ = map_.next_value()?;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(BudgetLineState {
+                    id: id__.unwrap_or_default(),
+                    budget_line: budget_line__,
+                    epochs_elapsed: epochs_elapsed__.unwrap_or_default(),
+                    spent: spent__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.community_pool.v1.BudgetLineState", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for BudgetLinesRequest {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let len = 0;
+        let struct_ser = serializer.serialize_struct("penumbra.core.component.community_pool.v1.BudgetLinesRequest", len)?;
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for BudgetLinesRequest {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                            Ok(GeneratedField::__SkipField__)
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = BudgetLinesRequest;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.community_pool.v1.BudgetLinesRequest")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<BudgetLinesRequest, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                while map_.next_key::<GeneratedField>()?.is_some() {
+                    let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                }
+                Ok(BudgetLinesRequest {
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.community_pool.v1.BudgetLinesRequest", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for BudgetLinesResponse {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.budget_line.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.community_pool.v1.BudgetLinesResponse", len)?;
+        if let Some(v) = self.budget_line.as_ref() {
+            struct_ser.serialize_field("budgetLine", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for BudgetLinesResponse {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "budget_line",
+            "budgetLine",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            BudgetLine,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "budgetLine" | "budget_line" => Ok(GeneratedField::BudgetLine),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = BudgetLinesResponse;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.community_pool.v1.BudgetLinesResponse")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<BudgetLinesResponse, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut budget_line__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::BudgetLine => {
+                            if budget_line__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("budgetLine"));
+                            }
+                            budget_line__ = map_.next_value()?;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(BudgetLinesResponse {
+                    budget_line: budget_line__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.community_pool.v1.BudgetLinesResponse", FIELDS, GeneratedVisitor)
+    }
+}
 impl serde::Serialize for CommunityPoolAssetBalancesRequest {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
@@ -285,6 +758,251 @@ impl<'de> serde::Deserialize<'de> for CommunityPoolParameters {
         deserializer.deserialize_struct("penumbra.core.component.community_pool.v1.CommunityPoolParameters", FIELDS, GeneratedVisitor)
     }
 }
+impl serde::Serialize for EventBudgetDisbursement {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.id != 0 {
+            len += 1;
+        }
+        if self.recipient.is_some() {
+            len += 1;
+        }
+        if self.value.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.community_pool.v1.EventBudgetDisbursement", len)?;
+        if self.id != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("id", ToString::to_string(&self.id).as_str())?;
+        }
+        if let Some(v) = self.recipient.as_ref() {
+            struct_ser.serialize_field("recipient", v)?;
+        }
+        if let Some(v) = self.value.as_ref() {
+            struct_ser.serialize_field("value", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for EventBudgetDisbursement {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "id",
+            "recipient",
+            "value",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Id,
+            Recipient,
+            Value,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "id" => Ok(GeneratedField::Id),
+                            "recipient" => Ok(GeneratedField::Recipient),
+                            "value" => Ok(GeneratedField::Value),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = EventBudgetDisbursement;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.community_pool.v1.EventBudgetDisbursement")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<EventBudgetDisbursement, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut id__ = None;
+                let mut recipient__ = None;
+                let mut value__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Id => {
+                            if id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("id"));
+                            }
+                            id__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::Recipient => {
+                            if recipient__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("recipient"));
+                            }
+                            recipient__ = map_.next_value()?;
+                        }
+                        GeneratedField::Value => {
+                            if value__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("value"));
+                            }
+                            value__ = map_.next_value()?;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(EventBudgetDisbursement {
+                    id: id__.unwrap_or_default(),
+                    recipient: recipient__,
+                    value: value__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.community_pool.v1.EventBudgetDisbursement", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for EventBudgetLineEnded {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.budget_line.is_some() {
+            len += 1;
+        }
+        if self.completed {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.community_pool.v1.EventBudgetLineEnded", len)?;
+        if let Some(v) = self.budget_line.as_ref() {
+            struct_ser.serialize_field("budgetLine", v)?;
+        }
+        if self.completed {
+            struct_ser.serialize_field("completed", &self.completed)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for EventBudgetLineEnded {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "budget_line",
+            "budgetLine",
+            "completed",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            BudgetLine,
+            Completed,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "budgetLine" | "budget_line" => Ok(GeneratedField::BudgetLine),
+                            "completed" => Ok(GeneratedField::Completed),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = EventBudgetLineEnded;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.community_pool.v1.EventBudgetLineEnded")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<EventBudgetLineEnded, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut budget_line__ = None;
+                let mut completed__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::BudgetLine => {
+                            if budget_line__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("budgetLine"));
+                            }
+                            budget_line__ = map_.next_value()?;
+                        }
+                        GeneratedField::Completed => {
+                            if completed__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("completed"));
+                            }
+                            completed__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(EventBudgetLineEnded {
+                    budget_line: budget_line__,
+                    completed: completed__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.community_pool.v1.EventBudgetLineEnded", FIELDS, GeneratedVisitor)
+    }
+}
 impl serde::Serialize for GenesisContent {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
@@ -132,6 +132,51 @@ impl ::prost::Name for StatePayload {
         )
     }
 }
+/// The FMD clues attached to the notes created in a block.
+///
+/// Clues are kept alongside the compact block, so that full nodes can filter
+/// compact blocks by detection key on behalf of light clients.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct NoteClues {
+    #[prost(message, repeated, tag = "1")]
+    pub clues: ::prost::alloc::vec::Vec<note_clues::NoteClue>,
+}
+/// Nested message and enum types in `NoteClues`.
+pub mod note_clues {
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct NoteClue {
+        /// The commitment to the note the clue was attached to.
+        #[prost(message, optional, tag = "1")]
+        pub commitment: ::core::option::Option<
+            super::super::super::super::super::crypto::tct::v1::StateCommitment,
+        >,
+        /// The clue itself.
+        #[prost(message, optional, tag = "2")]
+        pub clue: ::core::option::Option<
+            super::super::super::super::super::crypto::decaf377_fmd::v1::Clue,
+        >,
+    }
+    impl ::prost::Name for NoteClue {
+        const NAME: &'static str = "NoteClue";
+        const PACKAGE: &'static str = "penumbra.core.component.compact_block.v1";
+        fn full_name() -> ::prost::alloc::string::String {
+            ::prost::alloc::format!(
+                "penumbra.core.component.compact_block.v1.NoteClues.{}", Self::NAME
+            )
+        }
+    }
+}
+impl ::prost::Name for NoteClues {
+    const NAME: &'static str = "NoteClues";
+    const PACKAGE: &'static str = "penumbra.core.component.compact_block.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!(
+            "penumbra.core.component.compact_block.v1.{}", Self::NAME
+        )
+    }
+}
 /// Requests a range of compact block data.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -201,6 +246,56 @@ impl ::prost::Name for CompactBlockResponse {
         )
     }
 }
+/// Requests a stream of compact blocks, following the tip of the chain.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CompactBlockSyncRequest {
+    /// The height of the first compact block to stream.
+    #[prost(uint64, tag = "1")]
+    pub start_height: u64,
+    /// Optionally, FMD detection keys to filter the compact blocks with, each
+    /// encoded as 32 bytes.
+    ///
+    /// If any are given, the payloads of notes whose clues are not detected by
+    /// any of the keys are replaced by rolled-up payloads, carrying only the
+    /// note commitments.  Since the server learns which notes were detected,
+    /// clients should only share detection keys whose false positive rate they
+    /// are comfortable with.  Notes created by the chain itself have no clues,
+    /// and are never filtered out.  At most 16 detection keys may be given.
+    #[prost(bytes = "vec", repeated, tag = "2")]
+    pub detection_keys: ::prost::alloc::vec::Vec<::prost::alloc::vec::Vec<u8>>,
+}
+impl ::prost::Name for CompactBlockSyncRequest {
+    const NAME: &'static str = "CompactBlockSyncRequest";
+    const PACKAGE: &'static str = "penumbra.core.component.compact_block.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!(
+            "penumbra.core.component.compact_block.v1.{}", Self::NAME
+        )
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CompactBlockSyncResponse {
+    #[prost(message, optional, tag = "1")]
+    pub compact_block: ::core::option::Option<CompactBlock>,
+    /// Whether this compact block was the latest one at the time it was sent,
+    /// meaning that the client has caught up with the tip of the chain.
+    ///
+    /// Once the client has caught up, each further compact block is sent as soon
+    /// as it is created.
+    #[prost(bool, tag = "2")]
+    pub caught_up: bool,
+}
+impl ::prost::Name for CompactBlockSyncResponse {
+    const NAME: &'static str = "CompactBlockSyncResponse";
+    const PACKAGE: &'static str = "penumbra.core.component.compact_block.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!(
+            "penumbra.core.component.compact_block.v1.{}", Self::NAME
+        )
+    }
+}
 /// Generated client implementations.
 #[cfg(feature = "rpc")]
 pub mod query_service_client {
@@ -352,6 +447,43 @@ pub mod query_service_client {
                 );
             self.inner.unary(req, path, codec).await
         }
+        /// Returns a stream of compact blocks, starting from a given height, which
+        /// catches up to the tip of the chain and then follows it as new blocks are
+        /// created.
+        ///
+        /// Unlike `CompactBlockRange`, the stream can optionally be filtered by FMD
+        /// detection keys, and marks the block at which the client has caught up.
+        /// Blocks are only produced as fast as the client consumes them.
+        pub async fn compact_block_sync(
+            &mut self,
+            request: impl tonic::IntoRequest<super::CompactBlockSyncRequest>,
+        ) -> std::result::Result<
+            tonic::Response<tonic::codec::Streaming<super::CompactBlockSyncResponse>>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/penumbra.core.component.compact_block.v1.QueryService/CompactBlockSync",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "penumbra.core.component.compact_block.v1.QueryService",
+                        "CompactBlockSync",
+                    ),
+                );
+            self.inner.server_streaming(req, path, codec).await
+        }
     }
 }
 /// Generated server implementations.
@@ -389,6 +521,29 @@ pub mod query_service_server {
             tonic::Response<super::CompactBlockResponse>,
             tonic::Status,
         >;
+        /// Server streaming response type for the CompactBlockSync method.
+        type CompactBlockSyncStream: tonic::codegen::tokio_stream::Stream<
+                Item = std::result::Result<
+                    super::CompactBlockSyncResponse,
+                    tonic::Status,
+                >,
+            >
+            + Send
+            + 'static;
+        /// Returns a stream of compact blocks, starting from a given height, which
+        /// catches up to the tip of the chain and then follows it as new blocks are
+        /// created.
+        ///
+        /// Unlike `CompactBlockRange`, the stream can optionally be filtered by FMD
+        /// detection keys, and marks the block at which the client has caught up.
+        /// Blocks are only produced as fast as the client consumes them.
+        async fn compact_block_sync(
+            &self,
+            request: tonic::Request<super::CompactBlockSyncRequest>,
+        ) -> std::result::Result<
+            tonic::Response<Self::CompactBlockSyncStream>,
+            tonic::Status,
+        >;
     }
     /// Query operations for the compact block component.
     #[derive(Debug)]
@@ -565,6 +720,55 @@ pub mod query_service_server {
                     };
                     Box::pin(fut)
                 }
+                "/penumbra.core.component.compact_block.v1.QueryService/CompactBlockSync" => {
+                    #[allow(non_camel_case_types)]
+                    struct CompactBlockSyncSvc<T: QueryService>(pub Arc<T>);
+                    impl<
+                        T: QueryService,
+                    > tonic::server::ServerStreamingService<
+                        super::CompactBlockSyncRequest,
+                    > for CompactBlockSyncSvc<T> {
+                        type Response = super::CompactBlockSyncResponse;
+                        type ResponseStream = T::CompactBlockSyncStream;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::ResponseStream>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::CompactBlockSyncRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as QueryService>::compact_block_sync(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = CompactBlockSyncSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.server_streaming(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
                 _ => {
                     Box::pin(async move {
                         Ok(
@@ -169,6 +169,12 @@ pub struct SwapPlaintext {
     /// Swap rseed (blinding factors are derived from this)
     #[prost(bytes = "vec", tag = "6")]
     pub rseed: ::prost::alloc::vec::Vec<u8>,
+    /// Commitment to the key authorizing the claim of the swap.
+    ///
+    /// The claim key derives the swap's nullifier, and can be given to a third
+    /// party to claim the swap without revealing the swapper's viewing key.
+    #[prost(bytes = "vec", tag = "7")]
+    pub claim_key_commitment: ::prost::alloc::vec::Vec<u8>,
 }
 impl ::prost::Name for SwapPlaintext {
     const NAME: &'static str = "SwapPlaintext";
@@ -221,6 +227,11 @@ pub struct SwapClaimPlan {
     /// The second blinding factor to use for the ZK swap claim proof.
     #[prost(bytes = "vec", tag = "6")]
     pub proof_blinding_s: ::prost::alloc::vec::Vec<u8>,
+    /// The key authorizing the claim, if the swap is claimed on behalf of someone else.
+    ///
+    /// If empty, the claim key is derived from the full viewing key building the claim.
+    #[prost(bytes = "vec", tag = "7")]
+    pub claim_key: ::prost::alloc::vec::Vec<u8>,
 }
 impl ::prost::Name for SwapClaimPlan {
     const NAME: &'static str = "SwapClaimPlan";
@@ -568,6 +579,10 @@ pub struct Position {
     /// / immediately after being filled.
     #[prost(bool, tag = "5")]
     pub close_on_fill: bool,
+    /// If set, the position's fee increases from the fee of its trading function
+    /// as its reserves become imbalanced.
+    #[prost(message, optional, tag = "6")]
+    pub dynamic_fee: ::core::option::Option<DynamicFee>,
 }
 impl ::prost::Name for Position {
     const NAME: &'static str = "Position";
@@ -576,6 +591,26 @@ impl ::prost::Name for Position {
         ::prost::alloc::format!("penumbra.core.component.dex.v1.{}", Self::NAME)
     }
 }
+/// A schedule raising a position's fee as its reserves become imbalanced.
+///
+/// The reserves are balanced when `p * r1 == q * r2`. The fee increases linearly
+/// with the imbalance `|p * r1 - q * r2| / (p * r1 + q * r2)`, from the fee of
+/// the trading function when the reserves are balanced, up to `max_fee` when
+/// one side of the reserves is empty.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct DynamicFee {
+    /// The fee charged, in basis points, when one side of the reserves is empty.
+    #[prost(uint32, tag = "1")]
+    pub max_fee: u32,
+}
+impl ::prost::Name for DynamicFee {
+    const NAME: &'static str = "DynamicFee";
+    const PACKAGE: &'static str = "penumbra.core.component.dex.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.core.component.dex.v1.{}", Self::NAME)
+    }
+}
 /// A hash of a `Position`.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -677,6 +712,162 @@ impl ::prost::Name for PositionState {
         ::prost::alloc::format!("penumbra.core.component.dex.v1.{}", Self::NAME)
     }
 }
+/// Metadata about the provenance of a liquidity position, recorded when the
+/// position is opened.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PositionMetadata {
+    /// The height of the block in which the position was opened.
+    #[prost(uint64, tag = "1")]
+    pub creation_height: u64,
+    /// The trading pair the position was opened on.
+    #[prost(message, optional, tag = "2")]
+    pub trading_pair: ::core::option::Option<TradingPair>,
+    /// The strategy tag supplied when the position was opened, if any.
+    #[prost(string, tag = "3")]
+    pub strategy_tag: ::prost::alloc::string::String,
+    /// The position this position replaced with a `PositionRewrite`, if any.
+    #[prost(message, optional, tag = "4")]
+    pub rewritten_from: ::core::option::Option<PositionId>,
+}
+impl ::prost::Name for PositionMetadata {
+    const NAME: &'static str = "PositionMetadata";
+    const PACKAGE: &'static str = "penumbra.core.component.dex.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.core.component.dex.v1.{}", Self::NAME)
+    }
+}
+/// The changes to a liquidity position during a single block.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PositionHistoryEntry {
+    /// The height of the block.
+    #[prost(uint64, tag = "1")]
+    pub height: u64,
+    /// The state of the position before the block, if it existed before the block.
+    #[prost(message, optional, tag = "2")]
+    pub prev_state: ::core::option::Option<PositionState>,
+    /// The state of the position at the end of the block.
+    #[prost(message, optional, tag = "3")]
+    pub state: ::core::option::Option<PositionState>,
+    /// The reserves of the position at the end of the block.
+    #[prost(message, optional, tag = "4")]
+    pub reserves: ::core::option::Option<Reserves>,
+    /// The number of times the position was filled against during the block.
+    #[prost(uint32, tag = "5")]
+    pub fills: u32,
+    /// The total amount of asset 1 traded into the position during the block.
+    #[prost(message, optional, tag = "6")]
+    pub inflow_1: ::core::option::Option<super::super::super::num::v1::Amount>,
+    /// The total amount of asset 2 traded into the position during the block.
+    #[prost(message, optional, tag = "7")]
+    pub inflow_2: ::core::option::Option<super::super::super::num::v1::Amount>,
+    /// The total amount of asset 1 traded out of the position during the block.
+    #[prost(message, optional, tag = "8")]
+    pub outflow_1: ::core::option::Option<super::super::super::num::v1::Amount>,
+    /// The total amount of asset 2 traded out of the position during the block.
+    #[prost(message, optional, tag = "9")]
+    pub outflow_2: ::core::option::Option<super::super::super::num::v1::Amount>,
+    /// The fees accumulated by the position in asset 1 during the block.
+    #[prost(message, optional, tag = "10")]
+    pub fees_1: ::core::option::Option<super::super::super::num::v1::Amount>,
+    /// The fees accumulated by the position in asset 2 during the block.
+    #[prost(message, optional, tag = "11")]
+    pub fees_2: ::core::option::Option<super::super::super::num::v1::Amount>,
+    /// The position this position replaced with a `PositionRewrite` during the
+    /// block, if any, whose history continues into this position's.
+    #[prost(message, optional, tag = "12")]
+    pub rewritten_from: ::core::option::Option<PositionId>,
+    /// The position that replaced this position with a `PositionRewrite` during
+    /// the block, if any, whose history continues from this position's.
+    #[prost(message, optional, tag = "13")]
+    pub rewritten_to: ::core::option::Option<PositionId>,
+}
+impl ::prost::Name for PositionHistoryEntry {
+    const NAME: &'static str = "PositionHistoryEntry";
+    const PACKAGE: &'static str = "penumbra.core.component.dex.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.core.component.dex.v1.{}", Self::NAME)
+    }
+}
+/// Aggregate metrics for a set of liquidity positions on a single trading pair,
+/// computed from their histories.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PositionAnalytics {
+    /// The trading pair the positions were opened on.
+    #[prost(message, optional, tag = "1")]
+    pub trading_pair: ::core::option::Option<TradingPair>,
+    /// The number of positions aggregated.
+    #[prost(uint32, tag = "2")]
+    pub position_count: u32,
+    /// The total fees earned by the positions in asset 1.
+    #[prost(message, optional, tag = "3")]
+    pub fees_1: ::core::option::Option<super::super::super::num::v1::Amount>,
+    /// The total fees earned by the positions in asset 2.
+    #[prost(message, optional, tag = "4")]
+    pub fees_2: ::core::option::Option<super::super::super::num::v1::Amount>,
+    /// The total amount of asset 1 traded into and out of the positions.
+    #[prost(message, optional, tag = "5")]
+    pub volume_1: ::core::option::Option<super::super::super::num::v1::Amount>,
+    /// The total amount of asset 2 traded into and out of the positions.
+    #[prost(message, optional, tag = "6")]
+    pub volume_2: ::core::option::Option<super::super::super::num::v1::Amount>,
+    /// The total number of blocks the positions were open for.
+    #[prost(uint64, tag = "7")]
+    pub blocks_open: u64,
+    /// The total number of blocks the positions were open with reserves of both
+    /// assets, so that they could be traded against in either direction.
+    #[prost(uint64, tag = "8")]
+    pub blocks_in_range: u64,
+    /// The total reserves the positions were opened with.
+    #[prost(message, optional, tag = "9")]
+    pub initial_reserves: ::core::option::Option<Reserves>,
+    /// The total reserves of the positions as of the latest block, or for
+    /// positions which have been withdrawn, as of their withdrawal.
+    #[prost(message, optional, tag = "10")]
+    pub final_reserves: ::core::option::Option<Reserves>,
+    /// The value of the positions at a reference price, if any of the positions
+    /// were ever filled.
+    #[prost(message, optional, tag = "11")]
+    pub valuation: ::core::option::Option<PositionValuation>,
+}
+impl ::prost::Name for PositionAnalytics {
+    const NAME: &'static str = "PositionAnalytics";
+    const PACKAGE: &'static str = "penumbra.core.component.dex.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.core.component.dex.v1.{}", Self::NAME)
+    }
+}
+/// The value of a set of liquidity positions in terms of asset 2 of their
+/// trading pair, at the clearing price of a batch swap.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PositionValuation {
+    /// The height of the batch swap whose clearing price was used.
+    #[prost(uint64, tag = "1")]
+    pub height: u64,
+    /// The value of the initial reserves, had they been held instead.
+    #[prost(message, optional, tag = "2")]
+    pub held_value: ::core::option::Option<super::super::super::num::v1::Amount>,
+    /// The value of the final reserves.
+    #[prost(message, optional, tag = "3")]
+    pub final_value: ::core::option::Option<super::super::super::num::v1::Amount>,
+    /// The value of the fees earned, which are included in the final reserves.
+    #[prost(message, optional, tag = "4")]
+    pub fees_value: ::core::option::Option<super::super::super::num::v1::Amount>,
+    /// The value lost relative to holding the initial reserves, not counting the
+    /// fees earned, or zero if the positions gained value regardless.
+    #[prost(message, optional, tag = "5")]
+    pub impermanent_loss: ::core::option::Option<super::super::super::num::v1::Amount>,
+}
+impl ::prost::Name for PositionValuation {
+    const NAME: &'static str = "PositionValuation";
+    const PACKAGE: &'static str = "penumbra.core.component.dex.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.core.component.dex.v1.{}", Self::NAME)
+    }
+}
 /// An LPNFT tracking both ownership and state of a position.
 ///
 /// Tracking the state as part of the LPNFT means that all LP-related actions can
@@ -720,6 +911,13 @@ pub struct PositionOpen {
     /// are unchanged over the entire lifetime of the position.
     #[prost(message, optional, tag = "1")]
     pub position: ::core::option::Option<Position>,
+    /// An optional tag identifying the strategy which opened the position, such
+    /// as the name of the market-making software, recorded in the position's
+    /// metadata.
+    ///
+    /// The tag is at most 64 bytes long.
+    #[prost(string, tag = "2")]
+    pub strategy_tag: ::prost::alloc::string::String,
 }
 impl ::prost::Name for PositionOpen {
     const NAME: &'static str = "PositionOpen";
@@ -780,6 +978,127 @@ impl ::prost::Name for PositionWithdraw {
         ::prost::alloc::format!("penumbra.core.component.dex.v1.{}", Self::NAME)
     }
 }
+/// A transaction action that closes, withdraws from, and opens several positions at once.
+///
+/// This allows a market maker to refresh many quotes with a single compact
+/// action: the withdrawals share a single commitment to their combined
+/// reserves, and the whole batch is validated together.
+///
+/// This action's contribution to the transaction's value balance is the sum of
+/// the contributions of the equivalent individual position actions.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PositionUpdateBatch {
+    /// The positions to close.
+    #[prost(message, repeated, tag = "1")]
+    pub closes: ::prost::alloc::vec::Vec<PositionId>,
+    /// The positions to withdraw from.
+    #[prost(message, repeated, tag = "2")]
+    pub withdrawals: ::prost::alloc::vec::Vec<position_update_batch::Withdrawal>,
+    /// A transparent (zero blinding factor) commitment to the combined final
+    /// reserves of all of the withdrawn positions.
+    ///
+    /// The chain will check this commitment by recomputing it with the on-chain state.
+    #[prost(message, optional, tag = "3")]
+    pub withdrawn_reserves_commitment: ::core::option::Option<
+        super::super::super::asset::v1::BalanceCommitment,
+    >,
+    /// The positions to open.
+    #[prost(message, repeated, tag = "4")]
+    pub opens: ::prost::alloc::vec::Vec<Position>,
+}
+/// Nested message and enum types in `PositionUpdateBatch`.
+pub mod position_update_batch {
+    /// A withdrawal from a closed position.
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct Withdrawal {
+        #[prost(message, optional, tag = "1")]
+        pub position_id: ::core::option::Option<super::PositionId>,
+        /// The sequence number of the withdrawal.
+        #[prost(uint64, tag = "2")]
+        pub sequence: u64,
+    }
+    impl ::prost::Name for Withdrawal {
+        const NAME: &'static str = "Withdrawal";
+        const PACKAGE: &'static str = "penumbra.core.component.dex.v1";
+        fn full_name() -> ::prost::alloc::string::String {
+            ::prost::alloc::format!(
+                "penumbra.core.component.dex.v1.PositionUpdateBatch.{}", Self::NAME
+            )
+        }
+    }
+}
+impl ::prost::Name for PositionUpdateBatch {
+    const NAME: &'static str = "PositionUpdateBatch";
+    const PACKAGE: &'static str = "penumbra.core.component.dex.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.core.component.dex.v1.{}", Self::NAME)
+    }
+}
+/// A transaction action that opens several positions at once.
+///
+/// This allows a market maker to deploy a ladder of quotes with a single
+/// action, whose positions are validated together.
+///
+/// This action's contribution to the transaction's value balance is to consume
+/// the combined initial reserves of the positions, and produce an opened
+/// position NFT for each of them.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PositionOpenBatch {
+    /// The positions to open.
+    #[prost(message, repeated, tag = "1")]
+    pub positions: ::prost::alloc::vec::Vec<Position>,
+    /// An optional tag identifying the strategy which opened the positions,
+    /// recorded in the metadata of each of them.
+    #[prost(string, tag = "2")]
+    pub strategy_tag: ::prost::alloc::string::String,
+}
+impl ::prost::Name for PositionOpenBatch {
+    const NAME: &'static str = "PositionOpenBatch";
+    const PACKAGE: &'static str = "penumbra.core.component.dex.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.core.component.dex.v1.{}", Self::NAME)
+    }
+}
+/// A transaction action that atomically replaces an open position with a new
+/// position on the same trading pair.
+///
+/// The existing position is closed and its final reserves withdrawn immediately,
+/// rather than at the end of the block, and the new position is opened in its
+/// place, so that the liquidity provider's quote is never missing from the
+/// book. The new position's history records the position it replaced.
+///
+/// This action's contribution to the transaction's value balance is to consume
+/// the opened position NFT of the existing position and produce its first
+/// withdrawn position NFT along with its final reserves, and to consume the
+/// initial reserves of the new position and produce its opened position NFT.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PositionRewrite {
+    /// The open position to replace.
+    #[prost(message, optional, tag = "1")]
+    pub position_id: ::core::option::Option<PositionId>,
+    /// A transparent (zero blinding factor) commitment to the final reserves of
+    /// the replaced position.
+    ///
+    /// The chain will check this commitment by recomputing it with the on-chain state.
+    #[prost(message, optional, tag = "2")]
+    pub reserves_commitment: ::core::option::Option<
+        super::super::super::asset::v1::BalanceCommitment,
+    >,
+    /// The position to open in its place.
+    #[prost(message, optional, tag = "3")]
+    pub new_position: ::core::option::Option<Position>,
+}
+impl ::prost::Name for PositionRewrite {
+    const NAME: &'static str = "PositionRewrite";
+    const PACKAGE: &'static str = "penumbra.core.component.dex.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.core.component.dex.v1.{}", Self::NAME)
+    }
+}
 /// Deprecated.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -840,6 +1159,44 @@ impl ::prost::Name for SwapExecution {
         ::prost::alloc::format!("penumbra.core.component.dex.v1.{}", Self::NAME)
     }
 }
+/// A compact record of how a batch swap was executed in one direction of a
+/// trading pair, position by position.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SwapExecutionTrace {
+    /// The fills along each route used by the execution, in execution order.
+    #[prost(message, repeated, tag = "1")]
+    pub fills: ::prost::alloc::vec::Vec<RouteFill>,
+}
+impl ::prost::Name for SwapExecutionTrace {
+    const NAME: &'static str = "SwapExecutionTrace";
+    const PACKAGE: &'static str = "penumbra.core.component.dex.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.core.component.dex.v1.{}", Self::NAME)
+    }
+}
+/// A single fill along a route, against one liquidity position at each hop.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct RouteFill {
+    /// The assets along the route, from the input asset to the output asset.
+    #[prost(message, repeated, tag = "1")]
+    pub route: ::prost::alloc::vec::Vec<super::super::super::asset::v1::AssetId>,
+    /// The amount of each asset along the route: the first is the input of the
+    /// fill, and each other is the output of the hop ending at that asset.
+    #[prost(message, repeated, tag = "2")]
+    pub amounts: ::prost::alloc::vec::Vec<super::super::super::num::v1::Amount>,
+    /// The position filled against at each hop of the route.
+    #[prost(message, repeated, tag = "3")]
+    pub positions: ::prost::alloc::vec::Vec<PositionId>,
+}
+impl ::prost::Name for RouteFill {
+    const NAME: &'static str = "RouteFill";
+    const PACKAGE: &'static str = "penumbra.core.component.dex.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.core.component.dex.v1.{}", Self::NAME)
+    }
+}
 /// Contains private and public data for withdrawing funds from a closed position.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -864,151 +1221,515 @@ impl ::prost::Name for PositionWithdrawPlan {
         ::prost::alloc::format!("penumbra.core.component.dex.v1.{}", Self::NAME)
     }
 }
-/// Deprecated.
+/// Contains private data needed to form a `PositionUpdateBatch`.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
-pub struct PositionRewardClaimPlan {
-    #[prost(message, optional, tag = "1")]
-    pub reserves: ::core::option::Option<Reserves>,
+pub struct PositionUpdateBatchPlan {
+    /// The positions to close.
+    #[prost(message, repeated, tag = "1")]
+    pub closes: ::prost::alloc::vec::Vec<PositionId>,
+    /// The positions to withdraw from, with their final reserves.
+    #[prost(message, repeated, tag = "2")]
+    pub withdrawals: ::prost::alloc::vec::Vec<PositionWithdrawPlan>,
+    /// The positions to open.
+    #[prost(message, repeated, tag = "3")]
+    pub opens: ::prost::alloc::vec::Vec<Position>,
 }
-impl ::prost::Name for PositionRewardClaimPlan {
-    const NAME: &'static str = "PositionRewardClaimPlan";
+impl ::prost::Name for PositionUpdateBatchPlan {
+    const NAME: &'static str = "PositionUpdateBatchPlan";
     const PACKAGE: &'static str = "penumbra.core.component.dex.v1";
     fn full_name() -> ::prost::alloc::string::String {
         ::prost::alloc::format!("penumbra.core.component.dex.v1.{}", Self::NAME)
     }
 }
-/// Requests batch swap data associated with a given height and trading pair from the view service.
+/// Contains private data needed to form a `PositionRewrite`.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
-pub struct BatchSwapOutputDataRequest {
-    #[prost(uint64, tag = "2")]
-    pub height: u64,
+pub struct PositionRewritePlan {
+    /// The open position to replace.
+    #[prost(message, optional, tag = "1")]
+    pub position_id: ::core::option::Option<PositionId>,
+    /// The trading pair of the replaced position.
+    #[prost(message, optional, tag = "2")]
+    pub pair: ::core::option::Option<TradingPair>,
+    /// The final reserves of the replaced position.
     #[prost(message, optional, tag = "3")]
-    pub trading_pair: ::core::option::Option<TradingPair>,
+    pub reserves: ::core::option::Option<Reserves>,
+    /// The position to open in its place.
+    #[prost(message, optional, tag = "4")]
+    pub new_position: ::core::option::Option<Position>,
 }
-impl ::prost::Name for BatchSwapOutputDataRequest {
-    const NAME: &'static str = "BatchSwapOutputDataRequest";
+impl ::prost::Name for PositionRewritePlan {
+    const NAME: &'static str = "PositionRewritePlan";
     const PACKAGE: &'static str = "penumbra.core.component.dex.v1";
     fn full_name() -> ::prost::alloc::string::String {
         ::prost::alloc::format!("penumbra.core.component.dex.v1.{}", Self::NAME)
     }
 }
+/// Deprecated.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
-pub struct BatchSwapOutputDataResponse {
+pub struct PositionRewardClaimPlan {
     #[prost(message, optional, tag = "1")]
-    pub data: ::core::option::Option<BatchSwapOutputData>,
+    pub reserves: ::core::option::Option<Reserves>,
 }
-impl ::prost::Name for BatchSwapOutputDataResponse {
-    const NAME: &'static str = "BatchSwapOutputDataResponse";
+impl ::prost::Name for PositionRewardClaimPlan {
+    const NAME: &'static str = "PositionRewardClaimPlan";
     const PACKAGE: &'static str = "penumbra.core.component.dex.v1";
     fn full_name() -> ::prost::alloc::string::String {
         ::prost::alloc::format!("penumbra.core.component.dex.v1.{}", Self::NAME)
     }
 }
+/// A unique identifier for an auction, obtained from hashing its description.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
-pub struct SwapExecutionRequest {
-    #[prost(uint64, tag = "2")]
-    pub height: u64,
-    #[prost(message, optional, tag = "3")]
-    pub trading_pair: ::core::option::Option<DirectedTradingPair>,
+pub struct AuctionId {
+    #[prost(bytes = "vec", tag = "1")]
+    pub inner: ::prost::alloc::vec::Vec<u8>,
 }
-impl ::prost::Name for SwapExecutionRequest {
-    const NAME: &'static str = "SwapExecutionRequest";
+impl ::prost::Name for AuctionId {
+    const NAME: &'static str = "AuctionId";
     const PACKAGE: &'static str = "penumbra.core.component.dex.v1";
     fn full_name() -> ::prost::alloc::string::String {
         ::prost::alloc::format!("penumbra.core.component.dex.v1.{}", Self::NAME)
     }
 }
+/// A non-fungible token tracking the ownership and state of an auction.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
-pub struct SwapExecutionResponse {
+pub struct AuctionNft {
     #[prost(message, optional, tag = "1")]
-    pub swap_execution: ::core::option::Option<SwapExecution>,
+    pub id: ::core::option::Option<AuctionId>,
+    /// The sequence number of the auction state: 0 while the auction is
+    /// scheduled or running, 1 once it has ended, and 2 once its reserves have
+    /// been withdrawn.
+    #[prost(uint64, tag = "2")]
+    pub seq: u64,
 }
-impl ::prost::Name for SwapExecutionResponse {
-    const NAME: &'static str = "SwapExecutionResponse";
+impl ::prost::Name for AuctionNft {
+    const NAME: &'static str = "AuctionNft";
     const PACKAGE: &'static str = "penumbra.core.component.dex.v1";
     fn full_name() -> ::prost::alloc::string::String {
         ::prost::alloc::format!("penumbra.core.component.dex.v1.{}", Self::NAME)
     }
 }
+/// Describes a gradual Dutch auction.
+///
+/// The auction sells its `input` for the `output_id` asset.  The price starts
+/// at `max_output` for the entire input at `start_height`, and decays linearly
+/// with each block until it reaches `min_output` at `end_height`.  In each block
+/// of the auction, the remaining input is sold against the liquidity available
+/// on the DEX, at a price no worse than the auction's current price.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
-pub struct ArbExecutionRequest {
-    #[prost(uint64, tag = "2")]
-    pub height: u64,
+pub struct DutchAuctionDescription {
+    /// The value being auctioned.
+    #[prost(message, optional, tag = "1")]
+    pub input: ::core::option::Option<super::super::super::asset::v1::Value>,
+    /// The asset the input is being auctioned for.
+    #[prost(message, optional, tag = "2")]
+    pub output_id: ::core::option::Option<super::super::super::asset::v1::AssetId>,
+    /// The amount of output asked for the entire input at the start of the auction.
+    #[prost(message, optional, tag = "3")]
+    pub max_output: ::core::option::Option<super::super::super::num::v1::Amount>,
+    /// The amount of output asked for the entire input at the end of the auction.
+    #[prost(message, optional, tag = "4")]
+    pub min_output: ::core::option::Option<super::super::super::num::v1::Amount>,
+    /// The height of the first block of the auction.
+    #[prost(uint64, tag = "5")]
+    pub start_height: u64,
+    /// The height of the last block of the auction.
+    #[prost(uint64, tag = "6")]
+    pub end_height: u64,
+    /// A random nonce, used to ensure that auction IDs are unique.
+    #[prost(bytes = "vec", tag = "7")]
+    pub nonce: ::prost::alloc::vec::Vec<u8>,
+    /// Whether the steps of the auction should only offer its input in a
+    /// position on the DEX, rather than first selling against the liquidity
+    /// already on the DEX, when it offers a better price than the auction.
+    #[prost(bool, tag = "8")]
+    pub skip_dex_fill: bool,
 }
-impl ::prost::Name for ArbExecutionRequest {
-    const NAME: &'static str = "ArbExecutionRequest";
+impl ::prost::Name for DutchAuctionDescription {
+    const NAME: &'static str = "DutchAuctionDescription";
     const PACKAGE: &'static str = "penumbra.core.component.dex.v1";
     fn full_name() -> ::prost::alloc::string::String {
         ::prost::alloc::format!("penumbra.core.component.dex.v1.{}", Self::NAME)
     }
 }
+/// The progress of a gradual Dutch auction.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
-pub struct ArbExecutionResponse {
-    #[prost(message, optional, tag = "1")]
-    pub swap_execution: ::core::option::Option<SwapExecution>,
-    #[prost(uint64, tag = "2")]
-    pub height: u64,
+pub struct DutchAuctionState {
+    /// The sequence number of the auction state.
+    #[prost(uint64, tag = "1")]
+    pub seq: u64,
+    /// The amount of the input which has not yet been sold.
+    #[prost(message, optional, tag = "2")]
+    pub input_reserves: ::core::option::Option<super::super::super::num::v1::Amount>,
+    /// The amount of the output received for the input sold so far.
+    #[prost(message, optional, tag = "3")]
+    pub output_reserves: ::core::option::Option<super::super::super::num::v1::Amount>,
+    /// The position holding the auction's reserves on the DEX, if any.
+    ///
+    /// While the auction is trading, its reserves are deposited in a position
+    /// offering the input at the auction's current price, which is replaced at
+    /// each step of the auction.
+    #[prost(message, optional, tag = "4")]
+    pub current_position: ::core::option::Option<PositionId>,
 }
-impl ::prost::Name for ArbExecutionResponse {
-    const NAME: &'static str = "ArbExecutionResponse";
+impl ::prost::Name for DutchAuctionState {
+    const NAME: &'static str = "DutchAuctionState";
     const PACKAGE: &'static str = "penumbra.core.component.dex.v1";
     fn full_name() -> ::prost::alloc::string::String {
         ::prost::alloc::format!("penumbra.core.component.dex.v1.{}", Self::NAME)
     }
 }
+/// A gradual Dutch auction, along with its progress.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
-pub struct SwapExecutionsRequest {
-    /// If present, only return swap executions occurring after the given height.
-    #[prost(uint64, tag = "2")]
-    pub start_height: u64,
-    /// If present, only return swap executions occurring before the given height.
-    #[prost(uint64, tag = "3")]
-    pub end_height: u64,
-    /// If present, filter swap executions by the given trading pair.
-    #[prost(message, optional, tag = "4")]
-    pub trading_pair: ::core::option::Option<DirectedTradingPair>,
+pub struct DutchAuction {
+    #[prost(message, optional, tag = "1")]
+    pub description: ::core::option::Option<DutchAuctionDescription>,
+    #[prost(message, optional, tag = "2")]
+    pub state: ::core::option::Option<DutchAuctionState>,
 }
-impl ::prost::Name for SwapExecutionsRequest {
-    const NAME: &'static str = "SwapExecutionsRequest";
+impl ::prost::Name for DutchAuction {
+    const NAME: &'static str = "DutchAuction";
     const PACKAGE: &'static str = "penumbra.core.component.dex.v1";
     fn full_name() -> ::prost::alloc::string::String {
         ::prost::alloc::format!("penumbra.core.component.dex.v1.{}", Self::NAME)
     }
 }
+/// A transaction action that schedules a gradual Dutch auction.
+///
+/// This action's contribution to the transaction's value balance is to consume
+/// the auction's input and contribute an auction NFT with sequence number 0.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
-pub struct SwapExecutionsResponse {
+pub struct ActionDutchAuctionSchedule {
     #[prost(message, optional, tag = "1")]
-    pub swap_execution: ::core::option::Option<SwapExecution>,
-    #[prost(uint64, tag = "2")]
-    pub height: u64,
-    #[prost(message, optional, tag = "3")]
-    pub trading_pair: ::core::option::Option<DirectedTradingPair>,
+    pub description: ::core::option::Option<DutchAuctionDescription>,
 }
-impl ::prost::Name for SwapExecutionsResponse {
-    const NAME: &'static str = "SwapExecutionsResponse";
+impl ::prost::Name for ActionDutchAuctionSchedule {
+    const NAME: &'static str = "ActionDutchAuctionSchedule";
     const PACKAGE: &'static str = "penumbra.core.component.dex.v1";
     fn full_name() -> ::prost::alloc::string::String {
         ::prost::alloc::format!("penumbra.core.component.dex.v1.{}", Self::NAME)
     }
 }
+/// A transaction action that ends a gradual Dutch auction.
+///
+/// This action's contribution to the transaction's value balance is to consume
+/// an auction NFT with sequence number 0 and contribute one with sequence
+/// number 1.  Auctions which run until their end height, or sell their entire
+/// input, end automatically, but must still be ended by their owner before
+/// their reserves can be withdrawn.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
-pub struct ArbExecutionsRequest {
-    /// If present, only return arb executions occurring after the given height.
-    #[prost(uint64, tag = "2")]
-    pub start_height: u64,
-    /// If present, only return arb executions occurring before the given height.
-    #[prost(uint64, tag = "3")]
-    pub end_height: u64,
+pub struct ActionDutchAuctionEnd {
+    #[prost(message, optional, tag = "1")]
+    pub auction_id: ::core::option::Option<AuctionId>,
+}
+impl ::prost::Name for ActionDutchAuctionEnd {
+    const NAME: &'static str = "ActionDutchAuctionEnd";
+    const PACKAGE: &'static str = "penumbra.core.component.dex.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.core.component.dex.v1.{}", Self::NAME)
+    }
+}
+/// A transaction action that withdraws the reserves of an ended gradual Dutch auction.
+///
+/// This action's contribution to the transaction's value balance is to consume
+/// an auction NFT with sequence number `seq - 1` and contribute one with
+/// sequence number `seq`, as well as the auction's reserves.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ActionDutchAuctionWithdraw {
+    #[prost(message, optional, tag = "1")]
+    pub auction_id: ::core::option::Option<AuctionId>,
+    /// The sequence number of the auction state after the withdrawal.
+    #[prost(uint64, tag = "2")]
+    pub seq: u64,
+    /// A transparent (zero blinding factor) commitment to the auction's reserves.
+    ///
+    /// The chain will check this commitment by recomputing it with the on-chain state.
+    #[prost(message, optional, tag = "3")]
+    pub reserves_commitment: ::core::option::Option<
+        super::super::super::asset::v1::BalanceCommitment,
+    >,
+}
+impl ::prost::Name for ActionDutchAuctionWithdraw {
+    const NAME: &'static str = "ActionDutchAuctionWithdraw";
+    const PACKAGE: &'static str = "penumbra.core.component.dex.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.core.component.dex.v1.{}", Self::NAME)
+    }
+}
+/// Contains private data needed to form an `ActionDutchAuctionWithdraw`.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ActionDutchAuctionWithdrawPlan {
+    #[prost(message, optional, tag = "1")]
+    pub auction_id: ::core::option::Option<AuctionId>,
+    /// The sequence number of the auction state after the withdrawal.
+    #[prost(uint64, tag = "2")]
+    pub seq: u64,
+    /// The unsold input remaining in the auction.
+    #[prost(message, optional, tag = "3")]
+    pub reserves_input: ::core::option::Option<super::super::super::asset::v1::Value>,
+    /// The output received by the auction.
+    #[prost(message, optional, tag = "4")]
+    pub reserves_output: ::core::option::Option<super::super::super::asset::v1::Value>,
+}
+impl ::prost::Name for ActionDutchAuctionWithdrawPlan {
+    const NAME: &'static str = "ActionDutchAuctionWithdrawPlan";
+    const PACKAGE: &'static str = "penumbra.core.component.dex.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.core.component.dex.v1.{}", Self::NAME)
+    }
+}
+/// A record of the execution circuit breaker halting routing of a batch swap.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ExecutionCircuitBreakerTrip {
+    /// The height at which the circuit breaker was triggered.
+    #[prost(uint64, tag = "1")]
+    pub height: u64,
+    /// The direction of the batch swap whose routing was halted.
+    #[prost(message, optional, tag = "2")]
+    pub trading_pair: ::core::option::Option<DirectedTradingPair>,
+    /// The number of path searches performed before routing was halted.
+    #[prost(uint32, tag = "3")]
+    pub path_searches: u32,
+    /// The number of executions against positions performed before routing was halted.
+    #[prost(uint32, tag = "4")]
+    pub executions: u32,
+}
+impl ::prost::Name for ExecutionCircuitBreakerTrip {
+    const NAME: &'static str = "ExecutionCircuitBreakerTrip";
+    const PACKAGE: &'static str = "penumbra.core.component.dex.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.core.component.dex.v1.{}", Self::NAME)
+    }
+}
+/// A record of the value circuit breaker detecting that more of an asset would
+/// flow out of liquidity positions than was deposited in them.
+///
+/// Since this violates the DEX's value conservation invariant, the chain halts
+/// immediately, without committing the block in which the breach was detected.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ValueCircuitBreakerBreach {
+    /// The height at which the breach was detected.
+    #[prost(uint64, tag = "1")]
+    pub height: u64,
+    /// The trading pair whose execution breached the circuit breaker.
+    #[prost(message, optional, tag = "2")]
+    pub trading_pair: ::core::option::Option<TradingPair>,
+    /// The asset whose outflow exceeded its balance in liquidity positions.
+    #[prost(message, optional, tag = "3")]
+    pub asset_id: ::core::option::Option<super::super::super::asset::v1::AssetId>,
+    /// The balance of the asset held in liquidity positions before the outflow.
+    #[prost(message, optional, tag = "4")]
+    pub available: ::core::option::Option<super::super::super::num::v1::Amount>,
+    /// The amount of the asset that would have flowed out of liquidity positions.
+    #[prost(message, optional, tag = "5")]
+    pub outflow: ::core::option::Option<super::super::super::num::v1::Amount>,
+    /// The positions involved in the offending execution, if known.
+    #[prost(message, repeated, tag = "6")]
+    pub positions: ::prost::alloc::vec::Vec<PositionId>,
+}
+impl ::prost::Name for ValueCircuitBreakerBreach {
+    const NAME: &'static str = "ValueCircuitBreakerBreach";
+    const PACKAGE: &'static str = "penumbra.core.component.dex.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.core.component.dex.v1.{}", Self::NAME)
+    }
+}
+/// The status of the DEX's execution circuit breaker.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CircuitBreakerStatus {
+    /// The maximum number of path searches performed when routing a single input.
+    #[prost(uint32, tag = "1")]
+    pub max_path_searches: u32,
+    /// The maximum number of executions against positions when routing a single input.
+    #[prost(uint32, tag = "2")]
+    pub max_executions: u32,
+    /// The most recent time the circuit breaker halted routing of a batch swap, if ever.
+    #[prost(message, optional, tag = "3")]
+    pub last_trip: ::core::option::Option<ExecutionCircuitBreakerTrip>,
+}
+impl ::prost::Name for CircuitBreakerStatus {
+    const NAME: &'static str = "CircuitBreakerStatus";
+    const PACKAGE: &'static str = "penumbra.core.component.dex.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.core.component.dex.v1.{}", Self::NAME)
+    }
+}
+/// Requests batch swap data associated with a given height and trading pair from the view service.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct BatchSwapOutputDataRequest {
+    #[prost(uint64, tag = "2")]
+    pub height: u64,
+    #[prost(message, optional, tag = "3")]
+    pub trading_pair: ::core::option::Option<TradingPair>,
+}
+impl ::prost::Name for BatchSwapOutputDataRequest {
+    const NAME: &'static str = "BatchSwapOutputDataRequest";
+    const PACKAGE: &'static str = "penumbra.core.component.dex.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.core.component.dex.v1.{}", Self::NAME)
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct BatchSwapOutputDataResponse {
+    #[prost(message, optional, tag = "1")]
+    pub data: ::core::option::Option<BatchSwapOutputData>,
+}
+impl ::prost::Name for BatchSwapOutputDataResponse {
+    const NAME: &'static str = "BatchSwapOutputDataResponse";
+    const PACKAGE: &'static str = "penumbra.core.component.dex.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.core.component.dex.v1.{}", Self::NAME)
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SwapExecutionRequest {
+    #[prost(uint64, tag = "2")]
+    pub height: u64,
+    #[prost(message, optional, tag = "3")]
+    pub trading_pair: ::core::option::Option<DirectedTradingPair>,
+}
+impl ::prost::Name for SwapExecutionRequest {
+    const NAME: &'static str = "SwapExecutionRequest";
+    const PACKAGE: &'static str = "penumbra.core.component.dex.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.core.component.dex.v1.{}", Self::NAME)
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SwapExecutionResponse {
+    #[prost(message, optional, tag = "1")]
+    pub swap_execution: ::core::option::Option<SwapExecution>,
+}
+impl ::prost::Name for SwapExecutionResponse {
+    const NAME: &'static str = "SwapExecutionResponse";
+    const PACKAGE: &'static str = "penumbra.core.component.dex.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.core.component.dex.v1.{}", Self::NAME)
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SwapExecutionTraceRequest {
+    /// The height of the block in which the batch swap was executed.
+    #[prost(uint64, tag = "1")]
+    pub height: u64,
+    /// The direction of the trading pair in which the batch swap was executed.
+    #[prost(message, optional, tag = "2")]
+    pub trading_pair: ::core::option::Option<DirectedTradingPair>,
+}
+impl ::prost::Name for SwapExecutionTraceRequest {
+    const NAME: &'static str = "SwapExecutionTraceRequest";
+    const PACKAGE: &'static str = "penumbra.core.component.dex.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.core.component.dex.v1.{}", Self::NAME)
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SwapExecutionTraceResponse {
+    /// A fill along one of the routes used by the execution.
+    #[prost(message, optional, tag = "1")]
+    pub fill: ::core::option::Option<RouteFill>,
+}
+impl ::prost::Name for SwapExecutionTraceResponse {
+    const NAME: &'static str = "SwapExecutionTraceResponse";
+    const PACKAGE: &'static str = "penumbra.core.component.dex.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.core.component.dex.v1.{}", Self::NAME)
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ArbExecutionRequest {
+    #[prost(uint64, tag = "2")]
+    pub height: u64,
+}
+impl ::prost::Name for ArbExecutionRequest {
+    const NAME: &'static str = "ArbExecutionRequest";
+    const PACKAGE: &'static str = "penumbra.core.component.dex.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.core.component.dex.v1.{}", Self::NAME)
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ArbExecutionResponse {
+    #[prost(message, optional, tag = "1")]
+    pub swap_execution: ::core::option::Option<SwapExecution>,
+    #[prost(uint64, tag = "2")]
+    pub height: u64,
+}
+impl ::prost::Name for ArbExecutionResponse {
+    const NAME: &'static str = "ArbExecutionResponse";
+    const PACKAGE: &'static str = "penumbra.core.component.dex.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.core.component.dex.v1.{}", Self::NAME)
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SwapExecutionsRequest {
+    /// If present, only return swap executions occurring after the given height.
+    #[prost(uint64, tag = "2")]
+    pub start_height: u64,
+    /// If present, only return swap executions occurring before the given height.
+    #[prost(uint64, tag = "3")]
+    pub end_height: u64,
+    /// If present, filter swap executions by the given trading pair.
+    #[prost(message, optional, tag = "4")]
+    pub trading_pair: ::core::option::Option<DirectedTradingPair>,
+}
+impl ::prost::Name for SwapExecutionsRequest {
+    const NAME: &'static str = "SwapExecutionsRequest";
+    const PACKAGE: &'static str = "penumbra.core.component.dex.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.core.component.dex.v1.{}", Self::NAME)
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SwapExecutionsResponse {
+    #[prost(message, optional, tag = "1")]
+    pub swap_execution: ::core::option::Option<SwapExecution>,
+    #[prost(uint64, tag = "2")]
+    pub height: u64,
+    #[prost(message, optional, tag = "3")]
+    pub trading_pair: ::core::option::Option<DirectedTradingPair>,
+}
+impl ::prost::Name for SwapExecutionsResponse {
+    const NAME: &'static str = "SwapExecutionsResponse";
+    const PACKAGE: &'static str = "penumbra.core.component.dex.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.core.component.dex.v1.{}", Self::NAME)
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ArbExecutionsRequest {
+    /// If present, only return arb executions occurring at or after the given height.
+    #[prost(uint64, tag = "2")]
+    pub start_height: u64,
+    /// If present, only return arb executions occurring at or before the given height.
+    #[prost(uint64, tag = "3")]
+    pub end_height: u64,
 }
 impl ::prost::Name for ArbExecutionsRequest {
     const NAME: &'static str = "ArbExecutionsRequest";
@@ -1020,6 +1741,10 @@ impl ::prost::Name for ArbExecutionsRequest {
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct ArbExecutionsResponse {
+    /// The arbitrage executed at the end of the block, as a cycle from the
+    /// staking token back to itself: the traces are the routes taken, the input
+    /// is the amount routed through them, and the output is the profit, which
+    /// was burned.
     #[prost(message, optional, tag = "1")]
     pub swap_execution: ::core::option::Option<SwapExecution>,
     #[prost(uint64, tag = "2")]
@@ -1143,12 +1868,13 @@ impl ::prost::Name for LiquidityPositionsByPriceResponse {
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
-pub struct SpreadRequest {
-    #[prost(message, optional, tag = "2")]
-    pub trading_pair: ::core::option::Option<TradingPair>,
+pub struct PositionHistoryRequest {
+    /// The position to request the history of.
+    #[prost(message, optional, tag = "1")]
+    pub position_id: ::core::option::Option<PositionId>,
 }
-impl ::prost::Name for SpreadRequest {
-    const NAME: &'static str = "SpreadRequest";
+impl ::prost::Name for PositionHistoryRequest {
+    const NAME: &'static str = "PositionHistoryRequest";
     const PACKAGE: &'static str = "penumbra.core.component.dex.v1";
     fn full_name() -> ::prost::alloc::string::String {
         ::prost::alloc::format!("penumbra.core.component.dex.v1.{}", Self::NAME)
@@ -1156,22 +1882,12 @@ impl ::prost::Name for SpreadRequest {
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
-pub struct SpreadResponse {
-    /// The best position when trading 1 => 2.
+pub struct PositionHistoryResponse {
     #[prost(message, optional, tag = "1")]
-    pub best_1_to_2_position: ::core::option::Option<Position>,
-    /// The best position when trading 2 => 1.
-    #[prost(message, optional, tag = "2")]
-    pub best_2_to_1_position: ::core::option::Option<Position>,
-    /// An approximation of the effective price when trading 1 => 2.
-    #[prost(double, tag = "3")]
-    pub approx_effective_price_1_to_2: f64,
-    /// An approximation of the effective price when trading 2 => 1.
-    #[prost(double, tag = "4")]
-    pub approx_effective_price_2_to_1: f64,
+    pub entry: ::core::option::Option<PositionHistoryEntry>,
 }
-impl ::prost::Name for SpreadResponse {
-    const NAME: &'static str = "SpreadResponse";
+impl ::prost::Name for PositionHistoryResponse {
+    const NAME: &'static str = "PositionHistoryResponse";
     const PACKAGE: &'static str = "penumbra.core.component.dex.v1";
     fn full_name() -> ::prost::alloc::string::String {
         ::prost::alloc::format!("penumbra.core.component.dex.v1.{}", Self::NAME)
@@ -1179,15 +1895,240 @@ impl ::prost::Name for SpreadResponse {
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
-pub struct SimulateTradeRequest {
+pub struct PositionAtHeightRequest {
+    /// The position to request.
     #[prost(message, optional, tag = "1")]
-    pub input: ::core::option::Option<super::super::super::asset::v1::Value>,
-    #[prost(message, optional, tag = "2")]
-    pub output: ::core::option::Option<super::super::super::asset::v1::AssetId>,
-    #[prost(message, optional, tag = "3")]
-    pub routing: ::core::option::Option<simulate_trade_request::Routing>,
+    pub position_id: ::core::option::Option<PositionId>,
+    /// The height of the block at the end of which to return the position.
+    #[prost(uint64, tag = "2")]
+    pub height: u64,
 }
-/// Nested message and enum types in `SimulateTradeRequest`.
+impl ::prost::Name for PositionAtHeightRequest {
+    const NAME: &'static str = "PositionAtHeightRequest";
+    const PACKAGE: &'static str = "penumbra.core.component.dex.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.core.component.dex.v1.{}", Self::NAME)
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PositionAtHeightResponse {
+    /// The position as of the end of the requested block, or unset if the
+    /// position had not been opened yet.
+    #[prost(message, optional, tag = "1")]
+    pub position: ::core::option::Option<Position>,
+    /// The height of the last block up to the requested height in which the
+    /// position changed.
+    #[prost(uint64, tag = "2")]
+    pub last_changed_height: u64,
+}
+impl ::prost::Name for PositionAtHeightResponse {
+    const NAME: &'static str = "PositionAtHeightResponse";
+    const PACKAGE: &'static str = "penumbra.core.component.dex.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.core.component.dex.v1.{}", Self::NAME)
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PositionMetadataRequest {
+    /// The position to request the metadata of.
+    #[prost(message, optional, tag = "1")]
+    pub position_id: ::core::option::Option<PositionId>,
+}
+impl ::prost::Name for PositionMetadataRequest {
+    const NAME: &'static str = "PositionMetadataRequest";
+    const PACKAGE: &'static str = "penumbra.core.component.dex.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.core.component.dex.v1.{}", Self::NAME)
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PositionMetadataResponse {
+    #[prost(message, optional, tag = "1")]
+    pub metadata: ::core::option::Option<PositionMetadata>,
+}
+impl ::prost::Name for PositionMetadataResponse {
+    const NAME: &'static str = "PositionMetadataResponse";
+    const PACKAGE: &'static str = "penumbra.core.component.dex.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.core.component.dex.v1.{}", Self::NAME)
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct LiquidityPositionAnalyticsRequest {
+    /// The positions to summarize.
+    #[prost(message, repeated, tag = "1")]
+    pub position_ids: ::prost::alloc::vec::Vec<PositionId>,
+}
+impl ::prost::Name for LiquidityPositionAnalyticsRequest {
+    const NAME: &'static str = "LiquidityPositionAnalyticsRequest";
+    const PACKAGE: &'static str = "penumbra.core.component.dex.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.core.component.dex.v1.{}", Self::NAME)
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct LiquidityPositionAnalyticsResponse {
+    /// The metrics for the requested positions on each trading pair.
+    #[prost(message, repeated, tag = "1")]
+    pub analytics: ::prost::alloc::vec::Vec<PositionAnalytics>,
+}
+impl ::prost::Name for LiquidityPositionAnalyticsResponse {
+    const NAME: &'static str = "LiquidityPositionAnalyticsResponse";
+    const PACKAGE: &'static str = "penumbra.core.component.dex.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.core.component.dex.v1.{}", Self::NAME)
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct DutchAuctionByIdRequest {
+    /// The auction to request.
+    #[prost(message, optional, tag = "1")]
+    pub auction_id: ::core::option::Option<AuctionId>,
+}
+impl ::prost::Name for DutchAuctionByIdRequest {
+    const NAME: &'static str = "DutchAuctionByIdRequest";
+    const PACKAGE: &'static str = "penumbra.core.component.dex.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.core.component.dex.v1.{}", Self::NAME)
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct DutchAuctionByIdResponse {
+    #[prost(message, optional, tag = "1")]
+    pub auction: ::core::option::Option<DutchAuction>,
+}
+impl ::prost::Name for DutchAuctionByIdResponse {
+    const NAME: &'static str = "DutchAuctionByIdResponse";
+    const PACKAGE: &'static str = "penumbra.core.component.dex.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.core.component.dex.v1.{}", Self::NAME)
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SpreadRequest {
+    #[prost(message, optional, tag = "2")]
+    pub trading_pair: ::core::option::Option<TradingPair>,
+}
+impl ::prost::Name for SpreadRequest {
+    const NAME: &'static str = "SpreadRequest";
+    const PACKAGE: &'static str = "penumbra.core.component.dex.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.core.component.dex.v1.{}", Self::NAME)
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SpreadResponse {
+    /// The best position when trading 1 => 2.
+    #[prost(message, optional, tag = "1")]
+    pub best_1_to_2_position: ::core::option::Option<Position>,
+    /// The best position when trading 2 => 1.
+    #[prost(message, optional, tag = "2")]
+    pub best_2_to_1_position: ::core::option::Option<Position>,
+    /// An approximation of the effective price when trading 1 => 2.
+    #[prost(double, tag = "3")]
+    pub approx_effective_price_1_to_2: f64,
+    /// An approximation of the effective price when trading 2 => 1.
+    #[prost(double, tag = "4")]
+    pub approx_effective_price_2_to_1: f64,
+}
+impl ::prost::Name for SpreadResponse {
+    const NAME: &'static str = "SpreadResponse";
+    const PACKAGE: &'static str = "penumbra.core.component.dex.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.core.component.dex.v1.{}", Self::NAME)
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PausedTradingPairsRequest {}
+impl ::prost::Name for PausedTradingPairsRequest {
+    const NAME: &'static str = "PausedTradingPairsRequest";
+    const PACKAGE: &'static str = "penumbra.core.component.dex.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.core.component.dex.v1.{}", Self::NAME)
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PausedTradingPairsResponse {
+    /// The trading pairs on which trading is currently paused.
+    #[prost(message, repeated, tag = "1")]
+    pub trading_pairs: ::prost::alloc::vec::Vec<TradingPair>,
+}
+impl ::prost::Name for PausedTradingPairsResponse {
+    const NAME: &'static str = "PausedTradingPairsResponse";
+    const PACKAGE: &'static str = "penumbra.core.component.dex.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.core.component.dex.v1.{}", Self::NAME)
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CircuitBreakerStatusRequest {}
+impl ::prost::Name for CircuitBreakerStatusRequest {
+    const NAME: &'static str = "CircuitBreakerStatusRequest";
+    const PACKAGE: &'static str = "penumbra.core.component.dex.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.core.component.dex.v1.{}", Self::NAME)
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CircuitBreakerStatusResponse {
+    #[prost(message, optional, tag = "1")]
+    pub status: ::core::option::Option<CircuitBreakerStatus>,
+}
+impl ::prost::Name for CircuitBreakerStatusResponse {
+    const NAME: &'static str = "CircuitBreakerStatusResponse";
+    const PACKAGE: &'static str = "penumbra.core.component.dex.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.core.component.dex.v1.{}", Self::NAME)
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ValueCircuitBreakerBreachRequest {}
+impl ::prost::Name for ValueCircuitBreakerBreachRequest {
+    const NAME: &'static str = "ValueCircuitBreakerBreachRequest";
+    const PACKAGE: &'static str = "penumbra.core.component.dex.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.core.component.dex.v1.{}", Self::NAME)
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ValueCircuitBreakerBreachResponse {
+    /// The breach, if this node has halted on a breach of the value circuit breaker.
+    #[prost(message, optional, tag = "1")]
+    pub breach: ::core::option::Option<ValueCircuitBreakerBreach>,
+}
+impl ::prost::Name for ValueCircuitBreakerBreachResponse {
+    const NAME: &'static str = "ValueCircuitBreakerBreachResponse";
+    const PACKAGE: &'static str = "penumbra.core.component.dex.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.core.component.dex.v1.{}", Self::NAME)
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SimulateTradeRequest {
+    #[prost(message, optional, tag = "1")]
+    pub input: ::core::option::Option<super::super::super::asset::v1::Value>,
+    #[prost(message, optional, tag = "2")]
+    pub output: ::core::option::Option<super::super::super::asset::v1::AssetId>,
+    #[prost(message, optional, tag = "3")]
+    pub routing: ::core::option::Option<simulate_trade_request::Routing>,
+}
+/// Nested message and enum types in `SimulateTradeRequest`.
 pub mod simulate_trade_request {
     #[allow(clippy::derive_partial_eq_without_eq)]
     #[derive(Clone, PartialEq, ::prost::Message)]
@@ -1407,6 +2348,55 @@ impl ::prost::Name for EventPositionExecution {
         ::prost::alloc::format!("penumbra.core.component.dex.v1.{}", Self::NAME)
     }
 }
+/// Indicates that a share of the trading fees paid to a position was accrued
+/// by the protocol, and deposited into the community pool.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct EventProtocolFeeAccrual {
+    /// The ID of the position whose trading fees were shared.
+    #[prost(message, optional, tag = "1")]
+    pub position_id: ::core::option::Option<PositionId>,
+    /// The protocol's share of the trading fees, deposited into the community pool.
+    #[prost(message, optional, tag = "2")]
+    pub fee: ::core::option::Option<super::super::super::asset::v1::Value>,
+}
+impl ::prost::Name for EventProtocolFeeAccrual {
+    const NAME: &'static str = "EventProtocolFeeAccrual";
+    const PACKAGE: &'static str = "penumbra.core.component.dex.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.core.component.dex.v1.{}", Self::NAME)
+    }
+}
+/// Indicates that governance paused trading on a trading pair.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct EventTradingPairPaused {
+    /// The trading pair on which trading was paused.
+    #[prost(message, optional, tag = "1")]
+    pub trading_pair: ::core::option::Option<TradingPair>,
+}
+impl ::prost::Name for EventTradingPairPaused {
+    const NAME: &'static str = "EventTradingPairPaused";
+    const PACKAGE: &'static str = "penumbra.core.component.dex.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.core.component.dex.v1.{}", Self::NAME)
+    }
+}
+/// Indicates that governance resumed trading on a previously paused trading pair.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct EventTradingPairResumed {
+    /// The trading pair on which trading was resumed.
+    #[prost(message, optional, tag = "1")]
+    pub trading_pair: ::core::option::Option<TradingPair>,
+}
+impl ::prost::Name for EventTradingPairResumed {
+    const NAME: &'static str = "EventTradingPairResumed";
+    const PACKAGE: &'static str = "penumbra.core.component.dex.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.core.component.dex.v1.{}", Self::NAME)
+    }
+}
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct EventBatchSwap {
@@ -1444,41 +2434,192 @@ impl ::prost::Name for EventArbExecution {
         ::prost::alloc::format!("penumbra.core.component.dex.v1.{}", Self::NAME)
     }
 }
-/// Generated client implementations.
-#[cfg(feature = "rpc")]
-pub mod query_service_client {
-    #![allow(unused_variables, dead_code, missing_docs, clippy::let_unit_value)]
-    use tonic::codegen::*;
-    use tonic::codegen::http::Uri;
-    /// Query operations for the DEX component.
-    #[derive(Debug, Clone)]
-    pub struct QueryServiceClient<T> {
-        inner: tonic::client::Grpc<T>,
+/// Indicates that a gradual Dutch auction was scheduled.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct EventDutchAuctionScheduled {
+    /// The ID of the scheduled auction.
+    #[prost(message, optional, tag = "1")]
+    pub auction_id: ::core::option::Option<AuctionId>,
+    /// The description of the auction.
+    #[prost(message, optional, tag = "2")]
+    pub description: ::core::option::Option<DutchAuctionDescription>,
+}
+impl ::prost::Name for EventDutchAuctionScheduled {
+    const NAME: &'static str = "EventDutchAuctionScheduled";
+    const PACKAGE: &'static str = "penumbra.core.component.dex.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.core.component.dex.v1.{}", Self::NAME)
     }
-    impl QueryServiceClient<tonic::transport::Channel> {
-        /// Attempt to create a new client by connecting to a given endpoint.
-        pub async fn connect<D>(dst: D) -> Result<Self, tonic::transport::Error>
-        where
-            D: TryInto<tonic::transport::Endpoint>,
-            D::Error: Into<StdError>,
-        {
-            let conn = tonic::transport::Endpoint::new(dst)?.connect().await?;
-            Ok(Self::new(conn))
-        }
+}
+/// Indicates that a gradual Dutch auction sold some of its input.
+///
+/// This is emitted at a step of the auction, once for the input sold against
+/// the liquidity on the DEX, and by the auction's position since the last step.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct EventDutchAuctionExecution {
+    /// The ID of the auction.
+    #[prost(message, optional, tag = "1")]
+    pub auction_id: ::core::option::Option<AuctionId>,
+    /// The height at which the execution occurred.
+    #[prost(uint64, tag = "2")]
+    pub height: u64,
+    /// The record of execution against the liquidity on the DEX, if any input
+    /// was sold against it.
+    #[prost(message, optional, tag = "3")]
+    pub swap_execution: ::core::option::Option<SwapExecution>,
+    /// The state of the auction after the execution.
+    #[prost(message, optional, tag = "4")]
+    pub state: ::core::option::Option<DutchAuctionState>,
+    /// The amount of input sold by the auction's previous position.
+    #[prost(message, optional, tag = "5")]
+    pub position_input_sold: ::core::option::Option<
+        super::super::super::num::v1::Amount,
+    >,
+    /// The amount of output received by the auction's previous position.
+    #[prost(message, optional, tag = "6")]
+    pub position_output_received: ::core::option::Option<
+        super::super::super::num::v1::Amount,
+    >,
+}
+impl ::prost::Name for EventDutchAuctionExecution {
+    const NAME: &'static str = "EventDutchAuctionExecution";
+    const PACKAGE: &'static str = "penumbra.core.component.dex.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.core.component.dex.v1.{}", Self::NAME)
     }
-    impl<T> QueryServiceClient<T>
-    where
-        T: tonic::client::GrpcService<tonic::body::BoxBody>,
-        T::Error: Into<StdError>,
-        T::ResponseBody: Body<Data = Bytes> + Send + 'static,
-        <T::ResponseBody as Body>::Error: Into<StdError> + Send,
-    {
-        pub fn new(inner: T) -> Self {
-            let inner = tonic::client::Grpc::new(inner);
-            Self { inner }
-        }
-        pub fn with_origin(inner: T, origin: Uri) -> Self {
-            let inner = tonic::client::Grpc::with_origin(inner, origin);
+}
+/// Indicates that a gradual Dutch auction stopped trading.
+///
+/// This is emitted when an auction reaches its end height or sells all of its
+/// input, in which case the state's sequence number is 0, and again when the
+/// auction is ended by its owner, in which case the sequence number is 1.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct EventDutchAuctionEnded {
+    /// The ID of the ended auction.
+    #[prost(message, optional, tag = "1")]
+    pub auction_id: ::core::option::Option<AuctionId>,
+    /// The state of the auction when it ended.
+    #[prost(message, optional, tag = "2")]
+    pub state: ::core::option::Option<DutchAuctionState>,
+}
+impl ::prost::Name for EventDutchAuctionEnded {
+    const NAME: &'static str = "EventDutchAuctionEnded";
+    const PACKAGE: &'static str = "penumbra.core.component.dex.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.core.component.dex.v1.{}", Self::NAME)
+    }
+}
+/// Indicates that the reserves of a gradual Dutch auction were withdrawn.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct EventDutchAuctionWithdrawn {
+    /// The ID of the auction.
+    #[prost(message, optional, tag = "1")]
+    pub auction_id: ::core::option::Option<AuctionId>,
+    /// The state of the auction before the withdrawal.
+    #[prost(message, optional, tag = "2")]
+    pub state: ::core::option::Option<DutchAuctionState>,
+}
+impl ::prost::Name for EventDutchAuctionWithdrawn {
+    const NAME: &'static str = "EventDutchAuctionWithdrawn";
+    const PACKAGE: &'static str = "penumbra.core.component.dex.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.core.component.dex.v1.{}", Self::NAME)
+    }
+}
+/// Dex component configuration data.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct DexParameters {
+    /// The fee tiers, in basis points, that new positions may use.
+    ///
+    /// If empty, any fee up to the maximum is allowed.
+    #[prost(uint32, repeated, tag = "1")]
+    pub fee_tiers: ::prost::alloc::vec::Vec<u32>,
+    /// The share of each position's trading fees accrued by the protocol and
+    /// deposited into the community pool, in basis points.
+    #[prost(uint32, tag = "2")]
+    pub protocol_fee_share_bps: u32,
+    /// The trading pairs on which trading is paused.
+    ///
+    /// Swaps and new positions on a paused pair are rejected, and routing avoids
+    /// it, but existing positions can still be closed and withdrawn.
+    #[prost(message, repeated, tag = "3")]
+    pub paused_trading_pairs: ::prost::alloc::vec::Vec<TradingPair>,
+    /// The number of blocks for which nodes keep the trace of each batch swap
+    /// execution, for the `SwapExecutionTrace` query.
+    ///
+    /// If zero, traces are not kept.
+    #[prost(uint64, tag = "4")]
+    pub execution_trace_retention_blocks: u64,
+    /// How much shallower than the best route found so far to an asset a hop into
+    /// it must be to be pruned during route search, in basis points of the
+    /// liquidity of the last hop of that route.
+    ///
+    /// If zero, only hops with no liquidity at all are pruned.
+    #[prost(uint32, tag = "5")]
+    pub route_pruning_epsilon_bps: u32,
+}
+impl ::prost::Name for DexParameters {
+    const NAME: &'static str = "DexParameters";
+    const PACKAGE: &'static str = "penumbra.core.component.dex.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.core.component.dex.v1.{}", Self::NAME)
+    }
+}
+/// Genesis data for the dex component.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GenesisContent {
+    /// The initial parameters for the dex component.
+    #[prost(message, optional, tag = "1")]
+    pub dex_params: ::core::option::Option<DexParameters>,
+}
+impl ::prost::Name for GenesisContent {
+    const NAME: &'static str = "GenesisContent";
+    const PACKAGE: &'static str = "penumbra.core.component.dex.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.core.component.dex.v1.{}", Self::NAME)
+    }
+}
+/// Generated client implementations.
+#[cfg(feature = "rpc")]
+pub mod query_service_client {
+    #![allow(unused_variables, dead_code, missing_docs, clippy::let_unit_value)]
+    use tonic::codegen::*;
+    use tonic::codegen::http::Uri;
+    /// Query operations for the DEX component.
+    #[derive(Debug, Clone)]
+    pub struct QueryServiceClient<T> {
+        inner: tonic::client::Grpc<T>,
+    }
+    impl QueryServiceClient<tonic::transport::Channel> {
+        /// Attempt to create a new client by connecting to a given endpoint.
+        pub async fn connect<D>(dst: D) -> Result<Self, tonic::transport::Error>
+        where
+            D: TryInto<tonic::transport::Endpoint>,
+            D::Error: Into<StdError>,
+        {
+            let conn = tonic::transport::Endpoint::new(dst)?.connect().await?;
+            Ok(Self::new(conn))
+        }
+    }
+    impl<T> QueryServiceClient<T>
+    where
+        T: tonic::client::GrpcService<tonic::body::BoxBody>,
+        T::Error: Into<StdError>,
+        T::ResponseBody: Body<Data = Bytes> + Send + 'static,
+        <T::ResponseBody as Body>::Error: Into<StdError> + Send,
+    {
+        pub fn new(inner: T) -> Self {
+            let inner = tonic::client::Grpc::new(inner);
+            Self { inner }
+        }
+        pub fn with_origin(inner: T, origin: Uri) -> Self {
+            let inner = tonic::client::Grpc::with_origin(inner, origin);
             Self { inner }
         }
         pub fn with_interceptor<F>(
@@ -1655,6 +2796,42 @@ pub mod query_service_client {
                 );
             self.inner.server_streaming(req, path, codec).await
         }
+        /// Stream the fills of the batch swap executed in a given block in one
+        /// direction of a trading pair, including the positions filled against at
+        /// every hop of every route.
+        ///
+        /// Nodes only keep these traces for the number of blocks set by the
+        /// `execution_trace_retention_blocks` dex parameter.
+        pub async fn swap_execution_trace(
+            &mut self,
+            request: impl tonic::IntoRequest<super::SwapExecutionTraceRequest>,
+        ) -> std::result::Result<
+            tonic::Response<tonic::codec::Streaming<super::SwapExecutionTraceResponse>>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/penumbra.core.component.dex.v1.QueryService/SwapExecutionTrace",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "penumbra.core.component.dex.v1.QueryService",
+                        "SwapExecutionTrace",
+                    ),
+                );
+            self.inner.server_streaming(req, path, codec).await
+        }
         /// Stream all arbitrage executions over a range of heights, optionally subscribing to future executions.
         pub async fn arb_executions(
             &mut self,
@@ -1816,14 +2993,15 @@ pub mod query_service_client {
                 );
             self.inner.server_streaming(req, path, codec).await
         }
-        /// Get the current (direct) spread on a trading pair.
-        ///
-        /// This method doesn't do simulation, so actually executing might result in a
-        /// better price (if the chain takes a different route to the target asset).
-        pub async fn spread(
+        /// Query the history of a liquidity position, one entry for each block in
+        /// which it changed, from when it was opened until it was last withdrawn.
+        pub async fn position_history(
             &mut self,
-            request: impl tonic::IntoRequest<super::SpreadRequest>,
-        ) -> std::result::Result<tonic::Response<super::SpreadResponse>, tonic::Status> {
+            request: impl tonic::IntoRequest<super::PositionHistoryRequest>,
+        ) -> std::result::Result<
+            tonic::Response<tonic::codec::Streaming<super::PositionHistoryResponse>>,
+            tonic::Status,
+        > {
             self.inner
                 .ready()
                 .await
@@ -1835,117 +3013,25 @@ pub mod query_service_client {
                 })?;
             let codec = tonic::codec::ProstCodec::default();
             let path = http::uri::PathAndQuery::from_static(
-                "/penumbra.core.component.dex.v1.QueryService/Spread",
+                "/penumbra.core.component.dex.v1.QueryService/PositionHistory",
             );
             let mut req = request.into_request();
             req.extensions_mut()
                 .insert(
                     GrpcMethod::new(
                         "penumbra.core.component.dex.v1.QueryService",
-                        "Spread",
+                        "PositionHistory",
                     ),
                 );
-            self.inner.unary(req, path, codec).await
-        }
-    }
-}
-/// Generated client implementations.
-#[cfg(feature = "rpc")]
-pub mod simulation_service_client {
-    #![allow(unused_variables, dead_code, missing_docs, clippy::let_unit_value)]
-    use tonic::codegen::*;
-    use tonic::codegen::http::Uri;
-    /// Simulation for the DEX component.
-    ///
-    /// This is a separate service from the QueryService because it's not just a
-    /// simple read query from the state. Thus it poses greater DoS risks, and node
-    /// operators may want to enable it separately.
-    #[derive(Debug, Clone)]
-    pub struct SimulationServiceClient<T> {
-        inner: tonic::client::Grpc<T>,
-    }
-    impl SimulationServiceClient<tonic::transport::Channel> {
-        /// Attempt to create a new client by connecting to a given endpoint.
-        pub async fn connect<D>(dst: D) -> Result<Self, tonic::transport::Error>
-        where
-            D: TryInto<tonic::transport::Endpoint>,
-            D::Error: Into<StdError>,
-        {
-            let conn = tonic::transport::Endpoint::new(dst)?.connect().await?;
-            Ok(Self::new(conn))
-        }
-    }
-    impl<T> SimulationServiceClient<T>
-    where
-        T: tonic::client::GrpcService<tonic::body::BoxBody>,
-        T::Error: Into<StdError>,
-        T::ResponseBody: Body<Data = Bytes> + Send + 'static,
-        <T::ResponseBody as Body>::Error: Into<StdError> + Send,
-    {
-        pub fn new(inner: T) -> Self {
-            let inner = tonic::client::Grpc::new(inner);
-            Self { inner }
-        }
-        pub fn with_origin(inner: T, origin: Uri) -> Self {
-            let inner = tonic::client::Grpc::with_origin(inner, origin);
-            Self { inner }
-        }
-        pub fn with_interceptor<F>(
-            inner: T,
-            interceptor: F,
-        ) -> SimulationServiceClient<InterceptedService<T, F>>
-        where
-            F: tonic::service::Interceptor,
-            T::ResponseBody: Default,
-            T: tonic::codegen::Service<
-                http::Request<tonic::body::BoxBody>,
-                Response = http::Response<
-                    <T as tonic::client::GrpcService<tonic::body::BoxBody>>::ResponseBody,
-                >,
-            >,
-            <T as tonic::codegen::Service<
-                http::Request<tonic::body::BoxBody>,
-            >>::Error: Into<StdError> + Send + Sync,
-        {
-            SimulationServiceClient::new(InterceptedService::new(inner, interceptor))
-        }
-        /// Compress requests with the given encoding.
-        ///
-        /// This requires the server to support it otherwise it might respond with an
-        /// error.
-        #[must_use]
-        pub fn send_compressed(mut self, encoding: CompressionEncoding) -> Self {
-            self.inner = self.inner.send_compressed(encoding);
-            self
-        }
-        /// Enable decompressing responses.
-        #[must_use]
-        pub fn accept_compressed(mut self, encoding: CompressionEncoding) -> Self {
-            self.inner = self.inner.accept_compressed(encoding);
-            self
-        }
-        /// Limits the maximum size of a decoded message.
-        ///
-        /// Default: `4MB`
-        #[must_use]
-        pub fn max_decoding_message_size(mut self, limit: usize) -> Self {
-            self.inner = self.inner.max_decoding_message_size(limit);
-            self
-        }
-        /// Limits the maximum size of an encoded message.
-        ///
-        /// Default: `usize::MAX`
-        #[must_use]
-        pub fn max_encoding_message_size(mut self, limit: usize) -> Self {
-            self.inner = self.inner.max_encoding_message_size(limit);
-            self
+            self.inner.server_streaming(req, path, codec).await
         }
-        /// Simulate routing and trade execution.
-        pub async fn simulate_trade(
+        /// Query the state and reserves of a liquidity position as of the end of the
+        /// block at a given height.
+        pub async fn position_at_height(
             &mut self,
-            request: impl tonic::IntoRequest<super::SimulateTradeRequest>,
+            request: impl tonic::IntoRequest<super::PositionAtHeightRequest>,
         ) -> std::result::Result<
-            tonic::Response<super::SimulateTradeResponse>,
+            tonic::Response<super::PositionAtHeightResponse>,
             tonic::Status,
         > {
             self.inner
@@ -1959,193 +3045,315 @@ pub mod simulation_service_client {
                 })?;
             let codec = tonic::codec::ProstCodec::default();
             let path = http::uri::PathAndQuery::from_static(
-                "/penumbra.core.component.dex.v1.SimulationService/SimulateTrade",
+                "/penumbra.core.component.dex.v1.QueryService/PositionAtHeight",
             );
             let mut req = request.into_request();
             req.extensions_mut()
                 .insert(
                     GrpcMethod::new(
-                        "penumbra.core.component.dex.v1.SimulationService",
-                        "SimulateTrade",
+                        "penumbra.core.component.dex.v1.QueryService",
+                        "PositionAtHeight",
                     ),
                 );
             self.inner.unary(req, path, codec).await
         }
-    }
-}
-/// Generated server implementations.
-#[cfg(feature = "rpc")]
-pub mod query_service_server {
-    #![allow(unused_variables, dead_code, missing_docs, clippy::let_unit_value)]
-    use tonic::codegen::*;
-    /// Generated trait containing gRPC methods that should be implemented for use with QueryServiceServer.
-    #[async_trait]
-    pub trait QueryService: Send + Sync + 'static {
-        /// Get the batch clearing prices for a specific block height and trading pair.
-        async fn batch_swap_output_data(
-            &self,
-            request: tonic::Request<super::BatchSwapOutputDataRequest>,
-        ) -> std::result::Result<
-            tonic::Response<super::BatchSwapOutputDataResponse>,
-            tonic::Status,
-        >;
-        /// Get the precise swap execution used for a specific batch swap.
-        async fn swap_execution(
-            &self,
-            request: tonic::Request<super::SwapExecutionRequest>,
-        ) -> std::result::Result<
-            tonic::Response<super::SwapExecutionResponse>,
-            tonic::Status,
-        >;
-        /// Get the precise execution used to perform on-chain arbitrage.
-        async fn arb_execution(
-            &self,
-            request: tonic::Request<super::ArbExecutionRequest>,
+        /// Query the metadata recorded when a liquidity position was opened.
+        pub async fn position_metadata(
+            &mut self,
+            request: impl tonic::IntoRequest<super::PositionMetadataRequest>,
         ) -> std::result::Result<
-            tonic::Response<super::ArbExecutionResponse>,
+            tonic::Response<super::PositionMetadataResponse>,
             tonic::Status,
-        >;
-        /// Server streaming response type for the SwapExecutions method.
-        type SwapExecutionsStream: tonic::codegen::tokio_stream::Stream<
-                Item = std::result::Result<super::SwapExecutionsResponse, tonic::Status>,
-            >
-            + Send
-            + 'static;
-        /// Stream all swap executions over a range of heights, optionally subscribing to future executions.
-        async fn swap_executions(
-            &self,
-            request: tonic::Request<super::SwapExecutionsRequest>,
-        ) -> std::result::Result<
-            tonic::Response<Self::SwapExecutionsStream>,
-            tonic::Status,
-        >;
-        /// Server streaming response type for the ArbExecutions method.
-        type ArbExecutionsStream: tonic::codegen::tokio_stream::Stream<
-                Item = std::result::Result<super::ArbExecutionsResponse, tonic::Status>,
-            >
-            + Send
-            + 'static;
-        /// Stream all arbitrage executions over a range of heights, optionally subscribing to future executions.
-        async fn arb_executions(
-            &self,
-            request: tonic::Request<super::ArbExecutionsRequest>,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/penumbra.core.component.dex.v1.QueryService/PositionMetadata",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "penumbra.core.component.dex.v1.QueryService",
+                        "PositionMetadata",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// Summarize the histories of a set of liquidity positions into aggregate
+        /// metrics for each trading pair they were opened on.
+        pub async fn liquidity_position_analytics(
+            &mut self,
+            request: impl tonic::IntoRequest<super::LiquidityPositionAnalyticsRequest>,
         ) -> std::result::Result<
-            tonic::Response<Self::ArbExecutionsStream>,
+            tonic::Response<super::LiquidityPositionAnalyticsResponse>,
             tonic::Status,
-        >;
-        /// Server streaming response type for the LiquidityPositions method.
-        type LiquidityPositionsStream: tonic::codegen::tokio_stream::Stream<
-                Item = std::result::Result<
-                    super::LiquidityPositionsResponse,
-                    tonic::Status,
-                >,
-            >
-            + Send
-            + 'static;
-        /// Query all liquidity positions on the DEX.
-        async fn liquidity_positions(
-            &self,
-            request: tonic::Request<super::LiquidityPositionsRequest>,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/penumbra.core.component.dex.v1.QueryService/LiquidityPositionAnalytics",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "penumbra.core.component.dex.v1.QueryService",
+                        "LiquidityPositionAnalytics",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// Query a gradual Dutch auction by ID.
+        pub async fn dutch_auction_by_id(
+            &mut self,
+            request: impl tonic::IntoRequest<super::DutchAuctionByIdRequest>,
         ) -> std::result::Result<
-            tonic::Response<Self::LiquidityPositionsStream>,
+            tonic::Response<super::DutchAuctionByIdResponse>,
             tonic::Status,
-        >;
-        /// Query liquidity positions by ID.
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/penumbra.core.component.dex.v1.QueryService/DutchAuctionById",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "penumbra.core.component.dex.v1.QueryService",
+                        "DutchAuctionById",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// Get the current (direct) spread on a trading pair.
         ///
-        /// To get multiple positions, use `LiquidityPositionsById`.
-        async fn liquidity_position_by_id(
-            &self,
-            request: tonic::Request<super::LiquidityPositionByIdRequest>,
+        /// This method doesn't do simulation, so actually executing might result in a
+        /// better price (if the chain takes a different route to the target asset).
+        pub async fn spread(
+            &mut self,
+            request: impl tonic::IntoRequest<super::SpreadRequest>,
+        ) -> std::result::Result<tonic::Response<super::SpreadResponse>, tonic::Status> {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/penumbra.core.component.dex.v1.QueryService/Spread",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "penumbra.core.component.dex.v1.QueryService",
+                        "Spread",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// Get the trading pairs on which trading is currently paused by governance.
+        pub async fn paused_trading_pairs(
+            &mut self,
+            request: impl tonic::IntoRequest<super::PausedTradingPairsRequest>,
         ) -> std::result::Result<
-            tonic::Response<super::LiquidityPositionByIdResponse>,
+            tonic::Response<super::PausedTradingPairsResponse>,
             tonic::Status,
-        >;
-        /// Server streaming response type for the LiquidityPositionsById method.
-        type LiquidityPositionsByIdStream: tonic::codegen::tokio_stream::Stream<
-                Item = std::result::Result<
-                    super::LiquidityPositionsByIdResponse,
-                    tonic::Status,
-                >,
-            >
-            + Send
-            + 'static;
-        /// Query multiple liquidity positions by ID.
-        async fn liquidity_positions_by_id(
-            &self,
-            request: tonic::Request<super::LiquidityPositionsByIdRequest>,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/penumbra.core.component.dex.v1.QueryService/PausedTradingPairs",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "penumbra.core.component.dex.v1.QueryService",
+                        "PausedTradingPairs",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// Get the status of the execution circuit breaker, which limits the work done routing trades.
+        pub async fn circuit_breaker_status(
+            &mut self,
+            request: impl tonic::IntoRequest<super::CircuitBreakerStatusRequest>,
         ) -> std::result::Result<
-            tonic::Response<Self::LiquidityPositionsByIdStream>,
+            tonic::Response<super::CircuitBreakerStatusResponse>,
             tonic::Status,
-        >;
-        /// Server streaming response type for the LiquidityPositionsByPrice method.
-        type LiquidityPositionsByPriceStream: tonic::codegen::tokio_stream::Stream<
-                Item = std::result::Result<
-                    super::LiquidityPositionsByPriceResponse,
-                    tonic::Status,
-                >,
-            >
-            + Send
-            + 'static;
-        /// Query liquidity positions on a specific pair, sorted by effective price.
-        async fn liquidity_positions_by_price(
-            &self,
-            request: tonic::Request<super::LiquidityPositionsByPriceRequest>,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/penumbra.core.component.dex.v1.QueryService/CircuitBreakerStatus",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "penumbra.core.component.dex.v1.QueryService",
+                        "CircuitBreakerStatus",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// Get the breach of the value circuit breaker which halted the chain, if any.
+        ///
+        /// This is intended for post-mortem investigation of a halted chain. Since the
+        /// block in which the breach was detected is never committed, the breach is
+        /// recorded by the node which detected it, outside of the chain state.
+        pub async fn value_circuit_breaker_breach(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ValueCircuitBreakerBreachRequest>,
         ) -> std::result::Result<
-            tonic::Response<Self::LiquidityPositionsByPriceStream>,
+            tonic::Response<super::ValueCircuitBreakerBreachResponse>,
             tonic::Status,
-        >;
-        /// Get the current (direct) spread on a trading pair.
-        ///
-        /// This method doesn't do simulation, so actually executing might result in a
-        /// better price (if the chain takes a different route to the target asset).
-        async fn spread(
-            &self,
-            request: tonic::Request<super::SpreadRequest>,
-        ) -> std::result::Result<tonic::Response<super::SpreadResponse>, tonic::Status>;
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/penumbra.core.component.dex.v1.QueryService/ValueCircuitBreakerBreach",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "penumbra.core.component.dex.v1.QueryService",
+                        "ValueCircuitBreakerBreach",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
     }
-    /// Query operations for the DEX component.
-    #[derive(Debug)]
-    pub struct QueryServiceServer<T: QueryService> {
-        inner: _Inner<T>,
-        accept_compression_encodings: EnabledCompressionEncodings,
-        send_compression_encodings: EnabledCompressionEncodings,
-        max_decoding_message_size: Option<usize>,
-        max_encoding_message_size: Option<usize>,
+}
+/// Generated client implementations.
+#[cfg(feature = "rpc")]
+pub mod simulation_service_client {
+    #![allow(unused_variables, dead_code, missing_docs, clippy::let_unit_value)]
+    use tonic::codegen::*;
+    use tonic::codegen::http::Uri;
+    /// Simulation for the DEX component.
+    ///
+    /// This is a separate service from the QueryService because it's not just a
+    /// simple read query from the state. Thus it poses greater DoS risks, and node
+    /// operators may want to enable it separately.
+    #[derive(Debug, Clone)]
+    pub struct SimulationServiceClient<T> {
+        inner: tonic::client::Grpc<T>,
     }
-    struct _Inner<T>(Arc<T>);
-    impl<T: QueryService> QueryServiceServer<T> {
+    impl SimulationServiceClient<tonic::transport::Channel> {
+        /// Attempt to create a new client by connecting to a given endpoint.
+        pub async fn connect<D>(dst: D) -> Result<Self, tonic::transport::Error>
+        where
+            D: TryInto<tonic::transport::Endpoint>,
+            D::Error: Into<StdError>,
+        {
+            let conn = tonic::transport::Endpoint::new(dst)?.connect().await?;
+            Ok(Self::new(conn))
+        }
+    }
+    impl<T> SimulationServiceClient<T>
+    where
+        T: tonic::client::GrpcService<tonic::body::BoxBody>,
+        T::Error: Into<StdError>,
+        T::ResponseBody: Body<Data = Bytes> + Send + 'static,
+        <T::ResponseBody as Body>::Error: Into<StdError> + Send,
+    {
         pub fn new(inner: T) -> Self {
-            Self::from_arc(Arc::new(inner))
+            let inner = tonic::client::Grpc::new(inner);
+            Self { inner }
         }
-        pub fn from_arc(inner: Arc<T>) -> Self {
-            let inner = _Inner(inner);
-            Self {
-                inner,
-                accept_compression_encodings: Default::default(),
-                send_compression_encodings: Default::default(),
-                max_decoding_message_size: None,
-                max_encoding_message_size: None,
-            }
+        pub fn with_origin(inner: T, origin: Uri) -> Self {
+            let inner = tonic::client::Grpc::with_origin(inner, origin);
+            Self { inner }
         }
         pub fn with_interceptor<F>(
             inner: T,
             interceptor: F,
-        ) -> InterceptedService<Self, F>
+        ) -> SimulationServiceClient<InterceptedService<T, F>>
         where
             F: tonic::service::Interceptor,
+            T::ResponseBody: Default,
+            T: tonic::codegen::Service<
+                http::Request<tonic::body::BoxBody>,
+                Response = http::Response<
+                    <T as tonic::client::GrpcService<tonic::body::BoxBody>>::ResponseBody,
+                >,
+            >,
+            <T as tonic::codegen::Service<
+                http::Request<tonic::body::BoxBody>,
+            >>::Error: Into<StdError> + Send + Sync,
         {
-            InterceptedService::new(Self::new(inner), interceptor)
+            SimulationServiceClient::new(InterceptedService::new(inner, interceptor))
         }
-        /// Enable decompressing requests with the given encoding.
+        /// Compress requests with the given encoding.
+        ///
+        /// This requires the server to support it otherwise it might respond with an
+        /// error.
         #[must_use]
-        pub fn accept_compressed(mut self, encoding: CompressionEncoding) -> Self {
-            self.accept_compression_encodings.enable(encoding);
+        pub fn send_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.inner = self.inner.send_compressed(encoding);
             self
         }
-        /// Compress responses with the given encoding, if the client supports it.
+        /// Enable decompressing responses.
         #[must_use]
-        pub fn send_compressed(mut self, encoding: CompressionEncoding) -> Self {
-            self.send_compression_encodings.enable(encoding);
+        pub fn accept_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.inner = self.inner.accept_compressed(encoding);
             self
         }
         /// Limits the maximum size of a decoded message.
@@ -2153,7 +3361,7 @@ pub mod query_service_server {
         /// Default: `4MB`
         #[must_use]
         pub fn max_decoding_message_size(mut self, limit: usize) -> Self {
-            self.max_decoding_message_size = Some(limit);
+            self.inner = self.inner.max_decoding_message_size(limit);
             self
         }
         /// Limits the maximum size of an encoded message.
@@ -2161,47 +3369,704 @@ pub mod query_service_server {
         /// Default: `usize::MAX`
         #[must_use]
         pub fn max_encoding_message_size(mut self, limit: usize) -> Self {
-            self.max_encoding_message_size = Some(limit);
+            self.inner = self.inner.max_encoding_message_size(limit);
             self
         }
-    }
-    impl<T, B> tonic::codegen::Service<http::Request<B>> for QueryServiceServer<T>
-    where
-        T: QueryService,
-        B: Body + Send + 'static,
-        B::Error: Into<StdError> + Send + 'static,
-    {
-        type Response = http::Response<tonic::body::BoxBody>;
-        type Error = std::convert::Infallible;
-        type Future = BoxFuture<Self::Response, Self::Error>;
-        fn poll_ready(
+        /// Simulate routing and trade execution.
+        pub async fn simulate_trade(
             &mut self,
-            _cx: &mut Context<'_>,
-        ) -> Poll<std::result::Result<(), Self::Error>> {
-            Poll::Ready(Ok(()))
+            request: impl tonic::IntoRequest<super::SimulateTradeRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::SimulateTradeResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/penumbra.core.component.dex.v1.SimulationService/SimulateTrade",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "penumbra.core.component.dex.v1.SimulationService",
+                        "SimulateTrade",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
         }
-        fn call(&mut self, req: http::Request<B>) -> Self::Future {
-            let inner = self.inner.clone();
-            match req.uri().path() {
-                "/penumbra.core.component.dex.v1.QueryService/BatchSwapOutputData" => {
+    }
+}
+/// Generated server implementations.
+#[cfg(feature = "rpc")]
+pub mod query_service_server {
+    #![allow(unused_variables, dead_code, missing_docs, clippy::let_unit_value)]
+    use tonic::codegen::*;
+    /// Generated trait containing gRPC methods that should be implemented for use with QueryServiceServer.
+    #[async_trait]
+    pub trait QueryService: Send + Sync + 'static {
+        /// Get the batch clearing prices for a specific block height and trading pair.
+        async fn batch_swap_output_data(
+            &self,
+            request: tonic::Request<super::BatchSwapOutputDataRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::BatchSwapOutputDataResponse>,
+            tonic::Status,
+        >;
+        /// Get the precise swap execution used for a specific batch swap.
+        async fn swap_execution(
+            &self,
+            request: tonic::Request<super::SwapExecutionRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::SwapExecutionResponse>,
+            tonic::Status,
+        >;
+        /// Get the precise execution used to perform on-chain arbitrage.
+        async fn arb_execution(
+            &self,
+            request: tonic::Request<super::ArbExecutionRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ArbExecutionResponse>,
+            tonic::Status,
+        >;
+        /// Server streaming response type for the SwapExecutions method.
+        type SwapExecutionsStream: tonic::codegen::tokio_stream::Stream<
+                Item = std::result::Result<super::SwapExecutionsResponse, tonic::Status>,
+            >
+            + Send
+            + 'static;
+        /// Stream all swap executions over a range of heights, optionally subscribing to future executions.
+        async fn swap_executions(
+            &self,
+            request: tonic::Request<super::SwapExecutionsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<Self::SwapExecutionsStream>,
+            tonic::Status,
+        >;
+        /// Server streaming response type for the SwapExecutionTrace method.
+        type SwapExecutionTraceStream: tonic::codegen::tokio_stream::Stream<
+                Item = std::result::Result<
+                    super::SwapExecutionTraceResponse,
+                    tonic::Status,
+                >,
+            >
+            + Send
+            + 'static;
+        /// Stream the fills of the batch swap executed in a given block in one
+        /// direction of a trading pair, including the positions filled against at
+        /// every hop of every route.
+        ///
+        /// Nodes only keep these traces for the number of blocks set by the
+        /// `execution_trace_retention_blocks` dex parameter.
+        async fn swap_execution_trace(
+            &self,
+            request: tonic::Request<super::SwapExecutionTraceRequest>,
+        ) -> std::result::Result<
+            tonic::Response<Self::SwapExecutionTraceStream>,
+            tonic::Status,
+        >;
+        /// Server streaming response type for the ArbExecutions method.
+        type ArbExecutionsStream: tonic::codegen::tokio_stream::Stream<
+                Item = std::result::Result<super::ArbExecutionsResponse, tonic::Status>,
+            >
+            + Send
+            + 'static;
+        /// Stream all arbitrage executions over a range of heights, optionally subscribing to future executions.
+        async fn arb_executions(
+            &self,
+            request: tonic::Request<super::ArbExecutionsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<Self::ArbExecutionsStream>,
+            tonic::Status,
+        >;
+        /// Server streaming response type for the LiquidityPositions method.
+        type LiquidityPositionsStream: tonic::codegen::tokio_stream::Stream<
+                Item = std::result::Result<
+                    super::LiquidityPositionsResponse,
+                    tonic::Status,
+                >,
+            >
+            + Send
+            + 'static;
+        /// Query all liquidity positions on the DEX.
+        async fn liquidity_positions(
+            &self,
+            request: tonic::Request<super::LiquidityPositionsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<Self::LiquidityPositionsStream>,
+            tonic::Status,
+        >;
+        /// Query liquidity positions by ID.
+        ///
+        /// To get multiple positions, use `LiquidityPositionsById`.
+        async fn liquidity_position_by_id(
+            &self,
+            request: tonic::Request<super::LiquidityPositionByIdRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::LiquidityPositionByIdResponse>,
+            tonic::Status,
+        >;
+        /// Server streaming response type for the LiquidityPositionsById method.
+        type LiquidityPositionsByIdStream: tonic::codegen::tokio_stream::Stream<
+                Item = std::result::Result<
+                    super::LiquidityPositionsByIdResponse,
+                    tonic::Status,
+                >,
+            >
+            + Send
+            + 'static;
+        /// Query multiple liquidity positions by ID.
+        async fn liquidity_positions_by_id(
+            &self,
+            request: tonic::Request<super::LiquidityPositionsByIdRequest>,
+        ) -> std::result::Result<
+            tonic::Response<Self::LiquidityPositionsByIdStream>,
+            tonic::Status,
+        >;
+        /// Server streaming response type for the LiquidityPositionsByPrice method.
+        type LiquidityPositionsByPriceStream: tonic::codegen::tokio_stream::Stream<
+                Item = std::result::Result<
+                    super::LiquidityPositionsByPriceResponse,
+                    tonic::Status,
+                >,
+            >
+            + Send
+            + 'static;
+        /// Query liquidity positions on a specific pair, sorted by effective price.
+        async fn liquidity_positions_by_price(
+            &self,
+            request: tonic::Request<super::LiquidityPositionsByPriceRequest>,
+        ) -> std::result::Result<
+            tonic::Response<Self::LiquidityPositionsByPriceStream>,
+            tonic::Status,
+        >;
+        /// Server streaming response type for the PositionHistory method.
+        type PositionHistoryStream: tonic::codegen::tokio_stream::Stream<
+                Item = std::result::Result<super::PositionHistoryResponse, tonic::Status>,
+            >
+            + Send
+            + 'static;
+        /// Query the history of a liquidity position, one entry for each block in
+        /// which it changed, from when it was opened until it was last withdrawn.
+        async fn position_history(
+            &self,
+            request: tonic::Request<super::PositionHistoryRequest>,
+        ) -> std::result::Result<
+            tonic::Response<Self::PositionHistoryStream>,
+            tonic::Status,
+        >;
+        /// Query the state and reserves of a liquidity position as of the end of the
+        /// block at a given height.
+        async fn position_at_height(
+            &self,
+            request: tonic::Request<super::PositionAtHeightRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::PositionAtHeightResponse>,
+            tonic::Status,
+        >;
+        /// Query the metadata recorded when a liquidity position was opened.
+        async fn position_metadata(
+            &self,
+            request: tonic::Request<super::PositionMetadataRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::PositionMetadataResponse>,
+            tonic::Status,
+        >;
+        /// Summarize the histories of a set of liquidity positions into aggregate
+        /// metrics for each trading pair they were opened on.
+        async fn liquidity_position_analytics(
+            &self,
+            request: tonic::Request<super::LiquidityPositionAnalyticsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::LiquidityPositionAnalyticsResponse>,
+            tonic::Status,
+        >;
+        /// Query a gradual Dutch auction by ID.
+        async fn dutch_auction_by_id(
+            &self,
+            request: tonic::Request<super::DutchAuctionByIdRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::DutchAuctionByIdResponse>,
+            tonic::Status,
+        >;
+        /// Get the current (direct) spread on a trading pair.
+        ///
+        /// This method doesn't do simulation, so actually executing might result in a
+        /// better price (if the chain takes a different route to the target asset).
+        async fn spread(
+            &self,
+            request: tonic::Request<super::SpreadRequest>,
+        ) -> std::result::Result<tonic::Response<super::SpreadResponse>, tonic::Status>;
+        /// Get the trading pairs on which trading is currently paused by governance.
+        async fn paused_trading_pairs(
+            &self,
+            request: tonic::Request<super::PausedTradingPairsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::PausedTradingPairsResponse>,
+            tonic::Status,
+        >;
+        /// Get the status of the execution circuit breaker, which limits the work done routing trades.
+        async fn circuit_breaker_status(
+            &self,
+            request: tonic::Request<super::CircuitBreakerStatusRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::CircuitBreakerStatusResponse>,
+            tonic::Status,
+        >;
+        /// Get the breach of the value circuit breaker which halted the chain, if any.
+        ///
+        /// This is intended for post-mortem investigation of a halted chain. Since the
+        /// block in which the breach was detected is never committed, the breach is
+        /// recorded by the node which detected it, outside of the chain state.
+        async fn value_circuit_breaker_breach(
+            &self,
+            request: tonic::Request<super::ValueCircuitBreakerBreachRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ValueCircuitBreakerBreachResponse>,
+            tonic::Status,
+        >;
+    }
+    /// Query operations for the DEX component.
+    #[derive(Debug)]
+    pub struct QueryServiceServer<T: QueryService> {
+        inner: _Inner<T>,
+        accept_compression_encodings: EnabledCompressionEncodings,
+        send_compression_encodings: EnabledCompressionEncodings,
+        max_decoding_message_size: Option<usize>,
+        max_encoding_message_size: Option<usize>,
+    }
+    struct _Inner<T>(Arc<T>);
+    impl<T: QueryService> QueryServiceServer<T> {
+        pub fn new(inner: T) -> Self {
+            Self::from_arc(Arc::new(inner))
+        }
+        pub fn from_arc(inner: Arc<T>) -> Self {
+            let inner = _Inner(inner);
+            Self {
+                inner,
+                accept_compression_encodings: Default::default(),
+                send_compression_encodings: Default::default(),
+                max_decoding_message_size: None,
+                max_encoding_message_size: None,
+            }
+        }
+        pub fn with_interceptor<F>(
+            inner: T,
+            interceptor: F,
+        ) -> InterceptedService<Self, F>
+        where
+            F: tonic::service::Interceptor,
+        {
+            InterceptedService::new(Self::new(inner), interceptor)
+        }
+        /// Enable decompressing requests with the given encoding.
+        #[must_use]
+        pub fn accept_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.accept_compression_encodings.enable(encoding);
+            self
+        }
+        /// Compress responses with the given encoding, if the client supports it.
+        #[must_use]
+        pub fn send_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.send_compression_encodings.enable(encoding);
+            self
+        }
+        /// Limits the maximum size of a decoded message.
+        ///
+        /// Default: `4MB`
+        #[must_use]
+        pub fn max_decoding_message_size(mut self, limit: usize) -> Self {
+            self.max_decoding_message_size = Some(limit);
+            self
+        }
+        /// Limits the maximum size of an encoded message.
+        ///
+        /// Default: `usize::MAX`
+        #[must_use]
+        pub fn max_encoding_message_size(mut self, limit: usize) -> Self {
+            self.max_encoding_message_size = Some(limit);
+            self
+        }
+    }
+    impl<T, B> tonic::codegen::Service<http::Request<B>> for QueryServiceServer<T>
+    where
+        T: QueryService,
+        B: Body + Send + 'static,
+        B::Error: Into<StdError> + Send + 'static,
+    {
+        type Response = http::Response<tonic::body::BoxBody>;
+        type Error = std::convert::Infallible;
+        type Future = BoxFuture<Self::Response, Self::Error>;
+        fn poll_ready(
+            &mut self,
+            _cx: &mut Context<'_>,
+        ) -> Poll<std::result::Result<(), Self::Error>> {
+            Poll::Ready(Ok(()))
+        }
+        fn call(&mut self, req: http::Request<B>) -> Self::Future {
+            let inner = self.inner.clone();
+            match req.uri().path() {
+                "/penumbra.core.component.dex.v1.QueryService/BatchSwapOutputData" => {
+                    #[allow(non_camel_case_types)]
+                    struct BatchSwapOutputDataSvc<T: QueryService>(pub Arc<T>);
+                    impl<
+                        T: QueryService,
+                    > tonic::server::UnaryService<super::BatchSwapOutputDataRequest>
+                    for BatchSwapOutputDataSvc<T> {
+                        type Response = super::BatchSwapOutputDataResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::BatchSwapOutputDataRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as QueryService>::batch_swap_output_data(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = BatchSwapOutputDataSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/penumbra.core.component.dex.v1.QueryService/SwapExecution" => {
+                    #[allow(non_camel_case_types)]
+                    struct SwapExecutionSvc<T: QueryService>(pub Arc<T>);
+                    impl<
+                        T: QueryService,
+                    > tonic::server::UnaryService<super::SwapExecutionRequest>
+                    for SwapExecutionSvc<T> {
+                        type Response = super::SwapExecutionResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::SwapExecutionRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as QueryService>::swap_execution(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = SwapExecutionSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/penumbra.core.component.dex.v1.QueryService/ArbExecution" => {
+                    #[allow(non_camel_case_types)]
+                    struct ArbExecutionSvc<T: QueryService>(pub Arc<T>);
+                    impl<
+                        T: QueryService,
+                    > tonic::server::UnaryService<super::ArbExecutionRequest>
+                    for ArbExecutionSvc<T> {
+                        type Response = super::ArbExecutionResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::ArbExecutionRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as QueryService>::arb_execution(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = ArbExecutionSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/penumbra.core.component.dex.v1.QueryService/SwapExecutions" => {
+                    #[allow(non_camel_case_types)]
+                    struct SwapExecutionsSvc<T: QueryService>(pub Arc<T>);
+                    impl<
+                        T: QueryService,
+                    > tonic::server::ServerStreamingService<super::SwapExecutionsRequest>
+                    for SwapExecutionsSvc<T> {
+                        type Response = super::SwapExecutionsResponse;
+                        type ResponseStream = T::SwapExecutionsStream;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::ResponseStream>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::SwapExecutionsRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as QueryService>::swap_executions(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = SwapExecutionsSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.server_streaming(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/penumbra.core.component.dex.v1.QueryService/SwapExecutionTrace" => {
+                    #[allow(non_camel_case_types)]
+                    struct SwapExecutionTraceSvc<T: QueryService>(pub Arc<T>);
+                    impl<
+                        T: QueryService,
+                    > tonic::server::ServerStreamingService<
+                        super::SwapExecutionTraceRequest,
+                    > for SwapExecutionTraceSvc<T> {
+                        type Response = super::SwapExecutionTraceResponse;
+                        type ResponseStream = T::SwapExecutionTraceStream;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::ResponseStream>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::SwapExecutionTraceRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as QueryService>::swap_execution_trace(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = SwapExecutionTraceSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.server_streaming(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/penumbra.core.component.dex.v1.QueryService/ArbExecutions" => {
+                    #[allow(non_camel_case_types)]
+                    struct ArbExecutionsSvc<T: QueryService>(pub Arc<T>);
+                    impl<
+                        T: QueryService,
+                    > tonic::server::ServerStreamingService<super::ArbExecutionsRequest>
+                    for ArbExecutionsSvc<T> {
+                        type Response = super::ArbExecutionsResponse;
+                        type ResponseStream = T::ArbExecutionsStream;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::ResponseStream>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::ArbExecutionsRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as QueryService>::arb_executions(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = ArbExecutionsSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.server_streaming(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/penumbra.core.component.dex.v1.QueryService/LiquidityPositions" => {
+                    #[allow(non_camel_case_types)]
+                    struct LiquidityPositionsSvc<T: QueryService>(pub Arc<T>);
+                    impl<
+                        T: QueryService,
+                    > tonic::server::ServerStreamingService<
+                        super::LiquidityPositionsRequest,
+                    > for LiquidityPositionsSvc<T> {
+                        type Response = super::LiquidityPositionsResponse;
+                        type ResponseStream = T::LiquidityPositionsStream;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::ResponseStream>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::LiquidityPositionsRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as QueryService>::liquidity_positions(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = LiquidityPositionsSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.server_streaming(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/penumbra.core.component.dex.v1.QueryService/LiquidityPositionById" => {
                     #[allow(non_camel_case_types)]
-                    struct BatchSwapOutputDataSvc<T: QueryService>(pub Arc<T>);
+                    struct LiquidityPositionByIdSvc<T: QueryService>(pub Arc<T>);
                     impl<
                         T: QueryService,
-                    > tonic::server::UnaryService<super::BatchSwapOutputDataRequest>
-                    for BatchSwapOutputDataSvc<T> {
-                        type Response = super::BatchSwapOutputDataResponse;
+                    > tonic::server::UnaryService<super::LiquidityPositionByIdRequest>
+                    for LiquidityPositionByIdSvc<T> {
+                        type Response = super::LiquidityPositionByIdResponse;
                         type Future = BoxFuture<
                             tonic::Response<Self::Response>,
                             tonic::Status,
                         >;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::BatchSwapOutputDataRequest>,
+                            request: tonic::Request<super::LiquidityPositionByIdRequest>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                <T as QueryService>::batch_swap_output_data(&inner, request)
+                                <T as QueryService>::liquidity_position_by_id(
+                                        &inner,
+                                        request,
+                                    )
                                     .await
                             };
                             Box::pin(fut)
@@ -2214,7 +4079,7 @@ pub mod query_service_server {
                     let inner = self.inner.clone();
                     let fut = async move {
                         let inner = inner.0;
-                        let method = BatchSwapOutputDataSvc(inner);
+                        let method = LiquidityPositionByIdSvc(inner);
                         let codec = tonic::codec::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -2230,25 +4095,31 @@ pub mod query_service_server {
                     };
                     Box::pin(fut)
                 }
-                "/penumbra.core.component.dex.v1.QueryService/SwapExecution" => {
+                "/penumbra.core.component.dex.v1.QueryService/LiquidityPositionsById" => {
                     #[allow(non_camel_case_types)]
-                    struct SwapExecutionSvc<T: QueryService>(pub Arc<T>);
+                    struct LiquidityPositionsByIdSvc<T: QueryService>(pub Arc<T>);
                     impl<
                         T: QueryService,
-                    > tonic::server::UnaryService<super::SwapExecutionRequest>
-                    for SwapExecutionSvc<T> {
-                        type Response = super::SwapExecutionResponse;
+                    > tonic::server::ServerStreamingService<
+                        super::LiquidityPositionsByIdRequest,
+                    > for LiquidityPositionsByIdSvc<T> {
+                        type Response = super::LiquidityPositionsByIdResponse;
+                        type ResponseStream = T::LiquidityPositionsByIdStream;
                         type Future = BoxFuture<
-                            tonic::Response<Self::Response>,
+                            tonic::Response<Self::ResponseStream>,
                             tonic::Status,
                         >;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::SwapExecutionRequest>,
+                            request: tonic::Request<super::LiquidityPositionsByIdRequest>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                <T as QueryService>::swap_execution(&inner, request).await
+                                <T as QueryService>::liquidity_positions_by_id(
+                                        &inner,
+                                        request,
+                                    )
+                                    .await
                             };
                             Box::pin(fut)
                         }
@@ -2260,7 +4131,7 @@ pub mod query_service_server {
                     let inner = self.inner.clone();
                     let fut = async move {
                         let inner = inner.0;
-                        let method = SwapExecutionSvc(inner);
+                        let method = LiquidityPositionsByIdSvc(inner);
                         let codec = tonic::codec::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -2271,30 +4142,38 @@ pub mod query_service_server {
                                 max_decoding_message_size,
                                 max_encoding_message_size,
                             );
-                        let res = grpc.unary(method, req).await;
+                        let res = grpc.server_streaming(method, req).await;
                         Ok(res)
                     };
                     Box::pin(fut)
                 }
-                "/penumbra.core.component.dex.v1.QueryService/ArbExecution" => {
+                "/penumbra.core.component.dex.v1.QueryService/LiquidityPositionsByPrice" => {
                     #[allow(non_camel_case_types)]
-                    struct ArbExecutionSvc<T: QueryService>(pub Arc<T>);
+                    struct LiquidityPositionsByPriceSvc<T: QueryService>(pub Arc<T>);
                     impl<
                         T: QueryService,
-                    > tonic::server::UnaryService<super::ArbExecutionRequest>
-                    for ArbExecutionSvc<T> {
-                        type Response = super::ArbExecutionResponse;
+                    > tonic::server::ServerStreamingService<
+                        super::LiquidityPositionsByPriceRequest,
+                    > for LiquidityPositionsByPriceSvc<T> {
+                        type Response = super::LiquidityPositionsByPriceResponse;
+                        type ResponseStream = T::LiquidityPositionsByPriceStream;
                         type Future = BoxFuture<
-                            tonic::Response<Self::Response>,
+                            tonic::Response<Self::ResponseStream>,
                             tonic::Status,
                         >;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::ArbExecutionRequest>,
+                            request: tonic::Request<
+                                super::LiquidityPositionsByPriceRequest,
+                            >,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                <T as QueryService>::arb_execution(&inner, request).await
+                                <T as QueryService>::liquidity_positions_by_price(
+                                        &inner,
+                                        request,
+                                    )
+                                    .await
                             };
                             Box::pin(fut)
                         }
@@ -2306,7 +4185,7 @@ pub mod query_service_server {
                     let inner = self.inner.clone();
                     let fut = async move {
                         let inner = inner.0;
-                        let method = ArbExecutionSvc(inner);
+                        let method = LiquidityPositionsByPriceSvc(inner);
                         let codec = tonic::codec::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -2317,31 +4196,32 @@ pub mod query_service_server {
                                 max_decoding_message_size,
                                 max_encoding_message_size,
                             );
-                        let res = grpc.unary(method, req).await;
+                        let res = grpc.server_streaming(method, req).await;
                         Ok(res)
                     };
                     Box::pin(fut)
                 }
-                "/penumbra.core.component.dex.v1.QueryService/SwapExecutions" => {
+                "/penumbra.core.component.dex.v1.QueryService/PositionHistory" => {
                     #[allow(non_camel_case_types)]
-                    struct SwapExecutionsSvc<T: QueryService>(pub Arc<T>);
+                    struct PositionHistorySvc<T: QueryService>(pub Arc<T>);
                     impl<
                         T: QueryService,
-                    > tonic::server::ServerStreamingService<super::SwapExecutionsRequest>
-                    for SwapExecutionsSvc<T> {
-                        type Response = super::SwapExecutionsResponse;
-                        type ResponseStream = T::SwapExecutionsStream;
+                    > tonic::server::ServerStreamingService<
+                        super::PositionHistoryRequest,
+                    > for PositionHistorySvc<T> {
+                        type Response = super::PositionHistoryResponse;
+                        type ResponseStream = T::PositionHistoryStream;
                         type Future = BoxFuture<
                             tonic::Response<Self::ResponseStream>,
                             tonic::Status,
                         >;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::SwapExecutionsRequest>,
+                            request: tonic::Request<super::PositionHistoryRequest>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                <T as QueryService>::swap_executions(&inner, request).await
+                                <T as QueryService>::position_history(&inner, request).await
                             };
                             Box::pin(fut)
                         }
@@ -2353,7 +4233,7 @@ pub mod query_service_server {
                     let inner = self.inner.clone();
                     let fut = async move {
                         let inner = inner.0;
-                        let method = SwapExecutionsSvc(inner);
+                        let method = PositionHistorySvc(inner);
                         let codec = tonic::codec::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -2369,26 +4249,26 @@ pub mod query_service_server {
                     };
                     Box::pin(fut)
                 }
-                "/penumbra.core.component.dex.v1.QueryService/ArbExecutions" => {
+                "/penumbra.core.component.dex.v1.QueryService/PositionAtHeight" => {
                     #[allow(non_camel_case_types)]
-                    struct ArbExecutionsSvc<T: QueryService>(pub Arc<T>);
+                    struct PositionAtHeightSvc<T: QueryService>(pub Arc<T>);
                     impl<
                         T: QueryService,
-                    > tonic::server::ServerStreamingService<super::ArbExecutionsRequest>
-                    for ArbExecutionsSvc<T> {
-                        type Response = super::ArbExecutionsResponse;
-                        type ResponseStream = T::ArbExecutionsStream;
+                    > tonic::server::UnaryService<super::PositionAtHeightRequest>
+                    for PositionAtHeightSvc<T> {
+                        type Response = super::PositionAtHeightResponse;
                         type Future = BoxFuture<
-                            tonic::Response<Self::ResponseStream>,
+                            tonic::Response<Self::Response>,
                             tonic::Status,
                         >;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::ArbExecutionsRequest>,
+                            request: tonic::Request<super::PositionAtHeightRequest>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                <T as QueryService>::arb_executions(&inner, request).await
+                                <T as QueryService>::position_at_height(&inner, request)
+                                    .await
                             };
                             Box::pin(fut)
                         }
@@ -2400,7 +4280,7 @@ pub mod query_service_server {
                     let inner = self.inner.clone();
                     let fut = async move {
                         let inner = inner.0;
-                        let method = ArbExecutionsSvc(inner);
+                        let method = PositionAtHeightSvc(inner);
                         let codec = tonic::codec::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -2411,32 +4291,30 @@ pub mod query_service_server {
                                 max_decoding_message_size,
                                 max_encoding_message_size,
                             );
-                        let res = grpc.server_streaming(method, req).await;
+                        let res = grpc.unary(method, req).await;
                         Ok(res)
                     };
                     Box::pin(fut)
                 }
-                "/penumbra.core.component.dex.v1.QueryService/LiquidityPositions" => {
+                "/penumbra.core.component.dex.v1.QueryService/PositionMetadata" => {
                     #[allow(non_camel_case_types)]
-                    struct LiquidityPositionsSvc<T: QueryService>(pub Arc<T>);
+                    struct PositionMetadataSvc<T: QueryService>(pub Arc<T>);
                     impl<
                         T: QueryService,
-                    > tonic::server::ServerStreamingService<
-                        super::LiquidityPositionsRequest,
-                    > for LiquidityPositionsSvc<T> {
-                        type Response = super::LiquidityPositionsResponse;
-                        type ResponseStream = T::LiquidityPositionsStream;
+                    > tonic::server::UnaryService<super::PositionMetadataRequest>
+                    for PositionMetadataSvc<T> {
+                        type Response = super::PositionMetadataResponse;
                         type Future = BoxFuture<
-                            tonic::Response<Self::ResponseStream>,
+                            tonic::Response<Self::Response>,
                             tonic::Status,
                         >;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::LiquidityPositionsRequest>,
+                            request: tonic::Request<super::PositionMetadataRequest>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                <T as QueryService>::liquidity_positions(&inner, request)
+                                <T as QueryService>::position_metadata(&inner, request)
                                     .await
                             };
                             Box::pin(fut)
@@ -2449,7 +4327,7 @@ pub mod query_service_server {
                     let inner = self.inner.clone();
                     let fut = async move {
                         let inner = inner.0;
-                        let method = LiquidityPositionsSvc(inner);
+                        let method = PositionMetadataSvc(inner);
                         let codec = tonic::codec::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -2460,30 +4338,33 @@ pub mod query_service_server {
                                 max_decoding_message_size,
                                 max_encoding_message_size,
                             );
-                        let res = grpc.server_streaming(method, req).await;
+                        let res = grpc.unary(method, req).await;
                         Ok(res)
                     };
                     Box::pin(fut)
                 }
-                "/penumbra.core.component.dex.v1.QueryService/LiquidityPositionById" => {
+                "/penumbra.core.component.dex.v1.QueryService/LiquidityPositionAnalytics" => {
                     #[allow(non_camel_case_types)]
-                    struct LiquidityPositionByIdSvc<T: QueryService>(pub Arc<T>);
+                    struct LiquidityPositionAnalyticsSvc<T: QueryService>(pub Arc<T>);
                     impl<
                         T: QueryService,
-                    > tonic::server::UnaryService<super::LiquidityPositionByIdRequest>
-                    for LiquidityPositionByIdSvc<T> {
-                        type Response = super::LiquidityPositionByIdResponse;
+                    > tonic::server::UnaryService<
+                        super::LiquidityPositionAnalyticsRequest,
+                    > for LiquidityPositionAnalyticsSvc<T> {
+                        type Response = super::LiquidityPositionAnalyticsResponse;
                         type Future = BoxFuture<
                             tonic::Response<Self::Response>,
                             tonic::Status,
                         >;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::LiquidityPositionByIdRequest>,
+                            request: tonic::Request<
+                                super::LiquidityPositionAnalyticsRequest,
+                            >,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                <T as QueryService>::liquidity_position_by_id(
+                                <T as QueryService>::liquidity_position_analytics(
                                         &inner,
                                         request,
                                     )
@@ -2499,7 +4380,7 @@ pub mod query_service_server {
                     let inner = self.inner.clone();
                     let fut = async move {
                         let inner = inner.0;
-                        let method = LiquidityPositionByIdSvc(inner);
+                        let method = LiquidityPositionAnalyticsSvc(inner);
                         let codec = tonic::codec::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -2515,30 +4396,25 @@ pub mod query_service_server {
                     };
                     Box::pin(fut)
                 }
-                "/penumbra.core.component.dex.v1.QueryService/LiquidityPositionsById" => {
+                "/penumbra.core.component.dex.v1.QueryService/DutchAuctionById" => {
                     #[allow(non_camel_case_types)]
-                    struct LiquidityPositionsByIdSvc<T: QueryService>(pub Arc<T>);
+                    struct DutchAuctionByIdSvc<T: QueryService>(pub Arc<T>);
                     impl<
                         T: QueryService,
-                    > tonic::server::ServerStreamingService<
-                        super::LiquidityPositionsByIdRequest,
-                    > for LiquidityPositionsByIdSvc<T> {
-                        type Response = super::LiquidityPositionsByIdResponse;
-                        type ResponseStream = T::LiquidityPositionsByIdStream;
+                    > tonic::server::UnaryService<super::DutchAuctionByIdRequest>
+                    for DutchAuctionByIdSvc<T> {
+                        type Response = super::DutchAuctionByIdResponse;
                         type Future = BoxFuture<
-                            tonic::Response<Self::ResponseStream>,
+                            tonic::Response<Self::Response>,
                             tonic::Status,
                         >;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::LiquidityPositionsByIdRequest>,
+                            request: tonic::Request<super::DutchAuctionByIdRequest>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                <T as QueryService>::liquidity_positions_by_id(
-                                        &inner,
-                                        request,
-                                    )
+                                <T as QueryService>::dutch_auction_by_id(&inner, request)
                                     .await
                             };
                             Box::pin(fut)
@@ -2551,7 +4427,7 @@ pub mod query_service_server {
                     let inner = self.inner.clone();
                     let fut = async move {
                         let inner = inner.0;
-                        let method = LiquidityPositionsByIdSvc(inner);
+                        let method = DutchAuctionByIdSvc(inner);
                         let codec = tonic::codec::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -2562,37 +4438,76 @@ pub mod query_service_server {
                                 max_decoding_message_size,
                                 max_encoding_message_size,
                             );
-                        let res = grpc.server_streaming(method, req).await;
+                        let res = grpc.unary(method, req).await;
                         Ok(res)
                     };
                     Box::pin(fut)
                 }
-                "/penumbra.core.component.dex.v1.QueryService/LiquidityPositionsByPrice" => {
+                "/penumbra.core.component.dex.v1.QueryService/Spread" => {
                     #[allow(non_camel_case_types)]
-                    struct LiquidityPositionsByPriceSvc<T: QueryService>(pub Arc<T>);
+                    struct SpreadSvc<T: QueryService>(pub Arc<T>);
                     impl<
                         T: QueryService,
-                    > tonic::server::ServerStreamingService<
-                        super::LiquidityPositionsByPriceRequest,
-                    > for LiquidityPositionsByPriceSvc<T> {
-                        type Response = super::LiquidityPositionsByPriceResponse;
-                        type ResponseStream = T::LiquidityPositionsByPriceStream;
+                    > tonic::server::UnaryService<super::SpreadRequest>
+                    for SpreadSvc<T> {
+                        type Response = super::SpreadResponse;
                         type Future = BoxFuture<
-                            tonic::Response<Self::ResponseStream>,
+                            tonic::Response<Self::Response>,
                             tonic::Status,
                         >;
                         fn call(
                             &mut self,
-                            request: tonic::Request<
-                                super::LiquidityPositionsByPriceRequest,
-                            >,
+                            request: tonic::Request<super::SpreadRequest>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                <T as QueryService>::liquidity_positions_by_price(
-                                        &inner,
-                                        request,
-                                    )
+                                <T as QueryService>::spread(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = SpreadSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/penumbra.core.component.dex.v1.QueryService/PausedTradingPairs" => {
+                    #[allow(non_camel_case_types)]
+                    struct PausedTradingPairsSvc<T: QueryService>(pub Arc<T>);
+                    impl<
+                        T: QueryService,
+                    > tonic::server::UnaryService<super::PausedTradingPairsRequest>
+                    for PausedTradingPairsSvc<T> {
+                        type Response = super::PausedTradingPairsResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::PausedTradingPairsRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as QueryService>::paused_trading_pairs(&inner, request)
                                     .await
                             };
                             Box::pin(fut)
@@ -2605,7 +4520,7 @@ pub mod query_service_server {
                     let inner = self.inner.clone();
                     let fut = async move {
                         let inner = inner.0;
-                        let method = LiquidityPositionsByPriceSvc(inner);
+                        let method = PausedTradingPairsSvc(inner);
                         let codec = tonic::codec::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -2616,30 +4531,31 @@ pub mod query_service_server {
                                 max_decoding_message_size,
                                 max_encoding_message_size,
                             );
-                        let res = grpc.server_streaming(method, req).await;
+                        let res = grpc.unary(method, req).await;
                         Ok(res)
                     };
                     Box::pin(fut)
                 }
-                "/penumbra.core.component.dex.v1.QueryService/Spread" => {
+                "/penumbra.core.component.dex.v1.QueryService/CircuitBreakerStatus" => {
                     #[allow(non_camel_case_types)]
-                    struct SpreadSvc<T: QueryService>(pub Arc<T>);
+                    struct CircuitBreakerStatusSvc<T: QueryService>(pub Arc<T>);
                     impl<
                         T: QueryService,
-                    > tonic::server::UnaryService<super::SpreadRequest>
-                    for SpreadSvc<T> {
-                        type Response = super::SpreadResponse;
+                    > tonic::server::UnaryService<super::CircuitBreakerStatusRequest>
+                    for CircuitBreakerStatusSvc<T> {
+                        type Response = super::CircuitBreakerStatusResponse;
                         type Future = BoxFuture<
                             tonic::Response<Self::Response>,
                             tonic::Status,
                         >;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::SpreadRequest>,
+                            request: tonic::Request<super::CircuitBreakerStatusRequest>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                <T as QueryService>::spread(&inner, request).await
+                                <T as QueryService>::circuit_breaker_status(&inner, request)
+                                    .await
                             };
                             Box::pin(fut)
                         }
@@ -2651,7 +4567,60 @@ pub mod query_service_server {
                     let inner = self.inner.clone();
                     let fut = async move {
                         let inner = inner.0;
-                        let method = SpreadSvc(inner);
+                        let method = CircuitBreakerStatusSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/penumbra.core.component.dex.v1.QueryService/ValueCircuitBreakerBreach" => {
+                    #[allow(non_camel_case_types)]
+                    struct ValueCircuitBreakerBreachSvc<T: QueryService>(pub Arc<T>);
+                    impl<
+                        T: QueryService,
+                    > tonic::server::UnaryService<
+                        super::ValueCircuitBreakerBreachRequest,
+                    > for ValueCircuitBreakerBreachSvc<T> {
+                        type Response = super::ValueCircuitBreakerBreachResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<
+                                super::ValueCircuitBreakerBreachRequest,
+                            >,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as QueryService>::value_circuit_breaker_breach(
+                                        &inner,
+                                        request,
+                                    )
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = ValueCircuitBreakerBreachSvc(inner);
                         let codec = tonic::codec::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
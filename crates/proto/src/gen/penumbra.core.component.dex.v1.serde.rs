@@ -1,4 +1,4 @@
-impl serde::Serialize for ArbExecutionRequest {
+impl serde::Serialize for ActionDutchAuctionEnd {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -6,30 +6,30 @@ impl serde::Serialize for ArbExecutionRequest {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.height != 0 {
+        if self.auction_id.is_some() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.ArbExecutionRequest", len)?;
-        if self.height != 0 {
-            #[allow(clippy::needless_borrow)]
-            struct_ser.serialize_field("height", ToString::to_string(&self.height).as_str())?;
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.ActionDutchAuctionEnd", len)?;
+        if let Some(v) = self.auction_id.as_ref() {
+            struct_ser.serialize_field("auctionId", v)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for ArbExecutionRequest {
+impl<'de> serde::Deserialize<'de> for ActionDutchAuctionEnd {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "height",
+            "auction_id",
+            "auctionId",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            Height,
+            AuctionId,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -52,7 +52,7 @@ impl<'de> serde::Deserialize<'de> for ArbExecutionRequest {
                         E: serde::de::Error,
                     {
                         match value {
-                            "height" => Ok(GeneratedField::Height),
+                            "auctionId" | "auction_id" => Ok(GeneratedField::AuctionId),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -62,41 +62,39 @@ impl<'de> serde::Deserialize<'de> for ArbExecutionRequest {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = ArbExecutionRequest;
+            type Value = ActionDutchAuctionEnd;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.core.component.dex.v1.ArbExecutionRequest")
+                formatter.write_str("struct penumbra.core.component.dex.v1.ActionDutchAuctionEnd")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ArbExecutionRequest, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ActionDutchAuctionEnd, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut height__ = None;
+                let mut auction_id__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::Height => {
-                            if height__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("height"));
+                        GeneratedField::AuctionId => {
+                            if auction_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("auctionId"));
                             }
-                            height__ = 
-                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
-                            ;
+                            auction_id__ = map_.next_value()?;
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(ArbExecutionRequest {
-                    height: height__.unwrap_or_default(),
+                Ok(ActionDutchAuctionEnd {
+                    auction_id: auction_id__,
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.core.component.dex.v1.ArbExecutionRequest", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.core.component.dex.v1.ActionDutchAuctionEnd", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for ArbExecutionResponse {
+impl serde::Serialize for ActionDutchAuctionSchedule {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -104,39 +102,29 @@ impl serde::Serialize for ArbExecutionResponse {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.swap_execution.is_some() {
-            len += 1;
-        }
-        if self.height != 0 {
+        if self.description.is_some() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.ArbExecutionResponse", len)?;
-        if let Some(v) = self.swap_execution.as_ref() {
-            struct_ser.serialize_field("swapExecution", v)?;
-        }
-        if self.height != 0 {
-            #[allow(clippy::needless_borrow)]
-            struct_ser.serialize_field("height", ToString::to_string(&self.height).as_str())?;
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.ActionDutchAuctionSchedule", len)?;
+        if let Some(v) = self.description.as_ref() {
+            struct_ser.serialize_field("description", v)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for ArbExecutionResponse {
+impl<'de> serde::Deserialize<'de> for ActionDutchAuctionSchedule {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "swap_execution",
-            "swapExecution",
-            "height",
+            "description",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            SwapExecution,
-            Height,
+            Description,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -159,8 +147,7 @@ impl<'de> serde::Deserialize<'de> for ArbExecutionResponse {
                         E: serde::de::Error,
                     {
                         match value {
-                            "swapExecution" | "swap_execution" => Ok(GeneratedField::SwapExecution),
-                            "height" => Ok(GeneratedField::Height),
+                            "description" => Ok(GeneratedField::Description),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -170,49 +157,39 @@ impl<'de> serde::Deserialize<'de> for ArbExecutionResponse {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = ArbExecutionResponse;
+            type Value = ActionDutchAuctionSchedule;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.core.component.dex.v1.ArbExecutionResponse")
+                formatter.write_str("struct penumbra.core.component.dex.v1.ActionDutchAuctionSchedule")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ArbExecutionResponse, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ActionDutchAuctionSchedule, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut swap_execution__ = None;
-                let mut height__ = None;
+                let mut description__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::SwapExecution => {
-                            if swap_execution__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("swapExecution"));
-                            }
-                            swap_execution__ = map_.next_value()?;
-                        }
-                        GeneratedField::Height => {
-                            if height__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("height"));
+                        GeneratedField::Description => {
+                            if description__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("description"));
                             }
-                            height__ = 
-                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
-                            ;
+                            description__ = map_.next_value()?;
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(ArbExecutionResponse {
-                    swap_execution: swap_execution__,
-                    height: height__.unwrap_or_default(),
+                Ok(ActionDutchAuctionSchedule {
+                    description: description__,
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.core.component.dex.v1.ArbExecutionResponse", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.core.component.dex.v1.ActionDutchAuctionSchedule", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for ArbExecutionsRequest {
+impl serde::Serialize for ActionDutchAuctionWithdraw {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -220,41 +197,48 @@ impl serde::Serialize for ArbExecutionsRequest {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.start_height != 0 {
+        if self.auction_id.is_some() {
             len += 1;
         }
-        if self.end_height != 0 {
+        if self.seq != 0 {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.ArbExecutionsRequest", len)?;
-        if self.start_height != 0 {
-            #[allow(clippy::needless_borrow)]
-            struct_ser.serialize_field("startHeight", ToString::to_string(&self.start_height).as_str())?;
+        if self.reserves_commitment.is_some() {
+            len += 1;
         }
-        if self.end_height != 0 {
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.ActionDutchAuctionWithdraw", len)?;
+        if let Some(v) = self.auction_id.as_ref() {
+            struct_ser.serialize_field("auctionId", v)?;
+        }
+        if self.seq != 0 {
             #[allow(clippy::needless_borrow)]
-            struct_ser.serialize_field("endHeight", ToString::to_string(&self.end_height).as_str())?;
+            struct_ser.serialize_field("seq", ToString::to_string(&self.seq).as_str())?;
+        }
+        if let Some(v) = self.reserves_commitment.as_ref() {
+            struct_ser.serialize_field("reservesCommitment", v)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for ArbExecutionsRequest {
+impl<'de> serde::Deserialize<'de> for ActionDutchAuctionWithdraw {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "start_height",
-            "startHeight",
-            "end_height",
-            "endHeight",
+            "auction_id",
+            "auctionId",
+            "seq",
+            "reserves_commitment",
+            "reservesCommitment",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            StartHeight,
-            EndHeight,
+            AuctionId,
+            Seq,
+            ReservesCommitment,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -277,8 +261,9 @@ impl<'de> serde::Deserialize<'de> for ArbExecutionsRequest {
                         E: serde::de::Error,
                     {
                         match value {
-                            "startHeight" | "start_height" => Ok(GeneratedField::StartHeight),
-                            "endHeight" | "end_height" => Ok(GeneratedField::EndHeight),
+                            "auctionId" | "auction_id" => Ok(GeneratedField::AuctionId),
+                            "seq" => Ok(GeneratedField::Seq),
+                            "reservesCommitment" | "reserves_commitment" => Ok(GeneratedField::ReservesCommitment),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -288,51 +273,57 @@ impl<'de> serde::Deserialize<'de> for ArbExecutionsRequest {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = ArbExecutionsRequest;
+            type Value = ActionDutchAuctionWithdraw;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.core.component.dex.v1.ArbExecutionsRequest")
+                formatter.write_str("struct penumbra.core.component.dex.v1.ActionDutchAuctionWithdraw")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ArbExecutionsRequest, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ActionDutchAuctionWithdraw, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut start_height__ = None;
-                let mut end_height__ = None;
+                let mut auction_id__ = None;
+                let mut seq__ = None;
+                let mut reserves_commitment__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::StartHeight => {
-                            if start_height__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("startHeight"));
+                        GeneratedField::AuctionId => {
+                            if auction_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("auctionId"));
                             }
-                            start_height__ = 
-                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
-                            ;
+                            auction_id__ = map_.next_value()?;
                         }
-                        GeneratedField::EndHeight => {
-                            if end_height__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("endHeight"));
+                        GeneratedField::Seq => {
+                            if seq__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("seq"));
                             }
-                            end_height__ = 
+                            seq__ = 
                                 Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
                             ;
                         }
+                        GeneratedField::ReservesCommitment => {
+                            if reserves_commitment__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("reservesCommitment"));
+                            }
+                            reserves_commitment__ = map_.next_value()?;
+                        }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(ArbExecutionsRequest {
-                    start_height: start_height__.unwrap_or_default(),
-                    end_height: end_height__.unwrap_or_default(),
+                Ok(ActionDutchAuctionWithdraw {
+                    auction_id: auction_id__,
+                    seq: seq__.unwrap_or_default(),
+                    reserves_commitment: reserves_commitment__,
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.core.component.dex.v1.ArbExecutionsRequest", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.core.component.dex.v1.ActionDutchAuctionWithdraw", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for ArbExecutionsResponse {
+impl serde::Serialize for ActionDutchAuctionWithdrawPlan {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -340,39 +331,57 @@ impl serde::Serialize for ArbExecutionsResponse {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.swap_execution.is_some() {
+        if self.auction_id.is_some() {
             len += 1;
         }
-        if self.height != 0 {
+        if self.seq != 0 {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.ArbExecutionsResponse", len)?;
-        if let Some(v) = self.swap_execution.as_ref() {
-            struct_ser.serialize_field("swapExecution", v)?;
+        if self.reserves_input.is_some() {
+            len += 1;
         }
-        if self.height != 0 {
+        if self.reserves_output.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.ActionDutchAuctionWithdrawPlan", len)?;
+        if let Some(v) = self.auction_id.as_ref() {
+            struct_ser.serialize_field("auctionId", v)?;
+        }
+        if self.seq != 0 {
             #[allow(clippy::needless_borrow)]
-            struct_ser.serialize_field("height", ToString::to_string(&self.height).as_str())?;
+            struct_ser.serialize_field("seq", ToString::to_string(&self.seq).as_str())?;
+        }
+        if let Some(v) = self.reserves_input.as_ref() {
+            struct_ser.serialize_field("reservesInput", v)?;
+        }
+        if let Some(v) = self.reserves_output.as_ref() {
+            struct_ser.serialize_field("reservesOutput", v)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for ArbExecutionsResponse {
+impl<'de> serde::Deserialize<'de> for ActionDutchAuctionWithdrawPlan {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "swap_execution",
-            "swapExecution",
-            "height",
+            "auction_id",
+            "auctionId",
+            "seq",
+            "reserves_input",
+            "reservesInput",
+            "reserves_output",
+            "reservesOutput",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            SwapExecution,
-            Height,
+            AuctionId,
+            Seq,
+            ReservesInput,
+            ReservesOutput,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -395,8 +404,10 @@ impl<'de> serde::Deserialize<'de> for ArbExecutionsResponse {
                         E: serde::de::Error,
                     {
                         match value {
-                            "swapExecution" | "swap_execution" => Ok(GeneratedField::SwapExecution),
-                            "height" => Ok(GeneratedField::Height),
+                            "auctionId" | "auction_id" => Ok(GeneratedField::AuctionId),
+                            "seq" => Ok(GeneratedField::Seq),
+                            "reservesInput" | "reserves_input" => Ok(GeneratedField::ReservesInput),
+                            "reservesOutput" | "reserves_output" => Ok(GeneratedField::ReservesOutput),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -406,49 +417,65 @@ impl<'de> serde::Deserialize<'de> for ArbExecutionsResponse {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = ArbExecutionsResponse;
+            type Value = ActionDutchAuctionWithdrawPlan;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.core.component.dex.v1.ArbExecutionsResponse")
+                formatter.write_str("struct penumbra.core.component.dex.v1.ActionDutchAuctionWithdrawPlan")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ArbExecutionsResponse, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ActionDutchAuctionWithdrawPlan, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut swap_execution__ = None;
-                let mut height__ = None;
+                let mut auction_id__ = None;
+                let mut seq__ = None;
+                let mut reserves_input__ = None;
+                let mut reserves_output__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::SwapExecution => {
-                            if swap_execution__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("swapExecution"));
+                        GeneratedField::AuctionId => {
+                            if auction_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("auctionId"));
                             }
-                            swap_execution__ = map_.next_value()?;
+                            auction_id__ = map_.next_value()?;
                         }
-                        GeneratedField::Height => {
-                            if height__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("height"));
+                        GeneratedField::Seq => {
+                            if seq__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("seq"));
                             }
-                            height__ = 
+                            seq__ = 
                                 Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
                             ;
                         }
+                        GeneratedField::ReservesInput => {
+                            if reserves_input__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("reservesInput"));
+                            }
+                            reserves_input__ = map_.next_value()?;
+                        }
+                        GeneratedField::ReservesOutput => {
+                            if reserves_output__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("reservesOutput"));
+                            }
+                            reserves_output__ = map_.next_value()?;
+                        }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(ArbExecutionsResponse {
-                    swap_execution: swap_execution__,
-                    height: height__.unwrap_or_default(),
+                Ok(ActionDutchAuctionWithdrawPlan {
+                    auction_id: auction_id__,
+                    seq: seq__.unwrap_or_default(),
+                    reserves_input: reserves_input__,
+                    reserves_output: reserves_output__,
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.core.component.dex.v1.ArbExecutionsResponse", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.core.component.dex.v1.ActionDutchAuctionWithdrawPlan", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for BareTradingFunction {
+impl serde::Serialize for ArbExecutionRequest {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -456,45 +483,30 @@ impl serde::Serialize for BareTradingFunction {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.fee != 0 {
-            len += 1;
-        }
-        if self.p.is_some() {
-            len += 1;
-        }
-        if self.q.is_some() {
+        if self.height != 0 {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.BareTradingFunction", len)?;
-        if self.fee != 0 {
-            struct_ser.serialize_field("fee", &self.fee)?;
-        }
-        if let Some(v) = self.p.as_ref() {
-            struct_ser.serialize_field("p", v)?;
-        }
-        if let Some(v) = self.q.as_ref() {
-            struct_ser.serialize_field("q", v)?;
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.ArbExecutionRequest", len)?;
+        if self.height != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("height", ToString::to_string(&self.height).as_str())?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for BareTradingFunction {
+impl<'de> serde::Deserialize<'de> for ArbExecutionRequest {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "fee",
-            "p",
-            "q",
+            "height",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            Fee,
-            P,
-            Q,
+            Height,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -517,9 +529,7 @@ impl<'de> serde::Deserialize<'de> for BareTradingFunction {
                         E: serde::de::Error,
                     {
                         match value {
-                            "fee" => Ok(GeneratedField::Fee),
-                            "p" => Ok(GeneratedField::P),
-                            "q" => Ok(GeneratedField::Q),
+                            "height" => Ok(GeneratedField::Height),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -529,57 +539,41 @@ impl<'de> serde::Deserialize<'de> for BareTradingFunction {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = BareTradingFunction;
+            type Value = ArbExecutionRequest;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.core.component.dex.v1.BareTradingFunction")
+                formatter.write_str("struct penumbra.core.component.dex.v1.ArbExecutionRequest")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<BareTradingFunction, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ArbExecutionRequest, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut fee__ = None;
-                let mut p__ = None;
-                let mut q__ = None;
+                let mut height__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::Fee => {
-                            if fee__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("fee"));
+                        GeneratedField::Height => {
+                            if height__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("height"));
                             }
-                            fee__ = 
+                            height__ = 
                                 Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
                             ;
                         }
-                        GeneratedField::P => {
-                            if p__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("p"));
-                            }
-                            p__ = map_.next_value()?;
-                        }
-                        GeneratedField::Q => {
-                            if q__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("q"));
-                            }
-                            q__ = map_.next_value()?;
-                        }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(BareTradingFunction {
-                    fee: fee__.unwrap_or_default(),
-                    p: p__,
-                    q: q__,
+                Ok(ArbExecutionRequest {
+                    height: height__.unwrap_or_default(),
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.core.component.dex.v1.BareTradingFunction", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.core.component.dex.v1.ArbExecutionRequest", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for BatchSwapOutputData {
+impl serde::Serialize for ArbExecutionResponse {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -587,103 +581,39 @@ impl serde::Serialize for BatchSwapOutputData {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.delta_1.is_some() {
-            len += 1;
-        }
-        if self.delta_2.is_some() {
+        if self.swap_execution.is_some() {
             len += 1;
         }
-        if self.lambda_1.is_some() {
+        if self.height != 0 {
             len += 1;
         }
-        if self.lambda_2.is_some() {
-            len += 1;
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.ArbExecutionResponse", len)?;
+        if let Some(v) = self.swap_execution.as_ref() {
+            struct_ser.serialize_field("swapExecution", v)?;
         }
-        if self.unfilled_1.is_some() {
-            len += 1;
-        }
-        if self.unfilled_2.is_some() {
-            len += 1;
-        }
-        if self.height != 0 {
-            len += 1;
-        }
-        if self.trading_pair.is_some() {
-            len += 1;
-        }
-        if self.epoch_starting_height != 0 {
-            len += 1;
-        }
-        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.BatchSwapOutputData", len)?;
-        if let Some(v) = self.delta_1.as_ref() {
-            struct_ser.serialize_field("delta1", v)?;
-        }
-        if let Some(v) = self.delta_2.as_ref() {
-            struct_ser.serialize_field("delta2", v)?;
-        }
-        if let Some(v) = self.lambda_1.as_ref() {
-            struct_ser.serialize_field("lambda1", v)?;
-        }
-        if let Some(v) = self.lambda_2.as_ref() {
-            struct_ser.serialize_field("lambda2", v)?;
-        }
-        if let Some(v) = self.unfilled_1.as_ref() {
-            struct_ser.serialize_field("unfilled1", v)?;
-        }
-        if let Some(v) = self.unfilled_2.as_ref() {
-            struct_ser.serialize_field("unfilled2", v)?;
-        }
-        if self.height != 0 {
-            #[allow(clippy::needless_borrow)]
-            struct_ser.serialize_field("height", ToString::to_string(&self.height).as_str())?;
-        }
-        if let Some(v) = self.trading_pair.as_ref() {
-            struct_ser.serialize_field("tradingPair", v)?;
-        }
-        if self.epoch_starting_height != 0 {
-            #[allow(clippy::needless_borrow)]
-            struct_ser.serialize_field("epochStartingHeight", ToString::to_string(&self.epoch_starting_height).as_str())?;
+        if self.height != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("height", ToString::to_string(&self.height).as_str())?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for BatchSwapOutputData {
+impl<'de> serde::Deserialize<'de> for ArbExecutionResponse {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "delta_1",
-            "delta1",
-            "delta_2",
-            "delta2",
-            "lambda_1",
-            "lambda1",
-            "lambda_2",
-            "lambda2",
-            "unfilled_1",
-            "unfilled1",
-            "unfilled_2",
-            "unfilled2",
+            "swap_execution",
+            "swapExecution",
             "height",
-            "trading_pair",
-            "tradingPair",
-            "epoch_starting_height",
-            "epochStartingHeight",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            Delta1,
-            Delta2,
-            Lambda1,
-            Lambda2,
-            Unfilled1,
-            Unfilled2,
+            SwapExecution,
             Height,
-            TradingPair,
-            EpochStartingHeight,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -706,15 +636,8 @@ impl<'de> serde::Deserialize<'de> for BatchSwapOutputData {
                         E: serde::de::Error,
                     {
                         match value {
-                            "delta1" | "delta_1" => Ok(GeneratedField::Delta1),
-                            "delta2" | "delta_2" => Ok(GeneratedField::Delta2),
-                            "lambda1" | "lambda_1" => Ok(GeneratedField::Lambda1),
-                            "lambda2" | "lambda_2" => Ok(GeneratedField::Lambda2),
-                            "unfilled1" | "unfilled_1" => Ok(GeneratedField::Unfilled1),
-                            "unfilled2" | "unfilled_2" => Ok(GeneratedField::Unfilled2),
+                            "swapExecution" | "swap_execution" => Ok(GeneratedField::SwapExecution),
                             "height" => Ok(GeneratedField::Height),
-                            "tradingPair" | "trading_pair" => Ok(GeneratedField::TradingPair),
-                            "epochStartingHeight" | "epoch_starting_height" => Ok(GeneratedField::EpochStartingHeight),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -724,62 +647,25 @@ impl<'de> serde::Deserialize<'de> for BatchSwapOutputData {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = BatchSwapOutputData;
+            type Value = ArbExecutionResponse;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.core.component.dex.v1.BatchSwapOutputData")
+                formatter.write_str("struct penumbra.core.component.dex.v1.ArbExecutionResponse")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<BatchSwapOutputData, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ArbExecutionResponse, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut delta_1__ = None;
-                let mut delta_2__ = None;
-                let mut lambda_1__ = None;
-                let mut lambda_2__ = None;
-                let mut unfilled_1__ = None;
-                let mut unfilled_2__ = None;
+                let mut swap_execution__ = None;
                 let mut height__ = None;
-                let mut trading_pair__ = None;
-                let mut epoch_starting_height__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::Delta1 => {
-                            if delta_1__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("delta1"));
-                            }
-                            delta_1__ = map_.next_value()?;
-                        }
-                        GeneratedField::Delta2 => {
-                            if delta_2__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("delta2"));
-                            }
-                            delta_2__ = map_.next_value()?;
-                        }
-                        GeneratedField::Lambda1 => {
-                            if lambda_1__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("lambda1"));
-                            }
-                            lambda_1__ = map_.next_value()?;
-                        }
-                        GeneratedField::Lambda2 => {
-                            if lambda_2__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("lambda2"));
-                            }
-                            lambda_2__ = map_.next_value()?;
-                        }
-                        GeneratedField::Unfilled1 => {
-                            if unfilled_1__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("unfilled1"));
-                            }
-                            unfilled_1__ = map_.next_value()?;
-                        }
-                        GeneratedField::Unfilled2 => {
-                            if unfilled_2__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("unfilled2"));
+                        GeneratedField::SwapExecution => {
+                            if swap_execution__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("swapExecution"));
                             }
-                            unfilled_2__ = map_.next_value()?;
+                            swap_execution__ = map_.next_value()?;
                         }
                         GeneratedField::Height => {
                             if height__.is_some() {
@@ -789,42 +675,21 @@ impl<'de> serde::Deserialize<'de> for BatchSwapOutputData {
                                 Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
                             ;
                         }
-                        GeneratedField::TradingPair => {
-                            if trading_pair__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("tradingPair"));
-                            }
-                            trading_pair__ = map_.next_value()?;
-                        }
-                        GeneratedField::EpochStartingHeight => {
-                            if epoch_starting_height__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("epochStartingHeight"));
-                            }
-                            epoch_starting_height__ = 
-                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
-                            ;
-                        }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(BatchSwapOutputData {
-                    delta_1: delta_1__,
-                    delta_2: delta_2__,
-                    lambda_1: lambda_1__,
-                    lambda_2: lambda_2__,
-                    unfilled_1: unfilled_1__,
-                    unfilled_2: unfilled_2__,
+                Ok(ArbExecutionResponse {
+                    swap_execution: swap_execution__,
                     height: height__.unwrap_or_default(),
-                    trading_pair: trading_pair__,
-                    epoch_starting_height: epoch_starting_height__.unwrap_or_default(),
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.core.component.dex.v1.BatchSwapOutputData", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.core.component.dex.v1.ArbExecutionResponse", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for BatchSwapOutputDataRequest {
+impl serde::Serialize for ArbExecutionsRequest {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -832,39 +697,41 @@ impl serde::Serialize for BatchSwapOutputDataRequest {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.height != 0 {
+        if self.start_height != 0 {
             len += 1;
         }
-        if self.trading_pair.is_some() {
+        if self.end_height != 0 {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.BatchSwapOutputDataRequest", len)?;
-        if self.height != 0 {
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.ArbExecutionsRequest", len)?;
+        if self.start_height != 0 {
             #[allow(clippy::needless_borrow)]
-            struct_ser.serialize_field("height", ToString::to_string(&self.height).as_str())?;
+            struct_ser.serialize_field("startHeight", ToString::to_string(&self.start_height).as_str())?;
         }
-        if let Some(v) = self.trading_pair.as_ref() {
-            struct_ser.serialize_field("tradingPair", v)?;
+        if self.end_height != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("endHeight", ToString::to_string(&self.end_height).as_str())?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for BatchSwapOutputDataRequest {
+impl<'de> serde::Deserialize<'de> for ArbExecutionsRequest {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "height",
-            "trading_pair",
-            "tradingPair",
+            "start_height",
+            "startHeight",
+            "end_height",
+            "endHeight",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            Height,
-            TradingPair,
+            StartHeight,
+            EndHeight,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -887,8 +754,8 @@ impl<'de> serde::Deserialize<'de> for BatchSwapOutputDataRequest {
                         E: serde::de::Error,
                     {
                         match value {
-                            "height" => Ok(GeneratedField::Height),
-                            "tradingPair" | "trading_pair" => Ok(GeneratedField::TradingPair),
+                            "startHeight" | "start_height" => Ok(GeneratedField::StartHeight),
+                            "endHeight" | "end_height" => Ok(GeneratedField::EndHeight),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -898,49 +765,51 @@ impl<'de> serde::Deserialize<'de> for BatchSwapOutputDataRequest {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = BatchSwapOutputDataRequest;
+            type Value = ArbExecutionsRequest;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.core.component.dex.v1.BatchSwapOutputDataRequest")
+                formatter.write_str("struct penumbra.core.component.dex.v1.ArbExecutionsRequest")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<BatchSwapOutputDataRequest, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ArbExecutionsRequest, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut height__ = None;
-                let mut trading_pair__ = None;
+                let mut start_height__ = None;
+                let mut end_height__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::Height => {
-                            if height__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("height"));
+                        GeneratedField::StartHeight => {
+                            if start_height__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("startHeight"));
                             }
-                            height__ = 
+                            start_height__ = 
                                 Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
                             ;
                         }
-                        GeneratedField::TradingPair => {
-                            if trading_pair__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("tradingPair"));
+                        GeneratedField::EndHeight => {
+                            if end_height__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("endHeight"));
                             }
-                            trading_pair__ = map_.next_value()?;
+                            end_height__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(BatchSwapOutputDataRequest {
-                    height: height__.unwrap_or_default(),
-                    trading_pair: trading_pair__,
+                Ok(ArbExecutionsRequest {
+                    start_height: start_height__.unwrap_or_default(),
+                    end_height: end_height__.unwrap_or_default(),
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.core.component.dex.v1.BatchSwapOutputDataRequest", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.core.component.dex.v1.ArbExecutionsRequest", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for BatchSwapOutputDataResponse {
+impl serde::Serialize for ArbExecutionsResponse {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -948,29 +817,39 @@ impl serde::Serialize for BatchSwapOutputDataResponse {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.data.is_some() {
+        if self.swap_execution.is_some() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.BatchSwapOutputDataResponse", len)?;
-        if let Some(v) = self.data.as_ref() {
-            struct_ser.serialize_field("data", v)?;
+        if self.height != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.ArbExecutionsResponse", len)?;
+        if let Some(v) = self.swap_execution.as_ref() {
+            struct_ser.serialize_field("swapExecution", v)?;
+        }
+        if self.height != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("height", ToString::to_string(&self.height).as_str())?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for BatchSwapOutputDataResponse {
+impl<'de> serde::Deserialize<'de> for ArbExecutionsResponse {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "data",
+            "swap_execution",
+            "swapExecution",
+            "height",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            Data,
+            SwapExecution,
+            Height,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -993,7 +872,8 @@ impl<'de> serde::Deserialize<'de> for BatchSwapOutputDataResponse {
                         E: serde::de::Error,
                     {
                         match value {
-                            "data" => Ok(GeneratedField::Data),
+                            "swapExecution" | "swap_execution" => Ok(GeneratedField::SwapExecution),
+                            "height" => Ok(GeneratedField::Height),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -1003,39 +883,49 @@ impl<'de> serde::Deserialize<'de> for BatchSwapOutputDataResponse {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = BatchSwapOutputDataResponse;
+            type Value = ArbExecutionsResponse;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.core.component.dex.v1.BatchSwapOutputDataResponse")
+                formatter.write_str("struct penumbra.core.component.dex.v1.ArbExecutionsResponse")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<BatchSwapOutputDataResponse, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ArbExecutionsResponse, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut data__ = None;
+                let mut swap_execution__ = None;
+                let mut height__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::Data => {
-                            if data__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("data"));
+                        GeneratedField::SwapExecution => {
+                            if swap_execution__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("swapExecution"));
                             }
-                            data__ = map_.next_value()?;
+                            swap_execution__ = map_.next_value()?;
+                        }
+                        GeneratedField::Height => {
+                            if height__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("height"));
+                            }
+                            height__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(BatchSwapOutputDataResponse {
-                    data: data__,
+                Ok(ArbExecutionsResponse {
+                    swap_execution: swap_execution__,
+                    height: height__.unwrap_or_default(),
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.core.component.dex.v1.BatchSwapOutputDataResponse", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.core.component.dex.v1.ArbExecutionsResponse", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for DirectedTradingPair {
+impl serde::Serialize for AuctionId {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -1043,37 +933,30 @@ impl serde::Serialize for DirectedTradingPair {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.start.is_some() {
-            len += 1;
-        }
-        if self.end.is_some() {
+        if !self.inner.is_empty() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.DirectedTradingPair", len)?;
-        if let Some(v) = self.start.as_ref() {
-            struct_ser.serialize_field("start", v)?;
-        }
-        if let Some(v) = self.end.as_ref() {
-            struct_ser.serialize_field("end", v)?;
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.AuctionId", len)?;
+        if !self.inner.is_empty() {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("inner", pbjson::private::base64::encode(&self.inner).as_str())?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for DirectedTradingPair {
+impl<'de> serde::Deserialize<'de> for AuctionId {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "start",
-            "end",
+            "inner",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            Start,
-            End,
+            Inner,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -1096,8 +979,7 @@ impl<'de> serde::Deserialize<'de> for DirectedTradingPair {
                         E: serde::de::Error,
                     {
                         match value {
-                            "start" => Ok(GeneratedField::Start),
-                            "end" => Ok(GeneratedField::End),
+                            "inner" => Ok(GeneratedField::Inner),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -1107,47 +989,41 @@ impl<'de> serde::Deserialize<'de> for DirectedTradingPair {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = DirectedTradingPair;
+            type Value = AuctionId;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.core.component.dex.v1.DirectedTradingPair")
+                formatter.write_str("struct penumbra.core.component.dex.v1.AuctionId")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<DirectedTradingPair, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<AuctionId, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut start__ = None;
-                let mut end__ = None;
+                let mut inner__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::Start => {
-                            if start__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("start"));
-                            }
-                            start__ = map_.next_value()?;
-                        }
-                        GeneratedField::End => {
-                            if end__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("end"));
+                        GeneratedField::Inner => {
+                            if inner__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("inner"));
                             }
-                            end__ = map_.next_value()?;
+                            inner__ = 
+                                Some(map_.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
+                            ;
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(DirectedTradingPair {
-                    start: start__,
-                    end: end__,
+                Ok(AuctionId {
+                    inner: inner__.unwrap_or_default(),
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.core.component.dex.v1.DirectedTradingPair", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.core.component.dex.v1.AuctionId", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for EventArbExecution {
+impl serde::Serialize for AuctionNft {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -1155,39 +1031,38 @@ impl serde::Serialize for EventArbExecution {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.height != 0 {
+        if self.id.is_some() {
             len += 1;
         }
-        if self.swap_execution.is_some() {
+        if self.seq != 0 {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.EventArbExecution", len)?;
-        if self.height != 0 {
-            #[allow(clippy::needless_borrow)]
-            struct_ser.serialize_field("height", ToString::to_string(&self.height).as_str())?;
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.AuctionNft", len)?;
+        if let Some(v) = self.id.as_ref() {
+            struct_ser.serialize_field("id", v)?;
         }
-        if let Some(v) = self.swap_execution.as_ref() {
-            struct_ser.serialize_field("swapExecution", v)?;
+        if self.seq != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("seq", ToString::to_string(&self.seq).as_str())?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for EventArbExecution {
+impl<'de> serde::Deserialize<'de> for AuctionNft {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "height",
-            "swap_execution",
-            "swapExecution",
+            "id",
+            "seq",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            Height,
-            SwapExecution,
+            Id,
+            Seq,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -1210,8 +1085,8 @@ impl<'de> serde::Deserialize<'de> for EventArbExecution {
                         E: serde::de::Error,
                     {
                         match value {
-                            "height" => Ok(GeneratedField::Height),
-                            "swapExecution" | "swap_execution" => Ok(GeneratedField::SwapExecution),
+                            "id" => Ok(GeneratedField::Id),
+                            "seq" => Ok(GeneratedField::Seq),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -1221,49 +1096,49 @@ impl<'de> serde::Deserialize<'de> for EventArbExecution {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = EventArbExecution;
+            type Value = AuctionNft;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.core.component.dex.v1.EventArbExecution")
+                formatter.write_str("struct penumbra.core.component.dex.v1.AuctionNft")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<EventArbExecution, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<AuctionNft, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut height__ = None;
-                let mut swap_execution__ = None;
+                let mut id__ = None;
+                let mut seq__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::Height => {
-                            if height__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("height"));
+                        GeneratedField::Id => {
+                            if id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("id"));
                             }
-                            height__ = 
-                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
-                            ;
+                            id__ = map_.next_value()?;
                         }
-                        GeneratedField::SwapExecution => {
-                            if swap_execution__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("swapExecution"));
+                        GeneratedField::Seq => {
+                            if seq__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("seq"));
                             }
-                            swap_execution__ = map_.next_value()?;
+                            seq__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(EventArbExecution {
-                    height: height__.unwrap_or_default(),
-                    swap_execution: swap_execution__,
+                Ok(AuctionNft {
+                    id: id__,
+                    seq: seq__.unwrap_or_default(),
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.core.component.dex.v1.EventArbExecution", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.core.component.dex.v1.AuctionNft", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for EventBatchSwap {
+impl serde::Serialize for BareTradingFunction {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -1271,48 +1146,45 @@ impl serde::Serialize for EventBatchSwap {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.batch_swap_output_data.is_some() {
+        if self.fee != 0 {
             len += 1;
         }
-        if self.swap_execution_1_for_2.is_some() {
+        if self.p.is_some() {
             len += 1;
         }
-        if self.swap_execution_2_for_1.is_some() {
+        if self.q.is_some() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.EventBatchSwap", len)?;
-        if let Some(v) = self.batch_swap_output_data.as_ref() {
-            struct_ser.serialize_field("batchSwapOutputData", v)?;
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.BareTradingFunction", len)?;
+        if self.fee != 0 {
+            struct_ser.serialize_field("fee", &self.fee)?;
         }
-        if let Some(v) = self.swap_execution_1_for_2.as_ref() {
-            struct_ser.serialize_field("swapExecution1For2", v)?;
+        if let Some(v) = self.p.as_ref() {
+            struct_ser.serialize_field("p", v)?;
         }
-        if let Some(v) = self.swap_execution_2_for_1.as_ref() {
-            struct_ser.serialize_field("swapExecution2For1", v)?;
+        if let Some(v) = self.q.as_ref() {
+            struct_ser.serialize_field("q", v)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for EventBatchSwap {
+impl<'de> serde::Deserialize<'de> for BareTradingFunction {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "batch_swap_output_data",
-            "batchSwapOutputData",
-            "swap_execution_1_for_2",
-            "swapExecution1For2",
-            "swap_execution_2_for_1",
-            "swapExecution2For1",
+            "fee",
+            "p",
+            "q",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            BatchSwapOutputData,
-            SwapExecution1For2,
-            SwapExecution2For1,
+            Fee,
+            P,
+            Q,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -1335,9 +1207,9 @@ impl<'de> serde::Deserialize<'de> for EventBatchSwap {
                         E: serde::de::Error,
                     {
                         match value {
-                            "batchSwapOutputData" | "batch_swap_output_data" => Ok(GeneratedField::BatchSwapOutputData),
-                            "swapExecution1For2" | "swap_execution_1_for_2" => Ok(GeneratedField::SwapExecution1For2),
-                            "swapExecution2For1" | "swap_execution_2_for_1" => Ok(GeneratedField::SwapExecution2For1),
+                            "fee" => Ok(GeneratedField::Fee),
+                            "p" => Ok(GeneratedField::P),
+                            "q" => Ok(GeneratedField::Q),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -1347,55 +1219,57 @@ impl<'de> serde::Deserialize<'de> for EventBatchSwap {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = EventBatchSwap;
+            type Value = BareTradingFunction;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.core.component.dex.v1.EventBatchSwap")
+                formatter.write_str("struct penumbra.core.component.dex.v1.BareTradingFunction")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<EventBatchSwap, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<BareTradingFunction, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut batch_swap_output_data__ = None;
-                let mut swap_execution_1_for_2__ = None;
-                let mut swap_execution_2_for_1__ = None;
+                let mut fee__ = None;
+                let mut p__ = None;
+                let mut q__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::BatchSwapOutputData => {
-                            if batch_swap_output_data__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("batchSwapOutputData"));
+                        GeneratedField::Fee => {
+                            if fee__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("fee"));
                             }
-                            batch_swap_output_data__ = map_.next_value()?;
+                            fee__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
                         }
-                        GeneratedField::SwapExecution1For2 => {
-                            if swap_execution_1_for_2__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("swapExecution1For2"));
+                        GeneratedField::P => {
+                            if p__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("p"));
                             }
-                            swap_execution_1_for_2__ = map_.next_value()?;
+                            p__ = map_.next_value()?;
                         }
-                        GeneratedField::SwapExecution2For1 => {
-                            if swap_execution_2_for_1__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("swapExecution2For1"));
+                        GeneratedField::Q => {
+                            if q__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("q"));
                             }
-                            swap_execution_2_for_1__ = map_.next_value()?;
+                            q__ = map_.next_value()?;
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(EventBatchSwap {
-                    batch_swap_output_data: batch_swap_output_data__,
-                    swap_execution_1_for_2: swap_execution_1_for_2__,
-                    swap_execution_2_for_1: swap_execution_2_for_1__,
+                Ok(BareTradingFunction {
+                    fee: fee__.unwrap_or_default(),
+                    p: p__,
+                    q: q__,
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.core.component.dex.v1.EventBatchSwap", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.core.component.dex.v1.BareTradingFunction", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for EventPositionClose {
+impl serde::Serialize for BatchSwapOutputData {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -1403,40 +1277,6651 @@ impl serde::Serialize for EventPositionClose {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.position_id.is_some() {
+        if self.delta_1.is_some() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.EventPositionClose", len)?;
-        if let Some(v) = self.position_id.as_ref() {
-            struct_ser.serialize_field("positionId", v)?;
+        if self.delta_2.is_some() {
+            len += 1;
         }
-        struct_ser.end()
-    }
-}
-impl<'de> serde::Deserialize<'de> for EventPositionClose {
-    #[allow(deprecated)]
-    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
-    where
-        D: serde::Deserializer<'de>,
-    {
-        const FIELDS: &[&str] = &[
-            "position_id",
-            "positionId",
-        ];
-
-        #[allow(clippy::enum_variant_names)]
-        enum GeneratedField {
-            PositionId,
-            __SkipField__,
+        if self.lambda_1.is_some() {
+            len += 1;
         }
-        impl<'de> serde::Deserialize<'de> for GeneratedField {
-            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
-            where
-                D: serde::Deserializer<'de>,
-            {
-                struct GeneratedVisitor;
-
-                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+        if self.lambda_2.is_some() {
+            len += 1;
+        }
+        if self.unfilled_1.is_some() {
+            len += 1;
+        }
+        if self.unfilled_2.is_some() {
+            len += 1;
+        }
+        if self.height != 0 {
+            len += 1;
+        }
+        if self.trading_pair.is_some() {
+            len += 1;
+        }
+        if self.epoch_starting_height != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.BatchSwapOutputData", len)?;
+        if let Some(v) = self.delta_1.as_ref() {
+            struct_ser.serialize_field("delta1", v)?;
+        }
+        if let Some(v) = self.delta_2.as_ref() {
+            struct_ser.serialize_field("delta2", v)?;
+        }
+        if let Some(v) = self.lambda_1.as_ref() {
+            struct_ser.serialize_field("lambda1", v)?;
+        }
+        if let Some(v) = self.lambda_2.as_ref() {
+            struct_ser.serialize_field("lambda2", v)?;
+        }
+        if let Some(v) = self.unfilled_1.as_ref() {
+            struct_ser.serialize_field("unfilled1", v)?;
+        }
+        if let Some(v) = self.unfilled_2.as_ref() {
+            struct_ser.serialize_field("unfilled2", v)?;
+        }
+        if self.height != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("height", ToString::to_string(&self.height).as_str())?;
+        }
+        if let Some(v) = self.trading_pair.as_ref() {
+            struct_ser.serialize_field("tradingPair", v)?;
+        }
+        if self.epoch_starting_height != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("epochStartingHeight", ToString::to_string(&self.epoch_starting_height).as_str())?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for BatchSwapOutputData {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "delta_1",
+            "delta1",
+            "delta_2",
+            "delta2",
+            "lambda_1",
+            "lambda1",
+            "lambda_2",
+            "lambda2",
+            "unfilled_1",
+            "unfilled1",
+            "unfilled_2",
+            "unfilled2",
+            "height",
+            "trading_pair",
+            "tradingPair",
+            "epoch_starting_height",
+            "epochStartingHeight",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Delta1,
+            Delta2,
+            Lambda1,
+            Lambda2,
+            Unfilled1,
+            Unfilled2,
+            Height,
+            TradingPair,
+            EpochStartingHeight,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "delta1" | "delta_1" => Ok(GeneratedField::Delta1),
+                            "delta2" | "delta_2" => Ok(GeneratedField::Delta2),
+                            "lambda1" | "lambda_1" => Ok(GeneratedField::Lambda1),
+                            "lambda2" | "lambda_2" => Ok(GeneratedField::Lambda2),
+                            "unfilled1" | "unfilled_1" => Ok(GeneratedField::Unfilled1),
+                            "unfilled2" | "unfilled_2" => Ok(GeneratedField::Unfilled2),
+                            "height" => Ok(GeneratedField::Height),
+                            "tradingPair" | "trading_pair" => Ok(GeneratedField::TradingPair),
+                            "epochStartingHeight" | "epoch_starting_height" => Ok(GeneratedField::EpochStartingHeight),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = BatchSwapOutputData;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.dex.v1.BatchSwapOutputData")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<BatchSwapOutputData, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut delta_1__ = None;
+                let mut delta_2__ = None;
+                let mut lambda_1__ = None;
+                let mut lambda_2__ = None;
+                let mut unfilled_1__ = None;
+                let mut unfilled_2__ = None;
+                let mut height__ = None;
+                let mut trading_pair__ = None;
+                let mut epoch_starting_height__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Delta1 => {
+                            if delta_1__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("delta1"));
+                            }
+                            delta_1__ = map_.next_value()?;
+                        }
+                        GeneratedField::Delta2 => {
+                            if delta_2__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("delta2"));
+                            }
+                            delta_2__ = map_.next_value()?;
+                        }
+                        GeneratedField::Lambda1 => {
+                            if lambda_1__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("lambda1"));
+                            }
+                            lambda_1__ = map_.next_value()?;
+                        }
+                        GeneratedField::Lambda2 => {
+                            if lambda_2__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("lambda2"));
+                            }
+                            lambda_2__ = map_.next_value()?;
+                        }
+                        GeneratedField::Unfilled1 => {
+                            if unfilled_1__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("unfilled1"));
+                            }
+                            unfilled_1__ = map_.next_value()?;
+                        }
+                        GeneratedField::Unfilled2 => {
+                            if unfilled_2__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("unfilled2"));
+                            }
+                            unfilled_2__ = map_.next_value()?;
+                        }
+                        GeneratedField::Height => {
+                            if height__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("height"));
+                            }
+                            height__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::TradingPair => {
+                            if trading_pair__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("tradingPair"));
+                            }
+                            trading_pair__ = map_.next_value()?;
+                        }
+                        GeneratedField::EpochStartingHeight => {
+                            if epoch_starting_height__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("epochStartingHeight"));
+                            }
+                            epoch_starting_height__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(BatchSwapOutputData {
+                    delta_1: delta_1__,
+                    delta_2: delta_2__,
+                    lambda_1: lambda_1__,
+                    lambda_2: lambda_2__,
+                    unfilled_1: unfilled_1__,
+                    unfilled_2: unfilled_2__,
+                    height: height__.unwrap_or_default(),
+                    trading_pair: trading_pair__,
+                    epoch_starting_height: epoch_starting_height__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.dex.v1.BatchSwapOutputData", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for BatchSwapOutputDataRequest {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.height != 0 {
+            len += 1;
+        }
+        if self.trading_pair.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.BatchSwapOutputDataRequest", len)?;
+        if self.height != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("height", ToString::to_string(&self.height).as_str())?;
+        }
+        if let Some(v) = self.trading_pair.as_ref() {
+            struct_ser.serialize_field("tradingPair", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for BatchSwapOutputDataRequest {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "height",
+            "trading_pair",
+            "tradingPair",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Height,
+            TradingPair,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "height" => Ok(GeneratedField::Height),
+                            "tradingPair" | "trading_pair" => Ok(GeneratedField::TradingPair),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = BatchSwapOutputDataRequest;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.dex.v1.BatchSwapOutputDataRequest")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<BatchSwapOutputDataRequest, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut height__ = None;
+                let mut trading_pair__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Height => {
+                            if height__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("height"));
+                            }
+                            height__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::TradingPair => {
+                            if trading_pair__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("tradingPair"));
+                            }
+                            trading_pair__ = map_.next_value()?;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(BatchSwapOutputDataRequest {
+                    height: height__.unwrap_or_default(),
+                    trading_pair: trading_pair__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.dex.v1.BatchSwapOutputDataRequest", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for BatchSwapOutputDataResponse {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.data.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.BatchSwapOutputDataResponse", len)?;
+        if let Some(v) = self.data.as_ref() {
+            struct_ser.serialize_field("data", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for BatchSwapOutputDataResponse {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "data",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Data,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "data" => Ok(GeneratedField::Data),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = BatchSwapOutputDataResponse;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.dex.v1.BatchSwapOutputDataResponse")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<BatchSwapOutputDataResponse, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut data__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Data => {
+                            if data__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("data"));
+                            }
+                            data__ = map_.next_value()?;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(BatchSwapOutputDataResponse {
+                    data: data__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.dex.v1.BatchSwapOutputDataResponse", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for CircuitBreakerStatus {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.max_path_searches != 0 {
+            len += 1;
+        }
+        if self.max_executions != 0 {
+            len += 1;
+        }
+        if self.last_trip.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.CircuitBreakerStatus", len)?;
+        if self.max_path_searches != 0 {
+            struct_ser.serialize_field("maxPathSearches", &self.max_path_searches)?;
+        }
+        if self.max_executions != 0 {
+            struct_ser.serialize_field("maxExecutions", &self.max_executions)?;
+        }
+        if let Some(v) = self.last_trip.as_ref() {
+            struct_ser.serialize_field("lastTrip", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for CircuitBreakerStatus {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "max_path_searches",
+            "maxPathSearches",
+            "max_executions",
+            "maxExecutions",
+            "last_trip",
+            "lastTrip",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            MaxPathSearches,
+            MaxExecutions,
+            LastTrip,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "maxPathSearches" | "max_path_searches" => Ok(GeneratedField::MaxPathSearches),
+                            "maxExecutions" | "max_executions" => Ok(GeneratedField::MaxExecutions),
+                            "lastTrip" | "last_trip" => Ok(GeneratedField::LastTrip),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = CircuitBreakerStatus;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.dex.v1.CircuitBreakerStatus")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<CircuitBreakerStatus, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut max_path_searches__ = None;
+                let mut max_executions__ = None;
+                let mut last_trip__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::MaxPathSearches => {
+                            if max_path_searches__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("maxPathSearches"));
+                            }
+                            max_path_searches__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::MaxExecutions => {
+                            if max_executions__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("maxExecutions"));
+                            }
+                            max_executions__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::LastTrip => {
+                            if last_trip__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("lastTrip"));
+                            }
+                            last_trip__ = map_.next_value()?;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(CircuitBreakerStatus {
+                    max_path_searches: max_path_searches__.unwrap_or_default(),
+                    max_executions: max_executions__.unwrap_or_default(),
+                    last_trip: last_trip__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.dex.v1.CircuitBreakerStatus", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for CircuitBreakerStatusRequest {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let len = 0;
+        let struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.CircuitBreakerStatusRequest", len)?;
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for CircuitBreakerStatusRequest {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                            Ok(GeneratedField::__SkipField__)
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = CircuitBreakerStatusRequest;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.dex.v1.CircuitBreakerStatusRequest")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<CircuitBreakerStatusRequest, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                while map_.next_key::<GeneratedField>()?.is_some() {
+                    let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                }
+                Ok(CircuitBreakerStatusRequest {
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.dex.v1.CircuitBreakerStatusRequest", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for CircuitBreakerStatusResponse {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.status.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.CircuitBreakerStatusResponse", len)?;
+        if let Some(v) = self.status.as_ref() {
+            struct_ser.serialize_field("status", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for CircuitBreakerStatusResponse {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "status",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Status,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "status" => Ok(GeneratedField::Status),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = CircuitBreakerStatusResponse;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.dex.v1.CircuitBreakerStatusResponse")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<CircuitBreakerStatusResponse, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut status__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Status => {
+                            if status__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("status"));
+                            }
+                            status__ = map_.next_value()?;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(CircuitBreakerStatusResponse {
+                    status: status__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.dex.v1.CircuitBreakerStatusResponse", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for DexParameters {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.fee_tiers.is_empty() {
+            len += 1;
+        }
+        if self.protocol_fee_share_bps != 0 {
+            len += 1;
+        }
+        if !self.paused_trading_pairs.is_empty() {
+            len += 1;
+        }
+        if self.execution_trace_retention_blocks != 0 {
+            len += 1;
+        }
+        if self.route_pruning_epsilon_bps != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.DexParameters", len)?;
+        if !self.fee_tiers.is_empty() {
+            struct_ser.serialize_field("feeTiers", &self.fee_tiers)?;
+        }
+        if self.protocol_fee_share_bps != 0 {
+            struct_ser.serialize_field("protocolFeeShareBps", &self.protocol_fee_share_bps)?;
+        }
+        if !self.paused_trading_pairs.is_empty() {
+            struct_ser.serialize_field("pausedTradingPairs", &self.paused_trading_pairs)?;
+        }
+        if self.execution_trace_retention_blocks != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("executionTraceRetentionBlocks", ToString::to_string(&self.execution_trace_retention_blocks).as_str())?;
+        }
+        if self.route_pruning_epsilon_bps != 0 {
+            struct_ser.serialize_field("routePruningEpsilonBps", &self.route_pruning_epsilon_bps)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for DexParameters {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "fee_tiers",
+            "feeTiers",
+            "protocol_fee_share_bps",
+            "protocolFeeShareBps",
+            "paused_trading_pairs",
+            "pausedTradingPairs",
+            "execution_trace_retention_blocks",
+            "executionTraceRetentionBlocks",
+            "route_pruning_epsilon_bps",
+            "routePruningEpsilonBps",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            FeeTiers,
+            ProtocolFeeShareBps,
+            PausedTradingPairs,
+            ExecutionTraceRetentionBlocks,
+            RoutePruningEpsilonBps,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "feeTiers" | "fee_tiers" => Ok(GeneratedField::FeeTiers),
+                            "protocolFeeShareBps" | "protocol_fee_share_bps" => Ok(GeneratedField::ProtocolFeeShareBps),
+                            "pausedTradingPairs" | "paused_trading_pairs" => Ok(GeneratedField::PausedTradingPairs),
+                            "executionTraceRetentionBlocks" | "execution_trace_retention_blocks" => Ok(GeneratedField::ExecutionTraceRetentionBlocks),
+                            "routePruningEpsilonBps" | "route_pruning_epsilon_bps" => Ok(GeneratedField::RoutePruningEpsilonBps),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = DexParameters;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.dex.v1.DexParameters")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<DexParameters, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut fee_tiers__ = None;
+                let mut protocol_fee_share_bps__ = None;
+                let mut paused_trading_pairs__ = None;
+                let mut execution_trace_retention_blocks__ = None;
+                let mut route_pruning_epsilon_bps__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::FeeTiers => {
+                            if fee_tiers__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("feeTiers"));
+                            }
+                            fee_tiers__ = 
+                                Some(map_.next_value::<Vec<::pbjson::private::NumberDeserialize<_>>>()?
+                                    .into_iter().map(|x| x.0).collect())
+                            ;
+                        }
+                        GeneratedField::ProtocolFeeShareBps => {
+                            if protocol_fee_share_bps__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("protocolFeeShareBps"));
+                            }
+                            protocol_fee_share_bps__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::PausedTradingPairs => {
+                            if paused_trading_pairs__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("pausedTradingPairs"));
+                            }
+                            paused_trading_pairs__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::ExecutionTraceRetentionBlocks => {
+                            if execution_trace_retention_blocks__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("executionTraceRetentionBlocks"));
+                            }
+                            execution_trace_retention_blocks__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::RoutePruningEpsilonBps => {
+                            if route_pruning_epsilon_bps__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("routePruningEpsilonBps"));
+                            }
+                            route_pruning_epsilon_bps__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(DexParameters {
+                    fee_tiers: fee_tiers__.unwrap_or_default(),
+                    protocol_fee_share_bps: protocol_fee_share_bps__.unwrap_or_default(),
+                    paused_trading_pairs: paused_trading_pairs__.unwrap_or_default(),
+                    execution_trace_retention_blocks: execution_trace_retention_blocks__.unwrap_or_default(),
+                    route_pruning_epsilon_bps: route_pruning_epsilon_bps__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.dex.v1.DexParameters", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for DirectedTradingPair {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.start.is_some() {
+            len += 1;
+        }
+        if self.end.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.DirectedTradingPair", len)?;
+        if let Some(v) = self.start.as_ref() {
+            struct_ser.serialize_field("start", v)?;
+        }
+        if let Some(v) = self.end.as_ref() {
+            struct_ser.serialize_field("end", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for DirectedTradingPair {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "start",
+            "end",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Start,
+            End,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "start" => Ok(GeneratedField::Start),
+                            "end" => Ok(GeneratedField::End),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = DirectedTradingPair;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.dex.v1.DirectedTradingPair")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<DirectedTradingPair, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut start__ = None;
+                let mut end__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Start => {
+                            if start__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("start"));
+                            }
+                            start__ = map_.next_value()?;
+                        }
+                        GeneratedField::End => {
+                            if end__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("end"));
+                            }
+                            end__ = map_.next_value()?;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(DirectedTradingPair {
+                    start: start__,
+                    end: end__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.dex.v1.DirectedTradingPair", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for DutchAuction {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.description.is_some() {
+            len += 1;
+        }
+        if self.state.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.DutchAuction", len)?;
+        if let Some(v) = self.description.as_ref() {
+            struct_ser.serialize_field("description", v)?;
+        }
+        if let Some(v) = self.state.as_ref() {
+            struct_ser.serialize_field("state", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for DutchAuction {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "description",
+            "state",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Description,
+            State,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "description" => Ok(GeneratedField::Description),
+                            "state" => Ok(GeneratedField::State),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = DutchAuction;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.dex.v1.DutchAuction")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<DutchAuction, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut description__ = None;
+                let mut state__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Description => {
+                            if description__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("description"));
+                            }
+                            description__ = map_.next_value()?;
+                        }
+                        GeneratedField::State => {
+                            if state__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("state"));
+                            }
+                            state__ = map_.next_value()?;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(DutchAuction {
+                    description: description__,
+                    state: state__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.dex.v1.DutchAuction", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for DutchAuctionByIdRequest {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.auction_id.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.DutchAuctionByIdRequest", len)?;
+        if let Some(v) = self.auction_id.as_ref() {
+            struct_ser.serialize_field("auctionId", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for DutchAuctionByIdRequest {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "auction_id",
+            "auctionId",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            AuctionId,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "auctionId" | "auction_id" => Ok(GeneratedField::AuctionId),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = DutchAuctionByIdRequest;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.dex.v1.DutchAuctionByIdRequest")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<DutchAuctionByIdRequest, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut auction_id__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::AuctionId => {
+                            if auction_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("auctionId"));
+                            }
+                            auction_id__ = map_.next_value()?;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(DutchAuctionByIdRequest {
+                    auction_id: auction_id__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.dex.v1.DutchAuctionByIdRequest", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for DutchAuctionByIdResponse {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.auction.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.DutchAuctionByIdResponse", len)?;
+        if let Some(v) = self.auction.as_ref() {
+            struct_ser.serialize_field("auction", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for DutchAuctionByIdResponse {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "auction",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Auction,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "auction" => Ok(GeneratedField::Auction),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = DutchAuctionByIdResponse;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.dex.v1.DutchAuctionByIdResponse")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<DutchAuctionByIdResponse, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut auction__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Auction => {
+                            if auction__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("auction"));
+                            }
+                            auction__ = map_.next_value()?;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(DutchAuctionByIdResponse {
+                    auction: auction__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.dex.v1.DutchAuctionByIdResponse", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for DutchAuctionDescription {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.input.is_some() {
+            len += 1;
+        }
+        if self.output_id.is_some() {
+            len += 1;
+        }
+        if self.max_output.is_some() {
+            len += 1;
+        }
+        if self.min_output.is_some() {
+            len += 1;
+        }
+        if self.start_height != 0 {
+            len += 1;
+        }
+        if self.end_height != 0 {
+            len += 1;
+        }
+        if !self.nonce.is_empty() {
+            len += 1;
+        }
+        if self.skip_dex_fill {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.DutchAuctionDescription", len)?;
+        if let Some(v) = self.input.as_ref() {
+            struct_ser.serialize_field("input", v)?;
+        }
+        if let Some(v) = self.output_id.as_ref() {
+            struct_ser.serialize_field("outputId", v)?;
+        }
+        if let Some(v) = self.max_output.as_ref() {
+            struct_ser.serialize_field("maxOutput", v)?;
+        }
+        if let Some(v) = self.min_output.as_ref() {
+            struct_ser.serialize_field("minOutput", v)?;
+        }
+        if self.start_height != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("startHeight", ToString::to_string(&self.start_height).as_str())?;
+        }
+        if self.end_height != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("endHeight", ToString::to_string(&self.end_height).as_str())?;
+        }
+        if !self.nonce.is_empty() {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("nonce", pbjson::private::base64::encode(&self.nonce).as_str())?;
+        }
+        if self.skip_dex_fill {
+            struct_ser.serialize_field("skipDexFill", &self.skip_dex_fill)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for DutchAuctionDescription {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "input",
+            "output_id",
+            "outputId",
+            "max_output",
+            "maxOutput",
+            "min_output",
+            "minOutput",
+            "start_height",
+            "startHeight",
+            "end_height",
+            "endHeight",
+            "nonce",
+            "skip_dex_fill",
+            "skipDexFill",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Input,
+            OutputId,
+            MaxOutput,
+            MinOutput,
+            StartHeight,
+            EndHeight,
+            Nonce,
+            SkipDexFill,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "input" => Ok(GeneratedField::Input),
+                            "outputId" | "output_id" => Ok(GeneratedField::OutputId),
+                            "maxOutput" | "max_output" => Ok(GeneratedField::MaxOutput),
+                            "minOutput" | "min_output" => Ok(GeneratedField::MinOutput),
+                            "startHeight" | "start_height" => Ok(GeneratedField::StartHeight),
+                            "endHeight" | "end_height" => Ok(GeneratedField::EndHeight),
+                            "nonce" => Ok(GeneratedField::Nonce),
+                            "skipDexFill" | "skip_dex_fill" => Ok(GeneratedField::SkipDexFill),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = DutchAuctionDescription;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.dex.v1.DutchAuctionDescription")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<DutchAuctionDescription, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut input__ = None;
+                let mut output_id__ = None;
+                let mut max_output__ = None;
+                let mut min_output__ = None;
+                let mut start_height__ = None;
+                let mut end_height__ = None;
+                let mut nonce__ = None;
+                let mut skip_dex_fill__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Input => {
+                            if input__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("input"));
+                            }
+                            input__ = map_.next_value()?;
+                        }
+                        GeneratedField::OutputId => {
+                            if output_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("outputId"));
+                            }
+                            output_id__ = map_.next_value()?;
+                        }
+                        GeneratedField::MaxOutput => {
+                            if max_output__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("maxOutput"));
+                            }
+                            max_output__ = map_.next_value()?;
+                        }
+                        GeneratedField::MinOutput => {
+                            if min_output__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("minOutput"));
+                            }
+                            min_output__ = map_.next_value()?;
+                        }
+                        GeneratedField::StartHeight => {
+                            if start_height__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("startHeight"));
+                            }
+                            start_height__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::EndHeight => {
+                            if end_height__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("endHeight"));
+                            }
+                            end_height__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::Nonce => {
+                            if nonce__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("nonce"));
+                            }
+                            nonce__ = 
+                                Some(map_.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::SkipDexFill => {
+                            if skip_dex_fill__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("skipDexFill"));
+                            }
+                            skip_dex_fill__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(DutchAuctionDescription {
+                    input: input__,
+                    output_id: output_id__,
+                    max_output: max_output__,
+                    min_output: min_output__,
+                    start_height: start_height__.unwrap_or_default(),
+                    end_height: end_height__.unwrap_or_default(),
+                    nonce: nonce__.unwrap_or_default(),
+                    skip_dex_fill: skip_dex_fill__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.dex.v1.DutchAuctionDescription", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for DutchAuctionState {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.seq != 0 {
+            len += 1;
+        }
+        if self.input_reserves.is_some() {
+            len += 1;
+        }
+        if self.output_reserves.is_some() {
+            len += 1;
+        }
+        if self.current_position.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.DutchAuctionState", len)?;
+        if self.seq != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("seq", ToString::to_string(&self.seq).as_str())?;
+        }
+        if let Some(v) = self.input_reserves.as_ref() {
+            struct_ser.serialize_field("inputReserves", v)?;
+        }
+        if let Some(v) = self.output_reserves.as_ref() {
+            struct_ser.serialize_field("outputReserves", v)?;
+        }
+        if let Some(v) = self.current_position.as_ref() {
+            struct_ser.serialize_field("currentPosition", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for DutchAuctionState {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "seq",
+            "input_reserves",
+            "inputReserves",
+            "output_reserves",
+            "outputReserves",
+            "current_position",
+            "currentPosition",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Seq,
+            InputReserves,
+            OutputReserves,
+            CurrentPosition,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "seq" => Ok(GeneratedField::Seq),
+                            "inputReserves" | "input_reserves" => Ok(GeneratedField::InputReserves),
+                            "outputReserves" | "output_reserves" => Ok(GeneratedField::OutputReserves),
+                            "currentPosition" | "current_position" => Ok(GeneratedField::CurrentPosition),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = DutchAuctionState;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.dex.v1.DutchAuctionState")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<DutchAuctionState, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut seq__ = None;
+                let mut input_reserves__ = None;
+                let mut output_reserves__ = None;
+                let mut current_position__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Seq => {
+                            if seq__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("seq"));
+                            }
+                            seq__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::InputReserves => {
+                            if input_reserves__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("inputReserves"));
+                            }
+                            input_reserves__ = map_.next_value()?;
+                        }
+                        GeneratedField::OutputReserves => {
+                            if output_reserves__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("outputReserves"));
+                            }
+                            output_reserves__ = map_.next_value()?;
+                        }
+                        GeneratedField::CurrentPosition => {
+                            if current_position__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("currentPosition"));
+                            }
+                            current_position__ = map_.next_value()?;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(DutchAuctionState {
+                    seq: seq__.unwrap_or_default(),
+                    input_reserves: input_reserves__,
+                    output_reserves: output_reserves__,
+                    current_position: current_position__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.dex.v1.DutchAuctionState", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for DynamicFee {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.max_fee != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.DynamicFee", len)?;
+        if self.max_fee != 0 {
+            struct_ser.serialize_field("maxFee", &self.max_fee)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for DynamicFee {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "max_fee",
+            "maxFee",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            MaxFee,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "maxFee" | "max_fee" => Ok(GeneratedField::MaxFee),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = DynamicFee;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.dex.v1.DynamicFee")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<DynamicFee, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut max_fee__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::MaxFee => {
+                            if max_fee__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("maxFee"));
+                            }
+                            max_fee__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(DynamicFee {
+                    max_fee: max_fee__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.dex.v1.DynamicFee", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for EventArbExecution {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.height != 0 {
+            len += 1;
+        }
+        if self.swap_execution.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.EventArbExecution", len)?;
+        if self.height != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("height", ToString::to_string(&self.height).as_str())?;
+        }
+        if let Some(v) = self.swap_execution.as_ref() {
+            struct_ser.serialize_field("swapExecution", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for EventArbExecution {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "height",
+            "swap_execution",
+            "swapExecution",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Height,
+            SwapExecution,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "height" => Ok(GeneratedField::Height),
+                            "swapExecution" | "swap_execution" => Ok(GeneratedField::SwapExecution),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = EventArbExecution;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.dex.v1.EventArbExecution")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<EventArbExecution, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut height__ = None;
+                let mut swap_execution__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Height => {
+                            if height__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("height"));
+                            }
+                            height__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::SwapExecution => {
+                            if swap_execution__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("swapExecution"));
+                            }
+                            swap_execution__ = map_.next_value()?;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(EventArbExecution {
+                    height: height__.unwrap_or_default(),
+                    swap_execution: swap_execution__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.dex.v1.EventArbExecution", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for EventBatchSwap {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.batch_swap_output_data.is_some() {
+            len += 1;
+        }
+        if self.swap_execution_1_for_2.is_some() {
+            len += 1;
+        }
+        if self.swap_execution_2_for_1.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.EventBatchSwap", len)?;
+        if let Some(v) = self.batch_swap_output_data.as_ref() {
+            struct_ser.serialize_field("batchSwapOutputData", v)?;
+        }
+        if let Some(v) = self.swap_execution_1_for_2.as_ref() {
+            struct_ser.serialize_field("swapExecution1For2", v)?;
+        }
+        if let Some(v) = self.swap_execution_2_for_1.as_ref() {
+            struct_ser.serialize_field("swapExecution2For1", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for EventBatchSwap {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "batch_swap_output_data",
+            "batchSwapOutputData",
+            "swap_execution_1_for_2",
+            "swapExecution1For2",
+            "swap_execution_2_for_1",
+            "swapExecution2For1",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            BatchSwapOutputData,
+            SwapExecution1For2,
+            SwapExecution2For1,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "batchSwapOutputData" | "batch_swap_output_data" => Ok(GeneratedField::BatchSwapOutputData),
+                            "swapExecution1For2" | "swap_execution_1_for_2" => Ok(GeneratedField::SwapExecution1For2),
+                            "swapExecution2For1" | "swap_execution_2_for_1" => Ok(GeneratedField::SwapExecution2For1),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = EventBatchSwap;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.dex.v1.EventBatchSwap")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<EventBatchSwap, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut batch_swap_output_data__ = None;
+                let mut swap_execution_1_for_2__ = None;
+                let mut swap_execution_2_for_1__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::BatchSwapOutputData => {
+                            if batch_swap_output_data__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("batchSwapOutputData"));
+                            }
+                            batch_swap_output_data__ = map_.next_value()?;
+                        }
+                        GeneratedField::SwapExecution1For2 => {
+                            if swap_execution_1_for_2__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("swapExecution1For2"));
+                            }
+                            swap_execution_1_for_2__ = map_.next_value()?;
+                        }
+                        GeneratedField::SwapExecution2For1 => {
+                            if swap_execution_2_for_1__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("swapExecution2For1"));
+                            }
+                            swap_execution_2_for_1__ = map_.next_value()?;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(EventBatchSwap {
+                    batch_swap_output_data: batch_swap_output_data__,
+                    swap_execution_1_for_2: swap_execution_1_for_2__,
+                    swap_execution_2_for_1: swap_execution_2_for_1__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.dex.v1.EventBatchSwap", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for EventDutchAuctionEnded {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.auction_id.is_some() {
+            len += 1;
+        }
+        if self.state.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.EventDutchAuctionEnded", len)?;
+        if let Some(v) = self.auction_id.as_ref() {
+            struct_ser.serialize_field("auctionId", v)?;
+        }
+        if let Some(v) = self.state.as_ref() {
+            struct_ser.serialize_field("state", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for EventDutchAuctionEnded {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "auction_id",
+            "auctionId",
+            "state",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            AuctionId,
+            State,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "auctionId" | "auction_id" => Ok(GeneratedField::AuctionId),
+                            "state" => Ok(GeneratedField::State),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = EventDutchAuctionEnded;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.dex.v1.EventDutchAuctionEnded")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<EventDutchAuctionEnded, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut auction_id__ = None;
+                let mut state__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::AuctionId => {
+                            if auction_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("auctionId"));
+                            }
+                            auction_id__ = map_.next_value()?;
+                        }
+                        GeneratedField::State => {
+                            if state__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("state"));
+                            }
+                            state__ = map_.next_value()?;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(EventDutchAuctionEnded {
+                    auction_id: auction_id__,
+                    state: state__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.dex.v1.EventDutchAuctionEnded", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for EventDutchAuctionExecution {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.auction_id.is_some() {
+            len += 1;
+        }
+        if self.height != 0 {
+            len += 1;
+        }
+        if self.swap_execution.is_some() {
+            len += 1;
+        }
+        if self.state.is_some() {
+            len += 1;
+        }
+        if self.position_input_sold.is_some() {
+            len += 1;
+        }
+        if self.position_output_received.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.EventDutchAuctionExecution", len)?;
+        if let Some(v) = self.auction_id.as_ref() {
+            struct_ser.serialize_field("auctionId", v)?;
+        }
+        if self.height != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("height", ToString::to_string(&self.height).as_str())?;
+        }
+        if let Some(v) = self.swap_execution.as_ref() {
+            struct_ser.serialize_field("swapExecution", v)?;
+        }
+        if let Some(v) = self.state.as_ref() {
+            struct_ser.serialize_field("state", v)?;
+        }
+        if let Some(v) = self.position_input_sold.as_ref() {
+            struct_ser.serialize_field("positionInputSold", v)?;
+        }
+        if let Some(v) = self.position_output_received.as_ref() {
+            struct_ser.serialize_field("positionOutputReceived", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for EventDutchAuctionExecution {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "auction_id",
+            "auctionId",
+            "height",
+            "swap_execution",
+            "swapExecution",
+            "state",
+            "position_input_sold",
+            "positionInputSold",
+            "position_output_received",
+            "positionOutputReceived",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            AuctionId,
+            Height,
+            SwapExecution,
+            State,
+            PositionInputSold,
+            PositionOutputReceived,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "auctionId" | "auction_id" => Ok(GeneratedField::AuctionId),
+                            "height" => Ok(GeneratedField::Height),
+                            "swapExecution" | "swap_execution" => Ok(GeneratedField::SwapExecution),
+                            "state" => Ok(GeneratedField::State),
+                            "positionInputSold" | "position_input_sold" => Ok(GeneratedField::PositionInputSold),
+                            "positionOutputReceived" | "position_output_received" => Ok(GeneratedField::PositionOutputReceived),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = EventDutchAuctionExecution;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.dex.v1.EventDutchAuctionExecution")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<EventDutchAuctionExecution, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut auction_id__ = None;
+                let mut height__ = None;
+                let mut swap_execution__ = None;
+                let mut state__ = None;
+                let mut position_input_sold__ = None;
+                let mut position_output_received__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::AuctionId => {
+                            if auction_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("auctionId"));
+                            }
+                            auction_id__ = map_.next_value()?;
+                        }
+                        GeneratedField::Height => {
+                            if height__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("height"));
+                            }
+                            height__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::SwapExecution => {
+                            if swap_execution__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("swapExecution"));
+                            }
+                            swap_execution__ = map_.next_value()?;
+                        }
+                        GeneratedField::State => {
+                            if state__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("state"));
+                            }
+                            state__ = map_.next_value()?;
+                        }
+                        GeneratedField::PositionInputSold => {
+                            if position_input_sold__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("positionInputSold"));
+                            }
+                            position_input_sold__ = map_.next_value()?;
+                        }
+                        GeneratedField::PositionOutputReceived => {
+                            if position_output_received__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("positionOutputReceived"));
+                            }
+                            position_output_received__ = map_.next_value()?;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(EventDutchAuctionExecution {
+                    auction_id: auction_id__,
+                    height: height__.unwrap_or_default(),
+                    swap_execution: swap_execution__,
+                    state: state__,
+                    position_input_sold: position_input_sold__,
+                    position_output_received: position_output_received__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.dex.v1.EventDutchAuctionExecution", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for EventDutchAuctionScheduled {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.auction_id.is_some() {
+            len += 1;
+        }
+        if self.description.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.EventDutchAuctionScheduled", len)?;
+        if let Some(v) = self.auction_id.as_ref() {
+            struct_ser.serialize_field("auctionId", v)?;
+        }
+        if let Some(v) = self.description.as_ref() {
+            struct_ser.serialize_field("description", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for EventDutchAuctionScheduled {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "auction_id",
+            "auctionId",
+            "description",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            AuctionId,
+            Description,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "auctionId" | "auction_id" => Ok(GeneratedField::AuctionId),
+                            "description" => Ok(GeneratedField::Description),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = EventDutchAuctionScheduled;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.dex.v1.EventDutchAuctionScheduled")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<EventDutchAuctionScheduled, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut auction_id__ = None;
+                let mut description__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::AuctionId => {
+                            if auction_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("auctionId"));
+                            }
+                            auction_id__ = map_.next_value()?;
+                        }
+                        GeneratedField::Description => {
+                            if description__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("description"));
+                            }
+                            description__ = map_.next_value()?;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(EventDutchAuctionScheduled {
+                    auction_id: auction_id__,
+                    description: description__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.dex.v1.EventDutchAuctionScheduled", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for EventDutchAuctionWithdrawn {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.auction_id.is_some() {
+            len += 1;
+        }
+        if self.state.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.EventDutchAuctionWithdrawn", len)?;
+        if let Some(v) = self.auction_id.as_ref() {
+            struct_ser.serialize_field("auctionId", v)?;
+        }
+        if let Some(v) = self.state.as_ref() {
+            struct_ser.serialize_field("state", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for EventDutchAuctionWithdrawn {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "auction_id",
+            "auctionId",
+            "state",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            AuctionId,
+            State,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "auctionId" | "auction_id" => Ok(GeneratedField::AuctionId),
+                            "state" => Ok(GeneratedField::State),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = EventDutchAuctionWithdrawn;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.dex.v1.EventDutchAuctionWithdrawn")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<EventDutchAuctionWithdrawn, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut auction_id__ = None;
+                let mut state__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::AuctionId => {
+                            if auction_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("auctionId"));
+                            }
+                            auction_id__ = map_.next_value()?;
+                        }
+                        GeneratedField::State => {
+                            if state__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("state"));
+                            }
+                            state__ = map_.next_value()?;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(EventDutchAuctionWithdrawn {
+                    auction_id: auction_id__,
+                    state: state__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.dex.v1.EventDutchAuctionWithdrawn", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for EventPositionClose {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.position_id.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.EventPositionClose", len)?;
+        if let Some(v) = self.position_id.as_ref() {
+            struct_ser.serialize_field("positionId", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for EventPositionClose {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "position_id",
+            "positionId",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            PositionId,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "positionId" | "position_id" => Ok(GeneratedField::PositionId),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = EventPositionClose;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.dex.v1.EventPositionClose")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<EventPositionClose, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut position_id__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::PositionId => {
+                            if position_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("positionId"));
+                            }
+                            position_id__ = map_.next_value()?;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(EventPositionClose {
+                    position_id: position_id__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.dex.v1.EventPositionClose", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for EventPositionExecution {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.position_id.is_some() {
+            len += 1;
+        }
+        if self.trading_pair.is_some() {
+            len += 1;
+        }
+        if self.reserves_1.is_some() {
+            len += 1;
+        }
+        if self.reserves_2.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.EventPositionExecution", len)?;
+        if let Some(v) = self.position_id.as_ref() {
+            struct_ser.serialize_field("positionId", v)?;
+        }
+        if let Some(v) = self.trading_pair.as_ref() {
+            struct_ser.serialize_field("tradingPair", v)?;
+        }
+        if let Some(v) = self.reserves_1.as_ref() {
+            struct_ser.serialize_field("reserves1", v)?;
+        }
+        if let Some(v) = self.reserves_2.as_ref() {
+            struct_ser.serialize_field("reserves2", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for EventPositionExecution {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "position_id",
+            "positionId",
+            "trading_pair",
+            "tradingPair",
+            "reserves_1",
+            "reserves1",
+            "reserves_2",
+            "reserves2",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            PositionId,
+            TradingPair,
+            Reserves1,
+            Reserves2,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "positionId" | "position_id" => Ok(GeneratedField::PositionId),
+                            "tradingPair" | "trading_pair" => Ok(GeneratedField::TradingPair),
+                            "reserves1" | "reserves_1" => Ok(GeneratedField::Reserves1),
+                            "reserves2" | "reserves_2" => Ok(GeneratedField::Reserves2),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = EventPositionExecution;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.dex.v1.EventPositionExecution")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<EventPositionExecution, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut position_id__ = None;
+                let mut trading_pair__ = None;
+                let mut reserves_1__ = None;
+                let mut reserves_2__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::PositionId => {
+                            if position_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("positionId"));
+                            }
+                            position_id__ = map_.next_value()?;
+                        }
+                        GeneratedField::TradingPair => {
+                            if trading_pair__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("tradingPair"));
+                            }
+                            trading_pair__ = map_.next_value()?;
+                        }
+                        GeneratedField::Reserves1 => {
+                            if reserves_1__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("reserves1"));
+                            }
+                            reserves_1__ = map_.next_value()?;
+                        }
+                        GeneratedField::Reserves2 => {
+                            if reserves_2__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("reserves2"));
+                            }
+                            reserves_2__ = map_.next_value()?;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(EventPositionExecution {
+                    position_id: position_id__,
+                    trading_pair: trading_pair__,
+                    reserves_1: reserves_1__,
+                    reserves_2: reserves_2__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.dex.v1.EventPositionExecution", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for EventPositionOpen {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.position_id.is_some() {
+            len += 1;
+        }
+        if self.trading_pair.is_some() {
+            len += 1;
+        }
+        if self.reserves_1.is_some() {
+            len += 1;
+        }
+        if self.reserves_2.is_some() {
+            len += 1;
+        }
+        if self.trading_fee != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.EventPositionOpen", len)?;
+        if let Some(v) = self.position_id.as_ref() {
+            struct_ser.serialize_field("positionId", v)?;
+        }
+        if let Some(v) = self.trading_pair.as_ref() {
+            struct_ser.serialize_field("tradingPair", v)?;
+        }
+        if let Some(v) = self.reserves_1.as_ref() {
+            struct_ser.serialize_field("reserves1", v)?;
+        }
+        if let Some(v) = self.reserves_2.as_ref() {
+            struct_ser.serialize_field("reserves2", v)?;
+        }
+        if self.trading_fee != 0 {
+            struct_ser.serialize_field("tradingFee", &self.trading_fee)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for EventPositionOpen {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "position_id",
+            "positionId",
+            "trading_pair",
+            "tradingPair",
+            "reserves_1",
+            "reserves1",
+            "reserves_2",
+            "reserves2",
+            "trading_fee",
+            "tradingFee",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            PositionId,
+            TradingPair,
+            Reserves1,
+            Reserves2,
+            TradingFee,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "positionId" | "position_id" => Ok(GeneratedField::PositionId),
+                            "tradingPair" | "trading_pair" => Ok(GeneratedField::TradingPair),
+                            "reserves1" | "reserves_1" => Ok(GeneratedField::Reserves1),
+                            "reserves2" | "reserves_2" => Ok(GeneratedField::Reserves2),
+                            "tradingFee" | "trading_fee" => Ok(GeneratedField::TradingFee),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = EventPositionOpen;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.dex.v1.EventPositionOpen")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<EventPositionOpen, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut position_id__ = None;
+                let mut trading_pair__ = None;
+                let mut reserves_1__ = None;
+                let mut reserves_2__ = None;
+                let mut trading_fee__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::PositionId => {
+                            if position_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("positionId"));
+                            }
+                            position_id__ = map_.next_value()?;
+                        }
+                        GeneratedField::TradingPair => {
+                            if trading_pair__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("tradingPair"));
+                            }
+                            trading_pair__ = map_.next_value()?;
+                        }
+                        GeneratedField::Reserves1 => {
+                            if reserves_1__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("reserves1"));
+                            }
+                            reserves_1__ = map_.next_value()?;
+                        }
+                        GeneratedField::Reserves2 => {
+                            if reserves_2__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("reserves2"));
+                            }
+                            reserves_2__ = map_.next_value()?;
+                        }
+                        GeneratedField::TradingFee => {
+                            if trading_fee__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("tradingFee"));
+                            }
+                            trading_fee__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(EventPositionOpen {
+                    position_id: position_id__,
+                    trading_pair: trading_pair__,
+                    reserves_1: reserves_1__,
+                    reserves_2: reserves_2__,
+                    trading_fee: trading_fee__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.dex.v1.EventPositionOpen", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for EventPositionWithdraw {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.position_id.is_some() {
+            len += 1;
+        }
+        if self.trading_pair.is_some() {
+            len += 1;
+        }
+        if self.reserves_1.is_some() {
+            len += 1;
+        }
+        if self.reserves_2.is_some() {
+            len += 1;
+        }
+        if self.sequence != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.EventPositionWithdraw", len)?;
+        if let Some(v) = self.position_id.as_ref() {
+            struct_ser.serialize_field("positionId", v)?;
+        }
+        if let Some(v) = self.trading_pair.as_ref() {
+            struct_ser.serialize_field("tradingPair", v)?;
+        }
+        if let Some(v) = self.reserves_1.as_ref() {
+            struct_ser.serialize_field("reserves1", v)?;
+        }
+        if let Some(v) = self.reserves_2.as_ref() {
+            struct_ser.serialize_field("reserves2", v)?;
+        }
+        if self.sequence != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("sequence", ToString::to_string(&self.sequence).as_str())?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for EventPositionWithdraw {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "position_id",
+            "positionId",
+            "trading_pair",
+            "tradingPair",
+            "reserves_1",
+            "reserves1",
+            "reserves_2",
+            "reserves2",
+            "sequence",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            PositionId,
+            TradingPair,
+            Reserves1,
+            Reserves2,
+            Sequence,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "positionId" | "position_id" => Ok(GeneratedField::PositionId),
+                            "tradingPair" | "trading_pair" => Ok(GeneratedField::TradingPair),
+                            "reserves1" | "reserves_1" => Ok(GeneratedField::Reserves1),
+                            "reserves2" | "reserves_2" => Ok(GeneratedField::Reserves2),
+                            "sequence" => Ok(GeneratedField::Sequence),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = EventPositionWithdraw;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.dex.v1.EventPositionWithdraw")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<EventPositionWithdraw, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut position_id__ = None;
+                let mut trading_pair__ = None;
+                let mut reserves_1__ = None;
+                let mut reserves_2__ = None;
+                let mut sequence__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::PositionId => {
+                            if position_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("positionId"));
+                            }
+                            position_id__ = map_.next_value()?;
+                        }
+                        GeneratedField::TradingPair => {
+                            if trading_pair__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("tradingPair"));
+                            }
+                            trading_pair__ = map_.next_value()?;
+                        }
+                        GeneratedField::Reserves1 => {
+                            if reserves_1__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("reserves1"));
+                            }
+                            reserves_1__ = map_.next_value()?;
+                        }
+                        GeneratedField::Reserves2 => {
+                            if reserves_2__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("reserves2"));
+                            }
+                            reserves_2__ = map_.next_value()?;
+                        }
+                        GeneratedField::Sequence => {
+                            if sequence__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("sequence"));
+                            }
+                            sequence__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(EventPositionWithdraw {
+                    position_id: position_id__,
+                    trading_pair: trading_pair__,
+                    reserves_1: reserves_1__,
+                    reserves_2: reserves_2__,
+                    sequence: sequence__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.dex.v1.EventPositionWithdraw", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for EventProtocolFeeAccrual {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.position_id.is_some() {
+            len += 1;
+        }
+        if self.fee.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.EventProtocolFeeAccrual", len)?;
+        if let Some(v) = self.position_id.as_ref() {
+            struct_ser.serialize_field("positionId", v)?;
+        }
+        if let Some(v) = self.fee.as_ref() {
+            struct_ser.serialize_field("fee", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for EventProtocolFeeAccrual {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "position_id",
+            "positionId",
+            "fee",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            PositionId,
+            Fee,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "positionId" | "position_id" => Ok(GeneratedField::PositionId),
+                            "fee" => Ok(GeneratedField::Fee),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = EventProtocolFeeAccrual;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.dex.v1.EventProtocolFeeAccrual")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<EventProtocolFeeAccrual, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut position_id__ = None;
+                let mut fee__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::PositionId => {
+                            if position_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("positionId"));
+                            }
+                            position_id__ = map_.next_value()?;
+                        }
+                        GeneratedField::Fee => {
+                            if fee__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("fee"));
+                            }
+                            fee__ = map_.next_value()?;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(EventProtocolFeeAccrual {
+                    position_id: position_id__,
+                    fee: fee__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.dex.v1.EventProtocolFeeAccrual", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for EventSwap {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.trading_pair.is_some() {
+            len += 1;
+        }
+        if self.delta_1_i.is_some() {
+            len += 1;
+        }
+        if self.delta_2_i.is_some() {
+            len += 1;
+        }
+        if self.swap_commitment.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.EventSwap", len)?;
+        if let Some(v) = self.trading_pair.as_ref() {
+            struct_ser.serialize_field("tradingPair", v)?;
+        }
+        if let Some(v) = self.delta_1_i.as_ref() {
+            struct_ser.serialize_field("delta1I", v)?;
+        }
+        if let Some(v) = self.delta_2_i.as_ref() {
+            struct_ser.serialize_field("delta2I", v)?;
+        }
+        if let Some(v) = self.swap_commitment.as_ref() {
+            struct_ser.serialize_field("swapCommitment", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for EventSwap {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "trading_pair",
+            "tradingPair",
+            "delta_1_i",
+            "delta1I",
+            "delta_2_i",
+            "delta2I",
+            "swap_commitment",
+            "swapCommitment",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            TradingPair,
+            Delta1I,
+            Delta2I,
+            SwapCommitment,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "tradingPair" | "trading_pair" => Ok(GeneratedField::TradingPair),
+                            "delta1I" | "delta_1_i" => Ok(GeneratedField::Delta1I),
+                            "delta2I" | "delta_2_i" => Ok(GeneratedField::Delta2I),
+                            "swapCommitment" | "swap_commitment" => Ok(GeneratedField::SwapCommitment),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = EventSwap;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.dex.v1.EventSwap")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<EventSwap, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut trading_pair__ = None;
+                let mut delta_1_i__ = None;
+                let mut delta_2_i__ = None;
+                let mut swap_commitment__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::TradingPair => {
+                            if trading_pair__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("tradingPair"));
+                            }
+                            trading_pair__ = map_.next_value()?;
+                        }
+                        GeneratedField::Delta1I => {
+                            if delta_1_i__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("delta1I"));
+                            }
+                            delta_1_i__ = map_.next_value()?;
+                        }
+                        GeneratedField::Delta2I => {
+                            if delta_2_i__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("delta2I"));
+                            }
+                            delta_2_i__ = map_.next_value()?;
+                        }
+                        GeneratedField::SwapCommitment => {
+                            if swap_commitment__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("swapCommitment"));
+                            }
+                            swap_commitment__ = map_.next_value()?;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(EventSwap {
+                    trading_pair: trading_pair__,
+                    delta_1_i: delta_1_i__,
+                    delta_2_i: delta_2_i__,
+                    swap_commitment: swap_commitment__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.dex.v1.EventSwap", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for EventSwapClaim {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.trading_pair.is_some() {
+            len += 1;
+        }
+        if self.output_1_commitment.is_some() {
+            len += 1;
+        }
+        if self.output_2_commitment.is_some() {
+            len += 1;
+        }
+        if self.nullifier.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.EventSwapClaim", len)?;
+        if let Some(v) = self.trading_pair.as_ref() {
+            struct_ser.serialize_field("tradingPair", v)?;
+        }
+        if let Some(v) = self.output_1_commitment.as_ref() {
+            struct_ser.serialize_field("output1Commitment", v)?;
+        }
+        if let Some(v) = self.output_2_commitment.as_ref() {
+            struct_ser.serialize_field("output2Commitment", v)?;
+        }
+        if let Some(v) = self.nullifier.as_ref() {
+            struct_ser.serialize_field("nullifier", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for EventSwapClaim {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "trading_pair",
+            "tradingPair",
+            "output_1_commitment",
+            "output1Commitment",
+            "output_2_commitment",
+            "output2Commitment",
+            "nullifier",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            TradingPair,
+            Output1Commitment,
+            Output2Commitment,
+            Nullifier,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "tradingPair" | "trading_pair" => Ok(GeneratedField::TradingPair),
+                            "output1Commitment" | "output_1_commitment" => Ok(GeneratedField::Output1Commitment),
+                            "output2Commitment" | "output_2_commitment" => Ok(GeneratedField::Output2Commitment),
+                            "nullifier" => Ok(GeneratedField::Nullifier),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = EventSwapClaim;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.dex.v1.EventSwapClaim")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<EventSwapClaim, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut trading_pair__ = None;
+                let mut output_1_commitment__ = None;
+                let mut output_2_commitment__ = None;
+                let mut nullifier__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::TradingPair => {
+                            if trading_pair__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("tradingPair"));
+                            }
+                            trading_pair__ = map_.next_value()?;
+                        }
+                        GeneratedField::Output1Commitment => {
+                            if output_1_commitment__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("output1Commitment"));
+                            }
+                            output_1_commitment__ = map_.next_value()?;
+                        }
+                        GeneratedField::Output2Commitment => {
+                            if output_2_commitment__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("output2Commitment"));
+                            }
+                            output_2_commitment__ = map_.next_value()?;
+                        }
+                        GeneratedField::Nullifier => {
+                            if nullifier__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("nullifier"));
+                            }
+                            nullifier__ = map_.next_value()?;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(EventSwapClaim {
+                    trading_pair: trading_pair__,
+                    output_1_commitment: output_1_commitment__,
+                    output_2_commitment: output_2_commitment__,
+                    nullifier: nullifier__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.dex.v1.EventSwapClaim", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for EventTradingPairPaused {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.trading_pair.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.EventTradingPairPaused", len)?;
+        if let Some(v) = self.trading_pair.as_ref() {
+            struct_ser.serialize_field("tradingPair", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for EventTradingPairPaused {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "trading_pair",
+            "tradingPair",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            TradingPair,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "tradingPair" | "trading_pair" => Ok(GeneratedField::TradingPair),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = EventTradingPairPaused;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.dex.v1.EventTradingPairPaused")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<EventTradingPairPaused, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut trading_pair__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::TradingPair => {
+                            if trading_pair__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("tradingPair"));
+                            }
+                            trading_pair__ = map_.next_value()?;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(EventTradingPairPaused {
+                    trading_pair: trading_pair__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.dex.v1.EventTradingPairPaused", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for EventTradingPairResumed {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.trading_pair.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.EventTradingPairResumed", len)?;
+        if let Some(v) = self.trading_pair.as_ref() {
+            struct_ser.serialize_field("tradingPair", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for EventTradingPairResumed {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "trading_pair",
+            "tradingPair",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            TradingPair,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "tradingPair" | "trading_pair" => Ok(GeneratedField::TradingPair),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = EventTradingPairResumed;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.dex.v1.EventTradingPairResumed")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<EventTradingPairResumed, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut trading_pair__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::TradingPair => {
+                            if trading_pair__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("tradingPair"));
+                            }
+                            trading_pair__ = map_.next_value()?;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(EventTradingPairResumed {
+                    trading_pair: trading_pair__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.dex.v1.EventTradingPairResumed", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for ExecutionCircuitBreakerTrip {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.height != 0 {
+            len += 1;
+        }
+        if self.trading_pair.is_some() {
+            len += 1;
+        }
+        if self.path_searches != 0 {
+            len += 1;
+        }
+        if self.executions != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.ExecutionCircuitBreakerTrip", len)?;
+        if self.height != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("height", ToString::to_string(&self.height).as_str())?;
+        }
+        if let Some(v) = self.trading_pair.as_ref() {
+            struct_ser.serialize_field("tradingPair", v)?;
+        }
+        if self.path_searches != 0 {
+            struct_ser.serialize_field("pathSearches", &self.path_searches)?;
+        }
+        if self.executions != 0 {
+            struct_ser.serialize_field("executions", &self.executions)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for ExecutionCircuitBreakerTrip {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "height",
+            "trading_pair",
+            "tradingPair",
+            "path_searches",
+            "pathSearches",
+            "executions",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Height,
+            TradingPair,
+            PathSearches,
+            Executions,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "height" => Ok(GeneratedField::Height),
+                            "tradingPair" | "trading_pair" => Ok(GeneratedField::TradingPair),
+                            "pathSearches" | "path_searches" => Ok(GeneratedField::PathSearches),
+                            "executions" => Ok(GeneratedField::Executions),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = ExecutionCircuitBreakerTrip;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.dex.v1.ExecutionCircuitBreakerTrip")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ExecutionCircuitBreakerTrip, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut height__ = None;
+                let mut trading_pair__ = None;
+                let mut path_searches__ = None;
+                let mut executions__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Height => {
+                            if height__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("height"));
+                            }
+                            height__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::TradingPair => {
+                            if trading_pair__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("tradingPair"));
+                            }
+                            trading_pair__ = map_.next_value()?;
+                        }
+                        GeneratedField::PathSearches => {
+                            if path_searches__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("pathSearches"));
+                            }
+                            path_searches__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::Executions => {
+                            if executions__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("executions"));
+                            }
+                            executions__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(ExecutionCircuitBreakerTrip {
+                    height: height__.unwrap_or_default(),
+                    trading_pair: trading_pair__,
+                    path_searches: path_searches__.unwrap_or_default(),
+                    executions: executions__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.dex.v1.ExecutionCircuitBreakerTrip", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for GenesisContent {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.dex_params.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.GenesisContent", len)?;
+        if let Some(v) = self.dex_params.as_ref() {
+            struct_ser.serialize_field("dexParams", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for GenesisContent {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "dex_params",
+            "dexParams",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            DexParams,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "dexParams" | "dex_params" => Ok(GeneratedField::DexParams),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = GenesisContent;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.dex.v1.GenesisContent")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<GenesisContent, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut dex_params__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::DexParams => {
+                            if dex_params__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("dexParams"));
+                            }
+                            dex_params__ = map_.next_value()?;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(GenesisContent {
+                    dex_params: dex_params__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.dex.v1.GenesisContent", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for LiquidityPositionAnalyticsRequest {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.position_ids.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.LiquidityPositionAnalyticsRequest", len)?;
+        if !self.position_ids.is_empty() {
+            struct_ser.serialize_field("positionIds", &self.position_ids)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for LiquidityPositionAnalyticsRequest {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "position_ids",
+            "positionIds",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            PositionIds,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "positionIds" | "position_ids" => Ok(GeneratedField::PositionIds),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = LiquidityPositionAnalyticsRequest;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.dex.v1.LiquidityPositionAnalyticsRequest")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<LiquidityPositionAnalyticsRequest, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut position_ids__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::PositionIds => {
+                            if position_ids__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("positionIds"));
+                            }
+                            position_ids__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(LiquidityPositionAnalyticsRequest {
+                    position_ids: position_ids__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.dex.v1.LiquidityPositionAnalyticsRequest", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for LiquidityPositionAnalyticsResponse {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.analytics.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.LiquidityPositionAnalyticsResponse", len)?;
+        if !self.analytics.is_empty() {
+            struct_ser.serialize_field("analytics", &self.analytics)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for LiquidityPositionAnalyticsResponse {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "analytics",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Analytics,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "analytics" => Ok(GeneratedField::Analytics),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = LiquidityPositionAnalyticsResponse;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.dex.v1.LiquidityPositionAnalyticsResponse")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<LiquidityPositionAnalyticsResponse, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut analytics__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Analytics => {
+                            if analytics__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("analytics"));
+                            }
+                            analytics__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(LiquidityPositionAnalyticsResponse {
+                    analytics: analytics__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.dex.v1.LiquidityPositionAnalyticsResponse", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for LiquidityPositionByIdRequest {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.position_id.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.LiquidityPositionByIdRequest", len)?;
+        if let Some(v) = self.position_id.as_ref() {
+            struct_ser.serialize_field("positionId", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for LiquidityPositionByIdRequest {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "position_id",
+            "positionId",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            PositionId,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "positionId" | "position_id" => Ok(GeneratedField::PositionId),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = LiquidityPositionByIdRequest;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.dex.v1.LiquidityPositionByIdRequest")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<LiquidityPositionByIdRequest, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut position_id__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::PositionId => {
+                            if position_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("positionId"));
+                            }
+                            position_id__ = map_.next_value()?;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(LiquidityPositionByIdRequest {
+                    position_id: position_id__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.dex.v1.LiquidityPositionByIdRequest", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for LiquidityPositionByIdResponse {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.data.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.LiquidityPositionByIdResponse", len)?;
+        if let Some(v) = self.data.as_ref() {
+            struct_ser.serialize_field("data", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for LiquidityPositionByIdResponse {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "data",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Data,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "data" => Ok(GeneratedField::Data),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = LiquidityPositionByIdResponse;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.dex.v1.LiquidityPositionByIdResponse")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<LiquidityPositionByIdResponse, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut data__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Data => {
+                            if data__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("data"));
+                            }
+                            data__ = map_.next_value()?;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(LiquidityPositionByIdResponse {
+                    data: data__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.dex.v1.LiquidityPositionByIdResponse", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for LiquidityPositionsByIdRequest {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.position_id.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.LiquidityPositionsByIdRequest", len)?;
+        if !self.position_id.is_empty() {
+            struct_ser.serialize_field("positionId", &self.position_id)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for LiquidityPositionsByIdRequest {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "position_id",
+            "positionId",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            PositionId,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "positionId" | "position_id" => Ok(GeneratedField::PositionId),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = LiquidityPositionsByIdRequest;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.dex.v1.LiquidityPositionsByIdRequest")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<LiquidityPositionsByIdRequest, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut position_id__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::PositionId => {
+                            if position_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("positionId"));
+                            }
+                            position_id__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(LiquidityPositionsByIdRequest {
+                    position_id: position_id__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.dex.v1.LiquidityPositionsByIdRequest", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for LiquidityPositionsByIdResponse {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.data.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.LiquidityPositionsByIdResponse", len)?;
+        if let Some(v) = self.data.as_ref() {
+            struct_ser.serialize_field("data", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for LiquidityPositionsByIdResponse {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "data",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Data,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "data" => Ok(GeneratedField::Data),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = LiquidityPositionsByIdResponse;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.dex.v1.LiquidityPositionsByIdResponse")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<LiquidityPositionsByIdResponse, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut data__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Data => {
+                            if data__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("data"));
+                            }
+                            data__ = map_.next_value()?;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(LiquidityPositionsByIdResponse {
+                    data: data__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.dex.v1.LiquidityPositionsByIdResponse", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for LiquidityPositionsByPriceRequest {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.trading_pair.is_some() {
+            len += 1;
+        }
+        if self.limit != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.LiquidityPositionsByPriceRequest", len)?;
+        if let Some(v) = self.trading_pair.as_ref() {
+            struct_ser.serialize_field("tradingPair", v)?;
+        }
+        if self.limit != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("limit", ToString::to_string(&self.limit).as_str())?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for LiquidityPositionsByPriceRequest {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "trading_pair",
+            "tradingPair",
+            "limit",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            TradingPair,
+            Limit,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "tradingPair" | "trading_pair" => Ok(GeneratedField::TradingPair),
+                            "limit" => Ok(GeneratedField::Limit),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = LiquidityPositionsByPriceRequest;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.dex.v1.LiquidityPositionsByPriceRequest")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<LiquidityPositionsByPriceRequest, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut trading_pair__ = None;
+                let mut limit__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::TradingPair => {
+                            if trading_pair__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("tradingPair"));
+                            }
+                            trading_pair__ = map_.next_value()?;
+                        }
+                        GeneratedField::Limit => {
+                            if limit__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("limit"));
+                            }
+                            limit__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(LiquidityPositionsByPriceRequest {
+                    trading_pair: trading_pair__,
+                    limit: limit__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.dex.v1.LiquidityPositionsByPriceRequest", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for LiquidityPositionsByPriceResponse {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.data.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.LiquidityPositionsByPriceResponse", len)?;
+        if let Some(v) = self.data.as_ref() {
+            struct_ser.serialize_field("data", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for LiquidityPositionsByPriceResponse {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "data",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Data,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "data" => Ok(GeneratedField::Data),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = LiquidityPositionsByPriceResponse;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.dex.v1.LiquidityPositionsByPriceResponse")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<LiquidityPositionsByPriceResponse, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut data__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Data => {
+                            if data__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("data"));
+                            }
+                            data__ = map_.next_value()?;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(LiquidityPositionsByPriceResponse {
+                    data: data__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.dex.v1.LiquidityPositionsByPriceResponse", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for LiquidityPositionsRequest {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.include_closed {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.LiquidityPositionsRequest", len)?;
+        if self.include_closed {
+            struct_ser.serialize_field("includeClosed", &self.include_closed)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for LiquidityPositionsRequest {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "include_closed",
+            "includeClosed",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            IncludeClosed,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "includeClosed" | "include_closed" => Ok(GeneratedField::IncludeClosed),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = LiquidityPositionsRequest;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.dex.v1.LiquidityPositionsRequest")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<LiquidityPositionsRequest, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut include_closed__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::IncludeClosed => {
+                            if include_closed__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("includeClosed"));
+                            }
+                            include_closed__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(LiquidityPositionsRequest {
+                    include_closed: include_closed__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.dex.v1.LiquidityPositionsRequest", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for LiquidityPositionsResponse {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.data.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.LiquidityPositionsResponse", len)?;
+        if let Some(v) = self.data.as_ref() {
+            struct_ser.serialize_field("data", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for LiquidityPositionsResponse {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "data",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Data,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "data" => Ok(GeneratedField::Data),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = LiquidityPositionsResponse;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.dex.v1.LiquidityPositionsResponse")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<LiquidityPositionsResponse, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut data__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Data => {
+                            if data__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("data"));
+                            }
+                            data__ = map_.next_value()?;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(LiquidityPositionsResponse {
+                    data: data__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.dex.v1.LiquidityPositionsResponse", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for LpNft {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.position_id.is_some() {
+            len += 1;
+        }
+        if self.state.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.LpNft", len)?;
+        if let Some(v) = self.position_id.as_ref() {
+            struct_ser.serialize_field("positionId", v)?;
+        }
+        if let Some(v) = self.state.as_ref() {
+            struct_ser.serialize_field("state", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for LpNft {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "position_id",
+            "positionId",
+            "state",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            PositionId,
+            State,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "positionId" | "position_id" => Ok(GeneratedField::PositionId),
+                            "state" => Ok(GeneratedField::State),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = LpNft;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.dex.v1.LpNft")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<LpNft, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut position_id__ = None;
+                let mut state__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::PositionId => {
+                            if position_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("positionId"));
+                            }
+                            position_id__ = map_.next_value()?;
+                        }
+                        GeneratedField::State => {
+                            if state__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("state"));
+                            }
+                            state__ = map_.next_value()?;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(LpNft {
+                    position_id: position_id__,
+                    state: state__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.dex.v1.LpNft", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for PausedTradingPairsRequest {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let len = 0;
+        let struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.PausedTradingPairsRequest", len)?;
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for PausedTradingPairsRequest {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                            Ok(GeneratedField::__SkipField__)
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = PausedTradingPairsRequest;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.dex.v1.PausedTradingPairsRequest")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<PausedTradingPairsRequest, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                while map_.next_key::<GeneratedField>()?.is_some() {
+                    let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                }
+                Ok(PausedTradingPairsRequest {
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.dex.v1.PausedTradingPairsRequest", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for PausedTradingPairsResponse {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.trading_pairs.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.PausedTradingPairsResponse", len)?;
+        if !self.trading_pairs.is_empty() {
+            struct_ser.serialize_field("tradingPairs", &self.trading_pairs)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for PausedTradingPairsResponse {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "trading_pairs",
+            "tradingPairs",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            TradingPairs,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "tradingPairs" | "trading_pairs" => Ok(GeneratedField::TradingPairs),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = PausedTradingPairsResponse;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.dex.v1.PausedTradingPairsResponse")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<PausedTradingPairsResponse, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut trading_pairs__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::TradingPairs => {
+                            if trading_pairs__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("tradingPairs"));
+                            }
+                            trading_pairs__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(PausedTradingPairsResponse {
+                    trading_pairs: trading_pairs__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.dex.v1.PausedTradingPairsResponse", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for Position {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.phi.is_some() {
+            len += 1;
+        }
+        if !self.nonce.is_empty() {
+            len += 1;
+        }
+        if self.state.is_some() {
+            len += 1;
+        }
+        if self.reserves.is_some() {
+            len += 1;
+        }
+        if self.close_on_fill {
+            len += 1;
+        }
+        if self.dynamic_fee.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.Position", len)?;
+        if let Some(v) = self.phi.as_ref() {
+            struct_ser.serialize_field("phi", v)?;
+        }
+        if !self.nonce.is_empty() {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("nonce", pbjson::private::base64::encode(&self.nonce).as_str())?;
+        }
+        if let Some(v) = self.state.as_ref() {
+            struct_ser.serialize_field("state", v)?;
+        }
+        if let Some(v) = self.reserves.as_ref() {
+            struct_ser.serialize_field("reserves", v)?;
+        }
+        if self.close_on_fill {
+            struct_ser.serialize_field("closeOnFill", &self.close_on_fill)?;
+        }
+        if let Some(v) = self.dynamic_fee.as_ref() {
+            struct_ser.serialize_field("dynamicFee", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for Position {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "phi",
+            "nonce",
+            "state",
+            "reserves",
+            "close_on_fill",
+            "closeOnFill",
+            "dynamic_fee",
+            "dynamicFee",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Phi,
+            Nonce,
+            State,
+            Reserves,
+            CloseOnFill,
+            DynamicFee,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "phi" => Ok(GeneratedField::Phi),
+                            "nonce" => Ok(GeneratedField::Nonce),
+                            "state" => Ok(GeneratedField::State),
+                            "reserves" => Ok(GeneratedField::Reserves),
+                            "closeOnFill" | "close_on_fill" => Ok(GeneratedField::CloseOnFill),
+                            "dynamicFee" | "dynamic_fee" => Ok(GeneratedField::DynamicFee),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = Position;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.dex.v1.Position")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<Position, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut phi__ = None;
+                let mut nonce__ = None;
+                let mut state__ = None;
+                let mut reserves__ = None;
+                let mut close_on_fill__ = None;
+                let mut dynamic_fee__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Phi => {
+                            if phi__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("phi"));
+                            }
+                            phi__ = map_.next_value()?;
+                        }
+                        GeneratedField::Nonce => {
+                            if nonce__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("nonce"));
+                            }
+                            nonce__ = 
+                                Some(map_.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::State => {
+                            if state__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("state"));
+                            }
+                            state__ = map_.next_value()?;
+                        }
+                        GeneratedField::Reserves => {
+                            if reserves__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("reserves"));
+                            }
+                            reserves__ = map_.next_value()?;
+                        }
+                        GeneratedField::CloseOnFill => {
+                            if close_on_fill__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("closeOnFill"));
+                            }
+                            close_on_fill__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::DynamicFee => {
+                            if dynamic_fee__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("dynamicFee"));
+                            }
+                            dynamic_fee__ = map_.next_value()?;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(Position {
+                    phi: phi__,
+                    nonce: nonce__.unwrap_or_default(),
+                    state: state__,
+                    reserves: reserves__,
+                    close_on_fill: close_on_fill__.unwrap_or_default(),
+                    dynamic_fee: dynamic_fee__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.dex.v1.Position", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for PositionAnalytics {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.trading_pair.is_some() {
+            len += 1;
+        }
+        if self.position_count != 0 {
+            len += 1;
+        }
+        if self.fees_1.is_some() {
+            len += 1;
+        }
+        if self.fees_2.is_some() {
+            len += 1;
+        }
+        if self.volume_1.is_some() {
+            len += 1;
+        }
+        if self.volume_2.is_some() {
+            len += 1;
+        }
+        if self.blocks_open != 0 {
+            len += 1;
+        }
+        if self.blocks_in_range != 0 {
+            len += 1;
+        }
+        if self.initial_reserves.is_some() {
+            len += 1;
+        }
+        if self.final_reserves.is_some() {
+            len += 1;
+        }
+        if self.valuation.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.PositionAnalytics", len)?;
+        if let Some(v) = self.trading_pair.as_ref() {
+            struct_ser.serialize_field("tradingPair", v)?;
+        }
+        if self.position_count != 0 {
+            struct_ser.serialize_field("positionCount", &self.position_count)?;
+        }
+        if let Some(v) = self.fees_1.as_ref() {
+            struct_ser.serialize_field("fees1", v)?;
+        }
+        if let Some(v) = self.fees_2.as_ref() {
+            struct_ser.serialize_field("fees2", v)?;
+        }
+        if let Some(v) = self.volume_1.as_ref() {
+            struct_ser.serialize_field("volume1", v)?;
+        }
+        if let Some(v) = self.volume_2.as_ref() {
+            struct_ser.serialize_field("volume2", v)?;
+        }
+        if self.blocks_open != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("blocksOpen", ToString::to_string(&self.blocks_open).as_str())?;
+        }
+        if self.blocks_in_range != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("blocksInRange", ToString::to_string(&self.blocks_in_range).as_str())?;
+        }
+        if let Some(v) = self.initial_reserves.as_ref() {
+            struct_ser.serialize_field("initialReserves", v)?;
+        }
+        if let Some(v) = self.final_reserves.as_ref() {
+            struct_ser.serialize_field("finalReserves", v)?;
+        }
+        if let Some(v) = self.valuation.as_ref() {
+            struct_ser.serialize_field("valuation", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for PositionAnalytics {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "trading_pair",
+            "tradingPair",
+            "position_count",
+            "positionCount",
+            "fees_1",
+            "fees1",
+            "fees_2",
+            "fees2",
+            "volume_1",
+            "volume1",
+            "volume_2",
+            "volume2",
+            "blocks_open",
+            "blocksOpen",
+            "blocks_in_range",
+            "blocksInRange",
+            "initial_reserves",
+            "initialReserves",
+            "final_reserves",
+            "finalReserves",
+            "valuation",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            TradingPair,
+            PositionCount,
+            Fees1,
+            Fees2,
+            Volume1,
+            Volume2,
+            BlocksOpen,
+            BlocksInRange,
+            InitialReserves,
+            FinalReserves,
+            Valuation,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "tradingPair" | "trading_pair" => Ok(GeneratedField::TradingPair),
+                            "positionCount" | "position_count" => Ok(GeneratedField::PositionCount),
+                            "fees1" | "fees_1" => Ok(GeneratedField::Fees1),
+                            "fees2" | "fees_2" => Ok(GeneratedField::Fees2),
+                            "volume1" | "volume_1" => Ok(GeneratedField::Volume1),
+                            "volume2" | "volume_2" => Ok(GeneratedField::Volume2),
+                            "blocksOpen" | "blocks_open" => Ok(GeneratedField::BlocksOpen),
+                            "blocksInRange" | "blocks_in_range" => Ok(GeneratedField::BlocksInRange),
+                            "initialReserves" | "initial_reserves" => Ok(GeneratedField::InitialReserves),
+                            "finalReserves" | "final_reserves" => Ok(GeneratedField::FinalReserves),
+                            "valuation" => Ok(GeneratedField::Valuation),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = PositionAnalytics;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.dex.v1.PositionAnalytics")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<PositionAnalytics, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut trading_pair__ = None;
+                let mut position_count__ = None;
+                let mut fees_1__ = None;
+                let mut fees_2__ = None;
+                let mut volume_1__ = None;
+                let mut volume_2__ = None;
+                let mut blocks_open__ = None;
+                let mut blocks_in_range__ = None;
+                let mut initial_reserves__ = None;
+                let mut final_reserves__ = None;
+                let mut valuation__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::TradingPair => {
+                            if trading_pair__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("tradingPair"));
+                            }
+                            trading_pair__ = map_.next_value()?;
+                        }
+                        GeneratedField::PositionCount => {
+                            if position_count__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("positionCount"));
+                            }
+                            position_count__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::Fees1 => {
+                            if fees_1__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("fees1"));
+                            }
+                            fees_1__ = map_.next_value()?;
+                        }
+                        GeneratedField::Fees2 => {
+                            if fees_2__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("fees2"));
+                            }
+                            fees_2__ = map_.next_value()?;
+                        }
+                        GeneratedField::Volume1 => {
+                            if volume_1__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("volume1"));
+                            }
+                            volume_1__ = map_.next_value()?;
+                        }
+                        GeneratedField::Volume2 => {
+                            if volume_2__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("volume2"));
+                            }
+                            volume_2__ = map_.next_value()?;
+                        }
+                        GeneratedField::BlocksOpen => {
+                            if blocks_open__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("blocksOpen"));
+                            }
+                            blocks_open__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::BlocksInRange => {
+                            if blocks_in_range__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("blocksInRange"));
+                            }
+                            blocks_in_range__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::InitialReserves => {
+                            if initial_reserves__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("initialReserves"));
+                            }
+                            initial_reserves__ = map_.next_value()?;
+                        }
+                        GeneratedField::FinalReserves => {
+                            if final_reserves__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("finalReserves"));
+                            }
+                            final_reserves__ = map_.next_value()?;
+                        }
+                        GeneratedField::Valuation => {
+                            if valuation__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("valuation"));
+                            }
+                            valuation__ = map_.next_value()?;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(PositionAnalytics {
+                    trading_pair: trading_pair__,
+                    position_count: position_count__.unwrap_or_default(),
+                    fees_1: fees_1__,
+                    fees_2: fees_2__,
+                    volume_1: volume_1__,
+                    volume_2: volume_2__,
+                    blocks_open: blocks_open__.unwrap_or_default(),
+                    blocks_in_range: blocks_in_range__.unwrap_or_default(),
+                    initial_reserves: initial_reserves__,
+                    final_reserves: final_reserves__,
+                    valuation: valuation__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.dex.v1.PositionAnalytics", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for PositionAtHeightRequest {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.position_id.is_some() {
+            len += 1;
+        }
+        if self.height != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.PositionAtHeightRequest", len)?;
+        if let Some(v) = self.position_id.as_ref() {
+            struct_ser.serialize_field("positionId", v)?;
+        }
+        if self.height != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("height", ToString::to_string(&self.height).as_str())?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for PositionAtHeightRequest {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "position_id",
+            "positionId",
+            "height",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            PositionId,
+            Height,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "positionId" | "position_id" => Ok(GeneratedField::PositionId),
+                            "height" => Ok(GeneratedField::Height),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = PositionAtHeightRequest;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.dex.v1.PositionAtHeightRequest")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<PositionAtHeightRequest, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut position_id__ = None;
+                let mut height__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::PositionId => {
+                            if position_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("positionId"));
+                            }
+                            position_id__ = map_.next_value()?;
+                        }
+                        GeneratedField::Height => {
+                            if height__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("height"));
+                            }
+                            height__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(PositionAtHeightRequest {
+                    position_id: position_id__,
+                    height: height__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.dex.v1.PositionAtHeightRequest", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for PositionAtHeightResponse {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.position.is_some() {
+            len += 1;
+        }
+        if self.last_changed_height != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.PositionAtHeightResponse", len)?;
+        if let Some(v) = self.position.as_ref() {
+            struct_ser.serialize_field("position", v)?;
+        }
+        if self.last_changed_height != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("lastChangedHeight", ToString::to_string(&self.last_changed_height).as_str())?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for PositionAtHeightResponse {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "position",
+            "last_changed_height",
+            "lastChangedHeight",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Position,
+            LastChangedHeight,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "position" => Ok(GeneratedField::Position),
+                            "lastChangedHeight" | "last_changed_height" => Ok(GeneratedField::LastChangedHeight),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = PositionAtHeightResponse;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.dex.v1.PositionAtHeightResponse")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<PositionAtHeightResponse, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut position__ = None;
+                let mut last_changed_height__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Position => {
+                            if position__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("position"));
+                            }
+                            position__ = map_.next_value()?;
+                        }
+                        GeneratedField::LastChangedHeight => {
+                            if last_changed_height__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("lastChangedHeight"));
+                            }
+                            last_changed_height__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(PositionAtHeightResponse {
+                    position: position__,
+                    last_changed_height: last_changed_height__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.dex.v1.PositionAtHeightResponse", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for PositionClose {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.position_id.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.PositionClose", len)?;
+        if let Some(v) = self.position_id.as_ref() {
+            struct_ser.serialize_field("positionId", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for PositionClose {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "position_id",
+            "positionId",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            PositionId,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "positionId" | "position_id" => Ok(GeneratedField::PositionId),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = PositionClose;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.dex.v1.PositionClose")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<PositionClose, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut position_id__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::PositionId => {
+                            if position_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("positionId"));
+                            }
+                            position_id__ = map_.next_value()?;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(PositionClose {
+                    position_id: position_id__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.dex.v1.PositionClose", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for PositionHistoryEntry {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.height != 0 {
+            len += 1;
+        }
+        if self.prev_state.is_some() {
+            len += 1;
+        }
+        if self.state.is_some() {
+            len += 1;
+        }
+        if self.reserves.is_some() {
+            len += 1;
+        }
+        if self.fills != 0 {
+            len += 1;
+        }
+        if self.inflow_1.is_some() {
+            len += 1;
+        }
+        if self.inflow_2.is_some() {
+            len += 1;
+        }
+        if self.outflow_1.is_some() {
+            len += 1;
+        }
+        if self.outflow_2.is_some() {
+            len += 1;
+        }
+        if self.fees_1.is_some() {
+            len += 1;
+        }
+        if self.fees_2.is_some() {
+            len += 1;
+        }
+        if self.rewritten_from.is_some() {
+            len += 1;
+        }
+        if self.rewritten_to.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.PositionHistoryEntry", len)?;
+        if self.height != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("height", ToString::to_string(&self.height).as_str())?;
+        }
+        if let Some(v) = self.prev_state.as_ref() {
+            struct_ser.serialize_field("prevState", v)?;
+        }
+        if let Some(v) = self.state.as_ref() {
+            struct_ser.serialize_field("state", v)?;
+        }
+        if let Some(v) = self.reserves.as_ref() {
+            struct_ser.serialize_field("reserves", v)?;
+        }
+        if self.fills != 0 {
+            struct_ser.serialize_field("fills", &self.fills)?;
+        }
+        if let Some(v) = self.inflow_1.as_ref() {
+            struct_ser.serialize_field("inflow1", v)?;
+        }
+        if let Some(v) = self.inflow_2.as_ref() {
+            struct_ser.serialize_field("inflow2", v)?;
+        }
+        if let Some(v) = self.outflow_1.as_ref() {
+            struct_ser.serialize_field("outflow1", v)?;
+        }
+        if let Some(v) = self.outflow_2.as_ref() {
+            struct_ser.serialize_field("outflow2", v)?;
+        }
+        if let Some(v) = self.fees_1.as_ref() {
+            struct_ser.serialize_field("fees1", v)?;
+        }
+        if let Some(v) = self.fees_2.as_ref() {
+            struct_ser.serialize_field("fees2", v)?;
+        }
+        if let Some(v) = self.rewritten_from.as_ref() {
+            struct_ser.serialize_field("rewrittenFrom", v)?;
+        }
+        if let Some(v) = self.rewritten_to.as_ref() {
+            struct_ser.serialize_field("rewrittenTo", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for PositionHistoryEntry {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "height",
+            "prev_state",
+            "prevState",
+            "state",
+            "reserves",
+            "fills",
+            "inflow_1",
+            "inflow1",
+            "inflow_2",
+            "inflow2",
+            "outflow_1",
+            "outflow1",
+            "outflow_2",
+            "outflow2",
+            "fees_1",
+            "fees1",
+            "fees_2",
+            "fees2",
+            "rewritten_from",
+            "rewrittenFrom",
+            "rewritten_to",
+            "rewrittenTo",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Height,
+            PrevState,
+            State,
+            Reserves,
+            Fills,
+            Inflow1,
+            Inflow2,
+            Outflow1,
+            Outflow2,
+            Fees1,
+            Fees2,
+            RewrittenFrom,
+            RewrittenTo,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "height" => Ok(GeneratedField::Height),
+                            "prevState" | "prev_state" => Ok(GeneratedField::PrevState),
+                            "state" => Ok(GeneratedField::State),
+                            "reserves" => Ok(GeneratedField::Reserves),
+                            "fills" => Ok(GeneratedField::Fills),
+                            "inflow1" | "inflow_1" => Ok(GeneratedField::Inflow1),
+                            "inflow2" | "inflow_2" => Ok(GeneratedField::Inflow2),
+                            "outflow1" | "outflow_1" => Ok(GeneratedField::Outflow1),
+                            "outflow2" | "outflow_2" => Ok(GeneratedField::Outflow2),
+                            "fees1" | "fees_1" => Ok(GeneratedField::Fees1),
+                            "fees2" | "fees_2" => Ok(GeneratedField::Fees2),
+                            "rewrittenFrom" | "rewritten_from" => Ok(GeneratedField::RewrittenFrom),
+                            "rewrittenTo" | "rewritten_to" => Ok(GeneratedField::RewrittenTo),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = PositionHistoryEntry;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.dex.v1.PositionHistoryEntry")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<PositionHistoryEntry, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut height__ = None;
+                let mut prev_state__ = None;
+                let mut state__ = None;
+                let mut reserves__ = None;
+                let mut fills__ = None;
+                let mut inflow_1__ = None;
+                let mut inflow_2__ = None;
+                let mut outflow_1__ = None;
+                let mut outflow_2__ = None;
+                let mut fees_1__ = None;
+                let mut fees_2__ = None;
+                let mut rewritten_from__ = None;
+                let mut rewritten_to__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Height => {
+                            if height__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("height"));
+                            }
+                            height__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::PrevState => {
+                            if prev_state__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("prevState"));
+                            }
+                            prev_state__ = map_.next_value()?;
+                        }
+                        GeneratedField::State => {
+                            if state__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("state"));
+                            }
+                            state__ = map_.next_value()?;
+                        }
+                        GeneratedField::Reserves => {
+                            if reserves__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("reserves"));
+                            }
+                            reserves__ = map_.next_value()?;
+                        }
+                        GeneratedField::Fills => {
+                            if fills__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("fills"));
+                            }
+                            fills__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::Inflow1 => {
+                            if inflow_1__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("inflow1"));
+                            }
+                            inflow_1__ = map_.next_value()?;
+                        }
+                        GeneratedField::Inflow2 => {
+                            if inflow_2__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("inflow2"));
+                            }
+                            inflow_2__ = map_.next_value()?;
+                        }
+                        GeneratedField::Outflow1 => {
+                            if outflow_1__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("outflow1"));
+                            }
+                            outflow_1__ = map_.next_value()?;
+                        }
+                        GeneratedField::Outflow2 => {
+                            if outflow_2__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("outflow2"));
+                            }
+                            outflow_2__ = map_.next_value()?;
+                        }
+                        GeneratedField::Fees1 => {
+                            if fees_1__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("fees1"));
+                            }
+                            fees_1__ = map_.next_value()?;
+                        }
+                        GeneratedField::Fees2 => {
+                            if fees_2__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("fees2"));
+                            }
+                            fees_2__ = map_.next_value()?;
+                        }
+                        GeneratedField::RewrittenFrom => {
+                            if rewritten_from__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("rewrittenFrom"));
+                            }
+                            rewritten_from__ = map_.next_value()?;
+                        }
+                        GeneratedField::RewrittenTo => {
+                            if rewritten_to__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("rewrittenTo"));
+                            }
+                            rewritten_to__ = map_.next_value()?;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(PositionHistoryEntry {
+                    height: height__.unwrap_or_default(),
+                    prev_state: prev_state__,
+                    state: state__,
+                    reserves: reserves__,
+                    fills: fills__.unwrap_or_default(),
+                    inflow_1: inflow_1__,
+                    inflow_2: inflow_2__,
+                    outflow_1: outflow_1__,
+                    outflow_2: outflow_2__,
+                    fees_1: fees_1__,
+                    fees_2: fees_2__,
+                    rewritten_from: rewritten_from__,
+                    rewritten_to: rewritten_to__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.dex.v1.PositionHistoryEntry", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for PositionHistoryRequest {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.position_id.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.PositionHistoryRequest", len)?;
+        if let Some(v) = self.position_id.as_ref() {
+            struct_ser.serialize_field("positionId", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for PositionHistoryRequest {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "position_id",
+            "positionId",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            PositionId,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "positionId" | "position_id" => Ok(GeneratedField::PositionId),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = PositionHistoryRequest;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.dex.v1.PositionHistoryRequest")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<PositionHistoryRequest, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut position_id__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::PositionId => {
+                            if position_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("positionId"));
+                            }
+                            position_id__ = map_.next_value()?;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(PositionHistoryRequest {
+                    position_id: position_id__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.dex.v1.PositionHistoryRequest", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for PositionHistoryResponse {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.entry.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.PositionHistoryResponse", len)?;
+        if let Some(v) = self.entry.as_ref() {
+            struct_ser.serialize_field("entry", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for PositionHistoryResponse {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "entry",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Entry,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "entry" => Ok(GeneratedField::Entry),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = PositionHistoryResponse;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.dex.v1.PositionHistoryResponse")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<PositionHistoryResponse, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut entry__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Entry => {
+                            if entry__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("entry"));
+                            }
+                            entry__ = map_.next_value()?;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(PositionHistoryResponse {
+                    entry: entry__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.dex.v1.PositionHistoryResponse", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for PositionId {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.inner.is_empty() {
+            len += 1;
+        }
+        if !self.alt_bech32m.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.PositionId", len)?;
+        if !self.inner.is_empty() {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("inner", pbjson::private::base64::encode(&self.inner).as_str())?;
+        }
+        if !self.alt_bech32m.is_empty() {
+            struct_ser.serialize_field("altBech32m", &self.alt_bech32m)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for PositionId {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "inner",
+            "alt_bech32m",
+            "altBech32m",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Inner,
+            AltBech32m,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
                     type Value = GeneratedField;
 
                     fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
@@ -1449,7 +7934,8 @@ impl<'de> serde::Deserialize<'de> for EventPositionClose {
                         E: serde::de::Error,
                     {
                         match value {
-                            "positionId" | "position_id" => Ok(GeneratedField::PositionId),
+                            "inner" => Ok(GeneratedField::Inner),
+                            "altBech32m" | "alt_bech32m" => Ok(GeneratedField::AltBech32m),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -1459,39 +7945,49 @@ impl<'de> serde::Deserialize<'de> for EventPositionClose {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = EventPositionClose;
+            type Value = PositionId;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.core.component.dex.v1.EventPositionClose")
+                formatter.write_str("struct penumbra.core.component.dex.v1.PositionId")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<EventPositionClose, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<PositionId, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut position_id__ = None;
+                let mut inner__ = None;
+                let mut alt_bech32m__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::PositionId => {
-                            if position_id__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("positionId"));
+                        GeneratedField::Inner => {
+                            if inner__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("inner"));
                             }
-                            position_id__ = map_.next_value()?;
+                            inner__ = 
+                                Some(map_.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::AltBech32m => {
+                            if alt_bech32m__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("altBech32m"));
+                            }
+                            alt_bech32m__ = Some(map_.next_value()?);
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(EventPositionClose {
-                    position_id: position_id__,
+                Ok(PositionId {
+                    inner: inner__.unwrap_or_default(),
+                    alt_bech32m: alt_bech32m__.unwrap_or_default(),
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.core.component.dex.v1.EventPositionClose", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.core.component.dex.v1.PositionId", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for EventPositionExecution {
+impl serde::Serialize for PositionMetadata {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -1499,57 +7995,58 @@ impl serde::Serialize for EventPositionExecution {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.position_id.is_some() {
+        if self.creation_height != 0 {
             len += 1;
         }
         if self.trading_pair.is_some() {
             len += 1;
         }
-        if self.reserves_1.is_some() {
+        if !self.strategy_tag.is_empty() {
             len += 1;
         }
-        if self.reserves_2.is_some() {
+        if self.rewritten_from.is_some() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.EventPositionExecution", len)?;
-        if let Some(v) = self.position_id.as_ref() {
-            struct_ser.serialize_field("positionId", v)?;
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.PositionMetadata", len)?;
+        if self.creation_height != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("creationHeight", ToString::to_string(&self.creation_height).as_str())?;
         }
         if let Some(v) = self.trading_pair.as_ref() {
             struct_ser.serialize_field("tradingPair", v)?;
         }
-        if let Some(v) = self.reserves_1.as_ref() {
-            struct_ser.serialize_field("reserves1", v)?;
+        if !self.strategy_tag.is_empty() {
+            struct_ser.serialize_field("strategyTag", &self.strategy_tag)?;
         }
-        if let Some(v) = self.reserves_2.as_ref() {
-            struct_ser.serialize_field("reserves2", v)?;
+        if let Some(v) = self.rewritten_from.as_ref() {
+            struct_ser.serialize_field("rewrittenFrom", v)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for EventPositionExecution {
+impl<'de> serde::Deserialize<'de> for PositionMetadata {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "position_id",
-            "positionId",
+            "creation_height",
+            "creationHeight",
             "trading_pair",
             "tradingPair",
-            "reserves_1",
-            "reserves1",
-            "reserves_2",
-            "reserves2",
+            "strategy_tag",
+            "strategyTag",
+            "rewritten_from",
+            "rewrittenFrom",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            PositionId,
+            CreationHeight,
             TradingPair,
-            Reserves1,
-            Reserves2,
+            StrategyTag,
+            RewrittenFrom,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -1572,10 +8069,10 @@ impl<'de> serde::Deserialize<'de> for EventPositionExecution {
                         E: serde::de::Error,
                     {
                         match value {
-                            "positionId" | "position_id" => Ok(GeneratedField::PositionId),
+                            "creationHeight" | "creation_height" => Ok(GeneratedField::CreationHeight),
                             "tradingPair" | "trading_pair" => Ok(GeneratedField::TradingPair),
-                            "reserves1" | "reserves_1" => Ok(GeneratedField::Reserves1),
-                            "reserves2" | "reserves_2" => Ok(GeneratedField::Reserves2),
+                            "strategyTag" | "strategy_tag" => Ok(GeneratedField::StrategyTag),
+                            "rewrittenFrom" | "rewritten_from" => Ok(GeneratedField::RewrittenFrom),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -1585,27 +8082,29 @@ impl<'de> serde::Deserialize<'de> for EventPositionExecution {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = EventPositionExecution;
+            type Value = PositionMetadata;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.core.component.dex.v1.EventPositionExecution")
+                formatter.write_str("struct penumbra.core.component.dex.v1.PositionMetadata")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<EventPositionExecution, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<PositionMetadata, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut position_id__ = None;
+                let mut creation_height__ = None;
                 let mut trading_pair__ = None;
-                let mut reserves_1__ = None;
-                let mut reserves_2__ = None;
+                let mut strategy_tag__ = None;
+                let mut rewritten_from__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::PositionId => {
-                            if position_id__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("positionId"));
+                        GeneratedField::CreationHeight => {
+                            if creation_height__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("creationHeight"));
                             }
-                            position_id__ = map_.next_value()?;
+                            creation_height__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
                         }
                         GeneratedField::TradingPair => {
                             if trading_pair__.is_some() {
@@ -1613,35 +8112,35 @@ impl<'de> serde::Deserialize<'de> for EventPositionExecution {
                             }
                             trading_pair__ = map_.next_value()?;
                         }
-                        GeneratedField::Reserves1 => {
-                            if reserves_1__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("reserves1"));
+                        GeneratedField::StrategyTag => {
+                            if strategy_tag__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("strategyTag"));
                             }
-                            reserves_1__ = map_.next_value()?;
+                            strategy_tag__ = Some(map_.next_value()?);
                         }
-                        GeneratedField::Reserves2 => {
-                            if reserves_2__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("reserves2"));
+                        GeneratedField::RewrittenFrom => {
+                            if rewritten_from__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("rewrittenFrom"));
                             }
-                            reserves_2__ = map_.next_value()?;
+                            rewritten_from__ = map_.next_value()?;
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(EventPositionExecution {
-                    position_id: position_id__,
+                Ok(PositionMetadata {
+                    creation_height: creation_height__.unwrap_or_default(),
                     trading_pair: trading_pair__,
-                    reserves_1: reserves_1__,
-                    reserves_2: reserves_2__,
+                    strategy_tag: strategy_tag__.unwrap_or_default(),
+                    rewritten_from: rewritten_from__,
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.core.component.dex.v1.EventPositionExecution", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.core.component.dex.v1.PositionMetadata", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for EventPositionOpen {
+impl serde::Serialize for PositionMetadataRequest {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -1652,38 +8151,14 @@ impl serde::Serialize for EventPositionOpen {
         if self.position_id.is_some() {
             len += 1;
         }
-        if self.trading_pair.is_some() {
-            len += 1;
-        }
-        if self.reserves_1.is_some() {
-            len += 1;
-        }
-        if self.reserves_2.is_some() {
-            len += 1;
-        }
-        if self.trading_fee != 0 {
-            len += 1;
-        }
-        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.EventPositionOpen", len)?;
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.PositionMetadataRequest", len)?;
         if let Some(v) = self.position_id.as_ref() {
             struct_ser.serialize_field("positionId", v)?;
         }
-        if let Some(v) = self.trading_pair.as_ref() {
-            struct_ser.serialize_field("tradingPair", v)?;
-        }
-        if let Some(v) = self.reserves_1.as_ref() {
-            struct_ser.serialize_field("reserves1", v)?;
-        }
-        if let Some(v) = self.reserves_2.as_ref() {
-            struct_ser.serialize_field("reserves2", v)?;
-        }
-        if self.trading_fee != 0 {
-            struct_ser.serialize_field("tradingFee", &self.trading_fee)?;
-        }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for EventPositionOpen {
+impl<'de> serde::Deserialize<'de> for PositionMetadataRequest {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
@@ -1692,23 +8167,11 @@ impl<'de> serde::Deserialize<'de> for EventPositionOpen {
         const FIELDS: &[&str] = &[
             "position_id",
             "positionId",
-            "trading_pair",
-            "tradingPair",
-            "reserves_1",
-            "reserves1",
-            "reserves_2",
-            "reserves2",
-            "trading_fee",
-            "tradingFee",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
             PositionId,
-            TradingPair,
-            Reserves1,
-            Reserves2,
-            TradingFee,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -1732,10 +8195,6 @@ impl<'de> serde::Deserialize<'de> for EventPositionOpen {
                     {
                         match value {
                             "positionId" | "position_id" => Ok(GeneratedField::PositionId),
-                            "tradingPair" | "trading_pair" => Ok(GeneratedField::TradingPair),
-                            "reserves1" | "reserves_1" => Ok(GeneratedField::Reserves1),
-                            "reserves2" | "reserves_2" => Ok(GeneratedField::Reserves2),
-                            "tradingFee" | "trading_fee" => Ok(GeneratedField::TradingFee),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -1745,21 +8204,17 @@ impl<'de> serde::Deserialize<'de> for EventPositionOpen {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = EventPositionOpen;
+            type Value = PositionMetadataRequest;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.core.component.dex.v1.EventPositionOpen")
+                formatter.write_str("struct penumbra.core.component.dex.v1.PositionMetadataRequest")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<EventPositionOpen, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<PositionMetadataRequest, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
                 let mut position_id__ = None;
-                let mut trading_pair__ = None;
-                let mut reserves_1__ = None;
-                let mut reserves_2__ = None;
-                let mut trading_fee__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
                         GeneratedField::PositionId => {
@@ -1768,50 +8223,20 @@ impl<'de> serde::Deserialize<'de> for EventPositionOpen {
                             }
                             position_id__ = map_.next_value()?;
                         }
-                        GeneratedField::TradingPair => {
-                            if trading_pair__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("tradingPair"));
-                            }
-                            trading_pair__ = map_.next_value()?;
-                        }
-                        GeneratedField::Reserves1 => {
-                            if reserves_1__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("reserves1"));
-                            }
-                            reserves_1__ = map_.next_value()?;
-                        }
-                        GeneratedField::Reserves2 => {
-                            if reserves_2__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("reserves2"));
-                            }
-                            reserves_2__ = map_.next_value()?;
-                        }
-                        GeneratedField::TradingFee => {
-                            if trading_fee__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("tradingFee"));
-                            }
-                            trading_fee__ = 
-                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
-                            ;
-                        }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(EventPositionOpen {
+                Ok(PositionMetadataRequest {
                     position_id: position_id__,
-                    trading_pair: trading_pair__,
-                    reserves_1: reserves_1__,
-                    reserves_2: reserves_2__,
-                    trading_fee: trading_fee__.unwrap_or_default(),
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.core.component.dex.v1.EventPositionOpen", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.core.component.dex.v1.PositionMetadataRequest", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for EventPositionWithdraw {
+impl serde::Serialize for PositionMetadataResponse {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -1819,66 +8244,29 @@ impl serde::Serialize for EventPositionWithdraw {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.position_id.is_some() {
-            len += 1;
-        }
-        if self.trading_pair.is_some() {
-            len += 1;
-        }
-        if self.reserves_1.is_some() {
-            len += 1;
-        }
-        if self.reserves_2.is_some() {
-            len += 1;
-        }
-        if self.sequence != 0 {
+        if self.metadata.is_some() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.EventPositionWithdraw", len)?;
-        if let Some(v) = self.position_id.as_ref() {
-            struct_ser.serialize_field("positionId", v)?;
-        }
-        if let Some(v) = self.trading_pair.as_ref() {
-            struct_ser.serialize_field("tradingPair", v)?;
-        }
-        if let Some(v) = self.reserves_1.as_ref() {
-            struct_ser.serialize_field("reserves1", v)?;
-        }
-        if let Some(v) = self.reserves_2.as_ref() {
-            struct_ser.serialize_field("reserves2", v)?;
-        }
-        if self.sequence != 0 {
-            #[allow(clippy::needless_borrow)]
-            struct_ser.serialize_field("sequence", ToString::to_string(&self.sequence).as_str())?;
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.PositionMetadataResponse", len)?;
+        if let Some(v) = self.metadata.as_ref() {
+            struct_ser.serialize_field("metadata", v)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for EventPositionWithdraw {
+impl<'de> serde::Deserialize<'de> for PositionMetadataResponse {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "position_id",
-            "positionId",
-            "trading_pair",
-            "tradingPair",
-            "reserves_1",
-            "reserves1",
-            "reserves_2",
-            "reserves2",
-            "sequence",
+            "metadata",
         ];
 
         #[allow(clippy::enum_variant_names)]
-        enum GeneratedField {
-            PositionId,
-            TradingPair,
-            Reserves1,
-            Reserves2,
-            Sequence,
+        enum GeneratedField {
+            Metadata,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -1901,11 +8289,7 @@ impl<'de> serde::Deserialize<'de> for EventPositionWithdraw {
                         E: serde::de::Error,
                     {
                         match value {
-                            "positionId" | "position_id" => Ok(GeneratedField::PositionId),
-                            "tradingPair" | "trading_pair" => Ok(GeneratedField::TradingPair),
-                            "reserves1" | "reserves_1" => Ok(GeneratedField::Reserves1),
-                            "reserves2" | "reserves_2" => Ok(GeneratedField::Reserves2),
-                            "sequence" => Ok(GeneratedField::Sequence),
+                            "metadata" => Ok(GeneratedField::Metadata),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -1915,73 +8299,39 @@ impl<'de> serde::Deserialize<'de> for EventPositionWithdraw {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = EventPositionWithdraw;
+            type Value = PositionMetadataResponse;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.core.component.dex.v1.EventPositionWithdraw")
+                formatter.write_str("struct penumbra.core.component.dex.v1.PositionMetadataResponse")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<EventPositionWithdraw, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<PositionMetadataResponse, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut position_id__ = None;
-                let mut trading_pair__ = None;
-                let mut reserves_1__ = None;
-                let mut reserves_2__ = None;
-                let mut sequence__ = None;
+                let mut metadata__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::PositionId => {
-                            if position_id__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("positionId"));
-                            }
-                            position_id__ = map_.next_value()?;
-                        }
-                        GeneratedField::TradingPair => {
-                            if trading_pair__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("tradingPair"));
-                            }
-                            trading_pair__ = map_.next_value()?;
-                        }
-                        GeneratedField::Reserves1 => {
-                            if reserves_1__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("reserves1"));
-                            }
-                            reserves_1__ = map_.next_value()?;
-                        }
-                        GeneratedField::Reserves2 => {
-                            if reserves_2__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("reserves2"));
-                            }
-                            reserves_2__ = map_.next_value()?;
-                        }
-                        GeneratedField::Sequence => {
-                            if sequence__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("sequence"));
+                        GeneratedField::Metadata => {
+                            if metadata__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("metadata"));
                             }
-                            sequence__ = 
-                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
-                            ;
+                            metadata__ = map_.next_value()?;
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(EventPositionWithdraw {
-                    position_id: position_id__,
-                    trading_pair: trading_pair__,
-                    reserves_1: reserves_1__,
-                    reserves_2: reserves_2__,
-                    sequence: sequence__.unwrap_or_default(),
+                Ok(PositionMetadataResponse {
+                    metadata: metadata__,
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.core.component.dex.v1.EventPositionWithdraw", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.core.component.dex.v1.PositionMetadataResponse", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for EventSwap {
+impl serde::Serialize for PositionOpen {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -1989,57 +8339,38 @@ impl serde::Serialize for EventSwap {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.trading_pair.is_some() {
-            len += 1;
-        }
-        if self.delta_1_i.is_some() {
-            len += 1;
-        }
-        if self.delta_2_i.is_some() {
+        if self.position.is_some() {
             len += 1;
         }
-        if self.swap_commitment.is_some() {
+        if !self.strategy_tag.is_empty() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.EventSwap", len)?;
-        if let Some(v) = self.trading_pair.as_ref() {
-            struct_ser.serialize_field("tradingPair", v)?;
-        }
-        if let Some(v) = self.delta_1_i.as_ref() {
-            struct_ser.serialize_field("delta1I", v)?;
-        }
-        if let Some(v) = self.delta_2_i.as_ref() {
-            struct_ser.serialize_field("delta2I", v)?;
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.PositionOpen", len)?;
+        if let Some(v) = self.position.as_ref() {
+            struct_ser.serialize_field("position", v)?;
         }
-        if let Some(v) = self.swap_commitment.as_ref() {
-            struct_ser.serialize_field("swapCommitment", v)?;
+        if !self.strategy_tag.is_empty() {
+            struct_ser.serialize_field("strategyTag", &self.strategy_tag)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for EventSwap {
+impl<'de> serde::Deserialize<'de> for PositionOpen {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "trading_pair",
-            "tradingPair",
-            "delta_1_i",
-            "delta1I",
-            "delta_2_i",
-            "delta2I",
-            "swap_commitment",
-            "swapCommitment",
+            "position",
+            "strategy_tag",
+            "strategyTag",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            TradingPair,
-            Delta1I,
-            Delta2I,
-            SwapCommitment,
+            Position,
+            StrategyTag,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -2062,10 +8393,8 @@ impl<'de> serde::Deserialize<'de> for EventSwap {
                         E: serde::de::Error,
                     {
                         match value {
-                            "tradingPair" | "trading_pair" => Ok(GeneratedField::TradingPair),
-                            "delta1I" | "delta_1_i" => Ok(GeneratedField::Delta1I),
-                            "delta2I" | "delta_2_i" => Ok(GeneratedField::Delta2I),
-                            "swapCommitment" | "swap_commitment" => Ok(GeneratedField::SwapCommitment),
+                            "position" => Ok(GeneratedField::Position),
+                            "strategyTag" | "strategy_tag" => Ok(GeneratedField::StrategyTag),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -2075,63 +8404,47 @@ impl<'de> serde::Deserialize<'de> for EventSwap {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = EventSwap;
+            type Value = PositionOpen;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.core.component.dex.v1.EventSwap")
+                formatter.write_str("struct penumbra.core.component.dex.v1.PositionOpen")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<EventSwap, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<PositionOpen, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut trading_pair__ = None;
-                let mut delta_1_i__ = None;
-                let mut delta_2_i__ = None;
-                let mut swap_commitment__ = None;
+                let mut position__ = None;
+                let mut strategy_tag__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::TradingPair => {
-                            if trading_pair__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("tradingPair"));
-                            }
-                            trading_pair__ = map_.next_value()?;
-                        }
-                        GeneratedField::Delta1I => {
-                            if delta_1_i__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("delta1I"));
-                            }
-                            delta_1_i__ = map_.next_value()?;
-                        }
-                        GeneratedField::Delta2I => {
-                            if delta_2_i__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("delta2I"));
+                        GeneratedField::Position => {
+                            if position__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("position"));
                             }
-                            delta_2_i__ = map_.next_value()?;
+                            position__ = map_.next_value()?;
                         }
-                        GeneratedField::SwapCommitment => {
-                            if swap_commitment__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("swapCommitment"));
+                        GeneratedField::StrategyTag => {
+                            if strategy_tag__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("strategyTag"));
                             }
-                            swap_commitment__ = map_.next_value()?;
+                            strategy_tag__ = Some(map_.next_value()?);
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(EventSwap {
-                    trading_pair: trading_pair__,
-                    delta_1_i: delta_1_i__,
-                    delta_2_i: delta_2_i__,
-                    swap_commitment: swap_commitment__,
+                Ok(PositionOpen {
+                    position: position__,
+                    strategy_tag: strategy_tag__.unwrap_or_default(),
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.core.component.dex.v1.EventSwap", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.core.component.dex.v1.PositionOpen", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for EventSwapClaim {
+impl serde::Serialize for PositionOpenBatch {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -2139,56 +8452,38 @@ impl serde::Serialize for EventSwapClaim {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.trading_pair.is_some() {
-            len += 1;
-        }
-        if self.output_1_commitment.is_some() {
-            len += 1;
-        }
-        if self.output_2_commitment.is_some() {
+        if !self.positions.is_empty() {
             len += 1;
         }
-        if self.nullifier.is_some() {
+        if !self.strategy_tag.is_empty() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.EventSwapClaim", len)?;
-        if let Some(v) = self.trading_pair.as_ref() {
-            struct_ser.serialize_field("tradingPair", v)?;
-        }
-        if let Some(v) = self.output_1_commitment.as_ref() {
-            struct_ser.serialize_field("output1Commitment", v)?;
-        }
-        if let Some(v) = self.output_2_commitment.as_ref() {
-            struct_ser.serialize_field("output2Commitment", v)?;
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.PositionOpenBatch", len)?;
+        if !self.positions.is_empty() {
+            struct_ser.serialize_field("positions", &self.positions)?;
         }
-        if let Some(v) = self.nullifier.as_ref() {
-            struct_ser.serialize_field("nullifier", v)?;
+        if !self.strategy_tag.is_empty() {
+            struct_ser.serialize_field("strategyTag", &self.strategy_tag)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for EventSwapClaim {
+impl<'de> serde::Deserialize<'de> for PositionOpenBatch {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "trading_pair",
-            "tradingPair",
-            "output_1_commitment",
-            "output1Commitment",
-            "output_2_commitment",
-            "output2Commitment",
-            "nullifier",
+            "positions",
+            "strategy_tag",
+            "strategyTag",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            TradingPair,
-            Output1Commitment,
-            Output2Commitment,
-            Nullifier,
+            Positions,
+            StrategyTag,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -2211,10 +8506,8 @@ impl<'de> serde::Deserialize<'de> for EventSwapClaim {
                         E: serde::de::Error,
                     {
                         match value {
-                            "tradingPair" | "trading_pair" => Ok(GeneratedField::TradingPair),
-                            "output1Commitment" | "output_1_commitment" => Ok(GeneratedField::Output1Commitment),
-                            "output2Commitment" | "output_2_commitment" => Ok(GeneratedField::Output2Commitment),
-                            "nullifier" => Ok(GeneratedField::Nullifier),
+                            "positions" => Ok(GeneratedField::Positions),
+                            "strategyTag" | "strategy_tag" => Ok(GeneratedField::StrategyTag),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -2224,63 +8517,47 @@ impl<'de> serde::Deserialize<'de> for EventSwapClaim {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = EventSwapClaim;
+            type Value = PositionOpenBatch;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.core.component.dex.v1.EventSwapClaim")
+                formatter.write_str("struct penumbra.core.component.dex.v1.PositionOpenBatch")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<EventSwapClaim, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<PositionOpenBatch, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut trading_pair__ = None;
-                let mut output_1_commitment__ = None;
-                let mut output_2_commitment__ = None;
-                let mut nullifier__ = None;
+                let mut positions__ = None;
+                let mut strategy_tag__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::TradingPair => {
-                            if trading_pair__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("tradingPair"));
-                            }
-                            trading_pair__ = map_.next_value()?;
-                        }
-                        GeneratedField::Output1Commitment => {
-                            if output_1_commitment__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("output1Commitment"));
-                            }
-                            output_1_commitment__ = map_.next_value()?;
-                        }
-                        GeneratedField::Output2Commitment => {
-                            if output_2_commitment__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("output2Commitment"));
+                        GeneratedField::Positions => {
+                            if positions__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("positions"));
                             }
-                            output_2_commitment__ = map_.next_value()?;
+                            positions__ = Some(map_.next_value()?);
                         }
-                        GeneratedField::Nullifier => {
-                            if nullifier__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("nullifier"));
+                        GeneratedField::StrategyTag => {
+                            if strategy_tag__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("strategyTag"));
                             }
-                            nullifier__ = map_.next_value()?;
+                            strategy_tag__ = Some(map_.next_value()?);
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(EventSwapClaim {
-                    trading_pair: trading_pair__,
-                    output_1_commitment: output_1_commitment__,
-                    output_2_commitment: output_2_commitment__,
-                    nullifier: nullifier__,
+                Ok(PositionOpenBatch {
+                    positions: positions__.unwrap_or_default(),
+                    strategy_tag: strategy_tag__.unwrap_or_default(),
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.core.component.dex.v1.EventSwapClaim", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.core.component.dex.v1.PositionOpenBatch", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for LiquidityPositionByIdRequest {
+impl serde::Serialize for PositionRewardClaim {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -2291,14 +8568,20 @@ impl serde::Serialize for LiquidityPositionByIdRequest {
         if self.position_id.is_some() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.LiquidityPositionByIdRequest", len)?;
+        if self.rewards_commitment.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.PositionRewardClaim", len)?;
         if let Some(v) = self.position_id.as_ref() {
             struct_ser.serialize_field("positionId", v)?;
         }
+        if let Some(v) = self.rewards_commitment.as_ref() {
+            struct_ser.serialize_field("rewardsCommitment", v)?;
+        }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for LiquidityPositionByIdRequest {
+impl<'de> serde::Deserialize<'de> for PositionRewardClaim {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
@@ -2307,11 +8590,14 @@ impl<'de> serde::Deserialize<'de> for LiquidityPositionByIdRequest {
         const FIELDS: &[&str] = &[
             "position_id",
             "positionId",
+            "rewards_commitment",
+            "rewardsCommitment",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
             PositionId,
+            RewardsCommitment,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -2335,6 +8621,7 @@ impl<'de> serde::Deserialize<'de> for LiquidityPositionByIdRequest {
                     {
                         match value {
                             "positionId" | "position_id" => Ok(GeneratedField::PositionId),
+                            "rewardsCommitment" | "rewards_commitment" => Ok(GeneratedField::RewardsCommitment),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -2344,17 +8631,18 @@ impl<'de> serde::Deserialize<'de> for LiquidityPositionByIdRequest {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = LiquidityPositionByIdRequest;
+            type Value = PositionRewardClaim;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.core.component.dex.v1.LiquidityPositionByIdRequest")
+                formatter.write_str("struct penumbra.core.component.dex.v1.PositionRewardClaim")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<LiquidityPositionByIdRequest, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<PositionRewardClaim, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
                 let mut position_id__ = None;
+                let mut rewards_commitment__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
                         GeneratedField::PositionId => {
@@ -2363,20 +8651,27 @@ impl<'de> serde::Deserialize<'de> for LiquidityPositionByIdRequest {
                             }
                             position_id__ = map_.next_value()?;
                         }
+                        GeneratedField::RewardsCommitment => {
+                            if rewards_commitment__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("rewardsCommitment"));
+                            }
+                            rewards_commitment__ = map_.next_value()?;
+                        }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(LiquidityPositionByIdRequest {
+                Ok(PositionRewardClaim {
                     position_id: position_id__,
+                    rewards_commitment: rewards_commitment__,
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.core.component.dex.v1.LiquidityPositionByIdRequest", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.core.component.dex.v1.PositionRewardClaim", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for LiquidityPositionByIdResponse {
+impl serde::Serialize for PositionRewardClaimPlan {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -2384,29 +8679,29 @@ impl serde::Serialize for LiquidityPositionByIdResponse {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.data.is_some() {
+        if self.reserves.is_some() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.LiquidityPositionByIdResponse", len)?;
-        if let Some(v) = self.data.as_ref() {
-            struct_ser.serialize_field("data", v)?;
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.PositionRewardClaimPlan", len)?;
+        if let Some(v) = self.reserves.as_ref() {
+            struct_ser.serialize_field("reserves", v)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for LiquidityPositionByIdResponse {
+impl<'de> serde::Deserialize<'de> for PositionRewardClaimPlan {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "data",
+            "reserves",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            Data,
+            Reserves,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -2429,7 +8724,7 @@ impl<'de> serde::Deserialize<'de> for LiquidityPositionByIdResponse {
                         E: serde::de::Error,
                     {
                         match value {
-                            "data" => Ok(GeneratedField::Data),
+                            "reserves" => Ok(GeneratedField::Reserves),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -2439,39 +8734,39 @@ impl<'de> serde::Deserialize<'de> for LiquidityPositionByIdResponse {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = LiquidityPositionByIdResponse;
+            type Value = PositionRewardClaimPlan;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.core.component.dex.v1.LiquidityPositionByIdResponse")
+                formatter.write_str("struct penumbra.core.component.dex.v1.PositionRewardClaimPlan")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<LiquidityPositionByIdResponse, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<PositionRewardClaimPlan, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut data__ = None;
+                let mut reserves__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::Data => {
-                            if data__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("data"));
+                        GeneratedField::Reserves => {
+                            if reserves__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("reserves"));
                             }
-                            data__ = map_.next_value()?;
+                            reserves__ = map_.next_value()?;
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(LiquidityPositionByIdResponse {
-                    data: data__,
+                Ok(PositionRewardClaimPlan {
+                    reserves: reserves__,
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.core.component.dex.v1.LiquidityPositionByIdResponse", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.core.component.dex.v1.PositionRewardClaimPlan", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for LiquidityPositionsByIdRequest {
+impl serde::Serialize for PositionRewrite {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -2479,17 +8774,29 @@ impl serde::Serialize for LiquidityPositionsByIdRequest {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if !self.position_id.is_empty() {
+        if self.position_id.is_some() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.LiquidityPositionsByIdRequest", len)?;
-        if !self.position_id.is_empty() {
-            struct_ser.serialize_field("positionId", &self.position_id)?;
+        if self.reserves_commitment.is_some() {
+            len += 1;
+        }
+        if self.new_position.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.PositionRewrite", len)?;
+        if let Some(v) = self.position_id.as_ref() {
+            struct_ser.serialize_field("positionId", v)?;
+        }
+        if let Some(v) = self.reserves_commitment.as_ref() {
+            struct_ser.serialize_field("reservesCommitment", v)?;
+        }
+        if let Some(v) = self.new_position.as_ref() {
+            struct_ser.serialize_field("newPosition", v)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for LiquidityPositionsByIdRequest {
+impl<'de> serde::Deserialize<'de> for PositionRewrite {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
@@ -2498,11 +8805,17 @@ impl<'de> serde::Deserialize<'de> for LiquidityPositionsByIdRequest {
         const FIELDS: &[&str] = &[
             "position_id",
             "positionId",
+            "reserves_commitment",
+            "reservesCommitment",
+            "new_position",
+            "newPosition",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
             PositionId,
+            ReservesCommitment,
+            NewPosition,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -2526,6 +8839,8 @@ impl<'de> serde::Deserialize<'de> for LiquidityPositionsByIdRequest {
                     {
                         match value {
                             "positionId" | "position_id" => Ok(GeneratedField::PositionId),
+                            "reservesCommitment" | "reserves_commitment" => Ok(GeneratedField::ReservesCommitment),
+                            "newPosition" | "new_position" => Ok(GeneratedField::NewPosition),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -2535,39 +8850,55 @@ impl<'de> serde::Deserialize<'de> for LiquidityPositionsByIdRequest {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = LiquidityPositionsByIdRequest;
+            type Value = PositionRewrite;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.core.component.dex.v1.LiquidityPositionsByIdRequest")
+                formatter.write_str("struct penumbra.core.component.dex.v1.PositionRewrite")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<LiquidityPositionsByIdRequest, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<PositionRewrite, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
                 let mut position_id__ = None;
+                let mut reserves_commitment__ = None;
+                let mut new_position__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
                         GeneratedField::PositionId => {
                             if position_id__.is_some() {
                                 return Err(serde::de::Error::duplicate_field("positionId"));
                             }
-                            position_id__ = Some(map_.next_value()?);
+                            position_id__ = map_.next_value()?;
+                        }
+                        GeneratedField::ReservesCommitment => {
+                            if reserves_commitment__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("reservesCommitment"));
+                            }
+                            reserves_commitment__ = map_.next_value()?;
+                        }
+                        GeneratedField::NewPosition => {
+                            if new_position__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("newPosition"));
+                            }
+                            new_position__ = map_.next_value()?;
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(LiquidityPositionsByIdRequest {
-                    position_id: position_id__.unwrap_or_default(),
+                Ok(PositionRewrite {
+                    position_id: position_id__,
+                    reserves_commitment: reserves_commitment__,
+                    new_position: new_position__,
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.core.component.dex.v1.LiquidityPositionsByIdRequest", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.core.component.dex.v1.PositionRewrite", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for LiquidityPositionsByIdResponse {
+impl serde::Serialize for PositionRewritePlan {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -2575,29 +8906,55 @@ impl serde::Serialize for LiquidityPositionsByIdResponse {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.data.is_some() {
+        if self.position_id.is_some() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.LiquidityPositionsByIdResponse", len)?;
-        if let Some(v) = self.data.as_ref() {
-            struct_ser.serialize_field("data", v)?;
+        if self.pair.is_some() {
+            len += 1;
+        }
+        if self.reserves.is_some() {
+            len += 1;
+        }
+        if self.new_position.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.PositionRewritePlan", len)?;
+        if let Some(v) = self.position_id.as_ref() {
+            struct_ser.serialize_field("positionId", v)?;
+        }
+        if let Some(v) = self.pair.as_ref() {
+            struct_ser.serialize_field("pair", v)?;
+        }
+        if let Some(v) = self.reserves.as_ref() {
+            struct_ser.serialize_field("reserves", v)?;
+        }
+        if let Some(v) = self.new_position.as_ref() {
+            struct_ser.serialize_field("newPosition", v)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for LiquidityPositionsByIdResponse {
+impl<'de> serde::Deserialize<'de> for PositionRewritePlan {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "data",
+            "position_id",
+            "positionId",
+            "pair",
+            "reserves",
+            "new_position",
+            "newPosition",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            Data,
+            PositionId,
+            Pair,
+            Reserves,
+            NewPosition,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -2620,7 +8977,10 @@ impl<'de> serde::Deserialize<'de> for LiquidityPositionsByIdResponse {
                         E: serde::de::Error,
                     {
                         match value {
-                            "data" => Ok(GeneratedField::Data),
+                            "positionId" | "position_id" => Ok(GeneratedField::PositionId),
+                            "pair" => Ok(GeneratedField::Pair),
+                            "reserves" => Ok(GeneratedField::Reserves),
+                            "newPosition" | "new_position" => Ok(GeneratedField::NewPosition),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -2630,39 +8990,63 @@ impl<'de> serde::Deserialize<'de> for LiquidityPositionsByIdResponse {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = LiquidityPositionsByIdResponse;
+            type Value = PositionRewritePlan;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.core.component.dex.v1.LiquidityPositionsByIdResponse")
+                formatter.write_str("struct penumbra.core.component.dex.v1.PositionRewritePlan")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<LiquidityPositionsByIdResponse, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<PositionRewritePlan, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut data__ = None;
+                let mut position_id__ = None;
+                let mut pair__ = None;
+                let mut reserves__ = None;
+                let mut new_position__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::Data => {
-                            if data__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("data"));
+                        GeneratedField::PositionId => {
+                            if position_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("positionId"));
                             }
-                            data__ = map_.next_value()?;
+                            position_id__ = map_.next_value()?;
+                        }
+                        GeneratedField::Pair => {
+                            if pair__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("pair"));
+                            }
+                            pair__ = map_.next_value()?;
+                        }
+                        GeneratedField::Reserves => {
+                            if reserves__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("reserves"));
+                            }
+                            reserves__ = map_.next_value()?;
+                        }
+                        GeneratedField::NewPosition => {
+                            if new_position__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("newPosition"));
+                            }
+                            new_position__ = map_.next_value()?;
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(LiquidityPositionsByIdResponse {
-                    data: data__,
+                Ok(PositionRewritePlan {
+                    position_id: position_id__,
+                    pair: pair__,
+                    reserves: reserves__,
+                    new_position: new_position__,
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.core.component.dex.v1.LiquidityPositionsByIdResponse", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.core.component.dex.v1.PositionRewritePlan", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for LiquidityPositionsByPriceRequest {
+impl serde::Serialize for PositionState {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -2670,39 +9054,40 @@ impl serde::Serialize for LiquidityPositionsByPriceRequest {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.trading_pair.is_some() {
+        if self.state != 0 {
             len += 1;
         }
-        if self.limit != 0 {
+        if self.sequence != 0 {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.LiquidityPositionsByPriceRequest", len)?;
-        if let Some(v) = self.trading_pair.as_ref() {
-            struct_ser.serialize_field("tradingPair", v)?;
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.PositionState", len)?;
+        if self.state != 0 {
+            let v = position_state::PositionStateEnum::try_from(self.state)
+                .map_err(|_| serde::ser::Error::custom(format!("Invalid variant {}", self.state)))?;
+            struct_ser.serialize_field("state", &v)?;
         }
-        if self.limit != 0 {
+        if self.sequence != 0 {
             #[allow(clippy::needless_borrow)]
-            struct_ser.serialize_field("limit", ToString::to_string(&self.limit).as_str())?;
+            struct_ser.serialize_field("sequence", ToString::to_string(&self.sequence).as_str())?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for LiquidityPositionsByPriceRequest {
+impl<'de> serde::Deserialize<'de> for PositionState {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "trading_pair",
-            "tradingPair",
-            "limit",
+            "state",
+            "sequence",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            TradingPair,
-            Limit,
+            State,
+            Sequence,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -2725,8 +9110,8 @@ impl<'de> serde::Deserialize<'de> for LiquidityPositionsByPriceRequest {
                         E: serde::de::Error,
                     {
                         match value {
-                            "tradingPair" | "trading_pair" => Ok(GeneratedField::TradingPair),
-                            "limit" => Ok(GeneratedField::Limit),
+                            "state" => Ok(GeneratedField::State),
+                            "sequence" => Ok(GeneratedField::Sequence),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -2736,31 +9121,31 @@ impl<'de> serde::Deserialize<'de> for LiquidityPositionsByPriceRequest {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = LiquidityPositionsByPriceRequest;
+            type Value = PositionState;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.core.component.dex.v1.LiquidityPositionsByPriceRequest")
+                formatter.write_str("struct penumbra.core.component.dex.v1.PositionState")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<LiquidityPositionsByPriceRequest, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<PositionState, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut trading_pair__ = None;
-                let mut limit__ = None;
+                let mut state__ = None;
+                let mut sequence__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::TradingPair => {
-                            if trading_pair__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("tradingPair"));
+                        GeneratedField::State => {
+                            if state__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("state"));
                             }
-                            trading_pair__ = map_.next_value()?;
+                            state__ = Some(map_.next_value::<position_state::PositionStateEnum>()? as i32);
                         }
-                        GeneratedField::Limit => {
-                            if limit__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("limit"));
+                        GeneratedField::Sequence => {
+                            if sequence__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("sequence"));
                             }
-                            limit__ = 
+                            sequence__ = 
                                 Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
                             ;
                         }
@@ -2769,111 +9154,96 @@ impl<'de> serde::Deserialize<'de> for LiquidityPositionsByPriceRequest {
                         }
                     }
                 }
-                Ok(LiquidityPositionsByPriceRequest {
-                    trading_pair: trading_pair__,
-                    limit: limit__.unwrap_or_default(),
+                Ok(PositionState {
+                    state: state__.unwrap_or_default(),
+                    sequence: sequence__.unwrap_or_default(),
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.core.component.dex.v1.LiquidityPositionsByPriceRequest", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.core.component.dex.v1.PositionState", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for LiquidityPositionsByPriceResponse {
+impl serde::Serialize for position_state::PositionStateEnum {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
         S: serde::Serializer,
     {
-        use serde::ser::SerializeStruct;
-        let mut len = 0;
-        if self.data.is_some() {
-            len += 1;
-        }
-        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.LiquidityPositionsByPriceResponse", len)?;
-        if let Some(v) = self.data.as_ref() {
-            struct_ser.serialize_field("data", v)?;
-        }
-        struct_ser.end()
+        let variant = match self {
+            Self::Unspecified => "POSITION_STATE_ENUM_UNSPECIFIED",
+            Self::Opened => "POSITION_STATE_ENUM_OPENED",
+            Self::Closed => "POSITION_STATE_ENUM_CLOSED",
+            Self::Withdrawn => "POSITION_STATE_ENUM_WITHDRAWN",
+            Self::Claimed => "POSITION_STATE_ENUM_CLAIMED",
+        };
+        serializer.serialize_str(variant)
     }
 }
-impl<'de> serde::Deserialize<'de> for LiquidityPositionsByPriceResponse {
+impl<'de> serde::Deserialize<'de> for position_state::PositionStateEnum {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "data",
-        ];
-
-        #[allow(clippy::enum_variant_names)]
-        enum GeneratedField {
-            Data,
-            __SkipField__,
-        }
-        impl<'de> serde::Deserialize<'de> for GeneratedField {
-            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
-            where
-                D: serde::Deserializer<'de>,
-            {
-                struct GeneratedVisitor;
-
-                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-                    type Value = GeneratedField;
-
-                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                        write!(formatter, "expected one of: {:?}", &FIELDS)
-                    }
+            "POSITION_STATE_ENUM_UNSPECIFIED",
+            "POSITION_STATE_ENUM_OPENED",
+            "POSITION_STATE_ENUM_CLOSED",
+            "POSITION_STATE_ENUM_WITHDRAWN",
+            "POSITION_STATE_ENUM_CLAIMED",
+        ];
 
-                    #[allow(unused_variables)]
-                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
-                    where
-                        E: serde::de::Error,
-                    {
-                        match value {
-                            "data" => Ok(GeneratedField::Data),
-                            _ => Ok(GeneratedField::__SkipField__),
-                        }
-                    }
-                }
-                deserializer.deserialize_identifier(GeneratedVisitor)
-            }
-        }
         struct GeneratedVisitor;
+
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = LiquidityPositionsByPriceResponse;
+            type Value = position_state::PositionStateEnum;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.core.component.dex.v1.LiquidityPositionsByPriceResponse")
+                write!(formatter, "expected one of: {:?}", &FIELDS)
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<LiquidityPositionsByPriceResponse, V::Error>
-                where
-                    V: serde::de::MapAccess<'de>,
+            fn visit_i64<E>(self, v: i64) -> std::result::Result<Self::Value, E>
+            where
+                E: serde::de::Error,
             {
-                let mut data__ = None;
-                while let Some(k) = map_.next_key()? {
-                    match k {
-                        GeneratedField::Data => {
-                            if data__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("data"));
-                            }
-                            data__ = map_.next_value()?;
-                        }
-                        GeneratedField::__SkipField__ => {
-                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
-                        }
-                    }
+                i32::try_from(v)
+                    .ok()
+                    .and_then(|x| x.try_into().ok())
+                    .ok_or_else(|| {
+                        serde::de::Error::invalid_value(serde::de::Unexpected::Signed(v), &self)
+                    })
+            }
+
+            fn visit_u64<E>(self, v: u64) -> std::result::Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                i32::try_from(v)
+                    .ok()
+                    .and_then(|x| x.try_into().ok())
+                    .ok_or_else(|| {
+                        serde::de::Error::invalid_value(serde::de::Unexpected::Unsigned(v), &self)
+                    })
+            }
+
+            fn visit_str<E>(self, value: &str) -> std::result::Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                match value {
+                    "POSITION_STATE_ENUM_UNSPECIFIED" => Ok(position_state::PositionStateEnum::Unspecified),
+                    "POSITION_STATE_ENUM_OPENED" => Ok(position_state::PositionStateEnum::Opened),
+                    "POSITION_STATE_ENUM_CLOSED" => Ok(position_state::PositionStateEnum::Closed),
+                    "POSITION_STATE_ENUM_WITHDRAWN" => Ok(position_state::PositionStateEnum::Withdrawn),
+                    "POSITION_STATE_ENUM_CLAIMED" => Ok(position_state::PositionStateEnum::Claimed),
+                    _ => Err(serde::de::Error::unknown_variant(value, FIELDS)),
                 }
-                Ok(LiquidityPositionsByPriceResponse {
-                    data: data__,
-                })
             }
         }
-        deserializer.deserialize_struct("penumbra.core.component.dex.v1.LiquidityPositionsByPriceResponse", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_any(GeneratedVisitor)
     }
 }
-impl serde::Serialize for LiquidityPositionsRequest {
+impl serde::Serialize for PositionUpdateBatch {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -2881,30 +9251,54 @@ impl serde::Serialize for LiquidityPositionsRequest {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.include_closed {
+        if !self.closes.is_empty() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.LiquidityPositionsRequest", len)?;
-        if self.include_closed {
-            struct_ser.serialize_field("includeClosed", &self.include_closed)?;
+        if !self.withdrawals.is_empty() {
+            len += 1;
+        }
+        if self.withdrawn_reserves_commitment.is_some() {
+            len += 1;
+        }
+        if !self.opens.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.PositionUpdateBatch", len)?;
+        if !self.closes.is_empty() {
+            struct_ser.serialize_field("closes", &self.closes)?;
+        }
+        if !self.withdrawals.is_empty() {
+            struct_ser.serialize_field("withdrawals", &self.withdrawals)?;
+        }
+        if let Some(v) = self.withdrawn_reserves_commitment.as_ref() {
+            struct_ser.serialize_field("withdrawnReservesCommitment", v)?;
+        }
+        if !self.opens.is_empty() {
+            struct_ser.serialize_field("opens", &self.opens)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for LiquidityPositionsRequest {
+impl<'de> serde::Deserialize<'de> for PositionUpdateBatch {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "include_closed",
-            "includeClosed",
+            "closes",
+            "withdrawals",
+            "withdrawn_reserves_commitment",
+            "withdrawnReservesCommitment",
+            "opens",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            IncludeClosed,
+            Closes,
+            Withdrawals,
+            WithdrawnReservesCommitment,
+            Opens,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -2927,7 +9321,10 @@ impl<'de> serde::Deserialize<'de> for LiquidityPositionsRequest {
                         E: serde::de::Error,
                     {
                         match value {
-                            "includeClosed" | "include_closed" => Ok(GeneratedField::IncludeClosed),
+                            "closes" => Ok(GeneratedField::Closes),
+                            "withdrawals" => Ok(GeneratedField::Withdrawals),
+                            "withdrawnReservesCommitment" | "withdrawn_reserves_commitment" => Ok(GeneratedField::WithdrawnReservesCommitment),
+                            "opens" => Ok(GeneratedField::Opens),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -2937,39 +9334,63 @@ impl<'de> serde::Deserialize<'de> for LiquidityPositionsRequest {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = LiquidityPositionsRequest;
+            type Value = PositionUpdateBatch;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.core.component.dex.v1.LiquidityPositionsRequest")
+                formatter.write_str("struct penumbra.core.component.dex.v1.PositionUpdateBatch")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<LiquidityPositionsRequest, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<PositionUpdateBatch, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut include_closed__ = None;
+                let mut closes__ = None;
+                let mut withdrawals__ = None;
+                let mut withdrawn_reserves_commitment__ = None;
+                let mut opens__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::IncludeClosed => {
-                            if include_closed__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("includeClosed"));
+                        GeneratedField::Closes => {
+                            if closes__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("closes"));
                             }
-                            include_closed__ = Some(map_.next_value()?);
+                            closes__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Withdrawals => {
+                            if withdrawals__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("withdrawals"));
+                            }
+                            withdrawals__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::WithdrawnReservesCommitment => {
+                            if withdrawn_reserves_commitment__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("withdrawnReservesCommitment"));
+                            }
+                            withdrawn_reserves_commitment__ = map_.next_value()?;
+                        }
+                        GeneratedField::Opens => {
+                            if opens__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("opens"));
+                            }
+                            opens__ = Some(map_.next_value()?);
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(LiquidityPositionsRequest {
-                    include_closed: include_closed__.unwrap_or_default(),
+                Ok(PositionUpdateBatch {
+                    closes: closes__.unwrap_or_default(),
+                    withdrawals: withdrawals__.unwrap_or_default(),
+                    withdrawn_reserves_commitment: withdrawn_reserves_commitment__,
+                    opens: opens__.unwrap_or_default(),
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.core.component.dex.v1.LiquidityPositionsRequest", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.core.component.dex.v1.PositionUpdateBatch", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for LiquidityPositionsResponse {
+impl serde::Serialize for position_update_batch::Withdrawal {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -2977,29 +9398,39 @@ impl serde::Serialize for LiquidityPositionsResponse {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.data.is_some() {
+        if self.position_id.is_some() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.LiquidityPositionsResponse", len)?;
-        if let Some(v) = self.data.as_ref() {
-            struct_ser.serialize_field("data", v)?;
+        if self.sequence != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.PositionUpdateBatch.Withdrawal", len)?;
+        if let Some(v) = self.position_id.as_ref() {
+            struct_ser.serialize_field("positionId", v)?;
+        }
+        if self.sequence != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("sequence", ToString::to_string(&self.sequence).as_str())?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for LiquidityPositionsResponse {
+impl<'de> serde::Deserialize<'de> for position_update_batch::Withdrawal {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "data",
+            "position_id",
+            "positionId",
+            "sequence",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            Data,
+            PositionId,
+            Sequence,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -3022,7 +9453,8 @@ impl<'de> serde::Deserialize<'de> for LiquidityPositionsResponse {
                         E: serde::de::Error,
                     {
                         match value {
-                            "data" => Ok(GeneratedField::Data),
+                            "positionId" | "position_id" => Ok(GeneratedField::PositionId),
+                            "sequence" => Ok(GeneratedField::Sequence),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -3032,39 +9464,49 @@ impl<'de> serde::Deserialize<'de> for LiquidityPositionsResponse {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = LiquidityPositionsResponse;
+            type Value = position_update_batch::Withdrawal;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.core.component.dex.v1.LiquidityPositionsResponse")
+                formatter.write_str("struct penumbra.core.component.dex.v1.PositionUpdateBatch.Withdrawal")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<LiquidityPositionsResponse, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<position_update_batch::Withdrawal, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut data__ = None;
+                let mut position_id__ = None;
+                let mut sequence__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::Data => {
-                            if data__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("data"));
+                        GeneratedField::PositionId => {
+                            if position_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("positionId"));
                             }
-                            data__ = map_.next_value()?;
+                            position_id__ = map_.next_value()?;
+                        }
+                        GeneratedField::Sequence => {
+                            if sequence__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("sequence"));
+                            }
+                            sequence__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(LiquidityPositionsResponse {
-                    data: data__,
+                Ok(position_update_batch::Withdrawal {
+                    position_id: position_id__,
+                    sequence: sequence__.unwrap_or_default(),
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.core.component.dex.v1.LiquidityPositionsResponse", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.core.component.dex.v1.PositionUpdateBatch.Withdrawal", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for LpNft {
+impl serde::Serialize for PositionUpdateBatchPlan {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -3072,38 +9514,45 @@ impl serde::Serialize for LpNft {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.position_id.is_some() {
+        if !self.closes.is_empty() {
             len += 1;
         }
-        if self.state.is_some() {
+        if !self.withdrawals.is_empty() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.LpNft", len)?;
-        if let Some(v) = self.position_id.as_ref() {
-            struct_ser.serialize_field("positionId", v)?;
+        if !self.opens.is_empty() {
+            len += 1;
         }
-        if let Some(v) = self.state.as_ref() {
-            struct_ser.serialize_field("state", v)?;
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.PositionUpdateBatchPlan", len)?;
+        if !self.closes.is_empty() {
+            struct_ser.serialize_field("closes", &self.closes)?;
+        }
+        if !self.withdrawals.is_empty() {
+            struct_ser.serialize_field("withdrawals", &self.withdrawals)?;
+        }
+        if !self.opens.is_empty() {
+            struct_ser.serialize_field("opens", &self.opens)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for LpNft {
+impl<'de> serde::Deserialize<'de> for PositionUpdateBatchPlan {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "position_id",
-            "positionId",
-            "state",
+            "closes",
+            "withdrawals",
+            "opens",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            PositionId,
-            State,
+            Closes,
+            Withdrawals,
+            Opens,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -3126,8 +9575,9 @@ impl<'de> serde::Deserialize<'de> for LpNft {
                         E: serde::de::Error,
                     {
                         match value {
-                            "positionId" | "position_id" => Ok(GeneratedField::PositionId),
-                            "state" => Ok(GeneratedField::State),
+                            "closes" => Ok(GeneratedField::Closes),
+                            "withdrawals" => Ok(GeneratedField::Withdrawals),
+                            "opens" => Ok(GeneratedField::Opens),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -3137,47 +9587,55 @@ impl<'de> serde::Deserialize<'de> for LpNft {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = LpNft;
+            type Value = PositionUpdateBatchPlan;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.core.component.dex.v1.LpNft")
+                formatter.write_str("struct penumbra.core.component.dex.v1.PositionUpdateBatchPlan")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<LpNft, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<PositionUpdateBatchPlan, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut position_id__ = None;
-                let mut state__ = None;
+                let mut closes__ = None;
+                let mut withdrawals__ = None;
+                let mut opens__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::PositionId => {
-                            if position_id__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("positionId"));
+                        GeneratedField::Closes => {
+                            if closes__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("closes"));
                             }
-                            position_id__ = map_.next_value()?;
+                            closes__ = Some(map_.next_value()?);
                         }
-                        GeneratedField::State => {
-                            if state__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("state"));
+                        GeneratedField::Withdrawals => {
+                            if withdrawals__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("withdrawals"));
                             }
-                            state__ = map_.next_value()?;
+                            withdrawals__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Opens => {
+                            if opens__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("opens"));
+                            }
+                            opens__ = Some(map_.next_value()?);
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(LpNft {
-                    position_id: position_id__,
-                    state: state__,
+                Ok(PositionUpdateBatchPlan {
+                    closes: closes__.unwrap_or_default(),
+                    withdrawals: withdrawals__.unwrap_or_default(),
+                    opens: opens__.unwrap_or_default(),
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.core.component.dex.v1.LpNft", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.core.component.dex.v1.PositionUpdateBatchPlan", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for Position {
+impl serde::Serialize for PositionValuation {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -3185,63 +9643,66 @@ impl serde::Serialize for Position {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.phi.is_some() {
+        if self.height != 0 {
             len += 1;
         }
-        if !self.nonce.is_empty() {
+        if self.held_value.is_some() {
             len += 1;
         }
-        if self.state.is_some() {
+        if self.final_value.is_some() {
             len += 1;
         }
-        if self.reserves.is_some() {
+        if self.fees_value.is_some() {
             len += 1;
         }
-        if self.close_on_fill {
+        if self.impermanent_loss.is_some() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.Position", len)?;
-        if let Some(v) = self.phi.as_ref() {
-            struct_ser.serialize_field("phi", v)?;
-        }
-        if !self.nonce.is_empty() {
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.PositionValuation", len)?;
+        if self.height != 0 {
             #[allow(clippy::needless_borrow)]
-            struct_ser.serialize_field("nonce", pbjson::private::base64::encode(&self.nonce).as_str())?;
+            struct_ser.serialize_field("height", ToString::to_string(&self.height).as_str())?;
         }
-        if let Some(v) = self.state.as_ref() {
-            struct_ser.serialize_field("state", v)?;
+        if let Some(v) = self.held_value.as_ref() {
+            struct_ser.serialize_field("heldValue", v)?;
         }
-        if let Some(v) = self.reserves.as_ref() {
-            struct_ser.serialize_field("reserves", v)?;
+        if let Some(v) = self.final_value.as_ref() {
+            struct_ser.serialize_field("finalValue", v)?;
         }
-        if self.close_on_fill {
-            struct_ser.serialize_field("closeOnFill", &self.close_on_fill)?;
+        if let Some(v) = self.fees_value.as_ref() {
+            struct_ser.serialize_field("feesValue", v)?;
+        }
+        if let Some(v) = self.impermanent_loss.as_ref() {
+            struct_ser.serialize_field("impermanentLoss", v)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for Position {
+impl<'de> serde::Deserialize<'de> for PositionValuation {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "phi",
-            "nonce",
-            "state",
-            "reserves",
-            "close_on_fill",
-            "closeOnFill",
+            "height",
+            "held_value",
+            "heldValue",
+            "final_value",
+            "finalValue",
+            "fees_value",
+            "feesValue",
+            "impermanent_loss",
+            "impermanentLoss",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            Phi,
-            Nonce,
-            State,
-            Reserves,
-            CloseOnFill,
+            Height,
+            HeldValue,
+            FinalValue,
+            FeesValue,
+            ImpermanentLoss,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -3264,11 +9725,11 @@ impl<'de> serde::Deserialize<'de> for Position {
                         E: serde::de::Error,
                     {
                         match value {
-                            "phi" => Ok(GeneratedField::Phi),
-                            "nonce" => Ok(GeneratedField::Nonce),
-                            "state" => Ok(GeneratedField::State),
-                            "reserves" => Ok(GeneratedField::Reserves),
-                            "closeOnFill" | "close_on_fill" => Ok(GeneratedField::CloseOnFill),
+                            "height" => Ok(GeneratedField::Height),
+                            "heldValue" | "held_value" => Ok(GeneratedField::HeldValue),
+                            "finalValue" | "final_value" => Ok(GeneratedField::FinalValue),
+                            "feesValue" | "fees_value" => Ok(GeneratedField::FeesValue),
+                            "impermanentLoss" | "impermanent_loss" => Ok(GeneratedField::ImpermanentLoss),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -3278,73 +9739,73 @@ impl<'de> serde::Deserialize<'de> for Position {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = Position;
+            type Value = PositionValuation;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.core.component.dex.v1.Position")
+                formatter.write_str("struct penumbra.core.component.dex.v1.PositionValuation")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<Position, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<PositionValuation, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut phi__ = None;
-                let mut nonce__ = None;
-                let mut state__ = None;
-                let mut reserves__ = None;
-                let mut close_on_fill__ = None;
+                let mut height__ = None;
+                let mut held_value__ = None;
+                let mut final_value__ = None;
+                let mut fees_value__ = None;
+                let mut impermanent_loss__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::Phi => {
-                            if phi__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("phi"));
+                        GeneratedField::Height => {
+                            if height__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("height"));
                             }
-                            phi__ = map_.next_value()?;
+                            height__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
                         }
-                        GeneratedField::Nonce => {
-                            if nonce__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("nonce"));
+                        GeneratedField::HeldValue => {
+                            if held_value__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("heldValue"));
                             }
-                            nonce__ = 
-                                Some(map_.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
-                            ;
+                            held_value__ = map_.next_value()?;
                         }
-                        GeneratedField::State => {
-                            if state__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("state"));
+                        GeneratedField::FinalValue => {
+                            if final_value__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("finalValue"));
                             }
-                            state__ = map_.next_value()?;
+                            final_value__ = map_.next_value()?;
                         }
-                        GeneratedField::Reserves => {
-                            if reserves__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("reserves"));
+                        GeneratedField::FeesValue => {
+                            if fees_value__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("feesValue"));
                             }
-                            reserves__ = map_.next_value()?;
+                            fees_value__ = map_.next_value()?;
                         }
-                        GeneratedField::CloseOnFill => {
-                            if close_on_fill__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("closeOnFill"));
+                        GeneratedField::ImpermanentLoss => {
+                            if impermanent_loss__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("impermanentLoss"));
                             }
-                            close_on_fill__ = Some(map_.next_value()?);
+                            impermanent_loss__ = map_.next_value()?;
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(Position {
-                    phi: phi__,
-                    nonce: nonce__.unwrap_or_default(),
-                    state: state__,
-                    reserves: reserves__,
-                    close_on_fill: close_on_fill__.unwrap_or_default(),
+                Ok(PositionValuation {
+                    height: height__.unwrap_or_default(),
+                    held_value: held_value__,
+                    final_value: final_value__,
+                    fees_value: fees_value__,
+                    impermanent_loss: impermanent_loss__,
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.core.component.dex.v1.Position", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.core.component.dex.v1.PositionValuation", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for PositionClose {
+impl serde::Serialize for PositionWithdraw {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -3355,14 +9816,27 @@ impl serde::Serialize for PositionClose {
         if self.position_id.is_some() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.PositionClose", len)?;
+        if self.reserves_commitment.is_some() {
+            len += 1;
+        }
+        if self.sequence != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.PositionWithdraw", len)?;
         if let Some(v) = self.position_id.as_ref() {
             struct_ser.serialize_field("positionId", v)?;
         }
+        if let Some(v) = self.reserves_commitment.as_ref() {
+            struct_ser.serialize_field("reservesCommitment", v)?;
+        }
+        if self.sequence != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("sequence", ToString::to_string(&self.sequence).as_str())?;
+        }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for PositionClose {
+impl<'de> serde::Deserialize<'de> for PositionWithdraw {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
@@ -3371,11 +9845,16 @@ impl<'de> serde::Deserialize<'de> for PositionClose {
         const FIELDS: &[&str] = &[
             "position_id",
             "positionId",
+            "reserves_commitment",
+            "reservesCommitment",
+            "sequence",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
             PositionId,
+            ReservesCommitment,
+            Sequence,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -3399,6 +9878,8 @@ impl<'de> serde::Deserialize<'de> for PositionClose {
                     {
                         match value {
                             "positionId" | "position_id" => Ok(GeneratedField::PositionId),
+                            "reservesCommitment" | "reserves_commitment" => Ok(GeneratedField::ReservesCommitment),
+                            "sequence" => Ok(GeneratedField::Sequence),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -3408,17 +9889,19 @@ impl<'de> serde::Deserialize<'de> for PositionClose {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = PositionClose;
+            type Value = PositionWithdraw;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.core.component.dex.v1.PositionClose")
+                formatter.write_str("struct penumbra.core.component.dex.v1.PositionWithdraw")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<PositionClose, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<PositionWithdraw, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
                 let mut position_id__ = None;
+                let mut reserves_commitment__ = None;
+                let mut sequence__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
                         GeneratedField::PositionId => {
@@ -3427,20 +9910,36 @@ impl<'de> serde::Deserialize<'de> for PositionClose {
                             }
                             position_id__ = map_.next_value()?;
                         }
+                        GeneratedField::ReservesCommitment => {
+                            if reserves_commitment__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("reservesCommitment"));
+                            }
+                            reserves_commitment__ = map_.next_value()?;
+                        }
+                        GeneratedField::Sequence => {
+                            if sequence__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("sequence"));
+                            }
+                            sequence__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(PositionClose {
+                Ok(PositionWithdraw {
                     position_id: position_id__,
+                    reserves_commitment: reserves_commitment__,
+                    sequence: sequence__.unwrap_or_default(),
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.core.component.dex.v1.PositionClose", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.core.component.dex.v1.PositionWithdraw", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for PositionId {
+impl serde::Serialize for PositionWithdrawPlan {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -3448,39 +9947,63 @@ impl serde::Serialize for PositionId {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if !self.inner.is_empty() {
+        if self.reserves.is_some() {
             len += 1;
         }
-        if !self.alt_bech32m.is_empty() {
+        if self.position_id.is_some() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.PositionId", len)?;
-        if !self.inner.is_empty() {
+        if self.pair.is_some() {
+            len += 1;
+        }
+        if self.sequence != 0 {
+            len += 1;
+        }
+        if !self.rewards.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.PositionWithdrawPlan", len)?;
+        if let Some(v) = self.reserves.as_ref() {
+            struct_ser.serialize_field("reserves", v)?;
+        }
+        if let Some(v) = self.position_id.as_ref() {
+            struct_ser.serialize_field("positionId", v)?;
+        }
+        if let Some(v) = self.pair.as_ref() {
+            struct_ser.serialize_field("pair", v)?;
+        }
+        if self.sequence != 0 {
             #[allow(clippy::needless_borrow)]
-            struct_ser.serialize_field("inner", pbjson::private::base64::encode(&self.inner).as_str())?;
+            struct_ser.serialize_field("sequence", ToString::to_string(&self.sequence).as_str())?;
         }
-        if !self.alt_bech32m.is_empty() {
-            struct_ser.serialize_field("altBech32m", &self.alt_bech32m)?;
+        if !self.rewards.is_empty() {
+            struct_ser.serialize_field("rewards", &self.rewards)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for PositionId {
+impl<'de> serde::Deserialize<'de> for PositionWithdrawPlan {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "inner",
-            "alt_bech32m",
-            "altBech32m",
+            "reserves",
+            "position_id",
+            "positionId",
+            "pair",
+            "sequence",
+            "rewards",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            Inner,
-            AltBech32m,
+            Reserves,
+            PositionId,
+            Pair,
+            Sequence,
+            Rewards,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -3503,8 +10026,11 @@ impl<'de> serde::Deserialize<'de> for PositionId {
                         E: serde::de::Error,
                     {
                         match value {
-                            "inner" => Ok(GeneratedField::Inner),
-                            "altBech32m" | "alt_bech32m" => Ok(GeneratedField::AltBech32m),
+                            "reserves" => Ok(GeneratedField::Reserves),
+                            "positionId" | "position_id" => Ok(GeneratedField::PositionId),
+                            "pair" => Ok(GeneratedField::Pair),
+                            "sequence" => Ok(GeneratedField::Sequence),
+                            "rewards" => Ok(GeneratedField::Rewards),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -3514,49 +10040,73 @@ impl<'de> serde::Deserialize<'de> for PositionId {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = PositionId;
+            type Value = PositionWithdrawPlan;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.core.component.dex.v1.PositionId")
+                formatter.write_str("struct penumbra.core.component.dex.v1.PositionWithdrawPlan")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<PositionId, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<PositionWithdrawPlan, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut inner__ = None;
-                let mut alt_bech32m__ = None;
+                let mut reserves__ = None;
+                let mut position_id__ = None;
+                let mut pair__ = None;
+                let mut sequence__ = None;
+                let mut rewards__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::Inner => {
-                            if inner__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("inner"));
+                        GeneratedField::Reserves => {
+                            if reserves__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("reserves"));
                             }
-                            inner__ = 
-                                Some(map_.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
+                            reserves__ = map_.next_value()?;
+                        }
+                        GeneratedField::PositionId => {
+                            if position_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("positionId"));
+                            }
+                            position_id__ = map_.next_value()?;
+                        }
+                        GeneratedField::Pair => {
+                            if pair__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("pair"));
+                            }
+                            pair__ = map_.next_value()?;
+                        }
+                        GeneratedField::Sequence => {
+                            if sequence__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("sequence"));
+                            }
+                            sequence__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
                             ;
                         }
-                        GeneratedField::AltBech32m => {
-                            if alt_bech32m__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("altBech32m"));
+                        GeneratedField::Rewards => {
+                            if rewards__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("rewards"));
                             }
-                            alt_bech32m__ = Some(map_.next_value()?);
+                            rewards__ = Some(map_.next_value()?);
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(PositionId {
-                    inner: inner__.unwrap_or_default(),
-                    alt_bech32m: alt_bech32m__.unwrap_or_default(),
+                Ok(PositionWithdrawPlan {
+                    reserves: reserves__,
+                    position_id: position_id__,
+                    pair: pair__,
+                    sequence: sequence__.unwrap_or_default(),
+                    rewards: rewards__.unwrap_or_default(),
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.core.component.dex.v1.PositionId", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.core.component.dex.v1.PositionWithdrawPlan", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for PositionOpen {
+impl serde::Serialize for Reserves {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -3564,29 +10114,37 @@ impl serde::Serialize for PositionOpen {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.position.is_some() {
+        if self.r1.is_some() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.PositionOpen", len)?;
-        if let Some(v) = self.position.as_ref() {
-            struct_ser.serialize_field("position", v)?;
+        if self.r2.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.Reserves", len)?;
+        if let Some(v) = self.r1.as_ref() {
+            struct_ser.serialize_field("r1", v)?;
+        }
+        if let Some(v) = self.r2.as_ref() {
+            struct_ser.serialize_field("r2", v)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for PositionOpen {
+impl<'de> serde::Deserialize<'de> for Reserves {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "position",
+            "r1",
+            "r2",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            Position,
+            R1,
+            R2,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -3609,7 +10167,8 @@ impl<'de> serde::Deserialize<'de> for PositionOpen {
                         E: serde::de::Error,
                     {
                         match value {
-                            "position" => Ok(GeneratedField::Position),
+                            "r1" => Ok(GeneratedField::R1),
+                            "r2" => Ok(GeneratedField::R2),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -3619,39 +10178,47 @@ impl<'de> serde::Deserialize<'de> for PositionOpen {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = PositionOpen;
+            type Value = Reserves;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.core.component.dex.v1.PositionOpen")
+                formatter.write_str("struct penumbra.core.component.dex.v1.Reserves")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<PositionOpen, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<Reserves, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut position__ = None;
+                let mut r1__ = None;
+                let mut r2__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::Position => {
-                            if position__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("position"));
+                        GeneratedField::R1 => {
+                            if r1__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("r1"));
                             }
-                            position__ = map_.next_value()?;
+                            r1__ = map_.next_value()?;
+                        }
+                        GeneratedField::R2 => {
+                            if r2__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("r2"));
+                            }
+                            r2__ = map_.next_value()?;
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(PositionOpen {
-                    position: position__,
+                Ok(Reserves {
+                    r1: r1__,
+                    r2: r2__,
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.core.component.dex.v1.PositionOpen", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.core.component.dex.v1.Reserves", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for PositionRewardClaim {
+impl serde::Serialize for RouteFill {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -3659,39 +10226,45 @@ impl serde::Serialize for PositionRewardClaim {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.position_id.is_some() {
+        if !self.route.is_empty() {
             len += 1;
         }
-        if self.rewards_commitment.is_some() {
+        if !self.amounts.is_empty() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.PositionRewardClaim", len)?;
-        if let Some(v) = self.position_id.as_ref() {
-            struct_ser.serialize_field("positionId", v)?;
+        if !self.positions.is_empty() {
+            len += 1;
         }
-        if let Some(v) = self.rewards_commitment.as_ref() {
-            struct_ser.serialize_field("rewardsCommitment", v)?;
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.RouteFill", len)?;
+        if !self.route.is_empty() {
+            struct_ser.serialize_field("route", &self.route)?;
+        }
+        if !self.amounts.is_empty() {
+            struct_ser.serialize_field("amounts", &self.amounts)?;
+        }
+        if !self.positions.is_empty() {
+            struct_ser.serialize_field("positions", &self.positions)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for PositionRewardClaim {
+impl<'de> serde::Deserialize<'de> for RouteFill {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "position_id",
-            "positionId",
-            "rewards_commitment",
-            "rewardsCommitment",
+            "route",
+            "amounts",
+            "positions",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            PositionId,
-            RewardsCommitment,
+            Route,
+            Amounts,
+            Positions,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -3714,8 +10287,9 @@ impl<'de> serde::Deserialize<'de> for PositionRewardClaim {
                         E: serde::de::Error,
                     {
                         match value {
-                            "positionId" | "position_id" => Ok(GeneratedField::PositionId),
-                            "rewardsCommitment" | "rewards_commitment" => Ok(GeneratedField::RewardsCommitment),
+                            "route" => Ok(GeneratedField::Route),
+                            "amounts" => Ok(GeneratedField::Amounts),
+                            "positions" => Ok(GeneratedField::Positions),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -3725,47 +10299,55 @@ impl<'de> serde::Deserialize<'de> for PositionRewardClaim {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = PositionRewardClaim;
+            type Value = RouteFill;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.core.component.dex.v1.PositionRewardClaim")
+                formatter.write_str("struct penumbra.core.component.dex.v1.RouteFill")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<PositionRewardClaim, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<RouteFill, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut position_id__ = None;
-                let mut rewards_commitment__ = None;
+                let mut route__ = None;
+                let mut amounts__ = None;
+                let mut positions__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::PositionId => {
-                            if position_id__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("positionId"));
+                        GeneratedField::Route => {
+                            if route__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("route"));
                             }
-                            position_id__ = map_.next_value()?;
+                            route__ = Some(map_.next_value()?);
                         }
-                        GeneratedField::RewardsCommitment => {
-                            if rewards_commitment__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("rewardsCommitment"));
+                        GeneratedField::Amounts => {
+                            if amounts__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("amounts"));
                             }
-                            rewards_commitment__ = map_.next_value()?;
+                            amounts__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Positions => {
+                            if positions__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("positions"));
+                            }
+                            positions__ = Some(map_.next_value()?);
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(PositionRewardClaim {
-                    position_id: position_id__,
-                    rewards_commitment: rewards_commitment__,
+                Ok(RouteFill {
+                    route: route__.unwrap_or_default(),
+                    amounts: amounts__.unwrap_or_default(),
+                    positions: positions__.unwrap_or_default(),
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.core.component.dex.v1.PositionRewardClaim", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.core.component.dex.v1.RouteFill", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for PositionRewardClaimPlan {
+impl serde::Serialize for SimulateTradeRequest {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -3773,29 +10355,45 @@ impl serde::Serialize for PositionRewardClaimPlan {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.reserves.is_some() {
+        if self.input.is_some() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.PositionRewardClaimPlan", len)?;
-        if let Some(v) = self.reserves.as_ref() {
-            struct_ser.serialize_field("reserves", v)?;
+        if self.output.is_some() {
+            len += 1;
+        }
+        if self.routing.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.SimulateTradeRequest", len)?;
+        if let Some(v) = self.input.as_ref() {
+            struct_ser.serialize_field("input", v)?;
+        }
+        if let Some(v) = self.output.as_ref() {
+            struct_ser.serialize_field("output", v)?;
+        }
+        if let Some(v) = self.routing.as_ref() {
+            struct_ser.serialize_field("routing", v)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for PositionRewardClaimPlan {
+impl<'de> serde::Deserialize<'de> for SimulateTradeRequest {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "reserves",
+            "input",
+            "output",
+            "routing",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            Reserves,
+            Input,
+            Output,
+            Routing,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -3818,7 +10416,9 @@ impl<'de> serde::Deserialize<'de> for PositionRewardClaimPlan {
                         E: serde::de::Error,
                     {
                         match value {
-                            "reserves" => Ok(GeneratedField::Reserves),
+                            "input" => Ok(GeneratedField::Input),
+                            "output" => Ok(GeneratedField::Output),
+                            "routing" => Ok(GeneratedField::Routing),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -3828,39 +10428,55 @@ impl<'de> serde::Deserialize<'de> for PositionRewardClaimPlan {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = PositionRewardClaimPlan;
+            type Value = SimulateTradeRequest;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.core.component.dex.v1.PositionRewardClaimPlan")
+                formatter.write_str("struct penumbra.core.component.dex.v1.SimulateTradeRequest")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<PositionRewardClaimPlan, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<SimulateTradeRequest, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut reserves__ = None;
+                let mut input__ = None;
+                let mut output__ = None;
+                let mut routing__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::Reserves => {
-                            if reserves__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("reserves"));
+                        GeneratedField::Input => {
+                            if input__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("input"));
                             }
-                            reserves__ = map_.next_value()?;
+                            input__ = map_.next_value()?;
+                        }
+                        GeneratedField::Output => {
+                            if output__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("output"));
+                            }
+                            output__ = map_.next_value()?;
+                        }
+                        GeneratedField::Routing => {
+                            if routing__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("routing"));
+                            }
+                            routing__ = map_.next_value()?;
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(PositionRewardClaimPlan {
-                    reserves: reserves__,
+                Ok(SimulateTradeRequest {
+                    input: input__,
+                    output: output__,
+                    routing: routing__,
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.core.component.dex.v1.PositionRewardClaimPlan", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.core.component.dex.v1.SimulateTradeRequest", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for PositionState {
+impl serde::Serialize for simulate_trade_request::Routing {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -3868,40 +10484,39 @@ impl serde::Serialize for PositionState {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.state != 0 {
-            len += 1;
-        }
-        if self.sequence != 0 {
+        if self.setting.is_some() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.PositionState", len)?;
-        if self.state != 0 {
-            let v = position_state::PositionStateEnum::try_from(self.state)
-                .map_err(|_| serde::ser::Error::custom(format!("Invalid variant {}", self.state)))?;
-            struct_ser.serialize_field("state", &v)?;
-        }
-        if self.sequence != 0 {
-            #[allow(clippy::needless_borrow)]
-            struct_ser.serialize_field("sequence", ToString::to_string(&self.sequence).as_str())?;
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.SimulateTradeRequest.Routing", len)?;
+        if let Some(v) = self.setting.as_ref() {
+            match v {
+                simulate_trade_request::routing::Setting::Default(v) => {
+                    struct_ser.serialize_field("default", v)?;
+                }
+                simulate_trade_request::routing::Setting::SingleHop(v) => {
+                    struct_ser.serialize_field("singleHop", v)?;
+                }
+            }
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for PositionState {
+impl<'de> serde::Deserialize<'de> for simulate_trade_request::Routing {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "state",
-            "sequence",
+            "default",
+            "single_hop",
+            "singleHop",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            State,
-            Sequence,
+            Default,
+            SingleHop,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -3924,8 +10539,8 @@ impl<'de> serde::Deserialize<'de> for PositionState {
                         E: serde::de::Error,
                     {
                         match value {
-                            "state" => Ok(GeneratedField::State),
-                            "sequence" => Ok(GeneratedField::Sequence),
+                            "default" => Ok(GeneratedField::Default),
+                            "singleHop" | "single_hop" => Ok(GeneratedField::SingleHop),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -3935,178 +10550,141 @@ impl<'de> serde::Deserialize<'de> for PositionState {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = PositionState;
+            type Value = simulate_trade_request::Routing;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.core.component.dex.v1.PositionState")
+                formatter.write_str("struct penumbra.core.component.dex.v1.SimulateTradeRequest.Routing")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<PositionState, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<simulate_trade_request::Routing, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut state__ = None;
-                let mut sequence__ = None;
+                let mut setting__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::State => {
-                            if state__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("state"));
+                        GeneratedField::Default => {
+                            if setting__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("default"));
                             }
-                            state__ = Some(map_.next_value::<position_state::PositionStateEnum>()? as i32);
+                            setting__ = map_.next_value::<::std::option::Option<_>>()?.map(simulate_trade_request::routing::Setting::Default)
+;
                         }
-                        GeneratedField::Sequence => {
-                            if sequence__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("sequence"));
+                        GeneratedField::SingleHop => {
+                            if setting__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("singleHop"));
                             }
-                            sequence__ = 
-                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
-                            ;
+                            setting__ = map_.next_value::<::std::option::Option<_>>()?.map(simulate_trade_request::routing::Setting::SingleHop)
+;
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(PositionState {
-                    state: state__.unwrap_or_default(),
-                    sequence: sequence__.unwrap_or_default(),
+                Ok(simulate_trade_request::Routing {
+                    setting: setting__,
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.core.component.dex.v1.PositionState", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.core.component.dex.v1.SimulateTradeRequest.Routing", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for position_state::PositionStateEnum {
+impl serde::Serialize for simulate_trade_request::routing::Default {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
         S: serde::Serializer,
     {
-        let variant = match self {
-            Self::Unspecified => "POSITION_STATE_ENUM_UNSPECIFIED",
-            Self::Opened => "POSITION_STATE_ENUM_OPENED",
-            Self::Closed => "POSITION_STATE_ENUM_CLOSED",
-            Self::Withdrawn => "POSITION_STATE_ENUM_WITHDRAWN",
-            Self::Claimed => "POSITION_STATE_ENUM_CLAIMED",
-        };
-        serializer.serialize_str(variant)
+        use serde::ser::SerializeStruct;
+        let len = 0;
+        let struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.SimulateTradeRequest.Routing.Default", len)?;
+        struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for position_state::PositionStateEnum {
+impl<'de> serde::Deserialize<'de> for simulate_trade_request::routing::Default {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "POSITION_STATE_ENUM_UNSPECIFIED",
-            "POSITION_STATE_ENUM_OPENED",
-            "POSITION_STATE_ENUM_CLOSED",
-            "POSITION_STATE_ENUM_WITHDRAWN",
-            "POSITION_STATE_ENUM_CLAIMED",
         ];
 
-        struct GeneratedVisitor;
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
 
-        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = position_state::PositionStateEnum;
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
 
-            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                write!(formatter, "expected one of: {:?}", &FIELDS)
-            }
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
 
-            fn visit_i64<E>(self, v: i64) -> std::result::Result<Self::Value, E>
-            where
-                E: serde::de::Error,
-            {
-                i32::try_from(v)
-                    .ok()
-                    .and_then(|x| x.try_into().ok())
-                    .ok_or_else(|| {
-                        serde::de::Error::invalid_value(serde::de::Unexpected::Signed(v), &self)
-                    })
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                            Ok(GeneratedField::__SkipField__)
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
             }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = simulate_trade_request::routing::Default;
 
-            fn visit_u64<E>(self, v: u64) -> std::result::Result<Self::Value, E>
-            where
-                E: serde::de::Error,
-            {
-                i32::try_from(v)
-                    .ok()
-                    .and_then(|x| x.try_into().ok())
-                    .ok_or_else(|| {
-                        serde::de::Error::invalid_value(serde::de::Unexpected::Unsigned(v), &self)
-                    })
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.dex.v1.SimulateTradeRequest.Routing.Default")
             }
 
-            fn visit_str<E>(self, value: &str) -> std::result::Result<Self::Value, E>
-            where
-                E: serde::de::Error,
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<simulate_trade_request::routing::Default, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
             {
-                match value {
-                    "POSITION_STATE_ENUM_UNSPECIFIED" => Ok(position_state::PositionStateEnum::Unspecified),
-                    "POSITION_STATE_ENUM_OPENED" => Ok(position_state::PositionStateEnum::Opened),
-                    "POSITION_STATE_ENUM_CLOSED" => Ok(position_state::PositionStateEnum::Closed),
-                    "POSITION_STATE_ENUM_WITHDRAWN" => Ok(position_state::PositionStateEnum::Withdrawn),
-                    "POSITION_STATE_ENUM_CLAIMED" => Ok(position_state::PositionStateEnum::Claimed),
-                    _ => Err(serde::de::Error::unknown_variant(value, FIELDS)),
+                while map_.next_key::<GeneratedField>()?.is_some() {
+                    let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                 }
+                Ok(simulate_trade_request::routing::Default {
+                })
             }
         }
-        deserializer.deserialize_any(GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.core.component.dex.v1.SimulateTradeRequest.Routing.Default", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for PositionWithdraw {
+impl serde::Serialize for simulate_trade_request::routing::SingleHop {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
         S: serde::Serializer,
     {
         use serde::ser::SerializeStruct;
-        let mut len = 0;
-        if self.position_id.is_some() {
-            len += 1;
-        }
-        if self.reserves_commitment.is_some() {
-            len += 1;
-        }
-        if self.sequence != 0 {
-            len += 1;
-        }
-        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.PositionWithdraw", len)?;
-        if let Some(v) = self.position_id.as_ref() {
-            struct_ser.serialize_field("positionId", v)?;
-        }
-        if let Some(v) = self.reserves_commitment.as_ref() {
-            struct_ser.serialize_field("reservesCommitment", v)?;
-        }
-        if self.sequence != 0 {
-            #[allow(clippy::needless_borrow)]
-            struct_ser.serialize_field("sequence", ToString::to_string(&self.sequence).as_str())?;
-        }
+        let len = 0;
+        let struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.SimulateTradeRequest.Routing.SingleHop", len)?;
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for PositionWithdraw {
+impl<'de> serde::Deserialize<'de> for simulate_trade_request::routing::SingleHop {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "position_id",
-            "positionId",
-            "reserves_commitment",
-            "reservesCommitment",
-            "sequence",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            PositionId,
-            ReservesCommitment,
-            Sequence,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -4128,12 +10706,7 @@ impl<'de> serde::Deserialize<'de> for PositionWithdraw {
                     where
                         E: serde::de::Error,
                     {
-                        match value {
-                            "positionId" | "position_id" => Ok(GeneratedField::PositionId),
-                            "reservesCommitment" | "reserves_commitment" => Ok(GeneratedField::ReservesCommitment),
-                            "sequence" => Ok(GeneratedField::Sequence),
-                            _ => Ok(GeneratedField::__SkipField__),
-                        }
+                            Ok(GeneratedField::__SkipField__)
                     }
                 }
                 deserializer.deserialize_identifier(GeneratedVisitor)
@@ -4141,57 +10714,27 @@ impl<'de> serde::Deserialize<'de> for PositionWithdraw {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = PositionWithdraw;
+            type Value = simulate_trade_request::routing::SingleHop;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.core.component.dex.v1.PositionWithdraw")
+                formatter.write_str("struct penumbra.core.component.dex.v1.SimulateTradeRequest.Routing.SingleHop")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<PositionWithdraw, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<simulate_trade_request::routing::SingleHop, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut position_id__ = None;
-                let mut reserves_commitment__ = None;
-                let mut sequence__ = None;
-                while let Some(k) = map_.next_key()? {
-                    match k {
-                        GeneratedField::PositionId => {
-                            if position_id__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("positionId"));
-                            }
-                            position_id__ = map_.next_value()?;
-                        }
-                        GeneratedField::ReservesCommitment => {
-                            if reserves_commitment__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("reservesCommitment"));
-                            }
-                            reserves_commitment__ = map_.next_value()?;
-                        }
-                        GeneratedField::Sequence => {
-                            if sequence__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("sequence"));
-                            }
-                            sequence__ = 
-                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
-                            ;
-                        }
-                        GeneratedField::__SkipField__ => {
-                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
-                        }
-                    }
+                while map_.next_key::<GeneratedField>()?.is_some() {
+                    let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                 }
-                Ok(PositionWithdraw {
-                    position_id: position_id__,
-                    reserves_commitment: reserves_commitment__,
-                    sequence: sequence__.unwrap_or_default(),
+                Ok(simulate_trade_request::routing::SingleHop {
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.core.component.dex.v1.PositionWithdraw", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.core.component.dex.v1.SimulateTradeRequest.Routing.SingleHop", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for PositionWithdrawPlan {
+impl serde::Serialize for SimulateTradeResponse {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -4199,63 +10742,37 @@ impl serde::Serialize for PositionWithdrawPlan {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.reserves.is_some() {
-            len += 1;
-        }
-        if self.position_id.is_some() {
-            len += 1;
-        }
-        if self.pair.is_some() {
-            len += 1;
-        }
-        if self.sequence != 0 {
+        if self.output.is_some() {
             len += 1;
         }
-        if !self.rewards.is_empty() {
+        if self.unfilled.is_some() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.PositionWithdrawPlan", len)?;
-        if let Some(v) = self.reserves.as_ref() {
-            struct_ser.serialize_field("reserves", v)?;
-        }
-        if let Some(v) = self.position_id.as_ref() {
-            struct_ser.serialize_field("positionId", v)?;
-        }
-        if let Some(v) = self.pair.as_ref() {
-            struct_ser.serialize_field("pair", v)?;
-        }
-        if self.sequence != 0 {
-            #[allow(clippy::needless_borrow)]
-            struct_ser.serialize_field("sequence", ToString::to_string(&self.sequence).as_str())?;
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.SimulateTradeResponse", len)?;
+        if let Some(v) = self.output.as_ref() {
+            struct_ser.serialize_field("output", v)?;
         }
-        if !self.rewards.is_empty() {
-            struct_ser.serialize_field("rewards", &self.rewards)?;
+        if let Some(v) = self.unfilled.as_ref() {
+            struct_ser.serialize_field("unfilled", v)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for PositionWithdrawPlan {
+impl<'de> serde::Deserialize<'de> for SimulateTradeResponse {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "reserves",
-            "position_id",
-            "positionId",
-            "pair",
-            "sequence",
-            "rewards",
+            "output",
+            "unfilled",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            Reserves,
-            PositionId,
-            Pair,
-            Sequence,
-            Rewards,
+            Output,
+            Unfilled,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -4278,11 +10795,8 @@ impl<'de> serde::Deserialize<'de> for PositionWithdrawPlan {
                         E: serde::de::Error,
                     {
                         match value {
-                            "reserves" => Ok(GeneratedField::Reserves),
-                            "positionId" | "position_id" => Ok(GeneratedField::PositionId),
-                            "pair" => Ok(GeneratedField::Pair),
-                            "sequence" => Ok(GeneratedField::Sequence),
-                            "rewards" => Ok(GeneratedField::Rewards),
+                            "output" => Ok(GeneratedField::Output),
+                            "unfilled" => Ok(GeneratedField::Unfilled),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -4292,73 +10806,47 @@ impl<'de> serde::Deserialize<'de> for PositionWithdrawPlan {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = PositionWithdrawPlan;
+            type Value = SimulateTradeResponse;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.core.component.dex.v1.PositionWithdrawPlan")
+                formatter.write_str("struct penumbra.core.component.dex.v1.SimulateTradeResponse")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<PositionWithdrawPlan, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<SimulateTradeResponse, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut reserves__ = None;
-                let mut position_id__ = None;
-                let mut pair__ = None;
-                let mut sequence__ = None;
-                let mut rewards__ = None;
+                let mut output__ = None;
+                let mut unfilled__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::Reserves => {
-                            if reserves__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("reserves"));
-                            }
-                            reserves__ = map_.next_value()?;
-                        }
-                        GeneratedField::PositionId => {
-                            if position_id__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("positionId"));
-                            }
-                            position_id__ = map_.next_value()?;
-                        }
-                        GeneratedField::Pair => {
-                            if pair__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("pair"));
-                            }
-                            pair__ = map_.next_value()?;
-                        }
-                        GeneratedField::Sequence => {
-                            if sequence__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("sequence"));
+                        GeneratedField::Output => {
+                            if output__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("output"));
                             }
-                            sequence__ = 
-                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
-                            ;
+                            output__ = map_.next_value()?;
                         }
-                        GeneratedField::Rewards => {
-                            if rewards__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("rewards"));
+                        GeneratedField::Unfilled => {
+                            if unfilled__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("unfilled"));
                             }
-                            rewards__ = Some(map_.next_value()?);
+                            unfilled__ = map_.next_value()?;
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(PositionWithdrawPlan {
-                    reserves: reserves__,
-                    position_id: position_id__,
-                    pair: pair__,
-                    sequence: sequence__.unwrap_or_default(),
-                    rewards: rewards__.unwrap_or_default(),
+                Ok(SimulateTradeResponse {
+                    output: output__,
+                    unfilled: unfilled__,
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.core.component.dex.v1.PositionWithdrawPlan", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.core.component.dex.v1.SimulateTradeResponse", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for Reserves {
+impl serde::Serialize for SpreadRequest {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -4366,37 +10854,30 @@ impl serde::Serialize for Reserves {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.r1.is_some() {
-            len += 1;
-        }
-        if self.r2.is_some() {
+        if self.trading_pair.is_some() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.Reserves", len)?;
-        if let Some(v) = self.r1.as_ref() {
-            struct_ser.serialize_field("r1", v)?;
-        }
-        if let Some(v) = self.r2.as_ref() {
-            struct_ser.serialize_field("r2", v)?;
-        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.SpreadRequest", len)?;
+        if let Some(v) = self.trading_pair.as_ref() {
+            struct_ser.serialize_field("tradingPair", v)?;
+        }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for Reserves {
+impl<'de> serde::Deserialize<'de> for SpreadRequest {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "r1",
-            "r2",
+            "trading_pair",
+            "tradingPair",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            R1,
-            R2,
+            TradingPair,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -4419,8 +10900,7 @@ impl<'de> serde::Deserialize<'de> for Reserves {
                         E: serde::de::Error,
                     {
                         match value {
-                            "r1" => Ok(GeneratedField::R1),
-                            "r2" => Ok(GeneratedField::R2),
+                            "tradingPair" | "trading_pair" => Ok(GeneratedField::TradingPair),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -4430,47 +10910,39 @@ impl<'de> serde::Deserialize<'de> for Reserves {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = Reserves;
+            type Value = SpreadRequest;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.core.component.dex.v1.Reserves")
+                formatter.write_str("struct penumbra.core.component.dex.v1.SpreadRequest")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<Reserves, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<SpreadRequest, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut r1__ = None;
-                let mut r2__ = None;
+                let mut trading_pair__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::R1 => {
-                            if r1__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("r1"));
-                            }
-                            r1__ = map_.next_value()?;
-                        }
-                        GeneratedField::R2 => {
-                            if r2__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("r2"));
+                        GeneratedField::TradingPair => {
+                            if trading_pair__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("tradingPair"));
                             }
-                            r2__ = map_.next_value()?;
+                            trading_pair__ = map_.next_value()?;
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(Reserves {
-                    r1: r1__,
-                    r2: r2__,
+                Ok(SpreadRequest {
+                    trading_pair: trading_pair__,
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.core.component.dex.v1.Reserves", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.core.component.dex.v1.SpreadRequest", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for SimulateTradeRequest {
+impl serde::Serialize for SpreadResponse {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -4478,45 +10950,57 @@ impl serde::Serialize for SimulateTradeRequest {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.input.is_some() {
+        if self.best_1_to_2_position.is_some() {
             len += 1;
         }
-        if self.output.is_some() {
+        if self.best_2_to_1_position.is_some() {
             len += 1;
         }
-        if self.routing.is_some() {
+        if self.approx_effective_price_1_to_2 != 0. {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.SimulateTradeRequest", len)?;
-        if let Some(v) = self.input.as_ref() {
-            struct_ser.serialize_field("input", v)?;
+        if self.approx_effective_price_2_to_1 != 0. {
+            len += 1;
         }
-        if let Some(v) = self.output.as_ref() {
-            struct_ser.serialize_field("output", v)?;
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.SpreadResponse", len)?;
+        if let Some(v) = self.best_1_to_2_position.as_ref() {
+            struct_ser.serialize_field("best1To2Position", v)?;
         }
-        if let Some(v) = self.routing.as_ref() {
-            struct_ser.serialize_field("routing", v)?;
+        if let Some(v) = self.best_2_to_1_position.as_ref() {
+            struct_ser.serialize_field("best2To1Position", v)?;
+        }
+        if self.approx_effective_price_1_to_2 != 0. {
+            struct_ser.serialize_field("approxEffectivePrice1To2", &self.approx_effective_price_1_to_2)?;
+        }
+        if self.approx_effective_price_2_to_1 != 0. {
+            struct_ser.serialize_field("approxEffectivePrice2To1", &self.approx_effective_price_2_to_1)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for SimulateTradeRequest {
+impl<'de> serde::Deserialize<'de> for SpreadResponse {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "input",
-            "output",
-            "routing",
+            "best_1_to_2_position",
+            "best1To2Position",
+            "best_2_to_1_position",
+            "best2To1Position",
+            "approx_effective_price_1_to_2",
+            "approxEffectivePrice1To2",
+            "approx_effective_price_2_to_1",
+            "approxEffectivePrice2To1",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            Input,
-            Output,
-            Routing,
+            Best1To2Position,
+            Best2To1Position,
+            ApproxEffectivePrice1To2,
+            ApproxEffectivePrice2To1,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -4539,9 +11023,10 @@ impl<'de> serde::Deserialize<'de> for SimulateTradeRequest {
                         E: serde::de::Error,
                     {
                         match value {
-                            "input" => Ok(GeneratedField::Input),
-                            "output" => Ok(GeneratedField::Output),
-                            "routing" => Ok(GeneratedField::Routing),
+                            "best1To2Position" | "best_1_to_2_position" => Ok(GeneratedField::Best1To2Position),
+                            "best2To1Position" | "best_2_to_1_position" => Ok(GeneratedField::Best2To1Position),
+                            "approxEffectivePrice1To2" | "approx_effective_price_1_to_2" => Ok(GeneratedField::ApproxEffectivePrice1To2),
+                            "approxEffectivePrice2To1" | "approx_effective_price_2_to_1" => Ok(GeneratedField::ApproxEffectivePrice2To1),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -4551,55 +11036,67 @@ impl<'de> serde::Deserialize<'de> for SimulateTradeRequest {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = SimulateTradeRequest;
+            type Value = SpreadResponse;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.core.component.dex.v1.SimulateTradeRequest")
+                formatter.write_str("struct penumbra.core.component.dex.v1.SpreadResponse")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<SimulateTradeRequest, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<SpreadResponse, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut input__ = None;
-                let mut output__ = None;
-                let mut routing__ = None;
+                let mut best_1_to_2_position__ = None;
+                let mut best_2_to_1_position__ = None;
+                let mut approx_effective_price_1_to_2__ = None;
+                let mut approx_effective_price_2_to_1__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::Input => {
-                            if input__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("input"));
+                        GeneratedField::Best1To2Position => {
+                            if best_1_to_2_position__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("best1To2Position"));
                             }
-                            input__ = map_.next_value()?;
+                            best_1_to_2_position__ = map_.next_value()?;
                         }
-                        GeneratedField::Output => {
-                            if output__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("output"));
+                        GeneratedField::Best2To1Position => {
+                            if best_2_to_1_position__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("best2To1Position"));
                             }
-                            output__ = map_.next_value()?;
+                            best_2_to_1_position__ = map_.next_value()?;
                         }
-                        GeneratedField::Routing => {
-                            if routing__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("routing"));
+                        GeneratedField::ApproxEffectivePrice1To2 => {
+                            if approx_effective_price_1_to_2__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("approxEffectivePrice1To2"));
                             }
-                            routing__ = map_.next_value()?;
+                            approx_effective_price_1_to_2__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::ApproxEffectivePrice2To1 => {
+                            if approx_effective_price_2_to_1__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("approxEffectivePrice2To1"));
+                            }
+                            approx_effective_price_2_to_1__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(SimulateTradeRequest {
-                    input: input__,
-                    output: output__,
-                    routing: routing__,
+                Ok(SpreadResponse {
+                    best_1_to_2_position: best_1_to_2_position__,
+                    best_2_to_1_position: best_2_to_1_position__,
+                    approx_effective_price_1_to_2: approx_effective_price_1_to_2__.unwrap_or_default(),
+                    approx_effective_price_2_to_1: approx_effective_price_2_to_1__.unwrap_or_default(),
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.core.component.dex.v1.SimulateTradeRequest", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.core.component.dex.v1.SpreadResponse", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for simulate_trade_request::Routing {
+impl serde::Serialize for Swap {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -4607,39 +11104,37 @@ impl serde::Serialize for simulate_trade_request::Routing {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.setting.is_some() {
+        if self.proof.is_some() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.SimulateTradeRequest.Routing", len)?;
-        if let Some(v) = self.setting.as_ref() {
-            match v {
-                simulate_trade_request::routing::Setting::Default(v) => {
-                    struct_ser.serialize_field("default", v)?;
-                }
-                simulate_trade_request::routing::Setting::SingleHop(v) => {
-                    struct_ser.serialize_field("singleHop", v)?;
-                }
-            }
+        if self.body.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.Swap", len)?;
+        if let Some(v) = self.proof.as_ref() {
+            struct_ser.serialize_field("proof", v)?;
+        }
+        if let Some(v) = self.body.as_ref() {
+            struct_ser.serialize_field("body", v)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for simulate_trade_request::Routing {
+impl<'de> serde::Deserialize<'de> for Swap {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "default",
-            "single_hop",
-            "singleHop",
+            "proof",
+            "body",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            Default,
-            SingleHop,
+            Proof,
+            Body,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -4662,8 +11157,8 @@ impl<'de> serde::Deserialize<'de> for simulate_trade_request::Routing {
                         E: serde::de::Error,
                     {
                         match value {
-                            "default" => Ok(GeneratedField::Default),
-                            "singleHop" | "single_hop" => Ok(GeneratedField::SingleHop),
+                            "proof" => Ok(GeneratedField::Proof),
+                            "body" => Ok(GeneratedField::Body),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -4673,69 +11168,113 @@ impl<'de> serde::Deserialize<'de> for simulate_trade_request::Routing {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = simulate_trade_request::Routing;
+            type Value = Swap;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.core.component.dex.v1.SimulateTradeRequest.Routing")
+                formatter.write_str("struct penumbra.core.component.dex.v1.Swap")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<simulate_trade_request::Routing, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<Swap, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut setting__ = None;
+                let mut proof__ = None;
+                let mut body__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::Default => {
-                            if setting__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("default"));
+                        GeneratedField::Proof => {
+                            if proof__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("proof"));
                             }
-                            setting__ = map_.next_value::<::std::option::Option<_>>()?.map(simulate_trade_request::routing::Setting::Default)
-;
+                            proof__ = map_.next_value()?;
                         }
-                        GeneratedField::SingleHop => {
-                            if setting__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("singleHop"));
+                        GeneratedField::Body => {
+                            if body__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("body"));
                             }
-                            setting__ = map_.next_value::<::std::option::Option<_>>()?.map(simulate_trade_request::routing::Setting::SingleHop)
-;
+                            body__ = map_.next_value()?;
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(simulate_trade_request::Routing {
-                    setting: setting__,
+                Ok(Swap {
+                    proof: proof__,
+                    body: body__,
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.core.component.dex.v1.SimulateTradeRequest.Routing", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.core.component.dex.v1.Swap", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for simulate_trade_request::routing::Default {
+impl serde::Serialize for SwapBody {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
         S: serde::Serializer,
     {
         use serde::ser::SerializeStruct;
-        let len = 0;
-        let struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.SimulateTradeRequest.Routing.Default", len)?;
+        let mut len = 0;
+        if self.trading_pair.is_some() {
+            len += 1;
+        }
+        if self.delta_1_i.is_some() {
+            len += 1;
+        }
+        if self.delta_2_i.is_some() {
+            len += 1;
+        }
+        if self.fee_commitment.is_some() {
+            len += 1;
+        }
+        if self.payload.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.SwapBody", len)?;
+        if let Some(v) = self.trading_pair.as_ref() {
+            struct_ser.serialize_field("tradingPair", v)?;
+        }
+        if let Some(v) = self.delta_1_i.as_ref() {
+            struct_ser.serialize_field("delta1I", v)?;
+        }
+        if let Some(v) = self.delta_2_i.as_ref() {
+            struct_ser.serialize_field("delta2I", v)?;
+        }
+        if let Some(v) = self.fee_commitment.as_ref() {
+            struct_ser.serialize_field("feeCommitment", v)?;
+        }
+        if let Some(v) = self.payload.as_ref() {
+            struct_ser.serialize_field("payload", v)?;
+        }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for simulate_trade_request::routing::Default {
+impl<'de> serde::Deserialize<'de> for SwapBody {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
+            "trading_pair",
+            "tradingPair",
+            "delta_1_i",
+            "delta1I",
+            "delta_2_i",
+            "delta2I",
+            "fee_commitment",
+            "feeCommitment",
+            "payload",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
+            TradingPair,
+            Delta1I,
+            Delta2I,
+            FeeCommitment,
+            Payload,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -4757,7 +11296,14 @@ impl<'de> serde::Deserialize<'de> for simulate_trade_request::routing::Default {
                     where
                         E: serde::de::Error,
                     {
-                            Ok(GeneratedField::__SkipField__)
+                        match value {
+                            "tradingPair" | "trading_pair" => Ok(GeneratedField::TradingPair),
+                            "delta1I" | "delta_1_i" => Ok(GeneratedField::Delta1I),
+                            "delta2I" | "delta_2_i" => Ok(GeneratedField::Delta2I),
+                            "feeCommitment" | "fee_commitment" => Ok(GeneratedField::FeeCommitment),
+                            "payload" => Ok(GeneratedField::Payload),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
                     }
                 }
                 deserializer.deserialize_identifier(GeneratedVisitor)
@@ -4765,49 +11311,119 @@ impl<'de> serde::Deserialize<'de> for simulate_trade_request::routing::Default {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = simulate_trade_request::routing::Default;
+            type Value = SwapBody;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.core.component.dex.v1.SimulateTradeRequest.Routing.Default")
+                formatter.write_str("struct penumbra.core.component.dex.v1.SwapBody")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<simulate_trade_request::routing::Default, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<SwapBody, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                while map_.next_key::<GeneratedField>()?.is_some() {
-                    let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                let mut trading_pair__ = None;
+                let mut delta_1_i__ = None;
+                let mut delta_2_i__ = None;
+                let mut fee_commitment__ = None;
+                let mut payload__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::TradingPair => {
+                            if trading_pair__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("tradingPair"));
+                            }
+                            trading_pair__ = map_.next_value()?;
+                        }
+                        GeneratedField::Delta1I => {
+                            if delta_1_i__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("delta1I"));
+                            }
+                            delta_1_i__ = map_.next_value()?;
+                        }
+                        GeneratedField::Delta2I => {
+                            if delta_2_i__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("delta2I"));
+                            }
+                            delta_2_i__ = map_.next_value()?;
+                        }
+                        GeneratedField::FeeCommitment => {
+                            if fee_commitment__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("feeCommitment"));
+                            }
+                            fee_commitment__ = map_.next_value()?;
+                        }
+                        GeneratedField::Payload => {
+                            if payload__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("payload"));
+                            }
+                            payload__ = map_.next_value()?;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
                 }
-                Ok(simulate_trade_request::routing::Default {
+                Ok(SwapBody {
+                    trading_pair: trading_pair__,
+                    delta_1_i: delta_1_i__,
+                    delta_2_i: delta_2_i__,
+                    fee_commitment: fee_commitment__,
+                    payload: payload__,
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.core.component.dex.v1.SimulateTradeRequest.Routing.Default", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.core.component.dex.v1.SwapBody", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for simulate_trade_request::routing::SingleHop {
+impl serde::Serialize for SwapClaim {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
         S: serde::Serializer,
     {
         use serde::ser::SerializeStruct;
-        let len = 0;
-        let struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.SimulateTradeRequest.Routing.SingleHop", len)?;
+        let mut len = 0;
+        if self.proof.is_some() {
+            len += 1;
+        }
+        if self.body.is_some() {
+            len += 1;
+        }
+        if self.epoch_duration != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.SwapClaim", len)?;
+        if let Some(v) = self.proof.as_ref() {
+            struct_ser.serialize_field("proof", v)?;
+        }
+        if let Some(v) = self.body.as_ref() {
+            struct_ser.serialize_field("body", v)?;
+        }
+        if self.epoch_duration != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("epochDuration", ToString::to_string(&self.epoch_duration).as_str())?;
+        }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for simulate_trade_request::routing::SingleHop {
+impl<'de> serde::Deserialize<'de> for SwapClaim {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
+            "proof",
+            "body",
+            "epoch_duration",
+            "epochDuration",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
+            Proof,
+            Body,
+            EpochDuration,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -4829,7 +11445,12 @@ impl<'de> serde::Deserialize<'de> for simulate_trade_request::routing::SingleHop
                     where
                         E: serde::de::Error,
                     {
-                            Ok(GeneratedField::__SkipField__)
+                        match value {
+                            "proof" => Ok(GeneratedField::Proof),
+                            "body" => Ok(GeneratedField::Body),
+                            "epochDuration" | "epoch_duration" => Ok(GeneratedField::EpochDuration),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
                     }
                 }
                 deserializer.deserialize_identifier(GeneratedVisitor)
@@ -4837,27 +11458,57 @@ impl<'de> serde::Deserialize<'de> for simulate_trade_request::routing::SingleHop
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = simulate_trade_request::routing::SingleHop;
+            type Value = SwapClaim;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.core.component.dex.v1.SimulateTradeRequest.Routing.SingleHop")
+                formatter.write_str("struct penumbra.core.component.dex.v1.SwapClaim")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<simulate_trade_request::routing::SingleHop, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<SwapClaim, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                while map_.next_key::<GeneratedField>()?.is_some() {
-                    let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                let mut proof__ = None;
+                let mut body__ = None;
+                let mut epoch_duration__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Proof => {
+                            if proof__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("proof"));
+                            }
+                            proof__ = map_.next_value()?;
+                        }
+                        GeneratedField::Body => {
+                            if body__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("body"));
+                            }
+                            body__ = map_.next_value()?;
+                        }
+                        GeneratedField::EpochDuration => {
+                            if epoch_duration__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("epochDuration"));
+                            }
+                            epoch_duration__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
                 }
-                Ok(simulate_trade_request::routing::SingleHop {
+                Ok(SwapClaim {
+                    proof: proof__,
+                    body: body__,
+                    epoch_duration: epoch_duration__.unwrap_or_default(),
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.core.component.dex.v1.SimulateTradeRequest.Routing.SingleHop", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.core.component.dex.v1.SwapClaim", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for SimulateTradeResponse {
+impl serde::Serialize for SwapClaimBody {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -4865,37 +11516,64 @@ impl serde::Serialize for SimulateTradeResponse {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.output.is_some() {
+        if self.nullifier.is_some() {
             len += 1;
         }
-        if self.unfilled.is_some() {
+        if self.fee.is_some() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.SimulateTradeResponse", len)?;
-        if let Some(v) = self.output.as_ref() {
-            struct_ser.serialize_field("output", v)?;
+        if self.output_1_commitment.is_some() {
+            len += 1;
         }
-        if let Some(v) = self.unfilled.as_ref() {
-            struct_ser.serialize_field("unfilled", v)?;
+        if self.output_2_commitment.is_some() {
+            len += 1;
+        }
+        if self.output_data.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.SwapClaimBody", len)?;
+        if let Some(v) = self.nullifier.as_ref() {
+            struct_ser.serialize_field("nullifier", v)?;
+        }
+        if let Some(v) = self.fee.as_ref() {
+            struct_ser.serialize_field("fee", v)?;
+        }
+        if let Some(v) = self.output_1_commitment.as_ref() {
+            struct_ser.serialize_field("output1Commitment", v)?;
+        }
+        if let Some(v) = self.output_2_commitment.as_ref() {
+            struct_ser.serialize_field("output2Commitment", v)?;
+        }
+        if let Some(v) = self.output_data.as_ref() {
+            struct_ser.serialize_field("outputData", v)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for SimulateTradeResponse {
+impl<'de> serde::Deserialize<'de> for SwapClaimBody {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "output",
-            "unfilled",
+            "nullifier",
+            "fee",
+            "output_1_commitment",
+            "output1Commitment",
+            "output_2_commitment",
+            "output2Commitment",
+            "output_data",
+            "outputData",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            Output,
-            Unfilled,
+            Nullifier,
+            Fee,
+            Output1Commitment,
+            Output2Commitment,
+            OutputData,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -4918,8 +11596,11 @@ impl<'de> serde::Deserialize<'de> for SimulateTradeResponse {
                         E: serde::de::Error,
                     {
                         match value {
-                            "output" => Ok(GeneratedField::Output),
-                            "unfilled" => Ok(GeneratedField::Unfilled),
+                            "nullifier" => Ok(GeneratedField::Nullifier),
+                            "fee" => Ok(GeneratedField::Fee),
+                            "output1Commitment" | "output_1_commitment" => Ok(GeneratedField::Output1Commitment),
+                            "output2Commitment" | "output_2_commitment" => Ok(GeneratedField::Output2Commitment),
+                            "outputData" | "output_data" => Ok(GeneratedField::OutputData),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -4929,47 +11610,71 @@ impl<'de> serde::Deserialize<'de> for SimulateTradeResponse {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = SimulateTradeResponse;
+            type Value = SwapClaimBody;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.core.component.dex.v1.SimulateTradeResponse")
+                formatter.write_str("struct penumbra.core.component.dex.v1.SwapClaimBody")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<SimulateTradeResponse, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<SwapClaimBody, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut output__ = None;
-                let mut unfilled__ = None;
+                let mut nullifier__ = None;
+                let mut fee__ = None;
+                let mut output_1_commitment__ = None;
+                let mut output_2_commitment__ = None;
+                let mut output_data__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::Output => {
-                            if output__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("output"));
+                        GeneratedField::Nullifier => {
+                            if nullifier__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("nullifier"));
                             }
-                            output__ = map_.next_value()?;
+                            nullifier__ = map_.next_value()?;
                         }
-                        GeneratedField::Unfilled => {
-                            if unfilled__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("unfilled"));
+                        GeneratedField::Fee => {
+                            if fee__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("fee"));
                             }
-                            unfilled__ = map_.next_value()?;
+                            fee__ = map_.next_value()?;
+                        }
+                        GeneratedField::Output1Commitment => {
+                            if output_1_commitment__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("output1Commitment"));
+                            }
+                            output_1_commitment__ = map_.next_value()?;
+                        }
+                        GeneratedField::Output2Commitment => {
+                            if output_2_commitment__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("output2Commitment"));
+                            }
+                            output_2_commitment__ = map_.next_value()?;
+                        }
+                        GeneratedField::OutputData => {
+                            if output_data__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("outputData"));
+                            }
+                            output_data__ = map_.next_value()?;
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(SimulateTradeResponse {
-                    output: output__,
-                    unfilled: unfilled__,
+                Ok(SwapClaimBody {
+                    nullifier: nullifier__,
+                    fee: fee__,
+                    output_1_commitment: output_1_commitment__,
+                    output_2_commitment: output_2_commitment__,
+                    output_data: output_data__,
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.core.component.dex.v1.SimulateTradeResponse", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.core.component.dex.v1.SwapClaimBody", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for SpreadRequest {
+impl serde::Serialize for SwapClaimPlan {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -4977,30 +11682,88 @@ impl serde::Serialize for SpreadRequest {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.trading_pair.is_some() {
+        if self.swap_plaintext.is_some() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.SpreadRequest", len)?;
-        if let Some(v) = self.trading_pair.as_ref() {
-            struct_ser.serialize_field("tradingPair", v)?;
+        if self.position != 0 {
+            len += 1;
+        }
+        if self.output_data.is_some() {
+            len += 1;
+        }
+        if self.epoch_duration != 0 {
+            len += 1;
+        }
+        if !self.proof_blinding_r.is_empty() {
+            len += 1;
+        }
+        if !self.proof_blinding_s.is_empty() {
+            len += 1;
+        }
+        if !self.claim_key.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.SwapClaimPlan", len)?;
+        if let Some(v) = self.swap_plaintext.as_ref() {
+            struct_ser.serialize_field("swapPlaintext", v)?;
+        }
+        if self.position != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("position", ToString::to_string(&self.position).as_str())?;
+        }
+        if let Some(v) = self.output_data.as_ref() {
+            struct_ser.serialize_field("outputData", v)?;
+        }
+        if self.epoch_duration != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("epochDuration", ToString::to_string(&self.epoch_duration).as_str())?;
+        }
+        if !self.proof_blinding_r.is_empty() {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("proofBlindingR", pbjson::private::base64::encode(&self.proof_blinding_r).as_str())?;
+        }
+        if !self.proof_blinding_s.is_empty() {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("proofBlindingS", pbjson::private::base64::encode(&self.proof_blinding_s).as_str())?;
+        }
+        if !self.claim_key.is_empty() {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("claimKey", pbjson::private::base64::encode(&self.claim_key).as_str())?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for SpreadRequest {
+impl<'de> serde::Deserialize<'de> for SwapClaimPlan {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "trading_pair",
-            "tradingPair",
+            "swap_plaintext",
+            "swapPlaintext",
+            "position",
+            "output_data",
+            "outputData",
+            "epoch_duration",
+            "epochDuration",
+            "proof_blinding_r",
+            "proofBlindingR",
+            "proof_blinding_s",
+            "proofBlindingS",
+            "claim_key",
+            "claimKey",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            TradingPair,
+            SwapPlaintext,
+            Position,
+            OutputData,
+            EpochDuration,
+            ProofBlindingR,
+            ProofBlindingS,
+            ClaimKey,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -5023,7 +11786,13 @@ impl<'de> serde::Deserialize<'de> for SpreadRequest {
                         E: serde::de::Error,
                     {
                         match value {
-                            "tradingPair" | "trading_pair" => Ok(GeneratedField::TradingPair),
+                            "swapPlaintext" | "swap_plaintext" => Ok(GeneratedField::SwapPlaintext),
+                            "position" => Ok(GeneratedField::Position),
+                            "outputData" | "output_data" => Ok(GeneratedField::OutputData),
+                            "epochDuration" | "epoch_duration" => Ok(GeneratedField::EpochDuration),
+                            "proofBlindingR" | "proof_blinding_r" => Ok(GeneratedField::ProofBlindingR),
+                            "proofBlindingS" | "proof_blinding_s" => Ok(GeneratedField::ProofBlindingS),
+                            "claimKey" | "claim_key" => Ok(GeneratedField::ClaimKey),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -5033,39 +11802,97 @@ impl<'de> serde::Deserialize<'de> for SpreadRequest {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = SpreadRequest;
+            type Value = SwapClaimPlan;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.core.component.dex.v1.SpreadRequest")
+                formatter.write_str("struct penumbra.core.component.dex.v1.SwapClaimPlan")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<SpreadRequest, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<SwapClaimPlan, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut trading_pair__ = None;
+                let mut swap_plaintext__ = None;
+                let mut position__ = None;
+                let mut output_data__ = None;
+                let mut epoch_duration__ = None;
+                let mut proof_blinding_r__ = None;
+                let mut proof_blinding_s__ = None;
+                let mut claim_key__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::TradingPair => {
-                            if trading_pair__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("tradingPair"));
+                        GeneratedField::SwapPlaintext => {
+                            if swap_plaintext__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("swapPlaintext"));
                             }
-                            trading_pair__ = map_.next_value()?;
+                            swap_plaintext__ = map_.next_value()?;
+                        }
+                        GeneratedField::Position => {
+                            if position__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("position"));
+                            }
+                            position__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::OutputData => {
+                            if output_data__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("outputData"));
+                            }
+                            output_data__ = map_.next_value()?;
+                        }
+                        GeneratedField::EpochDuration => {
+                            if epoch_duration__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("epochDuration"));
+                            }
+                            epoch_duration__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::ProofBlindingR => {
+                            if proof_blinding_r__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("proofBlindingR"));
+                            }
+                            proof_blinding_r__ = 
+                                Some(map_.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::ProofBlindingS => {
+                            if proof_blinding_s__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("proofBlindingS"));
+                            }
+                            proof_blinding_s__ = 
+                                Some(map_.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::ClaimKey => {
+                            if claim_key__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("claimKey"));
+                            }
+                            claim_key__ = 
+                                Some(map_.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
+                            ;
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(SpreadRequest {
-                    trading_pair: trading_pair__,
+                Ok(SwapClaimPlan {
+                    swap_plaintext: swap_plaintext__,
+                    position: position__.unwrap_or_default(),
+                    output_data: output_data__,
+                    epoch_duration: epoch_duration__.unwrap_or_default(),
+                    proof_blinding_r: proof_blinding_r__.unwrap_or_default(),
+                    proof_blinding_s: proof_blinding_s__.unwrap_or_default(),
+                    claim_key: claim_key__.unwrap_or_default(),
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.core.component.dex.v1.SpreadRequest", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.core.component.dex.v1.SwapClaimPlan", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for SpreadResponse {
+impl serde::Serialize for SwapClaimView {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -5073,57 +11900,38 @@ impl serde::Serialize for SpreadResponse {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.best_1_to_2_position.is_some() {
-            len += 1;
-        }
-        if self.best_2_to_1_position.is_some() {
-            len += 1;
-        }
-        if self.approx_effective_price_1_to_2 != 0. {
-            len += 1;
-        }
-        if self.approx_effective_price_2_to_1 != 0. {
+        if self.swap_claim_view.is_some() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.SpreadResponse", len)?;
-        if let Some(v) = self.best_1_to_2_position.as_ref() {
-            struct_ser.serialize_field("best1To2Position", v)?;
-        }
-        if let Some(v) = self.best_2_to_1_position.as_ref() {
-            struct_ser.serialize_field("best2To1Position", v)?;
-        }
-        if self.approx_effective_price_1_to_2 != 0. {
-            struct_ser.serialize_field("approxEffectivePrice1To2", &self.approx_effective_price_1_to_2)?;
-        }
-        if self.approx_effective_price_2_to_1 != 0. {
-            struct_ser.serialize_field("approxEffectivePrice2To1", &self.approx_effective_price_2_to_1)?;
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.SwapClaimView", len)?;
+        if let Some(v) = self.swap_claim_view.as_ref() {
+            match v {
+                swap_claim_view::SwapClaimView::Visible(v) => {
+                    struct_ser.serialize_field("visible", v)?;
+                }
+                swap_claim_view::SwapClaimView::Opaque(v) => {
+                    struct_ser.serialize_field("opaque", v)?;
+                }
+            }
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for SpreadResponse {
+impl<'de> serde::Deserialize<'de> for SwapClaimView {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "best_1_to_2_position",
-            "best1To2Position",
-            "best_2_to_1_position",
-            "best2To1Position",
-            "approx_effective_price_1_to_2",
-            "approxEffectivePrice1To2",
-            "approx_effective_price_2_to_1",
-            "approxEffectivePrice2To1",
+            "visible",
+            "opaque",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            Best1To2Position,
-            Best2To1Position,
-            ApproxEffectivePrice1To2,
-            ApproxEffectivePrice2To1,
+            Visible,
+            Opaque,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -5146,10 +11954,8 @@ impl<'de> serde::Deserialize<'de> for SpreadResponse {
                         E: serde::de::Error,
                     {
                         match value {
-                            "best1To2Position" | "best_1_to_2_position" => Ok(GeneratedField::Best1To2Position),
-                            "best2To1Position" | "best_2_to_1_position" => Ok(GeneratedField::Best2To1Position),
-                            "approxEffectivePrice1To2" | "approx_effective_price_1_to_2" => Ok(GeneratedField::ApproxEffectivePrice1To2),
-                            "approxEffectivePrice2To1" | "approx_effective_price_2_to_1" => Ok(GeneratedField::ApproxEffectivePrice2To1),
+                            "visible" => Ok(GeneratedField::Visible),
+                            "opaque" => Ok(GeneratedField::Opaque),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -5159,67 +11965,47 @@ impl<'de> serde::Deserialize<'de> for SpreadResponse {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = SpreadResponse;
+            type Value = SwapClaimView;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.core.component.dex.v1.SpreadResponse")
+                formatter.write_str("struct penumbra.core.component.dex.v1.SwapClaimView")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<SpreadResponse, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<SwapClaimView, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut best_1_to_2_position__ = None;
-                let mut best_2_to_1_position__ = None;
-                let mut approx_effective_price_1_to_2__ = None;
-                let mut approx_effective_price_2_to_1__ = None;
+                let mut swap_claim_view__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::Best1To2Position => {
-                            if best_1_to_2_position__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("best1To2Position"));
-                            }
-                            best_1_to_2_position__ = map_.next_value()?;
-                        }
-                        GeneratedField::Best2To1Position => {
-                            if best_2_to_1_position__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("best2To1Position"));
-                            }
-                            best_2_to_1_position__ = map_.next_value()?;
-                        }
-                        GeneratedField::ApproxEffectivePrice1To2 => {
-                            if approx_effective_price_1_to_2__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("approxEffectivePrice1To2"));
+                        GeneratedField::Visible => {
+                            if swap_claim_view__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("visible"));
                             }
-                            approx_effective_price_1_to_2__ = 
-                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
-                            ;
+                            swap_claim_view__ = map_.next_value::<::std::option::Option<_>>()?.map(swap_claim_view::SwapClaimView::Visible)
+;
                         }
-                        GeneratedField::ApproxEffectivePrice2To1 => {
-                            if approx_effective_price_2_to_1__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("approxEffectivePrice2To1"));
+                        GeneratedField::Opaque => {
+                            if swap_claim_view__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("opaque"));
                             }
-                            approx_effective_price_2_to_1__ = 
-                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
-                            ;
+                            swap_claim_view__ = map_.next_value::<::std::option::Option<_>>()?.map(swap_claim_view::SwapClaimView::Opaque)
+;
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(SpreadResponse {
-                    best_1_to_2_position: best_1_to_2_position__,
-                    best_2_to_1_position: best_2_to_1_position__,
-                    approx_effective_price_1_to_2: approx_effective_price_1_to_2__.unwrap_or_default(),
-                    approx_effective_price_2_to_1: approx_effective_price_2_to_1__.unwrap_or_default(),
+                Ok(SwapClaimView {
+                    swap_claim_view: swap_claim_view__,
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.core.component.dex.v1.SpreadResponse", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.core.component.dex.v1.SwapClaimView", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for Swap {
+impl serde::Serialize for swap_claim_view::Opaque {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -5227,37 +12013,30 @@ impl serde::Serialize for Swap {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.proof.is_some() {
-            len += 1;
-        }
-        if self.body.is_some() {
+        if self.swap_claim.is_some() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.Swap", len)?;
-        if let Some(v) = self.proof.as_ref() {
-            struct_ser.serialize_field("proof", v)?;
-        }
-        if let Some(v) = self.body.as_ref() {
-            struct_ser.serialize_field("body", v)?;
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.SwapClaimView.Opaque", len)?;
+        if let Some(v) = self.swap_claim.as_ref() {
+            struct_ser.serialize_field("swapClaim", v)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for Swap {
+impl<'de> serde::Deserialize<'de> for swap_claim_view::Opaque {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "proof",
-            "body",
+            "swap_claim",
+            "swapClaim",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            Proof,
-            Body,
+            SwapClaim,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -5280,8 +12059,7 @@ impl<'de> serde::Deserialize<'de> for Swap {
                         E: serde::de::Error,
                     {
                         match value {
-                            "proof" => Ok(GeneratedField::Proof),
-                            "body" => Ok(GeneratedField::Body),
+                            "swapClaim" | "swap_claim" => Ok(GeneratedField::SwapClaim),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -5291,47 +12069,39 @@ impl<'de> serde::Deserialize<'de> for Swap {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = Swap;
+            type Value = swap_claim_view::Opaque;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.core.component.dex.v1.Swap")
+                formatter.write_str("struct penumbra.core.component.dex.v1.SwapClaimView.Opaque")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<Swap, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<swap_claim_view::Opaque, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut proof__ = None;
-                let mut body__ = None;
+                let mut swap_claim__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::Proof => {
-                            if proof__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("proof"));
-                            }
-                            proof__ = map_.next_value()?;
-                        }
-                        GeneratedField::Body => {
-                            if body__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("body"));
+                        GeneratedField::SwapClaim => {
+                            if swap_claim__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("swapClaim"));
                             }
-                            body__ = map_.next_value()?;
+                            swap_claim__ = map_.next_value()?;
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(Swap {
-                    proof: proof__,
-                    body: body__,
+                Ok(swap_claim_view::Opaque {
+                    swap_claim: swap_claim__,
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.core.component.dex.v1.Swap", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.core.component.dex.v1.SwapClaimView.Opaque", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for SwapBody {
+impl serde::Serialize for swap_claim_view::Visible {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -5339,65 +12109,57 @@ impl serde::Serialize for SwapBody {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.trading_pair.is_some() {
-            len += 1;
-        }
-        if self.delta_1_i.is_some() {
+        if self.swap_claim.is_some() {
             len += 1;
         }
-        if self.delta_2_i.is_some() {
+        if self.output_1.is_some() {
             len += 1;
         }
-        if self.fee_commitment.is_some() {
+        if self.output_2.is_some() {
             len += 1;
         }
-        if self.payload.is_some() {
+        if self.swap_tx.is_some() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.SwapBody", len)?;
-        if let Some(v) = self.trading_pair.as_ref() {
-            struct_ser.serialize_field("tradingPair", v)?;
-        }
-        if let Some(v) = self.delta_1_i.as_ref() {
-            struct_ser.serialize_field("delta1I", v)?;
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.SwapClaimView.Visible", len)?;
+        if let Some(v) = self.swap_claim.as_ref() {
+            struct_ser.serialize_field("swapClaim", v)?;
         }
-        if let Some(v) = self.delta_2_i.as_ref() {
-            struct_ser.serialize_field("delta2I", v)?;
+        if let Some(v) = self.output_1.as_ref() {
+            struct_ser.serialize_field("output1", v)?;
         }
-        if let Some(v) = self.fee_commitment.as_ref() {
-            struct_ser.serialize_field("feeCommitment", v)?;
+        if let Some(v) = self.output_2.as_ref() {
+            struct_ser.serialize_field("output2", v)?;
         }
-        if let Some(v) = self.payload.as_ref() {
-            struct_ser.serialize_field("payload", v)?;
+        if let Some(v) = self.swap_tx.as_ref() {
+            struct_ser.serialize_field("swapTx", v)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for SwapBody {
+impl<'de> serde::Deserialize<'de> for swap_claim_view::Visible {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "trading_pair",
-            "tradingPair",
-            "delta_1_i",
-            "delta1I",
-            "delta_2_i",
-            "delta2I",
-            "fee_commitment",
-            "feeCommitment",
-            "payload",
+            "swap_claim",
+            "swapClaim",
+            "output_1",
+            "output1",
+            "output_2",
+            "output2",
+            "swap_tx",
+            "swapTx",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            TradingPair,
-            Delta1I,
-            Delta2I,
-            FeeCommitment,
-            Payload,
+            SwapClaim,
+            Output1,
+            Output2,
+            SwapTx,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -5420,11 +12182,10 @@ impl<'de> serde::Deserialize<'de> for SwapBody {
                         E: serde::de::Error,
                     {
                         match value {
-                            "tradingPair" | "trading_pair" => Ok(GeneratedField::TradingPair),
-                            "delta1I" | "delta_1_i" => Ok(GeneratedField::Delta1I),
-                            "delta2I" | "delta_2_i" => Ok(GeneratedField::Delta2I),
-                            "feeCommitment" | "fee_commitment" => Ok(GeneratedField::FeeCommitment),
-                            "payload" => Ok(GeneratedField::Payload),
+                            "swapClaim" | "swap_claim" => Ok(GeneratedField::SwapClaim),
+                            "output1" | "output_1" => Ok(GeneratedField::Output1),
+                            "output2" | "output_2" => Ok(GeneratedField::Output2),
+                            "swapTx" | "swap_tx" => Ok(GeneratedField::SwapTx),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -5434,71 +12195,63 @@ impl<'de> serde::Deserialize<'de> for SwapBody {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = SwapBody;
+            type Value = swap_claim_view::Visible;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.core.component.dex.v1.SwapBody")
+                formatter.write_str("struct penumbra.core.component.dex.v1.SwapClaimView.Visible")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<SwapBody, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<swap_claim_view::Visible, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut trading_pair__ = None;
-                let mut delta_1_i__ = None;
-                let mut delta_2_i__ = None;
-                let mut fee_commitment__ = None;
-                let mut payload__ = None;
+                let mut swap_claim__ = None;
+                let mut output_1__ = None;
+                let mut output_2__ = None;
+                let mut swap_tx__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::TradingPair => {
-                            if trading_pair__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("tradingPair"));
-                            }
-                            trading_pair__ = map_.next_value()?;
-                        }
-                        GeneratedField::Delta1I => {
-                            if delta_1_i__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("delta1I"));
+                        GeneratedField::SwapClaim => {
+                            if swap_claim__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("swapClaim"));
                             }
-                            delta_1_i__ = map_.next_value()?;
+                            swap_claim__ = map_.next_value()?;
                         }
-                        GeneratedField::Delta2I => {
-                            if delta_2_i__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("delta2I"));
+                        GeneratedField::Output1 => {
+                            if output_1__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("output1"));
                             }
-                            delta_2_i__ = map_.next_value()?;
+                            output_1__ = map_.next_value()?;
                         }
-                        GeneratedField::FeeCommitment => {
-                            if fee_commitment__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("feeCommitment"));
+                        GeneratedField::Output2 => {
+                            if output_2__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("output2"));
                             }
-                            fee_commitment__ = map_.next_value()?;
+                            output_2__ = map_.next_value()?;
                         }
-                        GeneratedField::Payload => {
-                            if payload__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("payload"));
+                        GeneratedField::SwapTx => {
+                            if swap_tx__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("swapTx"));
                             }
-                            payload__ = map_.next_value()?;
+                            swap_tx__ = map_.next_value()?;
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(SwapBody {
-                    trading_pair: trading_pair__,
-                    delta_1_i: delta_1_i__,
-                    delta_2_i: delta_2_i__,
-                    fee_commitment: fee_commitment__,
-                    payload: payload__,
+                Ok(swap_claim_view::Visible {
+                    swap_claim: swap_claim__,
+                    output_1: output_1__,
+                    output_2: output_2__,
+                    swap_tx: swap_tx__,
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.core.component.dex.v1.SwapBody", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.core.component.dex.v1.SwapClaimView.Visible", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for SwapClaim {
+impl serde::Serialize for SwapExecution {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -5506,47 +12259,45 @@ impl serde::Serialize for SwapClaim {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.proof.is_some() {
+        if !self.traces.is_empty() {
             len += 1;
         }
-        if self.body.is_some() {
+        if self.input.is_some() {
             len += 1;
         }
-        if self.epoch_duration != 0 {
+        if self.output.is_some() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.SwapClaim", len)?;
-        if let Some(v) = self.proof.as_ref() {
-            struct_ser.serialize_field("proof", v)?;
-        }
-        if let Some(v) = self.body.as_ref() {
-            struct_ser.serialize_field("body", v)?;
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.SwapExecution", len)?;
+        if !self.traces.is_empty() {
+            struct_ser.serialize_field("traces", &self.traces)?;
         }
-        if self.epoch_duration != 0 {
-            #[allow(clippy::needless_borrow)]
-            struct_ser.serialize_field("epochDuration", ToString::to_string(&self.epoch_duration).as_str())?;
+        if let Some(v) = self.input.as_ref() {
+            struct_ser.serialize_field("input", v)?;
+        }
+        if let Some(v) = self.output.as_ref() {
+            struct_ser.serialize_field("output", v)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for SwapClaim {
+impl<'de> serde::Deserialize<'de> for SwapExecution {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "proof",
-            "body",
-            "epoch_duration",
-            "epochDuration",
+            "traces",
+            "input",
+            "output",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            Proof,
-            Body,
-            EpochDuration,
+            Traces,
+            Input,
+            Output,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -5569,9 +12320,9 @@ impl<'de> serde::Deserialize<'de> for SwapClaim {
                         E: serde::de::Error,
                     {
                         match value {
-                            "proof" => Ok(GeneratedField::Proof),
-                            "body" => Ok(GeneratedField::Body),
-                            "epochDuration" | "epoch_duration" => Ok(GeneratedField::EpochDuration),
+                            "traces" => Ok(GeneratedField::Traces),
+                            "input" => Ok(GeneratedField::Input),
+                            "output" => Ok(GeneratedField::Output),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -5581,57 +12332,55 @@ impl<'de> serde::Deserialize<'de> for SwapClaim {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = SwapClaim;
+            type Value = SwapExecution;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.core.component.dex.v1.SwapClaim")
+                formatter.write_str("struct penumbra.core.component.dex.v1.SwapExecution")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<SwapClaim, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<SwapExecution, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut proof__ = None;
-                let mut body__ = None;
-                let mut epoch_duration__ = None;
+                let mut traces__ = None;
+                let mut input__ = None;
+                let mut output__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::Proof => {
-                            if proof__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("proof"));
+                        GeneratedField::Traces => {
+                            if traces__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("traces"));
                             }
-                            proof__ = map_.next_value()?;
+                            traces__ = Some(map_.next_value()?);
                         }
-                        GeneratedField::Body => {
-                            if body__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("body"));
+                        GeneratedField::Input => {
+                            if input__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("input"));
                             }
-                            body__ = map_.next_value()?;
+                            input__ = map_.next_value()?;
                         }
-                        GeneratedField::EpochDuration => {
-                            if epoch_duration__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("epochDuration"));
+                        GeneratedField::Output => {
+                            if output__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("output"));
                             }
-                            epoch_duration__ = 
-                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
-                            ;
+                            output__ = map_.next_value()?;
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(SwapClaim {
-                    proof: proof__,
-                    body: body__,
-                    epoch_duration: epoch_duration__.unwrap_or_default(),
+                Ok(SwapExecution {
+                    traces: traces__.unwrap_or_default(),
+                    input: input__,
+                    output: output__,
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.core.component.dex.v1.SwapClaim", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.core.component.dex.v1.SwapExecution", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for SwapClaimBody {
+impl serde::Serialize for swap_execution::Trace {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -5639,64 +12388,29 @@ impl serde::Serialize for SwapClaimBody {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.nullifier.is_some() {
-            len += 1;
-        }
-        if self.fee.is_some() {
-            len += 1;
-        }
-        if self.output_1_commitment.is_some() {
-            len += 1;
-        }
-        if self.output_2_commitment.is_some() {
-            len += 1;
-        }
-        if self.output_data.is_some() {
+        if !self.value.is_empty() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.SwapClaimBody", len)?;
-        if let Some(v) = self.nullifier.as_ref() {
-            struct_ser.serialize_field("nullifier", v)?;
-        }
-        if let Some(v) = self.fee.as_ref() {
-            struct_ser.serialize_field("fee", v)?;
-        }
-        if let Some(v) = self.output_1_commitment.as_ref() {
-            struct_ser.serialize_field("output1Commitment", v)?;
-        }
-        if let Some(v) = self.output_2_commitment.as_ref() {
-            struct_ser.serialize_field("output2Commitment", v)?;
-        }
-        if let Some(v) = self.output_data.as_ref() {
-            struct_ser.serialize_field("outputData", v)?;
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.SwapExecution.Trace", len)?;
+        if !self.value.is_empty() {
+            struct_ser.serialize_field("value", &self.value)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for SwapClaimBody {
+impl<'de> serde::Deserialize<'de> for swap_execution::Trace {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "nullifier",
-            "fee",
-            "output_1_commitment",
-            "output1Commitment",
-            "output_2_commitment",
-            "output2Commitment",
-            "output_data",
-            "outputData",
+            "value",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            Nullifier,
-            Fee,
-            Output1Commitment,
-            Output2Commitment,
-            OutputData,
+            Value,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -5719,11 +12433,7 @@ impl<'de> serde::Deserialize<'de> for SwapClaimBody {
                         E: serde::de::Error,
                     {
                         match value {
-                            "nullifier" => Ok(GeneratedField::Nullifier),
-                            "fee" => Ok(GeneratedField::Fee),
-                            "output1Commitment" | "output_1_commitment" => Ok(GeneratedField::Output1Commitment),
-                            "output2Commitment" | "output_2_commitment" => Ok(GeneratedField::Output2Commitment),
-                            "outputData" | "output_data" => Ok(GeneratedField::OutputData),
+                            "value" => Ok(GeneratedField::Value),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -5733,71 +12443,39 @@ impl<'de> serde::Deserialize<'de> for SwapClaimBody {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = SwapClaimBody;
+            type Value = swap_execution::Trace;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.core.component.dex.v1.SwapClaimBody")
+                formatter.write_str("struct penumbra.core.component.dex.v1.SwapExecution.Trace")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<SwapClaimBody, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<swap_execution::Trace, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut nullifier__ = None;
-                let mut fee__ = None;
-                let mut output_1_commitment__ = None;
-                let mut output_2_commitment__ = None;
-                let mut output_data__ = None;
+                let mut value__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::Nullifier => {
-                            if nullifier__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("nullifier"));
-                            }
-                            nullifier__ = map_.next_value()?;
-                        }
-                        GeneratedField::Fee => {
-                            if fee__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("fee"));
-                            }
-                            fee__ = map_.next_value()?;
-                        }
-                        GeneratedField::Output1Commitment => {
-                            if output_1_commitment__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("output1Commitment"));
-                            }
-                            output_1_commitment__ = map_.next_value()?;
-                        }
-                        GeneratedField::Output2Commitment => {
-                            if output_2_commitment__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("output2Commitment"));
-                            }
-                            output_2_commitment__ = map_.next_value()?;
-                        }
-                        GeneratedField::OutputData => {
-                            if output_data__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("outputData"));
+                        GeneratedField::Value => {
+                            if value__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("value"));
                             }
-                            output_data__ = map_.next_value()?;
+                            value__ = Some(map_.next_value()?);
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(SwapClaimBody {
-                    nullifier: nullifier__,
-                    fee: fee__,
-                    output_1_commitment: output_1_commitment__,
-                    output_2_commitment: output_2_commitment__,
-                    output_data: output_data__,
+                Ok(swap_execution::Trace {
+                    value: value__.unwrap_or_default(),
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.core.component.dex.v1.SwapClaimBody", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.core.component.dex.v1.SwapExecution.Trace", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for SwapClaimPlan {
+impl serde::Serialize for SwapExecutionRequest {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -5805,78 +12483,39 @@ impl serde::Serialize for SwapClaimPlan {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.swap_plaintext.is_some() {
-            len += 1;
-        }
-        if self.position != 0 {
-            len += 1;
-        }
-        if self.output_data.is_some() {
-            len += 1;
-        }
-        if self.epoch_duration != 0 {
-            len += 1;
-        }
-        if !self.proof_blinding_r.is_empty() {
+        if self.height != 0 {
             len += 1;
         }
-        if !self.proof_blinding_s.is_empty() {
+        if self.trading_pair.is_some() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.SwapClaimPlan", len)?;
-        if let Some(v) = self.swap_plaintext.as_ref() {
-            struct_ser.serialize_field("swapPlaintext", v)?;
-        }
-        if self.position != 0 {
-            #[allow(clippy::needless_borrow)]
-            struct_ser.serialize_field("position", ToString::to_string(&self.position).as_str())?;
-        }
-        if let Some(v) = self.output_data.as_ref() {
-            struct_ser.serialize_field("outputData", v)?;
-        }
-        if self.epoch_duration != 0 {
-            #[allow(clippy::needless_borrow)]
-            struct_ser.serialize_field("epochDuration", ToString::to_string(&self.epoch_duration).as_str())?;
-        }
-        if !self.proof_blinding_r.is_empty() {
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.SwapExecutionRequest", len)?;
+        if self.height != 0 {
             #[allow(clippy::needless_borrow)]
-            struct_ser.serialize_field("proofBlindingR", pbjson::private::base64::encode(&self.proof_blinding_r).as_str())?;
+            struct_ser.serialize_field("height", ToString::to_string(&self.height).as_str())?;
         }
-        if !self.proof_blinding_s.is_empty() {
-            #[allow(clippy::needless_borrow)]
-            struct_ser.serialize_field("proofBlindingS", pbjson::private::base64::encode(&self.proof_blinding_s).as_str())?;
+        if let Some(v) = self.trading_pair.as_ref() {
+            struct_ser.serialize_field("tradingPair", v)?;
         }
         struct_ser.end()
-    }
-}
-impl<'de> serde::Deserialize<'de> for SwapClaimPlan {
-    #[allow(deprecated)]
-    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
-    where
-        D: serde::Deserializer<'de>,
-    {
-        const FIELDS: &[&str] = &[
-            "swap_plaintext",
-            "swapPlaintext",
-            "position",
-            "output_data",
-            "outputData",
-            "epoch_duration",
-            "epochDuration",
-            "proof_blinding_r",
-            "proofBlindingR",
-            "proof_blinding_s",
-            "proofBlindingS",
+    }
+}
+impl<'de> serde::Deserialize<'de> for SwapExecutionRequest {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "height",
+            "trading_pair",
+            "tradingPair",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            SwapPlaintext,
-            Position,
-            OutputData,
-            EpochDuration,
-            ProofBlindingR,
-            ProofBlindingS,
+            Height,
+            TradingPair,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -5899,12 +12538,8 @@ impl<'de> serde::Deserialize<'de> for SwapClaimPlan {
                         E: serde::de::Error,
                     {
                         match value {
-                            "swapPlaintext" | "swap_plaintext" => Ok(GeneratedField::SwapPlaintext),
-                            "position" => Ok(GeneratedField::Position),
-                            "outputData" | "output_data" => Ok(GeneratedField::OutputData),
-                            "epochDuration" | "epoch_duration" => Ok(GeneratedField::EpochDuration),
-                            "proofBlindingR" | "proof_blinding_r" => Ok(GeneratedField::ProofBlindingR),
-                            "proofBlindingS" | "proof_blinding_s" => Ok(GeneratedField::ProofBlindingS),
+                            "height" => Ok(GeneratedField::Height),
+                            "tradingPair" | "trading_pair" => Ok(GeneratedField::TradingPair),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -5914,87 +12549,49 @@ impl<'de> serde::Deserialize<'de> for SwapClaimPlan {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = SwapClaimPlan;
+            type Value = SwapExecutionRequest;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.core.component.dex.v1.SwapClaimPlan")
+                formatter.write_str("struct penumbra.core.component.dex.v1.SwapExecutionRequest")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<SwapClaimPlan, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<SwapExecutionRequest, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut swap_plaintext__ = None;
-                let mut position__ = None;
-                let mut output_data__ = None;
-                let mut epoch_duration__ = None;
-                let mut proof_blinding_r__ = None;
-                let mut proof_blinding_s__ = None;
+                let mut height__ = None;
+                let mut trading_pair__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::SwapPlaintext => {
-                            if swap_plaintext__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("swapPlaintext"));
-                            }
-                            swap_plaintext__ = map_.next_value()?;
-                        }
-                        GeneratedField::Position => {
-                            if position__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("position"));
-                            }
-                            position__ = 
-                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
-                            ;
-                        }
-                        GeneratedField::OutputData => {
-                            if output_data__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("outputData"));
-                            }
-                            output_data__ = map_.next_value()?;
-                        }
-                        GeneratedField::EpochDuration => {
-                            if epoch_duration__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("epochDuration"));
+                        GeneratedField::Height => {
+                            if height__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("height"));
                             }
-                            epoch_duration__ = 
+                            height__ = 
                                 Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
                             ;
                         }
-                        GeneratedField::ProofBlindingR => {
-                            if proof_blinding_r__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("proofBlindingR"));
-                            }
-                            proof_blinding_r__ = 
-                                Some(map_.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
-                            ;
-                        }
-                        GeneratedField::ProofBlindingS => {
-                            if proof_blinding_s__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("proofBlindingS"));
+                        GeneratedField::TradingPair => {
+                            if trading_pair__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("tradingPair"));
                             }
-                            proof_blinding_s__ = 
-                                Some(map_.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
-                            ;
+                            trading_pair__ = map_.next_value()?;
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(SwapClaimPlan {
-                    swap_plaintext: swap_plaintext__,
-                    position: position__.unwrap_or_default(),
-                    output_data: output_data__,
-                    epoch_duration: epoch_duration__.unwrap_or_default(),
-                    proof_blinding_r: proof_blinding_r__.unwrap_or_default(),
-                    proof_blinding_s: proof_blinding_s__.unwrap_or_default(),
+                Ok(SwapExecutionRequest {
+                    height: height__.unwrap_or_default(),
+                    trading_pair: trading_pair__,
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.core.component.dex.v1.SwapClaimPlan", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.core.component.dex.v1.SwapExecutionRequest", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for SwapClaimView {
+impl serde::Serialize for SwapExecutionResponse {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -6002,38 +12599,30 @@ impl serde::Serialize for SwapClaimView {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.swap_claim_view.is_some() {
+        if self.swap_execution.is_some() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.SwapClaimView", len)?;
-        if let Some(v) = self.swap_claim_view.as_ref() {
-            match v {
-                swap_claim_view::SwapClaimView::Visible(v) => {
-                    struct_ser.serialize_field("visible", v)?;
-                }
-                swap_claim_view::SwapClaimView::Opaque(v) => {
-                    struct_ser.serialize_field("opaque", v)?;
-                }
-            }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.SwapExecutionResponse", len)?;
+        if let Some(v) = self.swap_execution.as_ref() {
+            struct_ser.serialize_field("swapExecution", v)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for SwapClaimView {
+impl<'de> serde::Deserialize<'de> for SwapExecutionResponse {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "visible",
-            "opaque",
+            "swap_execution",
+            "swapExecution",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            Visible,
-            Opaque,
+            SwapExecution,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -6056,8 +12645,7 @@ impl<'de> serde::Deserialize<'de> for SwapClaimView {
                         E: serde::de::Error,
                     {
                         match value {
-                            "visible" => Ok(GeneratedField::Visible),
-                            "opaque" => Ok(GeneratedField::Opaque),
+                            "swapExecution" | "swap_execution" => Ok(GeneratedField::SwapExecution),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -6067,47 +12655,39 @@ impl<'de> serde::Deserialize<'de> for SwapClaimView {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = SwapClaimView;
+            type Value = SwapExecutionResponse;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.core.component.dex.v1.SwapClaimView")
+                formatter.write_str("struct penumbra.core.component.dex.v1.SwapExecutionResponse")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<SwapClaimView, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<SwapExecutionResponse, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut swap_claim_view__ = None;
+                let mut swap_execution__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::Visible => {
-                            if swap_claim_view__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("visible"));
-                            }
-                            swap_claim_view__ = map_.next_value::<::std::option::Option<_>>()?.map(swap_claim_view::SwapClaimView::Visible)
-;
-                        }
-                        GeneratedField::Opaque => {
-                            if swap_claim_view__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("opaque"));
+                        GeneratedField::SwapExecution => {
+                            if swap_execution__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("swapExecution"));
                             }
-                            swap_claim_view__ = map_.next_value::<::std::option::Option<_>>()?.map(swap_claim_view::SwapClaimView::Opaque)
-;
+                            swap_execution__ = map_.next_value()?;
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(SwapClaimView {
-                    swap_claim_view: swap_claim_view__,
+                Ok(SwapExecutionResponse {
+                    swap_execution: swap_execution__,
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.core.component.dex.v1.SwapClaimView", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.core.component.dex.v1.SwapExecutionResponse", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for swap_claim_view::Opaque {
+impl serde::Serialize for SwapExecutionTrace {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -6115,30 +12695,29 @@ impl serde::Serialize for swap_claim_view::Opaque {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.swap_claim.is_some() {
+        if !self.fills.is_empty() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.SwapClaimView.Opaque", len)?;
-        if let Some(v) = self.swap_claim.as_ref() {
-            struct_ser.serialize_field("swapClaim", v)?;
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.SwapExecutionTrace", len)?;
+        if !self.fills.is_empty() {
+            struct_ser.serialize_field("fills", &self.fills)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for swap_claim_view::Opaque {
+impl<'de> serde::Deserialize<'de> for SwapExecutionTrace {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "swap_claim",
-            "swapClaim",
+            "fills",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            SwapClaim,
+            Fills,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -6161,7 +12740,7 @@ impl<'de> serde::Deserialize<'de> for swap_claim_view::Opaque {
                         E: serde::de::Error,
                     {
                         match value {
-                            "swapClaim" | "swap_claim" => Ok(GeneratedField::SwapClaim),
+                            "fills" => Ok(GeneratedField::Fills),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -6171,39 +12750,39 @@ impl<'de> serde::Deserialize<'de> for swap_claim_view::Opaque {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = swap_claim_view::Opaque;
+            type Value = SwapExecutionTrace;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.core.component.dex.v1.SwapClaimView.Opaque")
+                formatter.write_str("struct penumbra.core.component.dex.v1.SwapExecutionTrace")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<swap_claim_view::Opaque, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<SwapExecutionTrace, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut swap_claim__ = None;
+                let mut fills__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::SwapClaim => {
-                            if swap_claim__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("swapClaim"));
+                        GeneratedField::Fills => {
+                            if fills__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("fills"));
                             }
-                            swap_claim__ = map_.next_value()?;
+                            fills__ = Some(map_.next_value()?);
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(swap_claim_view::Opaque {
-                    swap_claim: swap_claim__,
+                Ok(SwapExecutionTrace {
+                    fills: fills__.unwrap_or_default(),
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.core.component.dex.v1.SwapClaimView.Opaque", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.core.component.dex.v1.SwapExecutionTrace", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for swap_claim_view::Visible {
+impl serde::Serialize for SwapExecutionTraceRequest {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -6211,57 +12790,39 @@ impl serde::Serialize for swap_claim_view::Visible {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.swap_claim.is_some() {
-            len += 1;
-        }
-        if self.output_1.is_some() {
-            len += 1;
-        }
-        if self.output_2.is_some() {
+        if self.height != 0 {
             len += 1;
         }
-        if self.swap_tx.is_some() {
+        if self.trading_pair.is_some() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.SwapClaimView.Visible", len)?;
-        if let Some(v) = self.swap_claim.as_ref() {
-            struct_ser.serialize_field("swapClaim", v)?;
-        }
-        if let Some(v) = self.output_1.as_ref() {
-            struct_ser.serialize_field("output1", v)?;
-        }
-        if let Some(v) = self.output_2.as_ref() {
-            struct_ser.serialize_field("output2", v)?;
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.SwapExecutionTraceRequest", len)?;
+        if self.height != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("height", ToString::to_string(&self.height).as_str())?;
         }
-        if let Some(v) = self.swap_tx.as_ref() {
-            struct_ser.serialize_field("swapTx", v)?;
+        if let Some(v) = self.trading_pair.as_ref() {
+            struct_ser.serialize_field("tradingPair", v)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for swap_claim_view::Visible {
+impl<'de> serde::Deserialize<'de> for SwapExecutionTraceRequest {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "swap_claim",
-            "swapClaim",
-            "output_1",
-            "output1",
-            "output_2",
-            "output2",
-            "swap_tx",
-            "swapTx",
+            "height",
+            "trading_pair",
+            "tradingPair",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            SwapClaim,
-            Output1,
-            Output2,
-            SwapTx,
+            Height,
+            TradingPair,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -6284,10 +12845,8 @@ impl<'de> serde::Deserialize<'de> for swap_claim_view::Visible {
                         E: serde::de::Error,
                     {
                         match value {
-                            "swapClaim" | "swap_claim" => Ok(GeneratedField::SwapClaim),
-                            "output1" | "output_1" => Ok(GeneratedField::Output1),
-                            "output2" | "output_2" => Ok(GeneratedField::Output2),
-                            "swapTx" | "swap_tx" => Ok(GeneratedField::SwapTx),
+                            "height" => Ok(GeneratedField::Height),
+                            "tradingPair" | "trading_pair" => Ok(GeneratedField::TradingPair),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -6297,63 +12856,49 @@ impl<'de> serde::Deserialize<'de> for swap_claim_view::Visible {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = swap_claim_view::Visible;
+            type Value = SwapExecutionTraceRequest;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.core.component.dex.v1.SwapClaimView.Visible")
+                formatter.write_str("struct penumbra.core.component.dex.v1.SwapExecutionTraceRequest")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<swap_claim_view::Visible, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<SwapExecutionTraceRequest, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut swap_claim__ = None;
-                let mut output_1__ = None;
-                let mut output_2__ = None;
-                let mut swap_tx__ = None;
-                while let Some(k) = map_.next_key()? {
-                    match k {
-                        GeneratedField::SwapClaim => {
-                            if swap_claim__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("swapClaim"));
-                            }
-                            swap_claim__ = map_.next_value()?;
-                        }
-                        GeneratedField::Output1 => {
-                            if output_1__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("output1"));
-                            }
-                            output_1__ = map_.next_value()?;
-                        }
-                        GeneratedField::Output2 => {
-                            if output_2__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("output2"));
+                let mut height__ = None;
+                let mut trading_pair__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Height => {
+                            if height__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("height"));
                             }
-                            output_2__ = map_.next_value()?;
+                            height__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
                         }
-                        GeneratedField::SwapTx => {
-                            if swap_tx__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("swapTx"));
+                        GeneratedField::TradingPair => {
+                            if trading_pair__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("tradingPair"));
                             }
-                            swap_tx__ = map_.next_value()?;
+                            trading_pair__ = map_.next_value()?;
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(swap_claim_view::Visible {
-                    swap_claim: swap_claim__,
-                    output_1: output_1__,
-                    output_2: output_2__,
-                    swap_tx: swap_tx__,
+                Ok(SwapExecutionTraceRequest {
+                    height: height__.unwrap_or_default(),
+                    trading_pair: trading_pair__,
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.core.component.dex.v1.SwapClaimView.Visible", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.core.component.dex.v1.SwapExecutionTraceRequest", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for SwapExecution {
+impl serde::Serialize for SwapExecutionTraceResponse {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -6361,45 +12906,29 @@ impl serde::Serialize for SwapExecution {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if !self.traces.is_empty() {
-            len += 1;
-        }
-        if self.input.is_some() {
-            len += 1;
-        }
-        if self.output.is_some() {
+        if self.fill.is_some() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.SwapExecution", len)?;
-        if !self.traces.is_empty() {
-            struct_ser.serialize_field("traces", &self.traces)?;
-        }
-        if let Some(v) = self.input.as_ref() {
-            struct_ser.serialize_field("input", v)?;
-        }
-        if let Some(v) = self.output.as_ref() {
-            struct_ser.serialize_field("output", v)?;
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.SwapExecutionTraceResponse", len)?;
+        if let Some(v) = self.fill.as_ref() {
+            struct_ser.serialize_field("fill", v)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for SwapExecution {
+impl<'de> serde::Deserialize<'de> for SwapExecutionTraceResponse {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "traces",
-            "input",
-            "output",
+            "fill",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            Traces,
-            Input,
-            Output,
+            Fill,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -6422,9 +12951,7 @@ impl<'de> serde::Deserialize<'de> for SwapExecution {
                         E: serde::de::Error,
                     {
                         match value {
-                            "traces" => Ok(GeneratedField::Traces),
-                            "input" => Ok(GeneratedField::Input),
-                            "output" => Ok(GeneratedField::Output),
+                            "fill" => Ok(GeneratedField::Fill),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -6434,55 +12961,39 @@ impl<'de> serde::Deserialize<'de> for SwapExecution {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = SwapExecution;
+            type Value = SwapExecutionTraceResponse;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.core.component.dex.v1.SwapExecution")
+                formatter.write_str("struct penumbra.core.component.dex.v1.SwapExecutionTraceResponse")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<SwapExecution, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<SwapExecutionTraceResponse, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut traces__ = None;
-                let mut input__ = None;
-                let mut output__ = None;
+                let mut fill__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::Traces => {
-                            if traces__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("traces"));
-                            }
-                            traces__ = Some(map_.next_value()?);
-                        }
-                        GeneratedField::Input => {
-                            if input__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("input"));
-                            }
-                            input__ = map_.next_value()?;
-                        }
-                        GeneratedField::Output => {
-                            if output__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("output"));
+                        GeneratedField::Fill => {
+                            if fill__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("fill"));
                             }
-                            output__ = map_.next_value()?;
+                            fill__ = map_.next_value()?;
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(SwapExecution {
-                    traces: traces__.unwrap_or_default(),
-                    input: input__,
-                    output: output__,
+                Ok(SwapExecutionTraceResponse {
+                    fill: fill__,
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.core.component.dex.v1.SwapExecution", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.core.component.dex.v1.SwapExecutionTraceResponse", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for swap_execution::Trace {
+impl serde::Serialize for SwapExecutionsRequest {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -6490,29 +13001,50 @@ impl serde::Serialize for swap_execution::Trace {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if !self.value.is_empty() {
+        if self.start_height != 0 {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.SwapExecution.Trace", len)?;
-        if !self.value.is_empty() {
-            struct_ser.serialize_field("value", &self.value)?;
+        if self.end_height != 0 {
+            len += 1;
+        }
+        if self.trading_pair.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.SwapExecutionsRequest", len)?;
+        if self.start_height != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("startHeight", ToString::to_string(&self.start_height).as_str())?;
+        }
+        if self.end_height != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("endHeight", ToString::to_string(&self.end_height).as_str())?;
+        }
+        if let Some(v) = self.trading_pair.as_ref() {
+            struct_ser.serialize_field("tradingPair", v)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for swap_execution::Trace {
+impl<'de> serde::Deserialize<'de> for SwapExecutionsRequest {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "value",
+            "start_height",
+            "startHeight",
+            "end_height",
+            "endHeight",
+            "trading_pair",
+            "tradingPair",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            Value,
+            StartHeight,
+            EndHeight,
+            TradingPair,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -6535,7 +13067,9 @@ impl<'de> serde::Deserialize<'de> for swap_execution::Trace {
                         E: serde::de::Error,
                     {
                         match value {
-                            "value" => Ok(GeneratedField::Value),
+                            "startHeight" | "start_height" => Ok(GeneratedField::StartHeight),
+                            "endHeight" | "end_height" => Ok(GeneratedField::EndHeight),
+                            "tradingPair" | "trading_pair" => Ok(GeneratedField::TradingPair),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -6545,39 +13079,59 @@ impl<'de> serde::Deserialize<'de> for swap_execution::Trace {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = swap_execution::Trace;
+            type Value = SwapExecutionsRequest;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.core.component.dex.v1.SwapExecution.Trace")
+                formatter.write_str("struct penumbra.core.component.dex.v1.SwapExecutionsRequest")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<swap_execution::Trace, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<SwapExecutionsRequest, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut value__ = None;
+                let mut start_height__ = None;
+                let mut end_height__ = None;
+                let mut trading_pair__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::Value => {
-                            if value__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("value"));
+                        GeneratedField::StartHeight => {
+                            if start_height__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("startHeight"));
                             }
-                            value__ = Some(map_.next_value()?);
+                            start_height__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::EndHeight => {
+                            if end_height__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("endHeight"));
+                            }
+                            end_height__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::TradingPair => {
+                            if trading_pair__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("tradingPair"));
+                            }
+                            trading_pair__ = map_.next_value()?;
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(swap_execution::Trace {
-                    value: value__.unwrap_or_default(),
+                Ok(SwapExecutionsRequest {
+                    start_height: start_height__.unwrap_or_default(),
+                    end_height: end_height__.unwrap_or_default(),
+                    trading_pair: trading_pair__,
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.core.component.dex.v1.SwapExecution.Trace", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.core.component.dex.v1.SwapExecutionsRequest", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for SwapExecutionRequest {
+impl serde::Serialize for SwapExecutionsResponse {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -6585,13 +13139,19 @@ impl serde::Serialize for SwapExecutionRequest {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
+        if self.swap_execution.is_some() {
+            len += 1;
+        }
         if self.height != 0 {
             len += 1;
         }
         if self.trading_pair.is_some() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.SwapExecutionRequest", len)?;
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.SwapExecutionsResponse", len)?;
+        if let Some(v) = self.swap_execution.as_ref() {
+            struct_ser.serialize_field("swapExecution", v)?;
+        }
         if self.height != 0 {
             #[allow(clippy::needless_borrow)]
             struct_ser.serialize_field("height", ToString::to_string(&self.height).as_str())?;
@@ -6602,13 +13162,15 @@ impl serde::Serialize for SwapExecutionRequest {
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for SwapExecutionRequest {
+impl<'de> serde::Deserialize<'de> for SwapExecutionsResponse {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
+            "swap_execution",
+            "swapExecution",
             "height",
             "trading_pair",
             "tradingPair",
@@ -6616,6 +13178,7 @@ impl<'de> serde::Deserialize<'de> for SwapExecutionRequest {
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
+            SwapExecution,
             Height,
             TradingPair,
             __SkipField__,
@@ -6640,6 +13203,7 @@ impl<'de> serde::Deserialize<'de> for SwapExecutionRequest {
                         E: serde::de::Error,
                     {
                         match value {
+                            "swapExecution" | "swap_execution" => Ok(GeneratedField::SwapExecution),
                             "height" => Ok(GeneratedField::Height),
                             "tradingPair" | "trading_pair" => Ok(GeneratedField::TradingPair),
                             _ => Ok(GeneratedField::__SkipField__),
@@ -6651,20 +13215,27 @@ impl<'de> serde::Deserialize<'de> for SwapExecutionRequest {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = SwapExecutionRequest;
+            type Value = SwapExecutionsResponse;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.core.component.dex.v1.SwapExecutionRequest")
+                formatter.write_str("struct penumbra.core.component.dex.v1.SwapExecutionsResponse")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<SwapExecutionRequest, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<SwapExecutionsResponse, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
+                let mut swap_execution__ = None;
                 let mut height__ = None;
                 let mut trading_pair__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
+                        GeneratedField::SwapExecution => {
+                            if swap_execution__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("swapExecution"));
+                            }
+                            swap_execution__ = map_.next_value()?;
+                        }
                         GeneratedField::Height => {
                             if height__.is_some() {
                                 return Err(serde::de::Error::duplicate_field("height"));
@@ -6684,16 +13255,17 @@ impl<'de> serde::Deserialize<'de> for SwapExecutionRequest {
                         }
                     }
                 }
-                Ok(SwapExecutionRequest {
+                Ok(SwapExecutionsResponse {
+                    swap_execution: swap_execution__,
                     height: height__.unwrap_or_default(),
                     trading_pair: trading_pair__,
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.core.component.dex.v1.SwapExecutionRequest", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.core.component.dex.v1.SwapExecutionsResponse", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for SwapExecutionResponse {
+impl serde::Serialize for SwapPayload {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -6701,30 +13273,39 @@ impl serde::Serialize for SwapExecutionResponse {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.swap_execution.is_some() {
+        if self.commitment.is_some() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.SwapExecutionResponse", len)?;
-        if let Some(v) = self.swap_execution.as_ref() {
-            struct_ser.serialize_field("swapExecution", v)?;
+        if !self.encrypted_swap.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.SwapPayload", len)?;
+        if let Some(v) = self.commitment.as_ref() {
+            struct_ser.serialize_field("commitment", v)?;
+        }
+        if !self.encrypted_swap.is_empty() {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("encryptedSwap", pbjson::private::base64::encode(&self.encrypted_swap).as_str())?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for SwapExecutionResponse {
+impl<'de> serde::Deserialize<'de> for SwapPayload {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "swap_execution",
-            "swapExecution",
+            "commitment",
+            "encrypted_swap",
+            "encryptedSwap",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            SwapExecution,
+            Commitment,
+            EncryptedSwap,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -6747,7 +13328,8 @@ impl<'de> serde::Deserialize<'de> for SwapExecutionResponse {
                         E: serde::de::Error,
                     {
                         match value {
-                            "swapExecution" | "swap_execution" => Ok(GeneratedField::SwapExecution),
+                            "commitment" => Ok(GeneratedField::Commitment),
+                            "encryptedSwap" | "encrypted_swap" => Ok(GeneratedField::EncryptedSwap),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -6757,39 +13339,49 @@ impl<'de> serde::Deserialize<'de> for SwapExecutionResponse {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = SwapExecutionResponse;
+            type Value = SwapPayload;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.core.component.dex.v1.SwapExecutionResponse")
+                formatter.write_str("struct penumbra.core.component.dex.v1.SwapPayload")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<SwapExecutionResponse, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<SwapPayload, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut swap_execution__ = None;
+                let mut commitment__ = None;
+                let mut encrypted_swap__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::SwapExecution => {
-                            if swap_execution__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("swapExecution"));
+                        GeneratedField::Commitment => {
+                            if commitment__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("commitment"));
                             }
-                            swap_execution__ = map_.next_value()?;
+                            commitment__ = map_.next_value()?;
+                        }
+                        GeneratedField::EncryptedSwap => {
+                            if encrypted_swap__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("encryptedSwap"));
+                            }
+                            encrypted_swap__ = 
+                                Some(map_.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
+                            ;
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(SwapExecutionResponse {
-                    swap_execution: swap_execution__,
+                Ok(SwapPayload {
+                    commitment: commitment__,
+                    encrypted_swap: encrypted_swap__.unwrap_or_default(),
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.core.component.dex.v1.SwapExecutionResponse", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.core.component.dex.v1.SwapPayload", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for SwapExecutionsRequest {
+impl serde::Serialize for SwapPlaintext {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -6797,50 +13389,85 @@ impl serde::Serialize for SwapExecutionsRequest {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.start_height != 0 {
+        if self.trading_pair.is_some() {
             len += 1;
         }
-        if self.end_height != 0 {
+        if self.delta_1_i.is_some() {
             len += 1;
         }
-        if self.trading_pair.is_some() {
+        if self.delta_2_i.is_some() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.SwapExecutionsRequest", len)?;
-        if self.start_height != 0 {
-            #[allow(clippy::needless_borrow)]
-            struct_ser.serialize_field("startHeight", ToString::to_string(&self.start_height).as_str())?;
+        if self.claim_fee.is_some() {
+            len += 1;
         }
-        if self.end_height != 0 {
-            #[allow(clippy::needless_borrow)]
-            struct_ser.serialize_field("endHeight", ToString::to_string(&self.end_height).as_str())?;
+        if self.claim_address.is_some() {
+            len += 1;
+        }
+        if !self.rseed.is_empty() {
+            len += 1;
+        }
+        if !self.claim_key_commitment.is_empty() {
+            len += 1;
         }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.SwapPlaintext", len)?;
         if let Some(v) = self.trading_pair.as_ref() {
             struct_ser.serialize_field("tradingPair", v)?;
         }
+        if let Some(v) = self.delta_1_i.as_ref() {
+            struct_ser.serialize_field("delta1I", v)?;
+        }
+        if let Some(v) = self.delta_2_i.as_ref() {
+            struct_ser.serialize_field("delta2I", v)?;
+        }
+        if let Some(v) = self.claim_fee.as_ref() {
+            struct_ser.serialize_field("claimFee", v)?;
+        }
+        if let Some(v) = self.claim_address.as_ref() {
+            struct_ser.serialize_field("claimAddress", v)?;
+        }
+        if !self.rseed.is_empty() {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("rseed", pbjson::private::base64::encode(&self.rseed).as_str())?;
+        }
+        if !self.claim_key_commitment.is_empty() {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("claimKeyCommitment", pbjson::private::base64::encode(&self.claim_key_commitment).as_str())?;
+        }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for SwapExecutionsRequest {
+impl<'de> serde::Deserialize<'de> for SwapPlaintext {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "start_height",
-            "startHeight",
-            "end_height",
-            "endHeight",
             "trading_pair",
             "tradingPair",
+            "delta_1_i",
+            "delta1I",
+            "delta_2_i",
+            "delta2I",
+            "claim_fee",
+            "claimFee",
+            "claim_address",
+            "claimAddress",
+            "rseed",
+            "claim_key_commitment",
+            "claimKeyCommitment",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            StartHeight,
-            EndHeight,
             TradingPair,
+            Delta1I,
+            Delta2I,
+            ClaimFee,
+            ClaimAddress,
+            Rseed,
+            ClaimKeyCommitment,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -6863,9 +13490,13 @@ impl<'de> serde::Deserialize<'de> for SwapExecutionsRequest {
                         E: serde::de::Error,
                     {
                         match value {
-                            "startHeight" | "start_height" => Ok(GeneratedField::StartHeight),
-                            "endHeight" | "end_height" => Ok(GeneratedField::EndHeight),
                             "tradingPair" | "trading_pair" => Ok(GeneratedField::TradingPair),
+                            "delta1I" | "delta_1_i" => Ok(GeneratedField::Delta1I),
+                            "delta2I" | "delta_2_i" => Ok(GeneratedField::Delta2I),
+                            "claimFee" | "claim_fee" => Ok(GeneratedField::ClaimFee),
+                            "claimAddress" | "claim_address" => Ok(GeneratedField::ClaimAddress),
+                            "rseed" => Ok(GeneratedField::Rseed),
+                            "claimKeyCommitment" | "claim_key_commitment" => Ok(GeneratedField::ClaimKeyCommitment),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -6875,59 +13506,91 @@ impl<'de> serde::Deserialize<'de> for SwapExecutionsRequest {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = SwapExecutionsRequest;
+            type Value = SwapPlaintext;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.core.component.dex.v1.SwapExecutionsRequest")
+                formatter.write_str("struct penumbra.core.component.dex.v1.SwapPlaintext")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<SwapExecutionsRequest, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<SwapPlaintext, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut start_height__ = None;
-                let mut end_height__ = None;
                 let mut trading_pair__ = None;
+                let mut delta_1_i__ = None;
+                let mut delta_2_i__ = None;
+                let mut claim_fee__ = None;
+                let mut claim_address__ = None;
+                let mut rseed__ = None;
+                let mut claim_key_commitment__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::StartHeight => {
-                            if start_height__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("startHeight"));
+                        GeneratedField::TradingPair => {
+                            if trading_pair__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("tradingPair"));
                             }
-                            start_height__ = 
-                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
-                            ;
+                            trading_pair__ = map_.next_value()?;
                         }
-                        GeneratedField::EndHeight => {
-                            if end_height__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("endHeight"));
+                        GeneratedField::Delta1I => {
+                            if delta_1_i__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("delta1I"));
                             }
-                            end_height__ = 
-                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            delta_1_i__ = map_.next_value()?;
+                        }
+                        GeneratedField::Delta2I => {
+                            if delta_2_i__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("delta2I"));
+                            }
+                            delta_2_i__ = map_.next_value()?;
+                        }
+                        GeneratedField::ClaimFee => {
+                            if claim_fee__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("claimFee"));
+                            }
+                            claim_fee__ = map_.next_value()?;
+                        }
+                        GeneratedField::ClaimAddress => {
+                            if claim_address__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("claimAddress"));
+                            }
+                            claim_address__ = map_.next_value()?;
+                        }
+                        GeneratedField::Rseed => {
+                            if rseed__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("rseed"));
+                            }
+                            rseed__ = 
+                                Some(map_.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
                             ;
                         }
-                        GeneratedField::TradingPair => {
-                            if trading_pair__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("tradingPair"));
+                        GeneratedField::ClaimKeyCommitment => {
+                            if claim_key_commitment__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("claimKeyCommitment"));
                             }
-                            trading_pair__ = map_.next_value()?;
+                            claim_key_commitment__ = 
+                                Some(map_.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
+                            ;
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(SwapExecutionsRequest {
-                    start_height: start_height__.unwrap_or_default(),
-                    end_height: end_height__.unwrap_or_default(),
+                Ok(SwapPlaintext {
                     trading_pair: trading_pair__,
+                    delta_1_i: delta_1_i__,
+                    delta_2_i: delta_2_i__,
+                    claim_fee: claim_fee__,
+                    claim_address: claim_address__,
+                    rseed: rseed__.unwrap_or_default(),
+                    claim_key_commitment: claim_key_commitment__.unwrap_or_default(),
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.core.component.dex.v1.SwapExecutionsRequest", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.core.component.dex.v1.SwapPlaintext", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for SwapExecutionsResponse {
+impl serde::Serialize for SwapPlan {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -6935,48 +13598,60 @@ impl serde::Serialize for SwapExecutionsResponse {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.swap_execution.is_some() {
+        if self.swap_plaintext.is_some() {
             len += 1;
         }
-        if self.height != 0 {
+        if !self.fee_blinding.is_empty() {
             len += 1;
         }
-        if self.trading_pair.is_some() {
+        if !self.proof_blinding_r.is_empty() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.SwapExecutionsResponse", len)?;
-        if let Some(v) = self.swap_execution.as_ref() {
-            struct_ser.serialize_field("swapExecution", v)?;
+        if !self.proof_blinding_s.is_empty() {
+            len += 1;
         }
-        if self.height != 0 {
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.SwapPlan", len)?;
+        if let Some(v) = self.swap_plaintext.as_ref() {
+            struct_ser.serialize_field("swapPlaintext", v)?;
+        }
+        if !self.fee_blinding.is_empty() {
             #[allow(clippy::needless_borrow)]
-            struct_ser.serialize_field("height", ToString::to_string(&self.height).as_str())?;
+            struct_ser.serialize_field("feeBlinding", pbjson::private::base64::encode(&self.fee_blinding).as_str())?;
         }
-        if let Some(v) = self.trading_pair.as_ref() {
-            struct_ser.serialize_field("tradingPair", v)?;
+        if !self.proof_blinding_r.is_empty() {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("proofBlindingR", pbjson::private::base64::encode(&self.proof_blinding_r).as_str())?;
+        }
+        if !self.proof_blinding_s.is_empty() {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("proofBlindingS", pbjson::private::base64::encode(&self.proof_blinding_s).as_str())?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for SwapExecutionsResponse {
+impl<'de> serde::Deserialize<'de> for SwapPlan {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "swap_execution",
-            "swapExecution",
-            "height",
-            "trading_pair",
-            "tradingPair",
+            "swap_plaintext",
+            "swapPlaintext",
+            "fee_blinding",
+            "feeBlinding",
+            "proof_blinding_r",
+            "proofBlindingR",
+            "proof_blinding_s",
+            "proofBlindingS",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            SwapExecution,
-            Height,
-            TradingPair,
+            SwapPlaintext,
+            FeeBlinding,
+            ProofBlindingR,
+            ProofBlindingS,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -6999,9 +13674,10 @@ impl<'de> serde::Deserialize<'de> for SwapExecutionsResponse {
                         E: serde::de::Error,
                     {
                         match value {
-                            "swapExecution" | "swap_execution" => Ok(GeneratedField::SwapExecution),
-                            "height" => Ok(GeneratedField::Height),
-                            "tradingPair" | "trading_pair" => Ok(GeneratedField::TradingPair),
+                            "swapPlaintext" | "swap_plaintext" => Ok(GeneratedField::SwapPlaintext),
+                            "feeBlinding" | "fee_blinding" => Ok(GeneratedField::FeeBlinding),
+                            "proofBlindingR" | "proof_blinding_r" => Ok(GeneratedField::ProofBlindingR),
+                            "proofBlindingS" | "proof_blinding_s" => Ok(GeneratedField::ProofBlindingS),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -7011,57 +13687,69 @@ impl<'de> serde::Deserialize<'de> for SwapExecutionsResponse {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = SwapExecutionsResponse;
+            type Value = SwapPlan;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.core.component.dex.v1.SwapExecutionsResponse")
+                formatter.write_str("struct penumbra.core.component.dex.v1.SwapPlan")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<SwapExecutionsResponse, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<SwapPlan, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut swap_execution__ = None;
-                let mut height__ = None;
-                let mut trading_pair__ = None;
+                let mut swap_plaintext__ = None;
+                let mut fee_blinding__ = None;
+                let mut proof_blinding_r__ = None;
+                let mut proof_blinding_s__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::SwapExecution => {
-                            if swap_execution__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("swapExecution"));
+                        GeneratedField::SwapPlaintext => {
+                            if swap_plaintext__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("swapPlaintext"));
                             }
-                            swap_execution__ = map_.next_value()?;
+                            swap_plaintext__ = map_.next_value()?;
                         }
-                        GeneratedField::Height => {
-                            if height__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("height"));
+                        GeneratedField::FeeBlinding => {
+                            if fee_blinding__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("feeBlinding"));
                             }
-                            height__ = 
-                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            fee_blinding__ = 
+                                Some(map_.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
                             ;
                         }
-                        GeneratedField::TradingPair => {
-                            if trading_pair__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("tradingPair"));
+                        GeneratedField::ProofBlindingR => {
+                            if proof_blinding_r__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("proofBlindingR"));
                             }
-                            trading_pair__ = map_.next_value()?;
+                            proof_blinding_r__ = 
+                                Some(map_.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::ProofBlindingS => {
+                            if proof_blinding_s__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("proofBlindingS"));
+                            }
+                            proof_blinding_s__ = 
+                                Some(map_.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
+                            ;
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(SwapExecutionsResponse {
-                    swap_execution: swap_execution__,
-                    height: height__.unwrap_or_default(),
-                    trading_pair: trading_pair__,
+                Ok(SwapPlan {
+                    swap_plaintext: swap_plaintext__,
+                    fee_blinding: fee_blinding__.unwrap_or_default(),
+                    proof_blinding_r: proof_blinding_r__.unwrap_or_default(),
+                    proof_blinding_s: proof_blinding_s__.unwrap_or_default(),
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.core.component.dex.v1.SwapExecutionsResponse", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.core.component.dex.v1.SwapPlan", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for SwapPayload {
+impl serde::Serialize for SwapView {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -7069,39 +13757,38 @@ impl serde::Serialize for SwapPayload {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.commitment.is_some() {
-            len += 1;
-        }
-        if !self.encrypted_swap.is_empty() {
+        if self.swap_view.is_some() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.SwapPayload", len)?;
-        if let Some(v) = self.commitment.as_ref() {
-            struct_ser.serialize_field("commitment", v)?;
-        }
-        if !self.encrypted_swap.is_empty() {
-            #[allow(clippy::needless_borrow)]
-            struct_ser.serialize_field("encryptedSwap", pbjson::private::base64::encode(&self.encrypted_swap).as_str())?;
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.SwapView", len)?;
+        if let Some(v) = self.swap_view.as_ref() {
+            match v {
+                swap_view::SwapView::Visible(v) => {
+                    struct_ser.serialize_field("visible", v)?;
+                }
+                swap_view::SwapView::Opaque(v) => {
+                    struct_ser.serialize_field("opaque", v)?;
+                }
+            }
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for SwapPayload {
+impl<'de> serde::Deserialize<'de> for SwapView {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "commitment",
-            "encrypted_swap",
-            "encryptedSwap",
+            "visible",
+            "opaque",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            Commitment,
-            EncryptedSwap,
+            Visible,
+            Opaque,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -7124,8 +13811,8 @@ impl<'de> serde::Deserialize<'de> for SwapPayload {
                         E: serde::de::Error,
                     {
                         match value {
-                            "commitment" => Ok(GeneratedField::Commitment),
-                            "encryptedSwap" | "encrypted_swap" => Ok(GeneratedField::EncryptedSwap),
+                            "visible" => Ok(GeneratedField::Visible),
+                            "opaque" => Ok(GeneratedField::Opaque),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -7135,49 +13822,47 @@ impl<'de> serde::Deserialize<'de> for SwapPayload {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = SwapPayload;
+            type Value = SwapView;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.core.component.dex.v1.SwapPayload")
+                formatter.write_str("struct penumbra.core.component.dex.v1.SwapView")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<SwapPayload, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<SwapView, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut commitment__ = None;
-                let mut encrypted_swap__ = None;
+                let mut swap_view__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::Commitment => {
-                            if commitment__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("commitment"));
+                        GeneratedField::Visible => {
+                            if swap_view__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("visible"));
                             }
-                            commitment__ = map_.next_value()?;
+                            swap_view__ = map_.next_value::<::std::option::Option<_>>()?.map(swap_view::SwapView::Visible)
+;
                         }
-                        GeneratedField::EncryptedSwap => {
-                            if encrypted_swap__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("encryptedSwap"));
+                        GeneratedField::Opaque => {
+                            if swap_view__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("opaque"));
                             }
-                            encrypted_swap__ = 
-                                Some(map_.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
-                            ;
+                            swap_view__ = map_.next_value::<::std::option::Option<_>>()?.map(swap_view::SwapView::Opaque)
+;
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(SwapPayload {
-                    commitment: commitment__,
-                    encrypted_swap: encrypted_swap__.unwrap_or_default(),
+                Ok(SwapView {
+                    swap_view: swap_view__,
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.core.component.dex.v1.SwapPayload", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.core.component.dex.v1.SwapView", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for SwapPlaintext {
+impl serde::Serialize for swap_view::Opaque {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -7185,75 +13870,29 @@ impl serde::Serialize for SwapPlaintext {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.trading_pair.is_some() {
-            len += 1;
-        }
-        if self.delta_1_i.is_some() {
-            len += 1;
-        }
-        if self.delta_2_i.is_some() {
-            len += 1;
-        }
-        if self.claim_fee.is_some() {
-            len += 1;
-        }
-        if self.claim_address.is_some() {
-            len += 1;
-        }
-        if !self.rseed.is_empty() {
+        if self.swap.is_some() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.SwapPlaintext", len)?;
-        if let Some(v) = self.trading_pair.as_ref() {
-            struct_ser.serialize_field("tradingPair", v)?;
-        }
-        if let Some(v) = self.delta_1_i.as_ref() {
-            struct_ser.serialize_field("delta1I", v)?;
-        }
-        if let Some(v) = self.delta_2_i.as_ref() {
-            struct_ser.serialize_field("delta2I", v)?;
-        }
-        if let Some(v) = self.claim_fee.as_ref() {
-            struct_ser.serialize_field("claimFee", v)?;
-        }
-        if let Some(v) = self.claim_address.as_ref() {
-            struct_ser.serialize_field("claimAddress", v)?;
-        }
-        if !self.rseed.is_empty() {
-            #[allow(clippy::needless_borrow)]
-            struct_ser.serialize_field("rseed", pbjson::private::base64::encode(&self.rseed).as_str())?;
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.SwapView.Opaque", len)?;
+        if let Some(v) = self.swap.as_ref() {
+            struct_ser.serialize_field("swap", v)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for SwapPlaintext {
+impl<'de> serde::Deserialize<'de> for swap_view::Opaque {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "trading_pair",
-            "tradingPair",
-            "delta_1_i",
-            "delta1I",
-            "delta_2_i",
-            "delta2I",
-            "claim_fee",
-            "claimFee",
-            "claim_address",
-            "claimAddress",
-            "rseed",
+            "swap",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            TradingPair,
-            Delta1I,
-            Delta2I,
-            ClaimFee,
-            ClaimAddress,
-            Rseed,
+            Swap,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -7276,12 +13915,7 @@ impl<'de> serde::Deserialize<'de> for SwapPlaintext {
                         E: serde::de::Error,
                     {
                         match value {
-                            "tradingPair" | "trading_pair" => Ok(GeneratedField::TradingPair),
-                            "delta1I" | "delta_1_i" => Ok(GeneratedField::Delta1I),
-                            "delta2I" | "delta_2_i" => Ok(GeneratedField::Delta2I),
-                            "claimFee" | "claim_fee" => Ok(GeneratedField::ClaimFee),
-                            "claimAddress" | "claim_address" => Ok(GeneratedField::ClaimAddress),
-                            "rseed" => Ok(GeneratedField::Rseed),
+                            "swap" => Ok(GeneratedField::Swap),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -7291,81 +13925,39 @@ impl<'de> serde::Deserialize<'de> for SwapPlaintext {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = SwapPlaintext;
+            type Value = swap_view::Opaque;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.core.component.dex.v1.SwapPlaintext")
+                formatter.write_str("struct penumbra.core.component.dex.v1.SwapView.Opaque")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<SwapPlaintext, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<swap_view::Opaque, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut trading_pair__ = None;
-                let mut delta_1_i__ = None;
-                let mut delta_2_i__ = None;
-                let mut claim_fee__ = None;
-                let mut claim_address__ = None;
-                let mut rseed__ = None;
+                let mut swap__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::TradingPair => {
-                            if trading_pair__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("tradingPair"));
-                            }
-                            trading_pair__ = map_.next_value()?;
-                        }
-                        GeneratedField::Delta1I => {
-                            if delta_1_i__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("delta1I"));
-                            }
-                            delta_1_i__ = map_.next_value()?;
-                        }
-                        GeneratedField::Delta2I => {
-                            if delta_2_i__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("delta2I"));
-                            }
-                            delta_2_i__ = map_.next_value()?;
-                        }
-                        GeneratedField::ClaimFee => {
-                            if claim_fee__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("claimFee"));
-                            }
-                            claim_fee__ = map_.next_value()?;
-                        }
-                        GeneratedField::ClaimAddress => {
-                            if claim_address__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("claimAddress"));
-                            }
-                            claim_address__ = map_.next_value()?;
-                        }
-                        GeneratedField::Rseed => {
-                            if rseed__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("rseed"));
+                        GeneratedField::Swap => {
+                            if swap__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("swap"));
                             }
-                            rseed__ = 
-                                Some(map_.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
-                            ;
+                            swap__ = map_.next_value()?;
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(SwapPlaintext {
-                    trading_pair: trading_pair__,
-                    delta_1_i: delta_1_i__,
-                    delta_2_i: delta_2_i__,
-                    claim_fee: claim_fee__,
-                    claim_address: claim_address__,
-                    rseed: rseed__.unwrap_or_default(),
+                Ok(swap_view::Opaque {
+                    swap: swap__,
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.core.component.dex.v1.SwapPlaintext", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.core.component.dex.v1.SwapView.Opaque", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for SwapPlan {
+impl serde::Serialize for swap_view::Visible {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -7373,60 +13965,92 @@ impl serde::Serialize for SwapPlan {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
+        if self.swap.is_some() {
+            len += 1;
+        }
         if self.swap_plaintext.is_some() {
             len += 1;
         }
-        if !self.fee_blinding.is_empty() {
+        if self.claim_tx.is_some() {
             len += 1;
         }
-        if !self.proof_blinding_r.is_empty() {
+        if self.batch_swap_output_data.is_some() {
             len += 1;
         }
-        if !self.proof_blinding_s.is_empty() {
+        if self.output_1.is_some() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.SwapPlan", len)?;
+        if self.output_2.is_some() {
+            len += 1;
+        }
+        if self.asset_1_metadata.is_some() {
+            len += 1;
+        }
+        if self.asset_2_metadata.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.SwapView.Visible", len)?;
+        if let Some(v) = self.swap.as_ref() {
+            struct_ser.serialize_field("swap", v)?;
+        }
         if let Some(v) = self.swap_plaintext.as_ref() {
             struct_ser.serialize_field("swapPlaintext", v)?;
         }
-        if !self.fee_blinding.is_empty() {
-            #[allow(clippy::needless_borrow)]
-            struct_ser.serialize_field("feeBlinding", pbjson::private::base64::encode(&self.fee_blinding).as_str())?;
+        if let Some(v) = self.claim_tx.as_ref() {
+            struct_ser.serialize_field("claimTx", v)?;
+        }
+        if let Some(v) = self.batch_swap_output_data.as_ref() {
+            struct_ser.serialize_field("batchSwapOutputData", v)?;
+        }
+        if let Some(v) = self.output_1.as_ref() {
+            struct_ser.serialize_field("output1", v)?;
         }
-        if !self.proof_blinding_r.is_empty() {
-            #[allow(clippy::needless_borrow)]
-            struct_ser.serialize_field("proofBlindingR", pbjson::private::base64::encode(&self.proof_blinding_r).as_str())?;
+        if let Some(v) = self.output_2.as_ref() {
+            struct_ser.serialize_field("output2", v)?;
         }
-        if !self.proof_blinding_s.is_empty() {
-            #[allow(clippy::needless_borrow)]
-            struct_ser.serialize_field("proofBlindingS", pbjson::private::base64::encode(&self.proof_blinding_s).as_str())?;
+        if let Some(v) = self.asset_1_metadata.as_ref() {
+            struct_ser.serialize_field("asset1Metadata", v)?;
+        }
+        if let Some(v) = self.asset_2_metadata.as_ref() {
+            struct_ser.serialize_field("asset2Metadata", v)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for SwapPlan {
+impl<'de> serde::Deserialize<'de> for swap_view::Visible {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
+            "swap",
             "swap_plaintext",
             "swapPlaintext",
-            "fee_blinding",
-            "feeBlinding",
-            "proof_blinding_r",
-            "proofBlindingR",
-            "proof_blinding_s",
-            "proofBlindingS",
+            "claim_tx",
+            "claimTx",
+            "batch_swap_output_data",
+            "batchSwapOutputData",
+            "output_1",
+            "output1",
+            "output_2",
+            "output2",
+            "asset_1_metadata",
+            "asset1Metadata",
+            "asset_2_metadata",
+            "asset2Metadata",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
+            Swap,
             SwapPlaintext,
-            FeeBlinding,
-            ProofBlindingR,
-            ProofBlindingS,
+            ClaimTx,
+            BatchSwapOutputData,
+            Output1,
+            Output2,
+            Asset1Metadata,
+            Asset2Metadata,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -7449,10 +14073,14 @@ impl<'de> serde::Deserialize<'de> for SwapPlan {
                         E: serde::de::Error,
                     {
                         match value {
+                            "swap" => Ok(GeneratedField::Swap),
                             "swapPlaintext" | "swap_plaintext" => Ok(GeneratedField::SwapPlaintext),
-                            "feeBlinding" | "fee_blinding" => Ok(GeneratedField::FeeBlinding),
-                            "proofBlindingR" | "proof_blinding_r" => Ok(GeneratedField::ProofBlindingR),
-                            "proofBlindingS" | "proof_blinding_s" => Ok(GeneratedField::ProofBlindingS),
+                            "claimTx" | "claim_tx" => Ok(GeneratedField::ClaimTx),
+                            "batchSwapOutputData" | "batch_swap_output_data" => Ok(GeneratedField::BatchSwapOutputData),
+                            "output1" | "output_1" => Ok(GeneratedField::Output1),
+                            "output2" | "output_2" => Ok(GeneratedField::Output2),
+                            "asset1Metadata" | "asset_1_metadata" => Ok(GeneratedField::Asset1Metadata),
+                            "asset2Metadata" | "asset_2_metadata" => Ok(GeneratedField::Asset2Metadata),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -7462,69 +14090,95 @@ impl<'de> serde::Deserialize<'de> for SwapPlan {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = SwapPlan;
+            type Value = swap_view::Visible;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.core.component.dex.v1.SwapPlan")
+                formatter.write_str("struct penumbra.core.component.dex.v1.SwapView.Visible")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<SwapPlan, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<swap_view::Visible, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
+                let mut swap__ = None;
                 let mut swap_plaintext__ = None;
-                let mut fee_blinding__ = None;
-                let mut proof_blinding_r__ = None;
-                let mut proof_blinding_s__ = None;
+                let mut claim_tx__ = None;
+                let mut batch_swap_output_data__ = None;
+                let mut output_1__ = None;
+                let mut output_2__ = None;
+                let mut asset_1_metadata__ = None;
+                let mut asset_2_metadata__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
+                        GeneratedField::Swap => {
+                            if swap__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("swap"));
+                            }
+                            swap__ = map_.next_value()?;
+                        }
                         GeneratedField::SwapPlaintext => {
                             if swap_plaintext__.is_some() {
                                 return Err(serde::de::Error::duplicate_field("swapPlaintext"));
                             }
                             swap_plaintext__ = map_.next_value()?;
                         }
-                        GeneratedField::FeeBlinding => {
-                            if fee_blinding__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("feeBlinding"));
+                        GeneratedField::ClaimTx => {
+                            if claim_tx__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("claimTx"));
                             }
-                            fee_blinding__ = 
-                                Some(map_.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
-                            ;
+                            claim_tx__ = map_.next_value()?;
                         }
-                        GeneratedField::ProofBlindingR => {
-                            if proof_blinding_r__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("proofBlindingR"));
+                        GeneratedField::BatchSwapOutputData => {
+                            if batch_swap_output_data__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("batchSwapOutputData"));
                             }
-                            proof_blinding_r__ = 
-                                Some(map_.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
-                            ;
+                            batch_swap_output_data__ = map_.next_value()?;
                         }
-                        GeneratedField::ProofBlindingS => {
-                            if proof_blinding_s__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("proofBlindingS"));
+                        GeneratedField::Output1 => {
+                            if output_1__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("output1"));
                             }
-                            proof_blinding_s__ = 
-                                Some(map_.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
-                            ;
+                            output_1__ = map_.next_value()?;
+                        }
+                        GeneratedField::Output2 => {
+                            if output_2__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("output2"));
+                            }
+                            output_2__ = map_.next_value()?;
+                        }
+                        GeneratedField::Asset1Metadata => {
+                            if asset_1_metadata__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("asset1Metadata"));
+                            }
+                            asset_1_metadata__ = map_.next_value()?;
+                        }
+                        GeneratedField::Asset2Metadata => {
+                            if asset_2_metadata__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("asset2Metadata"));
+                            }
+                            asset_2_metadata__ = map_.next_value()?;
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(SwapPlan {
+                Ok(swap_view::Visible {
+                    swap: swap__,
                     swap_plaintext: swap_plaintext__,
-                    fee_blinding: fee_blinding__.unwrap_or_default(),
-                    proof_blinding_r: proof_blinding_r__.unwrap_or_default(),
-                    proof_blinding_s: proof_blinding_s__.unwrap_or_default(),
+                    claim_tx: claim_tx__,
+                    batch_swap_output_data: batch_swap_output_data__,
+                    output_1: output_1__,
+                    output_2: output_2__,
+                    asset_1_metadata: asset_1_metadata__,
+                    asset_2_metadata: asset_2_metadata__,
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.core.component.dex.v1.SwapPlan", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.core.component.dex.v1.SwapView.Visible", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for SwapView {
+impl serde::Serialize for TradingFunction {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -7532,38 +14186,37 @@ impl serde::Serialize for SwapView {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.swap_view.is_some() {
+        if self.component.is_some() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.SwapView", len)?;
-        if let Some(v) = self.swap_view.as_ref() {
-            match v {
-                swap_view::SwapView::Visible(v) => {
-                    struct_ser.serialize_field("visible", v)?;
-                }
-                swap_view::SwapView::Opaque(v) => {
-                    struct_ser.serialize_field("opaque", v)?;
-                }
-            }
+        if self.pair.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.TradingFunction", len)?;
+        if let Some(v) = self.component.as_ref() {
+            struct_ser.serialize_field("component", v)?;
+        }
+        if let Some(v) = self.pair.as_ref() {
+            struct_ser.serialize_field("pair", v)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for SwapView {
+impl<'de> serde::Deserialize<'de> for TradingFunction {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "visible",
-            "opaque",
+            "component",
+            "pair",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            Visible,
-            Opaque,
+            Component,
+            Pair,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -7586,8 +14239,8 @@ impl<'de> serde::Deserialize<'de> for SwapView {
                         E: serde::de::Error,
                     {
                         match value {
-                            "visible" => Ok(GeneratedField::Visible),
-                            "opaque" => Ok(GeneratedField::Opaque),
+                            "component" => Ok(GeneratedField::Component),
+                            "pair" => Ok(GeneratedField::Pair),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -7597,47 +14250,47 @@ impl<'de> serde::Deserialize<'de> for SwapView {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = SwapView;
+            type Value = TradingFunction;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.core.component.dex.v1.SwapView")
+                formatter.write_str("struct penumbra.core.component.dex.v1.TradingFunction")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<SwapView, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<TradingFunction, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut swap_view__ = None;
+                let mut component__ = None;
+                let mut pair__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::Visible => {
-                            if swap_view__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("visible"));
+                        GeneratedField::Component => {
+                            if component__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("component"));
                             }
-                            swap_view__ = map_.next_value::<::std::option::Option<_>>()?.map(swap_view::SwapView::Visible)
-;
+                            component__ = map_.next_value()?;
                         }
-                        GeneratedField::Opaque => {
-                            if swap_view__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("opaque"));
+                        GeneratedField::Pair => {
+                            if pair__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("pair"));
                             }
-                            swap_view__ = map_.next_value::<::std::option::Option<_>>()?.map(swap_view::SwapView::Opaque)
-;
+                            pair__ = map_.next_value()?;
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(SwapView {
-                    swap_view: swap_view__,
+                Ok(TradingFunction {
+                    component: component__,
+                    pair: pair__,
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.core.component.dex.v1.SwapView", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.core.component.dex.v1.TradingFunction", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for swap_view::Opaque {
+impl serde::Serialize for TradingPair {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -7645,29 +14298,39 @@ impl serde::Serialize for swap_view::Opaque {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.swap.is_some() {
+        if self.asset_1.is_some() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.SwapView.Opaque", len)?;
-        if let Some(v) = self.swap.as_ref() {
-            struct_ser.serialize_field("swap", v)?;
+        if self.asset_2.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.TradingPair", len)?;
+        if let Some(v) = self.asset_1.as_ref() {
+            struct_ser.serialize_field("asset1", v)?;
+        }
+        if let Some(v) = self.asset_2.as_ref() {
+            struct_ser.serialize_field("asset2", v)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for swap_view::Opaque {
+impl<'de> serde::Deserialize<'de> for TradingPair {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "swap",
+            "asset_1",
+            "asset1",
+            "asset_2",
+            "asset2",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            Swap,
+            Asset1,
+            Asset2,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -7690,7 +14353,8 @@ impl<'de> serde::Deserialize<'de> for swap_view::Opaque {
                         E: serde::de::Error,
                     {
                         match value {
-                            "swap" => Ok(GeneratedField::Swap),
+                            "asset1" | "asset_1" => Ok(GeneratedField::Asset1),
+                            "asset2" | "asset_2" => Ok(GeneratedField::Asset2),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -7700,39 +14364,47 @@ impl<'de> serde::Deserialize<'de> for swap_view::Opaque {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = swap_view::Opaque;
+            type Value = TradingPair;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.core.component.dex.v1.SwapView.Opaque")
+                formatter.write_str("struct penumbra.core.component.dex.v1.TradingPair")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<swap_view::Opaque, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<TradingPair, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut swap__ = None;
+                let mut asset_1__ = None;
+                let mut asset_2__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::Swap => {
-                            if swap__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("swap"));
+                        GeneratedField::Asset1 => {
+                            if asset_1__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("asset1"));
+                            }
+                            asset_1__ = map_.next_value()?;
+                        }
+                        GeneratedField::Asset2 => {
+                            if asset_2__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("asset2"));
                             }
-                            swap__ = map_.next_value()?;
+                            asset_2__ = map_.next_value()?;
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(swap_view::Opaque {
-                    swap: swap__,
+                Ok(TradingPair {
+                    asset_1: asset_1__,
+                    asset_2: asset_2__,
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.core.component.dex.v1.SwapView.Opaque", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.core.component.dex.v1.TradingPair", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for swap_view::Visible {
+impl serde::Serialize for ValueCircuitBreakerBreach {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -7740,92 +14412,72 @@ impl serde::Serialize for swap_view::Visible {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.swap.is_some() {
-            len += 1;
-        }
-        if self.swap_plaintext.is_some() {
-            len += 1;
-        }
-        if self.claim_tx.is_some() {
+        if self.height != 0 {
             len += 1;
         }
-        if self.batch_swap_output_data.is_some() {
+        if self.trading_pair.is_some() {
             len += 1;
         }
-        if self.output_1.is_some() {
+        if self.asset_id.is_some() {
             len += 1;
         }
-        if self.output_2.is_some() {
+        if self.available.is_some() {
             len += 1;
         }
-        if self.asset_1_metadata.is_some() {
+        if self.outflow.is_some() {
             len += 1;
         }
-        if self.asset_2_metadata.is_some() {
+        if !self.positions.is_empty() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.SwapView.Visible", len)?;
-        if let Some(v) = self.swap.as_ref() {
-            struct_ser.serialize_field("swap", v)?;
-        }
-        if let Some(v) = self.swap_plaintext.as_ref() {
-            struct_ser.serialize_field("swapPlaintext", v)?;
-        }
-        if let Some(v) = self.claim_tx.as_ref() {
-            struct_ser.serialize_field("claimTx", v)?;
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.ValueCircuitBreakerBreach", len)?;
+        if self.height != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("height", ToString::to_string(&self.height).as_str())?;
         }
-        if let Some(v) = self.batch_swap_output_data.as_ref() {
-            struct_ser.serialize_field("batchSwapOutputData", v)?;
+        if let Some(v) = self.trading_pair.as_ref() {
+            struct_ser.serialize_field("tradingPair", v)?;
         }
-        if let Some(v) = self.output_1.as_ref() {
-            struct_ser.serialize_field("output1", v)?;
+        if let Some(v) = self.asset_id.as_ref() {
+            struct_ser.serialize_field("assetId", v)?;
         }
-        if let Some(v) = self.output_2.as_ref() {
-            struct_ser.serialize_field("output2", v)?;
+        if let Some(v) = self.available.as_ref() {
+            struct_ser.serialize_field("available", v)?;
         }
-        if let Some(v) = self.asset_1_metadata.as_ref() {
-            struct_ser.serialize_field("asset1Metadata", v)?;
+        if let Some(v) = self.outflow.as_ref() {
+            struct_ser.serialize_field("outflow", v)?;
         }
-        if let Some(v) = self.asset_2_metadata.as_ref() {
-            struct_ser.serialize_field("asset2Metadata", v)?;
+        if !self.positions.is_empty() {
+            struct_ser.serialize_field("positions", &self.positions)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for swap_view::Visible {
+impl<'de> serde::Deserialize<'de> for ValueCircuitBreakerBreach {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "swap",
-            "swap_plaintext",
-            "swapPlaintext",
-            "claim_tx",
-            "claimTx",
-            "batch_swap_output_data",
-            "batchSwapOutputData",
-            "output_1",
-            "output1",
-            "output_2",
-            "output2",
-            "asset_1_metadata",
-            "asset1Metadata",
-            "asset_2_metadata",
-            "asset2Metadata",
+            "height",
+            "trading_pair",
+            "tradingPair",
+            "asset_id",
+            "assetId",
+            "available",
+            "outflow",
+            "positions",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            Swap,
-            SwapPlaintext,
-            ClaimTx,
-            BatchSwapOutputData,
-            Output1,
-            Output2,
-            Asset1Metadata,
-            Asset2Metadata,
+            Height,
+            TradingPair,
+            AssetId,
+            Available,
+            Outflow,
+            Positions,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -7848,14 +14500,12 @@ impl<'de> serde::Deserialize<'de> for swap_view::Visible {
                         E: serde::de::Error,
                     {
                         match value {
-                            "swap" => Ok(GeneratedField::Swap),
-                            "swapPlaintext" | "swap_plaintext" => Ok(GeneratedField::SwapPlaintext),
-                            "claimTx" | "claim_tx" => Ok(GeneratedField::ClaimTx),
-                            "batchSwapOutputData" | "batch_swap_output_data" => Ok(GeneratedField::BatchSwapOutputData),
-                            "output1" | "output_1" => Ok(GeneratedField::Output1),
-                            "output2" | "output_2" => Ok(GeneratedField::Output2),
-                            "asset1Metadata" | "asset_1_metadata" => Ok(GeneratedField::Asset1Metadata),
-                            "asset2Metadata" | "asset_2_metadata" => Ok(GeneratedField::Asset2Metadata),
+                            "height" => Ok(GeneratedField::Height),
+                            "tradingPair" | "trading_pair" => Ok(GeneratedField::TradingPair),
+                            "assetId" | "asset_id" => Ok(GeneratedField::AssetId),
+                            "available" => Ok(GeneratedField::Available),
+                            "outflow" => Ok(GeneratedField::Outflow),
+                            "positions" => Ok(GeneratedField::Positions),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -7865,133 +14515,103 @@ impl<'de> serde::Deserialize<'de> for swap_view::Visible {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = swap_view::Visible;
+            type Value = ValueCircuitBreakerBreach;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.core.component.dex.v1.SwapView.Visible")
+                formatter.write_str("struct penumbra.core.component.dex.v1.ValueCircuitBreakerBreach")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<swap_view::Visible, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ValueCircuitBreakerBreach, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut swap__ = None;
-                let mut swap_plaintext__ = None;
-                let mut claim_tx__ = None;
-                let mut batch_swap_output_data__ = None;
-                let mut output_1__ = None;
-                let mut output_2__ = None;
-                let mut asset_1_metadata__ = None;
-                let mut asset_2_metadata__ = None;
+                let mut height__ = None;
+                let mut trading_pair__ = None;
+                let mut asset_id__ = None;
+                let mut available__ = None;
+                let mut outflow__ = None;
+                let mut positions__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::Swap => {
-                            if swap__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("swap"));
-                            }
-                            swap__ = map_.next_value()?;
-                        }
-                        GeneratedField::SwapPlaintext => {
-                            if swap_plaintext__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("swapPlaintext"));
-                            }
-                            swap_plaintext__ = map_.next_value()?;
-                        }
-                        GeneratedField::ClaimTx => {
-                            if claim_tx__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("claimTx"));
+                        GeneratedField::Height => {
+                            if height__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("height"));
                             }
-                            claim_tx__ = map_.next_value()?;
+                            height__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
                         }
-                        GeneratedField::BatchSwapOutputData => {
-                            if batch_swap_output_data__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("batchSwapOutputData"));
+                        GeneratedField::TradingPair => {
+                            if trading_pair__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("tradingPair"));
                             }
-                            batch_swap_output_data__ = map_.next_value()?;
+                            trading_pair__ = map_.next_value()?;
                         }
-                        GeneratedField::Output1 => {
-                            if output_1__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("output1"));
+                        GeneratedField::AssetId => {
+                            if asset_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("assetId"));
                             }
-                            output_1__ = map_.next_value()?;
+                            asset_id__ = map_.next_value()?;
                         }
-                        GeneratedField::Output2 => {
-                            if output_2__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("output2"));
+                        GeneratedField::Available => {
+                            if available__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("available"));
                             }
-                            output_2__ = map_.next_value()?;
+                            available__ = map_.next_value()?;
                         }
-                        GeneratedField::Asset1Metadata => {
-                            if asset_1_metadata__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("asset1Metadata"));
+                        GeneratedField::Outflow => {
+                            if outflow__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("outflow"));
                             }
-                            asset_1_metadata__ = map_.next_value()?;
+                            outflow__ = map_.next_value()?;
                         }
-                        GeneratedField::Asset2Metadata => {
-                            if asset_2_metadata__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("asset2Metadata"));
+                        GeneratedField::Positions => {
+                            if positions__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("positions"));
                             }
-                            asset_2_metadata__ = map_.next_value()?;
+                            positions__ = Some(map_.next_value()?);
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(swap_view::Visible {
-                    swap: swap__,
-                    swap_plaintext: swap_plaintext__,
-                    claim_tx: claim_tx__,
-                    batch_swap_output_data: batch_swap_output_data__,
-                    output_1: output_1__,
-                    output_2: output_2__,
-                    asset_1_metadata: asset_1_metadata__,
-                    asset_2_metadata: asset_2_metadata__,
+                Ok(ValueCircuitBreakerBreach {
+                    height: height__.unwrap_or_default(),
+                    trading_pair: trading_pair__,
+                    asset_id: asset_id__,
+                    available: available__,
+                    outflow: outflow__,
+                    positions: positions__.unwrap_or_default(),
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.core.component.dex.v1.SwapView.Visible", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.core.component.dex.v1.ValueCircuitBreakerBreach", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for TradingFunction {
+impl serde::Serialize for ValueCircuitBreakerBreachRequest {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
         S: serde::Serializer,
     {
         use serde::ser::SerializeStruct;
-        let mut len = 0;
-        if self.component.is_some() {
-            len += 1;
-        }
-        if self.pair.is_some() {
-            len += 1;
-        }
-        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.TradingFunction", len)?;
-        if let Some(v) = self.component.as_ref() {
-            struct_ser.serialize_field("component", v)?;
-        }
-        if let Some(v) = self.pair.as_ref() {
-            struct_ser.serialize_field("pair", v)?;
-        }
+        let len = 0;
+        let struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.ValueCircuitBreakerBreachRequest", len)?;
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for TradingFunction {
+impl<'de> serde::Deserialize<'de> for ValueCircuitBreakerBreachRequest {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "component",
-            "pair",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            Component,
-            Pair,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -8013,11 +14633,7 @@ impl<'de> serde::Deserialize<'de> for TradingFunction {
                     where
                         E: serde::de::Error,
                     {
-                        match value {
-                            "component" => Ok(GeneratedField::Component),
-                            "pair" => Ok(GeneratedField::Pair),
-                            _ => Ok(GeneratedField::__SkipField__),
-                        }
+                            Ok(GeneratedField::__SkipField__)
                     }
                 }
                 deserializer.deserialize_identifier(GeneratedVisitor)
@@ -8025,47 +14641,27 @@ impl<'de> serde::Deserialize<'de> for TradingFunction {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = TradingFunction;
+            type Value = ValueCircuitBreakerBreachRequest;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.core.component.dex.v1.TradingFunction")
+                formatter.write_str("struct penumbra.core.component.dex.v1.ValueCircuitBreakerBreachRequest")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<TradingFunction, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ValueCircuitBreakerBreachRequest, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut component__ = None;
-                let mut pair__ = None;
-                while let Some(k) = map_.next_key()? {
-                    match k {
-                        GeneratedField::Component => {
-                            if component__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("component"));
-                            }
-                            component__ = map_.next_value()?;
-                        }
-                        GeneratedField::Pair => {
-                            if pair__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("pair"));
-                            }
-                            pair__ = map_.next_value()?;
-                        }
-                        GeneratedField::__SkipField__ => {
-                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
-                        }
-                    }
+                while map_.next_key::<GeneratedField>()?.is_some() {
+                    let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                 }
-                Ok(TradingFunction {
-                    component: component__,
-                    pair: pair__,
+                Ok(ValueCircuitBreakerBreachRequest {
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.core.component.dex.v1.TradingFunction", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.core.component.dex.v1.ValueCircuitBreakerBreachRequest", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for TradingPair {
+impl serde::Serialize for ValueCircuitBreakerBreachResponse {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -8073,39 +14669,29 @@ impl serde::Serialize for TradingPair {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.asset_1.is_some() {
-            len += 1;
-        }
-        if self.asset_2.is_some() {
+        if self.breach.is_some() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.TradingPair", len)?;
-        if let Some(v) = self.asset_1.as_ref() {
-            struct_ser.serialize_field("asset1", v)?;
-        }
-        if let Some(v) = self.asset_2.as_ref() {
-            struct_ser.serialize_field("asset2", v)?;
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.ValueCircuitBreakerBreachResponse", len)?;
+        if let Some(v) = self.breach.as_ref() {
+            struct_ser.serialize_field("breach", v)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for TradingPair {
+impl<'de> serde::Deserialize<'de> for ValueCircuitBreakerBreachResponse {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "asset_1",
-            "asset1",
-            "asset_2",
-            "asset2",
+            "breach",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            Asset1,
-            Asset2,
+            Breach,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -8128,8 +14714,7 @@ impl<'de> serde::Deserialize<'de> for TradingPair {
                         E: serde::de::Error,
                     {
                         match value {
-                            "asset1" | "asset_1" => Ok(GeneratedField::Asset1),
-                            "asset2" | "asset_2" => Ok(GeneratedField::Asset2),
+                            "breach" => Ok(GeneratedField::Breach),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -8139,44 +14724,36 @@ impl<'de> serde::Deserialize<'de> for TradingPair {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = TradingPair;
+            type Value = ValueCircuitBreakerBreachResponse;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.core.component.dex.v1.TradingPair")
+                formatter.write_str("struct penumbra.core.component.dex.v1.ValueCircuitBreakerBreachResponse")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<TradingPair, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ValueCircuitBreakerBreachResponse, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut asset_1__ = None;
-                let mut asset_2__ = None;
+                let mut breach__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::Asset1 => {
-                            if asset_1__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("asset1"));
-                            }
-                            asset_1__ = map_.next_value()?;
-                        }
-                        GeneratedField::Asset2 => {
-                            if asset_2__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("asset2"));
+                        GeneratedField::Breach => {
+                            if breach__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("breach"));
                             }
-                            asset_2__ = map_.next_value()?;
+                            breach__ = map_.next_value()?;
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(TradingPair {
-                    asset_1: asset_1__,
-                    asset_2: asset_2__,
+                Ok(ValueCircuitBreakerBreachResponse {
+                    breach: breach__,
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.core.component.dex.v1.TradingPair", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.core.component.dex.v1.ValueCircuitBreakerBreachResponse", FIELDS, GeneratedVisitor)
     }
 }
 impl serde::Serialize for ZkSwapClaimProof {
@@ -21,6 +21,14 @@ pub struct ProposalSubmit {
     /// The amount of the proposal deposit.
     #[prost(message, optional, tag = "3")]
     pub deposit_amount: ::core::option::Option<super::super::super::num::v1::Amount>,
+    /// The asset the proposal deposit is paid in.
+    ///
+    /// If unset, the deposit is paid in the staking token. Otherwise, it must be
+    /// one of the governance parameters' `accepted_deposit_assets`.
+    #[prost(message, optional, tag = "4")]
+    pub deposit_asset_id: ::core::option::Option<
+        super::super::super::asset::v1::AssetId,
+    >,
 }
 impl ::prost::Name for ProposalSubmit {
     const NAME: &'static str = "ProposalSubmit";
@@ -58,6 +66,13 @@ pub struct ProposalDepositClaim {
     /// The outcome of the proposal.
     #[prost(message, optional, tag = "3")]
     pub outcome: ::core::option::Option<ProposalOutcome>,
+    /// The asset the deposit was paid in, and is refunded in.
+    ///
+    /// If unset, the staking token.
+    #[prost(message, optional, tag = "4")]
+    pub deposit_asset_id: ::core::option::Option<
+        super::super::super::asset::v1::AssetId,
+    >,
 }
 impl ::prost::Name for ProposalDepositClaim {
     const NAME: &'static str = "ProposalDepositClaim";
@@ -118,6 +133,11 @@ pub struct ValidatorVoteBody {
     /// A justification of the vote.
     #[prost(message, optional, tag = "5")]
     pub reason: ::core::option::Option<ValidatorVoteReason>,
+    /// The vote, encrypted to the proposal's ballot key, if the proposal has sealed ballots.
+    ///
+    /// When this is set, `vote` must be set to abstain, and is ignored.
+    #[prost(message, optional, tag = "6")]
+    pub encrypted_vote: ::core::option::Option<EncryptedVote>,
 }
 impl ::prost::Name for ValidatorVoteBody {
     const NAME: &'static str = "ValidatorVoteBody";
@@ -174,6 +194,11 @@ pub struct DelegatorVoteBody {
     pub rk: ::core::option::Option<
         super::super::super::super::crypto::decaf377_rdsa::v1::SpendVerificationKey,
     >,
+    /// The vote, encrypted to the proposal's ballot key, if the proposal has sealed ballots.
+    ///
+    /// When this is set, `vote` must be set to abstain, and is ignored.
+    #[prost(message, optional, tag = "8")]
+    pub encrypted_vote: ::core::option::Option<EncryptedVote>,
 }
 impl ::prost::Name for DelegatorVoteBody {
     const NAME: &'static str = "DelegatorVoteBody";
@@ -270,6 +295,9 @@ pub struct DelegatorVotePlan {
     /// The second blinding factor to use for the ZK delegator vote proof.
     #[prost(bytes = "vec", tag = "9")]
     pub proof_blinding_s: ::prost::alloc::vec::Vec<u8>,
+    /// The vote, encrypted to the proposal's ballot key, if the proposal has sealed ballots.
+    #[prost(message, optional, tag = "10")]
+    pub encrypted_vote: ::core::option::Option<EncryptedVote>,
 }
 impl ::prost::Name for DelegatorVotePlan {
     const NAME: &'static str = "DelegatorVotePlan";
@@ -278,6 +306,240 @@ impl ::prost::Name for DelegatorVotePlan {
         ::prost::alloc::format!("penumbra.core.component.governance.v1.{}", Self::NAME)
     }
 }
+/// A vote encrypted to the ballot key of a proposal with sealed ballots.
+///
+/// The vote is ElGamal-encrypted as the multiple of the decaf377 generator given by the
+/// value of its `Vote.Vote` enum.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct EncryptedVote {
+    /// The ephemeral key, `rG`.
+    #[prost(bytes = "vec", tag = "1")]
+    pub ephemeral_key: ::prost::alloc::vec::Vec<u8>,
+    /// The encrypted vote, `rK + vG`, where `K` is the ballot key and `v` is the vote.
+    #[prost(bytes = "vec", tag = "2")]
+    pub ciphertext: ::prost::alloc::vec::Vec<u8>,
+}
+impl ::prost::Name for EncryptedVote {
+    const NAME: &'static str = "EncryptedVote";
+    const PACKAGE: &'static str = "penumbra.core.component.governance.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.core.component.governance.v1.{}", Self::NAME)
+    }
+}
+/// The key to which the ballots of a proposal with sealed ballots are encrypted.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct BallotKey {
+    /// The encoding of the ballot key, as a decaf377 element.
+    #[prost(bytes = "vec", tag = "1")]
+    pub inner: ::prost::alloc::vec::Vec<u8>,
+}
+impl ::prost::Name for BallotKey {
+    const NAME: &'static str = "BallotKey";
+    const PACKAGE: &'static str = "penumbra.core.component.governance.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.core.component.governance.v1.{}", Self::NAME)
+    }
+}
+/// A validator's contribution to the ballot key of a proposal with sealed ballots.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct BallotKeyDeal {
+    /// The effecting data for the deal.
+    #[prost(message, optional, tag = "1")]
+    pub body: ::core::option::Option<BallotKeyDealBody>,
+    /// The authorization signature by the validator's governance key over the body.
+    #[prost(message, optional, tag = "2")]
+    pub auth_sig: ::core::option::Option<
+        super::super::super::super::crypto::decaf377_rdsa::v1::SpendAuthSignature,
+    >,
+}
+impl ::prost::Name for BallotKeyDeal {
+    const NAME: &'static str = "BallotKeyDeal";
+    const PACKAGE: &'static str = "penumbra.core.component.governance.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.core.component.governance.v1.{}", Self::NAME)
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct BallotKeyDealBody {
+    /// The proposal whose ballot key is being dealt.
+    #[prost(uint64, tag = "1")]
+    pub proposal: u64,
+    /// The identity key of the dealing validator.
+    #[prost(message, optional, tag = "2")]
+    pub identity_key: ::core::option::Option<super::super::super::keys::v1::IdentityKey>,
+    /// The governance key of the dealing validator.
+    #[prost(message, optional, tag = "3")]
+    pub governance_key: ::core::option::Option<
+        super::super::super::keys::v1::GovernanceKey,
+    >,
+    /// Commitments to the coefficients of the validator's secret polynomial, as decaf377
+    /// elements, starting with the constant term, which is the validator's contribution to the
+    /// ballot key.
+    #[prost(bytes = "vec", repeated, tag = "4")]
+    pub commitments: ::prost::alloc::vec::Vec<::prost::alloc::vec::Vec<u8>>,
+    /// The shares of the secret polynomial, one for each share position, encrypted to the
+    /// governance key of the validator the position is allocated to.  Share positions are
+    /// allocated to the validators active at the start of the proposal in proportion to their
+    /// voting power, in order of identity key.
+    #[prost(message, repeated, tag = "5")]
+    pub encrypted_shares: ::prost::alloc::vec::Vec<EncryptedBallotKeyShare>,
+}
+impl ::prost::Name for BallotKeyDealBody {
+    const NAME: &'static str = "BallotKeyDealBody";
+    const PACKAGE: &'static str = "penumbra.core.component.governance.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.core.component.governance.v1.{}", Self::NAME)
+    }
+}
+/// A share of a validator's secret polynomial, encrypted to another validator's governance key.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct EncryptedBallotKeyShare {
+    /// The ephemeral key used to encrypt the share.
+    #[prost(bytes = "vec", tag = "1")]
+    pub ephemeral_key: ::prost::alloc::vec::Vec<u8>,
+    /// The encrypted share.
+    #[prost(bytes = "vec", tag = "2")]
+    pub ciphertext: ::prost::alloc::vec::Vec<u8>,
+}
+impl ::prost::Name for EncryptedBallotKeyShare {
+    const NAME: &'static str = "EncryptedBallotKeyShare";
+    const PACKAGE: &'static str = "penumbra.core.component.governance.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.core.component.governance.v1.{}", Self::NAME)
+    }
+}
+/// A validator's reveal of the secrets needed to open the sealed ballots of a proposal, after
+/// voting has closed.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct BallotKeyReveal {
+    /// The effecting data for the reveal.
+    #[prost(message, optional, tag = "1")]
+    pub body: ::core::option::Option<BallotKeyRevealBody>,
+    /// The authorization signature by the validator's governance key over the body.
+    #[prost(message, optional, tag = "2")]
+    pub auth_sig: ::core::option::Option<
+        super::super::super::super::crypto::decaf377_rdsa::v1::SpendAuthSignature,
+    >,
+}
+impl ::prost::Name for BallotKeyReveal {
+    const NAME: &'static str = "BallotKeyReveal";
+    const PACKAGE: &'static str = "penumbra.core.component.governance.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.core.component.governance.v1.{}", Self::NAME)
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct BallotKeyRevealBody {
+    /// The proposal whose ballot key secrets are being revealed.
+    #[prost(uint64, tag = "1")]
+    pub proposal: u64,
+    /// The identity key of the revealing validator.
+    #[prost(message, optional, tag = "2")]
+    pub identity_key: ::core::option::Option<super::super::super::keys::v1::IdentityKey>,
+    /// The governance key of the revealing validator.
+    #[prost(message, optional, tag = "3")]
+    pub governance_key: ::core::option::Option<
+        super::super::super::keys::v1::GovernanceKey,
+    >,
+    /// The validator's own contribution to the secret ballot key, if it dealt one.
+    #[prost(bytes = "vec", tag = "4")]
+    pub dealer_secret: ::prost::alloc::vec::Vec<u8>,
+    /// The decrypted shares the validator received from other dealers.
+    #[prost(message, repeated, tag = "5")]
+    pub shares: ::prost::alloc::vec::Vec<RevealedBallotKeyShare>,
+}
+impl ::prost::Name for BallotKeyRevealBody {
+    const NAME: &'static str = "BallotKeyRevealBody";
+    const PACKAGE: &'static str = "penumbra.core.component.governance.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.core.component.governance.v1.{}", Self::NAME)
+    }
+}
+/// A decrypted share of a dealer's secret polynomial.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct RevealedBallotKeyShare {
+    /// The identity key of the dealer.
+    #[prost(message, optional, tag = "1")]
+    pub dealer: ::core::option::Option<super::super::super::keys::v1::IdentityKey>,
+    /// The share, as a decaf377 scalar.
+    #[prost(bytes = "vec", tag = "2")]
+    pub share: ::prost::alloc::vec::Vec<u8>,
+    /// The share position at which the dealer's polynomial was evaluated.
+    #[prost(uint32, tag = "3")]
+    pub position: u32,
+}
+impl ::prost::Name for RevealedBallotKeyShare {
+    const NAME: &'static str = "RevealedBallotKeyShare";
+    const PACKAGE: &'static str = "penumbra.core.component.governance.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.core.component.governance.v1.{}", Self::NAME)
+    }
+}
+/// A validator's complaint that a dealer encrypted an invalid share of its secret polynomial to
+/// it, which excludes the dealer from the ballot key of the proposal.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct BallotKeyComplaint {
+    /// The effecting data for the complaint.
+    #[prost(message, optional, tag = "1")]
+    pub body: ::core::option::Option<BallotKeyComplaintBody>,
+    /// The authorization signature by the validator's governance key over the body.
+    #[prost(message, optional, tag = "2")]
+    pub auth_sig: ::core::option::Option<
+        super::super::super::super::crypto::decaf377_rdsa::v1::SpendAuthSignature,
+    >,
+}
+impl ::prost::Name for BallotKeyComplaint {
+    const NAME: &'static str = "BallotKeyComplaint";
+    const PACKAGE: &'static str = "penumbra.core.component.governance.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.core.component.governance.v1.{}", Self::NAME)
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct BallotKeyComplaintBody {
+    /// The proposal whose ballot key is being dealt.
+    #[prost(uint64, tag = "1")]
+    pub proposal: u64,
+    /// The identity key of the complaining validator.
+    #[prost(message, optional, tag = "2")]
+    pub identity_key: ::core::option::Option<super::super::super::keys::v1::IdentityKey>,
+    /// The governance key of the complaining validator.
+    #[prost(message, optional, tag = "3")]
+    pub governance_key: ::core::option::Option<
+        super::super::super::keys::v1::GovernanceKey,
+    >,
+    /// The identity key of the dealer of the invalid share.
+    #[prost(message, optional, tag = "4")]
+    pub dealer: ::core::option::Option<super::super::super::keys::v1::IdentityKey>,
+    /// The share position of the invalid share, which must be allocated to the complaining validator.
+    #[prost(uint32, tag = "5")]
+    pub position: u32,
+    /// The secret shared between the dealer and the complaining validator for the encryption of the
+    /// share, as a decaf377 element, with which anyone can decrypt the share.
+    #[prost(bytes = "vec", tag = "6")]
+    pub shared_secret: ::prost::alloc::vec::Vec<u8>,
+    /// A proof that the shared secret is the product of the share's ephemeral key and the secret
+    /// governance key of the complaining validator.
+    #[prost(bytes = "vec", tag = "7")]
+    pub proof: ::prost::alloc::vec::Vec<u8>,
+}
+impl ::prost::Name for BallotKeyComplaintBody {
+    const NAME: &'static str = "BallotKeyComplaintBody";
+    const PACKAGE: &'static str = "penumbra.core.component.governance.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.core.component.governance.v1.{}", Self::NAME)
+    }
+}
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CommunityPoolDeposit {
@@ -602,8 +864,11 @@ pub struct Proposal {
     /// A natural-language description of the effect of the proposal and its justification.
     #[prost(string, tag = "2")]
     pub description: ::prost::alloc::string::String,
+    /// Whether votes on the proposal are encrypted, and only opened once voting has closed.
+    #[prost(bool, tag = "12")]
+    pub sealed_ballots: bool,
     /// The proposal's payload.
-    #[prost(oneof = "proposal::Payload", tags = "5, 6, 7, 8, 9, 10, 11")]
+    #[prost(oneof = "proposal::Payload", tags = "5, 6, 7, 8, 9, 10, 11, 13")]
     pub payload: ::core::option::Option<proposal::Payload>,
 }
 /// Nested message and enum types in `Proposal`.
@@ -700,8 +965,15 @@ pub mod proposal {
     #[allow(clippy::derive_partial_eq_without_eq)]
     #[derive(Clone, PartialEq, ::prost::Message)]
     pub struct UpgradePlan {
+        /// The height at which the chain will halt for the upgrade.
         #[prost(uint64, tag = "1")]
         pub height: u64,
+        /// The version string of the `pd` release which must be run after the upgrade, if any.
+        #[prost(string, tag = "2")]
+        pub version: ::prost::alloc::string::String,
+        /// The hex-encoded SHA-256 hash of the upgraded `pd` binary, if any.
+        #[prost(string, tag = "4")]
+        pub binary_hash: ::prost::alloc::string::String,
     }
     impl ::prost::Name for UpgradePlan {
         const NAME: &'static str = "UpgradePlan";
@@ -748,6 +1020,31 @@ pub mod proposal {
             )
         }
     }
+    /// Establish a recurring budget line, paid automatically by the Community Pool at the end of
+    /// each epoch, and/or cancel budget lines established by previous passed proposals.
+    ///
+    /// The budget line established is identified by the ID of this proposal.
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct RecurringBudget {
+        /// The budget line to establish, if any.
+        #[prost(message, optional, tag = "1")]
+        pub budget_line: ::core::option::Option<
+            super::super::super::community_pool::v1::BudgetLine,
+        >,
+        /// The IDs of the active budget lines to cancel.
+        #[prost(uint64, repeated, tag = "2")]
+        pub cancel: ::prost::alloc::vec::Vec<u64>,
+    }
+    impl ::prost::Name for RecurringBudget {
+        const NAME: &'static str = "RecurringBudget";
+        const PACKAGE: &'static str = "penumbra.core.component.governance.v1";
+        fn full_name() -> ::prost::alloc::string::String {
+            ::prost::alloc::format!(
+                "penumbra.core.component.governance.v1.Proposal.{}", Self::NAME
+            )
+        }
+    }
     /// The proposal's payload.
     #[allow(clippy::derive_partial_eq_without_eq)]
     #[derive(Clone, PartialEq, ::prost::Oneof)]
@@ -766,6 +1063,8 @@ pub mod proposal {
         FreezeIbcClient(FreezeIbcClient),
         #[prost(message, tag = "11")]
         UnfreezeIbcClient(UnfreezeIbcClient),
+        #[prost(message, tag = "13")]
+        RecurringBudget(RecurringBudget),
     }
 }
 impl ::prost::Name for Proposal {
@@ -843,6 +1142,11 @@ pub struct ProposalDataResponse {
     pub proposal_deposit_amount: ::core::option::Option<
         super::super::super::num::v1::Amount,
     >,
+    /// The asset the deposit was paid in.
+    #[prost(message, optional, tag = "7")]
+    pub proposal_deposit_asset_id: ::core::option::Option<
+        super::super::super::asset::v1::AssetId,
+    >,
 }
 impl ::prost::Name for ProposalDataResponse {
     const NAME: &'static str = "ProposalDataResponse";
@@ -957,6 +1261,71 @@ impl ::prost::Name for ValidatorVotesResponse {
         ::prost::alloc::format!("penumbra.core.component.governance.v1.{}", Self::NAME)
     }
 }
+/// Requests the list of all validators which abstained from voting on a given proposal.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ValidatorAbstentionsRequest {
+    /// The proposal id to request information on.
+    #[prost(uint64, tag = "2")]
+    pub proposal_id: u64,
+}
+impl ::prost::Name for ValidatorAbstentionsRequest {
+    const NAME: &'static str = "ValidatorAbstentionsRequest";
+    const PACKAGE: &'static str = "penumbra.core.component.governance.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.core.component.governance.v1.{}", Self::NAME)
+    }
+}
+/// The data for a single validator abstention.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ValidatorAbstentionsResponse {
+    /// The validator identity.
+    #[prost(message, optional, tag = "1")]
+    pub identity_key: ::core::option::Option<super::super::super::keys::v1::IdentityKey>,
+    /// The voting power of the validator at the start of the proposal, which went unused.
+    #[prost(uint64, tag = "2")]
+    pub voting_power: u64,
+}
+impl ::prost::Name for ValidatorAbstentionsResponse {
+    const NAME: &'static str = "ValidatorAbstentionsResponse";
+    const PACKAGE: &'static str = "penumbra.core.component.governance.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.core.component.governance.v1.{}", Self::NAME)
+    }
+}
+/// Requests the governance participation statistics for a given validator.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ValidatorParticipationRequest {
+    /// The validator identity key to request information on.
+    #[prost(message, optional, tag = "2")]
+    pub identity_key: ::core::option::Option<super::super::super::keys::v1::IdentityKey>,
+}
+impl ::prost::Name for ValidatorParticipationRequest {
+    const NAME: &'static str = "ValidatorParticipationRequest";
+    const PACKAGE: &'static str = "penumbra.core.component.governance.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.core.component.governance.v1.{}", Self::NAME)
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ValidatorParticipationResponse {
+    /// The number of finished proposals for which the validator was active at proposal start.
+    #[prost(uint64, tag = "1")]
+    pub proposals_eligible: u64,
+    /// The number of those proposals on which the validator did not cast a vote.
+    #[prost(uint64, tag = "2")]
+    pub proposals_abstained: u64,
+}
+impl ::prost::Name for ValidatorParticipationResponse {
+    const NAME: &'static str = "ValidatorParticipationResponse";
+    const PACKAGE: &'static str = "penumbra.core.component.governance.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.core.component.governance.v1.{}", Self::NAME)
+    }
+}
 /// Governance configuration data.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -980,6 +1349,41 @@ pub struct GovernanceParameters {
     /// would have passed it, as a ratio of "no" votes over all total votes.
     #[prost(string, tag = "5")]
     pub proposal_slash_threshold: ::prost::alloc::string::String,
+    /// The number of blocks, at the start of voting on a proposal with sealed ballots, during which
+    /// validators deal its ballot key, and votes can't yet be cast.
+    #[prost(uint64, tag = "6")]
+    pub sealed_ballot_setup_blocks: u64,
+    /// The number of blocks, after voting on a proposal with sealed ballots has closed, during which
+    /// validators can reveal the secrets needed to open its ballots.
+    #[prost(uint64, tag = "7")]
+    pub sealed_ballot_reveal_blocks: u64,
+    /// Assets other than the staking token which proposal deposits may be paid in.
+    ///
+    /// A deposit in one of these assets must be worth at least
+    /// `proposal_deposit_amount` of the staking token, at the time-weighted
+    /// average rate of the batch swaps selling the asset for the staking token
+    /// over the last `deposit_rate_window_blocks` blocks.
+    #[prost(message, repeated, tag = "8")]
+    pub accepted_deposit_assets: ::prost::alloc::vec::Vec<
+        super::super::super::asset::v1::AssetId,
+    >,
+    /// The number of blocks over which the rates of batch swaps selling an
+    /// accepted deposit asset for the staking token are averaged.
+    ///
+    /// An asset can only be used for deposits once its batch swaps have been
+    /// recorded for at least this many blocks.
+    #[prost(uint64, tag = "9")]
+    pub deposit_rate_window_blocks: u64,
+    /// The maximum number of blocks since the last batch swap selling an accepted
+    /// deposit asset for the staking token, for the asset to be used for deposits.
+    #[prost(uint64, tag = "10")]
+    pub deposit_rate_max_age_blocks: u64,
+    /// The minimum amount of the staking token a batch swap must sell an accepted
+    /// deposit asset for, to count towards the asset's rate.
+    #[prost(message, optional, tag = "11")]
+    pub deposit_rate_min_volume: ::core::option::Option<
+        super::super::super::num::v1::Amount,
+    >,
 }
 impl ::prost::Name for GovernanceParameters {
     const NAME: &'static str = "GovernanceParameters";
@@ -988,6 +1392,45 @@ impl ::prost::Name for GovernanceParameters {
         ::prost::alloc::format!("penumbra.core.component.governance.v1.{}", Self::NAME)
     }
 }
+/// A batch swap selling an accepted deposit asset for the staking token, which
+/// sets the asset's rate from its height until the next such batch swap.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct DepositRate {
+    /// The amount of the asset sold by the batch swap.
+    #[prost(message, optional, tag = "1")]
+    pub input: ::core::option::Option<super::super::super::asset::v1::Value>,
+    /// The amount of the staking token it was sold for.
+    #[prost(message, optional, tag = "2")]
+    pub output: ::core::option::Option<super::super::super::num::v1::Amount>,
+    /// The height of the batch swap.
+    #[prost(uint64, tag = "3")]
+    pub height: u64,
+}
+impl ::prost::Name for DepositRate {
+    const NAME: &'static str = "DepositRate";
+    const PACKAGE: &'static str = "penumbra.core.component.governance.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.core.component.governance.v1.{}", Self::NAME)
+    }
+}
+/// The recent batch swaps selling an accepted deposit asset for the staking
+/// token, from which the asset's time-weighted average rate is computed.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct DepositRateHistory {
+    /// The batch swaps, in order of height, starting with the last one at or
+    /// before the start of the averaging window.
+    #[prost(message, repeated, tag = "1")]
+    pub rates: ::prost::alloc::vec::Vec<DepositRate>,
+}
+impl ::prost::Name for DepositRateHistory {
+    const NAME: &'static str = "DepositRateHistory";
+    const PACKAGE: &'static str = "penumbra.core.component.governance.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.core.component.governance.v1.{}", Self::NAME)
+    }
+}
 /// Governance genesis state.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -1043,6 +1486,9 @@ pub struct ChangedAppParameters {
     pub shielded_pool_params: ::core::option::Option<
         super::super::shielded_pool::v1::ShieldedPoolParameters,
     >,
+    /// Dex module parameters.
+    #[prost(message, optional, tag = "10")]
+    pub dex_params: ::core::option::Option<super::super::dex::v1::DexParameters>,
 }
 impl ::prost::Name for ChangedAppParameters {
     const NAME: &'static str = "ChangedAppParameters";
@@ -1157,19 +1603,11 @@ impl ::prost::Name for NextProposalIdResponse {
         ::prost::alloc::format!("penumbra.core.component.governance.v1.{}", Self::NAME)
     }
 }
-/// The ratio between two numbers, used in governance to describe vote thresholds and quorums.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
-pub struct Ratio {
-    /// The numerator.
-    #[prost(uint64, tag = "1")]
-    pub numerator: u64,
-    /// The denominator.
-    #[prost(uint64, tag = "2")]
-    pub denominator: u64,
-}
-impl ::prost::Name for Ratio {
-    const NAME: &'static str = "Ratio";
+pub struct ScheduledUpgradeRequest {}
+impl ::prost::Name for ScheduledUpgradeRequest {
+    const NAME: &'static str = "ScheduledUpgradeRequest";
     const PACKAGE: &'static str = "penumbra.core.component.governance.v1";
     fn full_name() -> ::prost::alloc::string::String {
         ::prost::alloc::format!("penumbra.core.component.governance.v1.{}", Self::NAME)
@@ -1177,27 +1615,28 @@ impl ::prost::Name for Ratio {
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
-pub struct EventDelegatorVote {
-    /// The delegator vote.
+pub struct ScheduledUpgradeResponse {
+    /// The scheduled upgrade, if any.
     #[prost(message, optional, tag = "1")]
-    pub vote: ::core::option::Option<DelegatorVote>,
+    pub upgrade_plan: ::core::option::Option<proposal::UpgradePlan>,
 }
-impl ::prost::Name for EventDelegatorVote {
-    const NAME: &'static str = "EventDelegatorVote";
+impl ::prost::Name for ScheduledUpgradeResponse {
+    const NAME: &'static str = "ScheduledUpgradeResponse";
     const PACKAGE: &'static str = "penumbra.core.component.governance.v1";
     fn full_name() -> ::prost::alloc::string::String {
         ::prost::alloc::format!("penumbra.core.component.governance.v1.{}", Self::NAME)
     }
 }
+/// Requests the ballot key of a proposal with sealed ballots.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
-pub struct EventProposalDepositClaim {
-    /// The deposit claim body.
-    #[prost(message, optional, tag = "1")]
-    pub deposit_claim: ::core::option::Option<ProposalDepositClaim>,
+pub struct BallotKeyRequest {
+    /// The proposal id to request information on.
+    #[prost(uint64, tag = "2")]
+    pub proposal_id: u64,
 }
-impl ::prost::Name for EventProposalDepositClaim {
-    const NAME: &'static str = "EventProposalDepositClaim";
+impl ::prost::Name for BallotKeyRequest {
+    const NAME: &'static str = "BallotKeyRequest";
     const PACKAGE: &'static str = "penumbra.core.component.governance.v1";
     fn full_name() -> ::prost::alloc::string::String {
         ::prost::alloc::format!("penumbra.core.component.governance.v1.{}", Self::NAME)
@@ -1205,27 +1644,28 @@ impl ::prost::Name for EventProposalDepositClaim {
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
-pub struct EventValidatorVote {
-    /// The validator vote.
+pub struct BallotKeyResponse {
+    /// The ballot key, if it has been established.
     #[prost(message, optional, tag = "1")]
-    pub vote: ::core::option::Option<ValidatorVote>,
+    pub ballot_key: ::core::option::Option<BallotKey>,
 }
-impl ::prost::Name for EventValidatorVote {
-    const NAME: &'static str = "EventValidatorVote";
+impl ::prost::Name for BallotKeyResponse {
+    const NAME: &'static str = "BallotKeyResponse";
     const PACKAGE: &'static str = "penumbra.core.component.governance.v1";
     fn full_name() -> ::prost::alloc::string::String {
         ::prost::alloc::format!("penumbra.core.component.governance.v1.{}", Self::NAME)
     }
 }
+/// Requests the state of the ballot key setup of a proposal with sealed ballots.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
-pub struct EventProposalWithdraw {
-    /// Details on the withdrawn proposal.
-    #[prost(message, optional, tag = "1")]
-    pub withdraw: ::core::option::Option<ProposalWithdraw>,
+pub struct BallotKeySetupRequest {
+    /// The proposal id to request information on.
+    #[prost(uint64, tag = "2")]
+    pub proposal_id: u64,
 }
-impl ::prost::Name for EventProposalWithdraw {
-    const NAME: &'static str = "EventProposalWithdraw";
+impl ::prost::Name for BallotKeySetupRequest {
+    const NAME: &'static str = "BallotKeySetupRequest";
     const PACKAGE: &'static str = "penumbra.core.component.governance.v1";
     fn full_name() -> ::prost::alloc::string::String {
         ::prost::alloc::format!("penumbra.core.component.governance.v1.{}", Self::NAME)
@@ -1233,7 +1673,126 @@ impl ::prost::Name for EventProposalWithdraw {
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
-pub struct EventProposalSubmit {
+pub struct BallotKeySetupResponse {
+    /// The validators active at the start of the proposal, ordered by identity key.
+    #[prost(message, repeated, tag = "1")]
+    pub participants: ::prost::alloc::vec::Vec<BallotKeyParticipant>,
+    /// The ballot key deals made so far.
+    #[prost(message, repeated, tag = "2")]
+    pub deals: ::prost::alloc::vec::Vec<BallotKeyDeal>,
+    /// The dealers excluded from the ballot key by a valid complaint.
+    #[prost(message, repeated, tag = "3")]
+    pub disqualified_dealers: ::prost::alloc::vec::Vec<
+        super::super::super::keys::v1::IdentityKey,
+    >,
+}
+impl ::prost::Name for BallotKeySetupResponse {
+    const NAME: &'static str = "BallotKeySetupResponse";
+    const PACKAGE: &'static str = "penumbra.core.component.governance.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.core.component.governance.v1.{}", Self::NAME)
+    }
+}
+/// A validator taking part in the ballot key setup of a proposal with sealed ballots.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct BallotKeyParticipant {
+    /// The identity key of the validator.
+    #[prost(message, optional, tag = "1")]
+    pub identity_key: ::core::option::Option<super::super::super::keys::v1::IdentityKey>,
+    /// The governance key of the validator, to which its shares are encrypted.
+    #[prost(message, optional, tag = "2")]
+    pub governance_key: ::core::option::Option<
+        super::super::super::keys::v1::GovernanceKey,
+    >,
+    /// The voting power of the validator at the start of the proposal.
+    #[prost(uint64, tag = "3")]
+    pub voting_power: u64,
+}
+impl ::prost::Name for BallotKeyParticipant {
+    const NAME: &'static str = "BallotKeyParticipant";
+    const PACKAGE: &'static str = "penumbra.core.component.governance.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.core.component.governance.v1.{}", Self::NAME)
+    }
+}
+/// The ratio between two numbers, used in governance to describe vote thresholds and quorums.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Ratio {
+    /// The numerator.
+    #[prost(uint64, tag = "1")]
+    pub numerator: u64,
+    /// The denominator.
+    #[prost(uint64, tag = "2")]
+    pub denominator: u64,
+}
+impl ::prost::Name for Ratio {
+    const NAME: &'static str = "Ratio";
+    const PACKAGE: &'static str = "penumbra.core.component.governance.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.core.component.governance.v1.{}", Self::NAME)
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct EventDelegatorVote {
+    /// The delegator vote.
+    #[prost(message, optional, tag = "1")]
+    pub vote: ::core::option::Option<DelegatorVote>,
+}
+impl ::prost::Name for EventDelegatorVote {
+    const NAME: &'static str = "EventDelegatorVote";
+    const PACKAGE: &'static str = "penumbra.core.component.governance.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.core.component.governance.v1.{}", Self::NAME)
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct EventProposalDepositClaim {
+    /// The deposit claim body.
+    #[prost(message, optional, tag = "1")]
+    pub deposit_claim: ::core::option::Option<ProposalDepositClaim>,
+}
+impl ::prost::Name for EventProposalDepositClaim {
+    const NAME: &'static str = "EventProposalDepositClaim";
+    const PACKAGE: &'static str = "penumbra.core.component.governance.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.core.component.governance.v1.{}", Self::NAME)
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct EventValidatorVote {
+    /// The validator vote.
+    #[prost(message, optional, tag = "1")]
+    pub vote: ::core::option::Option<ValidatorVote>,
+}
+impl ::prost::Name for EventValidatorVote {
+    const NAME: &'static str = "EventValidatorVote";
+    const PACKAGE: &'static str = "penumbra.core.component.governance.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.core.component.governance.v1.{}", Self::NAME)
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct EventProposalWithdraw {
+    /// Details on the withdrawn proposal.
+    #[prost(message, optional, tag = "1")]
+    pub withdraw: ::core::option::Option<ProposalWithdraw>,
+}
+impl ::prost::Name for EventProposalWithdraw {
+    const NAME: &'static str = "EventProposalWithdraw";
+    const PACKAGE: &'static str = "penumbra.core.component.governance.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.core.component.governance.v1.{}", Self::NAME)
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct EventProposalSubmit {
     /// Details on the submitted proposal.
     #[prost(message, optional, tag = "1")]
     pub submit: ::core::option::Option<ProposalSubmit>,
@@ -1287,6 +1846,106 @@ impl ::prost::Name for EventProposalSlashed {
         ::prost::alloc::format!("penumbra.core.component.governance.v1.{}", Self::NAME)
     }
 }
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct EventBallotKeyDeal {
+    /// The ballot key deal.
+    #[prost(message, optional, tag = "1")]
+    pub deal: ::core::option::Option<BallotKeyDeal>,
+}
+impl ::prost::Name for EventBallotKeyDeal {
+    const NAME: &'static str = "EventBallotKeyDeal";
+    const PACKAGE: &'static str = "penumbra.core.component.governance.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.core.component.governance.v1.{}", Self::NAME)
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct EventBallotKeyComplaint {
+    /// The ballot key complaint.
+    #[prost(message, optional, tag = "1")]
+    pub complaint: ::core::option::Option<BallotKeyComplaint>,
+}
+impl ::prost::Name for EventBallotKeyComplaint {
+    const NAME: &'static str = "EventBallotKeyComplaint";
+    const PACKAGE: &'static str = "penumbra.core.component.governance.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.core.component.governance.v1.{}", Self::NAME)
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct EventBallotKeyReveal {
+    /// The ballot key reveal.
+    #[prost(message, optional, tag = "1")]
+    pub reveal: ::core::option::Option<BallotKeyReveal>,
+}
+impl ::prost::Name for EventBallotKeyReveal {
+    const NAME: &'static str = "EventBallotKeyReveal";
+    const PACKAGE: &'static str = "penumbra.core.component.governance.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.core.component.governance.v1.{}", Self::NAME)
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct EventBallotKeyFinalized {
+    /// The proposal with sealed ballots.
+    #[prost(uint64, tag = "1")]
+    pub proposal_id: u64,
+    /// The ballot key, if enough validators dealt to form one.
+    #[prost(message, optional, tag = "2")]
+    pub ballot_key: ::core::option::Option<BallotKey>,
+}
+impl ::prost::Name for EventBallotKeyFinalized {
+    const NAME: &'static str = "EventBallotKeyFinalized";
+    const PACKAGE: &'static str = "penumbra.core.component.governance.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.core.component.governance.v1.{}", Self::NAME)
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct EventSealedBallotsOpened {
+    /// The proposal with sealed ballots.
+    #[prost(uint64, tag = "1")]
+    pub proposal_id: u64,
+    /// The number of ballots opened and counted.
+    #[prost(uint64, tag = "2")]
+    pub opened: u64,
+    /// The number of ballots which could not be counted, either because the ballot key could not be
+    /// recovered, or because they did not decrypt to a valid vote.
+    #[prost(uint64, tag = "3")]
+    pub discarded: u64,
+}
+impl ::prost::Name for EventSealedBallotsOpened {
+    const NAME: &'static str = "EventSealedBallotsOpened";
+    const PACKAGE: &'static str = "penumbra.core.component.governance.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.core.component.governance.v1.{}", Self::NAME)
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct EventValidatorAbstained {
+    /// The proposal the validator failed to vote on.
+    #[prost(uint64, tag = "1")]
+    pub proposal_id: u64,
+    /// The validator identity.
+    #[prost(message, optional, tag = "2")]
+    pub identity_key: ::core::option::Option<super::super::super::keys::v1::IdentityKey>,
+    /// The voting power of the validator at the start of the proposal.
+    #[prost(uint64, tag = "3")]
+    pub voting_power: u64,
+}
+impl ::prost::Name for EventValidatorAbstained {
+    const NAME: &'static str = "EventValidatorAbstained";
+    const PACKAGE: &'static str = "penumbra.core.component.governance.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.core.component.governance.v1.{}", Self::NAME)
+    }
+}
 /// Generated client implementations.
 #[cfg(feature = "rpc")]
 pub mod query_service_client {
@@ -1494,11 +2153,172 @@ pub mod query_service_client {
                 );
             self.inner.unary(req, path, codec).await
         }
-        pub async fn validator_votes(
+        pub async fn validator_votes(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ValidatorVotesRequest>,
+        ) -> std::result::Result<
+            tonic::Response<tonic::codec::Streaming<super::ValidatorVotesResponse>>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/penumbra.core.component.governance.v1.QueryService/ValidatorVotes",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "penumbra.core.component.governance.v1.QueryService",
+                        "ValidatorVotes",
+                    ),
+                );
+            self.inner.server_streaming(req, path, codec).await
+        }
+        pub async fn voting_power_at_proposal_start(
+            &mut self,
+            request: impl tonic::IntoRequest<super::VotingPowerAtProposalStartRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::VotingPowerAtProposalStartResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/penumbra.core.component.governance.v1.QueryService/VotingPowerAtProposalStart",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "penumbra.core.component.governance.v1.QueryService",
+                        "VotingPowerAtProposalStart",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn all_tallied_delegator_votes_for_proposal(
+            &mut self,
+            request: impl tonic::IntoRequest<
+                super::AllTalliedDelegatorVotesForProposalRequest,
+            >,
+        ) -> std::result::Result<
+            tonic::Response<
+                tonic::codec::Streaming<
+                    super::AllTalliedDelegatorVotesForProposalResponse,
+                >,
+            >,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/penumbra.core.component.governance.v1.QueryService/AllTalliedDelegatorVotesForProposal",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "penumbra.core.component.governance.v1.QueryService",
+                        "AllTalliedDelegatorVotesForProposal",
+                    ),
+                );
+            self.inner.server_streaming(req, path, codec).await
+        }
+        /// Used for computing voting power ?
+        pub async fn proposal_rate_data(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ProposalRateDataRequest>,
+        ) -> std::result::Result<
+            tonic::Response<tonic::codec::Streaming<super::ProposalRateDataResponse>>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/penumbra.core.component.governance.v1.QueryService/ProposalRateData",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "penumbra.core.component.governance.v1.QueryService",
+                        "ProposalRateData",
+                    ),
+                );
+            self.inner.server_streaming(req, path, codec).await
+        }
+        /// Streams the validators which were active at the start of a finished proposal but did not vote on it.
+        pub async fn validator_abstentions(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ValidatorAbstentionsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<
+                tonic::codec::Streaming<super::ValidatorAbstentionsResponse>,
+            >,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/penumbra.core.component.governance.v1.QueryService/ValidatorAbstentions",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "penumbra.core.component.governance.v1.QueryService",
+                        "ValidatorAbstentions",
+                    ),
+                );
+            self.inner.server_streaming(req, path, codec).await
+        }
+        /// Returns governance participation statistics for a single validator.
+        pub async fn validator_participation(
             &mut self,
-            request: impl tonic::IntoRequest<super::ValidatorVotesRequest>,
+            request: impl tonic::IntoRequest<super::ValidatorParticipationRequest>,
         ) -> std::result::Result<
-            tonic::Response<tonic::codec::Streaming<super::ValidatorVotesResponse>>,
+            tonic::Response<super::ValidatorParticipationResponse>,
             tonic::Status,
         > {
             self.inner
@@ -1512,23 +2332,24 @@ pub mod query_service_client {
                 })?;
             let codec = tonic::codec::ProstCodec::default();
             let path = http::uri::PathAndQuery::from_static(
-                "/penumbra.core.component.governance.v1.QueryService/ValidatorVotes",
+                "/penumbra.core.component.governance.v1.QueryService/ValidatorParticipation",
             );
             let mut req = request.into_request();
             req.extensions_mut()
                 .insert(
                     GrpcMethod::new(
                         "penumbra.core.component.governance.v1.QueryService",
-                        "ValidatorVotes",
+                        "ValidatorParticipation",
                     ),
                 );
-            self.inner.server_streaming(req, path, codec).await
+            self.inner.unary(req, path, codec).await
         }
-        pub async fn voting_power_at_proposal_start(
+        /// Returns the upgrade scheduled by the most recently passed upgrade plan proposal, if any.
+        pub async fn scheduled_upgrade(
             &mut self,
-            request: impl tonic::IntoRequest<super::VotingPowerAtProposalStartRequest>,
+            request: impl tonic::IntoRequest<super::ScheduledUpgradeRequest>,
         ) -> std::result::Result<
-            tonic::Response<super::VotingPowerAtProposalStartResponse>,
+            tonic::Response<super::ScheduledUpgradeResponse>,
             tonic::Status,
         > {
             self.inner
@@ -1542,29 +2363,24 @@ pub mod query_service_client {
                 })?;
             let codec = tonic::codec::ProstCodec::default();
             let path = http::uri::PathAndQuery::from_static(
-                "/penumbra.core.component.governance.v1.QueryService/VotingPowerAtProposalStart",
+                "/penumbra.core.component.governance.v1.QueryService/ScheduledUpgrade",
             );
             let mut req = request.into_request();
             req.extensions_mut()
                 .insert(
                     GrpcMethod::new(
                         "penumbra.core.component.governance.v1.QueryService",
-                        "VotingPowerAtProposalStart",
+                        "ScheduledUpgrade",
                     ),
                 );
             self.inner.unary(req, path, codec).await
         }
-        pub async fn all_tallied_delegator_votes_for_proposal(
+        /// Returns the ballot key of a proposal with sealed ballots, once it has been established.
+        pub async fn ballot_key(
             &mut self,
-            request: impl tonic::IntoRequest<
-                super::AllTalliedDelegatorVotesForProposalRequest,
-            >,
+            request: impl tonic::IntoRequest<super::BallotKeyRequest>,
         ) -> std::result::Result<
-            tonic::Response<
-                tonic::codec::Streaming<
-                    super::AllTalliedDelegatorVotesForProposalResponse,
-                >,
-            >,
+            tonic::Response<super::BallotKeyResponse>,
             tonic::Status,
         > {
             self.inner
@@ -1578,24 +2394,25 @@ pub mod query_service_client {
                 })?;
             let codec = tonic::codec::ProstCodec::default();
             let path = http::uri::PathAndQuery::from_static(
-                "/penumbra.core.component.governance.v1.QueryService/AllTalliedDelegatorVotesForProposal",
+                "/penumbra.core.component.governance.v1.QueryService/BallotKey",
             );
             let mut req = request.into_request();
             req.extensions_mut()
                 .insert(
                     GrpcMethod::new(
                         "penumbra.core.component.governance.v1.QueryService",
-                        "AllTalliedDelegatorVotesForProposal",
+                        "BallotKey",
                     ),
                 );
-            self.inner.server_streaming(req, path, codec).await
+            self.inner.unary(req, path, codec).await
         }
-        /// Used for computing voting power ?
-        pub async fn proposal_rate_data(
+        /// Returns what validators need to deal, complain about, and reveal the ballot key of a
+        /// proposal with sealed ballots.
+        pub async fn ballot_key_setup(
             &mut self,
-            request: impl tonic::IntoRequest<super::ProposalRateDataRequest>,
+            request: impl tonic::IntoRequest<super::BallotKeySetupRequest>,
         ) -> std::result::Result<
-            tonic::Response<tonic::codec::Streaming<super::ProposalRateDataResponse>>,
+            tonic::Response<super::BallotKeySetupResponse>,
             tonic::Status,
         > {
             self.inner
@@ -1609,17 +2426,17 @@ pub mod query_service_client {
                 })?;
             let codec = tonic::codec::ProstCodec::default();
             let path = http::uri::PathAndQuery::from_static(
-                "/penumbra.core.component.governance.v1.QueryService/ProposalRateData",
+                "/penumbra.core.component.governance.v1.QueryService/BallotKeySetup",
             );
             let mut req = request.into_request();
             req.extensions_mut()
                 .insert(
                     GrpcMethod::new(
                         "penumbra.core.component.governance.v1.QueryService",
-                        "ProposalRateData",
+                        "BallotKeySetup",
                     ),
                 );
-            self.inner.server_streaming(req, path, codec).await
+            self.inner.unary(req, path, codec).await
         }
     }
 }
@@ -1718,6 +2535,56 @@ pub mod query_service_server {
             tonic::Response<Self::ProposalRateDataStream>,
             tonic::Status,
         >;
+        /// Server streaming response type for the ValidatorAbstentions method.
+        type ValidatorAbstentionsStream: tonic::codegen::tokio_stream::Stream<
+                Item = std::result::Result<
+                    super::ValidatorAbstentionsResponse,
+                    tonic::Status,
+                >,
+            >
+            + Send
+            + 'static;
+        /// Streams the validators which were active at the start of a finished proposal but did not vote on it.
+        async fn validator_abstentions(
+            &self,
+            request: tonic::Request<super::ValidatorAbstentionsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<Self::ValidatorAbstentionsStream>,
+            tonic::Status,
+        >;
+        /// Returns governance participation statistics for a single validator.
+        async fn validator_participation(
+            &self,
+            request: tonic::Request<super::ValidatorParticipationRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ValidatorParticipationResponse>,
+            tonic::Status,
+        >;
+        /// Returns the upgrade scheduled by the most recently passed upgrade plan proposal, if any.
+        async fn scheduled_upgrade(
+            &self,
+            request: tonic::Request<super::ScheduledUpgradeRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ScheduledUpgradeResponse>,
+            tonic::Status,
+        >;
+        /// Returns the ballot key of a proposal with sealed ballots, once it has been established.
+        async fn ballot_key(
+            &self,
+            request: tonic::Request<super::BallotKeyRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::BallotKeyResponse>,
+            tonic::Status,
+        >;
+        /// Returns what validators need to deal, complain about, and reveal the ballot key of a
+        /// proposal with sealed ballots.
+        async fn ballot_key_setup(
+            &self,
+            request: tonic::Request<super::BallotKeySetupRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::BallotKeySetupResponse>,
+            tonic::Status,
+        >;
     }
     /// Query operations for the governance component.
     #[derive(Debug)]
@@ -2189,6 +3056,244 @@ pub mod query_service_server {
                     };
                     Box::pin(fut)
                 }
+                "/penumbra.core.component.governance.v1.QueryService/ValidatorAbstentions" => {
+                    #[allow(non_camel_case_types)]
+                    struct ValidatorAbstentionsSvc<T: QueryService>(pub Arc<T>);
+                    impl<
+                        T: QueryService,
+                    > tonic::server::ServerStreamingService<
+                        super::ValidatorAbstentionsRequest,
+                    > for ValidatorAbstentionsSvc<T> {
+                        type Response = super::ValidatorAbstentionsResponse;
+                        type ResponseStream = T::ValidatorAbstentionsStream;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::ResponseStream>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::ValidatorAbstentionsRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as QueryService>::validator_abstentions(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = ValidatorAbstentionsSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.server_streaming(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/penumbra.core.component.governance.v1.QueryService/ValidatorParticipation" => {
+                    #[allow(non_camel_case_types)]
+                    struct ValidatorParticipationSvc<T: QueryService>(pub Arc<T>);
+                    impl<
+                        T: QueryService,
+                    > tonic::server::UnaryService<super::ValidatorParticipationRequest>
+                    for ValidatorParticipationSvc<T> {
+                        type Response = super::ValidatorParticipationResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::ValidatorParticipationRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as QueryService>::validator_participation(
+                                        &inner,
+                                        request,
+                                    )
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = ValidatorParticipationSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/penumbra.core.component.governance.v1.QueryService/ScheduledUpgrade" => {
+                    #[allow(non_camel_case_types)]
+                    struct ScheduledUpgradeSvc<T: QueryService>(pub Arc<T>);
+                    impl<
+                        T: QueryService,
+                    > tonic::server::UnaryService<super::ScheduledUpgradeRequest>
+                    for ScheduledUpgradeSvc<T> {
+                        type Response = super::ScheduledUpgradeResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::ScheduledUpgradeRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as QueryService>::scheduled_upgrade(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = ScheduledUpgradeSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/penumbra.core.component.governance.v1.QueryService/BallotKey" => {
+                    #[allow(non_camel_case_types)]
+                    struct BallotKeySvc<T: QueryService>(pub Arc<T>);
+                    impl<
+                        T: QueryService,
+                    > tonic::server::UnaryService<super::BallotKeyRequest>
+                    for BallotKeySvc<T> {
+                        type Response = super::BallotKeyResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::BallotKeyRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as QueryService>::ballot_key(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = BallotKeySvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/penumbra.core.component.governance.v1.QueryService/BallotKeySetup" => {
+                    #[allow(non_camel_case_types)]
+                    struct BallotKeySetupSvc<T: QueryService>(pub Arc<T>);
+                    impl<
+                        T: QueryService,
+                    > tonic::server::UnaryService<super::BallotKeySetupRequest>
+                    for BallotKeySetupSvc<T> {
+                        type Response = super::BallotKeySetupResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::BallotKeySetupRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as QueryService>::ballot_key_setup(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = BallotKeySetupSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
                 _ => {
                     Box::pin(async move {
                         Ok(
@@ -210,7 +210,7 @@ impl<'de> serde::Deserialize<'de> for AllTalliedDelegatorVotesForProposalRespons
         deserializer.deserialize_struct("penumbra.core.component.governance.v1.AllTalliedDelegatorVotesForProposalResponse", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for ChangedAppParameters {
+impl serde::Serialize for BallotKey {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -218,102 +218,30 @@ impl serde::Serialize for ChangedAppParameters {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.sct_params.is_some() {
-            len += 1;
-        }
-        if self.community_pool_params.is_some() {
-            len += 1;
-        }
-        if self.governance_params.is_some() {
-            len += 1;
-        }
-        if self.ibc_params.is_some() {
-            len += 1;
-        }
-        if self.stake_params.is_some() {
-            len += 1;
-        }
-        if self.fee_params.is_some() {
-            len += 1;
-        }
-        if self.distributions_params.is_some() {
-            len += 1;
-        }
-        if self.funding_params.is_some() {
-            len += 1;
-        }
-        if self.shielded_pool_params.is_some() {
+        if !self.inner.is_empty() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.governance.v1.ChangedAppParameters", len)?;
-        if let Some(v) = self.sct_params.as_ref() {
-            struct_ser.serialize_field("sctParams", v)?;
-        }
-        if let Some(v) = self.community_pool_params.as_ref() {
-            struct_ser.serialize_field("communityPoolParams", v)?;
-        }
-        if let Some(v) = self.governance_params.as_ref() {
-            struct_ser.serialize_field("governanceParams", v)?;
-        }
-        if let Some(v) = self.ibc_params.as_ref() {
-            struct_ser.serialize_field("ibcParams", v)?;
-        }
-        if let Some(v) = self.stake_params.as_ref() {
-            struct_ser.serialize_field("stakeParams", v)?;
-        }
-        if let Some(v) = self.fee_params.as_ref() {
-            struct_ser.serialize_field("feeParams", v)?;
-        }
-        if let Some(v) = self.distributions_params.as_ref() {
-            struct_ser.serialize_field("distributionsParams", v)?;
-        }
-        if let Some(v) = self.funding_params.as_ref() {
-            struct_ser.serialize_field("fundingParams", v)?;
-        }
-        if let Some(v) = self.shielded_pool_params.as_ref() {
-            struct_ser.serialize_field("shieldedPoolParams", v)?;
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.governance.v1.BallotKey", len)?;
+        if !self.inner.is_empty() {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("inner", pbjson::private::base64::encode(&self.inner).as_str())?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for ChangedAppParameters {
+impl<'de> serde::Deserialize<'de> for BallotKey {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "sct_params",
-            "sctParams",
-            "community_pool_params",
-            "communityPoolParams",
-            "governance_params",
-            "governanceParams",
-            "ibc_params",
-            "ibcParams",
-            "stake_params",
-            "stakeParams",
-            "fee_params",
-            "feeParams",
-            "distributions_params",
-            "distributionsParams",
-            "funding_params",
-            "fundingParams",
-            "shielded_pool_params",
-            "shieldedPoolParams",
+            "inner",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            SctParams,
-            CommunityPoolParams,
-            GovernanceParams,
-            IbcParams,
-            StakeParams,
-            FeeParams,
-            DistributionsParams,
-            FundingParams,
-            ShieldedPoolParams,
+            Inner,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -336,15 +264,7 @@ impl<'de> serde::Deserialize<'de> for ChangedAppParameters {
                         E: serde::de::Error,
                     {
                         match value {
-                            "sctParams" | "sct_params" => Ok(GeneratedField::SctParams),
-                            "communityPoolParams" | "community_pool_params" => Ok(GeneratedField::CommunityPoolParams),
-                            "governanceParams" | "governance_params" => Ok(GeneratedField::GovernanceParams),
-                            "ibcParams" | "ibc_params" => Ok(GeneratedField::IbcParams),
-                            "stakeParams" | "stake_params" => Ok(GeneratedField::StakeParams),
-                            "feeParams" | "fee_params" => Ok(GeneratedField::FeeParams),
-                            "distributionsParams" | "distributions_params" => Ok(GeneratedField::DistributionsParams),
-                            "fundingParams" | "funding_params" => Ok(GeneratedField::FundingParams),
-                            "shieldedPoolParams" | "shielded_pool_params" => Ok(GeneratedField::ShieldedPoolParams),
+                            "inner" => Ok(GeneratedField::Inner),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -354,103 +274,41 @@ impl<'de> serde::Deserialize<'de> for ChangedAppParameters {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = ChangedAppParameters;
+            type Value = BallotKey;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.core.component.governance.v1.ChangedAppParameters")
+                formatter.write_str("struct penumbra.core.component.governance.v1.BallotKey")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ChangedAppParameters, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<BallotKey, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut sct_params__ = None;
-                let mut community_pool_params__ = None;
-                let mut governance_params__ = None;
-                let mut ibc_params__ = None;
-                let mut stake_params__ = None;
-                let mut fee_params__ = None;
-                let mut distributions_params__ = None;
-                let mut funding_params__ = None;
-                let mut shielded_pool_params__ = None;
+                let mut inner__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::SctParams => {
-                            if sct_params__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("sctParams"));
-                            }
-                            sct_params__ = map_.next_value()?;
-                        }
-                        GeneratedField::CommunityPoolParams => {
-                            if community_pool_params__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("communityPoolParams"));
-                            }
-                            community_pool_params__ = map_.next_value()?;
-                        }
-                        GeneratedField::GovernanceParams => {
-                            if governance_params__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("governanceParams"));
-                            }
-                            governance_params__ = map_.next_value()?;
-                        }
-                        GeneratedField::IbcParams => {
-                            if ibc_params__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("ibcParams"));
-                            }
-                            ibc_params__ = map_.next_value()?;
-                        }
-                        GeneratedField::StakeParams => {
-                            if stake_params__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("stakeParams"));
-                            }
-                            stake_params__ = map_.next_value()?;
-                        }
-                        GeneratedField::FeeParams => {
-                            if fee_params__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("feeParams"));
-                            }
-                            fee_params__ = map_.next_value()?;
-                        }
-                        GeneratedField::DistributionsParams => {
-                            if distributions_params__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("distributionsParams"));
-                            }
-                            distributions_params__ = map_.next_value()?;
-                        }
-                        GeneratedField::FundingParams => {
-                            if funding_params__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("fundingParams"));
-                            }
-                            funding_params__ = map_.next_value()?;
-                        }
-                        GeneratedField::ShieldedPoolParams => {
-                            if shielded_pool_params__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("shieldedPoolParams"));
+                        GeneratedField::Inner => {
+                            if inner__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("inner"));
                             }
-                            shielded_pool_params__ = map_.next_value()?;
+                            inner__ = 
+                                Some(map_.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
+                            ;
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(ChangedAppParameters {
-                    sct_params: sct_params__,
-                    community_pool_params: community_pool_params__,
-                    governance_params: governance_params__,
-                    ibc_params: ibc_params__,
-                    stake_params: stake_params__,
-                    fee_params: fee_params__,
-                    distributions_params: distributions_params__,
-                    funding_params: funding_params__,
-                    shielded_pool_params: shielded_pool_params__,
+                Ok(BallotKey {
+                    inner: inner__.unwrap_or_default(),
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.core.component.governance.v1.ChangedAppParameters", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.core.component.governance.v1.BallotKey", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for ChangedAppParametersSet {
+impl serde::Serialize for BallotKeyComplaint {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -458,37 +316,38 @@ impl serde::Serialize for ChangedAppParametersSet {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.old.is_some() {
+        if self.body.is_some() {
             len += 1;
         }
-        if self.new.is_some() {
+        if self.auth_sig.is_some() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.governance.v1.ChangedAppParametersSet", len)?;
-        if let Some(v) = self.old.as_ref() {
-            struct_ser.serialize_field("old", v)?;
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.governance.v1.BallotKeyComplaint", len)?;
+        if let Some(v) = self.body.as_ref() {
+            struct_ser.serialize_field("body", v)?;
         }
-        if let Some(v) = self.new.as_ref() {
-            struct_ser.serialize_field("new", v)?;
+        if let Some(v) = self.auth_sig.as_ref() {
+            struct_ser.serialize_field("authSig", v)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for ChangedAppParametersSet {
+impl<'de> serde::Deserialize<'de> for BallotKeyComplaint {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "old",
-            "new",
+            "body",
+            "auth_sig",
+            "authSig",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            Old,
-            New,
+            Body,
+            AuthSig,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -511,8 +370,8 @@ impl<'de> serde::Deserialize<'de> for ChangedAppParametersSet {
                         E: serde::de::Error,
                     {
                         match value {
-                            "old" => Ok(GeneratedField::Old),
-                            "new" => Ok(GeneratedField::New),
+                            "body" => Ok(GeneratedField::Body),
+                            "authSig" | "auth_sig" => Ok(GeneratedField::AuthSig),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -522,47 +381,47 @@ impl<'de> serde::Deserialize<'de> for ChangedAppParametersSet {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = ChangedAppParametersSet;
+            type Value = BallotKeyComplaint;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.core.component.governance.v1.ChangedAppParametersSet")
+                formatter.write_str("struct penumbra.core.component.governance.v1.BallotKeyComplaint")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ChangedAppParametersSet, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<BallotKeyComplaint, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut old__ = None;
-                let mut new__ = None;
+                let mut body__ = None;
+                let mut auth_sig__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::Old => {
-                            if old__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("old"));
+                        GeneratedField::Body => {
+                            if body__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("body"));
                             }
-                            old__ = map_.next_value()?;
+                            body__ = map_.next_value()?;
                         }
-                        GeneratedField::New => {
-                            if new__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("new"));
+                        GeneratedField::AuthSig => {
+                            if auth_sig__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("authSig"));
                             }
-                            new__ = map_.next_value()?;
+                            auth_sig__ = map_.next_value()?;
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(ChangedAppParametersSet {
-                    old: old__,
-                    new: new__,
+                Ok(BallotKeyComplaint {
+                    body: body__,
+                    auth_sig: auth_sig__,
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.core.component.governance.v1.ChangedAppParametersSet", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.core.component.governance.v1.BallotKeyComplaint", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for CommunityPoolDeposit {
+impl serde::Serialize for BallotKeyComplaintBody {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -570,29 +429,83 @@ impl serde::Serialize for CommunityPoolDeposit {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.value.is_some() {
+        if self.proposal != 0 {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.governance.v1.CommunityPoolDeposit", len)?;
-        if let Some(v) = self.value.as_ref() {
-            struct_ser.serialize_field("value", v)?;
+        if self.identity_key.is_some() {
+            len += 1;
         }
-        struct_ser.end()
-    }
-}
-impl<'de> serde::Deserialize<'de> for CommunityPoolDeposit {
-    #[allow(deprecated)]
-    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
-    where
-        D: serde::Deserializer<'de>,
-    {
-        const FIELDS: &[&str] = &[
-            "value",
+        if self.governance_key.is_some() {
+            len += 1;
+        }
+        if self.dealer.is_some() {
+            len += 1;
+        }
+        if self.position != 0 {
+            len += 1;
+        }
+        if !self.shared_secret.is_empty() {
+            len += 1;
+        }
+        if !self.proof.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.governance.v1.BallotKeyComplaintBody", len)?;
+        if self.proposal != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("proposal", ToString::to_string(&self.proposal).as_str())?;
+        }
+        if let Some(v) = self.identity_key.as_ref() {
+            struct_ser.serialize_field("identityKey", v)?;
+        }
+        if let Some(v) = self.governance_key.as_ref() {
+            struct_ser.serialize_field("governanceKey", v)?;
+        }
+        if let Some(v) = self.dealer.as_ref() {
+            struct_ser.serialize_field("dealer", v)?;
+        }
+        if self.position != 0 {
+            struct_ser.serialize_field("position", &self.position)?;
+        }
+        if !self.shared_secret.is_empty() {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("sharedSecret", pbjson::private::base64::encode(&self.shared_secret).as_str())?;
+        }
+        if !self.proof.is_empty() {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("proof", pbjson::private::base64::encode(&self.proof).as_str())?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for BallotKeyComplaintBody {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "proposal",
+            "identity_key",
+            "identityKey",
+            "governance_key",
+            "governanceKey",
+            "dealer",
+            "position",
+            "shared_secret",
+            "sharedSecret",
+            "proof",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            Value,
+            Proposal,
+            IdentityKey,
+            GovernanceKey,
+            Dealer,
+            Position,
+            SharedSecret,
+            Proof,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -615,7 +528,13 @@ impl<'de> serde::Deserialize<'de> for CommunityPoolDeposit {
                         E: serde::de::Error,
                     {
                         match value {
-                            "value" => Ok(GeneratedField::Value),
+                            "proposal" => Ok(GeneratedField::Proposal),
+                            "identityKey" | "identity_key" => Ok(GeneratedField::IdentityKey),
+                            "governanceKey" | "governance_key" => Ok(GeneratedField::GovernanceKey),
+                            "dealer" => Ok(GeneratedField::Dealer),
+                            "position" => Ok(GeneratedField::Position),
+                            "sharedSecret" | "shared_secret" => Ok(GeneratedField::SharedSecret),
+                            "proof" => Ok(GeneratedField::Proof),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -625,39 +544,95 @@ impl<'de> serde::Deserialize<'de> for CommunityPoolDeposit {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = CommunityPoolDeposit;
+            type Value = BallotKeyComplaintBody;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.core.component.governance.v1.CommunityPoolDeposit")
+                formatter.write_str("struct penumbra.core.component.governance.v1.BallotKeyComplaintBody")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<CommunityPoolDeposit, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<BallotKeyComplaintBody, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut value__ = None;
+                let mut proposal__ = None;
+                let mut identity_key__ = None;
+                let mut governance_key__ = None;
+                let mut dealer__ = None;
+                let mut position__ = None;
+                let mut shared_secret__ = None;
+                let mut proof__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::Value => {
-                            if value__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("value"));
+                        GeneratedField::Proposal => {
+                            if proposal__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("proposal"));
                             }
-                            value__ = map_.next_value()?;
+                            proposal__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::IdentityKey => {
+                            if identity_key__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("identityKey"));
+                            }
+                            identity_key__ = map_.next_value()?;
+                        }
+                        GeneratedField::GovernanceKey => {
+                            if governance_key__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("governanceKey"));
+                            }
+                            governance_key__ = map_.next_value()?;
+                        }
+                        GeneratedField::Dealer => {
+                            if dealer__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("dealer"));
+                            }
+                            dealer__ = map_.next_value()?;
+                        }
+                        GeneratedField::Position => {
+                            if position__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("position"));
+                            }
+                            position__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::SharedSecret => {
+                            if shared_secret__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("sharedSecret"));
+                            }
+                            shared_secret__ = 
+                                Some(map_.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::Proof => {
+                            if proof__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("proof"));
+                            }
+                            proof__ = 
+                                Some(map_.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
+                            ;
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(CommunityPoolDeposit {
-                    value: value__,
+                Ok(BallotKeyComplaintBody {
+                    proposal: proposal__.unwrap_or_default(),
+                    identity_key: identity_key__,
+                    governance_key: governance_key__,
+                    dealer: dealer__,
+                    position: position__.unwrap_or_default(),
+                    shared_secret: shared_secret__.unwrap_or_default(),
+                    proof: proof__.unwrap_or_default(),
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.core.component.governance.v1.CommunityPoolDeposit", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.core.component.governance.v1.BallotKeyComplaintBody", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for CommunityPoolOutput {
+impl serde::Serialize for BallotKeyDeal {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -665,37 +640,38 @@ impl serde::Serialize for CommunityPoolOutput {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.value.is_some() {
+        if self.body.is_some() {
             len += 1;
         }
-        if self.address.is_some() {
+        if self.auth_sig.is_some() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.governance.v1.CommunityPoolOutput", len)?;
-        if let Some(v) = self.value.as_ref() {
-            struct_ser.serialize_field("value", v)?;
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.governance.v1.BallotKeyDeal", len)?;
+        if let Some(v) = self.body.as_ref() {
+            struct_ser.serialize_field("body", v)?;
         }
-        if let Some(v) = self.address.as_ref() {
-            struct_ser.serialize_field("address", v)?;
+        if let Some(v) = self.auth_sig.as_ref() {
+            struct_ser.serialize_field("authSig", v)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for CommunityPoolOutput {
+impl<'de> serde::Deserialize<'de> for BallotKeyDeal {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "value",
-            "address",
+            "body",
+            "auth_sig",
+            "authSig",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            Value,
-            Address,
+            Body,
+            AuthSig,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -718,8 +694,8 @@ impl<'de> serde::Deserialize<'de> for CommunityPoolOutput {
                         E: serde::de::Error,
                     {
                         match value {
-                            "value" => Ok(GeneratedField::Value),
-                            "address" => Ok(GeneratedField::Address),
+                            "body" => Ok(GeneratedField::Body),
+                            "authSig" | "auth_sig" => Ok(GeneratedField::AuthSig),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -729,47 +705,47 @@ impl<'de> serde::Deserialize<'de> for CommunityPoolOutput {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = CommunityPoolOutput;
+            type Value = BallotKeyDeal;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.core.component.governance.v1.CommunityPoolOutput")
+                formatter.write_str("struct penumbra.core.component.governance.v1.BallotKeyDeal")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<CommunityPoolOutput, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<BallotKeyDeal, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut value__ = None;
-                let mut address__ = None;
+                let mut body__ = None;
+                let mut auth_sig__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::Value => {
-                            if value__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("value"));
+                        GeneratedField::Body => {
+                            if body__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("body"));
                             }
-                            value__ = map_.next_value()?;
+                            body__ = map_.next_value()?;
                         }
-                        GeneratedField::Address => {
-                            if address__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("address"));
+                        GeneratedField::AuthSig => {
+                            if auth_sig__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("authSig"));
                             }
-                            address__ = map_.next_value()?;
+                            auth_sig__ = map_.next_value()?;
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(CommunityPoolOutput {
-                    value: value__,
-                    address: address__,
+                Ok(BallotKeyDeal {
+                    body: body__,
+                    auth_sig: auth_sig__,
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.core.component.governance.v1.CommunityPoolOutput", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.core.component.governance.v1.BallotKeyDeal", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for CommunityPoolSpend {
+impl serde::Serialize for BallotKeyDealBody {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -777,29 +753,65 @@ impl serde::Serialize for CommunityPoolSpend {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.value.is_some() {
+        if self.proposal != 0 {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.governance.v1.CommunityPoolSpend", len)?;
-        if let Some(v) = self.value.as_ref() {
-            struct_ser.serialize_field("value", v)?;
+        if self.identity_key.is_some() {
+            len += 1;
+        }
+        if self.governance_key.is_some() {
+            len += 1;
+        }
+        if !self.commitments.is_empty() {
+            len += 1;
+        }
+        if !self.encrypted_shares.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.governance.v1.BallotKeyDealBody", len)?;
+        if self.proposal != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("proposal", ToString::to_string(&self.proposal).as_str())?;
+        }
+        if let Some(v) = self.identity_key.as_ref() {
+            struct_ser.serialize_field("identityKey", v)?;
+        }
+        if let Some(v) = self.governance_key.as_ref() {
+            struct_ser.serialize_field("governanceKey", v)?;
+        }
+        if !self.commitments.is_empty() {
+            struct_ser.serialize_field("commitments", &self.commitments.iter().map(pbjson::private::base64::encode).collect::<Vec<_>>())?;
+        }
+        if !self.encrypted_shares.is_empty() {
+            struct_ser.serialize_field("encryptedShares", &self.encrypted_shares)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for CommunityPoolSpend {
+impl<'de> serde::Deserialize<'de> for BallotKeyDealBody {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "value",
+            "proposal",
+            "identity_key",
+            "identityKey",
+            "governance_key",
+            "governanceKey",
+            "commitments",
+            "encrypted_shares",
+            "encryptedShares",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            Value,
+            Proposal,
+            IdentityKey,
+            GovernanceKey,
+            Commitments,
+            EncryptedShares,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -822,7 +834,11 @@ impl<'de> serde::Deserialize<'de> for CommunityPoolSpend {
                         E: serde::de::Error,
                     {
                         match value {
-                            "value" => Ok(GeneratedField::Value),
+                            "proposal" => Ok(GeneratedField::Proposal),
+                            "identityKey" | "identity_key" => Ok(GeneratedField::IdentityKey),
+                            "governanceKey" | "governance_key" => Ok(GeneratedField::GovernanceKey),
+                            "commitments" => Ok(GeneratedField::Commitments),
+                            "encryptedShares" | "encrypted_shares" => Ok(GeneratedField::EncryptedShares),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -832,39 +848,76 @@ impl<'de> serde::Deserialize<'de> for CommunityPoolSpend {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = CommunityPoolSpend;
+            type Value = BallotKeyDealBody;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.core.component.governance.v1.CommunityPoolSpend")
+                formatter.write_str("struct penumbra.core.component.governance.v1.BallotKeyDealBody")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<CommunityPoolSpend, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<BallotKeyDealBody, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut value__ = None;
+                let mut proposal__ = None;
+                let mut identity_key__ = None;
+                let mut governance_key__ = None;
+                let mut commitments__ = None;
+                let mut encrypted_shares__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::Value => {
-                            if value__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("value"));
+                        GeneratedField::Proposal => {
+                            if proposal__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("proposal"));
                             }
-                            value__ = map_.next_value()?;
+                            proposal__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::IdentityKey => {
+                            if identity_key__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("identityKey"));
+                            }
+                            identity_key__ = map_.next_value()?;
+                        }
+                        GeneratedField::GovernanceKey => {
+                            if governance_key__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("governanceKey"));
+                            }
+                            governance_key__ = map_.next_value()?;
+                        }
+                        GeneratedField::Commitments => {
+                            if commitments__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("commitments"));
+                            }
+                            commitments__ = 
+                                Some(map_.next_value::<Vec<::pbjson::private::BytesDeserialize<_>>>()?
+                                    .into_iter().map(|x| x.0).collect())
+                            ;
+                        }
+                        GeneratedField::EncryptedShares => {
+                            if encrypted_shares__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("encryptedShares"));
+                            }
+                            encrypted_shares__ = Some(map_.next_value()?);
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(CommunityPoolSpend {
-                    value: value__,
+                Ok(BallotKeyDealBody {
+                    proposal: proposal__.unwrap_or_default(),
+                    identity_key: identity_key__,
+                    governance_key: governance_key__,
+                    commitments: commitments__.unwrap_or_default(),
+                    encrypted_shares: encrypted_shares__.unwrap_or_default(),
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.core.component.governance.v1.CommunityPoolSpend", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.core.component.governance.v1.BallotKeyDealBody", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for DelegatorVote {
+impl serde::Serialize for BallotKeyParticipant {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -872,46 +925,49 @@ impl serde::Serialize for DelegatorVote {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.body.is_some() {
+        if self.identity_key.is_some() {
             len += 1;
         }
-        if self.auth_sig.is_some() {
+        if self.governance_key.is_some() {
             len += 1;
         }
-        if self.proof.is_some() {
+        if self.voting_power != 0 {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.governance.v1.DelegatorVote", len)?;
-        if let Some(v) = self.body.as_ref() {
-            struct_ser.serialize_field("body", v)?;
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.governance.v1.BallotKeyParticipant", len)?;
+        if let Some(v) = self.identity_key.as_ref() {
+            struct_ser.serialize_field("identityKey", v)?;
         }
-        if let Some(v) = self.auth_sig.as_ref() {
-            struct_ser.serialize_field("authSig", v)?;
+        if let Some(v) = self.governance_key.as_ref() {
+            struct_ser.serialize_field("governanceKey", v)?;
         }
-        if let Some(v) = self.proof.as_ref() {
-            struct_ser.serialize_field("proof", v)?;
+        if self.voting_power != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("votingPower", ToString::to_string(&self.voting_power).as_str())?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for DelegatorVote {
+impl<'de> serde::Deserialize<'de> for BallotKeyParticipant {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "body",
-            "auth_sig",
-            "authSig",
-            "proof",
+            "identity_key",
+            "identityKey",
+            "governance_key",
+            "governanceKey",
+            "voting_power",
+            "votingPower",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            Body,
-            AuthSig,
-            Proof,
+            IdentityKey,
+            GovernanceKey,
+            VotingPower,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -934,9 +990,9 @@ impl<'de> serde::Deserialize<'de> for DelegatorVote {
                         E: serde::de::Error,
                     {
                         match value {
-                            "body" => Ok(GeneratedField::Body),
-                            "authSig" | "auth_sig" => Ok(GeneratedField::AuthSig),
-                            "proof" => Ok(GeneratedField::Proof),
+                            "identityKey" | "identity_key" => Ok(GeneratedField::IdentityKey),
+                            "governanceKey" | "governance_key" => Ok(GeneratedField::GovernanceKey),
+                            "votingPower" | "voting_power" => Ok(GeneratedField::VotingPower),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -946,55 +1002,57 @@ impl<'de> serde::Deserialize<'de> for DelegatorVote {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = DelegatorVote;
+            type Value = BallotKeyParticipant;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.core.component.governance.v1.DelegatorVote")
+                formatter.write_str("struct penumbra.core.component.governance.v1.BallotKeyParticipant")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<DelegatorVote, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<BallotKeyParticipant, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut body__ = None;
-                let mut auth_sig__ = None;
-                let mut proof__ = None;
+                let mut identity_key__ = None;
+                let mut governance_key__ = None;
+                let mut voting_power__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::Body => {
-                            if body__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("body"));
+                        GeneratedField::IdentityKey => {
+                            if identity_key__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("identityKey"));
                             }
-                            body__ = map_.next_value()?;
+                            identity_key__ = map_.next_value()?;
                         }
-                        GeneratedField::AuthSig => {
-                            if auth_sig__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("authSig"));
+                        GeneratedField::GovernanceKey => {
+                            if governance_key__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("governanceKey"));
                             }
-                            auth_sig__ = map_.next_value()?;
+                            governance_key__ = map_.next_value()?;
                         }
-                        GeneratedField::Proof => {
-                            if proof__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("proof"));
+                        GeneratedField::VotingPower => {
+                            if voting_power__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("votingPower"));
                             }
-                            proof__ = map_.next_value()?;
+                            voting_power__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(DelegatorVote {
-                    body: body__,
-                    auth_sig: auth_sig__,
-                    proof: proof__,
+                Ok(BallotKeyParticipant {
+                    identity_key: identity_key__,
+                    governance_key: governance_key__,
+                    voting_power: voting_power__.unwrap_or_default(),
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.core.component.governance.v1.DelegatorVote", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.core.component.governance.v1.BallotKeyParticipant", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for DelegatorVoteBody {
+impl serde::Serialize for BallotKeyRequest {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -1002,81 +1060,31 @@ impl serde::Serialize for DelegatorVoteBody {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.proposal != 0 {
-            len += 1;
-        }
-        if self.start_position != 0 {
-            len += 1;
-        }
-        if self.vote.is_some() {
-            len += 1;
-        }
-        if self.value.is_some() {
-            len += 1;
-        }
-        if self.unbonded_amount.is_some() {
-            len += 1;
-        }
-        if self.nullifier.is_some() {
-            len += 1;
-        }
-        if self.rk.is_some() {
+        if self.proposal_id != 0 {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.governance.v1.DelegatorVoteBody", len)?;
-        if self.proposal != 0 {
-            #[allow(clippy::needless_borrow)]
-            struct_ser.serialize_field("proposal", ToString::to_string(&self.proposal).as_str())?;
-        }
-        if self.start_position != 0 {
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.governance.v1.BallotKeyRequest", len)?;
+        if self.proposal_id != 0 {
             #[allow(clippy::needless_borrow)]
-            struct_ser.serialize_field("startPosition", ToString::to_string(&self.start_position).as_str())?;
-        }
-        if let Some(v) = self.vote.as_ref() {
-            struct_ser.serialize_field("vote", v)?;
-        }
-        if let Some(v) = self.value.as_ref() {
-            struct_ser.serialize_field("value", v)?;
-        }
-        if let Some(v) = self.unbonded_amount.as_ref() {
-            struct_ser.serialize_field("unbondedAmount", v)?;
-        }
-        if let Some(v) = self.nullifier.as_ref() {
-            struct_ser.serialize_field("nullifier", v)?;
-        }
-        if let Some(v) = self.rk.as_ref() {
-            struct_ser.serialize_field("rk", v)?;
+            struct_ser.serialize_field("proposalId", ToString::to_string(&self.proposal_id).as_str())?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for DelegatorVoteBody {
+impl<'de> serde::Deserialize<'de> for BallotKeyRequest {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "proposal",
-            "start_position",
-            "startPosition",
-            "vote",
-            "value",
-            "unbonded_amount",
-            "unbondedAmount",
-            "nullifier",
-            "rk",
+            "proposal_id",
+            "proposalId",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            Proposal,
-            StartPosition,
-            Vote,
-            Value,
-            UnbondedAmount,
-            Nullifier,
-            Rk,
+            ProposalId,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -1099,13 +1107,7 @@ impl<'de> serde::Deserialize<'de> for DelegatorVoteBody {
                         E: serde::de::Error,
                     {
                         match value {
-                            "proposal" => Ok(GeneratedField::Proposal),
-                            "startPosition" | "start_position" => Ok(GeneratedField::StartPosition),
-                            "vote" => Ok(GeneratedField::Vote),
-                            "value" => Ok(GeneratedField::Value),
-                            "unbondedAmount" | "unbonded_amount" => Ok(GeneratedField::UnbondedAmount),
-                            "nullifier" => Ok(GeneratedField::Nullifier),
-                            "rk" => Ok(GeneratedField::Rk),
+                            "proposalId" | "proposal_id" => Ok(GeneratedField::ProposalId),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -1115,91 +1117,41 @@ impl<'de> serde::Deserialize<'de> for DelegatorVoteBody {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = DelegatorVoteBody;
+            type Value = BallotKeyRequest;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.core.component.governance.v1.DelegatorVoteBody")
+                formatter.write_str("struct penumbra.core.component.governance.v1.BallotKeyRequest")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<DelegatorVoteBody, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<BallotKeyRequest, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut proposal__ = None;
-                let mut start_position__ = None;
-                let mut vote__ = None;
-                let mut value__ = None;
-                let mut unbonded_amount__ = None;
-                let mut nullifier__ = None;
-                let mut rk__ = None;
+                let mut proposal_id__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::Proposal => {
-                            if proposal__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("proposal"));
-                            }
-                            proposal__ = 
-                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
-                            ;
-                        }
-                        GeneratedField::StartPosition => {
-                            if start_position__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("startPosition"));
+                        GeneratedField::ProposalId => {
+                            if proposal_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("proposalId"));
                             }
-                            start_position__ = 
+                            proposal_id__ = 
                                 Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
                             ;
                         }
-                        GeneratedField::Vote => {
-                            if vote__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("vote"));
-                            }
-                            vote__ = map_.next_value()?;
-                        }
-                        GeneratedField::Value => {
-                            if value__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("value"));
-                            }
-                            value__ = map_.next_value()?;
-                        }
-                        GeneratedField::UnbondedAmount => {
-                            if unbonded_amount__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("unbondedAmount"));
-                            }
-                            unbonded_amount__ = map_.next_value()?;
-                        }
-                        GeneratedField::Nullifier => {
-                            if nullifier__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("nullifier"));
-                            }
-                            nullifier__ = map_.next_value()?;
-                        }
-                        GeneratedField::Rk => {
-                            if rk__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("rk"));
-                            }
-                            rk__ = map_.next_value()?;
-                        }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(DelegatorVoteBody {
-                    proposal: proposal__.unwrap_or_default(),
-                    start_position: start_position__.unwrap_or_default(),
-                    vote: vote__,
-                    value: value__,
-                    unbonded_amount: unbonded_amount__,
-                    nullifier: nullifier__,
-                    rk: rk__,
+                Ok(BallotKeyRequest {
+                    proposal_id: proposal_id__.unwrap_or_default(),
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.core.component.governance.v1.DelegatorVoteBody", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.core.component.governance.v1.BallotKeyRequest", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for DelegatorVotePlan {
+impl serde::Serialize for BallotKeyResponse {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -1207,71 +1159,252 @@ impl serde::Serialize for DelegatorVotePlan {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.proposal != 0 {
-            len += 1;
-        }
-        if self.start_position != 0 {
-            len += 1;
-        }
-        if self.vote.is_some() {
-            len += 1;
-        }
-        if self.staked_note.is_some() {
-            len += 1;
-        }
-        if self.staked_note_position != 0 {
-            len += 1;
-        }
-        if self.unbonded_amount.is_some() {
+        if self.ballot_key.is_some() {
             len += 1;
         }
-        if !self.randomizer.is_empty() {
-            len += 1;
-        }
-        if !self.proof_blinding_r.is_empty() {
-            len += 1;
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.governance.v1.BallotKeyResponse", len)?;
+        if let Some(v) = self.ballot_key.as_ref() {
+            struct_ser.serialize_field("ballotKey", v)?;
         }
-        if !self.proof_blinding_s.is_empty() {
-            len += 1;
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for BallotKeyResponse {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "ballot_key",
+            "ballotKey",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            BallotKey,
+            __SkipField__,
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.governance.v1.DelegatorVotePlan", len)?;
-        if self.proposal != 0 {
-            #[allow(clippy::needless_borrow)]
-            struct_ser.serialize_field("proposal", ToString::to_string(&self.proposal).as_str())?;
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "ballotKey" | "ballot_key" => Ok(GeneratedField::BallotKey),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
         }
-        if self.start_position != 0 {
-            #[allow(clippy::needless_borrow)]
-            struct_ser.serialize_field("startPosition", ToString::to_string(&self.start_position).as_str())?;
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = BallotKeyResponse;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.governance.v1.BallotKeyResponse")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<BallotKeyResponse, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut ballot_key__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::BallotKey => {
+                            if ballot_key__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("ballotKey"));
+                            }
+                            ballot_key__ = map_.next_value()?;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(BallotKeyResponse {
+                    ballot_key: ballot_key__,
+                })
+            }
         }
-        if let Some(v) = self.vote.as_ref() {
-            struct_ser.serialize_field("vote", v)?;
+        deserializer.deserialize_struct("penumbra.core.component.governance.v1.BallotKeyResponse", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for BallotKeyReveal {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.body.is_some() {
+            len += 1;
         }
-        if let Some(v) = self.staked_note.as_ref() {
-            struct_ser.serialize_field("stakedNote", v)?;
+        if self.auth_sig.is_some() {
+            len += 1;
         }
-        if self.staked_note_position != 0 {
-            #[allow(clippy::needless_borrow)]
-            struct_ser.serialize_field("stakedNotePosition", ToString::to_string(&self.staked_note_position).as_str())?;
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.governance.v1.BallotKeyReveal", len)?;
+        if let Some(v) = self.body.as_ref() {
+            struct_ser.serialize_field("body", v)?;
         }
-        if let Some(v) = self.unbonded_amount.as_ref() {
-            struct_ser.serialize_field("unbondedAmount", v)?;
+        if let Some(v) = self.auth_sig.as_ref() {
+            struct_ser.serialize_field("authSig", v)?;
         }
-        if !self.randomizer.is_empty() {
-            #[allow(clippy::needless_borrow)]
-            struct_ser.serialize_field("randomizer", pbjson::private::base64::encode(&self.randomizer).as_str())?;
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for BallotKeyReveal {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "body",
+            "auth_sig",
+            "authSig",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Body,
+            AuthSig,
+            __SkipField__,
         }
-        if !self.proof_blinding_r.is_empty() {
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "body" => Ok(GeneratedField::Body),
+                            "authSig" | "auth_sig" => Ok(GeneratedField::AuthSig),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = BallotKeyReveal;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.governance.v1.BallotKeyReveal")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<BallotKeyReveal, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut body__ = None;
+                let mut auth_sig__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Body => {
+                            if body__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("body"));
+                            }
+                            body__ = map_.next_value()?;
+                        }
+                        GeneratedField::AuthSig => {
+                            if auth_sig__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("authSig"));
+                            }
+                            auth_sig__ = map_.next_value()?;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(BallotKeyReveal {
+                    body: body__,
+                    auth_sig: auth_sig__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.governance.v1.BallotKeyReveal", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for BallotKeyRevealBody {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.proposal != 0 {
+            len += 1;
+        }
+        if self.identity_key.is_some() {
+            len += 1;
+        }
+        if self.governance_key.is_some() {
+            len += 1;
+        }
+        if !self.dealer_secret.is_empty() {
+            len += 1;
+        }
+        if !self.shares.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.governance.v1.BallotKeyRevealBody", len)?;
+        if self.proposal != 0 {
             #[allow(clippy::needless_borrow)]
-            struct_ser.serialize_field("proofBlindingR", pbjson::private::base64::encode(&self.proof_blinding_r).as_str())?;
+            struct_ser.serialize_field("proposal", ToString::to_string(&self.proposal).as_str())?;
         }
-        if !self.proof_blinding_s.is_empty() {
+        if let Some(v) = self.identity_key.as_ref() {
+            struct_ser.serialize_field("identityKey", v)?;
+        }
+        if let Some(v) = self.governance_key.as_ref() {
+            struct_ser.serialize_field("governanceKey", v)?;
+        }
+        if !self.dealer_secret.is_empty() {
             #[allow(clippy::needless_borrow)]
-            struct_ser.serialize_field("proofBlindingS", pbjson::private::base64::encode(&self.proof_blinding_s).as_str())?;
+            struct_ser.serialize_field("dealerSecret", pbjson::private::base64::encode(&self.dealer_secret).as_str())?;
+        }
+        if !self.shares.is_empty() {
+            struct_ser.serialize_field("shares", &self.shares)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for DelegatorVotePlan {
+impl<'de> serde::Deserialize<'de> for BallotKeyRevealBody {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
@@ -1279,33 +1412,22 @@ impl<'de> serde::Deserialize<'de> for DelegatorVotePlan {
     {
         const FIELDS: &[&str] = &[
             "proposal",
-            "start_position",
-            "startPosition",
-            "vote",
-            "staked_note",
-            "stakedNote",
-            "staked_note_position",
-            "stakedNotePosition",
-            "unbonded_amount",
-            "unbondedAmount",
-            "randomizer",
-            "proof_blinding_r",
-            "proofBlindingR",
-            "proof_blinding_s",
-            "proofBlindingS",
+            "identity_key",
+            "identityKey",
+            "governance_key",
+            "governanceKey",
+            "dealer_secret",
+            "dealerSecret",
+            "shares",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
             Proposal,
-            StartPosition,
-            Vote,
-            StakedNote,
-            StakedNotePosition,
-            UnbondedAmount,
-            Randomizer,
-            ProofBlindingR,
-            ProofBlindingS,
+            IdentityKey,
+            GovernanceKey,
+            DealerSecret,
+            Shares,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -1329,14 +1451,10 @@ impl<'de> serde::Deserialize<'de> for DelegatorVotePlan {
                     {
                         match value {
                             "proposal" => Ok(GeneratedField::Proposal),
-                            "startPosition" | "start_position" => Ok(GeneratedField::StartPosition),
-                            "vote" => Ok(GeneratedField::Vote),
-                            "stakedNote" | "staked_note" => Ok(GeneratedField::StakedNote),
-                            "stakedNotePosition" | "staked_note_position" => Ok(GeneratedField::StakedNotePosition),
-                            "unbondedAmount" | "unbonded_amount" => Ok(GeneratedField::UnbondedAmount),
-                            "randomizer" => Ok(GeneratedField::Randomizer),
-                            "proofBlindingR" | "proof_blinding_r" => Ok(GeneratedField::ProofBlindingR),
-                            "proofBlindingS" | "proof_blinding_s" => Ok(GeneratedField::ProofBlindingS),
+                            "identityKey" | "identity_key" => Ok(GeneratedField::IdentityKey),
+                            "governanceKey" | "governance_key" => Ok(GeneratedField::GovernanceKey),
+                            "dealerSecret" | "dealer_secret" => Ok(GeneratedField::DealerSecret),
+                            "shares" => Ok(GeneratedField::Shares),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -1346,25 +1464,21 @@ impl<'de> serde::Deserialize<'de> for DelegatorVotePlan {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = DelegatorVotePlan;
+            type Value = BallotKeyRevealBody;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.core.component.governance.v1.DelegatorVotePlan")
+                formatter.write_str("struct penumbra.core.component.governance.v1.BallotKeyRevealBody")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<DelegatorVotePlan, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<BallotKeyRevealBody, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
                 let mut proposal__ = None;
-                let mut start_position__ = None;
-                let mut vote__ = None;
-                let mut staked_note__ = None;
-                let mut staked_note_position__ = None;
-                let mut unbonded_amount__ = None;
-                let mut randomizer__ = None;
-                let mut proof_blinding_r__ = None;
-                let mut proof_blinding_s__ = None;
+                let mut identity_key__ = None;
+                let mut governance_key__ = None;
+                let mut dealer_secret__ = None;
+                let mut shares__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
                         GeneratedField::Proposal => {
@@ -1375,86 +1489,3242 @@ impl<'de> serde::Deserialize<'de> for DelegatorVotePlan {
                                 Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
                             ;
                         }
-                        GeneratedField::StartPosition => {
-                            if start_position__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("startPosition"));
+                        GeneratedField::IdentityKey => {
+                            if identity_key__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("identityKey"));
                             }
-                            start_position__ = 
-                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
-                            ;
+                            identity_key__ = map_.next_value()?;
                         }
-                        GeneratedField::Vote => {
-                            if vote__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("vote"));
+                        GeneratedField::GovernanceKey => {
+                            if governance_key__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("governanceKey"));
                             }
-                            vote__ = map_.next_value()?;
+                            governance_key__ = map_.next_value()?;
                         }
-                        GeneratedField::StakedNote => {
-                            if staked_note__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("stakedNote"));
+                        GeneratedField::DealerSecret => {
+                            if dealer_secret__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("dealerSecret"));
                             }
-                            staked_note__ = map_.next_value()?;
+                            dealer_secret__ = 
+                                Some(map_.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
+                            ;
                         }
-                        GeneratedField::StakedNotePosition => {
-                            if staked_note_position__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("stakedNotePosition"));
+                        GeneratedField::Shares => {
+                            if shares__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("shares"));
                             }
-                            staked_note_position__ = 
+                            shares__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(BallotKeyRevealBody {
+                    proposal: proposal__.unwrap_or_default(),
+                    identity_key: identity_key__,
+                    governance_key: governance_key__,
+                    dealer_secret: dealer_secret__.unwrap_or_default(),
+                    shares: shares__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.governance.v1.BallotKeyRevealBody", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for BallotKeySetupRequest {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.proposal_id != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.governance.v1.BallotKeySetupRequest", len)?;
+        if self.proposal_id != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("proposalId", ToString::to_string(&self.proposal_id).as_str())?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for BallotKeySetupRequest {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "proposal_id",
+            "proposalId",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            ProposalId,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "proposalId" | "proposal_id" => Ok(GeneratedField::ProposalId),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = BallotKeySetupRequest;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.governance.v1.BallotKeySetupRequest")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<BallotKeySetupRequest, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut proposal_id__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::ProposalId => {
+                            if proposal_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("proposalId"));
+                            }
+                            proposal_id__ = 
                                 Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
                             ;
                         }
-                        GeneratedField::UnbondedAmount => {
-                            if unbonded_amount__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("unbondedAmount"));
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(BallotKeySetupRequest {
+                    proposal_id: proposal_id__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.governance.v1.BallotKeySetupRequest", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for BallotKeySetupResponse {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.participants.is_empty() {
+            len += 1;
+        }
+        if !self.deals.is_empty() {
+            len += 1;
+        }
+        if !self.disqualified_dealers.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.governance.v1.BallotKeySetupResponse", len)?;
+        if !self.participants.is_empty() {
+            struct_ser.serialize_field("participants", &self.participants)?;
+        }
+        if !self.deals.is_empty() {
+            struct_ser.serialize_field("deals", &self.deals)?;
+        }
+        if !self.disqualified_dealers.is_empty() {
+            struct_ser.serialize_field("disqualifiedDealers", &self.disqualified_dealers)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for BallotKeySetupResponse {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "participants",
+            "deals",
+            "disqualified_dealers",
+            "disqualifiedDealers",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Participants,
+            Deals,
+            DisqualifiedDealers,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "participants" => Ok(GeneratedField::Participants),
+                            "deals" => Ok(GeneratedField::Deals),
+                            "disqualifiedDealers" | "disqualified_dealers" => Ok(GeneratedField::DisqualifiedDealers),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = BallotKeySetupResponse;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.governance.v1.BallotKeySetupResponse")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<BallotKeySetupResponse, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut participants__ = None;
+                let mut deals__ = None;
+                let mut disqualified_dealers__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Participants => {
+                            if participants__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("participants"));
                             }
-                            unbonded_amount__ = map_.next_value()?;
+                            participants__ = Some(map_.next_value()?);
                         }
-                        GeneratedField::Randomizer => {
-                            if randomizer__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("randomizer"));
+                        GeneratedField::Deals => {
+                            if deals__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("deals"));
                             }
-                            randomizer__ = 
-                                Some(map_.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
-                            ;
+                            deals__ = Some(map_.next_value()?);
                         }
-                        GeneratedField::ProofBlindingR => {
-                            if proof_blinding_r__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("proofBlindingR"));
+                        GeneratedField::DisqualifiedDealers => {
+                            if disqualified_dealers__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("disqualifiedDealers"));
                             }
-                            proof_blinding_r__ = 
-                                Some(map_.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
-                            ;
+                            disqualified_dealers__ = Some(map_.next_value()?);
                         }
-                        GeneratedField::ProofBlindingS => {
-                            if proof_blinding_s__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("proofBlindingS"));
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(BallotKeySetupResponse {
+                    participants: participants__.unwrap_or_default(),
+                    deals: deals__.unwrap_or_default(),
+                    disqualified_dealers: disqualified_dealers__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.governance.v1.BallotKeySetupResponse", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for ChangedAppParameters {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.sct_params.is_some() {
+            len += 1;
+        }
+        if self.community_pool_params.is_some() {
+            len += 1;
+        }
+        if self.governance_params.is_some() {
+            len += 1;
+        }
+        if self.ibc_params.is_some() {
+            len += 1;
+        }
+        if self.stake_params.is_some() {
+            len += 1;
+        }
+        if self.fee_params.is_some() {
+            len += 1;
+        }
+        if self.distributions_params.is_some() {
+            len += 1;
+        }
+        if self.funding_params.is_some() {
+            len += 1;
+        }
+        if self.shielded_pool_params.is_some() {
+            len += 1;
+        }
+        if self.dex_params.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.governance.v1.ChangedAppParameters", len)?;
+        if let Some(v) = self.sct_params.as_ref() {
+            struct_ser.serialize_field("sctParams", v)?;
+        }
+        if let Some(v) = self.community_pool_params.as_ref() {
+            struct_ser.serialize_field("communityPoolParams", v)?;
+        }
+        if let Some(v) = self.governance_params.as_ref() {
+            struct_ser.serialize_field("governanceParams", v)?;
+        }
+        if let Some(v) = self.ibc_params.as_ref() {
+            struct_ser.serialize_field("ibcParams", v)?;
+        }
+        if let Some(v) = self.stake_params.as_ref() {
+            struct_ser.serialize_field("stakeParams", v)?;
+        }
+        if let Some(v) = self.fee_params.as_ref() {
+            struct_ser.serialize_field("feeParams", v)?;
+        }
+        if let Some(v) = self.distributions_params.as_ref() {
+            struct_ser.serialize_field("distributionsParams", v)?;
+        }
+        if let Some(v) = self.funding_params.as_ref() {
+            struct_ser.serialize_field("fundingParams", v)?;
+        }
+        if let Some(v) = self.shielded_pool_params.as_ref() {
+            struct_ser.serialize_field("shieldedPoolParams", v)?;
+        }
+        if let Some(v) = self.dex_params.as_ref() {
+            struct_ser.serialize_field("dexParams", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for ChangedAppParameters {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "sct_params",
+            "sctParams",
+            "community_pool_params",
+            "communityPoolParams",
+            "governance_params",
+            "governanceParams",
+            "ibc_params",
+            "ibcParams",
+            "stake_params",
+            "stakeParams",
+            "fee_params",
+            "feeParams",
+            "distributions_params",
+            "distributionsParams",
+            "funding_params",
+            "fundingParams",
+            "shielded_pool_params",
+            "shieldedPoolParams",
+            "dex_params",
+            "dexParams",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            SctParams,
+            CommunityPoolParams,
+            GovernanceParams,
+            IbcParams,
+            StakeParams,
+            FeeParams,
+            DistributionsParams,
+            FundingParams,
+            ShieldedPoolParams,
+            DexParams,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "sctParams" | "sct_params" => Ok(GeneratedField::SctParams),
+                            "communityPoolParams" | "community_pool_params" => Ok(GeneratedField::CommunityPoolParams),
+                            "governanceParams" | "governance_params" => Ok(GeneratedField::GovernanceParams),
+                            "ibcParams" | "ibc_params" => Ok(GeneratedField::IbcParams),
+                            "stakeParams" | "stake_params" => Ok(GeneratedField::StakeParams),
+                            "feeParams" | "fee_params" => Ok(GeneratedField::FeeParams),
+                            "distributionsParams" | "distributions_params" => Ok(GeneratedField::DistributionsParams),
+                            "fundingParams" | "funding_params" => Ok(GeneratedField::FundingParams),
+                            "shieldedPoolParams" | "shielded_pool_params" => Ok(GeneratedField::ShieldedPoolParams),
+                            "dexParams" | "dex_params" => Ok(GeneratedField::DexParams),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = ChangedAppParameters;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.governance.v1.ChangedAppParameters")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ChangedAppParameters, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut sct_params__ = None;
+                let mut community_pool_params__ = None;
+                let mut governance_params__ = None;
+                let mut ibc_params__ = None;
+                let mut stake_params__ = None;
+                let mut fee_params__ = None;
+                let mut distributions_params__ = None;
+                let mut funding_params__ = None;
+                let mut shielded_pool_params__ = None;
+                let mut dex_params__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::SctParams => {
+                            if sct_params__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("sctParams"));
+                            }
+                            sct_params__ = map_.next_value()?;
+                        }
+                        GeneratedField::CommunityPoolParams => {
+                            if community_pool_params__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("communityPoolParams"));
+                            }
+                            community_pool_params__ = map_.next_value()?;
+                        }
+                        GeneratedField::GovernanceParams => {
+                            if governance_params__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("governanceParams"));
+                            }
+                            governance_params__ = map_.next_value()?;
+                        }
+                        GeneratedField::IbcParams => {
+                            if ibc_params__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("ibcParams"));
+                            }
+                            ibc_params__ = map_.next_value()?;
+                        }
+                        GeneratedField::StakeParams => {
+                            if stake_params__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("stakeParams"));
+                            }
+                            stake_params__ = map_.next_value()?;
+                        }
+                        GeneratedField::FeeParams => {
+                            if fee_params__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("feeParams"));
+                            }
+                            fee_params__ = map_.next_value()?;
+                        }
+                        GeneratedField::DistributionsParams => {
+                            if distributions_params__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("distributionsParams"));
+                            }
+                            distributions_params__ = map_.next_value()?;
+                        }
+                        GeneratedField::FundingParams => {
+                            if funding_params__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("fundingParams"));
+                            }
+                            funding_params__ = map_.next_value()?;
+                        }
+                        GeneratedField::ShieldedPoolParams => {
+                            if shielded_pool_params__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("shieldedPoolParams"));
+                            }
+                            shielded_pool_params__ = map_.next_value()?;
+                        }
+                        GeneratedField::DexParams => {
+                            if dex_params__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("dexParams"));
+                            }
+                            dex_params__ = map_.next_value()?;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(ChangedAppParameters {
+                    sct_params: sct_params__,
+                    community_pool_params: community_pool_params__,
+                    governance_params: governance_params__,
+                    ibc_params: ibc_params__,
+                    stake_params: stake_params__,
+                    fee_params: fee_params__,
+                    distributions_params: distributions_params__,
+                    funding_params: funding_params__,
+                    shielded_pool_params: shielded_pool_params__,
+                    dex_params: dex_params__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.governance.v1.ChangedAppParameters", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for ChangedAppParametersSet {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.old.is_some() {
+            len += 1;
+        }
+        if self.new.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.governance.v1.ChangedAppParametersSet", len)?;
+        if let Some(v) = self.old.as_ref() {
+            struct_ser.serialize_field("old", v)?;
+        }
+        if let Some(v) = self.new.as_ref() {
+            struct_ser.serialize_field("new", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for ChangedAppParametersSet {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "old",
+            "new",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Old,
+            New,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "old" => Ok(GeneratedField::Old),
+                            "new" => Ok(GeneratedField::New),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = ChangedAppParametersSet;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.governance.v1.ChangedAppParametersSet")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ChangedAppParametersSet, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut old__ = None;
+                let mut new__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Old => {
+                            if old__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("old"));
+                            }
+                            old__ = map_.next_value()?;
+                        }
+                        GeneratedField::New => {
+                            if new__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("new"));
+                            }
+                            new__ = map_.next_value()?;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(ChangedAppParametersSet {
+                    old: old__,
+                    new: new__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.governance.v1.ChangedAppParametersSet", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for CommunityPoolDeposit {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.value.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.governance.v1.CommunityPoolDeposit", len)?;
+        if let Some(v) = self.value.as_ref() {
+            struct_ser.serialize_field("value", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for CommunityPoolDeposit {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "value",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Value,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "value" => Ok(GeneratedField::Value),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = CommunityPoolDeposit;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.governance.v1.CommunityPoolDeposit")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<CommunityPoolDeposit, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut value__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Value => {
+                            if value__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("value"));
+                            }
+                            value__ = map_.next_value()?;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(CommunityPoolDeposit {
+                    value: value__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.governance.v1.CommunityPoolDeposit", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for CommunityPoolOutput {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.value.is_some() {
+            len += 1;
+        }
+        if self.address.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.governance.v1.CommunityPoolOutput", len)?;
+        if let Some(v) = self.value.as_ref() {
+            struct_ser.serialize_field("value", v)?;
+        }
+        if let Some(v) = self.address.as_ref() {
+            struct_ser.serialize_field("address", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for CommunityPoolOutput {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "value",
+            "address",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Value,
+            Address,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "value" => Ok(GeneratedField::Value),
+                            "address" => Ok(GeneratedField::Address),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = CommunityPoolOutput;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.governance.v1.CommunityPoolOutput")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<CommunityPoolOutput, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut value__ = None;
+                let mut address__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Value => {
+                            if value__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("value"));
+                            }
+                            value__ = map_.next_value()?;
+                        }
+                        GeneratedField::Address => {
+                            if address__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("address"));
+                            }
+                            address__ = map_.next_value()?;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(CommunityPoolOutput {
+                    value: value__,
+                    address: address__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.governance.v1.CommunityPoolOutput", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for CommunityPoolSpend {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.value.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.governance.v1.CommunityPoolSpend", len)?;
+        if let Some(v) = self.value.as_ref() {
+            struct_ser.serialize_field("value", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for CommunityPoolSpend {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "value",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Value,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "value" => Ok(GeneratedField::Value),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = CommunityPoolSpend;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.governance.v1.CommunityPoolSpend")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<CommunityPoolSpend, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut value__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Value => {
+                            if value__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("value"));
+                            }
+                            value__ = map_.next_value()?;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(CommunityPoolSpend {
+                    value: value__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.governance.v1.CommunityPoolSpend", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for DelegatorVote {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.body.is_some() {
+            len += 1;
+        }
+        if self.auth_sig.is_some() {
+            len += 1;
+        }
+        if self.proof.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.governance.v1.DelegatorVote", len)?;
+        if let Some(v) = self.body.as_ref() {
+            struct_ser.serialize_field("body", v)?;
+        }
+        if let Some(v) = self.auth_sig.as_ref() {
+            struct_ser.serialize_field("authSig", v)?;
+        }
+        if let Some(v) = self.proof.as_ref() {
+            struct_ser.serialize_field("proof", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for DelegatorVote {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "body",
+            "auth_sig",
+            "authSig",
+            "proof",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Body,
+            AuthSig,
+            Proof,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "body" => Ok(GeneratedField::Body),
+                            "authSig" | "auth_sig" => Ok(GeneratedField::AuthSig),
+                            "proof" => Ok(GeneratedField::Proof),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = DelegatorVote;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.governance.v1.DelegatorVote")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<DelegatorVote, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut body__ = None;
+                let mut auth_sig__ = None;
+                let mut proof__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Body => {
+                            if body__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("body"));
+                            }
+                            body__ = map_.next_value()?;
+                        }
+                        GeneratedField::AuthSig => {
+                            if auth_sig__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("authSig"));
+                            }
+                            auth_sig__ = map_.next_value()?;
+                        }
+                        GeneratedField::Proof => {
+                            if proof__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("proof"));
+                            }
+                            proof__ = map_.next_value()?;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(DelegatorVote {
+                    body: body__,
+                    auth_sig: auth_sig__,
+                    proof: proof__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.governance.v1.DelegatorVote", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for DelegatorVoteBody {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.proposal != 0 {
+            len += 1;
+        }
+        if self.start_position != 0 {
+            len += 1;
+        }
+        if self.vote.is_some() {
+            len += 1;
+        }
+        if self.value.is_some() {
+            len += 1;
+        }
+        if self.unbonded_amount.is_some() {
+            len += 1;
+        }
+        if self.nullifier.is_some() {
+            len += 1;
+        }
+        if self.rk.is_some() {
+            len += 1;
+        }
+        if self.encrypted_vote.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.governance.v1.DelegatorVoteBody", len)?;
+        if self.proposal != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("proposal", ToString::to_string(&self.proposal).as_str())?;
+        }
+        if self.start_position != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("startPosition", ToString::to_string(&self.start_position).as_str())?;
+        }
+        if let Some(v) = self.vote.as_ref() {
+            struct_ser.serialize_field("vote", v)?;
+        }
+        if let Some(v) = self.value.as_ref() {
+            struct_ser.serialize_field("value", v)?;
+        }
+        if let Some(v) = self.unbonded_amount.as_ref() {
+            struct_ser.serialize_field("unbondedAmount", v)?;
+        }
+        if let Some(v) = self.nullifier.as_ref() {
+            struct_ser.serialize_field("nullifier", v)?;
+        }
+        if let Some(v) = self.rk.as_ref() {
+            struct_ser.serialize_field("rk", v)?;
+        }
+        if let Some(v) = self.encrypted_vote.as_ref() {
+            struct_ser.serialize_field("encryptedVote", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for DelegatorVoteBody {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "proposal",
+            "start_position",
+            "startPosition",
+            "vote",
+            "value",
+            "unbonded_amount",
+            "unbondedAmount",
+            "nullifier",
+            "rk",
+            "encrypted_vote",
+            "encryptedVote",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Proposal,
+            StartPosition,
+            Vote,
+            Value,
+            UnbondedAmount,
+            Nullifier,
+            Rk,
+            EncryptedVote,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "proposal" => Ok(GeneratedField::Proposal),
+                            "startPosition" | "start_position" => Ok(GeneratedField::StartPosition),
+                            "vote" => Ok(GeneratedField::Vote),
+                            "value" => Ok(GeneratedField::Value),
+                            "unbondedAmount" | "unbonded_amount" => Ok(GeneratedField::UnbondedAmount),
+                            "nullifier" => Ok(GeneratedField::Nullifier),
+                            "rk" => Ok(GeneratedField::Rk),
+                            "encryptedVote" | "encrypted_vote" => Ok(GeneratedField::EncryptedVote),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = DelegatorVoteBody;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.governance.v1.DelegatorVoteBody")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<DelegatorVoteBody, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut proposal__ = None;
+                let mut start_position__ = None;
+                let mut vote__ = None;
+                let mut value__ = None;
+                let mut unbonded_amount__ = None;
+                let mut nullifier__ = None;
+                let mut rk__ = None;
+                let mut encrypted_vote__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Proposal => {
+                            if proposal__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("proposal"));
+                            }
+                            proposal__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::StartPosition => {
+                            if start_position__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("startPosition"));
+                            }
+                            start_position__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::Vote => {
+                            if vote__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("vote"));
+                            }
+                            vote__ = map_.next_value()?;
+                        }
+                        GeneratedField::Value => {
+                            if value__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("value"));
+                            }
+                            value__ = map_.next_value()?;
+                        }
+                        GeneratedField::UnbondedAmount => {
+                            if unbonded_amount__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("unbondedAmount"));
+                            }
+                            unbonded_amount__ = map_.next_value()?;
+                        }
+                        GeneratedField::Nullifier => {
+                            if nullifier__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("nullifier"));
+                            }
+                            nullifier__ = map_.next_value()?;
+                        }
+                        GeneratedField::Rk => {
+                            if rk__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("rk"));
+                            }
+                            rk__ = map_.next_value()?;
+                        }
+                        GeneratedField::EncryptedVote => {
+                            if encrypted_vote__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("encryptedVote"));
+                            }
+                            encrypted_vote__ = map_.next_value()?;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(DelegatorVoteBody {
+                    proposal: proposal__.unwrap_or_default(),
+                    start_position: start_position__.unwrap_or_default(),
+                    vote: vote__,
+                    value: value__,
+                    unbonded_amount: unbonded_amount__,
+                    nullifier: nullifier__,
+                    rk: rk__,
+                    encrypted_vote: encrypted_vote__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.governance.v1.DelegatorVoteBody", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for DelegatorVotePlan {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.proposal != 0 {
+            len += 1;
+        }
+        if self.start_position != 0 {
+            len += 1;
+        }
+        if self.vote.is_some() {
+            len += 1;
+        }
+        if self.staked_note.is_some() {
+            len += 1;
+        }
+        if self.staked_note_position != 0 {
+            len += 1;
+        }
+        if self.unbonded_amount.is_some() {
+            len += 1;
+        }
+        if !self.randomizer.is_empty() {
+            len += 1;
+        }
+        if !self.proof_blinding_r.is_empty() {
+            len += 1;
+        }
+        if !self.proof_blinding_s.is_empty() {
+            len += 1;
+        }
+        if self.encrypted_vote.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.governance.v1.DelegatorVotePlan", len)?;
+        if self.proposal != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("proposal", ToString::to_string(&self.proposal).as_str())?;
+        }
+        if self.start_position != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("startPosition", ToString::to_string(&self.start_position).as_str())?;
+        }
+        if let Some(v) = self.vote.as_ref() {
+            struct_ser.serialize_field("vote", v)?;
+        }
+        if let Some(v) = self.staked_note.as_ref() {
+            struct_ser.serialize_field("stakedNote", v)?;
+        }
+        if self.staked_note_position != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("stakedNotePosition", ToString::to_string(&self.staked_note_position).as_str())?;
+        }
+        if let Some(v) = self.unbonded_amount.as_ref() {
+            struct_ser.serialize_field("unbondedAmount", v)?;
+        }
+        if !self.randomizer.is_empty() {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("randomizer", pbjson::private::base64::encode(&self.randomizer).as_str())?;
+        }
+        if !self.proof_blinding_r.is_empty() {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("proofBlindingR", pbjson::private::base64::encode(&self.proof_blinding_r).as_str())?;
+        }
+        if !self.proof_blinding_s.is_empty() {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("proofBlindingS", pbjson::private::base64::encode(&self.proof_blinding_s).as_str())?;
+        }
+        if let Some(v) = self.encrypted_vote.as_ref() {
+            struct_ser.serialize_field("encryptedVote", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for DelegatorVotePlan {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "proposal",
+            "start_position",
+            "startPosition",
+            "vote",
+            "staked_note",
+            "stakedNote",
+            "staked_note_position",
+            "stakedNotePosition",
+            "unbonded_amount",
+            "unbondedAmount",
+            "randomizer",
+            "proof_blinding_r",
+            "proofBlindingR",
+            "proof_blinding_s",
+            "proofBlindingS",
+            "encrypted_vote",
+            "encryptedVote",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Proposal,
+            StartPosition,
+            Vote,
+            StakedNote,
+            StakedNotePosition,
+            UnbondedAmount,
+            Randomizer,
+            ProofBlindingR,
+            ProofBlindingS,
+            EncryptedVote,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "proposal" => Ok(GeneratedField::Proposal),
+                            "startPosition" | "start_position" => Ok(GeneratedField::StartPosition),
+                            "vote" => Ok(GeneratedField::Vote),
+                            "stakedNote" | "staked_note" => Ok(GeneratedField::StakedNote),
+                            "stakedNotePosition" | "staked_note_position" => Ok(GeneratedField::StakedNotePosition),
+                            "unbondedAmount" | "unbonded_amount" => Ok(GeneratedField::UnbondedAmount),
+                            "randomizer" => Ok(GeneratedField::Randomizer),
+                            "proofBlindingR" | "proof_blinding_r" => Ok(GeneratedField::ProofBlindingR),
+                            "proofBlindingS" | "proof_blinding_s" => Ok(GeneratedField::ProofBlindingS),
+                            "encryptedVote" | "encrypted_vote" => Ok(GeneratedField::EncryptedVote),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = DelegatorVotePlan;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.governance.v1.DelegatorVotePlan")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<DelegatorVotePlan, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut proposal__ = None;
+                let mut start_position__ = None;
+                let mut vote__ = None;
+                let mut staked_note__ = None;
+                let mut staked_note_position__ = None;
+                let mut unbonded_amount__ = None;
+                let mut randomizer__ = None;
+                let mut proof_blinding_r__ = None;
+                let mut proof_blinding_s__ = None;
+                let mut encrypted_vote__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Proposal => {
+                            if proposal__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("proposal"));
+                            }
+                            proposal__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::StartPosition => {
+                            if start_position__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("startPosition"));
+                            }
+                            start_position__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::Vote => {
+                            if vote__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("vote"));
+                            }
+                            vote__ = map_.next_value()?;
+                        }
+                        GeneratedField::StakedNote => {
+                            if staked_note__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("stakedNote"));
+                            }
+                            staked_note__ = map_.next_value()?;
+                        }
+                        GeneratedField::StakedNotePosition => {
+                            if staked_note_position__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("stakedNotePosition"));
+                            }
+                            staked_note_position__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::UnbondedAmount => {
+                            if unbonded_amount__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("unbondedAmount"));
+                            }
+                            unbonded_amount__ = map_.next_value()?;
+                        }
+                        GeneratedField::Randomizer => {
+                            if randomizer__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("randomizer"));
+                            }
+                            randomizer__ = 
+                                Some(map_.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::ProofBlindingR => {
+                            if proof_blinding_r__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("proofBlindingR"));
+                            }
+                            proof_blinding_r__ = 
+                                Some(map_.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::ProofBlindingS => {
+                            if proof_blinding_s__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("proofBlindingS"));
+                            }
+                            proof_blinding_s__ = 
+                                Some(map_.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::EncryptedVote => {
+                            if encrypted_vote__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("encryptedVote"));
+                            }
+                            encrypted_vote__ = map_.next_value()?;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(DelegatorVotePlan {
+                    proposal: proposal__.unwrap_or_default(),
+                    start_position: start_position__.unwrap_or_default(),
+                    vote: vote__,
+                    staked_note: staked_note__,
+                    staked_note_position: staked_note_position__.unwrap_or_default(),
+                    unbonded_amount: unbonded_amount__,
+                    randomizer: randomizer__.unwrap_or_default(),
+                    proof_blinding_r: proof_blinding_r__.unwrap_or_default(),
+                    proof_blinding_s: proof_blinding_s__.unwrap_or_default(),
+                    encrypted_vote: encrypted_vote__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.governance.v1.DelegatorVotePlan", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for DelegatorVoteView {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.delegator_vote.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.governance.v1.DelegatorVoteView", len)?;
+        if let Some(v) = self.delegator_vote.as_ref() {
+            match v {
+                delegator_vote_view::DelegatorVote::Visible(v) => {
+                    struct_ser.serialize_field("visible", v)?;
+                }
+                delegator_vote_view::DelegatorVote::Opaque(v) => {
+                    struct_ser.serialize_field("opaque", v)?;
+                }
+            }
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for DelegatorVoteView {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "visible",
+            "opaque",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Visible,
+            Opaque,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "visible" => Ok(GeneratedField::Visible),
+                            "opaque" => Ok(GeneratedField::Opaque),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = DelegatorVoteView;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.governance.v1.DelegatorVoteView")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<DelegatorVoteView, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut delegator_vote__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Visible => {
+                            if delegator_vote__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("visible"));
+                            }
+                            delegator_vote__ = map_.next_value::<::std::option::Option<_>>()?.map(delegator_vote_view::DelegatorVote::Visible)
+;
+                        }
+                        GeneratedField::Opaque => {
+                            if delegator_vote__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("opaque"));
+                            }
+                            delegator_vote__ = map_.next_value::<::std::option::Option<_>>()?.map(delegator_vote_view::DelegatorVote::Opaque)
+;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(DelegatorVoteView {
+                    delegator_vote: delegator_vote__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.governance.v1.DelegatorVoteView", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for delegator_vote_view::Opaque {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.delegator_vote.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.governance.v1.DelegatorVoteView.Opaque", len)?;
+        if let Some(v) = self.delegator_vote.as_ref() {
+            struct_ser.serialize_field("delegatorVote", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for delegator_vote_view::Opaque {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "delegator_vote",
+            "delegatorVote",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            DelegatorVote,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "delegatorVote" | "delegator_vote" => Ok(GeneratedField::DelegatorVote),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = delegator_vote_view::Opaque;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.governance.v1.DelegatorVoteView.Opaque")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<delegator_vote_view::Opaque, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut delegator_vote__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::DelegatorVote => {
+                            if delegator_vote__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("delegatorVote"));
+                            }
+                            delegator_vote__ = map_.next_value()?;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(delegator_vote_view::Opaque {
+                    delegator_vote: delegator_vote__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.governance.v1.DelegatorVoteView.Opaque", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for delegator_vote_view::Visible {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.delegator_vote.is_some() {
+            len += 1;
+        }
+        if self.note.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.governance.v1.DelegatorVoteView.Visible", len)?;
+        if let Some(v) = self.delegator_vote.as_ref() {
+            struct_ser.serialize_field("delegatorVote", v)?;
+        }
+        if let Some(v) = self.note.as_ref() {
+            struct_ser.serialize_field("note", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for delegator_vote_view::Visible {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "delegator_vote",
+            "delegatorVote",
+            "note",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            DelegatorVote,
+            Note,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "delegatorVote" | "delegator_vote" => Ok(GeneratedField::DelegatorVote),
+                            "note" => Ok(GeneratedField::Note),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = delegator_vote_view::Visible;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.governance.v1.DelegatorVoteView.Visible")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<delegator_vote_view::Visible, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut delegator_vote__ = None;
+                let mut note__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::DelegatorVote => {
+                            if delegator_vote__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("delegatorVote"));
+                            }
+                            delegator_vote__ = map_.next_value()?;
+                        }
+                        GeneratedField::Note => {
+                            if note__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("note"));
+                            }
+                            note__ = map_.next_value()?;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(delegator_vote_view::Visible {
+                    delegator_vote: delegator_vote__,
+                    note: note__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.governance.v1.DelegatorVoteView.Visible", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for DepositRate {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.input.is_some() {
+            len += 1;
+        }
+        if self.output.is_some() {
+            len += 1;
+        }
+        if self.height != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.governance.v1.DepositRate", len)?;
+        if let Some(v) = self.input.as_ref() {
+            struct_ser.serialize_field("input", v)?;
+        }
+        if let Some(v) = self.output.as_ref() {
+            struct_ser.serialize_field("output", v)?;
+        }
+        if self.height != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("height", ToString::to_string(&self.height).as_str())?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for DepositRate {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "input",
+            "output",
+            "height",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Input,
+            Output,
+            Height,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "input" => Ok(GeneratedField::Input),
+                            "output" => Ok(GeneratedField::Output),
+                            "height" => Ok(GeneratedField::Height),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = DepositRate;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.governance.v1.DepositRate")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<DepositRate, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut input__ = None;
+                let mut output__ = None;
+                let mut height__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Input => {
+                            if input__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("input"));
+                            }
+                            input__ = map_.next_value()?;
+                        }
+                        GeneratedField::Output => {
+                            if output__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("output"));
+                            }
+                            output__ = map_.next_value()?;
+                        }
+                        GeneratedField::Height => {
+                            if height__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("height"));
+                            }
+                            height__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(DepositRate {
+                    input: input__,
+                    output: output__,
+                    height: height__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.governance.v1.DepositRate", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for DepositRateHistory {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.rates.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.governance.v1.DepositRateHistory", len)?;
+        if !self.rates.is_empty() {
+            struct_ser.serialize_field("rates", &self.rates)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for DepositRateHistory {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "rates",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Rates,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "rates" => Ok(GeneratedField::Rates),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = DepositRateHistory;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.governance.v1.DepositRateHistory")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<DepositRateHistory, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut rates__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Rates => {
+                            if rates__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("rates"));
+                            }
+                            rates__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(DepositRateHistory {
+                    rates: rates__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.governance.v1.DepositRateHistory", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for EncryptedBallotKeyShare {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.ephemeral_key.is_empty() {
+            len += 1;
+        }
+        if !self.ciphertext.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.governance.v1.EncryptedBallotKeyShare", len)?;
+        if !self.ephemeral_key.is_empty() {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("ephemeralKey", pbjson::private::base64::encode(&self.ephemeral_key).as_str())?;
+        }
+        if !self.ciphertext.is_empty() {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("ciphertext", pbjson::private::base64::encode(&self.ciphertext).as_str())?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for EncryptedBallotKeyShare {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "ephemeral_key",
+            "ephemeralKey",
+            "ciphertext",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            EphemeralKey,
+            Ciphertext,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "ephemeralKey" | "ephemeral_key" => Ok(GeneratedField::EphemeralKey),
+                            "ciphertext" => Ok(GeneratedField::Ciphertext),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = EncryptedBallotKeyShare;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.governance.v1.EncryptedBallotKeyShare")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<EncryptedBallotKeyShare, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut ephemeral_key__ = None;
+                let mut ciphertext__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::EphemeralKey => {
+                            if ephemeral_key__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("ephemeralKey"));
+                            }
+                            ephemeral_key__ = 
+                                Some(map_.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::Ciphertext => {
+                            if ciphertext__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("ciphertext"));
+                            }
+                            ciphertext__ = 
+                                Some(map_.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(EncryptedBallotKeyShare {
+                    ephemeral_key: ephemeral_key__.unwrap_or_default(),
+                    ciphertext: ciphertext__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.governance.v1.EncryptedBallotKeyShare", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for EncryptedVote {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.ephemeral_key.is_empty() {
+            len += 1;
+        }
+        if !self.ciphertext.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.governance.v1.EncryptedVote", len)?;
+        if !self.ephemeral_key.is_empty() {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("ephemeralKey", pbjson::private::base64::encode(&self.ephemeral_key).as_str())?;
+        }
+        if !self.ciphertext.is_empty() {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("ciphertext", pbjson::private::base64::encode(&self.ciphertext).as_str())?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for EncryptedVote {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "ephemeral_key",
+            "ephemeralKey",
+            "ciphertext",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            EphemeralKey,
+            Ciphertext,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "ephemeralKey" | "ephemeral_key" => Ok(GeneratedField::EphemeralKey),
+                            "ciphertext" => Ok(GeneratedField::Ciphertext),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = EncryptedVote;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.governance.v1.EncryptedVote")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<EncryptedVote, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut ephemeral_key__ = None;
+                let mut ciphertext__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::EphemeralKey => {
+                            if ephemeral_key__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("ephemeralKey"));
+                            }
+                            ephemeral_key__ = 
+                                Some(map_.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::Ciphertext => {
+                            if ciphertext__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("ciphertext"));
+                            }
+                            ciphertext__ = 
+                                Some(map_.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(EncryptedVote {
+                    ephemeral_key: ephemeral_key__.unwrap_or_default(),
+                    ciphertext: ciphertext__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.governance.v1.EncryptedVote", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for EventBallotKeyComplaint {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.complaint.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.governance.v1.EventBallotKeyComplaint", len)?;
+        if let Some(v) = self.complaint.as_ref() {
+            struct_ser.serialize_field("complaint", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for EventBallotKeyComplaint {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "complaint",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Complaint,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "complaint" => Ok(GeneratedField::Complaint),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = EventBallotKeyComplaint;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.governance.v1.EventBallotKeyComplaint")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<EventBallotKeyComplaint, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut complaint__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Complaint => {
+                            if complaint__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("complaint"));
+                            }
+                            complaint__ = map_.next_value()?;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(EventBallotKeyComplaint {
+                    complaint: complaint__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.governance.v1.EventBallotKeyComplaint", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for EventBallotKeyDeal {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.deal.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.governance.v1.EventBallotKeyDeal", len)?;
+        if let Some(v) = self.deal.as_ref() {
+            struct_ser.serialize_field("deal", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for EventBallotKeyDeal {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "deal",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Deal,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "deal" => Ok(GeneratedField::Deal),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = EventBallotKeyDeal;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.governance.v1.EventBallotKeyDeal")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<EventBallotKeyDeal, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut deal__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Deal => {
+                            if deal__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("deal"));
+                            }
+                            deal__ = map_.next_value()?;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(EventBallotKeyDeal {
+                    deal: deal__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.governance.v1.EventBallotKeyDeal", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for EventBallotKeyFinalized {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.proposal_id != 0 {
+            len += 1;
+        }
+        if self.ballot_key.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.governance.v1.EventBallotKeyFinalized", len)?;
+        if self.proposal_id != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("proposalId", ToString::to_string(&self.proposal_id).as_str())?;
+        }
+        if let Some(v) = self.ballot_key.as_ref() {
+            struct_ser.serialize_field("ballotKey", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for EventBallotKeyFinalized {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "proposal_id",
+            "proposalId",
+            "ballot_key",
+            "ballotKey",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            ProposalId,
+            BallotKey,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "proposalId" | "proposal_id" => Ok(GeneratedField::ProposalId),
+                            "ballotKey" | "ballot_key" => Ok(GeneratedField::BallotKey),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = EventBallotKeyFinalized;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.governance.v1.EventBallotKeyFinalized")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<EventBallotKeyFinalized, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut proposal_id__ = None;
+                let mut ballot_key__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::ProposalId => {
+                            if proposal_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("proposalId"));
+                            }
+                            proposal_id__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::BallotKey => {
+                            if ballot_key__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("ballotKey"));
+                            }
+                            ballot_key__ = map_.next_value()?;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(EventBallotKeyFinalized {
+                    proposal_id: proposal_id__.unwrap_or_default(),
+                    ballot_key: ballot_key__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.governance.v1.EventBallotKeyFinalized", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for EventBallotKeyReveal {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.reveal.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.governance.v1.EventBallotKeyReveal", len)?;
+        if let Some(v) = self.reveal.as_ref() {
+            struct_ser.serialize_field("reveal", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for EventBallotKeyReveal {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "reveal",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Reveal,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "reveal" => Ok(GeneratedField::Reveal),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = EventBallotKeyReveal;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.governance.v1.EventBallotKeyReveal")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<EventBallotKeyReveal, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut reveal__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Reveal => {
+                            if reveal__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("reveal"));
+                            }
+                            reveal__ = map_.next_value()?;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(EventBallotKeyReveal {
+                    reveal: reveal__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.governance.v1.EventBallotKeyReveal", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for EventDelegatorVote {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.vote.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.governance.v1.EventDelegatorVote", len)?;
+        if let Some(v) = self.vote.as_ref() {
+            struct_ser.serialize_field("vote", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for EventDelegatorVote {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "vote",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Vote,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "vote" => Ok(GeneratedField::Vote),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = EventDelegatorVote;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.governance.v1.EventDelegatorVote")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<EventDelegatorVote, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut vote__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Vote => {
+                            if vote__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("vote"));
+                            }
+                            vote__ = map_.next_value()?;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(EventDelegatorVote {
+                    vote: vote__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.governance.v1.EventDelegatorVote", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for EventEnactProposal {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.proposal.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.governance.v1.EventEnactProposal", len)?;
+        if let Some(v) = self.proposal.as_ref() {
+            struct_ser.serialize_field("proposal", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for EventEnactProposal {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "proposal",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Proposal,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "proposal" => Ok(GeneratedField::Proposal),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = EventEnactProposal;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.governance.v1.EventEnactProposal")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<EventEnactProposal, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut proposal__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Proposal => {
+                            if proposal__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("proposal"));
+                            }
+                            proposal__ = map_.next_value()?;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(EventEnactProposal {
+                    proposal: proposal__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.governance.v1.EventEnactProposal", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for EventProposalDepositClaim {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.deposit_claim.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.governance.v1.EventProposalDepositClaim", len)?;
+        if let Some(v) = self.deposit_claim.as_ref() {
+            struct_ser.serialize_field("depositClaim", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for EventProposalDepositClaim {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "deposit_claim",
+            "depositClaim",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            DepositClaim,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "depositClaim" | "deposit_claim" => Ok(GeneratedField::DepositClaim),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = EventProposalDepositClaim;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.governance.v1.EventProposalDepositClaim")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<EventProposalDepositClaim, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut deposit_claim__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::DepositClaim => {
+                            if deposit_claim__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("depositClaim"));
+                            }
+                            deposit_claim__ = map_.next_value()?;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(EventProposalDepositClaim {
+                    deposit_claim: deposit_claim__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.governance.v1.EventProposalDepositClaim", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for EventProposalFailed {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.proposal.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.governance.v1.EventProposalFailed", len)?;
+        if let Some(v) = self.proposal.as_ref() {
+            struct_ser.serialize_field("proposal", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for EventProposalFailed {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "proposal",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Proposal,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "proposal" => Ok(GeneratedField::Proposal),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = EventProposalFailed;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.governance.v1.EventProposalFailed")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<EventProposalFailed, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut proposal__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Proposal => {
+                            if proposal__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("proposal"));
+                            }
+                            proposal__ = map_.next_value()?;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(EventProposalFailed {
+                    proposal: proposal__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.governance.v1.EventProposalFailed", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for EventProposalSlashed {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.proposal.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.governance.v1.EventProposalSlashed", len)?;
+        if let Some(v) = self.proposal.as_ref() {
+            struct_ser.serialize_field("proposal", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for EventProposalSlashed {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "proposal",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Proposal,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "proposal" => Ok(GeneratedField::Proposal),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = EventProposalSlashed;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.governance.v1.EventProposalSlashed")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<EventProposalSlashed, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut proposal__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Proposal => {
+                            if proposal__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("proposal"));
                             }
-                            proof_blinding_s__ = 
-                                Some(map_.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
-                            ;
+                            proposal__ = map_.next_value()?;
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(DelegatorVotePlan {
-                    proposal: proposal__.unwrap_or_default(),
-                    start_position: start_position__.unwrap_or_default(),
-                    vote: vote__,
-                    staked_note: staked_note__,
-                    staked_note_position: staked_note_position__.unwrap_or_default(),
-                    unbonded_amount: unbonded_amount__,
-                    randomizer: randomizer__.unwrap_or_default(),
-                    proof_blinding_r: proof_blinding_r__.unwrap_or_default(),
-                    proof_blinding_s: proof_blinding_s__.unwrap_or_default(),
+                Ok(EventProposalSlashed {
+                    proposal: proposal__,
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.core.component.governance.v1.DelegatorVotePlan", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.core.component.governance.v1.EventProposalSlashed", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for DelegatorVoteView {
+impl serde::Serialize for EventProposalSubmit {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -1462,38 +4732,29 @@ impl serde::Serialize for DelegatorVoteView {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.delegator_vote.is_some() {
+        if self.submit.is_some() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.governance.v1.DelegatorVoteView", len)?;
-        if let Some(v) = self.delegator_vote.as_ref() {
-            match v {
-                delegator_vote_view::DelegatorVote::Visible(v) => {
-                    struct_ser.serialize_field("visible", v)?;
-                }
-                delegator_vote_view::DelegatorVote::Opaque(v) => {
-                    struct_ser.serialize_field("opaque", v)?;
-                }
-            }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.governance.v1.EventProposalSubmit", len)?;
+        if let Some(v) = self.submit.as_ref() {
+            struct_ser.serialize_field("submit", v)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for DelegatorVoteView {
+impl<'de> serde::Deserialize<'de> for EventProposalSubmit {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "visible",
-            "opaque",
+            "submit",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            Visible,
-            Opaque,
+            Submit,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -1516,8 +4777,7 @@ impl<'de> serde::Deserialize<'de> for DelegatorVoteView {
                         E: serde::de::Error,
                     {
                         match value {
-                            "visible" => Ok(GeneratedField::Visible),
-                            "opaque" => Ok(GeneratedField::Opaque),
+                            "submit" => Ok(GeneratedField::Submit),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -1527,47 +4787,39 @@ impl<'de> serde::Deserialize<'de> for DelegatorVoteView {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = DelegatorVoteView;
+            type Value = EventProposalSubmit;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.core.component.governance.v1.DelegatorVoteView")
+                formatter.write_str("struct penumbra.core.component.governance.v1.EventProposalSubmit")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<DelegatorVoteView, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<EventProposalSubmit, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut delegator_vote__ = None;
+                let mut submit__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::Visible => {
-                            if delegator_vote__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("visible"));
-                            }
-                            delegator_vote__ = map_.next_value::<::std::option::Option<_>>()?.map(delegator_vote_view::DelegatorVote::Visible)
-;
-                        }
-                        GeneratedField::Opaque => {
-                            if delegator_vote__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("opaque"));
+                        GeneratedField::Submit => {
+                            if submit__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("submit"));
                             }
-                            delegator_vote__ = map_.next_value::<::std::option::Option<_>>()?.map(delegator_vote_view::DelegatorVote::Opaque)
-;
+                            submit__ = map_.next_value()?;
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(DelegatorVoteView {
-                    delegator_vote: delegator_vote__,
+                Ok(EventProposalSubmit {
+                    submit: submit__,
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.core.component.governance.v1.DelegatorVoteView", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.core.component.governance.v1.EventProposalSubmit", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for delegator_vote_view::Opaque {
+impl serde::Serialize for EventProposalWithdraw {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -1575,30 +4827,29 @@ impl serde::Serialize for delegator_vote_view::Opaque {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.delegator_vote.is_some() {
+        if self.withdraw.is_some() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.governance.v1.DelegatorVoteView.Opaque", len)?;
-        if let Some(v) = self.delegator_vote.as_ref() {
-            struct_ser.serialize_field("delegatorVote", v)?;
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.governance.v1.EventProposalWithdraw", len)?;
+        if let Some(v) = self.withdraw.as_ref() {
+            struct_ser.serialize_field("withdraw", v)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for delegator_vote_view::Opaque {
+impl<'de> serde::Deserialize<'de> for EventProposalWithdraw {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "delegator_vote",
-            "delegatorVote",
+            "withdraw",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            DelegatorVote,
+            Withdraw,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -1621,7 +4872,7 @@ impl<'de> serde::Deserialize<'de> for delegator_vote_view::Opaque {
                         E: serde::de::Error,
                     {
                         match value {
-                            "delegatorVote" | "delegator_vote" => Ok(GeneratedField::DelegatorVote),
+                            "withdraw" => Ok(GeneratedField::Withdraw),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -1631,39 +4882,39 @@ impl<'de> serde::Deserialize<'de> for delegator_vote_view::Opaque {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = delegator_vote_view::Opaque;
+            type Value = EventProposalWithdraw;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.core.component.governance.v1.DelegatorVoteView.Opaque")
+                formatter.write_str("struct penumbra.core.component.governance.v1.EventProposalWithdraw")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<delegator_vote_view::Opaque, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<EventProposalWithdraw, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut delegator_vote__ = None;
+                let mut withdraw__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::DelegatorVote => {
-                            if delegator_vote__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("delegatorVote"));
+                        GeneratedField::Withdraw => {
+                            if withdraw__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("withdraw"));
                             }
-                            delegator_vote__ = map_.next_value()?;
+                            withdraw__ = map_.next_value()?;
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(delegator_vote_view::Opaque {
-                    delegator_vote: delegator_vote__,
+                Ok(EventProposalWithdraw {
+                    withdraw: withdraw__,
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.core.component.governance.v1.DelegatorVoteView.Opaque", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.core.component.governance.v1.EventProposalWithdraw", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for delegator_vote_view::Visible {
+impl serde::Serialize for EventSealedBallotsOpened {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -1671,38 +4922,49 @@ impl serde::Serialize for delegator_vote_view::Visible {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.delegator_vote.is_some() {
+        if self.proposal_id != 0 {
             len += 1;
         }
-        if self.note.is_some() {
+        if self.opened != 0 {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.governance.v1.DelegatorVoteView.Visible", len)?;
-        if let Some(v) = self.delegator_vote.as_ref() {
-            struct_ser.serialize_field("delegatorVote", v)?;
+        if self.discarded != 0 {
+            len += 1;
         }
-        if let Some(v) = self.note.as_ref() {
-            struct_ser.serialize_field("note", v)?;
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.governance.v1.EventSealedBallotsOpened", len)?;
+        if self.proposal_id != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("proposalId", ToString::to_string(&self.proposal_id).as_str())?;
+        }
+        if self.opened != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("opened", ToString::to_string(&self.opened).as_str())?;
+        }
+        if self.discarded != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("discarded", ToString::to_string(&self.discarded).as_str())?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for delegator_vote_view::Visible {
+impl<'de> serde::Deserialize<'de> for EventSealedBallotsOpened {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "delegator_vote",
-            "delegatorVote",
-            "note",
+            "proposal_id",
+            "proposalId",
+            "opened",
+            "discarded",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            DelegatorVote,
-            Note,
+            ProposalId,
+            Opened,
+            Discarded,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -1725,8 +4987,9 @@ impl<'de> serde::Deserialize<'de> for delegator_vote_view::Visible {
                         E: serde::de::Error,
                     {
                         match value {
-                            "delegatorVote" | "delegator_vote" => Ok(GeneratedField::DelegatorVote),
-                            "note" => Ok(GeneratedField::Note),
+                            "proposalId" | "proposal_id" => Ok(GeneratedField::ProposalId),
+                            "opened" => Ok(GeneratedField::Opened),
+                            "discarded" => Ok(GeneratedField::Discarded),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -1736,47 +4999,61 @@ impl<'de> serde::Deserialize<'de> for delegator_vote_view::Visible {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = delegator_vote_view::Visible;
+            type Value = EventSealedBallotsOpened;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.core.component.governance.v1.DelegatorVoteView.Visible")
+                formatter.write_str("struct penumbra.core.component.governance.v1.EventSealedBallotsOpened")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<delegator_vote_view::Visible, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<EventSealedBallotsOpened, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut delegator_vote__ = None;
-                let mut note__ = None;
+                let mut proposal_id__ = None;
+                let mut opened__ = None;
+                let mut discarded__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::DelegatorVote => {
-                            if delegator_vote__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("delegatorVote"));
+                        GeneratedField::ProposalId => {
+                            if proposal_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("proposalId"));
                             }
-                            delegator_vote__ = map_.next_value()?;
+                            proposal_id__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
                         }
-                        GeneratedField::Note => {
-                            if note__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("note"));
+                        GeneratedField::Opened => {
+                            if opened__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("opened"));
                             }
-                            note__ = map_.next_value()?;
+                            opened__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::Discarded => {
+                            if discarded__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("discarded"));
+                            }
+                            discarded__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(delegator_vote_view::Visible {
-                    delegator_vote: delegator_vote__,
-                    note: note__,
+                Ok(EventSealedBallotsOpened {
+                    proposal_id: proposal_id__.unwrap_or_default(),
+                    opened: opened__.unwrap_or_default(),
+                    discarded: discarded__.unwrap_or_default(),
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.core.component.governance.v1.DelegatorVoteView.Visible", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.core.component.governance.v1.EventSealedBallotsOpened", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for EventDelegatorVote {
+impl serde::Serialize for EventValidatorAbstained {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -1784,29 +5061,50 @@ impl serde::Serialize for EventDelegatorVote {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.vote.is_some() {
+        if self.proposal_id != 0 {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.governance.v1.EventDelegatorVote", len)?;
-        if let Some(v) = self.vote.as_ref() {
-            struct_ser.serialize_field("vote", v)?;
+        if self.identity_key.is_some() {
+            len += 1;
+        }
+        if self.voting_power != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.governance.v1.EventValidatorAbstained", len)?;
+        if self.proposal_id != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("proposalId", ToString::to_string(&self.proposal_id).as_str())?;
+        }
+        if let Some(v) = self.identity_key.as_ref() {
+            struct_ser.serialize_field("identityKey", v)?;
+        }
+        if self.voting_power != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("votingPower", ToString::to_string(&self.voting_power).as_str())?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for EventDelegatorVote {
+impl<'de> serde::Deserialize<'de> for EventValidatorAbstained {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "vote",
+            "proposal_id",
+            "proposalId",
+            "identity_key",
+            "identityKey",
+            "voting_power",
+            "votingPower",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            Vote,
+            ProposalId,
+            IdentityKey,
+            VotingPower,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -1829,7 +5127,9 @@ impl<'de> serde::Deserialize<'de> for EventDelegatorVote {
                         E: serde::de::Error,
                     {
                         match value {
-                            "vote" => Ok(GeneratedField::Vote),
+                            "proposalId" | "proposal_id" => Ok(GeneratedField::ProposalId),
+                            "identityKey" | "identity_key" => Ok(GeneratedField::IdentityKey),
+                            "votingPower" | "voting_power" => Ok(GeneratedField::VotingPower),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -1839,39 +5139,59 @@ impl<'de> serde::Deserialize<'de> for EventDelegatorVote {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = EventDelegatorVote;
+            type Value = EventValidatorAbstained;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.core.component.governance.v1.EventDelegatorVote")
+                formatter.write_str("struct penumbra.core.component.governance.v1.EventValidatorAbstained")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<EventDelegatorVote, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<EventValidatorAbstained, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut vote__ = None;
+                let mut proposal_id__ = None;
+                let mut identity_key__ = None;
+                let mut voting_power__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::Vote => {
-                            if vote__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("vote"));
+                        GeneratedField::ProposalId => {
+                            if proposal_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("proposalId"));
                             }
-                            vote__ = map_.next_value()?;
+                            proposal_id__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::IdentityKey => {
+                            if identity_key__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("identityKey"));
+                            }
+                            identity_key__ = map_.next_value()?;
+                        }
+                        GeneratedField::VotingPower => {
+                            if voting_power__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("votingPower"));
+                            }
+                            voting_power__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(EventDelegatorVote {
-                    vote: vote__,
+                Ok(EventValidatorAbstained {
+                    proposal_id: proposal_id__.unwrap_or_default(),
+                    identity_key: identity_key__,
+                    voting_power: voting_power__.unwrap_or_default(),
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.core.component.governance.v1.EventDelegatorVote", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.core.component.governance.v1.EventValidatorAbstained", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for EventEnactProposal {
+impl serde::Serialize for EventValidatorVote {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -1879,29 +5199,29 @@ impl serde::Serialize for EventEnactProposal {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.proposal.is_some() {
+        if self.vote.is_some() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.governance.v1.EventEnactProposal", len)?;
-        if let Some(v) = self.proposal.as_ref() {
-            struct_ser.serialize_field("proposal", v)?;
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.governance.v1.EventValidatorVote", len)?;
+        if let Some(v) = self.vote.as_ref() {
+            struct_ser.serialize_field("vote", v)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for EventEnactProposal {
+impl<'de> serde::Deserialize<'de> for EventValidatorVote {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "proposal",
+            "vote",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            Proposal,
+            Vote,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -1924,7 +5244,7 @@ impl<'de> serde::Deserialize<'de> for EventEnactProposal {
                         E: serde::de::Error,
                     {
                         match value {
-                            "proposal" => Ok(GeneratedField::Proposal),
+                            "vote" => Ok(GeneratedField::Vote),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -1934,39 +5254,39 @@ impl<'de> serde::Deserialize<'de> for EventEnactProposal {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = EventEnactProposal;
+            type Value = EventValidatorVote;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.core.component.governance.v1.EventEnactProposal")
+                formatter.write_str("struct penumbra.core.component.governance.v1.EventValidatorVote")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<EventEnactProposal, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<EventValidatorVote, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut proposal__ = None;
+                let mut vote__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::Proposal => {
-                            if proposal__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("proposal"));
+                        GeneratedField::Vote => {
+                            if vote__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("vote"));
                             }
-                            proposal__ = map_.next_value()?;
+                            vote__ = map_.next_value()?;
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(EventEnactProposal {
-                    proposal: proposal__,
+                Ok(EventValidatorVote {
+                    vote: vote__,
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.core.component.governance.v1.EventEnactProposal", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.core.component.governance.v1.EventValidatorVote", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for EventProposalDepositClaim {
+impl serde::Serialize for GenesisContent {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -1974,30 +5294,30 @@ impl serde::Serialize for EventProposalDepositClaim {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.deposit_claim.is_some() {
+        if self.governance_params.is_some() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.governance.v1.EventProposalDepositClaim", len)?;
-        if let Some(v) = self.deposit_claim.as_ref() {
-            struct_ser.serialize_field("depositClaim", v)?;
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.governance.v1.GenesisContent", len)?;
+        if let Some(v) = self.governance_params.as_ref() {
+            struct_ser.serialize_field("governanceParams", v)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for EventProposalDepositClaim {
+impl<'de> serde::Deserialize<'de> for GenesisContent {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "deposit_claim",
-            "depositClaim",
+            "governance_params",
+            "governanceParams",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            DepositClaim,
+            GovernanceParams,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -2020,7 +5340,7 @@ impl<'de> serde::Deserialize<'de> for EventProposalDepositClaim {
                         E: serde::de::Error,
                     {
                         match value {
-                            "depositClaim" | "deposit_claim" => Ok(GeneratedField::DepositClaim),
+                            "governanceParams" | "governance_params" => Ok(GeneratedField::GovernanceParams),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -2030,39 +5350,39 @@ impl<'de> serde::Deserialize<'de> for EventProposalDepositClaim {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = EventProposalDepositClaim;
+            type Value = GenesisContent;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.core.component.governance.v1.EventProposalDepositClaim")
+                formatter.write_str("struct penumbra.core.component.governance.v1.GenesisContent")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<EventProposalDepositClaim, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<GenesisContent, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut deposit_claim__ = None;
+                let mut governance_params__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::DepositClaim => {
-                            if deposit_claim__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("depositClaim"));
+                        GeneratedField::GovernanceParams => {
+                            if governance_params__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("governanceParams"));
                             }
-                            deposit_claim__ = map_.next_value()?;
+                            governance_params__ = map_.next_value()?;
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(EventProposalDepositClaim {
-                    deposit_claim: deposit_claim__,
+                Ok(GenesisContent {
+                    governance_params: governance_params__,
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.core.component.governance.v1.EventProposalDepositClaim", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.core.component.governance.v1.GenesisContent", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for EventProposalFailed {
+impl serde::Serialize for GovernanceParameters {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -2070,29 +5390,125 @@ impl serde::Serialize for EventProposalFailed {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.proposal.is_some() {
+        if self.proposal_voting_blocks != 0 {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.governance.v1.EventProposalFailed", len)?;
-        if let Some(v) = self.proposal.as_ref() {
-            struct_ser.serialize_field("proposal", v)?;
+        if self.proposal_deposit_amount.is_some() {
+            len += 1;
+        }
+        if !self.proposal_valid_quorum.is_empty() {
+            len += 1;
+        }
+        if !self.proposal_pass_threshold.is_empty() {
+            len += 1;
+        }
+        if !self.proposal_slash_threshold.is_empty() {
+            len += 1;
+        }
+        if self.sealed_ballot_setup_blocks != 0 {
+            len += 1;
+        }
+        if self.sealed_ballot_reveal_blocks != 0 {
+            len += 1;
+        }
+        if !self.accepted_deposit_assets.is_empty() {
+            len += 1;
+        }
+        if self.deposit_rate_window_blocks != 0 {
+            len += 1;
+        }
+        if self.deposit_rate_max_age_blocks != 0 {
+            len += 1;
+        }
+        if self.deposit_rate_min_volume.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.governance.v1.GovernanceParameters", len)?;
+        if self.proposal_voting_blocks != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("proposalVotingBlocks", ToString::to_string(&self.proposal_voting_blocks).as_str())?;
+        }
+        if let Some(v) = self.proposal_deposit_amount.as_ref() {
+            struct_ser.serialize_field("proposalDepositAmount", v)?;
+        }
+        if !self.proposal_valid_quorum.is_empty() {
+            struct_ser.serialize_field("proposalValidQuorum", &self.proposal_valid_quorum)?;
+        }
+        if !self.proposal_pass_threshold.is_empty() {
+            struct_ser.serialize_field("proposalPassThreshold", &self.proposal_pass_threshold)?;
+        }
+        if !self.proposal_slash_threshold.is_empty() {
+            struct_ser.serialize_field("proposalSlashThreshold", &self.proposal_slash_threshold)?;
+        }
+        if self.sealed_ballot_setup_blocks != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("sealedBallotSetupBlocks", ToString::to_string(&self.sealed_ballot_setup_blocks).as_str())?;
+        }
+        if self.sealed_ballot_reveal_blocks != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("sealedBallotRevealBlocks", ToString::to_string(&self.sealed_ballot_reveal_blocks).as_str())?;
+        }
+        if !self.accepted_deposit_assets.is_empty() {
+            struct_ser.serialize_field("acceptedDepositAssets", &self.accepted_deposit_assets)?;
+        }
+        if self.deposit_rate_window_blocks != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("depositRateWindowBlocks", ToString::to_string(&self.deposit_rate_window_blocks).as_str())?;
+        }
+        if self.deposit_rate_max_age_blocks != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("depositRateMaxAgeBlocks", ToString::to_string(&self.deposit_rate_max_age_blocks).as_str())?;
+        }
+        if let Some(v) = self.deposit_rate_min_volume.as_ref() {
+            struct_ser.serialize_field("depositRateMinVolume", v)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for EventProposalFailed {
+impl<'de> serde::Deserialize<'de> for GovernanceParameters {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "proposal",
+            "proposal_voting_blocks",
+            "proposalVotingBlocks",
+            "proposal_deposit_amount",
+            "proposalDepositAmount",
+            "proposal_valid_quorum",
+            "proposalValidQuorum",
+            "proposal_pass_threshold",
+            "proposalPassThreshold",
+            "proposal_slash_threshold",
+            "proposalSlashThreshold",
+            "sealed_ballot_setup_blocks",
+            "sealedBallotSetupBlocks",
+            "sealed_ballot_reveal_blocks",
+            "sealedBallotRevealBlocks",
+            "accepted_deposit_assets",
+            "acceptedDepositAssets",
+            "deposit_rate_window_blocks",
+            "depositRateWindowBlocks",
+            "deposit_rate_max_age_blocks",
+            "depositRateMaxAgeBlocks",
+            "deposit_rate_min_volume",
+            "depositRateMinVolume",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            Proposal,
+            ProposalVotingBlocks,
+            ProposalDepositAmount,
+            ProposalValidQuorum,
+            ProposalPassThreshold,
+            ProposalSlashThreshold,
+            SealedBallotSetupBlocks,
+            SealedBallotRevealBlocks,
+            AcceptedDepositAssets,
+            DepositRateWindowBlocks,
+            DepositRateMaxAgeBlocks,
+            DepositRateMinVolume,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -2115,7 +5531,17 @@ impl<'de> serde::Deserialize<'de> for EventProposalFailed {
                         E: serde::de::Error,
                     {
                         match value {
-                            "proposal" => Ok(GeneratedField::Proposal),
+                            "proposalVotingBlocks" | "proposal_voting_blocks" => Ok(GeneratedField::ProposalVotingBlocks),
+                            "proposalDepositAmount" | "proposal_deposit_amount" => Ok(GeneratedField::ProposalDepositAmount),
+                            "proposalValidQuorum" | "proposal_valid_quorum" => Ok(GeneratedField::ProposalValidQuorum),
+                            "proposalPassThreshold" | "proposal_pass_threshold" => Ok(GeneratedField::ProposalPassThreshold),
+                            "proposalSlashThreshold" | "proposal_slash_threshold" => Ok(GeneratedField::ProposalSlashThreshold),
+                            "sealedBallotSetupBlocks" | "sealed_ballot_setup_blocks" => Ok(GeneratedField::SealedBallotSetupBlocks),
+                            "sealedBallotRevealBlocks" | "sealed_ballot_reveal_blocks" => Ok(GeneratedField::SealedBallotRevealBlocks),
+                            "acceptedDepositAssets" | "accepted_deposit_assets" => Ok(GeneratedField::AcceptedDepositAssets),
+                            "depositRateWindowBlocks" | "deposit_rate_window_blocks" => Ok(GeneratedField::DepositRateWindowBlocks),
+                            "depositRateMaxAgeBlocks" | "deposit_rate_max_age_blocks" => Ok(GeneratedField::DepositRateMaxAgeBlocks),
+                            "depositRateMinVolume" | "deposit_rate_min_volume" => Ok(GeneratedField::DepositRateMinVolume),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -2125,69 +5551,151 @@ impl<'de> serde::Deserialize<'de> for EventProposalFailed {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = EventProposalFailed;
+            type Value = GovernanceParameters;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.core.component.governance.v1.EventProposalFailed")
+                formatter.write_str("struct penumbra.core.component.governance.v1.GovernanceParameters")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<EventProposalFailed, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<GovernanceParameters, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut proposal__ = None;
+                let mut proposal_voting_blocks__ = None;
+                let mut proposal_deposit_amount__ = None;
+                let mut proposal_valid_quorum__ = None;
+                let mut proposal_pass_threshold__ = None;
+                let mut proposal_slash_threshold__ = None;
+                let mut sealed_ballot_setup_blocks__ = None;
+                let mut sealed_ballot_reveal_blocks__ = None;
+                let mut accepted_deposit_assets__ = None;
+                let mut deposit_rate_window_blocks__ = None;
+                let mut deposit_rate_max_age_blocks__ = None;
+                let mut deposit_rate_min_volume__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::Proposal => {
-                            if proposal__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("proposal"));
+                        GeneratedField::ProposalVotingBlocks => {
+                            if proposal_voting_blocks__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("proposalVotingBlocks"));
                             }
-                            proposal__ = map_.next_value()?;
+                            proposal_voting_blocks__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::ProposalDepositAmount => {
+                            if proposal_deposit_amount__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("proposalDepositAmount"));
+                            }
+                            proposal_deposit_amount__ = map_.next_value()?;
+                        }
+                        GeneratedField::ProposalValidQuorum => {
+                            if proposal_valid_quorum__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("proposalValidQuorum"));
+                            }
+                            proposal_valid_quorum__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::ProposalPassThreshold => {
+                            if proposal_pass_threshold__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("proposalPassThreshold"));
+                            }
+                            proposal_pass_threshold__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::ProposalSlashThreshold => {
+                            if proposal_slash_threshold__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("proposalSlashThreshold"));
+                            }
+                            proposal_slash_threshold__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::SealedBallotSetupBlocks => {
+                            if sealed_ballot_setup_blocks__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("sealedBallotSetupBlocks"));
+                            }
+                            sealed_ballot_setup_blocks__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::SealedBallotRevealBlocks => {
+                            if sealed_ballot_reveal_blocks__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("sealedBallotRevealBlocks"));
+                            }
+                            sealed_ballot_reveal_blocks__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::AcceptedDepositAssets => {
+                            if accepted_deposit_assets__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("acceptedDepositAssets"));
+                            }
+                            accepted_deposit_assets__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::DepositRateWindowBlocks => {
+                            if deposit_rate_window_blocks__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("depositRateWindowBlocks"));
+                            }
+                            deposit_rate_window_blocks__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::DepositRateMaxAgeBlocks => {
+                            if deposit_rate_max_age_blocks__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("depositRateMaxAgeBlocks"));
+                            }
+                            deposit_rate_max_age_blocks__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::DepositRateMinVolume => {
+                            if deposit_rate_min_volume__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("depositRateMinVolume"));
+                            }
+                            deposit_rate_min_volume__ = map_.next_value()?;
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(EventProposalFailed {
-                    proposal: proposal__,
+                Ok(GovernanceParameters {
+                    proposal_voting_blocks: proposal_voting_blocks__.unwrap_or_default(),
+                    proposal_deposit_amount: proposal_deposit_amount__,
+                    proposal_valid_quorum: proposal_valid_quorum__.unwrap_or_default(),
+                    proposal_pass_threshold: proposal_pass_threshold__.unwrap_or_default(),
+                    proposal_slash_threshold: proposal_slash_threshold__.unwrap_or_default(),
+                    sealed_ballot_setup_blocks: sealed_ballot_setup_blocks__.unwrap_or_default(),
+                    sealed_ballot_reveal_blocks: sealed_ballot_reveal_blocks__.unwrap_or_default(),
+                    accepted_deposit_assets: accepted_deposit_assets__.unwrap_or_default(),
+                    deposit_rate_window_blocks: deposit_rate_window_blocks__.unwrap_or_default(),
+                    deposit_rate_max_age_blocks: deposit_rate_max_age_blocks__.unwrap_or_default(),
+                    deposit_rate_min_volume: deposit_rate_min_volume__,
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.core.component.governance.v1.EventProposalFailed", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.core.component.governance.v1.GovernanceParameters", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for EventProposalSlashed {
+impl serde::Serialize for NextProposalIdRequest {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
         S: serde::Serializer,
     {
         use serde::ser::SerializeStruct;
-        let mut len = 0;
-        if self.proposal.is_some() {
-            len += 1;
-        }
-        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.governance.v1.EventProposalSlashed", len)?;
-        if let Some(v) = self.proposal.as_ref() {
-            struct_ser.serialize_field("proposal", v)?;
-        }
+        let len = 0;
+        let struct_ser = serializer.serialize_struct("penumbra.core.component.governance.v1.NextProposalIdRequest", len)?;
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for EventProposalSlashed {
+impl<'de> serde::Deserialize<'de> for NextProposalIdRequest {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "proposal",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            Proposal,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -2209,10 +5717,7 @@ impl<'de> serde::Deserialize<'de> for EventProposalSlashed {
                     where
                         E: serde::de::Error,
                     {
-                        match value {
-                            "proposal" => Ok(GeneratedField::Proposal),
-                            _ => Ok(GeneratedField::__SkipField__),
-                        }
+                            Ok(GeneratedField::__SkipField__)
                     }
                 }
                 deserializer.deserialize_identifier(GeneratedVisitor)
@@ -2220,39 +5725,27 @@ impl<'de> serde::Deserialize<'de> for EventProposalSlashed {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = EventProposalSlashed;
+            type Value = NextProposalIdRequest;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.core.component.governance.v1.EventProposalSlashed")
+                formatter.write_str("struct penumbra.core.component.governance.v1.NextProposalIdRequest")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<EventProposalSlashed, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<NextProposalIdRequest, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut proposal__ = None;
-                while let Some(k) = map_.next_key()? {
-                    match k {
-                        GeneratedField::Proposal => {
-                            if proposal__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("proposal"));
-                            }
-                            proposal__ = map_.next_value()?;
-                        }
-                        GeneratedField::__SkipField__ => {
-                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
-                        }
-                    }
+                while map_.next_key::<GeneratedField>()?.is_some() {
+                    let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                 }
-                Ok(EventProposalSlashed {
-                    proposal: proposal__,
+                Ok(NextProposalIdRequest {
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.core.component.governance.v1.EventProposalSlashed", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.core.component.governance.v1.NextProposalIdRequest", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for EventProposalSubmit {
+impl serde::Serialize for NextProposalIdResponse {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -2260,29 +5753,31 @@ impl serde::Serialize for EventProposalSubmit {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.submit.is_some() {
+        if self.next_proposal_id != 0 {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.governance.v1.EventProposalSubmit", len)?;
-        if let Some(v) = self.submit.as_ref() {
-            struct_ser.serialize_field("submit", v)?;
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.governance.v1.NextProposalIdResponse", len)?;
+        if self.next_proposal_id != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("nextProposalId", ToString::to_string(&self.next_proposal_id).as_str())?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for EventProposalSubmit {
+impl<'de> serde::Deserialize<'de> for NextProposalIdResponse {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "submit",
+            "next_proposal_id",
+            "nextProposalId",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            Submit,
+            NextProposalId,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -2305,7 +5800,7 @@ impl<'de> serde::Deserialize<'de> for EventProposalSubmit {
                         E: serde::de::Error,
                     {
                         match value {
-                            "submit" => Ok(GeneratedField::Submit),
+                            "nextProposalId" | "next_proposal_id" => Ok(GeneratedField::NextProposalId),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -2315,39 +5810,41 @@ impl<'de> serde::Deserialize<'de> for EventProposalSubmit {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = EventProposalSubmit;
+            type Value = NextProposalIdResponse;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.core.component.governance.v1.EventProposalSubmit")
+                formatter.write_str("struct penumbra.core.component.governance.v1.NextProposalIdResponse")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<EventProposalSubmit, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<NextProposalIdResponse, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut submit__ = None;
+                let mut next_proposal_id__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::Submit => {
-                            if submit__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("submit"));
+                        GeneratedField::NextProposalId => {
+                            if next_proposal_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("nextProposalId"));
                             }
-                            submit__ = map_.next_value()?;
+                            next_proposal_id__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(EventProposalSubmit {
-                    submit: submit__,
+                Ok(NextProposalIdResponse {
+                    next_proposal_id: next_proposal_id__.unwrap_or_default(),
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.core.component.governance.v1.EventProposalSubmit", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.core.component.governance.v1.NextProposalIdResponse", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for EventProposalWithdraw {
+impl serde::Serialize for Proposal {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -2355,29 +5852,108 @@ impl serde::Serialize for EventProposalWithdraw {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.withdraw.is_some() {
+        if self.id != 0 {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.governance.v1.EventProposalWithdraw", len)?;
-        if let Some(v) = self.withdraw.as_ref() {
-            struct_ser.serialize_field("withdraw", v)?;
+        if !self.title.is_empty() {
+            len += 1;
+        }
+        if !self.description.is_empty() {
+            len += 1;
+        }
+        if self.sealed_ballots {
+            len += 1;
+        }
+        if self.payload.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.governance.v1.Proposal", len)?;
+        if self.id != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("id", ToString::to_string(&self.id).as_str())?;
+        }
+        if !self.title.is_empty() {
+            struct_ser.serialize_field("title", &self.title)?;
+        }
+        if !self.description.is_empty() {
+            struct_ser.serialize_field("description", &self.description)?;
+        }
+        if self.sealed_ballots {
+            struct_ser.serialize_field("sealedBallots", &self.sealed_ballots)?;
+        }
+        if let Some(v) = self.payload.as_ref() {
+            match v {
+                proposal::Payload::Signaling(v) => {
+                    struct_ser.serialize_field("signaling", v)?;
+                }
+                proposal::Payload::Emergency(v) => {
+                    struct_ser.serialize_field("emergency", v)?;
+                }
+                proposal::Payload::ParameterChange(v) => {
+                    struct_ser.serialize_field("parameterChange", v)?;
+                }
+                proposal::Payload::CommunityPoolSpend(v) => {
+                    struct_ser.serialize_field("communityPoolSpend", v)?;
+                }
+                proposal::Payload::UpgradePlan(v) => {
+                    struct_ser.serialize_field("upgradePlan", v)?;
+                }
+                proposal::Payload::FreezeIbcClient(v) => {
+                    struct_ser.serialize_field("freezeIbcClient", v)?;
+                }
+                proposal::Payload::UnfreezeIbcClient(v) => {
+                    struct_ser.serialize_field("unfreezeIbcClient", v)?;
+                }
+                proposal::Payload::RecurringBudget(v) => {
+                    struct_ser.serialize_field("recurringBudget", v)?;
+                }
+            }
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for EventProposalWithdraw {
+impl<'de> serde::Deserialize<'de> for Proposal {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "withdraw",
+            "id",
+            "title",
+            "description",
+            "sealed_ballots",
+            "sealedBallots",
+            "signaling",
+            "emergency",
+            "parameter_change",
+            "parameterChange",
+            "community_pool_spend",
+            "communityPoolSpend",
+            "upgrade_plan",
+            "upgradePlan",
+            "freeze_ibc_client",
+            "freezeIbcClient",
+            "unfreeze_ibc_client",
+            "unfreezeIbcClient",
+            "recurring_budget",
+            "recurringBudget",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            Withdraw,
+            Id,
+            Title,
+            Description,
+            SealedBallots,
+            Signaling,
+            Emergency,
+            ParameterChange,
+            CommunityPoolSpend,
+            UpgradePlan,
+            FreezeIbcClient,
+            UnfreezeIbcClient,
+            RecurringBudget,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -2400,7 +5976,18 @@ impl<'de> serde::Deserialize<'de> for EventProposalWithdraw {
                         E: serde::de::Error,
                     {
                         match value {
-                            "withdraw" => Ok(GeneratedField::Withdraw),
+                            "id" => Ok(GeneratedField::Id),
+                            "title" => Ok(GeneratedField::Title),
+                            "description" => Ok(GeneratedField::Description),
+                            "sealedBallots" | "sealed_ballots" => Ok(GeneratedField::SealedBallots),
+                            "signaling" => Ok(GeneratedField::Signaling),
+                            "emergency" => Ok(GeneratedField::Emergency),
+                            "parameterChange" | "parameter_change" => Ok(GeneratedField::ParameterChange),
+                            "communityPoolSpend" | "community_pool_spend" => Ok(GeneratedField::CommunityPoolSpend),
+                            "upgradePlan" | "upgrade_plan" => Ok(GeneratedField::UpgradePlan),
+                            "freezeIbcClient" | "freeze_ibc_client" => Ok(GeneratedField::FreezeIbcClient),
+                            "unfreezeIbcClient" | "unfreeze_ibc_client" => Ok(GeneratedField::UnfreezeIbcClient),
+                            "recurringBudget" | "recurring_budget" => Ok(GeneratedField::RecurringBudget),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -2410,39 +5997,123 @@ impl<'de> serde::Deserialize<'de> for EventProposalWithdraw {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = EventProposalWithdraw;
+            type Value = Proposal;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.core.component.governance.v1.EventProposalWithdraw")
+                formatter.write_str("struct penumbra.core.component.governance.v1.Proposal")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<EventProposalWithdraw, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<Proposal, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut withdraw__ = None;
+                let mut id__ = None;
+                let mut title__ = None;
+                let mut description__ = None;
+                let mut sealed_ballots__ = None;
+                let mut payload__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::Withdraw => {
-                            if withdraw__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("withdraw"));
+                        GeneratedField::Id => {
+                            if id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("id"));
+                            }
+                            id__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::Title => {
+                            if title__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("title"));
+                            }
+                            title__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Description => {
+                            if description__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("description"));
+                            }
+                            description__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::SealedBallots => {
+                            if sealed_ballots__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("sealedBallots"));
+                            }
+                            sealed_ballots__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Signaling => {
+                            if payload__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("signaling"));
+                            }
+                            payload__ = map_.next_value::<::std::option::Option<_>>()?.map(proposal::Payload::Signaling)
+;
+                        }
+                        GeneratedField::Emergency => {
+                            if payload__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("emergency"));
+                            }
+                            payload__ = map_.next_value::<::std::option::Option<_>>()?.map(proposal::Payload::Emergency)
+;
+                        }
+                        GeneratedField::ParameterChange => {
+                            if payload__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("parameterChange"));
+                            }
+                            payload__ = map_.next_value::<::std::option::Option<_>>()?.map(proposal::Payload::ParameterChange)
+;
+                        }
+                        GeneratedField::CommunityPoolSpend => {
+                            if payload__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("communityPoolSpend"));
+                            }
+                            payload__ = map_.next_value::<::std::option::Option<_>>()?.map(proposal::Payload::CommunityPoolSpend)
+;
+                        }
+                        GeneratedField::UpgradePlan => {
+                            if payload__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("upgradePlan"));
+                            }
+                            payload__ = map_.next_value::<::std::option::Option<_>>()?.map(proposal::Payload::UpgradePlan)
+;
+                        }
+                        GeneratedField::FreezeIbcClient => {
+                            if payload__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("freezeIbcClient"));
+                            }
+                            payload__ = map_.next_value::<::std::option::Option<_>>()?.map(proposal::Payload::FreezeIbcClient)
+;
+                        }
+                        GeneratedField::UnfreezeIbcClient => {
+                            if payload__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("unfreezeIbcClient"));
+                            }
+                            payload__ = map_.next_value::<::std::option::Option<_>>()?.map(proposal::Payload::UnfreezeIbcClient)
+;
+                        }
+                        GeneratedField::RecurringBudget => {
+                            if payload__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("recurringBudget"));
                             }
-                            withdraw__ = map_.next_value()?;
+                            payload__ = map_.next_value::<::std::option::Option<_>>()?.map(proposal::Payload::RecurringBudget)
+;
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(EventProposalWithdraw {
-                    withdraw: withdraw__,
+                Ok(Proposal {
+                    id: id__.unwrap_or_default(),
+                    title: title__.unwrap_or_default(),
+                    description: description__.unwrap_or_default(),
+                    sealed_ballots: sealed_ballots__.unwrap_or_default(),
+                    payload: payload__,
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.core.component.governance.v1.EventProposalWithdraw", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.core.component.governance.v1.Proposal", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for EventValidatorVote {
+impl serde::Serialize for proposal::CommunityPoolSpend {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -2450,29 +6121,30 @@ impl serde::Serialize for EventValidatorVote {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.vote.is_some() {
+        if self.transaction_plan.is_some() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.governance.v1.EventValidatorVote", len)?;
-        if let Some(v) = self.vote.as_ref() {
-            struct_ser.serialize_field("vote", v)?;
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.governance.v1.Proposal.CommunityPoolSpend", len)?;
+        if let Some(v) = self.transaction_plan.as_ref() {
+            struct_ser.serialize_field("transactionPlan", v)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for EventValidatorVote {
+impl<'de> serde::Deserialize<'de> for proposal::CommunityPoolSpend {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "vote",
+            "transaction_plan",
+            "transactionPlan",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            Vote,
+            TransactionPlan,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -2495,7 +6167,7 @@ impl<'de> serde::Deserialize<'de> for EventValidatorVote {
                         E: serde::de::Error,
                     {
                         match value {
-                            "vote" => Ok(GeneratedField::Vote),
+                            "transactionPlan" | "transaction_plan" => Ok(GeneratedField::TransactionPlan),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -2505,39 +6177,39 @@ impl<'de> serde::Deserialize<'de> for EventValidatorVote {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = EventValidatorVote;
+            type Value = proposal::CommunityPoolSpend;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.core.component.governance.v1.EventValidatorVote")
+                formatter.write_str("struct penumbra.core.component.governance.v1.Proposal.CommunityPoolSpend")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<EventValidatorVote, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<proposal::CommunityPoolSpend, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut vote__ = None;
+                let mut transaction_plan__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::Vote => {
-                            if vote__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("vote"));
+                        GeneratedField::TransactionPlan => {
+                            if transaction_plan__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("transactionPlan"));
                             }
-                            vote__ = map_.next_value()?;
+                            transaction_plan__ = map_.next_value()?;
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(EventValidatorVote {
-                    vote: vote__,
+                Ok(proposal::CommunityPoolSpend {
+                    transaction_plan: transaction_plan__,
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.core.component.governance.v1.EventValidatorVote", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.core.component.governance.v1.Proposal.CommunityPoolSpend", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for GenesisContent {
+impl serde::Serialize for proposal::Emergency {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -2545,30 +6217,30 @@ impl serde::Serialize for GenesisContent {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.governance_params.is_some() {
+        if self.halt_chain {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.governance.v1.GenesisContent", len)?;
-        if let Some(v) = self.governance_params.as_ref() {
-            struct_ser.serialize_field("governanceParams", v)?;
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.governance.v1.Proposal.Emergency", len)?;
+        if self.halt_chain {
+            struct_ser.serialize_field("haltChain", &self.halt_chain)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for GenesisContent {
+impl<'de> serde::Deserialize<'de> for proposal::Emergency {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "governance_params",
-            "governanceParams",
+            "halt_chain",
+            "haltChain",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            GovernanceParams,
+            HaltChain,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -2591,7 +6263,7 @@ impl<'de> serde::Deserialize<'de> for GenesisContent {
                         E: serde::de::Error,
                     {
                         match value {
-                            "governanceParams" | "governance_params" => Ok(GeneratedField::GovernanceParams),
+                            "haltChain" | "halt_chain" => Ok(GeneratedField::HaltChain),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -2601,39 +6273,39 @@ impl<'de> serde::Deserialize<'de> for GenesisContent {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = GenesisContent;
+            type Value = proposal::Emergency;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.core.component.governance.v1.GenesisContent")
+                formatter.write_str("struct penumbra.core.component.governance.v1.Proposal.Emergency")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<GenesisContent, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<proposal::Emergency, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut governance_params__ = None;
+                let mut halt_chain__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::GovernanceParams => {
-                            if governance_params__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("governanceParams"));
+                        GeneratedField::HaltChain => {
+                            if halt_chain__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("haltChain"));
                             }
-                            governance_params__ = map_.next_value()?;
+                            halt_chain__ = Some(map_.next_value()?);
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(GenesisContent {
-                    governance_params: governance_params__,
+                Ok(proposal::Emergency {
+                    halt_chain: halt_chain__.unwrap_or_default(),
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.core.component.governance.v1.GenesisContent", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.core.component.governance.v1.Proposal.Emergency", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for GovernanceParameters {
+impl serde::Serialize for proposal::FreezeIbcClient {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -2641,67 +6313,30 @@ impl serde::Serialize for GovernanceParameters {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.proposal_voting_blocks != 0 {
-            len += 1;
-        }
-        if self.proposal_deposit_amount.is_some() {
-            len += 1;
-        }
-        if !self.proposal_valid_quorum.is_empty() {
-            len += 1;
-        }
-        if !self.proposal_pass_threshold.is_empty() {
-            len += 1;
-        }
-        if !self.proposal_slash_threshold.is_empty() {
+        if !self.client_id.is_empty() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.governance.v1.GovernanceParameters", len)?;
-        if self.proposal_voting_blocks != 0 {
-            #[allow(clippy::needless_borrow)]
-            struct_ser.serialize_field("proposalVotingBlocks", ToString::to_string(&self.proposal_voting_blocks).as_str())?;
-        }
-        if let Some(v) = self.proposal_deposit_amount.as_ref() {
-            struct_ser.serialize_field("proposalDepositAmount", v)?;
-        }
-        if !self.proposal_valid_quorum.is_empty() {
-            struct_ser.serialize_field("proposalValidQuorum", &self.proposal_valid_quorum)?;
-        }
-        if !self.proposal_pass_threshold.is_empty() {
-            struct_ser.serialize_field("proposalPassThreshold", &self.proposal_pass_threshold)?;
-        }
-        if !self.proposal_slash_threshold.is_empty() {
-            struct_ser.serialize_field("proposalSlashThreshold", &self.proposal_slash_threshold)?;
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.governance.v1.Proposal.FreezeIbcClient", len)?;
+        if !self.client_id.is_empty() {
+            struct_ser.serialize_field("clientId", &self.client_id)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for GovernanceParameters {
+impl<'de> serde::Deserialize<'de> for proposal::FreezeIbcClient {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "proposal_voting_blocks",
-            "proposalVotingBlocks",
-            "proposal_deposit_amount",
-            "proposalDepositAmount",
-            "proposal_valid_quorum",
-            "proposalValidQuorum",
-            "proposal_pass_threshold",
-            "proposalPassThreshold",
-            "proposal_slash_threshold",
-            "proposalSlashThreshold",
+            "client_id",
+            "clientId",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            ProposalVotingBlocks,
-            ProposalDepositAmount,
-            ProposalValidQuorum,
-            ProposalPassThreshold,
-            ProposalSlashThreshold,
+            ClientId,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -2724,11 +6359,7 @@ impl<'de> serde::Deserialize<'de> for GovernanceParameters {
                         E: serde::de::Error,
                     {
                         match value {
-                            "proposalVotingBlocks" | "proposal_voting_blocks" => Ok(GeneratedField::ProposalVotingBlocks),
-                            "proposalDepositAmount" | "proposal_deposit_amount" => Ok(GeneratedField::ProposalDepositAmount),
-                            "proposalValidQuorum" | "proposal_valid_quorum" => Ok(GeneratedField::ProposalValidQuorum),
-                            "proposalPassThreshold" | "proposal_pass_threshold" => Ok(GeneratedField::ProposalPassThreshold),
-                            "proposalSlashThreshold" | "proposal_slash_threshold" => Ok(GeneratedField::ProposalSlashThreshold),
+                            "clientId" | "client_id" => Ok(GeneratedField::ClientId),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -2738,95 +6369,79 @@ impl<'de> serde::Deserialize<'de> for GovernanceParameters {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = GovernanceParameters;
+            type Value = proposal::FreezeIbcClient;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.core.component.governance.v1.GovernanceParameters")
+                formatter.write_str("struct penumbra.core.component.governance.v1.Proposal.FreezeIbcClient")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<GovernanceParameters, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<proposal::FreezeIbcClient, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut proposal_voting_blocks__ = None;
-                let mut proposal_deposit_amount__ = None;
-                let mut proposal_valid_quorum__ = None;
-                let mut proposal_pass_threshold__ = None;
-                let mut proposal_slash_threshold__ = None;
+                let mut client_id__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::ProposalVotingBlocks => {
-                            if proposal_voting_blocks__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("proposalVotingBlocks"));
-                            }
-                            proposal_voting_blocks__ = 
-                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
-                            ;
-                        }
-                        GeneratedField::ProposalDepositAmount => {
-                            if proposal_deposit_amount__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("proposalDepositAmount"));
-                            }
-                            proposal_deposit_amount__ = map_.next_value()?;
-                        }
-                        GeneratedField::ProposalValidQuorum => {
-                            if proposal_valid_quorum__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("proposalValidQuorum"));
-                            }
-                            proposal_valid_quorum__ = Some(map_.next_value()?);
-                        }
-                        GeneratedField::ProposalPassThreshold => {
-                            if proposal_pass_threshold__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("proposalPassThreshold"));
-                            }
-                            proposal_pass_threshold__ = Some(map_.next_value()?);
-                        }
-                        GeneratedField::ProposalSlashThreshold => {
-                            if proposal_slash_threshold__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("proposalSlashThreshold"));
+                        GeneratedField::ClientId => {
+                            if client_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("clientId"));
                             }
-                            proposal_slash_threshold__ = Some(map_.next_value()?);
+                            client_id__ = Some(map_.next_value()?);
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(GovernanceParameters {
-                    proposal_voting_blocks: proposal_voting_blocks__.unwrap_or_default(),
-                    proposal_deposit_amount: proposal_deposit_amount__,
-                    proposal_valid_quorum: proposal_valid_quorum__.unwrap_or_default(),
-                    proposal_pass_threshold: proposal_pass_threshold__.unwrap_or_default(),
-                    proposal_slash_threshold: proposal_slash_threshold__.unwrap_or_default(),
+                Ok(proposal::FreezeIbcClient {
+                    client_id: client_id__.unwrap_or_default(),
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.core.component.governance.v1.GovernanceParameters", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.core.component.governance.v1.Proposal.FreezeIbcClient", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for NextProposalIdRequest {
+impl serde::Serialize for proposal::ParameterChange {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
         S: serde::Serializer,
     {
         use serde::ser::SerializeStruct;
-        let len = 0;
-        let struct_ser = serializer.serialize_struct("penumbra.core.component.governance.v1.NextProposalIdRequest", len)?;
+        let mut len = 0;
+        if self.old_parameters.is_some() {
+            len += 1;
+        }
+        if self.new_parameters.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.governance.v1.Proposal.ParameterChange", len)?;
+        if let Some(v) = self.old_parameters.as_ref() {
+            struct_ser.serialize_field("oldParameters", v)?;
+        }
+        if let Some(v) = self.new_parameters.as_ref() {
+            struct_ser.serialize_field("newParameters", v)?;
+        }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for NextProposalIdRequest {
+impl<'de> serde::Deserialize<'de> for proposal::ParameterChange {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
+            "old_parameters",
+            "oldParameters",
+            "new_parameters",
+            "newParameters",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
+            OldParameters,
+            NewParameters,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -2848,7 +6463,11 @@ impl<'de> serde::Deserialize<'de> for NextProposalIdRequest {
                     where
                         E: serde::de::Error,
                     {
-                            Ok(GeneratedField::__SkipField__)
+                        match value {
+                            "oldParameters" | "old_parameters" => Ok(GeneratedField::OldParameters),
+                            "newParameters" | "new_parameters" => Ok(GeneratedField::NewParameters),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
                     }
                 }
                 deserializer.deserialize_identifier(GeneratedVisitor)
@@ -2856,27 +6475,47 @@ impl<'de> serde::Deserialize<'de> for NextProposalIdRequest {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = NextProposalIdRequest;
+            type Value = proposal::ParameterChange;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.core.component.governance.v1.NextProposalIdRequest")
+                formatter.write_str("struct penumbra.core.component.governance.v1.Proposal.ParameterChange")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<NextProposalIdRequest, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<proposal::ParameterChange, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                while map_.next_key::<GeneratedField>()?.is_some() {
-                    let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                let mut old_parameters__ = None;
+                let mut new_parameters__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::OldParameters => {
+                            if old_parameters__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("oldParameters"));
+                            }
+                            old_parameters__ = map_.next_value()?;
+                        }
+                        GeneratedField::NewParameters => {
+                            if new_parameters__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("newParameters"));
+                            }
+                            new_parameters__ = map_.next_value()?;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
                 }
-                Ok(NextProposalIdRequest {
+                Ok(proposal::ParameterChange {
+                    old_parameters: old_parameters__,
+                    new_parameters: new_parameters__,
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.core.component.governance.v1.NextProposalIdRequest", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.core.component.governance.v1.Proposal.ParameterChange", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for NextProposalIdResponse {
+impl serde::Serialize for proposal::RecurringBudget {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -2884,31 +6523,38 @@ impl serde::Serialize for NextProposalIdResponse {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.next_proposal_id != 0 {
+        if self.budget_line.is_some() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.governance.v1.NextProposalIdResponse", len)?;
-        if self.next_proposal_id != 0 {
-            #[allow(clippy::needless_borrow)]
-            struct_ser.serialize_field("nextProposalId", ToString::to_string(&self.next_proposal_id).as_str())?;
+        if !self.cancel.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.governance.v1.Proposal.RecurringBudget", len)?;
+        if let Some(v) = self.budget_line.as_ref() {
+            struct_ser.serialize_field("budgetLine", v)?;
+        }
+        if !self.cancel.is_empty() {
+            struct_ser.serialize_field("cancel", &self.cancel.iter().map(ToString::to_string).collect::<Vec<_>>())?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for NextProposalIdResponse {
+impl<'de> serde::Deserialize<'de> for proposal::RecurringBudget {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "next_proposal_id",
-            "nextProposalId",
+            "budget_line",
+            "budgetLine",
+            "cancel",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            NextProposalId,
+            BudgetLine,
+            Cancel,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -2931,7 +6577,8 @@ impl<'de> serde::Deserialize<'de> for NextProposalIdResponse {
                         E: serde::de::Error,
                     {
                         match value {
-                            "nextProposalId" | "next_proposal_id" => Ok(GeneratedField::NextProposalId),
+                            "budgetLine" | "budget_line" => Ok(GeneratedField::BudgetLine),
+                            "cancel" => Ok(GeneratedField::Cancel),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -2941,25 +6588,33 @@ impl<'de> serde::Deserialize<'de> for NextProposalIdResponse {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = NextProposalIdResponse;
+            type Value = proposal::RecurringBudget;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.core.component.governance.v1.NextProposalIdResponse")
+                formatter.write_str("struct penumbra.core.component.governance.v1.Proposal.RecurringBudget")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<NextProposalIdResponse, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<proposal::RecurringBudget, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut next_proposal_id__ = None;
+                let mut budget_line__ = None;
+                let mut cancel__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::NextProposalId => {
-                            if next_proposal_id__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("nextProposalId"));
+                        GeneratedField::BudgetLine => {
+                            if budget_line__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("budgetLine"));
                             }
-                            next_proposal_id__ = 
-                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            budget_line__ = map_.next_value()?;
+                        }
+                        GeneratedField::Cancel => {
+                            if cancel__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("cancel"));
+                            }
+                            cancel__ = 
+                                Some(map_.next_value::<Vec<::pbjson::private::NumberDeserialize<_>>>()?
+                                    .into_iter().map(|x| x.0).collect())
                             ;
                         }
                         GeneratedField::__SkipField__ => {
@@ -2967,15 +6622,16 @@ impl<'de> serde::Deserialize<'de> for NextProposalIdResponse {
                         }
                     }
                 }
-                Ok(NextProposalIdResponse {
-                    next_proposal_id: next_proposal_id__.unwrap_or_default(),
+                Ok(proposal::RecurringBudget {
+                    budget_line: budget_line__,
+                    cancel: cancel__.unwrap_or_default(),
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.core.component.governance.v1.NextProposalIdResponse", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.core.component.governance.v1.Proposal.RecurringBudget", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for Proposal {
+impl serde::Serialize for proposal::Signaling {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -2983,93 +6639,29 @@ impl serde::Serialize for Proposal {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.id != 0 {
-            len += 1;
-        }
-        if !self.title.is_empty() {
-            len += 1;
-        }
-        if !self.description.is_empty() {
-            len += 1;
-        }
-        if self.payload.is_some() {
+        if !self.commit.is_empty() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.governance.v1.Proposal", len)?;
-        if self.id != 0 {
-            #[allow(clippy::needless_borrow)]
-            struct_ser.serialize_field("id", ToString::to_string(&self.id).as_str())?;
-        }
-        if !self.title.is_empty() {
-            struct_ser.serialize_field("title", &self.title)?;
-        }
-        if !self.description.is_empty() {
-            struct_ser.serialize_field("description", &self.description)?;
-        }
-        if let Some(v) = self.payload.as_ref() {
-            match v {
-                proposal::Payload::Signaling(v) => {
-                    struct_ser.serialize_field("signaling", v)?;
-                }
-                proposal::Payload::Emergency(v) => {
-                    struct_ser.serialize_field("emergency", v)?;
-                }
-                proposal::Payload::ParameterChange(v) => {
-                    struct_ser.serialize_field("parameterChange", v)?;
-                }
-                proposal::Payload::CommunityPoolSpend(v) => {
-                    struct_ser.serialize_field("communityPoolSpend", v)?;
-                }
-                proposal::Payload::UpgradePlan(v) => {
-                    struct_ser.serialize_field("upgradePlan", v)?;
-                }
-                proposal::Payload::FreezeIbcClient(v) => {
-                    struct_ser.serialize_field("freezeIbcClient", v)?;
-                }
-                proposal::Payload::UnfreezeIbcClient(v) => {
-                    struct_ser.serialize_field("unfreezeIbcClient", v)?;
-                }
-            }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.governance.v1.Proposal.Signaling", len)?;
+        if !self.commit.is_empty() {
+            struct_ser.serialize_field("commit", &self.commit)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for Proposal {
+impl<'de> serde::Deserialize<'de> for proposal::Signaling {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "id",
-            "title",
-            "description",
-            "signaling",
-            "emergency",
-            "parameter_change",
-            "parameterChange",
-            "community_pool_spend",
-            "communityPoolSpend",
-            "upgrade_plan",
-            "upgradePlan",
-            "freeze_ibc_client",
-            "freezeIbcClient",
-            "unfreeze_ibc_client",
-            "unfreezeIbcClient",
+            "commit",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            Id,
-            Title,
-            Description,
-            Signaling,
-            Emergency,
-            ParameterChange,
-            CommunityPoolSpend,
-            UpgradePlan,
-            FreezeIbcClient,
-            UnfreezeIbcClient,
+            Commit,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -3092,16 +6684,7 @@ impl<'de> serde::Deserialize<'de> for Proposal {
                         E: serde::de::Error,
                     {
                         match value {
-                            "id" => Ok(GeneratedField::Id),
-                            "title" => Ok(GeneratedField::Title),
-                            "description" => Ok(GeneratedField::Description),
-                            "signaling" => Ok(GeneratedField::Signaling),
-                            "emergency" => Ok(GeneratedField::Emergency),
-                            "parameterChange" | "parameter_change" => Ok(GeneratedField::ParameterChange),
-                            "communityPoolSpend" | "community_pool_spend" => Ok(GeneratedField::CommunityPoolSpend),
-                            "upgradePlan" | "upgrade_plan" => Ok(GeneratedField::UpgradePlan),
-                            "freezeIbcClient" | "freeze_ibc_client" => Ok(GeneratedField::FreezeIbcClient),
-                            "unfreezeIbcClient" | "unfreeze_ibc_client" => Ok(GeneratedField::UnfreezeIbcClient),
+                            "commit" => Ok(GeneratedField::Commit),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -3111,108 +6694,39 @@ impl<'de> serde::Deserialize<'de> for Proposal {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = Proposal;
+            type Value = proposal::Signaling;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.core.component.governance.v1.Proposal")
+                formatter.write_str("struct penumbra.core.component.governance.v1.Proposal.Signaling")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<Proposal, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<proposal::Signaling, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut id__ = None;
-                let mut title__ = None;
-                let mut description__ = None;
-                let mut payload__ = None;
+                let mut commit__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::Id => {
-                            if id__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("id"));
-                            }
-                            id__ = 
-                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
-                            ;
-                        }
-                        GeneratedField::Title => {
-                            if title__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("title"));
-                            }
-                            title__ = Some(map_.next_value()?);
-                        }
-                        GeneratedField::Description => {
-                            if description__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("description"));
-                            }
-                            description__ = Some(map_.next_value()?);
-                        }
-                        GeneratedField::Signaling => {
-                            if payload__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("signaling"));
-                            }
-                            payload__ = map_.next_value::<::std::option::Option<_>>()?.map(proposal::Payload::Signaling)
-;
-                        }
-                        GeneratedField::Emergency => {
-                            if payload__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("emergency"));
-                            }
-                            payload__ = map_.next_value::<::std::option::Option<_>>()?.map(proposal::Payload::Emergency)
-;
-                        }
-                        GeneratedField::ParameterChange => {
-                            if payload__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("parameterChange"));
-                            }
-                            payload__ = map_.next_value::<::std::option::Option<_>>()?.map(proposal::Payload::ParameterChange)
-;
-                        }
-                        GeneratedField::CommunityPoolSpend => {
-                            if payload__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("communityPoolSpend"));
-                            }
-                            payload__ = map_.next_value::<::std::option::Option<_>>()?.map(proposal::Payload::CommunityPoolSpend)
-;
-                        }
-                        GeneratedField::UpgradePlan => {
-                            if payload__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("upgradePlan"));
-                            }
-                            payload__ = map_.next_value::<::std::option::Option<_>>()?.map(proposal::Payload::UpgradePlan)
-;
-                        }
-                        GeneratedField::FreezeIbcClient => {
-                            if payload__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("freezeIbcClient"));
-                            }
-                            payload__ = map_.next_value::<::std::option::Option<_>>()?.map(proposal::Payload::FreezeIbcClient)
-;
-                        }
-                        GeneratedField::UnfreezeIbcClient => {
-                            if payload__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("unfreezeIbcClient"));
+                        GeneratedField::Commit => {
+                            if commit__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("commit"));
                             }
-                            payload__ = map_.next_value::<::std::option::Option<_>>()?.map(proposal::Payload::UnfreezeIbcClient)
-;
+                            commit__ = Some(map_.next_value()?);
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(Proposal {
-                    id: id__.unwrap_or_default(),
-                    title: title__.unwrap_or_default(),
-                    description: description__.unwrap_or_default(),
-                    payload: payload__,
+                Ok(proposal::Signaling {
+                    commit: commit__.unwrap_or_default(),
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.core.component.governance.v1.Proposal", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.core.component.governance.v1.Proposal.Signaling", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for proposal::CommunityPoolSpend {
+impl serde::Serialize for proposal::UnfreezeIbcClient {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -3220,30 +6734,30 @@ impl serde::Serialize for proposal::CommunityPoolSpend {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.transaction_plan.is_some() {
+        if !self.client_id.is_empty() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.governance.v1.Proposal.CommunityPoolSpend", len)?;
-        if let Some(v) = self.transaction_plan.as_ref() {
-            struct_ser.serialize_field("transactionPlan", v)?;
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.governance.v1.Proposal.UnfreezeIbcClient", len)?;
+        if !self.client_id.is_empty() {
+            struct_ser.serialize_field("clientId", &self.client_id)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for proposal::CommunityPoolSpend {
+impl<'de> serde::Deserialize<'de> for proposal::UnfreezeIbcClient {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "transaction_plan",
-            "transactionPlan",
+            "client_id",
+            "clientId",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            TransactionPlan,
+            ClientId,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -3266,7 +6780,7 @@ impl<'de> serde::Deserialize<'de> for proposal::CommunityPoolSpend {
                         E: serde::de::Error,
                     {
                         match value {
-                            "transactionPlan" | "transaction_plan" => Ok(GeneratedField::TransactionPlan),
+                            "clientId" | "client_id" => Ok(GeneratedField::ClientId),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -3276,39 +6790,39 @@ impl<'de> serde::Deserialize<'de> for proposal::CommunityPoolSpend {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = proposal::CommunityPoolSpend;
+            type Value = proposal::UnfreezeIbcClient;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.core.component.governance.v1.Proposal.CommunityPoolSpend")
+                formatter.write_str("struct penumbra.core.component.governance.v1.Proposal.UnfreezeIbcClient")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<proposal::CommunityPoolSpend, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<proposal::UnfreezeIbcClient, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut transaction_plan__ = None;
+                let mut client_id__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::TransactionPlan => {
-                            if transaction_plan__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("transactionPlan"));
+                        GeneratedField::ClientId => {
+                            if client_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("clientId"));
                             }
-                            transaction_plan__ = map_.next_value()?;
+                            client_id__ = Some(map_.next_value()?);
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(proposal::CommunityPoolSpend {
-                    transaction_plan: transaction_plan__,
+                Ok(proposal::UnfreezeIbcClient {
+                    client_id: client_id__.unwrap_or_default(),
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.core.component.governance.v1.Proposal.CommunityPoolSpend", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.core.component.governance.v1.Proposal.UnfreezeIbcClient", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for proposal::Emergency {
+impl serde::Serialize for proposal::UpgradePlan {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -3316,30 +6830,47 @@ impl serde::Serialize for proposal::Emergency {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.halt_chain {
+        if self.height != 0 {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.governance.v1.Proposal.Emergency", len)?;
-        if self.halt_chain {
-            struct_ser.serialize_field("haltChain", &self.halt_chain)?;
+        if !self.version.is_empty() {
+            len += 1;
+        }
+        if !self.binary_hash.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.governance.v1.Proposal.UpgradePlan", len)?;
+        if self.height != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("height", ToString::to_string(&self.height).as_str())?;
+        }
+        if !self.version.is_empty() {
+            struct_ser.serialize_field("version", &self.version)?;
+        }
+        if !self.binary_hash.is_empty() {
+            struct_ser.serialize_field("binaryHash", &self.binary_hash)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for proposal::Emergency {
+impl<'de> serde::Deserialize<'de> for proposal::UpgradePlan {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "halt_chain",
-            "haltChain",
+            "height",
+            "version",
+            "binary_hash",
+            "binaryHash",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            HaltChain,
+            Height,
+            Version,
+            BinaryHash,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -3362,7 +6893,9 @@ impl<'de> serde::Deserialize<'de> for proposal::Emergency {
                         E: serde::de::Error,
                     {
                         match value {
-                            "haltChain" | "halt_chain" => Ok(GeneratedField::HaltChain),
+                            "height" => Ok(GeneratedField::Height),
+                            "version" => Ok(GeneratedField::Version),
+                            "binaryHash" | "binary_hash" => Ok(GeneratedField::BinaryHash),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -3372,39 +6905,57 @@ impl<'de> serde::Deserialize<'de> for proposal::Emergency {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = proposal::Emergency;
+            type Value = proposal::UpgradePlan;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.core.component.governance.v1.Proposal.Emergency")
+                formatter.write_str("struct penumbra.core.component.governance.v1.Proposal.UpgradePlan")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<proposal::Emergency, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<proposal::UpgradePlan, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut halt_chain__ = None;
+                let mut height__ = None;
+                let mut version__ = None;
+                let mut binary_hash__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::HaltChain => {
-                            if halt_chain__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("haltChain"));
+                        GeneratedField::Height => {
+                            if height__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("height"));
                             }
-                            halt_chain__ = Some(map_.next_value()?);
+                            height__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::Version => {
+                            if version__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("version"));
+                            }
+                            version__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::BinaryHash => {
+                            if binary_hash__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("binaryHash"));
+                            }
+                            binary_hash__ = Some(map_.next_value()?);
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(proposal::Emergency {
-                    halt_chain: halt_chain__.unwrap_or_default(),
+                Ok(proposal::UpgradePlan {
+                    height: height__.unwrap_or_default(),
+                    version: version__.unwrap_or_default(),
+                    binary_hash: binary_hash__.unwrap_or_default(),
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.core.component.governance.v1.Proposal.Emergency", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.core.component.governance.v1.Proposal.UpgradePlan", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for proposal::FreezeIbcClient {
+impl serde::Serialize for ProposalDataRequest {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -3412,30 +6963,31 @@ impl serde::Serialize for proposal::FreezeIbcClient {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if !self.client_id.is_empty() {
+        if self.proposal_id != 0 {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.governance.v1.Proposal.FreezeIbcClient", len)?;
-        if !self.client_id.is_empty() {
-            struct_ser.serialize_field("clientId", &self.client_id)?;
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.governance.v1.ProposalDataRequest", len)?;
+        if self.proposal_id != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("proposalId", ToString::to_string(&self.proposal_id).as_str())?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for proposal::FreezeIbcClient {
+impl<'de> serde::Deserialize<'de> for ProposalDataRequest {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "client_id",
-            "clientId",
+            "proposal_id",
+            "proposalId",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            ClientId,
+            ProposalId,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -3458,7 +7010,7 @@ impl<'de> serde::Deserialize<'de> for proposal::FreezeIbcClient {
                         E: serde::de::Error,
                     {
                         match value {
-                            "clientId" | "client_id" => Ok(GeneratedField::ClientId),
+                            "proposalId" | "proposal_id" => Ok(GeneratedField::ProposalId),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -3468,39 +7020,41 @@ impl<'de> serde::Deserialize<'de> for proposal::FreezeIbcClient {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = proposal::FreezeIbcClient;
+            type Value = ProposalDataRequest;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.core.component.governance.v1.Proposal.FreezeIbcClient")
+                formatter.write_str("struct penumbra.core.component.governance.v1.ProposalDataRequest")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<proposal::FreezeIbcClient, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ProposalDataRequest, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut client_id__ = None;
+                let mut proposal_id__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::ClientId => {
-                            if client_id__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("clientId"));
+                        GeneratedField::ProposalId => {
+                            if proposal_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("proposalId"));
                             }
-                            client_id__ = Some(map_.next_value()?);
+                            proposal_id__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(proposal::FreezeIbcClient {
-                    client_id: client_id__.unwrap_or_default(),
+                Ok(ProposalDataRequest {
+                    proposal_id: proposal_id__.unwrap_or_default(),
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.core.component.governance.v1.Proposal.FreezeIbcClient", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.core.component.governance.v1.ProposalDataRequest", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for proposal::ParameterChange {
+impl serde::Serialize for ProposalDataResponse {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -3508,39 +7062,85 @@ impl serde::Serialize for proposal::ParameterChange {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.old_parameters.is_some() {
+        if self.proposal.is_some() {
             len += 1;
         }
-        if self.new_parameters.is_some() {
+        if self.start_block_height != 0 {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.governance.v1.Proposal.ParameterChange", len)?;
-        if let Some(v) = self.old_parameters.as_ref() {
-            struct_ser.serialize_field("oldParameters", v)?;
+        if self.end_block_height != 0 {
+            len += 1;
         }
-        if let Some(v) = self.new_parameters.as_ref() {
-            struct_ser.serialize_field("newParameters", v)?;
+        if self.start_position != 0 {
+            len += 1;
+        }
+        if self.state.is_some() {
+            len += 1;
+        }
+        if self.proposal_deposit_amount.is_some() {
+            len += 1;
+        }
+        if self.proposal_deposit_asset_id.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.governance.v1.ProposalDataResponse", len)?;
+        if let Some(v) = self.proposal.as_ref() {
+            struct_ser.serialize_field("proposal", v)?;
+        }
+        if self.start_block_height != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("startBlockHeight", ToString::to_string(&self.start_block_height).as_str())?;
+        }
+        if self.end_block_height != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("endBlockHeight", ToString::to_string(&self.end_block_height).as_str())?;
+        }
+        if self.start_position != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("startPosition", ToString::to_string(&self.start_position).as_str())?;
+        }
+        if let Some(v) = self.state.as_ref() {
+            struct_ser.serialize_field("state", v)?;
+        }
+        if let Some(v) = self.proposal_deposit_amount.as_ref() {
+            struct_ser.serialize_field("proposalDepositAmount", v)?;
+        }
+        if let Some(v) = self.proposal_deposit_asset_id.as_ref() {
+            struct_ser.serialize_field("proposalDepositAssetId", v)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for proposal::ParameterChange {
+impl<'de> serde::Deserialize<'de> for ProposalDataResponse {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "old_parameters",
-            "oldParameters",
-            "new_parameters",
-            "newParameters",
+            "proposal",
+            "start_block_height",
+            "startBlockHeight",
+            "end_block_height",
+            "endBlockHeight",
+            "start_position",
+            "startPosition",
+            "state",
+            "proposal_deposit_amount",
+            "proposalDepositAmount",
+            "proposal_deposit_asset_id",
+            "proposalDepositAssetId",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            OldParameters,
-            NewParameters,
+            Proposal,
+            StartBlockHeight,
+            EndBlockHeight,
+            StartPosition,
+            State,
+            ProposalDepositAmount,
+            ProposalDepositAssetId,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -3563,58 +7163,109 @@ impl<'de> serde::Deserialize<'de> for proposal::ParameterChange {
                         E: serde::de::Error,
                     {
                         match value {
-                            "oldParameters" | "old_parameters" => Ok(GeneratedField::OldParameters),
-                            "newParameters" | "new_parameters" => Ok(GeneratedField::NewParameters),
+                            "proposal" => Ok(GeneratedField::Proposal),
+                            "startBlockHeight" | "start_block_height" => Ok(GeneratedField::StartBlockHeight),
+                            "endBlockHeight" | "end_block_height" => Ok(GeneratedField::EndBlockHeight),
+                            "startPosition" | "start_position" => Ok(GeneratedField::StartPosition),
+                            "state" => Ok(GeneratedField::State),
+                            "proposalDepositAmount" | "proposal_deposit_amount" => Ok(GeneratedField::ProposalDepositAmount),
+                            "proposalDepositAssetId" | "proposal_deposit_asset_id" => Ok(GeneratedField::ProposalDepositAssetId),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
-                    }
-                }
-                deserializer.deserialize_identifier(GeneratedVisitor)
-            }
-        }
-        struct GeneratedVisitor;
-        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = proposal::ParameterChange;
-
-            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.core.component.governance.v1.Proposal.ParameterChange")
-            }
-
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<proposal::ParameterChange, V::Error>
-                where
-                    V: serde::de::MapAccess<'de>,
-            {
-                let mut old_parameters__ = None;
-                let mut new_parameters__ = None;
-                while let Some(k) = map_.next_key()? {
-                    match k {
-                        GeneratedField::OldParameters => {
-                            if old_parameters__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("oldParameters"));
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = ProposalDataResponse;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.governance.v1.ProposalDataResponse")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ProposalDataResponse, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut proposal__ = None;
+                let mut start_block_height__ = None;
+                let mut end_block_height__ = None;
+                let mut start_position__ = None;
+                let mut state__ = None;
+                let mut proposal_deposit_amount__ = None;
+                let mut proposal_deposit_asset_id__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Proposal => {
+                            if proposal__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("proposal"));
+                            }
+                            proposal__ = map_.next_value()?;
+                        }
+                        GeneratedField::StartBlockHeight => {
+                            if start_block_height__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("startBlockHeight"));
+                            }
+                            start_block_height__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::EndBlockHeight => {
+                            if end_block_height__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("endBlockHeight"));
+                            }
+                            end_block_height__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::StartPosition => {
+                            if start_position__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("startPosition"));
+                            }
+                            start_position__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::State => {
+                            if state__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("state"));
+                            }
+                            state__ = map_.next_value()?;
+                        }
+                        GeneratedField::ProposalDepositAmount => {
+                            if proposal_deposit_amount__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("proposalDepositAmount"));
                             }
-                            old_parameters__ = map_.next_value()?;
+                            proposal_deposit_amount__ = map_.next_value()?;
                         }
-                        GeneratedField::NewParameters => {
-                            if new_parameters__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("newParameters"));
+                        GeneratedField::ProposalDepositAssetId => {
+                            if proposal_deposit_asset_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("proposalDepositAssetId"));
                             }
-                            new_parameters__ = map_.next_value()?;
+                            proposal_deposit_asset_id__ = map_.next_value()?;
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(proposal::ParameterChange {
-                    old_parameters: old_parameters__,
-                    new_parameters: new_parameters__,
+                Ok(ProposalDataResponse {
+                    proposal: proposal__,
+                    start_block_height: start_block_height__.unwrap_or_default(),
+                    end_block_height: end_block_height__.unwrap_or_default(),
+                    start_position: start_position__.unwrap_or_default(),
+                    state: state__,
+                    proposal_deposit_amount: proposal_deposit_amount__,
+                    proposal_deposit_asset_id: proposal_deposit_asset_id__,
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.core.component.governance.v1.Proposal.ParameterChange", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.core.component.governance.v1.ProposalDataResponse", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for proposal::Signaling {
+impl serde::Serialize for ProposalDepositClaim {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -3622,29 +7273,56 @@ impl serde::Serialize for proposal::Signaling {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if !self.commit.is_empty() {
+        if self.proposal != 0 {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.governance.v1.Proposal.Signaling", len)?;
-        if !self.commit.is_empty() {
-            struct_ser.serialize_field("commit", &self.commit)?;
+        if self.deposit_amount.is_some() {
+            len += 1;
+        }
+        if self.outcome.is_some() {
+            len += 1;
+        }
+        if self.deposit_asset_id.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.governance.v1.ProposalDepositClaim", len)?;
+        if self.proposal != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("proposal", ToString::to_string(&self.proposal).as_str())?;
+        }
+        if let Some(v) = self.deposit_amount.as_ref() {
+            struct_ser.serialize_field("depositAmount", v)?;
+        }
+        if let Some(v) = self.outcome.as_ref() {
+            struct_ser.serialize_field("outcome", v)?;
+        }
+        if let Some(v) = self.deposit_asset_id.as_ref() {
+            struct_ser.serialize_field("depositAssetId", v)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for proposal::Signaling {
+impl<'de> serde::Deserialize<'de> for ProposalDepositClaim {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "commit",
+            "proposal",
+            "deposit_amount",
+            "depositAmount",
+            "outcome",
+            "deposit_asset_id",
+            "depositAssetId",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            Commit,
+            Proposal,
+            DepositAmount,
+            Outcome,
+            DepositAssetId,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -3667,7 +7345,10 @@ impl<'de> serde::Deserialize<'de> for proposal::Signaling {
                         E: serde::de::Error,
                     {
                         match value {
-                            "commit" => Ok(GeneratedField::Commit),
+                            "proposal" => Ok(GeneratedField::Proposal),
+                            "depositAmount" | "deposit_amount" => Ok(GeneratedField::DepositAmount),
+                            "outcome" => Ok(GeneratedField::Outcome),
+                            "depositAssetId" | "deposit_asset_id" => Ok(GeneratedField::DepositAssetId),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -3677,39 +7358,65 @@ impl<'de> serde::Deserialize<'de> for proposal::Signaling {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = proposal::Signaling;
+            type Value = ProposalDepositClaim;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.core.component.governance.v1.Proposal.Signaling")
+                formatter.write_str("struct penumbra.core.component.governance.v1.ProposalDepositClaim")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<proposal::Signaling, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ProposalDepositClaim, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut commit__ = None;
+                let mut proposal__ = None;
+                let mut deposit_amount__ = None;
+                let mut outcome__ = None;
+                let mut deposit_asset_id__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::Commit => {
-                            if commit__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("commit"));
+                        GeneratedField::Proposal => {
+                            if proposal__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("proposal"));
                             }
-                            commit__ = Some(map_.next_value()?);
+                            proposal__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::DepositAmount => {
+                            if deposit_amount__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("depositAmount"));
+                            }
+                            deposit_amount__ = map_.next_value()?;
+                        }
+                        GeneratedField::Outcome => {
+                            if outcome__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("outcome"));
+                            }
+                            outcome__ = map_.next_value()?;
+                        }
+                        GeneratedField::DepositAssetId => {
+                            if deposit_asset_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("depositAssetId"));
+                            }
+                            deposit_asset_id__ = map_.next_value()?;
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(proposal::Signaling {
-                    commit: commit__.unwrap_or_default(),
+                Ok(ProposalDepositClaim {
+                    proposal: proposal__.unwrap_or_default(),
+                    deposit_amount: deposit_amount__,
+                    outcome: outcome__,
+                    deposit_asset_id: deposit_asset_id__,
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.core.component.governance.v1.Proposal.Signaling", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.core.component.governance.v1.ProposalDepositClaim", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for proposal::UnfreezeIbcClient {
+impl serde::Serialize for ProposalInfoRequest {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -3717,30 +7424,31 @@ impl serde::Serialize for proposal::UnfreezeIbcClient {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if !self.client_id.is_empty() {
+        if self.proposal_id != 0 {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.governance.v1.Proposal.UnfreezeIbcClient", len)?;
-        if !self.client_id.is_empty() {
-            struct_ser.serialize_field("clientId", &self.client_id)?;
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.governance.v1.ProposalInfoRequest", len)?;
+        if self.proposal_id != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("proposalId", ToString::to_string(&self.proposal_id).as_str())?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for proposal::UnfreezeIbcClient {
+impl<'de> serde::Deserialize<'de> for ProposalInfoRequest {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "client_id",
-            "clientId",
+            "proposal_id",
+            "proposalId",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            ClientId,
+            ProposalId,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -3763,7 +7471,7 @@ impl<'de> serde::Deserialize<'de> for proposal::UnfreezeIbcClient {
                         E: serde::de::Error,
                     {
                         match value {
-                            "clientId" | "client_id" => Ok(GeneratedField::ClientId),
+                            "proposalId" | "proposal_id" => Ok(GeneratedField::ProposalId),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -3773,39 +7481,41 @@ impl<'de> serde::Deserialize<'de> for proposal::UnfreezeIbcClient {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = proposal::UnfreezeIbcClient;
+            type Value = ProposalInfoRequest;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.core.component.governance.v1.Proposal.UnfreezeIbcClient")
+                formatter.write_str("struct penumbra.core.component.governance.v1.ProposalInfoRequest")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<proposal::UnfreezeIbcClient, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ProposalInfoRequest, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut client_id__ = None;
+                let mut proposal_id__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::ClientId => {
-                            if client_id__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("clientId"));
+                        GeneratedField::ProposalId => {
+                            if proposal_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("proposalId"));
                             }
-                            client_id__ = Some(map_.next_value()?);
+                            proposal_id__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(proposal::UnfreezeIbcClient {
-                    client_id: client_id__.unwrap_or_default(),
+                Ok(ProposalInfoRequest {
+                    proposal_id: proposal_id__.unwrap_or_default(),
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.core.component.governance.v1.Proposal.UnfreezeIbcClient", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.core.component.governance.v1.ProposalInfoRequest", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for proposal::UpgradePlan {
+impl serde::Serialize for ProposalInfoResponse {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -3813,30 +7523,41 @@ impl serde::Serialize for proposal::UpgradePlan {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.height != 0 {
+        if self.start_block_height != 0 {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.governance.v1.Proposal.UpgradePlan", len)?;
-        if self.height != 0 {
+        if self.start_position != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.governance.v1.ProposalInfoResponse", len)?;
+        if self.start_block_height != 0 {
             #[allow(clippy::needless_borrow)]
-            struct_ser.serialize_field("height", ToString::to_string(&self.height).as_str())?;
+            struct_ser.serialize_field("startBlockHeight", ToString::to_string(&self.start_block_height).as_str())?;
+        }
+        if self.start_position != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("startPosition", ToString::to_string(&self.start_position).as_str())?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for proposal::UpgradePlan {
+impl<'de> serde::Deserialize<'de> for ProposalInfoResponse {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "height",
+            "start_block_height",
+            "startBlockHeight",
+            "start_position",
+            "startPosition",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            Height,
+            StartBlockHeight,
+            StartPosition,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -3859,7 +7580,8 @@ impl<'de> serde::Deserialize<'de> for proposal::UpgradePlan {
                         E: serde::de::Error,
                     {
                         match value {
-                            "height" => Ok(GeneratedField::Height),
+                            "startBlockHeight" | "start_block_height" => Ok(GeneratedField::StartBlockHeight),
+                            "startPosition" | "start_position" => Ok(GeneratedField::StartPosition),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -3869,24 +7591,33 @@ impl<'de> serde::Deserialize<'de> for proposal::UpgradePlan {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = proposal::UpgradePlan;
+            type Value = ProposalInfoResponse;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.core.component.governance.v1.Proposal.UpgradePlan")
+                formatter.write_str("struct penumbra.core.component.governance.v1.ProposalInfoResponse")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<proposal::UpgradePlan, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ProposalInfoResponse, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut height__ = None;
+                let mut start_block_height__ = None;
+                let mut start_position__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::Height => {
-                            if height__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("height"));
+                        GeneratedField::StartBlockHeight => {
+                            if start_block_height__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("startBlockHeight"));
                             }
-                            height__ = 
+                            start_block_height__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::StartPosition => {
+                            if start_position__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("startPosition"));
+                            }
+                            start_position__ = 
                                 Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
                             ;
                         }
@@ -3895,15 +7626,16 @@ impl<'de> serde::Deserialize<'de> for proposal::UpgradePlan {
                         }
                     }
                 }
-                Ok(proposal::UpgradePlan {
-                    height: height__.unwrap_or_default(),
+                Ok(ProposalInfoResponse {
+                    start_block_height: start_block_height__.unwrap_or_default(),
+                    start_position: start_position__.unwrap_or_default(),
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.core.component.governance.v1.Proposal.UpgradePlan", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.core.component.governance.v1.ProposalInfoResponse", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for ProposalDataRequest {
+impl serde::Serialize for ProposalListRequest {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -3911,31 +7643,29 @@ impl serde::Serialize for ProposalDataRequest {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.proposal_id != 0 {
+        if self.inactive {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.governance.v1.ProposalDataRequest", len)?;
-        if self.proposal_id != 0 {
-            #[allow(clippy::needless_borrow)]
-            struct_ser.serialize_field("proposalId", ToString::to_string(&self.proposal_id).as_str())?;
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.governance.v1.ProposalListRequest", len)?;
+        if self.inactive {
+            struct_ser.serialize_field("inactive", &self.inactive)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for ProposalDataRequest {
+impl<'de> serde::Deserialize<'de> for ProposalListRequest {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "proposal_id",
-            "proposalId",
+            "inactive",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            ProposalId,
+            Inactive,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -3958,7 +7688,7 @@ impl<'de> serde::Deserialize<'de> for ProposalDataRequest {
                         E: serde::de::Error,
                     {
                         match value {
-                            "proposalId" | "proposal_id" => Ok(GeneratedField::ProposalId),
+                            "inactive" => Ok(GeneratedField::Inactive),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -3968,41 +7698,39 @@ impl<'de> serde::Deserialize<'de> for ProposalDataRequest {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = ProposalDataRequest;
+            type Value = ProposalListRequest;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.core.component.governance.v1.ProposalDataRequest")
+                formatter.write_str("struct penumbra.core.component.governance.v1.ProposalListRequest")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ProposalDataRequest, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ProposalListRequest, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut proposal_id__ = None;
+                let mut inactive__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::ProposalId => {
-                            if proposal_id__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("proposalId"));
+                        GeneratedField::Inactive => {
+                            if inactive__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("inactive"));
                             }
-                            proposal_id__ = 
-                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
-                            ;
+                            inactive__ = Some(map_.next_value()?);
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(ProposalDataRequest {
-                    proposal_id: proposal_id__.unwrap_or_default(),
+                Ok(ProposalListRequest {
+                    inactive: inactive__.unwrap_or_default(),
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.core.component.governance.v1.ProposalDataRequest", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.core.component.governance.v1.ProposalListRequest", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for ProposalDataResponse {
+impl serde::Serialize for ProposalListResponse {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -4025,10 +7753,7 @@ impl serde::Serialize for ProposalDataResponse {
         if self.state.is_some() {
             len += 1;
         }
-        if self.proposal_deposit_amount.is_some() {
-            len += 1;
-        }
-        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.governance.v1.ProposalDataResponse", len)?;
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.governance.v1.ProposalListResponse", len)?;
         if let Some(v) = self.proposal.as_ref() {
             struct_ser.serialize_field("proposal", v)?;
         }
@@ -4047,13 +7772,10 @@ impl serde::Serialize for ProposalDataResponse {
         if let Some(v) = self.state.as_ref() {
             struct_ser.serialize_field("state", v)?;
         }
-        if let Some(v) = self.proposal_deposit_amount.as_ref() {
-            struct_ser.serialize_field("proposalDepositAmount", v)?;
-        }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for ProposalDataResponse {
+impl<'de> serde::Deserialize<'de> for ProposalListResponse {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
@@ -4068,8 +7790,6 @@ impl<'de> serde::Deserialize<'de> for ProposalDataResponse {
             "start_position",
             "startPosition",
             "state",
-            "proposal_deposit_amount",
-            "proposalDepositAmount",
         ];
 
         #[allow(clippy::enum_variant_names)]
@@ -4079,7 +7799,6 @@ impl<'de> serde::Deserialize<'de> for ProposalDataResponse {
             EndBlockHeight,
             StartPosition,
             State,
-            ProposalDepositAmount,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -4107,7 +7826,6 @@ impl<'de> serde::Deserialize<'de> for ProposalDataResponse {
                             "endBlockHeight" | "end_block_height" => Ok(GeneratedField::EndBlockHeight),
                             "startPosition" | "start_position" => Ok(GeneratedField::StartPosition),
                             "state" => Ok(GeneratedField::State),
-                            "proposalDepositAmount" | "proposal_deposit_amount" => Ok(GeneratedField::ProposalDepositAmount),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -4117,13 +7835,13 @@ impl<'de> serde::Deserialize<'de> for ProposalDataResponse {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = ProposalDataResponse;
+            type Value = ProposalListResponse;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.core.component.governance.v1.ProposalDataResponse")
+                formatter.write_str("struct penumbra.core.component.governance.v1.ProposalListResponse")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ProposalDataResponse, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ProposalListResponse, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
@@ -4132,7 +7850,6 @@ impl<'de> serde::Deserialize<'de> for ProposalDataResponse {
                 let mut end_block_height__ = None;
                 let mut start_position__ = None;
                 let mut state__ = None;
-                let mut proposal_deposit_amount__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
                         GeneratedField::Proposal => {
@@ -4171,79 +7888,267 @@ impl<'de> serde::Deserialize<'de> for ProposalDataResponse {
                             }
                             state__ = map_.next_value()?;
                         }
-                        GeneratedField::ProposalDepositAmount => {
-                            if proposal_deposit_amount__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("proposalDepositAmount"));
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(ProposalListResponse {
+                    proposal: proposal__,
+                    start_block_height: start_block_height__.unwrap_or_default(),
+                    end_block_height: end_block_height__.unwrap_or_default(),
+                    start_position: start_position__.unwrap_or_default(),
+                    state: state__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.governance.v1.ProposalListResponse", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for ProposalOutcome {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.outcome.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.governance.v1.ProposalOutcome", len)?;
+        if let Some(v) = self.outcome.as_ref() {
+            match v {
+                proposal_outcome::Outcome::Passed(v) => {
+                    struct_ser.serialize_field("passed", v)?;
+                }
+                proposal_outcome::Outcome::Failed(v) => {
+                    struct_ser.serialize_field("failed", v)?;
+                }
+                proposal_outcome::Outcome::Slashed(v) => {
+                    struct_ser.serialize_field("slashed", v)?;
+                }
+            }
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for ProposalOutcome {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "passed",
+            "failed",
+            "slashed",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Passed,
+            Failed,
+            Slashed,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "passed" => Ok(GeneratedField::Passed),
+                            "failed" => Ok(GeneratedField::Failed),
+                            "slashed" => Ok(GeneratedField::Slashed),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = ProposalOutcome;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.governance.v1.ProposalOutcome")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ProposalOutcome, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut outcome__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Passed => {
+                            if outcome__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("passed"));
+                            }
+                            outcome__ = map_.next_value::<::std::option::Option<_>>()?.map(proposal_outcome::Outcome::Passed)
+;
+                        }
+                        GeneratedField::Failed => {
+                            if outcome__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("failed"));
+                            }
+                            outcome__ = map_.next_value::<::std::option::Option<_>>()?.map(proposal_outcome::Outcome::Failed)
+;
+                        }
+                        GeneratedField::Slashed => {
+                            if outcome__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("slashed"));
+                            }
+                            outcome__ = map_.next_value::<::std::option::Option<_>>()?.map(proposal_outcome::Outcome::Slashed)
+;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(ProposalOutcome {
+                    outcome: outcome__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.governance.v1.ProposalOutcome", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for proposal_outcome::Failed {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.withdrawn.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.governance.v1.ProposalOutcome.Failed", len)?;
+        if let Some(v) = self.withdrawn.as_ref() {
+            struct_ser.serialize_field("withdrawn", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for proposal_outcome::Failed {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "withdrawn",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Withdrawn,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "withdrawn" => Ok(GeneratedField::Withdrawn),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = proposal_outcome::Failed;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.governance.v1.ProposalOutcome.Failed")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<proposal_outcome::Failed, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut withdrawn__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Withdrawn => {
+                            if withdrawn__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("withdrawn"));
                             }
-                            proposal_deposit_amount__ = map_.next_value()?;
+                            withdrawn__ = map_.next_value()?;
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(ProposalDataResponse {
-                    proposal: proposal__,
-                    start_block_height: start_block_height__.unwrap_or_default(),
-                    end_block_height: end_block_height__.unwrap_or_default(),
-                    start_position: start_position__.unwrap_or_default(),
-                    state: state__,
-                    proposal_deposit_amount: proposal_deposit_amount__,
+                Ok(proposal_outcome::Failed {
+                    withdrawn: withdrawn__,
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.core.component.governance.v1.ProposalDataResponse", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.core.component.governance.v1.ProposalOutcome.Failed", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for ProposalDepositClaim {
+impl serde::Serialize for proposal_outcome::Passed {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
         S: serde::Serializer,
     {
         use serde::ser::SerializeStruct;
-        let mut len = 0;
-        if self.proposal != 0 {
-            len += 1;
-        }
-        if self.deposit_amount.is_some() {
-            len += 1;
-        }
-        if self.outcome.is_some() {
-            len += 1;
-        }
-        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.governance.v1.ProposalDepositClaim", len)?;
-        if self.proposal != 0 {
-            #[allow(clippy::needless_borrow)]
-            struct_ser.serialize_field("proposal", ToString::to_string(&self.proposal).as_str())?;
-        }
-        if let Some(v) = self.deposit_amount.as_ref() {
-            struct_ser.serialize_field("depositAmount", v)?;
-        }
-        if let Some(v) = self.outcome.as_ref() {
-            struct_ser.serialize_field("outcome", v)?;
-        }
+        let len = 0;
+        let struct_ser = serializer.serialize_struct("penumbra.core.component.governance.v1.ProposalOutcome.Passed", len)?;
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for ProposalDepositClaim {
+impl<'de> serde::Deserialize<'de> for proposal_outcome::Passed {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "proposal",
-            "deposit_amount",
-            "depositAmount",
-            "outcome",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            Proposal,
-            DepositAmount,
-            Outcome,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -4265,12 +8170,7 @@ impl<'de> serde::Deserialize<'de> for ProposalDepositClaim {
                     where
                         E: serde::de::Error,
                     {
-                        match value {
-                            "proposal" => Ok(GeneratedField::Proposal),
-                            "depositAmount" | "deposit_amount" => Ok(GeneratedField::DepositAmount),
-                            "outcome" => Ok(GeneratedField::Outcome),
-                            _ => Ok(GeneratedField::__SkipField__),
-                        }
+                            Ok(GeneratedField::__SkipField__)
                     }
                 }
                 deserializer.deserialize_identifier(GeneratedVisitor)
@@ -4278,57 +8178,27 @@ impl<'de> serde::Deserialize<'de> for ProposalDepositClaim {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = ProposalDepositClaim;
+            type Value = proposal_outcome::Passed;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.core.component.governance.v1.ProposalDepositClaim")
+                formatter.write_str("struct penumbra.core.component.governance.v1.ProposalOutcome.Passed")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ProposalDepositClaim, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<proposal_outcome::Passed, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut proposal__ = None;
-                let mut deposit_amount__ = None;
-                let mut outcome__ = None;
-                while let Some(k) = map_.next_key()? {
-                    match k {
-                        GeneratedField::Proposal => {
-                            if proposal__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("proposal"));
-                            }
-                            proposal__ = 
-                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
-                            ;
-                        }
-                        GeneratedField::DepositAmount => {
-                            if deposit_amount__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("depositAmount"));
-                            }
-                            deposit_amount__ = map_.next_value()?;
-                        }
-                        GeneratedField::Outcome => {
-                            if outcome__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("outcome"));
-                            }
-                            outcome__ = map_.next_value()?;
-                        }
-                        GeneratedField::__SkipField__ => {
-                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
-                        }
-                    }
+                while map_.next_key::<GeneratedField>()?.is_some() {
+                    let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                 }
-                Ok(ProposalDepositClaim {
-                    proposal: proposal__.unwrap_or_default(),
-                    deposit_amount: deposit_amount__,
-                    outcome: outcome__,
+                Ok(proposal_outcome::Passed {
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.core.component.governance.v1.ProposalDepositClaim", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.core.component.governance.v1.ProposalOutcome.Passed", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for ProposalInfoRequest {
+impl serde::Serialize for proposal_outcome::Slashed {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -4336,31 +8206,29 @@ impl serde::Serialize for ProposalInfoRequest {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.proposal_id != 0 {
+        if self.withdrawn.is_some() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.governance.v1.ProposalInfoRequest", len)?;
-        if self.proposal_id != 0 {
-            #[allow(clippy::needless_borrow)]
-            struct_ser.serialize_field("proposalId", ToString::to_string(&self.proposal_id).as_str())?;
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.governance.v1.ProposalOutcome.Slashed", len)?;
+        if let Some(v) = self.withdrawn.as_ref() {
+            struct_ser.serialize_field("withdrawn", v)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for ProposalInfoRequest {
+impl<'de> serde::Deserialize<'de> for proposal_outcome::Slashed {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "proposal_id",
-            "proposalId",
+            "withdrawn",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            ProposalId,
+            Withdrawn,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -4383,7 +8251,7 @@ impl<'de> serde::Deserialize<'de> for ProposalInfoRequest {
                         E: serde::de::Error,
                     {
                         match value {
-                            "proposalId" | "proposal_id" => Ok(GeneratedField::ProposalId),
+                            "withdrawn" => Ok(GeneratedField::Withdrawn),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -4393,41 +8261,39 @@ impl<'de> serde::Deserialize<'de> for ProposalInfoRequest {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = ProposalInfoRequest;
+            type Value = proposal_outcome::Slashed;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.core.component.governance.v1.ProposalInfoRequest")
+                formatter.write_str("struct penumbra.core.component.governance.v1.ProposalOutcome.Slashed")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ProposalInfoRequest, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<proposal_outcome::Slashed, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut proposal_id__ = None;
+                let mut withdrawn__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::ProposalId => {
-                            if proposal_id__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("proposalId"));
+                        GeneratedField::Withdrawn => {
+                            if withdrawn__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("withdrawn"));
                             }
-                            proposal_id__ = 
-                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
-                            ;
+                            withdrawn__ = map_.next_value()?;
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(ProposalInfoRequest {
-                    proposal_id: proposal_id__.unwrap_or_default(),
+                Ok(proposal_outcome::Slashed {
+                    withdrawn: withdrawn__,
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.core.component.governance.v1.ProposalInfoRequest", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.core.component.governance.v1.ProposalOutcome.Slashed", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for ProposalInfoResponse {
+impl serde::Serialize for proposal_outcome::Withdrawn {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -4435,41 +8301,29 @@ impl serde::Serialize for ProposalInfoResponse {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.start_block_height != 0 {
-            len += 1;
-        }
-        if self.start_position != 0 {
+        if !self.reason.is_empty() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.governance.v1.ProposalInfoResponse", len)?;
-        if self.start_block_height != 0 {
-            #[allow(clippy::needless_borrow)]
-            struct_ser.serialize_field("startBlockHeight", ToString::to_string(&self.start_block_height).as_str())?;
-        }
-        if self.start_position != 0 {
-            #[allow(clippy::needless_borrow)]
-            struct_ser.serialize_field("startPosition", ToString::to_string(&self.start_position).as_str())?;
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.governance.v1.ProposalOutcome.Withdrawn", len)?;
+        if !self.reason.is_empty() {
+            struct_ser.serialize_field("reason", &self.reason)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for ProposalInfoResponse {
+impl<'de> serde::Deserialize<'de> for proposal_outcome::Withdrawn {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "start_block_height",
-            "startBlockHeight",
-            "start_position",
-            "startPosition",
+            "reason",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            StartBlockHeight,
-            StartPosition,
+            Reason,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -4492,8 +8346,7 @@ impl<'de> serde::Deserialize<'de> for ProposalInfoResponse {
                         E: serde::de::Error,
                     {
                         match value {
-                            "startBlockHeight" | "start_block_height" => Ok(GeneratedField::StartBlockHeight),
-                            "startPosition" | "start_position" => Ok(GeneratedField::StartPosition),
+                            "reason" => Ok(GeneratedField::Reason),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -4503,51 +8356,39 @@ impl<'de> serde::Deserialize<'de> for ProposalInfoResponse {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = ProposalInfoResponse;
+            type Value = proposal_outcome::Withdrawn;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.core.component.governance.v1.ProposalInfoResponse")
+                formatter.write_str("struct penumbra.core.component.governance.v1.ProposalOutcome.Withdrawn")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ProposalInfoResponse, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<proposal_outcome::Withdrawn, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut start_block_height__ = None;
-                let mut start_position__ = None;
+                let mut reason__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::StartBlockHeight => {
-                            if start_block_height__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("startBlockHeight"));
-                            }
-                            start_block_height__ = 
-                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
-                            ;
-                        }
-                        GeneratedField::StartPosition => {
-                            if start_position__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("startPosition"));
+                        GeneratedField::Reason => {
+                            if reason__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("reason"));
                             }
-                            start_position__ = 
-                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
-                            ;
+                            reason__ = Some(map_.next_value()?);
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(ProposalInfoResponse {
-                    start_block_height: start_block_height__.unwrap_or_default(),
-                    start_position: start_position__.unwrap_or_default(),
+                Ok(proposal_outcome::Withdrawn {
+                    reason: reason__.unwrap_or_default(),
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.core.component.governance.v1.ProposalInfoResponse", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.core.component.governance.v1.ProposalOutcome.Withdrawn", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for ProposalListRequest {
+impl serde::Serialize for ProposalRateDataRequest {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -4555,29 +8396,31 @@ impl serde::Serialize for ProposalListRequest {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.inactive {
+        if self.proposal_id != 0 {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.governance.v1.ProposalListRequest", len)?;
-        if self.inactive {
-            struct_ser.serialize_field("inactive", &self.inactive)?;
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.governance.v1.ProposalRateDataRequest", len)?;
+        if self.proposal_id != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("proposalId", ToString::to_string(&self.proposal_id).as_str())?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for ProposalListRequest {
+impl<'de> serde::Deserialize<'de> for ProposalRateDataRequest {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "inactive",
+            "proposal_id",
+            "proposalId",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            Inactive,
+            ProposalId,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -4600,7 +8443,7 @@ impl<'de> serde::Deserialize<'de> for ProposalListRequest {
                         E: serde::de::Error,
                     {
                         match value {
-                            "inactive" => Ok(GeneratedField::Inactive),
+                            "proposalId" | "proposal_id" => Ok(GeneratedField::ProposalId),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -4610,39 +8453,41 @@ impl<'de> serde::Deserialize<'de> for ProposalListRequest {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = ProposalListRequest;
+            type Value = ProposalRateDataRequest;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.core.component.governance.v1.ProposalListRequest")
+                formatter.write_str("struct penumbra.core.component.governance.v1.ProposalRateDataRequest")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ProposalListRequest, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ProposalRateDataRequest, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut inactive__ = None;
+                let mut proposal_id__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::Inactive => {
-                            if inactive__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("inactive"));
+                        GeneratedField::ProposalId => {
+                            if proposal_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("proposalId"));
                             }
-                            inactive__ = Some(map_.next_value()?);
+                            proposal_id__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(ProposalListRequest {
-                    inactive: inactive__.unwrap_or_default(),
+                Ok(ProposalRateDataRequest {
+                    proposal_id: proposal_id__.unwrap_or_default(),
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.core.component.governance.v1.ProposalListRequest", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.core.component.governance.v1.ProposalRateDataRequest", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for ProposalListResponse {
+impl serde::Serialize for ProposalRateDataResponse {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -4650,67 +8495,30 @@ impl serde::Serialize for ProposalListResponse {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.proposal.is_some() {
-            len += 1;
-        }
-        if self.start_block_height != 0 {
-            len += 1;
-        }
-        if self.end_block_height != 0 {
-            len += 1;
-        }
-        if self.start_position != 0 {
-            len += 1;
-        }
-        if self.state.is_some() {
+        if self.rate_data.is_some() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.governance.v1.ProposalListResponse", len)?;
-        if let Some(v) = self.proposal.as_ref() {
-            struct_ser.serialize_field("proposal", v)?;
-        }
-        if self.start_block_height != 0 {
-            #[allow(clippy::needless_borrow)]
-            struct_ser.serialize_field("startBlockHeight", ToString::to_string(&self.start_block_height).as_str())?;
-        }
-        if self.end_block_height != 0 {
-            #[allow(clippy::needless_borrow)]
-            struct_ser.serialize_field("endBlockHeight", ToString::to_string(&self.end_block_height).as_str())?;
-        }
-        if self.start_position != 0 {
-            #[allow(clippy::needless_borrow)]
-            struct_ser.serialize_field("startPosition", ToString::to_string(&self.start_position).as_str())?;
-        }
-        if let Some(v) = self.state.as_ref() {
-            struct_ser.serialize_field("state", v)?;
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.governance.v1.ProposalRateDataResponse", len)?;
+        if let Some(v) = self.rate_data.as_ref() {
+            struct_ser.serialize_field("rateData", v)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for ProposalListResponse {
+impl<'de> serde::Deserialize<'de> for ProposalRateDataResponse {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "proposal",
-            "start_block_height",
-            "startBlockHeight",
-            "end_block_height",
-            "endBlockHeight",
-            "start_position",
-            "startPosition",
-            "state",
+            "rate_data",
+            "rateData",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            Proposal,
-            StartBlockHeight,
-            EndBlockHeight,
-            StartPosition,
-            State,
+            RateData,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -4733,11 +8541,7 @@ impl<'de> serde::Deserialize<'de> for ProposalListResponse {
                         E: serde::de::Error,
                     {
                         match value {
-                            "proposal" => Ok(GeneratedField::Proposal),
-                            "startBlockHeight" | "start_block_height" => Ok(GeneratedField::StartBlockHeight),
-                            "endBlockHeight" | "end_block_height" => Ok(GeneratedField::EndBlockHeight),
-                            "startPosition" | "start_position" => Ok(GeneratedField::StartPosition),
-                            "state" => Ok(GeneratedField::State),
+                            "rateData" | "rate_data" => Ok(GeneratedField::RateData),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -4747,77 +8551,39 @@ impl<'de> serde::Deserialize<'de> for ProposalListResponse {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = ProposalListResponse;
+            type Value = ProposalRateDataResponse;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.core.component.governance.v1.ProposalListResponse")
+                formatter.write_str("struct penumbra.core.component.governance.v1.ProposalRateDataResponse")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ProposalListResponse, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ProposalRateDataResponse, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut proposal__ = None;
-                let mut start_block_height__ = None;
-                let mut end_block_height__ = None;
-                let mut start_position__ = None;
-                let mut state__ = None;
+                let mut rate_data__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::Proposal => {
-                            if proposal__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("proposal"));
-                            }
-                            proposal__ = map_.next_value()?;
-                        }
-                        GeneratedField::StartBlockHeight => {
-                            if start_block_height__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("startBlockHeight"));
-                            }
-                            start_block_height__ = 
-                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
-                            ;
-                        }
-                        GeneratedField::EndBlockHeight => {
-                            if end_block_height__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("endBlockHeight"));
-                            }
-                            end_block_height__ = 
-                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
-                            ;
-                        }
-                        GeneratedField::StartPosition => {
-                            if start_position__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("startPosition"));
-                            }
-                            start_position__ = 
-                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
-                            ;
-                        }
-                        GeneratedField::State => {
-                            if state__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("state"));
+                        GeneratedField::RateData => {
+                            if rate_data__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("rateData"));
                             }
-                            state__ = map_.next_value()?;
+                            rate_data__ = map_.next_value()?;
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(ProposalListResponse {
-                    proposal: proposal__,
-                    start_block_height: start_block_height__.unwrap_or_default(),
-                    end_block_height: end_block_height__.unwrap_or_default(),
-                    start_position: start_position__.unwrap_or_default(),
-                    state: state__,
+                Ok(ProposalRateDataResponse {
+                    rate_data: rate_data__,
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.core.component.governance.v1.ProposalListResponse", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.core.component.governance.v1.ProposalRateDataResponse", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for ProposalOutcome {
+impl serde::Serialize for ProposalState {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -4825,43 +8591,48 @@ impl serde::Serialize for ProposalOutcome {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.outcome.is_some() {
+        if self.state.is_some() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.governance.v1.ProposalOutcome", len)?;
-        if let Some(v) = self.outcome.as_ref() {
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.governance.v1.ProposalState", len)?;
+        if let Some(v) = self.state.as_ref() {
             match v {
-                proposal_outcome::Outcome::Passed(v) => {
-                    struct_ser.serialize_field("passed", v)?;
+                proposal_state::State::Voting(v) => {
+                    struct_ser.serialize_field("voting", v)?;
                 }
-                proposal_outcome::Outcome::Failed(v) => {
-                    struct_ser.serialize_field("failed", v)?;
+                proposal_state::State::Withdrawn(v) => {
+                    struct_ser.serialize_field("withdrawn", v)?;
                 }
-                proposal_outcome::Outcome::Slashed(v) => {
-                    struct_ser.serialize_field("slashed", v)?;
+                proposal_state::State::Finished(v) => {
+                    struct_ser.serialize_field("finished", v)?;
+                }
+                proposal_state::State::Claimed(v) => {
+                    struct_ser.serialize_field("claimed", v)?;
                 }
             }
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for ProposalOutcome {
+impl<'de> serde::Deserialize<'de> for ProposalState {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "passed",
-            "failed",
-            "slashed",
+            "voting",
+            "withdrawn",
+            "finished",
+            "claimed",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            Passed,
-            Failed,
-            Slashed,
+            Voting,
+            Withdrawn,
+            Finished,
+            Claimed,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -4884,9 +8655,10 @@ impl<'de> serde::Deserialize<'de> for ProposalOutcome {
                         E: serde::de::Error,
                     {
                         match value {
-                            "passed" => Ok(GeneratedField::Passed),
-                            "failed" => Ok(GeneratedField::Failed),
-                            "slashed" => Ok(GeneratedField::Slashed),
+                            "voting" => Ok(GeneratedField::Voting),
+                            "withdrawn" => Ok(GeneratedField::Withdrawn),
+                            "finished" => Ok(GeneratedField::Finished),
+                            "claimed" => Ok(GeneratedField::Claimed),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -4896,38 +8668,45 @@ impl<'de> serde::Deserialize<'de> for ProposalOutcome {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = ProposalOutcome;
+            type Value = ProposalState;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.core.component.governance.v1.ProposalOutcome")
+                formatter.write_str("struct penumbra.core.component.governance.v1.ProposalState")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ProposalOutcome, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ProposalState, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut outcome__ = None;
+                let mut state__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::Passed => {
-                            if outcome__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("passed"));
+                        GeneratedField::Voting => {
+                            if state__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("voting"));
                             }
-                            outcome__ = map_.next_value::<::std::option::Option<_>>()?.map(proposal_outcome::Outcome::Passed)
+                            state__ = map_.next_value::<::std::option::Option<_>>()?.map(proposal_state::State::Voting)
 ;
                         }
-                        GeneratedField::Failed => {
-                            if outcome__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("failed"));
+                        GeneratedField::Withdrawn => {
+                            if state__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("withdrawn"));
                             }
-                            outcome__ = map_.next_value::<::std::option::Option<_>>()?.map(proposal_outcome::Outcome::Failed)
+                            state__ = map_.next_value::<::std::option::Option<_>>()?.map(proposal_state::State::Withdrawn)
 ;
                         }
-                        GeneratedField::Slashed => {
-                            if outcome__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("slashed"));
+                        GeneratedField::Finished => {
+                            if state__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("finished"));
                             }
-                            outcome__ = map_.next_value::<::std::option::Option<_>>()?.map(proposal_outcome::Outcome::Slashed)
+                            state__ = map_.next_value::<::std::option::Option<_>>()?.map(proposal_state::State::Finished)
+;
+                        }
+                        GeneratedField::Claimed => {
+                            if state__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("claimed"));
+                            }
+                            state__ = map_.next_value::<::std::option::Option<_>>()?.map(proposal_state::State::Claimed)
 ;
                         }
                         GeneratedField::__SkipField__ => {
@@ -4935,15 +8714,15 @@ impl<'de> serde::Deserialize<'de> for ProposalOutcome {
                         }
                     }
                 }
-                Ok(ProposalOutcome {
-                    outcome: outcome__,
+                Ok(ProposalState {
+                    state: state__,
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.core.component.governance.v1.ProposalOutcome", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.core.component.governance.v1.ProposalState", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for proposal_outcome::Failed {
+impl serde::Serialize for proposal_state::Claimed {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -4951,29 +8730,29 @@ impl serde::Serialize for proposal_outcome::Failed {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.withdrawn.is_some() {
+        if self.outcome.is_some() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.governance.v1.ProposalOutcome.Failed", len)?;
-        if let Some(v) = self.withdrawn.as_ref() {
-            struct_ser.serialize_field("withdrawn", v)?;
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.governance.v1.ProposalState.Claimed", len)?;
+        if let Some(v) = self.outcome.as_ref() {
+            struct_ser.serialize_field("outcome", v)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for proposal_outcome::Failed {
+impl<'de> serde::Deserialize<'de> for proposal_state::Claimed {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "withdrawn",
+            "outcome",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            Withdrawn,
+            Outcome,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -4996,7 +8775,7 @@ impl<'de> serde::Deserialize<'de> for proposal_outcome::Failed {
                         E: serde::de::Error,
                     {
                         match value {
-                            "withdrawn" => Ok(GeneratedField::Withdrawn),
+                            "outcome" => Ok(GeneratedField::Outcome),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -5006,61 +8785,69 @@ impl<'de> serde::Deserialize<'de> for proposal_outcome::Failed {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = proposal_outcome::Failed;
+            type Value = proposal_state::Claimed;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.core.component.governance.v1.ProposalOutcome.Failed")
+                formatter.write_str("struct penumbra.core.component.governance.v1.ProposalState.Claimed")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<proposal_outcome::Failed, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<proposal_state::Claimed, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut withdrawn__ = None;
+                let mut outcome__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::Withdrawn => {
-                            if withdrawn__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("withdrawn"));
+                        GeneratedField::Outcome => {
+                            if outcome__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("outcome"));
                             }
-                            withdrawn__ = map_.next_value()?;
+                            outcome__ = map_.next_value()?;
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(proposal_outcome::Failed {
-                    withdrawn: withdrawn__,
+                Ok(proposal_state::Claimed {
+                    outcome: outcome__,
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.core.component.governance.v1.ProposalOutcome.Failed", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.core.component.governance.v1.ProposalState.Claimed", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for proposal_outcome::Passed {
+impl serde::Serialize for proposal_state::Finished {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
         S: serde::Serializer,
     {
         use serde::ser::SerializeStruct;
-        let len = 0;
-        let struct_ser = serializer.serialize_struct("penumbra.core.component.governance.v1.ProposalOutcome.Passed", len)?;
+        let mut len = 0;
+        if self.outcome.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.governance.v1.ProposalState.Finished", len)?;
+        if let Some(v) = self.outcome.as_ref() {
+            struct_ser.serialize_field("outcome", v)?;
+        }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for proposal_outcome::Passed {
+impl<'de> serde::Deserialize<'de> for proposal_state::Finished {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
+            "outcome",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
+            Outcome,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -5082,7 +8869,10 @@ impl<'de> serde::Deserialize<'de> for proposal_outcome::Passed {
                     where
                         E: serde::de::Error,
                     {
-                            Ok(GeneratedField::__SkipField__)
+                        match value {
+                            "outcome" => Ok(GeneratedField::Outcome),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
                     }
                 }
                 deserializer.deserialize_identifier(GeneratedVisitor)
@@ -5090,57 +8880,61 @@ impl<'de> serde::Deserialize<'de> for proposal_outcome::Passed {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = proposal_outcome::Passed;
+            type Value = proposal_state::Finished;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.core.component.governance.v1.ProposalOutcome.Passed")
+                formatter.write_str("struct penumbra.core.component.governance.v1.ProposalState.Finished")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<proposal_outcome::Passed, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<proposal_state::Finished, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                while map_.next_key::<GeneratedField>()?.is_some() {
-                    let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                let mut outcome__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Outcome => {
+                            if outcome__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("outcome"));
+                            }
+                            outcome__ = map_.next_value()?;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
                 }
-                Ok(proposal_outcome::Passed {
+                Ok(proposal_state::Finished {
+                    outcome: outcome__,
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.core.component.governance.v1.ProposalOutcome.Passed", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.core.component.governance.v1.ProposalState.Finished", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for proposal_outcome::Slashed {
+impl serde::Serialize for proposal_state::Voting {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
         S: serde::Serializer,
     {
         use serde::ser::SerializeStruct;
-        let mut len = 0;
-        if self.withdrawn.is_some() {
-            len += 1;
-        }
-        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.governance.v1.ProposalOutcome.Slashed", len)?;
-        if let Some(v) = self.withdrawn.as_ref() {
-            struct_ser.serialize_field("withdrawn", v)?;
-        }
+        let len = 0;
+        let struct_ser = serializer.serialize_struct("penumbra.core.component.governance.v1.ProposalState.Voting", len)?;
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for proposal_outcome::Slashed {
+impl<'de> serde::Deserialize<'de> for proposal_state::Voting {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "withdrawn",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            Withdrawn,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -5162,10 +8956,7 @@ impl<'de> serde::Deserialize<'de> for proposal_outcome::Slashed {
                     where
                         E: serde::de::Error,
                     {
-                        match value {
-                            "withdrawn" => Ok(GeneratedField::Withdrawn),
-                            _ => Ok(GeneratedField::__SkipField__),
-                        }
+                            Ok(GeneratedField::__SkipField__)
                     }
                 }
                 deserializer.deserialize_identifier(GeneratedVisitor)
@@ -5173,39 +8964,27 @@ impl<'de> serde::Deserialize<'de> for proposal_outcome::Slashed {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = proposal_outcome::Slashed;
+            type Value = proposal_state::Voting;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.core.component.governance.v1.ProposalOutcome.Slashed")
+                formatter.write_str("struct penumbra.core.component.governance.v1.ProposalState.Voting")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<proposal_outcome::Slashed, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<proposal_state::Voting, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut withdrawn__ = None;
-                while let Some(k) = map_.next_key()? {
-                    match k {
-                        GeneratedField::Withdrawn => {
-                            if withdrawn__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("withdrawn"));
-                            }
-                            withdrawn__ = map_.next_value()?;
-                        }
-                        GeneratedField::__SkipField__ => {
-                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
-                        }
-                    }
+                while map_.next_key::<GeneratedField>()?.is_some() {
+                    let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                 }
-                Ok(proposal_outcome::Slashed {
-                    withdrawn: withdrawn__,
+                Ok(proposal_state::Voting {
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.core.component.governance.v1.ProposalOutcome.Slashed", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.core.component.governance.v1.ProposalState.Voting", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for proposal_outcome::Withdrawn {
+impl serde::Serialize for proposal_state::Withdrawn {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -5216,14 +8995,14 @@ impl serde::Serialize for proposal_outcome::Withdrawn {
         if !self.reason.is_empty() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.governance.v1.ProposalOutcome.Withdrawn", len)?;
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.governance.v1.ProposalState.Withdrawn", len)?;
         if !self.reason.is_empty() {
             struct_ser.serialize_field("reason", &self.reason)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for proposal_outcome::Withdrawn {
+impl<'de> serde::Deserialize<'de> for proposal_state::Withdrawn {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
@@ -5268,13 +9047,13 @@ impl<'de> serde::Deserialize<'de> for proposal_outcome::Withdrawn {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = proposal_outcome::Withdrawn;
+            type Value = proposal_state::Withdrawn;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.core.component.governance.v1.ProposalOutcome.Withdrawn")
+                formatter.write_str("struct penumbra.core.component.governance.v1.ProposalState.Withdrawn")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<proposal_outcome::Withdrawn, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<proposal_state::Withdrawn, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
@@ -5292,15 +9071,15 @@ impl<'de> serde::Deserialize<'de> for proposal_outcome::Withdrawn {
                         }
                     }
                 }
-                Ok(proposal_outcome::Withdrawn {
+                Ok(proposal_state::Withdrawn {
                     reason: reason__.unwrap_or_default(),
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.core.component.governance.v1.ProposalOutcome.Withdrawn", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.core.component.governance.v1.ProposalState.Withdrawn", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for ProposalRateDataRequest {
+impl serde::Serialize for ProposalSubmit {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -5308,31 +9087,47 @@ impl serde::Serialize for ProposalRateDataRequest {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.proposal_id != 0 {
+        if self.proposal.is_some() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.governance.v1.ProposalRateDataRequest", len)?;
-        if self.proposal_id != 0 {
-            #[allow(clippy::needless_borrow)]
-            struct_ser.serialize_field("proposalId", ToString::to_string(&self.proposal_id).as_str())?;
+        if self.deposit_amount.is_some() {
+            len += 1;
+        }
+        if self.deposit_asset_id.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.governance.v1.ProposalSubmit", len)?;
+        if let Some(v) = self.proposal.as_ref() {
+            struct_ser.serialize_field("proposal", v)?;
+        }
+        if let Some(v) = self.deposit_amount.as_ref() {
+            struct_ser.serialize_field("depositAmount", v)?;
+        }
+        if let Some(v) = self.deposit_asset_id.as_ref() {
+            struct_ser.serialize_field("depositAssetId", v)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for ProposalRateDataRequest {
+impl<'de> serde::Deserialize<'de> for ProposalSubmit {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "proposal_id",
-            "proposalId",
+            "proposal",
+            "deposit_amount",
+            "depositAmount",
+            "deposit_asset_id",
+            "depositAssetId",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            ProposalId,
+            Proposal,
+            DepositAmount,
+            DepositAssetId,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -5355,7 +9150,9 @@ impl<'de> serde::Deserialize<'de> for ProposalRateDataRequest {
                         E: serde::de::Error,
                     {
                         match value {
-                            "proposalId" | "proposal_id" => Ok(GeneratedField::ProposalId),
+                            "proposal" => Ok(GeneratedField::Proposal),
+                            "depositAmount" | "deposit_amount" => Ok(GeneratedField::DepositAmount),
+                            "depositAssetId" | "deposit_asset_id" => Ok(GeneratedField::DepositAssetId),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -5365,41 +9162,55 @@ impl<'de> serde::Deserialize<'de> for ProposalRateDataRequest {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = ProposalRateDataRequest;
+            type Value = ProposalSubmit;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.core.component.governance.v1.ProposalRateDataRequest")
+                formatter.write_str("struct penumbra.core.component.governance.v1.ProposalSubmit")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ProposalRateDataRequest, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ProposalSubmit, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut proposal_id__ = None;
+                let mut proposal__ = None;
+                let mut deposit_amount__ = None;
+                let mut deposit_asset_id__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::ProposalId => {
-                            if proposal_id__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("proposalId"));
+                        GeneratedField::Proposal => {
+                            if proposal__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("proposal"));
                             }
-                            proposal_id__ = 
-                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
-                            ;
+                            proposal__ = map_.next_value()?;
+                        }
+                        GeneratedField::DepositAmount => {
+                            if deposit_amount__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("depositAmount"));
+                            }
+                            deposit_amount__ = map_.next_value()?;
+                        }
+                        GeneratedField::DepositAssetId => {
+                            if deposit_asset_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("depositAssetId"));
+                            }
+                            deposit_asset_id__ = map_.next_value()?;
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(ProposalRateDataRequest {
-                    proposal_id: proposal_id__.unwrap_or_default(),
+                Ok(ProposalSubmit {
+                    proposal: proposal__,
+                    deposit_amount: deposit_amount__,
+                    deposit_asset_id: deposit_asset_id__,
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.core.component.governance.v1.ProposalRateDataRequest", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.core.component.governance.v1.ProposalSubmit", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for ProposalRateDataResponse {
+impl serde::Serialize for ProposalWithdraw {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -5407,30 +9218,38 @@ impl serde::Serialize for ProposalRateDataResponse {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.rate_data.is_some() {
+        if self.proposal != 0 {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.governance.v1.ProposalRateDataResponse", len)?;
-        if let Some(v) = self.rate_data.as_ref() {
-            struct_ser.serialize_field("rateData", v)?;
+        if !self.reason.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.governance.v1.ProposalWithdraw", len)?;
+        if self.proposal != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("proposal", ToString::to_string(&self.proposal).as_str())?;
+        }
+        if !self.reason.is_empty() {
+            struct_ser.serialize_field("reason", &self.reason)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for ProposalRateDataResponse {
+impl<'de> serde::Deserialize<'de> for ProposalWithdraw {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "rate_data",
-            "rateData",
+            "proposal",
+            "reason",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            RateData,
+            Proposal,
+            Reason,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -5453,7 +9272,8 @@ impl<'de> serde::Deserialize<'de> for ProposalRateDataResponse {
                         E: serde::de::Error,
                     {
                         match value {
-                            "rateData" | "rate_data" => Ok(GeneratedField::RateData),
+                            "proposal" => Ok(GeneratedField::Proposal),
+                            "reason" => Ok(GeneratedField::Reason),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -5463,39 +9283,49 @@ impl<'de> serde::Deserialize<'de> for ProposalRateDataResponse {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = ProposalRateDataResponse;
+            type Value = ProposalWithdraw;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.core.component.governance.v1.ProposalRateDataResponse")
+                formatter.write_str("struct penumbra.core.component.governance.v1.ProposalWithdraw")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ProposalRateDataResponse, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ProposalWithdraw, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut rate_data__ = None;
+                let mut proposal__ = None;
+                let mut reason__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::RateData => {
-                            if rate_data__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("rateData"));
+                        GeneratedField::Proposal => {
+                            if proposal__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("proposal"));
                             }
-                            rate_data__ = map_.next_value()?;
+                            proposal__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::Reason => {
+                            if reason__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("reason"));
+                            }
+                            reason__ = Some(map_.next_value()?);
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(ProposalRateDataResponse {
-                    rate_data: rate_data__,
+                Ok(ProposalWithdraw {
+                    proposal: proposal__.unwrap_or_default(),
+                    reason: reason__.unwrap_or_default(),
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.core.component.governance.v1.ProposalRateDataResponse", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.core.component.governance.v1.ProposalWithdraw", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for ProposalState {
+impl serde::Serialize for Ratio {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -5503,48 +9333,39 @@ impl serde::Serialize for ProposalState {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.state.is_some() {
+        if self.numerator != 0 {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.governance.v1.ProposalState", len)?;
-        if let Some(v) = self.state.as_ref() {
-            match v {
-                proposal_state::State::Voting(v) => {
-                    struct_ser.serialize_field("voting", v)?;
-                }
-                proposal_state::State::Withdrawn(v) => {
-                    struct_ser.serialize_field("withdrawn", v)?;
-                }
-                proposal_state::State::Finished(v) => {
-                    struct_ser.serialize_field("finished", v)?;
-                }
-                proposal_state::State::Claimed(v) => {
-                    struct_ser.serialize_field("claimed", v)?;
-                }
-            }
+        if self.denominator != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.governance.v1.Ratio", len)?;
+        if self.numerator != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("numerator", ToString::to_string(&self.numerator).as_str())?;
+        }
+        if self.denominator != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("denominator", ToString::to_string(&self.denominator).as_str())?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for ProposalState {
+impl<'de> serde::Deserialize<'de> for Ratio {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "voting",
-            "withdrawn",
-            "finished",
-            "claimed",
+            "numerator",
+            "denominator",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            Voting,
-            Withdrawn,
-            Finished,
-            Claimed,
+            Numerator,
+            Denominator,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -5567,10 +9388,8 @@ impl<'de> serde::Deserialize<'de> for ProposalState {
                         E: serde::de::Error,
                     {
                         match value {
-                            "voting" => Ok(GeneratedField::Voting),
-                            "withdrawn" => Ok(GeneratedField::Withdrawn),
-                            "finished" => Ok(GeneratedField::Finished),
-                            "claimed" => Ok(GeneratedField::Claimed),
+                            "numerator" => Ok(GeneratedField::Numerator),
+                            "denominator" => Ok(GeneratedField::Denominator),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -5580,61 +9399,51 @@ impl<'de> serde::Deserialize<'de> for ProposalState {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = ProposalState;
+            type Value = Ratio;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.core.component.governance.v1.ProposalState")
+                formatter.write_str("struct penumbra.core.component.governance.v1.Ratio")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ProposalState, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<Ratio, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut state__ = None;
+                let mut numerator__ = None;
+                let mut denominator__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::Voting => {
-                            if state__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("voting"));
-                            }
-                            state__ = map_.next_value::<::std::option::Option<_>>()?.map(proposal_state::State::Voting)
-;
-                        }
-                        GeneratedField::Withdrawn => {
-                            if state__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("withdrawn"));
-                            }
-                            state__ = map_.next_value::<::std::option::Option<_>>()?.map(proposal_state::State::Withdrawn)
-;
-                        }
-                        GeneratedField::Finished => {
-                            if state__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("finished"));
+                        GeneratedField::Numerator => {
+                            if numerator__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("numerator"));
                             }
-                            state__ = map_.next_value::<::std::option::Option<_>>()?.map(proposal_state::State::Finished)
-;
+                            numerator__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
                         }
-                        GeneratedField::Claimed => {
-                            if state__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("claimed"));
+                        GeneratedField::Denominator => {
+                            if denominator__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("denominator"));
                             }
-                            state__ = map_.next_value::<::std::option::Option<_>>()?.map(proposal_state::State::Claimed)
-;
+                            denominator__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(ProposalState {
-                    state: state__,
+                Ok(Ratio {
+                    numerator: numerator__.unwrap_or_default(),
+                    denominator: denominator__.unwrap_or_default(),
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.core.component.governance.v1.ProposalState", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.core.component.governance.v1.Ratio", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for proposal_state::Claimed {
+impl serde::Serialize for RevealedBallotKeyShare {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -5642,29 +9451,46 @@ impl serde::Serialize for proposal_state::Claimed {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.outcome.is_some() {
+        if self.dealer.is_some() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.governance.v1.ProposalState.Claimed", len)?;
-        if let Some(v) = self.outcome.as_ref() {
-            struct_ser.serialize_field("outcome", v)?;
+        if !self.share.is_empty() {
+            len += 1;
+        }
+        if self.position != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.governance.v1.RevealedBallotKeyShare", len)?;
+        if let Some(v) = self.dealer.as_ref() {
+            struct_ser.serialize_field("dealer", v)?;
+        }
+        if !self.share.is_empty() {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("share", pbjson::private::base64::encode(&self.share).as_str())?;
+        }
+        if self.position != 0 {
+            struct_ser.serialize_field("position", &self.position)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for proposal_state::Claimed {
+impl<'de> serde::Deserialize<'de> for RevealedBallotKeyShare {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "outcome",
+            "dealer",
+            "share",
+            "position",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            Outcome,
+            Dealer,
+            Share,
+            Position,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -5687,7 +9513,9 @@ impl<'de> serde::Deserialize<'de> for proposal_state::Claimed {
                         E: serde::de::Error,
                     {
                         match value {
-                            "outcome" => Ok(GeneratedField::Outcome),
+                            "dealer" => Ok(GeneratedField::Dealer),
+                            "share" => Ok(GeneratedField::Share),
+                            "position" => Ok(GeneratedField::Position),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -5697,69 +9525,81 @@ impl<'de> serde::Deserialize<'de> for proposal_state::Claimed {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = proposal_state::Claimed;
+            type Value = RevealedBallotKeyShare;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.core.component.governance.v1.ProposalState.Claimed")
+                formatter.write_str("struct penumbra.core.component.governance.v1.RevealedBallotKeyShare")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<proposal_state::Claimed, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<RevealedBallotKeyShare, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut outcome__ = None;
+                let mut dealer__ = None;
+                let mut share__ = None;
+                let mut position__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::Outcome => {
-                            if outcome__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("outcome"));
+                        GeneratedField::Dealer => {
+                            if dealer__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("dealer"));
                             }
-                            outcome__ = map_.next_value()?;
+                            dealer__ = map_.next_value()?;
+                        }
+                        GeneratedField::Share => {
+                            if share__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("share"));
+                            }
+                            share__ = 
+                                Some(map_.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::Position => {
+                            if position__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("position"));
+                            }
+                            position__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(proposal_state::Claimed {
-                    outcome: outcome__,
+                Ok(RevealedBallotKeyShare {
+                    dealer: dealer__,
+                    share: share__.unwrap_or_default(),
+                    position: position__.unwrap_or_default(),
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.core.component.governance.v1.ProposalState.Claimed", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.core.component.governance.v1.RevealedBallotKeyShare", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for proposal_state::Finished {
+impl serde::Serialize for ScheduledUpgradeRequest {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
         S: serde::Serializer,
     {
         use serde::ser::SerializeStruct;
-        let mut len = 0;
-        if self.outcome.is_some() {
-            len += 1;
-        }
-        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.governance.v1.ProposalState.Finished", len)?;
-        if let Some(v) = self.outcome.as_ref() {
-            struct_ser.serialize_field("outcome", v)?;
-        }
+        let len = 0;
+        let struct_ser = serializer.serialize_struct("penumbra.core.component.governance.v1.ScheduledUpgradeRequest", len)?;
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for proposal_state::Finished {
+impl<'de> serde::Deserialize<'de> for ScheduledUpgradeRequest {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "outcome",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            Outcome,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -5781,10 +9621,7 @@ impl<'de> serde::Deserialize<'de> for proposal_state::Finished {
                     where
                         E: serde::de::Error,
                     {
-                        match value {
-                            "outcome" => Ok(GeneratedField::Outcome),
-                            _ => Ok(GeneratedField::__SkipField__),
-                        }
+                            Ok(GeneratedField::__SkipField__)
                     }
                 }
                 deserializer.deserialize_identifier(GeneratedVisitor)
@@ -5792,61 +9629,58 @@ impl<'de> serde::Deserialize<'de> for proposal_state::Finished {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = proposal_state::Finished;
+            type Value = ScheduledUpgradeRequest;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.core.component.governance.v1.ProposalState.Finished")
+                formatter.write_str("struct penumbra.core.component.governance.v1.ScheduledUpgradeRequest")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<proposal_state::Finished, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ScheduledUpgradeRequest, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut outcome__ = None;
-                while let Some(k) = map_.next_key()? {
-                    match k {
-                        GeneratedField::Outcome => {
-                            if outcome__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("outcome"));
-                            }
-                            outcome__ = map_.next_value()?;
-                        }
-                        GeneratedField::__SkipField__ => {
-                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
-                        }
-                    }
+                while map_.next_key::<GeneratedField>()?.is_some() {
+                    let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                 }
-                Ok(proposal_state::Finished {
-                    outcome: outcome__,
+                Ok(ScheduledUpgradeRequest {
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.core.component.governance.v1.ProposalState.Finished", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.core.component.governance.v1.ScheduledUpgradeRequest", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for proposal_state::Voting {
+impl serde::Serialize for ScheduledUpgradeResponse {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
         S: serde::Serializer,
     {
         use serde::ser::SerializeStruct;
-        let len = 0;
-        let struct_ser = serializer.serialize_struct("penumbra.core.component.governance.v1.ProposalState.Voting", len)?;
+        let mut len = 0;
+        if self.upgrade_plan.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.governance.v1.ScheduledUpgradeResponse", len)?;
+        if let Some(v) = self.upgrade_plan.as_ref() {
+            struct_ser.serialize_field("upgradePlan", v)?;
+        }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for proposal_state::Voting {
+impl<'de> serde::Deserialize<'de> for ScheduledUpgradeResponse {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
+            "upgrade_plan",
+            "upgradePlan",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
+            UpgradePlan,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -5868,7 +9702,10 @@ impl<'de> serde::Deserialize<'de> for proposal_state::Voting {
                     where
                         E: serde::de::Error,
                     {
-                            Ok(GeneratedField::__SkipField__)
+                        match value {
+                            "upgradePlan" | "upgrade_plan" => Ok(GeneratedField::UpgradePlan),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
                     }
                 }
                 deserializer.deserialize_identifier(GeneratedVisitor)
@@ -5876,27 +9713,39 @@ impl<'de> serde::Deserialize<'de> for proposal_state::Voting {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = proposal_state::Voting;
+            type Value = ScheduledUpgradeResponse;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.core.component.governance.v1.ProposalState.Voting")
+                formatter.write_str("struct penumbra.core.component.governance.v1.ScheduledUpgradeResponse")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<proposal_state::Voting, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ScheduledUpgradeResponse, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                while map_.next_key::<GeneratedField>()?.is_some() {
-                    let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                let mut upgrade_plan__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::UpgradePlan => {
+                            if upgrade_plan__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("upgradePlan"));
+                            }
+                            upgrade_plan__ = map_.next_value()?;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
                 }
-                Ok(proposal_state::Voting {
+                Ok(ScheduledUpgradeResponse {
+                    upgrade_plan: upgrade_plan__,
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.core.component.governance.v1.ProposalState.Voting", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.core.component.governance.v1.ScheduledUpgradeResponse", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for proposal_state::Withdrawn {
+impl serde::Serialize for Tally {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -5904,29 +9753,48 @@ impl serde::Serialize for proposal_state::Withdrawn {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if !self.reason.is_empty() {
+        if self.yes != 0 {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.governance.v1.ProposalState.Withdrawn", len)?;
-        if !self.reason.is_empty() {
-            struct_ser.serialize_field("reason", &self.reason)?;
+        if self.no != 0 {
+            len += 1;
+        }
+        if self.abstain != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.governance.v1.Tally", len)?;
+        if self.yes != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("yes", ToString::to_string(&self.yes).as_str())?;
+        }
+        if self.no != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("no", ToString::to_string(&self.no).as_str())?;
+        }
+        if self.abstain != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("abstain", ToString::to_string(&self.abstain).as_str())?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for proposal_state::Withdrawn {
+impl<'de> serde::Deserialize<'de> for Tally {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "reason",
+            "yes",
+            "no",
+            "abstain",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            Reason,
+            Yes,
+            No,
+            Abstain,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -5949,7 +9817,9 @@ impl<'de> serde::Deserialize<'de> for proposal_state::Withdrawn {
                         E: serde::de::Error,
                     {
                         match value {
-                            "reason" => Ok(GeneratedField::Reason),
+                            "yes" => Ok(GeneratedField::Yes),
+                            "no" => Ok(GeneratedField::No),
+                            "abstain" => Ok(GeneratedField::Abstain),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -5959,39 +9829,61 @@ impl<'de> serde::Deserialize<'de> for proposal_state::Withdrawn {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = proposal_state::Withdrawn;
+            type Value = Tally;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.core.component.governance.v1.ProposalState.Withdrawn")
+                formatter.write_str("struct penumbra.core.component.governance.v1.Tally")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<proposal_state::Withdrawn, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<Tally, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut reason__ = None;
+                let mut yes__ = None;
+                let mut no__ = None;
+                let mut abstain__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::Reason => {
-                            if reason__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("reason"));
+                        GeneratedField::Yes => {
+                            if yes__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("yes"));
+                            }
+                            yes__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::No => {
+                            if no__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("no"));
                             }
-                            reason__ = Some(map_.next_value()?);
+                            no__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::Abstain => {
+                            if abstain__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("abstain"));
+                            }
+                            abstain__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(proposal_state::Withdrawn {
-                    reason: reason__.unwrap_or_default(),
+                Ok(Tally {
+                    yes: yes__.unwrap_or_default(),
+                    no: no__.unwrap_or_default(),
+                    abstain: abstain__.unwrap_or_default(),
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.core.component.governance.v1.ProposalState.Withdrawn", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.core.component.governance.v1.Tally", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for ProposalSubmit {
+impl serde::Serialize for ValidatorAbstentionsRequest {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -5999,38 +9891,31 @@ impl serde::Serialize for ProposalSubmit {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.proposal.is_some() {
-            len += 1;
-        }
-        if self.deposit_amount.is_some() {
+        if self.proposal_id != 0 {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.governance.v1.ProposalSubmit", len)?;
-        if let Some(v) = self.proposal.as_ref() {
-            struct_ser.serialize_field("proposal", v)?;
-        }
-        if let Some(v) = self.deposit_amount.as_ref() {
-            struct_ser.serialize_field("depositAmount", v)?;
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.governance.v1.ValidatorAbstentionsRequest", len)?;
+        if self.proposal_id != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("proposalId", ToString::to_string(&self.proposal_id).as_str())?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for ProposalSubmit {
+impl<'de> serde::Deserialize<'de> for ValidatorAbstentionsRequest {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "proposal",
-            "deposit_amount",
-            "depositAmount",
+            "proposal_id",
+            "proposalId",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            Proposal,
-            DepositAmount,
+            ProposalId,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -6053,8 +9938,7 @@ impl<'de> serde::Deserialize<'de> for ProposalSubmit {
                         E: serde::de::Error,
                     {
                         match value {
-                            "proposal" => Ok(GeneratedField::Proposal),
-                            "depositAmount" | "deposit_amount" => Ok(GeneratedField::DepositAmount),
+                            "proposalId" | "proposal_id" => Ok(GeneratedField::ProposalId),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -6064,47 +9948,41 @@ impl<'de> serde::Deserialize<'de> for ProposalSubmit {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = ProposalSubmit;
+            type Value = ValidatorAbstentionsRequest;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.core.component.governance.v1.ProposalSubmit")
+                formatter.write_str("struct penumbra.core.component.governance.v1.ValidatorAbstentionsRequest")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ProposalSubmit, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ValidatorAbstentionsRequest, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut proposal__ = None;
-                let mut deposit_amount__ = None;
+                let mut proposal_id__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::Proposal => {
-                            if proposal__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("proposal"));
-                            }
-                            proposal__ = map_.next_value()?;
-                        }
-                        GeneratedField::DepositAmount => {
-                            if deposit_amount__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("depositAmount"));
+                        GeneratedField::ProposalId => {
+                            if proposal_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("proposalId"));
                             }
-                            deposit_amount__ = map_.next_value()?;
+                            proposal_id__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(ProposalSubmit {
-                    proposal: proposal__,
-                    deposit_amount: deposit_amount__,
+                Ok(ValidatorAbstentionsRequest {
+                    proposal_id: proposal_id__.unwrap_or_default(),
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.core.component.governance.v1.ProposalSubmit", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.core.component.governance.v1.ValidatorAbstentionsRequest", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for ProposalWithdraw {
+impl serde::Serialize for ValidatorAbstentionsResponse {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -6112,38 +9990,40 @@ impl serde::Serialize for ProposalWithdraw {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.proposal != 0 {
+        if self.identity_key.is_some() {
             len += 1;
         }
-        if !self.reason.is_empty() {
+        if self.voting_power != 0 {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.governance.v1.ProposalWithdraw", len)?;
-        if self.proposal != 0 {
-            #[allow(clippy::needless_borrow)]
-            struct_ser.serialize_field("proposal", ToString::to_string(&self.proposal).as_str())?;
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.governance.v1.ValidatorAbstentionsResponse", len)?;
+        if let Some(v) = self.identity_key.as_ref() {
+            struct_ser.serialize_field("identityKey", v)?;
         }
-        if !self.reason.is_empty() {
-            struct_ser.serialize_field("reason", &self.reason)?;
+        if self.voting_power != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("votingPower", ToString::to_string(&self.voting_power).as_str())?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for ProposalWithdraw {
+impl<'de> serde::Deserialize<'de> for ValidatorAbstentionsResponse {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "proposal",
-            "reason",
+            "identity_key",
+            "identityKey",
+            "voting_power",
+            "votingPower",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            Proposal,
-            Reason,
+            IdentityKey,
+            VotingPower,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -6166,8 +10046,8 @@ impl<'de> serde::Deserialize<'de> for ProposalWithdraw {
                         E: serde::de::Error,
                     {
                         match value {
-                            "proposal" => Ok(GeneratedField::Proposal),
-                            "reason" => Ok(GeneratedField::Reason),
+                            "identityKey" | "identity_key" => Ok(GeneratedField::IdentityKey),
+                            "votingPower" | "voting_power" => Ok(GeneratedField::VotingPower),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -6177,49 +10057,49 @@ impl<'de> serde::Deserialize<'de> for ProposalWithdraw {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = ProposalWithdraw;
+            type Value = ValidatorAbstentionsResponse;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.core.component.governance.v1.ProposalWithdraw")
+                formatter.write_str("struct penumbra.core.component.governance.v1.ValidatorAbstentionsResponse")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ProposalWithdraw, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ValidatorAbstentionsResponse, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut proposal__ = None;
-                let mut reason__ = None;
+                let mut identity_key__ = None;
+                let mut voting_power__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::Proposal => {
-                            if proposal__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("proposal"));
+                        GeneratedField::IdentityKey => {
+                            if identity_key__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("identityKey"));
                             }
-                            proposal__ = 
-                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
-                            ;
+                            identity_key__ = map_.next_value()?;
                         }
-                        GeneratedField::Reason => {
-                            if reason__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("reason"));
+                        GeneratedField::VotingPower => {
+                            if voting_power__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("votingPower"));
                             }
-                            reason__ = Some(map_.next_value()?);
+                            voting_power__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(ProposalWithdraw {
-                    proposal: proposal__.unwrap_or_default(),
-                    reason: reason__.unwrap_or_default(),
+                Ok(ValidatorAbstentionsResponse {
+                    identity_key: identity_key__,
+                    voting_power: voting_power__.unwrap_or_default(),
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.core.component.governance.v1.ProposalWithdraw", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.core.component.governance.v1.ValidatorAbstentionsResponse", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for Ratio {
+impl serde::Serialize for ValidatorParticipationRequest {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -6227,39 +10107,30 @@ impl serde::Serialize for Ratio {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.numerator != 0 {
-            len += 1;
-        }
-        if self.denominator != 0 {
+        if self.identity_key.is_some() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.governance.v1.Ratio", len)?;
-        if self.numerator != 0 {
-            #[allow(clippy::needless_borrow)]
-            struct_ser.serialize_field("numerator", ToString::to_string(&self.numerator).as_str())?;
-        }
-        if self.denominator != 0 {
-            #[allow(clippy::needless_borrow)]
-            struct_ser.serialize_field("denominator", ToString::to_string(&self.denominator).as_str())?;
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.governance.v1.ValidatorParticipationRequest", len)?;
+        if let Some(v) = self.identity_key.as_ref() {
+            struct_ser.serialize_field("identityKey", v)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for Ratio {
+impl<'de> serde::Deserialize<'de> for ValidatorParticipationRequest {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "numerator",
-            "denominator",
+            "identity_key",
+            "identityKey",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            Numerator,
-            Denominator,
+            IdentityKey,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -6282,8 +10153,7 @@ impl<'de> serde::Deserialize<'de> for Ratio {
                         E: serde::de::Error,
                     {
                         match value {
-                            "numerator" => Ok(GeneratedField::Numerator),
-                            "denominator" => Ok(GeneratedField::Denominator),
+                            "identityKey" | "identity_key" => Ok(GeneratedField::IdentityKey),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -6293,51 +10163,39 @@ impl<'de> serde::Deserialize<'de> for Ratio {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = Ratio;
+            type Value = ValidatorParticipationRequest;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.core.component.governance.v1.Ratio")
+                formatter.write_str("struct penumbra.core.component.governance.v1.ValidatorParticipationRequest")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<Ratio, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ValidatorParticipationRequest, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut numerator__ = None;
-                let mut denominator__ = None;
+                let mut identity_key__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::Numerator => {
-                            if numerator__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("numerator"));
-                            }
-                            numerator__ = 
-                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
-                            ;
-                        }
-                        GeneratedField::Denominator => {
-                            if denominator__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("denominator"));
+                        GeneratedField::IdentityKey => {
+                            if identity_key__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("identityKey"));
                             }
-                            denominator__ = 
-                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
-                            ;
+                            identity_key__ = map_.next_value()?;
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(Ratio {
-                    numerator: numerator__.unwrap_or_default(),
-                    denominator: denominator__.unwrap_or_default(),
+                Ok(ValidatorParticipationRequest {
+                    identity_key: identity_key__,
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.core.component.governance.v1.Ratio", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.core.component.governance.v1.ValidatorParticipationRequest", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for Tally {
+impl serde::Serialize for ValidatorParticipationResponse {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -6345,48 +10203,41 @@ impl serde::Serialize for Tally {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.yes != 0 {
-            len += 1;
-        }
-        if self.no != 0 {
+        if self.proposals_eligible != 0 {
             len += 1;
         }
-        if self.abstain != 0 {
+        if self.proposals_abstained != 0 {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.governance.v1.Tally", len)?;
-        if self.yes != 0 {
-            #[allow(clippy::needless_borrow)]
-            struct_ser.serialize_field("yes", ToString::to_string(&self.yes).as_str())?;
-        }
-        if self.no != 0 {
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.governance.v1.ValidatorParticipationResponse", len)?;
+        if self.proposals_eligible != 0 {
             #[allow(clippy::needless_borrow)]
-            struct_ser.serialize_field("no", ToString::to_string(&self.no).as_str())?;
+            struct_ser.serialize_field("proposalsEligible", ToString::to_string(&self.proposals_eligible).as_str())?;
         }
-        if self.abstain != 0 {
+        if self.proposals_abstained != 0 {
             #[allow(clippy::needless_borrow)]
-            struct_ser.serialize_field("abstain", ToString::to_string(&self.abstain).as_str())?;
+            struct_ser.serialize_field("proposalsAbstained", ToString::to_string(&self.proposals_abstained).as_str())?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for Tally {
+impl<'de> serde::Deserialize<'de> for ValidatorParticipationResponse {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "yes",
-            "no",
-            "abstain",
+            "proposals_eligible",
+            "proposalsEligible",
+            "proposals_abstained",
+            "proposalsAbstained",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            Yes,
-            No,
-            Abstain,
+            ProposalsEligible,
+            ProposalsAbstained,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -6409,9 +10260,8 @@ impl<'de> serde::Deserialize<'de> for Tally {
                         E: serde::de::Error,
                     {
                         match value {
-                            "yes" => Ok(GeneratedField::Yes),
-                            "no" => Ok(GeneratedField::No),
-                            "abstain" => Ok(GeneratedField::Abstain),
+                            "proposalsEligible" | "proposals_eligible" => Ok(GeneratedField::ProposalsEligible),
+                            "proposalsAbstained" | "proposals_abstained" => Ok(GeneratedField::ProposalsAbstained),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -6421,42 +10271,33 @@ impl<'de> serde::Deserialize<'de> for Tally {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = Tally;
+            type Value = ValidatorParticipationResponse;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.core.component.governance.v1.Tally")
+                formatter.write_str("struct penumbra.core.component.governance.v1.ValidatorParticipationResponse")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<Tally, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ValidatorParticipationResponse, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut yes__ = None;
-                let mut no__ = None;
-                let mut abstain__ = None;
+                let mut proposals_eligible__ = None;
+                let mut proposals_abstained__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::Yes => {
-                            if yes__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("yes"));
-                            }
-                            yes__ = 
-                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
-                            ;
-                        }
-                        GeneratedField::No => {
-                            if no__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("no"));
+                        GeneratedField::ProposalsEligible => {
+                            if proposals_eligible__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("proposalsEligible"));
                             }
-                            no__ = 
+                            proposals_eligible__ = 
                                 Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
                             ;
                         }
-                        GeneratedField::Abstain => {
-                            if abstain__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("abstain"));
+                        GeneratedField::ProposalsAbstained => {
+                            if proposals_abstained__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("proposalsAbstained"));
                             }
-                            abstain__ = 
+                            proposals_abstained__ = 
                                 Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
                             ;
                         }
@@ -6465,14 +10306,13 @@ impl<'de> serde::Deserialize<'de> for Tally {
                         }
                     }
                 }
-                Ok(Tally {
-                    yes: yes__.unwrap_or_default(),
-                    no: no__.unwrap_or_default(),
-                    abstain: abstain__.unwrap_or_default(),
+                Ok(ValidatorParticipationResponse {
+                    proposals_eligible: proposals_eligible__.unwrap_or_default(),
+                    proposals_abstained: proposals_abstained__.unwrap_or_default(),
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.core.component.governance.v1.Tally", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.core.component.governance.v1.ValidatorParticipationResponse", FIELDS, GeneratedVisitor)
     }
 }
 impl serde::Serialize for ValidatorVote {
@@ -6611,6 +10451,9 @@ impl serde::Serialize for ValidatorVoteBody {
         if self.reason.is_some() {
             len += 1;
         }
+        if self.encrypted_vote.is_some() {
+            len += 1;
+        }
         let mut struct_ser = serializer.serialize_struct("penumbra.core.component.governance.v1.ValidatorVoteBody", len)?;
         if self.proposal != 0 {
             #[allow(clippy::needless_borrow)]
@@ -6628,6 +10471,9 @@ impl serde::Serialize for ValidatorVoteBody {
         if let Some(v) = self.reason.as_ref() {
             struct_ser.serialize_field("reason", v)?;
         }
+        if let Some(v) = self.encrypted_vote.as_ref() {
+            struct_ser.serialize_field("encryptedVote", v)?;
+        }
         struct_ser.end()
     }
 }
@@ -6645,6 +10491,8 @@ impl<'de> serde::Deserialize<'de> for ValidatorVoteBody {
             "governance_key",
             "governanceKey",
             "reason",
+            "encrypted_vote",
+            "encryptedVote",
         ];
 
         #[allow(clippy::enum_variant_names)]
@@ -6654,6 +10502,7 @@ impl<'de> serde::Deserialize<'de> for ValidatorVoteBody {
             IdentityKey,
             GovernanceKey,
             Reason,
+            EncryptedVote,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -6681,6 +10530,7 @@ impl<'de> serde::Deserialize<'de> for ValidatorVoteBody {
                             "identityKey" | "identity_key" => Ok(GeneratedField::IdentityKey),
                             "governanceKey" | "governance_key" => Ok(GeneratedField::GovernanceKey),
                             "reason" => Ok(GeneratedField::Reason),
+                            "encryptedVote" | "encrypted_vote" => Ok(GeneratedField::EncryptedVote),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -6705,6 +10555,7 @@ impl<'de> serde::Deserialize<'de> for ValidatorVoteBody {
                 let mut identity_key__ = None;
                 let mut governance_key__ = None;
                 let mut reason__ = None;
+                let mut encrypted_vote__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
                         GeneratedField::Proposal => {
@@ -6739,6 +10590,12 @@ impl<'de> serde::Deserialize<'de> for ValidatorVoteBody {
                             }
                             reason__ = map_.next_value()?;
                         }
+                        GeneratedField::EncryptedVote => {
+                            if encrypted_vote__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("encryptedVote"));
+                            }
+                            encrypted_vote__ = map_.next_value()?;
+                        }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
@@ -6750,6 +10607,7 @@ impl<'de> serde::Deserialize<'de> for ValidatorVoteBody {
                     identity_key: identity_key__,
                     governance_key: governance_key__,
                     reason: reason__,
+                    encrypted_vote: encrypted_vote__,
                 })
             }
         }
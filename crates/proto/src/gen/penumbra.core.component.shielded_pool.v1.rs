@@ -526,6 +526,76 @@ impl ::prost::Name for OutputPlan {
         )
     }
 }
+/// An attestation that a full viewing key controlled at least some amount of an
+/// asset at a given height, without revealing the key or its other notes.
+///
+/// The amount and nullifier of each attested note are revealed, so the
+/// transactions which later spend the attested notes can be linked to the
+/// attestation.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct FundsAttestation {
+    /// The height of the state the attestation is made against.
+    #[prost(uint64, tag = "1")]
+    pub height: u64,
+    /// The root of the state commitment tree at that height.
+    #[prost(message, optional, tag = "2")]
+    pub anchor: ::core::option::Option<
+        super::super::super::super::crypto::tct::v1::MerkleRoot,
+    >,
+    /// A proof that the anchor is the root of the state commitment tree at that
+    /// height, against the app hash at that height.
+    #[prost(bytes = "vec", tag = "3")]
+    pub anchor_proof: ::prost::alloc::vec::Vec<u8>,
+    /// The asset whose ownership is attested.
+    #[prost(message, optional, tag = "4")]
+    pub asset_id: ::core::option::Option<super::super::super::asset::v1::AssetId>,
+    /// The attested notes.
+    #[prost(message, repeated, tag = "5")]
+    pub notes: ::prost::alloc::vec::Vec<AttestedNote>,
+}
+impl ::prost::Name for FundsAttestation {
+    const NAME: &'static str = "FundsAttestation";
+    const PACKAGE: &'static str = "penumbra.core.component.shielded_pool.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!(
+            "penumbra.core.component.shielded_pool.v1.{}", Self::NAME
+        )
+    }
+}
+/// A single note included in a funds attestation.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct AttestedNote {
+    /// The amount of the attested asset held by the note.
+    #[prost(message, optional, tag = "1")]
+    pub amount: ::core::option::Option<super::super::super::num::v1::Amount>,
+    /// The nullifier of the note.
+    #[prost(message, optional, tag = "2")]
+    pub nullifier: ::core::option::Option<super::super::sct::v1::Nullifier>,
+    /// The randomized spend verification key used in the proof.
+    #[prost(message, optional, tag = "3")]
+    pub rk: ::core::option::Option<
+        super::super::super::super::crypto::decaf377_rdsa::v1::SpendVerificationKey,
+    >,
+    /// A proof of the note's inclusion in the anchor, and of the nullifier's
+    /// correct derivation, by the viewing key which controls the note.
+    #[prost(message, optional, tag = "4")]
+    pub proof: ::core::option::Option<ZkSpendProof>,
+    /// A proof that the nullifier was not spent, against the app hash at the
+    /// attested height.
+    #[prost(bytes = "vec", tag = "5")]
+    pub unspent_proof: ::prost::alloc::vec::Vec<u8>,
+}
+impl ::prost::Name for AttestedNote {
+    const NAME: &'static str = "AttestedNote";
+    const PACKAGE: &'static str = "penumbra.core.component.shielded_pool.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!(
+            "penumbra.core.component.shielded_pool.v1.{}", Self::NAME
+        )
+    }
+}
 /// Requests information on an asset by asset id
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -49,6 +49,12 @@ pub struct Validator {
     pub governance_key: ::core::option::Option<
         super::super::super::keys::v1::GovernanceKey,
     >,
+    /// If nonzero, the index of the epoch from which the funding streams in this
+    /// definition take effect, giving delegators advance notice of a commission
+    /// change. Until then, the validator's previous funding streams remain in
+    /// effect. If zero, the funding streams take effect immediately.
+    #[prost(uint64, tag = "10")]
+    pub funding_streams_effective_epoch: u64,
 }
 impl ::prost::Name for Validator {
     const NAME: &'static str = "Validator";
@@ -93,6 +99,11 @@ pub mod funding_stream {
         /// allocated to this funding stream, specified in basis points.
         #[prost(uint32, tag = "2")]
         pub rate_bps: u32,
+        /// Whether the rewards for this funding stream are automatically
+        /// re-delegated to the validator's delegation pool at each epoch boundary,
+        /// rather than paid out as staking tokens.
+        #[prost(bool, tag = "3")]
+        pub auto_compound: bool,
     }
     impl ::prost::Name for ToAddress {
         const NAME: &'static str = "ToAddress";
@@ -110,6 +121,11 @@ pub mod funding_stream {
         /// allocated to this funding stream, specified in basis points.
         #[prost(uint32, tag = "2")]
         pub rate_bps: u32,
+        /// Whether the rewards for this funding stream are automatically
+        /// re-delegated to the validator's delegation pool at each epoch boundary,
+        /// rather than paid out as staking tokens.
+        #[prost(bool, tag = "3")]
+        pub auto_compound: bool,
     }
     impl ::prost::Name for ToCommunityPool {
         const NAME: &'static str = "ToCommunityPool";
@@ -388,6 +404,16 @@ pub struct Delegate {
     /// stateless verification that the transaction is internally consistent.
     #[prost(message, optional, tag = "4")]
     pub delegation_amount: ::core::option::Option<super::super::super::num::v1::Amount>,
+    /// If set, this delegation adds to the validator's self-bond, authorized by
+    /// this signature by the validator's identity key over the delegation, with
+    /// this signature left empty, prefixed by the "penumbra_self_bond" domain
+    /// separator.
+    ///
+    /// Self-bonded delegations do not produce delegation tokens. Instead, the
+    /// delegation is locked in the validator's self-bond, and can only be
+    /// undelegated with a `SelfBondRelease` signed by the validator.
+    #[prost(bytes = "vec", tag = "5")]
+    pub self_bond_auth_sig: ::prost::alloc::vec::Vec<u8>,
 }
 impl ::prost::Name for Delegate {
     const NAME: &'static str = "Delegate";
@@ -396,6 +422,26 @@ impl ::prost::Name for Delegate {
         ::prost::alloc::format!("penumbra.core.component.stake.v1.{}", Self::NAME)
     }
 }
+/// Authorizes an undelegation from a validator's self-bond.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SelfBondRelease {
+    /// The sequence number of the release, which must be one more than that of
+    /// the validator's previous release, so that releases can't be replayed.
+    #[prost(uint64, tag = "1")]
+    pub sequence: u64,
+    /// A signature by the validator's identity key over the undelegation, with
+    /// this signature left empty.
+    #[prost(bytes = "vec", tag = "2")]
+    pub auth_sig: ::prost::alloc::vec::Vec<u8>,
+}
+impl ::prost::Name for SelfBondRelease {
+    const NAME: &'static str = "SelfBondRelease";
+    const PACKAGE: &'static str = "penumbra.core.component.stake.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.core.component.stake.v1.{}", Self::NAME)
+    }
+}
 /// A transaction action withdrawing stake from a validator's delegation pool.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -418,6 +464,10 @@ pub struct Undelegate {
     /// stateless verification that the transaction is internally consistent.
     #[prost(message, optional, tag = "4")]
     pub delegation_amount: ::core::option::Option<super::super::super::num::v1::Amount>,
+    /// If set, the undelegation is released from the validator's self-bond,
+    /// rather than consuming delegation tokens.
+    #[prost(message, optional, tag = "5")]
+    pub self_bond_release: ::core::option::Option<SelfBondRelease>,
 }
 impl ::prost::Name for Undelegate {
     const NAME: &'static str = "Undelegate";
@@ -426,6 +476,57 @@ impl ::prost::Name for Undelegate {
         ::prost::alloc::format!("penumbra.core.component.stake.v1.{}", Self::NAME)
     }
 }
+/// A transaction action moving stake from one validator's delegation pool to
+/// another's, without waiting out the unbonding period.
+///
+/// The amount of stake which can be redelegated away from a validator in each
+/// epoch is limited by the `redelegation_limit_bps` staking parameter, and a
+/// validator which has received redelegations in an epoch can't be redelegated
+/// away from until the next one.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Redelegate {
+    /// The identity key of the validator to undelegate from.
+    #[prost(message, optional, tag = "1")]
+    pub from_validator_identity: ::core::option::Option<
+        super::super::super::keys::v1::IdentityKey,
+    >,
+    /// The identity key of the validator to delegate to.
+    #[prost(message, optional, tag = "2")]
+    pub to_validator_identity: ::core::option::Option<
+        super::super::super::keys::v1::IdentityKey,
+    >,
+    /// The index of the epoch in which this redelegation was performed.
+    /// The redelegation takes effect in the next epoch.
+    #[prost(uint64, tag = "3")]
+    pub epoch_index: u64,
+    /// The amount of the source validator's delegation tokens consumed by this action.
+    #[prost(message, optional, tag = "4")]
+    pub from_delegation_amount: ::core::option::Option<
+        super::super::super::num::v1::Amount,
+    >,
+    /// The amount of stake moved, in units of unbonded stake.
+    ///
+    /// This is implied by the source validator's exchange rate in the specified
+    /// epoch, and is checked in transaction validation.
+    #[prost(message, optional, tag = "5")]
+    pub unbonded_amount: ::core::option::Option<super::super::super::num::v1::Amount>,
+    /// The amount of the destination validator's delegation tokens produced by this action.
+    ///
+    /// This is implied by the destination validator's exchange rate in the
+    /// specified epoch, and is checked in transaction validation.
+    #[prost(message, optional, tag = "6")]
+    pub to_delegation_amount: ::core::option::Option<
+        super::super::super::num::v1::Amount,
+    >,
+}
+impl ::prost::Name for Redelegate {
+    const NAME: &'static str = "Redelegate";
+    const PACKAGE: &'static str = "penumbra.core.component.stake.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.core.component.stake.v1.{}", Self::NAME)
+    }
+}
 /// A transaction action finishing an undelegation, converting (slashable)
 /// "unbonding tokens" to (unslashable) staking tokens.
 #[allow(clippy::derive_partial_eq_without_eq)]
@@ -572,6 +673,369 @@ impl ::prost::Name for Penalty {
         ::prost::alloc::format!("penumbra.core.component.stake.v1.{}", Self::NAME)
     }
 }
+/// A record of the terms on which unbonding tokens were claimed, for accounting purposes.
+///
+/// Claimed amounts are shielded, so a receipt applies to every claim of unbonding tokens
+/// for the same validator and start epoch.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct DelegationReceipt {
+    /// The validator the tokens were delegated to.
+    #[prost(message, optional, tag = "1")]
+    pub validator_identity: ::core::option::Option<
+        super::super::super::keys::v1::IdentityKey,
+    >,
+    /// The epoch in which the undelegation was initiated.
+    #[prost(uint64, tag = "2")]
+    pub start_epoch_index: u64,
+    /// The epoch at which the unbonding tokens became claimable.
+    #[prost(uint64, tag = "3")]
+    pub unbonding_epoch_index: u64,
+    /// The epoch in which the unbonding tokens were claimed.
+    #[prost(uint64, tag = "4")]
+    pub claim_epoch_index: u64,
+    /// The validator's rate data in the epoch the undelegation was initiated, if recorded.
+    #[prost(message, optional, tag = "5")]
+    pub entry_rate: ::core::option::Option<RateData>,
+    /// The validator's rate data in the epoch the unbonding tokens became claimable, if recorded.
+    #[prost(message, optional, tag = "6")]
+    pub exit_rate: ::core::option::Option<RateData>,
+    /// The penalty applied to the unbonding tokens.
+    #[prost(message, optional, tag = "7")]
+    pub penalty: ::core::option::Option<Penalty>,
+}
+impl ::prost::Name for DelegationReceipt {
+    const NAME: &'static str = "DelegationReceipt";
+    const PACKAGE: &'static str = "penumbra.core.component.stake.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.core.component.stake.v1.{}", Self::NAME)
+    }
+}
+/// A compact record of a validator whose state was pruned after it spent too long
+/// defined or disabled with no stake delegated to it.
+///
+/// This is unrelated to the `TOMBSTONED` validator state, which results from
+/// misbehavior.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ValidatorTombstone {
+    /// The identity key of the pruned validator.
+    #[prost(message, optional, tag = "1")]
+    pub identity_key: ::core::option::Option<super::super::super::keys::v1::IdentityKey>,
+    /// The name from the validator's last definition.
+    #[prost(string, tag = "2")]
+    pub name: ::prost::alloc::string::String,
+    /// The sequence number of the validator's last definition. Any later
+    /// definition of the validator must have a higher sequence number.
+    #[prost(uint32, tag = "3")]
+    pub sequence_number: u32,
+    /// The state the validator was in when it was pruned.
+    #[prost(message, optional, tag = "4")]
+    pub state: ::core::option::Option<ValidatorState>,
+    /// The validator's rate data when it was pruned.
+    #[prost(message, optional, tag = "5")]
+    pub rate_data: ::core::option::Option<RateData>,
+    /// The epoch since which the validator had been inactive with no stake.
+    #[prost(uint64, tag = "6")]
+    pub inactive_since_epoch_index: u64,
+    /// The epoch at the end of which the validator was pruned.
+    #[prost(uint64, tag = "7")]
+    pub pruned_epoch_index: u64,
+}
+impl ::prost::Name for ValidatorTombstone {
+    const NAME: &'static str = "ValidatorTombstone";
+    const PACKAGE: &'static str = "penumbra.core.component.stake.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.core.component.stake.v1.{}", Self::NAME)
+    }
+}
+/// An explanation of why a validator is, or is not, in the active set, computed
+/// from the staking component's state.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ValidatorDiagnosis {
+    #[prost(message, optional, tag = "1")]
+    pub identity_key: ::core::option::Option<super::super::super::keys::v1::IdentityKey>,
+    /// The validator's state, or the state it was in when it was pruned.
+    #[prost(message, optional, tag = "2")]
+    pub state: ::core::option::Option<ValidatorState>,
+    #[prost(enumeration = "validator_diagnosis::InactivityReason", tag = "3")]
+    pub reason: i32,
+    /// The number of blocks the validator missed signing in the current uptime window.
+    #[prost(uint64, tag = "4")]
+    pub missed_blocks: u64,
+    /// The number of missed blocks in the window at which the validator is jailed.
+    #[prost(uint64, tag = "5")]
+    pub missed_blocks_maximum: u64,
+    /// The length of the uptime window, in blocks.
+    #[prost(uint64, tag = "6")]
+    pub signed_blocks_window_len: u64,
+    /// The penalties applied to the validator, by epoch.
+    #[prost(message, repeated, tag = "7")]
+    pub penalties: ::prost::alloc::vec::Vec<validator_diagnosis::EpochPenalty>,
+    /// The value of the validator's delegation pool, in staking tokens.
+    #[prost(message, optional, tag = "8")]
+    pub pool_value: ::core::option::Option<super::super::super::num::v1::Amount>,
+    #[prost(message, optional, tag = "9")]
+    pub min_validator_stake: ::core::option::Option<
+        super::super::super::num::v1::Amount,
+    >,
+    /// The value of the validator's self-bond, in staking tokens.
+    #[prost(message, optional, tag = "10")]
+    pub self_bond_value: ::core::option::Option<super::super::super::num::v1::Amount>,
+    #[prost(message, optional, tag = "11")]
+    pub min_validator_self_delegation: ::core::option::Option<
+        super::super::super::num::v1::Amount,
+    >,
+    /// The validator's voting power in the current epoch.
+    #[prost(message, optional, tag = "12")]
+    pub voting_power: ::core::option::Option<super::super::super::num::v1::Amount>,
+    /// The validator's rank by voting power among the validators eligible for the
+    /// active set, starting from 1, or 0 if it is not eligible.
+    #[prost(uint64, tag = "13")]
+    pub power_rank: u64,
+    #[prost(uint64, tag = "14")]
+    pub active_validator_limit: u64,
+    /// The voting power of the least powerful validator within the active
+    /// validator limit, if the limit is reached.
+    #[prost(message, optional, tag = "15")]
+    pub active_set_threshold: ::core::option::Option<
+        super::super::super::num::v1::Amount,
+    >,
+    /// Steps the validator's operator can take to recover, derived from the
+    /// other fields.
+    #[prost(string, repeated, tag = "16")]
+    pub recovery_hints: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+}
+/// Nested message and enum types in `ValidatorDiagnosis`.
+pub mod validator_diagnosis {
+    /// A penalty applied to the validator in some epoch.
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct EpochPenalty {
+        #[prost(uint64, tag = "1")]
+        pub epoch_index: u64,
+        #[prost(message, optional, tag = "2")]
+        pub penalty: ::core::option::Option<super::Penalty>,
+    }
+    impl ::prost::Name for EpochPenalty {
+        const NAME: &'static str = "EpochPenalty";
+        const PACKAGE: &'static str = "penumbra.core.component.stake.v1";
+        fn full_name() -> ::prost::alloc::string::String {
+            ::prost::alloc::format!(
+                "penumbra.core.component.stake.v1.ValidatorDiagnosis.{}", Self::NAME
+            )
+        }
+    }
+    /// Why a validator is not in the active set.
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum InactivityReason {
+        Unspecified = 0,
+        /// The validator is active.
+        None = 1,
+        /// The validator's delegation pool is worth less than the minimum validator stake.
+        InsufficientStake = 2,
+        /// The validator's self-bond is worth less than the minimum self-delegation.
+        InsufficientSelfBond = 3,
+        /// The validator has too little voting power to rank within the active validator limit.
+        OutsideActiveSet = 4,
+        /// The validator qualifies for the active set, which is only updated at the end of an epoch.
+        PendingEpoch = 5,
+        /// The validator's operator disabled it.
+        Disabled = 6,
+        /// The validator was jailed for missing too many blocks.
+        JailedForDowntime = 7,
+        /// The validator was tombstoned for signing conflicting votes.
+        TombstonedForMisbehavior = 8,
+        /// The validator's state was pruned after it spent too long inactive with no stake.
+        Pruned = 9,
+    }
+    impl InactivityReason {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                InactivityReason::Unspecified => "INACTIVITY_REASON_UNSPECIFIED",
+                InactivityReason::None => "INACTIVITY_REASON_NONE",
+                InactivityReason::InsufficientStake => {
+                    "INACTIVITY_REASON_INSUFFICIENT_STAKE"
+                }
+                InactivityReason::InsufficientSelfBond => {
+                    "INACTIVITY_REASON_INSUFFICIENT_SELF_BOND"
+                }
+                InactivityReason::OutsideActiveSet => {
+                    "INACTIVITY_REASON_OUTSIDE_ACTIVE_SET"
+                }
+                InactivityReason::PendingEpoch => "INACTIVITY_REASON_PENDING_EPOCH",
+                InactivityReason::Disabled => "INACTIVITY_REASON_DISABLED",
+                InactivityReason::JailedForDowntime => {
+                    "INACTIVITY_REASON_JAILED_FOR_DOWNTIME"
+                }
+                InactivityReason::TombstonedForMisbehavior => {
+                    "INACTIVITY_REASON_TOMBSTONED_FOR_MISBEHAVIOR"
+                }
+                InactivityReason::Pruned => "INACTIVITY_REASON_PRUNED",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "INACTIVITY_REASON_UNSPECIFIED" => Some(Self::Unspecified),
+                "INACTIVITY_REASON_NONE" => Some(Self::None),
+                "INACTIVITY_REASON_INSUFFICIENT_STAKE" => Some(Self::InsufficientStake),
+                "INACTIVITY_REASON_INSUFFICIENT_SELF_BOND" => {
+                    Some(Self::InsufficientSelfBond)
+                }
+                "INACTIVITY_REASON_OUTSIDE_ACTIVE_SET" => Some(Self::OutsideActiveSet),
+                "INACTIVITY_REASON_PENDING_EPOCH" => Some(Self::PendingEpoch),
+                "INACTIVITY_REASON_DISABLED" => Some(Self::Disabled),
+                "INACTIVITY_REASON_JAILED_FOR_DOWNTIME" => Some(Self::JailedForDowntime),
+                "INACTIVITY_REASON_TOMBSTONED_FOR_MISBEHAVIOR" => {
+                    Some(Self::TombstonedForMisbehavior)
+                }
+                "INACTIVITY_REASON_PRUNED" => Some(Self::Pruned),
+                _ => None,
+            }
+        }
+    }
+}
+impl ::prost::Name for ValidatorDiagnosis {
+    const NAME: &'static str = "ValidatorDiagnosis";
+    const PACKAGE: &'static str = "penumbra.core.component.stake.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.core.component.stake.v1.{}", Self::NAME)
+    }
+}
+/// The voting power of a single validator, as recorded in a power table.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ValidatorPower {
+    #[prost(message, optional, tag = "1")]
+    pub identity_key: ::core::option::Option<super::super::super::keys::v1::IdentityKey>,
+    #[prost(message, optional, tag = "2")]
+    pub voting_power: ::core::option::Option<super::super::super::num::v1::Amount>,
+}
+impl ::prost::Name for ValidatorPower {
+    const NAME: &'static str = "ValidatorPower";
+    const PACKAGE: &'static str = "penumbra.core.component.stake.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.core.component.stake.v1.{}", Self::NAME)
+    }
+}
+/// The voting power of every validator in the consensus set during an epoch,
+/// as recorded when the epoch began.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ValidatorPowerTable {
+    /// The index of the epoch the table applies to.
+    #[prost(uint64, tag = "1")]
+    pub epoch_index: u64,
+    /// The validators' voting power, ordered by identity key. Validators which
+    /// are not active have zero voting power.
+    #[prost(message, repeated, tag = "2")]
+    pub entries: ::prost::alloc::vec::Vec<ValidatorPower>,
+}
+impl ::prost::Name for ValidatorPowerTable {
+    const NAME: &'static str = "ValidatorPowerTable";
+    const PACKAGE: &'static str = "penumbra.core.component.stake.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.core.component.stake.v1.{}", Self::NAME)
+    }
+}
+/// A commitment to a `ValidatorPowerTable`, recorded in the chain state for
+/// each epoch.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ValidatorPowerTableCommitment {
+    /// The root of the Merkle tree over the entries of the table.
+    #[prost(bytes = "vec", tag = "1")]
+    pub inner: ::prost::alloc::vec::Vec<u8>,
+}
+impl ::prost::Name for ValidatorPowerTableCommitment {
+    const NAME: &'static str = "ValidatorPowerTableCommitment";
+    const PACKAGE: &'static str = "penumbra.core.component.stake.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.core.component.stake.v1.{}", Self::NAME)
+    }
+}
+/// Proves that a validator had some voting power in an epoch, against the app
+/// hash of the state the proof was taken from.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ValidatorPowerProof {
+    /// The index of the epoch the power applies to.
+    #[prost(uint64, tag = "1")]
+    pub epoch_index: u64,
+    /// The validator's voting power in the epoch.
+    #[prost(message, optional, tag = "2")]
+    pub power: ::core::option::Option<ValidatorPower>,
+    /// The position of the validator's entry in the power table.
+    #[prost(uint64, tag = "3")]
+    pub leaf_index: u64,
+    /// The number of entries in the power table.
+    #[prost(uint64, tag = "4")]
+    pub leaf_count: u64,
+    /// The sibling hashes on the path from the entry to the table commitment.
+    #[prost(bytes = "vec", repeated, tag = "5")]
+    pub siblings: ::prost::alloc::vec::Vec<::prost::alloc::vec::Vec<u8>>,
+    /// A proof of the epoch's table commitment against the app hash.
+    #[prost(message, optional, tag = "6")]
+    pub commitment_proof: ::core::option::Option<validator_set_snapshot_proof::KeyProof>,
+    /// The height of the state the proof was taken from.
+    #[prost(uint64, tag = "7")]
+    pub height: u64,
+    /// The app hash of that state, which is committed to by the header of the
+    /// following block.
+    #[prost(bytes = "vec", tag = "8")]
+    pub app_hash: ::prost::alloc::vec::Vec<u8>,
+}
+impl ::prost::Name for ValidatorPowerProof {
+    const NAME: &'static str = "ValidatorPowerProof";
+    const PACKAGE: &'static str = "penumbra.core.component.stake.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.core.component.stake.v1.{}", Self::NAME)
+    }
+}
+/// Indicates that a validator was slashed for signing conflicting votes.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct EventSlashed {
+    /// The identity key of the slashed validator.
+    #[prost(message, optional, tag = "1")]
+    pub identity_key: ::core::option::Option<super::super::super::keys::v1::IdentityKey>,
+    /// The height at which the validator signed conflicting votes.
+    #[prost(uint64, tag = "2")]
+    pub misbehavior_height: u64,
+    /// The penalty applied to the validator's delegation pool.
+    #[prost(message, optional, tag = "3")]
+    pub penalty: ::core::option::Option<Penalty>,
+    /// The hash of the duplicate vote evidence, as computed by CometBFT.
+    ///
+    /// Empty if the misbehavior was reported by CometBFT itself, rather than
+    /// submitted as evidence.
+    #[prost(bytes = "vec", tag = "4")]
+    pub evidence_hash: ::prost::alloc::vec::Vec<u8>,
+}
+impl ::prost::Name for EventSlashed {
+    const NAME: &'static str = "EventSlashed";
+    const PACKAGE: &'static str = "penumbra.core.component.stake.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.core.component.stake.v1.{}", Self::NAME)
+    }
+}
 /// Requests information on the chain's validators.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -683,59 +1147,449 @@ impl ::prost::Name for CurrentValidatorRateResponse {
         ::prost::alloc::format!("penumbra.core.component.stake.v1.{}", Self::NAME)
     }
 }
-/// Staking configuration data.
+/// Requests the delegation changes queued in the current epoch.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
-pub struct StakeParameters {
-    /// The number of epochs an unbonding note for before being released.
-    #[prost(uint64, tag = "1")]
-    pub unbonding_epochs: u64,
-    /// The maximum number of validators in the consensus set.
-    #[prost(uint64, tag = "2")]
-    pub active_validator_limit: u64,
-    /// The base reward rate, expressed in basis points of basis points
-    #[prost(uint64, tag = "3")]
-    pub base_reward_rate: u64,
-    /// The penalty for slashing due to misbehavior.
-    #[prost(uint64, tag = "4")]
-    pub slashing_penalty_misbehavior: u64,
-    /// The penalty for slashing due to downtime.
-    #[prost(uint64, tag = "5")]
-    pub slashing_penalty_downtime: u64,
-    /// The number of blocks in the window to check for downtime.
-    #[prost(uint64, tag = "6")]
-    pub signed_blocks_window_len: u64,
-    /// The maximum number of blocks in the window each validator can miss signing without slashing.
-    #[prost(uint64, tag = "7")]
-    pub missed_blocks_maximum: u64,
-    /// The minimum amount of stake required for a validator to be indexed by the protocol.
-    #[prost(message, optional, tag = "8")]
-    pub min_validator_stake: ::core::option::Option<
-        super::super::super::num::v1::Amount,
-    >,
+pub struct DelegationChangeQueueRequest {
+    /// If set, only return the queued changes for this validator.
+    #[prost(message, optional, tag = "1")]
+    pub identity_key: ::core::option::Option<super::super::super::keys::v1::IdentityKey>,
 }
-impl ::prost::Name for StakeParameters {
-    const NAME: &'static str = "StakeParameters";
+impl ::prost::Name for DelegationChangeQueueRequest {
+    const NAME: &'static str = "DelegationChangeQueueRequest";
     const PACKAGE: &'static str = "penumbra.core.component.stake.v1";
     fn full_name() -> ::prost::alloc::string::String {
         ::prost::alloc::format!("penumbra.core.component.stake.v1.{}", Self::NAME)
     }
 }
-/// Genesis data for the staking component.
+/// The delegation changes queued for a single validator in the current epoch,
+/// and their projected effects at the next epoch boundary.
+///
+/// The staking issuance budget is only known at the epoch boundary itself, so
+/// the projection assumes that the current epoch lasts for its full duration,
+/// and that the active stake stays as it is.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
-pub struct GenesisContent {
-    /// The configuration parameters for the staking component present at genesis
+pub struct DelegationChangeQueueResponse {
     #[prost(message, optional, tag = "1")]
-    pub stake_params: ::core::option::Option<StakeParameters>,
-    /// The list of validators present at genesis.
+    pub identity_key: ::core::option::Option<super::super::super::keys::v1::IdentityKey>,
+    /// The delegations to this validator queued in the current epoch.
     #[prost(message, repeated, tag = "2")]
-    pub validators: ::prost::alloc::vec::Vec<Validator>,
-}
-impl ::prost::Name for GenesisContent {
-    const NAME: &'static str = "GenesisContent";
-    const PACKAGE: &'static str = "penumbra.core.component.stake.v1";
-    fn full_name() -> ::prost::alloc::string::String {
+    pub delegations: ::prost::alloc::vec::Vec<Delegate>,
+    /// The undelegations from this validator queued in the current epoch.
+    #[prost(message, repeated, tag = "3")]
+    pub undelegations: ::prost::alloc::vec::Vec<Undelegate>,
+    /// The validator's rate data for the current epoch.
+    #[prost(message, optional, tag = "4")]
+    pub current_rate_data: ::core::option::Option<RateData>,
+    /// The validator's projected rate data for the next epoch.
+    #[prost(message, optional, tag = "5")]
+    pub projected_rate_data: ::core::option::Option<RateData>,
+    /// The validator's voting power in the current epoch.
+    #[prost(message, optional, tag = "6")]
+    pub current_voting_power: ::core::option::Option<
+        super::super::super::num::v1::Amount,
+    >,
+    /// The validator's projected voting power in the next epoch.
+    #[prost(message, optional, tag = "7")]
+    pub projected_voting_power: ::core::option::Option<
+        super::super::super::num::v1::Amount,
+    >,
+}
+impl ::prost::Name for DelegationChangeQueueResponse {
+    const NAME: &'static str = "DelegationChangeQueueResponse";
+    const PACKAGE: &'static str = "penumbra.core.component.stake.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.core.component.stake.v1.{}", Self::NAME)
+    }
+}
+/// Requests a snapshot of the validator set.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ValidatorSetSnapshotRequest {
+    /// The height at which to take the snapshot. If unset, the latest height is used.
+    #[prost(uint64, tag = "1")]
+    pub height: u64,
+    /// If set, the response includes a proof of the snapshot against the app hash.
+    #[prost(bool, tag = "2")]
+    pub proof: bool,
+}
+impl ::prost::Name for ValidatorSetSnapshotRequest {
+    const NAME: &'static str = "ValidatorSetSnapshotRequest";
+    const PACKAGE: &'static str = "penumbra.core.component.stake.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.core.component.stake.v1.{}", Self::NAME)
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ValidatorSetSnapshotResponse {
+    #[prost(message, optional, tag = "1")]
+    pub snapshot: ::core::option::Option<ValidatorSetSnapshot>,
+    /// A proof of the snapshot against the app hash, if one was requested.
+    #[prost(message, optional, tag = "2")]
+    pub proof: ::core::option::Option<ValidatorSetSnapshotProof>,
+}
+impl ::prost::Name for ValidatorSetSnapshotResponse {
+    const NAME: &'static str = "ValidatorSetSnapshotResponse";
+    const PACKAGE: &'static str = "penumbra.core.component.stake.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.core.component.stake.v1.{}", Self::NAME)
+    }
+}
+/// Proves the contents of a `ValidatorSetSnapshot` against the app hash of the
+/// state it was taken from.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ValidatorSetSnapshotProof {
+    /// The app hash the proofs are against.
+    #[prost(bytes = "vec", tag = "1")]
+    pub app_hash: ::prost::alloc::vec::Vec<u8>,
+    /// Proofs of the block height and of each validator's recorded state.
+    #[prost(message, repeated, tag = "2")]
+    pub key_proofs: ::prost::alloc::vec::Vec<validator_set_snapshot_proof::KeyProof>,
+}
+/// Nested message and enum types in `ValidatorSetSnapshotProof`.
+pub mod validator_set_snapshot_proof {
+    /// A proof of a single state key.
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct KeyProof {
+        /// The state key.
+        #[prost(string, tag = "1")]
+        pub key: ::prost::alloc::string::String,
+        /// The raw value stored under the key.
+        #[prost(bytes = "vec", tag = "2")]
+        pub value: ::prost::alloc::vec::Vec<u8>,
+        /// The encoded ICS23 `MerkleProof` of the value against the app hash.
+        #[prost(bytes = "vec", tag = "3")]
+        pub proof: ::prost::alloc::vec::Vec<u8>,
+    }
+    impl ::prost::Name for KeyProof {
+        const NAME: &'static str = "KeyProof";
+        const PACKAGE: &'static str = "penumbra.core.component.stake.v1";
+        fn full_name() -> ::prost::alloc::string::String {
+            ::prost::alloc::format!(
+                "penumbra.core.component.stake.v1.ValidatorSetSnapshotProof.{}",
+                Self::NAME
+            )
+        }
+    }
+}
+impl ::prost::Name for ValidatorSetSnapshotProof {
+    const NAME: &'static str = "ValidatorSetSnapshotProof";
+    const PACKAGE: &'static str = "penumbra.core.component.stake.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.core.component.stake.v1.{}", Self::NAME)
+    }
+}
+/// The recorded state of a single validator.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ValidatorSnapshot {
+    /// The validator's definition, status, and current rate data.
+    #[prost(message, optional, tag = "1")]
+    pub info: ::core::option::Option<ValidatorInfo>,
+    /// The validator's uptime tracker, if it has one.
+    #[prost(message, optional, tag = "2")]
+    pub uptime: ::core::option::Option<Uptime>,
+}
+impl ::prost::Name for ValidatorSnapshot {
+    const NAME: &'static str = "ValidatorSnapshot";
+    const PACKAGE: &'static str = "penumbra.core.component.stake.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.core.component.stake.v1.{}", Self::NAME)
+    }
+}
+/// A deterministic snapshot of the state of every known validator at some height.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ValidatorSetSnapshot {
+    /// The height at which the snapshot was taken.
+    #[prost(uint64, tag = "1")]
+    pub height: u64,
+    /// The validators, ordered by identity key.
+    #[prost(message, repeated, tag = "2")]
+    pub validators: ::prost::alloc::vec::Vec<ValidatorSnapshot>,
+}
+impl ::prost::Name for ValidatorSetSnapshot {
+    const NAME: &'static str = "ValidatorSetSnapshot";
+    const PACKAGE: &'static str = "penumbra.core.component.stake.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.core.component.stake.v1.{}", Self::NAME)
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ValidatorParticipationRequest {
+    #[prost(message, optional, tag = "1")]
+    pub identity_key: ::core::option::Option<super::super::super::keys::v1::IdentityKey>,
+}
+impl ::prost::Name for ValidatorParticipationRequest {
+    const NAME: &'static str = "ValidatorParticipationRequest";
+    const PACKAGE: &'static str = "penumbra.core.component.stake.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.core.component.stake.v1.{}", Self::NAME)
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ValidatorParticipationResponse {
+    /// The validator's participation over the most recent blocks, in the same
+    /// format as its uptime.
+    #[prost(message, optional, tag = "1")]
+    pub participation: ::core::option::Option<Uptime>,
+}
+impl ::prost::Name for ValidatorParticipationResponse {
+    const NAME: &'static str = "ValidatorParticipationResponse";
+    const PACKAGE: &'static str = "penumbra.core.component.stake.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.core.component.stake.v1.{}", Self::NAME)
+    }
+}
+/// Requests the delegation receipt for claiming unbonding tokens.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct DelegationReceiptRequest {
+    #[prost(message, optional, tag = "1")]
+    pub identity_key: ::core::option::Option<super::super::super::keys::v1::IdentityKey>,
+    #[prost(uint64, tag = "2")]
+    pub start_epoch_index: u64,
+}
+impl ::prost::Name for DelegationReceiptRequest {
+    const NAME: &'static str = "DelegationReceiptRequest";
+    const PACKAGE: &'static str = "penumbra.core.component.stake.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.core.component.stake.v1.{}", Self::NAME)
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct DelegationReceiptResponse {
+    #[prost(message, optional, tag = "1")]
+    pub receipt: ::core::option::Option<DelegationReceipt>,
+}
+impl ::prost::Name for DelegationReceiptResponse {
+    const NAME: &'static str = "DelegationReceiptResponse";
+    const PACKAGE: &'static str = "penumbra.core.component.stake.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.core.component.stake.v1.{}", Self::NAME)
+    }
+}
+/// Requests a validator's rate data over a range of epochs.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ValidatorRateHistoryRequest {
+    #[prost(message, optional, tag = "1")]
+    pub identity_key: ::core::option::Option<super::super::super::keys::v1::IdentityKey>,
+    /// The first epoch to return the rate data for.
+    #[prost(uint64, tag = "2")]
+    pub start_epoch_index: u64,
+    /// The last epoch to return the rate data for, or zero for the current epoch.
+    #[prost(uint64, tag = "3")]
+    pub end_epoch_index: u64,
+}
+impl ::prost::Name for ValidatorRateHistoryRequest {
+    const NAME: &'static str = "ValidatorRateHistoryRequest";
+    const PACKAGE: &'static str = "penumbra.core.component.stake.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.core.component.stake.v1.{}", Self::NAME)
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ValidatorRateHistoryResponse {
+    /// The validator's rate data in a single epoch; epochs in which no rate data
+    /// was recorded are skipped.
+    #[prost(message, optional, tag = "1")]
+    pub rate_data: ::core::option::Option<RateData>,
+}
+impl ::prost::Name for ValidatorRateHistoryResponse {
+    const NAME: &'static str = "ValidatorRateHistoryResponse";
+    const PACKAGE: &'static str = "penumbra.core.component.stake.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.core.component.stake.v1.{}", Self::NAME)
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ValidatorTombstoneRequest {
+    #[prost(message, optional, tag = "1")]
+    pub identity_key: ::core::option::Option<super::super::super::keys::v1::IdentityKey>,
+}
+impl ::prost::Name for ValidatorTombstoneRequest {
+    const NAME: &'static str = "ValidatorTombstoneRequest";
+    const PACKAGE: &'static str = "penumbra.core.component.stake.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.core.component.stake.v1.{}", Self::NAME)
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ValidatorTombstoneResponse {
+    #[prost(message, optional, tag = "1")]
+    pub tombstone: ::core::option::Option<ValidatorTombstone>,
+}
+impl ::prost::Name for ValidatorTombstoneResponse {
+    const NAME: &'static str = "ValidatorTombstoneResponse";
+    const PACKAGE: &'static str = "penumbra.core.component.stake.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.core.component.stake.v1.{}", Self::NAME)
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ValidatorDiagnosisRequest {
+    #[prost(message, optional, tag = "1")]
+    pub identity_key: ::core::option::Option<super::super::super::keys::v1::IdentityKey>,
+}
+impl ::prost::Name for ValidatorDiagnosisRequest {
+    const NAME: &'static str = "ValidatorDiagnosisRequest";
+    const PACKAGE: &'static str = "penumbra.core.component.stake.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.core.component.stake.v1.{}", Self::NAME)
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ValidatorDiagnosisResponse {
+    #[prost(message, optional, tag = "1")]
+    pub diagnosis: ::core::option::Option<ValidatorDiagnosis>,
+}
+impl ::prost::Name for ValidatorDiagnosisResponse {
+    const NAME: &'static str = "ValidatorDiagnosisResponse";
+    const PACKAGE: &'static str = "penumbra.core.component.stake.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.core.component.stake.v1.{}", Self::NAME)
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ValidatorPowerProofRequest {
+    #[prost(message, optional, tag = "1")]
+    pub identity_key: ::core::option::Option<super::super::super::keys::v1::IdentityKey>,
+    /// The index of the epoch to prove the validator's voting power in.
+    #[prost(uint64, tag = "2")]
+    pub epoch_index: u64,
+}
+impl ::prost::Name for ValidatorPowerProofRequest {
+    const NAME: &'static str = "ValidatorPowerProofRequest";
+    const PACKAGE: &'static str = "penumbra.core.component.stake.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.core.component.stake.v1.{}", Self::NAME)
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ValidatorPowerProofResponse {
+    #[prost(message, optional, tag = "1")]
+    pub proof: ::core::option::Option<ValidatorPowerProof>,
+}
+impl ::prost::Name for ValidatorPowerProofResponse {
+    const NAME: &'static str = "ValidatorPowerProofResponse";
+    const PACKAGE: &'static str = "penumbra.core.component.stake.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.core.component.stake.v1.{}", Self::NAME)
+    }
+}
+/// Staking configuration data.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct StakeParameters {
+    /// The number of epochs an unbonding note for before being released.
+    #[prost(uint64, tag = "1")]
+    pub unbonding_epochs: u64,
+    /// The maximum number of validators in the consensus set.
+    #[prost(uint64, tag = "2")]
+    pub active_validator_limit: u64,
+    /// The base reward rate, expressed in basis points of basis points
+    #[prost(uint64, tag = "3")]
+    pub base_reward_rate: u64,
+    /// The penalty for slashing due to misbehavior.
+    #[prost(uint64, tag = "4")]
+    pub slashing_penalty_misbehavior: u64,
+    /// The penalty for slashing due to downtime.
+    #[prost(uint64, tag = "5")]
+    pub slashing_penalty_downtime: u64,
+    /// The number of blocks in the window to check for downtime.
+    #[prost(uint64, tag = "6")]
+    pub signed_blocks_window_len: u64,
+    /// The maximum number of blocks in the window each validator can miss signing without slashing.
+    #[prost(uint64, tag = "7")]
+    pub missed_blocks_maximum: u64,
+    /// The minimum amount of stake required for a validator to be indexed by the protocol.
+    #[prost(message, optional, tag = "8")]
+    pub min_validator_stake: ::core::option::Option<
+        super::super::super::num::v1::Amount,
+    >,
+    /// The minimum value of a validator's self-bond, in staking tokens, for it to
+    /// be in the active set.
+    ///
+    /// Validators whose self-bond falls below this amount are moved out of the
+    /// active set at the next epoch boundary.
+    #[prost(message, optional, tag = "9")]
+    pub min_validator_self_delegation: ::core::option::Option<
+        super::super::super::num::v1::Amount,
+    >,
+    /// The number of epochs a validator can stay defined or disabled with no stake
+    /// delegated to it before its state is pruned, leaving only a tombstone.
+    ///
+    /// If zero, validators are never pruned.
+    #[prost(uint64, tag = "10")]
+    pub inactive_validator_retention_epochs: u64,
+    /// The largest share of a validator's delegation pool which can be
+    /// redelegated to other validators in a single epoch, in basis points.
+    ///
+    /// The limit is released gradually over the course of the epoch, in
+    /// proportion to the blocks elapsed since it began.
+    ///
+    /// If zero, redelegation is disabled.
+    #[prost(uint64, tag = "11")]
+    pub redelegation_limit_bps: u64,
+    /// Whether the delegation tokens of validators which are jailed or unbonding
+    /// are barred from being committed to the DEX, as the reserves of a new
+    /// liquidity position or the input of a new auction.
+    ///
+    /// This limits how far the losses of a slashing event can spread through the
+    /// DEX while the validator's fate is still undecided.
+    #[prost(bool, tag = "12")]
+    pub restrict_at_risk_delegation_tokens: bool,
+    /// Whether a validator definition is rejected if its name is the same as
+    /// another validator's, after folding case, fullwidth characters and
+    /// whitespace.
+    ///
+    /// Regardless of this setting, definitions whose names are easily mistaken
+    /// for another validator's are flagged with an event.
+    #[prost(bool, tag = "13")]
+    pub enforce_unique_validator_names: bool,
+}
+impl ::prost::Name for StakeParameters {
+    const NAME: &'static str = "StakeParameters";
+    const PACKAGE: &'static str = "penumbra.core.component.stake.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.core.component.stake.v1.{}", Self::NAME)
+    }
+}
+/// Genesis data for the staking component.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GenesisContent {
+    /// The configuration parameters for the staking component present at genesis
+    #[prost(message, optional, tag = "1")]
+    pub stake_params: ::core::option::Option<StakeParameters>,
+    /// The list of validators present at genesis.
+    #[prost(message, repeated, tag = "2")]
+    pub validators: ::prost::alloc::vec::Vec<Validator>,
+    /// A snapshot of validators to import at genesis, with their recorded state.
+    ///
+    /// This is intended for reproducing an existing validator set in a test network.
+    #[prost(message, optional, tag = "3")]
+    pub validator_snapshot: ::core::option::Option<ValidatorSetSnapshot>,
+}
+impl ::prost::Name for GenesisContent {
+    const NAME: &'static str = "GenesisContent";
+    const PACKAGE: &'static str = "penumbra.core.component.stake.v1";
+    fn full_name() -> ::prost::alloc::string::String {
         ::prost::alloc::format!("penumbra.core.component.stake.v1.{}", Self::NAME)
     }
 }
@@ -810,28 +1664,286 @@ pub mod query_service_client {
             self.inner = self.inner.accept_compressed(encoding);
             self
         }
-        /// Limits the maximum size of a decoded message.
+        /// Limits the maximum size of a decoded message.
+        ///
+        /// Default: `4MB`
+        #[must_use]
+        pub fn max_decoding_message_size(mut self, limit: usize) -> Self {
+            self.inner = self.inner.max_decoding_message_size(limit);
+            self
+        }
+        /// Limits the maximum size of an encoded message.
+        ///
+        /// Default: `usize::MAX`
+        #[must_use]
+        pub fn max_encoding_message_size(mut self, limit: usize) -> Self {
+            self.inner = self.inner.max_encoding_message_size(limit);
+            self
+        }
+        /// Queries the current validator set, with filtering.
+        pub async fn validator_info(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ValidatorInfoRequest>,
+        ) -> std::result::Result<
+            tonic::Response<tonic::codec::Streaming<super::ValidatorInfoResponse>>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/penumbra.core.component.stake.v1.QueryService/ValidatorInfo",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "penumbra.core.component.stake.v1.QueryService",
+                        "ValidatorInfo",
+                    ),
+                );
+            self.inner.server_streaming(req, path, codec).await
+        }
+        pub async fn validator_status(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ValidatorStatusRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ValidatorStatusResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/penumbra.core.component.stake.v1.QueryService/ValidatorStatus",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "penumbra.core.component.stake.v1.QueryService",
+                        "ValidatorStatus",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn validator_penalty(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ValidatorPenaltyRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ValidatorPenaltyResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/penumbra.core.component.stake.v1.QueryService/ValidatorPenalty",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "penumbra.core.component.stake.v1.QueryService",
+                        "ValidatorPenalty",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn current_validator_rate(
+            &mut self,
+            request: impl tonic::IntoRequest<super::CurrentValidatorRateRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::CurrentValidatorRateResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/penumbra.core.component.stake.v1.QueryService/CurrentValidatorRate",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "penumbra.core.component.stake.v1.QueryService",
+                        "CurrentValidatorRate",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// Queries the delegation changes queued so far in the current epoch, along with
+        /// their projected effects on each affected validator at the next epoch boundary.
+        pub async fn delegation_change_queue(
+            &mut self,
+            request: impl tonic::IntoRequest<super::DelegationChangeQueueRequest>,
+        ) -> std::result::Result<
+            tonic::Response<
+                tonic::codec::Streaming<super::DelegationChangeQueueResponse>,
+            >,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/penumbra.core.component.stake.v1.QueryService/DelegationChangeQueue",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "penumbra.core.component.stake.v1.QueryService",
+                        "DelegationChangeQueue",
+                    ),
+                );
+            self.inner.server_streaming(req, path, codec).await
+        }
+        /// Exports the state of every known validator at a given height, as a
+        /// deterministic snapshot which can be imported into a test genesis.
+        pub async fn validator_set_snapshot(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ValidatorSetSnapshotRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ValidatorSetSnapshotResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/penumbra.core.component.stake.v1.QueryService/ValidatorSetSnapshot",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "penumbra.core.component.stake.v1.QueryService",
+                        "ValidatorSetSnapshot",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// Queries a validator's participation, counting only its votes for each
+        /// decided block, as recorded by this node. This is informational only, and is
+        /// not used for slashing.
         ///
-        /// Default: `4MB`
-        #[must_use]
-        pub fn max_decoding_message_size(mut self, limit: usize) -> Self {
-            self.inner = self.inner.max_decoding_message_size(limit);
-            self
+        /// Only available if the node tracks participation via vote extensions.
+        pub async fn validator_participation(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ValidatorParticipationRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ValidatorParticipationResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/penumbra.core.component.stake.v1.QueryService/ValidatorParticipation",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "penumbra.core.component.stake.v1.QueryService",
+                        "ValidatorParticipation",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
         }
-        /// Limits the maximum size of an encoded message.
-        ///
-        /// Default: `usize::MAX`
-        #[must_use]
-        pub fn max_encoding_message_size(mut self, limit: usize) -> Self {
-            self.inner = self.inner.max_encoding_message_size(limit);
-            self
+        /// Computes the receipt for claiming, in the current epoch, the unbonding tokens
+        /// of an undelegation from a validator initiated in a given epoch.
+        pub async fn delegation_receipt(
+            &mut self,
+            request: impl tonic::IntoRequest<super::DelegationReceiptRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::DelegationReceiptResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/penumbra.core.component.stake.v1.QueryService/DelegationReceipt",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "penumbra.core.component.stake.v1.QueryService",
+                        "DelegationReceipt",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
         }
-        /// Queries the current validator set, with filtering.
-        pub async fn validator_info(
+        /// Streams a validator's rate data for each epoch in a range, as recorded at the
+        /// start of each epoch, so that historical returns can be computed without
+        /// replaying the chain.
+        pub async fn validator_rate_history(
             &mut self,
-            request: impl tonic::IntoRequest<super::ValidatorInfoRequest>,
+            request: impl tonic::IntoRequest<super::ValidatorRateHistoryRequest>,
         ) -> std::result::Result<
-            tonic::Response<tonic::codec::Streaming<super::ValidatorInfoResponse>>,
+            tonic::Response<
+                tonic::codec::Streaming<super::ValidatorRateHistoryResponse>,
+            >,
             tonic::Status,
         > {
             self.inner
@@ -845,23 +1957,25 @@ pub mod query_service_client {
                 })?;
             let codec = tonic::codec::ProstCodec::default();
             let path = http::uri::PathAndQuery::from_static(
-                "/penumbra.core.component.stake.v1.QueryService/ValidatorInfo",
+                "/penumbra.core.component.stake.v1.QueryService/ValidatorRateHistory",
             );
             let mut req = request.into_request();
             req.extensions_mut()
                 .insert(
                     GrpcMethod::new(
                         "penumbra.core.component.stake.v1.QueryService",
-                        "ValidatorInfo",
+                        "ValidatorRateHistory",
                     ),
                 );
             self.inner.server_streaming(req, path, codec).await
         }
-        pub async fn validator_status(
+        /// Queries the tombstone left by a validator whose state was pruned for
+        /// inactivity.
+        pub async fn validator_tombstone(
             &mut self,
-            request: impl tonic::IntoRequest<super::ValidatorStatusRequest>,
+            request: impl tonic::IntoRequest<super::ValidatorTombstoneRequest>,
         ) -> std::result::Result<
-            tonic::Response<super::ValidatorStatusResponse>,
+            tonic::Response<super::ValidatorTombstoneResponse>,
             tonic::Status,
         > {
             self.inner
@@ -875,23 +1989,25 @@ pub mod query_service_client {
                 })?;
             let codec = tonic::codec::ProstCodec::default();
             let path = http::uri::PathAndQuery::from_static(
-                "/penumbra.core.component.stake.v1.QueryService/ValidatorStatus",
+                "/penumbra.core.component.stake.v1.QueryService/ValidatorTombstone",
             );
             let mut req = request.into_request();
             req.extensions_mut()
                 .insert(
                     GrpcMethod::new(
                         "penumbra.core.component.stake.v1.QueryService",
-                        "ValidatorStatus",
+                        "ValidatorTombstone",
                     ),
                 );
             self.inner.unary(req, path, codec).await
         }
-        pub async fn validator_penalty(
+        /// Explains why a validator is not in the active set, from the staking
+        /// component's state, with hints for how its operator can recover.
+        pub async fn validator_diagnosis(
             &mut self,
-            request: impl tonic::IntoRequest<super::ValidatorPenaltyRequest>,
+            request: impl tonic::IntoRequest<super::ValidatorDiagnosisRequest>,
         ) -> std::result::Result<
-            tonic::Response<super::ValidatorPenaltyResponse>,
+            tonic::Response<super::ValidatorDiagnosisResponse>,
             tonic::Status,
         > {
             self.inner
@@ -905,23 +2021,26 @@ pub mod query_service_client {
                 })?;
             let codec = tonic::codec::ProstCodec::default();
             let path = http::uri::PathAndQuery::from_static(
-                "/penumbra.core.component.stake.v1.QueryService/ValidatorPenalty",
+                "/penumbra.core.component.stake.v1.QueryService/ValidatorDiagnosis",
             );
             let mut req = request.into_request();
             req.extensions_mut()
                 .insert(
                     GrpcMethod::new(
                         "penumbra.core.component.stake.v1.QueryService",
-                        "ValidatorPenalty",
+                        "ValidatorDiagnosis",
                     ),
                 );
             self.inner.unary(req, path, codec).await
         }
-        pub async fn current_validator_rate(
+        /// Proves a validator's voting power in a past epoch against the app hash,
+        /// using the power table checkpointed when the epoch began, so that light
+        /// clients can verify it without trusting the node.
+        pub async fn validator_power_proof(
             &mut self,
-            request: impl tonic::IntoRequest<super::CurrentValidatorRateRequest>,
+            request: impl tonic::IntoRequest<super::ValidatorPowerProofRequest>,
         ) -> std::result::Result<
-            tonic::Response<super::CurrentValidatorRateResponse>,
+            tonic::Response<super::ValidatorPowerProofResponse>,
             tonic::Status,
         > {
             self.inner
@@ -935,14 +2054,14 @@ pub mod query_service_client {
                 })?;
             let codec = tonic::codec::ProstCodec::default();
             let path = http::uri::PathAndQuery::from_static(
-                "/penumbra.core.component.stake.v1.QueryService/CurrentValidatorRate",
+                "/penumbra.core.component.stake.v1.QueryService/ValidatorPowerProof",
             );
             let mut req = request.into_request();
             req.extensions_mut()
                 .insert(
                     GrpcMethod::new(
                         "penumbra.core.component.stake.v1.QueryService",
-                        "CurrentValidatorRate",
+                        "ValidatorPowerProof",
                     ),
                 );
             self.inner.unary(req, path, codec).await
@@ -992,6 +2111,101 @@ pub mod query_service_server {
             tonic::Response<super::CurrentValidatorRateResponse>,
             tonic::Status,
         >;
+        /// Server streaming response type for the DelegationChangeQueue method.
+        type DelegationChangeQueueStream: tonic::codegen::tokio_stream::Stream<
+                Item = std::result::Result<
+                    super::DelegationChangeQueueResponse,
+                    tonic::Status,
+                >,
+            >
+            + Send
+            + 'static;
+        /// Queries the delegation changes queued so far in the current epoch, along with
+        /// their projected effects on each affected validator at the next epoch boundary.
+        async fn delegation_change_queue(
+            &self,
+            request: tonic::Request<super::DelegationChangeQueueRequest>,
+        ) -> std::result::Result<
+            tonic::Response<Self::DelegationChangeQueueStream>,
+            tonic::Status,
+        >;
+        /// Exports the state of every known validator at a given height, as a
+        /// deterministic snapshot which can be imported into a test genesis.
+        async fn validator_set_snapshot(
+            &self,
+            request: tonic::Request<super::ValidatorSetSnapshotRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ValidatorSetSnapshotResponse>,
+            tonic::Status,
+        >;
+        /// Queries a validator's participation, counting only its votes for each
+        /// decided block, as recorded by this node. This is informational only, and is
+        /// not used for slashing.
+        ///
+        /// Only available if the node tracks participation via vote extensions.
+        async fn validator_participation(
+            &self,
+            request: tonic::Request<super::ValidatorParticipationRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ValidatorParticipationResponse>,
+            tonic::Status,
+        >;
+        /// Computes the receipt for claiming, in the current epoch, the unbonding tokens
+        /// of an undelegation from a validator initiated in a given epoch.
+        async fn delegation_receipt(
+            &self,
+            request: tonic::Request<super::DelegationReceiptRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::DelegationReceiptResponse>,
+            tonic::Status,
+        >;
+        /// Server streaming response type for the ValidatorRateHistory method.
+        type ValidatorRateHistoryStream: tonic::codegen::tokio_stream::Stream<
+                Item = std::result::Result<
+                    super::ValidatorRateHistoryResponse,
+                    tonic::Status,
+                >,
+            >
+            + Send
+            + 'static;
+        /// Streams a validator's rate data for each epoch in a range, as recorded at the
+        /// start of each epoch, so that historical returns can be computed without
+        /// replaying the chain.
+        async fn validator_rate_history(
+            &self,
+            request: tonic::Request<super::ValidatorRateHistoryRequest>,
+        ) -> std::result::Result<
+            tonic::Response<Self::ValidatorRateHistoryStream>,
+            tonic::Status,
+        >;
+        /// Queries the tombstone left by a validator whose state was pruned for
+        /// inactivity.
+        async fn validator_tombstone(
+            &self,
+            request: tonic::Request<super::ValidatorTombstoneRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ValidatorTombstoneResponse>,
+            tonic::Status,
+        >;
+        /// Explains why a validator is not in the active set, from the staking
+        /// component's state, with hints for how its operator can recover.
+        async fn validator_diagnosis(
+            &self,
+            request: tonic::Request<super::ValidatorDiagnosisRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ValidatorDiagnosisResponse>,
+            tonic::Status,
+        >;
+        /// Proves a validator's voting power in a past epoch against the app hash,
+        /// using the power table checkpointed when the epoch began, so that light
+        /// clients can verify it without trusting the node.
+        async fn validator_power_proof(
+            &self,
+            request: tonic::Request<super::ValidatorPowerProofRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ValidatorPowerProofResponse>,
+            tonic::Status,
+        >;
     }
     /// Query operations for the staking component.
     #[derive(Debug)]
@@ -1075,24 +2289,216 @@ pub mod query_service_server {
             match req.uri().path() {
                 "/penumbra.core.component.stake.v1.QueryService/ValidatorInfo" => {
                     #[allow(non_camel_case_types)]
-                    struct ValidatorInfoSvc<T: QueryService>(pub Arc<T>);
+                    struct ValidatorInfoSvc<T: QueryService>(pub Arc<T>);
+                    impl<
+                        T: QueryService,
+                    > tonic::server::ServerStreamingService<super::ValidatorInfoRequest>
+                    for ValidatorInfoSvc<T> {
+                        type Response = super::ValidatorInfoResponse;
+                        type ResponseStream = T::ValidatorInfoStream;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::ResponseStream>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::ValidatorInfoRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as QueryService>::validator_info(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = ValidatorInfoSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.server_streaming(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/penumbra.core.component.stake.v1.QueryService/ValidatorStatus" => {
+                    #[allow(non_camel_case_types)]
+                    struct ValidatorStatusSvc<T: QueryService>(pub Arc<T>);
+                    impl<
+                        T: QueryService,
+                    > tonic::server::UnaryService<super::ValidatorStatusRequest>
+                    for ValidatorStatusSvc<T> {
+                        type Response = super::ValidatorStatusResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::ValidatorStatusRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as QueryService>::validator_status(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = ValidatorStatusSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/penumbra.core.component.stake.v1.QueryService/ValidatorPenalty" => {
+                    #[allow(non_camel_case_types)]
+                    struct ValidatorPenaltySvc<T: QueryService>(pub Arc<T>);
+                    impl<
+                        T: QueryService,
+                    > tonic::server::UnaryService<super::ValidatorPenaltyRequest>
+                    for ValidatorPenaltySvc<T> {
+                        type Response = super::ValidatorPenaltyResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::ValidatorPenaltyRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as QueryService>::validator_penalty(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = ValidatorPenaltySvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/penumbra.core.component.stake.v1.QueryService/CurrentValidatorRate" => {
+                    #[allow(non_camel_case_types)]
+                    struct CurrentValidatorRateSvc<T: QueryService>(pub Arc<T>);
                     impl<
                         T: QueryService,
-                    > tonic::server::ServerStreamingService<super::ValidatorInfoRequest>
-                    for ValidatorInfoSvc<T> {
-                        type Response = super::ValidatorInfoResponse;
-                        type ResponseStream = T::ValidatorInfoStream;
+                    > tonic::server::UnaryService<super::CurrentValidatorRateRequest>
+                    for CurrentValidatorRateSvc<T> {
+                        type Response = super::CurrentValidatorRateResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::CurrentValidatorRateRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as QueryService>::current_validator_rate(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = CurrentValidatorRateSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/penumbra.core.component.stake.v1.QueryService/DelegationChangeQueue" => {
+                    #[allow(non_camel_case_types)]
+                    struct DelegationChangeQueueSvc<T: QueryService>(pub Arc<T>);
+                    impl<
+                        T: QueryService,
+                    > tonic::server::ServerStreamingService<
+                        super::DelegationChangeQueueRequest,
+                    > for DelegationChangeQueueSvc<T> {
+                        type Response = super::DelegationChangeQueueResponse;
+                        type ResponseStream = T::DelegationChangeQueueStream;
                         type Future = BoxFuture<
                             tonic::Response<Self::ResponseStream>,
                             tonic::Status,
                         >;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::ValidatorInfoRequest>,
+                            request: tonic::Request<super::DelegationChangeQueueRequest>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                <T as QueryService>::validator_info(&inner, request).await
+                                <T as QueryService>::delegation_change_queue(
+                                        &inner,
+                                        request,
+                                    )
+                                    .await
                             };
                             Box::pin(fut)
                         }
@@ -1104,7 +2510,7 @@ pub mod query_service_server {
                     let inner = self.inner.clone();
                     let fut = async move {
                         let inner = inner.0;
-                        let method = ValidatorInfoSvc(inner);
+                        let method = DelegationChangeQueueSvc(inner);
                         let codec = tonic::codec::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -1120,25 +2526,26 @@ pub mod query_service_server {
                     };
                     Box::pin(fut)
                 }
-                "/penumbra.core.component.stake.v1.QueryService/ValidatorStatus" => {
+                "/penumbra.core.component.stake.v1.QueryService/ValidatorSetSnapshot" => {
                     #[allow(non_camel_case_types)]
-                    struct ValidatorStatusSvc<T: QueryService>(pub Arc<T>);
+                    struct ValidatorSetSnapshotSvc<T: QueryService>(pub Arc<T>);
                     impl<
                         T: QueryService,
-                    > tonic::server::UnaryService<super::ValidatorStatusRequest>
-                    for ValidatorStatusSvc<T> {
-                        type Response = super::ValidatorStatusResponse;
+                    > tonic::server::UnaryService<super::ValidatorSetSnapshotRequest>
+                    for ValidatorSetSnapshotSvc<T> {
+                        type Response = super::ValidatorSetSnapshotResponse;
                         type Future = BoxFuture<
                             tonic::Response<Self::Response>,
                             tonic::Status,
                         >;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::ValidatorStatusRequest>,
+                            request: tonic::Request<super::ValidatorSetSnapshotRequest>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                <T as QueryService>::validator_status(&inner, request).await
+                                <T as QueryService>::validator_set_snapshot(&inner, request)
+                                    .await
                             };
                             Box::pin(fut)
                         }
@@ -1150,7 +2557,7 @@ pub mod query_service_server {
                     let inner = self.inner.clone();
                     let fut = async move {
                         let inner = inner.0;
-                        let method = ValidatorStatusSvc(inner);
+                        let method = ValidatorSetSnapshotSvc(inner);
                         let codec = tonic::codec::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -1166,25 +2573,28 @@ pub mod query_service_server {
                     };
                     Box::pin(fut)
                 }
-                "/penumbra.core.component.stake.v1.QueryService/ValidatorPenalty" => {
+                "/penumbra.core.component.stake.v1.QueryService/ValidatorParticipation" => {
                     #[allow(non_camel_case_types)]
-                    struct ValidatorPenaltySvc<T: QueryService>(pub Arc<T>);
+                    struct ValidatorParticipationSvc<T: QueryService>(pub Arc<T>);
                     impl<
                         T: QueryService,
-                    > tonic::server::UnaryService<super::ValidatorPenaltyRequest>
-                    for ValidatorPenaltySvc<T> {
-                        type Response = super::ValidatorPenaltyResponse;
+                    > tonic::server::UnaryService<super::ValidatorParticipationRequest>
+                    for ValidatorParticipationSvc<T> {
+                        type Response = super::ValidatorParticipationResponse;
                         type Future = BoxFuture<
                             tonic::Response<Self::Response>,
                             tonic::Status,
                         >;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::ValidatorPenaltyRequest>,
+                            request: tonic::Request<super::ValidatorParticipationRequest>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                <T as QueryService>::validator_penalty(&inner, request)
+                                <T as QueryService>::validator_participation(
+                                        &inner,
+                                        request,
+                                    )
                                     .await
                             };
                             Box::pin(fut)
@@ -1197,7 +2607,7 @@ pub mod query_service_server {
                     let inner = self.inner.clone();
                     let fut = async move {
                         let inner = inner.0;
-                        let method = ValidatorPenaltySvc(inner);
+                        let method = ValidatorParticipationSvc(inner);
                         let codec = tonic::codec::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -1213,25 +2623,25 @@ pub mod query_service_server {
                     };
                     Box::pin(fut)
                 }
-                "/penumbra.core.component.stake.v1.QueryService/CurrentValidatorRate" => {
+                "/penumbra.core.component.stake.v1.QueryService/DelegationReceipt" => {
                     #[allow(non_camel_case_types)]
-                    struct CurrentValidatorRateSvc<T: QueryService>(pub Arc<T>);
+                    struct DelegationReceiptSvc<T: QueryService>(pub Arc<T>);
                     impl<
                         T: QueryService,
-                    > tonic::server::UnaryService<super::CurrentValidatorRateRequest>
-                    for CurrentValidatorRateSvc<T> {
-                        type Response = super::CurrentValidatorRateResponse;
+                    > tonic::server::UnaryService<super::DelegationReceiptRequest>
+                    for DelegationReceiptSvc<T> {
+                        type Response = super::DelegationReceiptResponse;
                         type Future = BoxFuture<
                             tonic::Response<Self::Response>,
                             tonic::Status,
                         >;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::CurrentValidatorRateRequest>,
+                            request: tonic::Request<super::DelegationReceiptRequest>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                <T as QueryService>::current_validator_rate(&inner, request)
+                                <T as QueryService>::delegation_receipt(&inner, request)
                                     .await
                             };
                             Box::pin(fut)
@@ -1244,7 +2654,197 @@ pub mod query_service_server {
                     let inner = self.inner.clone();
                     let fut = async move {
                         let inner = inner.0;
-                        let method = CurrentValidatorRateSvc(inner);
+                        let method = DelegationReceiptSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/penumbra.core.component.stake.v1.QueryService/ValidatorRateHistory" => {
+                    #[allow(non_camel_case_types)]
+                    struct ValidatorRateHistorySvc<T: QueryService>(pub Arc<T>);
+                    impl<
+                        T: QueryService,
+                    > tonic::server::ServerStreamingService<
+                        super::ValidatorRateHistoryRequest,
+                    > for ValidatorRateHistorySvc<T> {
+                        type Response = super::ValidatorRateHistoryResponse;
+                        type ResponseStream = T::ValidatorRateHistoryStream;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::ResponseStream>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::ValidatorRateHistoryRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as QueryService>::validator_rate_history(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = ValidatorRateHistorySvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.server_streaming(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/penumbra.core.component.stake.v1.QueryService/ValidatorTombstone" => {
+                    #[allow(non_camel_case_types)]
+                    struct ValidatorTombstoneSvc<T: QueryService>(pub Arc<T>);
+                    impl<
+                        T: QueryService,
+                    > tonic::server::UnaryService<super::ValidatorTombstoneRequest>
+                    for ValidatorTombstoneSvc<T> {
+                        type Response = super::ValidatorTombstoneResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::ValidatorTombstoneRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as QueryService>::validator_tombstone(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = ValidatorTombstoneSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/penumbra.core.component.stake.v1.QueryService/ValidatorDiagnosis" => {
+                    #[allow(non_camel_case_types)]
+                    struct ValidatorDiagnosisSvc<T: QueryService>(pub Arc<T>);
+                    impl<
+                        T: QueryService,
+                    > tonic::server::UnaryService<super::ValidatorDiagnosisRequest>
+                    for ValidatorDiagnosisSvc<T> {
+                        type Response = super::ValidatorDiagnosisResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::ValidatorDiagnosisRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as QueryService>::validator_diagnosis(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = ValidatorDiagnosisSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/penumbra.core.component.stake.v1.QueryService/ValidatorPowerProof" => {
+                    #[allow(non_camel_case_types)]
+                    struct ValidatorPowerProofSvc<T: QueryService>(pub Arc<T>);
+                    impl<
+                        T: QueryService,
+                    > tonic::server::UnaryService<super::ValidatorPowerProofRequest>
+                    for ValidatorPowerProofSvc<T> {
+                        type Response = super::ValidatorPowerProofResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::ValidatorPowerProofRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as QueryService>::validator_power_proof(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = ValidatorPowerProofSvc(inner);
                         let codec = tonic::codec::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -635,6 +635,9 @@ impl serde::Serialize for Delegate {
         if self.delegation_amount.is_some() {
             len += 1;
         }
+        if !self.self_bond_auth_sig.is_empty() {
+            len += 1;
+        }
         let mut struct_ser = serializer.serialize_struct("penumbra.core.component.stake.v1.Delegate", len)?;
         if let Some(v) = self.validator_identity.as_ref() {
             struct_ser.serialize_field("validatorIdentity", v)?;
@@ -649,6 +652,10 @@ impl serde::Serialize for Delegate {
         if let Some(v) = self.delegation_amount.as_ref() {
             struct_ser.serialize_field("delegationAmount", v)?;
         }
+        if !self.self_bond_auth_sig.is_empty() {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("selfBondAuthSig", pbjson::private::base64::encode(&self.self_bond_auth_sig).as_str())?;
+        }
         struct_ser.end()
     }
 }
@@ -667,6 +674,8 @@ impl<'de> serde::Deserialize<'de> for Delegate {
             "unbondedAmount",
             "delegation_amount",
             "delegationAmount",
+            "self_bond_auth_sig",
+            "selfBondAuthSig",
         ];
 
         #[allow(clippy::enum_variant_names)]
@@ -675,6 +684,7 @@ impl<'de> serde::Deserialize<'de> for Delegate {
             EpochIndex,
             UnbondedAmount,
             DelegationAmount,
+            SelfBondAuthSig,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -701,6 +711,7 @@ impl<'de> serde::Deserialize<'de> for Delegate {
                             "epochIndex" | "epoch_index" => Ok(GeneratedField::EpochIndex),
                             "unbondedAmount" | "unbonded_amount" => Ok(GeneratedField::UnbondedAmount),
                             "delegationAmount" | "delegation_amount" => Ok(GeneratedField::DelegationAmount),
+                            "selfBondAuthSig" | "self_bond_auth_sig" => Ok(GeneratedField::SelfBondAuthSig),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -724,6 +735,7 @@ impl<'de> serde::Deserialize<'de> for Delegate {
                 let mut epoch_index__ = None;
                 let mut unbonded_amount__ = None;
                 let mut delegation_amount__ = None;
+                let mut self_bond_auth_sig__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
                         GeneratedField::ValidatorIdentity => {
@@ -752,6 +764,14 @@ impl<'de> serde::Deserialize<'de> for Delegate {
                             }
                             delegation_amount__ = map_.next_value()?;
                         }
+                        GeneratedField::SelfBondAuthSig => {
+                            if self_bond_auth_sig__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("selfBondAuthSig"));
+                            }
+                            self_bond_auth_sig__ = 
+                                Some(map_.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
+                            ;
+                        }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
@@ -762,13 +782,14 @@ impl<'de> serde::Deserialize<'de> for Delegate {
                     epoch_index: epoch_index__.unwrap_or_default(),
                     unbonded_amount: unbonded_amount__,
                     delegation_amount: delegation_amount__,
+                    self_bond_auth_sig: self_bond_auth_sig__.unwrap_or_default(),
                 })
             }
         }
         deserializer.deserialize_struct("penumbra.core.component.stake.v1.Delegate", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for DelegationChanges {
+impl serde::Serialize for DelegationChangeQueueRequest {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -776,37 +797,30 @@ impl serde::Serialize for DelegationChanges {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if !self.delegations.is_empty() {
-            len += 1;
-        }
-        if !self.undelegations.is_empty() {
+        if self.identity_key.is_some() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.stake.v1.DelegationChanges", len)?;
-        if !self.delegations.is_empty() {
-            struct_ser.serialize_field("delegations", &self.delegations)?;
-        }
-        if !self.undelegations.is_empty() {
-            struct_ser.serialize_field("undelegations", &self.undelegations)?;
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.stake.v1.DelegationChangeQueueRequest", len)?;
+        if let Some(v) = self.identity_key.as_ref() {
+            struct_ser.serialize_field("identityKey", v)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for DelegationChanges {
+impl<'de> serde::Deserialize<'de> for DelegationChangeQueueRequest {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "delegations",
-            "undelegations",
+            "identity_key",
+            "identityKey",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            Delegations,
-            Undelegations,
+            IdentityKey,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -829,8 +843,7 @@ impl<'de> serde::Deserialize<'de> for DelegationChanges {
                         E: serde::de::Error,
                     {
                         match value {
-                            "delegations" => Ok(GeneratedField::Delegations),
-                            "undelegations" => Ok(GeneratedField::Undelegations),
+                            "identityKey" | "identity_key" => Ok(GeneratedField::IdentityKey),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -840,47 +853,39 @@ impl<'de> serde::Deserialize<'de> for DelegationChanges {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = DelegationChanges;
+            type Value = DelegationChangeQueueRequest;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.core.component.stake.v1.DelegationChanges")
+                formatter.write_str("struct penumbra.core.component.stake.v1.DelegationChangeQueueRequest")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<DelegationChanges, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<DelegationChangeQueueRequest, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut delegations__ = None;
-                let mut undelegations__ = None;
+                let mut identity_key__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::Delegations => {
-                            if delegations__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("delegations"));
-                            }
-                            delegations__ = Some(map_.next_value()?);
-                        }
-                        GeneratedField::Undelegations => {
-                            if undelegations__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("undelegations"));
+                        GeneratedField::IdentityKey => {
+                            if identity_key__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("identityKey"));
                             }
-                            undelegations__ = Some(map_.next_value()?);
+                            identity_key__ = map_.next_value()?;
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(DelegationChanges {
-                    delegations: delegations__.unwrap_or_default(),
-                    undelegations: undelegations__.unwrap_or_default(),
+                Ok(DelegationChangeQueueRequest {
+                    identity_key: identity_key__,
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.core.component.stake.v1.DelegationChanges", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.core.component.stake.v1.DelegationChangeQueueRequest", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for FundingStream {
+impl serde::Serialize for DelegationChangeQueueResponse {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -888,40 +893,82 @@ impl serde::Serialize for FundingStream {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.recipient.is_some() {
+        if self.identity_key.is_some() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.stake.v1.FundingStream", len)?;
-        if let Some(v) = self.recipient.as_ref() {
-            match v {
-                funding_stream::Recipient::ToAddress(v) => {
-                    struct_ser.serialize_field("toAddress", v)?;
-                }
-                funding_stream::Recipient::ToCommunityPool(v) => {
-                    struct_ser.serialize_field("toCommunityPool", v)?;
-                }
-            }
+        if !self.delegations.is_empty() {
+            len += 1;
+        }
+        if !self.undelegations.is_empty() {
+            len += 1;
+        }
+        if self.current_rate_data.is_some() {
+            len += 1;
+        }
+        if self.projected_rate_data.is_some() {
+            len += 1;
+        }
+        if self.current_voting_power.is_some() {
+            len += 1;
+        }
+        if self.projected_voting_power.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.stake.v1.DelegationChangeQueueResponse", len)?;
+        if let Some(v) = self.identity_key.as_ref() {
+            struct_ser.serialize_field("identityKey", v)?;
+        }
+        if !self.delegations.is_empty() {
+            struct_ser.serialize_field("delegations", &self.delegations)?;
+        }
+        if !self.undelegations.is_empty() {
+            struct_ser.serialize_field("undelegations", &self.undelegations)?;
+        }
+        if let Some(v) = self.current_rate_data.as_ref() {
+            struct_ser.serialize_field("currentRateData", v)?;
+        }
+        if let Some(v) = self.projected_rate_data.as_ref() {
+            struct_ser.serialize_field("projectedRateData", v)?;
+        }
+        if let Some(v) = self.current_voting_power.as_ref() {
+            struct_ser.serialize_field("currentVotingPower", v)?;
+        }
+        if let Some(v) = self.projected_voting_power.as_ref() {
+            struct_ser.serialize_field("projectedVotingPower", v)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for FundingStream {
+impl<'de> serde::Deserialize<'de> for DelegationChangeQueueResponse {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "to_address",
-            "toAddress",
-            "to_community_pool",
-            "toCommunityPool",
+            "identity_key",
+            "identityKey",
+            "delegations",
+            "undelegations",
+            "current_rate_data",
+            "currentRateData",
+            "projected_rate_data",
+            "projectedRateData",
+            "current_voting_power",
+            "currentVotingPower",
+            "projected_voting_power",
+            "projectedVotingPower",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            ToAddress,
-            ToCommunityPool,
+            IdentityKey,
+            Delegations,
+            Undelegations,
+            CurrentRateData,
+            ProjectedRateData,
+            CurrentVotingPower,
+            ProjectedVotingPower,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -944,8 +991,13 @@ impl<'de> serde::Deserialize<'de> for FundingStream {
                         E: serde::de::Error,
                     {
                         match value {
-                            "toAddress" | "to_address" => Ok(GeneratedField::ToAddress),
-                            "toCommunityPool" | "to_community_pool" => Ok(GeneratedField::ToCommunityPool),
+                            "identityKey" | "identity_key" => Ok(GeneratedField::IdentityKey),
+                            "delegations" => Ok(GeneratedField::Delegations),
+                            "undelegations" => Ok(GeneratedField::Undelegations),
+                            "currentRateData" | "current_rate_data" => Ok(GeneratedField::CurrentRateData),
+                            "projectedRateData" | "projected_rate_data" => Ok(GeneratedField::ProjectedRateData),
+                            "currentVotingPower" | "current_voting_power" => Ok(GeneratedField::CurrentVotingPower),
+                            "projectedVotingPower" | "projected_voting_power" => Ok(GeneratedField::ProjectedVotingPower),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -955,47 +1007,87 @@ impl<'de> serde::Deserialize<'de> for FundingStream {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = FundingStream;
+            type Value = DelegationChangeQueueResponse;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.core.component.stake.v1.FundingStream")
+                formatter.write_str("struct penumbra.core.component.stake.v1.DelegationChangeQueueResponse")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<FundingStream, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<DelegationChangeQueueResponse, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut recipient__ = None;
+                let mut identity_key__ = None;
+                let mut delegations__ = None;
+                let mut undelegations__ = None;
+                let mut current_rate_data__ = None;
+                let mut projected_rate_data__ = None;
+                let mut current_voting_power__ = None;
+                let mut projected_voting_power__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::ToAddress => {
-                            if recipient__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("toAddress"));
+                        GeneratedField::IdentityKey => {
+                            if identity_key__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("identityKey"));
                             }
-                            recipient__ = map_.next_value::<::std::option::Option<_>>()?.map(funding_stream::Recipient::ToAddress)
-;
+                            identity_key__ = map_.next_value()?;
                         }
-                        GeneratedField::ToCommunityPool => {
-                            if recipient__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("toCommunityPool"));
+                        GeneratedField::Delegations => {
+                            if delegations__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("delegations"));
                             }
-                            recipient__ = map_.next_value::<::std::option::Option<_>>()?.map(funding_stream::Recipient::ToCommunityPool)
-;
+                            delegations__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Undelegations => {
+                            if undelegations__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("undelegations"));
+                            }
+                            undelegations__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::CurrentRateData => {
+                            if current_rate_data__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("currentRateData"));
+                            }
+                            current_rate_data__ = map_.next_value()?;
+                        }
+                        GeneratedField::ProjectedRateData => {
+                            if projected_rate_data__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("projectedRateData"));
+                            }
+                            projected_rate_data__ = map_.next_value()?;
+                        }
+                        GeneratedField::CurrentVotingPower => {
+                            if current_voting_power__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("currentVotingPower"));
+                            }
+                            current_voting_power__ = map_.next_value()?;
+                        }
+                        GeneratedField::ProjectedVotingPower => {
+                            if projected_voting_power__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("projectedVotingPower"));
+                            }
+                            projected_voting_power__ = map_.next_value()?;
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(FundingStream {
-                    recipient: recipient__,
+                Ok(DelegationChangeQueueResponse {
+                    identity_key: identity_key__,
+                    delegations: delegations__.unwrap_or_default(),
+                    undelegations: undelegations__.unwrap_or_default(),
+                    current_rate_data: current_rate_data__,
+                    projected_rate_data: projected_rate_data__,
+                    current_voting_power: current_voting_power__,
+                    projected_voting_power: projected_voting_power__,
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.core.component.stake.v1.FundingStream", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.core.component.stake.v1.DelegationChangeQueueResponse", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for funding_stream::ToAddress {
+impl serde::Serialize for DelegationChanges {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -1003,38 +1095,37 @@ impl serde::Serialize for funding_stream::ToAddress {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if !self.address.is_empty() {
+        if !self.delegations.is_empty() {
             len += 1;
         }
-        if self.rate_bps != 0 {
+        if !self.undelegations.is_empty() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.stake.v1.FundingStream.ToAddress", len)?;
-        if !self.address.is_empty() {
-            struct_ser.serialize_field("address", &self.address)?;
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.stake.v1.DelegationChanges", len)?;
+        if !self.delegations.is_empty() {
+            struct_ser.serialize_field("delegations", &self.delegations)?;
         }
-        if self.rate_bps != 0 {
-            struct_ser.serialize_field("rateBps", &self.rate_bps)?;
+        if !self.undelegations.is_empty() {
+            struct_ser.serialize_field("undelegations", &self.undelegations)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for funding_stream::ToAddress {
+impl<'de> serde::Deserialize<'de> for DelegationChanges {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "address",
-            "rate_bps",
-            "rateBps",
+            "delegations",
+            "undelegations",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            Address,
-            RateBps,
+            Delegations,
+            Undelegations,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -1057,8 +1148,8 @@ impl<'de> serde::Deserialize<'de> for funding_stream::ToAddress {
                         E: serde::de::Error,
                     {
                         match value {
-                            "address" => Ok(GeneratedField::Address),
-                            "rateBps" | "rate_bps" => Ok(GeneratedField::RateBps),
+                            "delegations" => Ok(GeneratedField::Delegations),
+                            "undelegations" => Ok(GeneratedField::Undelegations),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -1068,49 +1159,47 @@ impl<'de> serde::Deserialize<'de> for funding_stream::ToAddress {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = funding_stream::ToAddress;
+            type Value = DelegationChanges;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.core.component.stake.v1.FundingStream.ToAddress")
+                formatter.write_str("struct penumbra.core.component.stake.v1.DelegationChanges")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<funding_stream::ToAddress, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<DelegationChanges, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut address__ = None;
-                let mut rate_bps__ = None;
+                let mut delegations__ = None;
+                let mut undelegations__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::Address => {
-                            if address__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("address"));
+                        GeneratedField::Delegations => {
+                            if delegations__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("delegations"));
                             }
-                            address__ = Some(map_.next_value()?);
+                            delegations__ = Some(map_.next_value()?);
                         }
-                        GeneratedField::RateBps => {
-                            if rate_bps__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("rateBps"));
+                        GeneratedField::Undelegations => {
+                            if undelegations__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("undelegations"));
                             }
-                            rate_bps__ = 
-                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
-                            ;
+                            undelegations__ = Some(map_.next_value()?);
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(funding_stream::ToAddress {
-                    address: address__.unwrap_or_default(),
-                    rate_bps: rate_bps__.unwrap_or_default(),
+                Ok(DelegationChanges {
+                    delegations: delegations__.unwrap_or_default(),
+                    undelegations: undelegations__.unwrap_or_default(),
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.core.component.stake.v1.FundingStream.ToAddress", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.core.component.stake.v1.DelegationChanges", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for funding_stream::ToCommunityPool {
+impl serde::Serialize for DelegationReceipt {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -1118,33 +1207,89 @@ impl serde::Serialize for funding_stream::ToCommunityPool {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.rate_bps != 0 {
+        if self.validator_identity.is_some() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.stake.v1.FundingStream.ToCommunityPool", len)?;
-        if self.rate_bps != 0 {
-            struct_ser.serialize_field("rateBps", &self.rate_bps)?;
+        if self.start_epoch_index != 0 {
+            len += 1;
         }
-        struct_ser.end()
-    }
-}
-impl<'de> serde::Deserialize<'de> for funding_stream::ToCommunityPool {
-    #[allow(deprecated)]
-    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
-    where
-        D: serde::Deserializer<'de>,
-    {
-        const FIELDS: &[&str] = &[
-            "rate_bps",
-            "rateBps",
-        ];
-
-        #[allow(clippy::enum_variant_names)]
-        enum GeneratedField {
-            RateBps,
-            __SkipField__,
+        if self.unbonding_epoch_index != 0 {
+            len += 1;
         }
-        impl<'de> serde::Deserialize<'de> for GeneratedField {
+        if self.claim_epoch_index != 0 {
+            len += 1;
+        }
+        if self.entry_rate.is_some() {
+            len += 1;
+        }
+        if self.exit_rate.is_some() {
+            len += 1;
+        }
+        if self.penalty.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.stake.v1.DelegationReceipt", len)?;
+        if let Some(v) = self.validator_identity.as_ref() {
+            struct_ser.serialize_field("validatorIdentity", v)?;
+        }
+        if self.start_epoch_index != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("startEpochIndex", ToString::to_string(&self.start_epoch_index).as_str())?;
+        }
+        if self.unbonding_epoch_index != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("unbondingEpochIndex", ToString::to_string(&self.unbonding_epoch_index).as_str())?;
+        }
+        if self.claim_epoch_index != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("claimEpochIndex", ToString::to_string(&self.claim_epoch_index).as_str())?;
+        }
+        if let Some(v) = self.entry_rate.as_ref() {
+            struct_ser.serialize_field("entryRate", v)?;
+        }
+        if let Some(v) = self.exit_rate.as_ref() {
+            struct_ser.serialize_field("exitRate", v)?;
+        }
+        if let Some(v) = self.penalty.as_ref() {
+            struct_ser.serialize_field("penalty", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for DelegationReceipt {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "validator_identity",
+            "validatorIdentity",
+            "start_epoch_index",
+            "startEpochIndex",
+            "unbonding_epoch_index",
+            "unbondingEpochIndex",
+            "claim_epoch_index",
+            "claimEpochIndex",
+            "entry_rate",
+            "entryRate",
+            "exit_rate",
+            "exitRate",
+            "penalty",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            ValidatorIdentity,
+            StartEpochIndex,
+            UnbondingEpochIndex,
+            ClaimEpochIndex,
+            EntryRate,
+            ExitRate,
+            Penalty,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
             where
                 D: serde::Deserializer<'de>,
@@ -1164,7 +1309,13 @@ impl<'de> serde::Deserialize<'de> for funding_stream::ToCommunityPool {
                         E: serde::de::Error,
                     {
                         match value {
-                            "rateBps" | "rate_bps" => Ok(GeneratedField::RateBps),
+                            "validatorIdentity" | "validator_identity" => Ok(GeneratedField::ValidatorIdentity),
+                            "startEpochIndex" | "start_epoch_index" => Ok(GeneratedField::StartEpochIndex),
+                            "unbondingEpochIndex" | "unbonding_epoch_index" => Ok(GeneratedField::UnbondingEpochIndex),
+                            "claimEpochIndex" | "claim_epoch_index" => Ok(GeneratedField::ClaimEpochIndex),
+                            "entryRate" | "entry_rate" => Ok(GeneratedField::EntryRate),
+                            "exitRate" | "exit_rate" => Ok(GeneratedField::ExitRate),
+                            "penalty" => Ok(GeneratedField::Penalty),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -1174,41 +1325,93 @@ impl<'de> serde::Deserialize<'de> for funding_stream::ToCommunityPool {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = funding_stream::ToCommunityPool;
+            type Value = DelegationReceipt;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.core.component.stake.v1.FundingStream.ToCommunityPool")
+                formatter.write_str("struct penumbra.core.component.stake.v1.DelegationReceipt")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<funding_stream::ToCommunityPool, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<DelegationReceipt, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut rate_bps__ = None;
+                let mut validator_identity__ = None;
+                let mut start_epoch_index__ = None;
+                let mut unbonding_epoch_index__ = None;
+                let mut claim_epoch_index__ = None;
+                let mut entry_rate__ = None;
+                let mut exit_rate__ = None;
+                let mut penalty__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::RateBps => {
-                            if rate_bps__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("rateBps"));
+                        GeneratedField::ValidatorIdentity => {
+                            if validator_identity__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("validatorIdentity"));
                             }
-                            rate_bps__ = 
+                            validator_identity__ = map_.next_value()?;
+                        }
+                        GeneratedField::StartEpochIndex => {
+                            if start_epoch_index__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("startEpochIndex"));
+                            }
+                            start_epoch_index__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::UnbondingEpochIndex => {
+                            if unbonding_epoch_index__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("unbondingEpochIndex"));
+                            }
+                            unbonding_epoch_index__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::ClaimEpochIndex => {
+                            if claim_epoch_index__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("claimEpochIndex"));
+                            }
+                            claim_epoch_index__ = 
                                 Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
                             ;
                         }
+                        GeneratedField::EntryRate => {
+                            if entry_rate__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("entryRate"));
+                            }
+                            entry_rate__ = map_.next_value()?;
+                        }
+                        GeneratedField::ExitRate => {
+                            if exit_rate__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("exitRate"));
+                            }
+                            exit_rate__ = map_.next_value()?;
+                        }
+                        GeneratedField::Penalty => {
+                            if penalty__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("penalty"));
+                            }
+                            penalty__ = map_.next_value()?;
+                        }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(funding_stream::ToCommunityPool {
-                    rate_bps: rate_bps__.unwrap_or_default(),
+                Ok(DelegationReceipt {
+                    validator_identity: validator_identity__,
+                    start_epoch_index: start_epoch_index__.unwrap_or_default(),
+                    unbonding_epoch_index: unbonding_epoch_index__.unwrap_or_default(),
+                    claim_epoch_index: claim_epoch_index__.unwrap_or_default(),
+                    entry_rate: entry_rate__,
+                    exit_rate: exit_rate__,
+                    penalty: penalty__,
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.core.component.stake.v1.FundingStream.ToCommunityPool", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.core.component.stake.v1.DelegationReceipt", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for GenesisContent {
+impl serde::Serialize for DelegationReceiptRequest {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -1216,38 +1419,40 @@ impl serde::Serialize for GenesisContent {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.stake_params.is_some() {
+        if self.identity_key.is_some() {
             len += 1;
         }
-        if !self.validators.is_empty() {
+        if self.start_epoch_index != 0 {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.stake.v1.GenesisContent", len)?;
-        if let Some(v) = self.stake_params.as_ref() {
-            struct_ser.serialize_field("stakeParams", v)?;
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.stake.v1.DelegationReceiptRequest", len)?;
+        if let Some(v) = self.identity_key.as_ref() {
+            struct_ser.serialize_field("identityKey", v)?;
         }
-        if !self.validators.is_empty() {
-            struct_ser.serialize_field("validators", &self.validators)?;
+        if self.start_epoch_index != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("startEpochIndex", ToString::to_string(&self.start_epoch_index).as_str())?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for GenesisContent {
+impl<'de> serde::Deserialize<'de> for DelegationReceiptRequest {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "stake_params",
-            "stakeParams",
-            "validators",
+            "identity_key",
+            "identityKey",
+            "start_epoch_index",
+            "startEpochIndex",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            StakeParams,
-            Validators,
+            IdentityKey,
+            StartEpochIndex,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -1270,8 +1475,8 @@ impl<'de> serde::Deserialize<'de> for GenesisContent {
                         E: serde::de::Error,
                     {
                         match value {
-                            "stakeParams" | "stake_params" => Ok(GeneratedField::StakeParams),
-                            "validators" => Ok(GeneratedField::Validators),
+                            "identityKey" | "identity_key" => Ok(GeneratedField::IdentityKey),
+                            "startEpochIndex" | "start_epoch_index" => Ok(GeneratedField::StartEpochIndex),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -1281,47 +1486,49 @@ impl<'de> serde::Deserialize<'de> for GenesisContent {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = GenesisContent;
+            type Value = DelegationReceiptRequest;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.core.component.stake.v1.GenesisContent")
+                formatter.write_str("struct penumbra.core.component.stake.v1.DelegationReceiptRequest")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<GenesisContent, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<DelegationReceiptRequest, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut stake_params__ = None;
-                let mut validators__ = None;
+                let mut identity_key__ = None;
+                let mut start_epoch_index__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::StakeParams => {
-                            if stake_params__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("stakeParams"));
+                        GeneratedField::IdentityKey => {
+                            if identity_key__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("identityKey"));
                             }
-                            stake_params__ = map_.next_value()?;
+                            identity_key__ = map_.next_value()?;
                         }
-                        GeneratedField::Validators => {
-                            if validators__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("validators"));
+                        GeneratedField::StartEpochIndex => {
+                            if start_epoch_index__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("startEpochIndex"));
                             }
-                            validators__ = Some(map_.next_value()?);
+                            start_epoch_index__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(GenesisContent {
-                    stake_params: stake_params__,
-                    validators: validators__.unwrap_or_default(),
+                Ok(DelegationReceiptRequest {
+                    identity_key: identity_key__,
+                    start_epoch_index: start_epoch_index__.unwrap_or_default(),
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.core.component.stake.v1.GenesisContent", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.core.component.stake.v1.DelegationReceiptRequest", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for Penalty {
+impl serde::Serialize for DelegationReceiptResponse {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -1329,30 +1536,29 @@ impl serde::Serialize for Penalty {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if !self.inner.is_empty() {
+        if self.receipt.is_some() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.stake.v1.Penalty", len)?;
-        if !self.inner.is_empty() {
-            #[allow(clippy::needless_borrow)]
-            struct_ser.serialize_field("inner", pbjson::private::base64::encode(&self.inner).as_str())?;
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.stake.v1.DelegationReceiptResponse", len)?;
+        if let Some(v) = self.receipt.as_ref() {
+            struct_ser.serialize_field("receipt", v)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for Penalty {
+impl<'de> serde::Deserialize<'de> for DelegationReceiptResponse {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "inner",
+            "receipt",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            Inner,
+            Receipt,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -1375,7 +1581,7 @@ impl<'de> serde::Deserialize<'de> for Penalty {
                         E: serde::de::Error,
                     {
                         match value {
-                            "inner" => Ok(GeneratedField::Inner),
+                            "receipt" => Ok(GeneratedField::Receipt),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -1385,41 +1591,39 @@ impl<'de> serde::Deserialize<'de> for Penalty {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = Penalty;
+            type Value = DelegationReceiptResponse;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.core.component.stake.v1.Penalty")
+                formatter.write_str("struct penumbra.core.component.stake.v1.DelegationReceiptResponse")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<Penalty, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<DelegationReceiptResponse, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut inner__ = None;
+                let mut receipt__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::Inner => {
-                            if inner__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("inner"));
+                        GeneratedField::Receipt => {
+                            if receipt__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("receipt"));
                             }
-                            inner__ = 
-                                Some(map_.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
-                            ;
+                            receipt__ = map_.next_value()?;
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(Penalty {
-                    inner: inner__.unwrap_or_default(),
+                Ok(DelegationReceiptResponse {
+                    receipt: receipt__,
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.core.component.stake.v1.Penalty", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.core.component.stake.v1.DelegationReceiptResponse", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for RateData {
+impl serde::Serialize for EventSlashed {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -1430,33 +1634,34 @@ impl serde::Serialize for RateData {
         if self.identity_key.is_some() {
             len += 1;
         }
-        if self.epoch_index != 0 {
+        if self.misbehavior_height != 0 {
             len += 1;
         }
-        if self.validator_reward_rate.is_some() {
+        if self.penalty.is_some() {
             len += 1;
         }
-        if self.validator_exchange_rate.is_some() {
+        if !self.evidence_hash.is_empty() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.stake.v1.RateData", len)?;
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.stake.v1.EventSlashed", len)?;
         if let Some(v) = self.identity_key.as_ref() {
             struct_ser.serialize_field("identityKey", v)?;
         }
-        if self.epoch_index != 0 {
+        if self.misbehavior_height != 0 {
             #[allow(clippy::needless_borrow)]
-            struct_ser.serialize_field("epochIndex", ToString::to_string(&self.epoch_index).as_str())?;
+            struct_ser.serialize_field("misbehaviorHeight", ToString::to_string(&self.misbehavior_height).as_str())?;
         }
-        if let Some(v) = self.validator_reward_rate.as_ref() {
-            struct_ser.serialize_field("validatorRewardRate", v)?;
+        if let Some(v) = self.penalty.as_ref() {
+            struct_ser.serialize_field("penalty", v)?;
         }
-        if let Some(v) = self.validator_exchange_rate.as_ref() {
-            struct_ser.serialize_field("validatorExchangeRate", v)?;
+        if !self.evidence_hash.is_empty() {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("evidenceHash", pbjson::private::base64::encode(&self.evidence_hash).as_str())?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for RateData {
+impl<'de> serde::Deserialize<'de> for EventSlashed {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
@@ -1465,20 +1670,19 @@ impl<'de> serde::Deserialize<'de> for RateData {
         const FIELDS: &[&str] = &[
             "identity_key",
             "identityKey",
-            "epoch_index",
-            "epochIndex",
-            "validator_reward_rate",
-            "validatorRewardRate",
-            "validator_exchange_rate",
-            "validatorExchangeRate",
+            "misbehavior_height",
+            "misbehaviorHeight",
+            "penalty",
+            "evidence_hash",
+            "evidenceHash",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
             IdentityKey,
-            EpochIndex,
-            ValidatorRewardRate,
-            ValidatorExchangeRate,
+            MisbehaviorHeight,
+            Penalty,
+            EvidenceHash,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -1502,9 +1706,9 @@ impl<'de> serde::Deserialize<'de> for RateData {
                     {
                         match value {
                             "identityKey" | "identity_key" => Ok(GeneratedField::IdentityKey),
-                            "epochIndex" | "epoch_index" => Ok(GeneratedField::EpochIndex),
-                            "validatorRewardRate" | "validator_reward_rate" => Ok(GeneratedField::ValidatorRewardRate),
-                            "validatorExchangeRate" | "validator_exchange_rate" => Ok(GeneratedField::ValidatorExchangeRate),
+                            "misbehaviorHeight" | "misbehavior_height" => Ok(GeneratedField::MisbehaviorHeight),
+                            "penalty" => Ok(GeneratedField::Penalty),
+                            "evidenceHash" | "evidence_hash" => Ok(GeneratedField::EvidenceHash),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -1514,20 +1718,20 @@ impl<'de> serde::Deserialize<'de> for RateData {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = RateData;
+            type Value = EventSlashed;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.core.component.stake.v1.RateData")
+                formatter.write_str("struct penumbra.core.component.stake.v1.EventSlashed")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<RateData, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<EventSlashed, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
                 let mut identity_key__ = None;
-                let mut epoch_index__ = None;
-                let mut validator_reward_rate__ = None;
-                let mut validator_exchange_rate__ = None;
+                let mut misbehavior_height__ = None;
+                let mut penalty__ = None;
+                let mut evidence_hash__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
                         GeneratedField::IdentityKey => {
@@ -1536,43 +1740,45 @@ impl<'de> serde::Deserialize<'de> for RateData {
                             }
                             identity_key__ = map_.next_value()?;
                         }
-                        GeneratedField::EpochIndex => {
-                            if epoch_index__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("epochIndex"));
+                        GeneratedField::MisbehaviorHeight => {
+                            if misbehavior_height__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("misbehaviorHeight"));
                             }
-                            epoch_index__ = 
+                            misbehavior_height__ = 
                                 Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
                             ;
                         }
-                        GeneratedField::ValidatorRewardRate => {
-                            if validator_reward_rate__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("validatorRewardRate"));
+                        GeneratedField::Penalty => {
+                            if penalty__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("penalty"));
                             }
-                            validator_reward_rate__ = map_.next_value()?;
+                            penalty__ = map_.next_value()?;
                         }
-                        GeneratedField::ValidatorExchangeRate => {
-                            if validator_exchange_rate__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("validatorExchangeRate"));
+                        GeneratedField::EvidenceHash => {
+                            if evidence_hash__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("evidenceHash"));
                             }
-                            validator_exchange_rate__ = map_.next_value()?;
+                            evidence_hash__ = 
+                                Some(map_.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
+                            ;
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(RateData {
+                Ok(EventSlashed {
                     identity_key: identity_key__,
-                    epoch_index: epoch_index__.unwrap_or_default(),
-                    validator_reward_rate: validator_reward_rate__,
-                    validator_exchange_rate: validator_exchange_rate__,
+                    misbehavior_height: misbehavior_height__.unwrap_or_default(),
+                    penalty: penalty__,
+                    evidence_hash: evidence_hash__.unwrap_or_default(),
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.core.component.stake.v1.RateData", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.core.component.stake.v1.EventSlashed", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for StakeParameters {
+impl serde::Serialize for FundingStream {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -1580,100 +1786,4578 @@ impl serde::Serialize for StakeParameters {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.unbonding_epochs != 0 {
-            len += 1;
-        }
-        if self.active_validator_limit != 0 {
-            len += 1;
-        }
-        if self.base_reward_rate != 0 {
+        if self.recipient.is_some() {
             len += 1;
         }
-        if self.slashing_penalty_misbehavior != 0 {
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.stake.v1.FundingStream", len)?;
+        if let Some(v) = self.recipient.as_ref() {
+            match v {
+                funding_stream::Recipient::ToAddress(v) => {
+                    struct_ser.serialize_field("toAddress", v)?;
+                }
+                funding_stream::Recipient::ToCommunityPool(v) => {
+                    struct_ser.serialize_field("toCommunityPool", v)?;
+                }
+            }
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for FundingStream {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "to_address",
+            "toAddress",
+            "to_community_pool",
+            "toCommunityPool",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            ToAddress,
+            ToCommunityPool,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "toAddress" | "to_address" => Ok(GeneratedField::ToAddress),
+                            "toCommunityPool" | "to_community_pool" => Ok(GeneratedField::ToCommunityPool),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = FundingStream;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.stake.v1.FundingStream")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<FundingStream, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut recipient__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::ToAddress => {
+                            if recipient__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("toAddress"));
+                            }
+                            recipient__ = map_.next_value::<::std::option::Option<_>>()?.map(funding_stream::Recipient::ToAddress)
+;
+                        }
+                        GeneratedField::ToCommunityPool => {
+                            if recipient__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("toCommunityPool"));
+                            }
+                            recipient__ = map_.next_value::<::std::option::Option<_>>()?.map(funding_stream::Recipient::ToCommunityPool)
+;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(FundingStream {
+                    recipient: recipient__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.stake.v1.FundingStream", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for funding_stream::ToAddress {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.address.is_empty() {
+            len += 1;
+        }
+        if self.rate_bps != 0 {
+            len += 1;
+        }
+        if self.auto_compound {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.stake.v1.FundingStream.ToAddress", len)?;
+        if !self.address.is_empty() {
+            struct_ser.serialize_field("address", &self.address)?;
+        }
+        if self.rate_bps != 0 {
+            struct_ser.serialize_field("rateBps", &self.rate_bps)?;
+        }
+        if self.auto_compound {
+            struct_ser.serialize_field("autoCompound", &self.auto_compound)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for funding_stream::ToAddress {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "address",
+            "rate_bps",
+            "rateBps",
+            "auto_compound",
+            "autoCompound",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Address,
+            RateBps,
+            AutoCompound,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "address" => Ok(GeneratedField::Address),
+                            "rateBps" | "rate_bps" => Ok(GeneratedField::RateBps),
+                            "autoCompound" | "auto_compound" => Ok(GeneratedField::AutoCompound),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = funding_stream::ToAddress;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.stake.v1.FundingStream.ToAddress")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<funding_stream::ToAddress, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut address__ = None;
+                let mut rate_bps__ = None;
+                let mut auto_compound__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Address => {
+                            if address__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("address"));
+                            }
+                            address__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::RateBps => {
+                            if rate_bps__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("rateBps"));
+                            }
+                            rate_bps__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::AutoCompound => {
+                            if auto_compound__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("autoCompound"));
+                            }
+                            auto_compound__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(funding_stream::ToAddress {
+                    address: address__.unwrap_or_default(),
+                    rate_bps: rate_bps__.unwrap_or_default(),
+                    auto_compound: auto_compound__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.stake.v1.FundingStream.ToAddress", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for funding_stream::ToCommunityPool {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.rate_bps != 0 {
+            len += 1;
+        }
+        if self.auto_compound {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.stake.v1.FundingStream.ToCommunityPool", len)?;
+        if self.rate_bps != 0 {
+            struct_ser.serialize_field("rateBps", &self.rate_bps)?;
+        }
+        if self.auto_compound {
+            struct_ser.serialize_field("autoCompound", &self.auto_compound)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for funding_stream::ToCommunityPool {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "rate_bps",
+            "rateBps",
+            "auto_compound",
+            "autoCompound",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            RateBps,
+            AutoCompound,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "rateBps" | "rate_bps" => Ok(GeneratedField::RateBps),
+                            "autoCompound" | "auto_compound" => Ok(GeneratedField::AutoCompound),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = funding_stream::ToCommunityPool;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.stake.v1.FundingStream.ToCommunityPool")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<funding_stream::ToCommunityPool, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut rate_bps__ = None;
+                let mut auto_compound__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::RateBps => {
+                            if rate_bps__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("rateBps"));
+                            }
+                            rate_bps__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::AutoCompound => {
+                            if auto_compound__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("autoCompound"));
+                            }
+                            auto_compound__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(funding_stream::ToCommunityPool {
+                    rate_bps: rate_bps__.unwrap_or_default(),
+                    auto_compound: auto_compound__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.stake.v1.FundingStream.ToCommunityPool", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for GenesisContent {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.stake_params.is_some() {
+            len += 1;
+        }
+        if !self.validators.is_empty() {
+            len += 1;
+        }
+        if self.validator_snapshot.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.stake.v1.GenesisContent", len)?;
+        if let Some(v) = self.stake_params.as_ref() {
+            struct_ser.serialize_field("stakeParams", v)?;
+        }
+        if !self.validators.is_empty() {
+            struct_ser.serialize_field("validators", &self.validators)?;
+        }
+        if let Some(v) = self.validator_snapshot.as_ref() {
+            struct_ser.serialize_field("validatorSnapshot", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for GenesisContent {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "stake_params",
+            "stakeParams",
+            "validators",
+            "validator_snapshot",
+            "validatorSnapshot",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            StakeParams,
+            Validators,
+            ValidatorSnapshot,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "stakeParams" | "stake_params" => Ok(GeneratedField::StakeParams),
+                            "validators" => Ok(GeneratedField::Validators),
+                            "validatorSnapshot" | "validator_snapshot" => Ok(GeneratedField::ValidatorSnapshot),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = GenesisContent;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.stake.v1.GenesisContent")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<GenesisContent, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut stake_params__ = None;
+                let mut validators__ = None;
+                let mut validator_snapshot__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::StakeParams => {
+                            if stake_params__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("stakeParams"));
+                            }
+                            stake_params__ = map_.next_value()?;
+                        }
+                        GeneratedField::Validators => {
+                            if validators__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("validators"));
+                            }
+                            validators__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::ValidatorSnapshot => {
+                            if validator_snapshot__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("validatorSnapshot"));
+                            }
+                            validator_snapshot__ = map_.next_value()?;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(GenesisContent {
+                    stake_params: stake_params__,
+                    validators: validators__.unwrap_or_default(),
+                    validator_snapshot: validator_snapshot__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.stake.v1.GenesisContent", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for Penalty {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.inner.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.stake.v1.Penalty", len)?;
+        if !self.inner.is_empty() {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("inner", pbjson::private::base64::encode(&self.inner).as_str())?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for Penalty {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "inner",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Inner,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "inner" => Ok(GeneratedField::Inner),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = Penalty;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.stake.v1.Penalty")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<Penalty, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut inner__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Inner => {
+                            if inner__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("inner"));
+                            }
+                            inner__ = 
+                                Some(map_.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(Penalty {
+                    inner: inner__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.stake.v1.Penalty", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for RateData {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.identity_key.is_some() {
+            len += 1;
+        }
+        if self.epoch_index != 0 {
+            len += 1;
+        }
+        if self.validator_reward_rate.is_some() {
+            len += 1;
+        }
+        if self.validator_exchange_rate.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.stake.v1.RateData", len)?;
+        if let Some(v) = self.identity_key.as_ref() {
+            struct_ser.serialize_field("identityKey", v)?;
+        }
+        if self.epoch_index != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("epochIndex", ToString::to_string(&self.epoch_index).as_str())?;
+        }
+        if let Some(v) = self.validator_reward_rate.as_ref() {
+            struct_ser.serialize_field("validatorRewardRate", v)?;
+        }
+        if let Some(v) = self.validator_exchange_rate.as_ref() {
+            struct_ser.serialize_field("validatorExchangeRate", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for RateData {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "identity_key",
+            "identityKey",
+            "epoch_index",
+            "epochIndex",
+            "validator_reward_rate",
+            "validatorRewardRate",
+            "validator_exchange_rate",
+            "validatorExchangeRate",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            IdentityKey,
+            EpochIndex,
+            ValidatorRewardRate,
+            ValidatorExchangeRate,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "identityKey" | "identity_key" => Ok(GeneratedField::IdentityKey),
+                            "epochIndex" | "epoch_index" => Ok(GeneratedField::EpochIndex),
+                            "validatorRewardRate" | "validator_reward_rate" => Ok(GeneratedField::ValidatorRewardRate),
+                            "validatorExchangeRate" | "validator_exchange_rate" => Ok(GeneratedField::ValidatorExchangeRate),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = RateData;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.stake.v1.RateData")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<RateData, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut identity_key__ = None;
+                let mut epoch_index__ = None;
+                let mut validator_reward_rate__ = None;
+                let mut validator_exchange_rate__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::IdentityKey => {
+                            if identity_key__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("identityKey"));
+                            }
+                            identity_key__ = map_.next_value()?;
+                        }
+                        GeneratedField::EpochIndex => {
+                            if epoch_index__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("epochIndex"));
+                            }
+                            epoch_index__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::ValidatorRewardRate => {
+                            if validator_reward_rate__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("validatorRewardRate"));
+                            }
+                            validator_reward_rate__ = map_.next_value()?;
+                        }
+                        GeneratedField::ValidatorExchangeRate => {
+                            if validator_exchange_rate__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("validatorExchangeRate"));
+                            }
+                            validator_exchange_rate__ = map_.next_value()?;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(RateData {
+                    identity_key: identity_key__,
+                    epoch_index: epoch_index__.unwrap_or_default(),
+                    validator_reward_rate: validator_reward_rate__,
+                    validator_exchange_rate: validator_exchange_rate__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.stake.v1.RateData", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for Redelegate {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.from_validator_identity.is_some() {
+            len += 1;
+        }
+        if self.to_validator_identity.is_some() {
+            len += 1;
+        }
+        if self.epoch_index != 0 {
+            len += 1;
+        }
+        if self.from_delegation_amount.is_some() {
+            len += 1;
+        }
+        if self.unbonded_amount.is_some() {
+            len += 1;
+        }
+        if self.to_delegation_amount.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.stake.v1.Redelegate", len)?;
+        if let Some(v) = self.from_validator_identity.as_ref() {
+            struct_ser.serialize_field("fromValidatorIdentity", v)?;
+        }
+        if let Some(v) = self.to_validator_identity.as_ref() {
+            struct_ser.serialize_field("toValidatorIdentity", v)?;
+        }
+        if self.epoch_index != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("epochIndex", ToString::to_string(&self.epoch_index).as_str())?;
+        }
+        if let Some(v) = self.from_delegation_amount.as_ref() {
+            struct_ser.serialize_field("fromDelegationAmount", v)?;
+        }
+        if let Some(v) = self.unbonded_amount.as_ref() {
+            struct_ser.serialize_field("unbondedAmount", v)?;
+        }
+        if let Some(v) = self.to_delegation_amount.as_ref() {
+            struct_ser.serialize_field("toDelegationAmount", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for Redelegate {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "from_validator_identity",
+            "fromValidatorIdentity",
+            "to_validator_identity",
+            "toValidatorIdentity",
+            "epoch_index",
+            "epochIndex",
+            "from_delegation_amount",
+            "fromDelegationAmount",
+            "unbonded_amount",
+            "unbondedAmount",
+            "to_delegation_amount",
+            "toDelegationAmount",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            FromValidatorIdentity,
+            ToValidatorIdentity,
+            EpochIndex,
+            FromDelegationAmount,
+            UnbondedAmount,
+            ToDelegationAmount,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "fromValidatorIdentity" | "from_validator_identity" => Ok(GeneratedField::FromValidatorIdentity),
+                            "toValidatorIdentity" | "to_validator_identity" => Ok(GeneratedField::ToValidatorIdentity),
+                            "epochIndex" | "epoch_index" => Ok(GeneratedField::EpochIndex),
+                            "fromDelegationAmount" | "from_delegation_amount" => Ok(GeneratedField::FromDelegationAmount),
+                            "unbondedAmount" | "unbonded_amount" => Ok(GeneratedField::UnbondedAmount),
+                            "toDelegationAmount" | "to_delegation_amount" => Ok(GeneratedField::ToDelegationAmount),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = Redelegate;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.stake.v1.Redelegate")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<Redelegate, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut from_validator_identity__ = None;
+                let mut to_validator_identity__ = None;
+                let mut epoch_index__ = None;
+                let mut from_delegation_amount__ = None;
+                let mut unbonded_amount__ = None;
+                let mut to_delegation_amount__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::FromValidatorIdentity => {
+                            if from_validator_identity__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("fromValidatorIdentity"));
+                            }
+                            from_validator_identity__ = map_.next_value()?;
+                        }
+                        GeneratedField::ToValidatorIdentity => {
+                            if to_validator_identity__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("toValidatorIdentity"));
+                            }
+                            to_validator_identity__ = map_.next_value()?;
+                        }
+                        GeneratedField::EpochIndex => {
+                            if epoch_index__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("epochIndex"));
+                            }
+                            epoch_index__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::FromDelegationAmount => {
+                            if from_delegation_amount__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("fromDelegationAmount"));
+                            }
+                            from_delegation_amount__ = map_.next_value()?;
+                        }
+                        GeneratedField::UnbondedAmount => {
+                            if unbonded_amount__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("unbondedAmount"));
+                            }
+                            unbonded_amount__ = map_.next_value()?;
+                        }
+                        GeneratedField::ToDelegationAmount => {
+                            if to_delegation_amount__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("toDelegationAmount"));
+                            }
+                            to_delegation_amount__ = map_.next_value()?;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(Redelegate {
+                    from_validator_identity: from_validator_identity__,
+                    to_validator_identity: to_validator_identity__,
+                    epoch_index: epoch_index__.unwrap_or_default(),
+                    from_delegation_amount: from_delegation_amount__,
+                    unbonded_amount: unbonded_amount__,
+                    to_delegation_amount: to_delegation_amount__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.stake.v1.Redelegate", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for SelfBondRelease {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.sequence != 0 {
+            len += 1;
+        }
+        if !self.auth_sig.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.stake.v1.SelfBondRelease", len)?;
+        if self.sequence != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("sequence", ToString::to_string(&self.sequence).as_str())?;
+        }
+        if !self.auth_sig.is_empty() {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("authSig", pbjson::private::base64::encode(&self.auth_sig).as_str())?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for SelfBondRelease {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "sequence",
+            "auth_sig",
+            "authSig",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Sequence,
+            AuthSig,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "sequence" => Ok(GeneratedField::Sequence),
+                            "authSig" | "auth_sig" => Ok(GeneratedField::AuthSig),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = SelfBondRelease;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.stake.v1.SelfBondRelease")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<SelfBondRelease, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut sequence__ = None;
+                let mut auth_sig__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Sequence => {
+                            if sequence__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("sequence"));
+                            }
+                            sequence__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::AuthSig => {
+                            if auth_sig__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("authSig"));
+                            }
+                            auth_sig__ = 
+                                Some(map_.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(SelfBondRelease {
+                    sequence: sequence__.unwrap_or_default(),
+                    auth_sig: auth_sig__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.stake.v1.SelfBondRelease", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for StakeParameters {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.unbonding_epochs != 0 {
+            len += 1;
+        }
+        if self.active_validator_limit != 0 {
+            len += 1;
+        }
+        if self.base_reward_rate != 0 {
+            len += 1;
+        }
+        if self.slashing_penalty_misbehavior != 0 {
+            len += 1;
+        }
+        if self.slashing_penalty_downtime != 0 {
+            len += 1;
+        }
+        if self.signed_blocks_window_len != 0 {
+            len += 1;
+        }
+        if self.missed_blocks_maximum != 0 {
+            len += 1;
+        }
+        if self.min_validator_stake.is_some() {
+            len += 1;
+        }
+        if self.min_validator_self_delegation.is_some() {
+            len += 1;
+        }
+        if self.inactive_validator_retention_epochs != 0 {
+            len += 1;
+        }
+        if self.redelegation_limit_bps != 0 {
+            len += 1;
+        }
+        if self.restrict_at_risk_delegation_tokens {
+            len += 1;
+        }
+        if self.enforce_unique_validator_names {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.stake.v1.StakeParameters", len)?;
+        if self.unbonding_epochs != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("unbondingEpochs", ToString::to_string(&self.unbonding_epochs).as_str())?;
+        }
+        if self.active_validator_limit != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("activeValidatorLimit", ToString::to_string(&self.active_validator_limit).as_str())?;
+        }
+        if self.base_reward_rate != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("baseRewardRate", ToString::to_string(&self.base_reward_rate).as_str())?;
+        }
+        if self.slashing_penalty_misbehavior != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("slashingPenaltyMisbehavior", ToString::to_string(&self.slashing_penalty_misbehavior).as_str())?;
+        }
+        if self.slashing_penalty_downtime != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("slashingPenaltyDowntime", ToString::to_string(&self.slashing_penalty_downtime).as_str())?;
+        }
+        if self.signed_blocks_window_len != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("signedBlocksWindowLen", ToString::to_string(&self.signed_blocks_window_len).as_str())?;
+        }
+        if self.missed_blocks_maximum != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("missedBlocksMaximum", ToString::to_string(&self.missed_blocks_maximum).as_str())?;
+        }
+        if let Some(v) = self.min_validator_stake.as_ref() {
+            struct_ser.serialize_field("minValidatorStake", v)?;
+        }
+        if let Some(v) = self.min_validator_self_delegation.as_ref() {
+            struct_ser.serialize_field("minValidatorSelfDelegation", v)?;
+        }
+        if self.inactive_validator_retention_epochs != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("inactiveValidatorRetentionEpochs", ToString::to_string(&self.inactive_validator_retention_epochs).as_str())?;
+        }
+        if self.redelegation_limit_bps != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("redelegationLimitBps", ToString::to_string(&self.redelegation_limit_bps).as_str())?;
+        }
+        if self.restrict_at_risk_delegation_tokens {
+            struct_ser.serialize_field("restrictAtRiskDelegationTokens", &self.restrict_at_risk_delegation_tokens)?;
+        }
+        if self.enforce_unique_validator_names {
+            struct_ser.serialize_field("enforceUniqueValidatorNames", &self.enforce_unique_validator_names)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for StakeParameters {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "unbonding_epochs",
+            "unbondingEpochs",
+            "active_validator_limit",
+            "activeValidatorLimit",
+            "base_reward_rate",
+            "baseRewardRate",
+            "slashing_penalty_misbehavior",
+            "slashingPenaltyMisbehavior",
+            "slashing_penalty_downtime",
+            "slashingPenaltyDowntime",
+            "signed_blocks_window_len",
+            "signedBlocksWindowLen",
+            "missed_blocks_maximum",
+            "missedBlocksMaximum",
+            "min_validator_stake",
+            "minValidatorStake",
+            "min_validator_self_delegation",
+            "minValidatorSelfDelegation",
+            "inactive_validator_retention_epochs",
+            "inactiveValidatorRetentionEpochs",
+            "redelegation_limit_bps",
+            "redelegationLimitBps",
+            "restrict_at_risk_delegation_tokens",
+            "restrictAtRiskDelegationTokens",
+            "enforce_unique_validator_names",
+            "enforceUniqueValidatorNames",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            UnbondingEpochs,
+            ActiveValidatorLimit,
+            BaseRewardRate,
+            SlashingPenaltyMisbehavior,
+            SlashingPenaltyDowntime,
+            SignedBlocksWindowLen,
+            MissedBlocksMaximum,
+            MinValidatorStake,
+            MinValidatorSelfDelegation,
+            InactiveValidatorRetentionEpochs,
+            RedelegationLimitBps,
+            RestrictAtRiskDelegationTokens,
+            EnforceUniqueValidatorNames,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "unbondingEpochs" | "unbonding_epochs" => Ok(GeneratedField::UnbondingEpochs),
+                            "activeValidatorLimit" | "active_validator_limit" => Ok(GeneratedField::ActiveValidatorLimit),
+                            "baseRewardRate" | "base_reward_rate" => Ok(GeneratedField::BaseRewardRate),
+                            "slashingPenaltyMisbehavior" | "slashing_penalty_misbehavior" => Ok(GeneratedField::SlashingPenaltyMisbehavior),
+                            "slashingPenaltyDowntime" | "slashing_penalty_downtime" => Ok(GeneratedField::SlashingPenaltyDowntime),
+                            "signedBlocksWindowLen" | "signed_blocks_window_len" => Ok(GeneratedField::SignedBlocksWindowLen),
+                            "missedBlocksMaximum" | "missed_blocks_maximum" => Ok(GeneratedField::MissedBlocksMaximum),
+                            "minValidatorStake" | "min_validator_stake" => Ok(GeneratedField::MinValidatorStake),
+                            "minValidatorSelfDelegation" | "min_validator_self_delegation" => Ok(GeneratedField::MinValidatorSelfDelegation),
+                            "inactiveValidatorRetentionEpochs" | "inactive_validator_retention_epochs" => Ok(GeneratedField::InactiveValidatorRetentionEpochs),
+                            "redelegationLimitBps" | "redelegation_limit_bps" => Ok(GeneratedField::RedelegationLimitBps),
+                            "restrictAtRiskDelegationTokens" | "restrict_at_risk_delegation_tokens" => Ok(GeneratedField::RestrictAtRiskDelegationTokens),
+                            "enforceUniqueValidatorNames" | "enforce_unique_validator_names" => Ok(GeneratedField::EnforceUniqueValidatorNames),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = StakeParameters;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.stake.v1.StakeParameters")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<StakeParameters, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut unbonding_epochs__ = None;
+                let mut active_validator_limit__ = None;
+                let mut base_reward_rate__ = None;
+                let mut slashing_penalty_misbehavior__ = None;
+                let mut slashing_penalty_downtime__ = None;
+                let mut signed_blocks_window_len__ = None;
+                let mut missed_blocks_maximum__ = None;
+                let mut min_validator_stake__ = None;
+                let mut min_validator_self_delegation__ = None;
+                let mut inactive_validator_retention_epochs__ = None;
+                let mut redelegation_limit_bps__ = None;
+                let mut restrict_at_risk_delegation_tokens__ = None;
+                let mut enforce_unique_validator_names__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::UnbondingEpochs => {
+                            if unbonding_epochs__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("unbondingEpochs"));
+                            }
+                            unbonding_epochs__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::ActiveValidatorLimit => {
+                            if active_validator_limit__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("activeValidatorLimit"));
+                            }
+                            active_validator_limit__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::BaseRewardRate => {
+                            if base_reward_rate__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("baseRewardRate"));
+                            }
+                            base_reward_rate__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::SlashingPenaltyMisbehavior => {
+                            if slashing_penalty_misbehavior__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("slashingPenaltyMisbehavior"));
+                            }
+                            slashing_penalty_misbehavior__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::SlashingPenaltyDowntime => {
+                            if slashing_penalty_downtime__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("slashingPenaltyDowntime"));
+                            }
+                            slashing_penalty_downtime__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::SignedBlocksWindowLen => {
+                            if signed_blocks_window_len__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("signedBlocksWindowLen"));
+                            }
+                            signed_blocks_window_len__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::MissedBlocksMaximum => {
+                            if missed_blocks_maximum__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("missedBlocksMaximum"));
+                            }
+                            missed_blocks_maximum__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::MinValidatorStake => {
+                            if min_validator_stake__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("minValidatorStake"));
+                            }
+                            min_validator_stake__ = map_.next_value()?;
+                        }
+                        GeneratedField::MinValidatorSelfDelegation => {
+                            if min_validator_self_delegation__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("minValidatorSelfDelegation"));
+                            }
+                            min_validator_self_delegation__ = map_.next_value()?;
+                        }
+                        GeneratedField::InactiveValidatorRetentionEpochs => {
+                            if inactive_validator_retention_epochs__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("inactiveValidatorRetentionEpochs"));
+                            }
+                            inactive_validator_retention_epochs__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::RedelegationLimitBps => {
+                            if redelegation_limit_bps__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("redelegationLimitBps"));
+                            }
+                            redelegation_limit_bps__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::RestrictAtRiskDelegationTokens => {
+                            if restrict_at_risk_delegation_tokens__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("restrictAtRiskDelegationTokens"));
+                            }
+                            restrict_at_risk_delegation_tokens__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::EnforceUniqueValidatorNames => {
+                            if enforce_unique_validator_names__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("enforceUniqueValidatorNames"));
+                            }
+                            enforce_unique_validator_names__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(StakeParameters {
+                    unbonding_epochs: unbonding_epochs__.unwrap_or_default(),
+                    active_validator_limit: active_validator_limit__.unwrap_or_default(),
+                    base_reward_rate: base_reward_rate__.unwrap_or_default(),
+                    slashing_penalty_misbehavior: slashing_penalty_misbehavior__.unwrap_or_default(),
+                    slashing_penalty_downtime: slashing_penalty_downtime__.unwrap_or_default(),
+                    signed_blocks_window_len: signed_blocks_window_len__.unwrap_or_default(),
+                    missed_blocks_maximum: missed_blocks_maximum__.unwrap_or_default(),
+                    min_validator_stake: min_validator_stake__,
+                    min_validator_self_delegation: min_validator_self_delegation__,
+                    inactive_validator_retention_epochs: inactive_validator_retention_epochs__.unwrap_or_default(),
+                    redelegation_limit_bps: redelegation_limit_bps__.unwrap_or_default(),
+                    restrict_at_risk_delegation_tokens: restrict_at_risk_delegation_tokens__.unwrap_or_default(),
+                    enforce_unique_validator_names: enforce_unique_validator_names__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.stake.v1.StakeParameters", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for Undelegate {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.validator_identity.is_some() {
+            len += 1;
+        }
+        if self.start_epoch_index != 0 {
+            len += 1;
+        }
+        if self.unbonded_amount.is_some() {
+            len += 1;
+        }
+        if self.delegation_amount.is_some() {
+            len += 1;
+        }
+        if self.self_bond_release.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.stake.v1.Undelegate", len)?;
+        if let Some(v) = self.validator_identity.as_ref() {
+            struct_ser.serialize_field("validatorIdentity", v)?;
+        }
+        if self.start_epoch_index != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("startEpochIndex", ToString::to_string(&self.start_epoch_index).as_str())?;
+        }
+        if let Some(v) = self.unbonded_amount.as_ref() {
+            struct_ser.serialize_field("unbondedAmount", v)?;
+        }
+        if let Some(v) = self.delegation_amount.as_ref() {
+            struct_ser.serialize_field("delegationAmount", v)?;
+        }
+        if let Some(v) = self.self_bond_release.as_ref() {
+            struct_ser.serialize_field("selfBondRelease", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for Undelegate {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "validator_identity",
+            "validatorIdentity",
+            "start_epoch_index",
+            "startEpochIndex",
+            "unbonded_amount",
+            "unbondedAmount",
+            "delegation_amount",
+            "delegationAmount",
+            "self_bond_release",
+            "selfBondRelease",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            ValidatorIdentity,
+            StartEpochIndex,
+            UnbondedAmount,
+            DelegationAmount,
+            SelfBondRelease,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "validatorIdentity" | "validator_identity" => Ok(GeneratedField::ValidatorIdentity),
+                            "startEpochIndex" | "start_epoch_index" => Ok(GeneratedField::StartEpochIndex),
+                            "unbondedAmount" | "unbonded_amount" => Ok(GeneratedField::UnbondedAmount),
+                            "delegationAmount" | "delegation_amount" => Ok(GeneratedField::DelegationAmount),
+                            "selfBondRelease" | "self_bond_release" => Ok(GeneratedField::SelfBondRelease),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = Undelegate;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.stake.v1.Undelegate")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<Undelegate, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut validator_identity__ = None;
+                let mut start_epoch_index__ = None;
+                let mut unbonded_amount__ = None;
+                let mut delegation_amount__ = None;
+                let mut self_bond_release__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::ValidatorIdentity => {
+                            if validator_identity__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("validatorIdentity"));
+                            }
+                            validator_identity__ = map_.next_value()?;
+                        }
+                        GeneratedField::StartEpochIndex => {
+                            if start_epoch_index__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("startEpochIndex"));
+                            }
+                            start_epoch_index__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::UnbondedAmount => {
+                            if unbonded_amount__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("unbondedAmount"));
+                            }
+                            unbonded_amount__ = map_.next_value()?;
+                        }
+                        GeneratedField::DelegationAmount => {
+                            if delegation_amount__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("delegationAmount"));
+                            }
+                            delegation_amount__ = map_.next_value()?;
+                        }
+                        GeneratedField::SelfBondRelease => {
+                            if self_bond_release__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("selfBondRelease"));
+                            }
+                            self_bond_release__ = map_.next_value()?;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(Undelegate {
+                    validator_identity: validator_identity__,
+                    start_epoch_index: start_epoch_index__.unwrap_or_default(),
+                    unbonded_amount: unbonded_amount__,
+                    delegation_amount: delegation_amount__,
+                    self_bond_release: self_bond_release__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.stake.v1.Undelegate", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for UndelegateClaim {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.body.is_some() {
+            len += 1;
+        }
+        if !self.proof.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.stake.v1.UndelegateClaim", len)?;
+        if let Some(v) = self.body.as_ref() {
+            struct_ser.serialize_field("body", v)?;
+        }
+        if !self.proof.is_empty() {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("proof", pbjson::private::base64::encode(&self.proof).as_str())?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for UndelegateClaim {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "body",
+            "proof",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Body,
+            Proof,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "body" => Ok(GeneratedField::Body),
+                            "proof" => Ok(GeneratedField::Proof),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = UndelegateClaim;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.stake.v1.UndelegateClaim")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<UndelegateClaim, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut body__ = None;
+                let mut proof__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Body => {
+                            if body__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("body"));
+                            }
+                            body__ = map_.next_value()?;
+                        }
+                        GeneratedField::Proof => {
+                            if proof__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("proof"));
+                            }
+                            proof__ = 
+                                Some(map_.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(UndelegateClaim {
+                    body: body__,
+                    proof: proof__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.stake.v1.UndelegateClaim", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for UndelegateClaimBody {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.validator_identity.is_some() {
+            len += 1;
+        }
+        if self.start_epoch_index != 0 {
+            len += 1;
+        }
+        if self.penalty.is_some() {
+            len += 1;
+        }
+        if self.balance_commitment.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.stake.v1.UndelegateClaimBody", len)?;
+        if let Some(v) = self.validator_identity.as_ref() {
+            struct_ser.serialize_field("validatorIdentity", v)?;
+        }
+        if self.start_epoch_index != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("startEpochIndex", ToString::to_string(&self.start_epoch_index).as_str())?;
+        }
+        if let Some(v) = self.penalty.as_ref() {
+            struct_ser.serialize_field("penalty", v)?;
+        }
+        if let Some(v) = self.balance_commitment.as_ref() {
+            struct_ser.serialize_field("balanceCommitment", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for UndelegateClaimBody {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "validator_identity",
+            "validatorIdentity",
+            "start_epoch_index",
+            "startEpochIndex",
+            "penalty",
+            "balance_commitment",
+            "balanceCommitment",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            ValidatorIdentity,
+            StartEpochIndex,
+            Penalty,
+            BalanceCommitment,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "validatorIdentity" | "validator_identity" => Ok(GeneratedField::ValidatorIdentity),
+                            "startEpochIndex" | "start_epoch_index" => Ok(GeneratedField::StartEpochIndex),
+                            "penalty" => Ok(GeneratedField::Penalty),
+                            "balanceCommitment" | "balance_commitment" => Ok(GeneratedField::BalanceCommitment),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = UndelegateClaimBody;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.stake.v1.UndelegateClaimBody")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<UndelegateClaimBody, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut validator_identity__ = None;
+                let mut start_epoch_index__ = None;
+                let mut penalty__ = None;
+                let mut balance_commitment__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::ValidatorIdentity => {
+                            if validator_identity__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("validatorIdentity"));
+                            }
+                            validator_identity__ = map_.next_value()?;
+                        }
+                        GeneratedField::StartEpochIndex => {
+                            if start_epoch_index__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("startEpochIndex"));
+                            }
+                            start_epoch_index__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::Penalty => {
+                            if penalty__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("penalty"));
+                            }
+                            penalty__ = map_.next_value()?;
+                        }
+                        GeneratedField::BalanceCommitment => {
+                            if balance_commitment__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("balanceCommitment"));
+                            }
+                            balance_commitment__ = map_.next_value()?;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(UndelegateClaimBody {
+                    validator_identity: validator_identity__,
+                    start_epoch_index: start_epoch_index__.unwrap_or_default(),
+                    penalty: penalty__,
+                    balance_commitment: balance_commitment__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.stake.v1.UndelegateClaimBody", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for UndelegateClaimPlan {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.validator_identity.is_some() {
+            len += 1;
+        }
+        if self.start_epoch_index != 0 {
+            len += 1;
+        }
+        if self.penalty.is_some() {
+            len += 1;
+        }
+        if self.unbonding_amount.is_some() {
+            len += 1;
+        }
+        if !self.balance_blinding.is_empty() {
+            len += 1;
+        }
+        if !self.proof_blinding_r.is_empty() {
+            len += 1;
+        }
+        if !self.proof_blinding_s.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.stake.v1.UndelegateClaimPlan", len)?;
+        if let Some(v) = self.validator_identity.as_ref() {
+            struct_ser.serialize_field("validatorIdentity", v)?;
+        }
+        if self.start_epoch_index != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("startEpochIndex", ToString::to_string(&self.start_epoch_index).as_str())?;
+        }
+        if let Some(v) = self.penalty.as_ref() {
+            struct_ser.serialize_field("penalty", v)?;
+        }
+        if let Some(v) = self.unbonding_amount.as_ref() {
+            struct_ser.serialize_field("unbondingAmount", v)?;
+        }
+        if !self.balance_blinding.is_empty() {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("balanceBlinding", pbjson::private::base64::encode(&self.balance_blinding).as_str())?;
+        }
+        if !self.proof_blinding_r.is_empty() {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("proofBlindingR", pbjson::private::base64::encode(&self.proof_blinding_r).as_str())?;
+        }
+        if !self.proof_blinding_s.is_empty() {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("proofBlindingS", pbjson::private::base64::encode(&self.proof_blinding_s).as_str())?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for UndelegateClaimPlan {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "validator_identity",
+            "validatorIdentity",
+            "start_epoch_index",
+            "startEpochIndex",
+            "penalty",
+            "unbonding_amount",
+            "unbondingAmount",
+            "balance_blinding",
+            "balanceBlinding",
+            "proof_blinding_r",
+            "proofBlindingR",
+            "proof_blinding_s",
+            "proofBlindingS",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            ValidatorIdentity,
+            StartEpochIndex,
+            Penalty,
+            UnbondingAmount,
+            BalanceBlinding,
+            ProofBlindingR,
+            ProofBlindingS,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "validatorIdentity" | "validator_identity" => Ok(GeneratedField::ValidatorIdentity),
+                            "startEpochIndex" | "start_epoch_index" => Ok(GeneratedField::StartEpochIndex),
+                            "penalty" => Ok(GeneratedField::Penalty),
+                            "unbondingAmount" | "unbonding_amount" => Ok(GeneratedField::UnbondingAmount),
+                            "balanceBlinding" | "balance_blinding" => Ok(GeneratedField::BalanceBlinding),
+                            "proofBlindingR" | "proof_blinding_r" => Ok(GeneratedField::ProofBlindingR),
+                            "proofBlindingS" | "proof_blinding_s" => Ok(GeneratedField::ProofBlindingS),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = UndelegateClaimPlan;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.stake.v1.UndelegateClaimPlan")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<UndelegateClaimPlan, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut validator_identity__ = None;
+                let mut start_epoch_index__ = None;
+                let mut penalty__ = None;
+                let mut unbonding_amount__ = None;
+                let mut balance_blinding__ = None;
+                let mut proof_blinding_r__ = None;
+                let mut proof_blinding_s__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::ValidatorIdentity => {
+                            if validator_identity__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("validatorIdentity"));
+                            }
+                            validator_identity__ = map_.next_value()?;
+                        }
+                        GeneratedField::StartEpochIndex => {
+                            if start_epoch_index__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("startEpochIndex"));
+                            }
+                            start_epoch_index__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::Penalty => {
+                            if penalty__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("penalty"));
+                            }
+                            penalty__ = map_.next_value()?;
+                        }
+                        GeneratedField::UnbondingAmount => {
+                            if unbonding_amount__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("unbondingAmount"));
+                            }
+                            unbonding_amount__ = map_.next_value()?;
+                        }
+                        GeneratedField::BalanceBlinding => {
+                            if balance_blinding__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("balanceBlinding"));
+                            }
+                            balance_blinding__ = 
+                                Some(map_.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::ProofBlindingR => {
+                            if proof_blinding_r__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("proofBlindingR"));
+                            }
+                            proof_blinding_r__ = 
+                                Some(map_.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::ProofBlindingS => {
+                            if proof_blinding_s__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("proofBlindingS"));
+                            }
+                            proof_blinding_s__ = 
+                                Some(map_.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(UndelegateClaimPlan {
+                    validator_identity: validator_identity__,
+                    start_epoch_index: start_epoch_index__.unwrap_or_default(),
+                    penalty: penalty__,
+                    unbonding_amount: unbonding_amount__,
+                    balance_blinding: balance_blinding__.unwrap_or_default(),
+                    proof_blinding_r: proof_blinding_r__.unwrap_or_default(),
+                    proof_blinding_s: proof_blinding_s__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.stake.v1.UndelegateClaimPlan", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for Uptime {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.as_of_block_height != 0 {
+            len += 1;
+        }
+        if self.window_len != 0 {
+            len += 1;
+        }
+        if !self.bitvec.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.stake.v1.Uptime", len)?;
+        if self.as_of_block_height != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("asOfBlockHeight", ToString::to_string(&self.as_of_block_height).as_str())?;
+        }
+        if self.window_len != 0 {
+            struct_ser.serialize_field("windowLen", &self.window_len)?;
+        }
+        if !self.bitvec.is_empty() {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("bitvec", pbjson::private::base64::encode(&self.bitvec).as_str())?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for Uptime {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "as_of_block_height",
+            "asOfBlockHeight",
+            "window_len",
+            "windowLen",
+            "bitvec",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            AsOfBlockHeight,
+            WindowLen,
+            Bitvec,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "asOfBlockHeight" | "as_of_block_height" => Ok(GeneratedField::AsOfBlockHeight),
+                            "windowLen" | "window_len" => Ok(GeneratedField::WindowLen),
+                            "bitvec" => Ok(GeneratedField::Bitvec),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = Uptime;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.stake.v1.Uptime")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<Uptime, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut as_of_block_height__ = None;
+                let mut window_len__ = None;
+                let mut bitvec__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::AsOfBlockHeight => {
+                            if as_of_block_height__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("asOfBlockHeight"));
+                            }
+                            as_of_block_height__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::WindowLen => {
+                            if window_len__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("windowLen"));
+                            }
+                            window_len__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::Bitvec => {
+                            if bitvec__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("bitvec"));
+                            }
+                            bitvec__ = 
+                                Some(map_.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(Uptime {
+                    as_of_block_height: as_of_block_height__.unwrap_or_default(),
+                    window_len: window_len__.unwrap_or_default(),
+                    bitvec: bitvec__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.stake.v1.Uptime", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for Validator {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.identity_key.is_some() {
+            len += 1;
+        }
+        if !self.consensus_key.is_empty() {
+            len += 1;
+        }
+        if !self.name.is_empty() {
+            len += 1;
+        }
+        if !self.website.is_empty() {
+            len += 1;
+        }
+        if !self.description.is_empty() {
+            len += 1;
+        }
+        if self.enabled {
+            len += 1;
+        }
+        if !self.funding_streams.is_empty() {
+            len += 1;
+        }
+        if self.sequence_number != 0 {
+            len += 1;
+        }
+        if self.governance_key.is_some() {
+            len += 1;
+        }
+        if self.funding_streams_effective_epoch != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.stake.v1.Validator", len)?;
+        if let Some(v) = self.identity_key.as_ref() {
+            struct_ser.serialize_field("identityKey", v)?;
+        }
+        if !self.consensus_key.is_empty() {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("consensusKey", pbjson::private::base64::encode(&self.consensus_key).as_str())?;
+        }
+        if !self.name.is_empty() {
+            struct_ser.serialize_field("name", &self.name)?;
+        }
+        if !self.website.is_empty() {
+            struct_ser.serialize_field("website", &self.website)?;
+        }
+        if !self.description.is_empty() {
+            struct_ser.serialize_field("description", &self.description)?;
+        }
+        if self.enabled {
+            struct_ser.serialize_field("enabled", &self.enabled)?;
+        }
+        if !self.funding_streams.is_empty() {
+            struct_ser.serialize_field("fundingStreams", &self.funding_streams)?;
+        }
+        if self.sequence_number != 0 {
+            struct_ser.serialize_field("sequenceNumber", &self.sequence_number)?;
+        }
+        if let Some(v) = self.governance_key.as_ref() {
+            struct_ser.serialize_field("governanceKey", v)?;
+        }
+        if self.funding_streams_effective_epoch != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("fundingStreamsEffectiveEpoch", ToString::to_string(&self.funding_streams_effective_epoch).as_str())?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for Validator {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "identity_key",
+            "identityKey",
+            "consensus_key",
+            "consensusKey",
+            "name",
+            "website",
+            "description",
+            "enabled",
+            "funding_streams",
+            "fundingStreams",
+            "sequence_number",
+            "sequenceNumber",
+            "governance_key",
+            "governanceKey",
+            "funding_streams_effective_epoch",
+            "fundingStreamsEffectiveEpoch",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            IdentityKey,
+            ConsensusKey,
+            Name,
+            Website,
+            Description,
+            Enabled,
+            FundingStreams,
+            SequenceNumber,
+            GovernanceKey,
+            FundingStreamsEffectiveEpoch,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "identityKey" | "identity_key" => Ok(GeneratedField::IdentityKey),
+                            "consensusKey" | "consensus_key" => Ok(GeneratedField::ConsensusKey),
+                            "name" => Ok(GeneratedField::Name),
+                            "website" => Ok(GeneratedField::Website),
+                            "description" => Ok(GeneratedField::Description),
+                            "enabled" => Ok(GeneratedField::Enabled),
+                            "fundingStreams" | "funding_streams" => Ok(GeneratedField::FundingStreams),
+                            "sequenceNumber" | "sequence_number" => Ok(GeneratedField::SequenceNumber),
+                            "governanceKey" | "governance_key" => Ok(GeneratedField::GovernanceKey),
+                            "fundingStreamsEffectiveEpoch" | "funding_streams_effective_epoch" => Ok(GeneratedField::FundingStreamsEffectiveEpoch),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = Validator;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.stake.v1.Validator")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<Validator, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut identity_key__ = None;
+                let mut consensus_key__ = None;
+                let mut name__ = None;
+                let mut website__ = None;
+                let mut description__ = None;
+                let mut enabled__ = None;
+                let mut funding_streams__ = None;
+                let mut sequence_number__ = None;
+                let mut governance_key__ = None;
+                let mut funding_streams_effective_epoch__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::IdentityKey => {
+                            if identity_key__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("identityKey"));
+                            }
+                            identity_key__ = map_.next_value()?;
+                        }
+                        GeneratedField::ConsensusKey => {
+                            if consensus_key__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("consensusKey"));
+                            }
+                            consensus_key__ = 
+                                Some(map_.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::Name => {
+                            if name__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("name"));
+                            }
+                            name__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Website => {
+                            if website__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("website"));
+                            }
+                            website__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Description => {
+                            if description__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("description"));
+                            }
+                            description__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Enabled => {
+                            if enabled__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("enabled"));
+                            }
+                            enabled__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::FundingStreams => {
+                            if funding_streams__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("fundingStreams"));
+                            }
+                            funding_streams__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::SequenceNumber => {
+                            if sequence_number__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("sequenceNumber"));
+                            }
+                            sequence_number__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::GovernanceKey => {
+                            if governance_key__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("governanceKey"));
+                            }
+                            governance_key__ = map_.next_value()?;
+                        }
+                        GeneratedField::FundingStreamsEffectiveEpoch => {
+                            if funding_streams_effective_epoch__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("fundingStreamsEffectiveEpoch"));
+                            }
+                            funding_streams_effective_epoch__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(Validator {
+                    identity_key: identity_key__,
+                    consensus_key: consensus_key__.unwrap_or_default(),
+                    name: name__.unwrap_or_default(),
+                    website: website__.unwrap_or_default(),
+                    description: description__.unwrap_or_default(),
+                    enabled: enabled__.unwrap_or_default(),
+                    funding_streams: funding_streams__.unwrap_or_default(),
+                    sequence_number: sequence_number__.unwrap_or_default(),
+                    governance_key: governance_key__,
+                    funding_streams_effective_epoch: funding_streams_effective_epoch__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.stake.v1.Validator", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for ValidatorDefinition {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.validator.is_some() {
+            len += 1;
+        }
+        if !self.auth_sig.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.stake.v1.ValidatorDefinition", len)?;
+        if let Some(v) = self.validator.as_ref() {
+            struct_ser.serialize_field("validator", v)?;
+        }
+        if !self.auth_sig.is_empty() {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("authSig", pbjson::private::base64::encode(&self.auth_sig).as_str())?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for ValidatorDefinition {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "validator",
+            "auth_sig",
+            "authSig",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Validator,
+            AuthSig,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "validator" => Ok(GeneratedField::Validator),
+                            "authSig" | "auth_sig" => Ok(GeneratedField::AuthSig),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = ValidatorDefinition;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.stake.v1.ValidatorDefinition")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ValidatorDefinition, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut validator__ = None;
+                let mut auth_sig__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Validator => {
+                            if validator__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("validator"));
+                            }
+                            validator__ = map_.next_value()?;
+                        }
+                        GeneratedField::AuthSig => {
+                            if auth_sig__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("authSig"));
+                            }
+                            auth_sig__ = 
+                                Some(map_.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(ValidatorDefinition {
+                    validator: validator__,
+                    auth_sig: auth_sig__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.stake.v1.ValidatorDefinition", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for ValidatorDiagnosis {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.identity_key.is_some() {
+            len += 1;
+        }
+        if self.state.is_some() {
+            len += 1;
+        }
+        if self.reason != 0 {
+            len += 1;
+        }
+        if self.missed_blocks != 0 {
+            len += 1;
+        }
+        if self.missed_blocks_maximum != 0 {
+            len += 1;
+        }
+        if self.signed_blocks_window_len != 0 {
+            len += 1;
+        }
+        if !self.penalties.is_empty() {
+            len += 1;
+        }
+        if self.pool_value.is_some() {
+            len += 1;
+        }
+        if self.min_validator_stake.is_some() {
+            len += 1;
+        }
+        if self.self_bond_value.is_some() {
+            len += 1;
+        }
+        if self.min_validator_self_delegation.is_some() {
+            len += 1;
+        }
+        if self.voting_power.is_some() {
+            len += 1;
+        }
+        if self.power_rank != 0 {
+            len += 1;
+        }
+        if self.active_validator_limit != 0 {
+            len += 1;
+        }
+        if self.active_set_threshold.is_some() {
+            len += 1;
+        }
+        if !self.recovery_hints.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.stake.v1.ValidatorDiagnosis", len)?;
+        if let Some(v) = self.identity_key.as_ref() {
+            struct_ser.serialize_field("identityKey", v)?;
+        }
+        if let Some(v) = self.state.as_ref() {
+            struct_ser.serialize_field("state", v)?;
+        }
+        if self.reason != 0 {
+            let v = validator_diagnosis::InactivityReason::try_from(self.reason)
+                .map_err(|_| serde::ser::Error::custom(format!("Invalid variant {}", self.reason)))?;
+            struct_ser.serialize_field("reason", &v)?;
+        }
+        if self.missed_blocks != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("missedBlocks", ToString::to_string(&self.missed_blocks).as_str())?;
+        }
+        if self.missed_blocks_maximum != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("missedBlocksMaximum", ToString::to_string(&self.missed_blocks_maximum).as_str())?;
+        }
+        if self.signed_blocks_window_len != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("signedBlocksWindowLen", ToString::to_string(&self.signed_blocks_window_len).as_str())?;
+        }
+        if !self.penalties.is_empty() {
+            struct_ser.serialize_field("penalties", &self.penalties)?;
+        }
+        if let Some(v) = self.pool_value.as_ref() {
+            struct_ser.serialize_field("poolValue", v)?;
+        }
+        if let Some(v) = self.min_validator_stake.as_ref() {
+            struct_ser.serialize_field("minValidatorStake", v)?;
+        }
+        if let Some(v) = self.self_bond_value.as_ref() {
+            struct_ser.serialize_field("selfBondValue", v)?;
+        }
+        if let Some(v) = self.min_validator_self_delegation.as_ref() {
+            struct_ser.serialize_field("minValidatorSelfDelegation", v)?;
+        }
+        if let Some(v) = self.voting_power.as_ref() {
+            struct_ser.serialize_field("votingPower", v)?;
+        }
+        if self.power_rank != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("powerRank", ToString::to_string(&self.power_rank).as_str())?;
+        }
+        if self.active_validator_limit != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("activeValidatorLimit", ToString::to_string(&self.active_validator_limit).as_str())?;
+        }
+        if let Some(v) = self.active_set_threshold.as_ref() {
+            struct_ser.serialize_field("activeSetThreshold", v)?;
+        }
+        if !self.recovery_hints.is_empty() {
+            struct_ser.serialize_field("recoveryHints", &self.recovery_hints)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for ValidatorDiagnosis {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "identity_key",
+            "identityKey",
+            "state",
+            "reason",
+            "missed_blocks",
+            "missedBlocks",
+            "missed_blocks_maximum",
+            "missedBlocksMaximum",
+            "signed_blocks_window_len",
+            "signedBlocksWindowLen",
+            "penalties",
+            "pool_value",
+            "poolValue",
+            "min_validator_stake",
+            "minValidatorStake",
+            "self_bond_value",
+            "selfBondValue",
+            "min_validator_self_delegation",
+            "minValidatorSelfDelegation",
+            "voting_power",
+            "votingPower",
+            "power_rank",
+            "powerRank",
+            "active_validator_limit",
+            "activeValidatorLimit",
+            "active_set_threshold",
+            "activeSetThreshold",
+            "recovery_hints",
+            "recoveryHints",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            IdentityKey,
+            State,
+            Reason,
+            MissedBlocks,
+            MissedBlocksMaximum,
+            SignedBlocksWindowLen,
+            Penalties,
+            PoolValue,
+            MinValidatorStake,
+            SelfBondValue,
+            MinValidatorSelfDelegation,
+            VotingPower,
+            PowerRank,
+            ActiveValidatorLimit,
+            ActiveSetThreshold,
+            RecoveryHints,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "identityKey" | "identity_key" => Ok(GeneratedField::IdentityKey),
+                            "state" => Ok(GeneratedField::State),
+                            "reason" => Ok(GeneratedField::Reason),
+                            "missedBlocks" | "missed_blocks" => Ok(GeneratedField::MissedBlocks),
+                            "missedBlocksMaximum" | "missed_blocks_maximum" => Ok(GeneratedField::MissedBlocksMaximum),
+                            "signedBlocksWindowLen" | "signed_blocks_window_len" => Ok(GeneratedField::SignedBlocksWindowLen),
+                            "penalties" => Ok(GeneratedField::Penalties),
+                            "poolValue" | "pool_value" => Ok(GeneratedField::PoolValue),
+                            "minValidatorStake" | "min_validator_stake" => Ok(GeneratedField::MinValidatorStake),
+                            "selfBondValue" | "self_bond_value" => Ok(GeneratedField::SelfBondValue),
+                            "minValidatorSelfDelegation" | "min_validator_self_delegation" => Ok(GeneratedField::MinValidatorSelfDelegation),
+                            "votingPower" | "voting_power" => Ok(GeneratedField::VotingPower),
+                            "powerRank" | "power_rank" => Ok(GeneratedField::PowerRank),
+                            "activeValidatorLimit" | "active_validator_limit" => Ok(GeneratedField::ActiveValidatorLimit),
+                            "activeSetThreshold" | "active_set_threshold" => Ok(GeneratedField::ActiveSetThreshold),
+                            "recoveryHints" | "recovery_hints" => Ok(GeneratedField::RecoveryHints),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = ValidatorDiagnosis;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.stake.v1.ValidatorDiagnosis")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ValidatorDiagnosis, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut identity_key__ = None;
+                let mut state__ = None;
+                let mut reason__ = None;
+                let mut missed_blocks__ = None;
+                let mut missed_blocks_maximum__ = None;
+                let mut signed_blocks_window_len__ = None;
+                let mut penalties__ = None;
+                let mut pool_value__ = None;
+                let mut min_validator_stake__ = None;
+                let mut self_bond_value__ = None;
+                let mut min_validator_self_delegation__ = None;
+                let mut voting_power__ = None;
+                let mut power_rank__ = None;
+                let mut active_validator_limit__ = None;
+                let mut active_set_threshold__ = None;
+                let mut recovery_hints__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::IdentityKey => {
+                            if identity_key__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("identityKey"));
+                            }
+                            identity_key__ = map_.next_value()?;
+                        }
+                        GeneratedField::State => {
+                            if state__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("state"));
+                            }
+                            state__ = map_.next_value()?;
+                        }
+                        GeneratedField::Reason => {
+                            if reason__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("reason"));
+                            }
+                            reason__ = Some(map_.next_value::<validator_diagnosis::InactivityReason>()? as i32);
+                        }
+                        GeneratedField::MissedBlocks => {
+                            if missed_blocks__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("missedBlocks"));
+                            }
+                            missed_blocks__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::MissedBlocksMaximum => {
+                            if missed_blocks_maximum__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("missedBlocksMaximum"));
+                            }
+                            missed_blocks_maximum__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::SignedBlocksWindowLen => {
+                            if signed_blocks_window_len__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("signedBlocksWindowLen"));
+                            }
+                            signed_blocks_window_len__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::Penalties => {
+                            if penalties__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("penalties"));
+                            }
+                            penalties__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::PoolValue => {
+                            if pool_value__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("poolValue"));
+                            }
+                            pool_value__ = map_.next_value()?;
+                        }
+                        GeneratedField::MinValidatorStake => {
+                            if min_validator_stake__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("minValidatorStake"));
+                            }
+                            min_validator_stake__ = map_.next_value()?;
+                        }
+                        GeneratedField::SelfBondValue => {
+                            if self_bond_value__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("selfBondValue"));
+                            }
+                            self_bond_value__ = map_.next_value()?;
+                        }
+                        GeneratedField::MinValidatorSelfDelegation => {
+                            if min_validator_self_delegation__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("minValidatorSelfDelegation"));
+                            }
+                            min_validator_self_delegation__ = map_.next_value()?;
+                        }
+                        GeneratedField::VotingPower => {
+                            if voting_power__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("votingPower"));
+                            }
+                            voting_power__ = map_.next_value()?;
+                        }
+                        GeneratedField::PowerRank => {
+                            if power_rank__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("powerRank"));
+                            }
+                            power_rank__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::ActiveValidatorLimit => {
+                            if active_validator_limit__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("activeValidatorLimit"));
+                            }
+                            active_validator_limit__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::ActiveSetThreshold => {
+                            if active_set_threshold__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("activeSetThreshold"));
+                            }
+                            active_set_threshold__ = map_.next_value()?;
+                        }
+                        GeneratedField::RecoveryHints => {
+                            if recovery_hints__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("recoveryHints"));
+                            }
+                            recovery_hints__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(ValidatorDiagnosis {
+                    identity_key: identity_key__,
+                    state: state__,
+                    reason: reason__.unwrap_or_default(),
+                    missed_blocks: missed_blocks__.unwrap_or_default(),
+                    missed_blocks_maximum: missed_blocks_maximum__.unwrap_or_default(),
+                    signed_blocks_window_len: signed_blocks_window_len__.unwrap_or_default(),
+                    penalties: penalties__.unwrap_or_default(),
+                    pool_value: pool_value__,
+                    min_validator_stake: min_validator_stake__,
+                    self_bond_value: self_bond_value__,
+                    min_validator_self_delegation: min_validator_self_delegation__,
+                    voting_power: voting_power__,
+                    power_rank: power_rank__.unwrap_or_default(),
+                    active_validator_limit: active_validator_limit__.unwrap_or_default(),
+                    active_set_threshold: active_set_threshold__,
+                    recovery_hints: recovery_hints__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.stake.v1.ValidatorDiagnosis", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for validator_diagnosis::EpochPenalty {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.epoch_index != 0 {
+            len += 1;
+        }
+        if self.penalty.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.stake.v1.ValidatorDiagnosis.EpochPenalty", len)?;
+        if self.epoch_index != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("epochIndex", ToString::to_string(&self.epoch_index).as_str())?;
+        }
+        if let Some(v) = self.penalty.as_ref() {
+            struct_ser.serialize_field("penalty", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for validator_diagnosis::EpochPenalty {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "epoch_index",
+            "epochIndex",
+            "penalty",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            EpochIndex,
+            Penalty,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "epochIndex" | "epoch_index" => Ok(GeneratedField::EpochIndex),
+                            "penalty" => Ok(GeneratedField::Penalty),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = validator_diagnosis::EpochPenalty;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.stake.v1.ValidatorDiagnosis.EpochPenalty")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<validator_diagnosis::EpochPenalty, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut epoch_index__ = None;
+                let mut penalty__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::EpochIndex => {
+                            if epoch_index__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("epochIndex"));
+                            }
+                            epoch_index__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::Penalty => {
+                            if penalty__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("penalty"));
+                            }
+                            penalty__ = map_.next_value()?;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(validator_diagnosis::EpochPenalty {
+                    epoch_index: epoch_index__.unwrap_or_default(),
+                    penalty: penalty__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.stake.v1.ValidatorDiagnosis.EpochPenalty", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for validator_diagnosis::InactivityReason {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        let variant = match self {
+            Self::Unspecified => "INACTIVITY_REASON_UNSPECIFIED",
+            Self::None => "INACTIVITY_REASON_NONE",
+            Self::InsufficientStake => "INACTIVITY_REASON_INSUFFICIENT_STAKE",
+            Self::InsufficientSelfBond => "INACTIVITY_REASON_INSUFFICIENT_SELF_BOND",
+            Self::OutsideActiveSet => "INACTIVITY_REASON_OUTSIDE_ACTIVE_SET",
+            Self::PendingEpoch => "INACTIVITY_REASON_PENDING_EPOCH",
+            Self::Disabled => "INACTIVITY_REASON_DISABLED",
+            Self::JailedForDowntime => "INACTIVITY_REASON_JAILED_FOR_DOWNTIME",
+            Self::TombstonedForMisbehavior => "INACTIVITY_REASON_TOMBSTONED_FOR_MISBEHAVIOR",
+            Self::Pruned => "INACTIVITY_REASON_PRUNED",
+        };
+        serializer.serialize_str(variant)
+    }
+}
+impl<'de> serde::Deserialize<'de> for validator_diagnosis::InactivityReason {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "INACTIVITY_REASON_UNSPECIFIED",
+            "INACTIVITY_REASON_NONE",
+            "INACTIVITY_REASON_INSUFFICIENT_STAKE",
+            "INACTIVITY_REASON_INSUFFICIENT_SELF_BOND",
+            "INACTIVITY_REASON_OUTSIDE_ACTIVE_SET",
+            "INACTIVITY_REASON_PENDING_EPOCH",
+            "INACTIVITY_REASON_DISABLED",
+            "INACTIVITY_REASON_JAILED_FOR_DOWNTIME",
+            "INACTIVITY_REASON_TOMBSTONED_FOR_MISBEHAVIOR",
+            "INACTIVITY_REASON_PRUNED",
+        ];
+
+        struct GeneratedVisitor;
+
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = validator_diagnosis::InactivityReason;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                write!(formatter, "expected one of: {:?}", &FIELDS)
+            }
+
+            fn visit_i64<E>(self, v: i64) -> std::result::Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                i32::try_from(v)
+                    .ok()
+                    .and_then(|x| x.try_into().ok())
+                    .ok_or_else(|| {
+                        serde::de::Error::invalid_value(serde::de::Unexpected::Signed(v), &self)
+                    })
+            }
+
+            fn visit_u64<E>(self, v: u64) -> std::result::Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                i32::try_from(v)
+                    .ok()
+                    .and_then(|x| x.try_into().ok())
+                    .ok_or_else(|| {
+                        serde::de::Error::invalid_value(serde::de::Unexpected::Unsigned(v), &self)
+                    })
+            }
+
+            fn visit_str<E>(self, value: &str) -> std::result::Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                match value {
+                    "INACTIVITY_REASON_UNSPECIFIED" => Ok(validator_diagnosis::InactivityReason::Unspecified),
+                    "INACTIVITY_REASON_NONE" => Ok(validator_diagnosis::InactivityReason::None),
+                    "INACTIVITY_REASON_INSUFFICIENT_STAKE" => Ok(validator_diagnosis::InactivityReason::InsufficientStake),
+                    "INACTIVITY_REASON_INSUFFICIENT_SELF_BOND" => Ok(validator_diagnosis::InactivityReason::InsufficientSelfBond),
+                    "INACTIVITY_REASON_OUTSIDE_ACTIVE_SET" => Ok(validator_diagnosis::InactivityReason::OutsideActiveSet),
+                    "INACTIVITY_REASON_PENDING_EPOCH" => Ok(validator_diagnosis::InactivityReason::PendingEpoch),
+                    "INACTIVITY_REASON_DISABLED" => Ok(validator_diagnosis::InactivityReason::Disabled),
+                    "INACTIVITY_REASON_JAILED_FOR_DOWNTIME" => Ok(validator_diagnosis::InactivityReason::JailedForDowntime),
+                    "INACTIVITY_REASON_TOMBSTONED_FOR_MISBEHAVIOR" => Ok(validator_diagnosis::InactivityReason::TombstonedForMisbehavior),
+                    "INACTIVITY_REASON_PRUNED" => Ok(validator_diagnosis::InactivityReason::Pruned),
+                    _ => Err(serde::de::Error::unknown_variant(value, FIELDS)),
+                }
+            }
+        }
+        deserializer.deserialize_any(GeneratedVisitor)
+    }
+}
+impl serde::Serialize for ValidatorDiagnosisRequest {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.identity_key.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.stake.v1.ValidatorDiagnosisRequest", len)?;
+        if let Some(v) = self.identity_key.as_ref() {
+            struct_ser.serialize_field("identityKey", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for ValidatorDiagnosisRequest {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "identity_key",
+            "identityKey",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            IdentityKey,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "identityKey" | "identity_key" => Ok(GeneratedField::IdentityKey),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = ValidatorDiagnosisRequest;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.stake.v1.ValidatorDiagnosisRequest")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ValidatorDiagnosisRequest, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut identity_key__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::IdentityKey => {
+                            if identity_key__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("identityKey"));
+                            }
+                            identity_key__ = map_.next_value()?;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(ValidatorDiagnosisRequest {
+                    identity_key: identity_key__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.stake.v1.ValidatorDiagnosisRequest", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for ValidatorDiagnosisResponse {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.diagnosis.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.stake.v1.ValidatorDiagnosisResponse", len)?;
+        if let Some(v) = self.diagnosis.as_ref() {
+            struct_ser.serialize_field("diagnosis", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for ValidatorDiagnosisResponse {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "diagnosis",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Diagnosis,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "diagnosis" => Ok(GeneratedField::Diagnosis),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = ValidatorDiagnosisResponse;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.stake.v1.ValidatorDiagnosisResponse")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ValidatorDiagnosisResponse, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut diagnosis__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Diagnosis => {
+                            if diagnosis__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("diagnosis"));
+                            }
+                            diagnosis__ = map_.next_value()?;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(ValidatorDiagnosisResponse {
+                    diagnosis: diagnosis__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.stake.v1.ValidatorDiagnosisResponse", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for ValidatorInfo {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.validator.is_some() {
+            len += 1;
+        }
+        if self.status.is_some() {
+            len += 1;
+        }
+        if self.rate_data.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.stake.v1.ValidatorInfo", len)?;
+        if let Some(v) = self.validator.as_ref() {
+            struct_ser.serialize_field("validator", v)?;
+        }
+        if let Some(v) = self.status.as_ref() {
+            struct_ser.serialize_field("status", v)?;
+        }
+        if let Some(v) = self.rate_data.as_ref() {
+            struct_ser.serialize_field("rateData", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for ValidatorInfo {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "validator",
+            "status",
+            "rate_data",
+            "rateData",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Validator,
+            Status,
+            RateData,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "validator" => Ok(GeneratedField::Validator),
+                            "status" => Ok(GeneratedField::Status),
+                            "rateData" | "rate_data" => Ok(GeneratedField::RateData),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = ValidatorInfo;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.stake.v1.ValidatorInfo")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ValidatorInfo, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut validator__ = None;
+                let mut status__ = None;
+                let mut rate_data__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Validator => {
+                            if validator__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("validator"));
+                            }
+                            validator__ = map_.next_value()?;
+                        }
+                        GeneratedField::Status => {
+                            if status__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("status"));
+                            }
+                            status__ = map_.next_value()?;
+                        }
+                        GeneratedField::RateData => {
+                            if rate_data__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("rateData"));
+                            }
+                            rate_data__ = map_.next_value()?;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(ValidatorInfo {
+                    validator: validator__,
+                    status: status__,
+                    rate_data: rate_data__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.stake.v1.ValidatorInfo", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for ValidatorInfoRequest {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.show_inactive {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.stake.v1.ValidatorInfoRequest", len)?;
+        if self.show_inactive {
+            struct_ser.serialize_field("showInactive", &self.show_inactive)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for ValidatorInfoRequest {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "show_inactive",
+            "showInactive",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            ShowInactive,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "showInactive" | "show_inactive" => Ok(GeneratedField::ShowInactive),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = ValidatorInfoRequest;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.stake.v1.ValidatorInfoRequest")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ValidatorInfoRequest, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut show_inactive__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::ShowInactive => {
+                            if show_inactive__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("showInactive"));
+                            }
+                            show_inactive__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(ValidatorInfoRequest {
+                    show_inactive: show_inactive__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.stake.v1.ValidatorInfoRequest", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for ValidatorInfoResponse {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.validator_info.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.stake.v1.ValidatorInfoResponse", len)?;
+        if let Some(v) = self.validator_info.as_ref() {
+            struct_ser.serialize_field("validatorInfo", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for ValidatorInfoResponse {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "validator_info",
+            "validatorInfo",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            ValidatorInfo,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "validatorInfo" | "validator_info" => Ok(GeneratedField::ValidatorInfo),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = ValidatorInfoResponse;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.stake.v1.ValidatorInfoResponse")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ValidatorInfoResponse, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut validator_info__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::ValidatorInfo => {
+                            if validator_info__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("validatorInfo"));
+                            }
+                            validator_info__ = map_.next_value()?;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(ValidatorInfoResponse {
+                    validator_info: validator_info__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.stake.v1.ValidatorInfoResponse", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for ValidatorList {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.validator_keys.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.stake.v1.ValidatorList", len)?;
+        if !self.validator_keys.is_empty() {
+            struct_ser.serialize_field("validatorKeys", &self.validator_keys)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for ValidatorList {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "validator_keys",
+            "validatorKeys",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            ValidatorKeys,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "validatorKeys" | "validator_keys" => Ok(GeneratedField::ValidatorKeys),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = ValidatorList;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.stake.v1.ValidatorList")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ValidatorList, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut validator_keys__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::ValidatorKeys => {
+                            if validator_keys__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("validatorKeys"));
+                            }
+                            validator_keys__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(ValidatorList {
+                    validator_keys: validator_keys__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.stake.v1.ValidatorList", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for ValidatorParticipationRequest {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.identity_key.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.stake.v1.ValidatorParticipationRequest", len)?;
+        if let Some(v) = self.identity_key.as_ref() {
+            struct_ser.serialize_field("identityKey", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for ValidatorParticipationRequest {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "identity_key",
+            "identityKey",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            IdentityKey,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "identityKey" | "identity_key" => Ok(GeneratedField::IdentityKey),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = ValidatorParticipationRequest;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.stake.v1.ValidatorParticipationRequest")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ValidatorParticipationRequest, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut identity_key__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::IdentityKey => {
+                            if identity_key__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("identityKey"));
+                            }
+                            identity_key__ = map_.next_value()?;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(ValidatorParticipationRequest {
+                    identity_key: identity_key__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.stake.v1.ValidatorParticipationRequest", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for ValidatorParticipationResponse {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.participation.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.stake.v1.ValidatorParticipationResponse", len)?;
+        if let Some(v) = self.participation.as_ref() {
+            struct_ser.serialize_field("participation", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for ValidatorParticipationResponse {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "participation",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Participation,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "participation" => Ok(GeneratedField::Participation),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = ValidatorParticipationResponse;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.stake.v1.ValidatorParticipationResponse")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ValidatorParticipationResponse, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut participation__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Participation => {
+                            if participation__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("participation"));
+                            }
+                            participation__ = map_.next_value()?;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(ValidatorParticipationResponse {
+                    participation: participation__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.stake.v1.ValidatorParticipationResponse", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for ValidatorPenaltyRequest {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.identity_key.is_some() {
+            len += 1;
+        }
+        if self.start_epoch_index != 0 {
+            len += 1;
+        }
+        if self.end_epoch_index != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.stake.v1.ValidatorPenaltyRequest", len)?;
+        if let Some(v) = self.identity_key.as_ref() {
+            struct_ser.serialize_field("identityKey", v)?;
+        }
+        if self.start_epoch_index != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("startEpochIndex", ToString::to_string(&self.start_epoch_index).as_str())?;
+        }
+        if self.end_epoch_index != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("endEpochIndex", ToString::to_string(&self.end_epoch_index).as_str())?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for ValidatorPenaltyRequest {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "identity_key",
+            "identityKey",
+            "start_epoch_index",
+            "startEpochIndex",
+            "end_epoch_index",
+            "endEpochIndex",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            IdentityKey,
+            StartEpochIndex,
+            EndEpochIndex,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "identityKey" | "identity_key" => Ok(GeneratedField::IdentityKey),
+                            "startEpochIndex" | "start_epoch_index" => Ok(GeneratedField::StartEpochIndex),
+                            "endEpochIndex" | "end_epoch_index" => Ok(GeneratedField::EndEpochIndex),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = ValidatorPenaltyRequest;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.stake.v1.ValidatorPenaltyRequest")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ValidatorPenaltyRequest, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut identity_key__ = None;
+                let mut start_epoch_index__ = None;
+                let mut end_epoch_index__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::IdentityKey => {
+                            if identity_key__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("identityKey"));
+                            }
+                            identity_key__ = map_.next_value()?;
+                        }
+                        GeneratedField::StartEpochIndex => {
+                            if start_epoch_index__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("startEpochIndex"));
+                            }
+                            start_epoch_index__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::EndEpochIndex => {
+                            if end_epoch_index__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("endEpochIndex"));
+                            }
+                            end_epoch_index__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(ValidatorPenaltyRequest {
+                    identity_key: identity_key__,
+                    start_epoch_index: start_epoch_index__.unwrap_or_default(),
+                    end_epoch_index: end_epoch_index__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.stake.v1.ValidatorPenaltyRequest", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for ValidatorPenaltyResponse {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.penalty.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.stake.v1.ValidatorPenaltyResponse", len)?;
+        if let Some(v) = self.penalty.as_ref() {
+            struct_ser.serialize_field("penalty", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for ValidatorPenaltyResponse {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "penalty",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Penalty,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "penalty" => Ok(GeneratedField::Penalty),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = ValidatorPenaltyResponse;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.stake.v1.ValidatorPenaltyResponse")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ValidatorPenaltyResponse, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut penalty__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Penalty => {
+                            if penalty__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("penalty"));
+                            }
+                            penalty__ = map_.next_value()?;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(ValidatorPenaltyResponse {
+                    penalty: penalty__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.stake.v1.ValidatorPenaltyResponse", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for ValidatorPower {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.identity_key.is_some() {
             len += 1;
         }
-        if self.slashing_penalty_downtime != 0 {
+        if self.voting_power.is_some() {
             len += 1;
         }
-        if self.signed_blocks_window_len != 0 {
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.stake.v1.ValidatorPower", len)?;
+        if let Some(v) = self.identity_key.as_ref() {
+            struct_ser.serialize_field("identityKey", v)?;
+        }
+        if let Some(v) = self.voting_power.as_ref() {
+            struct_ser.serialize_field("votingPower", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for ValidatorPower {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "identity_key",
+            "identityKey",
+            "voting_power",
+            "votingPower",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            IdentityKey,
+            VotingPower,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "identityKey" | "identity_key" => Ok(GeneratedField::IdentityKey),
+                            "votingPower" | "voting_power" => Ok(GeneratedField::VotingPower),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = ValidatorPower;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.stake.v1.ValidatorPower")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ValidatorPower, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut identity_key__ = None;
+                let mut voting_power__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::IdentityKey => {
+                            if identity_key__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("identityKey"));
+                            }
+                            identity_key__ = map_.next_value()?;
+                        }
+                        GeneratedField::VotingPower => {
+                            if voting_power__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("votingPower"));
+                            }
+                            voting_power__ = map_.next_value()?;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(ValidatorPower {
+                    identity_key: identity_key__,
+                    voting_power: voting_power__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.stake.v1.ValidatorPower", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for ValidatorPowerProof {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.epoch_index != 0 {
             len += 1;
         }
-        if self.missed_blocks_maximum != 0 {
+        if self.power.is_some() {
             len += 1;
         }
-        if self.min_validator_stake.is_some() {
+        if self.leaf_index != 0 {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.stake.v1.StakeParameters", len)?;
-        if self.unbonding_epochs != 0 {
+        if self.leaf_count != 0 {
+            len += 1;
+        }
+        if !self.siblings.is_empty() {
+            len += 1;
+        }
+        if self.commitment_proof.is_some() {
+            len += 1;
+        }
+        if self.height != 0 {
+            len += 1;
+        }
+        if !self.app_hash.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.stake.v1.ValidatorPowerProof", len)?;
+        if self.epoch_index != 0 {
             #[allow(clippy::needless_borrow)]
-            struct_ser.serialize_field("unbondingEpochs", ToString::to_string(&self.unbonding_epochs).as_str())?;
+            struct_ser.serialize_field("epochIndex", ToString::to_string(&self.epoch_index).as_str())?;
         }
-        if self.active_validator_limit != 0 {
+        if let Some(v) = self.power.as_ref() {
+            struct_ser.serialize_field("power", v)?;
+        }
+        if self.leaf_index != 0 {
             #[allow(clippy::needless_borrow)]
-            struct_ser.serialize_field("activeValidatorLimit", ToString::to_string(&self.active_validator_limit).as_str())?;
+            struct_ser.serialize_field("leafIndex", ToString::to_string(&self.leaf_index).as_str())?;
         }
-        if self.base_reward_rate != 0 {
+        if self.leaf_count != 0 {
             #[allow(clippy::needless_borrow)]
-            struct_ser.serialize_field("baseRewardRate", ToString::to_string(&self.base_reward_rate).as_str())?;
+            struct_ser.serialize_field("leafCount", ToString::to_string(&self.leaf_count).as_str())?;
         }
-        if self.slashing_penalty_misbehavior != 0 {
+        if !self.siblings.is_empty() {
+            struct_ser.serialize_field("siblings", &self.siblings.iter().map(pbjson::private::base64::encode).collect::<Vec<_>>())?;
+        }
+        if let Some(v) = self.commitment_proof.as_ref() {
+            struct_ser.serialize_field("commitmentProof", v)?;
+        }
+        if self.height != 0 {
             #[allow(clippy::needless_borrow)]
-            struct_ser.serialize_field("slashingPenaltyMisbehavior", ToString::to_string(&self.slashing_penalty_misbehavior).as_str())?;
+            struct_ser.serialize_field("height", ToString::to_string(&self.height).as_str())?;
         }
-        if self.slashing_penalty_downtime != 0 {
+        if !self.app_hash.is_empty() {
             #[allow(clippy::needless_borrow)]
-            struct_ser.serialize_field("slashingPenaltyDowntime", ToString::to_string(&self.slashing_penalty_downtime).as_str())?;
+            struct_ser.serialize_field("appHash", pbjson::private::base64::encode(&self.app_hash).as_str())?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for ValidatorPowerProof {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "epoch_index",
+            "epochIndex",
+            "power",
+            "leaf_index",
+            "leafIndex",
+            "leaf_count",
+            "leafCount",
+            "siblings",
+            "commitment_proof",
+            "commitmentProof",
+            "height",
+            "app_hash",
+            "appHash",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            EpochIndex,
+            Power,
+            LeafIndex,
+            LeafCount,
+            Siblings,
+            CommitmentProof,
+            Height,
+            AppHash,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "epochIndex" | "epoch_index" => Ok(GeneratedField::EpochIndex),
+                            "power" => Ok(GeneratedField::Power),
+                            "leafIndex" | "leaf_index" => Ok(GeneratedField::LeafIndex),
+                            "leafCount" | "leaf_count" => Ok(GeneratedField::LeafCount),
+                            "siblings" => Ok(GeneratedField::Siblings),
+                            "commitmentProof" | "commitment_proof" => Ok(GeneratedField::CommitmentProof),
+                            "height" => Ok(GeneratedField::Height),
+                            "appHash" | "app_hash" => Ok(GeneratedField::AppHash),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = ValidatorPowerProof;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.stake.v1.ValidatorPowerProof")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ValidatorPowerProof, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut epoch_index__ = None;
+                let mut power__ = None;
+                let mut leaf_index__ = None;
+                let mut leaf_count__ = None;
+                let mut siblings__ = None;
+                let mut commitment_proof__ = None;
+                let mut height__ = None;
+                let mut app_hash__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::EpochIndex => {
+                            if epoch_index__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("epochIndex"));
+                            }
+                            epoch_index__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::Power => {
+                            if power__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("power"));
+                            }
+                            power__ = map_.next_value()?;
+                        }
+                        GeneratedField::LeafIndex => {
+                            if leaf_index__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("leafIndex"));
+                            }
+                            leaf_index__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::LeafCount => {
+                            if leaf_count__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("leafCount"));
+                            }
+                            leaf_count__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::Siblings => {
+                            if siblings__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("siblings"));
+                            }
+                            siblings__ = 
+                                Some(map_.next_value::<Vec<::pbjson::private::BytesDeserialize<_>>>()?
+                                    .into_iter().map(|x| x.0).collect())
+                            ;
+                        }
+                        GeneratedField::CommitmentProof => {
+                            if commitment_proof__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("commitmentProof"));
+                            }
+                            commitment_proof__ = map_.next_value()?;
+                        }
+                        GeneratedField::Height => {
+                            if height__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("height"));
+                            }
+                            height__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::AppHash => {
+                            if app_hash__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("appHash"));
+                            }
+                            app_hash__ = 
+                                Some(map_.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(ValidatorPowerProof {
+                    epoch_index: epoch_index__.unwrap_or_default(),
+                    power: power__,
+                    leaf_index: leaf_index__.unwrap_or_default(),
+                    leaf_count: leaf_count__.unwrap_or_default(),
+                    siblings: siblings__.unwrap_or_default(),
+                    commitment_proof: commitment_proof__,
+                    height: height__.unwrap_or_default(),
+                    app_hash: app_hash__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.stake.v1.ValidatorPowerProof", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for ValidatorPowerProofRequest {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.identity_key.is_some() {
+            len += 1;
+        }
+        if self.epoch_index != 0 {
+            len += 1;
         }
-        if self.signed_blocks_window_len != 0 {
-            #[allow(clippy::needless_borrow)]
-            struct_ser.serialize_field("signedBlocksWindowLen", ToString::to_string(&self.signed_blocks_window_len).as_str())?;
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.stake.v1.ValidatorPowerProofRequest", len)?;
+        if let Some(v) = self.identity_key.as_ref() {
+            struct_ser.serialize_field("identityKey", v)?;
         }
-        if self.missed_blocks_maximum != 0 {
+        if self.epoch_index != 0 {
             #[allow(clippy::needless_borrow)]
-            struct_ser.serialize_field("missedBlocksMaximum", ToString::to_string(&self.missed_blocks_maximum).as_str())?;
-        }
-        if let Some(v) = self.min_validator_stake.as_ref() {
-            struct_ser.serialize_field("minValidatorStake", v)?;
+            struct_ser.serialize_field("epochIndex", ToString::to_string(&self.epoch_index).as_str())?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for StakeParameters {
+impl<'de> serde::Deserialize<'de> for ValidatorPowerProofRequest {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "unbonding_epochs",
-            "unbondingEpochs",
-            "active_validator_limit",
-            "activeValidatorLimit",
-            "base_reward_rate",
-            "baseRewardRate",
-            "slashing_penalty_misbehavior",
-            "slashingPenaltyMisbehavior",
-            "slashing_penalty_downtime",
-            "slashingPenaltyDowntime",
-            "signed_blocks_window_len",
-            "signedBlocksWindowLen",
-            "missed_blocks_maximum",
-            "missedBlocksMaximum",
-            "min_validator_stake",
-            "minValidatorStake",
+            "identity_key",
+            "identityKey",
+            "epoch_index",
+            "epochIndex",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            UnbondingEpochs,
-            ActiveValidatorLimit,
-            BaseRewardRate,
-            SlashingPenaltyMisbehavior,
-            SlashingPenaltyDowntime,
-            SignedBlocksWindowLen,
-            MissedBlocksMaximum,
-            MinValidatorStake,
+            IdentityKey,
+            EpochIndex,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -1696,14 +6380,8 @@ impl<'de> serde::Deserialize<'de> for StakeParameters {
                         E: serde::de::Error,
                     {
                         match value {
-                            "unbondingEpochs" | "unbonding_epochs" => Ok(GeneratedField::UnbondingEpochs),
-                            "activeValidatorLimit" | "active_validator_limit" => Ok(GeneratedField::ActiveValidatorLimit),
-                            "baseRewardRate" | "base_reward_rate" => Ok(GeneratedField::BaseRewardRate),
-                            "slashingPenaltyMisbehavior" | "slashing_penalty_misbehavior" => Ok(GeneratedField::SlashingPenaltyMisbehavior),
-                            "slashingPenaltyDowntime" | "slashing_penalty_downtime" => Ok(GeneratedField::SlashingPenaltyDowntime),
-                            "signedBlocksWindowLen" | "signed_blocks_window_len" => Ok(GeneratedField::SignedBlocksWindowLen),
-                            "missedBlocksMaximum" | "missed_blocks_maximum" => Ok(GeneratedField::MissedBlocksMaximum),
-                            "minValidatorStake" | "min_validator_stake" => Ok(GeneratedField::MinValidatorStake),
+                            "identityKey" | "identity_key" => Ok(GeneratedField::IdentityKey),
+                            "epochIndex" | "epoch_index" => Ok(GeneratedField::EpochIndex),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -1713,109 +6391,144 @@ impl<'de> serde::Deserialize<'de> for StakeParameters {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = StakeParameters;
+            type Value = ValidatorPowerProofRequest;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.core.component.stake.v1.StakeParameters")
+                formatter.write_str("struct penumbra.core.component.stake.v1.ValidatorPowerProofRequest")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<StakeParameters, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ValidatorPowerProofRequest, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut unbonding_epochs__ = None;
-                let mut active_validator_limit__ = None;
-                let mut base_reward_rate__ = None;
-                let mut slashing_penalty_misbehavior__ = None;
-                let mut slashing_penalty_downtime__ = None;
-                let mut signed_blocks_window_len__ = None;
-                let mut missed_blocks_maximum__ = None;
-                let mut min_validator_stake__ = None;
+                let mut identity_key__ = None;
+                let mut epoch_index__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::UnbondingEpochs => {
-                            if unbonding_epochs__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("unbondingEpochs"));
-                            }
-                            unbonding_epochs__ = 
-                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
-                            ;
-                        }
-                        GeneratedField::ActiveValidatorLimit => {
-                            if active_validator_limit__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("activeValidatorLimit"));
-                            }
-                            active_validator_limit__ = 
-                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
-                            ;
-                        }
-                        GeneratedField::BaseRewardRate => {
-                            if base_reward_rate__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("baseRewardRate"));
-                            }
-                            base_reward_rate__ = 
-                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
-                            ;
-                        }
-                        GeneratedField::SlashingPenaltyMisbehavior => {
-                            if slashing_penalty_misbehavior__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("slashingPenaltyMisbehavior"));
+                        GeneratedField::IdentityKey => {
+                            if identity_key__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("identityKey"));
                             }
-                            slashing_penalty_misbehavior__ = 
-                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
-                            ;
+                            identity_key__ = map_.next_value()?;
                         }
-                        GeneratedField::SlashingPenaltyDowntime => {
-                            if slashing_penalty_downtime__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("slashingPenaltyDowntime"));
+                        GeneratedField::EpochIndex => {
+                            if epoch_index__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("epochIndex"));
                             }
-                            slashing_penalty_downtime__ = 
+                            epoch_index__ = 
                                 Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
                             ;
                         }
-                        GeneratedField::SignedBlocksWindowLen => {
-                            if signed_blocks_window_len__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("signedBlocksWindowLen"));
-                            }
-                            signed_blocks_window_len__ = 
-                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
-                            ;
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
-                        GeneratedField::MissedBlocksMaximum => {
-                            if missed_blocks_maximum__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("missedBlocksMaximum"));
-                            }
-                            missed_blocks_maximum__ = 
-                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
-                            ;
+                    }
+                }
+                Ok(ValidatorPowerProofRequest {
+                    identity_key: identity_key__,
+                    epoch_index: epoch_index__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.stake.v1.ValidatorPowerProofRequest", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for ValidatorPowerProofResponse {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.proof.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.stake.v1.ValidatorPowerProofResponse", len)?;
+        if let Some(v) = self.proof.as_ref() {
+            struct_ser.serialize_field("proof", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for ValidatorPowerProofResponse {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "proof",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Proof,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "proof" => Ok(GeneratedField::Proof),
+                            _ => Ok(GeneratedField::__SkipField__),
                         }
-                        GeneratedField::MinValidatorStake => {
-                            if min_validator_stake__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("minValidatorStake"));
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = ValidatorPowerProofResponse;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.stake.v1.ValidatorPowerProofResponse")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ValidatorPowerProofResponse, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut proof__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Proof => {
+                            if proof__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("proof"));
                             }
-                            min_validator_stake__ = map_.next_value()?;
+                            proof__ = map_.next_value()?;
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(StakeParameters {
-                    unbonding_epochs: unbonding_epochs__.unwrap_or_default(),
-                    active_validator_limit: active_validator_limit__.unwrap_or_default(),
-                    base_reward_rate: base_reward_rate__.unwrap_or_default(),
-                    slashing_penalty_misbehavior: slashing_penalty_misbehavior__.unwrap_or_default(),
-                    slashing_penalty_downtime: slashing_penalty_downtime__.unwrap_or_default(),
-                    signed_blocks_window_len: signed_blocks_window_len__.unwrap_or_default(),
-                    missed_blocks_maximum: missed_blocks_maximum__.unwrap_or_default(),
-                    min_validator_stake: min_validator_stake__,
+                Ok(ValidatorPowerProofResponse {
+                    proof: proof__,
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.core.component.stake.v1.StakeParameters", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.core.component.stake.v1.ValidatorPowerProofResponse", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for Undelegate {
+impl serde::Serialize for ValidatorPowerTable {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -1823,58 +6536,39 @@ impl serde::Serialize for Undelegate {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.validator_identity.is_some() {
-            len += 1;
-        }
-        if self.start_epoch_index != 0 {
-            len += 1;
-        }
-        if self.unbonded_amount.is_some() {
+        if self.epoch_index != 0 {
             len += 1;
         }
-        if self.delegation_amount.is_some() {
+        if !self.entries.is_empty() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.stake.v1.Undelegate", len)?;
-        if let Some(v) = self.validator_identity.as_ref() {
-            struct_ser.serialize_field("validatorIdentity", v)?;
-        }
-        if self.start_epoch_index != 0 {
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.stake.v1.ValidatorPowerTable", len)?;
+        if self.epoch_index != 0 {
             #[allow(clippy::needless_borrow)]
-            struct_ser.serialize_field("startEpochIndex", ToString::to_string(&self.start_epoch_index).as_str())?;
-        }
-        if let Some(v) = self.unbonded_amount.as_ref() {
-            struct_ser.serialize_field("unbondedAmount", v)?;
+            struct_ser.serialize_field("epochIndex", ToString::to_string(&self.epoch_index).as_str())?;
         }
-        if let Some(v) = self.delegation_amount.as_ref() {
-            struct_ser.serialize_field("delegationAmount", v)?;
+        if !self.entries.is_empty() {
+            struct_ser.serialize_field("entries", &self.entries)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for Undelegate {
-    #[allow(deprecated)]
-    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
-    where
-        D: serde::Deserializer<'de>,
-    {
-        const FIELDS: &[&str] = &[
-            "validator_identity",
-            "validatorIdentity",
-            "start_epoch_index",
-            "startEpochIndex",
-            "unbonded_amount",
-            "unbondedAmount",
-            "delegation_amount",
-            "delegationAmount",
+impl<'de> serde::Deserialize<'de> for ValidatorPowerTable {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "epoch_index",
+            "epochIndex",
+            "entries",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            ValidatorIdentity,
-            StartEpochIndex,
-            UnbondedAmount,
-            DelegationAmount,
+            EpochIndex,
+            Entries,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -1897,10 +6591,8 @@ impl<'de> serde::Deserialize<'de> for Undelegate {
                         E: serde::de::Error,
                     {
                         match value {
-                            "validatorIdentity" | "validator_identity" => Ok(GeneratedField::ValidatorIdentity),
-                            "startEpochIndex" | "start_epoch_index" => Ok(GeneratedField::StartEpochIndex),
-                            "unbondedAmount" | "unbonded_amount" => Ok(GeneratedField::UnbondedAmount),
-                            "delegationAmount" | "delegation_amount" => Ok(GeneratedField::DelegationAmount),
+                            "epochIndex" | "epoch_index" => Ok(GeneratedField::EpochIndex),
+                            "entries" => Ok(GeneratedField::Entries),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -1910,65 +6602,49 @@ impl<'de> serde::Deserialize<'de> for Undelegate {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = Undelegate;
+            type Value = ValidatorPowerTable;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.core.component.stake.v1.Undelegate")
+                formatter.write_str("struct penumbra.core.component.stake.v1.ValidatorPowerTable")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<Undelegate, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ValidatorPowerTable, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut validator_identity__ = None;
-                let mut start_epoch_index__ = None;
-                let mut unbonded_amount__ = None;
-                let mut delegation_amount__ = None;
+                let mut epoch_index__ = None;
+                let mut entries__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::ValidatorIdentity => {
-                            if validator_identity__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("validatorIdentity"));
-                            }
-                            validator_identity__ = map_.next_value()?;
-                        }
-                        GeneratedField::StartEpochIndex => {
-                            if start_epoch_index__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("startEpochIndex"));
+                        GeneratedField::EpochIndex => {
+                            if epoch_index__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("epochIndex"));
                             }
-                            start_epoch_index__ = 
+                            epoch_index__ = 
                                 Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
                             ;
                         }
-                        GeneratedField::UnbondedAmount => {
-                            if unbonded_amount__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("unbondedAmount"));
-                            }
-                            unbonded_amount__ = map_.next_value()?;
-                        }
-                        GeneratedField::DelegationAmount => {
-                            if delegation_amount__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("delegationAmount"));
+                        GeneratedField::Entries => {
+                            if entries__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("entries"));
                             }
-                            delegation_amount__ = map_.next_value()?;
+                            entries__ = Some(map_.next_value()?);
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(Undelegate {
-                    validator_identity: validator_identity__,
-                    start_epoch_index: start_epoch_index__.unwrap_or_default(),
-                    unbonded_amount: unbonded_amount__,
-                    delegation_amount: delegation_amount__,
+                Ok(ValidatorPowerTable {
+                    epoch_index: epoch_index__.unwrap_or_default(),
+                    entries: entries__.unwrap_or_default(),
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.core.component.stake.v1.Undelegate", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.core.component.stake.v1.ValidatorPowerTable", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for UndelegateClaim {
+impl serde::Serialize for ValidatorPowerTableCommitment {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -1976,38 +6652,30 @@ impl serde::Serialize for UndelegateClaim {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.body.is_some() {
-            len += 1;
-        }
-        if !self.proof.is_empty() {
+        if !self.inner.is_empty() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.stake.v1.UndelegateClaim", len)?;
-        if let Some(v) = self.body.as_ref() {
-            struct_ser.serialize_field("body", v)?;
-        }
-        if !self.proof.is_empty() {
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.stake.v1.ValidatorPowerTableCommitment", len)?;
+        if !self.inner.is_empty() {
             #[allow(clippy::needless_borrow)]
-            struct_ser.serialize_field("proof", pbjson::private::base64::encode(&self.proof).as_str())?;
+            struct_ser.serialize_field("inner", pbjson::private::base64::encode(&self.inner).as_str())?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for UndelegateClaim {
+impl<'de> serde::Deserialize<'de> for ValidatorPowerTableCommitment {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "body",
-            "proof",
+            "inner",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            Body,
-            Proof,
+            Inner,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -2030,8 +6698,7 @@ impl<'de> serde::Deserialize<'de> for UndelegateClaim {
                         E: serde::de::Error,
                     {
                         match value {
-                            "body" => Ok(GeneratedField::Body),
-                            "proof" => Ok(GeneratedField::Proof),
+                            "inner" => Ok(GeneratedField::Inner),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -2041,31 +6708,24 @@ impl<'de> serde::Deserialize<'de> for UndelegateClaim {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = UndelegateClaim;
+            type Value = ValidatorPowerTableCommitment;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.core.component.stake.v1.UndelegateClaim")
+                formatter.write_str("struct penumbra.core.component.stake.v1.ValidatorPowerTableCommitment")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<UndelegateClaim, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ValidatorPowerTableCommitment, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut body__ = None;
-                let mut proof__ = None;
+                let mut inner__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::Body => {
-                            if body__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("body"));
-                            }
-                            body__ = map_.next_value()?;
-                        }
-                        GeneratedField::Proof => {
-                            if proof__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("proof"));
+                        GeneratedField::Inner => {
+                            if inner__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("inner"));
                             }
-                            proof__ = 
+                            inner__ = 
                                 Some(map_.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
                             ;
                         }
@@ -2074,16 +6734,15 @@ impl<'de> serde::Deserialize<'de> for UndelegateClaim {
                         }
                     }
                 }
-                Ok(UndelegateClaim {
-                    body: body__,
-                    proof: proof__.unwrap_or_default(),
+                Ok(ValidatorPowerTableCommitment {
+                    inner: inner__.unwrap_or_default(),
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.core.component.stake.v1.UndelegateClaim", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.core.component.stake.v1.ValidatorPowerTableCommitment", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for UndelegateClaimBody {
+impl serde::Serialize for ValidatorRateHistoryRequest {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -2091,57 +6750,50 @@ impl serde::Serialize for UndelegateClaimBody {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.validator_identity.is_some() {
+        if self.identity_key.is_some() {
             len += 1;
         }
         if self.start_epoch_index != 0 {
             len += 1;
         }
-        if self.penalty.is_some() {
-            len += 1;
-        }
-        if self.balance_commitment.is_some() {
+        if self.end_epoch_index != 0 {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.stake.v1.UndelegateClaimBody", len)?;
-        if let Some(v) = self.validator_identity.as_ref() {
-            struct_ser.serialize_field("validatorIdentity", v)?;
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.stake.v1.ValidatorRateHistoryRequest", len)?;
+        if let Some(v) = self.identity_key.as_ref() {
+            struct_ser.serialize_field("identityKey", v)?;
         }
         if self.start_epoch_index != 0 {
             #[allow(clippy::needless_borrow)]
             struct_ser.serialize_field("startEpochIndex", ToString::to_string(&self.start_epoch_index).as_str())?;
         }
-        if let Some(v) = self.penalty.as_ref() {
-            struct_ser.serialize_field("penalty", v)?;
-        }
-        if let Some(v) = self.balance_commitment.as_ref() {
-            struct_ser.serialize_field("balanceCommitment", v)?;
+        if self.end_epoch_index != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("endEpochIndex", ToString::to_string(&self.end_epoch_index).as_str())?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for UndelegateClaimBody {
+impl<'de> serde::Deserialize<'de> for ValidatorRateHistoryRequest {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "validator_identity",
-            "validatorIdentity",
+            "identity_key",
+            "identityKey",
             "start_epoch_index",
             "startEpochIndex",
-            "penalty",
-            "balance_commitment",
-            "balanceCommitment",
+            "end_epoch_index",
+            "endEpochIndex",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            ValidatorIdentity,
+            IdentityKey,
             StartEpochIndex,
-            Penalty,
-            BalanceCommitment,
+            EndEpochIndex,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -2164,10 +6816,9 @@ impl<'de> serde::Deserialize<'de> for UndelegateClaimBody {
                         E: serde::de::Error,
                     {
                         match value {
-                            "validatorIdentity" | "validator_identity" => Ok(GeneratedField::ValidatorIdentity),
+                            "identityKey" | "identity_key" => Ok(GeneratedField::IdentityKey),
                             "startEpochIndex" | "start_epoch_index" => Ok(GeneratedField::StartEpochIndex),
-                            "penalty" => Ok(GeneratedField::Penalty),
-                            "balanceCommitment" | "balance_commitment" => Ok(GeneratedField::BalanceCommitment),
+                            "endEpochIndex" | "end_epoch_index" => Ok(GeneratedField::EndEpochIndex),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -2177,27 +6828,26 @@ impl<'de> serde::Deserialize<'de> for UndelegateClaimBody {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = UndelegateClaimBody;
+            type Value = ValidatorRateHistoryRequest;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.core.component.stake.v1.UndelegateClaimBody")
+                formatter.write_str("struct penumbra.core.component.stake.v1.ValidatorRateHistoryRequest")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<UndelegateClaimBody, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ValidatorRateHistoryRequest, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut validator_identity__ = None;
+                let mut identity_key__ = None;
                 let mut start_epoch_index__ = None;
-                let mut penalty__ = None;
-                let mut balance_commitment__ = None;
+                let mut end_epoch_index__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::ValidatorIdentity => {
-                            if validator_identity__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("validatorIdentity"));
+                        GeneratedField::IdentityKey => {
+                            if identity_key__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("identityKey"));
                             }
-                            validator_identity__ = map_.next_value()?;
+                            identity_key__ = map_.next_value()?;
                         }
                         GeneratedField::StartEpochIndex => {
                             if start_epoch_index__.is_some() {
@@ -2207,35 +6857,30 @@ impl<'de> serde::Deserialize<'de> for UndelegateClaimBody {
                                 Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
                             ;
                         }
-                        GeneratedField::Penalty => {
-                            if penalty__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("penalty"));
-                            }
-                            penalty__ = map_.next_value()?;
-                        }
-                        GeneratedField::BalanceCommitment => {
-                            if balance_commitment__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("balanceCommitment"));
+                        GeneratedField::EndEpochIndex => {
+                            if end_epoch_index__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("endEpochIndex"));
                             }
-                            balance_commitment__ = map_.next_value()?;
+                            end_epoch_index__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(UndelegateClaimBody {
-                    validator_identity: validator_identity__,
+                Ok(ValidatorRateHistoryRequest {
+                    identity_key: identity_key__,
                     start_epoch_index: start_epoch_index__.unwrap_or_default(),
-                    penalty: penalty__,
-                    balance_commitment: balance_commitment__,
+                    end_epoch_index: end_epoch_index__.unwrap_or_default(),
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.core.component.stake.v1.UndelegateClaimBody", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.core.component.stake.v1.ValidatorRateHistoryRequest", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for UndelegateClaimPlan {
+impl serde::Serialize for ValidatorRateHistoryResponse {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -2243,87 +6888,30 @@ impl serde::Serialize for UndelegateClaimPlan {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.validator_identity.is_some() {
-            len += 1;
-        }
-        if self.start_epoch_index != 0 {
-            len += 1;
-        }
-        if self.penalty.is_some() {
-            len += 1;
-        }
-        if self.unbonding_amount.is_some() {
-            len += 1;
-        }
-        if !self.balance_blinding.is_empty() {
-            len += 1;
-        }
-        if !self.proof_blinding_r.is_empty() {
-            len += 1;
-        }
-        if !self.proof_blinding_s.is_empty() {
+        if self.rate_data.is_some() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.stake.v1.UndelegateClaimPlan", len)?;
-        if let Some(v) = self.validator_identity.as_ref() {
-            struct_ser.serialize_field("validatorIdentity", v)?;
-        }
-        if self.start_epoch_index != 0 {
-            #[allow(clippy::needless_borrow)]
-            struct_ser.serialize_field("startEpochIndex", ToString::to_string(&self.start_epoch_index).as_str())?;
-        }
-        if let Some(v) = self.penalty.as_ref() {
-            struct_ser.serialize_field("penalty", v)?;
-        }
-        if let Some(v) = self.unbonding_amount.as_ref() {
-            struct_ser.serialize_field("unbondingAmount", v)?;
-        }
-        if !self.balance_blinding.is_empty() {
-            #[allow(clippy::needless_borrow)]
-            struct_ser.serialize_field("balanceBlinding", pbjson::private::base64::encode(&self.balance_blinding).as_str())?;
-        }
-        if !self.proof_blinding_r.is_empty() {
-            #[allow(clippy::needless_borrow)]
-            struct_ser.serialize_field("proofBlindingR", pbjson::private::base64::encode(&self.proof_blinding_r).as_str())?;
-        }
-        if !self.proof_blinding_s.is_empty() {
-            #[allow(clippy::needless_borrow)]
-            struct_ser.serialize_field("proofBlindingS", pbjson::private::base64::encode(&self.proof_blinding_s).as_str())?;
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.stake.v1.ValidatorRateHistoryResponse", len)?;
+        if let Some(v) = self.rate_data.as_ref() {
+            struct_ser.serialize_field("rateData", v)?;
         }
         struct_ser.end()
-    }
-}
-impl<'de> serde::Deserialize<'de> for UndelegateClaimPlan {
-    #[allow(deprecated)]
-    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
-    where
-        D: serde::Deserializer<'de>,
-    {
-        const FIELDS: &[&str] = &[
-            "validator_identity",
-            "validatorIdentity",
-            "start_epoch_index",
-            "startEpochIndex",
-            "penalty",
-            "unbonding_amount",
-            "unbondingAmount",
-            "balance_blinding",
-            "balanceBlinding",
-            "proof_blinding_r",
-            "proofBlindingR",
-            "proof_blinding_s",
-            "proofBlindingS",
+    }
+}
+impl<'de> serde::Deserialize<'de> for ValidatorRateHistoryResponse {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "rate_data",
+            "rateData",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            ValidatorIdentity,
-            StartEpochIndex,
-            Penalty,
-            UnbondingAmount,
-            BalanceBlinding,
-            ProofBlindingR,
-            ProofBlindingS,
+            RateData,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -2346,13 +6934,7 @@ impl<'de> serde::Deserialize<'de> for UndelegateClaimPlan {
                         E: serde::de::Error,
                     {
                         match value {
-                            "validatorIdentity" | "validator_identity" => Ok(GeneratedField::ValidatorIdentity),
-                            "startEpochIndex" | "start_epoch_index" => Ok(GeneratedField::StartEpochIndex),
-                            "penalty" => Ok(GeneratedField::Penalty),
-                            "unbondingAmount" | "unbonding_amount" => Ok(GeneratedField::UnbondingAmount),
-                            "balanceBlinding" | "balance_blinding" => Ok(GeneratedField::BalanceBlinding),
-                            "proofBlindingR" | "proof_blinding_r" => Ok(GeneratedField::ProofBlindingR),
-                            "proofBlindingS" | "proof_blinding_s" => Ok(GeneratedField::ProofBlindingS),
+                            "rateData" | "rate_data" => Ok(GeneratedField::RateData),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -2362,95 +6944,39 @@ impl<'de> serde::Deserialize<'de> for UndelegateClaimPlan {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = UndelegateClaimPlan;
+            type Value = ValidatorRateHistoryResponse;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.core.component.stake.v1.UndelegateClaimPlan")
+                formatter.write_str("struct penumbra.core.component.stake.v1.ValidatorRateHistoryResponse")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<UndelegateClaimPlan, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ValidatorRateHistoryResponse, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut validator_identity__ = None;
-                let mut start_epoch_index__ = None;
-                let mut penalty__ = None;
-                let mut unbonding_amount__ = None;
-                let mut balance_blinding__ = None;
-                let mut proof_blinding_r__ = None;
-                let mut proof_blinding_s__ = None;
+                let mut rate_data__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::ValidatorIdentity => {
-                            if validator_identity__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("validatorIdentity"));
-                            }
-                            validator_identity__ = map_.next_value()?;
-                        }
-                        GeneratedField::StartEpochIndex => {
-                            if start_epoch_index__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("startEpochIndex"));
-                            }
-                            start_epoch_index__ = 
-                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
-                            ;
-                        }
-                        GeneratedField::Penalty => {
-                            if penalty__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("penalty"));
-                            }
-                            penalty__ = map_.next_value()?;
-                        }
-                        GeneratedField::UnbondingAmount => {
-                            if unbonding_amount__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("unbondingAmount"));
-                            }
-                            unbonding_amount__ = map_.next_value()?;
-                        }
-                        GeneratedField::BalanceBlinding => {
-                            if balance_blinding__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("balanceBlinding"));
-                            }
-                            balance_blinding__ = 
-                                Some(map_.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
-                            ;
-                        }
-                        GeneratedField::ProofBlindingR => {
-                            if proof_blinding_r__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("proofBlindingR"));
-                            }
-                            proof_blinding_r__ = 
-                                Some(map_.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
-                            ;
-                        }
-                        GeneratedField::ProofBlindingS => {
-                            if proof_blinding_s__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("proofBlindingS"));
+                        GeneratedField::RateData => {
+                            if rate_data__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("rateData"));
                             }
-                            proof_blinding_s__ = 
-                                Some(map_.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
-                            ;
+                            rate_data__ = map_.next_value()?;
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(UndelegateClaimPlan {
-                    validator_identity: validator_identity__,
-                    start_epoch_index: start_epoch_index__.unwrap_or_default(),
-                    penalty: penalty__,
-                    unbonding_amount: unbonding_amount__,
-                    balance_blinding: balance_blinding__.unwrap_or_default(),
-                    proof_blinding_r: proof_blinding_r__.unwrap_or_default(),
-                    proof_blinding_s: proof_blinding_s__.unwrap_or_default(),
+                Ok(ValidatorRateHistoryResponse {
+                    rate_data: rate_data__,
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.core.component.stake.v1.UndelegateClaimPlan", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.core.component.stake.v1.ValidatorRateHistoryResponse", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for Uptime {
+impl serde::Serialize for ValidatorSetSnapshot {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -2458,49 +6984,38 @@ impl serde::Serialize for Uptime {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.as_of_block_height != 0 {
-            len += 1;
-        }
-        if self.window_len != 0 {
+        if self.height != 0 {
             len += 1;
         }
-        if !self.bitvec.is_empty() {
+        if !self.validators.is_empty() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.stake.v1.Uptime", len)?;
-        if self.as_of_block_height != 0 {
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.stake.v1.ValidatorSetSnapshot", len)?;
+        if self.height != 0 {
             #[allow(clippy::needless_borrow)]
-            struct_ser.serialize_field("asOfBlockHeight", ToString::to_string(&self.as_of_block_height).as_str())?;
-        }
-        if self.window_len != 0 {
-            struct_ser.serialize_field("windowLen", &self.window_len)?;
+            struct_ser.serialize_field("height", ToString::to_string(&self.height).as_str())?;
         }
-        if !self.bitvec.is_empty() {
-            #[allow(clippy::needless_borrow)]
-            struct_ser.serialize_field("bitvec", pbjson::private::base64::encode(&self.bitvec).as_str())?;
+        if !self.validators.is_empty() {
+            struct_ser.serialize_field("validators", &self.validators)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for Uptime {
+impl<'de> serde::Deserialize<'de> for ValidatorSetSnapshot {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "as_of_block_height",
-            "asOfBlockHeight",
-            "window_len",
-            "windowLen",
-            "bitvec",
+            "height",
+            "validators",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            AsOfBlockHeight,
-            WindowLen,
-            Bitvec,
+            Height,
+            Validators,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -2523,9 +7038,8 @@ impl<'de> serde::Deserialize<'de> for Uptime {
                         E: serde::de::Error,
                     {
                         match value {
-                            "asOfBlockHeight" | "as_of_block_height" => Ok(GeneratedField::AsOfBlockHeight),
-                            "windowLen" | "window_len" => Ok(GeneratedField::WindowLen),
-                            "bitvec" => Ok(GeneratedField::Bitvec),
+                            "height" => Ok(GeneratedField::Height),
+                            "validators" => Ok(GeneratedField::Validators),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -2535,61 +7049,49 @@ impl<'de> serde::Deserialize<'de> for Uptime {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = Uptime;
+            type Value = ValidatorSetSnapshot;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.core.component.stake.v1.Uptime")
+                formatter.write_str("struct penumbra.core.component.stake.v1.ValidatorSetSnapshot")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<Uptime, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ValidatorSetSnapshot, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut as_of_block_height__ = None;
-                let mut window_len__ = None;
-                let mut bitvec__ = None;
+                let mut height__ = None;
+                let mut validators__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::AsOfBlockHeight => {
-                            if as_of_block_height__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("asOfBlockHeight"));
-                            }
-                            as_of_block_height__ = 
-                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
-                            ;
-                        }
-                        GeneratedField::WindowLen => {
-                            if window_len__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("windowLen"));
+                        GeneratedField::Height => {
+                            if height__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("height"));
                             }
-                            window_len__ = 
+                            height__ = 
                                 Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
                             ;
                         }
-                        GeneratedField::Bitvec => {
-                            if bitvec__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("bitvec"));
+                        GeneratedField::Validators => {
+                            if validators__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("validators"));
                             }
-                            bitvec__ = 
-                                Some(map_.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
-                            ;
+                            validators__ = Some(map_.next_value()?);
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(Uptime {
-                    as_of_block_height: as_of_block_height__.unwrap_or_default(),
-                    window_len: window_len__.unwrap_or_default(),
-                    bitvec: bitvec__.unwrap_or_default(),
+                Ok(ValidatorSetSnapshot {
+                    height: height__.unwrap_or_default(),
+                    validators: validators__.unwrap_or_default(),
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.core.component.stake.v1.Uptime", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.core.component.stake.v1.ValidatorSetSnapshot", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for Validator {
+impl serde::Serialize for ValidatorSetSnapshotProof {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -2597,99 +7099,40 @@ impl serde::Serialize for Validator {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.identity_key.is_some() {
-            len += 1;
-        }
-        if !self.consensus_key.is_empty() {
-            len += 1;
-        }
-        if !self.name.is_empty() {
-            len += 1;
-        }
-        if !self.website.is_empty() {
-            len += 1;
-        }
-        if !self.description.is_empty() {
-            len += 1;
-        }
-        if self.enabled {
+        if !self.app_hash.is_empty() {
             len += 1;
         }
-        if !self.funding_streams.is_empty() {
-            len += 1;
-        }
-        if self.sequence_number != 0 {
+        if !self.key_proofs.is_empty() {
             len += 1;
         }
-        if self.governance_key.is_some() {
-            len += 1;
-        }
-        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.stake.v1.Validator", len)?;
-        if let Some(v) = self.identity_key.as_ref() {
-            struct_ser.serialize_field("identityKey", v)?;
-        }
-        if !self.consensus_key.is_empty() {
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.stake.v1.ValidatorSetSnapshotProof", len)?;
+        if !self.app_hash.is_empty() {
             #[allow(clippy::needless_borrow)]
-            struct_ser.serialize_field("consensusKey", pbjson::private::base64::encode(&self.consensus_key).as_str())?;
-        }
-        if !self.name.is_empty() {
-            struct_ser.serialize_field("name", &self.name)?;
-        }
-        if !self.website.is_empty() {
-            struct_ser.serialize_field("website", &self.website)?;
-        }
-        if !self.description.is_empty() {
-            struct_ser.serialize_field("description", &self.description)?;
-        }
-        if self.enabled {
-            struct_ser.serialize_field("enabled", &self.enabled)?;
-        }
-        if !self.funding_streams.is_empty() {
-            struct_ser.serialize_field("fundingStreams", &self.funding_streams)?;
+            struct_ser.serialize_field("appHash", pbjson::private::base64::encode(&self.app_hash).as_str())?;
         }
-        if self.sequence_number != 0 {
-            struct_ser.serialize_field("sequenceNumber", &self.sequence_number)?;
-        }
-        if let Some(v) = self.governance_key.as_ref() {
-            struct_ser.serialize_field("governanceKey", v)?;
+        if !self.key_proofs.is_empty() {
+            struct_ser.serialize_field("keyProofs", &self.key_proofs)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for Validator {
+impl<'de> serde::Deserialize<'de> for ValidatorSetSnapshotProof {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "identity_key",
-            "identityKey",
-            "consensus_key",
-            "consensusKey",
-            "name",
-            "website",
-            "description",
-            "enabled",
-            "funding_streams",
-            "fundingStreams",
-            "sequence_number",
-            "sequenceNumber",
-            "governance_key",
-            "governanceKey",
+            "app_hash",
+            "appHash",
+            "key_proofs",
+            "keyProofs",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            IdentityKey,
-            ConsensusKey,
-            Name,
-            Website,
-            Description,
-            Enabled,
-            FundingStreams,
-            SequenceNumber,
-            GovernanceKey,
+            AppHash,
+            KeyProofs,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -2706,21 +7149,14 @@ impl<'de> serde::Deserialize<'de> for Validator {
                         write!(formatter, "expected one of: {:?}", &FIELDS)
                     }
 
-                    #[allow(unused_variables)]
-                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
-                    where
-                        E: serde::de::Error,
-                    {
-                        match value {
-                            "identityKey" | "identity_key" => Ok(GeneratedField::IdentityKey),
-                            "consensusKey" | "consensus_key" => Ok(GeneratedField::ConsensusKey),
-                            "name" => Ok(GeneratedField::Name),
-                            "website" => Ok(GeneratedField::Website),
-                            "description" => Ok(GeneratedField::Description),
-                            "enabled" => Ok(GeneratedField::Enabled),
-                            "fundingStreams" | "funding_streams" => Ok(GeneratedField::FundingStreams),
-                            "sequenceNumber" | "sequence_number" => Ok(GeneratedField::SequenceNumber),
-                            "governanceKey" | "governance_key" => Ok(GeneratedField::GovernanceKey),
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "appHash" | "app_hash" => Ok(GeneratedField::AppHash),
+                            "keyProofs" | "key_proofs" => Ok(GeneratedField::KeyProofs),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -2730,107 +7166,49 @@ impl<'de> serde::Deserialize<'de> for Validator {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = Validator;
+            type Value = ValidatorSetSnapshotProof;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.core.component.stake.v1.Validator")
+                formatter.write_str("struct penumbra.core.component.stake.v1.ValidatorSetSnapshotProof")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<Validator, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ValidatorSetSnapshotProof, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut identity_key__ = None;
-                let mut consensus_key__ = None;
-                let mut name__ = None;
-                let mut website__ = None;
-                let mut description__ = None;
-                let mut enabled__ = None;
-                let mut funding_streams__ = None;
-                let mut sequence_number__ = None;
-                let mut governance_key__ = None;
+                let mut app_hash__ = None;
+                let mut key_proofs__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::IdentityKey => {
-                            if identity_key__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("identityKey"));
-                            }
-                            identity_key__ = map_.next_value()?;
-                        }
-                        GeneratedField::ConsensusKey => {
-                            if consensus_key__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("consensusKey"));
+                        GeneratedField::AppHash => {
+                            if app_hash__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("appHash"));
                             }
-                            consensus_key__ = 
+                            app_hash__ = 
                                 Some(map_.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
                             ;
                         }
-                        GeneratedField::Name => {
-                            if name__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("name"));
-                            }
-                            name__ = Some(map_.next_value()?);
-                        }
-                        GeneratedField::Website => {
-                            if website__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("website"));
-                            }
-                            website__ = Some(map_.next_value()?);
-                        }
-                        GeneratedField::Description => {
-                            if description__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("description"));
-                            }
-                            description__ = Some(map_.next_value()?);
-                        }
-                        GeneratedField::Enabled => {
-                            if enabled__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("enabled"));
-                            }
-                            enabled__ = Some(map_.next_value()?);
-                        }
-                        GeneratedField::FundingStreams => {
-                            if funding_streams__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("fundingStreams"));
-                            }
-                            funding_streams__ = Some(map_.next_value()?);
-                        }
-                        GeneratedField::SequenceNumber => {
-                            if sequence_number__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("sequenceNumber"));
-                            }
-                            sequence_number__ = 
-                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
-                            ;
-                        }
-                        GeneratedField::GovernanceKey => {
-                            if governance_key__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("governanceKey"));
+                        GeneratedField::KeyProofs => {
+                            if key_proofs__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("keyProofs"));
                             }
-                            governance_key__ = map_.next_value()?;
+                            key_proofs__ = Some(map_.next_value()?);
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(Validator {
-                    identity_key: identity_key__,
-                    consensus_key: consensus_key__.unwrap_or_default(),
-                    name: name__.unwrap_or_default(),
-                    website: website__.unwrap_or_default(),
-                    description: description__.unwrap_or_default(),
-                    enabled: enabled__.unwrap_or_default(),
-                    funding_streams: funding_streams__.unwrap_or_default(),
-                    sequence_number: sequence_number__.unwrap_or_default(),
-                    governance_key: governance_key__,
+                Ok(ValidatorSetSnapshotProof {
+                    app_hash: app_hash__.unwrap_or_default(),
+                    key_proofs: key_proofs__.unwrap_or_default(),
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.core.component.stake.v1.Validator", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.core.component.stake.v1.ValidatorSetSnapshotProof", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for ValidatorDefinition {
+impl serde::Serialize for validator_set_snapshot_proof::KeyProof {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -2838,39 +7216,47 @@ impl serde::Serialize for ValidatorDefinition {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.validator.is_some() {
+        if !self.key.is_empty() {
             len += 1;
         }
-        if !self.auth_sig.is_empty() {
+        if !self.value.is_empty() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.stake.v1.ValidatorDefinition", len)?;
-        if let Some(v) = self.validator.as_ref() {
-            struct_ser.serialize_field("validator", v)?;
+        if !self.proof.is_empty() {
+            len += 1;
         }
-        if !self.auth_sig.is_empty() {
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.stake.v1.ValidatorSetSnapshotProof.KeyProof", len)?;
+        if !self.key.is_empty() {
+            struct_ser.serialize_field("key", &self.key)?;
+        }
+        if !self.value.is_empty() {
             #[allow(clippy::needless_borrow)]
-            struct_ser.serialize_field("authSig", pbjson::private::base64::encode(&self.auth_sig).as_str())?;
+            struct_ser.serialize_field("value", pbjson::private::base64::encode(&self.value).as_str())?;
+        }
+        if !self.proof.is_empty() {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("proof", pbjson::private::base64::encode(&self.proof).as_str())?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for ValidatorDefinition {
+impl<'de> serde::Deserialize<'de> for validator_set_snapshot_proof::KeyProof {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "validator",
-            "auth_sig",
-            "authSig",
+            "key",
+            "value",
+            "proof",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            Validator,
-            AuthSig,
+            Key,
+            Value,
+            Proof,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -2893,8 +7279,9 @@ impl<'de> serde::Deserialize<'de> for ValidatorDefinition {
                         E: serde::de::Error,
                     {
                         match value {
-                            "validator" => Ok(GeneratedField::Validator),
-                            "authSig" | "auth_sig" => Ok(GeneratedField::AuthSig),
+                            "key" => Ok(GeneratedField::Key),
+                            "value" => Ok(GeneratedField::Value),
+                            "proof" => Ok(GeneratedField::Proof),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -2904,31 +7291,40 @@ impl<'de> serde::Deserialize<'de> for ValidatorDefinition {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = ValidatorDefinition;
+            type Value = validator_set_snapshot_proof::KeyProof;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.core.component.stake.v1.ValidatorDefinition")
+                formatter.write_str("struct penumbra.core.component.stake.v1.ValidatorSetSnapshotProof.KeyProof")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ValidatorDefinition, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<validator_set_snapshot_proof::KeyProof, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut validator__ = None;
-                let mut auth_sig__ = None;
+                let mut key__ = None;
+                let mut value__ = None;
+                let mut proof__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::Validator => {
-                            if validator__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("validator"));
+                        GeneratedField::Key => {
+                            if key__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("key"));
                             }
-                            validator__ = map_.next_value()?;
+                            key__ = Some(map_.next_value()?);
                         }
-                        GeneratedField::AuthSig => {
-                            if auth_sig__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("authSig"));
+                        GeneratedField::Value => {
+                            if value__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("value"));
                             }
-                            auth_sig__ = 
+                            value__ = 
+                                Some(map_.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::Proof => {
+                            if proof__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("proof"));
+                            }
+                            proof__ = 
                                 Some(map_.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
                             ;
                         }
@@ -2937,16 +7333,17 @@ impl<'de> serde::Deserialize<'de> for ValidatorDefinition {
                         }
                     }
                 }
-                Ok(ValidatorDefinition {
-                    validator: validator__,
-                    auth_sig: auth_sig__.unwrap_or_default(),
+                Ok(validator_set_snapshot_proof::KeyProof {
+                    key: key__.unwrap_or_default(),
+                    value: value__.unwrap_or_default(),
+                    proof: proof__.unwrap_or_default(),
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.core.component.stake.v1.ValidatorDefinition", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.core.component.stake.v1.ValidatorSetSnapshotProof.KeyProof", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for ValidatorInfo {
+impl serde::Serialize for ValidatorSetSnapshotRequest {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -2954,46 +7351,38 @@ impl serde::Serialize for ValidatorInfo {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.validator.is_some() {
+        if self.height != 0 {
             len += 1;
         }
-        if self.status.is_some() {
-            len += 1;
-        }
-        if self.rate_data.is_some() {
+        if self.proof {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.stake.v1.ValidatorInfo", len)?;
-        if let Some(v) = self.validator.as_ref() {
-            struct_ser.serialize_field("validator", v)?;
-        }
-        if let Some(v) = self.status.as_ref() {
-            struct_ser.serialize_field("status", v)?;
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.stake.v1.ValidatorSetSnapshotRequest", len)?;
+        if self.height != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("height", ToString::to_string(&self.height).as_str())?;
         }
-        if let Some(v) = self.rate_data.as_ref() {
-            struct_ser.serialize_field("rateData", v)?;
+        if self.proof {
+            struct_ser.serialize_field("proof", &self.proof)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for ValidatorInfo {
+impl<'de> serde::Deserialize<'de> for ValidatorSetSnapshotRequest {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "validator",
-            "status",
-            "rate_data",
-            "rateData",
+            "height",
+            "proof",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            Validator,
-            Status,
-            RateData,
+            Height,
+            Proof,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -3016,9 +7405,8 @@ impl<'de> serde::Deserialize<'de> for ValidatorInfo {
                         E: serde::de::Error,
                     {
                         match value {
-                            "validator" => Ok(GeneratedField::Validator),
-                            "status" => Ok(GeneratedField::Status),
-                            "rateData" | "rate_data" => Ok(GeneratedField::RateData),
+                            "height" => Ok(GeneratedField::Height),
+                            "proof" => Ok(GeneratedField::Proof),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -3028,55 +7416,49 @@ impl<'de> serde::Deserialize<'de> for ValidatorInfo {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = ValidatorInfo;
+            type Value = ValidatorSetSnapshotRequest;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.core.component.stake.v1.ValidatorInfo")
+                formatter.write_str("struct penumbra.core.component.stake.v1.ValidatorSetSnapshotRequest")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ValidatorInfo, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ValidatorSetSnapshotRequest, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut validator__ = None;
-                let mut status__ = None;
-                let mut rate_data__ = None;
+                let mut height__ = None;
+                let mut proof__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::Validator => {
-                            if validator__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("validator"));
-                            }
-                            validator__ = map_.next_value()?;
-                        }
-                        GeneratedField::Status => {
-                            if status__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("status"));
+                        GeneratedField::Height => {
+                            if height__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("height"));
                             }
-                            status__ = map_.next_value()?;
+                            height__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
                         }
-                        GeneratedField::RateData => {
-                            if rate_data__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("rateData"));
+                        GeneratedField::Proof => {
+                            if proof__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("proof"));
                             }
-                            rate_data__ = map_.next_value()?;
+                            proof__ = Some(map_.next_value()?);
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(ValidatorInfo {
-                    validator: validator__,
-                    status: status__,
-                    rate_data: rate_data__,
+                Ok(ValidatorSetSnapshotRequest {
+                    height: height__.unwrap_or_default(),
+                    proof: proof__.unwrap_or_default(),
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.core.component.stake.v1.ValidatorInfo", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.core.component.stake.v1.ValidatorSetSnapshotRequest", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for ValidatorInfoRequest {
+impl serde::Serialize for ValidatorSetSnapshotResponse {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -3084,30 +7466,37 @@ impl serde::Serialize for ValidatorInfoRequest {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.show_inactive {
+        if self.snapshot.is_some() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.stake.v1.ValidatorInfoRequest", len)?;
-        if self.show_inactive {
-            struct_ser.serialize_field("showInactive", &self.show_inactive)?;
+        if self.proof.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.stake.v1.ValidatorSetSnapshotResponse", len)?;
+        if let Some(v) = self.snapshot.as_ref() {
+            struct_ser.serialize_field("snapshot", v)?;
+        }
+        if let Some(v) = self.proof.as_ref() {
+            struct_ser.serialize_field("proof", v)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for ValidatorInfoRequest {
+impl<'de> serde::Deserialize<'de> for ValidatorSetSnapshotResponse {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "show_inactive",
-            "showInactive",
+            "snapshot",
+            "proof",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            ShowInactive,
+            Snapshot,
+            Proof,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -3130,7 +7519,8 @@ impl<'de> serde::Deserialize<'de> for ValidatorInfoRequest {
                         E: serde::de::Error,
                     {
                         match value {
-                            "showInactive" | "show_inactive" => Ok(GeneratedField::ShowInactive),
+                            "snapshot" => Ok(GeneratedField::Snapshot),
+                            "proof" => Ok(GeneratedField::Proof),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -3140,39 +7530,47 @@ impl<'de> serde::Deserialize<'de> for ValidatorInfoRequest {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = ValidatorInfoRequest;
+            type Value = ValidatorSetSnapshotResponse;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.core.component.stake.v1.ValidatorInfoRequest")
+                formatter.write_str("struct penumbra.core.component.stake.v1.ValidatorSetSnapshotResponse")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ValidatorInfoRequest, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ValidatorSetSnapshotResponse, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut show_inactive__ = None;
+                let mut snapshot__ = None;
+                let mut proof__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::ShowInactive => {
-                            if show_inactive__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("showInactive"));
+                        GeneratedField::Snapshot => {
+                            if snapshot__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("snapshot"));
                             }
-                            show_inactive__ = Some(map_.next_value()?);
+                            snapshot__ = map_.next_value()?;
+                        }
+                        GeneratedField::Proof => {
+                            if proof__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("proof"));
+                            }
+                            proof__ = map_.next_value()?;
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(ValidatorInfoRequest {
-                    show_inactive: show_inactive__.unwrap_or_default(),
+                Ok(ValidatorSetSnapshotResponse {
+                    snapshot: snapshot__,
+                    proof: proof__,
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.core.component.stake.v1.ValidatorInfoRequest", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.core.component.stake.v1.ValidatorSetSnapshotResponse", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for ValidatorInfoResponse {
+impl serde::Serialize for ValidatorSnapshot {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -3180,30 +7578,37 @@ impl serde::Serialize for ValidatorInfoResponse {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.validator_info.is_some() {
+        if self.info.is_some() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.stake.v1.ValidatorInfoResponse", len)?;
-        if let Some(v) = self.validator_info.as_ref() {
-            struct_ser.serialize_field("validatorInfo", v)?;
+        if self.uptime.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.stake.v1.ValidatorSnapshot", len)?;
+        if let Some(v) = self.info.as_ref() {
+            struct_ser.serialize_field("info", v)?;
+        }
+        if let Some(v) = self.uptime.as_ref() {
+            struct_ser.serialize_field("uptime", v)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for ValidatorInfoResponse {
+impl<'de> serde::Deserialize<'de> for ValidatorSnapshot {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "validator_info",
-            "validatorInfo",
+            "info",
+            "uptime",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            ValidatorInfo,
+            Info,
+            Uptime,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -3226,7 +7631,8 @@ impl<'de> serde::Deserialize<'de> for ValidatorInfoResponse {
                         E: serde::de::Error,
                     {
                         match value {
-                            "validatorInfo" | "validator_info" => Ok(GeneratedField::ValidatorInfo),
+                            "info" => Ok(GeneratedField::Info),
+                            "uptime" => Ok(GeneratedField::Uptime),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -3236,39 +7642,47 @@ impl<'de> serde::Deserialize<'de> for ValidatorInfoResponse {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = ValidatorInfoResponse;
+            type Value = ValidatorSnapshot;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.core.component.stake.v1.ValidatorInfoResponse")
+                formatter.write_str("struct penumbra.core.component.stake.v1.ValidatorSnapshot")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ValidatorInfoResponse, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ValidatorSnapshot, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut validator_info__ = None;
+                let mut info__ = None;
+                let mut uptime__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::ValidatorInfo => {
-                            if validator_info__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("validatorInfo"));
+                        GeneratedField::Info => {
+                            if info__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("info"));
                             }
-                            validator_info__ = map_.next_value()?;
+                            info__ = map_.next_value()?;
+                        }
+                        GeneratedField::Uptime => {
+                            if uptime__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("uptime"));
+                            }
+                            uptime__ = map_.next_value()?;
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(ValidatorInfoResponse {
-                    validator_info: validator_info__,
+                Ok(ValidatorSnapshot {
+                    info: info__,
+                    uptime: uptime__,
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.core.component.stake.v1.ValidatorInfoResponse", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.core.component.stake.v1.ValidatorSnapshot", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for ValidatorList {
+impl serde::Serialize for ValidatorState {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -3276,30 +7690,31 @@ impl serde::Serialize for ValidatorList {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if !self.validator_keys.is_empty() {
+        if self.state != 0 {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.stake.v1.ValidatorList", len)?;
-        if !self.validator_keys.is_empty() {
-            struct_ser.serialize_field("validatorKeys", &self.validator_keys)?;
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.stake.v1.ValidatorState", len)?;
+        if self.state != 0 {
+            let v = validator_state::ValidatorStateEnum::try_from(self.state)
+                .map_err(|_| serde::ser::Error::custom(format!("Invalid variant {}", self.state)))?;
+            struct_ser.serialize_field("state", &v)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for ValidatorList {
+impl<'de> serde::Deserialize<'de> for ValidatorState {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "validator_keys",
-            "validatorKeys",
+            "state",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            ValidatorKeys,
+            State,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -3322,7 +7737,7 @@ impl<'de> serde::Deserialize<'de> for ValidatorList {
                         E: serde::de::Error,
                     {
                         match value {
-                            "validatorKeys" | "validator_keys" => Ok(GeneratedField::ValidatorKeys),
+                            "state" => Ok(GeneratedField::State),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -3332,39 +7747,125 @@ impl<'de> serde::Deserialize<'de> for ValidatorList {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = ValidatorList;
+            type Value = ValidatorState;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.core.component.stake.v1.ValidatorList")
+                formatter.write_str("struct penumbra.core.component.stake.v1.ValidatorState")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ValidatorList, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ValidatorState, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut validator_keys__ = None;
+                let mut state__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::ValidatorKeys => {
-                            if validator_keys__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("validatorKeys"));
+                        GeneratedField::State => {
+                            if state__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("state"));
                             }
-                            validator_keys__ = Some(map_.next_value()?);
+                            state__ = Some(map_.next_value::<validator_state::ValidatorStateEnum>()? as i32);
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(ValidatorList {
-                    validator_keys: validator_keys__.unwrap_or_default(),
+                Ok(ValidatorState {
+                    state: state__.unwrap_or_default(),
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.core.component.stake.v1.ValidatorList", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.core.component.stake.v1.ValidatorState", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for ValidatorPenaltyRequest {
+impl serde::Serialize for validator_state::ValidatorStateEnum {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        let variant = match self {
+            Self::Unspecified => "VALIDATOR_STATE_ENUM_UNSPECIFIED",
+            Self::Defined => "VALIDATOR_STATE_ENUM_DEFINED",
+            Self::Inactive => "VALIDATOR_STATE_ENUM_INACTIVE",
+            Self::Active => "VALIDATOR_STATE_ENUM_ACTIVE",
+            Self::Jailed => "VALIDATOR_STATE_ENUM_JAILED",
+            Self::Tombstoned => "VALIDATOR_STATE_ENUM_TOMBSTONED",
+            Self::Disabled => "VALIDATOR_STATE_ENUM_DISABLED",
+        };
+        serializer.serialize_str(variant)
+    }
+}
+impl<'de> serde::Deserialize<'de> for validator_state::ValidatorStateEnum {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "VALIDATOR_STATE_ENUM_UNSPECIFIED",
+            "VALIDATOR_STATE_ENUM_DEFINED",
+            "VALIDATOR_STATE_ENUM_INACTIVE",
+            "VALIDATOR_STATE_ENUM_ACTIVE",
+            "VALIDATOR_STATE_ENUM_JAILED",
+            "VALIDATOR_STATE_ENUM_TOMBSTONED",
+            "VALIDATOR_STATE_ENUM_DISABLED",
+        ];
+
+        struct GeneratedVisitor;
+
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = validator_state::ValidatorStateEnum;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                write!(formatter, "expected one of: {:?}", &FIELDS)
+            }
+
+            fn visit_i64<E>(self, v: i64) -> std::result::Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                i32::try_from(v)
+                    .ok()
+                    .and_then(|x| x.try_into().ok())
+                    .ok_or_else(|| {
+                        serde::de::Error::invalid_value(serde::de::Unexpected::Signed(v), &self)
+                    })
+            }
+
+            fn visit_u64<E>(self, v: u64) -> std::result::Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                i32::try_from(v)
+                    .ok()
+                    .and_then(|x| x.try_into().ok())
+                    .ok_or_else(|| {
+                        serde::de::Error::invalid_value(serde::de::Unexpected::Unsigned(v), &self)
+                    })
+            }
+
+            fn visit_str<E>(self, value: &str) -> std::result::Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                match value {
+                    "VALIDATOR_STATE_ENUM_UNSPECIFIED" => Ok(validator_state::ValidatorStateEnum::Unspecified),
+                    "VALIDATOR_STATE_ENUM_DEFINED" => Ok(validator_state::ValidatorStateEnum::Defined),
+                    "VALIDATOR_STATE_ENUM_INACTIVE" => Ok(validator_state::ValidatorStateEnum::Inactive),
+                    "VALIDATOR_STATE_ENUM_ACTIVE" => Ok(validator_state::ValidatorStateEnum::Active),
+                    "VALIDATOR_STATE_ENUM_JAILED" => Ok(validator_state::ValidatorStateEnum::Jailed),
+                    "VALIDATOR_STATE_ENUM_TOMBSTONED" => Ok(validator_state::ValidatorStateEnum::Tombstoned),
+                    "VALIDATOR_STATE_ENUM_DISABLED" => Ok(validator_state::ValidatorStateEnum::Disabled),
+                    _ => Err(serde::de::Error::unknown_variant(value, FIELDS)),
+                }
+            }
+        }
+        deserializer.deserialize_any(GeneratedVisitor)
+    }
+}
+impl serde::Serialize for ValidatorStatus {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -3375,28 +7876,32 @@ impl serde::Serialize for ValidatorPenaltyRequest {
         if self.identity_key.is_some() {
             len += 1;
         }
-        if self.start_epoch_index != 0 {
+        if self.state.is_some() {
             len += 1;
         }
-        if self.end_epoch_index != 0 {
+        if self.voting_power.is_some() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.stake.v1.ValidatorPenaltyRequest", len)?;
+        if self.bonding_state.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.stake.v1.ValidatorStatus", len)?;
         if let Some(v) = self.identity_key.as_ref() {
             struct_ser.serialize_field("identityKey", v)?;
         }
-        if self.start_epoch_index != 0 {
-            #[allow(clippy::needless_borrow)]
-            struct_ser.serialize_field("startEpochIndex", ToString::to_string(&self.start_epoch_index).as_str())?;
+        if let Some(v) = self.state.as_ref() {
+            struct_ser.serialize_field("state", v)?;
         }
-        if self.end_epoch_index != 0 {
-            #[allow(clippy::needless_borrow)]
-            struct_ser.serialize_field("endEpochIndex", ToString::to_string(&self.end_epoch_index).as_str())?;
+        if let Some(v) = self.voting_power.as_ref() {
+            struct_ser.serialize_field("votingPower", v)?;
+        }
+        if let Some(v) = self.bonding_state.as_ref() {
+            struct_ser.serialize_field("bondingState", v)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for ValidatorPenaltyRequest {
+impl<'de> serde::Deserialize<'de> for ValidatorStatus {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
@@ -3405,17 +7910,19 @@ impl<'de> serde::Deserialize<'de> for ValidatorPenaltyRequest {
         const FIELDS: &[&str] = &[
             "identity_key",
             "identityKey",
-            "start_epoch_index",
-            "startEpochIndex",
-            "end_epoch_index",
-            "endEpochIndex",
+            "state",
+            "voting_power",
+            "votingPower",
+            "bonding_state",
+            "bondingState",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
             IdentityKey,
-            StartEpochIndex,
-            EndEpochIndex,
+            State,
+            VotingPower,
+            BondingState,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -3439,8 +7946,9 @@ impl<'de> serde::Deserialize<'de> for ValidatorPenaltyRequest {
                     {
                         match value {
                             "identityKey" | "identity_key" => Ok(GeneratedField::IdentityKey),
-                            "startEpochIndex" | "start_epoch_index" => Ok(GeneratedField::StartEpochIndex),
-                            "endEpochIndex" | "end_epoch_index" => Ok(GeneratedField::EndEpochIndex),
+                            "state" => Ok(GeneratedField::State),
+                            "votingPower" | "voting_power" => Ok(GeneratedField::VotingPower),
+                            "bondingState" | "bonding_state" => Ok(GeneratedField::BondingState),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -3450,19 +7958,20 @@ impl<'de> serde::Deserialize<'de> for ValidatorPenaltyRequest {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = ValidatorPenaltyRequest;
+            type Value = ValidatorStatus;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.core.component.stake.v1.ValidatorPenaltyRequest")
+                formatter.write_str("struct penumbra.core.component.stake.v1.ValidatorStatus")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ValidatorPenaltyRequest, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ValidatorStatus, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
                 let mut identity_key__ = None;
-                let mut start_epoch_index__ = None;
-                let mut end_epoch_index__ = None;
+                let mut state__ = None;
+                let mut voting_power__ = None;
+                let mut bonding_state__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
                         GeneratedField::IdentityKey => {
@@ -3471,38 +7980,41 @@ impl<'de> serde::Deserialize<'de> for ValidatorPenaltyRequest {
                             }
                             identity_key__ = map_.next_value()?;
                         }
-                        GeneratedField::StartEpochIndex => {
-                            if start_epoch_index__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("startEpochIndex"));
+                        GeneratedField::State => {
+                            if state__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("state"));
                             }
-                            start_epoch_index__ = 
-                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
-                            ;
+                            state__ = map_.next_value()?;
                         }
-                        GeneratedField::EndEpochIndex => {
-                            if end_epoch_index__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("endEpochIndex"));
+                        GeneratedField::VotingPower => {
+                            if voting_power__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("votingPower"));
                             }
-                            end_epoch_index__ = 
-                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
-                            ;
+                            voting_power__ = map_.next_value()?;
+                        }
+                        GeneratedField::BondingState => {
+                            if bonding_state__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("bondingState"));
+                            }
+                            bonding_state__ = map_.next_value()?;
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(ValidatorPenaltyRequest {
+                Ok(ValidatorStatus {
                     identity_key: identity_key__,
-                    start_epoch_index: start_epoch_index__.unwrap_or_default(),
-                    end_epoch_index: end_epoch_index__.unwrap_or_default(),
+                    state: state__,
+                    voting_power: voting_power__,
+                    bonding_state: bonding_state__,
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.core.component.stake.v1.ValidatorPenaltyRequest", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.core.component.stake.v1.ValidatorStatus", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for ValidatorPenaltyResponse {
+impl serde::Serialize for ValidatorStatusRequest {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -3510,29 +8022,30 @@ impl serde::Serialize for ValidatorPenaltyResponse {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.penalty.is_some() {
+        if self.identity_key.is_some() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.stake.v1.ValidatorPenaltyResponse", len)?;
-        if let Some(v) = self.penalty.as_ref() {
-            struct_ser.serialize_field("penalty", v)?;
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.stake.v1.ValidatorStatusRequest", len)?;
+        if let Some(v) = self.identity_key.as_ref() {
+            struct_ser.serialize_field("identityKey", v)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for ValidatorPenaltyResponse {
+impl<'de> serde::Deserialize<'de> for ValidatorStatusRequest {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "penalty",
+            "identity_key",
+            "identityKey",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            Penalty,
+            IdentityKey,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -3555,7 +8068,7 @@ impl<'de> serde::Deserialize<'de> for ValidatorPenaltyResponse {
                         E: serde::de::Error,
                     {
                         match value {
-                            "penalty" => Ok(GeneratedField::Penalty),
+                            "identityKey" | "identity_key" => Ok(GeneratedField::IdentityKey),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -3565,39 +8078,39 @@ impl<'de> serde::Deserialize<'de> for ValidatorPenaltyResponse {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = ValidatorPenaltyResponse;
+            type Value = ValidatorStatusRequest;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.core.component.stake.v1.ValidatorPenaltyResponse")
+                formatter.write_str("struct penumbra.core.component.stake.v1.ValidatorStatusRequest")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ValidatorPenaltyResponse, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ValidatorStatusRequest, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut penalty__ = None;
+                let mut identity_key__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::Penalty => {
-                            if penalty__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("penalty"));
+                        GeneratedField::IdentityKey => {
+                            if identity_key__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("identityKey"));
                             }
-                            penalty__ = map_.next_value()?;
+                            identity_key__ = map_.next_value()?;
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
-                }
-                Ok(ValidatorPenaltyResponse {
-                    penalty: penalty__,
+                }
+                Ok(ValidatorStatusRequest {
+                    identity_key: identity_key__,
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.core.component.stake.v1.ValidatorPenaltyResponse", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.core.component.stake.v1.ValidatorStatusRequest", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for ValidatorState {
+impl serde::Serialize for ValidatorStatusResponse {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -3605,31 +8118,29 @@ impl serde::Serialize for ValidatorState {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.state != 0 {
+        if self.status.is_some() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.stake.v1.ValidatorState", len)?;
-        if self.state != 0 {
-            let v = validator_state::ValidatorStateEnum::try_from(self.state)
-                .map_err(|_| serde::ser::Error::custom(format!("Invalid variant {}", self.state)))?;
-            struct_ser.serialize_field("state", &v)?;
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.stake.v1.ValidatorStatusResponse", len)?;
+        if let Some(v) = self.status.as_ref() {
+            struct_ser.serialize_field("status", v)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for ValidatorState {
+impl<'de> serde::Deserialize<'de> for ValidatorStatusResponse {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "state",
+            "status",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            State,
+            Status,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -3652,7 +8163,7 @@ impl<'de> serde::Deserialize<'de> for ValidatorState {
                         E: serde::de::Error,
                     {
                         match value {
-                            "state" => Ok(GeneratedField::State),
+                            "status" => Ok(GeneratedField::Status),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -3662,125 +8173,39 @@ impl<'de> serde::Deserialize<'de> for ValidatorState {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = ValidatorState;
+            type Value = ValidatorStatusResponse;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.core.component.stake.v1.ValidatorState")
+                formatter.write_str("struct penumbra.core.component.stake.v1.ValidatorStatusResponse")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ValidatorState, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ValidatorStatusResponse, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut state__ = None;
+                let mut status__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::State => {
-                            if state__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("state"));
+                        GeneratedField::Status => {
+                            if status__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("status"));
                             }
-                            state__ = Some(map_.next_value::<validator_state::ValidatorStateEnum>()? as i32);
+                            status__ = map_.next_value()?;
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(ValidatorState {
-                    state: state__.unwrap_or_default(),
+                Ok(ValidatorStatusResponse {
+                    status: status__,
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.core.component.stake.v1.ValidatorState", FIELDS, GeneratedVisitor)
-    }
-}
-impl serde::Serialize for validator_state::ValidatorStateEnum {
-    #[allow(deprecated)]
-    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
-    where
-        S: serde::Serializer,
-    {
-        let variant = match self {
-            Self::Unspecified => "VALIDATOR_STATE_ENUM_UNSPECIFIED",
-            Self::Defined => "VALIDATOR_STATE_ENUM_DEFINED",
-            Self::Inactive => "VALIDATOR_STATE_ENUM_INACTIVE",
-            Self::Active => "VALIDATOR_STATE_ENUM_ACTIVE",
-            Self::Jailed => "VALIDATOR_STATE_ENUM_JAILED",
-            Self::Tombstoned => "VALIDATOR_STATE_ENUM_TOMBSTONED",
-            Self::Disabled => "VALIDATOR_STATE_ENUM_DISABLED",
-        };
-        serializer.serialize_str(variant)
-    }
-}
-impl<'de> serde::Deserialize<'de> for validator_state::ValidatorStateEnum {
-    #[allow(deprecated)]
-    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
-    where
-        D: serde::Deserializer<'de>,
-    {
-        const FIELDS: &[&str] = &[
-            "VALIDATOR_STATE_ENUM_UNSPECIFIED",
-            "VALIDATOR_STATE_ENUM_DEFINED",
-            "VALIDATOR_STATE_ENUM_INACTIVE",
-            "VALIDATOR_STATE_ENUM_ACTIVE",
-            "VALIDATOR_STATE_ENUM_JAILED",
-            "VALIDATOR_STATE_ENUM_TOMBSTONED",
-            "VALIDATOR_STATE_ENUM_DISABLED",
-        ];
-
-        struct GeneratedVisitor;
-
-        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = validator_state::ValidatorStateEnum;
-
-            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                write!(formatter, "expected one of: {:?}", &FIELDS)
-            }
-
-            fn visit_i64<E>(self, v: i64) -> std::result::Result<Self::Value, E>
-            where
-                E: serde::de::Error,
-            {
-                i32::try_from(v)
-                    .ok()
-                    .and_then(|x| x.try_into().ok())
-                    .ok_or_else(|| {
-                        serde::de::Error::invalid_value(serde::de::Unexpected::Signed(v), &self)
-                    })
-            }
-
-            fn visit_u64<E>(self, v: u64) -> std::result::Result<Self::Value, E>
-            where
-                E: serde::de::Error,
-            {
-                i32::try_from(v)
-                    .ok()
-                    .and_then(|x| x.try_into().ok())
-                    .ok_or_else(|| {
-                        serde::de::Error::invalid_value(serde::de::Unexpected::Unsigned(v), &self)
-                    })
-            }
-
-            fn visit_str<E>(self, value: &str) -> std::result::Result<Self::Value, E>
-            where
-                E: serde::de::Error,
-            {
-                match value {
-                    "VALIDATOR_STATE_ENUM_UNSPECIFIED" => Ok(validator_state::ValidatorStateEnum::Unspecified),
-                    "VALIDATOR_STATE_ENUM_DEFINED" => Ok(validator_state::ValidatorStateEnum::Defined),
-                    "VALIDATOR_STATE_ENUM_INACTIVE" => Ok(validator_state::ValidatorStateEnum::Inactive),
-                    "VALIDATOR_STATE_ENUM_ACTIVE" => Ok(validator_state::ValidatorStateEnum::Active),
-                    "VALIDATOR_STATE_ENUM_JAILED" => Ok(validator_state::ValidatorStateEnum::Jailed),
-                    "VALIDATOR_STATE_ENUM_TOMBSTONED" => Ok(validator_state::ValidatorStateEnum::Tombstoned),
-                    "VALIDATOR_STATE_ENUM_DISABLED" => Ok(validator_state::ValidatorStateEnum::Disabled),
-                    _ => Err(serde::de::Error::unknown_variant(value, FIELDS)),
-                }
-            }
-        }
-        deserializer.deserialize_any(GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.core.component.stake.v1.ValidatorStatusResponse", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for ValidatorStatus {
+impl serde::Serialize for ValidatorTombstone {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -3791,32 +8216,52 @@ impl serde::Serialize for ValidatorStatus {
         if self.identity_key.is_some() {
             len += 1;
         }
+        if !self.name.is_empty() {
+            len += 1;
+        }
+        if self.sequence_number != 0 {
+            len += 1;
+        }
         if self.state.is_some() {
             len += 1;
         }
-        if self.voting_power.is_some() {
+        if self.rate_data.is_some() {
             len += 1;
         }
-        if self.bonding_state.is_some() {
+        if self.inactive_since_epoch_index != 0 {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.stake.v1.ValidatorStatus", len)?;
+        if self.pruned_epoch_index != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.stake.v1.ValidatorTombstone", len)?;
         if let Some(v) = self.identity_key.as_ref() {
             struct_ser.serialize_field("identityKey", v)?;
         }
+        if !self.name.is_empty() {
+            struct_ser.serialize_field("name", &self.name)?;
+        }
+        if self.sequence_number != 0 {
+            struct_ser.serialize_field("sequenceNumber", &self.sequence_number)?;
+        }
         if let Some(v) = self.state.as_ref() {
             struct_ser.serialize_field("state", v)?;
         }
-        if let Some(v) = self.voting_power.as_ref() {
-            struct_ser.serialize_field("votingPower", v)?;
+        if let Some(v) = self.rate_data.as_ref() {
+            struct_ser.serialize_field("rateData", v)?;
         }
-        if let Some(v) = self.bonding_state.as_ref() {
-            struct_ser.serialize_field("bondingState", v)?;
+        if self.inactive_since_epoch_index != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("inactiveSinceEpochIndex", ToString::to_string(&self.inactive_since_epoch_index).as_str())?;
+        }
+        if self.pruned_epoch_index != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("prunedEpochIndex", ToString::to_string(&self.pruned_epoch_index).as_str())?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for ValidatorStatus {
+impl<'de> serde::Deserialize<'de> for ValidatorTombstone {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
@@ -3825,19 +8270,27 @@ impl<'de> serde::Deserialize<'de> for ValidatorStatus {
         const FIELDS: &[&str] = &[
             "identity_key",
             "identityKey",
+            "name",
+            "sequence_number",
+            "sequenceNumber",
             "state",
-            "voting_power",
-            "votingPower",
-            "bonding_state",
-            "bondingState",
+            "rate_data",
+            "rateData",
+            "inactive_since_epoch_index",
+            "inactiveSinceEpochIndex",
+            "pruned_epoch_index",
+            "prunedEpochIndex",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
             IdentityKey,
+            Name,
+            SequenceNumber,
             State,
-            VotingPower,
-            BondingState,
+            RateData,
+            InactiveSinceEpochIndex,
+            PrunedEpochIndex,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -3861,9 +8314,12 @@ impl<'de> serde::Deserialize<'de> for ValidatorStatus {
                     {
                         match value {
                             "identityKey" | "identity_key" => Ok(GeneratedField::IdentityKey),
+                            "name" => Ok(GeneratedField::Name),
+                            "sequenceNumber" | "sequence_number" => Ok(GeneratedField::SequenceNumber),
                             "state" => Ok(GeneratedField::State),
-                            "votingPower" | "voting_power" => Ok(GeneratedField::VotingPower),
-                            "bondingState" | "bonding_state" => Ok(GeneratedField::BondingState),
+                            "rateData" | "rate_data" => Ok(GeneratedField::RateData),
+                            "inactiveSinceEpochIndex" | "inactive_since_epoch_index" => Ok(GeneratedField::InactiveSinceEpochIndex),
+                            "prunedEpochIndex" | "pruned_epoch_index" => Ok(GeneratedField::PrunedEpochIndex),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -3873,20 +8329,23 @@ impl<'de> serde::Deserialize<'de> for ValidatorStatus {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = ValidatorStatus;
+            type Value = ValidatorTombstone;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.core.component.stake.v1.ValidatorStatus")
+                formatter.write_str("struct penumbra.core.component.stake.v1.ValidatorTombstone")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ValidatorStatus, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ValidatorTombstone, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
                 let mut identity_key__ = None;
+                let mut name__ = None;
+                let mut sequence_number__ = None;
                 let mut state__ = None;
-                let mut voting_power__ = None;
-                let mut bonding_state__ = None;
+                let mut rate_data__ = None;
+                let mut inactive_since_epoch_index__ = None;
+                let mut pruned_epoch_index__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
                         GeneratedField::IdentityKey => {
@@ -3895,41 +8354,68 @@ impl<'de> serde::Deserialize<'de> for ValidatorStatus {
                             }
                             identity_key__ = map_.next_value()?;
                         }
+                        GeneratedField::Name => {
+                            if name__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("name"));
+                            }
+                            name__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::SequenceNumber => {
+                            if sequence_number__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("sequenceNumber"));
+                            }
+                            sequence_number__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
                         GeneratedField::State => {
                             if state__.is_some() {
                                 return Err(serde::de::Error::duplicate_field("state"));
                             }
                             state__ = map_.next_value()?;
                         }
-                        GeneratedField::VotingPower => {
-                            if voting_power__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("votingPower"));
+                        GeneratedField::RateData => {
+                            if rate_data__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("rateData"));
                             }
-                            voting_power__ = map_.next_value()?;
+                            rate_data__ = map_.next_value()?;
                         }
-                        GeneratedField::BondingState => {
-                            if bonding_state__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("bondingState"));
+                        GeneratedField::InactiveSinceEpochIndex => {
+                            if inactive_since_epoch_index__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("inactiveSinceEpochIndex"));
                             }
-                            bonding_state__ = map_.next_value()?;
+                            inactive_since_epoch_index__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::PrunedEpochIndex => {
+                            if pruned_epoch_index__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("prunedEpochIndex"));
+                            }
+                            pruned_epoch_index__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(ValidatorStatus {
+                Ok(ValidatorTombstone {
                     identity_key: identity_key__,
+                    name: name__.unwrap_or_default(),
+                    sequence_number: sequence_number__.unwrap_or_default(),
                     state: state__,
-                    voting_power: voting_power__,
-                    bonding_state: bonding_state__,
+                    rate_data: rate_data__,
+                    inactive_since_epoch_index: inactive_since_epoch_index__.unwrap_or_default(),
+                    pruned_epoch_index: pruned_epoch_index__.unwrap_or_default(),
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.core.component.stake.v1.ValidatorStatus", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.core.component.stake.v1.ValidatorTombstone", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for ValidatorStatusRequest {
+impl serde::Serialize for ValidatorTombstoneRequest {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -3940,14 +8426,14 @@ impl serde::Serialize for ValidatorStatusRequest {
         if self.identity_key.is_some() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.stake.v1.ValidatorStatusRequest", len)?;
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.stake.v1.ValidatorTombstoneRequest", len)?;
         if let Some(v) = self.identity_key.as_ref() {
             struct_ser.serialize_field("identityKey", v)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for ValidatorStatusRequest {
+impl<'de> serde::Deserialize<'de> for ValidatorTombstoneRequest {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
@@ -3993,13 +8479,13 @@ impl<'de> serde::Deserialize<'de> for ValidatorStatusRequest {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = ValidatorStatusRequest;
+            type Value = ValidatorTombstoneRequest;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.core.component.stake.v1.ValidatorStatusRequest")
+                formatter.write_str("struct penumbra.core.component.stake.v1.ValidatorTombstoneRequest")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ValidatorStatusRequest, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ValidatorTombstoneRequest, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
@@ -4017,15 +8503,15 @@ impl<'de> serde::Deserialize<'de> for ValidatorStatusRequest {
                         }
                     }
                 }
-                Ok(ValidatorStatusRequest {
+                Ok(ValidatorTombstoneRequest {
                     identity_key: identity_key__,
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.core.component.stake.v1.ValidatorStatusRequest", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.core.component.stake.v1.ValidatorTombstoneRequest", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for ValidatorStatusResponse {
+impl serde::Serialize for ValidatorTombstoneResponse {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -4033,29 +8519,29 @@ impl serde::Serialize for ValidatorStatusResponse {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.status.is_some() {
+        if self.tombstone.is_some() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.stake.v1.ValidatorStatusResponse", len)?;
-        if let Some(v) = self.status.as_ref() {
-            struct_ser.serialize_field("status", v)?;
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.stake.v1.ValidatorTombstoneResponse", len)?;
+        if let Some(v) = self.tombstone.as_ref() {
+            struct_ser.serialize_field("tombstone", v)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for ValidatorStatusResponse {
+impl<'de> serde::Deserialize<'de> for ValidatorTombstoneResponse {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "status",
+            "tombstone",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            Status,
+            Tombstone,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -4078,7 +8564,7 @@ impl<'de> serde::Deserialize<'de> for ValidatorStatusResponse {
                         E: serde::de::Error,
                     {
                         match value {
-                            "status" => Ok(GeneratedField::Status),
+                            "tombstone" => Ok(GeneratedField::Tombstone),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -4088,36 +8574,36 @@ impl<'de> serde::Deserialize<'de> for ValidatorStatusResponse {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = ValidatorStatusResponse;
+            type Value = ValidatorTombstoneResponse;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.core.component.stake.v1.ValidatorStatusResponse")
+                formatter.write_str("struct penumbra.core.component.stake.v1.ValidatorTombstoneResponse")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ValidatorStatusResponse, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ValidatorTombstoneResponse, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut status__ = None;
+                let mut tombstone__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::Status => {
-                            if status__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("status"));
+                        GeneratedField::Tombstone => {
+                            if tombstone__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("tombstone"));
                             }
-                            status__ = map_.next_value()?;
+                            tombstone__ = map_.next_value()?;
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(ValidatorStatusResponse {
-                    status: status__,
+                Ok(ValidatorTombstoneResponse {
+                    tombstone: tombstone__,
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.core.component.stake.v1.ValidatorStatusResponse", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.core.component.stake.v1.ValidatorTombstoneResponse", FIELDS, GeneratedVisitor)
     }
 }
 impl serde::Serialize for ZkUndelegateClaimProof {
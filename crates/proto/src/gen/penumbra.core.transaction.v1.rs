@@ -94,7 +94,7 @@ impl ::prost::Name for DetectionData {
 pub struct Action {
     #[prost(
         oneof = "action::Action",
-        tags = "1, 2, 3, 4, 16, 17, 18, 19, 20, 21, 22, 30, 31, 32, 34, 40, 41, 42, 50, 51, 52, 200"
+        tags = "1, 2, 3, 4, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 30, 31, 32, 34, 35, 39, 45, 36, 37, 38, 40, 41, 42, 43, 50, 51, 52, 200"
     )]
     pub action: ::core::option::Option<action::Action>,
 }
@@ -133,6 +133,14 @@ pub mod action {
         ProposalDepositClaim(
             super::super::super::component::governance::v1::ProposalDepositClaim,
         ),
+        #[prost(message, tag = "23")]
+        BallotKeyDeal(super::super::super::component::governance::v1::BallotKeyDeal),
+        #[prost(message, tag = "24")]
+        BallotKeyReveal(super::super::super::component::governance::v1::BallotKeyReveal),
+        #[prost(message, tag = "25")]
+        BallotKeyComplaint(
+            super::super::super::component::governance::v1::BallotKeyComplaint,
+        ),
         /// Positions
         #[prost(message, tag = "30")]
         PositionOpen(super::super::super::component::dex::v1::PositionOpen),
@@ -144,6 +152,27 @@ pub mod action {
         PositionRewardClaim(
             super::super::super::component::dex::v1::PositionRewardClaim,
         ),
+        #[prost(message, tag = "35")]
+        PositionUpdateBatch(
+            super::super::super::component::dex::v1::PositionUpdateBatch,
+        ),
+        #[prost(message, tag = "39")]
+        PositionRewrite(super::super::super::component::dex::v1::PositionRewrite),
+        #[prost(message, tag = "45")]
+        PositionOpenBatch(super::super::super::component::dex::v1::PositionOpenBatch),
+        /// Dutch auctions
+        #[prost(message, tag = "36")]
+        ActionDutchAuctionSchedule(
+            super::super::super::component::dex::v1::ActionDutchAuctionSchedule,
+        ),
+        #[prost(message, tag = "37")]
+        ActionDutchAuctionEnd(
+            super::super::super::component::dex::v1::ActionDutchAuctionEnd,
+        ),
+        #[prost(message, tag = "38")]
+        ActionDutchAuctionWithdraw(
+            super::super::super::component::dex::v1::ActionDutchAuctionWithdraw,
+        ),
         /// (un)delegation
         #[prost(message, tag = "40")]
         Delegate(super::super::super::component::stake::v1::Delegate),
@@ -151,6 +180,8 @@ pub mod action {
         Undelegate(super::super::super::component::stake::v1::Undelegate),
         #[prost(message, tag = "42")]
         UndelegateClaim(super::super::super::component::stake::v1::UndelegateClaim),
+        #[prost(message, tag = "43")]
+        Redelegate(super::super::super::component::stake::v1::Redelegate),
         /// Community Pool
         #[prost(message, tag = "50")]
         CommunityPoolSpend(
@@ -322,7 +353,7 @@ impl ::prost::Name for TransactionBodyView {
 pub struct ActionView {
     #[prost(
         oneof = "action_view::ActionView",
-        tags = "1, 2, 3, 4, 16, 17, 18, 19, 20, 21, 22, 30, 31, 32, 34, 41, 42, 50, 51, 52, 43, 200"
+        tags = "1, 2, 3, 4, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 30, 31, 32, 34, 35, 39, 45, 36, 37, 38, 41, 42, 50, 51, 52, 43, 44, 200"
     )]
     pub action_view: ::core::option::Option<action_view::ActionView>,
 }
@@ -362,6 +393,14 @@ pub mod action_view {
         ProposalDepositClaim(
             super::super::super::component::governance::v1::ProposalDepositClaim,
         ),
+        #[prost(message, tag = "23")]
+        BallotKeyDeal(super::super::super::component::governance::v1::BallotKeyDeal),
+        #[prost(message, tag = "24")]
+        BallotKeyReveal(super::super::super::component::governance::v1::BallotKeyReveal),
+        #[prost(message, tag = "25")]
+        BallotKeyComplaint(
+            super::super::super::component::governance::v1::BallotKeyComplaint,
+        ),
         #[prost(message, tag = "30")]
         PositionOpen(super::super::super::component::dex::v1::PositionOpen),
         #[prost(message, tag = "31")]
@@ -372,6 +411,27 @@ pub mod action_view {
         PositionRewardClaim(
             super::super::super::component::dex::v1::PositionRewardClaim,
         ),
+        #[prost(message, tag = "35")]
+        PositionUpdateBatch(
+            super::super::super::component::dex::v1::PositionUpdateBatch,
+        ),
+        #[prost(message, tag = "39")]
+        PositionRewrite(super::super::super::component::dex::v1::PositionRewrite),
+        #[prost(message, tag = "45")]
+        PositionOpenBatch(super::super::super::component::dex::v1::PositionOpenBatch),
+        /// Dutch auctions
+        #[prost(message, tag = "36")]
+        ActionDutchAuctionSchedule(
+            super::super::super::component::dex::v1::ActionDutchAuctionSchedule,
+        ),
+        #[prost(message, tag = "37")]
+        ActionDutchAuctionEnd(
+            super::super::super::component::dex::v1::ActionDutchAuctionEnd,
+        ),
+        #[prost(message, tag = "38")]
+        ActionDutchAuctionWithdraw(
+            super::super::super::component::dex::v1::ActionDutchAuctionWithdraw,
+        ),
         #[prost(message, tag = "41")]
         Delegate(super::super::super::component::stake::v1::Delegate),
         #[prost(message, tag = "42")]
@@ -394,6 +454,8 @@ pub mod action_view {
         /// of the transaction. is that fine?
         #[prost(message, tag = "43")]
         UndelegateClaim(super::super::super::component::stake::v1::UndelegateClaim),
+        #[prost(message, tag = "44")]
+        Redelegate(super::super::super::component::stake::v1::Redelegate),
         #[prost(message, tag = "200")]
         Ics20Withdrawal(super::super::super::component::ibc::v1::Ics20Withdrawal),
     }
@@ -503,7 +565,7 @@ impl ::prost::Name for DetectionDataPlan {
 pub struct ActionPlan {
     #[prost(
         oneof = "action_plan::Action",
-        tags = "1, 2, 3, 4, 16, 17, 18, 19, 20, 21, 22, 200, 30, 31, 32, 34, 40, 41, 42, 50, 51, 52"
+        tags = "1, 2, 3, 4, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 200, 30, 31, 32, 34, 35, 39, 45, 36, 37, 38, 40, 41, 42, 43, 50, 51, 52"
     )]
     pub action: ::core::option::Option<action_plan::Action>,
 }
@@ -543,6 +605,14 @@ pub mod action_plan {
         ProposalDepositClaim(
             super::super::super::component::governance::v1::ProposalDepositClaim,
         ),
+        #[prost(message, tag = "23")]
+        BallotKeyDeal(super::super::super::component::governance::v1::BallotKeyDeal),
+        #[prost(message, tag = "24")]
+        BallotKeyReveal(super::super::super::component::governance::v1::BallotKeyReveal),
+        #[prost(message, tag = "25")]
+        BallotKeyComplaint(
+            super::super::super::component::governance::v1::BallotKeyComplaint,
+        ),
         #[prost(message, tag = "200")]
         Ics20Withdrawal(super::super::super::component::ibc::v1::Ics20Withdrawal),
         #[prost(message, tag = "30")]
@@ -556,6 +626,29 @@ pub mod action_plan {
         PositionRewardClaim(
             super::super::super::component::dex::v1::PositionRewardClaimPlan,
         ),
+        #[prost(message, tag = "35")]
+        PositionUpdateBatch(
+            super::super::super::component::dex::v1::PositionUpdateBatchPlan,
+        ),
+        #[prost(message, tag = "39")]
+        PositionRewrite(super::super::super::component::dex::v1::PositionRewritePlan),
+        /// This is just a message relayed to the chain.
+        #[prost(message, tag = "45")]
+        PositionOpenBatch(super::super::super::component::dex::v1::PositionOpenBatch),
+        /// Dutch auctions
+        #[prost(message, tag = "36")]
+        ActionDutchAuctionSchedule(
+            super::super::super::component::dex::v1::ActionDutchAuctionSchedule,
+        ),
+        #[prost(message, tag = "37")]
+        ActionDutchAuctionEnd(
+            super::super::super::component::dex::v1::ActionDutchAuctionEnd,
+        ),
+        /// Withdrawing the auction's reserves requires knowing their value, so it has a Plan type.
+        #[prost(message, tag = "38")]
+        ActionDutchAuctionWithdraw(
+            super::super::super::component::dex::v1::ActionDutchAuctionWithdrawPlan,
+        ),
         /// We don't need any extra information (yet) to understand delegations,
         /// because we don't yet use flow encryption.
         #[prost(message, tag = "40")]
@@ -566,6 +659,9 @@ pub mod action_plan {
         Undelegate(super::super::super::component::stake::v1::Undelegate),
         #[prost(message, tag = "42")]
         UndelegateClaim(super::super::super::component::stake::v1::UndelegateClaimPlan),
+        /// Redelegations need no extra information either.
+        #[prost(message, tag = "43")]
+        Redelegate(super::super::super::component::stake::v1::Redelegate),
         /// Community Pool
         #[prost(message, tag = "50")]
         CommunityPoolSpend(
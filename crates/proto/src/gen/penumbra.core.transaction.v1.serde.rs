@@ -45,6 +45,15 @@ impl serde::Serialize for Action {
                 action::Action::ProposalDepositClaim(v) => {
                     struct_ser.serialize_field("proposalDepositClaim", v)?;
                 }
+                action::Action::BallotKeyDeal(v) => {
+                    struct_ser.serialize_field("ballotKeyDeal", v)?;
+                }
+                action::Action::BallotKeyReveal(v) => {
+                    struct_ser.serialize_field("ballotKeyReveal", v)?;
+                }
+                action::Action::BallotKeyComplaint(v) => {
+                    struct_ser.serialize_field("ballotKeyComplaint", v)?;
+                }
                 action::Action::PositionOpen(v) => {
                     struct_ser.serialize_field("positionOpen", v)?;
                 }
@@ -57,6 +66,24 @@ impl serde::Serialize for Action {
                 action::Action::PositionRewardClaim(v) => {
                     struct_ser.serialize_field("positionRewardClaim", v)?;
                 }
+                action::Action::PositionUpdateBatch(v) => {
+                    struct_ser.serialize_field("positionUpdateBatch", v)?;
+                }
+                action::Action::PositionRewrite(v) => {
+                    struct_ser.serialize_field("positionRewrite", v)?;
+                }
+                action::Action::PositionOpenBatch(v) => {
+                    struct_ser.serialize_field("positionOpenBatch", v)?;
+                }
+                action::Action::ActionDutchAuctionSchedule(v) => {
+                    struct_ser.serialize_field("actionDutchAuctionSchedule", v)?;
+                }
+                action::Action::ActionDutchAuctionEnd(v) => {
+                    struct_ser.serialize_field("actionDutchAuctionEnd", v)?;
+                }
+                action::Action::ActionDutchAuctionWithdraw(v) => {
+                    struct_ser.serialize_field("actionDutchAuctionWithdraw", v)?;
+                }
                 action::Action::Delegate(v) => {
                     struct_ser.serialize_field("delegate", v)?;
                 }
@@ -66,6 +93,9 @@ impl serde::Serialize for Action {
                 action::Action::UndelegateClaim(v) => {
                     struct_ser.serialize_field("undelegateClaim", v)?;
                 }
+                action::Action::Redelegate(v) => {
+                    struct_ser.serialize_field("redelegate", v)?;
+                }
                 action::Action::CommunityPoolSpend(v) => {
                     struct_ser.serialize_field("communityPoolSpend", v)?;
                 }
@@ -109,6 +139,12 @@ impl<'de> serde::Deserialize<'de> for Action {
             "delegatorVote",
             "proposal_deposit_claim",
             "proposalDepositClaim",
+            "ballot_key_deal",
+            "ballotKeyDeal",
+            "ballot_key_reveal",
+            "ballotKeyReveal",
+            "ballot_key_complaint",
+            "ballotKeyComplaint",
             "position_open",
             "positionOpen",
             "position_close",
@@ -117,10 +153,23 @@ impl<'de> serde::Deserialize<'de> for Action {
             "positionWithdraw",
             "position_reward_claim",
             "positionRewardClaim",
+            "position_update_batch",
+            "positionUpdateBatch",
+            "position_rewrite",
+            "positionRewrite",
+            "position_open_batch",
+            "positionOpenBatch",
+            "action_dutch_auction_schedule",
+            "actionDutchAuctionSchedule",
+            "action_dutch_auction_end",
+            "actionDutchAuctionEnd",
+            "action_dutch_auction_withdraw",
+            "actionDutchAuctionWithdraw",
             "delegate",
             "undelegate",
             "undelegate_claim",
             "undelegateClaim",
+            "redelegate",
             "community_pool_spend",
             "communityPoolSpend",
             "community_pool_output",
@@ -144,13 +193,23 @@ impl<'de> serde::Deserialize<'de> for Action {
             ValidatorVote,
             DelegatorVote,
             ProposalDepositClaim,
+            BallotKeyDeal,
+            BallotKeyReveal,
+            BallotKeyComplaint,
             PositionOpen,
             PositionClose,
             PositionWithdraw,
             PositionRewardClaim,
+            PositionUpdateBatch,
+            PositionRewrite,
+            PositionOpenBatch,
+            ActionDutchAuctionSchedule,
+            ActionDutchAuctionEnd,
+            ActionDutchAuctionWithdraw,
             Delegate,
             Undelegate,
             UndelegateClaim,
+            Redelegate,
             CommunityPoolSpend,
             CommunityPoolOutput,
             CommunityPoolDeposit,
@@ -188,13 +247,23 @@ impl<'de> serde::Deserialize<'de> for Action {
                             "validatorVote" | "validator_vote" => Ok(GeneratedField::ValidatorVote),
                             "delegatorVote" | "delegator_vote" => Ok(GeneratedField::DelegatorVote),
                             "proposalDepositClaim" | "proposal_deposit_claim" => Ok(GeneratedField::ProposalDepositClaim),
+                            "ballotKeyDeal" | "ballot_key_deal" => Ok(GeneratedField::BallotKeyDeal),
+                            "ballotKeyReveal" | "ballot_key_reveal" => Ok(GeneratedField::BallotKeyReveal),
+                            "ballotKeyComplaint" | "ballot_key_complaint" => Ok(GeneratedField::BallotKeyComplaint),
                             "positionOpen" | "position_open" => Ok(GeneratedField::PositionOpen),
                             "positionClose" | "position_close" => Ok(GeneratedField::PositionClose),
                             "positionWithdraw" | "position_withdraw" => Ok(GeneratedField::PositionWithdraw),
                             "positionRewardClaim" | "position_reward_claim" => Ok(GeneratedField::PositionRewardClaim),
+                            "positionUpdateBatch" | "position_update_batch" => Ok(GeneratedField::PositionUpdateBatch),
+                            "positionRewrite" | "position_rewrite" => Ok(GeneratedField::PositionRewrite),
+                            "positionOpenBatch" | "position_open_batch" => Ok(GeneratedField::PositionOpenBatch),
+                            "actionDutchAuctionSchedule" | "action_dutch_auction_schedule" => Ok(GeneratedField::ActionDutchAuctionSchedule),
+                            "actionDutchAuctionEnd" | "action_dutch_auction_end" => Ok(GeneratedField::ActionDutchAuctionEnd),
+                            "actionDutchAuctionWithdraw" | "action_dutch_auction_withdraw" => Ok(GeneratedField::ActionDutchAuctionWithdraw),
                             "delegate" => Ok(GeneratedField::Delegate),
                             "undelegate" => Ok(GeneratedField::Undelegate),
                             "undelegateClaim" | "undelegate_claim" => Ok(GeneratedField::UndelegateClaim),
+                            "redelegate" => Ok(GeneratedField::Redelegate),
                             "communityPoolSpend" | "community_pool_spend" => Ok(GeneratedField::CommunityPoolSpend),
                             "communityPoolOutput" | "community_pool_output" => Ok(GeneratedField::CommunityPoolOutput),
                             "communityPoolDeposit" | "community_pool_deposit" => Ok(GeneratedField::CommunityPoolDeposit),
@@ -296,6 +365,27 @@ impl<'de> serde::Deserialize<'de> for Action {
                                 return Err(serde::de::Error::duplicate_field("proposalDepositClaim"));
                             }
                             action__ = map_.next_value::<::std::option::Option<_>>()?.map(action::Action::ProposalDepositClaim)
+;
+                        }
+                        GeneratedField::BallotKeyDeal => {
+                            if action__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("ballotKeyDeal"));
+                            }
+                            action__ = map_.next_value::<::std::option::Option<_>>()?.map(action::Action::BallotKeyDeal)
+;
+                        }
+                        GeneratedField::BallotKeyReveal => {
+                            if action__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("ballotKeyReveal"));
+                            }
+                            action__ = map_.next_value::<::std::option::Option<_>>()?.map(action::Action::BallotKeyReveal)
+;
+                        }
+                        GeneratedField::BallotKeyComplaint => {
+                            if action__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("ballotKeyComplaint"));
+                            }
+                            action__ = map_.next_value::<::std::option::Option<_>>()?.map(action::Action::BallotKeyComplaint)
 ;
                         }
                         GeneratedField::PositionOpen => {
@@ -324,6 +414,48 @@ impl<'de> serde::Deserialize<'de> for Action {
                                 return Err(serde::de::Error::duplicate_field("positionRewardClaim"));
                             }
                             action__ = map_.next_value::<::std::option::Option<_>>()?.map(action::Action::PositionRewardClaim)
+;
+                        }
+                        GeneratedField::PositionUpdateBatch => {
+                            if action__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("positionUpdateBatch"));
+                            }
+                            action__ = map_.next_value::<::std::option::Option<_>>()?.map(action::Action::PositionUpdateBatch)
+;
+                        }
+                        GeneratedField::PositionRewrite => {
+                            if action__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("positionRewrite"));
+                            }
+                            action__ = map_.next_value::<::std::option::Option<_>>()?.map(action::Action::PositionRewrite)
+;
+                        }
+                        GeneratedField::PositionOpenBatch => {
+                            if action__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("positionOpenBatch"));
+                            }
+                            action__ = map_.next_value::<::std::option::Option<_>>()?.map(action::Action::PositionOpenBatch)
+;
+                        }
+                        GeneratedField::ActionDutchAuctionSchedule => {
+                            if action__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("actionDutchAuctionSchedule"));
+                            }
+                            action__ = map_.next_value::<::std::option::Option<_>>()?.map(action::Action::ActionDutchAuctionSchedule)
+;
+                        }
+                        GeneratedField::ActionDutchAuctionEnd => {
+                            if action__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("actionDutchAuctionEnd"));
+                            }
+                            action__ = map_.next_value::<::std::option::Option<_>>()?.map(action::Action::ActionDutchAuctionEnd)
+;
+                        }
+                        GeneratedField::ActionDutchAuctionWithdraw => {
+                            if action__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("actionDutchAuctionWithdraw"));
+                            }
+                            action__ = map_.next_value::<::std::option::Option<_>>()?.map(action::Action::ActionDutchAuctionWithdraw)
 ;
                         }
                         GeneratedField::Delegate => {
@@ -345,6 +477,13 @@ impl<'de> serde::Deserialize<'de> for Action {
                                 return Err(serde::de::Error::duplicate_field("undelegateClaim"));
                             }
                             action__ = map_.next_value::<::std::option::Option<_>>()?.map(action::Action::UndelegateClaim)
+;
+                        }
+                        GeneratedField::Redelegate => {
+                            if action__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("redelegate"));
+                            }
+                            action__ = map_.next_value::<::std::option::Option<_>>()?.map(action::Action::Redelegate)
 ;
                         }
                         GeneratedField::CommunityPoolSpend => {
@@ -435,6 +574,15 @@ impl serde::Serialize for ActionPlan {
                 action_plan::Action::ProposalDepositClaim(v) => {
                     struct_ser.serialize_field("proposalDepositClaim", v)?;
                 }
+                action_plan::Action::BallotKeyDeal(v) => {
+                    struct_ser.serialize_field("ballotKeyDeal", v)?;
+                }
+                action_plan::Action::BallotKeyReveal(v) => {
+                    struct_ser.serialize_field("ballotKeyReveal", v)?;
+                }
+                action_plan::Action::BallotKeyComplaint(v) => {
+                    struct_ser.serialize_field("ballotKeyComplaint", v)?;
+                }
                 action_plan::Action::Ics20Withdrawal(v) => {
                     struct_ser.serialize_field("ics20Withdrawal", v)?;
                 }
@@ -450,6 +598,24 @@ impl serde::Serialize for ActionPlan {
                 action_plan::Action::PositionRewardClaim(v) => {
                     struct_ser.serialize_field("positionRewardClaim", v)?;
                 }
+                action_plan::Action::PositionUpdateBatch(v) => {
+                    struct_ser.serialize_field("positionUpdateBatch", v)?;
+                }
+                action_plan::Action::PositionRewrite(v) => {
+                    struct_ser.serialize_field("positionRewrite", v)?;
+                }
+                action_plan::Action::PositionOpenBatch(v) => {
+                    struct_ser.serialize_field("positionOpenBatch", v)?;
+                }
+                action_plan::Action::ActionDutchAuctionSchedule(v) => {
+                    struct_ser.serialize_field("actionDutchAuctionSchedule", v)?;
+                }
+                action_plan::Action::ActionDutchAuctionEnd(v) => {
+                    struct_ser.serialize_field("actionDutchAuctionEnd", v)?;
+                }
+                action_plan::Action::ActionDutchAuctionWithdraw(v) => {
+                    struct_ser.serialize_field("actionDutchAuctionWithdraw", v)?;
+                }
                 action_plan::Action::Delegate(v) => {
                     struct_ser.serialize_field("delegate", v)?;
                 }
@@ -459,6 +625,9 @@ impl serde::Serialize for ActionPlan {
                 action_plan::Action::UndelegateClaim(v) => {
                     struct_ser.serialize_field("undelegateClaim", v)?;
                 }
+                action_plan::Action::Redelegate(v) => {
+                    struct_ser.serialize_field("redelegate", v)?;
+                }
                 action_plan::Action::CommunityPoolSpend(v) => {
                     struct_ser.serialize_field("communityPoolSpend", v)?;
                 }
@@ -499,6 +668,12 @@ impl<'de> serde::Deserialize<'de> for ActionPlan {
             "delegatorVote",
             "proposal_deposit_claim",
             "proposalDepositClaim",
+            "ballot_key_deal",
+            "ballotKeyDeal",
+            "ballot_key_reveal",
+            "ballotKeyReveal",
+            "ballot_key_complaint",
+            "ballotKeyComplaint",
             "ics20_withdrawal",
             "ics20Withdrawal",
             "position_open",
@@ -509,10 +684,23 @@ impl<'de> serde::Deserialize<'de> for ActionPlan {
             "positionWithdraw",
             "position_reward_claim",
             "positionRewardClaim",
+            "position_update_batch",
+            "positionUpdateBatch",
+            "position_rewrite",
+            "positionRewrite",
+            "position_open_batch",
+            "positionOpenBatch",
+            "action_dutch_auction_schedule",
+            "actionDutchAuctionSchedule",
+            "action_dutch_auction_end",
+            "actionDutchAuctionEnd",
+            "action_dutch_auction_withdraw",
+            "actionDutchAuctionWithdraw",
             "delegate",
             "undelegate",
             "undelegate_claim",
             "undelegateClaim",
+            "redelegate",
             "community_pool_spend",
             "communityPoolSpend",
             "community_pool_output",
@@ -534,14 +722,24 @@ impl<'de> serde::Deserialize<'de> for ActionPlan {
             ValidatorVote,
             DelegatorVote,
             ProposalDepositClaim,
+            BallotKeyDeal,
+            BallotKeyReveal,
+            BallotKeyComplaint,
             Ics20Withdrawal,
             PositionOpen,
             PositionClose,
             PositionWithdraw,
             PositionRewardClaim,
+            PositionUpdateBatch,
+            PositionRewrite,
+            PositionOpenBatch,
+            ActionDutchAuctionSchedule,
+            ActionDutchAuctionEnd,
+            ActionDutchAuctionWithdraw,
             Delegate,
             Undelegate,
             UndelegateClaim,
+            Redelegate,
             CommunityPoolSpend,
             CommunityPoolOutput,
             CommunityPoolDeposit,
@@ -578,14 +776,24 @@ impl<'de> serde::Deserialize<'de> for ActionPlan {
                             "validatorVote" | "validator_vote" => Ok(GeneratedField::ValidatorVote),
                             "delegatorVote" | "delegator_vote" => Ok(GeneratedField::DelegatorVote),
                             "proposalDepositClaim" | "proposal_deposit_claim" => Ok(GeneratedField::ProposalDepositClaim),
+                            "ballotKeyDeal" | "ballot_key_deal" => Ok(GeneratedField::BallotKeyDeal),
+                            "ballotKeyReveal" | "ballot_key_reveal" => Ok(GeneratedField::BallotKeyReveal),
+                            "ballotKeyComplaint" | "ballot_key_complaint" => Ok(GeneratedField::BallotKeyComplaint),
                             "ics20Withdrawal" | "ics20_withdrawal" => Ok(GeneratedField::Ics20Withdrawal),
                             "positionOpen" | "position_open" => Ok(GeneratedField::PositionOpen),
                             "positionClose" | "position_close" => Ok(GeneratedField::PositionClose),
                             "positionWithdraw" | "position_withdraw" => Ok(GeneratedField::PositionWithdraw),
                             "positionRewardClaim" | "position_reward_claim" => Ok(GeneratedField::PositionRewardClaim),
+                            "positionUpdateBatch" | "position_update_batch" => Ok(GeneratedField::PositionUpdateBatch),
+                            "positionRewrite" | "position_rewrite" => Ok(GeneratedField::PositionRewrite),
+                            "positionOpenBatch" | "position_open_batch" => Ok(GeneratedField::PositionOpenBatch),
+                            "actionDutchAuctionSchedule" | "action_dutch_auction_schedule" => Ok(GeneratedField::ActionDutchAuctionSchedule),
+                            "actionDutchAuctionEnd" | "action_dutch_auction_end" => Ok(GeneratedField::ActionDutchAuctionEnd),
+                            "actionDutchAuctionWithdraw" | "action_dutch_auction_withdraw" => Ok(GeneratedField::ActionDutchAuctionWithdraw),
                             "delegate" => Ok(GeneratedField::Delegate),
                             "undelegate" => Ok(GeneratedField::Undelegate),
                             "undelegateClaim" | "undelegate_claim" => Ok(GeneratedField::UndelegateClaim),
+                            "redelegate" => Ok(GeneratedField::Redelegate),
                             "communityPoolSpend" | "community_pool_spend" => Ok(GeneratedField::CommunityPoolSpend),
                             "communityPoolOutput" | "community_pool_output" => Ok(GeneratedField::CommunityPoolOutput),
                             "communityPoolDeposit" | "community_pool_deposit" => Ok(GeneratedField::CommunityPoolDeposit),
@@ -686,6 +894,27 @@ impl<'de> serde::Deserialize<'de> for ActionPlan {
                                 return Err(serde::de::Error::duplicate_field("proposalDepositClaim"));
                             }
                             action__ = map_.next_value::<::std::option::Option<_>>()?.map(action_plan::Action::ProposalDepositClaim)
+;
+                        }
+                        GeneratedField::BallotKeyDeal => {
+                            if action__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("ballotKeyDeal"));
+                            }
+                            action__ = map_.next_value::<::std::option::Option<_>>()?.map(action_plan::Action::BallotKeyDeal)
+;
+                        }
+                        GeneratedField::BallotKeyReveal => {
+                            if action__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("ballotKeyReveal"));
+                            }
+                            action__ = map_.next_value::<::std::option::Option<_>>()?.map(action_plan::Action::BallotKeyReveal)
+;
+                        }
+                        GeneratedField::BallotKeyComplaint => {
+                            if action__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("ballotKeyComplaint"));
+                            }
+                            action__ = map_.next_value::<::std::option::Option<_>>()?.map(action_plan::Action::BallotKeyComplaint)
 ;
                         }
                         GeneratedField::Ics20Withdrawal => {
@@ -721,6 +950,48 @@ impl<'de> serde::Deserialize<'de> for ActionPlan {
                                 return Err(serde::de::Error::duplicate_field("positionRewardClaim"));
                             }
                             action__ = map_.next_value::<::std::option::Option<_>>()?.map(action_plan::Action::PositionRewardClaim)
+;
+                        }
+                        GeneratedField::PositionUpdateBatch => {
+                            if action__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("positionUpdateBatch"));
+                            }
+                            action__ = map_.next_value::<::std::option::Option<_>>()?.map(action_plan::Action::PositionUpdateBatch)
+;
+                        }
+                        GeneratedField::PositionRewrite => {
+                            if action__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("positionRewrite"));
+                            }
+                            action__ = map_.next_value::<::std::option::Option<_>>()?.map(action_plan::Action::PositionRewrite)
+;
+                        }
+                        GeneratedField::PositionOpenBatch => {
+                            if action__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("positionOpenBatch"));
+                            }
+                            action__ = map_.next_value::<::std::option::Option<_>>()?.map(action_plan::Action::PositionOpenBatch)
+;
+                        }
+                        GeneratedField::ActionDutchAuctionSchedule => {
+                            if action__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("actionDutchAuctionSchedule"));
+                            }
+                            action__ = map_.next_value::<::std::option::Option<_>>()?.map(action_plan::Action::ActionDutchAuctionSchedule)
+;
+                        }
+                        GeneratedField::ActionDutchAuctionEnd => {
+                            if action__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("actionDutchAuctionEnd"));
+                            }
+                            action__ = map_.next_value::<::std::option::Option<_>>()?.map(action_plan::Action::ActionDutchAuctionEnd)
+;
+                        }
+                        GeneratedField::ActionDutchAuctionWithdraw => {
+                            if action__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("actionDutchAuctionWithdraw"));
+                            }
+                            action__ = map_.next_value::<::std::option::Option<_>>()?.map(action_plan::Action::ActionDutchAuctionWithdraw)
 ;
                         }
                         GeneratedField::Delegate => {
@@ -742,6 +1013,13 @@ impl<'de> serde::Deserialize<'de> for ActionPlan {
                                 return Err(serde::de::Error::duplicate_field("undelegateClaim"));
                             }
                             action__ = map_.next_value::<::std::option::Option<_>>()?.map(action_plan::Action::UndelegateClaim)
+;
+                        }
+                        GeneratedField::Redelegate => {
+                            if action__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("redelegate"));
+                            }
+                            action__ = map_.next_value::<::std::option::Option<_>>()?.map(action_plan::Action::Redelegate)
 ;
                         }
                         GeneratedField::CommunityPoolSpend => {
@@ -825,6 +1103,15 @@ impl serde::Serialize for ActionView {
                 action_view::ActionView::ProposalDepositClaim(v) => {
                     struct_ser.serialize_field("proposalDepositClaim", v)?;
                 }
+                action_view::ActionView::BallotKeyDeal(v) => {
+                    struct_ser.serialize_field("ballotKeyDeal", v)?;
+                }
+                action_view::ActionView::BallotKeyReveal(v) => {
+                    struct_ser.serialize_field("ballotKeyReveal", v)?;
+                }
+                action_view::ActionView::BallotKeyComplaint(v) => {
+                    struct_ser.serialize_field("ballotKeyComplaint", v)?;
+                }
                 action_view::ActionView::PositionOpen(v) => {
                     struct_ser.serialize_field("positionOpen", v)?;
                 }
@@ -837,6 +1124,24 @@ impl serde::Serialize for ActionView {
                 action_view::ActionView::PositionRewardClaim(v) => {
                     struct_ser.serialize_field("positionRewardClaim", v)?;
                 }
+                action_view::ActionView::PositionUpdateBatch(v) => {
+                    struct_ser.serialize_field("positionUpdateBatch", v)?;
+                }
+                action_view::ActionView::PositionRewrite(v) => {
+                    struct_ser.serialize_field("positionRewrite", v)?;
+                }
+                action_view::ActionView::PositionOpenBatch(v) => {
+                    struct_ser.serialize_field("positionOpenBatch", v)?;
+                }
+                action_view::ActionView::ActionDutchAuctionSchedule(v) => {
+                    struct_ser.serialize_field("actionDutchAuctionSchedule", v)?;
+                }
+                action_view::ActionView::ActionDutchAuctionEnd(v) => {
+                    struct_ser.serialize_field("actionDutchAuctionEnd", v)?;
+                }
+                action_view::ActionView::ActionDutchAuctionWithdraw(v) => {
+                    struct_ser.serialize_field("actionDutchAuctionWithdraw", v)?;
+                }
                 action_view::ActionView::Delegate(v) => {
                     struct_ser.serialize_field("delegate", v)?;
                 }
@@ -855,6 +1160,9 @@ impl serde::Serialize for ActionView {
                 action_view::ActionView::UndelegateClaim(v) => {
                     struct_ser.serialize_field("undelegateClaim", v)?;
                 }
+                action_view::ActionView::Redelegate(v) => {
+                    struct_ser.serialize_field("redelegate", v)?;
+                }
                 action_view::ActionView::Ics20Withdrawal(v) => {
                     struct_ser.serialize_field("ics20Withdrawal", v)?;
                 }
@@ -889,6 +1197,12 @@ impl<'de> serde::Deserialize<'de> for ActionView {
             "delegatorVote",
             "proposal_deposit_claim",
             "proposalDepositClaim",
+            "ballot_key_deal",
+            "ballotKeyDeal",
+            "ballot_key_reveal",
+            "ballotKeyReveal",
+            "ballot_key_complaint",
+            "ballotKeyComplaint",
             "position_open",
             "positionOpen",
             "position_close",
@@ -897,6 +1211,18 @@ impl<'de> serde::Deserialize<'de> for ActionView {
             "positionWithdraw",
             "position_reward_claim",
             "positionRewardClaim",
+            "position_update_batch",
+            "positionUpdateBatch",
+            "position_rewrite",
+            "positionRewrite",
+            "position_open_batch",
+            "positionOpenBatch",
+            "action_dutch_auction_schedule",
+            "actionDutchAuctionSchedule",
+            "action_dutch_auction_end",
+            "actionDutchAuctionEnd",
+            "action_dutch_auction_withdraw",
+            "actionDutchAuctionWithdraw",
             "delegate",
             "undelegate",
             "community_pool_spend",
@@ -907,6 +1233,7 @@ impl<'de> serde::Deserialize<'de> for ActionView {
             "communityPoolDeposit",
             "undelegate_claim",
             "undelegateClaim",
+            "redelegate",
             "ics20_withdrawal",
             "ics20Withdrawal",
         ];
@@ -924,16 +1251,26 @@ impl<'de> serde::Deserialize<'de> for ActionView {
             ValidatorVote,
             DelegatorVote,
             ProposalDepositClaim,
+            BallotKeyDeal,
+            BallotKeyReveal,
+            BallotKeyComplaint,
             PositionOpen,
             PositionClose,
             PositionWithdraw,
             PositionRewardClaim,
+            PositionUpdateBatch,
+            PositionRewrite,
+            PositionOpenBatch,
+            ActionDutchAuctionSchedule,
+            ActionDutchAuctionEnd,
+            ActionDutchAuctionWithdraw,
             Delegate,
             Undelegate,
             CommunityPoolSpend,
             CommunityPoolOutput,
             CommunityPoolDeposit,
             UndelegateClaim,
+            Redelegate,
             Ics20Withdrawal,
             __SkipField__,
         }
@@ -968,16 +1305,26 @@ impl<'de> serde::Deserialize<'de> for ActionView {
                             "validatorVote" | "validator_vote" => Ok(GeneratedField::ValidatorVote),
                             "delegatorVote" | "delegator_vote" => Ok(GeneratedField::DelegatorVote),
                             "proposalDepositClaim" | "proposal_deposit_claim" => Ok(GeneratedField::ProposalDepositClaim),
+                            "ballotKeyDeal" | "ballot_key_deal" => Ok(GeneratedField::BallotKeyDeal),
+                            "ballotKeyReveal" | "ballot_key_reveal" => Ok(GeneratedField::BallotKeyReveal),
+                            "ballotKeyComplaint" | "ballot_key_complaint" => Ok(GeneratedField::BallotKeyComplaint),
                             "positionOpen" | "position_open" => Ok(GeneratedField::PositionOpen),
                             "positionClose" | "position_close" => Ok(GeneratedField::PositionClose),
                             "positionWithdraw" | "position_withdraw" => Ok(GeneratedField::PositionWithdraw),
                             "positionRewardClaim" | "position_reward_claim" => Ok(GeneratedField::PositionRewardClaim),
+                            "positionUpdateBatch" | "position_update_batch" => Ok(GeneratedField::PositionUpdateBatch),
+                            "positionRewrite" | "position_rewrite" => Ok(GeneratedField::PositionRewrite),
+                            "positionOpenBatch" | "position_open_batch" => Ok(GeneratedField::PositionOpenBatch),
+                            "actionDutchAuctionSchedule" | "action_dutch_auction_schedule" => Ok(GeneratedField::ActionDutchAuctionSchedule),
+                            "actionDutchAuctionEnd" | "action_dutch_auction_end" => Ok(GeneratedField::ActionDutchAuctionEnd),
+                            "actionDutchAuctionWithdraw" | "action_dutch_auction_withdraw" => Ok(GeneratedField::ActionDutchAuctionWithdraw),
                             "delegate" => Ok(GeneratedField::Delegate),
                             "undelegate" => Ok(GeneratedField::Undelegate),
                             "communityPoolSpend" | "community_pool_spend" => Ok(GeneratedField::CommunityPoolSpend),
                             "communityPoolOutput" | "community_pool_output" => Ok(GeneratedField::CommunityPoolOutput),
                             "communityPoolDeposit" | "community_pool_deposit" => Ok(GeneratedField::CommunityPoolDeposit),
                             "undelegateClaim" | "undelegate_claim" => Ok(GeneratedField::UndelegateClaim),
+                            "redelegate" => Ok(GeneratedField::Redelegate),
                             "ics20Withdrawal" | "ics20_withdrawal" => Ok(GeneratedField::Ics20Withdrawal),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
@@ -1076,6 +1423,27 @@ impl<'de> serde::Deserialize<'de> for ActionView {
                                 return Err(serde::de::Error::duplicate_field("proposalDepositClaim"));
                             }
                             action_view__ = map_.next_value::<::std::option::Option<_>>()?.map(action_view::ActionView::ProposalDepositClaim)
+;
+                        }
+                        GeneratedField::BallotKeyDeal => {
+                            if action_view__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("ballotKeyDeal"));
+                            }
+                            action_view__ = map_.next_value::<::std::option::Option<_>>()?.map(action_view::ActionView::BallotKeyDeal)
+;
+                        }
+                        GeneratedField::BallotKeyReveal => {
+                            if action_view__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("ballotKeyReveal"));
+                            }
+                            action_view__ = map_.next_value::<::std::option::Option<_>>()?.map(action_view::ActionView::BallotKeyReveal)
+;
+                        }
+                        GeneratedField::BallotKeyComplaint => {
+                            if action_view__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("ballotKeyComplaint"));
+                            }
+                            action_view__ = map_.next_value::<::std::option::Option<_>>()?.map(action_view::ActionView::BallotKeyComplaint)
 ;
                         }
                         GeneratedField::PositionOpen => {
@@ -1104,6 +1472,48 @@ impl<'de> serde::Deserialize<'de> for ActionView {
                                 return Err(serde::de::Error::duplicate_field("positionRewardClaim"));
                             }
                             action_view__ = map_.next_value::<::std::option::Option<_>>()?.map(action_view::ActionView::PositionRewardClaim)
+;
+                        }
+                        GeneratedField::PositionUpdateBatch => {
+                            if action_view__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("positionUpdateBatch"));
+                            }
+                            action_view__ = map_.next_value::<::std::option::Option<_>>()?.map(action_view::ActionView::PositionUpdateBatch)
+;
+                        }
+                        GeneratedField::PositionRewrite => {
+                            if action_view__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("positionRewrite"));
+                            }
+                            action_view__ = map_.next_value::<::std::option::Option<_>>()?.map(action_view::ActionView::PositionRewrite)
+;
+                        }
+                        GeneratedField::PositionOpenBatch => {
+                            if action_view__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("positionOpenBatch"));
+                            }
+                            action_view__ = map_.next_value::<::std::option::Option<_>>()?.map(action_view::ActionView::PositionOpenBatch)
+;
+                        }
+                        GeneratedField::ActionDutchAuctionSchedule => {
+                            if action_view__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("actionDutchAuctionSchedule"));
+                            }
+                            action_view__ = map_.next_value::<::std::option::Option<_>>()?.map(action_view::ActionView::ActionDutchAuctionSchedule)
+;
+                        }
+                        GeneratedField::ActionDutchAuctionEnd => {
+                            if action_view__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("actionDutchAuctionEnd"));
+                            }
+                            action_view__ = map_.next_value::<::std::option::Option<_>>()?.map(action_view::ActionView::ActionDutchAuctionEnd)
+;
+                        }
+                        GeneratedField::ActionDutchAuctionWithdraw => {
+                            if action_view__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("actionDutchAuctionWithdraw"));
+                            }
+                            action_view__ = map_.next_value::<::std::option::Option<_>>()?.map(action_view::ActionView::ActionDutchAuctionWithdraw)
 ;
                         }
                         GeneratedField::Delegate => {
@@ -1146,6 +1556,13 @@ impl<'de> serde::Deserialize<'de> for ActionView {
                                 return Err(serde::de::Error::duplicate_field("undelegateClaim"));
                             }
                             action_view__ = map_.next_value::<::std::option::Option<_>>()?.map(action_view::ActionView::UndelegateClaim)
+;
+                        }
+                        GeneratedField::Redelegate => {
+                            if action_view__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("redelegate"));
+                            }
+                            action_view__ = map_.next_value::<::std::option::Option<_>>()?.map(action_view::ActionView::Redelegate)
 ;
                         }
                         GeneratedField::Ics20Withdrawal => {
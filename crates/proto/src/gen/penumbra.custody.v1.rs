@@ -14,6 +14,11 @@ pub struct AuthorizeRequest {
     /// to support multi-party pre-authorizations.
     #[prost(message, repeated, tag = "3")]
     pub pre_authorizations: ::prost::alloc::vec::Vec<PreAuthorization>,
+    /// Optionally, a nonce previously issued by the custodian in a `ChallengeResponse`.
+    ///
+    /// Each nonce can only be used once, and only for a limited time after it was issued.
+    #[prost(bytes = "vec", tag = "4")]
+    pub nonce: ::prost::alloc::vec::Vec<u8>,
 }
 impl ::prost::Name for AuthorizeRequest {
     const NAME: &'static str = "AuthorizeRequest";
@@ -29,6 +34,10 @@ pub struct AuthorizeResponse {
     pub data: ::core::option::Option<
         super::super::core::transaction::v1::AuthorizationData,
     >,
+    /// A structured summary of the authorized transaction plan, describing its
+    /// effects on the custodian's wallet.
+    #[prost(message, optional, tag = "2")]
+    pub summary: ::core::option::Option<PlanSummary>,
 }
 impl ::prost::Name for AuthorizeResponse {
     const NAME: &'static str = "AuthorizeResponse";
@@ -37,6 +46,253 @@ impl ::prost::Name for AuthorizeResponse {
         ::prost::alloc::format!("penumbra.custody.v1.{}", Self::NAME)
     }
 }
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SimulateAuthorizationResponse {
+    /// The outcome of checking the request.
+    #[prost(message, optional, tag = "1")]
+    pub simulation: ::core::option::Option<AuthorizeSimulation>,
+    /// A structured summary of the transaction plan, describing its effects on
+    /// the custodian's wallet.
+    #[prost(message, optional, tag = "2")]
+    pub summary: ::core::option::Option<PlanSummary>,
+}
+impl ::prost::Name for SimulateAuthorizationResponse {
+    const NAME: &'static str = "SimulateAuthorizationResponse";
+    const PACKAGE: &'static str = "penumbra.custody.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.custody.v1.{}", Self::NAME)
+    }
+}
+/// The outcome of simulating an authorization request against a custodian's
+/// checks, so that clients can find out whether a plan would be authorized,
+/// and why not, before asking for signatures.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct AuthorizeSimulation {
+    /// Whether the custodian would authorize the request, which is the case if
+    /// it passes every check.
+    #[prost(bool, tag = "1")]
+    pub approved: bool,
+    /// The outcome of every check, in the order the custodian performs them.
+    ///
+    /// Unlike an authorization, a simulation doesn't stop at the first failing
+    /// check, so all of the reasons a request would be denied are reported.
+    #[prost(message, repeated, tag = "2")]
+    pub checks: ::prost::alloc::vec::Vec<authorize_simulation::Check>,
+    /// The distinct pre-authorizers whose signatures were verified by the
+    /// custodian's policies.
+    #[prost(message, repeated, tag = "3")]
+    pub approvers: ::prost::alloc::vec::Vec<Approver>,
+}
+/// Nested message and enum types in `AuthorizeSimulation`.
+pub mod authorize_simulation {
+    /// The outcome of a single check.
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct Check {
+        /// The name of the check, such as `freeze`, `replay`, or the type of an
+        /// authorization policy.
+        #[prost(string, tag = "1")]
+        pub name: ::prost::alloc::string::String,
+        /// If the request fails the check, a human-readable explanation of why.
+        /// Empty if the request passes the check.
+        #[prost(string, tag = "2")]
+        pub denial: ::prost::alloc::string::String,
+    }
+    impl ::prost::Name for Check {
+        const NAME: &'static str = "Check";
+        const PACKAGE: &'static str = "penumbra.custody.v1";
+        fn full_name() -> ::prost::alloc::string::String {
+            ::prost::alloc::format!(
+                "penumbra.custody.v1.AuthorizeSimulation.{}", Self::NAME
+            )
+        }
+    }
+}
+impl ::prost::Name for AuthorizeSimulation {
+    const NAME: &'static str = "AuthorizeSimulation";
+    const PACKAGE: &'static str = "penumbra.custody.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.custody.v1.{}", Self::NAME)
+    }
+}
+/// A structured summary of the effects of a transaction plan, from the
+/// perspective of the wallet whose funds it spends.
+///
+/// Unlike the plan text, which lists every action, the summary nets out the
+/// value moved between the wallet's own addresses, so that clients can show
+/// the user what leaves and enters the wallet.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PlanSummary {
+    /// The net value received by the wallet, per asset.
+    #[prost(message, repeated, tag = "1")]
+    pub received: ::prost::alloc::vec::Vec<super::super::core::asset::v1::Value>,
+    /// The net value sent out of the wallet, per asset, including the fee.
+    #[prost(message, repeated, tag = "2")]
+    pub sent: ::prost::alloc::vec::Vec<super::super::core::asset::v1::Value>,
+    /// The addresses outside the wallet which receive outputs of the transaction.
+    #[prost(message, repeated, tag = "3")]
+    pub destinations: ::prost::alloc::vec::Vec<super::super::core::keys::v1::Address>,
+    /// The counterparty chain addresses which receive ICS-20 withdrawals.
+    #[prost(string, repeated, tag = "4")]
+    pub ics20_destinations: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+    /// The fee paid by the transaction.
+    #[prost(message, optional, tag = "5")]
+    pub fee: ::core::option::Option<super::super::core::component::fee::v1::Fee>,
+    /// The plaintext of the transaction memo, if any.
+    #[prost(message, optional, tag = "6")]
+    pub memo: ::core::option::Option<super::super::core::transaction::v1::MemoPlaintext>,
+    /// The hash of the plan text the summary was derived from.
+    #[prost(bytes = "vec", tag = "7")]
+    pub plan_text_hash: ::prost::alloc::vec::Vec<u8>,
+}
+impl ::prost::Name for PlanSummary {
+    const NAME: &'static str = "PlanSummary";
+    const PACKAGE: &'static str = "penumbra.custody.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.custody.v1.{}", Self::NAME)
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct AuthorizeMessageRequest {
+    /// The domain the message is signed under, naming its purpose, such as
+    /// `forum.penumbra.zone/identity`.
+    ///
+    /// Domains must be between 1 and 128 bytes of printable ASCII.
+    #[prost(string, tag = "1")]
+    pub domain: ::prost::alloc::string::String,
+    /// The message to sign.
+    #[prost(bytes = "vec", tag = "2")]
+    pub message: ::prost::alloc::vec::Vec<u8>,
+}
+impl ::prost::Name for AuthorizeMessageRequest {
+    const NAME: &'static str = "AuthorizeMessageRequest";
+    const PACKAGE: &'static str = "penumbra.custody.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.custody.v1.{}", Self::NAME)
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct AuthorizeMessageResponse {
+    /// A signature over the domain-separated hash of the message.
+    #[prost(message, optional, tag = "1")]
+    pub signature: ::core::option::Option<
+        super::super::crypto::decaf377_rdsa::v1::SpendAuthSignature,
+    >,
+    /// The spend verification key the signature verifies under.
+    #[prost(message, optional, tag = "2")]
+    pub verification_key: ::core::option::Option<
+        super::super::crypto::decaf377_rdsa::v1::SpendVerificationKey,
+    >,
+}
+impl ::prost::Name for AuthorizeMessageResponse {
+    const NAME: &'static str = "AuthorizeMessageResponse";
+    const PACKAGE: &'static str = "penumbra.custody.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.custody.v1.{}", Self::NAME)
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct AuthorizeStreamResponse {
+    #[prost(oneof = "authorize_stream_response::Status", tags = "1, 2, 3, 4")]
+    pub status: ::core::option::Option<authorize_stream_response::Status>,
+}
+/// Nested message and enum types in `AuthorizeStreamResponse`.
+pub mod authorize_stream_response {
+    /// Signals that the custodian is waiting for the user to approve the request.
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct PendingUserApproval {}
+    impl ::prost::Name for PendingUserApproval {
+        const NAME: &'static str = "PendingUserApproval";
+        const PACKAGE: &'static str = "penumbra.custody.v1";
+        fn full_name() -> ::prost::alloc::string::String {
+            ::prost::alloc::format!(
+                "penumbra.custody.v1.AuthorizeStreamResponse.{}", Self::NAME
+            )
+        }
+    }
+    /// Signals that the custodian is waiting for cosigners to contribute to the
+    /// authorization.
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct AwaitingCosigner {
+        /// The number of cosigner contributions received so far.
+        #[prost(uint32, tag = "1")]
+        pub received: u32,
+        /// The number of cosigner contributions required.
+        #[prost(uint32, tag = "2")]
+        pub required: u32,
+    }
+    impl ::prost::Name for AwaitingCosigner {
+        const NAME: &'static str = "AwaitingCosigner";
+        const PACKAGE: &'static str = "penumbra.custody.v1";
+        fn full_name() -> ::prost::alloc::string::String {
+            ::prost::alloc::format!(
+                "penumbra.custody.v1.AuthorizeStreamResponse.{}", Self::NAME
+            )
+        }
+    }
+    /// Signals that the request was approved, and contains the authorization data.
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct Approved {
+        #[prost(message, optional, tag = "1")]
+        pub data: ::core::option::Option<
+            super::super::super::core::transaction::v1::AuthorizationData,
+        >,
+    }
+    impl ::prost::Name for Approved {
+        const NAME: &'static str = "Approved";
+        const PACKAGE: &'static str = "penumbra.custody.v1";
+        fn full_name() -> ::prost::alloc::string::String {
+            ::prost::alloc::format!(
+                "penumbra.custody.v1.AuthorizeStreamResponse.{}", Self::NAME
+            )
+        }
+    }
+    /// Signals that the request was denied.
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct Denied {
+        /// A human-readable explanation of why the request was denied.
+        #[prost(string, tag = "1")]
+        pub reason: ::prost::alloc::string::String,
+    }
+    impl ::prost::Name for Denied {
+        const NAME: &'static str = "Denied";
+        const PACKAGE: &'static str = "penumbra.custody.v1";
+        fn full_name() -> ::prost::alloc::string::String {
+            ::prost::alloc::format!(
+                "penumbra.custody.v1.AuthorizeStreamResponse.{}", Self::NAME
+            )
+        }
+    }
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Oneof)]
+    pub enum Status {
+        #[prost(message, tag = "1")]
+        PendingUserApproval(PendingUserApproval),
+        #[prost(message, tag = "2")]
+        AwaitingCosigner(AwaitingCosigner),
+        #[prost(message, tag = "3")]
+        Approved(Approved),
+        #[prost(message, tag = "4")]
+        Denied(Denied),
+    }
+}
+impl ::prost::Name for AuthorizeStreamResponse {
+    const NAME: &'static str = "AuthorizeStreamResponse";
+    const PACKAGE: &'static str = "penumbra.custody.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.custody.v1.{}", Self::NAME)
+    }
+}
 /// A pre-authorization packet.  This allows a custodian to delegate (partial)
 /// signing authority to other authorization mechanisms.  Details of how a
 /// custodian manages those keys are out-of-scope for the custody protocol and
@@ -44,7 +300,7 @@ impl ::prost::Name for AuthorizeResponse {
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct PreAuthorization {
-    #[prost(oneof = "pre_authorization::PreAuthorization", tags = "1")]
+    #[prost(oneof = "pre_authorization::PreAuthorization", tags = "1, 2, 3")]
     pub pre_authorization: ::core::option::Option<pre_authorization::PreAuthorization>,
 }
 /// Nested message and enum types in `PreAuthorization`.
@@ -70,11 +326,66 @@ pub mod pre_authorization {
             )
         }
     }
+    /// A secp256k1-based preauthorization, containing an ECDSA signature over the
+    /// `TransactionPlan`.
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct Secp256k1 {
+        /// The SEC1-encoded secp256k1 verification key used to verify the signature.
+        #[prost(bytes = "vec", tag = "1")]
+        pub vk: ::prost::alloc::vec::Vec<u8>,
+        /// The ECDSA signature over the SHA-256 hash of the `TransactionPlan`, in
+        /// 64-byte fixed-width (r || s) encoding.
+        #[prost(bytes = "vec", tag = "2")]
+        pub sig: ::prost::alloc::vec::Vec<u8>,
+    }
+    impl ::prost::Name for Secp256k1 {
+        const NAME: &'static str = "Secp256k1";
+        const PACKAGE: &'static str = "penumbra.custody.v1";
+        fn full_name() -> ::prost::alloc::string::String {
+            ::prost::alloc::format!(
+                "penumbra.custody.v1.PreAuthorization.{}", Self::NAME
+            )
+        }
+    }
+    /// A WebAuthn (passkey) preauthorization, containing an assertion whose
+    /// challenge is the SHA-256 hash of the `TransactionPlan`.
+    ///
+    /// Only ES256 (ECDSA over P-256) credentials are supported.
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct WebAuthn {
+        /// The SEC1-encoded P-256 public key of the credential.
+        #[prost(bytes = "vec", tag = "1")]
+        pub public_key: ::prost::alloc::vec::Vec<u8>,
+        /// The raw `authenticatorData` returned by the authenticator.
+        #[prost(bytes = "vec", tag = "2")]
+        pub authenticator_data: ::prost::alloc::vec::Vec<u8>,
+        /// The raw `clientDataJSON` returned by the client.
+        #[prost(bytes = "vec", tag = "3")]
+        pub client_data_json: ::prost::alloc::vec::Vec<u8>,
+        /// The DER-encoded ECDSA signature over `authenticatorData || SHA-256(clientDataJSON)`.
+        #[prost(bytes = "vec", tag = "4")]
+        pub sig: ::prost::alloc::vec::Vec<u8>,
+    }
+    impl ::prost::Name for WebAuthn {
+        const NAME: &'static str = "WebAuthn";
+        const PACKAGE: &'static str = "penumbra.custody.v1";
+        fn full_name() -> ::prost::alloc::string::String {
+            ::prost::alloc::format!(
+                "penumbra.custody.v1.PreAuthorization.{}", Self::NAME
+            )
+        }
+    }
     #[allow(clippy::derive_partial_eq_without_eq)]
     #[derive(Clone, PartialEq, ::prost::Oneof)]
     pub enum PreAuthorization {
         #[prost(message, tag = "1")]
         Ed25519(Ed25519),
+        #[prost(message, tag = "2")]
+        Secp256k1(Secp256k1),
+        #[prost(message, tag = "3")]
+        WebAuthn(WebAuthn),
     }
 }
 impl ::prost::Name for PreAuthorization {
@@ -84,6 +395,37 @@ impl ::prost::Name for PreAuthorization {
         ::prost::alloc::format!("penumbra.custody.v1.{}", Self::NAME)
     }
 }
+/// A pre-authorizer whose signature over a `TransactionPlan` was verified by a
+/// custodian's policies, identified by its verification key.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Approver {
+    #[prost(oneof = "approver::Key", tags = "1, 2, 3")]
+    pub key: ::core::option::Option<approver::Key>,
+}
+/// Nested message and enum types in `Approver`.
+pub mod approver {
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Oneof)]
+    pub enum Key {
+        /// An Ed25519 verification key.
+        #[prost(bytes, tag = "1")]
+        Ed25519(::prost::alloc::vec::Vec<u8>),
+        /// A SEC1-encoded secp256k1 verification key.
+        #[prost(bytes, tag = "2")]
+        Secp256k1(::prost::alloc::vec::Vec<u8>),
+        /// A SEC1-encoded P-256 WebAuthn credential public key.
+        #[prost(bytes, tag = "3")]
+        WebAuthn(::prost::alloc::vec::Vec<u8>),
+    }
+}
+impl ::prost::Name for Approver {
+    const NAME: &'static str = "Approver";
+    const PACKAGE: &'static str = "penumbra.custody.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.custody.v1.{}", Self::NAME)
+    }
+}
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct ExportFullViewingKeyRequest {}
@@ -138,49 +480,379 @@ impl ::prost::Name for ConfirmAddressResponse {
         ::prost::alloc::format!("penumbra.custody.v1.{}", Self::NAME)
     }
 }
-/// Generated client implementations.
-#[cfg(feature = "rpc")]
-pub mod custody_service_client {
-    #![allow(unused_variables, dead_code, missing_docs, clippy::let_unit_value)]
-    use tonic::codegen::*;
-    use tonic::codegen::http::Uri;
-    /// The custody protocol is used by a wallet client to request authorization for
-    /// a transaction they've constructed.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ChallengeRequest {}
+impl ::prost::Name for ChallengeRequest {
+    const NAME: &'static str = "ChallengeRequest";
+    const PACKAGE: &'static str = "penumbra.custody.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.custody.v1.{}", Self::NAME)
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ChallengeResponse {
+    /// A single-use nonce, to be included in the next `AuthorizeRequest`.
+    #[prost(bytes = "vec", tag = "1")]
+    pub nonce: ::prost::alloc::vec::Vec<u8>,
+}
+impl ::prost::Name for ChallengeResponse {
+    const NAME: &'static str = "ChallengeResponse";
+    const PACKAGE: &'static str = "penumbra.custody.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.custody.v1.{}", Self::NAME)
+    }
+}
+/// A record of a single authorization decision made by a custodian.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct AuditLogEntry {
+    /// The position of this entry in the log, starting from 0.
+    #[prost(uint64, tag = "1")]
+    pub index: u64,
+    /// The time the decision was made, in seconds since the Unix epoch.
+    #[prost(uint64, tag = "2")]
+    pub timestamp: u64,
+    /// The hash of the plan text of the transaction plan that was decided, which
+    /// identifies the plan without revealing it.
+    #[prost(bytes = "vec", tag = "9")]
+    pub plan_text_hash: ::prost::alloc::vec::Vec<u8>,
+    /// If the request was rejected by the custodian's policies, the reason for
+    /// the rejection. Empty if the request satisfied every policy.
+    #[prost(string, tag = "4")]
+    pub policy_denial: ::prost::alloc::string::String,
+    /// Whether the custodian authorized the request.
+    #[prost(bool, tag = "5")]
+    pub approved: bool,
+    /// If the request was not authorized, a human-readable explanation of why.
+    #[prost(string, tag = "6")]
+    pub denial_reason: ::prost::alloc::string::String,
+    /// The hash of the previous entry in the log, or empty for the first entry.
+    #[prost(bytes = "vec", tag = "7")]
+    pub previous_hash: ::prost::alloc::vec::Vec<u8>,
+    /// The distinct pre-authorizers whose signatures were verified by the
+    /// custodian's policies. Empty if the request did not satisfy the policies.
+    #[prost(message, repeated, tag = "8")]
+    pub approvers: ::prost::alloc::vec::Vec<Approver>,
+}
+impl ::prost::Name for AuditLogEntry {
+    const NAME: &'static str = "AuditLogEntry";
+    const PACKAGE: &'static str = "penumbra.custody.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.custody.v1.{}", Self::NAME)
+    }
+}
+/// An audit log entry, signed by the custodian's identity key.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SignedAuditLogEntry {
+    #[prost(message, optional, tag = "1")]
+    pub entry: ::core::option::Option<AuditLogEntry>,
+    /// An Ed25519 signature over the hash of the entry.
+    #[prost(bytes = "vec", tag = "2")]
+    pub signature: ::prost::alloc::vec::Vec<u8>,
+}
+impl ::prost::Name for SignedAuditLogEntry {
+    const NAME: &'static str = "SignedAuditLogEntry";
+    const PACKAGE: &'static str = "penumbra.custody.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.custody.v1.{}", Self::NAME)
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct QueryAuditLogRequest {
+    /// The index of the first entry to return.
+    #[prost(uint64, tag = "1")]
+    pub start_index: u64,
+    /// The maximum number of entries to return.
     ///
-    /// Modeling transaction authorization as an asynchronous RPC call encourages
-    /// software to be written in a way that has a compatible data flow with a "soft
-    /// HSM", threshold signing, a hardware wallet, etc.
+    /// If zero, a custodian-specific default is used. Custodians may also return
+    /// fewer entries than requested.
+    #[prost(uint32, tag = "2")]
+    pub limit: u32,
+    /// If nonzero, only entries recorded at or after this time, in seconds since
+    /// the Unix epoch, are returned.
+    #[prost(uint64, tag = "3")]
+    pub start_time: u64,
+    /// If nonzero, only entries recorded before this time, in seconds since the
+    /// Unix epoch, are returned.
+    #[prost(uint64, tag = "4")]
+    pub end_time: u64,
+}
+impl ::prost::Name for QueryAuditLogRequest {
+    const NAME: &'static str = "QueryAuditLogRequest";
+    const PACKAGE: &'static str = "penumbra.custody.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.custody.v1.{}", Self::NAME)
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct QueryAuditLogResponse {
+    /// The matching entries, in order of increasing index.
+    #[prost(message, repeated, tag = "1")]
+    pub entries: ::prost::alloc::vec::Vec<SignedAuditLogEntry>,
+    /// The index at which to resume the query to fetch further entries, or zero
+    /// if there are no further entries.
+    #[prost(uint64, tag = "2")]
+    pub next_index: u64,
+    /// The Ed25519 verification key of the custodian's identity key, which
+    /// signed the entries.
+    #[prost(bytes = "vec", tag = "3")]
+    pub identity_key: ::prost::alloc::vec::Vec<u8>,
+}
+impl ::prost::Name for QueryAuditLogResponse {
+    const NAME: &'static str = "QueryAuditLogResponse";
+    const PACKAGE: &'static str = "penumbra.custody.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.custody.v1.{}", Self::NAME)
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct FreezeRequest {
+    /// Why the custodian is being frozen, for the operators.
+    #[prost(string, tag = "1")]
+    pub reason: ::prost::alloc::string::String,
+}
+impl ::prost::Name for FreezeRequest {
+    const NAME: &'static str = "FreezeRequest";
+    const PACKAGE: &'static str = "penumbra.custody.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.custody.v1.{}", Self::NAME)
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct FreezeResponse {
+    /// The generation of the freeze, which recovery keys sign to lift it.
     ///
-    /// The custody protocol does not trust the client to authorize spends, so
-    /// custody requests must contain sufficient information for the custodian to
-    /// understand the transaction and determine whether or not it should be
-    /// authorized.
-    #[derive(Debug, Clone)]
-    pub struct CustodyServiceClient<T> {
-        inner: tonic::client::Grpc<T>,
+    /// The generation counts how many times the custodian has been frozen, so
+    /// signatures lifting one freeze can't be replayed to lift a later one.
+    #[prost(uint64, tag = "1")]
+    pub generation: u64,
+}
+impl ::prost::Name for FreezeResponse {
+    const NAME: &'static str = "FreezeResponse";
+    const PACKAGE: &'static str = "penumbra.custody.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.custody.v1.{}", Self::NAME)
     }
-    impl CustodyServiceClient<tonic::transport::Channel> {
-        /// Attempt to create a new client by connecting to a given endpoint.
-        pub async fn connect<D>(dst: D) -> Result<Self, tonic::transport::Error>
-        where
-            D: TryInto<tonic::transport::Endpoint>,
-            D::Error: Into<StdError>,
-        {
-            let conn = tonic::transport::Endpoint::new(dst)?.connect().await?;
-            Ok(Self::new(conn))
-        }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct UnfreezeRequest {
+    /// The generation of the freeze to lift.
+    #[prost(uint64, tag = "1")]
+    pub generation: u64,
+    /// Signatures approving the unfreeze, from the custodian's recovery keys.
+    #[prost(message, repeated, tag = "2")]
+    pub signatures: ::prost::alloc::vec::Vec<RecoverySignature>,
+    /// The ed25519 verification key of the identity key of the custodian to
+    /// unfreeze, which the recovery keys sign along with the generation.
+    #[prost(bytes = "vec", tag = "3")]
+    pub custodian: ::prost::alloc::vec::Vec<u8>,
+}
+impl ::prost::Name for UnfreezeRequest {
+    const NAME: &'static str = "UnfreezeRequest";
+    const PACKAGE: &'static str = "penumbra.custody.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.custody.v1.{}", Self::NAME)
     }
-    impl<T> CustodyServiceClient<T>
-    where
-        T: tonic::client::GrpcService<tonic::body::BoxBody>,
-        T::Error: Into<StdError>,
-        T::ResponseBody: Body<Data = Bytes> + Send + 'static,
-        <T::ResponseBody as Body>::Error: Into<StdError> + Send,
-    {
-        pub fn new(inner: T) -> Self {
-            let inner = tonic::client::Grpc::new(inner);
-            Self { inner }
-        }
+}
+/// A signature from a recovery key approving an unfreeze.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct RecoverySignature {
+    /// The ed25519 verification key of the signer.
+    #[prost(bytes = "vec", tag = "1")]
+    pub signer: ::prost::alloc::vec::Vec<u8>,
+    /// An ed25519 signature over the hash of the unfreeze.
+    #[prost(bytes = "vec", tag = "2")]
+    pub signature: ::prost::alloc::vec::Vec<u8>,
+}
+impl ::prost::Name for RecoverySignature {
+    const NAME: &'static str = "RecoverySignature";
+    const PACKAGE: &'static str = "penumbra.custody.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.custody.v1.{}", Self::NAME)
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct UnfreezeResponse {}
+impl ::prost::Name for UnfreezeResponse {
+    const NAME: &'static str = "UnfreezeResponse";
+    const PACKAGE: &'static str = "penumbra.custody.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.custody.v1.{}", Self::NAME)
+    }
+}
+/// A statement replacing the custodian's authorization key with a new one.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct KeyRotation {
+    /// The number of the rotation, which must be one more than the number of the
+    /// previous rotation, so that a rotation can't be replayed.
+    #[prost(uint64, tag = "1")]
+    pub sequence: u64,
+    /// The ed25519 verification key of the current authorization key.
+    #[prost(bytes = "vec", tag = "2")]
+    pub old_key: ::prost::alloc::vec::Vec<u8>,
+    /// The ed25519 verification key of the new authorization key.
+    #[prost(bytes = "vec", tag = "3")]
+    pub new_key: ::prost::alloc::vec::Vec<u8>,
+    /// The ed25519 verification key of the identity key of the custodian whose
+    /// authorization key is rotated, so that a rotation can't be replayed against
+    /// another custodian sharing the same authorization key.
+    #[prost(bytes = "vec", tag = "4")]
+    pub custodian: ::prost::alloc::vec::Vec<u8>,
+}
+impl ::prost::Name for KeyRotation {
+    const NAME: &'static str = "KeyRotation";
+    const PACKAGE: &'static str = "penumbra.custody.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.custody.v1.{}", Self::NAME)
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct RotateAuthorizationKeyRequest {
+    /// The rotation to perform.
+    #[prost(message, optional, tag = "1")]
+    pub rotation: ::core::option::Option<KeyRotation>,
+    /// An ed25519 signature over the hash of the rotation by the old key.
+    #[prost(bytes = "vec", tag = "2")]
+    pub old_signature: ::prost::alloc::vec::Vec<u8>,
+    /// An ed25519 signature over the hash of the rotation by the new key.
+    #[prost(bytes = "vec", tag = "3")]
+    pub new_signature: ::prost::alloc::vec::Vec<u8>,
+}
+impl ::prost::Name for RotateAuthorizationKeyRequest {
+    const NAME: &'static str = "RotateAuthorizationKeyRequest";
+    const PACKAGE: &'static str = "penumbra.custody.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.custody.v1.{}", Self::NAME)
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct RotateAuthorizationKeyResponse {
+    /// The rotation which was performed.
+    #[prost(message, optional, tag = "1")]
+    pub rotation: ::core::option::Option<KeyRotation>,
+    /// The ed25519 verification key of the custodian's identity key.
+    #[prost(bytes = "vec", tag = "2")]
+    pub identity_key: ::prost::alloc::vec::Vec<u8>,
+    /// An ed25519 signature over the hash of the rotation by the custodian's
+    /// identity key.
+    #[prost(bytes = "vec", tag = "3")]
+    pub signature: ::prost::alloc::vec::Vec<u8>,
+}
+impl ::prost::Name for RotateAuthorizationKeyResponse {
+    const NAME: &'static str = "RotateAuthorizationKeyResponse";
+    const PACKAGE: &'static str = "penumbra.custody.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.custody.v1.{}", Self::NAME)
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ExportPolicyRequest {}
+impl ::prost::Name for ExportPolicyRequest {
+    const NAME: &'static str = "ExportPolicyRequest";
+    const PACKAGE: &'static str = "penumbra.custody.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.custody.v1.{}", Self::NAME)
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ExportPolicyResponse {
+    /// The JSON encoding of the policy bundle currently enforced.
+    #[prost(string, tag = "1")]
+    pub bundle: ::prost::alloc::string::String,
+}
+impl ::prost::Name for ExportPolicyResponse {
+    const NAME: &'static str = "ExportPolicyResponse";
+    const PACKAGE: &'static str = "penumbra.custody.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.custody.v1.{}", Self::NAME)
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ImportPolicyRequest {
+    /// The JSON encoding of the signed policy bundle to import.
+    #[prost(string, tag = "1")]
+    pub signed_bundle: ::prost::alloc::string::String,
+}
+impl ::prost::Name for ImportPolicyRequest {
+    const NAME: &'static str = "ImportPolicyRequest";
+    const PACKAGE: &'static str = "penumbra.custody.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.custody.v1.{}", Self::NAME)
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ImportPolicyResponse {
+    /// The version of the imported policy bundle.
+    #[prost(uint64, tag = "1")]
+    pub version: u64,
+}
+impl ::prost::Name for ImportPolicyResponse {
+    const NAME: &'static str = "ImportPolicyResponse";
+    const PACKAGE: &'static str = "penumbra.custody.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.custody.v1.{}", Self::NAME)
+    }
+}
+/// Generated client implementations.
+#[cfg(feature = "rpc")]
+pub mod custody_service_client {
+    #![allow(unused_variables, dead_code, missing_docs, clippy::let_unit_value)]
+    use tonic::codegen::*;
+    use tonic::codegen::http::Uri;
+    /// The custody protocol is used by a wallet client to request authorization for
+    /// a transaction they've constructed.
+    ///
+    /// Modeling transaction authorization as an asynchronous RPC call encourages
+    /// software to be written in a way that has a compatible data flow with a "soft
+    /// HSM", threshold signing, a hardware wallet, etc.
+    ///
+    /// The custody protocol does not trust the client to authorize spends, so
+    /// custody requests must contain sufficient information for the custodian to
+    /// understand the transaction and determine whether or not it should be
+    /// authorized.
+    #[derive(Debug, Clone)]
+    pub struct CustodyServiceClient<T> {
+        inner: tonic::client::Grpc<T>,
+    }
+    impl CustodyServiceClient<tonic::transport::Channel> {
+        /// Attempt to create a new client by connecting to a given endpoint.
+        pub async fn connect<D>(dst: D) -> Result<Self, tonic::transport::Error>
+        where
+            D: TryInto<tonic::transport::Endpoint>,
+            D::Error: Into<StdError>,
+        {
+            let conn = tonic::transport::Endpoint::new(dst)?.connect().await?;
+            Ok(Self::new(conn))
+        }
+    }
+    impl<T> CustodyServiceClient<T>
+    where
+        T: tonic::client::GrpcService<tonic::body::BoxBody>,
+        T::Error: Into<StdError>,
+        T::ResponseBody: Body<Data = Bytes> + Send + 'static,
+        <T::ResponseBody as Body>::Error: Into<StdError> + Send,
+    {
+        pub fn new(inner: T) -> Self {
+            let inner = tonic::client::Grpc::new(inner);
+            Self { inner }
+        }
         pub fn with_origin(inner: T, origin: Uri) -> Self {
             let inner = tonic::client::Grpc::with_origin(inner, origin);
             Self { inner }
@@ -263,6 +935,121 @@ pub mod custody_service_client {
                 );
             self.inner.unary(req, path, codec).await
         }
+        /// Requests authorization of the transaction with the given description,
+        /// streaming status updates while the request is being processed.
+        ///
+        /// This is useful for custody backends that may take a long time to respond,
+        /// such as hardware wallets or threshold signing ceremonies, allowing clients
+        /// to display progress rather than blocking on a single response.
+        ///
+        /// The stream ends after either an `Approved` or a `Denied` update.
+        pub async fn authorize_stream(
+            &mut self,
+            request: impl tonic::IntoRequest<super::AuthorizeRequest>,
+        ) -> std::result::Result<
+            tonic::Response<tonic::codec::Streaming<super::AuthorizeStreamResponse>>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/penumbra.custody.v1.CustodyService/AuthorizeStream",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "penumbra.custody.v1.CustodyService",
+                        "AuthorizeStream",
+                    ),
+                );
+            self.inner.server_streaming(req, path, codec).await
+        }
+        /// Checks whether the custodian would authorize the transaction with the
+        /// given description, reporting the outcome of each of its checks, without
+        /// producing any signatures.
+        ///
+        /// A simulation does not consume the request's nonce, and is not recorded in
+        /// the audit log. Custodians which can't simulate authorization, including
+        /// those which predate this method, return `UNIMPLEMENTED`; since this is a
+        /// separate method, they never mistake a simulation for a real request.
+        pub async fn simulate_authorization(
+            &mut self,
+            request: impl tonic::IntoRequest<super::AuthorizeRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::SimulateAuthorizationResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/penumbra.custody.v1.CustodyService/SimulateAuthorization",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "penumbra.custody.v1.CustodyService",
+                        "SimulateAuthorization",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// Requests a signature over an off-chain message, such as an attestation of
+        /// ownership of the custodian's addresses, made with the spend authorization
+        /// key.
+        ///
+        /// Each message is signed under a domain naming its purpose, which is hashed
+        /// together with it, so that the signature can't be mistaken for a
+        /// transaction authorization or for a signature under another domain.
+        /// Custodians should refuse to sign messages under domains their policy
+        /// doesn't explicitly allow.
+        pub async fn authorize_message(
+            &mut self,
+            request: impl tonic::IntoRequest<super::AuthorizeMessageRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::AuthorizeMessageResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/penumbra.custody.v1.CustodyService/AuthorizeMessage",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "penumbra.custody.v1.CustodyService",
+                        "AuthorizeMessage",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
         /// Requests the full viewing key from the custodian.
         ///
         /// Custody backends should decide whether to honor this request, and how to
@@ -333,51 +1120,415 @@ pub mod custody_service_client {
                 );
             self.inner.unary(req, path, codec).await
         }
-    }
-}
-/// Generated server implementations.
-#[cfg(feature = "rpc")]
-pub mod custody_service_server {
-    #![allow(unused_variables, dead_code, missing_docs, clippy::let_unit_value)]
-    use tonic::codegen::*;
-    /// Generated trait containing gRPC methods that should be implemented for use with CustodyServiceServer.
-    #[async_trait]
-    pub trait CustodyService: Send + Sync + 'static {
-        /// Requests authorization of the transaction with the given description.
-        async fn authorize(
-            &self,
-            request: tonic::Request<super::AuthorizeRequest>,
-        ) -> std::result::Result<
-            tonic::Response<super::AuthorizeResponse>,
-            tonic::Status,
-        >;
-        /// Requests the full viewing key from the custodian.
+        /// Requests a single-use nonce, to be included in the next `AuthorizeRequest`.
         ///
-        /// Custody backends should decide whether to honor this request, and how to
-        /// control access to it.
-        async fn export_full_viewing_key(
-            &self,
-            request: tonic::Request<super::ExportFullViewingKeyRequest>,
+        /// Binding each authorization request to a nonce issued by the custodian
+        /// ensures that a captured request can't be replayed against the custodian
+        /// later. Custody backends may require a nonce in every authorization request.
+        pub async fn challenge(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ChallengeRequest>,
         ) -> std::result::Result<
-            tonic::Response<super::ExportFullViewingKeyResponse>,
+            tonic::Response<super::ChallengeResponse>,
             tonic::Status,
-        >;
-        /// Displays an address to a user for confirmation.
-        ///
-        /// Custody backends with user interaction should present the address to the
-        /// user and wait for explicit confirmation before returning.
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/penumbra.custody.v1.CustodyService/Challenge",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new("penumbra.custody.v1.CustodyService", "Challenge"),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// Queries the custodian's audit log of authorization decisions.
         ///
-        /// Non-interactive custody backends may return immediately.
-        async fn confirm_address(
-            &self,
-            request: tonic::Request<super::ConfirmAddressRequest>,
+        /// Each entry is signed by the custodian's identity key, and commits to the
+        /// entry before it, so that the log can be checked for tampering.
+        pub async fn query_audit_log(
+            &mut self,
+            request: impl tonic::IntoRequest<super::QueryAuditLogRequest>,
         ) -> std::result::Result<
-            tonic::Response<super::ConfirmAddressResponse>,
+            tonic::Response<super::QueryAuditLogResponse>,
             tonic::Status,
-        >;
-    }
-    /// The custody protocol is used by a wallet client to request authorization for
-    /// a transaction they've constructed.
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/penumbra.custody.v1.CustodyService/QueryAuditLog",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "penumbra.custody.v1.CustodyService",
+                        "QueryAuditLog",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// Freezes the custodian, which then refuses every authorization request
+        /// until it is unfrozen.
+        ///
+        /// This is a kill switch for operators who suspect that a key or a client has
+        /// been compromised, so custody backends should honor it from any client
+        /// allowed to request authorizations. Freezing a frozen custodian has no
+        /// further effect.
+        pub async fn freeze(
+            &mut self,
+            request: impl tonic::IntoRequest<super::FreezeRequest>,
+        ) -> std::result::Result<tonic::Response<super::FreezeResponse>, tonic::Status> {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/penumbra.custody.v1.CustodyService/Freeze",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("penumbra.custody.v1.CustodyService", "Freeze"));
+            self.inner.unary(req, path, codec).await
+        }
+        /// Unfreezes a frozen custodian.
+        ///
+        /// So that a compromised client can't simply undo a freeze, unfreezing
+        /// requires signatures over the freeze being lifted from a quorum of the
+        /// recovery keys configured in the custodian.
+        pub async fn unfreeze(
+            &mut self,
+            request: impl tonic::IntoRequest<super::UnfreezeRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::UnfreezeResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/penumbra.custody.v1.CustodyService/Unfreeze",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new("penumbra.custody.v1.CustodyService", "Unfreeze"),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// Rotates the custodian's authorization key, the operator key whose
+        /// periodic rotation shows that the custodian is still under its operators'
+        /// control.
+        ///
+        /// Custody backends may be configured with an inactivity timeout, after which
+        /// they refuse every authorization request until the key is rotated. The
+        /// rotation must be signed by both the old and the new key, and the custodian
+        /// returns it countersigned by its identity key, as a record that the
+        /// rotation was confirmed.
+        pub async fn rotate_authorization_key(
+            &mut self,
+            request: impl tonic::IntoRequest<super::RotateAuthorizationKeyRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::RotateAuthorizationKeyResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/penumbra.custody.v1.CustodyService/RotateAuthorizationKey",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "penumbra.custody.v1.CustodyService",
+                        "RotateAuthorizationKey",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// Exports the authorization policy enforced by the custodian, as a
+        /// versioned policy bundle, so that changes to it can be reviewed and signed.
+        pub async fn export_policy(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ExportPolicyRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ExportPolicyResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/penumbra.custody.v1.CustodyService/ExportPolicy",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new("penumbra.custody.v1.CustodyService", "ExportPolicy"),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// Replaces the authorization policy enforced by the custodian with a signed
+        /// policy bundle.
+        ///
+        /// The bundle must be signed by a quorum of the policy signers configured in
+        /// the custodian, and its version must be newer than the current policy's, so
+        /// that an older bundle can't be replayed to roll back a later change.
+        pub async fn import_policy(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ImportPolicyRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ImportPolicyResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/penumbra.custody.v1.CustodyService/ImportPolicy",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new("penumbra.custody.v1.CustodyService", "ImportPolicy"),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+    }
+}
+/// Generated server implementations.
+#[cfg(feature = "rpc")]
+pub mod custody_service_server {
+    #![allow(unused_variables, dead_code, missing_docs, clippy::let_unit_value)]
+    use tonic::codegen::*;
+    /// Generated trait containing gRPC methods that should be implemented for use with CustodyServiceServer.
+    #[async_trait]
+    pub trait CustodyService: Send + Sync + 'static {
+        /// Requests authorization of the transaction with the given description.
+        async fn authorize(
+            &self,
+            request: tonic::Request<super::AuthorizeRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::AuthorizeResponse>,
+            tonic::Status,
+        >;
+        /// Server streaming response type for the AuthorizeStream method.
+        type AuthorizeStreamStream: tonic::codegen::tokio_stream::Stream<
+                Item = std::result::Result<super::AuthorizeStreamResponse, tonic::Status>,
+            >
+            + Send
+            + 'static;
+        /// Requests authorization of the transaction with the given description,
+        /// streaming status updates while the request is being processed.
+        ///
+        /// This is useful for custody backends that may take a long time to respond,
+        /// such as hardware wallets or threshold signing ceremonies, allowing clients
+        /// to display progress rather than blocking on a single response.
+        ///
+        /// The stream ends after either an `Approved` or a `Denied` update.
+        async fn authorize_stream(
+            &self,
+            request: tonic::Request<super::AuthorizeRequest>,
+        ) -> std::result::Result<
+            tonic::Response<Self::AuthorizeStreamStream>,
+            tonic::Status,
+        >;
+        /// Checks whether the custodian would authorize the transaction with the
+        /// given description, reporting the outcome of each of its checks, without
+        /// producing any signatures.
+        ///
+        /// A simulation does not consume the request's nonce, and is not recorded in
+        /// the audit log. Custodians which can't simulate authorization, including
+        /// those which predate this method, return `UNIMPLEMENTED`; since this is a
+        /// separate method, they never mistake a simulation for a real request.
+        async fn simulate_authorization(
+            &self,
+            request: tonic::Request<super::AuthorizeRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::SimulateAuthorizationResponse>,
+            tonic::Status,
+        >;
+        /// Requests a signature over an off-chain message, such as an attestation of
+        /// ownership of the custodian's addresses, made with the spend authorization
+        /// key.
+        ///
+        /// Each message is signed under a domain naming its purpose, which is hashed
+        /// together with it, so that the signature can't be mistaken for a
+        /// transaction authorization or for a signature under another domain.
+        /// Custodians should refuse to sign messages under domains their policy
+        /// doesn't explicitly allow.
+        async fn authorize_message(
+            &self,
+            request: tonic::Request<super::AuthorizeMessageRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::AuthorizeMessageResponse>,
+            tonic::Status,
+        >;
+        /// Requests the full viewing key from the custodian.
+        ///
+        /// Custody backends should decide whether to honor this request, and how to
+        /// control access to it.
+        async fn export_full_viewing_key(
+            &self,
+            request: tonic::Request<super::ExportFullViewingKeyRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ExportFullViewingKeyResponse>,
+            tonic::Status,
+        >;
+        /// Displays an address to a user for confirmation.
+        ///
+        /// Custody backends with user interaction should present the address to the
+        /// user and wait for explicit confirmation before returning.
+        ///
+        /// Non-interactive custody backends may return immediately.
+        async fn confirm_address(
+            &self,
+            request: tonic::Request<super::ConfirmAddressRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ConfirmAddressResponse>,
+            tonic::Status,
+        >;
+        /// Requests a single-use nonce, to be included in the next `AuthorizeRequest`.
+        ///
+        /// Binding each authorization request to a nonce issued by the custodian
+        /// ensures that a captured request can't be replayed against the custodian
+        /// later. Custody backends may require a nonce in every authorization request.
+        async fn challenge(
+            &self,
+            request: tonic::Request<super::ChallengeRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ChallengeResponse>,
+            tonic::Status,
+        >;
+        /// Queries the custodian's audit log of authorization decisions.
+        ///
+        /// Each entry is signed by the custodian's identity key, and commits to the
+        /// entry before it, so that the log can be checked for tampering.
+        async fn query_audit_log(
+            &self,
+            request: tonic::Request<super::QueryAuditLogRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::QueryAuditLogResponse>,
+            tonic::Status,
+        >;
+        /// Freezes the custodian, which then refuses every authorization request
+        /// until it is unfrozen.
+        ///
+        /// This is a kill switch for operators who suspect that a key or a client has
+        /// been compromised, so custody backends should honor it from any client
+        /// allowed to request authorizations. Freezing a frozen custodian has no
+        /// further effect.
+        async fn freeze(
+            &self,
+            request: tonic::Request<super::FreezeRequest>,
+        ) -> std::result::Result<tonic::Response<super::FreezeResponse>, tonic::Status>;
+        /// Unfreezes a frozen custodian.
+        ///
+        /// So that a compromised client can't simply undo a freeze, unfreezing
+        /// requires signatures over the freeze being lifted from a quorum of the
+        /// recovery keys configured in the custodian.
+        async fn unfreeze(
+            &self,
+            request: tonic::Request<super::UnfreezeRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::UnfreezeResponse>,
+            tonic::Status,
+        >;
+        /// Rotates the custodian's authorization key, the operator key whose
+        /// periodic rotation shows that the custodian is still under its operators'
+        /// control.
+        ///
+        /// Custody backends may be configured with an inactivity timeout, after which
+        /// they refuse every authorization request until the key is rotated. The
+        /// rotation must be signed by both the old and the new key, and the custodian
+        /// returns it countersigned by its identity key, as a record that the
+        /// rotation was confirmed.
+        async fn rotate_authorization_key(
+            &self,
+            request: tonic::Request<super::RotateAuthorizationKeyRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::RotateAuthorizationKeyResponse>,
+            tonic::Status,
+        >;
+        /// Exports the authorization policy enforced by the custodian, as a
+        /// versioned policy bundle, so that changes to it can be reviewed and signed.
+        async fn export_policy(
+            &self,
+            request: tonic::Request<super::ExportPolicyRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ExportPolicyResponse>,
+            tonic::Status,
+        >;
+        /// Replaces the authorization policy enforced by the custodian with a signed
+        /// policy bundle.
+        ///
+        /// The bundle must be signed by a quorum of the policy signers configured in
+        /// the custodian, and its version must be newer than the current policy's, so
+        /// that an older bundle can't be replayed to roll back a later change.
+        async fn import_policy(
+            &self,
+            request: tonic::Request<super::ImportPolicyRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ImportPolicyResponse>,
+            tonic::Status,
+        >;
+    }
+    /// The custody protocol is used by a wallet client to request authorization for
+    /// a transaction they've constructed.
     ///
     /// Modeling transaction authorization as an asynchronous RPC call encourages
     /// software to be written in a way that has a compatible data flow with a "soft
@@ -512,28 +1663,26 @@ pub mod custody_service_server {
                     };
                     Box::pin(fut)
                 }
-                "/penumbra.custody.v1.CustodyService/ExportFullViewingKey" => {
+                "/penumbra.custody.v1.CustodyService/AuthorizeStream" => {
                     #[allow(non_camel_case_types)]
-                    struct ExportFullViewingKeySvc<T: CustodyService>(pub Arc<T>);
+                    struct AuthorizeStreamSvc<T: CustodyService>(pub Arc<T>);
                     impl<
                         T: CustodyService,
-                    > tonic::server::UnaryService<super::ExportFullViewingKeyRequest>
-                    for ExportFullViewingKeySvc<T> {
-                        type Response = super::ExportFullViewingKeyResponse;
+                    > tonic::server::ServerStreamingService<super::AuthorizeRequest>
+                    for AuthorizeStreamSvc<T> {
+                        type Response = super::AuthorizeStreamResponse;
+                        type ResponseStream = T::AuthorizeStreamStream;
                         type Future = BoxFuture<
-                            tonic::Response<Self::Response>,
+                            tonic::Response<Self::ResponseStream>,
                             tonic::Status,
                         >;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::ExportFullViewingKeyRequest>,
+                            request: tonic::Request<super::AuthorizeRequest>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                <T as CustodyService>::export_full_viewing_key(
-                                        &inner,
-                                        request,
-                                    )
+                                <T as CustodyService>::authorize_stream(&inner, request)
                                     .await
                             };
                             Box::pin(fut)
@@ -546,7 +1695,7 @@ pub mod custody_service_server {
                     let inner = self.inner.clone();
                     let fut = async move {
                         let inner = inner.0;
-                        let method = ExportFullViewingKeySvc(inner);
+                        let method = AuthorizeStreamSvc(inner);
                         let codec = tonic::codec::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -557,30 +1706,33 @@ pub mod custody_service_server {
                                 max_decoding_message_size,
                                 max_encoding_message_size,
                             );
-                        let res = grpc.unary(method, req).await;
+                        let res = grpc.server_streaming(method, req).await;
                         Ok(res)
                     };
                     Box::pin(fut)
                 }
-                "/penumbra.custody.v1.CustodyService/ConfirmAddress" => {
+                "/penumbra.custody.v1.CustodyService/SimulateAuthorization" => {
                     #[allow(non_camel_case_types)]
-                    struct ConfirmAddressSvc<T: CustodyService>(pub Arc<T>);
+                    struct SimulateAuthorizationSvc<T: CustodyService>(pub Arc<T>);
                     impl<
                         T: CustodyService,
-                    > tonic::server::UnaryService<super::ConfirmAddressRequest>
-                    for ConfirmAddressSvc<T> {
-                        type Response = super::ConfirmAddressResponse;
+                    > tonic::server::UnaryService<super::AuthorizeRequest>
+                    for SimulateAuthorizationSvc<T> {
+                        type Response = super::SimulateAuthorizationResponse;
                         type Future = BoxFuture<
                             tonic::Response<Self::Response>,
                             tonic::Status,
                         >;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::ConfirmAddressRequest>,
+                            request: tonic::Request<super::AuthorizeRequest>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                <T as CustodyService>::confirm_address(&inner, request)
+                                <T as CustodyService>::simulate_authorization(
+                                        &inner,
+                                        request,
+                                    )
                                     .await
                             };
                             Box::pin(fut)
@@ -593,7 +1745,478 @@ pub mod custody_service_server {
                     let inner = self.inner.clone();
                     let fut = async move {
                         let inner = inner.0;
-                        let method = ConfirmAddressSvc(inner);
+                        let method = SimulateAuthorizationSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/penumbra.custody.v1.CustodyService/AuthorizeMessage" => {
+                    #[allow(non_camel_case_types)]
+                    struct AuthorizeMessageSvc<T: CustodyService>(pub Arc<T>);
+                    impl<
+                        T: CustodyService,
+                    > tonic::server::UnaryService<super::AuthorizeMessageRequest>
+                    for AuthorizeMessageSvc<T> {
+                        type Response = super::AuthorizeMessageResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::AuthorizeMessageRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as CustodyService>::authorize_message(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = AuthorizeMessageSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/penumbra.custody.v1.CustodyService/ExportFullViewingKey" => {
+                    #[allow(non_camel_case_types)]
+                    struct ExportFullViewingKeySvc<T: CustodyService>(pub Arc<T>);
+                    impl<
+                        T: CustodyService,
+                    > tonic::server::UnaryService<super::ExportFullViewingKeyRequest>
+                    for ExportFullViewingKeySvc<T> {
+                        type Response = super::ExportFullViewingKeyResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::ExportFullViewingKeyRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as CustodyService>::export_full_viewing_key(
+                                        &inner,
+                                        request,
+                                    )
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = ExportFullViewingKeySvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/penumbra.custody.v1.CustodyService/ConfirmAddress" => {
+                    #[allow(non_camel_case_types)]
+                    struct ConfirmAddressSvc<T: CustodyService>(pub Arc<T>);
+                    impl<
+                        T: CustodyService,
+                    > tonic::server::UnaryService<super::ConfirmAddressRequest>
+                    for ConfirmAddressSvc<T> {
+                        type Response = super::ConfirmAddressResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::ConfirmAddressRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as CustodyService>::confirm_address(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = ConfirmAddressSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/penumbra.custody.v1.CustodyService/Challenge" => {
+                    #[allow(non_camel_case_types)]
+                    struct ChallengeSvc<T: CustodyService>(pub Arc<T>);
+                    impl<
+                        T: CustodyService,
+                    > tonic::server::UnaryService<super::ChallengeRequest>
+                    for ChallengeSvc<T> {
+                        type Response = super::ChallengeResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::ChallengeRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as CustodyService>::challenge(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = ChallengeSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/penumbra.custody.v1.CustodyService/QueryAuditLog" => {
+                    #[allow(non_camel_case_types)]
+                    struct QueryAuditLogSvc<T: CustodyService>(pub Arc<T>);
+                    impl<
+                        T: CustodyService,
+                    > tonic::server::UnaryService<super::QueryAuditLogRequest>
+                    for QueryAuditLogSvc<T> {
+                        type Response = super::QueryAuditLogResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::QueryAuditLogRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as CustodyService>::query_audit_log(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = QueryAuditLogSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/penumbra.custody.v1.CustodyService/Freeze" => {
+                    #[allow(non_camel_case_types)]
+                    struct FreezeSvc<T: CustodyService>(pub Arc<T>);
+                    impl<
+                        T: CustodyService,
+                    > tonic::server::UnaryService<super::FreezeRequest>
+                    for FreezeSvc<T> {
+                        type Response = super::FreezeResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::FreezeRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as CustodyService>::freeze(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = FreezeSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/penumbra.custody.v1.CustodyService/Unfreeze" => {
+                    #[allow(non_camel_case_types)]
+                    struct UnfreezeSvc<T: CustodyService>(pub Arc<T>);
+                    impl<
+                        T: CustodyService,
+                    > tonic::server::UnaryService<super::UnfreezeRequest>
+                    for UnfreezeSvc<T> {
+                        type Response = super::UnfreezeResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::UnfreezeRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as CustodyService>::unfreeze(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = UnfreezeSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/penumbra.custody.v1.CustodyService/RotateAuthorizationKey" => {
+                    #[allow(non_camel_case_types)]
+                    struct RotateAuthorizationKeySvc<T: CustodyService>(pub Arc<T>);
+                    impl<
+                        T: CustodyService,
+                    > tonic::server::UnaryService<super::RotateAuthorizationKeyRequest>
+                    for RotateAuthorizationKeySvc<T> {
+                        type Response = super::RotateAuthorizationKeyResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::RotateAuthorizationKeyRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as CustodyService>::rotate_authorization_key(
+                                        &inner,
+                                        request,
+                                    )
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = RotateAuthorizationKeySvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/penumbra.custody.v1.CustodyService/ExportPolicy" => {
+                    #[allow(non_camel_case_types)]
+                    struct ExportPolicySvc<T: CustodyService>(pub Arc<T>);
+                    impl<
+                        T: CustodyService,
+                    > tonic::server::UnaryService<super::ExportPolicyRequest>
+                    for ExportPolicySvc<T> {
+                        type Response = super::ExportPolicyResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::ExportPolicyRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as CustodyService>::export_policy(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = ExportPolicySvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/penumbra.custody.v1.CustodyService/ImportPolicy" => {
+                    #[allow(non_camel_case_types)]
+                    struct ImportPolicySvc<T: CustodyService>(pub Arc<T>);
+                    impl<
+                        T: CustodyService,
+                    > tonic::server::UnaryService<super::ImportPolicyRequest>
+                    for ImportPolicySvc<T> {
+                        type Response = super::ImportPolicyResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::ImportPolicyRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as CustodyService>::import_policy(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = ImportPolicySvc(inner);
                         let codec = tonic::codec::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -1,3 +1,588 @@
+impl serde::Serialize for Approver {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.key.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.custody.v1.Approver", len)?;
+        if let Some(v) = self.key.as_ref() {
+            match v {
+                approver::Key::Ed25519(v) => {
+                    #[allow(clippy::needless_borrow)]
+                    struct_ser.serialize_field("ed25519", pbjson::private::base64::encode(&v).as_str())?;
+                }
+                approver::Key::Secp256k1(v) => {
+                    #[allow(clippy::needless_borrow)]
+                    struct_ser.serialize_field("secp256k1", pbjson::private::base64::encode(&v).as_str())?;
+                }
+                approver::Key::WebAuthn(v) => {
+                    #[allow(clippy::needless_borrow)]
+                    struct_ser.serialize_field("webAuthn", pbjson::private::base64::encode(&v).as_str())?;
+                }
+            }
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for Approver {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "ed25519",
+            "secp256k1",
+            "web_authn",
+            "webAuthn",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Ed25519,
+            Secp256k1,
+            WebAuthn,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "ed25519" => Ok(GeneratedField::Ed25519),
+                            "secp256k1" => Ok(GeneratedField::Secp256k1),
+                            "webAuthn" | "web_authn" => Ok(GeneratedField::WebAuthn),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = Approver;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.custody.v1.Approver")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<Approver, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut key__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Ed25519 => {
+                            if key__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("ed25519"));
+                            }
+                            key__ = map_.next_value::<::std::option::Option<::pbjson::private::BytesDeserialize<_>>>()?.map(|x| approver::Key::Ed25519(x.0));
+                        }
+                        GeneratedField::Secp256k1 => {
+                            if key__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("secp256k1"));
+                            }
+                            key__ = map_.next_value::<::std::option::Option<::pbjson::private::BytesDeserialize<_>>>()?.map(|x| approver::Key::Secp256k1(x.0));
+                        }
+                        GeneratedField::WebAuthn => {
+                            if key__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("webAuthn"));
+                            }
+                            key__ = map_.next_value::<::std::option::Option<::pbjson::private::BytesDeserialize<_>>>()?.map(|x| approver::Key::WebAuthn(x.0));
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(Approver {
+                    key: key__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.custody.v1.Approver", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for AuditLogEntry {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.index != 0 {
+            len += 1;
+        }
+        if self.timestamp != 0 {
+            len += 1;
+        }
+        if !self.plan_text_hash.is_empty() {
+            len += 1;
+        }
+        if !self.policy_denial.is_empty() {
+            len += 1;
+        }
+        if self.approved {
+            len += 1;
+        }
+        if !self.denial_reason.is_empty() {
+            len += 1;
+        }
+        if !self.previous_hash.is_empty() {
+            len += 1;
+        }
+        if !self.approvers.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.custody.v1.AuditLogEntry", len)?;
+        if self.index != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("index", ToString::to_string(&self.index).as_str())?;
+        }
+        if self.timestamp != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("timestamp", ToString::to_string(&self.timestamp).as_str())?;
+        }
+        if !self.plan_text_hash.is_empty() {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("planTextHash", pbjson::private::base64::encode(&self.plan_text_hash).as_str())?;
+        }
+        if !self.policy_denial.is_empty() {
+            struct_ser.serialize_field("policyDenial", &self.policy_denial)?;
+        }
+        if self.approved {
+            struct_ser.serialize_field("approved", &self.approved)?;
+        }
+        if !self.denial_reason.is_empty() {
+            struct_ser.serialize_field("denialReason", &self.denial_reason)?;
+        }
+        if !self.previous_hash.is_empty() {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("previousHash", pbjson::private::base64::encode(&self.previous_hash).as_str())?;
+        }
+        if !self.approvers.is_empty() {
+            struct_ser.serialize_field("approvers", &self.approvers)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for AuditLogEntry {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "index",
+            "timestamp",
+            "plan_text_hash",
+            "planTextHash",
+            "policy_denial",
+            "policyDenial",
+            "approved",
+            "denial_reason",
+            "denialReason",
+            "previous_hash",
+            "previousHash",
+            "approvers",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Index,
+            Timestamp,
+            PlanTextHash,
+            PolicyDenial,
+            Approved,
+            DenialReason,
+            PreviousHash,
+            Approvers,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "index" => Ok(GeneratedField::Index),
+                            "timestamp" => Ok(GeneratedField::Timestamp),
+                            "planTextHash" | "plan_text_hash" => Ok(GeneratedField::PlanTextHash),
+                            "policyDenial" | "policy_denial" => Ok(GeneratedField::PolicyDenial),
+                            "approved" => Ok(GeneratedField::Approved),
+                            "denialReason" | "denial_reason" => Ok(GeneratedField::DenialReason),
+                            "previousHash" | "previous_hash" => Ok(GeneratedField::PreviousHash),
+                            "approvers" => Ok(GeneratedField::Approvers),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = AuditLogEntry;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.custody.v1.AuditLogEntry")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<AuditLogEntry, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut index__ = None;
+                let mut timestamp__ = None;
+                let mut plan_text_hash__ = None;
+                let mut policy_denial__ = None;
+                let mut approved__ = None;
+                let mut denial_reason__ = None;
+                let mut previous_hash__ = None;
+                let mut approvers__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Index => {
+                            if index__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("index"));
+                            }
+                            index__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::Timestamp => {
+                            if timestamp__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("timestamp"));
+                            }
+                            timestamp__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::PlanTextHash => {
+                            if plan_text_hash__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("planTextHash"));
+                            }
+                            plan_text_hash__ = 
+                                Some(map_.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::PolicyDenial => {
+                            if policy_denial__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("policyDenial"));
+                            }
+                            policy_denial__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Approved => {
+                            if approved__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("approved"));
+                            }
+                            approved__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::DenialReason => {
+                            if denial_reason__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("denialReason"));
+                            }
+                            denial_reason__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::PreviousHash => {
+                            if previous_hash__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("previousHash"));
+                            }
+                            previous_hash__ = 
+                                Some(map_.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::Approvers => {
+                            if approvers__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("approvers"));
+                            }
+                            approvers__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(AuditLogEntry {
+                    index: index__.unwrap_or_default(),
+                    timestamp: timestamp__.unwrap_or_default(),
+                    plan_text_hash: plan_text_hash__.unwrap_or_default(),
+                    policy_denial: policy_denial__.unwrap_or_default(),
+                    approved: approved__.unwrap_or_default(),
+                    denial_reason: denial_reason__.unwrap_or_default(),
+                    previous_hash: previous_hash__.unwrap_or_default(),
+                    approvers: approvers__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.custody.v1.AuditLogEntry", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for AuthorizeMessageRequest {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.domain.is_empty() {
+            len += 1;
+        }
+        if !self.message.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.custody.v1.AuthorizeMessageRequest", len)?;
+        if !self.domain.is_empty() {
+            struct_ser.serialize_field("domain", &self.domain)?;
+        }
+        if !self.message.is_empty() {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("message", pbjson::private::base64::encode(&self.message).as_str())?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for AuthorizeMessageRequest {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "domain",
+            "message",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Domain,
+            Message,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "domain" => Ok(GeneratedField::Domain),
+                            "message" => Ok(GeneratedField::Message),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = AuthorizeMessageRequest;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.custody.v1.AuthorizeMessageRequest")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<AuthorizeMessageRequest, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut domain__ = None;
+                let mut message__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Domain => {
+                            if domain__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("domain"));
+                            }
+                            domain__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Message => {
+                            if message__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("message"));
+                            }
+                            message__ = 
+                                Some(map_.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(AuthorizeMessageRequest {
+                    domain: domain__.unwrap_or_default(),
+                    message: message__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.custody.v1.AuthorizeMessageRequest", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for AuthorizeMessageResponse {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.signature.is_some() {
+            len += 1;
+        }
+        if self.verification_key.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.custody.v1.AuthorizeMessageResponse", len)?;
+        if let Some(v) = self.signature.as_ref() {
+            struct_ser.serialize_field("signature", v)?;
+        }
+        if let Some(v) = self.verification_key.as_ref() {
+            struct_ser.serialize_field("verificationKey", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for AuthorizeMessageResponse {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "signature",
+            "verification_key",
+            "verificationKey",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Signature,
+            VerificationKey,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "signature" => Ok(GeneratedField::Signature),
+                            "verificationKey" | "verification_key" => Ok(GeneratedField::VerificationKey),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = AuthorizeMessageResponse;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.custody.v1.AuthorizeMessageResponse")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<AuthorizeMessageResponse, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut signature__ = None;
+                let mut verification_key__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Signature => {
+                            if signature__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("signature"));
+                            }
+                            signature__ = map_.next_value()?;
+                        }
+                        GeneratedField::VerificationKey => {
+                            if verification_key__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("verificationKey"));
+                            }
+                            verification_key__ = map_.next_value()?;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(AuthorizeMessageResponse {
+                    signature: signature__,
+                    verification_key: verification_key__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.custody.v1.AuthorizeMessageResponse", FIELDS, GeneratedVisitor)
+    }
+}
 impl serde::Serialize for AuthorizeRequest {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
@@ -6,38 +591,3033 @@ impl serde::Serialize for AuthorizeRequest {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.plan.is_some() {
+        if self.plan.is_some() {
+            len += 1;
+        }
+        if !self.pre_authorizations.is_empty() {
+            len += 1;
+        }
+        if !self.nonce.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.custody.v1.AuthorizeRequest", len)?;
+        if let Some(v) = self.plan.as_ref() {
+            struct_ser.serialize_field("plan", v)?;
+        }
+        if !self.pre_authorizations.is_empty() {
+            struct_ser.serialize_field("preAuthorizations", &self.pre_authorizations)?;
+        }
+        if !self.nonce.is_empty() {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("nonce", pbjson::private::base64::encode(&self.nonce).as_str())?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for AuthorizeRequest {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "plan",
+            "pre_authorizations",
+            "preAuthorizations",
+            "nonce",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Plan,
+            PreAuthorizations,
+            Nonce,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "plan" => Ok(GeneratedField::Plan),
+                            "preAuthorizations" | "pre_authorizations" => Ok(GeneratedField::PreAuthorizations),
+                            "nonce" => Ok(GeneratedField::Nonce),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = AuthorizeRequest;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.custody.v1.AuthorizeRequest")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<AuthorizeRequest, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut plan__ = None;
+                let mut pre_authorizations__ = None;
+                let mut nonce__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Plan => {
+                            if plan__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("plan"));
+                            }
+                            plan__ = map_.next_value()?;
+                        }
+                        GeneratedField::PreAuthorizations => {
+                            if pre_authorizations__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("preAuthorizations"));
+                            }
+                            pre_authorizations__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Nonce => {
+                            if nonce__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("nonce"));
+                            }
+                            nonce__ = 
+                                Some(map_.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(AuthorizeRequest {
+                    plan: plan__,
+                    pre_authorizations: pre_authorizations__.unwrap_or_default(),
+                    nonce: nonce__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.custody.v1.AuthorizeRequest", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for AuthorizeResponse {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.data.is_some() {
+            len += 1;
+        }
+        if self.summary.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.custody.v1.AuthorizeResponse", len)?;
+        if let Some(v) = self.data.as_ref() {
+            struct_ser.serialize_field("data", v)?;
+        }
+        if let Some(v) = self.summary.as_ref() {
+            struct_ser.serialize_field("summary", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for AuthorizeResponse {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "data",
+            "summary",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Data,
+            Summary,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "data" => Ok(GeneratedField::Data),
+                            "summary" => Ok(GeneratedField::Summary),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = AuthorizeResponse;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.custody.v1.AuthorizeResponse")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<AuthorizeResponse, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut data__ = None;
+                let mut summary__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Data => {
+                            if data__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("data"));
+                            }
+                            data__ = map_.next_value()?;
+                        }
+                        GeneratedField::Summary => {
+                            if summary__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("summary"));
+                            }
+                            summary__ = map_.next_value()?;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(AuthorizeResponse {
+                    data: data__,
+                    summary: summary__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.custody.v1.AuthorizeResponse", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for AuthorizeSimulation {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.approved {
+            len += 1;
+        }
+        if !self.checks.is_empty() {
+            len += 1;
+        }
+        if !self.approvers.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.custody.v1.AuthorizeSimulation", len)?;
+        if self.approved {
+            struct_ser.serialize_field("approved", &self.approved)?;
+        }
+        if !self.checks.is_empty() {
+            struct_ser.serialize_field("checks", &self.checks)?;
+        }
+        if !self.approvers.is_empty() {
+            struct_ser.serialize_field("approvers", &self.approvers)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for AuthorizeSimulation {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "approved",
+            "checks",
+            "approvers",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Approved,
+            Checks,
+            Approvers,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "approved" => Ok(GeneratedField::Approved),
+                            "checks" => Ok(GeneratedField::Checks),
+                            "approvers" => Ok(GeneratedField::Approvers),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = AuthorizeSimulation;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.custody.v1.AuthorizeSimulation")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<AuthorizeSimulation, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut approved__ = None;
+                let mut checks__ = None;
+                let mut approvers__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Approved => {
+                            if approved__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("approved"));
+                            }
+                            approved__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Checks => {
+                            if checks__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("checks"));
+                            }
+                            checks__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Approvers => {
+                            if approvers__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("approvers"));
+                            }
+                            approvers__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(AuthorizeSimulation {
+                    approved: approved__.unwrap_or_default(),
+                    checks: checks__.unwrap_or_default(),
+                    approvers: approvers__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.custody.v1.AuthorizeSimulation", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for authorize_simulation::Check {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.name.is_empty() {
+            len += 1;
+        }
+        if !self.denial.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.custody.v1.AuthorizeSimulation.Check", len)?;
+        if !self.name.is_empty() {
+            struct_ser.serialize_field("name", &self.name)?;
+        }
+        if !self.denial.is_empty() {
+            struct_ser.serialize_field("denial", &self.denial)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for authorize_simulation::Check {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "name",
+            "denial",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Name,
+            Denial,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "name" => Ok(GeneratedField::Name),
+                            "denial" => Ok(GeneratedField::Denial),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = authorize_simulation::Check;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.custody.v1.AuthorizeSimulation.Check")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<authorize_simulation::Check, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut name__ = None;
+                let mut denial__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Name => {
+                            if name__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("name"));
+                            }
+                            name__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Denial => {
+                            if denial__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("denial"));
+                            }
+                            denial__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(authorize_simulation::Check {
+                    name: name__.unwrap_or_default(),
+                    denial: denial__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.custody.v1.AuthorizeSimulation.Check", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for AuthorizeStreamResponse {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.status.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.custody.v1.AuthorizeStreamResponse", len)?;
+        if let Some(v) = self.status.as_ref() {
+            match v {
+                authorize_stream_response::Status::PendingUserApproval(v) => {
+                    struct_ser.serialize_field("pendingUserApproval", v)?;
+                }
+                authorize_stream_response::Status::AwaitingCosigner(v) => {
+                    struct_ser.serialize_field("awaitingCosigner", v)?;
+                }
+                authorize_stream_response::Status::Approved(v) => {
+                    struct_ser.serialize_field("approved", v)?;
+                }
+                authorize_stream_response::Status::Denied(v) => {
+                    struct_ser.serialize_field("denied", v)?;
+                }
+            }
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for AuthorizeStreamResponse {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "pending_user_approval",
+            "pendingUserApproval",
+            "awaiting_cosigner",
+            "awaitingCosigner",
+            "approved",
+            "denied",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            PendingUserApproval,
+            AwaitingCosigner,
+            Approved,
+            Denied,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "pendingUserApproval" | "pending_user_approval" => Ok(GeneratedField::PendingUserApproval),
+                            "awaitingCosigner" | "awaiting_cosigner" => Ok(GeneratedField::AwaitingCosigner),
+                            "approved" => Ok(GeneratedField::Approved),
+                            "denied" => Ok(GeneratedField::Denied),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = AuthorizeStreamResponse;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.custody.v1.AuthorizeStreamResponse")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<AuthorizeStreamResponse, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut status__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::PendingUserApproval => {
+                            if status__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("pendingUserApproval"));
+                            }
+                            status__ = map_.next_value::<::std::option::Option<_>>()?.map(authorize_stream_response::Status::PendingUserApproval)
+;
+                        }
+                        GeneratedField::AwaitingCosigner => {
+                            if status__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("awaitingCosigner"));
+                            }
+                            status__ = map_.next_value::<::std::option::Option<_>>()?.map(authorize_stream_response::Status::AwaitingCosigner)
+;
+                        }
+                        GeneratedField::Approved => {
+                            if status__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("approved"));
+                            }
+                            status__ = map_.next_value::<::std::option::Option<_>>()?.map(authorize_stream_response::Status::Approved)
+;
+                        }
+                        GeneratedField::Denied => {
+                            if status__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("denied"));
+                            }
+                            status__ = map_.next_value::<::std::option::Option<_>>()?.map(authorize_stream_response::Status::Denied)
+;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(AuthorizeStreamResponse {
+                    status: status__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.custody.v1.AuthorizeStreamResponse", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for authorize_stream_response::Approved {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.data.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.custody.v1.AuthorizeStreamResponse.Approved", len)?;
+        if let Some(v) = self.data.as_ref() {
+            struct_ser.serialize_field("data", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for authorize_stream_response::Approved {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "data",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Data,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "data" => Ok(GeneratedField::Data),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = authorize_stream_response::Approved;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.custody.v1.AuthorizeStreamResponse.Approved")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<authorize_stream_response::Approved, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut data__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Data => {
+                            if data__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("data"));
+                            }
+                            data__ = map_.next_value()?;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(authorize_stream_response::Approved {
+                    data: data__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.custody.v1.AuthorizeStreamResponse.Approved", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for authorize_stream_response::AwaitingCosigner {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.received != 0 {
+            len += 1;
+        }
+        if self.required != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.custody.v1.AuthorizeStreamResponse.AwaitingCosigner", len)?;
+        if self.received != 0 {
+            struct_ser.serialize_field("received", &self.received)?;
+        }
+        if self.required != 0 {
+            struct_ser.serialize_field("required", &self.required)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for authorize_stream_response::AwaitingCosigner {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "received",
+            "required",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Received,
+            Required,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "received" => Ok(GeneratedField::Received),
+                            "required" => Ok(GeneratedField::Required),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = authorize_stream_response::AwaitingCosigner;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.custody.v1.AuthorizeStreamResponse.AwaitingCosigner")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<authorize_stream_response::AwaitingCosigner, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut received__ = None;
+                let mut required__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Received => {
+                            if received__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("received"));
+                            }
+                            received__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::Required => {
+                            if required__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("required"));
+                            }
+                            required__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(authorize_stream_response::AwaitingCosigner {
+                    received: received__.unwrap_or_default(),
+                    required: required__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.custody.v1.AuthorizeStreamResponse.AwaitingCosigner", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for authorize_stream_response::Denied {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.reason.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.custody.v1.AuthorizeStreamResponse.Denied", len)?;
+        if !self.reason.is_empty() {
+            struct_ser.serialize_field("reason", &self.reason)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for authorize_stream_response::Denied {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "reason",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Reason,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "reason" => Ok(GeneratedField::Reason),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = authorize_stream_response::Denied;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.custody.v1.AuthorizeStreamResponse.Denied")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<authorize_stream_response::Denied, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut reason__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Reason => {
+                            if reason__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("reason"));
+                            }
+                            reason__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(authorize_stream_response::Denied {
+                    reason: reason__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.custody.v1.AuthorizeStreamResponse.Denied", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for authorize_stream_response::PendingUserApproval {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let len = 0;
+        let struct_ser = serializer.serialize_struct("penumbra.custody.v1.AuthorizeStreamResponse.PendingUserApproval", len)?;
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for authorize_stream_response::PendingUserApproval {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                            Ok(GeneratedField::__SkipField__)
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = authorize_stream_response::PendingUserApproval;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.custody.v1.AuthorizeStreamResponse.PendingUserApproval")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<authorize_stream_response::PendingUserApproval, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                while map_.next_key::<GeneratedField>()?.is_some() {
+                    let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                }
+                Ok(authorize_stream_response::PendingUserApproval {
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.custody.v1.AuthorizeStreamResponse.PendingUserApproval", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for ChallengeRequest {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let len = 0;
+        let struct_ser = serializer.serialize_struct("penumbra.custody.v1.ChallengeRequest", len)?;
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for ChallengeRequest {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                            Ok(GeneratedField::__SkipField__)
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = ChallengeRequest;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.custody.v1.ChallengeRequest")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ChallengeRequest, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                while map_.next_key::<GeneratedField>()?.is_some() {
+                    let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                }
+                Ok(ChallengeRequest {
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.custody.v1.ChallengeRequest", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for ChallengeResponse {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.nonce.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.custody.v1.ChallengeResponse", len)?;
+        if !self.nonce.is_empty() {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("nonce", pbjson::private::base64::encode(&self.nonce).as_str())?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for ChallengeResponse {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "nonce",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Nonce,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "nonce" => Ok(GeneratedField::Nonce),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = ChallengeResponse;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.custody.v1.ChallengeResponse")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ChallengeResponse, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut nonce__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Nonce => {
+                            if nonce__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("nonce"));
+                            }
+                            nonce__ = 
+                                Some(map_.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(ChallengeResponse {
+                    nonce: nonce__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.custody.v1.ChallengeResponse", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for ConfirmAddressRequest {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.address_index.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.custody.v1.ConfirmAddressRequest", len)?;
+        if let Some(v) = self.address_index.as_ref() {
+            struct_ser.serialize_field("addressIndex", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for ConfirmAddressRequest {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "address_index",
+            "addressIndex",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            AddressIndex,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "addressIndex" | "address_index" => Ok(GeneratedField::AddressIndex),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = ConfirmAddressRequest;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.custody.v1.ConfirmAddressRequest")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ConfirmAddressRequest, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut address_index__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::AddressIndex => {
+                            if address_index__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("addressIndex"));
+                            }
+                            address_index__ = map_.next_value()?;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(ConfirmAddressRequest {
+                    address_index: address_index__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.custody.v1.ConfirmAddressRequest", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for ConfirmAddressResponse {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.address.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.custody.v1.ConfirmAddressResponse", len)?;
+        if let Some(v) = self.address.as_ref() {
+            struct_ser.serialize_field("address", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for ConfirmAddressResponse {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "address",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Address,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "address" => Ok(GeneratedField::Address),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = ConfirmAddressResponse;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.custody.v1.ConfirmAddressResponse")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ConfirmAddressResponse, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut address__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Address => {
+                            if address__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("address"));
+                            }
+                            address__ = map_.next_value()?;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(ConfirmAddressResponse {
+                    address: address__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.custody.v1.ConfirmAddressResponse", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for ExportFullViewingKeyRequest {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let len = 0;
+        let struct_ser = serializer.serialize_struct("penumbra.custody.v1.ExportFullViewingKeyRequest", len)?;
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for ExportFullViewingKeyRequest {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                            Ok(GeneratedField::__SkipField__)
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = ExportFullViewingKeyRequest;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.custody.v1.ExportFullViewingKeyRequest")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ExportFullViewingKeyRequest, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                while map_.next_key::<GeneratedField>()?.is_some() {
+                    let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                }
+                Ok(ExportFullViewingKeyRequest {
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.custody.v1.ExportFullViewingKeyRequest", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for ExportFullViewingKeyResponse {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.full_viewing_key.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.custody.v1.ExportFullViewingKeyResponse", len)?;
+        if let Some(v) = self.full_viewing_key.as_ref() {
+            struct_ser.serialize_field("fullViewingKey", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for ExportFullViewingKeyResponse {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "full_viewing_key",
+            "fullViewingKey",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            FullViewingKey,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "fullViewingKey" | "full_viewing_key" => Ok(GeneratedField::FullViewingKey),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = ExportFullViewingKeyResponse;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.custody.v1.ExportFullViewingKeyResponse")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ExportFullViewingKeyResponse, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut full_viewing_key__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::FullViewingKey => {
+                            if full_viewing_key__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("fullViewingKey"));
+                            }
+                            full_viewing_key__ = map_.next_value()?;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(ExportFullViewingKeyResponse {
+                    full_viewing_key: full_viewing_key__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.custody.v1.ExportFullViewingKeyResponse", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for ExportPolicyRequest {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let len = 0;
+        let struct_ser = serializer.serialize_struct("penumbra.custody.v1.ExportPolicyRequest", len)?;
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for ExportPolicyRequest {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                            Ok(GeneratedField::__SkipField__)
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = ExportPolicyRequest;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.custody.v1.ExportPolicyRequest")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ExportPolicyRequest, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                while map_.next_key::<GeneratedField>()?.is_some() {
+                    let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                }
+                Ok(ExportPolicyRequest {
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.custody.v1.ExportPolicyRequest", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for ExportPolicyResponse {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.bundle.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.custody.v1.ExportPolicyResponse", len)?;
+        if !self.bundle.is_empty() {
+            struct_ser.serialize_field("bundle", &self.bundle)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for ExportPolicyResponse {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "bundle",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Bundle,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "bundle" => Ok(GeneratedField::Bundle),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = ExportPolicyResponse;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.custody.v1.ExportPolicyResponse")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ExportPolicyResponse, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut bundle__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Bundle => {
+                            if bundle__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("bundle"));
+                            }
+                            bundle__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(ExportPolicyResponse {
+                    bundle: bundle__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.custody.v1.ExportPolicyResponse", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for FreezeRequest {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.reason.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.custody.v1.FreezeRequest", len)?;
+        if !self.reason.is_empty() {
+            struct_ser.serialize_field("reason", &self.reason)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for FreezeRequest {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "reason",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Reason,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "reason" => Ok(GeneratedField::Reason),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = FreezeRequest;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.custody.v1.FreezeRequest")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<FreezeRequest, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut reason__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Reason => {
+                            if reason__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("reason"));
+                            }
+                            reason__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(FreezeRequest {
+                    reason: reason__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.custody.v1.FreezeRequest", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for FreezeResponse {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.generation != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.custody.v1.FreezeResponse", len)?;
+        if self.generation != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("generation", ToString::to_string(&self.generation).as_str())?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for FreezeResponse {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "generation",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Generation,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "generation" => Ok(GeneratedField::Generation),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = FreezeResponse;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.custody.v1.FreezeResponse")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<FreezeResponse, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut generation__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Generation => {
+                            if generation__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("generation"));
+                            }
+                            generation__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(FreezeResponse {
+                    generation: generation__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.custody.v1.FreezeResponse", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for ImportPolicyRequest {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.signed_bundle.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.custody.v1.ImportPolicyRequest", len)?;
+        if !self.signed_bundle.is_empty() {
+            struct_ser.serialize_field("signedBundle", &self.signed_bundle)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for ImportPolicyRequest {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "signed_bundle",
+            "signedBundle",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            SignedBundle,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "signedBundle" | "signed_bundle" => Ok(GeneratedField::SignedBundle),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = ImportPolicyRequest;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.custody.v1.ImportPolicyRequest")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ImportPolicyRequest, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut signed_bundle__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::SignedBundle => {
+                            if signed_bundle__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("signedBundle"));
+                            }
+                            signed_bundle__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(ImportPolicyRequest {
+                    signed_bundle: signed_bundle__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.custody.v1.ImportPolicyRequest", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for ImportPolicyResponse {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.version != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.custody.v1.ImportPolicyResponse", len)?;
+        if self.version != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("version", ToString::to_string(&self.version).as_str())?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for ImportPolicyResponse {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "version",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Version,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "version" => Ok(GeneratedField::Version),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = ImportPolicyResponse;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.custody.v1.ImportPolicyResponse")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ImportPolicyResponse, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut version__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Version => {
+                            if version__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("version"));
+                            }
+                            version__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(ImportPolicyResponse {
+                    version: version__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.custody.v1.ImportPolicyResponse", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for KeyRotation {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.sequence != 0 {
+            len += 1;
+        }
+        if !self.old_key.is_empty() {
+            len += 1;
+        }
+        if !self.new_key.is_empty() {
+            len += 1;
+        }
+        if !self.custodian.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.custody.v1.KeyRotation", len)?;
+        if self.sequence != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("sequence", ToString::to_string(&self.sequence).as_str())?;
+        }
+        if !self.old_key.is_empty() {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("oldKey", pbjson::private::base64::encode(&self.old_key).as_str())?;
+        }
+        if !self.new_key.is_empty() {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("newKey", pbjson::private::base64::encode(&self.new_key).as_str())?;
+        }
+        if !self.custodian.is_empty() {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("custodian", pbjson::private::base64::encode(&self.custodian).as_str())?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for KeyRotation {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "sequence",
+            "old_key",
+            "oldKey",
+            "new_key",
+            "newKey",
+            "custodian",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Sequence,
+            OldKey,
+            NewKey,
+            Custodian,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "sequence" => Ok(GeneratedField::Sequence),
+                            "oldKey" | "old_key" => Ok(GeneratedField::OldKey),
+                            "newKey" | "new_key" => Ok(GeneratedField::NewKey),
+                            "custodian" => Ok(GeneratedField::Custodian),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = KeyRotation;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.custody.v1.KeyRotation")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<KeyRotation, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut sequence__ = None;
+                let mut old_key__ = None;
+                let mut new_key__ = None;
+                let mut custodian__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Sequence => {
+                            if sequence__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("sequence"));
+                            }
+                            sequence__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::OldKey => {
+                            if old_key__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("oldKey"));
+                            }
+                            old_key__ = 
+                                Some(map_.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::NewKey => {
+                            if new_key__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("newKey"));
+                            }
+                            new_key__ = 
+                                Some(map_.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::Custodian => {
+                            if custodian__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("custodian"));
+                            }
+                            custodian__ = 
+                                Some(map_.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(KeyRotation {
+                    sequence: sequence__.unwrap_or_default(),
+                    old_key: old_key__.unwrap_or_default(),
+                    new_key: new_key__.unwrap_or_default(),
+                    custodian: custodian__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.custody.v1.KeyRotation", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for PlanSummary {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.received.is_empty() {
+            len += 1;
+        }
+        if !self.sent.is_empty() {
+            len += 1;
+        }
+        if !self.destinations.is_empty() {
+            len += 1;
+        }
+        if !self.ics20_destinations.is_empty() {
+            len += 1;
+        }
+        if self.fee.is_some() {
+            len += 1;
+        }
+        if self.memo.is_some() {
+            len += 1;
+        }
+        if !self.plan_text_hash.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.custody.v1.PlanSummary", len)?;
+        if !self.received.is_empty() {
+            struct_ser.serialize_field("received", &self.received)?;
+        }
+        if !self.sent.is_empty() {
+            struct_ser.serialize_field("sent", &self.sent)?;
+        }
+        if !self.destinations.is_empty() {
+            struct_ser.serialize_field("destinations", &self.destinations)?;
+        }
+        if !self.ics20_destinations.is_empty() {
+            struct_ser.serialize_field("ics20Destinations", &self.ics20_destinations)?;
+        }
+        if let Some(v) = self.fee.as_ref() {
+            struct_ser.serialize_field("fee", v)?;
+        }
+        if let Some(v) = self.memo.as_ref() {
+            struct_ser.serialize_field("memo", v)?;
+        }
+        if !self.plan_text_hash.is_empty() {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("planTextHash", pbjson::private::base64::encode(&self.plan_text_hash).as_str())?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for PlanSummary {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "received",
+            "sent",
+            "destinations",
+            "ics20_destinations",
+            "ics20Destinations",
+            "fee",
+            "memo",
+            "plan_text_hash",
+            "planTextHash",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Received,
+            Sent,
+            Destinations,
+            Ics20Destinations,
+            Fee,
+            Memo,
+            PlanTextHash,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "received" => Ok(GeneratedField::Received),
+                            "sent" => Ok(GeneratedField::Sent),
+                            "destinations" => Ok(GeneratedField::Destinations),
+                            "ics20Destinations" | "ics20_destinations" => Ok(GeneratedField::Ics20Destinations),
+                            "fee" => Ok(GeneratedField::Fee),
+                            "memo" => Ok(GeneratedField::Memo),
+                            "planTextHash" | "plan_text_hash" => Ok(GeneratedField::PlanTextHash),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = PlanSummary;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.custody.v1.PlanSummary")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<PlanSummary, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut received__ = None;
+                let mut sent__ = None;
+                let mut destinations__ = None;
+                let mut ics20_destinations__ = None;
+                let mut fee__ = None;
+                let mut memo__ = None;
+                let mut plan_text_hash__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Received => {
+                            if received__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("received"));
+                            }
+                            received__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Sent => {
+                            if sent__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("sent"));
+                            }
+                            sent__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Destinations => {
+                            if destinations__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("destinations"));
+                            }
+                            destinations__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Ics20Destinations => {
+                            if ics20_destinations__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("ics20Destinations"));
+                            }
+                            ics20_destinations__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Fee => {
+                            if fee__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("fee"));
+                            }
+                            fee__ = map_.next_value()?;
+                        }
+                        GeneratedField::Memo => {
+                            if memo__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("memo"));
+                            }
+                            memo__ = map_.next_value()?;
+                        }
+                        GeneratedField::PlanTextHash => {
+                            if plan_text_hash__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("planTextHash"));
+                            }
+                            plan_text_hash__ = 
+                                Some(map_.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(PlanSummary {
+                    received: received__.unwrap_or_default(),
+                    sent: sent__.unwrap_or_default(),
+                    destinations: destinations__.unwrap_or_default(),
+                    ics20_destinations: ics20_destinations__.unwrap_or_default(),
+                    fee: fee__,
+                    memo: memo__,
+                    plan_text_hash: plan_text_hash__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.custody.v1.PlanSummary", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for PreAuthorization {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.pre_authorization.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.custody.v1.PreAuthorization", len)?;
+        if let Some(v) = self.pre_authorization.as_ref() {
+            match v {
+                pre_authorization::PreAuthorization::Ed25519(v) => {
+                    struct_ser.serialize_field("ed25519", v)?;
+                }
+                pre_authorization::PreAuthorization::Secp256k1(v) => {
+                    struct_ser.serialize_field("secp256k1", v)?;
+                }
+                pre_authorization::PreAuthorization::WebAuthn(v) => {
+                    struct_ser.serialize_field("webAuthn", v)?;
+                }
+            }
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for PreAuthorization {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "ed25519",
+            "secp256k1",
+            "web_authn",
+            "webAuthn",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Ed25519,
+            Secp256k1,
+            WebAuthn,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "ed25519" => Ok(GeneratedField::Ed25519),
+                            "secp256k1" => Ok(GeneratedField::Secp256k1),
+                            "webAuthn" | "web_authn" => Ok(GeneratedField::WebAuthn),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = PreAuthorization;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.custody.v1.PreAuthorization")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<PreAuthorization, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut pre_authorization__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Ed25519 => {
+                            if pre_authorization__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("ed25519"));
+                            }
+                            pre_authorization__ = map_.next_value::<::std::option::Option<_>>()?.map(pre_authorization::PreAuthorization::Ed25519)
+;
+                        }
+                        GeneratedField::Secp256k1 => {
+                            if pre_authorization__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("secp256k1"));
+                            }
+                            pre_authorization__ = map_.next_value::<::std::option::Option<_>>()?.map(pre_authorization::PreAuthorization::Secp256k1)
+;
+                        }
+                        GeneratedField::WebAuthn => {
+                            if pre_authorization__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("webAuthn"));
+                            }
+                            pre_authorization__ = map_.next_value::<::std::option::Option<_>>()?.map(pre_authorization::PreAuthorization::WebAuthn)
+;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(PreAuthorization {
+                    pre_authorization: pre_authorization__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.custody.v1.PreAuthorization", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for pre_authorization::Ed25519 {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.vk.is_empty() {
+            len += 1;
+        }
+        if !self.sig.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.custody.v1.PreAuthorization.Ed25519", len)?;
+        if !self.vk.is_empty() {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("vk", pbjson::private::base64::encode(&self.vk).as_str())?;
+        }
+        if !self.sig.is_empty() {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("sig", pbjson::private::base64::encode(&self.sig).as_str())?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for pre_authorization::Ed25519 {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "vk",
+            "sig",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Vk,
+            Sig,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "vk" => Ok(GeneratedField::Vk),
+                            "sig" => Ok(GeneratedField::Sig),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = pre_authorization::Ed25519;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.custody.v1.PreAuthorization.Ed25519")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<pre_authorization::Ed25519, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut vk__ = None;
+                let mut sig__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Vk => {
+                            if vk__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("vk"));
+                            }
+                            vk__ = 
+                                Some(map_.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::Sig => {
+                            if sig__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("sig"));
+                            }
+                            sig__ = 
+                                Some(map_.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(pre_authorization::Ed25519 {
+                    vk: vk__.unwrap_or_default(),
+                    sig: sig__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.custody.v1.PreAuthorization.Ed25519", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for pre_authorization::Secp256k1 {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.vk.is_empty() {
+            len += 1;
+        }
+        if !self.sig.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.custody.v1.PreAuthorization.Secp256k1", len)?;
+        if !self.vk.is_empty() {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("vk", pbjson::private::base64::encode(&self.vk).as_str())?;
+        }
+        if !self.sig.is_empty() {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("sig", pbjson::private::base64::encode(&self.sig).as_str())?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for pre_authorization::Secp256k1 {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "vk",
+            "sig",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Vk,
+            Sig,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "vk" => Ok(GeneratedField::Vk),
+                            "sig" => Ok(GeneratedField::Sig),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = pre_authorization::Secp256k1;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.custody.v1.PreAuthorization.Secp256k1")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<pre_authorization::Secp256k1, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut vk__ = None;
+                let mut sig__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Vk => {
+                            if vk__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("vk"));
+                            }
+                            vk__ = 
+                                Some(map_.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::Sig => {
+                            if sig__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("sig"));
+                            }
+                            sig__ = 
+                                Some(map_.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(pre_authorization::Secp256k1 {
+                    vk: vk__.unwrap_or_default(),
+                    sig: sig__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.custody.v1.PreAuthorization.Secp256k1", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for pre_authorization::WebAuthn {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.public_key.is_empty() {
+            len += 1;
+        }
+        if !self.authenticator_data.is_empty() {
+            len += 1;
+        }
+        if !self.client_data_json.is_empty() {
+            len += 1;
+        }
+        if !self.sig.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.custody.v1.PreAuthorization.WebAuthn", len)?;
+        if !self.public_key.is_empty() {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("publicKey", pbjson::private::base64::encode(&self.public_key).as_str())?;
+        }
+        if !self.authenticator_data.is_empty() {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("authenticatorData", pbjson::private::base64::encode(&self.authenticator_data).as_str())?;
+        }
+        if !self.client_data_json.is_empty() {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("clientDataJson", pbjson::private::base64::encode(&self.client_data_json).as_str())?;
+        }
+        if !self.sig.is_empty() {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("sig", pbjson::private::base64::encode(&self.sig).as_str())?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for pre_authorization::WebAuthn {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "public_key",
+            "publicKey",
+            "authenticator_data",
+            "authenticatorData",
+            "client_data_json",
+            "clientDataJson",
+            "sig",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            PublicKey,
+            AuthenticatorData,
+            ClientDataJson,
+            Sig,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "publicKey" | "public_key" => Ok(GeneratedField::PublicKey),
+                            "authenticatorData" | "authenticator_data" => Ok(GeneratedField::AuthenticatorData),
+                            "clientDataJson" | "client_data_json" => Ok(GeneratedField::ClientDataJson),
+                            "sig" => Ok(GeneratedField::Sig),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = pre_authorization::WebAuthn;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.custody.v1.PreAuthorization.WebAuthn")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<pre_authorization::WebAuthn, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut public_key__ = None;
+                let mut authenticator_data__ = None;
+                let mut client_data_json__ = None;
+                let mut sig__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::PublicKey => {
+                            if public_key__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("publicKey"));
+                            }
+                            public_key__ = 
+                                Some(map_.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::AuthenticatorData => {
+                            if authenticator_data__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("authenticatorData"));
+                            }
+                            authenticator_data__ = 
+                                Some(map_.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::ClientDataJson => {
+                            if client_data_json__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("clientDataJson"));
+                            }
+                            client_data_json__ = 
+                                Some(map_.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::Sig => {
+                            if sig__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("sig"));
+                            }
+                            sig__ = 
+                                Some(map_.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(pre_authorization::WebAuthn {
+                    public_key: public_key__.unwrap_or_default(),
+                    authenticator_data: authenticator_data__.unwrap_or_default(),
+                    client_data_json: client_data_json__.unwrap_or_default(),
+                    sig: sig__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.custody.v1.PreAuthorization.WebAuthn", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for QueryAuditLogRequest {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.start_index != 0 {
             len += 1;
         }
-        if !self.pre_authorizations.is_empty() {
+        if self.limit != 0 {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.custody.v1.AuthorizeRequest", len)?;
-        if let Some(v) = self.plan.as_ref() {
-            struct_ser.serialize_field("plan", v)?;
+        if self.start_time != 0 {
+            len += 1;
         }
-        if !self.pre_authorizations.is_empty() {
-            struct_ser.serialize_field("preAuthorizations", &self.pre_authorizations)?;
+        if self.end_time != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.custody.v1.QueryAuditLogRequest", len)?;
+        if self.start_index != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("startIndex", ToString::to_string(&self.start_index).as_str())?;
+        }
+        if self.limit != 0 {
+            struct_ser.serialize_field("limit", &self.limit)?;
+        }
+        if self.start_time != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("startTime", ToString::to_string(&self.start_time).as_str())?;
+        }
+        if self.end_time != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("endTime", ToString::to_string(&self.end_time).as_str())?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for AuthorizeRequest {
+impl<'de> serde::Deserialize<'de> for QueryAuditLogRequest {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "plan",
-            "pre_authorizations",
-            "preAuthorizations",
+            "start_index",
+            "startIndex",
+            "limit",
+            "start_time",
+            "startTime",
+            "end_time",
+            "endTime",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            Plan,
-            PreAuthorizations,
+            StartIndex,
+            Limit,
+            StartTime,
+            EndTime,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -60,8 +3640,10 @@ impl<'de> serde::Deserialize<'de> for AuthorizeRequest {
                         E: serde::de::Error,
                     {
                         match value {
-                            "plan" => Ok(GeneratedField::Plan),
-                            "preAuthorizations" | "pre_authorizations" => Ok(GeneratedField::PreAuthorizations),
+                            "startIndex" | "start_index" => Ok(GeneratedField::StartIndex),
+                            "limit" => Ok(GeneratedField::Limit),
+                            "startTime" | "start_time" => Ok(GeneratedField::StartTime),
+                            "endTime" | "end_time" => Ok(GeneratedField::EndTime),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -71,47 +3653,71 @@ impl<'de> serde::Deserialize<'de> for AuthorizeRequest {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = AuthorizeRequest;
+            type Value = QueryAuditLogRequest;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.custody.v1.AuthorizeRequest")
+                formatter.write_str("struct penumbra.custody.v1.QueryAuditLogRequest")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<AuthorizeRequest, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<QueryAuditLogRequest, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut plan__ = None;
-                let mut pre_authorizations__ = None;
+                let mut start_index__ = None;
+                let mut limit__ = None;
+                let mut start_time__ = None;
+                let mut end_time__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::Plan => {
-                            if plan__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("plan"));
+                        GeneratedField::StartIndex => {
+                            if start_index__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("startIndex"));
                             }
-                            plan__ = map_.next_value()?;
+                            start_index__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
                         }
-                        GeneratedField::PreAuthorizations => {
-                            if pre_authorizations__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("preAuthorizations"));
+                        GeneratedField::Limit => {
+                            if limit__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("limit"));
                             }
-                            pre_authorizations__ = Some(map_.next_value()?);
+                            limit__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::StartTime => {
+                            if start_time__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("startTime"));
+                            }
+                            start_time__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::EndTime => {
+                            if end_time__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("endTime"));
+                            }
+                            end_time__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(AuthorizeRequest {
-                    plan: plan__,
-                    pre_authorizations: pre_authorizations__.unwrap_or_default(),
+                Ok(QueryAuditLogRequest {
+                    start_index: start_index__.unwrap_or_default(),
+                    limit: limit__.unwrap_or_default(),
+                    start_time: start_time__.unwrap_or_default(),
+                    end_time: end_time__.unwrap_or_default(),
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.custody.v1.AuthorizeRequest", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.custody.v1.QueryAuditLogRequest", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for AuthorizeResponse {
+impl serde::Serialize for QueryAuditLogResponse {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -119,29 +3725,176 @@ impl serde::Serialize for AuthorizeResponse {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.data.is_some() {
+        if !self.entries.is_empty() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.custody.v1.AuthorizeResponse", len)?;
-        if let Some(v) = self.data.as_ref() {
-            struct_ser.serialize_field("data", v)?;
+        if self.next_index != 0 {
+            len += 1;
+        }
+        if !self.identity_key.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.custody.v1.QueryAuditLogResponse", len)?;
+        if !self.entries.is_empty() {
+            struct_ser.serialize_field("entries", &self.entries)?;
+        }
+        if self.next_index != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("nextIndex", ToString::to_string(&self.next_index).as_str())?;
+        }
+        if !self.identity_key.is_empty() {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("identityKey", pbjson::private::base64::encode(&self.identity_key).as_str())?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for AuthorizeResponse {
+impl<'de> serde::Deserialize<'de> for QueryAuditLogResponse {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "entries",
+            "next_index",
+            "nextIndex",
+            "identity_key",
+            "identityKey",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Entries,
+            NextIndex,
+            IdentityKey,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "entries" => Ok(GeneratedField::Entries),
+                            "nextIndex" | "next_index" => Ok(GeneratedField::NextIndex),
+                            "identityKey" | "identity_key" => Ok(GeneratedField::IdentityKey),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = QueryAuditLogResponse;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.custody.v1.QueryAuditLogResponse")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<QueryAuditLogResponse, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut entries__ = None;
+                let mut next_index__ = None;
+                let mut identity_key__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Entries => {
+                            if entries__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("entries"));
+                            }
+                            entries__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::NextIndex => {
+                            if next_index__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("nextIndex"));
+                            }
+                            next_index__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::IdentityKey => {
+                            if identity_key__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("identityKey"));
+                            }
+                            identity_key__ = 
+                                Some(map_.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(QueryAuditLogResponse {
+                    entries: entries__.unwrap_or_default(),
+                    next_index: next_index__.unwrap_or_default(),
+                    identity_key: identity_key__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.custody.v1.QueryAuditLogResponse", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for RecoverySignature {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.signer.is_empty() {
+            len += 1;
+        }
+        if !self.signature.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.custody.v1.RecoverySignature", len)?;
+        if !self.signer.is_empty() {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("signer", pbjson::private::base64::encode(&self.signer).as_str())?;
+        }
+        if !self.signature.is_empty() {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("signature", pbjson::private::base64::encode(&self.signature).as_str())?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for RecoverySignature {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "data",
+            "signer",
+            "signature",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            Data,
+            Signer,
+            Signature,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -164,7 +3917,8 @@ impl<'de> serde::Deserialize<'de> for AuthorizeResponse {
                         E: serde::de::Error,
                     {
                         match value {
-                            "data" => Ok(GeneratedField::Data),
+                            "signer" => Ok(GeneratedField::Signer),
+                            "signature" => Ok(GeneratedField::Signature),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -174,39 +3928,51 @@ impl<'de> serde::Deserialize<'de> for AuthorizeResponse {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = AuthorizeResponse;
+            type Value = RecoverySignature;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.custody.v1.AuthorizeResponse")
+                formatter.write_str("struct penumbra.custody.v1.RecoverySignature")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<AuthorizeResponse, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<RecoverySignature, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut data__ = None;
+                let mut signer__ = None;
+                let mut signature__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::Data => {
-                            if data__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("data"));
+                        GeneratedField::Signer => {
+                            if signer__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("signer"));
                             }
-                            data__ = map_.next_value()?;
+                            signer__ = 
+                                Some(map_.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::Signature => {
+                            if signature__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("signature"));
+                            }
+                            signature__ = 
+                                Some(map_.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
+                            ;
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(AuthorizeResponse {
-                    data: data__,
+                Ok(RecoverySignature {
+                    signer: signer__.unwrap_or_default(),
+                    signature: signature__.unwrap_or_default(),
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.custody.v1.AuthorizeResponse", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.custody.v1.RecoverySignature", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for ConfirmAddressRequest {
+impl serde::Serialize for RotateAuthorizationKeyRequest {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -214,30 +3980,49 @@ impl serde::Serialize for ConfirmAddressRequest {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.address_index.is_some() {
+        if self.rotation.is_some() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.custody.v1.ConfirmAddressRequest", len)?;
-        if let Some(v) = self.address_index.as_ref() {
-            struct_ser.serialize_field("addressIndex", v)?;
+        if !self.old_signature.is_empty() {
+            len += 1;
+        }
+        if !self.new_signature.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.custody.v1.RotateAuthorizationKeyRequest", len)?;
+        if let Some(v) = self.rotation.as_ref() {
+            struct_ser.serialize_field("rotation", v)?;
+        }
+        if !self.old_signature.is_empty() {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("oldSignature", pbjson::private::base64::encode(&self.old_signature).as_str())?;
+        }
+        if !self.new_signature.is_empty() {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("newSignature", pbjson::private::base64::encode(&self.new_signature).as_str())?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for ConfirmAddressRequest {
+impl<'de> serde::Deserialize<'de> for RotateAuthorizationKeyRequest {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "address_index",
-            "addressIndex",
+            "rotation",
+            "old_signature",
+            "oldSignature",
+            "new_signature",
+            "newSignature",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            AddressIndex,
+            Rotation,
+            OldSignature,
+            NewSignature,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -260,7 +4045,9 @@ impl<'de> serde::Deserialize<'de> for ConfirmAddressRequest {
                         E: serde::de::Error,
                     {
                         match value {
-                            "addressIndex" | "address_index" => Ok(GeneratedField::AddressIndex),
+                            "rotation" => Ok(GeneratedField::Rotation),
+                            "oldSignature" | "old_signature" => Ok(GeneratedField::OldSignature),
+                            "newSignature" | "new_signature" => Ok(GeneratedField::NewSignature),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -270,39 +4057,59 @@ impl<'de> serde::Deserialize<'de> for ConfirmAddressRequest {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = ConfirmAddressRequest;
+            type Value = RotateAuthorizationKeyRequest;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.custody.v1.ConfirmAddressRequest")
+                formatter.write_str("struct penumbra.custody.v1.RotateAuthorizationKeyRequest")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ConfirmAddressRequest, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<RotateAuthorizationKeyRequest, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut address_index__ = None;
+                let mut rotation__ = None;
+                let mut old_signature__ = None;
+                let mut new_signature__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::AddressIndex => {
-                            if address_index__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("addressIndex"));
+                        GeneratedField::Rotation => {
+                            if rotation__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("rotation"));
                             }
-                            address_index__ = map_.next_value()?;
+                            rotation__ = map_.next_value()?;
+                        }
+                        GeneratedField::OldSignature => {
+                            if old_signature__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("oldSignature"));
+                            }
+                            old_signature__ = 
+                                Some(map_.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::NewSignature => {
+                            if new_signature__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("newSignature"));
+                            }
+                            new_signature__ = 
+                                Some(map_.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
+                            ;
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(ConfirmAddressRequest {
-                    address_index: address_index__,
+                Ok(RotateAuthorizationKeyRequest {
+                    rotation: rotation__,
+                    old_signature: old_signature__.unwrap_or_default(),
+                    new_signature: new_signature__.unwrap_or_default(),
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.custody.v1.ConfirmAddressRequest", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.custody.v1.RotateAuthorizationKeyRequest", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for ConfirmAddressResponse {
+impl serde::Serialize for RotateAuthorizationKeyResponse {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -310,29 +4117,48 @@ impl serde::Serialize for ConfirmAddressResponse {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.address.is_some() {
+        if self.rotation.is_some() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.custody.v1.ConfirmAddressResponse", len)?;
-        if let Some(v) = self.address.as_ref() {
-            struct_ser.serialize_field("address", v)?;
+        if !self.identity_key.is_empty() {
+            len += 1;
+        }
+        if !self.signature.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.custody.v1.RotateAuthorizationKeyResponse", len)?;
+        if let Some(v) = self.rotation.as_ref() {
+            struct_ser.serialize_field("rotation", v)?;
+        }
+        if !self.identity_key.is_empty() {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("identityKey", pbjson::private::base64::encode(&self.identity_key).as_str())?;
+        }
+        if !self.signature.is_empty() {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("signature", pbjson::private::base64::encode(&self.signature).as_str())?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for ConfirmAddressResponse {
+impl<'de> serde::Deserialize<'de> for RotateAuthorizationKeyResponse {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "address",
+            "rotation",
+            "identity_key",
+            "identityKey",
+            "signature",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            Address,
+            Rotation,
+            IdentityKey,
+            Signature,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -355,7 +4181,9 @@ impl<'de> serde::Deserialize<'de> for ConfirmAddressResponse {
                         E: serde::de::Error,
                     {
                         match value {
-                            "address" => Ok(GeneratedField::Address),
+                            "rotation" => Ok(GeneratedField::Rotation),
+                            "identityKey" | "identity_key" => Ok(GeneratedField::IdentityKey),
+                            "signature" => Ok(GeneratedField::Signature),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -365,61 +4193,98 @@ impl<'de> serde::Deserialize<'de> for ConfirmAddressResponse {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = ConfirmAddressResponse;
+            type Value = RotateAuthorizationKeyResponse;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.custody.v1.ConfirmAddressResponse")
+                formatter.write_str("struct penumbra.custody.v1.RotateAuthorizationKeyResponse")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ConfirmAddressResponse, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<RotateAuthorizationKeyResponse, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut address__ = None;
+                let mut rotation__ = None;
+                let mut identity_key__ = None;
+                let mut signature__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::Address => {
-                            if address__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("address"));
+                        GeneratedField::Rotation => {
+                            if rotation__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("rotation"));
                             }
-                            address__ = map_.next_value()?;
+                            rotation__ = map_.next_value()?;
+                        }
+                        GeneratedField::IdentityKey => {
+                            if identity_key__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("identityKey"));
+                            }
+                            identity_key__ = 
+                                Some(map_.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::Signature => {
+                            if signature__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("signature"));
+                            }
+                            signature__ = 
+                                Some(map_.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
+                            ;
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(ConfirmAddressResponse {
-                    address: address__,
+                Ok(RotateAuthorizationKeyResponse {
+                    rotation: rotation__,
+                    identity_key: identity_key__.unwrap_or_default(),
+                    signature: signature__.unwrap_or_default(),
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.custody.v1.ConfirmAddressResponse", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.custody.v1.RotateAuthorizationKeyResponse", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for ExportFullViewingKeyRequest {
+impl serde::Serialize for SignedAuditLogEntry {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
         S: serde::Serializer,
     {
         use serde::ser::SerializeStruct;
-        let len = 0;
-        let struct_ser = serializer.serialize_struct("penumbra.custody.v1.ExportFullViewingKeyRequest", len)?;
+        let mut len = 0;
+        if self.entry.is_some() {
+            len += 1;
+        }
+        if !self.signature.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.custody.v1.SignedAuditLogEntry", len)?;
+        if let Some(v) = self.entry.as_ref() {
+            struct_ser.serialize_field("entry", v)?;
+        }
+        if !self.signature.is_empty() {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("signature", pbjson::private::base64::encode(&self.signature).as_str())?;
+        }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for ExportFullViewingKeyRequest {
+impl<'de> serde::Deserialize<'de> for SignedAuditLogEntry {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
+            "entry",
+            "signature",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
+            Entry,
+            Signature,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -441,7 +4306,11 @@ impl<'de> serde::Deserialize<'de> for ExportFullViewingKeyRequest {
                     where
                         E: serde::de::Error,
                     {
-                            Ok(GeneratedField::__SkipField__)
+                        match value {
+                            "entry" => Ok(GeneratedField::Entry),
+                            "signature" => Ok(GeneratedField::Signature),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
                     }
                 }
                 deserializer.deserialize_identifier(GeneratedVisitor)
@@ -449,27 +4318,49 @@ impl<'de> serde::Deserialize<'de> for ExportFullViewingKeyRequest {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = ExportFullViewingKeyRequest;
+            type Value = SignedAuditLogEntry;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.custody.v1.ExportFullViewingKeyRequest")
+                formatter.write_str("struct penumbra.custody.v1.SignedAuditLogEntry")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ExportFullViewingKeyRequest, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<SignedAuditLogEntry, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                while map_.next_key::<GeneratedField>()?.is_some() {
-                    let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                let mut entry__ = None;
+                let mut signature__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Entry => {
+                            if entry__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("entry"));
+                            }
+                            entry__ = map_.next_value()?;
+                        }
+                        GeneratedField::Signature => {
+                            if signature__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("signature"));
+                            }
+                            signature__ = 
+                                Some(map_.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
                 }
-                Ok(ExportFullViewingKeyRequest {
+                Ok(SignedAuditLogEntry {
+                    entry: entry__,
+                    signature: signature__.unwrap_or_default(),
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.custody.v1.ExportFullViewingKeyRequest", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.custody.v1.SignedAuditLogEntry", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for ExportFullViewingKeyResponse {
+impl serde::Serialize for SimulateAuthorizationResponse {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -477,30 +4368,37 @@ impl serde::Serialize for ExportFullViewingKeyResponse {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.full_viewing_key.is_some() {
+        if self.simulation.is_some() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.custody.v1.ExportFullViewingKeyResponse", len)?;
-        if let Some(v) = self.full_viewing_key.as_ref() {
-            struct_ser.serialize_field("fullViewingKey", v)?;
+        if self.summary.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.custody.v1.SimulateAuthorizationResponse", len)?;
+        if let Some(v) = self.simulation.as_ref() {
+            struct_ser.serialize_field("simulation", v)?;
+        }
+        if let Some(v) = self.summary.as_ref() {
+            struct_ser.serialize_field("summary", v)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for ExportFullViewingKeyResponse {
+impl<'de> serde::Deserialize<'de> for SimulateAuthorizationResponse {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "full_viewing_key",
-            "fullViewingKey",
+            "simulation",
+            "summary",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            FullViewingKey,
+            Simulation,
+            Summary,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -523,7 +4421,8 @@ impl<'de> serde::Deserialize<'de> for ExportFullViewingKeyResponse {
                         E: serde::de::Error,
                     {
                         match value {
-                            "fullViewingKey" | "full_viewing_key" => Ok(GeneratedField::FullViewingKey),
+                            "simulation" => Ok(GeneratedField::Simulation),
+                            "summary" => Ok(GeneratedField::Summary),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -533,39 +4432,47 @@ impl<'de> serde::Deserialize<'de> for ExportFullViewingKeyResponse {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = ExportFullViewingKeyResponse;
+            type Value = SimulateAuthorizationResponse;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.custody.v1.ExportFullViewingKeyResponse")
+                formatter.write_str("struct penumbra.custody.v1.SimulateAuthorizationResponse")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ExportFullViewingKeyResponse, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<SimulateAuthorizationResponse, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut full_viewing_key__ = None;
+                let mut simulation__ = None;
+                let mut summary__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::FullViewingKey => {
-                            if full_viewing_key__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("fullViewingKey"));
+                        GeneratedField::Simulation => {
+                            if simulation__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("simulation"));
                             }
-                            full_viewing_key__ = map_.next_value()?;
+                            simulation__ = map_.next_value()?;
+                        }
+                        GeneratedField::Summary => {
+                            if summary__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("summary"));
+                            }
+                            summary__ = map_.next_value()?;
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(ExportFullViewingKeyResponse {
-                    full_viewing_key: full_viewing_key__,
+                Ok(SimulateAuthorizationResponse {
+                    simulation: simulation__,
+                    summary: summary__,
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.custody.v1.ExportFullViewingKeyResponse", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.custody.v1.SimulateAuthorizationResponse", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for PreAuthorization {
+impl serde::Serialize for UnfreezeRequest {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -573,33 +4480,47 @@ impl serde::Serialize for PreAuthorization {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.pre_authorization.is_some() {
+        if self.generation != 0 {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.custody.v1.PreAuthorization", len)?;
-        if let Some(v) = self.pre_authorization.as_ref() {
-            match v {
-                pre_authorization::PreAuthorization::Ed25519(v) => {
-                    struct_ser.serialize_field("ed25519", v)?;
-                }
-            }
+        if !self.signatures.is_empty() {
+            len += 1;
+        }
+        if !self.custodian.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.custody.v1.UnfreezeRequest", len)?;
+        if self.generation != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("generation", ToString::to_string(&self.generation).as_str())?;
+        }
+        if !self.signatures.is_empty() {
+            struct_ser.serialize_field("signatures", &self.signatures)?;
+        }
+        if !self.custodian.is_empty() {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("custodian", pbjson::private::base64::encode(&self.custodian).as_str())?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for PreAuthorization {
+impl<'de> serde::Deserialize<'de> for UnfreezeRequest {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "ed25519",
+            "generation",
+            "signatures",
+            "custodian",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            Ed25519,
+            Generation,
+            Signatures,
+            Custodian,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -622,7 +4543,9 @@ impl<'de> serde::Deserialize<'de> for PreAuthorization {
                         E: serde::de::Error,
                     {
                         match value {
-                            "ed25519" => Ok(GeneratedField::Ed25519),
+                            "generation" => Ok(GeneratedField::Generation),
+                            "signatures" => Ok(GeneratedField::Signatures),
+                            "custodian" => Ok(GeneratedField::Custodian),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -632,80 +4555,81 @@ impl<'de> serde::Deserialize<'de> for PreAuthorization {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = PreAuthorization;
+            type Value = UnfreezeRequest;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.custody.v1.PreAuthorization")
+                formatter.write_str("struct penumbra.custody.v1.UnfreezeRequest")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<PreAuthorization, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<UnfreezeRequest, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut pre_authorization__ = None;
+                let mut generation__ = None;
+                let mut signatures__ = None;
+                let mut custodian__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::Ed25519 => {
-                            if pre_authorization__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("ed25519"));
+                        GeneratedField::Generation => {
+                            if generation__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("generation"));
                             }
-                            pre_authorization__ = map_.next_value::<::std::option::Option<_>>()?.map(pre_authorization::PreAuthorization::Ed25519)
-;
+                            generation__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::Signatures => {
+                            if signatures__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("signatures"));
+                            }
+                            signatures__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Custodian => {
+                            if custodian__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("custodian"));
+                            }
+                            custodian__ = 
+                                Some(map_.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
+                            ;
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(PreAuthorization {
-                    pre_authorization: pre_authorization__,
+                Ok(UnfreezeRequest {
+                    generation: generation__.unwrap_or_default(),
+                    signatures: signatures__.unwrap_or_default(),
+                    custodian: custodian__.unwrap_or_default(),
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.custody.v1.PreAuthorization", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.custody.v1.UnfreezeRequest", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for pre_authorization::Ed25519 {
+impl serde::Serialize for UnfreezeResponse {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
         S: serde::Serializer,
     {
         use serde::ser::SerializeStruct;
-        let mut len = 0;
-        if !self.vk.is_empty() {
-            len += 1;
-        }
-        if !self.sig.is_empty() {
-            len += 1;
-        }
-        let mut struct_ser = serializer.serialize_struct("penumbra.custody.v1.PreAuthorization.Ed25519", len)?;
-        if !self.vk.is_empty() {
-            #[allow(clippy::needless_borrow)]
-            struct_ser.serialize_field("vk", pbjson::private::base64::encode(&self.vk).as_str())?;
-        }
-        if !self.sig.is_empty() {
-            #[allow(clippy::needless_borrow)]
-            struct_ser.serialize_field("sig", pbjson::private::base64::encode(&self.sig).as_str())?;
-        }
+        let len = 0;
+        let struct_ser = serializer.serialize_struct("penumbra.custody.v1.UnfreezeResponse", len)?;
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for pre_authorization::Ed25519 {
+impl<'de> serde::Deserialize<'de> for UnfreezeResponse {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "vk",
-            "sig",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            Vk,
-            Sig,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -727,11 +4651,7 @@ impl<'de> serde::Deserialize<'de> for pre_authorization::Ed25519 {
                     where
                         E: serde::de::Error,
                     {
-                        match value {
-                            "vk" => Ok(GeneratedField::Vk),
-                            "sig" => Ok(GeneratedField::Sig),
-                            _ => Ok(GeneratedField::__SkipField__),
-                        }
+                            Ok(GeneratedField::__SkipField__)
                     }
                 }
                 deserializer.deserialize_identifier(GeneratedVisitor)
@@ -739,47 +4659,23 @@ impl<'de> serde::Deserialize<'de> for pre_authorization::Ed25519 {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = pre_authorization::Ed25519;
+            type Value = UnfreezeResponse;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.custody.v1.PreAuthorization.Ed25519")
+                formatter.write_str("struct penumbra.custody.v1.UnfreezeResponse")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<pre_authorization::Ed25519, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<UnfreezeResponse, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut vk__ = None;
-                let mut sig__ = None;
-                while let Some(k) = map_.next_key()? {
-                    match k {
-                        GeneratedField::Vk => {
-                            if vk__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("vk"));
-                            }
-                            vk__ = 
-                                Some(map_.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
-                            ;
-                        }
-                        GeneratedField::Sig => {
-                            if sig__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("sig"));
-                            }
-                            sig__ = 
-                                Some(map_.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
-                            ;
-                        }
-                        GeneratedField::__SkipField__ => {
-                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
-                        }
-                    }
+                while map_.next_key::<GeneratedField>()?.is_some() {
+                    let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                 }
-                Ok(pre_authorization::Ed25519 {
-                    vk: vk__.unwrap_or_default(),
-                    sig: sig__.unwrap_or_default(),
+                Ok(UnfreezeResponse {
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.custody.v1.PreAuthorization.Ed25519", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.custody.v1.UnfreezeResponse", FIELDS, GeneratedVisitor)
     }
 }
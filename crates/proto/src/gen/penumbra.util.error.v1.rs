@@ -0,0 +1,220 @@
+/// A machine-readable cause of an error returned by a Penumbra gRPC service.
+///
+/// Services attach an `ErrorDetail` to the status they return, packed in the
+/// `details` of a `google.rpc.Status`, as gRPC's rich error model expects, so
+/// that clients can tell causes apart without matching on the status message,
+/// which is meant for humans and may change.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ErrorDetail {
+    #[prost(oneof = "error_detail::Detail", tags = "1, 2, 3, 4, 5, 6, 7, 8")]
+    pub detail: ::core::option::Option<error_detail::Detail>,
+}
+/// Nested message and enum types in `ErrorDetail`.
+pub mod error_detail {
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Oneof)]
+    pub enum Detail {
+        #[prost(message, tag = "1")]
+        InsufficientBalance(super::InsufficientBalance),
+        #[prost(message, tag = "2")]
+        AnchorUnknown(super::AnchorUnknown),
+        #[prost(message, tag = "3")]
+        PolicyDenied(super::PolicyDenied),
+        #[prost(message, tag = "4")]
+        RequestReplayed(super::RequestReplayed),
+        #[prost(message, tag = "5")]
+        CustodianFrozen(super::CustodianFrozen),
+        #[prost(message, tag = "6")]
+        RotationOverdue(super::RotationOverdue),
+        #[prost(message, tag = "7")]
+        ViewOnly(super::ViewOnly),
+        #[prost(message, tag = "8")]
+        ControlRejected(super::ControlRejected),
+    }
+}
+impl ::prost::Name for ErrorDetail {
+    const NAME: &'static str = "ErrorDetail";
+    const PACKAGE: &'static str = "penumbra.util.error.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.util.error.v1.{}", Self::NAME)
+    }
+}
+/// There are not enough notes to fund the request.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct InsufficientBalance {
+    /// The value which was required.
+    #[prost(message, optional, tag = "1")]
+    pub required: ::core::option::Option<super::super::super::core::asset::v1::Value>,
+    /// The value which was available.
+    #[prost(message, optional, tag = "2")]
+    pub available: ::core::option::Option<super::super::super::core::asset::v1::Value>,
+}
+impl ::prost::Name for InsufficientBalance {
+    const NAME: &'static str = "InsufficientBalance";
+    const PACKAGE: &'static str = "penumbra.util.error.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.util.error.v1.{}", Self::NAME)
+    }
+}
+/// A proof or request refers to a state commitment tree anchor which is not
+/// known, usually because it is too old or from a different chain.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct AnchorUnknown {
+    #[prost(message, optional, tag = "1")]
+    pub anchor: ::core::option::Option<super::super::super::crypto::tct::v1::MerkleRoot>,
+}
+impl ::prost::Name for AnchorUnknown {
+    const NAME: &'static str = "AnchorUnknown";
+    const PACKAGE: &'static str = "penumbra.util.error.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.util.error.v1.{}", Self::NAME)
+    }
+}
+/// A request was refused by the policies of the service.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PolicyDenied {
+    /// Why the request was refused.
+    #[prost(string, tag = "1")]
+    pub reason: ::prost::alloc::string::String,
+}
+impl ::prost::Name for PolicyDenied {
+    const NAME: &'static str = "PolicyDenied";
+    const PACKAGE: &'static str = "penumbra.util.error.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.util.error.v1.{}", Self::NAME)
+    }
+}
+/// An authorization request was refused as a possible replay.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct RequestReplayed {
+    #[prost(enumeration = "request_replayed::Cause", tag = "1")]
+    pub cause: i32,
+}
+/// Nested message and enum types in `RequestReplayed`.
+pub mod request_replayed {
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum Cause {
+        Unspecified = 0,
+        /// The service requires a nonce from a challenge, but none was given.
+        MissingNonce = 1,
+        /// The nonce was never issued, has already been used, or has expired.
+        UnknownNonce = 2,
+        /// A request with the same effect hash was already authorized.
+        DuplicateEffectHash = 3,
+    }
+    impl Cause {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Cause::Unspecified => "CAUSE_UNSPECIFIED",
+                Cause::MissingNonce => "CAUSE_MISSING_NONCE",
+                Cause::UnknownNonce => "CAUSE_UNKNOWN_NONCE",
+                Cause::DuplicateEffectHash => "CAUSE_DUPLICATE_EFFECT_HASH",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "CAUSE_UNSPECIFIED" => Some(Self::Unspecified),
+                "CAUSE_MISSING_NONCE" => Some(Self::MissingNonce),
+                "CAUSE_UNKNOWN_NONCE" => Some(Self::UnknownNonce),
+                "CAUSE_DUPLICATE_EFFECT_HASH" => Some(Self::DuplicateEffectHash),
+                _ => None,
+            }
+        }
+    }
+}
+impl ::prost::Name for RequestReplayed {
+    const NAME: &'static str = "RequestReplayed";
+    const PACKAGE: &'static str = "penumbra.util.error.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.util.error.v1.{}", Self::NAME)
+    }
+}
+/// The custodian is frozen, and refuses to authorize anything until unfrozen.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CustodianFrozen {
+    /// The generation of the freeze, to be named by an unfreeze.
+    #[prost(uint64, tag = "1")]
+    pub generation: u64,
+    /// Why the custodian was frozen.
+    #[prost(string, tag = "2")]
+    pub reason: ::prost::alloc::string::String,
+}
+impl ::prost::Name for CustodianFrozen {
+    const NAME: &'static str = "CustodianFrozen";
+    const PACKAGE: &'static str = "penumbra.util.error.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.util.error.v1.{}", Self::NAME)
+    }
+}
+/// The custodian's authorization key was not rotated within the configured
+/// inactivity timeout, so it refuses to authorize anything until it is.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct RotationOverdue {
+    /// The time of the last rotation, in seconds since the Unix epoch.
+    #[prost(uint64, tag = "1")]
+    pub last_rotation: u64,
+    /// The configured inactivity timeout, in seconds.
+    #[prost(uint64, tag = "2")]
+    pub inactivity_timeout_secs: u64,
+}
+impl ::prost::Name for RotationOverdue {
+    const NAME: &'static str = "RotationOverdue";
+    const PACKAGE: &'static str = "penumbra.util.error.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.util.error.v1.{}", Self::NAME)
+    }
+}
+/// The service holds no spend authority, being in view-only mode.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ViewOnly {}
+impl ::prost::Name for ViewOnly {
+    const NAME: &'static str = "ViewOnly";
+    const PACKAGE: &'static str = "penumbra.util.error.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.util.error.v1.{}", Self::NAME)
+    }
+}
+/// A request to control the custodian, such as to unfreeze it, to rotate its
+/// authorization key or to import a policy bundle, was refused.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ControlRejected {
+    /// Why the request was refused.
+    #[prost(string, tag = "1")]
+    pub reason: ::prost::alloc::string::String,
+    /// Whether the custodian is not configured to accept this kind of request at
+    /// all, as opposed to refusing this particular request.
+    #[prost(bool, tag = "2")]
+    pub not_configured: bool,
+}
+impl ::prost::Name for ControlRejected {
+    const NAME: &'static str = "ControlRejected";
+    const PACKAGE: &'static str = "penumbra.util.error.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.util.error.v1.{}", Self::NAME)
+    }
+}
@@ -0,0 +1,1095 @@
+impl serde::Serialize for AnchorUnknown {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.anchor.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.util.error.v1.AnchorUnknown", len)?;
+        if let Some(v) = self.anchor.as_ref() {
+            struct_ser.serialize_field("anchor", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for AnchorUnknown {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "anchor",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Anchor,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "anchor" => Ok(GeneratedField::Anchor),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = AnchorUnknown;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.util.error.v1.AnchorUnknown")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<AnchorUnknown, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut anchor__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Anchor => {
+                            if anchor__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("anchor"));
+                            }
+                            anchor__ = map_.next_value()?;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(AnchorUnknown {
+                    anchor: anchor__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.util.error.v1.AnchorUnknown", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for ControlRejected {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.reason.is_empty() {
+            len += 1;
+        }
+        if self.not_configured {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.util.error.v1.ControlRejected", len)?;
+        if !self.reason.is_empty() {
+            struct_ser.serialize_field("reason", &self.reason)?;
+        }
+        if self.not_configured {
+            struct_ser.serialize_field("notConfigured", &self.not_configured)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for ControlRejected {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "reason",
+            "not_configured",
+            "notConfigured",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Reason,
+            NotConfigured,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "reason" => Ok(GeneratedField::Reason),
+                            "notConfigured" | "not_configured" => Ok(GeneratedField::NotConfigured),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = ControlRejected;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.util.error.v1.ControlRejected")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ControlRejected, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut reason__ = None;
+                let mut not_configured__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Reason => {
+                            if reason__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("reason"));
+                            }
+                            reason__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::NotConfigured => {
+                            if not_configured__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("notConfigured"));
+                            }
+                            not_configured__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(ControlRejected {
+                    reason: reason__.unwrap_or_default(),
+                    not_configured: not_configured__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.util.error.v1.ControlRejected", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for CustodianFrozen {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.generation != 0 {
+            len += 1;
+        }
+        if !self.reason.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.util.error.v1.CustodianFrozen", len)?;
+        if self.generation != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("generation", ToString::to_string(&self.generation).as_str())?;
+        }
+        if !self.reason.is_empty() {
+            struct_ser.serialize_field("reason", &self.reason)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for CustodianFrozen {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "generation",
+            "reason",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Generation,
+            Reason,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "generation" => Ok(GeneratedField::Generation),
+                            "reason" => Ok(GeneratedField::Reason),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = CustodianFrozen;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.util.error.v1.CustodianFrozen")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<CustodianFrozen, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut generation__ = None;
+                let mut reason__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Generation => {
+                            if generation__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("generation"));
+                            }
+                            generation__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::Reason => {
+                            if reason__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("reason"));
+                            }
+                            reason__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(CustodianFrozen {
+                    generation: generation__.unwrap_or_default(),
+                    reason: reason__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.util.error.v1.CustodianFrozen", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for ErrorDetail {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.detail.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.util.error.v1.ErrorDetail", len)?;
+        if let Some(v) = self.detail.as_ref() {
+            match v {
+                error_detail::Detail::InsufficientBalance(v) => {
+                    struct_ser.serialize_field("insufficientBalance", v)?;
+                }
+                error_detail::Detail::AnchorUnknown(v) => {
+                    struct_ser.serialize_field("anchorUnknown", v)?;
+                }
+                error_detail::Detail::PolicyDenied(v) => {
+                    struct_ser.serialize_field("policyDenied", v)?;
+                }
+                error_detail::Detail::RequestReplayed(v) => {
+                    struct_ser.serialize_field("requestReplayed", v)?;
+                }
+                error_detail::Detail::CustodianFrozen(v) => {
+                    struct_ser.serialize_field("custodianFrozen", v)?;
+                }
+                error_detail::Detail::RotationOverdue(v) => {
+                    struct_ser.serialize_field("rotationOverdue", v)?;
+                }
+                error_detail::Detail::ViewOnly(v) => {
+                    struct_ser.serialize_field("viewOnly", v)?;
+                }
+                error_detail::Detail::ControlRejected(v) => {
+                    struct_ser.serialize_field("controlRejected", v)?;
+                }
+            }
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for ErrorDetail {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "insufficient_balance",
+            "insufficientBalance",
+            "anchor_unknown",
+            "anchorUnknown",
+            "policy_denied",
+            "policyDenied",
+            "request_replayed",
+            "requestReplayed",
+            "custodian_frozen",
+            "custodianFrozen",
+            "rotation_overdue",
+            "rotationOverdue",
+            "view_only",
+            "viewOnly",
+            "control_rejected",
+            "controlRejected",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            InsufficientBalance,
+            AnchorUnknown,
+            PolicyDenied,
+            RequestReplayed,
+            CustodianFrozen,
+            RotationOverdue,
+            ViewOnly,
+            ControlRejected,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "insufficientBalance" | "insufficient_balance" => Ok(GeneratedField::InsufficientBalance),
+                            "anchorUnknown" | "anchor_unknown" => Ok(GeneratedField::AnchorUnknown),
+                            "policyDenied" | "policy_denied" => Ok(GeneratedField::PolicyDenied),
+                            "requestReplayed" | "request_replayed" => Ok(GeneratedField::RequestReplayed),
+                            "custodianFrozen" | "custodian_frozen" => Ok(GeneratedField::CustodianFrozen),
+                            "rotationOverdue" | "rotation_overdue" => Ok(GeneratedField::RotationOverdue),
+                            "viewOnly" | "view_only" => Ok(GeneratedField::ViewOnly),
+                            "controlRejected" | "control_rejected" => Ok(GeneratedField::ControlRejected),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = ErrorDetail;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.util.error.v1.ErrorDetail")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ErrorDetail, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut detail__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::InsufficientBalance => {
+                            if detail__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("insufficientBalance"));
+                            }
+                            detail__ = map_.next_value::<::std::option::Option<_>>()?.map(error_detail::Detail::InsufficientBalance)
+;
+                        }
+                        GeneratedField::AnchorUnknown => {
+                            if detail__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("anchorUnknown"));
+                            }
+                            detail__ = map_.next_value::<::std::option::Option<_>>()?.map(error_detail::Detail::AnchorUnknown)
+;
+                        }
+                        GeneratedField::PolicyDenied => {
+                            if detail__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("policyDenied"));
+                            }
+                            detail__ = map_.next_value::<::std::option::Option<_>>()?.map(error_detail::Detail::PolicyDenied)
+;
+                        }
+                        GeneratedField::RequestReplayed => {
+                            if detail__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("requestReplayed"));
+                            }
+                            detail__ = map_.next_value::<::std::option::Option<_>>()?.map(error_detail::Detail::RequestReplayed)
+;
+                        }
+                        GeneratedField::CustodianFrozen => {
+                            if detail__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("custodianFrozen"));
+                            }
+                            detail__ = map_.next_value::<::std::option::Option<_>>()?.map(error_detail::Detail::CustodianFrozen)
+;
+                        }
+                        GeneratedField::RotationOverdue => {
+                            if detail__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("rotationOverdue"));
+                            }
+                            detail__ = map_.next_value::<::std::option::Option<_>>()?.map(error_detail::Detail::RotationOverdue)
+;
+                        }
+                        GeneratedField::ViewOnly => {
+                            if detail__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("viewOnly"));
+                            }
+                            detail__ = map_.next_value::<::std::option::Option<_>>()?.map(error_detail::Detail::ViewOnly)
+;
+                        }
+                        GeneratedField::ControlRejected => {
+                            if detail__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("controlRejected"));
+                            }
+                            detail__ = map_.next_value::<::std::option::Option<_>>()?.map(error_detail::Detail::ControlRejected)
+;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(ErrorDetail {
+                    detail: detail__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.util.error.v1.ErrorDetail", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for InsufficientBalance {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.required.is_some() {
+            len += 1;
+        }
+        if self.available.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.util.error.v1.InsufficientBalance", len)?;
+        if let Some(v) = self.required.as_ref() {
+            struct_ser.serialize_field("required", v)?;
+        }
+        if let Some(v) = self.available.as_ref() {
+            struct_ser.serialize_field("available", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for InsufficientBalance {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "required",
+            "available",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Required,
+            Available,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "required" => Ok(GeneratedField::Required),
+                            "available" => Ok(GeneratedField::Available),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = InsufficientBalance;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.util.error.v1.InsufficientBalance")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<InsufficientBalance, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut required__ = None;
+                let mut available__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Required => {
+                            if required__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("required"));
+                            }
+                            required__ = map_.next_value()?;
+                        }
+                        GeneratedField::Available => {
+                            if available__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("available"));
+                            }
+                            available__ = map_.next_value()?;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(InsufficientBalance {
+                    required: required__,
+                    available: available__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.util.error.v1.InsufficientBalance", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for PolicyDenied {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.reason.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.util.error.v1.PolicyDenied", len)?;
+        if !self.reason.is_empty() {
+            struct_ser.serialize_field("reason", &self.reason)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for PolicyDenied {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "reason",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Reason,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "reason" => Ok(GeneratedField::Reason),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = PolicyDenied;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.util.error.v1.PolicyDenied")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<PolicyDenied, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut reason__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Reason => {
+                            if reason__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("reason"));
+                            }
+                            reason__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(PolicyDenied {
+                    reason: reason__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.util.error.v1.PolicyDenied", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for RequestReplayed {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.cause != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.util.error.v1.RequestReplayed", len)?;
+        if self.cause != 0 {
+            let v = request_replayed::Cause::try_from(self.cause)
+                .map_err(|_| serde::ser::Error::custom(format!("Invalid variant {}", self.cause)))?;
+            struct_ser.serialize_field("cause", &v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for RequestReplayed {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "cause",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Cause,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "cause" => Ok(GeneratedField::Cause),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = RequestReplayed;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.util.error.v1.RequestReplayed")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<RequestReplayed, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut cause__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Cause => {
+                            if cause__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("cause"));
+                            }
+                            cause__ = Some(map_.next_value::<request_replayed::Cause>()? as i32);
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(RequestReplayed {
+                    cause: cause__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.util.error.v1.RequestReplayed", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for request_replayed::Cause {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        let variant = match self {
+            Self::Unspecified => "CAUSE_UNSPECIFIED",
+            Self::MissingNonce => "CAUSE_MISSING_NONCE",
+            Self::UnknownNonce => "CAUSE_UNKNOWN_NONCE",
+            Self::DuplicateEffectHash => "CAUSE_DUPLICATE_EFFECT_HASH",
+        };
+        serializer.serialize_str(variant)
+    }
+}
+impl<'de> serde::Deserialize<'de> for request_replayed::Cause {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "CAUSE_UNSPECIFIED",
+            "CAUSE_MISSING_NONCE",
+            "CAUSE_UNKNOWN_NONCE",
+            "CAUSE_DUPLICATE_EFFECT_HASH",
+        ];
+
+        struct GeneratedVisitor;
+
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = request_replayed::Cause;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                write!(formatter, "expected one of: {:?}", &FIELDS)
+            }
+
+            fn visit_i64<E>(self, v: i64) -> std::result::Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                i32::try_from(v)
+                    .ok()
+                    .and_then(|x| x.try_into().ok())
+                    .ok_or_else(|| {
+                        serde::de::Error::invalid_value(serde::de::Unexpected::Signed(v), &self)
+                    })
+            }
+
+            fn visit_u64<E>(self, v: u64) -> std::result::Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                i32::try_from(v)
+                    .ok()
+                    .and_then(|x| x.try_into().ok())
+                    .ok_or_else(|| {
+                        serde::de::Error::invalid_value(serde::de::Unexpected::Unsigned(v), &self)
+                    })
+            }
+
+            fn visit_str<E>(self, value: &str) -> std::result::Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                match value {
+                    "CAUSE_UNSPECIFIED" => Ok(request_replayed::Cause::Unspecified),
+                    "CAUSE_MISSING_NONCE" => Ok(request_replayed::Cause::MissingNonce),
+                    "CAUSE_UNKNOWN_NONCE" => Ok(request_replayed::Cause::UnknownNonce),
+                    "CAUSE_DUPLICATE_EFFECT_HASH" => Ok(request_replayed::Cause::DuplicateEffectHash),
+                    _ => Err(serde::de::Error::unknown_variant(value, FIELDS)),
+                }
+            }
+        }
+        deserializer.deserialize_any(GeneratedVisitor)
+    }
+}
+impl serde::Serialize for RotationOverdue {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.last_rotation != 0 {
+            len += 1;
+        }
+        if self.inactivity_timeout_secs != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.util.error.v1.RotationOverdue", len)?;
+        if self.last_rotation != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("lastRotation", ToString::to_string(&self.last_rotation).as_str())?;
+        }
+        if self.inactivity_timeout_secs != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("inactivityTimeoutSecs", ToString::to_string(&self.inactivity_timeout_secs).as_str())?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for RotationOverdue {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "last_rotation",
+            "lastRotation",
+            "inactivity_timeout_secs",
+            "inactivityTimeoutSecs",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            LastRotation,
+            InactivityTimeoutSecs,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "lastRotation" | "last_rotation" => Ok(GeneratedField::LastRotation),
+                            "inactivityTimeoutSecs" | "inactivity_timeout_secs" => Ok(GeneratedField::InactivityTimeoutSecs),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = RotationOverdue;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.util.error.v1.RotationOverdue")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<RotationOverdue, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut last_rotation__ = None;
+                let mut inactivity_timeout_secs__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::LastRotation => {
+                            if last_rotation__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("lastRotation"));
+                            }
+                            last_rotation__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::InactivityTimeoutSecs => {
+                            if inactivity_timeout_secs__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("inactivityTimeoutSecs"));
+                            }
+                            inactivity_timeout_secs__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(RotationOverdue {
+                    last_rotation: last_rotation__.unwrap_or_default(),
+                    inactivity_timeout_secs: inactivity_timeout_secs__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.util.error.v1.RotationOverdue", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for ViewOnly {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let len = 0;
+        let struct_ser = serializer.serialize_struct("penumbra.util.error.v1.ViewOnly", len)?;
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for ViewOnly {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                            Ok(GeneratedField::__SkipField__)
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = ViewOnly;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.util.error.v1.ViewOnly")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ViewOnly, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                while map_.next_key::<GeneratedField>()?.is_some() {
+                    let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                }
+                Ok(ViewOnly {
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.util.error.v1.ViewOnly", FIELDS, GeneratedVisitor)
+    }
+}
@@ -110,6 +110,9 @@ pub mod broadcast_transaction_response {
         pub id: ::core::option::Option<
             super::super::super::core::txhash::v1::TransactionId,
         >,
+        /// The log returned by the fullnode when accepting the transaction into its mempool.
+        #[prost(string, tag = "2")]
+        pub mempool_log: ::prost::alloc::string::String,
     }
     impl ::prost::Name for BroadcastSuccess {
         const NAME: &'static str = "BroadcastSuccess";
@@ -162,6 +165,98 @@ impl ::prost::Name for BroadcastTransactionResponse {
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PendingTransactionsRequest {}
+impl ::prost::Name for PendingTransactionsRequest {
+    const NAME: &'static str = "PendingTransactionsRequest";
+    const PACKAGE: &'static str = "penumbra.view.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.view.v1.{}", Self::NAME)
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PendingTransactionsResponse {
+    /// The hash of the pending transaction.
+    #[prost(message, optional, tag = "1")]
+    pub id: ::core::option::Option<super::super::core::txhash::v1::TransactionId>,
+    /// The pending transaction.
+    #[prost(message, optional, tag = "2")]
+    pub transaction: ::core::option::Option<
+        super::super::core::transaction::v1::Transaction,
+    >,
+    /// The sync height of the view service when the transaction was broadcast.
+    #[prost(uint64, tag = "3")]
+    pub broadcast_height: u64,
+    /// The log returned by the fullnode when accepting the transaction into its mempool.
+    #[prost(string, tag = "4")]
+    pub mempool_log: ::prost::alloc::string::String,
+}
+impl ::prost::Name for PendingTransactionsResponse {
+    const NAME: &'static str = "PendingTransactionsResponse";
+    const PACKAGE: &'static str = "penumbra.view.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.view.v1.{}", Self::NAME)
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct DatabaseMaintenanceRequest {
+    /// If true, delete the orphaned and duplicate records found by the check,
+    /// where this loses no information.
+    #[prost(bool, tag = "1")]
+    pub repair: bool,
+    /// If true, compact the database after the check, reclaiming unused space.
+    #[prost(bool, tag = "2")]
+    pub vacuum: bool,
+}
+impl ::prost::Name for DatabaseMaintenanceRequest {
+    const NAME: &'static str = "DatabaseMaintenanceRequest";
+    const PACKAGE: &'static str = "penumbra.view.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.view.v1.{}", Self::NAME)
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct DatabaseMaintenanceResponse {
+    /// Problems reported by SQLite's own integrity check of the database file,
+    /// which is empty if the file is intact.
+    #[prost(string, repeated, tag = "1")]
+    pub storage_errors: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+    /// Records of notes, pending nullifiers, and nullifier lookups which refer
+    /// to a note or transaction missing from the database.
+    #[prost(uint64, tag = "2")]
+    pub orphaned_records: u64,
+    /// Unspent notes and unclaimed swaps which are not witnessed at their
+    /// position in the state commitment tree, so could not be spent.
+    #[prost(uint64, tag = "3")]
+    pub unwitnessed_records: u64,
+    /// Records which duplicate another.
+    #[prost(uint64, tag = "4")]
+    pub duplicate_records: u64,
+    /// The number of records deleted by the repair, if one was requested.
+    #[prost(uint64, tag = "5")]
+    pub repaired_records: u64,
+    /// The size of the database, in bytes, before any repair or compaction.
+    #[prost(uint64, tag = "6")]
+    pub size_before: u64,
+    /// The size of the database, in bytes, after any repair or compaction.
+    #[prost(uint64, tag = "7")]
+    pub size_after: u64,
+    /// Whether problems remain which can only be fixed by resetting the
+    /// database and resynchronizing.
+    #[prost(bool, tag = "8")]
+    pub needs_resync: bool,
+}
+impl ::prost::Name for DatabaseMaintenanceResponse {
+    const NAME: &'static str = "DatabaseMaintenanceResponse";
+    const PACKAGE: &'static str = "penumbra.view.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.view.v1.{}", Self::NAME)
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
 pub struct TransactionPlannerRequest {
     /// The expiry height for the requested TransactionPlan
     #[prost(uint64, tag = "1")]
@@ -173,6 +268,9 @@ pub struct TransactionPlannerRequest {
     /// If present, only spends funds from the given account.
     #[prost(message, optional, tag = "4")]
     pub source: ::core::option::Option<super::super::core::keys::v1::AddressIndex>,
+    /// How to choose the notes spent to fund the transaction.
+    #[prost(enumeration = "NoteSelectionStrategy", tag = "5")]
+    pub note_selection_strategy: i32,
     /// Request contents
     #[prost(message, repeated, tag = "20")]
     pub outputs: ::prost::alloc::vec::Vec<transaction_planner_request::Output>,
@@ -578,6 +676,11 @@ pub struct BalancesRequest {
     /// If present, filter balances to only include the specified asset ID.
     #[prost(message, optional, tag = "2")]
     pub asset_id_filter: ::core::option::Option<super::super::core::asset::v1::AssetId>,
+    /// If present, the view server estimates the value of each balance in terms
+    /// of each of these numeraires, using its configured price sources, and
+    /// includes the results as equivalent values in the returned `balance_view`s.
+    #[prost(message, repeated, tag = "3")]
+    pub numeraires: ::prost::alloc::vec::Vec<super::super::core::asset::v1::AssetId>,
 }
 impl ::prost::Name for BalancesRequest {
     const NAME: &'static str = "BalancesRequest";
@@ -1088,6 +1191,13 @@ pub struct TransactionInfoByHashRequest {
     /// The transaction hash to query for.
     #[prost(message, optional, tag = "2")]
     pub id: ::core::option::Option<super::super::core::txhash::v1::TransactionId>,
+    /// If present, the view server estimates the prices of the assets visible in
+    /// the transaction in terms of each of these numeraires, as of the height at
+    /// which it was included, using its configured price sources.  The estimates
+    /// are included in the transaction perspective, so that the values in the
+    /// transaction view carry equivalent values in each numeraire.
+    #[prost(message, repeated, tag = "3")]
+    pub numeraires: ::prost::alloc::vec::Vec<super::super::core::asset::v1::AssetId>,
 }
 impl ::prost::Name for TransactionInfoByHashRequest {
     const NAME: &'static str = "TransactionInfoByHashRequest";
@@ -1105,6 +1215,10 @@ pub struct TransactionInfoRequest {
     /// If present, return only transactions before this height.
     #[prost(uint64, tag = "2")]
     pub end_height: u64,
+    /// If present, estimate the values in each transaction in terms of these
+    /// numeraires, as with `TransactionInfoByHashRequest.numeraires`.
+    #[prost(message, repeated, tag = "3")]
+    pub numeraires: ::prost::alloc::vec::Vec<super::super::core::asset::v1::AssetId>,
 }
 impl ::prost::Name for TransactionInfoRequest {
     const NAME: &'static str = "TransactionInfoRequest";
@@ -1137,6 +1251,10 @@ pub struct TransactionInfo {
     pub view: ::core::option::Option<
         super::super::core::transaction::v1::TransactionView,
     >,
+    /// Notes refunding ICS20 withdrawals made by this transaction, which were
+    /// returned because the transfer timed out.
+    #[prost(message, repeated, tag = "6")]
+    pub refunds: ::prost::alloc::vec::Vec<SpendableNoteRecord>,
 }
 impl ::prost::Name for TransactionInfo {
     const NAME: &'static str = "TransactionInfo";
@@ -1352,6 +1470,142 @@ impl ::prost::Name for AssetMetadataByIdResponse {
         ::prost::alloc::format!("penumbra.view.v1.{}", Self::NAME)
     }
 }
+/// A transaction plan together with the authorizations collected for it so far.
+///
+/// This is the file format used to pass a plan between the parties which must
+/// authorize it, in the manner of a Bitcoin PSBT: approvers add the
+/// pre-authorizations the wallet's custodian requires, the custodian adds its
+/// signatures, partial copies are merged, and once every spend and delegator
+/// vote is authorized, the bundle is finalized into the `AuthorizationData`
+/// needed to build the transaction.
+///
+/// Every note spent or voted with by the plan must be controlled by the
+/// bundle's full viewing key.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PartiallyAuthorizedTransaction {
+    /// The version of the format, currently 1.
+    #[prost(uint32, tag = "1")]
+    pub version: u32,
+    /// The plan being authorized.
+    #[prost(message, optional, tag = "2")]
+    pub plan: ::core::option::Option<
+        super::super::core::transaction::v1::TransactionPlan,
+    >,
+    /// The effect hash of the plan under the full viewing key.
+    #[prost(message, optional, tag = "3")]
+    pub effect_hash: ::core::option::Option<super::super::core::txhash::v1::EffectHash>,
+    /// The spend authorizations collected so far, indexed by the position of the
+    /// spend among the plan's spends.
+    #[prost(message, repeated, tag = "4")]
+    pub spend_auths: ::prost::alloc::vec::Vec<PartialAuthorization>,
+    /// The delegator vote authorizations collected so far, indexed by the
+    /// position of the vote among the plan's delegator votes.
+    #[prost(message, repeated, tag = "5")]
+    pub delegator_vote_auths: ::prost::alloc::vec::Vec<PartialAuthorization>,
+    /// Pre-authorizations of the plan, to be passed on to custodians which require them.
+    #[prost(message, repeated, tag = "6")]
+    pub pre_authorizations: ::prost::alloc::vec::Vec<
+        super::super::custody::v1::PreAuthorization,
+    >,
+    /// Free-form metadata, such as a description of the transaction for reviewers.
+    #[prost(message, repeated, tag = "7")]
+    pub metadata: ::prost::alloc::vec::Vec<PartiallyAuthorizedTransactionMetadata>,
+    /// The full viewing key of the wallet whose notes the plan spends.
+    #[prost(message, optional, tag = "8")]
+    pub full_viewing_key: ::core::option::Option<
+        super::super::core::keys::v1::FullViewingKey,
+    >,
+}
+impl ::prost::Name for PartiallyAuthorizedTransaction {
+    const NAME: &'static str = "PartiallyAuthorizedTransaction";
+    const PACKAGE: &'static str = "penumbra.view.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.view.v1.{}", Self::NAME)
+    }
+}
+/// A single authorization signature in a `PartiallyAuthorizedTransaction`.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PartialAuthorization {
+    /// The index of the authorized action among the actions of its kind in the plan.
+    #[prost(uint32, tag = "1")]
+    pub index: u32,
+    /// The signature over the effect hash, under the wallet's spend verification
+    /// key randomized by the action's randomizer.
+    #[prost(message, optional, tag = "3")]
+    pub signature: ::core::option::Option<
+        super::super::crypto::decaf377_rdsa::v1::SpendAuthSignature,
+    >,
+}
+impl ::prost::Name for PartialAuthorization {
+    const NAME: &'static str = "PartialAuthorization";
+    const PACKAGE: &'static str = "penumbra.view.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.view.v1.{}", Self::NAME)
+    }
+}
+/// An item of metadata in a `PartiallyAuthorizedTransaction`.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PartiallyAuthorizedTransactionMetadata {
+    #[prost(string, tag = "1")]
+    pub key: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub value: ::prost::alloc::string::String,
+}
+impl ::prost::Name for PartiallyAuthorizedTransactionMetadata {
+    const NAME: &'static str = "PartiallyAuthorizedTransactionMetadata";
+    const PACKAGE: &'static str = "penumbra.view.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.view.v1.{}", Self::NAME)
+    }
+}
+/// A strategy for choosing which notes to spend when planning a transaction.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum NoteSelectionStrategy {
+    /// Spend notes in the order the view service stores them.
+    Unspecified = 0,
+    /// Spend the oldest notes first.
+    OldestFirst = 1,
+    /// Spend as few notes as possible, largest first.
+    FewestNotes = 2,
+    /// Spend notes chosen at random, so the selection reveals nothing about
+    /// the ages or sizes of the notes held.
+    PrivacyMax = 3,
+    /// Spend the notes whose total exceeds the amount needed by the least,
+    /// producing as little change as possible.
+    MinimizeChange = 4,
+}
+impl NoteSelectionStrategy {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            NoteSelectionStrategy::Unspecified => "NOTE_SELECTION_STRATEGY_UNSPECIFIED",
+            NoteSelectionStrategy::OldestFirst => "NOTE_SELECTION_STRATEGY_OLDEST_FIRST",
+            NoteSelectionStrategy::FewestNotes => "NOTE_SELECTION_STRATEGY_FEWEST_NOTES",
+            NoteSelectionStrategy::PrivacyMax => "NOTE_SELECTION_STRATEGY_PRIVACY_MAX",
+            NoteSelectionStrategy::MinimizeChange => {
+                "NOTE_SELECTION_STRATEGY_MINIMIZE_CHANGE"
+            }
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "NOTE_SELECTION_STRATEGY_UNSPECIFIED" => Some(Self::Unspecified),
+            "NOTE_SELECTION_STRATEGY_OLDEST_FIRST" => Some(Self::OldestFirst),
+            "NOTE_SELECTION_STRATEGY_FEWEST_NOTES" => Some(Self::FewestNotes),
+            "NOTE_SELECTION_STRATEGY_PRIVACY_MAX" => Some(Self::PrivacyMax),
+            "NOTE_SELECTION_STRATEGY_MINIMIZE_CHANGE" => Some(Self::MinimizeChange),
+            _ => None,
+        }
+    }
+}
 /// Generated client implementations.
 #[cfg(feature = "rpc")]
 pub mod view_service_client {
@@ -2191,6 +2445,75 @@ pub mod view_service_client {
                 );
             self.inner.server_streaming(req, path, codec).await
         }
+        /// Queries for transactions broadcast by the view service which the fullnode
+        /// accepted into its mempool, but which haven't been detected on chain yet.
+        ///
+        /// The notes spent by pending transactions are treated as provisionally
+        /// spent, and are not returned as unspent notes, until the transaction is
+        /// detected on chain or expires.
+        pub async fn pending_transactions(
+            &mut self,
+            request: impl tonic::IntoRequest<super::PendingTransactionsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<tonic::codec::Streaming<super::PendingTransactionsResponse>>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/penumbra.view.v1.ViewService/PendingTransactions",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "penumbra.view.v1.ViewService",
+                        "PendingTransactions",
+                    ),
+                );
+            self.inner.server_streaming(req, path, codec).await
+        }
+        /// Checks the integrity of the view service's database, optionally repairing
+        /// what can be repaired without resynchronizing and compacting the database,
+        /// and reports a summary of its health.
+        pub async fn database_maintenance(
+            &mut self,
+            request: impl tonic::IntoRequest<super::DatabaseMaintenanceRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::DatabaseMaintenanceResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/penumbra.view.v1.ViewService/DatabaseMaintenance",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "penumbra.view.v1.ViewService",
+                        "DatabaseMaintenance",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
     }
 }
 /// Generated server implementations.
@@ -2501,6 +2824,38 @@ pub mod view_service_server {
             tonic::Response<Self::BroadcastTransactionStream>,
             tonic::Status,
         >;
+        /// Server streaming response type for the PendingTransactions method.
+        type PendingTransactionsStream: tonic::codegen::tokio_stream::Stream<
+                Item = std::result::Result<
+                    super::PendingTransactionsResponse,
+                    tonic::Status,
+                >,
+            >
+            + Send
+            + 'static;
+        /// Queries for transactions broadcast by the view service which the fullnode
+        /// accepted into its mempool, but which haven't been detected on chain yet.
+        ///
+        /// The notes spent by pending transactions are treated as provisionally
+        /// spent, and are not returned as unspent notes, until the transaction is
+        /// detected on chain or expires.
+        async fn pending_transactions(
+            &self,
+            request: tonic::Request<super::PendingTransactionsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<Self::PendingTransactionsStream>,
+            tonic::Status,
+        >;
+        /// Checks the integrity of the view service's database, optionally repairing
+        /// what can be repaired without resynchronizing and compacting the database,
+        /// and reports a summary of its health.
+        async fn database_maintenance(
+            &self,
+            request: tonic::Request<super::DatabaseMaintenanceRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::DatabaseMaintenanceResponse>,
+            tonic::Status,
+        >;
     }
     /// The view RPC is used by a view client, who wants to do some
     /// transaction-related actions, to request data from a view service, which is
@@ -3808,6 +4163,102 @@ pub mod view_service_server {
                     };
                     Box::pin(fut)
                 }
+                "/penumbra.view.v1.ViewService/PendingTransactions" => {
+                    #[allow(non_camel_case_types)]
+                    struct PendingTransactionsSvc<T: ViewService>(pub Arc<T>);
+                    impl<
+                        T: ViewService,
+                    > tonic::server::ServerStreamingService<
+                        super::PendingTransactionsRequest,
+                    > for PendingTransactionsSvc<T> {
+                        type Response = super::PendingTransactionsResponse;
+                        type ResponseStream = T::PendingTransactionsStream;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::ResponseStream>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::PendingTransactionsRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as ViewService>::pending_transactions(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = PendingTransactionsSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.server_streaming(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/penumbra.view.v1.ViewService/DatabaseMaintenance" => {
+                    #[allow(non_camel_case_types)]
+                    struct DatabaseMaintenanceSvc<T: ViewService>(pub Arc<T>);
+                    impl<
+                        T: ViewService,
+                    > tonic::server::UnaryService<super::DatabaseMaintenanceRequest>
+                    for DatabaseMaintenanceSvc<T> {
+                        type Response = super::DatabaseMaintenanceResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::DatabaseMaintenanceRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as ViewService>::database_maintenance(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = DatabaseMaintenanceSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
                 _ => {
                     Box::pin(async move {
                         Ok(
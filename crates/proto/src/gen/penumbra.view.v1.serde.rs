@@ -1263,6 +1263,9 @@ impl serde::Serialize for BalancesRequest {
         if self.asset_id_filter.is_some() {
             len += 1;
         }
+        if !self.numeraires.is_empty() {
+            len += 1;
+        }
         let mut struct_ser = serializer.serialize_struct("penumbra.view.v1.BalancesRequest", len)?;
         if let Some(v) = self.account_filter.as_ref() {
             struct_ser.serialize_field("accountFilter", v)?;
@@ -1270,6 +1273,9 @@ impl serde::Serialize for BalancesRequest {
         if let Some(v) = self.asset_id_filter.as_ref() {
             struct_ser.serialize_field("assetIdFilter", v)?;
         }
+        if !self.numeraires.is_empty() {
+            struct_ser.serialize_field("numeraires", &self.numeraires)?;
+        }
         struct_ser.end()
     }
 }
@@ -1284,12 +1290,14 @@ impl<'de> serde::Deserialize<'de> for BalancesRequest {
             "accountFilter",
             "asset_id_filter",
             "assetIdFilter",
+            "numeraires",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
             AccountFilter,
             AssetIdFilter,
+            Numeraires,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -1314,6 +1322,7 @@ impl<'de> serde::Deserialize<'de> for BalancesRequest {
                         match value {
                             "accountFilter" | "account_filter" => Ok(GeneratedField::AccountFilter),
                             "assetIdFilter" | "asset_id_filter" => Ok(GeneratedField::AssetIdFilter),
+                            "numeraires" => Ok(GeneratedField::Numeraires),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -1335,6 +1344,7 @@ impl<'de> serde::Deserialize<'de> for BalancesRequest {
             {
                 let mut account_filter__ = None;
                 let mut asset_id_filter__ = None;
+                let mut numeraires__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
                         GeneratedField::AccountFilter => {
@@ -1349,6 +1359,12 @@ impl<'de> serde::Deserialize<'de> for BalancesRequest {
                             }
                             asset_id_filter__ = map_.next_value()?;
                         }
+                        GeneratedField::Numeraires => {
+                            if numeraires__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("numeraires"));
+                            }
+                            numeraires__ = Some(map_.next_value()?);
+                        }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
@@ -1357,6 +1373,7 @@ impl<'de> serde::Deserialize<'de> for BalancesRequest {
                 Ok(BalancesRequest {
                     account_filter: account_filter__,
                     asset_id_filter: asset_id_filter__,
+                    numeraires: numeraires__.unwrap_or_default(),
                 })
             }
         }
@@ -1749,10 +1766,16 @@ impl serde::Serialize for broadcast_transaction_response::BroadcastSuccess {
         if self.id.is_some() {
             len += 1;
         }
+        if !self.mempool_log.is_empty() {
+            len += 1;
+        }
         let mut struct_ser = serializer.serialize_struct("penumbra.view.v1.BroadcastTransactionResponse.BroadcastSuccess", len)?;
         if let Some(v) = self.id.as_ref() {
             struct_ser.serialize_field("id", v)?;
         }
+        if !self.mempool_log.is_empty() {
+            struct_ser.serialize_field("mempoolLog", &self.mempool_log)?;
+        }
         struct_ser.end()
     }
 }
@@ -1764,11 +1787,14 @@ impl<'de> serde::Deserialize<'de> for broadcast_transaction_response::BroadcastS
     {
         const FIELDS: &[&str] = &[
             "id",
+            "mempool_log",
+            "mempoolLog",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
             Id,
+            MempoolLog,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -1792,6 +1818,7 @@ impl<'de> serde::Deserialize<'de> for broadcast_transaction_response::BroadcastS
                     {
                         match value {
                             "id" => Ok(GeneratedField::Id),
+                            "mempoolLog" | "mempool_log" => Ok(GeneratedField::MempoolLog),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -1812,6 +1839,7 @@ impl<'de> serde::Deserialize<'de> for broadcast_transaction_response::BroadcastS
                     V: serde::de::MapAccess<'de>,
             {
                 let mut id__ = None;
+                let mut mempool_log__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
                         GeneratedField::Id => {
@@ -1820,6 +1848,12 @@ impl<'de> serde::Deserialize<'de> for broadcast_transaction_response::BroadcastS
                             }
                             id__ = map_.next_value()?;
                         }
+                        GeneratedField::MempoolLog => {
+                            if mempool_log__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("mempoolLog"));
+                            }
+                            mempool_log__ = Some(map_.next_value()?);
+                        }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
@@ -1827,6 +1861,7 @@ impl<'de> serde::Deserialize<'de> for broadcast_transaction_response::BroadcastS
                 }
                 Ok(broadcast_transaction_response::BroadcastSuccess {
                     id: id__,
+                    mempool_log: mempool_log__.unwrap_or_default(),
                 })
             }
         }
@@ -1949,6 +1984,358 @@ impl<'de> serde::Deserialize<'de> for broadcast_transaction_response::Confirmed
         deserializer.deserialize_struct("penumbra.view.v1.BroadcastTransactionResponse.Confirmed", FIELDS, GeneratedVisitor)
     }
 }
+impl serde::Serialize for DatabaseMaintenanceRequest {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.repair {
+            len += 1;
+        }
+        if self.vacuum {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1.DatabaseMaintenanceRequest", len)?;
+        if self.repair {
+            struct_ser.serialize_field("repair", &self.repair)?;
+        }
+        if self.vacuum {
+            struct_ser.serialize_field("vacuum", &self.vacuum)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for DatabaseMaintenanceRequest {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "repair",
+            "vacuum",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Repair,
+            Vacuum,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "repair" => Ok(GeneratedField::Repair),
+                            "vacuum" => Ok(GeneratedField::Vacuum),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = DatabaseMaintenanceRequest;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.view.v1.DatabaseMaintenanceRequest")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<DatabaseMaintenanceRequest, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut repair__ = None;
+                let mut vacuum__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Repair => {
+                            if repair__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("repair"));
+                            }
+                            repair__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Vacuum => {
+                            if vacuum__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("vacuum"));
+                            }
+                            vacuum__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(DatabaseMaintenanceRequest {
+                    repair: repair__.unwrap_or_default(),
+                    vacuum: vacuum__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.view.v1.DatabaseMaintenanceRequest", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for DatabaseMaintenanceResponse {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.storage_errors.is_empty() {
+            len += 1;
+        }
+        if self.orphaned_records != 0 {
+            len += 1;
+        }
+        if self.unwitnessed_records != 0 {
+            len += 1;
+        }
+        if self.duplicate_records != 0 {
+            len += 1;
+        }
+        if self.repaired_records != 0 {
+            len += 1;
+        }
+        if self.size_before != 0 {
+            len += 1;
+        }
+        if self.size_after != 0 {
+            len += 1;
+        }
+        if self.needs_resync {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1.DatabaseMaintenanceResponse", len)?;
+        if !self.storage_errors.is_empty() {
+            struct_ser.serialize_field("storageErrors", &self.storage_errors)?;
+        }
+        if self.orphaned_records != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("orphanedRecords", ToString::to_string(&self.orphaned_records).as_str())?;
+        }
+        if self.unwitnessed_records != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("unwitnessedRecords", ToString::to_string(&self.unwitnessed_records).as_str())?;
+        }
+        if self.duplicate_records != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("duplicateRecords", ToString::to_string(&self.duplicate_records).as_str())?;
+        }
+        if self.repaired_records != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("repairedRecords", ToString::to_string(&self.repaired_records).as_str())?;
+        }
+        if self.size_before != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("sizeBefore", ToString::to_string(&self.size_before).as_str())?;
+        }
+        if self.size_after != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("sizeAfter", ToString::to_string(&self.size_after).as_str())?;
+        }
+        if self.needs_resync {
+            struct_ser.serialize_field("needsResync", &self.needs_resync)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for DatabaseMaintenanceResponse {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "storage_errors",
+            "storageErrors",
+            "orphaned_records",
+            "orphanedRecords",
+            "unwitnessed_records",
+            "unwitnessedRecords",
+            "duplicate_records",
+            "duplicateRecords",
+            "repaired_records",
+            "repairedRecords",
+            "size_before",
+            "sizeBefore",
+            "size_after",
+            "sizeAfter",
+            "needs_resync",
+            "needsResync",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            StorageErrors,
+            OrphanedRecords,
+            UnwitnessedRecords,
+            DuplicateRecords,
+            RepairedRecords,
+            SizeBefore,
+            SizeAfter,
+            NeedsResync,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "storageErrors" | "storage_errors" => Ok(GeneratedField::StorageErrors),
+                            "orphanedRecords" | "orphaned_records" => Ok(GeneratedField::OrphanedRecords),
+                            "unwitnessedRecords" | "unwitnessed_records" => Ok(GeneratedField::UnwitnessedRecords),
+                            "duplicateRecords" | "duplicate_records" => Ok(GeneratedField::DuplicateRecords),
+                            "repairedRecords" | "repaired_records" => Ok(GeneratedField::RepairedRecords),
+                            "sizeBefore" | "size_before" => Ok(GeneratedField::SizeBefore),
+                            "sizeAfter" | "size_after" => Ok(GeneratedField::SizeAfter),
+                            "needsResync" | "needs_resync" => Ok(GeneratedField::NeedsResync),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = DatabaseMaintenanceResponse;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.view.v1.DatabaseMaintenanceResponse")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<DatabaseMaintenanceResponse, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut storage_errors__ = None;
+                let mut orphaned_records__ = None;
+                let mut unwitnessed_records__ = None;
+                let mut duplicate_records__ = None;
+                let mut repaired_records__ = None;
+                let mut size_before__ = None;
+                let mut size_after__ = None;
+                let mut needs_resync__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::StorageErrors => {
+                            if storage_errors__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("storageErrors"));
+                            }
+                            storage_errors__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::OrphanedRecords => {
+                            if orphaned_records__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("orphanedRecords"));
+                            }
+                            orphaned_records__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::UnwitnessedRecords => {
+                            if unwitnessed_records__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("unwitnessedRecords"));
+                            }
+                            unwitnessed_records__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::DuplicateRecords => {
+                            if duplicate_records__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("duplicateRecords"));
+                            }
+                            duplicate_records__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::RepairedRecords => {
+                            if repaired_records__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("repairedRecords"));
+                            }
+                            repaired_records__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::SizeBefore => {
+                            if size_before__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("sizeBefore"));
+                            }
+                            size_before__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::SizeAfter => {
+                            if size_after__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("sizeAfter"));
+                            }
+                            size_after__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::NeedsResync => {
+                            if needs_resync__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("needsResync"));
+                            }
+                            needs_resync__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(DatabaseMaintenanceResponse {
+                    storage_errors: storage_errors__.unwrap_or_default(),
+                    orphaned_records: orphaned_records__.unwrap_or_default(),
+                    unwitnessed_records: unwitnessed_records__.unwrap_or_default(),
+                    duplicate_records: duplicate_records__.unwrap_or_default(),
+                    repaired_records: repaired_records__.unwrap_or_default(),
+                    size_before: size_before__.unwrap_or_default(),
+                    size_after: size_after__.unwrap_or_default(),
+                    needs_resync: needs_resync__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.view.v1.DatabaseMaintenanceResponse", FIELDS, GeneratedVisitor)
+    }
+}
 impl serde::Serialize for EphemeralAddressRequest {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
@@ -2876,6 +3263,86 @@ impl<'de> serde::Deserialize<'de> for NoteByCommitmentResponse {
         deserializer.deserialize_struct("penumbra.view.v1.NoteByCommitmentResponse", FIELDS, GeneratedVisitor)
     }
 }
+impl serde::Serialize for NoteSelectionStrategy {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        let variant = match self {
+            Self::Unspecified => "NOTE_SELECTION_STRATEGY_UNSPECIFIED",
+            Self::OldestFirst => "NOTE_SELECTION_STRATEGY_OLDEST_FIRST",
+            Self::FewestNotes => "NOTE_SELECTION_STRATEGY_FEWEST_NOTES",
+            Self::PrivacyMax => "NOTE_SELECTION_STRATEGY_PRIVACY_MAX",
+            Self::MinimizeChange => "NOTE_SELECTION_STRATEGY_MINIMIZE_CHANGE",
+        };
+        serializer.serialize_str(variant)
+    }
+}
+impl<'de> serde::Deserialize<'de> for NoteSelectionStrategy {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "NOTE_SELECTION_STRATEGY_UNSPECIFIED",
+            "NOTE_SELECTION_STRATEGY_OLDEST_FIRST",
+            "NOTE_SELECTION_STRATEGY_FEWEST_NOTES",
+            "NOTE_SELECTION_STRATEGY_PRIVACY_MAX",
+            "NOTE_SELECTION_STRATEGY_MINIMIZE_CHANGE",
+        ];
+
+        struct GeneratedVisitor;
+
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = NoteSelectionStrategy;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                write!(formatter, "expected one of: {:?}", &FIELDS)
+            }
+
+            fn visit_i64<E>(self, v: i64) -> std::result::Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                i32::try_from(v)
+                    .ok()
+                    .and_then(|x| x.try_into().ok())
+                    .ok_or_else(|| {
+                        serde::de::Error::invalid_value(serde::de::Unexpected::Signed(v), &self)
+                    })
+            }
+
+            fn visit_u64<E>(self, v: u64) -> std::result::Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                i32::try_from(v)
+                    .ok()
+                    .and_then(|x| x.try_into().ok())
+                    .ok_or_else(|| {
+                        serde::de::Error::invalid_value(serde::de::Unexpected::Unsigned(v), &self)
+                    })
+            }
+
+            fn visit_str<E>(self, value: &str) -> std::result::Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                match value {
+                    "NOTE_SELECTION_STRATEGY_UNSPECIFIED" => Ok(NoteSelectionStrategy::Unspecified),
+                    "NOTE_SELECTION_STRATEGY_OLDEST_FIRST" => Ok(NoteSelectionStrategy::OldestFirst),
+                    "NOTE_SELECTION_STRATEGY_FEWEST_NOTES" => Ok(NoteSelectionStrategy::FewestNotes),
+                    "NOTE_SELECTION_STRATEGY_PRIVACY_MAX" => Ok(NoteSelectionStrategy::PrivacyMax),
+                    "NOTE_SELECTION_STRATEGY_MINIMIZE_CHANGE" => Ok(NoteSelectionStrategy::MinimizeChange),
+                    _ => Err(serde::de::Error::unknown_variant(value, FIELDS)),
+                }
+            }
+        }
+        deserializer.deserialize_any(GeneratedVisitor)
+    }
+}
 impl serde::Serialize for NotesForVotingRequest {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
@@ -3771,7 +4238,677 @@ impl<'de> serde::Deserialize<'de> for OwnedPositionIdsResponse {
         deserializer.deserialize_struct("penumbra.view.v1.OwnedPositionIdsResponse", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for SpendableNoteRecord {
+impl serde::Serialize for PartialAuthorization {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.index != 0 {
+            len += 1;
+        }
+        if self.signature.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1.PartialAuthorization", len)?;
+        if self.index != 0 {
+            struct_ser.serialize_field("index", &self.index)?;
+        }
+        if let Some(v) = self.signature.as_ref() {
+            struct_ser.serialize_field("signature", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for PartialAuthorization {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "index",
+            "signature",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Index,
+            Signature,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "index" => Ok(GeneratedField::Index),
+                            "signature" => Ok(GeneratedField::Signature),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = PartialAuthorization;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.view.v1.PartialAuthorization")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<PartialAuthorization, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut index__ = None;
+                let mut signature__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Index => {
+                            if index__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("index"));
+                            }
+                            index__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::Signature => {
+                            if signature__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("signature"));
+                            }
+                            signature__ = map_.next_value()?;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(PartialAuthorization {
+                    index: index__.unwrap_or_default(),
+                    signature: signature__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.view.v1.PartialAuthorization", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for PartiallyAuthorizedTransaction {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.version != 0 {
+            len += 1;
+        }
+        if self.plan.is_some() {
+            len += 1;
+        }
+        if self.effect_hash.is_some() {
+            len += 1;
+        }
+        if !self.spend_auths.is_empty() {
+            len += 1;
+        }
+        if !self.delegator_vote_auths.is_empty() {
+            len += 1;
+        }
+        if !self.pre_authorizations.is_empty() {
+            len += 1;
+        }
+        if !self.metadata.is_empty() {
+            len += 1;
+        }
+        if self.full_viewing_key.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1.PartiallyAuthorizedTransaction", len)?;
+        if self.version != 0 {
+            struct_ser.serialize_field("version", &self.version)?;
+        }
+        if let Some(v) = self.plan.as_ref() {
+            struct_ser.serialize_field("plan", v)?;
+        }
+        if let Some(v) = self.effect_hash.as_ref() {
+            struct_ser.serialize_field("effectHash", v)?;
+        }
+        if !self.spend_auths.is_empty() {
+            struct_ser.serialize_field("spendAuths", &self.spend_auths)?;
+        }
+        if !self.delegator_vote_auths.is_empty() {
+            struct_ser.serialize_field("delegatorVoteAuths", &self.delegator_vote_auths)?;
+        }
+        if !self.pre_authorizations.is_empty() {
+            struct_ser.serialize_field("preAuthorizations", &self.pre_authorizations)?;
+        }
+        if !self.metadata.is_empty() {
+            struct_ser.serialize_field("metadata", &self.metadata)?;
+        }
+        if let Some(v) = self.full_viewing_key.as_ref() {
+            struct_ser.serialize_field("fullViewingKey", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for PartiallyAuthorizedTransaction {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "version",
+            "plan",
+            "effect_hash",
+            "effectHash",
+            "spend_auths",
+            "spendAuths",
+            "delegator_vote_auths",
+            "delegatorVoteAuths",
+            "pre_authorizations",
+            "preAuthorizations",
+            "metadata",
+            "full_viewing_key",
+            "fullViewingKey",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Version,
+            Plan,
+            EffectHash,
+            SpendAuths,
+            DelegatorVoteAuths,
+            PreAuthorizations,
+            Metadata,
+            FullViewingKey,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "version" => Ok(GeneratedField::Version),
+                            "plan" => Ok(GeneratedField::Plan),
+                            "effectHash" | "effect_hash" => Ok(GeneratedField::EffectHash),
+                            "spendAuths" | "spend_auths" => Ok(GeneratedField::SpendAuths),
+                            "delegatorVoteAuths" | "delegator_vote_auths" => Ok(GeneratedField::DelegatorVoteAuths),
+                            "preAuthorizations" | "pre_authorizations" => Ok(GeneratedField::PreAuthorizations),
+                            "metadata" => Ok(GeneratedField::Metadata),
+                            "fullViewingKey" | "full_viewing_key" => Ok(GeneratedField::FullViewingKey),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = PartiallyAuthorizedTransaction;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.view.v1.PartiallyAuthorizedTransaction")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<PartiallyAuthorizedTransaction, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut version__ = None;
+                let mut plan__ = None;
+                let mut effect_hash__ = None;
+                let mut spend_auths__ = None;
+                let mut delegator_vote_auths__ = None;
+                let mut pre_authorizations__ = None;
+                let mut metadata__ = None;
+                let mut full_viewing_key__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Version => {
+                            if version__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("version"));
+                            }
+                            version__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::Plan => {
+                            if plan__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("plan"));
+                            }
+                            plan__ = map_.next_value()?;
+                        }
+                        GeneratedField::EffectHash => {
+                            if effect_hash__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("effectHash"));
+                            }
+                            effect_hash__ = map_.next_value()?;
+                        }
+                        GeneratedField::SpendAuths => {
+                            if spend_auths__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("spendAuths"));
+                            }
+                            spend_auths__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::DelegatorVoteAuths => {
+                            if delegator_vote_auths__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("delegatorVoteAuths"));
+                            }
+                            delegator_vote_auths__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::PreAuthorizations => {
+                            if pre_authorizations__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("preAuthorizations"));
+                            }
+                            pre_authorizations__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Metadata => {
+                            if metadata__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("metadata"));
+                            }
+                            metadata__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::FullViewingKey => {
+                            if full_viewing_key__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("fullViewingKey"));
+                            }
+                            full_viewing_key__ = map_.next_value()?;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(PartiallyAuthorizedTransaction {
+                    version: version__.unwrap_or_default(),
+                    plan: plan__,
+                    effect_hash: effect_hash__,
+                    spend_auths: spend_auths__.unwrap_or_default(),
+                    delegator_vote_auths: delegator_vote_auths__.unwrap_or_default(),
+                    pre_authorizations: pre_authorizations__.unwrap_or_default(),
+                    metadata: metadata__.unwrap_or_default(),
+                    full_viewing_key: full_viewing_key__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.view.v1.PartiallyAuthorizedTransaction", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for PartiallyAuthorizedTransactionMetadata {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.key.is_empty() {
+            len += 1;
+        }
+        if !self.value.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1.PartiallyAuthorizedTransactionMetadata", len)?;
+        if !self.key.is_empty() {
+            struct_ser.serialize_field("key", &self.key)?;
+        }
+        if !self.value.is_empty() {
+            struct_ser.serialize_field("value", &self.value)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for PartiallyAuthorizedTransactionMetadata {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "key",
+            "value",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Key,
+            Value,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "key" => Ok(GeneratedField::Key),
+                            "value" => Ok(GeneratedField::Value),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = PartiallyAuthorizedTransactionMetadata;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.view.v1.PartiallyAuthorizedTransactionMetadata")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<PartiallyAuthorizedTransactionMetadata, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut key__ = None;
+                let mut value__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Key => {
+                            if key__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("key"));
+                            }
+                            key__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Value => {
+                            if value__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("value"));
+                            }
+                            value__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(PartiallyAuthorizedTransactionMetadata {
+                    key: key__.unwrap_or_default(),
+                    value: value__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.view.v1.PartiallyAuthorizedTransactionMetadata", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for PendingTransactionsRequest {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let len = 0;
+        let struct_ser = serializer.serialize_struct("penumbra.view.v1.PendingTransactionsRequest", len)?;
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for PendingTransactionsRequest {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                            Ok(GeneratedField::__SkipField__)
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = PendingTransactionsRequest;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.view.v1.PendingTransactionsRequest")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<PendingTransactionsRequest, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                while map_.next_key::<GeneratedField>()?.is_some() {
+                    let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                }
+                Ok(PendingTransactionsRequest {
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.view.v1.PendingTransactionsRequest", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for PendingTransactionsResponse {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.id.is_some() {
+            len += 1;
+        }
+        if self.transaction.is_some() {
+            len += 1;
+        }
+        if self.broadcast_height != 0 {
+            len += 1;
+        }
+        if !self.mempool_log.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1.PendingTransactionsResponse", len)?;
+        if let Some(v) = self.id.as_ref() {
+            struct_ser.serialize_field("id", v)?;
+        }
+        if let Some(v) = self.transaction.as_ref() {
+            struct_ser.serialize_field("transaction", v)?;
+        }
+        if self.broadcast_height != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("broadcastHeight", ToString::to_string(&self.broadcast_height).as_str())?;
+        }
+        if !self.mempool_log.is_empty() {
+            struct_ser.serialize_field("mempoolLog", &self.mempool_log)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for PendingTransactionsResponse {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "id",
+            "transaction",
+            "broadcast_height",
+            "broadcastHeight",
+            "mempool_log",
+            "mempoolLog",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Id,
+            Transaction,
+            BroadcastHeight,
+            MempoolLog,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "id" => Ok(GeneratedField::Id),
+                            "transaction" => Ok(GeneratedField::Transaction),
+                            "broadcastHeight" | "broadcast_height" => Ok(GeneratedField::BroadcastHeight),
+                            "mempoolLog" | "mempool_log" => Ok(GeneratedField::MempoolLog),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = PendingTransactionsResponse;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.view.v1.PendingTransactionsResponse")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<PendingTransactionsResponse, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut id__ = None;
+                let mut transaction__ = None;
+                let mut broadcast_height__ = None;
+                let mut mempool_log__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Id => {
+                            if id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("id"));
+                            }
+                            id__ = map_.next_value()?;
+                        }
+                        GeneratedField::Transaction => {
+                            if transaction__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("transaction"));
+                            }
+                            transaction__ = map_.next_value()?;
+                        }
+                        GeneratedField::BroadcastHeight => {
+                            if broadcast_height__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("broadcastHeight"));
+                            }
+                            broadcast_height__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::MempoolLog => {
+                            if mempool_log__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("mempoolLog"));
+                            }
+                            mempool_log__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(PendingTransactionsResponse {
+                    id: id__,
+                    transaction: transaction__,
+                    broadcast_height: broadcast_height__.unwrap_or_default(),
+                    mempool_log: mempool_log__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.view.v1.PendingTransactionsResponse", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for SpendableNoteRecord {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -4877,6 +6014,9 @@ impl serde::Serialize for TransactionInfo {
         if self.view.is_some() {
             len += 1;
         }
+        if !self.refunds.is_empty() {
+            len += 1;
+        }
         let mut struct_ser = serializer.serialize_struct("penumbra.view.v1.TransactionInfo", len)?;
         if self.height != 0 {
             #[allow(clippy::needless_borrow)]
@@ -4894,6 +6034,9 @@ impl serde::Serialize for TransactionInfo {
         if let Some(v) = self.view.as_ref() {
             struct_ser.serialize_field("view", v)?;
         }
+        if !self.refunds.is_empty() {
+            struct_ser.serialize_field("refunds", &self.refunds)?;
+        }
         struct_ser.end()
     }
 }
@@ -4909,6 +6052,7 @@ impl<'de> serde::Deserialize<'de> for TransactionInfo {
             "transaction",
             "perspective",
             "view",
+            "refunds",
         ];
 
         #[allow(clippy::enum_variant_names)]
@@ -4918,6 +6062,7 @@ impl<'de> serde::Deserialize<'de> for TransactionInfo {
             Transaction,
             Perspective,
             View,
+            Refunds,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -4945,6 +6090,7 @@ impl<'de> serde::Deserialize<'de> for TransactionInfo {
                             "transaction" => Ok(GeneratedField::Transaction),
                             "perspective" => Ok(GeneratedField::Perspective),
                             "view" => Ok(GeneratedField::View),
+                            "refunds" => Ok(GeneratedField::Refunds),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -4969,6 +6115,7 @@ impl<'de> serde::Deserialize<'de> for TransactionInfo {
                 let mut transaction__ = None;
                 let mut perspective__ = None;
                 let mut view__ = None;
+                let mut refunds__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
                         GeneratedField::Height => {
@@ -5003,6 +6150,12 @@ impl<'de> serde::Deserialize<'de> for TransactionInfo {
                             }
                             view__ = map_.next_value()?;
                         }
+                        GeneratedField::Refunds => {
+                            if refunds__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("refunds"));
+                            }
+                            refunds__ = Some(map_.next_value()?);
+                        }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
@@ -5014,6 +6167,7 @@ impl<'de> serde::Deserialize<'de> for TransactionInfo {
                     transaction: transaction__,
                     perspective: perspective__,
                     view: view__,
+                    refunds: refunds__.unwrap_or_default(),
                 })
             }
         }
@@ -5031,10 +6185,16 @@ impl serde::Serialize for TransactionInfoByHashRequest {
         if self.id.is_some() {
             len += 1;
         }
+        if !self.numeraires.is_empty() {
+            len += 1;
+        }
         let mut struct_ser = serializer.serialize_struct("penumbra.view.v1.TransactionInfoByHashRequest", len)?;
         if let Some(v) = self.id.as_ref() {
             struct_ser.serialize_field("id", v)?;
         }
+        if !self.numeraires.is_empty() {
+            struct_ser.serialize_field("numeraires", &self.numeraires)?;
+        }
         struct_ser.end()
     }
 }
@@ -5046,11 +6206,13 @@ impl<'de> serde::Deserialize<'de> for TransactionInfoByHashRequest {
     {
         const FIELDS: &[&str] = &[
             "id",
+            "numeraires",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
             Id,
+            Numeraires,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -5074,6 +6236,7 @@ impl<'de> serde::Deserialize<'de> for TransactionInfoByHashRequest {
                     {
                         match value {
                             "id" => Ok(GeneratedField::Id),
+                            "numeraires" => Ok(GeneratedField::Numeraires),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -5094,6 +6257,7 @@ impl<'de> serde::Deserialize<'de> for TransactionInfoByHashRequest {
                     V: serde::de::MapAccess<'de>,
             {
                 let mut id__ = None;
+                let mut numeraires__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
                         GeneratedField::Id => {
@@ -5102,6 +6266,12 @@ impl<'de> serde::Deserialize<'de> for TransactionInfoByHashRequest {
                             }
                             id__ = map_.next_value()?;
                         }
+                        GeneratedField::Numeraires => {
+                            if numeraires__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("numeraires"));
+                            }
+                            numeraires__ = Some(map_.next_value()?);
+                        }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
@@ -5109,6 +6279,7 @@ impl<'de> serde::Deserialize<'de> for TransactionInfoByHashRequest {
                 }
                 Ok(TransactionInfoByHashRequest {
                     id: id__,
+                    numeraires: numeraires__.unwrap_or_default(),
                 })
             }
         }
@@ -5225,6 +6396,9 @@ impl serde::Serialize for TransactionInfoRequest {
         if self.end_height != 0 {
             len += 1;
         }
+        if !self.numeraires.is_empty() {
+            len += 1;
+        }
         let mut struct_ser = serializer.serialize_struct("penumbra.view.v1.TransactionInfoRequest", len)?;
         if self.start_height != 0 {
             #[allow(clippy::needless_borrow)]
@@ -5234,6 +6408,9 @@ impl serde::Serialize for TransactionInfoRequest {
             #[allow(clippy::needless_borrow)]
             struct_ser.serialize_field("endHeight", ToString::to_string(&self.end_height).as_str())?;
         }
+        if !self.numeraires.is_empty() {
+            struct_ser.serialize_field("numeraires", &self.numeraires)?;
+        }
         struct_ser.end()
     }
 }
@@ -5248,12 +6425,14 @@ impl<'de> serde::Deserialize<'de> for TransactionInfoRequest {
             "startHeight",
             "end_height",
             "endHeight",
+            "numeraires",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
             StartHeight,
             EndHeight,
+            Numeraires,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -5278,6 +6457,7 @@ impl<'de> serde::Deserialize<'de> for TransactionInfoRequest {
                         match value {
                             "startHeight" | "start_height" => Ok(GeneratedField::StartHeight),
                             "endHeight" | "end_height" => Ok(GeneratedField::EndHeight),
+                            "numeraires" => Ok(GeneratedField::Numeraires),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -5299,6 +6479,7 @@ impl<'de> serde::Deserialize<'de> for TransactionInfoRequest {
             {
                 let mut start_height__ = None;
                 let mut end_height__ = None;
+                let mut numeraires__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
                         GeneratedField::StartHeight => {
@@ -5317,6 +6498,12 @@ impl<'de> serde::Deserialize<'de> for TransactionInfoRequest {
                                 Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
                             ;
                         }
+                        GeneratedField::Numeraires => {
+                            if numeraires__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("numeraires"));
+                            }
+                            numeraires__ = Some(map_.next_value()?);
+                        }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
@@ -5325,6 +6512,7 @@ impl<'de> serde::Deserialize<'de> for TransactionInfoRequest {
                 Ok(TransactionInfoRequest {
                     start_height: start_height__.unwrap_or_default(),
                     end_height: end_height__.unwrap_or_default(),
+                    numeraires: numeraires__.unwrap_or_default(),
                 })
             }
         }
@@ -5444,6 +6632,9 @@ impl serde::Serialize for TransactionPlannerRequest {
         if self.source.is_some() {
             len += 1;
         }
+        if self.note_selection_strategy != 0 {
+            len += 1;
+        }
         if !self.outputs.is_empty() {
             len += 1;
         }
@@ -5491,6 +6682,11 @@ impl serde::Serialize for TransactionPlannerRequest {
         if let Some(v) = self.source.as_ref() {
             struct_ser.serialize_field("source", v)?;
         }
+        if self.note_selection_strategy != 0 {
+            let v = NoteSelectionStrategy::try_from(self.note_selection_strategy)
+                .map_err(|_| serde::ser::Error::custom(format!("Invalid variant {}", self.note_selection_strategy)))?;
+            struct_ser.serialize_field("noteSelectionStrategy", &v)?;
+        }
         if !self.outputs.is_empty() {
             struct_ser.serialize_field("outputs", &self.outputs)?;
         }
@@ -5548,6 +6744,8 @@ impl<'de> serde::Deserialize<'de> for TransactionPlannerRequest {
             "expiryHeight",
             "memo",
             "source",
+            "note_selection_strategy",
+            "noteSelectionStrategy",
             "outputs",
             "swaps",
             "swap_claims",
@@ -5577,6 +6775,7 @@ impl<'de> serde::Deserialize<'de> for TransactionPlannerRequest {
             ExpiryHeight,
             Memo,
             Source,
+            NoteSelectionStrategy,
             Outputs,
             Swaps,
             SwapClaims,
@@ -5615,6 +6814,7 @@ impl<'de> serde::Deserialize<'de> for TransactionPlannerRequest {
                             "expiryHeight" | "expiry_height" => Ok(GeneratedField::ExpiryHeight),
                             "memo" => Ok(GeneratedField::Memo),
                             "source" => Ok(GeneratedField::Source),
+                            "noteSelectionStrategy" | "note_selection_strategy" => Ok(GeneratedField::NoteSelectionStrategy),
                             "outputs" => Ok(GeneratedField::Outputs),
                             "swaps" => Ok(GeneratedField::Swaps),
                             "swapClaims" | "swap_claims" => Ok(GeneratedField::SwapClaims),
@@ -5650,6 +6850,7 @@ impl<'de> serde::Deserialize<'de> for TransactionPlannerRequest {
                 let mut expiry_height__ = None;
                 let mut memo__ = None;
                 let mut source__ = None;
+                let mut note_selection_strategy__ = None;
                 let mut outputs__ = None;
                 let mut swaps__ = None;
                 let mut swap_claims__ = None;
@@ -5684,6 +6885,12 @@ impl<'de> serde::Deserialize<'de> for TransactionPlannerRequest {
                             }
                             source__ = map_.next_value()?;
                         }
+                        GeneratedField::NoteSelectionStrategy => {
+                            if note_selection_strategy__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("noteSelectionStrategy"));
+                            }
+                            note_selection_strategy__ = Some(map_.next_value::<NoteSelectionStrategy>()? as i32);
+                        }
                         GeneratedField::Outputs => {
                             if outputs__.is_some() {
                                 return Err(serde::de::Error::duplicate_field("outputs"));
@@ -5773,6 +6980,7 @@ impl<'de> serde::Deserialize<'de> for TransactionPlannerRequest {
                     expiry_height: expiry_height__.unwrap_or_default(),
                     memo: memo__,
                     source: source__,
+                    note_selection_strategy: note_selection_strategy__.unwrap_or_default(),
                     outputs: outputs__.unwrap_or_default(),
                     swaps: swaps__.unwrap_or_default(),
                     swap_claims: swap_claims__.unwrap_or_default(),
@@ -0,0 +1,431 @@
+//! Serving Penumbra's gRPC services directly to browsers.
+//!
+//! Browsers can't make native gRPC requests, so a service must be reachable some other way for a
+//! web wallet to use it without a proxy in front of the node. Wrapping a generated server with
+//! [`enable`] makes it reachable in two:
+//!
+//! - gRPC-web, as spoken by `grpc-web` and `connect-web` clients, via [`tonic_web`];
+//! - gRPC-JSON transcoding, via [`JsonTranscoder`]: a `POST` of a request message encoded as
+//!   JSON to a method's gRPC path, such as `/penumbra.core.component.dex.v1.QueryService/Spread`,
+//!   is answered with the response message encoded as JSON.
+//!
+//! The JSON encoding is the canonical proto3 JSON mapping, the same as the [`serde`]
+//! implementations of the generated types. Server-streaming methods respond with newline-delimited
+//! JSON, one response message per line, so that long-lived streams work too.
+
+use std::{
+    collections::HashMap,
+    convert::Infallible,
+    sync::{Arc, OnceLock},
+    task::{Context, Poll},
+};
+
+use bytes::{Buf, BufMut, Bytes, BytesMut};
+use futures::{future::BoxFuture, stream};
+use http_body::Body as _;
+use prost::Message as _;
+use prost_reflect::{DescriptorPool, DynamicMessage, MessageDescriptor, MethodDescriptor};
+use tonic::{
+    body::BoxBody,
+    codegen::http::{header, HeaderValue, Method, Request, Response, StatusCode, Version},
+    server::NamedService,
+    transport::Body,
+    Code, Status,
+};
+use tower::Service;
+
+pub use tonic_web::{GrpcWebLayer, GrpcWebService};
+
+/// The content type of JSON requests, and of the responses of unary methods.
+const JSON: &str = "application/json";
+
+/// The content type of the responses of server-streaming methods, one JSON message per line.
+const NDJSON: &str = "application/x-ndjson";
+
+/// The largest JSON request body which will be transcoded.
+const MAX_JSON_REQUEST_LEN: usize = 1 << 20;
+
+/// Wrap a generated gRPC server so that browsers can call it, over either gRPC-web or JSON.
+///
+/// This is a drop-in replacement for [`tonic_web::enable`].
+pub fn enable<S>(service: S) -> JsonTranscoder<GrpcWebService<S>>
+where
+    S: Service<Request<Body>, Response = Response<BoxBody>> + NamedService,
+    S: Clone + Send + 'static,
+    S::Future: Send + 'static,
+    S::Error: Into<tower::BoxError> + Send,
+{
+    JsonTranscoder::new(tonic_web::enable(service))
+}
+
+/// The descriptors of all Penumbra services and messages.
+fn descriptor_pool() -> &'static DescriptorPool {
+    static POOL: OnceLock<DescriptorPool> = OnceLock::new();
+    POOL.get_or_init(|| {
+        DescriptorPool::decode(crate::FILE_DESCRIPTOR_SET)
+            .expect("the Penumbra file descriptor set is valid")
+    })
+}
+
+/// A gRPC service which also answers JSON requests to any of its methods, by transcoding them to
+/// and from protobuf.
+///
+/// Requests which aren't a `POST` with a JSON content type are passed through untouched, so this
+/// should wrap any [`GrpcWebService`] rather than be wrapped by one: the transcoded requests are
+/// sent on to the inner service as HTTP/2 gRPC requests.
+///
+/// Only services described by [`FILE_DESCRIPTOR_SET`](crate::FILE_DESCRIPTOR_SET) can be
+/// transcoded; any other service is served as if it weren't wrapped.
+#[derive(Debug, Clone)]
+pub struct JsonTranscoder<S> {
+    inner: S,
+    /// The methods of the inner service, by their gRPC path.
+    methods: Arc<HashMap<String, MethodDescriptor>>,
+}
+
+impl<S: NamedService> JsonTranscoder<S> {
+    /// Wrap a generated gRPC server, or a [`GrpcWebService`] wrapping one.
+    pub fn new(inner: S) -> Self {
+        let methods = descriptor_pool()
+            .get_service_by_name(S::NAME)
+            .map(|service| {
+                service
+                    .methods()
+                    .map(|method| (format!("/{}/{}", S::NAME, method.name()), method))
+                    .collect()
+            })
+            .unwrap_or_default();
+        Self {
+            inner,
+            methods: Arc::new(methods),
+        }
+    }
+}
+
+impl<S: NamedService> NamedService for JsonTranscoder<S> {
+    const NAME: &'static str = S::NAME;
+}
+
+impl<S> Service<Request<Body>> for JsonTranscoder<S>
+where
+    S: Service<Request<Body>, Response = Response<BoxBody>> + Clone + Send + 'static,
+    S::Future: Send + 'static,
+{
+    type Response = Response<BoxBody>;
+    type Error = S::Error;
+    type Future = BoxFuture<'static, Result<Self::Response, Self::Error>>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, request: Request<Body>) -> Self::Future {
+        let method = if request.method() == Method::POST && has_content_type(&request, JSON) {
+            self.methods.get(request.uri().path()).cloned()
+        } else {
+            None
+        };
+        let Some(method) = method else {
+            return Box::pin(self.inner.call(request));
+        };
+
+        // Keep the service that was driven to readiness for this request, leaving a clone for the
+        // next one.
+        let clone = self.inner.clone();
+        let inner = std::mem::replace(&mut self.inner, clone);
+        Box::pin(transcode(inner, method, request))
+    }
+}
+
+fn has_content_type<B>(request: &Request<B>, content_type: &str) -> bool {
+    request
+        .headers()
+        .get(header::CONTENT_TYPE)
+        .and_then(|value| value.to_str().ok())
+        .map_or(false, |value| value.starts_with(content_type))
+}
+
+/// Answer a JSON request to `method` by way of the gRPC service `inner`.
+async fn transcode<S>(
+    mut inner: S,
+    method: MethodDescriptor,
+    request: Request<Body>,
+) -> Result<Response<BoxBody>, S::Error>
+where
+    S: Service<Request<Body>, Response = Response<BoxBody>>,
+{
+    if method.is_client_streaming() {
+        return Ok(status_response(&Status::unimplemented(
+            "client-streaming methods can't be called with JSON",
+        )));
+    }
+
+    let (parts, body) = request.into_parts();
+    let message = match decode_json_request(method.input(), body).await {
+        Ok(message) => message,
+        Err(status) => return Ok(status_response(&status)),
+    };
+
+    let mut grpc_request = Request::new(Body::from(encode_frame(&message)));
+    *grpc_request.method_mut() = Method::POST;
+    *grpc_request.uri_mut() = parts.uri;
+    *grpc_request.version_mut() = Version::HTTP_2;
+    *grpc_request.extensions_mut() = parts.extensions;
+    let headers = grpc_request.headers_mut();
+    // Keep any other headers, since they may carry gRPC metadata.
+    for (name, value) in parts.headers.iter() {
+        if name != header::CONTENT_TYPE && name != header::CONTENT_LENGTH && name != header::ACCEPT
+        {
+            headers.append(name, value.clone());
+        }
+    }
+    headers.insert(
+        header::CONTENT_TYPE,
+        HeaderValue::from_static("application/grpc"),
+    );
+    headers.insert(header::TE, HeaderValue::from_static("trailers"));
+
+    let response = inner.call(grpc_request).await?;
+    Ok(if method.is_server_streaming() {
+        streaming_response(method.output(), response)
+    } else {
+        unary_response(method.output(), response).await
+    })
+}
+
+async fn decode_json_request(
+    descriptor: MessageDescriptor,
+    mut body: Body,
+) -> Result<DynamicMessage, Status> {
+    let mut buf = BytesMut::new();
+    while let Some(chunk) = body.data().await {
+        let chunk = chunk.map_err(|e| Status::invalid_argument(e.to_string()))?;
+        if buf.len() + chunk.len() > MAX_JSON_REQUEST_LEN {
+            return Err(Status::resource_exhausted(format!(
+                "JSON request is longer than {MAX_JSON_REQUEST_LEN} bytes"
+            )));
+        }
+        buf.extend_from_slice(&chunk);
+    }
+
+    // An empty body is an empty request message, as for a gRPC request.
+    let json = if buf.is_empty() { &b"{}"[..] } else { &buf[..] };
+    let mut deserializer = serde_json::Deserializer::from_slice(json);
+    let message = DynamicMessage::deserialize(descriptor, &mut deserializer)
+        .and_then(|message| deserializer.end().map(|()| message))
+        .map_err(|e| Status::invalid_argument(format!("invalid JSON request: {e}")))?;
+    Ok(message)
+}
+
+/// Encode a message as a single uncompressed gRPC frame.
+fn encode_frame(message: &DynamicMessage) -> Bytes {
+    let len = message.encoded_len();
+    let mut buf = BytesMut::with_capacity(5 + len);
+    buf.put_u8(0);
+    buf.put_u32(len as u32);
+    message
+        .encode(&mut buf)
+        .expect("buffer has capacity for the message");
+    buf.freeze()
+}
+
+/// Split the payload of the first complete gRPC frame off the front of `buf`, if there is one.
+fn take_frame(buf: &mut BytesMut) -> Result<Option<Bytes>, Status> {
+    if buf.len() < 5 {
+        return Ok(None);
+    }
+    if buf[0] != 0 {
+        return Err(Status::internal(
+            "compressed gRPC responses can't be transcoded to JSON",
+        ));
+    }
+    let len = u32::from_be_bytes([buf[1], buf[2], buf[3], buf[4]]) as usize;
+    if buf.len() < 5 + len {
+        return Ok(None);
+    }
+    buf.advance(5);
+    Ok(Some(buf.split_to(len).freeze()))
+}
+
+fn encode_json(descriptor: MessageDescriptor, payload: Bytes) -> Result<Vec<u8>, Status> {
+    let message = DynamicMessage::decode(descriptor, payload)
+        .map_err(|e| Status::internal(format!("invalid gRPC response: {e}")))?;
+    serde_json::to_vec(&message).map_err(|e| Status::internal(e.to_string()))
+}
+
+/// The error status in the headers or trailers of a gRPC response, if any.
+fn error_status(headers: &tonic::codegen::http::HeaderMap) -> Option<Status> {
+    Status::from_header_map(headers).filter(|status| status.code() != Code::Ok)
+}
+
+async fn unary_response(
+    output: MessageDescriptor,
+    response: Response<BoxBody>,
+) -> Response<BoxBody> {
+    let (parts, mut body) = response.into_parts();
+    if let Some(status) = error_status(&parts.headers) {
+        return status_response(&status);
+    }
+
+    let mut buf = BytesMut::new();
+    while let Some(chunk) = body.data().await {
+        match chunk {
+            Ok(chunk) => buf.extend_from_slice(&chunk),
+            Err(status) => return status_response(&status),
+        }
+    }
+    match body.trailers().await {
+        Ok(trailers) => {
+            if let Some(status) = trailers.as_ref().and_then(error_status) {
+                return status_response(&status);
+            }
+        }
+        Err(status) => return status_response(&status),
+    }
+
+    let json = match take_frame(&mut buf) {
+        Ok(Some(payload)) if buf.is_empty() => encode_json(output, payload),
+        Ok(_) => Err(Status::internal(
+            "expected exactly one message in the gRPC response",
+        )),
+        Err(status) => Err(status),
+    };
+    match json {
+        Ok(json) => json_response(StatusCode::OK, JSON, Body::from(json)),
+        Err(status) => status_response(&status),
+    }
+}
+
+/// The state of a server-streaming response being transcoded to newline-delimited JSON.
+struct Lines {
+    output: MessageDescriptor,
+    body: BoxBody,
+    buf: BytesMut,
+    done: bool,
+}
+
+impl Lines {
+    /// The next line of the response, ending the stream with an error line if the gRPC response
+    /// ends in an error.
+    async fn next(&mut self) -> Option<Bytes> {
+        if self.done {
+            return None;
+        }
+        let result = self.next_message().await;
+        if !matches!(result, Ok(Some(_))) {
+            self.done = true;
+        }
+        let mut line = match result {
+            Ok(Some(json)) => json,
+            Ok(None) => return None,
+            Err(status) => status_json(&status),
+        };
+        line.push(b'\n');
+        Some(line.into())
+    }
+
+    async fn next_message(&mut self) -> Result<Option<Vec<u8>>, Status> {
+        loop {
+            if let Some(payload) = take_frame(&mut self.buf)? {
+                return encode_json(self.output.clone(), payload).map(Some);
+            }
+            match self.body.data().await {
+                Some(chunk) => self.buf.extend_from_slice(&chunk?),
+                None => break,
+            }
+        }
+        if let Some(status) = self.body.trailers().await?.as_ref().and_then(error_status) {
+            return Err(status);
+        }
+        if !self.buf.is_empty() {
+            return Err(Status::internal("truncated gRPC response"));
+        }
+        Ok(None)
+    }
+}
+
+fn streaming_response(output: MessageDescriptor, response: Response<BoxBody>) -> Response<BoxBody> {
+    let (parts, body) = response.into_parts();
+    if let Some(status) = error_status(&parts.headers) {
+        return status_response(&status);
+    }
+
+    let lines = Lines {
+        output,
+        body,
+        buf: BytesMut::new(),
+        done: false,
+    };
+    let lines = stream::unfold(lines, |mut lines| async move {
+        let line = lines.next().await?;
+        Some((Ok::<_, Infallible>(line), lines))
+    });
+    json_response(StatusCode::OK, NDJSON, Body::wrap_stream(lines))
+}
+
+/// The JSON form of an error status, as in the response to a failed request.
+fn status_json(status: &Status) -> Vec<u8> {
+    serde_json::json!({
+        "code": status.code() as i32,
+        "message": status.message(),
+    })
+    .to_string()
+    .into_bytes()
+}
+
+/// Respond to a JSON request with an error, with the HTTP status closest to the gRPC one.
+fn status_response(status: &Status) -> Response<BoxBody> {
+    let http_status = match status.code() {
+        Code::Ok => StatusCode::OK,
+        Code::InvalidArgument | Code::FailedPrecondition | Code::OutOfRange => {
+            StatusCode::BAD_REQUEST
+        }
+        Code::Unauthenticated => StatusCode::UNAUTHORIZED,
+        Code::PermissionDenied => StatusCode::FORBIDDEN,
+        Code::NotFound => StatusCode::NOT_FOUND,
+        Code::AlreadyExists | Code::Aborted => StatusCode::CONFLICT,
+        Code::ResourceExhausted => StatusCode::TOO_MANY_REQUESTS,
+        Code::Unimplemented => StatusCode::NOT_IMPLEMENTED,
+        Code::Unavailable => StatusCode::SERVICE_UNAVAILABLE,
+        Code::DeadlineExceeded => StatusCode::GATEWAY_TIMEOUT,
+        Code::Cancelled | Code::Unknown | Code::Internal | Code::DataLoss => {
+            StatusCode::INTERNAL_SERVER_ERROR
+        }
+    };
+    json_response(http_status, JSON, Body::from(status_json(status)))
+}
+
+fn json_response(status: StatusCode, content_type: &'static str, body: Body) -> Response<BoxBody> {
+    let mut response = Response::new(tonic::body::boxed(body));
+    *response.status_mut() = status;
+    response
+        .headers_mut()
+        .insert(header::CONTENT_TYPE, HeaderValue::from_static(content_type));
+    response
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn frames_round_trip() {
+        let descriptor = descriptor_pool()
+            .get_message_by_name("penumbra.core.component.sct.v1.EpochByHeightRequest")
+            .expect("message is described");
+        let message =
+            DynamicMessage::deserialize(descriptor.clone(), serde_json::json!({ "height": "42" }))
+                .unwrap();
+
+        let mut buf = BytesMut::from(&encode_frame(&message)[..]);
+        let mut partial = buf.split_to(3);
+        assert_eq!(take_frame(&mut partial).unwrap(), None);
+        partial.unsplit(buf);
+
+        let payload = take_frame(&mut partial).unwrap().unwrap();
+        assert!(partial.is_empty());
+        let json: serde_json::Value =
+            serde_json::from_slice(&encode_json(descriptor, payload).unwrap()).unwrap();
+        assert_eq!(json, serde_json::json!({ "height": "42" }));
+    }
+}
@@ -33,6 +33,9 @@ pub mod box_grpc_svc;
 
 /// Helper trait for using Protobuf messages as ABCI events.
 pub mod event;
+
+/// Machine-readable error details attached to gRPC statuses.
+pub mod error;
 mod protobuf;
 pub use protobuf::DomainType;
 
@@ -227,6 +230,13 @@ pub mod penumbra {
     }
 
     pub mod util {
+        pub mod error {
+            pub mod v1 {
+                include!("gen/penumbra.util.error.v1.rs");
+                include!("gen/penumbra.util.error.v1.serde.rs");
+            }
+        }
+
         pub mod tendermint_proxy {
             pub mod v1 {
                 include!("gen/penumbra.util.tendermint_proxy.v1.rs");
@@ -275,3 +285,12 @@ pub mod tendermint {
 #[cfg(feature = "rpc")]
 // https://github.com/penumbra-zone/penumbra/issues/3038#issuecomment-1722534133
 pub const FILE_DESCRIPTOR_SET: &[u8] = include_bytes!("gen/proto_descriptor.bin.no_lfs");
+
+#[cfg(feature = "rpc")]
+pub mod rpc;
+
+#[cfg(feature = "rpc")]
+pub mod standard_services;
+
+#[cfg(feature = "grpc-web")]
+pub mod grpc_web;
@@ -0,0 +1,289 @@
+//! Shared configuration for clients of Penumbra's gRPC services.
+//!
+//! Each generated client has its own methods for configuring compression and
+//! message size limits, and the underlying [`Channel`] has its own for TLS and
+//! timeouts.  A [`ClientBuilder`] collects all of these settings in one place,
+//! so that a binary talking to several services only has to configure them
+//! once.
+//!
+//! ```no_run
+//! # async fn example() -> anyhow::Result<()> {
+//! use penumbra_proto::{
+//!     rpc::ClientBuilder,
+//!     core::component::stake::v1::query_service_client::QueryServiceClient as StakeQueryServiceClient,
+//!     view::v1::view_service_client::ViewServiceClient,
+//! };
+//! use std::time::Duration;
+//!
+//! let builder = ClientBuilder::new("https://grpc.testnet.penumbra.zone")
+//!     .timeout(Duration::from_secs(30))
+//!     .max_decoding_message_size(32 * 1024 * 1024);
+//!
+//! // Connect a single client...
+//! let mut view = builder.connect_client::<ViewServiceClient<_>>().await?;
+//!
+//! // ...or share one connection between several clients.
+//! let channel = builder.connect().await?;
+//! let mut stake = builder.client::<StakeQueryServiceClient<_>>(channel.clone());
+//! # Ok(())
+//! # }
+//! ```
+
+use std::time::Duration;
+
+use anyhow::Context as _;
+#[cfg(feature = "tls")]
+use tonic::transport::ClientTlsConfig;
+use tonic::{
+    codec::CompressionEncoding,
+    transport::{Channel, Endpoint},
+};
+
+/// A generated gRPC client, which can be constructed by a [`ClientBuilder`].
+///
+/// This is implemented for every client generated from Penumbra's protos, and
+/// just forwards to the inherent methods of the same names.
+pub trait GrpcClient: Sized {
+    /// Construct a client over the given channel.
+    fn new(channel: Channel) -> Self;
+    /// Compress requests with the given encoding.
+    fn send_compressed(self, encoding: CompressionEncoding) -> Self;
+    /// Accept responses compressed with the given encoding.
+    fn accept_compressed(self, encoding: CompressionEncoding) -> Self;
+    /// Limit the size of decoded response messages.
+    fn max_decoding_message_size(self, limit: usize) -> Self;
+    /// Limit the size of encoded request messages.
+    fn max_encoding_message_size(self, limit: usize) -> Self;
+}
+
+macro_rules! impl_grpc_client {
+    ($($client:ty),* $(,)?) => {
+        $(
+            impl GrpcClient for $client {
+                fn new(channel: Channel) -> Self {
+                    <$client>::new(channel)
+                }
+                fn send_compressed(self, encoding: CompressionEncoding) -> Self {
+                    <$client>::send_compressed(self, encoding)
+                }
+                fn accept_compressed(self, encoding: CompressionEncoding) -> Self {
+                    <$client>::accept_compressed(self, encoding)
+                }
+                fn max_decoding_message_size(self, limit: usize) -> Self {
+                    <$client>::max_decoding_message_size(self, limit)
+                }
+                fn max_encoding_message_size(self, limit: usize) -> Self {
+                    <$client>::max_encoding_message_size(self, limit)
+                }
+            }
+        )*
+    };
+}
+
+impl_grpc_client!(
+    crate::cnidarium::v1::query_service_client::QueryServiceClient<Channel>,
+    crate::core::app::v1::query_service_client::QueryServiceClient<Channel>,
+    crate::core::component::community_pool::v1::query_service_client::QueryServiceClient<Channel>,
+    crate::core::component::compact_block::v1::query_service_client::QueryServiceClient<Channel>,
+    crate::core::component::dex::v1::query_service_client::QueryServiceClient<Channel>,
+    crate::core::component::dex::v1::simulation_service_client::SimulationServiceClient<Channel>,
+    crate::core::component::fee::v1::query_service_client::QueryServiceClient<Channel>,
+    crate::core::component::governance::v1::query_service_client::QueryServiceClient<Channel>,
+    crate::core::component::sct::v1::query_service_client::QueryServiceClient<Channel>,
+    crate::core::component::shielded_pool::v1::query_service_client::QueryServiceClient<Channel>,
+    crate::core::component::stake::v1::query_service_client::QueryServiceClient<Channel>,
+    crate::custody::v1::custody_service_client::CustodyServiceClient<Channel>,
+    crate::tools::summoning::v1::ceremony_coordinator_service_client::CeremonyCoordinatorServiceClient<Channel>,
+    crate::util::tendermint_proxy::v1::tendermint_proxy_service_client::TendermintProxyServiceClient<Channel>,
+    crate::view::v1::view_service_client::ViewServiceClient<Channel>,
+);
+
+/// How many times to retry connecting to the server, and how long to wait
+/// between attempts.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct RetryPolicy {
+    /// The number of times to retry after the first failed attempt.
+    pub max_retries: u32,
+    /// The delay before the first retry, which doubles after each attempt.
+    pub initial_backoff: Duration,
+    /// The maximum delay between attempts.
+    pub max_backoff: Duration,
+}
+
+impl RetryPolicy {
+    /// Never retry a failed connection attempt.
+    pub const NONE: Self = Self {
+        max_retries: 0,
+        initial_backoff: Duration::ZERO,
+        max_backoff: Duration::ZERO,
+    };
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self::NONE
+    }
+}
+
+/// Configures connections to a Penumbra gRPC server, and constructs clients
+/// for any of its services.
+///
+/// With the `tls` feature, TLS is enabled automatically for `https` URLs,
+/// using the webpki root certificates, unless a different configuration is set
+/// with `tls_config`. Without it, only `http` URLs can be used.
+#[derive(Clone, Debug)]
+pub struct ClientBuilder {
+    url: String,
+    #[cfg(feature = "tls")]
+    tls: Option<ClientTlsConfig>,
+    connect_timeout: Option<Duration>,
+    timeout: Option<Duration>,
+    compression: Option<CompressionEncoding>,
+    max_decoding_message_size: Option<usize>,
+    max_encoding_message_size: Option<usize>,
+    retry: RetryPolicy,
+}
+
+impl ClientBuilder {
+    /// Start configuring connections to the server at the given URL.
+    pub fn new(url: impl Into<String>) -> Self {
+        Self {
+            url: url.into(),
+            #[cfg(feature = "tls")]
+            tls: None,
+            connect_timeout: None,
+            timeout: None,
+            compression: None,
+            max_decoding_message_size: None,
+            max_encoding_message_size: None,
+            retry: RetryPolicy::default(),
+        }
+    }
+
+    /// Use the given TLS configuration, regardless of the URL scheme.
+    #[cfg(feature = "tls")]
+    pub fn tls_config(mut self, tls: ClientTlsConfig) -> Self {
+        self.tls = Some(tls);
+        self
+    }
+
+    /// Give up on connecting to the server after the given duration.
+    pub fn connect_timeout(mut self, timeout: Duration) -> Self {
+        self.connect_timeout = Some(timeout);
+        self
+    }
+
+    /// Give up on each request after the given duration.
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Compress requests with the given encoding, and accept responses
+    /// compressed with it.
+    pub fn compression(mut self, encoding: CompressionEncoding) -> Self {
+        self.compression = Some(encoding);
+        self
+    }
+
+    /// Limit the size of decoded response messages, in bytes.
+    pub fn max_decoding_message_size(mut self, limit: usize) -> Self {
+        self.max_decoding_message_size = Some(limit);
+        self
+    }
+
+    /// Limit the size of encoded request messages, in bytes.
+    pub fn max_encoding_message_size(mut self, limit: usize) -> Self {
+        self.max_encoding_message_size = Some(limit);
+        self
+    }
+
+    /// Retry failed connection attempts according to the given policy.
+    ///
+    /// This only applies to establishing the connection: failed requests
+    /// are never retried, since they may not be idempotent.
+    pub fn retry_policy(mut self, retry: RetryPolicy) -> Self {
+        self.retry = retry;
+        self
+    }
+
+    /// Build the endpoint for the server, without connecting to it.
+    pub fn endpoint(&self) -> anyhow::Result<Endpoint> {
+        let mut endpoint = Endpoint::from_shared(self.url.clone())
+            .with_context(|| format!("invalid grpc url {}", self.url))?;
+
+        #[cfg(feature = "tls")]
+        {
+            let tls = match (&self.tls, endpoint.uri().scheme_str()) {
+                (Some(tls), _) => Some(tls.clone()),
+                (None, Some("https")) => Some(ClientTlsConfig::new()),
+                (None, Some("http")) => None,
+                (None, other) => anyhow::bail!("unknown url scheme {}", other.unwrap_or_default()),
+            };
+            if let Some(tls) = tls {
+                endpoint = endpoint.tls_config(tls)?;
+            }
+        }
+        #[cfg(not(feature = "tls"))]
+        match endpoint.uri().scheme_str() {
+            Some("http") => {}
+            Some("https") => anyhow::bail!(
+                "cannot connect to {} without the `tls` feature of penumbra-proto",
+                self.url
+            ),
+            other => anyhow::bail!("unknown url scheme {}", other.unwrap_or_default()),
+        }
+        if let Some(timeout) = self.connect_timeout {
+            endpoint = endpoint.connect_timeout(timeout);
+        }
+        if let Some(timeout) = self.timeout {
+            endpoint = endpoint.timeout(timeout);
+        }
+
+        Ok(endpoint)
+    }
+
+    /// Connect to the server, returning a channel which can be shared between
+    /// several clients.
+    pub async fn connect(&self) -> anyhow::Result<Channel> {
+        let endpoint = self.endpoint()?;
+
+        let mut backoff = self.retry.initial_backoff;
+        let mut retries = 0;
+        loop {
+            match endpoint.connect().await {
+                Ok(channel) => return Ok(channel),
+                Err(error) if retries < self.retry.max_retries => {
+                    tracing::debug!(url = %self.url, ?error, ?backoff, "retrying connection");
+                    tokio::time::sleep(backoff).await;
+                    backoff = (backoff * 2).min(self.retry.max_backoff);
+                    retries += 1;
+                }
+                Err(error) => {
+                    return Err(error).with_context(|| format!("could not connect to {}", self.url))
+                }
+            }
+        }
+    }
+
+    /// Construct a client over an existing channel, applying the configured
+    /// compression and message size limits.
+    pub fn client<C: GrpcClient>(&self, channel: Channel) -> C {
+        let mut client = C::new(channel);
+        if let Some(encoding) = self.compression {
+            client = client.send_compressed(encoding).accept_compressed(encoding);
+        }
+        if let Some(limit) = self.max_decoding_message_size {
+            client = client.max_decoding_message_size(limit);
+        }
+        if let Some(limit) = self.max_encoding_message_size {
+            client = client.max_encoding_message_size(limit);
+        }
+        client
+    }
+
+    /// Connect to the server and construct a client over the new connection.
+    pub async fn connect_client<C: GrpcClient>(&self) -> anyhow::Result<C> {
+        Ok(self.client(self.connect().await?))
+    }
+}
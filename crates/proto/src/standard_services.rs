@@ -0,0 +1,46 @@
+//! The standard gRPC reflection and health services, to serve alongside Penumbra's.
+//!
+//! Serving these lets generic tooling work with any Penumbra gRPC server out of the box: `grpcurl`
+//! and similar clients use [reflection](reflection_service) to discover the available services,
+//! and load balancers use the [`grpc.health.v1`](health_service) service to check they are up.
+
+use anyhow::Context as _;
+use tonic_health::server::{Health, HealthServer};
+use tonic_reflection::server::{Builder, ServerReflection, ServerReflectionServer};
+
+pub use tonic_health::{server::HealthReporter, ServingStatus};
+
+/// Configure a reflection service describing all Penumbra services, and the health service.
+///
+/// Use this rather than [`reflection_service`] to register the descriptors of any other services
+/// served alongside them.
+pub fn reflection_builder() -> Builder<'static> {
+    Builder::configure()
+        .register_encoded_file_descriptor_set(crate::FILE_DESCRIPTOR_SET)
+        .register_encoded_file_descriptor_set(tonic_health::pb::FILE_DESCRIPTOR_SET)
+}
+
+/// Build a reflection service describing all Penumbra services, and the health service.
+pub fn reflection_service() -> anyhow::Result<ServerReflectionServer<impl ServerReflection>> {
+    reflection_builder()
+        .build()
+        .context("could not configure grpc reflection service")
+}
+
+/// Build a `grpc.health.v1` health service, reporting the server and each of the named services
+/// as serving.
+///
+/// The names are the fully-qualified names of the services, which every generated server exposes
+/// as [`NamedService::NAME`](tonic::server::NamedService::NAME). The returned [`HealthReporter`]
+/// can be used to update their status later.
+pub async fn health_service(
+    service_names: impl IntoIterator<Item = &str>,
+) -> (HealthReporter, HealthServer<impl Health>) {
+    let (mut reporter, server) = tonic_health::server::health_reporter();
+    for service_name in service_names {
+        reporter
+            .set_service_status(service_name, ServingStatus::Serving)
+            .await;
+    }
+    (reporter, server)
+}
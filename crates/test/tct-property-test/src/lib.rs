@@ -1,4 +1,9 @@
-//! This is an empty crate that exists solely to contain property tests for the `penumbra_tct`
-//! package, so we can isolate expensive tests and run them with different optimizations.
+//! This crate exists to contain property tests for the `penumbra_tct` package, so we can isolate
+//! expensive tests and run them with different optimizations.
+//!
+//! It also provides a naive [`reference`] implementation of the tree's indexing semantics, against
+//! which the real tree is differentially tested.
 // Requires nightly.
 #![cfg_attr(docsrs, feature(doc_auto_cfg))]
+
+pub mod reference;
@@ -0,0 +1,408 @@
+//! A naive reference implementation of the indexing semantics of the tiered commitment tree.
+//!
+//! The reference keeps every epoch, block, and commitment in nested [`Vec`]s, and finds witnessed
+//! commitments by linear search, so it is slow but easy to check by inspection. It does not compute
+//! any hashes: it only predicts where each commitment is inserted and which commitments remain
+//! witnessed, which is exactly what the index of a [`penumbra_tct::Tree`] must agree with.
+//!
+//! As in the real tree, a commitment is witnessed at most once: witnessing it again (by inserting
+//! it directly, or as part of a block or epoch) forgets its previous position.
+
+use penumbra_tct::{error::InsertError, Position, StateCommitment, Witness};
+
+/// The maximum number of children of each tier: commitments in a block, blocks in an epoch, and
+/// epochs in a tree.
+pub const TIER_CAPACITY: usize = 1 << 16;
+
+/// A block of commitments, mirroring a [`block::Builder`](penumbra_tct::builder::block::Builder).
+#[derive(Debug, Clone, Default)]
+pub struct Block {
+    /// The commitments in the block, in order of insertion, or `None` if not witnessed.
+    commitments: Vec<Option<StateCommitment>>,
+    finalized: bool,
+}
+
+/// An epoch of blocks, mirroring an [`epoch::Builder`](penumbra_tct::builder::epoch::Builder).
+#[derive(Debug, Clone, Default)]
+pub struct Epoch {
+    blocks: Vec<Block>,
+    finalized: bool,
+}
+
+/// A tree of epochs, mirroring a [`Tree`](penumbra_tct::Tree).
+#[derive(Debug, Clone, Default)]
+pub struct Tree {
+    epochs: Vec<Epoch>,
+}
+
+impl Block {
+    /// Create a new empty block.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a commitment to the block, failing with [`InsertError::BlockFull`] if the block is full.
+    pub fn insert(
+        &mut self,
+        witness: Witness,
+        commitment: StateCommitment,
+    ) -> Result<(), InsertError> {
+        if self.is_full() {
+            return Err(InsertError::BlockFull);
+        }
+        self.push(witness, commitment);
+        Ok(())
+    }
+
+    fn push(&mut self, witness: Witness, commitment: StateCommitment) {
+        match witness {
+            Witness::Keep => {
+                self.forget(commitment);
+                self.commitments.push(Some(commitment));
+            }
+            Witness::Forget => self.commitments.push(None),
+        }
+    }
+
+    fn is_full(&self) -> bool {
+        self.commitments.len() == TIER_CAPACITY
+    }
+
+    fn forget(&mut self, commitment: StateCommitment) -> bool {
+        let mut forgotten = false;
+        for slot in self.commitments.iter_mut() {
+            if *slot == Some(commitment) {
+                *slot = None;
+                forgotten = true;
+            }
+        }
+        forgotten
+    }
+
+    fn witnessed(&self) -> impl Iterator<Item = (u16, StateCommitment)> + '_ {
+        self.commitments
+            .iter()
+            .enumerate()
+            .filter_map(|(index, slot)| slot.map(|commitment| (index as u16, commitment)))
+    }
+
+    fn finalized(mut self) -> Self {
+        self.finalized = true;
+        self
+    }
+}
+
+impl Epoch {
+    /// Create a new empty epoch.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The block and commitment index at which the next commitment would be inserted, or `None`
+    /// if the epoch is full.
+    fn position(&self) -> Option<(u16, u16)> {
+        let Some(block) = self.blocks.last() else {
+            return Some((0, 0));
+        };
+        let latest = self.blocks.len() - 1;
+        if !block.finalized && !block.is_full() {
+            Some((latest as u16, block.commitments.len() as u16))
+        } else if self.blocks.len() < TIER_CAPACITY {
+            Some((self.blocks.len() as u16, 0))
+        } else {
+            None
+        }
+    }
+
+    fn is_full(&self) -> bool {
+        self.blocks.len() == TIER_CAPACITY
+            && self
+                .blocks
+                .last()
+                .map_or(true, |block| block.finalized || block.is_full())
+    }
+
+    /// The latest block, if it can still be inserted into.
+    fn unfinalized_block(&mut self) -> Option<&mut Block> {
+        self.blocks.last_mut().filter(|block| !block.finalized)
+    }
+
+    /// Add a commitment to the latest block of the epoch, starting a new block if the latest one
+    /// is finalized.
+    ///
+    /// Fails with [`InsertError::BlockFull`] if the latest block is full, or [`InsertError::Full`]
+    /// if the epoch is full.
+    pub fn insert(
+        &mut self,
+        witness: Witness,
+        commitment: StateCommitment,
+    ) -> Result<(), InsertError> {
+        if self.position().is_none() {
+            return Err(InsertError::Full);
+        }
+        if let Some(block) = self.unfinalized_block() {
+            if block.is_full() {
+                return Err(InsertError::BlockFull);
+            }
+        } else if self.blocks.len() == TIER_CAPACITY {
+            return Err(InsertError::Full);
+        }
+
+        if let Witness::Keep = witness {
+            self.forget(commitment);
+        }
+        match self.unfinalized_block() {
+            Some(block) => block.push(witness, commitment),
+            None => {
+                let mut block = Block::new();
+                block.push(witness, commitment);
+                self.blocks.push(block);
+            }
+        }
+        Ok(())
+    }
+
+    /// Insert a block into the epoch, finalizing the latest block, and failing if the epoch is
+    /// full.
+    pub fn insert_block(&mut self, block: Block) -> Result<(), InsertError> {
+        if self.is_full() {
+            return Err(InsertError::Full);
+        }
+        if let Some(latest) = self.unfinalized_block() {
+            latest.finalized = true;
+        }
+        self.push_block(block);
+        Ok(())
+    }
+
+    /// Insert a block with no witnessed commitments, as when inserting a block root.
+    pub fn insert_block_root(&mut self) -> Result<(), InsertError> {
+        self.insert_block(Block::new())
+    }
+
+    /// Finalize the latest block, or insert an empty block if it is already finalized.
+    pub fn end_block(&mut self) -> Result<(), InsertError> {
+        match self.unfinalized_block() {
+            Some(block) => {
+                block.finalized = true;
+                Ok(())
+            }
+            None => self.insert_block(Block::new()),
+        }
+    }
+
+    fn push_block(&mut self, block: Block) {
+        for (_, commitment) in block.witnessed() {
+            self.forget(commitment);
+        }
+        self.blocks.push(block.finalized());
+    }
+
+    fn forget(&mut self, commitment: StateCommitment) -> bool {
+        let mut forgotten = false;
+        for block in self.blocks.iter_mut() {
+            forgotten |= block.forget(commitment);
+        }
+        forgotten
+    }
+
+    fn witnessed(&self) -> impl Iterator<Item = (u16, u16, StateCommitment)> + '_ {
+        self.blocks.iter().enumerate().flat_map(|(index, block)| {
+            block
+                .witnessed()
+                .map(move |(commitment, c)| (index as u16, commitment, c))
+        })
+    }
+
+    fn finalize(&mut self) {
+        if let Some(block) = self.unfinalized_block() {
+            block.finalized = true;
+        }
+        self.finalized = true;
+    }
+
+    fn finalized(mut self) -> Self {
+        self.finalize();
+        self
+    }
+}
+
+impl Tree {
+    /// Create a new empty tree.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The position at which the next commitment would be inserted, or `None` if the tree is
+    /// full.
+    pub fn position(&self) -> Option<Position> {
+        let Some(epoch) = self.epochs.last() else {
+            return Some((0, 0, 0).into());
+        };
+        let latest = self.epochs.len() - 1;
+        match epoch.position().filter(|_| !epoch.finalized) {
+            Some((block, commitment)) => Some((latest as u16, block, commitment).into()),
+            None if self.epochs.len() < TIER_CAPACITY => {
+                Some((self.epochs.len() as u16, 0, 0).into())
+            }
+            None => None,
+        }
+    }
+
+    fn is_full(&self) -> bool {
+        self.epochs.len() == TIER_CAPACITY
+            && self
+                .epochs
+                .last()
+                .map_or(true, |epoch| epoch.finalized || epoch.is_full())
+    }
+
+    /// The latest epoch, if it can still be inserted into.
+    fn unfinalized_epoch(&mut self) -> Option<&mut Epoch> {
+        self.epochs.last_mut().filter(|epoch| !epoch.finalized)
+    }
+
+    /// Add a commitment to the latest block of the latest epoch, returning its position.
+    pub fn insert(
+        &mut self,
+        witness: Witness,
+        commitment: StateCommitment,
+    ) -> Result<Position, InsertError> {
+        let position = self.position().ok_or(InsertError::Full)?;
+
+        if let Some(epoch) = self.unfinalized_epoch() {
+            if let Some(block) = epoch.unfinalized_block() {
+                if block.is_full() {
+                    return Err(InsertError::BlockFull);
+                }
+            } else if epoch.blocks.len() == TIER_CAPACITY {
+                return Err(InsertError::EpochFull);
+            }
+        }
+
+        if let Witness::Keep = witness {
+            self.forget(commitment);
+        }
+        match self.unfinalized_epoch() {
+            Some(epoch) => epoch
+                .insert(witness, commitment)
+                .expect("insertion into the latest epoch was checked to succeed"),
+            None => {
+                let mut epoch = Epoch::new();
+                epoch
+                    .insert(witness, commitment)
+                    .expect("insertion into an empty epoch always succeeds");
+                self.epochs.push(epoch);
+            }
+        }
+        Ok(position)
+    }
+
+    /// Insert a block into the latest epoch, finalizing the latest block, and starting a new epoch
+    /// if the latest one is finalized.
+    ///
+    /// Fails with [`InsertError::EpochFull`] if the latest epoch is full, or [`InsertError::Full`]
+    /// if the tree is full. As in the real tree, the latest block is finalized even on failure.
+    pub fn insert_block(&mut self, block: Block) -> Result<(), InsertError> {
+        if let Some(latest) = self.unfinalized_epoch().and_then(Epoch::unfinalized_block) {
+            latest.finalized = true;
+        }
+
+        let epoch_full = self.unfinalized_epoch().map(|epoch| epoch.is_full());
+        match epoch_full {
+            Some(true) => return Err(InsertError::EpochFull),
+            None if self.is_full() => return Err(InsertError::Full),
+            _ => {}
+        }
+
+        for (_, commitment) in block.witnessed() {
+            self.forget(commitment);
+        }
+        match self.unfinalized_epoch() {
+            Some(epoch) => epoch.blocks.push(block.finalized()),
+            None => self.epochs.push(Epoch {
+                blocks: vec![block.finalized()],
+                finalized: false,
+            }),
+        }
+        Ok(())
+    }
+
+    /// Insert a block with no witnessed commitments, as when inserting a block root.
+    pub fn insert_block_root(&mut self) -> Result<(), InsertError> {
+        self.insert_block(Block::new())
+    }
+
+    /// Finalize the latest block, or insert an empty block if it is already finalized.
+    pub fn end_block(&mut self) -> Result<(), InsertError> {
+        match self.unfinalized_epoch().and_then(Epoch::unfinalized_block) {
+            Some(block) => {
+                block.finalized = true;
+                Ok(())
+            }
+            None => self.insert_block(Block::new()),
+        }
+    }
+
+    /// Insert an epoch into the tree, finalizing the latest epoch, and failing if the tree is
+    /// full.
+    pub fn insert_epoch(&mut self, epoch: Epoch) -> Result<(), InsertError> {
+        if self.is_full() {
+            return Err(InsertError::Full);
+        }
+        if let Some(latest) = self.unfinalized_epoch() {
+            latest.finalize();
+        }
+        for (_, _, commitment) in epoch.witnessed() {
+            self.forget(commitment);
+        }
+        self.epochs.push(epoch.finalized());
+        Ok(())
+    }
+
+    /// Insert an epoch with no witnessed commitments, as when inserting an epoch root.
+    pub fn insert_epoch_root(&mut self) -> Result<(), InsertError> {
+        self.insert_epoch(Epoch::new())
+    }
+
+    /// Finalize the latest epoch, or insert an empty epoch if it is already finalized.
+    pub fn end_epoch(&mut self) -> Result<(), InsertError> {
+        match self.unfinalized_epoch() {
+            Some(epoch) => {
+                epoch.finalize();
+                Ok(())
+            }
+            None => self.insert_epoch(Epoch::new()),
+        }
+    }
+
+    /// Forget the commitment, returning whether it was witnessed.
+    pub fn forget(&mut self, commitment: StateCommitment) -> bool {
+        let mut forgotten = false;
+        for epoch in self.epochs.iter_mut() {
+            forgotten |= epoch.forget(commitment);
+        }
+        forgotten
+    }
+
+    /// The position of the commitment, if it is witnessed.
+    pub fn witness(&self, commitment: StateCommitment) -> Option<Position> {
+        self.commitments()
+            .find(|(_, c)| *c == commitment)
+            .map(|(position, _)| position)
+    }
+
+    /// The number of witnessed commitments.
+    pub fn witnessed_count(&self) -> usize {
+        self.commitments().count()
+    }
+
+    /// All witnessed commitments, ordered by position.
+    pub fn commitments(&self) -> impl Iterator<Item = (Position, StateCommitment)> + '_ {
+        self.epochs.iter().enumerate().flat_map(|(index, epoch)| {
+            epoch
+                .witnessed()
+                .map(move |(block, commitment, c)| ((index as u16, block, commitment).into(), c))
+        })
+    }
+}
@@ -0,0 +1,207 @@
+//! Differential tests of [`penumbra_tct::Tree`] against the naive [`reference`] implementation.
+//!
+//! Random interleavings of insertions, block and epoch insertions, forgetting, and witnessing are
+//! applied to both trees, and after every step the position, the witnessed commitments, and the
+//! outcome of the step must agree.
+
+use proptest::{arbitrary::*, prelude::*};
+
+use penumbra_tct::{builder, validate, StateCommitment, Tree, Witness};
+use penumbra_tct_property_test::reference;
+
+const MAX_USED_COMMITMENTS: usize = 4;
+const MAX_ACTIONS: usize = 40;
+const MAX_BUILDER_ACTIONS: usize = 6;
+
+#[derive(Debug, Clone)]
+enum Action {
+    Insert(Witness, StateCommitment),
+    Forget(StateCommitment),
+    Witness(StateCommitment),
+    EndBlock,
+    EndEpoch,
+    InsertBlock(Vec<(Witness, StateCommitment)>),
+    InsertBlockRoot,
+    InsertEpoch(Vec<EpochAction>),
+    InsertEpochRoot,
+}
+
+#[derive(Debug, Clone)]
+enum EpochAction {
+    Insert(Witness, StateCommitment),
+    EndBlock,
+    InsertBlock(Vec<(Witness, StateCommitment)>),
+    InsertBlockRoot,
+}
+
+fn block(
+    commitments: Vec<StateCommitment>,
+) -> impl Strategy<Value = Vec<(Witness, StateCommitment)>> {
+    prop::collection::vec(
+        (any::<Witness>(), any_with::<StateCommitment>(commitments)),
+        0..MAX_BUILDER_ACTIONS,
+    )
+}
+
+fn epoch_action(commitments: Vec<StateCommitment>) -> impl Strategy<Value = EpochAction> {
+    prop_oneof![
+        (
+            any::<Witness>(),
+            any_with::<StateCommitment>(commitments.clone())
+        )
+            .prop_map(|(witness, commitment)| EpochAction::Insert(witness, commitment)),
+        Just(EpochAction::EndBlock),
+        block(commitments).prop_map(EpochAction::InsertBlock),
+        Just(EpochAction::InsertBlockRoot),
+    ]
+}
+
+fn action(commitments: Vec<StateCommitment>) -> impl Strategy<Value = Action> {
+    prop_oneof![
+        4 => (
+            any::<Witness>(),
+            any_with::<StateCommitment>(commitments.clone())
+        )
+            .prop_map(|(witness, commitment)| Action::Insert(witness, commitment)),
+        2 => any_with::<StateCommitment>(commitments.clone()).prop_map(Action::Forget),
+        2 => any_with::<StateCommitment>(commitments.clone()).prop_map(Action::Witness),
+        1 => Just(Action::EndBlock),
+        1 => Just(Action::EndEpoch),
+        1 => block(commitments.clone()).prop_map(Action::InsertBlock),
+        1 => Just(Action::InsertBlockRoot),
+        1 => prop::collection::vec(epoch_action(commitments), 0..MAX_BUILDER_ACTIONS)
+            .prop_map(Action::InsertEpoch),
+        1 => Just(Action::InsertEpochRoot),
+    ]
+}
+
+fn actions() -> impl Strategy<Value = Vec<Action>> {
+    prop::collection::vec(any::<StateCommitment>(), 1..MAX_USED_COMMITMENTS)
+        .prop_flat_map(|commitments| prop::collection::vec(action(commitments), 1..MAX_ACTIONS))
+}
+
+fn build_block(
+    commitments: &[(Witness, StateCommitment)],
+) -> (builder::block::Builder, reference::Block) {
+    let mut real = builder::block::Builder::new();
+    let mut naive = reference::Block::new();
+    for &(witness, commitment) in commitments {
+        let real_result = real.insert(witness, commitment);
+        let naive_result = naive.insert(witness, commitment);
+        assert_eq!(real_result.is_ok(), naive_result.is_ok());
+    }
+    (real, naive)
+}
+
+fn build_epoch(actions: &[EpochAction]) -> (builder::epoch::Builder, reference::Epoch) {
+    let mut real = builder::epoch::Builder::new();
+    let mut naive = reference::Epoch::new();
+    for action in actions {
+        let (real_ok, naive_ok) = match action {
+            EpochAction::Insert(witness, commitment) => (
+                real.insert(*witness, *commitment).is_ok(),
+                naive.insert(*witness, *commitment).is_ok(),
+            ),
+            EpochAction::EndBlock => (real.end_block().is_ok(), naive.end_block().is_ok()),
+            EpochAction::InsertBlock(commitments) => {
+                let (real_block, naive_block) = build_block(commitments);
+                (
+                    real.insert_block(real_block).is_ok(),
+                    naive.insert_block(naive_block).is_ok(),
+                )
+            }
+            EpochAction::InsertBlockRoot => (
+                real.insert_block(builder::block::Finalized::default().root())
+                    .is_ok(),
+                naive.insert_block_root().is_ok(),
+            ),
+        };
+        assert_eq!(real_ok, naive_ok, "{action:?}");
+    }
+    (real, naive)
+}
+
+impl Action {
+    fn apply(&self, real: &mut Tree, naive: &mut reference::Tree) {
+        match self {
+            Action::Insert(witness, commitment) => {
+                let real_result = real.insert(*witness, *commitment);
+                let naive_result = naive.insert(*witness, *commitment);
+                assert_eq!(real_result, naive_result);
+            }
+            Action::Forget(commitment) => {
+                assert_eq!(real.forget(*commitment), naive.forget(*commitment));
+            }
+            Action::Witness(commitment) => {
+                let proof = real.witness(*commitment);
+                assert_eq!(
+                    proof.as_ref().map(|proof| proof.position()),
+                    naive.witness(*commitment)
+                );
+                if let Some(proof) = proof {
+                    assert_eq!(proof.commitment(), *commitment);
+                    assert!(proof.verify(real.root()).is_ok());
+                }
+            }
+            Action::EndBlock => {
+                assert_eq!(real.end_block().is_ok(), naive.end_block().is_ok());
+            }
+            Action::EndEpoch => {
+                assert_eq!(real.end_epoch().is_ok(), naive.end_epoch().is_ok());
+            }
+            Action::InsertBlock(commitments) => {
+                let (real_block, naive_block) = build_block(commitments);
+                assert_eq!(
+                    real.insert_block(real_block).is_ok(),
+                    naive.insert_block(naive_block).is_ok()
+                );
+            }
+            Action::InsertBlockRoot => {
+                let root = builder::block::Finalized::default().root();
+                assert_eq!(
+                    real.insert_block(root).is_ok(),
+                    naive.insert_block_root().is_ok()
+                );
+            }
+            Action::InsertEpoch(actions) => {
+                let (real_epoch, naive_epoch) = build_epoch(actions);
+                assert_eq!(
+                    real.insert_epoch(real_epoch).is_ok(),
+                    naive.insert_epoch(naive_epoch).is_ok()
+                );
+            }
+            Action::InsertEpochRoot => {
+                let root = builder::epoch::Finalized::default().root();
+                assert_eq!(
+                    real.insert_epoch(root).is_ok(),
+                    naive.insert_epoch_root().is_ok()
+                );
+            }
+        }
+
+        assert_eq!(real.position(), naive.position(), "{self:?}");
+        assert_eq!(real.witnessed_count(), naive.witnessed_count(), "{self:?}");
+    }
+}
+
+proptest! {
+    #[test]
+    fn agrees_with_reference(actions in actions()) {
+        let mut real = Tree::new();
+        let mut naive = reference::Tree::new();
+
+        for action in &actions {
+            action.apply(&mut real, &mut naive);
+        }
+
+        let real_commitments: Vec<_> = real.commitments().collect();
+        let naive_commitments: Vec<_> = naive.commitments().collect();
+        assert_eq!(real_commitments, naive_commitments);
+
+        for (position, commitment) in naive_commitments {
+            assert_eq!(real.position_of(commitment), Some(position));
+        }
+
+        validate::index(&real).unwrap();
+    }
+}
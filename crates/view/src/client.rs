@@ -503,6 +503,7 @@ where
                 tonic::Request::new(pb::BalancesRequest {
                     account_filter: Some(address_index.into()),
                     asset_id_filter: asset_id.map(Into::into),
+                    numeraires: Vec::new(),
                 }),
             );
 
@@ -706,6 +707,7 @@ where
                 &mut self2,
                 tonic::Request::new(pb::TransactionInfoByHashRequest {
                     id: Some(id.into()),
+                    numeraires: Vec::new(),
                 }),
             )
             .await?
@@ -736,6 +738,11 @@ where
                     .view
                     .ok_or_else(|| anyhow::anyhow!("missing view"))?
                     .try_into()?,
+                refunds: rsp
+                    .refunds
+                    .into_iter()
+                    .map(TryInto::try_into)
+                    .collect::<Result<_, _>>()?,
             };
 
             Ok(tx_info)
@@ -758,6 +765,7 @@ where
             let rsp = self2.transaction_info(tonic::Request::new(pb::TransactionInfoRequest {
                 start_height: start_h,
                 end_height: end_h,
+                numeraires: Vec::new(),
             }));
             let pb_txs: Vec<_> = rsp.await?.into_inner().try_collect().await?;
 
@@ -793,6 +801,11 @@ where
                             .view
                             .ok_or_else(|| anyhow::anyhow!("missing view"))?
                             .try_into()?,
+                        refunds: tx_rsp
+                            .refunds
+                            .into_iter()
+                            .map(TryInto::try_into)
+                            .collect::<Result<_, _>>()?,
                     };
 
                     Ok(tx_info)
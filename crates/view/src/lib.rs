@@ -7,7 +7,10 @@
 //! This crate also provides a [`Planner`]. This is a planner for
 //! [`TransactionPlan`][penumbra_transaction::TransactionPlan].
 //!
-//! Finally, this crate provides a [`Storage`] type for managing persistent sqlite storage.
+//! This crate also provides a [`Storage`] type for managing persistent sqlite storage.
+//!
+//! Finally, the [`test_vectors`] module generates deterministic fixtures for checking the
+//! compatibility of other wallet implementations.
 
 #![deny(clippy::unwrap_used)]
 #![recursion_limit = "512"]
@@ -17,18 +20,21 @@ mod client;
 mod metrics;
 mod note_record;
 mod planner;
+mod price;
 mod service;
 mod status;
 mod storage;
 mod swap_record;
 mod sync;
+pub mod test_vectors;
 mod transaction_info;
 mod worker;
 
 pub use crate::client::ViewClient;
 pub use crate::metrics::register_metrics;
 pub use crate::note_record::SpendableNoteRecord;
-pub use crate::planner::Planner;
+pub use crate::planner::{InsufficientFunds, NoteSelection, Planner, Shortfall};
+pub use crate::price::{DexTwap, HttpOracle, PriceSource, StaticPrices};
 pub use crate::service::ViewServer;
 pub use crate::status::StatusStreamResponse;
 pub use crate::storage::Storage;
@@ -1,33 +1,37 @@
 use std::{
     collections::BTreeMap,
-    fmt::{self, Debug, Formatter},
+    fmt::{self, Debug, Display, Formatter},
     mem,
 };
 
 use anyhow::Result;
-use rand::{CryptoRng, RngCore};
+use rand::{seq::SliceRandom, CryptoRng, RngCore};
 use tracing::instrument;
 
 use penumbra_asset::{asset, Balance, Value, STAKING_TOKEN_ASSET_ID};
 use penumbra_community_pool::CommunityPoolDeposit;
 use penumbra_dex::{
-    lp::action::{PositionClose, PositionOpen},
-    lp::plan::PositionWithdrawPlan,
+    auction::{dutch::DutchAuctionDescription, plan::ActionDutchAuctionWithdrawPlan, AuctionId},
+    lp::action::{PositionClose, PositionOpen, PositionOpenBatch},
+    lp::plan::{PositionRewritePlan, PositionUpdateBatchPlan, PositionWithdrawPlan},
     lp::position::{self, Position},
-    lp::Reserves,
+    lp::{Ladder, Reserves},
     swap::SwapPlaintext,
     swap::SwapPlan,
     swap_claim::SwapClaimPlan,
-    TradingPair,
+    ActionDutchAuctionEnd, ActionDutchAuctionSchedule, TradingPair,
 };
 use penumbra_fee::{Fee, FeeTier, GasPrices};
 use penumbra_governance::{
-    proposal_state, DelegatorVotePlan, Proposal, ProposalDepositClaim, ProposalSubmit,
-    ProposalWithdraw, ValidatorVote, Vote,
+    proposal_state, BallotKeyComplaint, BallotKeyDeal, BallotKeyReveal, DelegatorVotePlan,
+    Proposal, ProposalDepositClaim, ProposalSubmit, ProposalWithdraw, ValidatorVote, Vote,
 };
 use penumbra_ibc::IbcRelay;
-use penumbra_keys::{keys::AddressIndex, Address};
-use penumbra_num::Amount;
+use penumbra_keys::{
+    keys::{AddressIndex, NullifierKey},
+    Address,
+};
+use penumbra_num::{fixpoint::U128x128, Amount};
 use penumbra_proto::view::v1::{NotesForVotingRequest, NotesRequest};
 use penumbra_shielded_pool::{fmd, Ics20Withdrawal, Note, OutputPlan, SpendPlan};
 use penumbra_stake::{rate::RateData, validator, IdentityKey, UndelegateClaimPlan};
@@ -50,9 +54,239 @@ pub struct Planner<R: RngCore + CryptoRng> {
     ibc_actions: Vec<IbcRelay>,
     gas_prices: GasPrices,
     fee_tier: FeeTier,
+    note_selection: Option<NoteSelection>,
     // IMPORTANT: if you add more fields here, make sure to clear them when the planner is finished
 }
 
+/// An error returned when the source account doesn't hold enough funds to balance a transaction.
+///
+/// This is detected while planning, so that the user can be told what's missing and what to do
+/// about it, rather than the transaction failing later on.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InsufficientFunds {
+    /// The funds missing for each asset the transaction couldn't be balanced in.
+    pub shortfalls: Vec<Shortfall>,
+}
+
+/// The funds of one asset missing from the source account to balance a transaction.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Shortfall {
+    /// The value missing from the source account.
+    pub missing: Value,
+    /// How much of the missing amount is due to the transaction fee.
+    pub for_fee: Amount,
+    /// The number of notes of the asset spendable by the source account.
+    pub spendable_notes: usize,
+    /// The amount of the largest note of the asset spendable by the source account, if any.
+    pub largest_note: Option<Amount>,
+    /// How much of the transaction fee pays for the transaction's spends, which consolidating
+    /// notes beforehand would reduce.
+    pub spend_fee: Amount,
+}
+
+impl Display for InsufficientFunds {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let cache = asset::Cache::with_known_assets();
+        write!(f, "insufficient funds to plan transaction")?;
+        for (i, shortfall) in self.shortfalls.iter().enumerate() {
+            let separator = if i == 0 { ": " } else { "; and " };
+            write!(
+                f,
+                "{separator}need {} more",
+                shortfall.missing.format(&cache)
+            )?;
+            if shortfall.for_fee == shortfall.missing.amount {
+                write!(f, " for fees")?;
+            } else if shortfall.for_fee > Amount::zero() {
+                let for_fee = Value {
+                    amount: shortfall.for_fee,
+                    asset_id: shortfall.missing.asset_id,
+                };
+                write!(f, " (including {} for fees)", for_fee.format(&cache))?;
+            }
+            match shortfall.largest_note {
+                Some(amount) => {
+                    let largest = Value {
+                        amount,
+                        asset_id: shortfall.missing.asset_id,
+                    };
+                    write!(
+                        f,
+                        "; largest spendable note is {} (of {} spendable notes)",
+                        largest.format(&cache),
+                        shortfall.spendable_notes
+                    )?;
+                }
+                None => write!(f, "; no notes of it are spendable")?,
+            }
+            // If spending fewer notes would cover the shortfall, consolidating them will help.
+            if shortfall.spendable_notes > 1
+                && shortfall.for_fee > Amount::zero()
+                && shortfall.spend_fee >= shortfall.missing.amount
+            {
+                write!(
+                    f,
+                    "; spending many small notes raises the fee, so run a consolidation (sweep) transaction first"
+                )?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for InsufficientFunds {}
+
+/// The notes of one asset spendable by the source account, for reporting [`InsufficientFunds`].
+#[derive(Debug, Clone, Default)]
+struct SpendableNotes {
+    count: usize,
+    largest: Option<Amount>,
+}
+
+/// A strategy for choosing which notes to spend to balance a transaction.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NoteSelection {
+    /// Spend the oldest notes first.
+    OldestFirst,
+    /// Spend as few notes as possible, largest first, which keeps the fee down.
+    FewestNotes,
+    /// Spend notes chosen at random, so the selection reveals nothing about the ages or sizes of
+    /// the notes held.
+    PrivacyMax,
+    /// Spend the notes whose total exceeds the amount needed by the least, producing as little
+    /// change as possible.
+    MinimizeChange,
+}
+
+impl NoteSelection {
+    /// The number of subsets of notes the [`MinimizeChange`](Self::MinimizeChange) search may
+    /// consider before settling for the best found so far.
+    const MAX_SEARCH_STEPS: usize = 100_000;
+
+    /// Choose which of the notes of one asset to spend, to cover the target amount.
+    ///
+    /// If the notes don't cover the target, all of them are chosen, and the shortfall is left to
+    /// be reported by the planner.
+    fn select<R: RngCore>(
+        self,
+        notes: Vec<SpendableNoteRecord>,
+        target: Amount,
+        rng: &mut R,
+    ) -> Vec<SpendableNoteRecord> {
+        let candidates = notes
+            .iter()
+            .map(|record| (u64::from(record.position), record.note.amount()))
+            .collect::<Vec<_>>();
+        let mut chosen = self.select_indices(&candidates, target, rng);
+        chosen.sort_unstable();
+
+        let mut notes = notes.into_iter().map(Some).collect::<Vec<_>>();
+        chosen.into_iter().filter_map(|i| notes[i].take()).collect()
+    }
+
+    /// Choose the indices of the notes to spend, given the position and amount of each.
+    fn select_indices<R: RngCore>(
+        self,
+        notes: &[(u64, Amount)],
+        target: Amount,
+        rng: &mut R,
+    ) -> Vec<usize> {
+        let mut order = (0..notes.len()).collect::<Vec<_>>();
+        match self {
+            NoteSelection::OldestFirst => order.sort_by_key(|&i| notes[i].0),
+            NoteSelection::FewestNotes => order.sort_by_key(|&i| std::cmp::Reverse(notes[i].1)),
+            NoteSelection::PrivacyMax => order.shuffle(rng),
+            NoteSelection::MinimizeChange => {
+                return minimize_change(notes, target, Self::MAX_SEARCH_STEPS)
+            }
+        }
+        take_until_covered(notes, order, target)
+    }
+}
+
+/// Take notes in the given order until their total covers the target.
+fn take_until_covered(notes: &[(u64, Amount)], order: Vec<usize>, target: Amount) -> Vec<usize> {
+    let mut total = Amount::zero();
+    order
+        .into_iter()
+        .take_while(|&i| {
+            let covered = total >= target;
+            total += notes[i].1;
+            !covered
+        })
+        .collect()
+}
+
+/// Search for the notes whose total covers the target with the least excess, by a depth-first
+/// branch and bound over the notes from largest to smallest, giving up after `max_steps`.
+///
+/// The search starts from the largest-first selection, so it always has an answer, and ends early
+/// if it finds an exact match.
+fn minimize_change(notes: &[(u64, Amount)], target: Amount, max_steps: usize) -> Vec<usize> {
+    struct Search<'a> {
+        notes: &'a [(u64, Amount)],
+        order: Vec<usize>,
+        // The total of the notes from each point in the order onwards.
+        remaining: Vec<Amount>,
+        target: Amount,
+        steps: usize,
+        best: (Amount, Vec<usize>),
+    }
+
+    impl Search<'_> {
+        fn visit(&mut self, depth: usize, total: Amount, chosen: &mut Vec<usize>) {
+            if self.steps == 0 || self.best.0 == Amount::zero() {
+                return;
+            }
+            self.steps -= 1;
+
+            if total >= self.target {
+                // Adding more notes would only increase the excess.
+                let excess = total - self.target;
+                if excess < self.best.0 {
+                    self.best = (excess, chosen.clone());
+                }
+                return;
+            }
+            if depth == self.order.len() || total + self.remaining[depth] < self.target {
+                return;
+            }
+
+            let i = self.order[depth];
+            chosen.push(i);
+            self.visit(depth + 1, total + self.notes[i].1, chosen);
+            chosen.pop();
+            self.visit(depth + 1, total, chosen);
+        }
+    }
+
+    let mut order = (0..notes.len()).collect::<Vec<_>>();
+    order.sort_by_key(|&i| std::cmp::Reverse(notes[i].1));
+
+    let greedy = take_until_covered(notes, order.clone(), target);
+    let greedy_total: Amount = greedy.iter().map(|&i| notes[i].1).sum();
+    if greedy_total < target {
+        // Not even all the notes cover the target.
+        return greedy;
+    }
+
+    let mut remaining = vec![Amount::zero(); order.len() + 1];
+    for depth in (0..order.len()).rev() {
+        remaining[depth] = remaining[depth + 1] + notes[order[depth]].1;
+    }
+
+    let mut search = Search {
+        notes,
+        order,
+        remaining,
+        target,
+        steps: max_steps,
+        best: (greedy_total - target, greedy),
+    };
+    search.visit(0, Amount::zero(), &mut Vec::new());
+    search.best.1
+}
+
 #[derive(Debug, Clone)]
 struct VoteIntent {
     start_block_height: u64,
@@ -81,6 +315,7 @@ impl<R: RngCore + CryptoRng> Planner<R> {
             ibc_actions: Vec::new(),
             gas_prices: GasPrices::zero(),
             fee_tier: FeeTier::default(),
+            note_selection: None,
         }
     }
 
@@ -98,6 +333,15 @@ impl<R: RngCore + CryptoRng> Planner<R> {
         self
     }
 
+    /// Set the strategy for choosing the notes spent to balance the transaction.
+    ///
+    /// If no strategy is set, notes are spent in the order the view service returns them.
+    #[instrument(skip(self))]
+    pub fn set_note_selection(&mut self, note_selection: NoteSelection) -> &mut Self {
+        self.note_selection = Some(note_selection);
+        self
+    }
+
     /// Get the current transaction balance of the planner.
     pub fn balance(&self) -> &Balance {
         &self.balance
@@ -203,10 +447,53 @@ impl<R: RngCore + CryptoRng> Planner<R> {
     /// Open a liquidity position in the order book.
     #[instrument(skip(self))]
     pub fn position_open(&mut self, position: Position) -> &mut Self {
-        self.action(ActionPlan::PositionOpen(PositionOpen { position }));
+        self.action(ActionPlan::PositionOpen(PositionOpen {
+            position,
+            strategy_tag: String::new(),
+        }));
         self
     }
 
+    /// Open several liquidity positions in the order book in a single action.
+    ///
+    /// Errors if there are more positions than a single
+    /// [`PositionOpenBatch`] may open.
+    #[instrument(skip(self, positions))]
+    pub fn position_open_batch(
+        &mut self,
+        positions: Vec<Position>,
+        strategy_tag: String,
+    ) -> Result<&mut Self> {
+        anyhow::ensure!(
+            positions.len() <= PositionOpenBatch::MAX_POSITIONS,
+            "{} positions can't be opened in one batch, which opens at most {}",
+            positions.len(),
+            PositionOpenBatch::MAX_POSITIONS
+        );
+        self.action(ActionPlan::PositionOpenBatch(PositionOpenBatch {
+            positions,
+            strategy_tag,
+        }));
+        Ok(self)
+    }
+
+    /// Open a [`Ladder`] of liquidity positions around the current price in a single action,
+    /// provisioning the rungs on either side of it with the given total reserves of the start
+    /// and end assets of the ladder's pair.
+    #[instrument(skip(self))]
+    pub fn position_ladder(
+        &mut self,
+        ladder: &Ladder,
+        current_price: U128x128,
+        start_reserves: Amount,
+        end_reserves: Amount,
+        strategy_tag: String,
+    ) -> Result<&mut Self> {
+        let positions =
+            ladder.positions(&mut self.rng, current_price, start_reserves, end_reserves)?;
+        self.position_open_batch(positions, strategy_tag)
+    }
+
     /// Close a liquidity position in the order book.
     #[instrument(skip(self))]
     pub fn position_close(&mut self, position_id: position::Id) -> &mut Self {
@@ -234,6 +521,69 @@ impl<R: RngCore + CryptoRng> Planner<R> {
         self
     }
 
+    /// Close, withdraw from, and open several liquidity positions in a single action.
+    #[instrument(skip(self, plan))]
+    pub fn position_update_batch(&mut self, plan: PositionUpdateBatchPlan) -> &mut Self {
+        self.action(ActionPlan::PositionUpdateBatch(plan));
+        self
+    }
+
+    /// Replace an open liquidity position, with the given final reserves, by a new position on
+    /// the same trading pair, in a single action.
+    #[instrument(skip(self))]
+    pub fn position_rewrite(
+        &mut self,
+        position_id: position::Id,
+        reserves: Reserves,
+        pair: TradingPair,
+        new_position: Position,
+    ) -> &mut Self {
+        self.action(ActionPlan::PositionRewrite(PositionRewritePlan {
+            position_id,
+            pair,
+            reserves,
+            new_position,
+        }));
+        self
+    }
+
+    /// Schedule a gradual Dutch auction.
+    #[instrument(skip(self))]
+    pub fn dutch_auction_schedule(&mut self, description: DutchAuctionDescription) -> &mut Self {
+        self.action(ActionPlan::ActionDutchAuctionSchedule(
+            ActionDutchAuctionSchedule { description },
+        ));
+        self
+    }
+
+    /// End a gradual Dutch auction, so that its reserves can be withdrawn.
+    #[instrument(skip(self))]
+    pub fn dutch_auction_end(&mut self, auction_id: AuctionId) -> &mut Self {
+        self.action(ActionPlan::ActionDutchAuctionEnd(ActionDutchAuctionEnd {
+            auction_id,
+        }));
+        self
+    }
+
+    /// Withdraw the reserves of an ended gradual Dutch auction.
+    #[instrument(skip(self))]
+    pub fn dutch_auction_withdraw(
+        &mut self,
+        auction_id: AuctionId,
+        reserves_input: Value,
+        reserves_output: Value,
+    ) -> &mut Self {
+        self.action(ActionPlan::ActionDutchAuctionWithdraw(
+            ActionDutchAuctionWithdrawPlan {
+                auction_id,
+                seq: 2,
+                reserves_input,
+                reserves_output,
+            },
+        ));
+        self
+    }
+
     /// Perform a swap claim based on an input swap NFT with a pre-paid fee.
     #[instrument(skip(self))]
     pub fn swap_claim(&mut self, plan: SwapClaimPlan) -> &mut Self {
@@ -247,13 +597,17 @@ impl<R: RngCore + CryptoRng> Planner<R> {
     }
 
     /// Perform a swap based on input notes in the transaction.
-    #[instrument(skip(self))]
+    ///
+    /// The swap is bound to a claim key derived from `nk`, so that it can be claimed either by
+    /// the wallet holding `nk`, or by anyone the wallet hands the claim key to out of band.
+    #[instrument(skip(self, nk))]
     pub fn swap(
         &mut self,
         input_value: Value,
         into_asset: asset::Id,
         swap_claim_fee: Fee,
         claim_address: Address,
+        nk: &NullifierKey,
     ) -> Result<&mut Self> {
         // Determine the canonical order for the assets being swapped.
         // This will determine whether the input amount is assigned to delta_1 or delta_2.
@@ -282,6 +636,7 @@ impl<R: RngCore + CryptoRng> Planner<R> {
             delta_2,
             swap_claim_fee,
             claim_address,
+            nk,
         );
 
         let swap = SwapPlan::new(&mut self.rng, swap_plaintext).into();
@@ -321,6 +676,20 @@ impl<R: RngCore + CryptoRng> Planner<R> {
         self
     }
 
+    /// Add a redelegation of `delegation_amount` of the delegation tokens of the validator with
+    /// rate data `from` to the validator with rate data `to` to this transaction.
+    #[instrument(skip(self))]
+    pub fn redelegate(
+        &mut self,
+        delegation_amount: Amount,
+        from: RateData,
+        to: RateData,
+    ) -> &mut Self {
+        let redelegation = from.build_redelegate(&to, delegation_amount).into();
+        self.action(redelegation);
+        self
+    }
+
     /// Add an undelegate claim to this transaction.
     #[instrument(skip(self))]
     pub fn undelegate_claim(&mut self, claim_plan: UndelegateClaimPlan) -> &mut Self {
@@ -336,11 +705,15 @@ impl<R: RngCore + CryptoRng> Planner<R> {
     }
 
     /// Submit a new governance proposal in this transaction.
+    ///
+    /// The deposit is usually in the staking token, but may be in any asset accepted by the
+    /// governance parameters.
     #[instrument(skip(self))]
-    pub fn proposal_submit(&mut self, proposal: Proposal, deposit_amount: Amount) -> &mut Self {
+    pub fn proposal_submit(&mut self, proposal: Proposal, deposit: Value) -> &mut Self {
         self.action(ActionPlan::ProposalSubmit(ProposalSubmit {
             proposal,
-            deposit_amount,
+            deposit_amount: deposit.amount,
+            deposit_asset_id: deposit.asset_id,
         }));
         self
     }
@@ -360,12 +733,13 @@ impl<R: RngCore + CryptoRng> Planner<R> {
     pub fn proposal_deposit_claim(
         &mut self,
         proposal: u64,
-        deposit_amount: Amount,
+        deposit: Value,
         outcome: proposal_state::Outcome<()>,
     ) -> &mut Self {
         self.action(ActionPlan::ProposalDepositClaim(ProposalDepositClaim {
             proposal,
-            deposit_amount,
+            deposit_amount: deposit.amount,
+            deposit_asset_id: deposit.asset_id,
             outcome,
         }));
         self
@@ -387,6 +761,27 @@ impl<R: RngCore + CryptoRng> Planner<R> {
         self
     }
 
+    /// Deal a validator's share of the ballot key of a proposal in this transaction.
+    #[instrument(skip(self))]
+    pub fn ballot_key_deal(&mut self, deal: BallotKeyDeal) -> &mut Self {
+        self.action(ActionPlan::BallotKeyDeal(deal));
+        self
+    }
+
+    /// Complain about a ballot key deal in this transaction.
+    #[instrument(skip(self))]
+    pub fn ballot_key_complaint(&mut self, complaint: BallotKeyComplaint) -> &mut Self {
+        self.action(ActionPlan::BallotKeyComplaint(complaint));
+        self
+    }
+
+    /// Reveal a validator's ballot key secrets in this transaction.
+    #[instrument(skip(self))]
+    pub fn ballot_key_reveal(&mut self, reveal: BallotKeyReveal) -> &mut Self {
+        self.action(ActionPlan::BallotKeyReveal(reveal));
+        self
+    }
+
     /// Perform an ICS-20 withdrawal
     #[instrument(skip(self))]
     pub fn ics20_withdrawal(&mut self, withdrawal: Ics20Withdrawal) -> &mut Self {
@@ -486,8 +881,21 @@ impl<R: RngCore + CryptoRng> Planner<R> {
         let mut spendable_notes = Vec::new();
         let mut voting_notes = Vec::new();
         let (spendable_requests, voting_requests) = self.notes_requests(source);
-        for request in spendable_requests {
-            let notes = view.notes(request).await?;
+        for mut request in spendable_requests {
+            let notes = match self.note_selection {
+                None => view.notes(request).await?,
+                Some(strategy) => {
+                    // Fetch all the notes of the asset, so the strategy can choose among them.
+                    let target = request
+                        .amount_to_spend
+                        .take()
+                        .map(Amount::try_from)
+                        .transpose()?
+                        .unwrap_or_default();
+                    let notes = view.notes(request).await?;
+                    strategy.select(notes, target, &mut self.rng)
+                }
+            };
             spendable_notes.extend(notes);
         }
         for request in voting_requests {
@@ -534,6 +942,14 @@ impl<R: RngCore + CryptoRng> Planner<R> {
         // Fill in the chain id based on the view service
         self.plan.transaction_parameters.chain_id = chain_id;
 
+        // Keep track of the notes available, so we can explain any shortfall
+        let mut available = BTreeMap::<asset::Id, SpendableNotes>::new();
+        for record in &spendable_notes {
+            let notes = available.entry(record.note.asset_id()).or_default();
+            notes.count += 1;
+            notes.largest = notes.largest.max(Some(record.note.amount()));
+        }
+
         // Add the required spends to the planner
         for record in spendable_notes {
             self.spend(record.note, record.position);
@@ -651,6 +1067,41 @@ impl<R: RngCore + CryptoRng> Planner<R> {
         }
 
         // Now the transaction should be fully balanced, unless we didn't have enough to spend
+        let fee = self.plan.transaction_parameters.fee.clone();
+        // The fee is paid for the gas used by the whole transaction, so the share of it paid for
+        // the spends is the share of the gas they use, whatever asset the fee is paid in.
+        let spend_fee = {
+            let total_gas_fee = self.gas_prices.fee(&self.plan.gas_cost()).value();
+            let spends_gas_fee = self.plan.num_spends() as u128
+                * self.gas_prices.fee(&gas::spend_gas_cost()).value();
+            if total_gas_fee == 0 {
+                Amount::zero()
+            } else {
+                Amount::from(fee.amount().value().saturating_mul(spends_gas_fee) / total_gas_fee)
+            }
+        };
+        let shortfalls: Vec<Shortfall> = self
+            .balance
+            .required()
+            .map(|missing| {
+                let notes = available.remove(&missing.asset_id).unwrap_or_default();
+                let (for_fee, spend_fee) = if missing.asset_id == fee.asset_id() {
+                    (missing.amount.min(fee.amount()), spend_fee)
+                } else {
+                    (Amount::zero(), Amount::zero())
+                };
+                Shortfall {
+                    missing,
+                    for_fee,
+                    spendable_notes: notes.count,
+                    largest_note: notes.largest,
+                    spend_fee,
+                }
+            })
+            .collect();
+        if !shortfalls.is_empty() {
+            return Err(InsufficientFunds { shortfalls }.into());
+        }
         if !self.balance.is_zero() {
             anyhow::bail!(
                 "balance is non-zero after attempting to balance transaction: {:?}",
@@ -678,8 +1129,244 @@ impl<R: RngCore + CryptoRng> Planner<R> {
         self.vote_intents = BTreeMap::new();
         self.ibc_actions = Vec::new();
         self.gas_prices = GasPrices::zero();
+        self.note_selection = None;
         let plan = mem::take(&mut self.plan);
 
         Ok(plan)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use penumbra_keys::test_keys;
+    use penumbra_sct::{CommitmentSource, Nullifier};
+    use rand_chacha::ChaCha20Rng;
+    use rand_core::SeedableRng;
+
+    use super::*;
+
+    const STRATEGIES: [NoteSelection; 4] = [
+        NoteSelection::OldestFirst,
+        NoteSelection::FewestNotes,
+        NoteSelection::PrivacyMax,
+        NoteSelection::MinimizeChange,
+    ];
+
+    /// Notes with the given amounts, oldest first.
+    fn notes(amounts: impl IntoIterator<Item = u64>) -> Vec<(u64, Amount)> {
+        amounts
+            .into_iter()
+            .enumerate()
+            .map(|(position, amount)| (position as u64, amount.into()))
+            .collect()
+    }
+
+    fn total(notes: &[(u64, Amount)], chosen: &[usize]) -> Amount {
+        chosen.iter().map(|&i| notes[i].1).sum()
+    }
+
+    #[test]
+    fn every_strategy_covers_the_target() {
+        let mut rng = ChaCha20Rng::seed_from_u64(0);
+        let notes = notes([7, 1, 30, 2, 2, 15, 1, 9]);
+        for target in [1u64, 10, 40, 67] {
+            for strategy in STRATEGIES {
+                let chosen = strategy.select_indices(&notes, target.into(), &mut rng);
+                assert!(
+                    total(&notes, &chosen) >= target.into(),
+                    "{strategy:?} failed to cover {target}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn insufficient_notes_are_all_spent() {
+        let mut rng = ChaCha20Rng::seed_from_u64(0);
+        let notes = notes([3, 4, 5]);
+        for strategy in STRATEGIES {
+            let mut chosen = strategy.select_indices(&notes, 100u64.into(), &mut rng);
+            chosen.sort_unstable();
+            assert_eq!(chosen, [0, 1, 2], "{strategy:?}");
+        }
+    }
+
+    #[test]
+    fn dust_and_one_large_note() {
+        // A thousand notes of dust, and one recent large note.
+        let mut rng = ChaCha20Rng::seed_from_u64(0);
+        let notes = notes(std::iter::repeat(1).take(1000).chain([1000]));
+        let target = Amount::from(500u64);
+
+        let oldest = NoteSelection::OldestFirst.select_indices(&notes, target, &mut rng);
+        assert_eq!(oldest, (0..500).collect::<Vec<_>>());
+
+        let fewest = NoteSelection::FewestNotes.select_indices(&notes, target, &mut rng);
+        assert_eq!(fewest, [1000]);
+
+        let minimal = NoteSelection::MinimizeChange.select_indices(&notes, target, &mut rng);
+        assert_eq!(total(&notes, &minimal), target);
+    }
+
+    #[test]
+    fn minimize_change_finds_an_exact_subset() {
+        // Taking the largest notes first overshoots, but two smaller notes match exactly.
+        let mut rng = ChaCha20Rng::seed_from_u64(0);
+        let notes = notes([6, 5, 3, 5]);
+        let target = Amount::from(10u64);
+
+        let fewest = NoteSelection::FewestNotes.select_indices(&notes, target, &mut rng);
+        assert_eq!(total(&notes, &fewest), 11u64.into());
+
+        let mut minimal = NoteSelection::MinimizeChange.select_indices(&notes, target, &mut rng);
+        minimal.sort_unstable();
+        assert_eq!(minimal, [1, 3]);
+    }
+
+    #[test]
+    fn minimize_change_search_is_bounded() {
+        // No subset of even notes matches an odd target, and there are far too many subsets to
+        // try them all, so the search must give up and settle for the best it found.
+        let notes = notes(std::iter::repeat(2).take(60));
+        let target = Amount::from(61u64);
+
+        let minimal = minimize_change(&notes, target, 1_000);
+        assert_eq!(total(&notes, &minimal), 62u64.into());
+    }
+
+    fn gm() -> asset::Id {
+        asset::Cache::with_known_assets()
+            .get_unit("gm")
+            .unwrap()
+            .id()
+    }
+
+    /// A spendable note of the given value, held by the test keys.
+    fn record(position: u64, value: Value) -> SpendableNoteRecord {
+        let note = Note::generate(
+            &mut ChaCha20Rng::seed_from_u64(position),
+            &test_keys::ADDRESS_0,
+            value,
+        );
+        SpendableNoteRecord {
+            note_commitment: note.commit(),
+            nullifier: Nullifier::derive(
+                test_keys::FULL_VIEWING_KEY.nullifier_key(),
+                position.into(),
+                &note.commit(),
+            ),
+            note,
+            address_index: AddressIndex::new(0),
+            height_created: 1,
+            height_spent: None,
+            position: position.into(),
+            source: CommitmentSource::Transaction { id: None },
+            return_address: None,
+        }
+    }
+
+    /// Plan sending `value` with nonzero gas prices, spending the given notes.
+    fn plan_output(
+        value: Value,
+        notes: Vec<SpendableNoteRecord>,
+    ) -> (anyhow::Result<TransactionPlan>, Fee) {
+        let mut planner = Planner::new(ChaCha20Rng::seed_from_u64(0));
+        planner
+            .set_gas_prices(GasPrices {
+                block_space_price: 1_000,
+                compact_block_space_price: 1_000,
+                verification_price: 1_000,
+                execution_price: 1_000,
+            })
+            .output(value, test_keys::ADDRESS_1.clone())
+            .add_gas_fees();
+        let result = planner.plan_with_spendable_and_votable_notes(
+            "test-chain".to_string(),
+            &fmd::Parameters::default(),
+            notes,
+            Vec::new(),
+            test_keys::ADDRESS_0.clone(),
+        );
+        (result, planner.plan.transaction_parameters.fee.clone())
+    }
+
+    fn shortfalls(result: anyhow::Result<TransactionPlan>) -> Vec<Shortfall> {
+        result
+            .unwrap_err()
+            .downcast::<InsufficientFunds>()
+            .expect("planning fails for lack of funds")
+            .shortfalls
+    }
+
+    #[test]
+    fn shortfalls_attribute_the_fee_to_its_asset() {
+        let gm = gm();
+        let value = |amount: u64, asset_id| Value {
+            amount: amount.into(),
+            asset_id,
+        };
+
+        // Sending gm with only a little gm falls short in gm, and in the fee asset for the fee.
+        let (result, fee) = plan_output(
+            value(1_000, gm),
+            vec![record(0, value(300, gm)), record(1, value(200, gm))],
+        );
+        assert!(fee.amount() > Amount::zero());
+        let shortfalls = shortfalls(result);
+        assert_eq!(shortfalls.len(), 2);
+        let (in_gm, in_fee_asset): (Vec<_>, Vec<_>) = shortfalls
+            .into_iter()
+            .partition(|shortfall| shortfall.missing.asset_id == gm);
+
+        assert_eq!(in_gm[0].missing, value(500, gm));
+        assert_eq!(in_gm[0].for_fee, Amount::zero());
+        assert_eq!(in_gm[0].spendable_notes, 2);
+        assert_eq!(in_gm[0].largest_note, Some(300u64.into()));
+
+        assert_eq!(in_fee_asset[0].missing.asset_id, fee.asset_id());
+        assert_eq!(in_fee_asset[0].for_fee, in_fee_asset[0].missing.amount);
+        assert_eq!(in_fee_asset[0].spendable_notes, 0);
+        // Two of the transaction's actions are spends, so some of the fee pays for them.
+        assert!(in_fee_asset[0].spend_fee > Amount::zero());
+        assert!(in_fee_asset[0].spend_fee < fee.amount());
+        assert!(InsufficientFunds {
+            shortfalls: in_fee_asset
+        }
+        .to_string()
+        .contains("for fees"));
+    }
+
+    #[test]
+    fn shortfalls_in_the_fee_asset_include_the_fee() {
+        let amount = Amount::from(1_000u64);
+        let (result, fee) = plan_output(
+            Value {
+                amount,
+                asset_id: *STAKING_TOKEN_ASSET_ID,
+            },
+            Vec::new(),
+        );
+        let shortfalls = shortfalls(result);
+        assert_eq!(shortfalls.len(), 1);
+        assert_eq!(shortfalls[0].missing.amount, amount + fee.amount());
+        assert_eq!(shortfalls[0].for_fee, fee.amount());
+        // Nothing is spent, so none of the fee pays for spends.
+        assert_eq!(shortfalls[0].spend_fee, Amount::zero());
+        assert_eq!(shortfalls[0].largest_note, None);
+    }
+
+    #[test]
+    fn privacy_max_depends_only_on_randomness() {
+        // Notes of identical amounts, so neither age nor size can explain the selection.
+        let notes = notes(std::iter::repeat(10).take(100));
+        let target = Amount::from(50u64);
+
+        let select = |seed| {
+            let mut rng = ChaCha20Rng::seed_from_u64(seed);
+            NoteSelection::PrivacyMax.select_indices(&notes, target, &mut rng)
+        };
+        assert_eq!(select(1), select(1));
+        assert_ne!(select(1), select(2));
+        assert_eq!(select(1).len(), 5);
+    }
+}
@@ -0,0 +1,414 @@
+//! Pluggable sources of estimated asset prices.
+//!
+//! A [`ViewServer`](crate::ViewServer) can be configured with any number of
+//! [`PriceSource`]s, which it consults to estimate the values of balances and
+//! transactions in terms of the numeraires requested by its clients.  Doing
+//! this server-side means every client of the view server shows the same
+//! valuations.
+
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    sync::Arc,
+};
+
+use anyhow::Context;
+use async_trait::async_trait;
+use camino::Utf8Path;
+use futures::TryStreamExt;
+use penumbra_asset::{asset, EstimatedPrice};
+use penumbra_dex::{DirectedTradingPair, SwapExecution};
+use penumbra_proto::core::component::{
+    dex::v1::{
+        query_service_client::QueryServiceClient as DexQueryServiceClient, SwapExecutionsRequest,
+    },
+    shielded_pool::v1::{
+        query_service_client::QueryServiceClient as ShieldedPoolQueryServiceClient,
+        AssetMetadataByIdRequest,
+    },
+};
+use tonic::transport::Channel;
+use url::Url;
+
+/// A source of estimated asset prices.
+///
+/// Prices are quoted in display units: the `numeraire_per_unit` of an
+/// [`EstimatedPrice`] is the number of display units of the numeraire worth
+/// one display unit of the priced asset.
+#[async_trait]
+pub trait PriceSource: Send + Sync + 'static {
+    /// Estimate the price of each of the `assets` in terms of each of the
+    /// `numeraires`, as of the given block height.
+    ///
+    /// Pairs for which the source has no estimate are omitted from the result.
+    async fn estimate_prices(
+        &self,
+        assets: &[asset::Id],
+        numeraires: &[asset::Id],
+        height: u64,
+    ) -> anyhow::Result<Vec<EstimatedPrice>>;
+}
+
+/// Estimate prices using the given sources, in order of preference.
+///
+/// Each pair is priced by the first source with an estimate for it.  Since
+/// valuations are only advisory, errors from individual sources are logged
+/// rather than returned.
+pub(crate) async fn estimate_prices(
+    sources: &[Arc<dyn PriceSource>],
+    assets: impl IntoIterator<Item = asset::Id>,
+    numeraires: impl IntoIterator<Item = asset::Id>,
+    height: u64,
+) -> Vec<EstimatedPrice> {
+    let assets = assets.into_iter().collect::<BTreeSet<_>>();
+    let numeraires = numeraires.into_iter().collect::<BTreeSet<_>>();
+    let mut prices = BTreeMap::new();
+
+    // Any asset is worth exactly its own amount of itself.
+    for id in assets.intersection(&numeraires) {
+        prices.insert(
+            (*id, *id),
+            EstimatedPrice {
+                priced_asset: *id,
+                numeraire: *id,
+                numeraire_per_unit: 1.0,
+                as_of_height: height,
+            },
+        );
+    }
+
+    let requested = assets.len() * numeraires.len();
+    let assets = assets.into_iter().collect::<Vec<_>>();
+    let numeraires = numeraires.into_iter().collect::<Vec<_>>();
+
+    for source in sources {
+        if prices.len() == requested {
+            break;
+        }
+
+        match source.estimate_prices(&assets, &numeraires, height).await {
+            Ok(estimates) => {
+                for price in estimates {
+                    if assets.contains(&price.priced_asset) && numeraires.contains(&price.numeraire)
+                    {
+                        prices
+                            .entry((price.priced_asset, price.numeraire))
+                            .or_insert(price);
+                    }
+                }
+            }
+            Err(e) => tracing::warn!(?e, "error estimating prices"),
+        }
+    }
+
+    prices.into_values().collect()
+}
+
+/// A fixed set of prices, such as prices loaded from a file.
+#[derive(Clone, Debug, Default)]
+pub struct StaticPrices {
+    prices: Vec<EstimatedPrice>,
+}
+
+impl StaticPrices {
+    pub fn new(prices: impl IntoIterator<Item = EstimatedPrice>) -> Self {
+        Self {
+            prices: prices.into_iter().collect(),
+        }
+    }
+
+    /// Load prices from a JSON file containing an array of `EstimatedPrice`s,
+    /// in their protobuf JSON encoding.
+    pub fn from_file(path: impl AsRef<Utf8Path>) -> anyhow::Result<Self> {
+        let path = path.as_ref();
+        let contents = std::fs::read_to_string(path)
+            .with_context(|| format!("could not read price file {path}"))?;
+        let prices: Vec<EstimatedPrice> = serde_json::from_str(&contents)
+            .with_context(|| format!("could not parse price file {path}"))?;
+        Ok(Self::new(prices))
+    }
+}
+
+#[async_trait]
+impl PriceSource for StaticPrices {
+    async fn estimate_prices(
+        &self,
+        assets: &[asset::Id],
+        numeraires: &[asset::Id],
+        _height: u64,
+    ) -> anyhow::Result<Vec<EstimatedPrice>> {
+        Ok(self
+            .prices
+            .iter()
+            .filter(|price| {
+                assets.contains(&price.priced_asset) && numeraires.contains(&price.numeraire)
+            })
+            .cloned()
+            .collect())
+    }
+}
+
+/// Prices fetched from an external HTTP oracle.
+///
+/// The oracle is sent a `GET` request with the requested assets and
+/// numeraires as repeated `asset` and `numeraire` query parameters, and the
+/// height as a `height` parameter.  It must respond with a JSON array of
+/// `EstimatedPrice`s, in their protobuf JSON encoding.
+#[derive(Clone, Debug)]
+pub struct HttpOracle {
+    url: Url,
+    client: reqwest::Client,
+}
+
+impl HttpOracle {
+    pub fn new(url: Url) -> Self {
+        Self {
+            url,
+            client: reqwest::Client::new(),
+        }
+    }
+}
+
+#[async_trait]
+impl PriceSource for HttpOracle {
+    async fn estimate_prices(
+        &self,
+        assets: &[asset::Id],
+        numeraires: &[asset::Id],
+        height: u64,
+    ) -> anyhow::Result<Vec<EstimatedPrice>> {
+        let mut url = self.url.clone();
+        {
+            let mut query = url.query_pairs_mut();
+            for id in assets {
+                query.append_pair("asset", &id.to_string());
+            }
+            for id in numeraires {
+                query.append_pair("numeraire", &id.to_string());
+            }
+            query.append_pair("height", &height.to_string());
+        }
+
+        let prices = self
+            .client
+            .get(url)
+            .send()
+            .await
+            .context("could not query price oracle")?
+            .error_for_status()?
+            .json::<Vec<EstimatedPrice>>()
+            .await
+            .context("could not parse price oracle response")?;
+
+        Ok(prices)
+    }
+}
+
+/// Time-weighted average prices of swaps executed by the DEX.
+///
+/// The price of an asset is the average of the prices at which the DEX
+/// swapped it into the numeraire over a window of recent blocks, with each
+/// price weighted by the number of blocks for which it was the latest.
+///
+/// Swaps trade base units, so their prices are converted to display units
+/// using the denominations of both assets, as known to the node.  Pairs with
+/// an asset whose denomination the node doesn't know are not priced.
+#[derive(Clone, Debug)]
+pub struct DexTwap {
+    channel: Channel,
+    window: u64,
+}
+
+impl DexTwap {
+    /// The default averaging window, in blocks.
+    pub const DEFAULT_WINDOW: u64 = 720;
+
+    /// Connect to the DEX query service of the given node, averaging prices
+    /// over the given number of blocks.
+    pub async fn connect(node: Url, window: u64) -> anyhow::Result<Self> {
+        let channel = Channel::from_shared(node.to_string())?
+            .connect()
+            .await
+            .context("could not connect to node")?;
+        Ok(Self { channel, window })
+    }
+
+    /// Look up the exponent of the display unit of the given asset.
+    async fn display_exponent(&self, id: asset::Id) -> anyhow::Result<Option<u8>> {
+        let metadata = ShieldedPoolQueryServiceClient::new(self.channel.clone())
+            .asset_metadata_by_id(AssetMetadataByIdRequest {
+                asset_id: Some(id.into()),
+            })
+            .await?
+            .into_inner()
+            .denom_metadata
+            .map(asset::Metadata::try_from)
+            .transpose()?;
+        Ok(metadata.map(|metadata| metadata.default_unit().exponent()))
+    }
+
+    async fn twap(
+        &self,
+        asset: asset::Id,
+        numeraire: asset::Id,
+        height: u64,
+    ) -> anyhow::Result<Option<EstimatedPrice>> {
+        let (Some(asset_exponent), Some(numeraire_exponent)) = (
+            self.display_exponent(asset).await?,
+            self.display_exponent(numeraire).await?,
+        ) else {
+            return Ok(None);
+        };
+
+        let start_height = height.saturating_sub(self.window);
+        let mut observations = DexQueryServiceClient::new(self.channel.clone())
+            .swap_executions(SwapExecutionsRequest {
+                start_height,
+                end_height: height,
+                trading_pair: Some(DirectedTradingPair::new(asset, numeraire).into()),
+            })
+            .await?
+            .into_inner()
+            .map_err(anyhow::Error::from)
+            .and_then(|rsp| async move {
+                let execution: SwapExecution = rsp
+                    .swap_execution
+                    .context("missing swap execution")?
+                    .try_into()?;
+                Ok((rsp.height, execution))
+            })
+            .try_filter_map(|(height, execution)| async move {
+                // Skip executions which didn't trade anything, since they have no price.
+                if execution.input.amount.value() == 0 {
+                    return Ok(None);
+                }
+                let price =
+                    execution.output.amount.value() as f64 / execution.input.amount.value() as f64;
+                Ok(Some((height, price)))
+            })
+            .try_collect::<Vec<_>>()
+            .await?;
+        observations.sort_by_key(|(height, _)| *height);
+
+        Ok(
+            time_weighted_average(&observations, height).map(|(price, as_of_height)| {
+                EstimatedPrice {
+                    priced_asset: asset,
+                    numeraire,
+                    numeraire_per_unit: to_display_price(price, asset_exponent, numeraire_exponent),
+                    as_of_height,
+                }
+            }),
+        )
+    }
+}
+
+#[async_trait]
+impl PriceSource for DexTwap {
+    async fn estimate_prices(
+        &self,
+        assets: &[asset::Id],
+        numeraires: &[asset::Id],
+        height: u64,
+    ) -> anyhow::Result<Vec<EstimatedPrice>> {
+        let mut prices = Vec::new();
+        for asset in assets {
+            for numeraire in numeraires {
+                if asset == numeraire {
+                    continue;
+                }
+                if let Some(price) = self.twap(*asset, *numeraire, height).await? {
+                    prices.push(price);
+                }
+            }
+        }
+        Ok(prices)
+    }
+}
+
+/// Convert a price in base units of the numeraire per base unit of the priced
+/// asset to one in display units, given the exponents of their display units.
+fn to_display_price(base_price: f64, asset_exponent: u8, numeraire_exponent: u8) -> f64 {
+    base_price * 10f64.powi(i32::from(asset_exponent) - i32::from(numeraire_exponent))
+}
+
+/// Average the given `(height, price)` observations, sorted by height, over
+/// the blocks up to and including `end_height`, weighting each price by the
+/// number of blocks for which it was the latest.
+///
+/// Returns the average along with the height of the latest observation.
+fn time_weighted_average(observations: &[(u64, f64)], end_height: u64) -> Option<(f64, u64)> {
+    let (last_height, _) = *observations.last()?;
+
+    let mut weighted_sum = 0.0;
+    let mut total_weight = 0.0;
+    for (i, (height, price)) in observations.iter().enumerate() {
+        let until = observations
+            .get(i + 1)
+            .map(|(next_height, _)| *next_height)
+            .unwrap_or(end_height.max(last_height) + 1);
+        let weight = until.saturating_sub(*height) as f64;
+        weighted_sum += price * weight;
+        total_weight += weight;
+    }
+
+    if total_weight == 0.0 {
+        return None;
+    }
+    Some((weighted_sum / total_weight, last_height))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn twap_weights_prices_by_blocks() {
+        assert_eq!(time_weighted_average(&[], 10), None);
+        assert_eq!(time_weighted_average(&[(5, 2.0)], 10), Some((2.0, 5)));
+
+        // 2.0 for blocks 0..=5, then 8.0 for blocks 6..=8.
+        let (price, as_of_height) = time_weighted_average(&[(0, 2.0), (6, 8.0)], 8).unwrap();
+        assert_eq!(price, (2.0 * 6.0 + 8.0 * 3.0) / 9.0);
+        assert_eq!(as_of_height, 6);
+    }
+
+    #[test]
+    fn display_prices_account_for_exponents() {
+        let close = |a: f64, b: f64| (a - b).abs() < 1e-9 * b;
+        // 1 penumbra (10^6 upenumbra) swaps for 2 of an asset with 18 decimals...
+        assert!(close(to_display_price(2e18 / 1e6, 6, 18), 2.0));
+        // ... which are worth 0.5 penumbra each.
+        assert!(close(to_display_price(1e6 / 2e18, 18, 6), 0.5));
+        assert_eq!(to_display_price(3.0, 6, 6), 3.0);
+    }
+
+    #[tokio::test]
+    async fn earlier_sources_take_precedence() {
+        let a = *penumbra_asset::STAKING_TOKEN_ASSET_ID;
+        let b = asset::Cache::with_known_assets()
+            .get_unit("ugm")
+            .unwrap()
+            .id();
+        let price = |numeraire_per_unit| EstimatedPrice {
+            priced_asset: a,
+            numeraire: b,
+            numeraire_per_unit,
+            as_of_height: 0,
+        };
+
+        let sources: Vec<Arc<dyn PriceSource>> = vec![
+            Arc::new(StaticPrices::new([price(1.5)])),
+            Arc::new(StaticPrices::new([price(3.0)])),
+        ];
+        let prices = estimate_prices(&sources, [a, b], [b], 7).await;
+
+        assert_eq!(prices.len(), 2);
+        assert!(prices.contains(&price(1.5)));
+        assert!(prices.contains(&EstimatedPrice {
+            priced_asset: b,
+            numeraire: b,
+            numeraire_per_unit: 1.0,
+            as_of_height: 7,
+        }));
+    }
+}
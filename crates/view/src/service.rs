@@ -57,7 +57,7 @@ use penumbra_transaction::{
     AuthorizationData, Transaction, TransactionPerspective, TransactionPlan, WitnessData,
 };
 
-use crate::{worker::Worker, Planner, Storage};
+use crate::{price, worker::Worker, NoteSelection, Planner, PriceSource, Storage};
 
 /// A [`futures::Stream`] of broadcast transaction responses.
 ///
@@ -86,6 +86,8 @@ pub struct ViewServer {
     node: Url,
     /// Used to watch for changes to the sync height.
     sync_height_rx: watch::Receiver<u64>,
+    /// Sources of price estimates, in order of preference.
+    price_sources: Vec<Arc<dyn PriceSource>>,
 }
 
 impl ViewServer {
@@ -119,9 +121,19 @@ impl ViewServer {
             sync_height_rx,
             state_commitment_tree: sct,
             node,
+            price_sources: Vec::new(),
         })
     }
 
+    /// Add a source of price estimates, used to value balances and
+    /// transactions in terms of the numeraires requested by clients.
+    ///
+    /// Sources are consulted in the order in which they were added.
+    pub fn with_price_source(mut self, source: impl PriceSource) -> Self {
+        self.price_sources.push(Arc::new(source));
+        self
+    }
+
     async fn check_worker(&self) -> Result<(), tonic::Status> {
         // If the shared error slot is set, then an error has occurred in the worker
         // that we should bubble up.
@@ -217,8 +229,15 @@ impl ViewServer {
                     )),
                 }?;
 
+                // The transaction was accepted into the mempool, so track it as pending
+                // until it's detected on chain.
+                self2.storage
+                    .record_pending_transaction(&transaction, node_rsp.log.clone())
+                    .await
+                    .map_err(|e| tonic::Status::internal(format!("error recording pending transaction: {:#}", e)))?;
+
                 // The transaction was submitted so we provide a status update
-                yield BroadcastTransactionResponse{ status: Some(BroadcastStatus::BroadcastSuccess(BroadcastSuccess{id:Some(transaction.id().into())}))};
+                yield BroadcastTransactionResponse{ status: Some(BroadcastStatus::BroadcastSuccess(BroadcastSuccess{id:Some(transaction.id().into()), mempool_log: node_rsp.log}))};
 
                 // 3. Optionally wait for the transaction to be detected by the view service.
                 let nullifier = if await_detection {
@@ -366,6 +385,12 @@ impl ViewService for ViewServer {
         Box<dyn futures::Stream<Item = Result<pb::UnclaimedSwapsResponse, tonic::Status>> + Send>,
     >;
     type BroadcastTransactionStream = BroadcastTransactionStream;
+    type PendingTransactionsStream = Pin<
+        Box<
+            dyn futures::Stream<Item = Result<pb::PendingTransactionsResponse, tonic::Status>>
+                + Send,
+        >,
+    >;
     type WitnessAndBuildStream = Pin<
         Box<dyn futures::Stream<Item = Result<pb::WitnessAndBuildResponse, tonic::Status>> + Send>,
     >;
@@ -418,6 +443,19 @@ impl ViewService for ViewServer {
         planner.set_gas_prices(gas_prices);
         planner.expiry_height(prq.expiry_height);
 
+        let note_selection = match pb::NoteSelectionStrategy::try_from(prq.note_selection_strategy)
+            .map_err(|_| tonic::Status::invalid_argument("invalid note selection strategy"))?
+        {
+            pb::NoteSelectionStrategy::Unspecified => None,
+            pb::NoteSelectionStrategy::OldestFirst => Some(NoteSelection::OldestFirst),
+            pb::NoteSelectionStrategy::FewestNotes => Some(NoteSelection::FewestNotes),
+            pb::NoteSelectionStrategy::PrivacyMax => Some(NoteSelection::PrivacyMax),
+            pb::NoteSelectionStrategy::MinimizeChange => Some(NoteSelection::MinimizeChange),
+        };
+        if let Some(note_selection) = note_selection {
+            planner.set_note_selection(note_selection);
+        }
+
         for output in prq.outputs {
             let address: Address = output
                 .address
@@ -471,8 +509,12 @@ impl ViewService for ViewServer {
                     tonic::Status::invalid_argument(format!("Could not parse claim address: {e:#}"))
                 })?;
 
+            let fvk = self.storage.full_viewing_key().await.map_err(|_| {
+                tonic::Status::failed_precondition("Error retrieving full viewing key")
+            })?;
+
             planner
-                .swap(value, target_asset, fee, claim_address)
+                .swap(value, target_asset, fee, claim_address, fvk.nullifier_key())
                 .map_err(|e| {
                     tonic::Status::invalid_argument(format!("Could not plan swap: {e:#}"))
                 })?;
@@ -504,6 +546,7 @@ impl ViewService for ViewServer {
                 position: swap_record.position,
                 output_data: swap_record.output_data,
                 epoch_duration: app_params.sct_params.epoch_duration,
+                claim_key: None,
                 proof_blinding_r: Fq::rand(&mut OsRng),
                 proof_blinding_s: Fq::rand(&mut OsRng),
             });
@@ -742,6 +785,13 @@ impl ViewService for ViewServer {
                 ))
             })?;
 
+        let numeraires = request
+            .numeraires
+            .into_iter()
+            .map(asset::Id::try_from)
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|_| tonic::Status::invalid_argument("invalid numeraire in request"))?;
+
         let Some((height, tx)) = maybe_tx else {
             return Ok(tonic::Response::new(
                 pb::TransactionInfoByHashResponse::default(),
@@ -856,6 +906,9 @@ impl ViewService for ViewServer {
 
         // Now, extend the TxV with information helpful to understand the data it can view:
 
+        // Equivalent values can only be shown for numeraires with known denoms.
+        asset_ids.extend(numeraires.iter().copied());
+
         let mut denoms = Vec::new();
 
         for id in asset_ids {
@@ -868,11 +921,29 @@ impl ViewService for ViewServer {
 
         txp.denoms.extend(denoms);
 
+        // If requested, estimate the prices of the visible assets as of the
+        // transaction's height, so that the view carries equivalent values.
+        if !numeraires.is_empty() {
+            txp.prices = price::estimate_prices(
+                &self.price_sources,
+                txp.denoms.keys().copied(),
+                numeraires,
+                height,
+            )
+            .await;
+        }
+
         txp.address_views = address_views.into_values().collect();
 
         // Finally, compute the full TxV from the full TxP:
         let txv = tx.view_from_perspective(&txp);
 
+        // Include the refunds of any ICS20 withdrawals which timed out, so that clients can tell
+        // them apart from new deposits.
+        let refunds = self.storage.ics20_refunds(&tx.id().0).await.map_err(|e| {
+            tonic::Status::internal(format!("Error retrieving ics20 refunds: {:#}", e))
+        })?;
+
         let response = pb::TransactionInfoByHashResponse {
             tx_info: Some(pb::TransactionInfo {
                 height,
@@ -880,6 +951,7 @@ impl ViewService for ViewServer {
                 perspective: Some(txp.into()),
                 transaction: Some(tx.into()),
                 view: Some(txv.into()),
+                refunds: refunds.into_iter().map(Into::into).collect(),
             }),
         };
 
@@ -948,6 +1020,37 @@ impl ViewService for ViewServer {
 
         tracing::debug!(?account_filter, ?asset_id_filter, ?result);
 
+        let numeraires = request
+            .numeraires
+            .into_iter()
+            .map(asset::Id::try_from)
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|_| tonic::Status::invalid_argument("invalid numeraire in request"))?;
+
+        // If requested, estimate the value of each balance in each numeraire.
+        let mut numeraire_denoms = Vec::new();
+        let mut prices = Vec::new();
+        if !numeraires.is_empty() {
+            for id in &numeraires {
+                if let Some(metadata) = self.storage.asset_by_id(id).await.map_err(|e| {
+                    tonic::Status::internal(format!("error retrieving asset by id: {e:#}"))
+                })? {
+                    numeraire_denoms.push(metadata);
+                }
+            }
+
+            let height = *self.sync_height_rx.borrow();
+            prices = price::estimate_prices(
+                &self.price_sources,
+                result.iter().map(|element| element.id),
+                numeraires,
+                height,
+            )
+            .await;
+        }
+
+        let numeraire_denoms = numeraire_denoms.into_iter().collect::<asset::Cache>();
+
         let self2 = self.clone();
         let stream = try_stream! {
             // retrieve balance and address views
@@ -967,7 +1070,9 @@ impl ViewService for ViewServer {
                     amount: element.amount.into(),
                 };
 
-                let value_view = value.view_with_denom(metadata)?;
+                let value_view = value
+                    .view_with_denom(metadata)?
+                    .with_prices(&prices, &numeraire_denoms);
 
                 let address: Address = self2
                   .address_by_index(Request::new(pb::AddressByIndexRequest {
@@ -1283,6 +1388,8 @@ impl ViewService for ViewServer {
             Some(request.get_ref().end_height)
         };
 
+        let numeraires = request.get_ref().numeraires.clone();
+
         // Fetch transactions from storage.
         let txs = self
             .storage
@@ -1296,6 +1403,7 @@ impl ViewService for ViewServer {
 
                 let rsp = self2.transaction_info_by_hash(tonic::Request::new(pb::TransactionInfoByHashRequest {
                     id: Some(tx.2.id().into()),
+                    numeraires: numeraires.clone(),
                 })).await?.into_inner();
 
                 yield pb::TransactionInfoResponse {
@@ -1559,6 +1667,52 @@ impl ViewService for ViewServer {
         ))
     }
 
+    async fn pending_transactions(
+        &self,
+        _request: tonic::Request<pb::PendingTransactionsRequest>,
+    ) -> Result<tonic::Response<Self::PendingTransactionsStream>, tonic::Status> {
+        self.check_worker().await?;
+
+        let pending = self.storage.pending_transactions().await.map_err(|e| {
+            tonic::Status::unavailable(format!("error getting pending transactions: {e}"))
+        })?;
+
+        let stream = try_stream! {
+            for (broadcast_height, mempool_log, transaction) in pending {
+                yield pb::PendingTransactionsResponse {
+                    id: Some(transaction.id().into()),
+                    transaction: Some(transaction.into()),
+                    broadcast_height,
+                    mempool_log,
+                }
+            }
+        };
+
+        Ok(tonic::Response::new(
+            stream
+                .map_err(|e: anyhow::Error| {
+                    tonic::Status::unavailable(format!("error getting pending transactions: {e}"))
+                })
+                .boxed(),
+        ))
+    }
+
+    async fn database_maintenance(
+        &self,
+        request: tonic::Request<pb::DatabaseMaintenanceRequest>,
+    ) -> Result<tonic::Response<pb::DatabaseMaintenanceResponse>, tonic::Status> {
+        self.check_worker().await?;
+
+        let request = request.into_inner();
+        let health = self
+            .storage
+            .maintain(request.repair, request.vacuum)
+            .await
+            .map_err(|e| tonic::Status::internal(format!("error maintaining database: {e:#}")))?;
+
+        Ok(tonic::Response::new(health.into()))
+    }
+
     async fn authorize_and_build(
         &self,
         _request: tonic::Request<pb::AuthorizeAndBuildRequest>,
@@ -42,8 +42,13 @@ use tct::StateCommitment;
 
 use crate::{sync::FilteredBlock, SpendableNoteRecord, SwapRecord};
 
+mod ics20;
+mod maintenance;
+mod pending;
 mod sct;
 
+pub use maintenance::DatabaseHealth;
+
 #[derive(Debug, PartialEq, Eq, PartialOrd, Ord)]
 pub struct BalanceEntry {
     pub id: Id,
@@ -51,6 +56,13 @@ pub struct BalanceEntry {
     pub address_index: AddressIndex,
 }
 
+/// The number of blocks for which a broadcast transaction without an earlier
+/// expiry height is considered pending, if it isn't detected on chain.
+///
+/// A transaction can be dropped from the mempool without ever being included,
+/// so its notes must eventually stop being treated as provisionally spent.
+pub const PENDING_TRANSACTION_LIFETIME_BLOCKS: u64 = 100;
+
 /// The hash of the schema for the database.
 static SCHEMA_HASH: Lazy<String> =
     Lazy::new(|| hex::encode(Sha256::digest(include_str!("storage/schema.sql"))));
@@ -247,19 +259,26 @@ impl Storage {
         address_index: Option<AddressIndex>,
         asset_id: Option<asset::Id>,
     ) -> anyhow::Result<Vec<BalanceEntry>> {
+        // Notes which are provisionally spent by pending transactions aren't
+        // counted, as they can't be spent again.
+        let sync_height = self.last_sync_height().await?.unwrap_or(0);
         let pool = self.pool.clone();
 
         spawn_blocking(move || {
-            let query = "SELECT notes.asset_id, notes.amount, spendable_notes.address_index
+            let query = format!(
+                "SELECT notes.asset_id, notes.amount, spendable_notes.address_index
                 FROM    notes
                 JOIN    spendable_notes ON notes.note_commitment = spendable_notes.note_commitment
-                WHERE   spendable_notes.height_spent IS NULL";
+                WHERE   spendable_notes.height_spent IS NULL
+                AND     {}",
+                pending::not_provisionally_spent(sync_height)
+            );
 
             tracing::debug!(?query);
 
             let mut entries = Vec::new();
 
-            for result in pool.get()?.prepare_cached(query)?.query_map([], |row| {
+            for result in pool.get()?.prepare_cached(&query)?.query_map([], |row| {
                 let asset_id = row.get::<&str, Vec<u8>>("asset_id")?;
                 let amount = row.get::<&str, Vec<u8>>("amount")?;
                 let address_index = row.get::<&str, Vec<u8>>("address_index")?;
@@ -681,6 +700,80 @@ impl Storage {
         .await?
     }
 
+    /// Record a transaction which the fullnode accepted into its mempool.
+    ///
+    /// The transaction is pending until it is detected on chain, or until it
+    /// expires, and until then the notes it spends are treated as
+    /// provisionally spent, so that they aren't planned into other
+    /// transactions.
+    pub async fn record_pending_transaction(
+        &self,
+        transaction: &Transaction,
+        mempool_log: String,
+    ) -> anyhow::Result<()> {
+        let broadcast_height = self.last_sync_height().await?.unwrap_or(0);
+        let expiry_height = transaction.transaction_parameters().expiry_height;
+        let mut expires_at = broadcast_height + PENDING_TRANSACTION_LIFETIME_BLOCKS;
+        if expiry_height != 0 {
+            expires_at = expires_at.min(expiry_height);
+        }
+
+        let pending = pending::PendingTransaction {
+            tx_hash: transaction.id().0.to_vec(),
+            tx_bytes: transaction.encode_to_vec(),
+            broadcast_height,
+            expires_at,
+            mempool_log,
+            nullifiers: transaction
+                .spent_nullifiers()
+                .map(|nf| nf.0.to_bytes().to_vec())
+                .collect(),
+        };
+
+        let pool = self.pool.clone();
+
+        spawn_blocking(move || {
+            let mut lock = pool.get()?;
+            let dbtx = lock.transaction()?;
+
+            pending::record(&dbtx, &pending)?;
+
+            dbtx.commit()?;
+            anyhow::Ok(())
+        })
+        .await??;
+
+        Ok(())
+    }
+
+    /// Returns a tuple of (broadcast height, mempool log, transaction) for all
+    /// pending transactions, which were accepted into the fullnode's mempool but
+    /// haven't been detected on chain yet.
+    pub async fn pending_transactions(&self) -> anyhow::Result<Vec<(u64, String, Transaction)>> {
+        let sync_height = self.last_sync_height().await?.unwrap_or(0) as i64;
+
+        let pool = self.pool.clone();
+
+        spawn_blocking(move || {
+            pool.get()?
+                .prepare_cached(
+                    "SELECT broadcast_height, mempool_log, tx_bytes
+                    FROM pending_tx
+                    WHERE expires_at > ?1
+                    ORDER BY broadcast_height",
+                )?
+                .query_and_then([sync_height], |row| {
+                    let broadcast_height: u64 = row.get("broadcast_height")?;
+                    let mempool_log: String = row.get("mempool_log")?;
+                    let tx_bytes: Vec<u8> = row.get("tx_bytes")?;
+                    let tx = Transaction::decode(tx_bytes.as_slice())?;
+                    anyhow::Ok((broadcast_height, mempool_log, tx))
+                })?
+                .collect()
+        })
+        .await?
+    }
+
     // Query for a note by its note commitment, optionally waiting until the note is detected.
     pub async fn note_by_nullifier(
         &self,
@@ -842,6 +935,16 @@ impl Storage {
             true => "height_spent",
         };
 
+        // Unless spent notes are requested, skip notes which are provisionally
+        // spent by pending transactions.
+        let pending_clause = match include_spent {
+            false => {
+                let sync_height = self.last_sync_height().await?.unwrap_or(0);
+                format!("AND {}", pending::not_provisionally_spent(sync_height))
+            }
+            true => String::new(),
+        };
+
         // If set, only return notes with the specified asset id.
         // core.crypto.v1.AssetId asset_id = 3;
         let asset_clause = asset_id
@@ -893,7 +996,8 @@ impl Storage {
                 LEFT JOIN tx ON spendable_notes.tx_hash = tx.tx_hash
                 WHERE spendable_notes.height_spent IS {spent_clause}
                 AND notes.asset_id IS {asset_clause}
-                AND spendable_notes.address_index IS {address_clause}"
+                AND spendable_notes.address_index IS {address_clause}
+                {pending_clause}"
                 ))?
                 .query_and_then((), |row| SpendableNoteRecord::try_from(row))?
             {
@@ -1447,6 +1551,22 @@ impl Storage {
                         (&nf_bytes, &tx_hash),
                     )?;
                 }
+
+                // Record any ICS20 withdrawals, so that refunds can be linked back to them.
+                let packet_sequences = filtered_block
+                    .ics20_packet_sequences
+                    .get(&transaction.id().0)
+                    .map(Vec::as_slice);
+                ics20::record_withdrawals(&dbtx, tx_hash, &transaction, packet_sequences)?;
+            }
+
+            // Transactions detected on chain are no longer pending, and neither are
+            // those which expired without being detected.
+            pending::prune(&dbtx, filtered_block.height)?;
+
+            // Link refunds of timed out ICS20 withdrawals to the withdrawal they refund.
+            for note_record in filtered_block.new_notes.values() {
+                ics20::link_refund(&dbtx, note_record)?;
             }
 
             // Update FMD parameters if they've changed.
@@ -1563,6 +1683,39 @@ impl Storage {
         .await?
     }
 
+    /// Query for the notes refunding ICS20 withdrawals made by the given transaction.
+    pub async fn ics20_refunds(&self, tx_hash: &[u8]) -> anyhow::Result<Vec<SpendableNoteRecord>> {
+        let pool = self.pool.clone();
+
+        let query = "SELECT notes.note_commitment,
+            spendable_notes.height_created,
+            notes.address,
+            notes.amount,
+            notes.asset_id,
+            notes.rseed,
+            spendable_notes.address_index,
+            spendable_notes.source,
+            spendable_notes.height_spent,
+            spendable_notes.nullifier,
+            spendable_notes.position
+            FROM notes
+            JOIN spendable_notes ON notes.note_commitment = spendable_notes.note_commitment
+            JOIN ics20_withdrawals ON notes.note_commitment = ics20_withdrawals.refund_commitment
+            WHERE ics20_withdrawals.tx_hash = ?1";
+
+        let tx_hash = tx_hash.to_vec();
+
+        let records = spawn_blocking(move || {
+            pool.get()?
+                .prepare(query)?
+                .query_and_then([tx_hash], |record| record.try_into())?
+                .collect::<anyhow::Result<Vec<_>>>()
+        })
+        .await??;
+
+        Ok(records)
+    }
+
     pub async fn notes_by_sender(
         &self,
         return_address: &Address,
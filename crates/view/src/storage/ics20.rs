@@ -0,0 +1,192 @@
+use std::str::FromStr;
+
+use penumbra_keys::Address;
+use penumbra_sct::CommitmentSource;
+use penumbra_transaction::{Action, Transaction};
+use r2d2_sqlite::rusqlite::Transaction as DbTransaction;
+
+use crate::SpendableNoteRecord;
+
+/// Record the ICS20 withdrawals made by a transaction, so that refunds can be linked back to them.
+///
+/// The `packet_sequences` are the sequence numbers of the packets sent by the withdrawals, in the
+/// order of the withdrawals in the transaction, if they are known.
+pub(super) fn record_withdrawals(
+    dbtx: &DbTransaction,
+    tx_hash: &[u8],
+    transaction: &Transaction,
+    packet_sequences: Option<&[u64]>,
+) -> anyhow::Result<()> {
+    let withdrawals = transaction.actions().filter_map(|action| match action {
+        Action::Ics20Withdrawal(withdrawal) => Some(withdrawal),
+        _ => None,
+    });
+    for (index, withdrawal) in withdrawals.enumerate() {
+        let packet_seq = packet_sequences
+            .and_then(|sequences| sequences.get(index))
+            .map(|&sequence| sequence as i64);
+        dbtx.execute(
+            "INSERT INTO ics20_withdrawals (tx_hash, channel_id, return_address, packet_seq, refund_commitment)
+            VALUES (?1, ?2, ?3, ?4, NULL)",
+            (
+                tx_hash,
+                withdrawal.source_channel.to_string(),
+                withdrawal.return_address.to_vec(),
+                packet_seq,
+            ),
+        )?;
+    }
+    Ok(())
+}
+
+/// If the note refunds a recorded ICS20 withdrawal whose transfer timed out, link it to that
+/// withdrawal, returning whether it did.
+///
+/// Refunds are minted with the source of an inbound transfer, naming the packet that timed out
+/// and its sender, which is the return address of the withdrawal. They are linked to the
+/// withdrawal which sent that packet on that channel; if the sequence numbers of the packets sent
+/// by some withdrawals are unknown, refunds with no such withdrawal fall back to the earliest of
+/// those with the same return address.
+pub(super) fn link_refund(
+    dbtx: &DbTransaction,
+    note_record: &SpendableNoteRecord,
+) -> anyhow::Result<bool> {
+    let CommitmentSource::Ics20Transfer {
+        packet_seq,
+        channel_id,
+        sender,
+    } = &note_record.source
+    else {
+        return Ok(false);
+    };
+    // Inbound transfers are sent by addresses on the counterparty chain, which never parse.
+    let Ok(return_address) = Address::from_str(sender) else {
+        return Ok(false);
+    };
+    let note_commitment = note_record.note_commitment.0.to_bytes().to_vec();
+
+    let linked = dbtx.execute(
+        "UPDATE ics20_withdrawals SET refund_commitment = ?1
+        WHERE rowid = (
+            SELECT rowid FROM ics20_withdrawals
+            WHERE channel_id = ?2 AND return_address = ?3 AND refund_commitment IS NULL
+            AND (packet_seq = ?4 OR packet_seq IS NULL)
+            ORDER BY packet_seq IS NULL, rowid LIMIT 1
+        )",
+        (
+            &note_commitment,
+            channel_id,
+            return_address.to_vec(),
+            *packet_seq as i64,
+        ),
+    )?;
+    Ok(linked > 0)
+}
+
+#[cfg(test)]
+mod test {
+    use ibc_types::core::{channel::ChannelId, client::Height};
+    use penumbra_app::params::AppParameters;
+    use penumbra_asset::{asset, Value, STAKING_TOKEN_ASSET_ID};
+    use penumbra_keys::{keys::AddressIndex, test_keys};
+    use penumbra_sct::Nullifier;
+    use penumbra_shielded_pool::{Ics20Withdrawal, Note};
+    use penumbra_tct::StateCommitment;
+    use rand_core::OsRng;
+
+    use super::*;
+    use crate::Storage;
+
+    fn withdrawal(return_address: &Address) -> Action {
+        Action::Ics20Withdrawal(Ics20Withdrawal {
+            amount: 100u64.into(),
+            denom: asset::REGISTRY.parse_unit("upenumbra").base(),
+            destination_chain_address: "osmo1receiver".to_string(),
+            return_address: *return_address,
+            timeout_height: Height::new(0, 1000).expect("height is valid"),
+            timeout_time: 1,
+            source_channel: ChannelId::new(0),
+        })
+    }
+
+    fn refund(note: &Note, packet_seq: u64, sender: String) -> SpendableNoteRecord {
+        SpendableNoteRecord {
+            note_commitment: note.commit(),
+            note: note.clone(),
+            address_index: AddressIndex::new(0),
+            nullifier: Nullifier::derive(
+                test_keys::FULL_VIEWING_KEY.nullifier_key(),
+                0u64.into(),
+                &note.commit(),
+            ),
+            height_created: 1,
+            height_spent: None,
+            position: 0u64.into(),
+            source: CommitmentSource::Ics20Transfer {
+                packet_seq,
+                channel_id: "channel-0".to_string(),
+                sender,
+            },
+            return_address: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn refunds_are_linked_by_packet_sequence() -> anyhow::Result<()> {
+        let storage = Storage::initialize(
+            None::<&str>,
+            test_keys::FULL_VIEWING_KEY.clone(),
+            AppParameters::default(),
+        )
+        .await?;
+        let mut conn = storage.pool.get()?;
+        let dbtx = conn.transaction()?;
+
+        // Two transactions make identical withdrawals, which send packets 7 and 8.
+        let return_address = *test_keys::ADDRESS_0;
+        let mut transaction = Transaction::default();
+        transaction.transaction_body.actions = vec![withdrawal(&return_address)];
+        record_withdrawals(&dbtx, &[1; 32], &transaction, Some(&[7]))?;
+        record_withdrawals(&dbtx, &[2; 32], &transaction, Some(&[8]))?;
+
+        let value = Value {
+            amount: 100u64.into(),
+            asset_id: *STAKING_TOKEN_ASSET_ID,
+        };
+
+        // The later one times out first, and its refund is linked to it, not the earlier one.
+        let note = Note::generate(&mut OsRng, &return_address, value);
+        let sender = return_address.to_string();
+        assert!(link_refund(&dbtx, &refund(&note, 8, sender.clone()))?);
+        let refunded: Vec<u8> = dbtx.query_row(
+            "SELECT tx_hash FROM ics20_withdrawals WHERE refund_commitment IS NOT NULL",
+            (),
+            |row| row.get(0),
+        )?;
+        assert_eq!(refunded, vec![2; 32]);
+
+        // Refunds of packets which weren't sent by a recorded withdrawal aren't linked...
+        let other = Note::generate(&mut OsRng, &return_address, value);
+        assert!(!link_refund(&dbtx, &refund(&other, 9, sender.clone()))?);
+        // ... and neither are inbound transfers, sent from other chains.
+        assert!(!link_refund(
+            &dbtx,
+            &refund(&other, 7, "osmo1sender".to_string())
+        )?);
+
+        // A withdrawal whose packet sequence is unknown is linked by its return address alone.
+        record_withdrawals(&dbtx, &[3; 32], &transaction, None)?;
+        assert!(link_refund(&dbtx, &refund(&other, 9, sender))?);
+        let refunded: StateCommitment = dbtx.query_row(
+            "SELECT refund_commitment FROM ics20_withdrawals WHERE tx_hash = ?1",
+            [vec![3u8; 32]],
+            |row| {
+                let bytes: Vec<u8> = row.get(0)?;
+                Ok(StateCommitment::try_from(&bytes[..]).expect("valid commitment"))
+            },
+        )?;
+        assert_eq!(refunded, other.commit());
+
+        Ok(())
+    }
+}
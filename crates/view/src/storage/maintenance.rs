@@ -0,0 +1,241 @@
+use penumbra_proto::view::v1 as pb;
+use r2d2_sqlite::rusqlite::Connection;
+use tokio::task::spawn_blocking;
+
+use super::Storage;
+
+/// A query counting note records without a note plaintext, which can only be recovered by
+/// resynchronizing.
+const ORPHANED_NOTES: &str = "SELECT COUNT(*) FROM spendable_notes
+    WHERE note_commitment NOT IN (SELECT note_commitment FROM notes)";
+
+/// Queries counting, and then deleting, pending nullifiers and nullifier lookups which refer to a
+/// transaction missing from the database: they are recorded alongside the transaction, so without
+/// it there is nothing left for them to refer to.
+const ORPHANED_REPAIRABLE: &[(&str, &str)] = &[
+    (
+        "SELECT COUNT(*) FROM pending_nullifiers
+        WHERE tx_hash NOT IN (SELECT tx_hash FROM pending_tx)",
+        "DELETE FROM pending_nullifiers
+        WHERE tx_hash NOT IN (SELECT tx_hash FROM pending_tx)",
+    ),
+    (
+        "SELECT COUNT(*) FROM tx_by_nullifier
+        WHERE tx_hash NOT IN (SELECT tx_hash FROM tx)",
+        "DELETE FROM tx_by_nullifier
+        WHERE tx_hash NOT IN (SELECT tx_hash FROM tx)",
+    ),
+];
+
+/// Queries counting unspent notes and unclaimed swaps whose commitment is not witnessed at their
+/// position in the state commitment tree. Spent notes and claimed swaps are forgotten from the
+/// tree, so they are not expected to be witnessed.
+const UNWITNESSED: &[&str] = &[
+    "SELECT COUNT(*) FROM spendable_notes
+    WHERE height_spent IS NULL AND NOT EXISTS (
+        SELECT 1 FROM sct_commitments
+        WHERE sct_commitments.position = spendable_notes.position
+        AND sct_commitments.commitment = spendable_notes.note_commitment
+    )",
+    "SELECT COUNT(*) FROM swaps
+    WHERE height_claimed IS NULL AND NOT EXISTS (
+        SELECT 1 FROM sct_commitments
+        WHERE sct_commitments.position = swaps.position
+        AND sct_commitments.commitment = swaps.swap_commitment
+    )",
+];
+
+/// Tables whose rows are not constrained to be unique, and the columns which identify a row.
+///
+/// Identical rows carry no more information than one of them, so all but the first are deleted
+/// by a repair.
+const DEDUPLICATED: &[(&str, &str)] = &[
+    ("sct_hashes", "position, height, hash"),
+    ("sct_commitments", "position, commitment"),
+    ("pending_nullifiers", "nullifier, tx_hash"),
+    (
+        "ics20_withdrawals",
+        "tx_hash, channel_id, return_address, refund_commitment",
+    ),
+];
+
+/// A query counting notes which share their nullifier with another, and so cannot be told apart
+/// without resynchronizing.
+const DUPLICATE_NULLIFIERS: &str =
+    "SELECT COUNT(*) - COUNT(DISTINCT nullifier) FROM spendable_notes";
+
+/// A summary of the health of the database, as found by [`Storage::maintain`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct DatabaseHealth {
+    /// Problems reported by SQLite's integrity check, if the file is damaged.
+    pub storage_errors: Vec<String>,
+    /// Records referring to a note or transaction missing from the database.
+    pub orphaned_records: u64,
+    /// Unspent notes and unclaimed swaps which are not witnessed in the state commitment tree.
+    pub unwitnessed_records: u64,
+    /// Records which duplicate another.
+    pub duplicate_records: u64,
+    /// The number of records deleted by the repair.
+    pub repaired_records: u64,
+    /// The size of the database in bytes, before any repair or compaction.
+    pub size_before: u64,
+    /// The size of the database in bytes, after any repair or compaction.
+    pub size_after: u64,
+    /// Whether problems remain which can only be fixed by resynchronizing.
+    pub needs_resync: bool,
+}
+
+impl Storage {
+    /// Check the integrity of the database, optionally deleting the orphaned and duplicate
+    /// records which can be deleted without losing information, and compacting the database.
+    ///
+    /// Problems which can't be repaired in place, such as a note record missing its plaintext or
+    /// an unspent note missing its witness, are reported in
+    /// [`needs_resync`](DatabaseHealth::needs_resync).
+    pub async fn maintain(&self, repair: bool, vacuum: bool) -> anyhow::Result<DatabaseHealth> {
+        let pool = self.pool.clone();
+
+        spawn_blocking(move || {
+            let mut lock = pool.get()?;
+            let mut health = DatabaseHealth {
+                size_before: database_size(&lock)?,
+                ..Default::default()
+            };
+
+            health.storage_errors = lock
+                .prepare("PRAGMA integrity_check")?
+                .query_map([], |row| row.get::<_, String>(0))?
+                .collect::<Result<Vec<_>, _>>()?
+                .into_iter()
+                .filter(|message| message != "ok")
+                .collect();
+
+            let dbtx = lock.transaction()?;
+
+            let orphaned_notes = count(&dbtx, ORPHANED_NOTES)?;
+            health.orphaned_records = orphaned_notes;
+            for (query, repair_query) in ORPHANED_REPAIRABLE {
+                health.orphaned_records += count(&dbtx, query)?;
+                if repair {
+                    health.repaired_records += dbtx.execute(repair_query, [])? as u64;
+                }
+            }
+
+            for query in UNWITNESSED {
+                health.unwitnessed_records += count(&dbtx, query)?;
+            }
+
+            let duplicate_nullifiers = count(&dbtx, DUPLICATE_NULLIFIERS)?;
+            health.duplicate_records = duplicate_nullifiers;
+            for (table, columns) in DEDUPLICATED {
+                health.duplicate_records += count(
+                    &dbtx,
+                    &format!(
+                        "SELECT COUNT(*) - (SELECT COUNT(*) FROM (SELECT 1 FROM {table} GROUP BY {columns})) FROM {table}"
+                    ),
+                )?;
+                if repair {
+                    health.repaired_records += dbtx.execute(
+                        &format!(
+                            "DELETE FROM {table} WHERE rowid NOT IN (SELECT MIN(rowid) FROM {table} GROUP BY {columns})"
+                        ),
+                        [],
+                    )? as u64;
+                }
+            }
+
+            dbtx.commit()?;
+
+            // Vacuuming rewrites the whole file, so it can't happen inside a transaction.
+            if vacuum {
+                lock.execute_batch("VACUUM")?;
+            }
+            health.size_after = database_size(&lock)?;
+
+            health.needs_resync = !health.storage_errors.is_empty()
+                || orphaned_notes > 0
+                || health.unwitnessed_records > 0
+                || duplicate_nullifiers > 0;
+
+            if health.needs_resync {
+                tracing::warn!(?health, "view database needs to be resynchronized");
+            } else {
+                tracing::info!(?health, "checked view database");
+            }
+
+            Ok(health)
+        })
+        .await?
+    }
+}
+
+fn count(conn: &Connection, query: &str) -> anyhow::Result<u64> {
+    let count: i64 = conn.query_row(query, [], |row| row.get(0))?;
+    Ok(count as u64)
+}
+
+fn database_size(conn: &Connection) -> anyhow::Result<u64> {
+    let page_count: i64 = conn.query_row("PRAGMA page_count", [], |row| row.get(0))?;
+    let page_size: i64 = conn.query_row("PRAGMA page_size", [], |row| row.get(0))?;
+    Ok((page_count * page_size) as u64)
+}
+
+impl From<DatabaseHealth> for pb::DatabaseMaintenanceResponse {
+    fn from(health: DatabaseHealth) -> Self {
+        Self {
+            storage_errors: health.storage_errors,
+            orphaned_records: health.orphaned_records,
+            unwitnessed_records: health.unwitnessed_records,
+            duplicate_records: health.duplicate_records,
+            repaired_records: health.repaired_records,
+            size_before: health.size_before,
+            size_after: health.size_after,
+            needs_resync: health.needs_resync,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use penumbra_app::params::AppParameters;
+    use penumbra_keys::test_keys;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn repairs_orphaned_and_duplicate_records() -> anyhow::Result<()> {
+        let storage = Storage::initialize(
+            None::<&str>,
+            test_keys::FULL_VIEWING_KEY.clone(),
+            AppParameters::default(),
+        )
+        .await?;
+
+        storage.pool.get()?.execute_batch(
+            "INSERT INTO pending_nullifiers (nullifier, tx_hash) VALUES (x'01', x'02');
+            INSERT INTO sct_hashes (position, height, hash) VALUES (0, 0, x'03');
+            INSERT INTO sct_hashes (position, height, hash) VALUES (0, 0, x'03');",
+        )?;
+
+        let health = storage.maintain(false, false).await?;
+        assert_eq!(health.orphaned_records, 1);
+        assert_eq!(health.duplicate_records, 1);
+        assert_eq!(health.repaired_records, 0);
+        assert!(!health.needs_resync);
+
+        let health = storage.maintain(true, true).await?;
+        assert_eq!(health.repaired_records, 2);
+
+        let health = storage.maintain(false, false).await?;
+        assert_eq!(
+            health,
+            DatabaseHealth {
+                size_before: health.size_before,
+                size_after: health.size_after,
+                ..Default::default()
+            }
+        );
+
+        Ok(())
+    }
+}
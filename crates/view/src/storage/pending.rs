@@ -0,0 +1,163 @@
+use r2d2_sqlite::rusqlite::Transaction as DbTransaction;
+
+/// A transaction which the fullnode accepted into its mempool, but which hasn't been detected on
+/// chain yet.
+pub(super) struct PendingTransaction {
+    /// The transaction's id.
+    pub tx_hash: Vec<u8>,
+    pub tx_bytes: Vec<u8>,
+    /// The sync height when the transaction was broadcast.
+    pub broadcast_height: u64,
+    /// The sync height at which the transaction stops being pending.
+    pub expires_at: u64,
+    pub mempool_log: String,
+    /// The nullifiers revealed by the transaction, whose notes are provisionally spent.
+    pub nullifiers: Vec<Vec<u8>>,
+}
+
+/// Record a pending transaction, unless it was already detected on chain.
+pub(super) fn record(dbtx: &DbTransaction, pending: &PendingTransaction) -> anyhow::Result<()> {
+    let inserted = dbtx.execute(
+        "INSERT OR IGNORE INTO pending_tx (tx_hash, tx_bytes, broadcast_height, expires_at, mempool_log)
+        SELECT ?1, ?2, ?3, ?4, ?5
+        WHERE NOT EXISTS (SELECT 1 FROM tx WHERE tx_hash = ?1)",
+        (
+            &pending.tx_hash,
+            &pending.tx_bytes,
+            pending.broadcast_height as i64,
+            pending.expires_at as i64,
+            &pending.mempool_log,
+        ),
+    )?;
+    if inserted > 0 {
+        for nullifier in &pending.nullifiers {
+            dbtx.execute(
+                "INSERT INTO pending_nullifiers (nullifier, tx_hash) VALUES (?1, ?2)",
+                (nullifier, &pending.tx_hash),
+            )?;
+        }
+    }
+    Ok(())
+}
+
+/// A condition on `spendable_notes` excluding notes which are provisionally spent by transactions
+/// still pending at the sync height.
+pub(super) fn not_provisionally_spent(sync_height: u64) -> String {
+    format!(
+        "spendable_notes.nullifier NOT IN (
+            SELECT pending_nullifiers.nullifier FROM pending_nullifiers
+            JOIN pending_tx ON pending_nullifiers.tx_hash = pending_tx.tx_hash
+            WHERE pending_tx.expires_at > {sync_height}
+        )"
+    )
+}
+
+/// Stop tracking transactions which were detected on chain, or which expired without being
+/// detected, by the given height.
+pub(super) fn prune(dbtx: &DbTransaction, height: u64) -> anyhow::Result<()> {
+    dbtx.execute(
+        "DELETE FROM pending_tx
+        WHERE expires_at <= ?1 OR tx_hash IN (SELECT tx_hash FROM tx)",
+        [height as i64],
+    )?;
+    dbtx.execute(
+        "DELETE FROM pending_nullifiers
+        WHERE tx_hash NOT IN (SELECT tx_hash FROM pending_tx)",
+        (),
+    )?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use penumbra_app::params::AppParameters;
+    use penumbra_asset::{Value, STAKING_TOKEN_ASSET_ID};
+    use penumbra_keys::{keys::AddressIndex, test_keys};
+    use penumbra_proto::DomainType;
+    use penumbra_sct::{CommitmentSource, Nullifier};
+    use penumbra_shielded_pool::Note;
+    use rand_core::OsRng;
+
+    use super::*;
+    use crate::Storage;
+
+    #[tokio::test]
+    async fn provisionally_spent_notes_are_not_spendable() -> anyhow::Result<()> {
+        let storage = Storage::initialize(
+            None::<&str>,
+            test_keys::FULL_VIEWING_KEY.clone(),
+            AppParameters::default(),
+        )
+        .await?;
+
+        // The wallet holds a note...
+        let note = Note::generate(
+            &mut OsRng,
+            &test_keys::ADDRESS_0,
+            Value {
+                amount: 100u64.into(),
+                asset_id: *STAKING_TOKEN_ASSET_ID,
+            },
+        );
+        let nullifier = Nullifier::derive(
+            test_keys::FULL_VIEWING_KEY.nullifier_key(),
+            0u64.into(),
+            &note.commit(),
+        );
+        {
+            let mut conn = storage.pool.get()?;
+            let dbtx = conn.transaction()?;
+            Storage::record_note_inner(&dbtx, &note)?;
+            dbtx.execute(
+                "INSERT INTO spendable_notes
+                (note_commitment, nullifier, position, height_created, address_index, source, height_spent, tx_hash)
+                VALUES (?1, ?2, 0, 0, ?3, ?4, NULL, NULL)",
+                (
+                    note.commit().0.to_bytes().to_vec(),
+                    nullifier.to_bytes().to_vec(),
+                    AddressIndex::new(0).to_bytes().to_vec(),
+                    CommitmentSource::Genesis.encode_to_vec(),
+                ),
+            )?;
+            dbtx.commit()?;
+        }
+        assert_eq!(storage.notes(false, None, None, None).await?.len(), 1);
+        assert_eq!(storage.balances(None, None).await?.len(), 1);
+
+        // ... which a transaction pending until height 10 spends.
+        {
+            let mut conn = storage.pool.get()?;
+            let dbtx = conn.transaction()?;
+            record(
+                &dbtx,
+                &PendingTransaction {
+                    tx_hash: vec![1; 32],
+                    tx_bytes: Vec::new(),
+                    broadcast_height: 0,
+                    expires_at: 10,
+                    mempool_log: String::new(),
+                    nullifiers: vec![nullifier.to_bytes().to_vec()],
+                },
+            )?;
+            dbtx.commit()?;
+        }
+
+        // It's neither planned into other transactions, nor counted in balances...
+        assert!(storage.notes(false, None, None, None).await?.is_empty());
+        assert!(storage.balances(None, None).await?.is_empty());
+        // ... but is still listed among all notes.
+        assert_eq!(storage.notes(true, None, None, None).await?.len(), 1);
+
+        // Once the transaction expires without being detected, the note is spendable again.
+        {
+            let mut conn = storage.pool.get()?;
+            let dbtx = conn.transaction()?;
+            prune(&dbtx, 10)?;
+            dbtx.commit()?;
+        }
+        assert_eq!(storage.notes(false, None, None, None).await?.len(), 1);
+        assert_eq!(storage.balances(None, None).await?.len(), 1);
+
+        Ok(())
+    }
+}
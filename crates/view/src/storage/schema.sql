@@ -56,6 +56,41 @@ CREATE TABLE tx (
     return_address          BLOB
 );
 
+-- transactions broadcast by the view service which the fullnode accepted into
+-- its mempool, but which haven't been detected on chain yet
+CREATE TABLE pending_tx (
+    tx_hash                 BLOB PRIMARY KEY NOT NULL,
+    tx_bytes                BLOB NOT NULL,
+    -- the sync height when the transaction was broadcast
+    broadcast_height        BIGINT NOT NULL,
+    -- the sync height at which the transaction stops being pending, if it
+    -- still hasn't been detected on chain
+    expires_at              BIGINT NOT NULL,
+    -- the log returned by the fullnode's mempool check
+    mempool_log             TEXT NOT NULL
+);
+
+-- nullifiers revealed by pending transactions, whose notes are treated as
+-- provisionally spent
+CREATE TABLE pending_nullifiers (
+    nullifier               BLOB NOT NULL,
+    tx_hash                 BLOB NOT NULL
+);
+
+-- ICS20 withdrawals made by relevant transactions, linked to the note which
+-- refunded each one if the transfer timed out
+CREATE TABLE ics20_withdrawals (
+    tx_hash                 BLOB NOT NULL,
+    channel_id              TEXT NOT NULL,
+    return_address          BLOB NOT NULL,
+    -- null if the node couldn't report the sequence number of the packet sent
+    packet_seq              BIGINT,
+    -- null unless the transfer was refunded
+    refund_commitment       BLOB
+);
+
+CREATE INDEX ics20_withdrawals_return_address_idx ON ics20_withdrawals (return_address);
+
 -- This table just records the mapping from note commitments to note plaintexts.
 -- This is also used as a way to give advice about out-of-band notes during scanning,
 -- by allowing the user to add notes to the database before they are scanned.
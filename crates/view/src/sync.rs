@@ -21,6 +21,9 @@ pub struct FilteredBlock {
     pub fmd_parameters: Option<fmd::Parameters>,
     pub app_parameters_updated: bool,
     pub gas_prices: Option<GasPrices>,
+    /// The sequence numbers of the packets sent by the ICS20 withdrawals in each relevant
+    /// transaction, keyed by transaction hash, as reported by the node.
+    pub ics20_packet_sequences: BTreeMap<[u8; 32], Vec<u64>>,
 }
 
 #[tracing::instrument(skip_all, fields(height = %height))]
@@ -176,8 +179,12 @@ pub async fn scan_block(
                     storage.give_advice(output_2).await?;
 
                     let source = payload.source().clone();
-                    let nullifier =
-                        Nullifier::derive(fvk.nullifier_key(), position, payload.commitment());
+                    // Swaps are nullified with their claim key, rather than our nullifier key.
+                    let nullifier = Nullifier::derive(
+                        &swap.claim_key(fvk.nullifier_key()),
+                        position,
+                        payload.commitment(),
+                    );
 
                     new_swaps.insert(
                         *payload.commitment(),
@@ -235,6 +242,7 @@ pub async fn scan_block(
         fmd_parameters,
         app_parameters_updated,
         gas_prices,
+        ics20_packet_sequences: BTreeMap::new(),
     };
 
     Ok(result)
@@ -0,0 +1,320 @@
+//! Deterministic test vectors for wallet interoperability.
+//!
+//! [`TestVectors::generate`] derives, from a single 32-byte seed, a chain of fixtures covering the
+//! data another wallet implementation needs to agree on with this one:
+//!
+//! seed phrase → spend key → full viewing key → addresses → notes → witnessed state commitment
+//! tree → transaction plans, with their effect hashes and authorizations.
+//!
+//! All randomness is drawn from a ChaCha20 RNG seeded with the given seed, so generating vectors
+//! from the same seed always produces the same JSON. The transactions are not built, since their
+//! zero-knowledge proofs are expensive to compute and not needed to check compatibility.
+
+use anyhow::Result;
+use rand_chacha::ChaCha20Rng;
+use rand_core::{RngCore, SeedableRng};
+use serde::{Deserialize, Serialize};
+
+use penumbra_asset::{asset, Value, STAKING_TOKEN_ASSET_ID};
+use penumbra_keys::{
+    keys::{AddressIndex, Bip44Path, SeedPhrase, SpendKey},
+    Address,
+};
+use penumbra_proto::core::transaction::v1 as pb_transaction;
+use penumbra_proto::crypto::tct::v1 as pb_tct;
+use penumbra_sct::Nullifier;
+use penumbra_shielded_pool::{Note, OutputPlan, SpendPlan};
+use penumbra_tct as tct;
+use penumbra_transaction::{memo::MemoPlaintext, plan::MemoPlan, TransactionPlan};
+
+/// The seed from which the published test vectors are generated.
+pub const DEFAULT_SEED: [u8; 32] = *b"penumbra wallet interop vectors.";
+
+/// The chain ID used in the generated transaction plans.
+pub const CHAIN_ID: &str = "penumbra-test-vectors";
+
+/// The number of addresses derived from the generated keys.
+const NUM_ADDRESSES: u32 = 4;
+
+/// The number of notes generated, sent round-robin to the derived addresses.
+const NUM_NOTES: usize = 8;
+
+/// The number of notes included in each block of the state commitment tree.
+const NOTES_PER_BLOCK: usize = 3;
+
+/// A complete set of interop fixtures, derived deterministically from a seed.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct TestVectors {
+    /// The hex-encoded seed the vectors were generated from.
+    pub seed: String,
+    /// The BIP39 seed phrase.
+    pub seed_phrase: String,
+    /// The spend key for account 0 of the seed phrase, in Bech32m.
+    pub spend_key: String,
+    /// The full viewing key of the spend key, in Bech32m.
+    pub full_viewing_key: String,
+    /// The wallet ID of the full viewing key, in Bech32m.
+    pub wallet_id: String,
+    /// Addresses derived from the full viewing key.
+    pub addresses: Vec<AddressVector>,
+    /// Notes sent to the derived addresses.
+    pub notes: Vec<NoteVector>,
+    /// The roots of the state commitment tree at the end of each block of notes.
+    pub blocks: Vec<BlockVector>,
+    /// Transactions spending the notes.
+    pub transactions: Vec<TransactionVector>,
+}
+
+/// An address derived from the full viewing key.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct AddressVector {
+    /// The account index of the address.
+    pub index: u32,
+    /// The address, in Bech32m.
+    pub address: String,
+}
+
+/// A note sent to one of the derived addresses, and its place in the state commitment tree.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct NoteVector {
+    /// The account index of the address the note was sent to.
+    pub address_index: u32,
+    /// The amount of the note.
+    pub amount: String,
+    /// The asset ID of the note, in Bech32m.
+    pub asset_id: String,
+    /// The hex-encoded rseed of the note.
+    pub rseed: String,
+    /// The hex-encoded note commitment.
+    pub commitment: String,
+    /// The position of the note in the state commitment tree.
+    pub position: u64,
+    /// The hex-encoded nullifier of the note.
+    pub nullifier: String,
+    /// The note's proof of inclusion in the final state commitment tree.
+    pub proof: pb_tct::StateCommitmentProof,
+}
+
+/// The state commitment tree at the end of a block of notes.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct BlockVector {
+    /// The positions of the notes witnessed in the block.
+    pub positions: Vec<u64>,
+    /// The hex-encoded root of the block.
+    pub block_root: String,
+    /// The hex-encoded root of the whole tree at the end of the block.
+    pub root: String,
+}
+
+/// A transaction spending one of the notes.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct TransactionVector {
+    /// The transaction plan.
+    pub plan: TransactionPlan,
+    /// The hex-encoded effect hash of the transaction.
+    pub effect_hash: String,
+    /// The authorization of the transaction by the spend key.
+    pub authorization: pb_transaction::AuthorizationData,
+}
+
+impl TestVectors {
+    /// Generate test vectors deterministically from the given seed.
+    pub fn generate(seed: [u8; 32]) -> Result<Self> {
+        let mut rng = ChaCha20Rng::from_seed(seed);
+
+        // Keys
+        let mut randomness = [0u8; 32];
+        rng.fill_bytes(&mut randomness);
+        let seed_phrase = SeedPhrase::from_randomness(&randomness);
+        let sk = SpendKey::from_seed_phrase_bip44(seed_phrase.clone(), &Bip44Path::new(0));
+        let fvk = sk.full_viewing_key();
+
+        // Addresses
+        let addresses: Vec<Address> = (0..NUM_ADDRESSES)
+            .map(|index| fvk.payment_address(AddressIndex::new(index)).0)
+            .collect();
+
+        // Notes, alternating between the staking token and another asset
+        let gm = asset::Cache::with_known_assets()
+            .get_unit("gm")
+            .expect("gm is a known asset")
+            .id();
+        let notes: Vec<(u32, Note)> = (0..NUM_NOTES)
+            .map(|i| {
+                let address_index = i as u32 % NUM_ADDRESSES;
+                let value = Value {
+                    amount: (1_000_000u64 * (i as u64 + 1)).into(),
+                    asset_id: if i % 2 == 0 {
+                        *STAKING_TOKEN_ASSET_ID
+                    } else {
+                        gm
+                    },
+                };
+                let note = Note::generate(&mut rng, &addresses[address_index as usize], value);
+                (address_index, note)
+            })
+            .collect();
+
+        // The state commitment tree, witnessing every note
+        let mut sct = tct::Tree::new();
+        let mut positions = Vec::with_capacity(notes.len());
+        let mut blocks = Vec::new();
+        for block in notes.chunks(NOTES_PER_BLOCK) {
+            let mut block_positions = Vec::with_capacity(block.len());
+            for (_, note) in block {
+                let position = sct.insert(tct::Witness::Keep, note.commit())?;
+                positions.push(position);
+                block_positions.push(u64::from(position));
+            }
+            let block_root = sct.end_block()?;
+            blocks.push(BlockVector {
+                positions: block_positions,
+                block_root: block_root.to_string(),
+                root: sct.root().to_string(),
+            });
+        }
+
+        let note_vectors = notes
+            .iter()
+            .zip(&positions)
+            .map(|((address_index, note), &position)| {
+                let commitment = note.commit();
+                let proof = sct
+                    .witness(commitment)
+                    .expect("every note is witnessed in the tree");
+                NoteVector {
+                    address_index: *address_index,
+                    amount: note.amount().to_string(),
+                    asset_id: note.asset_id().to_string(),
+                    rseed: hex::encode(note.rseed().0),
+                    commitment: commitment.to_string(),
+                    position: position.into(),
+                    nullifier: Nullifier::derive(fvk.nullifier_key(), position, &commitment)
+                        .to_string(),
+                    proof: proof.into(),
+                }
+            })
+            .collect();
+
+        // Transactions, each spending a note to the next address with a memo
+        let mut transactions = Vec::new();
+        for (i, ((address_index, note), &position)) in notes.iter().zip(&positions).enumerate() {
+            let destination = &addresses[((address_index + 1) % NUM_ADDRESSES) as usize];
+            let return_address = &addresses[*address_index as usize];
+
+            let mut plan = TransactionPlan::default();
+            plan.transaction_parameters.chain_id = CHAIN_ID.to_string();
+            plan.transaction_parameters.expiry_height = 1_000 + i as u64;
+            plan.actions
+                .push(SpendPlan::new(&mut rng, note.clone(), position).into());
+            plan.actions
+                .push(OutputPlan::new(&mut rng, note.value(), destination.clone()).into());
+            plan.memo = Some(MemoPlan::new(
+                &mut rng,
+                MemoPlaintext::new(return_address.clone(), format!("test vector {i}"))?,
+            )?);
+
+            let effect_hash = plan.effect_hash(fvk)?;
+            let authorization = plan.authorize(&mut rng, &sk)?;
+            transactions.push(TransactionVector {
+                plan,
+                effect_hash: hex::encode(effect_hash.as_ref()),
+                authorization: authorization.into(),
+            });
+        }
+
+        Ok(Self {
+            seed: hex::encode(seed),
+            seed_phrase: seed_phrase.to_string(),
+            spend_key: sk.to_string(),
+            full_viewing_key: fvk.to_string(),
+            wallet_id: fvk.wallet_id().to_string(),
+            addresses: addresses
+                .iter()
+                .enumerate()
+                .map(|(index, address)| AddressVector {
+                    index: index as u32,
+                    address: address.to_string(),
+                })
+                .collect(),
+            notes: note_vectors,
+            blocks,
+            transactions,
+        })
+    }
+
+    /// Serialize the test vectors as pretty-printed JSON.
+    pub fn to_json(&self) -> Result<String> {
+        Ok(serde_json::to_string_pretty(self)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use penumbra_keys::FullViewingKey;
+    use penumbra_transaction::AuthorizationData;
+
+    use super::*;
+
+    /// The keys derived from [`DEFAULT_SEED`], as computed by an independent implementation of
+    /// the ChaCha20, BIP39, BIP44 and Bech32m steps.
+    const GOLDEN_KEYS: &str = include_str!("../test-vectors/keys.json");
+
+    #[derive(Deserialize)]
+    struct GoldenKeys {
+        seed: String,
+        seed_phrase: String,
+        spend_key: String,
+    }
+
+    #[test]
+    fn keys_match_golden_values() {
+        let golden: GoldenKeys = serde_json::from_str(GOLDEN_KEYS).unwrap();
+        let vectors = TestVectors::generate(DEFAULT_SEED).unwrap();
+        assert_eq!(vectors.seed, golden.seed);
+        assert_eq!(vectors.seed_phrase, golden.seed_phrase);
+        assert_eq!(vectors.spend_key, golden.spend_key);
+
+        let other = TestVectors::generate([0u8; 32]).unwrap();
+        assert_ne!(other.seed_phrase, golden.seed_phrase);
+        assert_ne!(other.spend_key, golden.spend_key);
+    }
+
+    #[test]
+    fn vectors_are_consistent() {
+        let vectors = TestVectors::generate(DEFAULT_SEED).unwrap();
+
+        // Round-trip the JSON, as another implementation would read it
+        let vectors: TestVectors = serde_json::from_str(&vectors.to_json().unwrap()).unwrap();
+
+        let seed_phrase = SeedPhrase::from_str(&vectors.seed_phrase).unwrap();
+        let sk = SpendKey::from_seed_phrase_bip44(seed_phrase, &Bip44Path::new(0));
+        assert_eq!(sk.to_string(), vectors.spend_key);
+        let fvk = FullViewingKey::from_str(&vectors.full_viewing_key).unwrap();
+        assert_eq!(&fvk, sk.full_viewing_key());
+
+        for address in &vectors.addresses {
+            let (expected, _) = fvk.payment_address(AddressIndex::new(address.index));
+            assert_eq!(expected.to_string(), address.address);
+        }
+
+        let root = &vectors.blocks.last().unwrap().root;
+        for note in &vectors.notes {
+            let proof = tct::Proof::try_from(note.proof.clone()).unwrap();
+            assert_eq!(proof.commitment().to_string(), note.commitment);
+            assert_eq!(u64::from(proof.position()), note.position);
+            assert_eq!(&proof.root().to_string(), root);
+            assert!(proof.verify(proof.root()).is_ok());
+        }
+
+        for transaction in &vectors.transactions {
+            let effect_hash = transaction.plan.effect_hash(&fvk).unwrap();
+            assert_eq!(hex::encode(effect_hash.as_ref()), transaction.effect_hash);
+            let authorization =
+                AuthorizationData::try_from(transaction.authorization.clone()).unwrap();
+            assert_eq!(authorization.effect_hash, Some(effect_hash));
+        }
+    }
+}
@@ -2,6 +2,8 @@ use penumbra_transaction::{
     txhash::TransactionId, Transaction, TransactionPerspective, TransactionView,
 };
 
+use crate::SpendableNoteRecord;
+
 #[derive(Debug, Clone)]
 pub struct TransactionInfo {
     // The height the transaction was included in a block, if known.
@@ -14,4 +16,6 @@ pub struct TransactionInfo {
     pub perspective: TransactionPerspective,
     // A precomputed transaction view of `transaction` from `perspective`, included for convenience of clients that don't have support for viewing transactions on their own.
     pub view: TransactionView,
+    // Notes refunding ICS20 withdrawals made by this transaction, which were returned because the transfer timed out.
+    pub refunds: Vec<SpendableNoteRecord>,
 }
@@ -6,7 +6,10 @@ use std::{
 
 use anyhow::Context;
 use penumbra_compact_block::CompactBlock;
-use penumbra_dex::lp::{position, LpNft};
+use penumbra_dex::{
+    auction::AuctionNft,
+    lp::{position, LpNft},
+};
 use penumbra_keys::FullViewingKey;
 use penumbra_proto::{
     self as proto,
@@ -23,6 +26,9 @@ use penumbra_proto::{
             },
         },
     },
+    util::tendermint_proxy::v1::{
+        tendermint_proxy_service_client::TendermintProxyServiceClient, GetTxRequest,
+    },
 };
 use penumbra_sct::{CommitmentSource, Nullifier};
 use penumbra_transaction::Transaction;
@@ -248,6 +254,40 @@ impl Worker {
                 // Download any transactions we detected.
                 let transactions = self.fetch_transactions(&mut filtered_block).await?;
 
+                // Ask the node which packets our ICS20 withdrawals sent, so that refunds can be
+                // linked to the right withdrawal if the transfers time out.
+                for transaction in &transactions {
+                    let withdrawals = transaction
+                        .actions()
+                        .filter(|action| {
+                            matches!(action, penumbra_transaction::Action::Ics20Withdrawal(_))
+                        })
+                        .count();
+                    if withdrawals == 0 {
+                        continue;
+                    }
+                    let tx_hash = transaction.id().0;
+                    match fetch_packet_sequences(self.channel.clone(), tx_hash).await {
+                        Ok(sequences) if sequences.len() == withdrawals => {
+                            filtered_block
+                                .ics20_packet_sequences
+                                .insert(tx_hash, sequences);
+                        }
+                        // Without the sequences, refunds are linked by return address alone.
+                        Ok(sequences) => tracing::warn!(
+                            tx_hash = ?hex::encode(tx_hash),
+                            ?sequences,
+                            withdrawals,
+                            "could not match ics20 withdrawals to the packets they sent"
+                        ),
+                        Err(e) => tracing::warn!(
+                            tx_hash = ?hex::encode(tx_hash),
+                            ?e,
+                            "could not fetch the packets sent by ics20 withdrawals"
+                        ),
+                    }
+                }
+
                 // LPNFT asset IDs won't be known to the chain, so we need to pre-populate them in the local
                 // registry based on transaction contents.
                 for transaction in &transactions {
@@ -281,6 +321,20 @@ impl Worker {
                                     .record_position(position_open.position.clone())
                                     .await?;
                             }
+                            penumbra_transaction::Action::PositionOpenBatch(batch) => {
+                                for position in &batch.positions {
+                                    let position_id = position.id();
+                                    for state in [
+                                        position::State::Opened,
+                                        position::State::Closed,
+                                        position::State::Withdrawn { sequence: 0 },
+                                    ] {
+                                        let lp_nft = LpNft::new(position_id, state);
+                                        self.storage.record_asset(lp_nft.denom()).await?;
+                                    }
+                                    self.storage.record_position(position.clone()).await?;
+                                }
+                            }
                             penumbra_transaction::Action::PositionClose(position_close) => {
                                 let position_id = position_close.position_id;
 
@@ -303,6 +357,68 @@ impl Worker {
                                 // Update the position record
                                 self.storage.update_position(position_id, state).await?;
                             }
+                            penumbra_transaction::Action::PositionUpdateBatch(batch) => {
+                                for position_id in &batch.closes {
+                                    self.storage
+                                        .update_position(*position_id, position::State::Closed)
+                                        .await?;
+                                }
+
+                                for withdrawal in &batch.withdrawals {
+                                    let state = position::State::Withdrawn {
+                                        sequence: withdrawal.sequence,
+                                    };
+                                    let lp_nft = LpNft::new(withdrawal.position_id, state);
+                                    self.storage.record_asset(lp_nft.denom()).await?;
+                                    self.storage
+                                        .update_position(withdrawal.position_id, state)
+                                        .await?;
+                                }
+
+                                for position in &batch.opens {
+                                    let position_id = position.id();
+                                    for state in [
+                                        position::State::Opened,
+                                        position::State::Closed,
+                                        position::State::Withdrawn { sequence: 0 },
+                                    ] {
+                                        let lp_nft = LpNft::new(position_id, state);
+                                        self.storage.record_asset(lp_nft.denom()).await?;
+                                    }
+                                    self.storage.record_position(position.clone()).await?;
+                                }
+                            }
+                            penumbra_transaction::Action::PositionRewrite(rewrite) => {
+                                // The replaced position is withdrawn immediately.
+                                let state = position::State::Withdrawn { sequence: 0 };
+                                let lp_nft = LpNft::new(rewrite.position_id, state);
+                                self.storage.record_asset(lp_nft.denom()).await?;
+                                self.storage
+                                    .update_position(rewrite.position_id, state)
+                                    .await?;
+
+                                let position_id = rewrite.new_position.id();
+                                for state in [
+                                    position::State::Opened,
+                                    position::State::Closed,
+                                    position::State::Withdrawn { sequence: 0 },
+                                ] {
+                                    let lp_nft = LpNft::new(position_id, state);
+                                    self.storage.record_asset(lp_nft.denom()).await?;
+                                }
+                                self.storage
+                                    .record_position(rewrite.new_position.clone())
+                                    .await?;
+                            }
+                            penumbra_transaction::Action::ActionDutchAuctionSchedule(schedule) => {
+                                let auction_id = schedule.description.id();
+
+                                // Record all possible auction NFTs for this auction.
+                                for seq in 0..=2 {
+                                    let auction_nft = AuctionNft::new(auction_id, seq);
+                                    self.storage.record_asset(auction_nft.denom()).await?;
+                                }
+                            }
                             _ => (),
                         };
                     }
@@ -425,6 +541,27 @@ async fn fetch_transactions(
     Ok(transactions)
 }
 
+// Fetches the sequence numbers of the packets sent by the transaction, in the order they were
+// sent, from the events the node recorded when executing it.
+async fn fetch_packet_sequences(channel: Channel, tx_hash: [u8; 32]) -> anyhow::Result<Vec<u64>> {
+    let mut client = TendermintProxyServiceClient::new(channel);
+    let tx_result = client
+        .get_tx(GetTxRequest {
+            hash: tx_hash.to_vec(),
+            prove: false,
+        })
+        .await?
+        .into_inner()
+        .tx_result
+        .context("missing tx result")?;
+    tx_result
+        .tags
+        .into_iter()
+        .filter(|tag| tag.key == b"packet_sequence")
+        .map(|tag| Ok(String::from_utf8(tag.value)?.parse()?))
+        .collect()
+}
+
 #[cfg(feature = "sct-divergence-check")]
 async fn sct_divergence_check(
     channel: Channel,
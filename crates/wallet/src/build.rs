@@ -2,9 +2,11 @@ use anyhow::Result;
 
 use penumbra_custody::{AuthorizeRequest, CustodyClient};
 use penumbra_keys::FullViewingKey;
-use penumbra_transaction::{AuthorizationData, Transaction, TransactionPlan};
+use penumbra_transaction::{AuthorizationData, Transaction, TransactionPlan, WitnessData};
 use penumbra_view::ViewClient;
 
+use crate::partial::PartiallyAuthorizedTransaction;
+
 pub async fn build_transaction<V, C>(
     fvk: &FullViewingKey,
     view: &mut V,
@@ -15,20 +17,38 @@ where
     V: ViewClient,
     C: CustodyClient,
 {
-    // Get the authorization data from the custody service...
+    // Send a witness request to the view service to get witness data
+    let witness_data = view.witness(&plan).await?;
+
+    build_transaction_with_witness(fvk, custody, plan, witness_data).await
+}
+
+/// Build a transaction using witness data which was obtained elsewhere, such as
+/// for a swap claim delegated to us, whose swap isn't in our view service's tree.
+pub async fn build_transaction_with_witness<C>(
+    fvk: &FullViewingKey,
+    custody: &mut C,
+    plan: TransactionPlan,
+    witness_data: WitnessData,
+) -> Result<Transaction>
+where
+    C: CustodyClient,
+{
+    // Get a nonce binding the request to this session, if the custody service supports it...
+    let nonce = custody.challenge().await?;
+
+    // ... then get the authorization data from the custody service...
     let auth_data: AuthorizationData = custody
         .authorize(AuthorizeRequest {
             plan: plan.clone(),
             pre_authorizations: Vec::new(),
+            nonce,
         })
         .await?
         .data
         .ok_or_else(|| anyhow::anyhow!("empty AuthorizeResponse message"))?
         .try_into()?;
 
-    // Send a witness request to the view service to get witness data
-    let witness_data = view.witness(&plan).await?;
-
     // ... and then build the transaction:
     #[cfg(not(feature = "parallel"))]
     {
@@ -46,3 +66,44 @@ where
         Ok(tx)
     }
 }
+
+/// Ask the custody service to authorize a partially authorized transaction, with the
+/// pre-authorizations collected for it so far, adding the authorizations it returns to the
+/// bundle.
+///
+/// Returns the number of authorizations which were added.
+pub async fn authorize_partially<C>(
+    custody: &mut C,
+    bundle: &mut PartiallyAuthorizedTransaction,
+) -> Result<usize>
+where
+    C: CustodyClient,
+{
+    let nonce = custody.challenge().await?;
+    let auth_data: AuthorizationData = custody
+        .authorize(bundle.authorize_request(nonce))
+        .await?
+        .data
+        .ok_or_else(|| anyhow::anyhow!("empty AuthorizeResponse message"))?
+        .try_into()?;
+
+    bundle.add_authorization(&auth_data)
+}
+
+/// Build a transaction from a fully authorized bundle.
+pub async fn build_partially_authorized<V>(
+    view: &mut V,
+    bundle: &PartiallyAuthorizedTransaction,
+) -> Result<Transaction>
+where
+    V: ViewClient,
+{
+    let auth_data = bundle.finalize()?;
+    let witness_data = view.witness(&bundle.plan).await?;
+
+    let tx = bundle
+        .plan
+        .clone()
+        .build(&bundle.full_viewing_key, &witness_data, &auth_data)?;
+    Ok(tx)
+}
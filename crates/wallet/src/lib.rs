@@ -1,6 +1,10 @@
 #![deny(clippy::unwrap_used)]
 #![cfg_attr(docsrs, feature(doc_auto_cfg))]
 mod build;
-pub use build::build_transaction;
+pub use build::{
+    authorize_partially, build_partially_authorized, build_transaction,
+    build_transaction_with_witness,
+};
 
+pub mod partial;
 pub mod plan;
@@ -0,0 +1,495 @@
+//! Partially authorized transactions, for authorizing a plan across several parties.
+//!
+//! A [`PartiallyAuthorizedTransaction`] bundles a [`TransactionPlan`] with the authorizations
+//! collected for it so far, in a stable file format, in the manner of a Bitcoin PSBT.
+//!
+//! A bundle is made for a plan spending the notes of a single wallet, identified by its full
+//! viewing key, which fixes the plan's effect hash. The wallet's owner creates the bundle and
+//! passes it to the approvers whose pre-authorizations the wallet's custodian requires, each of
+//! which adds its own. The partial copies are [`merge`](PartiallyAuthorizedTransaction::merge)d,
+//! the custodian is asked to [authorize](PartiallyAuthorizedTransaction::authorize_request) the
+//! plan with the collected pre-authorizations, and once every spend and delegator vote is
+//! signed, the bundle is [`finalize`](PartiallyAuthorizedTransaction::finalize)d into the
+//! [`AuthorizationData`] needed to build the transaction.
+
+use std::collections::{btree_map::Entry, BTreeMap};
+
+use anyhow::{Context, Result};
+use decaf377_rdsa::{Signature, SpendAuth};
+use penumbra_custody::{replay::Nonce, AuthorizeRequest, PreAuthorization};
+use penumbra_keys::{Address, FullViewingKey};
+use penumbra_proto::{view::v1 as pb, DomainType};
+use penumbra_transaction::{txhash::EffectHash, AuthorizationData, TransactionPlan};
+use serde::{Deserialize, Serialize};
+
+/// The version of the file format written by this crate.
+pub const FORMAT_VERSION: u32 = 1;
+
+/// A transaction plan, together with the authorizations collected for it so far.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(
+    try_from = "pb::PartiallyAuthorizedTransaction",
+    into = "pb::PartiallyAuthorizedTransaction"
+)]
+pub struct PartiallyAuthorizedTransaction {
+    /// The plan being authorized.
+    pub plan: TransactionPlan,
+    /// The full viewing key of the wallet whose notes the plan spends.
+    pub full_viewing_key: FullViewingKey,
+    /// The effect hash of the plan under the full viewing key.
+    pub effect_hash: EffectHash,
+    /// The spend authorizations collected so far, by the index of the spend among the plan's spends.
+    pub spend_auths: BTreeMap<u32, Signature<SpendAuth>>,
+    /// The delegator vote authorizations collected so far, by the index of the vote among the
+    /// plan's delegator votes.
+    pub delegator_vote_auths: BTreeMap<u32, Signature<SpendAuth>>,
+    /// Pre-authorizations of the plan, for custodians which require them.
+    pub pre_authorizations: Vec<PreAuthorization>,
+    /// Free-form metadata, such as a description of the transaction for reviewers.
+    pub metadata: BTreeMap<String, String>,
+}
+
+impl PartiallyAuthorizedTransaction {
+    /// Start collecting authorizations for a plan spending the notes of the given wallet.
+    ///
+    /// Fails if the plan spends or votes with a note the wallet doesn't control, since the
+    /// effect hash, and so every signature, depends on the key the plan is built with.
+    pub fn new(plan: TransactionPlan, fvk: &FullViewingKey) -> Result<Self> {
+        check_controlled(&plan, fvk)?;
+        let effect_hash = plan.effect_hash(fvk)?;
+        Ok(Self {
+            plan,
+            full_viewing_key: fvk.clone(),
+            effect_hash,
+            spend_auths: BTreeMap::new(),
+            delegator_vote_auths: BTreeMap::new(),
+            pre_authorizations: Vec::new(),
+            metadata: BTreeMap::new(),
+        })
+    }
+
+    /// The request asking the wallet's custodian to authorize the plan, with the
+    /// pre-authorizations collected so far.
+    pub fn authorize_request(&self, nonce: Option<Nonce>) -> AuthorizeRequest {
+        AuthorizeRequest {
+            plan: self.plan.clone(),
+            pre_authorizations: self.pre_authorizations.clone(),
+            nonce,
+        }
+    }
+
+    /// Add the authorizations returned by the wallet's custodian, returning how many were added.
+    ///
+    /// Slots which are already authorized are left as they are. Fails, leaving this bundle
+    /// unchanged, if any of the signatures is invalid.
+    pub fn add_authorization(&mut self, auth: &AuthorizationData) -> Result<usize> {
+        if let Some(effect_hash) = &auth.effect_hash {
+            anyhow::ensure!(
+                *effect_hash == self.effect_hash,
+                "authorization is for a different effect hash"
+            );
+        }
+        anyhow::ensure!(
+            auth.spend_auths.len() == self.plan.spend_plans().count()
+                && auth.delegator_vote_auths.len() == self.plan.delegator_vote_plans().count(),
+            "authorization does not match the number of spends and delegator votes in the plan"
+        );
+
+        let mut updated = self.clone();
+        let mut added = 0;
+        for (slots, signatures) in [
+            (&mut updated.spend_auths, &auth.spend_auths),
+            (
+                &mut updated.delegator_vote_auths,
+                &auth.delegator_vote_auths,
+            ),
+        ] {
+            for (index, signature) in signatures.iter().enumerate() {
+                if let Entry::Vacant(slot) = slots.entry(u32::try_from(index)?) {
+                    slot.insert(*signature);
+                    added += 1;
+                }
+            }
+        }
+        updated.verify()?;
+
+        *self = updated;
+        Ok(added)
+    }
+
+    /// Add a pre-authorization of the plan, unless it is already present.
+    pub fn add_pre_authorization(&mut self, pre_authorization: PreAuthorization) {
+        let encoded = pre_authorization.encode_to_vec();
+        if !self
+            .pre_authorizations
+            .iter()
+            .any(|existing| existing.encode_to_vec() == encoded)
+        {
+            self.pre_authorizations.push(pre_authorization);
+        }
+    }
+
+    /// Merge in the authorizations of another copy of this bundle.
+    ///
+    /// Fails, leaving this bundle unchanged, if the other copy is for a different plan or
+    /// wallet, if it is invalid, or if the copies disagree on the value of a metadata key.
+    pub fn merge(&mut self, other: PartiallyAuthorizedTransaction) -> Result<()> {
+        anyhow::ensure!(
+            self.plan.encode_to_vec() == other.plan.encode_to_vec(),
+            "cannot merge authorizations for a different plan"
+        );
+        anyhow::ensure!(
+            self.full_viewing_key == other.full_viewing_key,
+            "cannot merge authorizations for a different wallet"
+        );
+        other.verify()?;
+
+        let mut merged = self.clone();
+        for (slots, others) in [
+            (&mut merged.spend_auths, other.spend_auths),
+            (&mut merged.delegator_vote_auths, other.delegator_vote_auths),
+        ] {
+            // Signatures are randomized, so the copies may hold different signatures for the
+            // same action, and either one will do.
+            for (index, signature) in others {
+                slots.entry(index).or_insert(signature);
+            }
+        }
+        for pre_authorization in other.pre_authorizations {
+            merged.add_pre_authorization(pre_authorization);
+        }
+        for (key, value) in other.metadata {
+            match merged.metadata.get(&key) {
+                Some(existing) => anyhow::ensure!(
+                    *existing == value,
+                    "conflicting values for metadata key {key:?}"
+                ),
+                None => {
+                    merged.metadata.insert(key, value);
+                }
+            }
+        }
+
+        *self = merged;
+        Ok(())
+    }
+
+    /// Check that the bundle is consistent: the plan only spends notes of the wallet, the effect
+    /// hash is the plan's, and every authorization collected so far is a valid signature for its
+    /// action.
+    pub fn verify(&self) -> Result<()> {
+        check_controlled(&self.plan, &self.full_viewing_key)?;
+        anyhow::ensure!(
+            self.plan.effect_hash(&self.full_viewing_key)? == self.effect_hash,
+            "effect hash does not match the plan"
+        );
+
+        let spends: Vec<_> = self.plan.spend_plans().map(|s| s.randomizer).collect();
+        let votes: Vec<_> = self
+            .plan
+            .delegator_vote_plans()
+            .map(|v| v.randomizer)
+            .collect();
+        let signer = self.full_viewing_key.spend_verification_key();
+        for (slots, randomizers) in [
+            (&self.spend_auths, &spends),
+            (&self.delegator_vote_auths, &votes),
+        ] {
+            for (index, signature) in slots {
+                let randomizer = randomizers
+                    .get(*index as usize)
+                    .with_context(|| format!("authorization for nonexistent action {index}"))?;
+                signer
+                    .randomize(randomizer)
+                    .verify(self.effect_hash.as_ref(), signature)
+                    .map_err(|_| anyhow::anyhow!("invalid signature for action {index}"))?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Whether every spend and delegator vote in the plan is authorized.
+    pub fn is_complete(&self) -> bool {
+        self.spend_auths.len() == self.plan.spend_plans().count()
+            && self.delegator_vote_auths.len() == self.plan.delegator_vote_plans().count()
+    }
+
+    /// Finish authorizing the plan, returning the [`AuthorizationData`] needed to build it.
+    ///
+    /// Fails if any spend or delegator vote is not yet authorized, or the bundle is invalid.
+    pub fn finalize(&self) -> Result<AuthorizationData> {
+        self.verify()?;
+        anyhow::ensure!(
+            self.is_complete(),
+            "plan is missing {} of {} spend authorizations and {} of {} delegator vote authorizations",
+            self.plan.spend_plans().count() - self.spend_auths.len(),
+            self.plan.spend_plans().count(),
+            self.plan.delegator_vote_plans().count() - self.delegator_vote_auths.len(),
+            self.plan.delegator_vote_plans().count(),
+        );
+        Ok(AuthorizationData {
+            effect_hash: Some(self.effect_hash),
+            spend_auths: self.spend_auths.values().copied().collect(),
+            delegator_vote_auths: self.delegator_vote_auths.values().copied().collect(),
+        })
+    }
+}
+
+/// Check that every note the plan spends or votes with is controlled by the full viewing key.
+fn check_controlled(plan: &TransactionPlan, fvk: &FullViewingKey) -> Result<()> {
+    let controls = |address: &Address| fvk.address_index(address).is_some();
+    anyhow::ensure!(
+        plan.spend_plans()
+            .all(|spend| controls(&spend.note.address()))
+            && plan
+                .delegator_vote_plans()
+                .all(|vote| controls(&vote.staked_note.address())),
+        "plan spends notes which are not controlled by the wallet"
+    );
+    Ok(())
+}
+
+impl DomainType for PartiallyAuthorizedTransaction {
+    type Proto = pb::PartiallyAuthorizedTransaction;
+}
+
+impl From<PartiallyAuthorizedTransaction> for pb::PartiallyAuthorizedTransaction {
+    fn from(msg: PartiallyAuthorizedTransaction) -> Self {
+        let auths = |slots: BTreeMap<u32, Signature<SpendAuth>>| {
+            slots
+                .into_iter()
+                .map(|(index, signature)| pb::PartialAuthorization {
+                    index,
+                    signature: Some(signature.into()),
+                })
+                .collect()
+        };
+        Self {
+            version: FORMAT_VERSION,
+            plan: Some(msg.plan.into()),
+            effect_hash: Some(msg.effect_hash.into()),
+            spend_auths: auths(msg.spend_auths),
+            delegator_vote_auths: auths(msg.delegator_vote_auths),
+            pre_authorizations: msg.pre_authorizations.into_iter().map(Into::into).collect(),
+            metadata: msg
+                .metadata
+                .into_iter()
+                .map(|(key, value)| pb::PartiallyAuthorizedTransactionMetadata { key, value })
+                .collect(),
+            full_viewing_key: Some(msg.full_viewing_key.into()),
+        }
+    }
+}
+
+impl TryFrom<pb::PartiallyAuthorizedTransaction> for PartiallyAuthorizedTransaction {
+    type Error = anyhow::Error;
+
+    fn try_from(value: pb::PartiallyAuthorizedTransaction) -> Result<Self, Self::Error> {
+        anyhow::ensure!(
+            value.version == FORMAT_VERSION,
+            "unsupported partially authorized transaction version {}",
+            value.version
+        );
+        let auths = |slots: Vec<pb::PartialAuthorization>| {
+            let mut auths = BTreeMap::new();
+            for auth in slots {
+                let signature = auth
+                    .signature
+                    .ok_or_else(|| anyhow::anyhow!("missing signature field in proto"))?
+                    .try_into()?;
+                anyhow::ensure!(
+                    auths.insert(auth.index, signature).is_none(),
+                    "duplicate authorization for action {}",
+                    auth.index
+                );
+            }
+            Ok::<_, anyhow::Error>(auths)
+        };
+        Ok(Self {
+            plan: value
+                .plan
+                .ok_or_else(|| anyhow::anyhow!("missing plan field in proto"))?
+                .try_into()?,
+            full_viewing_key: value
+                .full_viewing_key
+                .ok_or_else(|| anyhow::anyhow!("missing full_viewing_key field in proto"))?
+                .try_into()?,
+            effect_hash: value
+                .effect_hash
+                .ok_or_else(|| anyhow::anyhow!("missing effect_hash field in proto"))?
+                .try_into()?,
+            spend_auths: auths(value.spend_auths)?,
+            delegator_vote_auths: auths(value.delegator_vote_auths)?,
+            pre_authorizations: value
+                .pre_authorizations
+                .into_iter()
+                .map(TryInto::try_into)
+                .collect::<Result<_, _>>()?,
+            metadata: value
+                .metadata
+                .into_iter()
+                .map(|entry| (entry.key, entry.value))
+                .collect(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ed25519_consensus::SigningKey;
+    use penumbra_asset::{Value, STAKING_TOKEN_ASSET_ID};
+    use penumbra_custody::{
+        policy::{AuthPolicy, PreAuthorizationPolicy},
+        soft_kms::{self, SoftKms},
+    };
+    use penumbra_keys::keys::{Bip44Path, SeedPhrase, SpendKey};
+    use penumbra_proto::custody::v1 as custody_pb;
+    use penumbra_shielded_pool::{Note, SpendPlan};
+    use penumbra_tct as tct;
+    use penumbra_transaction::{txhash::EffectingData as _, WitnessData};
+    use rand_core::OsRng;
+
+    use super::*;
+
+    fn spend_key(seed: u8) -> SpendKey {
+        let seed_phrase = SeedPhrase::from_randomness(&[seed; 32]);
+        SpendKey::from_seed_phrase_bip44(seed_phrase, &Bip44Path::new(0))
+    }
+
+    /// A plan spending notes of the given amounts, received by the given accounts of the wallet,
+    /// along with the tree the notes were committed to.
+    fn spend_plan(fvk: &FullViewingKey, notes: &[(u32, u64)]) -> (TransactionPlan, tct::Tree) {
+        let mut plan = TransactionPlan::default();
+        let mut sct = tct::Tree::new();
+        for (account, amount) in notes {
+            let (address, _) = fvk.payment_address((*account).into());
+            let value = Value {
+                amount: (*amount).into(),
+                asset_id: *STAKING_TOKEN_ASSET_ID,
+            };
+            let note = Note::generate(&mut OsRng, &address, value);
+            let position = sct
+                .insert(tct::Witness::Keep, note.commit())
+                .expect("tree is not full");
+            plan.actions
+                .push(SpendPlan::new(&mut OsRng, note, position).into());
+        }
+        (plan, sct)
+    }
+
+    fn pre_authorize(approver: &SigningKey, plan: &TransactionPlan) -> Result<PreAuthorization> {
+        custody_pb::PreAuthorization {
+            pre_authorization: Some(custody_pb::pre_authorization::PreAuthorization::Ed25519(
+                custody_pb::pre_authorization::Ed25519 {
+                    vk: approver.verification_key().to_bytes().to_vec(),
+                    sig: approver.sign(&plan.encode_to_vec()).to_bytes().to_vec(),
+                },
+            )),
+        }
+        .try_into()
+    }
+
+    #[test]
+    fn approved_bundles_are_authorized_by_the_custodian_and_build() -> Result<()> {
+        let sk = spend_key(1);
+        let fvk = sk.full_viewing_key();
+        let approvers: Vec<_> = (0..3).map(|_| SigningKey::new(OsRng)).collect();
+        let custodian = SoftKms::new(soft_kms::Config {
+            auth_policy: vec![AuthPolicy::PreAuthorization(
+                PreAuthorizationPolicy::Ed25519 {
+                    required_signatures: 2,
+                    allowed_signers: approvers.iter().map(|a| a.verification_key()).collect(),
+                },
+            )],
+            ..soft_kms::Config::from(sk.clone())
+        })?;
+
+        // Spend notes received by two accounts of the wallet.
+        let (plan, sct) = spend_plan(fvk, &[(0, 1), (1, 2)]);
+        let mut bundle = PartiallyAuthorizedTransaction::new(plan, fvk)?;
+        bundle
+            .metadata
+            .insert("description".to_string(), "approved spend".to_string());
+
+        // One approval isn't enough for the custodian.
+        let mut first = bundle.clone();
+        first.add_pre_authorization(pre_authorize(&approvers[0], &bundle.plan)?);
+        assert!(custodian.sign(&first.authorize_request(None)).is_err());
+
+        // The bundle survives being written to a file and read back by another approver.
+        let json = serde_json::to_string(&bundle)?;
+        let mut second: PartiallyAuthorizedTransaction = serde_json::from_str(&json)?;
+        second.add_pre_authorization(pre_authorize(&approvers[2], &second.plan)?);
+        first.merge(second)?;
+        assert_eq!(first.pre_authorizations.len(), 2);
+        assert!(!first.is_complete());
+        assert!(first.finalize().is_err());
+
+        // With both approvals, the custodian signs, and the transaction builds.
+        let auth = custodian.sign(&first.authorize_request(None))?;
+        assert_eq!(first.add_authorization(&auth)?, 2);
+        assert_eq!(first.add_authorization(&auth)?, 0);
+        let auth = first.finalize()?;
+
+        let witness_data = WitnessData {
+            anchor: sct.root(),
+            state_commitment_proofs: first
+                .plan
+                .spend_plans()
+                .map(|spend| {
+                    let commitment = spend.note.commit();
+                    let proof = sct.witness(commitment).expect("note is witnessed");
+                    (commitment, proof)
+                })
+                .collect(),
+        };
+        let transaction = first.plan.clone().build(fvk, &witness_data, &auth)?;
+        assert_eq!(transaction.effect_hash(), bundle.effect_hash);
+        for spend in transaction.spends() {
+            spend
+                .body
+                .rk
+                .verify(bundle.effect_hash.as_ref(), &spend.auth_sig)?;
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn bundles_only_hold_one_wallets_authorizations() -> Result<()> {
+        let (alice, bob) = (spend_key(1), spend_key(2));
+        let (plan, _) = spend_plan(alice.full_viewing_key(), &[(0, 1)]);
+        let mut bundle =
+            PartiallyAuthorizedTransaction::new(plan.clone(), alice.full_viewing_key())?;
+
+        // The plan can't spend another wallet's notes...
+        assert!(PartiallyAuthorizedTransaction::new(plan.clone(), bob.full_viewing_key()).is_err());
+        let (mut joint, _) = spend_plan(bob.full_viewing_key(), &[(0, 1)]);
+        joint.actions.extend(plan.actions);
+        assert!(PartiallyAuthorizedTransaction::new(joint, alice.full_viewing_key()).is_err());
+
+        // ... and another wallet's signatures, or a copy for another wallet, can't be added.
+        let forged = bob
+            .spend_auth_key()
+            .randomize(
+                &bundle
+                    .plan
+                    .spend_plans()
+                    .next()
+                    .expect("one spend")
+                    .randomizer,
+            )
+            .sign(OsRng, bundle.effect_hash.as_ref());
+        assert!(bundle
+            .add_authorization(&AuthorizationData {
+                effect_hash: Some(bundle.effect_hash),
+                spend_auths: vec![forged],
+                delegator_vote_auths: Vec::new(),
+            })
+            .is_err());
+        let mut other = bundle.clone();
+        other.full_viewing_key = bob.full_viewing_key().clone();
+        assert!(bundle.merge(other).is_err());
+        assert!(bundle.spend_auths.is_empty());
+        Ok(())
+    }
+}
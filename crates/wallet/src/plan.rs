@@ -6,7 +6,7 @@ use decaf377::Fq;
 use rand_core::{CryptoRng, RngCore};
 use tracing::instrument;
 
-use penumbra_asset::Value;
+use penumbra_asset::{Value, STAKING_TOKEN_ASSET_ID};
 use penumbra_dex::swap_claim::SwapClaimPlan;
 use penumbra_fee::Fee;
 use penumbra_governance::{proposal_state, Proposal, ValidatorVote};
@@ -174,6 +174,7 @@ where
             position: swap.position,
             output_data,
             epoch_duration,
+            claim_key: None,
             proof_blinding_r: Fq::rand(&mut rng),
             proof_blinding_s: Fq::rand(&mut rng),
         };
@@ -261,10 +262,14 @@ where
         .fee(fee)
         .proposal_submit(
             proposal,
-            view.app_params()
-                .await?
-                .governance_params
-                .proposal_deposit_amount,
+            Value {
+                amount: view
+                    .app_params()
+                    .await?
+                    .governance_params
+                    .proposal_deposit_amount,
+                asset_id: *STAKING_TOKEN_ASSET_ID,
+            },
         )
         .plan(view, source_address)
         .await
@@ -299,7 +304,7 @@ pub async fn proposal_deposit_claim<V, R>(
     view: &mut V,
     rng: R,
     proposal_id: u64,
-    deposit_amount: Amount,
+    deposit: Value,
     outcome: proposal_state::Outcome<()>,
     fee: Fee,
     source_address: AddressIndex,
@@ -310,7 +315,7 @@ where
 {
     Planner::new(rng)
         .fee(fee)
-        .proposal_deposit_claim(proposal_id, deposit_amount, outcome)
+        .proposal_deposit_claim(proposal_id, deposit, outcome)
         .plan(view, source_address)
         .await
         .context("can't build proposal withdraw transaction")
@@ -113,6 +113,7 @@ fn main() -> anyhow::Result<()> {
                 // Also included in the cnidarium crate directly.
                 "../../proto/penumbra/penumbra/cnidarium/v1/cnidarium.proto",
                 "../../proto/penumbra/penumbra/tools/summoning/v1/summoning.proto",
+                "../../proto/penumbra/penumbra/util/error/v1/error.proto",
                 "../../proto/penumbra/penumbra/util/tendermint_proxy/v1/tendermint_proxy.proto",
                 "../../proto/penumbra/penumbra/view/v1/view.proto",
                 "../../proto/rust-vendored/tendermint/types/validator.proto",